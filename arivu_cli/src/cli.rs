@@ -157,15 +157,121 @@ pub enum Commands {
   DOI:           https://doi.org/10.xxx, 10.1234/example
   Any URL:       Falls back to web scraper
 
+\x1b[1;33mFallback:\x1b[0m
+  When the live fetch fails, Arivu tries the local response cache, the closest
+  Wayback Machine snapshot and a search provider's crawled copy (URLs only).
+  The output's served_by field says which source answered.
+
+\x1b[1;33mLanguage:\x1b[0m
+  The output's language field names the detected language of the text.
+  --translate <LANG> adds a translation when ARIVU_TRANSLATE_BACKEND is set
+  (deepl with DEEPL_API_KEY, or openai for any OpenAI-compatible chat API).
+
 \x1b[1;33mExamples:\x1b[0m
   arivu fetch https://www.youtube.com/watch?v=dQw4w9WgXcQ
   arivu fetch arXiv:2301.07041
   arivu fetch PMID:12345678
   arivu fetch rust-lang/rust
-  arivu fetch r/rust")]
+  arivu fetch r/rust
+  arivu fetch https://example.com/gone --fallback wayback
+  arivu fetch https://example.com --fallback live,cache --max-age 24
+  arivu fetch arXiv:2301.07041 --document --output json
+  arivu fetch https://www.lemonde.fr/article --translate en")]
     Fetch {
         /// URL or ID to fetch (auto-detected)
         input: String,
+        /// Sources to try in order: live, cache, wayback, search (wayback and search send the URL to a third party)
+        #[arg(
+            long,
+            env = "ARIVU_FETCH_FALLBACK",
+            default_value = arivu_core::fetch_fallback::DEFAULT_CHAIN
+        )]
+        fallback: String,
+        /// Ignore cached responses older than this many hours
+        #[arg(long, value_name = "HOURS")]
+        max_age: Option<u64>,
+        /// Map the result to the shared document shape (title, authors, published_at, url, sections, text)
+        #[arg(long)]
+        document: bool,
+        /// Translate the text into this language (e.g. en) using the configured backend
+        #[arg(long, env = "ARIVU_TRANSLATE_TO", value_name = "LANG")]
+        translate: Option<String>,
+    },
+
+    /// Research a question across connectors and cite the sources behind each claim
+    ///
+    /// Plans searches across the enabled connectors, merges duplicate hits, fetches the top
+    /// sources and extracts the sentences that answer the question.
+    #[command(after_help = "\x1b[1;33mPlanning:\x1b[0m
+  Web search providers get the question as asked; other connectors get its keywords.
+  Wikipedia is always searched, the research profile joins for research questions and
+  the social profile for opinions and comparisons. --sources replaces the plan.
+
+\x1b[1;33mExamples:\x1b[0m
+  arivu research \"Does intermittent fasting extend lifespan?\"
+  arivu research \"Is Zed worth switching to?\" --max-fetch 3
+  arivu research \"CRISPR off-target effects\" -s pubmed,arxiv --output json")]
+    Research {
+        /// The question to research
+        question: String,
+        /// Comma-separated connectors to search instead of the planned ones
+        #[arg(short = 's', long)]
+        sources: Option<String>,
+        /// Maximum number of search results per source
+        #[arg(short, long, default_value_t = 5)]
+        limit: u32,
+        /// How many of the top sources to fetch in full (0 uses search snippets only)
+        #[arg(long, default_value_t = 6)]
+        max_fetch: usize,
+        /// Maximum number of claims to show
+        #[arg(long, default_value_t = 15)]
+        max_claims: usize,
+    },
+
+    /// Search content fetched or found earlier, offline
+    ///
+    /// With ARIVU_INDEX=on every fetched document and search hit is added to a local
+    /// full-text index (~/.arivu/index, or ARIVU_INDEX_DIR).
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  ARIVU_INDEX=on arivu fetch https://arxiv.org/abs/1706.03762
+  arivu index search \"self-attention\"
+  arivu index search \"lifespan mice\" --source pubmed --limit 5
+  ARIVU_EMBED_BACKEND=ollama arivu index semantic \"does fasting slow ageing?\"
+  arivu index stats
+  arivu index clear")]
+    Index {
+        #[command(subcommand)]
+        action: IndexAction,
+    },
+
+    /// Export fetched documents as chunked JSONL for RAG pipelines
+    ///
+    /// Reads JSON saved from `arivu fetch --output json` (files, or - for stdin), or the
+    /// whole local index when no inputs are given. Each line is one chunk with its source,
+    /// title, URL and position in the document.
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu fetch https://arxiv.org/abs/1706.03762 --output json > paper.json
+  arivu export --format rag-jsonl paper.json --out chunks.jsonl
+  arivu fetch https://example.com/post --output json | arivu export --format rag-jsonl -
+  arivu export --format rag-jsonl --chunk-size 800 --overlap 100 --source web > web.jsonl")]
+    Export {
+        /// JSON files from `arivu fetch --output json` (- for stdin); the local index when omitted
+        inputs: Vec<String>,
+        /// Export format
+        #[arg(long, value_enum, default_value = "rag-jsonl")]
+        format: ExportFormat,
+        /// Write to this file instead of stdout
+        #[arg(long, value_name = "PATH")]
+        out: Option<std::path::PathBuf>,
+        /// Maximum tokens per chunk
+        #[arg(long, default_value_t = arivu_core::export::DEFAULT_CHUNK_TOKENS)]
+        chunk_size: usize,
+        /// Tokens shared by consecutive chunks
+        #[arg(long, default_value_t = arivu_core::export::DEFAULT_OVERLAP_TOKENS)]
+        overlap: usize,
+        /// Only export documents from this connector
+        #[arg(short = 's', long)]
+        source: Option<String>,
     },
 
     /// Show all supported URL/ID patterns for auto-detection
@@ -357,13 +463,35 @@ pub enum Commands {
         tool: ParallelSearchTools,
     },
 
+    /// Brave web search
+    #[command(name = "brave-search", alias = "brave")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu brave-search search --query \"rust async programming\"
+  arivu brave-search search --query \"AI news\" --limit 10 --freshness pw --country GB")]
+    BraveSearch {
+        #[command(subcommand)]
+        tool: BraveSearchTools,
+    },
+
+    /// DuckDuckGo web search (no API key)
+    #[command(name = "ddg-search", alias = "ddg")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu ddg-search search --query \"rust async programming\"
+  arivu ddg-search search --query \"AI news\" --limit 5 --date-preset last_7_days")]
+    DdgSearch {
+        #[command(subcommand)]
+        tool: DdgSearchTools,
+    },
+
     /// Google Calendar events and management
     #[command(name = "google-calendar", alias = "gcal")]
     #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
   arivu google-calendar list-events
   arivu google-calendar create-event --summary \"Meeting\" --start \"2025-01-01T10:00:00Z\" --end \"2025-01-01T11:00:00Z\"
   arivu google-calendar update-event --event-id abc123 --summary \"Updated Meeting\"
-  arivu google-calendar delete-event --event-id abc123")]
+  arivu google-calendar create-event --summary \"Sync\" --start \"2025-01-01T10:00:00Z\" --end \"2025-01-01T10:30:00Z\" --attendee ada@example.com --meet --yes
+  arivu google-calendar freebusy --time-min \"2025-01-06T09:00:00Z\" --time-max \"2025-01-06T17:00:00Z\" --calendar primary --calendar ada@example.com
+  arivu google-calendar delete-event --event-id abc123 --yes")]
     GoogleCalendar {
         #[command(subcommand)]
         tool: GoogleCalendarTools,
@@ -375,7 +503,10 @@ pub enum Commands {
   arivu google-drive list-files
   arivu google-drive get-file --file-id abc123
   arivu google-drive download-file --file-id abc123
-  arivu google-drive export-file --file-id abc123 --mime-type application/pdf")]
+  arivu google-drive export-file --file-id abc123 --mime-type application/pdf
+  arivu google-drive export-text --file-id abc123 --range \"Budget!A1:F50\"
+  arivu google-drive tree --folder-id abc123 -d 2
+  arivu google-drive changes --page-token 12345 --folder-id abc123")]
     GoogleDrive {
         #[command(subcommand)]
         tool: GoogleDriveTools,
@@ -407,7 +538,9 @@ pub enum Commands {
     #[command(name = "google-scholar", alias = "gscholar")]
     #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
   arivu google-scholar search-papers --query \"CRISPR gene therapy\"
-  arivu google-scholar search-papers --query \"machine learning\" --limit 20")]
+  arivu google-scholar search-papers --query \"machine learning\" --limit 20
+  arivu google-scholar author --author \"Yann LeCun\" --sort year
+  arivu google-scholar cited-by --query \"Attention is all you need\"")]
     GoogleScholar {
         #[command(subcommand)]
         tool: GoogleScholarTools,
@@ -419,6 +552,9 @@ pub enum Commands {
   arivu atlassian test-auth
   arivu atlassian jira-search --jql \"project = DEMO AND status = Open\"
   arivu atlassian jira-get --key DEMO-123
+  arivu atlassian jira-detail DEMO-123 --comments 10
+  arivu atlassian jira-transition DEMO-123 \"In Progress\" --comment \"Picking this up\" --yes
+  arivu atlassian jira-sprints 42 --state active
   arivu atlassian conf-search --cql \"type = page AND space = DEMO\"
   arivu atlassian conf-get --id 123456")]
     Atlassian {
@@ -433,7 +569,12 @@ pub enum Commands {
   arivu microsoft-graph list-events --days-ahead 7
   arivu microsoft-graph get-message --message-id ABC123
   arivu microsoft-graph send-mail --to user@example.com --subject \"Hello\" --body \"Test\"
-  arivu microsoft-graph create-draft --to user@example.com --subject \"Draft\" --body \"Draft message\"")]
+  arivu microsoft-graph create-draft --to user@example.com --subject \"Draft\" --body \"Draft message\"
+  arivu microsoft-graph list-chats
+  arivu microsoft-graph chat-messages --chat-id 19:abc@thread.v2
+  arivu microsoft-graph search-teams \"rollout plan\"
+  arivu microsoft-graph list-pages --section-id 1-abc
+  arivu microsoft-graph get-page --page-id 1-def")]
     MicrosoftGraph {
         #[command(subcommand)]
         tool: MicrosoftGraphTools,
@@ -445,6 +586,7 @@ pub enum Commands {
   arivu imap list-mailboxes
   arivu imap fetch-messages --limit 20
   arivu imap get-message --uid 12345
+  arivu imap get-attachments --uid 12345 --select Q3.pdf
   arivu imap search --query \"UNSEEN\"
   arivu imap search --query \"FROM alice SINCE 1-Jan-2024\"")]
     Imap {
@@ -452,6 +594,33 @@ pub enum Commands {
         tool: ImapTools,
     },
 
+    /// Email newsletters (issues from your IMAP mailbox)
+    #[command(name = "newsletters", alias = "newsletter")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu newsletters list --days 30
+  arivu newsletters issues --publication stratechery
+  arivu newsletters search \"rate cuts\"
+  arivu newsletters read 48213
+  arivu newsletters set senders news@example.com,digest.example.org")]
+    Newsletters {
+        #[command(subcommand)]
+        tool: NewslettersTools,
+    },
+
+    /// Webhook inbox: receive pushed events and read them back
+    #[command(name = "inbox", alias = "webhooks")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu inbox serve --listen 127.0.0.1:8787
+  arivu inbox secret github <webhook-secret>
+  arivu inbox list --source github --unacked
+  arivu inbox search cus_N1x
+  arivu inbox get evt_20250701090000123_0001
+  arivu inbox ack evt_20250701090000123_0001")]
+    Inbox {
+        #[command(subcommand)]
+        tool: InboxTools,
+    },
+
     /// Local filesystem text extraction (PDF, EPUB, DOCX, HTML, Markdown, code)
     #[command(name = "localfs", alias = "fs", alias = "file")]
     #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
@@ -463,6 +632,19 @@ pub enum Commands {
         tool: LocalfsTools,
     },
 
+    /// Local paper library: search and read downloaded PDFs by section
+    #[command(name = "paper-library", alias = "papers")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu paper-library dir ~/Papers
+  arivu paper-library search \"contrastive pretraining\" --section methods
+  arivu paper-library get 10.1038/nature14539 --section abstract --section conclusion
+  arivu paper-library list --sort title
+  arivu paper-library reindex --force")]
+    PaperLibrary {
+        #[command(subcommand)]
+        tool: PaperLibraryTools,
+    },
+
     /// YouTube video details, transcripts, and search
     #[command(name = "youtube", alias = "yt")]
     #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
@@ -568,7 +750,9 @@ pub enum Commands {
     #[command(name = "x", alias = "twitter")]
     #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
   arivu x profile --username elonmusk
-  arivu x search --query \"rust lang\" --limit 20")]
+  arivu x search --query \"rust lang\" --limit 20
+  arivu x thread https://x.com/user/status/1790000000000000001
+  arivu x list-timeline 1234567890 --limit 50")]
     X {
         #[command(subcommand)]
         tool: XTools,
@@ -588,7 +772,9 @@ pub enum Commands {
     #[command(name = "rss")]
     #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
   arivu rss feed --url https://example.com/feed.xml
-  arivu rss entries --url https://example.com/feed.xml --limit 20")]
+  arivu rss entries --url https://example.com/feed.xml --limit 20
+  arivu rss import ~/Downloads/feeds.opml
+  arivu rss new --category Rust --limit 10")]
     Rss {
         #[command(subcommand)]
         tool: RssTools,
@@ -604,10 +790,311 @@ pub enum Commands {
         tool: BiorxivTools,
     },
 
+    /// Crossref DOI metadata, funders and journals
+    #[command(name = "crossref")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu crossref work --doi 10.1038/nature12373
+  arivu crossref search \"attention is all you need\" --limit 5
+  arivu crossref funders \"National Science Foundation\"
+  arivu crossref journal-works --issn 2041-1723 --query CRISPR")]
+    Crossref {
+        #[command(subcommand)]
+        tool: CrossrefTools,
+    },
+
+    /// DBLP computer science bibliography
+    #[command(name = "dblp")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu dblp search \"attention is all you need\" --bibtex
+  arivu dblp authors \"Donald Knuth\"
+  arivu dblp author-pubs --pid k/DonaldEKnuth --limit 20
+  arivu dblp venue --venue ICML --year 2023
+  arivu dblp bibtex --key conf/nips/VaswaniSPUJGKP17")]
+    Dblp {
+        #[command(subcommand)]
+        tool: DblpTools,
+    },
+
+    /// Stock/ETF/FX quotes and price history (Yahoo Finance, Stooq)
+    #[command(name = "market-data", alias = "stocks")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu market-data quote AAPL,MSFT
+  arivu market-data history AAPL --start-date 2024-01-01 --end-date 2024-03-31
+  arivu market-data history ^GSPC --range 1y --interval 1wk
+  arivu market-data search nvidia")]
+    MarketData {
+        #[command(subcommand)]
+        tool: MarketDataTools,
+    },
+
+    /// CoinGecko crypto prices, markets and charts
+    #[command(name = "coingecko", alias = "crypto")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu coingecko search solana
+  arivu coingecko price bitcoin,ethereum --vs usd,eur
+  arivu coingecko markets --limit 20
+  arivu coingecko chart bitcoin --days 90
+  arivu coingecko chart ethereum --start-date 2024-01-01 --end-date 2024-06-30")]
+    Coingecko {
+        #[command(subcommand)]
+        tool: CoingeckoTools,
+    },
+
+    /// Open Library books, editions and authors
+    #[command(name = "openlibrary", alias = "books")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu openlibrary search --title dune --author herbert
+  arivu openlibrary search --isbn 9780441013593
+  arivu openlibrary work OL45804W
+  arivu openlibrary edition --isbn 9780441013593
+  arivu openlibrary author-works OL26320A --limit 50")]
+    Openlibrary {
+        #[command(subcommand)]
+        tool: OpenlibraryTools,
+    },
+
+    /// Podcast search, episodes and transcripts
+    #[command(name = "podcasts", alias = "podcast")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu podcasts search \"hardcore history\"
+  arivu podcasts episodes \"rust programming\" --limit 5
+  arivu podcasts list --id 1150510297 --limit 10
+  arivu podcasts episode --feed-url https://example.com/feed.xml --title \"episode 42\"
+  arivu podcasts transcript --id 1150510297 --title \"episode 42\"")]
+    Podcasts {
+        #[command(subcommand)]
+        tool: PodcastsTools,
+    },
+
+    /// Twitch channels, streams, VODs, clips and chat replay
+    #[command(name = "twitch")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu twitch channels speedrun --live
+  arivu twitch streams --game Chess --language en
+  arivu twitch videos gamesdonequick --type archive
+  arivu twitch clips --channel xqc --since 2024-01-01T00:00:00Z
+  arivu twitch chat 1234567890 --offset 3600 --limit 200")]
+    Twitch {
+        #[command(subcommand)]
+        tool: TwitchTools,
+    },
+
+    /// Vimeo video search, metadata and captions
+    #[command(name = "vimeo")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu vimeo search \"short film\" --limit 5
+  arivu vimeo get 76979871
+  arivu vimeo get https://vimeo.com/76979871 --detailed
+  arivu vimeo captions 76979871 --language en --vtt")]
+    Vimeo {
+        #[command(subcommand)]
+        tool: VimeoTools,
+    },
+
+    /// Salesforce SOQL queries, records and object metadata
+    #[command(name = "salesforce", alias = "sf")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu salesforce objects --filter invoice
+  arivu salesforce describe Opportunity --picklists
+  arivu salesforce query \"SELECT Id, Name FROM Account WHERE Industry = 'Banking' LIMIT 10\"
+  arivu salesforce record Account 001xx000003DGb2AAG --fields Name,Industry")]
+    Salesforce {
+        #[command(subcommand)]
+        tool: SalesforceTools,
+    },
+
+    /// HubSpot CRM records and engagements
+    #[command(name = "hubspot")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu hubspot search contacts --query acme.com
+  arivu hubspot search deals --filter dealstage=closedwon --sort -amount
+  arivu hubspot get companies 12345 --associations contacts,deals
+  arivu hubspot engagements contacts 51 --types notes,emails,calls")]
+    Hubspot {
+        #[command(subcommand)]
+        tool: HubspotTools,
+    },
+
+    /// Airtable bases, tables and records
+    #[command(name = "airtable")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu airtable bases
+  arivu airtable tables appXXXXXXXXXXXXXX
+  arivu airtable records appXXXXXXXXXXXXXX Tasks --filter \"{Status}='Open'\" --sort -Priority
+  arivu airtable record https://airtable.com/appXXX/tblXXX/recXXX")]
+    Airtable {
+        #[command(subcommand)]
+        tool: AirtableTools,
+    },
+
+    /// Box folders, search and file content
+    #[command(name = "box")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu box list-files
+  arivu box search \"quarterly report\" --ext pdf,docx
+  arivu box extract-text 123456789
+  arivu box download-file 123456789 --max-bytes 5000000")]
+    Box {
+        #[command(subcommand)]
+        tool: BoxTools,
+    },
+
+    /// S3-compatible object storage (AWS, MinIO, R2)
+    #[command(name = "s3")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu s3 buckets
+  arivu s3 ls my-bucket reports/2024/
+  arivu s3 head s3://my-bucket/reports/q1.csv
+  arivu s3 get s3://my-bucket/reports/q1.csv --max-bytes 65536")]
+    S3 {
+        #[command(subcommand)]
+        tool: S3Tools,
+    },
+
+    /// Read-only SQL over named Postgres/MySQL/SQLite connections
+    #[command(name = "sql")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu sql add analytics postgres://reader:pw@db.internal/analytics
+  arivu sql tables analytics --schema public
+  arivu sql describe analytics public.orders
+  arivu sql query analytics \"SELECT id, total FROM orders WHERE status = $1\" --param paid")]
+    Sql {
+        #[command(subcommand)]
+        tool: SqlTools,
+    },
+
+    /// Tools generated from an OpenAPI document
+    #[command(name = "openapi")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu openapi set spec https://api.internal/openapi.json
+  arivu openapi set operations \"listOrders,getOrder,POST /refunds\"
+  arivu openapi operations
+  arivu openapi call get_order -p orderId=42
+  arivu openapi call create_refund --body '{\"order_id\": 42}'")]
+    Openapi {
+        #[command(subcommand)]
+        tool: OpenapiTools,
+    },
+
+    /// GraphQL endpoint: schema and allowlisted queries
+    #[command(name = "graphql")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu graphql set endpoint https://api.internal/graphql
+  arivu graphql header X-Org-Id acme
+  arivu graphql add orderById @queries/order.graphql
+  arivu graphql schema Order
+  arivu graphql run orderById -v id=42")]
+    Graphql {
+        #[command(subcommand)]
+        tool: GraphqlTools,
+    },
+
+    /// Sentry issues, stack traces and release health
+    #[command(name = "sentry")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu sentry projects
+  arivu sentry issues \"is:unresolved level:error\" --project web --sort freq
+  arivu sentry issue WEB-1A2 --in-app
+  arivu sentry releases --project web
+  arivu sentry release web@1.4.2")]
+    Sentry {
+        #[command(subcommand)]
+        tool: SentryTools,
+    },
+
+    /// PagerDuty incidents and on-call
+    #[command(name = "pagerduty", alias = "pd")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu pagerduty incidents
+  arivu pagerduty incidents checkout --status resolved --since 2025-06-01
+  arivu pagerduty incident 1234
+  arivu pagerduty oncall --schedule \"Primary SRE\"
+  arivu pagerduty schedule \"Primary SRE\" --until 2025-07-01")]
+    Pagerduty {
+        #[command(subcommand)]
+        tool: PagerdutyTools,
+    },
+
+    /// Confluence pages, spaces and page tree
+    #[command(name = "confluence")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu confluence search \"deploy runbook\" --space ENG
+  arivu confluence search --cql \"type = page AND label = postmortem\"
+  arivu confluence page https://acme.atlassian.net/wiki/spaces/ENG/pages/98765/Runbook
+  arivu confluence spaces
+  arivu confluence children 98765")]
+    Confluence {
+        #[command(subcommand)]
+        tool: ConfluenceTools,
+    },
+
+    /// Zoom cloud recordings and transcripts
+    #[command(name = "zoom")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu zoom recordings --from 2024-05-01
+  arivu zoom search \"weekly sync\" --from 2024-01-01
+  arivu zoom transcript 85746065432
+  arivu zoom transcript \"4444AAAiAAAAAiAiAiiAii==\" --format segments")]
+    Zoom {
+        #[command(subcommand)]
+        tool: ZoomTools,
+    },
+
+    /// Rust crates from crates.io and docs.rs
+    #[command(name = "crates-io", alias = "crates")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu crates-io search \"async http client\" --sort downloads
+  arivu crates-io info serde
+  arivu crates-io info tokio --version 1.38.0
+  arivu crates-io rdeps serde_json --limit 10
+  arivu crates-io readme reqwest --source docs-rs")]
+    CratesIo {
+        #[command(subcommand)]
+        tool: CratesIoTools,
+    },
+
+    /// npm registry packages
+    #[command(name = "npm")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu npm search \"date formatting\"
+  arivu npm info react
+  arivu npm info @types/node --version 20.11.0
+  arivu npm readme zod")]
+    Npm {
+        #[command(subcommand)]
+        tool: NpmTools,
+    },
+
+    /// Python packages from PyPI
+    #[command(name = "pypi")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu pypi search \"pdf table extraction\"
+  arivu pypi info requests
+  arivu pypi info django --version 4.2.11
+  arivu pypi description httpx")]
+    Pypi {
+        #[command(subcommand)]
+        tool: PypiTools,
+    },
+
+    /// Docker Hub images, tags and Dockerfiles
+    #[command(name = "dockerhub", alias = "docker")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu dockerhub search postgres
+  arivu dockerhub tags python --filter slim
+  arivu dockerhub info bitnami/redis
+  arivu dockerhub dockerfile python:3.12-slim --platform linux/arm64")]
+    Dockerhub {
+        #[command(subcommand)]
+        tool: DockerhubTools,
+    },
+
     /// Sci-Hub paper access
     #[command(name = "scihub")]
     #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
-  arivu scihub paper --doi 10.1038/nature12373")]
+  arivu scihub paper --doi 10.1038/nature12373
+  arivu scihub paper --doi 10.1038/nature12373 --text
+  arivu scihub mirrors --discover")]
     Scihub {
         #[command(subcommand)]
         tool: ScihubTools,
@@ -617,7 +1104,9 @@ pub enum Commands {
     #[command(name = "macos")]
     #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
   arivu macos script --script \"display dialog \\\"Hello\\\"\"
-  arivu macos notify --message \"Task complete\"")]
+  arivu macos notify --message \"Task complete\"
+  arivu macos shortcut --name \"Summarize Text\" --input \"...\"
+  arivu macos notes --folder Work --query standup")]
     Macos {
         #[command(subcommand)]
         tool: MacosTools,
@@ -627,11 +1116,33 @@ pub enum Commands {
     #[command(name = "spotlight")]
     #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
   arivu spotlight search --query \"rust async\"
-  arivu spotlight name --name \"cargo.toml\"")]
+  arivu spotlight name --name \"cargo.toml\"
+  arivu spotlight filter --kind pdf --author Jane --modified-since 30d")]
     Spotlight {
         #[command(subcommand)]
         tool: SpotlightTools,
     },
+
+    /// Linux desktop search (GNOME Tracker or Recoll)
+    #[command(name = "tracker")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu tracker search --query \"rust async\" --mime application/pdf
+  arivu tracker name --name \"cargo.toml\"")]
+    Tracker {
+        #[command(subcommand)]
+        tool: TrackerTools,
+    },
+
+    /// Local browser history (Chrome, Brave, Firefox, Safari)
+    #[command(name = "browser-history", alias = "history")]
+    #[command(after_help = "\x1b[1;33mExamples:\x1b[0m
+  arivu browser-history search \"rust async\" --since 2025-01-01
+  arivu browser-history top --browser chrome --date-preset last_7_days
+  arivu browser-history browsers")]
+    BrowserHistory {
+        #[command(subcommand)]
+        tool: BrowserHistoryTools,
+    },
 }
 
 #[derive(Subcommand, Clone)]
@@ -664,6 +1175,42 @@ pub enum ConfigAction {
     },
 }
 
+#[derive(Subcommand, Clone)]
+pub enum IndexAction {
+    /// Search titles and text of indexed content
+    Search {
+        /// Words to find; supports "phrases", OR and -exclusions
+        query: String,
+        /// Only return entries from this connector
+        #[arg(short = 's', long)]
+        source: Option<String>,
+        /// Maximum number of hits
+        #[arg(short, long, default_value_t = 10)]
+        limit: usize,
+    },
+    /// Find indexed content by meaning (needs ARIVU_EMBED_BACKEND)
+    Semantic {
+        /// What to look for, in natural language
+        query: String,
+        /// Only return entries from this connector
+        #[arg(short = 's', long)]
+        source: Option<String>,
+        /// Maximum number of hits
+        #[arg(short, long, default_value_t = 10)]
+        limit: usize,
+    },
+    /// Show where the index lives and how many entries it holds
+    Stats,
+    /// Remove every entry from the index
+    Clear,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// Chunked JSON Lines with source metadata on every chunk
+    RagJsonl,
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
 pub enum OutputFormat {
     /// Human-readable formatted output
@@ -934,6 +1481,54 @@ pub enum ParallelSearchTools {
     },
 }
 
+/// Brave Search tools
+#[derive(Subcommand, Clone)]
+pub enum BraveSearchTools {
+    /// Search the web using Brave's index
+    #[command(name = "search")]
+    Search {
+        /// Search query
+        #[arg(long, short)]
+        query: String,
+        /// Maximum number of results (max 20)
+        #[arg(long, default_value_t = 10, alias = "max-results", alias = "count")]
+        limit: u32,
+        /// Freshness: pd, pw, pm, py or YYYY-MM-DDtoYYYY-MM-DD
+        #[arg(long)]
+        freshness: Option<String>,
+        /// Country code, e.g. US
+        #[arg(long)]
+        country: Option<String>,
+        /// Response format: concise or detailed
+        #[arg(long, default_value = "concise")]
+        response_format: String,
+    },
+}
+
+/// DuckDuckGo Search tools
+#[derive(Subcommand, Clone)]
+pub enum DdgSearchTools {
+    /// Search the web using DuckDuckGo
+    #[command(name = "search")]
+    Search {
+        /// Search query
+        #[arg(long, short)]
+        query: String,
+        /// Maximum number of results (max 30)
+        #[arg(long, default_value_t = 10, alias = "max-results")]
+        limit: u32,
+        /// Results page
+        #[arg(long, default_value_t = 1)]
+        page: u32,
+        /// last_24_hours, last_7_days, last_30_days or past_year
+        #[arg(long)]
+        date_preset: Option<String>,
+        /// Locale like en-US
+        #[arg(long)]
+        locale: Option<String>,
+    },
+}
+
 // ============================================================================
 // Google Connector tools with proper CLI flags
 // ============================================================================
@@ -963,18 +1558,40 @@ pub enum GoogleCalendarTools {
         response_format: String,
     },
 
-    /// Create an event in primary calendar
+    /// Create an event (needs allow_writes and --yes)
     #[command(name = "create-event", alias = "create")]
     CreateEvent {
         /// Event title/summary
         #[arg(long, short)]
         summary: String,
-        /// Start time (RFC3339 format)
+        /// Start time (RFC3339, or YYYY-MM-DD for all-day)
         #[arg(long)]
         start: String,
-        /// End time (RFC3339 format)
+        /// End time (RFC3339, or YYYY-MM-DD exclusive for all-day)
         #[arg(long)]
         end: String,
+        #[command(flatten)]
+        event: CalendarEventArgs,
+    },
+
+    /// Free/busy blocks and common free slots across calendars
+    #[command(name = "freebusy", alias = "free")]
+    Freebusy {
+        /// Window start (RFC3339)
+        #[arg(long)]
+        time_min: String,
+        /// Window end (RFC3339)
+        #[arg(long)]
+        time_max: String,
+        /// Calendar id or email (repeatable; default primary)
+        #[arg(long = "calendar")]
+        calendars: Vec<String>,
+        /// Shortest free slot to report, in minutes
+        #[arg(long, default_value_t = 30)]
+        min_minutes: u32,
+        /// IANA time zone for the response
+        #[arg(long)]
+        time_zone: Option<String>,
     },
 
     /// Incremental sync using syncToken
@@ -993,7 +1610,7 @@ pub enum GoogleCalendarTools {
         max_results: u32,
     },
 
-    /// Update an event in primary calendar
+    /// Update an event (needs allow_writes and --yes)
     #[command(name = "update-event", alias = "update")]
     UpdateEvent {
         /// Event ID
@@ -1002,23 +1619,63 @@ pub enum GoogleCalendarTools {
         /// New event title/summary
         #[arg(long)]
         summary: Option<String>,
-        /// New start time (RFC3339 format)
+        /// New start time (RFC3339, or YYYY-MM-DD for all-day)
         #[arg(long)]
         start: Option<String>,
-        /// New end time (RFC3339 format)
+        /// New end time (RFC3339, or YYYY-MM-DD for all-day)
         #[arg(long)]
         end: Option<String>,
+        #[command(flatten)]
+        event: CalendarEventArgs,
     },
 
-    /// Delete an event in primary calendar
+    /// Delete an event (needs allow_writes and --yes)
     #[command(name = "delete-event", alias = "delete")]
     DeleteEvent {
         /// Event ID
         #[arg(long, short)]
-        event_id: Option<String>,
+        event_id: String,
+        /// Calendar id (default primary)
+        #[arg(long)]
+        calendar_id: Option<String>,
+        /// Email attendees: all, externalOnly or none
+        #[arg(long)]
+        send_updates: Option<String>,
+        /// Confirm the change
+        #[arg(long, short = 'y')]
+        yes: bool,
     },
 }
 
+/// Optional event fields shared by create-event and update-event
+#[derive(Args, Clone)]
+pub struct CalendarEventArgs {
+    /// Calendar id (default primary)
+    #[arg(long)]
+    pub calendar_id: Option<String>,
+    /// Event description
+    #[arg(long)]
+    pub description: Option<String>,
+    /// Event location
+    #[arg(long)]
+    pub location: Option<String>,
+    /// IANA time zone for start/end, e.g. Europe/Berlin
+    #[arg(long)]
+    pub time_zone: Option<String>,
+    /// Attendee email (repeatable; on update, replaces the list)
+    #[arg(long = "attendee")]
+    pub attendees: Vec<String>,
+    /// Add a Google Meet link
+    #[arg(long)]
+    pub meet: bool,
+    /// Email attendees: all, externalOnly or none
+    #[arg(long)]
+    pub send_updates: Option<String>,
+    /// Confirm the change
+    #[arg(long, short = 'y')]
+    pub yes: bool,
+}
+
 /// Google Drive tools
 #[derive(Subcommand, Clone)]
 pub enum GoogleDriveTools {
@@ -1080,6 +1737,51 @@ pub enum GoogleDriveTools {
         mime_type: String,
     },
 
+    /// Read a Doc as markdown, Slides as per-slide text, a Sheet range as CSV, or a PDF by page
+    #[command(name = "export-text", alias = "read")]
+    ExportText {
+        /// File ID
+        #[arg(long, short)]
+        file_id: String,
+        /// Sheets only: A1 range or sheet name (e.g., "Budget!A1:F50")
+        #[arg(long, short)]
+        range: Option<String>,
+        /// PDFs only: also split the text into sections with page numbers
+        #[arg(long)]
+        sections: bool,
+        /// Truncate the text to this many characters
+        #[arg(long)]
+        max_chars: Option<u64>,
+    },
+
+    /// Walk a folder recursively and list files with their paths
+    #[command(name = "list-folder-tree", alias = "tree")]
+    ListFolderTree {
+        /// Folder ID (default: My Drive root)
+        #[arg(long, short)]
+        folder_id: Option<String>,
+        /// Levels to descend; 1 lists direct children only
+        #[arg(long, short = 'd', default_value_t = 3, value_parser = clap::value_parser!(u32).range(1..=10))]
+        max_depth: u32,
+        /// Stop after this many entries
+        #[arg(long, default_value_t = 1000, value_parser = clap::value_parser!(u32).range(1..=10000))]
+        max_items: u32,
+    },
+
+    /// Changes since a saved page token (omit the token to get a baseline)
+    #[command(name = "list-changes", alias = "changes")]
+    ListChanges {
+        /// Token from a previous run (next_page_token or new_start_page_token)
+        #[arg(long, short)]
+        page_token: Option<String>,
+        /// Only changes to direct children of this folder (repeatable)
+        #[arg(long = "folder-id")]
+        folder_ids: Vec<String>,
+        /// Stop after scanning this many changes
+        #[arg(long, short, value_parser = clap::value_parser!(u32).range(1..=10000))]
+        limit: Option<u32>,
+    },
+
     /// Upload a small file via base64
     #[command(name = "upload-file", alias = "upload")]
     UploadFile {
@@ -1135,11 +1837,48 @@ pub enum GoogleGmailTools {
         /// Optional cursor from a previous response (nextPageToken)
         #[arg(long)]
         page_token: Option<String>,
+        /// Only messages with this label (id or name; repeatable)
+        #[arg(long = "label")]
+        labels: Vec<String>,
         /// Response format (concise or detailed)
         #[arg(long, default_value = "concise")]
         response_format: String,
     },
 
+    /// List labels
+    #[command(name = "labels", alias = "list-labels")]
+    Labels {
+        /// Include total and unread counts (one request per label)
+        #[arg(long)]
+        counts: bool,
+    },
+
+    /// Add/remove labels or mark read on many messages (needs writes enabled)
+    #[command(name = "modify", alias = "batch-modify")]
+    Modify {
+        /// Message IDs
+        #[arg(required = true)]
+        ids: Vec<String>,
+        /// Label to add (id or name; repeatable)
+        #[arg(long)]
+        add: Vec<String>,
+        /// Label to remove (id or name; repeatable)
+        #[arg(long)]
+        remove: Vec<String>,
+        /// Mark as read
+        #[arg(long, conflicts_with = "unread")]
+        read: bool,
+        /// Mark as unread
+        #[arg(long)]
+        unread: bool,
+        /// Remove from the inbox
+        #[arg(long)]
+        archive: bool,
+        /// Confirm the write
+        #[arg(long, short)]
+        yes: bool,
+    },
+
     /// Decode a Gmail raw message
     #[command(name = "decode-message-raw", alias = "decode")]
     DecodeMessageRaw {
@@ -1169,6 +1908,84 @@ pub enum GoogleGmailTools {
         #[arg(long, short)]
         id: String,
     },
+
+    /// List a message's attachments and extract text from selected ones
+    #[command(name = "attachments", alias = "get-attachments")]
+    Attachments {
+        /// Message ID
+        #[arg(long, short)]
+        id: String,
+        /// part_id or filename to download (repeatable)
+        #[arg(long)]
+        select: Vec<String>,
+        /// Download every attachment
+        #[arg(long)]
+        all: bool,
+        /// Truncate each extracted text to this many characters
+        #[arg(long)]
+        max_chars: Option<u32>,
+        /// Also split PDF text into sections with page numbers
+        #[arg(long)]
+        sections: bool,
+    },
+
+    /// Create a draft for review in Gmail (needs writes enabled)
+    #[command(name = "create-draft", alias = "draft")]
+    CreateDraft {
+        /// Recipient (repeatable or comma-separated)
+        #[arg(long, required = true)]
+        to: Vec<String>,
+        /// CC recipient (repeatable)
+        #[arg(long)]
+        cc: Vec<String>,
+        /// BCC recipient (repeatable)
+        #[arg(long)]
+        bcc: Vec<String>,
+        /// Subject (defaults to "Re: <original>" with --reply-to)
+        #[arg(long, short)]
+        subject: Option<String>,
+        /// Plain-text body
+        #[arg(long, short)]
+        body: Option<String>,
+        /// HTML body
+        #[arg(long)]
+        html_body: Option<String>,
+        /// Gmail message ID to reply to (threads the message)
+        #[arg(long)]
+        reply_to: Option<String>,
+        /// Confirm the write
+        #[arg(long, short)]
+        yes: bool,
+    },
+
+    /// Send an email (needs writes enabled)
+    #[command(name = "send-email", alias = "send")]
+    SendEmail {
+        /// Recipient (repeatable or comma-separated)
+        #[arg(long, required = true)]
+        to: Vec<String>,
+        /// CC recipient (repeatable)
+        #[arg(long)]
+        cc: Vec<String>,
+        /// BCC recipient (repeatable)
+        #[arg(long)]
+        bcc: Vec<String>,
+        /// Subject (defaults to "Re: <original>" with --reply-to)
+        #[arg(long, short)]
+        subject: Option<String>,
+        /// Plain-text body
+        #[arg(long, short)]
+        body: Option<String>,
+        /// HTML body
+        #[arg(long)]
+        html_body: Option<String>,
+        /// Gmail message ID to reply to (threads the message)
+        #[arg(long)]
+        reply_to: Option<String>,
+        /// Confirm the write
+        #[arg(long, short)]
+        yes: bool,
+    },
 }
 
 /// Google People tools
@@ -1224,7 +2041,41 @@ pub enum GoogleScholarTools {
         #[arg(long, short, default_value_t = 10)]
         limit: u32,
     },
-}
+
+    /// Author profile with metrics and publications
+    #[command(name = "author", alias = "get-author")]
+    Author {
+        /// Profile id, profile URL, or author name
+        #[arg(long, short)]
+        author: String,
+        /// Publications per page (max 100)
+        #[arg(long, short, default_value_t = 20)]
+        limit: u32,
+        /// Publication offset (the previous response's `next`)
+        #[arg(long, short, default_value_t = 0)]
+        offset: u32,
+        /// Publication order: citations or year
+        #[arg(long, short)]
+        sort: Option<String>,
+    },
+
+    /// Papers citing a paper
+    #[command(name = "cited-by", alias = "get-cited-by")]
+    CitedBy {
+        /// Cites id from search results or an author's publications
+        #[arg(long)]
+        cites_id: Option<String>,
+        /// Paper title to look up instead of a cites id
+        #[arg(long, short)]
+        query: Option<String>,
+        /// Maximum number of results (max 50)
+        #[arg(long, short, default_value_t = 10)]
+        limit: u32,
+        /// Result offset (the previous response's `next`)
+        #[arg(long, short, default_value_t = 0)]
+        offset: u32,
+    },
+}
 
 // ============================================================================
 // Productivity connector tools
@@ -1243,9 +2094,12 @@ pub enum AtlassianTools {
         /// JQL query
         #[arg(long, short)]
         jql: String,
-        /// Starting index
+        /// Starting index (uses the older offset-paged search)
         #[arg(long, default_value_t = 0)]
         start_at: u32,
+        /// nextPageToken from the previous page
+        #[arg(long, short = 'p')]
+        next_page_token: Option<String>,
         /// Maximum results
         #[arg(long, short, default_value_t = 50)]
         max_results: u32,
@@ -1265,6 +2119,97 @@ pub enum AtlassianTools {
         expand: Option<String>,
     },
 
+    /// Issue fields, description, latest comments and changelog
+    #[command(name = "jira-detail", alias = "jira-show")]
+    JiraDetail {
+        /// Issue key (e.g., PROJ-123)
+        key: String,
+        /// Latest comments to include (0 to skip)
+        #[arg(long, default_value_t = 20)]
+        comments: usize,
+        /// Latest changelog entries to include (0 to skip)
+        #[arg(long, default_value_t = 20)]
+        changelog: usize,
+    },
+
+    /// Transitions available from an issue's current status
+    #[command(name = "jira-transitions")]
+    JiraTransitions {
+        /// Issue key (e.g., PROJ-123)
+        key: String,
+    },
+
+    /// Comment on an issue (needs writes enabled)
+    #[command(name = "jira-comment")]
+    JiraComment {
+        /// Issue key (e.g., PROJ-123)
+        key: String,
+        /// Comment text
+        #[arg(long, short)]
+        body: String,
+        /// Confirm the write
+        #[arg(long, short)]
+        yes: bool,
+    },
+
+    /// Move an issue to another status (needs writes enabled)
+    #[command(name = "jira-transition")]
+    JiraTransition {
+        /// Issue key (e.g., PROJ-123)
+        key: String,
+        /// Transition id or name, or target status
+        transition: String,
+        /// Comment to add with the transition
+        #[arg(long)]
+        comment: Option<String>,
+        /// Confirm the write
+        #[arg(long, short)]
+        yes: bool,
+    },
+
+    /// List Scrum and Kanban boards
+    #[command(name = "jira-boards", alias = "boards")]
+    JiraBoards {
+        /// Project key or id
+        #[arg(long, short)]
+        project: Option<String>,
+        /// Board name contains
+        #[arg(long, short)]
+        name: Option<String>,
+        /// Board type (scrum, kanban, simple)
+        #[arg(long, short = 't')]
+        board_type: Option<String>,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 50)]
+        max_results: u32,
+    },
+
+    /// List a board's sprints
+    #[command(name = "jira-sprints", alias = "sprints")]
+    JiraSprints {
+        /// Board id
+        board_id: u64,
+        /// Comma-separated states: future, active, closed
+        #[arg(long, short)]
+        state: Option<String>,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 50)]
+        max_results: u32,
+    },
+
+    /// Issues in a sprint
+    #[command(name = "jira-sprint-issues")]
+    JiraSprintIssues {
+        /// Sprint id
+        sprint_id: u64,
+        /// Extra JQL filter
+        #[arg(long, short)]
+        jql: Option<String>,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 50)]
+        max_results: u32,
+    },
+
     /// Search Confluence pages with CQL
     #[command(name = "conf-search", alias = "confluence")]
     ConfSearch {
@@ -1375,6 +2320,121 @@ pub enum MicrosoftGraphTools {
         body: String,
     },
 
+    /// List the Teams teams you belong to
+    #[command(name = "list-teams", alias = "teams")]
+    ListTeams {
+        /// Maximum teams to return
+        #[arg(long, short, default_value_t = 50)]
+        limit: u32,
+    },
+
+    /// List a team's channels
+    #[command(name = "list-channels", alias = "channels")]
+    ListChannels {
+        /// Team ID (from list-teams)
+        #[arg(long, short)]
+        team_id: String,
+    },
+
+    /// List messages in a Teams channel
+    #[command(name = "channel-messages")]
+    ChannelMessages {
+        /// Team ID
+        #[arg(long, short)]
+        team_id: String,
+        /// Channel ID (from list-channels)
+        #[arg(long)]
+        channel_id: String,
+        /// Maximum messages to return
+        #[arg(long, short, default_value_t = 25)]
+        limit: u32,
+        /// Include each thread's replies
+        #[arg(long, short)]
+        replies: bool,
+        /// Optional cursor from a previous response (nextLink)
+        #[arg(long)]
+        next_link: Option<String>,
+    },
+
+    /// List your Teams chats
+    #[command(name = "list-chats", alias = "chats")]
+    ListChats {
+        /// Maximum chats to return
+        #[arg(long, short, default_value_t = 25)]
+        limit: u32,
+        /// Optional cursor from a previous response (nextLink)
+        #[arg(long)]
+        next_link: Option<String>,
+    },
+
+    /// List messages in a Teams chat
+    #[command(name = "chat-messages")]
+    ChatMessages {
+        /// Chat ID (from list-chats)
+        #[arg(long)]
+        chat_id: String,
+        /// Maximum messages to return
+        #[arg(long, short, default_value_t = 25)]
+        limit: u32,
+        /// Optional cursor from a previous response (nextLink)
+        #[arg(long)]
+        next_link: Option<String>,
+    },
+
+    /// Search Teams chat and channel messages
+    #[command(name = "search-teams")]
+    SearchTeams {
+        /// Search query (KQL)
+        query: String,
+        /// Maximum hits to return
+        #[arg(long, short, default_value_t = 25)]
+        limit: u32,
+    },
+
+    /// List OneNote notebooks
+    #[command(name = "list-notebooks", alias = "notebooks")]
+    ListNotebooks {
+        /// Maximum notebooks to return
+        #[arg(long, short, default_value_t = 50)]
+        limit: u32,
+    },
+
+    /// List OneNote sections
+    #[command(name = "list-sections", alias = "sections")]
+    ListSections {
+        /// Only sections of this notebook
+        #[arg(long, short)]
+        notebook_id: Option<String>,
+        /// Maximum sections to return
+        #[arg(long, short, default_value_t = 50)]
+        limit: u32,
+    },
+
+    /// List OneNote pages
+    #[command(name = "list-pages", alias = "pages")]
+    ListPages {
+        /// Only pages of this section
+        #[arg(long, short)]
+        section_id: Option<String>,
+        /// Maximum pages to return
+        #[arg(long, short, default_value_t = 25)]
+        limit: u32,
+        /// Optional cursor from a previous response (nextLink)
+        #[arg(long)]
+        next_link: Option<String>,
+    },
+
+    /// Get a OneNote page as text
+    #[command(name = "get-page", alias = "page")]
+    GetPage {
+        /// Page ID (from list-pages)
+        #[arg(long, short)]
+        page_id: String,
+        /// Truncate the text to this many characters
+        #[arg(long)]
+        max_chars: Option<u32>,
+    },
+
     /// Upload a large attachment to a draft
     #[command(name = "upload-attachment")]
     UploadAttachment {
@@ -1505,6 +2565,32 @@ pub enum ImapTools {
         include_raw: bool,
     },
 
+    /// List a message's attachments and download selected ones as text
+    #[command(name = "get-attachments", alias = "attachments")]
+    GetAttachments {
+        /// Mailbox name
+        #[arg(long, short)]
+        mailbox: Option<String>,
+        /// Message UID
+        #[arg(long, short)]
+        uid: u32,
+        /// part_id or filename to download (repeatable)
+        #[arg(long, short)]
+        select: Vec<String>,
+        /// Download every attachment
+        #[arg(long, short)]
+        all: bool,
+        /// Also return the decoded bytes as base64
+        #[arg(long)]
+        include_data: bool,
+        /// Skip attachments larger than this many bytes
+        #[arg(long)]
+        max_bytes: Option<u64>,
+        /// Truncate each extracted text to this many characters
+        #[arg(long)]
+        max_chars: Option<u32>,
+    },
+
     /// Search messages in a mailbox
     #[command(name = "search")]
     Search {
@@ -1523,6 +2609,167 @@ pub enum ImapTools {
         )]
         limit: u32,
     },
+
+    /// Wait for new mail with IMAP IDLE
+    #[command(name = "idle", alias = "watch")]
+    Idle {
+        /// Mailbox to watch
+        #[arg(long, short)]
+        mailbox: Option<String>,
+        /// Seconds to wait per call (max 3600)
+        #[arg(
+            long,
+            short,
+            default_value_t = 300,
+            value_parser = clap::value_parser!(u64).range(1..=3600)
+        )]
+        timeout: u64,
+        /// Return after this many new messages
+        #[arg(long, default_value_t = 1)]
+        max_messages: u32,
+        /// Keep watching until interrupted, printing each batch of new mail
+        #[arg(long, short)]
+        follow: bool,
+    },
+}
+
+/// Newsletter tools
+#[derive(Subcommand, Clone)]
+pub enum NewslettersTools {
+    /// Save a config value (mailbox, senders, exclude)
+    #[command(name = "set")]
+    Set {
+        /// Config key
+        key: String,
+        /// Value (senders/exclude take comma-separated addresses or domains)
+        value: String,
+    },
+
+    /// Publications with recent issues
+    #[command(name = "list", alias = "publications")]
+    List {
+        /// Look back this many days
+        #[arg(long, short, default_value_t = 30)]
+        days: u32,
+        /// IMAP folder
+        #[arg(long, short)]
+        mailbox: Option<String>,
+    },
+
+    /// Recent issues, newest first
+    #[command(name = "issues")]
+    Issues {
+        /// Publication name, sender address or domain
+        #[arg(long, short)]
+        publication: Option<String>,
+        /// Look back this many days
+        #[arg(long, short, default_value_t = 30)]
+        days: u32,
+        /// Maximum number of issues
+        #[arg(long, short, default_value_t = 25)]
+        limit: u32,
+        /// IMAP folder
+        #[arg(long, short)]
+        mailbox: Option<String>,
+    },
+
+    /// Full-text search across issues
+    #[command(name = "search")]
+    Search {
+        /// Text to find
+        query: String,
+        /// Publication name, sender address or domain
+        #[arg(long, short)]
+        publication: Option<String>,
+        /// Look back this many days
+        #[arg(long, short, default_value_t = 90)]
+        days: u32,
+        /// Maximum number of issues
+        #[arg(long, short, default_value_t = 25)]
+        limit: u32,
+        /// IMAP folder
+        #[arg(long, short)]
+        mailbox: Option<String>,
+    },
+
+    /// Read an issue as Markdown
+    #[command(name = "read")]
+    Read {
+        /// Issue uid (from issues/search)
+        uid: u32,
+        /// IMAP folder
+        #[arg(long, short)]
+        mailbox: Option<String>,
+    },
+}
+
+/// Webhook inbox tools
+#[derive(Subcommand, Clone)]
+pub enum InboxTools {
+    /// Receive webhooks until stopped (POST to /github, /stripe, /<source>)
+    #[command(name = "serve", alias = "listen")]
+    Serve {
+        /// Address to listen on
+        #[arg(long, short, default_value = "127.0.0.1:8787")]
+        listen: String,
+    },
+
+    /// Save a signing secret; deliveries from that source must then be signed
+    #[command(name = "secret")]
+    Secret {
+        /// Source (github, stripe, or a custom path name)
+        source: String,
+        /// Webhook signing secret
+        secret: String,
+    },
+
+    /// List received events, newest first
+    #[command(name = "list", alias = "events")]
+    List {
+        /// Source, e.g. github or stripe
+        #[arg(long, short)]
+        source: Option<String>,
+        /// Event type prefix, e.g. pull_request
+        #[arg(long = "type", short = 't')]
+        event_type: Option<String>,
+        /// Only events received at or after this time (RFC 3339)
+        #[arg(long)]
+        since: Option<String>,
+        /// Skip acknowledged events
+        #[arg(long)]
+        unacked: bool,
+        /// Maximum number of events
+        #[arg(long, short, default_value_t = 25)]
+        limit: u32,
+    },
+
+    /// Search event payloads
+    #[command(name = "search")]
+    Search {
+        /// Text to find
+        query: String,
+        /// Source, e.g. github or stripe
+        #[arg(long, short)]
+        source: Option<String>,
+        /// Maximum number of events
+        #[arg(long, short, default_value_t = 25)]
+        limit: u32,
+    },
+
+    /// Show one event with its payload
+    #[command(name = "get", alias = "show")]
+    Get {
+        /// Event id
+        id: String,
+    },
+
+    /// Mark events as handled
+    #[command(name = "ack")]
+    Ack {
+        /// Event ids
+        #[arg(required = true)]
+        ids: Vec<String>,
+    },
 }
 
 /// Local filesystem tools for text extraction from documents
@@ -1604,6 +2851,62 @@ pub enum LocalfsTools {
     },
 }
 
+/// Paper library tools
+#[derive(Subcommand, Clone)]
+pub enum PaperLibraryTools {
+    /// Set the folder of PDFs to index
+    #[command(name = "dir")]
+    Dir {
+        /// Directory (searched recursively)
+        path: String,
+    },
+
+    /// Full-text search across papers
+    #[command(name = "search")]
+    Search {
+        /// Words that must all appear
+        query: String,
+        /// Only search this section (abstract, methods, results, references, ...)
+        #[arg(long, short)]
+        section: Option<String>,
+        /// Maximum number of papers
+        #[arg(long, short, default_value_t = 10)]
+        limit: u32,
+    },
+
+    /// Show a paper's outline and section text
+    #[command(name = "get", alias = "read")]
+    Get {
+        /// Library path, DOI, arXiv id, or file name
+        id: String,
+        /// Sections to include (repeatable; default: all but references)
+        #[arg(long = "section", short)]
+        sections: Vec<String>,
+        /// Maximum characters of section text
+        #[arg(long)]
+        max_chars: Option<u32>,
+    },
+
+    /// List indexed papers
+    #[command(name = "list", alias = "ls")]
+    List {
+        /// Order: recent, title or path
+        #[arg(long, default_value = "recent")]
+        sort: String,
+        /// Maximum number of papers
+        #[arg(long, short, default_value_t = 50)]
+        limit: u32,
+    },
+
+    /// Rescan the library for new, changed and deleted PDFs
+    #[command(name = "reindex")]
+    Reindex {
+        /// Re-extract every file
+        #[arg(long)]
+        force: bool,
+    },
+}
+
 /// YouTube tools
 #[derive(Subcommand, Clone)]
 pub enum YoutubeTools {
@@ -1631,7 +2934,7 @@ pub enum YoutubeTools {
         /// Playlist ID/URL (e.g., PL..., <https://youtube.com/playlist?list=PL>...)
         #[arg(long, conflicts_with = "channel", required_unless_present = "channel")]
         playlist: Option<String>,
-        /// Maximum number of videos to return
+        /// Maximum number of videos to return (playlists: up to 1000)
         #[arg(long, short, default_value_t = 5)]
         limit: u32,
         /// Only include videos from the last N days (UTC)
@@ -1659,6 +2962,17 @@ pub enum YoutubeTools {
         prefer_verified: bool,
     },
 
+    /// Get channel metadata (description, counts, links, upload cadence)
+    #[command(name = "channel-info", alias = "about")]
+    ChannelInfo {
+        /// Channel ID/URL/handle (e.g., UC..., @hubermanlab)
+        #[arg(value_name = "CHANNEL")]
+        channel: String,
+        /// Recent uploads to include (max 15)
+        #[arg(long, short, default_value_t = 5)]
+        limit: u32,
+    },
+
     /// Get video details (title, description, transcript, chapters)
     #[command(
         name = "get",
@@ -1679,6 +2993,55 @@ pub enum YoutubeTools {
         /// Video ID or URL (flag)
         #[arg(long, short, required_unless_present = "id_or_url")]
         id: Option<String>,
+        /// Transcript language in order of preference (repeatable; default: en)
+        #[arg(long = "lang", short = 'L')]
+        languages: Vec<String>,
+        /// Prefer auto-generated captions over creator-uploaded ones
+        #[arg(long)]
+        prefer_auto: bool,
+        /// Machine-translate the transcript into this language code
+        #[arg(long)]
+        translate_to: Option<String>,
+    },
+
+    /// Find where a topic is discussed in a video (timestamped transcript matches)
+    #[command(name = "search-transcript", alias = "find", alias = "grep")]
+    SearchTranscript {
+        /// Video ID or URL
+        #[arg(value_name = "ID_OR_URL")]
+        id_or_url: String,
+        /// Words to find (all must appear); quote exact phrases
+        #[arg(long, short)]
+        query: String,
+        /// Transcript language in order of preference (repeatable; default: en)
+        #[arg(long = "lang", short = 'L')]
+        languages: Vec<String>,
+        /// Caption lines of context around each match
+        #[arg(long, default_value_t = 1)]
+        context: u32,
+        /// Maximum number of matches
+        #[arg(long, short, default_value_t = 20)]
+        limit: u32,
+    },
+
+    /// Download audio or video to a local file (requires the youtube-download feature)
+    #[command(name = "download", alias = "dl")]
+    Download {
+        /// Video ID or URL
+        #[arg(value_name = "ID_OR_URL")]
+        id_or_url: String,
+        /// What to download: audio or video
+        #[arg(long, default_value = "audio")]
+        kind: String,
+        /// Stream quality: highest or lowest
+        #[arg(long, default_value = "highest")]
+        quality: String,
+        /// Subdirectory of the download directory (ARIVU_DOWNLOAD_DIR, default: system temp dir)
+        #[arg(long)]
+        output_dir: Option<String>,
+        /// Refuse streams larger than this many megabytes
+        #[arg(long, default_value_t = 200)]
+        max_mb: u64,
     },
 
     /// Get video transcript (compat alias; use `arivu youtube get`)
@@ -1741,6 +3104,15 @@ pub enum HackernewsTools {
         /// Maximum number of results
         #[arg(long, short, default_value_t = 10)]
         limit: u32,
+        /// Only items created since (RFC3339, YYYY-MM-DD, or relative like 7d, 12h)
+        #[arg(long)]
+        since: Option<String>,
+        /// Only items created before (same formats as --since)
+        #[arg(long)]
+        until: Option<String>,
+        /// Newest first instead of by relevance
+        #[arg(long)]
+        recent: bool,
     },
 
     /// Get a story by ID
@@ -1785,23 +3157,65 @@ pub enum HackernewsTools {
         #[arg(long, short, default_value_t = 50)]
         limit: u32,
     },
-}
 
-/// arXiv tools
-#[derive(Subcommand, Clone)]
-pub enum ArxivTools {
-    /// Search papers
-    #[command(name = "search")]
-    Search {
-        /// Search query
+    /// Get a user's profile and recent activity
+    #[command(name = "user")]
+    User {
+        /// Username (case-sensitive)
         #[arg(long, short)]
-        query: String,
-        /// Maximum number of results
+        username: String,
+        /// Activity to include: all, stories, comments, none
+        #[arg(long, short, default_value = "all", value_parser = ["all", "stories", "comments", "none"])]
+        activity: String,
+        /// Activity page (0-based)
+        #[arg(long, short, default_value_t = 0)]
+        page: u32,
+        /// Activity items per page
+        #[arg(long, short, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..=100))]
+        limit: u32,
+    },
+
+    /// Show the newest item id (a starting point for `since`)
+    #[command(name = "max-item")]
+    MaxItem,
+
+    /// Recently changed item ids and profiles
+    #[command(name = "updates")]
+    Updates,
+
+    /// Items created after an id, oldest first
+    #[command(name = "since")]
+    Since {
+        /// Return items with ids greater than this
+        #[arg(long = "id", short)]
+        since_id: i64,
+        /// Maximum ids to scan
+        #[arg(long, short, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..=500))]
+        limit: u32,
+        /// Only these types (comma-separated): story, comment, job, poll, pollopt
+        #[arg(long, short)]
+        types: Option<String>,
+    },
+}
+
+/// arXiv tools
+#[derive(Subcommand, Clone)]
+pub enum ArxivTools {
+    /// Search papers
+    #[command(name = "search")]
+    Search {
+        /// Search query
+        #[arg(long, short)]
+        query: String,
+        /// Maximum number of results
         #[arg(long, short, default_value_t = 10)]
         limit: u32,
         /// Sort by: relevance, lastUpdatedDate, submittedDate
         #[arg(long, default_value = "relevance")]
         sort: String,
+        /// Include a BibTeX entry for each result
+        #[arg(long)]
+        bibtex: bool,
     },
 
     /// Get paper details
@@ -1819,6 +3233,58 @@ pub enum ArxivTools {
         #[arg(long, short)]
         id: String,
     },
+
+    /// List new papers in a category (latest announcement or a date window)
+    #[command(name = "new", alias = "list-new")]
+    New {
+        /// Category (e.g., cs.CL, hep-th)
+        category: String,
+        /// Start of the submission window (YYYY-MM-DD); omit for today's announcement
+        #[arg(long)]
+        since: Option<String>,
+        /// End of the submission window (YYYY-MM-DD, default: today)
+        #[arg(long)]
+        until: Option<String>,
+        /// Maximum number of papers
+        #[arg(
+            long,
+            short,
+            default_value_t = 50,
+            value_parser = clap::value_parser!(u32).range(1..=500)
+        )]
+        limit: u32,
+        /// Only papers whose primary category is this one
+        #[arg(long)]
+        no_cross_lists: bool,
+    },
+
+    /// Export citations as BibTeX or RIS
+    #[command(name = "cite", alias = "bibtex")]
+    Cite {
+        /// arXiv IDs (e.g., 1706.03762 1810.04805)
+        #[arg(required = true)]
+        ids: Vec<String>,
+        /// Citation format: bibtex, ris
+        #[arg(long, short, default_value = "bibtex")]
+        format: String,
+    },
+
+    /// Get the full text split into sections
+    #[command(name = "fulltext", alias = "read")]
+    Fulltext {
+        /// arXiv ID (e.g., 1706.03762)
+        #[arg(long, short)]
+        id: String,
+        /// Only sections whose heading contains this (repeatable)
+        #[arg(long = "section", short = 's')]
+        sections: Vec<String>,
+        /// Source: auto (HTML, then PDF), html, pdf
+        #[arg(long, default_value = "auto")]
+        source: String,
+        /// Cap on total section text
+        #[arg(long, default_value_t = 60_000)]
+        max_chars: usize,
+    },
 }
 
 /// GitHub tools
@@ -1844,11 +3310,33 @@ pub enum GithubTools {
         /// Repository (owner/repo)
         #[arg(long, short)]
         repo: Option<String>,
+        /// Organization
+        #[arg(long)]
+        org: Option<String>,
+        /// Only files under this path
+        #[arg(long)]
+        path: Option<String>,
+        /// Language (e.g., rust)
+        #[arg(long = "language")]
+        language: Option<String>,
         /// Maximum number of results
         #[arg(long, short, default_value_t = 10)]
         limit: u32,
     },
 
+    /// Get file contents at a ref
+    #[command(name = "file", alias = "cat")]
+    File {
+        /// Repository (owner/repo)
+        #[arg(long, short)]
+        repo: String,
+        /// File or directory path
+        path: String,
+        /// Branch, tag or commit (default: default branch)
+        #[arg(long = "ref")]
+        reference: Option<String>,
+    },
+
     /// List repository issues
     #[command(name = "issues")]
     Issues {
@@ -1884,6 +3372,132 @@ pub enum GithubTools {
         #[arg(long, short)]
         repo: String,
     },
+
+    /// List GitHub Actions workflow runs
+    #[command(name = "runs")]
+    Runs {
+        /// Repository (owner/repo)
+        #[arg(long, short)]
+        repo: String,
+        /// Only runs for this branch
+        #[arg(long, short)]
+        branch: Option<String>,
+        /// Status or conclusion: queued, in_progress, completed, success, failure, ...
+        #[arg(long)]
+        status: Option<String>,
+        /// Maximum number of results
+        #[arg(long, short, default_value_t = 20)]
+        limit: u32,
+    },
+
+    /// Show a workflow run with its jobs and failed steps
+    #[command(name = "run")]
+    Run {
+        /// Repository (owner/repo)
+        #[arg(long, short)]
+        repo: String,
+        /// Workflow run ID
+        run_id: u64,
+    },
+
+    /// Show a job's trimmed log (errors plus the last lines)
+    #[command(name = "logs")]
+    Logs {
+        /// Repository (owner/repo)
+        #[arg(long, short)]
+        repo: String,
+        /// Job ID
+        #[arg(long, conflicts_with = "run", required_unless_present = "run")]
+        job: Option<u64>,
+        /// Run ID: read its first failing job
+        #[arg(long)]
+        run: Option<u64>,
+        /// Lines kept from the end of the log
+        #[arg(long, default_value_t = 200)]
+        tail: usize,
+    },
+
+    /// List repository discussions
+    #[command(name = "discussions")]
+    Discussions {
+        /// Repository (owner/repo)
+        #[arg(long, short)]
+        repo: String,
+        /// Maximum number of results
+        #[arg(long, short, default_value_t = 20)]
+        limit: u32,
+        /// Cursor from a previous page
+        #[arg(long)]
+        cursor: Option<String>,
+    },
+
+    /// Show a discussion with its comments
+    #[command(name = "discussion")]
+    Discussion {
+        /// Repository (owner/repo)
+        #[arg(long, short)]
+        repo: String,
+        /// Discussion number
+        number: u64,
+    },
+
+    /// List releases
+    #[command(name = "releases")]
+    Releases {
+        /// Repository (owner/repo)
+        #[arg(long, short)]
+        repo: String,
+        /// Maximum number of results
+        #[arg(long, short, default_value_t = 20)]
+        limit: u32,
+    },
+
+    /// Show a release with notes and assets (latest by default)
+    #[command(name = "release")]
+    Release {
+        /// Repository (owner/repo)
+        #[arg(long, short)]
+        repo: String,
+        /// Tag name (default: latest release)
+        #[arg(long, short)]
+        tag: Option<String>,
+    },
+
+    /// Create an issue (needs ARIVU_GITHUB_ALLOW_WRITES=1 or allow_writes config)
+    #[command(name = "create-issue")]
+    CreateIssue {
+        /// Repository (owner/repo)
+        #[arg(long, short)]
+        repo: String,
+        /// Issue title
+        #[arg(long, short)]
+        title: String,
+        /// Issue body (Markdown)
+        #[arg(long, short)]
+        body: Option<String>,
+        /// Label (repeatable)
+        #[arg(long = "label")]
+        labels: Vec<String>,
+        /// Confirm the write
+        #[arg(long, short)]
+        yes: bool,
+    },
+
+    /// Comment on an issue or pull request (needs writes enabled)
+    #[command(name = "comment")]
+    Comment {
+        /// Repository (owner/repo)
+        #[arg(long, short)]
+        repo: String,
+        /// Issue or pull request number
+        number: u64,
+        /// Comment body (Markdown)
+        #[arg(long, short)]
+        body: String,
+        /// Confirm the write
+        #[arg(long, short)]
+        yes: bool,
+    },
 }
 
 /// Reddit tools
@@ -1914,6 +3528,87 @@ pub enum RedditTools {
         limit: u32,
     },
 
+    /// Find subreddits by topic
+    #[command(name = "subreddits", alias = "find")]
+    Subreddits {
+        /// Topic or keywords
+        #[arg(long, short)]
+        query: String,
+        /// Maximum number of results
+        #[arg(long, short, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..=100))]
+        limit: u32,
+        /// Include NSFW communities
+        #[arg(long)]
+        nsfw: bool,
+    },
+
+    /// Your saved posts and comments (requires OAuth via `arivu setup reddit`)
+    #[command(name = "saved")]
+    Saved {
+        /// Maximum number of results
+        #[arg(
+            long,
+            short,
+            default_value_t = 25,
+            value_parser = clap::value_parser!(u32).range(1..=5000)
+        )]
+        limit: u32,
+    },
+
+    /// Your upvoted posts (requires OAuth)
+    #[command(name = "upvoted")]
+    Upvoted {
+        /// Maximum number of results
+        #[arg(
+            long,
+            short,
+            default_value_t = 25,
+            value_parser = clap::value_parser!(u32).range(1..=5000)
+        )]
+        limit: u32,
+    },
+
+    /// Your inbox: messages, replies and mentions (requires OAuth)
+    #[command(name = "inbox")]
+    Inbox {
+        /// Which folder: inbox, unread, messages, comments, mentions
+        #[arg(long, default_value = "inbox", value_parser = ["inbox", "unread", "messages", "comments", "mentions"])]
+        filter: String,
+        /// Maximum number of results
+        #[arg(
+            long,
+            short,
+            default_value_t = 25,
+            value_parser = clap::value_parser!(u32).range(1..=5000)
+        )]
+        limit: u32,
+    },
+
+    /// Search comment bodies (scans threads of matching posts)
+    #[command(name = "search-comments")]
+    SearchComments {
+        /// Terms that must all appear in a comment
+        #[arg(long, short)]
+        query: String,
+        /// Subreddit to search in
+        #[arg(long, short)]
+        subreddit: Option<String>,
+        /// Time filter for scanned posts: hour, day, week, month, year, all
+        #[arg(long, default_value = "all")]
+        time: String,
+        /// Maximum number of comments
+        #[arg(
+            long,
+            short,
+            default_value_t = 25,
+            value_parser = clap::value_parser!(u32).range(1..=5000)
+        )]
+        limit: u32,
+        /// Number of matching posts to scan
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..=50))]
+        max_posts: u32,
+    },
+
     /// Get hot posts
     #[command(name = "hot")]
     Hot {
@@ -1978,6 +3673,50 @@ pub enum RedditTools {
         #[arg(long, default_value = "best", value_parser = ["best", "top", "new", "controversial", "old", "qa"])]
         comment_sort: String,
     },
+
+    /// Posts submitted by a user
+    #[command(name = "user-posts")]
+    UserPosts {
+        /// Username (with or without u/)
+        #[arg(long, short)]
+        username: String,
+        /// Sort order: new, hot, top, controversial
+        #[arg(long, default_value = "new", value_parser = ["new", "hot", "top", "controversial"])]
+        sort: String,
+        /// Time filter for top/controversial: hour, day, week, month, year, all
+        #[arg(long, short, default_value = "all")]
+        time: String,
+        /// Maximum number of results
+        #[arg(
+            long,
+            short,
+            default_value_t = 25,
+            value_parser = clap::value_parser!(u32).range(1..=5000)
+        )]
+        limit: u32,
+    },
+
+    /// Comments written by a user
+    #[command(name = "user-comments")]
+    UserComments {
+        /// Username (with or without u/)
+        #[arg(long, short)]
+        username: String,
+        /// Sort order: new, hot, top, controversial
+        #[arg(long, default_value = "new", value_parser = ["new", "hot", "top", "controversial"])]
+        sort: String,
+        /// Time filter for top/controversial: hour, day, week, month, year, all
+        #[arg(long, short, default_value = "all")]
+        time: String,
+        /// Maximum number of results
+        #[arg(
+            long,
+            short,
+            default_value_t = 25,
+            value_parser = clap::value_parser!(u32).range(1..=5000)
+        )]
+        limit: u32,
+    },
 }
 
 /// Web scraping tools
@@ -1992,6 +3731,12 @@ pub enum WebTools {
         /// Output format: text, markdown, html
         #[arg(long, short, default_value = "markdown")]
         format: String,
+        /// Render JavaScript with headless Chrome (web-render builds)
+        #[arg(long)]
+        render: bool,
+        /// Readability extraction: title, byline, date and a clean markdown body
+        #[arg(long)]
+        article: bool,
     },
 
     /// Extract main content from a page
@@ -2000,6 +3745,9 @@ pub enum WebTools {
         /// URL to extract from
         #[arg(long, short)]
         url: String,
+        /// Render JavaScript with headless Chrome (web-render builds)
+        #[arg(long)]
+        render: bool,
         /// Extract images
         #[arg(long)]
         images: bool,
@@ -2015,6 +3763,46 @@ pub enum WebTools {
         #[arg(long, short)]
         url: String,
     },
+
+    /// Crawl a site from a seed URL (content per page plus link graph)
+    #[command(name = "crawl")]
+    Crawl {
+        /// Seed URL
+        #[arg(long, short)]
+        url: String,
+        /// Pages to fetch (max 200)
+        #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..=200))]
+        max_pages: u32,
+        /// Link hops from the seed (max 5)
+        #[arg(long, short = 'd', default_value_t = 2, value_parser = clap::value_parser!(u32).range(0..=5))]
+        max_depth: u32,
+        /// Follow links to other domains too
+        #[arg(long)]
+        any_domain: bool,
+        /// Fetch pages robots.txt disallows
+        #[arg(long)]
+        ignore_robots: bool,
+        /// Readability extraction for each page
+        #[arg(long)]
+        article: bool,
+        /// Truncate each page's content to this many characters
+        #[arg(long)]
+        max_chars: Option<u32>,
+    },
+
+    /// Find a site's sitemap URLs and RSS/Atom feeds
+    #[command(name = "discover", alias = "sitemap")]
+    Discover {
+        /// Domain or URL
+        #[arg(long, short)]
+        domain: String,
+        /// Sitemap page URLs to return (max 5000)
+        #[arg(long, short, default_value_t = 200, value_parser = clap::value_parser!(u32).range(1..=5000))]
+        limit: u32,
+        /// Don't probe common feed paths when the page links no feed
+        #[arg(long)]
+        no_probe: bool,
+    },
 }
 
 /// Wikipedia tools
@@ -2034,6 +3822,9 @@ pub enum WikipediaTools {
             value_parser = clap::value_parser!(u32).range(1..=5000)
         )]
         limit: u32,
+        /// Language edition (e.g., de, fr, simple)
+        #[arg(long = "lang", short = 'L')]
+        language: Option<String>,
     },
 
     /// Get article content
@@ -2042,6 +3833,15 @@ pub enum WikipediaTools {
         /// Article title
         #[arg(long, short)]
         title: String,
+        /// Only this section, by heading (e.g., "History")
+        #[arg(long, short)]
+        section: Option<String>,
+        /// Render as markdown converted from wikitext instead of plain text
+        #[arg(long)]
+        markdown: bool,
+        /// Language edition (e.g., de, fr, simple)
+        #[arg(long = "lang", short = 'L')]
+        language: Option<String>,
     },
 
     /// Get article summary
@@ -2050,6 +3850,75 @@ pub enum WikipediaTools {
         /// Article title
         #[arg(long, short)]
         title: String,
+        /// Language edition (e.g., de, fr, simple)
+        #[arg(long = "lang", short = 'L')]
+        language: Option<String>,
+    },
+
+    /// List pages and subcategories in a category
+    #[command(name = "category", alias = "members")]
+    Category {
+        /// Category name (the "Category:" prefix is optional)
+        category: String,
+        /// Member kinds to include: page, subcat, file (repeatable)
+        #[arg(long = "type", short = 't')]
+        types: Vec<String>,
+        /// Maximum number of members
+        #[arg(
+            long,
+            short,
+            default_value_t = 100,
+            value_parser = clap::value_parser!(u32).range(1..=5000)
+        )]
+        limit: u32,
+        /// Continue from a previous next_cursor
+        #[arg(long)]
+        cursor: Option<String>,
+        /// Language edition (e.g., de, fr, simple)
+        #[arg(long = "lang", short = 'L')]
+        language: Option<String>,
+    },
+
+    /// List articles an article links to
+    #[command(name = "links")]
+    Links {
+        /// Article title
+        title: String,
+        /// Maximum number of links
+        #[arg(
+            long,
+            short,
+            default_value_t = 100,
+            value_parser = clap::value_parser!(u32).range(1..=5000)
+        )]
+        limit: u32,
+        /// Continue from a previous next_cursor
+        #[arg(long)]
+        cursor: Option<String>,
+        /// Language edition (e.g., de, fr, simple)
+        #[arg(long = "lang", short = 'L')]
+        language: Option<String>,
+    },
+
+    /// List articles that link to an article
+    #[command(name = "backlinks")]
+    Backlinks {
+        /// Article title
+        title: String,
+        /// Maximum number of links
+        #[arg(
+            long,
+            short,
+            default_value_t = 100,
+            value_parser = clap::value_parser!(u32).range(1..=5000)
+        )]
+        limit: u32,
+        /// Continue from a previous next_cursor
+        #[arg(long)]
+        cursor: Option<String>,
+        /// Language edition (e.g., de, fr, simple)
+        #[arg(long = "lang", short = 'L')]
+        language: Option<String>,
     },
 }
 
@@ -2059,9 +3928,9 @@ pub enum PubmedTools {
     /// Search articles
     #[command(name = "search")]
     Search {
-        /// Search query
+        /// Search query (optional when filters are given)
         #[arg(long, short)]
-        query: String,
+        query: Option<String>,
         /// Maximum number of results
         #[arg(
             long,
@@ -2070,6 +3939,21 @@ pub enum PubmedTools {
             value_parser = clap::value_parser!(u32).range(1..=5000)
         )]
         limit: u32,
+        /// MeSH heading that must apply (repeatable)
+        #[arg(long = "mesh")]
+        mesh_terms: Vec<String>,
+        /// Publication type, e.g. rct, review, meta-analysis (repeatable)
+        #[arg(long = "pub-type")]
+        publication_types: Vec<String>,
+        /// humans or animals
+        #[arg(long, value_parser = ["humans", "animals"])]
+        species: Option<String>,
+        /// Earliest publication date (YYYY, YYYY/MM or YYYY/MM/DD)
+        #[arg(long = "from")]
+        start_date: Option<String>,
+        /// Latest publication date (YYYY, YYYY/MM or YYYY/MM/DD)
+        #[arg(long = "to")]
+        end_date: Option<String>,
     },
 
     /// Get article by PMID
@@ -2079,6 +3963,19 @@ pub enum PubmedTools {
         #[arg(long, short)]
         pmid: String,
     },
+
+    /// Get full text from PubMed Central (falls back to the abstract)
+    #[command(name = "fulltext", alias = "read")]
+    Fulltext {
+        /// PubMed ID or PMCID (e.g., 23193287 or PMC3531190)
+        id: String,
+        /// Only sections whose heading contains this (repeatable)
+        #[arg(long = "section", short = 's')]
+        sections: Vec<String>,
+        /// Cap on total section text
+        #[arg(long, default_value_t = 60_000)]
+        max_chars: usize,
+    },
 }
 
 /// Semantic Scholar tools
@@ -2122,6 +4019,12 @@ pub enum SemanticScholarTools {
             value_parser = clap::value_parser!(u32).range(1..=5000)
         )]
         limit: u32,
+        /// Offset to start from (use `next` from a previous call)
+        #[arg(long, default_value_t = 0)]
+        offset: u32,
+        /// Fields to return, comma-separated (e.g. title,year,authors.name)
+        #[arg(long, value_delimiter = ',')]
+        fields: Vec<String>,
     },
 
     /// Get paper references
@@ -2138,6 +4041,42 @@ pub enum SemanticScholarTools {
             value_parser = clap::value_parser!(u32).range(1..=5000)
         )]
         limit: u32,
+        /// Offset to start from (use `next` from a previous call)
+        #[arg(long, default_value_t = 0)]
+        offset: u32,
+        /// Fields to return, comma-separated (e.g. title,year,authors.name)
+        #[arg(long, value_delimiter = ',')]
+        fields: Vec<String>,
+    },
+
+    /// Recommend papers similar to seed papers
+    #[command(name = "recommend")]
+    Recommend {
+        /// Seed paper IDs to find more like
+        #[arg(required = true)]
+        ids: Vec<String>,
+        /// Paper ID to steer away from (repeatable)
+        #[arg(long = "not")]
+        negative: Vec<String>,
+        /// Maximum number of recommendations
+        #[arg(
+            long,
+            short,
+            default_value_t = 10,
+            value_parser = clap::value_parser!(u32).range(1..=500)
+        )]
+        limit: u32,
+    },
+
+    /// Get details for many papers in one request
+    #[command(name = "batch")]
+    Batch {
+        /// Paper IDs (S2 IDs or DOI:..., ARXIV:..., PMID:...)
+        #[arg(required = true, num_args = 1..=500)]
+        ids: Vec<String>,
+        /// Fields to return, comma-separated (e.g. title,year,authors.name)
+        #[arg(long, value_delimiter = ',')]
+        fields: Vec<String>,
     },
 }
 
@@ -2158,6 +4097,9 @@ pub enum SlackTools {
         /// Pagination cursor
         #[arg(long)]
         cursor: Option<String>,
+        /// Include archived channels
+        #[arg(long)]
+        include_archived: bool,
     },
 
     /// Get channel messages
@@ -2179,23 +4121,112 @@ pub enum SlackTools {
         cursor: Option<String>,
     },
 
-    /// Search messages
+    /// Get a thread: the parent message and all replies
+    #[command(name = "thread", alias = "replies")]
+    Thread {
+        /// Channel ID
+        #[arg(long)]
+        channel: String,
+        /// Parent message ts
+        ts: String,
+        /// Cap on messages returned (default: whole thread)
+        #[arg(long, short, value_parser = clap::value_parser!(u32).range(1..=5000))]
+        limit: Option<u32>,
+    },
+
+    /// List files shared in a channel
+    #[command(name = "files", alias = "list-files")]
+    Files {
+        /// Channel ID
+        #[arg(long)]
+        channel: Option<String>,
+        /// Uploader's user ID
+        #[arg(long)]
+        user: Option<String>,
+        /// File types, comma-separated (e.g. canvas,pdfs,snippets)
+        #[arg(long)]
+        types: Option<String>,
+        /// Maximum number of results
+        #[arg(
+            long,
+            short,
+            default_value_t = 100,
+            value_parser = clap::value_parser!(u32).range(1..=5000)
+        )]
+        limit: u32,
+        /// Pagination cursor
+        #[arg(long)]
+        cursor: Option<String>,
+    },
+
+    /// Get a file or canvas as text
+    #[command(name = "file", alias = "get-file")]
+    File {
+        /// File or canvas ID (F…)
+        id: String,
+        /// Refuse downloads larger than this many bytes
+        #[arg(long)]
+        max_bytes: Option<u64>,
+        /// Truncate the text to this many characters
+        #[arg(long)]
+        max_chars: Option<usize>,
+    },
+
+    /// Post a message or thread reply (needs writes enabled)
+    #[command(name = "post")]
+    Post {
+        /// Channel ID
+        #[arg(long)]
+        channel: String,
+        /// Message text
+        #[arg(long, short)]
+        text: String,
+        /// Reply in this thread
+        #[arg(long)]
+        thread_ts: Option<String>,
+        /// Also show the thread reply in the channel
+        #[arg(long, requires = "thread_ts")]
+        broadcast: bool,
+        /// Confirm the write
+        #[arg(long, short)]
+        yes: bool,
+    },
+
+    /// Search messages across the workspace
     #[command(name = "search")]
     Search {
         /// Search query
         #[arg(long, short)]
-        query: String,
-        /// Results per page (1-100)
+        query: Option<String>,
+        /// Author (user ID or @handle)
+        #[arg(long)]
+        from: Option<String>,
+        /// Channel (ID or #name)
+        #[arg(long = "in")]
+        in_channel: Option<String>,
+        /// Only messages before this date (YYYY-MM-DD)
+        #[arg(long)]
+        before: Option<String>,
+        /// Only messages after this date (YYYY-MM-DD)
+        #[arg(long)]
+        after: Option<String>,
+        /// Only messages containing a link
+        #[arg(long)]
+        has_link: bool,
+        /// Total matches to return (page size with --page)
         #[arg(
             long,
             short,
-            default_value_t = 50,
-            value_parser = clap::value_parser!(u32).range(1..=100)
+            default_value_t = 20,
+            value_parser = clap::value_parser!(u32).range(1..=1000)
         )]
         limit: u32,
-        /// Page number (1+)
-        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
-        page: u32,
+        /// Pagination cursor (next_cursor from a previous search)
+        #[arg(long, conflicts_with = "page")]
+        cursor: Option<String>,
+        /// Page number (1+), for page-number pagination
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        page: Option<u32>,
         /// Sort order: score or timestamp
         #[arg(long, value_parser = ["score", "timestamp"])]
         sort: Option<String>,
@@ -2265,6 +4296,32 @@ pub enum XTools {
         tweet_id: String,
     },
 
+    /// Unroll the thread containing a tweet
+    #[command(name = "thread", alias = "get-thread")]
+    Thread {
+        /// Any tweet ID or status URL in the thread
+        tweet_id: String,
+        /// Also include replies from other accounts
+        #[arg(long, short)]
+        replies: bool,
+        /// Replies to fetch with --replies
+        #[arg(long, default_value_t = 50)]
+        max_replies: u32,
+    },
+
+    /// Latest tweets from a list
+    #[command(name = "list-timeline", alias = "list")]
+    ListTimeline {
+        /// List ID or x.com/i/lists/<id> URL
+        list_id: String,
+        /// Number of tweets
+        #[arg(long, short, default_value_t = 20)]
+        limit: u32,
+        /// Pagination cursor
+        #[arg(long)]
+        cursor: Option<String>,
+    },
+
     /// Get home timeline
     #[command(name = "timeline", alias = "home")]
     Timeline {
@@ -2361,15 +4418,48 @@ pub enum DiscordTools {
         limit: Option<u32>,
     },
 
-    /// Send message
+    /// Send message to a channel or thread (needs writes enabled)
     #[command(name = "send", alias = "send-message")]
     Send {
-        /// Channel ID
+        /// Channel or thread ID
         #[arg(long, short)]
         channel_id: u64,
         /// Message content
         #[arg(long, short)]
         content: String,
+        /// Reply to this message ID
+        #[arg(long)]
+        reply_to: Option<u64>,
+        /// Confirm the write
+        #[arg(long, short)]
+        yes: bool,
+    },
+
+    /// List active threads in a server, or archived threads of a channel
+    #[command(name = "threads", alias = "list-threads")]
+    Threads {
+        /// Guild/server ID (active threads)
+        #[arg(long, short)]
+        guild_id: Option<u64>,
+        /// Parent channel ID
+        #[arg(long)]
+        channel_id: Option<u64>,
+        /// Archived public threads of --channel-id
+        #[arg(long, requires = "channel_id")]
+        archived: bool,
+        /// Maximum archived threads (max 100)
+        #[arg(long, short)]
+        limit: Option<u32>,
+    },
+
+    /// Read a thread's messages, oldest first
+    #[command(name = "thread", alias = "thread-messages")]
+    Thread {
+        /// Thread ID
+        thread_id: u64,
+        /// Maximum messages (max 1000)
+        #[arg(long, short)]
+        limit: Option<u32>,
     },
 
     /// Search messages
@@ -2433,6 +4523,68 @@ pub enum RssTools {
         #[arg(long, short)]
         url: String,
     },
+
+    /// Subscribe to a feed
+    #[command(name = "subscribe", alias = "add")]
+    Subscribe {
+        /// Feed URL
+        #[arg(long, short)]
+        url: String,
+        /// Display title (default: the feed's title)
+        #[arg(long, short)]
+        title: Option<String>,
+        /// Category to file the feed under
+        #[arg(long)]
+        category: Option<String>,
+    },
+
+    /// Remove a feed subscription
+    #[command(name = "unsubscribe", alias = "remove")]
+    Unsubscribe {
+        /// Feed URL
+        #[arg(long, short)]
+        url: String,
+    },
+
+    /// List subscribed feeds
+    #[command(name = "subscriptions", alias = "list-subscriptions")]
+    Subscriptions {
+        /// Only feeds in this category
+        #[arg(long)]
+        category: Option<String>,
+    },
+
+    /// Subscribe to every feed in an OPML file
+    #[command(name = "import", alias = "import-opml")]
+    Import {
+        /// OPML file path
+        path: String,
+    },
+
+    /// Export subscriptions as OPML
+    #[command(name = "export", alias = "export-opml")]
+    Export {
+        /// File to write (default: print the OPML)
+        #[arg(long, short)]
+        output: Option<String>,
+    },
+
+    /// Items from subscribed feeds not seen in earlier polls
+    #[command(name = "new", alias = "fetch-new")]
+    New {
+        /// Only this subscribed feed
+        #[arg(long, short)]
+        url: Option<String>,
+        /// Only feeds in this category
+        #[arg(long)]
+        category: Option<String>,
+        /// Most new items per feed
+        #[arg(long, short)]
+        limit: Option<u32>,
+        /// Preview without marking items as seen
+        #[arg(long)]
+        peek: bool,
+    },
 }
 
 /// bioRxiv tools
@@ -2475,133 +4627,1862 @@ pub enum BiorxivTools {
     },
 }
 
-/// Sci-Hub tools
+/// Crossref tools
 #[derive(Subcommand, Clone)]
-pub enum ScihubTools {
-    /// Get paper by DOI
-    #[command(name = "paper", alias = "get-paper")]
-    Paper {
-        /// DOI
+pub enum CrossrefTools {
+    /// Get metadata for a DOI
+    #[command(name = "work", alias = "get-work")]
+    Work {
+        /// DOI (bare or doi.org URL)
         #[arg(long, short)]
         doi: String,
     },
-}
 
-/// macOS tools
-#[derive(Subcommand, Clone)]
-pub enum MacosTools {
-    /// Run AppleScript or JXA
-    #[command(name = "script", alias = "run-script")]
-    Script {
-        /// Script language (applescript, javascript, jxa)
-        #[arg(long, short, default_value = "applescript")]
-        language: String,
-        /// Script source code
+    /// Bibliographic search over works
+    #[command(name = "search", alias = "search-works")]
+    Search {
+        /// Query (title, keywords or citation string)
+        query: String,
+        /// Restrict to author name
         #[arg(long, short)]
-        script: String,
-        /// Optional parameters (JSON)
+        author: Option<String>,
+        /// Earliest publication date (YYYY[-MM[-DD]])
         #[arg(long)]
-        params: Option<String>,
-        /// Max output characters
+        from_date: Option<String>,
+        /// Latest publication date (YYYY[-MM[-DD]])
         #[arg(long)]
-        max_output_chars: Option<u32>,
-    },
-
-    /// Show notification
-    #[command(name = "notify", alias = "notification")]
-    Notify {
-        /// Title
-        #[arg(long, short)]
-        title: Option<String>,
-        /// Message
+        until_date: Option<String>,
+        /// Work type (e.g. journal-article)
+        #[arg(long = "type")]
+        work_type: Option<String>,
+        /// Sort: relevance, published, is-referenced-by-count
+        #[arg(long)]
+        sort: Option<String>,
+        /// Maximum results (max 100)
         #[arg(long, short)]
-        message: String,
-        /// Subtitle
+        limit: Option<u32>,
+        /// Offset for pagination
         #[arg(long)]
-        subtitle: Option<String>,
+        offset: Option<u32>,
     },
 
-    /// Reveal file in Finder
-    #[command(name = "reveal")]
-    Reveal {
-        /// File path
+    /// Search the Funder Registry
+    #[command(name = "funders", alias = "search-funders")]
+    Funders {
+        /// Funder name
+        query: String,
+        /// Maximum results
         #[arg(long, short)]
-        path: String,
+        limit: Option<u32>,
     },
 
-    /// Get clipboard content
-    #[command(name = "clipboard", alias = "get-clipboard")]
-    GetClipboard,
+    /// Works acknowledging a funder
+    #[command(name = "funder-works")]
+    FunderWorks {
+        /// Funder Registry ID (e.g. 100000001)
+        #[arg(long, short)]
+        funder_id: String,
+        /// Optional keyword filter
+        #[arg(long, short)]
+        query: Option<String>,
+        /// Maximum results (max 100)
+        #[arg(long, short)]
+        limit: Option<u32>,
+        /// Offset for pagination
+        #[arg(long)]
+        offset: Option<u32>,
+    },
 
-    /// Set clipboard content
-    #[command(name = "set-clipboard")]
-    SetClipboard {
-        /// Text to copy
+    /// Search journals by title
+    #[command(name = "journals", alias = "search-journals")]
+    Journals {
+        /// Journal title
+        query: String,
+        /// Maximum results
         #[arg(long, short)]
-        text: String,
+        limit: Option<u32>,
     },
 
-    /// Run Apple Shortcut
-    #[command(name = "shortcut", alias = "run-shortcut")]
-    Shortcut {
-        /// Shortcut name
+    /// Works published in a journal
+    #[command(name = "journal-works")]
+    JournalWorks {
+        /// Journal ISSN
         #[arg(long, short)]
-        name: String,
-        /// Optional input (JSON)
+        issn: String,
+        /// Optional keyword filter
         #[arg(long, short)]
-        input: Option<String>,
+        query: Option<String>,
+        /// Maximum results (max 100)
+        #[arg(long, short)]
+        limit: Option<u32>,
+        /// Offset for pagination
+        #[arg(long)]
+        offset: Option<u32>,
     },
 }
 
-/// Spotlight tools
+/// DBLP tools
 #[derive(Subcommand, Clone)]
-pub enum SpotlightTools {
-    /// Full-text content search
-    #[command(name = "search", alias = "search-content")]
-    SearchContent {
-        /// Search query
-        #[arg(long, short)]
+pub enum DblpTools {
+    /// Search publications by title/keywords
+    #[command(name = "search", alias = "search-publications")]
+    Search {
+        /// Title words or keywords
         query: String,
-        /// Directory to search in
+        /// Restrict to publication year
         #[arg(long, short)]
-        directory: Option<String>,
-        /// File kind filter
+        year: Option<u32>,
+        /// Restrict to venue (e.g. NeurIPS)
+        #[arg(long)]
+        venue: Option<String>,
+        /// Maximum results (max 100)
         #[arg(long, short)]
-        kind: Option<String>,
+        limit: Option<u32>,
+        /// Offset for pagination
+        #[arg(long)]
+        offset: Option<u32>,
+        /// Include BibTeX for each hit
+        #[arg(long)]
+        bibtex: bool,
+    },
+
+    /// Search authors
+    #[command(name = "authors", alias = "search-authors")]
+    Authors {
+        /// Author name
+        query: String,
         /// Maximum results
-        #[arg(long, short, default_value_t = 50)]
-        limit: u32,
+        #[arg(long, short)]
+        limit: Option<u32>,
     },
 
-    /// Search by file name
-    #[command(name = "name", alias = "search-by-name")]
-    SearchByName {
-        /// File name
+    /// List an author's publications
+    #[command(name = "author-pubs", alias = "author")]
+    AuthorPubs {
+        /// DBLP person id (e.g. k/DonaldEKnuth)
         #[arg(long, short)]
-        name: String,
-        /// Directory to search in
+        pid: Option<String>,
+        /// Author name (resolved to the top match)
         #[arg(long, short)]
-        directory: Option<String>,
+        name: Option<String>,
+        /// Maximum publications
+        #[arg(long, short)]
+        limit: Option<u32>,
+        /// Include BibTeX for each publication
+        #[arg(long)]
+        bibtex: bool,
+    },
+
+    /// Search venues (conferences and journals)
+    #[command(name = "venues", alias = "search-venues")]
+    Venues {
+        /// Venue name or acronym
+        query: String,
         /// Maximum results
-        #[arg(long, short, default_value_t = 50)]
-        limit: u32,
+        #[arg(long, short)]
+        limit: Option<u32>,
     },
 
-    /// Search by file kind
-    #[command(name = "kind", alias = "search-by-kind")]
-    SearchByKind {
-        /// File kind (pdf, image, video, etc.)
+    /// Browse publications in a venue
+    #[command(name = "venue", alias = "venue-pubs")]
+    Venue {
+        /// Venue name or acronym
         #[arg(long, short)]
-        kind: String,
-        /// Directory to search in
+        venue: String,
+        /// Publication year
         #[arg(long, short)]
-        directory: Option<String>,
-        /// Maximum results
-        #[arg(long, short, default_value_t = 50)]
-        limit: u32,
+        year: Option<u32>,
+        /// Maximum results (max 100)
+        #[arg(long, short)]
+        limit: Option<u32>,
+        /// Offset for pagination
+        #[arg(long)]
+        offset: Option<u32>,
     },
 
-    /// Search recent files
+    /// Get BibTeX for a record key
+    #[command(name = "bibtex")]
+    Bibtex {
+        /// DBLP record key
+        #[arg(long, short)]
+        key: String,
+    },
+}
+
+/// Market data tools
+#[derive(Subcommand, Clone)]
+pub enum MarketDataTools {
+    /// Latest quote for one or more tickers
+    #[command(name = "quote", alias = "get-quote")]
+    Quote {
+        /// Comma-separated tickers (e.g. AAPL,MSFT,BTC-USD)
+        symbols: String,
+        /// Data source: yahoo (default) or stooq
+        #[arg(long)]
+        source: Option<String>,
+    },
+
+    /// Historical OHLCV candles
+    #[command(name = "history", alias = "get-history")]
+    History {
+        /// Ticker symbol
+        symbol: String,
+        /// Start date (YYYY-MM-DD)
+        #[arg(long)]
+        start_date: Option<String>,
+        /// End date, inclusive (YYYY-MM-DD)
+        #[arg(long)]
+        end_date: Option<String>,
+        /// Relative range when no dates are given (5d, 1mo, 1y, max, ...)
+        #[arg(long, short)]
+        range: Option<String>,
+        /// Candle interval: 1d, 1wk, 1mo
+        #[arg(long, short)]
+        interval: Option<String>,
+        /// Data source: yahoo (default) or stooq
+        #[arg(long)]
+        source: Option<String>,
+    },
+
+    /// Search ticker symbols
+    #[command(name = "search", alias = "search-symbols")]
+    Search {
+        /// Company, fund or index name
+        query: String,
+        /// Maximum results
+        #[arg(long, short)]
+        limit: Option<u32>,
+    },
+}
+
+/// CoinGecko tools
+#[derive(Subcommand, Clone)]
+pub enum CoingeckoTools {
+    /// Find coin ids by name or ticker
+    #[command(name = "search", alias = "search-coins")]
+    Search {
+        /// Coin name or ticker
+        query: String,
+        /// Maximum results
+        #[arg(long, short)]
+        limit: Option<usize>,
+    },
+
+    /// Current price for coin ids
+    #[command(name = "price", alias = "get-price")]
+    Price {
+        /// Comma-separated CoinGecko ids (e.g. bitcoin,ethereum)
+        ids: String,
+        /// Comma-separated quote currencies (default: usd)
+        #[arg(long = "vs")]
+        vs_currencies: Option<String>,
+    },
+
+    /// Market data ranked by market cap
+    #[command(name = "markets", alias = "get-markets")]
+    Markets {
+        /// Quote currency (default: usd)
+        #[arg(long = "vs")]
+        vs_currency: Option<String>,
+        /// Comma-separated CoinGecko ids
+        #[arg(long)]
+        ids: Option<String>,
+        /// Category id (e.g. layer-1)
+        #[arg(long, short)]
+        category: Option<String>,
+        /// Results per page (max 250)
+        #[arg(long, short)]
+        limit: Option<u32>,
+        /// Page number
+        #[arg(long, short)]
+        page: Option<u32>,
+    },
+
+    /// Coin profile and market data
+    #[command(name = "coin", alias = "get-coin")]
+    Coin {
+        /// CoinGecko coin id
+        id: String,
+    },
+
+    /// Historical price, market cap and volume
+    #[command(name = "chart", alias = "market-chart")]
+    Chart {
+        /// CoinGecko coin id
+        id: String,
+        /// Quote currency (default: usd)
+        #[arg(long = "vs")]
+        vs_currency: Option<String>,
+        /// Lookback in days or 'max'
+        #[arg(long, short)]
+        days: Option<String>,
+        /// Start date (YYYY-MM-DD)
+        #[arg(long)]
+        start_date: Option<String>,
+        /// End date (YYYY-MM-DD)
+        #[arg(long)]
+        end_date: Option<String>,
+    },
+}
+
+/// Open Library tools
+#[derive(Subcommand, Clone)]
+pub enum OpenlibraryTools {
+    /// Search books
+    #[command(name = "search", alias = "search-books")]
+    Search {
+        /// Free-text query
+        query: Option<String>,
+        /// Title filter
+        #[arg(long, short)]
+        title: Option<String>,
+        /// Author filter
+        #[arg(long, short)]
+        author: Option<String>,
+        /// ISBN-10 or ISBN-13
+        #[arg(long, short)]
+        isbn: Option<String>,
+        /// Subject filter
+        #[arg(long, short)]
+        subject: Option<String>,
+        /// Maximum results (max 100)
+        #[arg(long, short)]
+        limit: Option<u32>,
+        /// Page number
+        #[arg(long, short)]
+        page: Option<u32>,
+    },
+
+    /// Get work (book) details
+    #[command(name = "work", alias = "get-work")]
+    Work {
+        /// Work id (OL...W), key or URL
+        id: String,
+    },
+
+    /// Get edition details by id or ISBN
+    #[command(name = "edition", alias = "get-edition")]
+    Edition {
+        /// Edition id (OL...M), key or URL
+        id: Option<String>,
+        /// ISBN-10 or ISBN-13
+        #[arg(long, short)]
+        isbn: Option<String>,
+    },
+
+    /// Search authors
+    #[command(name = "authors", alias = "search-authors")]
+    Authors {
+        /// Author name
+        query: String,
+        /// Maximum results
+        #[arg(long, short)]
+        limit: Option<u32>,
+    },
+
+    /// Get author profile
+    #[command(name = "author", alias = "get-author")]
+    Author {
+        /// Author id (OL...A), key or URL
+        id: String,
+    },
+
+    /// List an author's works
+    #[command(name = "author-works")]
+    AuthorWorks {
+        /// Author id (OL...A), key or URL
+        id: String,
+        /// Maximum works
+        #[arg(long, short)]
+        limit: Option<u32>,
+        /// Offset for pagination
+        #[arg(long)]
+        offset: Option<u32>,
+    },
+}
+
+/// Podcast tools
+#[derive(Subcommand, Clone)]
+pub enum PodcastsTools {
+    /// Search podcast shows
+    #[command(name = "search", alias = "search-podcasts")]
+    Search {
+        /// Show name or topic
+        query: String,
+        /// Maximum results
+        #[arg(long, short)]
+        limit: Option<u32>,
+    },
+
+    /// Search episodes across shows
+    #[command(name = "episodes", alias = "search-episodes")]
+    Episodes {
+        /// Episode topic or guest
+        query: String,
+        /// Maximum results
+        #[arg(long, short)]
+        limit: Option<u32>,
+    },
+
+    /// List a show's episodes from its RSS feed
+    #[command(name = "list", alias = "list-episodes")]
+    List {
+        /// Podcast id (iTunes collection id)
+        #[arg(long)]
+        id: Option<String>,
+        /// RSS feed URL
+        #[arg(long)]
+        feed_url: Option<String>,
+        /// Maximum episodes
+        #[arg(long, short)]
+        limit: Option<u32>,
+    },
+
+    /// Get episode metadata, audio and transcript links
+    #[command(name = "episode", alias = "get-episode")]
+    Episode {
+        /// Podcast id (iTunes collection id)
+        #[arg(long)]
+        id: Option<String>,
+        /// RSS feed URL
+        #[arg(long)]
+        feed_url: Option<String>,
+        /// Episode GUID
+        #[arg(long)]
+        guid: Option<String>,
+        /// Episode title substring
+        #[arg(long, short)]
+        title: Option<String>,
+    },
+
+    /// Get an episode transcript as plain text
+    #[command(name = "transcript", alias = "get-transcript")]
+    Transcript {
+        /// Transcript URL (skips feed lookup)
+        #[arg(long)]
+        url: Option<String>,
+        /// Podcast id (iTunes collection id)
+        #[arg(long)]
+        id: Option<String>,
+        /// RSS feed URL
+        #[arg(long)]
+        feed_url: Option<String>,
+        /// Episode GUID
+        #[arg(long)]
+        guid: Option<String>,
+        /// Episode title substring
+        #[arg(long, short)]
+        title: Option<String>,
+    },
+}
+
+/// Twitch tools
+#[derive(Subcommand, Clone)]
+pub enum TwitchTools {
+    /// Search channels
+    #[command(name = "channels", alias = "search-channels")]
+    Channels {
+        /// Search text
+        query: String,
+        /// Only live channels
+        #[arg(long)]
+        live: bool,
+        /// Maximum results
+        #[arg(long, short)]
+        limit: Option<u32>,
+    },
+
+    /// Live streams by channel or game
+    #[command(name = "streams", alias = "get-streams")]
+    Streams {
+        /// Comma-separated channel logins
+        #[arg(long, short)]
+        channels: Option<String>,
+        /// Game/category name
+        #[arg(long, short)]
+        game: Option<String>,
+        /// Stream language (ISO 639-1)
+        #[arg(long)]
+        language: Option<String>,
+        /// Maximum results
+        #[arg(long, short)]
+        limit: Option<u32>,
+    },
+
+    /// List a channel's VODs
+    #[command(name = "videos", alias = "vods")]
+    Videos {
+        /// Channel login or URL
+        channel: String,
+        /// Video type (all, archive, highlight, upload)
+        #[arg(long = "type", short)]
+        video_type: Option<String>,
+        /// Sort order (time, trending, views)
+        #[arg(long, short)]
+        sort: Option<String>,
+        /// Maximum results
+        #[arg(long, short)]
+        limit: Option<u32>,
+        /// Pagination cursor
+        #[arg(long)]
+        cursor: Option<String>,
+    },
+
+    /// Top clips for a channel or game
+    #[command(name = "clips", alias = "search-clips")]
+    Clips {
+        /// Channel login or URL
+        #[arg(long, short)]
+        channel: Option<String>,
+        /// Game/category name
+        #[arg(long, short)]
+        game: Option<String>,
+        /// RFC 3339 start time
+        #[arg(long)]
+        since: Option<String>,
+        /// RFC 3339 end time
+        #[arg(long)]
+        until: Option<String>,
+        /// Maximum results
+        #[arg(long, short)]
+        limit: Option<u32>,
+        /// Pagination cursor
+        #[arg(long)]
+        cursor: Option<String>,
+    },
+
+    /// Chat replay for a VOD
+    #[command(name = "chat", alias = "chat-replay")]
+    Chat {
+        /// VOD id or twitch.tv/videos URL
+        video_id: String,
+        /// Start offset in seconds
+        #[arg(long, short)]
+        offset: Option<u64>,
+        /// Pagination cursor
+        #[arg(long)]
+        cursor: Option<String>,
+        /// Maximum messages
+        #[arg(long, short)]
+        limit: Option<u32>,
+    },
+}
+
+/// Vimeo tools
+#[derive(Subcommand, Clone)]
+pub enum VimeoTools {
+    /// Search for videos
+    #[command(name = "search")]
+    Search {
+        /// Search query
+        query: String,
+        /// Maximum number of results
+        #[arg(long, short)]
+        limit: Option<u32>,
+        /// Sort order (relevant, date, plays, likes, duration)
+        #[arg(long, short)]
+        sort: Option<String>,
+    },
+
+    /// Get video metadata and transcript
+    #[command(name = "get", alias = "video")]
+    Get {
+        /// Video ID or URL
+        video_id: String,
+        /// Preferred caption language
+        #[arg(long)]
+        language: Option<String>,
+        /// Include description, tags and text track list
+        #[arg(long)]
+        detailed: bool,
+    },
+
+    /// Download a caption/subtitle track
+    #[command(name = "captions", alias = "texttracks")]
+    Captions {
+        /// Video ID or URL
+        video_id: String,
+        /// Caption language
+        #[arg(long)]
+        language: Option<String>,
+        /// Return raw WebVTT instead of plain text
+        #[arg(long)]
+        vtt: bool,
+    },
+}
+
+/// Salesforce tools
+#[derive(Subcommand, Clone)]
+pub enum SalesforceTools {
+    /// Run a SOQL SELECT
+    #[command(name = "query", alias = "soql")]
+    Query {
+        /// SOQL statement
+        soql: String,
+        /// Maximum records across pages
+        #[arg(long, short)]
+        max: Option<u32>,
+        /// Include deleted/archived records
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Get a record by id
+    #[command(name = "record", alias = "get")]
+    Record {
+        /// Object API name (e.g. Account)
+        object: String,
+        /// Record id
+        id: String,
+        /// Comma-separated field API names
+        #[arg(long, short)]
+        fields: Option<String>,
+    },
+
+    /// Describe an object's fields and relationships
+    #[command(name = "describe")]
+    Describe {
+        /// Object API name
+        object: String,
+        /// Include picklist values
+        #[arg(long)]
+        picklists: bool,
+    },
+
+    /// List queryable objects
+    #[command(name = "objects", alias = "list-objects")]
+    Objects {
+        /// Substring of name or label
+        #[arg(long, short)]
+        filter: Option<String>,
+        /// Only custom objects
+        #[arg(long)]
+        custom: bool,
+    },
+}
+
+/// HubSpot tools
+#[derive(Subcommand, Clone)]
+pub enum HubspotTools {
+    /// Search contacts, companies, deals or tickets
+    #[command(name = "search", alias = "search-records")]
+    Search {
+        /// Object type (contacts, companies, deals, tickets)
+        object: String,
+        /// Free-text query
+        #[arg(long, short)]
+        query: Option<String>,
+        /// Equality filter as property=value (repeatable)
+        #[arg(long = "filter", short)]
+        filters: Vec<String>,
+        /// Comma-separated properties to return
+        #[arg(long, short)]
+        properties: Option<String>,
+        /// Sort property (prefix with '-' for descending)
+        #[arg(long, short, allow_hyphen_values = true)]
+        sort: Option<String>,
+        /// Maximum results
+        #[arg(long, short)]
+        limit: Option<u32>,
+        /// Pagination cursor
+        #[arg(long)]
+        after: Option<String>,
+    },
+
+    /// Get a record
+    #[command(name = "get", alias = "record")]
+    Get {
+        /// Object type
+        object: String,
+        /// Record id
+        id: String,
+        /// Comma-separated properties to return
+        #[arg(long, short)]
+        properties: Option<String>,
+        /// Comma-separated associated object types
+        #[arg(long, short)]
+        associations: Option<String>,
+    },
+
+    /// List notes, emails, calls and meetings for a record
+    #[command(name = "engagements", alias = "activity")]
+    Engagements {
+        /// Object type
+        object: String,
+        /// Record id
+        id: String,
+        /// Comma-separated engagement types (notes, emails, calls, meetings)
+        #[arg(long, short)]
+        types: Option<String>,
+        /// Maximum engagements
+        #[arg(long, short)]
+        limit: Option<u32>,
+    },
+}
+
+/// Airtable tools
+#[derive(Subcommand, Clone)]
+pub enum AirtableTools {
+    /// List accessible bases
+    #[command(name = "bases", alias = "list-bases")]
+    Bases,
+
+    /// List tables, views and fields in a base
+    #[command(name = "tables", alias = "list-tables")]
+    Tables {
+        /// Base id or Airtable URL
+        base: String,
+        /// Omit field schemas
+        #[arg(long)]
+        no_fields: bool,
+    },
+
+    /// Query records in a table
+    #[command(name = "records", alias = "list-records")]
+    Records {
+        /// Base id or Airtable URL
+        base: String,
+        /// Table name or id (optional if base is a table URL)
+        table: Option<String>,
+        /// Filter formula, e.g. "{Status}='Open'"
+        #[arg(long, short)]
+        filter: Option<String>,
+        /// View name or id
+        #[arg(long, short)]
+        view: Option<String>,
+        /// Comma-separated fields to return
+        #[arg(long)]
+        fields: Option<String>,
+        /// Comma-separated sort fields ('-' prefix for descending)
+        #[arg(long, short, allow_hyphen_values = true)]
+        sort: Option<String>,
+        /// Maximum records
+        #[arg(long, short)]
+        limit: Option<u32>,
+        /// Pagination offset
+        #[arg(long)]
+        offset: Option<String>,
+    },
+
+    /// Get a single record
+    #[command(name = "record", alias = "get")]
+    Record {
+        /// Base id or record URL
+        base: String,
+        /// Table name or id
+        table: Option<String>,
+        /// Record id
+        record_id: Option<String>,
+    },
+}
+
+/// Box tools
+#[derive(Subcommand, Clone)]
+pub enum BoxTools {
+    /// List items in a folder
+    #[command(name = "list-files", alias = "list")]
+    ListFiles {
+        /// Folder ID (default: root "0")
+        folder_id: Option<String>,
+        /// Maximum items
+        #[arg(long, short)]
+        limit: Option<u32>,
+        /// Offset for pagination
+        #[arg(long)]
+        offset: Option<u32>,
+    },
+
+    /// Search names, descriptions and file contents
+    #[command(name = "search")]
+    Search {
+        /// Search query
+        query: String,
+        /// Item type (file, folder, web_link)
+        #[arg(long = "type", short)]
+        item_type: Option<String>,
+        /// Comma-separated file extensions
+        #[arg(long = "ext")]
+        file_extensions: Option<String>,
+        /// Only search inside this folder
+        #[arg(long, short)]
+        folder: Option<String>,
+        /// Comma-separated content types (name, description, file_content, comments, tag)
+        #[arg(long)]
+        content_types: Option<String>,
+        /// Maximum results
+        #[arg(long, short)]
+        limit: Option<u32>,
+        /// Offset for pagination
+        #[arg(long)]
+        offset: Option<u32>,
+    },
+
+    /// Get file metadata by ID
+    #[command(name = "get-file", alias = "get")]
+    GetFile {
+        /// File ID
+        file_id: String,
+        /// Response format (concise or detailed)
+        #[arg(long, default_value = "concise")]
+        response_format: String,
+    },
+
+    /// Download file content by ID
+    #[command(name = "download-file", alias = "download")]
+    DownloadFile {
+        /// File ID
+        file_id: String,
+        /// Maximum bytes to download
+        #[arg(long)]
+        max_bytes: Option<u64>,
+    },
+
+    /// Extract readable text from a file
+    #[command(name = "extract-text", alias = "text")]
+    ExtractText {
+        /// File ID
+        file_id: String,
+        /// Maximum characters to return
+        #[arg(long)]
+        max_chars: Option<usize>,
+    },
+}
+
+/// S3 tools
+#[derive(Subcommand, Clone)]
+pub enum S3Tools {
+    /// List buckets
+    #[command(name = "buckets", alias = "list-buckets")]
+    Buckets,
+
+    /// List objects under a prefix
+    #[command(name = "ls", alias = "list-objects")]
+    Ls {
+        /// Bucket name or s3://bucket/prefix
+        bucket: String,
+        /// Key prefix
+        prefix: Option<String>,
+        /// List all keys under the prefix, not just one level
+        #[arg(long, short)]
+        recursive: bool,
+        /// Maximum entries
+        #[arg(long, short)]
+        limit: Option<u32>,
+        /// Continuation token from a previous listing
+        #[arg(long)]
+        token: Option<String>,
+    },
+
+    /// Show object metadata
+    #[command(name = "head", alias = "metadata")]
+    Head {
+        /// Bucket name or s3://bucket/key
+        bucket: String,
+        /// Object key
+        key: Option<String>,
+    },
+
+    /// Read object content
+    #[command(name = "get", alias = "cat")]
+    Get {
+        /// Bucket name or s3://bucket/key
+        bucket: String,
+        /// Object key
+        key: Option<String>,
+        /// Maximum bytes to read
+        #[arg(long)]
+        max_bytes: Option<u64>,
+        /// Byte offset to start from
+        #[arg(long)]
+        offset: Option<u64>,
+        /// Return base64 instead of text
+        #[arg(long)]
+        base64: bool,
+    },
+}
+
+/// GraphQL tools
+#[derive(Subcommand, Clone)]
+pub enum GraphqlTools {
+    /// Save a config value (endpoint, api_key, allow_adhoc)
+    #[command(name = "set")]
+    Set {
+        /// Config key
+        key: String,
+        /// Value
+        value: String,
+    },
+
+    /// Save a request header sent with every call
+    #[command(name = "header")]
+    Header {
+        /// Header name
+        name: String,
+        /// Header value
+        value: String,
+    },
+
+    /// Allowlist a named query (document, @file, or sha256:<hash> for a persisted query)
+    #[command(name = "add")]
+    Add {
+        /// Query name
+        name: String,
+        /// GraphQL document, @path to read it from a file, or sha256:<hash>
+        document: String,
+    },
+
+    /// Remove an allowlisted query
+    #[command(name = "remove", alias = "rm")]
+    Remove {
+        /// Query name
+        name: String,
+    },
+
+    /// Introspect the schema (optionally one type)
+    #[command(name = "schema", alias = "introspect")]
+    Schema {
+        /// Type to describe
+        type_name: Option<String>,
+    },
+
+    /// List allowlisted queries and their variables
+    #[command(name = "queries", alias = "list")]
+    Queries,
+
+    /// Run an allowlisted query (or an ad-hoc one with --query)
+    #[command(name = "run")]
+    Run {
+        /// Allowlisted query name
+        name: Option<String>,
+        /// Ad-hoc query document (requires allow_adhoc)
+        #[arg(long, short)]
+        query: Option<String>,
+        /// Variable as name=value (repeatable; JSON values are parsed)
+        #[arg(long = "var", short = 'v')]
+        vars: Vec<String>,
+        /// Variables as a JSON object
+        #[arg(long)]
+        variables: Option<String>,
+        /// Operation to run when the document defines several
+        #[arg(long)]
+        operation: Option<String>,
+    },
+}
+
+/// Sentry tools
+#[derive(Subcommand, Clone)]
+pub enum SentryTools {
+    /// List projects in the organization
+    #[command(name = "projects", alias = "list-projects")]
+    Projects,
+
+    /// Search issues
+    #[command(name = "issues", alias = "search")]
+    Issues {
+        /// Sentry search query (default: is:unresolved)
+        query: Option<String>,
+        /// Project slug
+        #[arg(long, short)]
+        project: Option<String>,
+        /// Environment, e.g. production
+        #[arg(long, short)]
+        environment: Option<String>,
+        /// Time window, e.g. 24h or 14d
+        #[arg(long)]
+        period: Option<String>,
+        /// Sort: date, new, freq, user, trends
+        #[arg(long, short)]
+        sort: Option<String>,
+        /// Maximum number of issues
+        #[arg(long, short, default_value_t = 25)]
+        limit: u32,
+        /// Pagination cursor from a previous call
+        #[arg(long)]
+        cursor: Option<String>,
+    },
+
+    /// Issue detail with the latest event's stack trace
+    #[command(name = "issue", alias = "get-issue")]
+    Issue {
+        /// Issue id, short id (WEB-1A2) or issue URL
+        issue: String,
+        /// Event: latest, oldest, recommended or an event id
+        #[arg(long)]
+        event: Option<String>,
+        /// Only show in-app frames
+        #[arg(long)]
+        in_app: bool,
+    },
+
+    /// Recent releases with crash-free rates and adoption
+    #[command(name = "releases", alias = "list-releases")]
+    Releases {
+        /// Project slug
+        #[arg(long, short)]
+        project: Option<String>,
+        /// Filter by version substring
+        #[arg(long, short)]
+        query: Option<String>,
+        /// Health window, e.g. 24h or 7d
+        #[arg(long)]
+        period: Option<String>,
+        /// Maximum number of releases
+        #[arg(long, short, default_value_t = 10)]
+        limit: u32,
+    },
+
+    /// One release's health and deploys
+    #[command(name = "release", alias = "get-release")]
+    Release {
+        /// Release version
+        version: String,
+        /// Project slug
+        #[arg(long, short)]
+        project: Option<String>,
+        /// Health window, e.g. 24h or 7d
+        #[arg(long)]
+        period: Option<String>,
+    },
+}
+
+/// PagerDuty tools
+#[derive(Subcommand, Clone)]
+pub enum PagerdutyTools {
+    /// List or search incidents (default: open)
+    #[command(name = "incidents", alias = "list-incidents")]
+    Incidents {
+        /// Text to match in the title, description or service
+        query: Option<String>,
+        /// Status: triggered, acknowledged, resolved (repeatable)
+        #[arg(long = "status", short)]
+        statuses: Vec<String>,
+        /// Urgency: high or low
+        #[arg(long, short)]
+        urgency: Option<String>,
+        /// Service name or id
+        #[arg(long)]
+        service: Option<String>,
+        /// Team name or id
+        #[arg(long)]
+        team: Option<String>,
+        /// Start time (ISO 8601)
+        #[arg(long)]
+        since: Option<String>,
+        /// End time (ISO 8601)
+        #[arg(long)]
+        until: Option<String>,
+        /// Maximum number of incidents
+        #[arg(long, short, default_value_t = 25)]
+        limit: u32,
+    },
+
+    /// Incident detail with notes and timeline
+    #[command(name = "incident", alias = "get-incident")]
+    Incident {
+        /// Incident id, number or URL
+        incident: String,
+        /// Skip the log-entry timeline
+        #[arg(long)]
+        no_timeline: bool,
+    },
+
+    /// Who is on call now
+    #[command(name = "oncall", alias = "who")]
+    Oncall {
+        /// Schedule name or id
+        #[arg(long, short)]
+        schedule: Option<String>,
+        /// Escalation policy name or id
+        #[arg(long, short)]
+        escalation_policy: Option<String>,
+        /// Start time (ISO 8601)
+        #[arg(long)]
+        since: Option<String>,
+        /// End time (ISO 8601)
+        #[arg(long)]
+        until: Option<String>,
+    },
+
+    /// List on-call schedules
+    #[command(name = "schedules", alias = "list-schedules")]
+    Schedules {
+        /// Filter by name
+        query: Option<String>,
+    },
+
+    /// A schedule's shifts (default: next 7 days)
+    #[command(name = "schedule", alias = "get-schedule")]
+    Schedule {
+        /// Schedule name or id
+        schedule: String,
+        /// Start time (ISO 8601)
+        #[arg(long)]
+        since: Option<String>,
+        /// End time (ISO 8601)
+        #[arg(long)]
+        until: Option<String>,
+    },
+}
+
+/// OpenAPI tools
+#[derive(Subcommand, Clone)]
+pub enum OpenapiTools {
+    /// Save a config value (spec, base_url, operations, allow_writes, api_key, auth_header, auth_query)
+    #[command(name = "set")]
+    Set {
+        /// Config key
+        key: String,
+        /// Value
+        value: String,
+    },
+
+    /// List operations in the spec and which are exposed as tools
+    #[command(name = "operations", alias = "ops")]
+    Operations {
+        /// Only operations exposed as tools
+        #[arg(long)]
+        enabled: bool,
+    },
+
+    /// Call a generated tool
+    #[command(name = "call")]
+    Call {
+        /// Tool name from `operations`
+        operation: String,
+        /// Parameter as name=value (repeatable; JSON values are parsed)
+        #[arg(long = "param", short = 'p')]
+        params: Vec<String>,
+        /// JSON request body
+        #[arg(long)]
+        body: Option<String>,
+    },
+}
+
+/// SQL tools
+#[derive(Subcommand, Clone)]
+pub enum SqlTools {
+    /// List registered connections
+    #[command(name = "connections", alias = "list")]
+    Connections,
+
+    /// Register a named connection
+    #[command(name = "add")]
+    Add {
+        /// Connection name
+        name: String,
+        /// postgres://, mysql:// or sqlite: URL
+        url: String,
+    },
+
+    /// Remove a named connection
+    #[command(name = "remove", alias = "rm")]
+    Remove {
+        /// Connection name
+        name: String,
+    },
+
+    /// List tables and views
+    #[command(name = "tables")]
+    Tables {
+        /// Connection name
+        connection: String,
+        /// Schema to list
+        #[arg(long, short)]
+        schema: Option<String>,
+    },
+
+    /// Show the columns of a table
+    #[command(name = "describe", alias = "desc")]
+    Describe {
+        /// Connection name
+        connection: String,
+        /// Table name, optionally schema-qualified
+        table: String,
+    },
+
+    /// Run a read-only query
+    #[command(name = "query", alias = "q")]
+    Query {
+        /// Connection name
+        connection: String,
+        /// SQL statement
+        sql: String,
+        /// Positional parameter (repeatable; JSON literals like 42 or null are typed)
+        #[arg(long = "param", short = 'p')]
+        params: Vec<String>,
+        /// Maximum rows
+        #[arg(long, short = 'n')]
+        max_rows: Option<u32>,
+    },
+}
+
+/// Confluence tools
+#[derive(Subcommand, Clone)]
+pub enum ConfluenceTools {
+    /// Search pages by text or CQL
+    #[command(name = "search")]
+    Search {
+        /// Free-text query
+        query: Option<String>,
+        /// CQL query (overrides the free-text query)
+        #[arg(long)]
+        cql: Option<String>,
+        /// Restrict a free-text search to a space key
+        #[arg(long, short)]
+        space: Option<String>,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 25)]
+        limit: u32,
+        /// Offset for the next page of results
+        #[arg(long, default_value_t = 0)]
+        start: u32,
+    },
+
+    /// Get page content as markdown
+    #[command(name = "page", alias = "get")]
+    Page {
+        /// Page id or URL
+        page: String,
+        /// Output format: markdown, html or storage
+        #[arg(long, short, default_value = "markdown")]
+        format: String,
+    },
+
+    /// List spaces
+    #[command(name = "spaces")]
+    Spaces {
+        /// Space type: global or personal
+        #[arg(long = "type", short = 't')]
+        space_type: Option<String>,
+        /// Maximum spaces
+        #[arg(long, short, default_value_t = 25)]
+        limit: u32,
+        /// Offset for the next page of results
+        #[arg(long, default_value_t = 0)]
+        start: u32,
+    },
+
+    /// List child pages
+    #[command(name = "children")]
+    Children {
+        /// Page id or URL
+        page: String,
+        /// Maximum children
+        #[arg(long, short, default_value_t = 25)]
+        limit: u32,
+        /// Offset for the next page of results
+        #[arg(long, default_value_t = 0)]
+        start: u32,
+    },
+
+    /// List ancestors from the space root to the parent
+    #[command(name = "ancestors", alias = "breadcrumbs")]
+    Ancestors {
+        /// Page id or URL
+        page: String,
+    },
+}
+
+/// Zoom tools
+#[derive(Subcommand, Clone)]
+pub enum ZoomTools {
+    /// List cloud recordings in a date range
+    #[command(name = "recordings", alias = "list")]
+    Recordings {
+        /// Start date (YYYY-MM-DD)
+        #[arg(long)]
+        from: Option<String>,
+        /// End date (YYYY-MM-DD)
+        #[arg(long)]
+        to: Option<String>,
+        /// Zoom user id or email
+        #[arg(long, short)]
+        user: Option<String>,
+        /// Maximum meetings
+        #[arg(long, short, default_value_t = 30)]
+        limit: u32,
+    },
+
+    /// Search recordings by topic
+    #[command(name = "search")]
+    Search {
+        /// Words to match in the meeting topic
+        query: String,
+        /// Start date (YYYY-MM-DD)
+        #[arg(long)]
+        from: Option<String>,
+        /// End date (YYYY-MM-DD)
+        #[arg(long)]
+        to: Option<String>,
+        /// Zoom user id or email
+        #[arg(long, short)]
+        user: Option<String>,
+        /// Maximum meetings
+        #[arg(long, short, default_value_t = 30)]
+        limit: u32,
+    },
+
+    /// Download a meeting transcript
+    #[command(name = "transcript")]
+    Transcript {
+        /// Meeting uuid or numeric meeting id
+        meeting_id: String,
+        /// Output format: text, segments or vtt
+        #[arg(long, short, default_value = "text")]
+        format: String,
+    },
+}
+
+/// crates.io tools
+#[derive(Subcommand, Clone)]
+pub enum CratesIoTools {
+    /// Search crates
+    #[command(name = "search")]
+    Search {
+        /// Search terms
+        query: String,
+        /// Sort: relevance, downloads, recent-downloads, recent-updates, new
+        #[arg(long, short, default_value = "relevance")]
+        sort: String,
+        /// Results per page
+        #[arg(long, short, default_value_t = 10)]
+        limit: u32,
+        /// Page number
+        #[arg(long, short, default_value_t = 1)]
+        page: u32,
+    },
+
+    /// Crate metadata, features and dependencies
+    #[command(name = "info", alias = "get")]
+    Info {
+        /// Crate name
+        name: String,
+        /// Version (default: latest stable)
+        #[arg(long, short)]
+        version: Option<String>,
+    },
+
+    /// Crates that depend on a crate
+    #[command(name = "rdeps", alias = "reverse-deps")]
+    Rdeps {
+        /// Crate name
+        name: String,
+        /// Results per page
+        #[arg(long, short, default_value_t = 20)]
+        limit: u32,
+        /// Page number
+        #[arg(long, short, default_value_t = 1)]
+        page: u32,
+    },
+
+    /// README or docs.rs crate docs as markdown
+    #[command(name = "readme")]
+    Readme {
+        /// Crate name
+        name: String,
+        /// Version (default: latest stable)
+        #[arg(long, short)]
+        version: Option<String>,
+        /// Source: crates-io or docs-rs
+        #[arg(long, short, default_value = "crates-io")]
+        source: String,
+    },
+}
+
+/// npm tools
+#[derive(Subcommand, Clone)]
+pub enum NpmTools {
+    /// Search packages
+    #[command(name = "search")]
+    Search {
+        /// Search text (supports keywords:, author:, scope:)
+        query: String,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 10)]
+        limit: u32,
+        /// Result offset
+        #[arg(long, default_value_t = 0)]
+        offset: u32,
+    },
+
+    /// Package metadata, versions and dependencies
+    #[command(name = "info", alias = "get")]
+    Info {
+        /// Package name
+        name: String,
+        /// Exact version or dist-tag (default: latest)
+        #[arg(long, short)]
+        version: Option<String>,
+    },
+
+    /// Package README
+    #[command(name = "readme")]
+    Readme {
+        /// Package name
+        name: String,
+        /// Exact version or dist-tag (default: latest)
+        #[arg(long, short)]
+        version: Option<String>,
+    },
+}
+
+/// PyPI tools
+#[derive(Subcommand, Clone)]
+pub enum PypiTools {
+    /// Search projects
+    #[command(name = "search")]
+    Search {
+        /// Search terms or a project name
+        query: String,
+        /// Results page
+        #[arg(long, short, default_value_t = 1)]
+        page: u32,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 20)]
+        limit: u32,
+    },
+
+    /// Release metadata, files and versions
+    #[command(name = "info", alias = "get")]
+    Info {
+        /// Project name
+        name: String,
+        /// Release version (default: latest)
+        #[arg(long, short)]
+        version: Option<String>,
+    },
+
+    /// Project long description (README)
+    #[command(name = "description", alias = "readme")]
+    Description {
+        /// Project name
+        name: String,
+        /// Release version (default: latest)
+        #[arg(long, short)]
+        version: Option<String>,
+    },
+}
+
+/// Docker Hub tools
+#[derive(Subcommand, Clone)]
+pub enum DockerhubTools {
+    /// Search images
+    #[command(name = "search")]
+    Search {
+        /// Search terms
+        query: String,
+        /// Results page
+        #[arg(long, short, default_value_t = 1)]
+        page: u32,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 10)]
+        limit: u32,
+    },
+
+    /// List tags with digests, sizes and platforms
+    #[command(name = "tags")]
+    Tags {
+        /// Image, e.g. nginx or bitnami/redis
+        image: String,
+        /// Only tags containing this text
+        #[arg(long, short)]
+        filter: Option<String>,
+        /// Results page
+        #[arg(long, short, default_value_t = 1)]
+        page: u32,
+        /// Maximum tags
+        #[arg(long, short, default_value_t = 25)]
+        limit: u32,
+    },
+
+    /// Repository stats and full description
+    #[command(name = "info", alias = "readme")]
+    Info {
+        /// Image, e.g. nginx or bitnami/redis
+        image: String,
+    },
+
+    /// Dockerfile reconstructed from image history
+    #[command(name = "dockerfile", alias = "history")]
+    Dockerfile {
+        /// Image with optional tag or digest, e.g. python:3.12-slim
+        image: String,
+        /// os/arch[/variant] (default: linux/amd64)
+        #[arg(long, short)]
+        platform: Option<String>,
+    },
+}
+
+/// Sci-Hub tools
+#[derive(Subcommand, Clone)]
+pub enum ScihubTools {
+    /// Get paper by DOI
+    #[command(name = "paper", alias = "get-paper")]
+    Paper {
+        /// DOI
+        #[arg(long, short)]
+        doi: String,
+        /// Download the PDF and include its text
+        #[arg(long)]
+        text: bool,
+        /// With --text, also split the text into sections with page numbers
+        #[arg(long, requires = "text")]
+        sections: bool,
+        /// Maximum characters of extracted text
+        #[arg(long)]
+        max_chars: Option<u32>,
+        /// Use only this mirror
+        #[arg(long, short)]
+        mirror: Option<String>,
+    },
+
+    /// Check which mirrors respond
+    #[command(name = "mirrors", alias = "check-mirrors")]
+    Mirrors {
+        /// Look up currently listed mirror domains first
+        #[arg(long)]
+        discover: bool,
+    },
+}
+
+/// macOS tools
+#[derive(Subcommand, Clone)]
+pub enum MacosTools {
+    /// Run AppleScript or JXA
+    #[command(name = "script", alias = "run-script")]
+    Script {
+        /// Script language (applescript, javascript, jxa)
+        #[arg(long, short, default_value = "applescript")]
+        language: String,
+        /// Script source code
+        #[arg(long, short)]
+        script: String,
+        /// Optional parameters (JSON)
+        #[arg(long)]
+        params: Option<String>,
+        /// Max output characters
+        #[arg(long)]
+        max_output_chars: Option<u32>,
+    },
+
+    /// Show notification
+    #[command(name = "notify", alias = "notification")]
+    Notify {
+        /// Title
+        #[arg(long, short)]
+        title: Option<String>,
+        /// Message
+        #[arg(long, short)]
+        message: String,
+        /// Subtitle
+        #[arg(long)]
+        subtitle: Option<String>,
+    },
+
+    /// Reveal file in Finder
+    #[command(name = "reveal")]
+    Reveal {
+        /// File path
+        #[arg(long, short)]
+        path: String,
+    },
+
+    /// Get clipboard content
+    #[command(name = "clipboard", alias = "get-clipboard")]
+    GetClipboard,
+
+    /// Set clipboard content
+    #[command(name = "set-clipboard")]
+    SetClipboard {
+        /// Text to copy
+        #[arg(long, short)]
+        text: String,
+    },
+
+    /// Run Apple Shortcut
+    #[command(name = "shortcut", alias = "run-shortcut")]
+    Shortcut {
+        /// Shortcut name
+        #[arg(long, short)]
+        name: String,
+        /// Optional input (JSON, or plain text)
+        #[arg(long, short)]
+        input: Option<String>,
+        /// File to pass as input instead
+        #[arg(long)]
+        input_path: Option<String>,
+        /// UTI to coerce the result to (e.g. public.plain-text)
+        #[arg(long)]
+        output_type: Option<String>,
+        /// Kill the shortcut after this many seconds
+        #[arg(long)]
+        timeout: Option<u64>,
+    },
+
+    /// List Apple Shortcuts
+    #[command(name = "shortcuts", alias = "list-shortcuts")]
+    Shortcuts {
+        /// Only shortcuts in this folder
+        #[arg(long, short)]
+        folder: Option<String>,
+    },
+
+    /// List Apple Notes, most recently modified first
+    #[command(name = "notes", alias = "list-notes")]
+    Notes {
+        /// Folder name
+        #[arg(long, short)]
+        folder: Option<String>,
+        /// Account name
+        #[arg(long, short)]
+        account: Option<String>,
+        /// Title/text filter
+        #[arg(long, short)]
+        query: Option<String>,
+        /// Max notes
+        #[arg(long, short)]
+        limit: Option<u32>,
+    },
+
+    /// Read an Apple Note
+    #[command(name = "note", alias = "get-note")]
+    Note {
+        /// Note id from `macos notes`
+        id: String,
+        /// Max characters of text
+        #[arg(long)]
+        max_chars: Option<u32>,
+    },
+
+    /// Create an Apple Note
+    #[command(name = "new-note", alias = "create-note")]
+    NewNote {
+        /// Note title
+        #[arg(long, short)]
+        title: String,
+        /// Plain-text body
+        #[arg(long, short)]
+        body: Option<String>,
+        /// Folder name
+        #[arg(long, short)]
+        folder: Option<String>,
+        /// Account name
+        #[arg(long, short)]
+        account: Option<String>,
+    },
+}
+
+/// Browser history tools
+#[derive(Subcommand, Clone)]
+pub enum BrowserHistoryTools {
+    /// List detected browsers and profiles
+    #[command(name = "browsers", alias = "list-browsers")]
+    Browsers,
+
+    /// Search visited pages by keyword and/or date range
+    #[command(name = "search", alias = "search-history")]
+    Search {
+        /// Keyword matched against URL and title (omit for recent history)
+        query: Option<String>,
+        /// Browser: chrome, brave, firefox, safari (default: all)
+        #[arg(long, short)]
+        browser: Option<String>,
+        /// Profile name from `browsers`
+        #[arg(long, short)]
+        profile: Option<String>,
+        /// Visits on/after this date (YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+        /// Visits up to this date (YYYY-MM-DD, inclusive)
+        #[arg(long)]
+        until: Option<String>,
+        /// last_24_hours, last_7_days, last_30_days, this_month, last_365_days
+        #[arg(long)]
+        date_preset: Option<String>,
+        /// Only this domain (repeatable)
+        #[arg(long = "domain")]
+        domains: Vec<String>,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 25)]
+        limit: u32,
+    },
+
+    /// Most visited pages in a date range (default: last 30 days)
+    #[command(name = "top", alias = "top-sites")]
+    Top {
+        /// Browser: chrome, brave, firefox, safari (default: all)
+        #[arg(long, short)]
+        browser: Option<String>,
+        /// Profile name from `browsers`
+        #[arg(long, short)]
+        profile: Option<String>,
+        /// Visits on/after this date (YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+        /// Visits up to this date (YYYY-MM-DD, inclusive)
+        #[arg(long)]
+        until: Option<String>,
+        /// last_24_hours, last_7_days, last_30_days, this_month, last_365_days
+        #[arg(long)]
+        date_preset: Option<String>,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 20)]
+        limit: u32,
+    },
+}
+
+/// Linux desktop search tools
+#[derive(Subcommand, Clone)]
+pub enum TrackerTools {
+    /// Full-text content search
+    #[command(name = "search", alias = "search-content")]
+    SearchContent {
+        /// Search query
+        #[arg(long, short)]
+        query: String,
+        /// Directory to search in
+        #[arg(long, short)]
+        directory: Option<String>,
+        /// File kind filter
+        #[arg(long, short)]
+        kind: Option<String>,
+        /// MIME type filter (repeatable, e.g. image/*)
+        #[arg(long)]
+        mime: Vec<String>,
+        /// Index backend: auto, tracker, recoll
+        #[arg(long)]
+        backend: Option<String>,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 50)]
+        limit: u32,
+    },
+
+    /// Search by file name
+    #[command(name = "name", alias = "search-by-name")]
+    SearchByName {
+        /// File name
+        #[arg(long, short)]
+        name: String,
+        /// Directory to search in
+        #[arg(long, short)]
+        directory: Option<String>,
+        /// Index backend: auto, tracker, recoll
+        #[arg(long)]
+        backend: Option<String>,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 50)]
+        limit: u32,
+    },
+
+    /// Search by file kind or MIME type
+    #[command(name = "kind", alias = "search-by-kind")]
+    SearchByKind {
+        /// File kind (pdf, image, video, etc.) or MIME type
+        #[arg(long, short)]
+        kind: String,
+        /// Directory to search in
+        #[arg(long, short)]
+        directory: Option<String>,
+        /// Index backend: auto, tracker, recoll
+        #[arg(long)]
+        backend: Option<String>,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 50)]
+        limit: u32,
+    },
+
+    /// Search recent files
+    #[command(name = "recent", alias = "search-recent")]
+    SearchRecent {
+        /// Number of days
+        #[arg(long, short, default_value_t = 7)]
+        days: u32,
+        /// File kind filter
+        #[arg(long, short)]
+        kind: Option<String>,
+        /// Directory to search in
+        #[arg(long)]
+        directory: Option<String>,
+        /// Index backend: auto, tracker, recoll
+        #[arg(long)]
+        backend: Option<String>,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 50)]
+        limit: u32,
+    },
+
+    /// Get file metadata
+    #[command(name = "metadata", alias = "get-metadata")]
+    Metadata {
+        /// File path
+        #[arg(long, short)]
+        path: String,
+    },
+
+    /// Raw index query (SPARQL selecting ?url, or Recoll query language)
+    #[command(name = "raw", alias = "raw-query")]
+    RawQuery {
+        /// Raw query
+        #[arg(long, short)]
+        query: String,
+        /// Index backend: auto, tracker, recoll
+        #[arg(long)]
+        backend: Option<String>,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 50)]
+        limit: u32,
+    },
+}
+
+/// Spotlight tools
+#[derive(Subcommand, Clone)]
+pub enum SpotlightTools {
+    /// Full-text content search
+    #[command(name = "search", alias = "search-content")]
+    SearchContent {
+        /// Search query
+        #[arg(long, short)]
+        query: String,
+        /// Directory to search in
+        #[arg(long, short)]
+        directory: Option<String>,
+        /// File kind filter
+        #[arg(long, short)]
+        kind: Option<String>,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 50)]
+        limit: u32,
+    },
+
+    /// Search by file name
+    #[command(name = "name", alias = "search-by-name")]
+    SearchByName {
+        /// File name
+        #[arg(long, short)]
+        name: String,
+        /// Directory to search in
+        #[arg(long, short)]
+        directory: Option<String>,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 50)]
+        limit: u32,
+    },
+
+    /// Search by file kind
+    #[command(name = "kind", alias = "search-by-kind")]
+    SearchByKind {
+        /// File kind (pdf, image, video, etc.)
+        #[arg(long, short)]
+        kind: String,
+        /// Directory to search in
+        #[arg(long, short)]
+        directory: Option<String>,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 50)]
+        limit: u32,
+    },
+
+    /// Search recent files
     #[command(name = "recent", alias = "search-recent")]
     SearchRecent {
         /// Number of days
@@ -2618,12 +6499,50 @@ pub enum SpotlightTools {
         limit: u32,
     },
 
+    /// Search by metadata filters (kind, author, tags, date ranges)
+    #[command(name = "filter")]
+    Filter {
+        /// Content text to match
+        #[arg(long, short)]
+        query: Option<String>,
+        /// File kind (pdf, image, ...) or UTI
+        #[arg(long, short)]
+        kind: Option<String>,
+        /// Author name (partial match)
+        #[arg(long, short)]
+        author: Option<String>,
+        /// Finder tag the file must carry (repeatable or comma-separated)
+        #[arg(long = "tag", short, value_delimiter = ',')]
+        tags: Vec<String>,
+        /// Created at or after (YYYY-MM-DD, RFC 3339, or relative like 7d)
+        #[arg(long)]
+        created_since: Option<String>,
+        /// Created at or before
+        #[arg(long)]
+        created_until: Option<String>,
+        /// Modified at or after (YYYY-MM-DD, RFC 3339, or relative like 7d)
+        #[arg(long)]
+        modified_since: Option<String>,
+        /// Modified at or before
+        #[arg(long)]
+        modified_until: Option<String>,
+        /// Directory to search in
+        #[arg(long, short)]
+        directory: Option<String>,
+        /// Maximum results
+        #[arg(long, short, default_value_t = 50)]
+        limit: u32,
+    },
+
     /// Get file metadata
     #[command(name = "metadata", alias = "get-metadata")]
     Metadata {
         /// File path
         #[arg(long, short)]
         path: String,
+        /// Only these attributes (repeatable or comma-separated)
+        #[arg(long = "attr", value_delimiter = ',')]
+        attributes: Vec<String>,
     },
 
     /// Raw Spotlight query
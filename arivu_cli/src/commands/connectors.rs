@@ -213,6 +213,8 @@ fn print_connector_categories(connectors: &[Value]) -> Result<()> {
                 "serper-search",
                 "tavily-search",
                 "serpapi-search",
+                "brave-search",
+                "ddg-search",
             ],
         ),
         (
@@ -272,13 +274,19 @@ fn print_connector_categories(connectors: &[Value]) -> Result<()> {
 // ============================================================================
 
 use crate::cli::{
-    AnthropicSearchTools, ArxivTools, AtlassianTools, BiorxivTools, DiscordTools, ExaTools,
-    FirecrawlSearchTools, GeminiSearchTools, GithubTools, GoogleCalendarTools, GoogleDriveTools,
-    GoogleGmailTools, GooglePeopleTools, GoogleScholarTools, HackernewsTools, ImapTools,
-    LocalfsTools, MacosTools, MicrosoftGraphTools, OpenaiSearchTools, ParallelSearchTools,
-    PerplexitySearchTools, PubmedTools, RedditTools, RssTools, ScihubTools, SemanticScholarTools,
-    SerpapiSearchTools, SerperSearchTools, SlackTools, SpotlightTools, TavilySearchTools, WebTools,
-    WikipediaTools, XTools, XaiSearchTools, YoutubeArgs, YoutubeTools,
+    AirtableTools, AnthropicSearchTools, ArxivTools, AtlassianTools, BiorxivTools, BoxTools,
+    BraveSearchTools, BrowserHistoryTools, CalendarEventArgs, CoingeckoTools, ConfluenceTools,
+    CratesIoTools, CrossrefTools, DblpTools, DdgSearchTools, DiscordTools, DockerhubTools,
+    ExaTools, FirecrawlSearchTools, GeminiSearchTools, GithubTools, GoogleCalendarTools,
+    GoogleDriveTools, GoogleGmailTools, GooglePeopleTools, GoogleScholarTools, GraphqlTools,
+    HackernewsTools, HubspotTools, ImapTools, InboxTools, LocalfsTools, MacosTools,
+    MarketDataTools, MicrosoftGraphTools, NewslettersTools, NpmTools, OpenaiSearchTools,
+    OpenapiTools, OpenlibraryTools, PagerdutyTools, PaperLibraryTools, ParallelSearchTools,
+    PerplexitySearchTools, PodcastsTools, PubmedTools, PypiTools, RedditTools, RssTools, S3Tools,
+    SalesforceTools, ScihubTools, SemanticScholarTools, SentryTools, SerpapiSearchTools,
+    SerperSearchTools, SlackTools, SpotlightTools, SqlTools, TavilySearchTools, TrackerTools,
+    TwitchTools, VimeoTools, WebTools, WikipediaTools, XTools, XaiSearchTools, YoutubeArgs,
+    YoutubeTools, ZoomTools,
 };
 use crate::commands::copy_to_clipboard;
 use crate::commands::usage_helpers::print_cost_summary;
@@ -336,7 +344,8 @@ async fn call_tool_raw(
         arguments: Some(args.into_iter().collect()),
     };
 
-    let result = c.call_tool(request).await?;
+    drop(c);
+    let result = arivu_core::call_locked_connector(provider, request).await?;
 
     let meta_value = result
         .meta
@@ -649,6 +658,60 @@ pub async fn handle_parallel_search(cli: &Cli, tool: ParallelSearchTools) -> Res
     call_tool(cli, "parallel-search", tool_name, args).await
 }
 
+/// Handle Brave Search commands
+pub async fn handle_brave_search(cli: &Cli, tool: BraveSearchTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        BraveSearchTools::Search {
+            query,
+            limit,
+            freshness,
+            country,
+            response_format,
+        } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            args.insert("limit".to_string(), json!(limit));
+            if let Some(f) = freshness {
+                args.insert("freshness".to_string(), json!(f));
+            }
+            if let Some(c) = country {
+                args.insert("region".to_string(), json!(c));
+            }
+            args.insert("response_format".to_string(), json!(response_format));
+            ("search", args)
+        }
+    };
+
+    call_tool(cli, "brave-search", tool_name, args).await
+}
+
+/// Handle DuckDuckGo Search commands
+pub async fn handle_ddg_search(cli: &Cli, tool: DdgSearchTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        DdgSearchTools::Search {
+            query,
+            limit,
+            page,
+            date_preset,
+            locale,
+        } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            args.insert("limit".to_string(), json!(limit));
+            args.insert("page".to_string(), json!(page));
+            if let Some(d) = date_preset {
+                args.insert("date_preset".to_string(), json!(d));
+            }
+            if let Some(l) = locale {
+                args.insert("locale".to_string(), json!(l));
+            }
+            ("search", args)
+        }
+    };
+
+    call_tool(cli, "ddg-search", tool_name, args).await
+}
+
 /// Handle Google Calendar commands
 pub async fn handle_google_calendar(cli: &Cli, tool: GoogleCalendarTools) -> Result<()> {
     let (tool_name, args) = match tool {
@@ -678,13 +741,34 @@ pub async fn handle_google_calendar(cli: &Cli, tool: GoogleCalendarTools) -> Res
             summary,
             start,
             end,
+            event,
         } => {
             let mut args = Map::new();
             args.insert("summary".to_string(), json!(summary));
             args.insert("start".to_string(), json!(start));
             args.insert("end".to_string(), json!(end));
+            insert_event_args(&mut args, event);
             ("create_event", args)
         }
+        GoogleCalendarTools::Freebusy {
+            time_min,
+            time_max,
+            calendars,
+            min_minutes,
+            time_zone,
+        } => {
+            let mut args = Map::new();
+            args.insert("time_min".to_string(), json!(time_min));
+            args.insert("time_max".to_string(), json!(time_max));
+            if !calendars.is_empty() {
+                args.insert("calendars".to_string(), json!(calendars));
+            }
+            args.insert("min_minutes".to_string(), json!(min_minutes));
+            if let Some(tz) = time_zone {
+                args.insert("time_zone".to_string(), json!(tz));
+            }
+            ("freebusy", args)
+        }
         GoogleCalendarTools::SyncEvents {
             sync_token,
             max_results,
@@ -699,6 +783,7 @@ pub async fn handle_google_calendar(cli: &Cli, tool: GoogleCalendarTools) -> Res
             summary,
             start,
             end,
+            event,
         } => {
             let mut args = Map::new();
             args.insert("event_id".to_string(), json!(event_id));
@@ -711,13 +796,24 @@ pub async fn handle_google_calendar(cli: &Cli, tool: GoogleCalendarTools) -> Res
             if let Some(e) = end {
                 args.insert("end".to_string(), json!(e));
             }
+            insert_event_args(&mut args, event);
             ("update_event", args)
         }
-        GoogleCalendarTools::DeleteEvent { event_id } => {
+        GoogleCalendarTools::DeleteEvent {
+            event_id,
+            calendar_id,
+            send_updates,
+            yes,
+        } => {
             let mut args = Map::new();
-            if let Some(id) = event_id {
-                args.insert("event_id".to_string(), json!(id));
+            args.insert("event_id".to_string(), json!(event_id));
+            if let Some(c) = calendar_id {
+                args.insert("calendar_id".to_string(), json!(c));
+            }
+            if let Some(s) = send_updates {
+                args.insert("send_updates".to_string(), json!(s));
             }
+            args.insert("confirm".to_string(), json!(yes));
             ("delete_event", args)
         }
     };
@@ -725,6 +821,28 @@ pub async fn handle_google_calendar(cli: &Cli, tool: GoogleCalendarTools) -> Res
     call_tool(cli, "google-calendar", tool_name, args).await
 }
 
+fn insert_event_args(args: &mut Map<String, Value>, event: CalendarEventArgs) {
+    let optional = [
+        ("calendar_id", event.calendar_id),
+        ("description", event.description),
+        ("location", event.location),
+        ("time_zone", event.time_zone),
+        ("send_updates", event.send_updates),
+    ];
+    for (key, value) in optional {
+        if let Some(v) = value {
+            args.insert(key.to_string(), json!(v));
+        }
+    }
+    if !event.attendees.is_empty() {
+        args.insert("attendees".to_string(), json!(event.attendees));
+    }
+    if event.meet {
+        args.insert("conference".to_string(), json!(true));
+    }
+    args.insert("confirm".to_string(), json!(event.yes));
+}
+
 /// Handle Google Drive commands
 pub async fn handle_google_drive(cli: &Cli, tool: GoogleDriveTools) -> Result<()> {
     let (tool_name, args) = match tool {
@@ -782,6 +900,55 @@ pub async fn handle_google_drive(cli: &Cli, tool: GoogleDriveTools) -> Result<()
             args.insert("mime_type".to_string(), json!(mime_type));
             ("export_file", args)
         }
+        GoogleDriveTools::ExportText {
+            file_id,
+            range,
+            sections,
+            max_chars,
+        } => {
+            let mut args = Map::new();
+            args.insert("file_id".to_string(), json!(file_id));
+            if let Some(r) = range {
+                args.insert("range".to_string(), json!(r));
+            }
+            if sections {
+                args.insert("sections".to_string(), json!(true));
+            }
+            if let Some(m) = max_chars {
+                args.insert("max_chars".to_string(), json!(m));
+            }
+            ("export", args)
+        }
+        GoogleDriveTools::ListFolderTree {
+            folder_id,
+            max_depth,
+            max_items,
+        } => {
+            let mut args = Map::new();
+            if let Some(f) = folder_id {
+                args.insert("folder_id".to_string(), json!(f));
+            }
+            args.insert("max_depth".to_string(), json!(max_depth));
+            args.insert("max_items".to_string(), json!(max_items));
+            ("list_folder_tree", args)
+        }
+        GoogleDriveTools::ListChanges {
+            page_token,
+            folder_ids,
+            limit,
+        } => {
+            let mut args = Map::new();
+            if let Some(t) = page_token {
+                args.insert("page_token".to_string(), json!(t));
+            }
+            if !folder_ids.is_empty() {
+                args.insert("folder_ids".to_string(), json!(folder_ids));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("list_changes", args)
+        }
         GoogleDriveTools::UploadFile {
             name,
             mime_type,
@@ -826,6 +993,7 @@ pub async fn handle_google_gmail(cli: &Cli, tool: GoogleGmailTools) -> Result<()
             q,
             max_results,
             page_token,
+            labels,
             response_format,
         } => {
             let mut args = Map::new();
@@ -836,6 +1004,9 @@ pub async fn handle_google_gmail(cli: &Cli, tool: GoogleGmailTools) -> Result<()
             if let Some(t) = page_token {
                 args.insert("page_token".to_string(), json!(t));
             }
+            if !labels.is_empty() {
+                args.insert("label_ids".to_string(), json!(labels));
+            }
             let response_format = if response_format == "full" {
                 "detailed".to_string()
             } else {
@@ -844,6 +1015,39 @@ pub async fn handle_google_gmail(cli: &Cli, tool: GoogleGmailTools) -> Result<()
             args.insert("response_format".to_string(), json!(response_format));
             ("list_messages", args)
         }
+        GoogleGmailTools::Labels { counts } => {
+            let mut args = Map::new();
+            if counts {
+                args.insert("with_counts".to_string(), json!(true));
+            }
+            ("list_labels", args)
+        }
+        GoogleGmailTools::Modify {
+            ids,
+            add,
+            remove,
+            read,
+            unread,
+            archive,
+            yes,
+        } => {
+            let mut args = Map::new();
+            args.insert("ids".to_string(), json!(ids));
+            if !add.is_empty() {
+                args.insert("add_labels".to_string(), json!(add));
+            }
+            if !remove.is_empty() {
+                args.insert("remove_labels".to_string(), json!(remove));
+            }
+            if read || unread {
+                args.insert("mark_read".to_string(), json!(read));
+            }
+            if archive {
+                args.insert("archive".to_string(), json!(true));
+            }
+            args.insert("confirm".to_string(), json!(yes));
+            ("batch_modify", args)
+        }
         GoogleGmailTools::DecodeMessageRaw { raw_base64url } => {
             let mut args = Map::new();
             args.insert("raw_base64url".to_string(), json!(raw_base64url));
@@ -870,6 +1074,95 @@ pub async fn handle_google_gmail(cli: &Cli, tool: GoogleGmailTools) -> Result<()
             args.insert("id".to_string(), json!(id));
             ("get_thread", args)
         }
+        GoogleGmailTools::Attachments {
+            id,
+            select,
+            all,
+            max_chars,
+            sections,
+        } => {
+            let mut args = Map::new();
+            args.insert("id".to_string(), json!(id));
+            if !select.is_empty() {
+                args.insert("select".to_string(), json!(select));
+            }
+            if all {
+                args.insert("all".to_string(), json!(true));
+            }
+            if let Some(m) = max_chars {
+                args.insert("max_chars".to_string(), json!(m));
+            }
+            if sections {
+                args.insert("sections".to_string(), json!(true));
+            }
+            ("get_attachments", args)
+        }
+        GoogleGmailTools::CreateDraft {
+            to,
+            cc,
+            bcc,
+            subject,
+            body,
+            html_body,
+            reply_to,
+            yes,
+        } => {
+            let mut args = Map::new();
+            args.insert("to".to_string(), json!(to.join(",")));
+            if !cc.is_empty() {
+                args.insert("cc".to_string(), json!(cc.join(",")));
+            }
+            if !bcc.is_empty() {
+                args.insert("bcc".to_string(), json!(bcc.join(",")));
+            }
+            if let Some(s) = subject {
+                args.insert("subject".to_string(), json!(s));
+            }
+            if let Some(b) = body {
+                args.insert("body".to_string(), json!(b));
+            }
+            if let Some(h) = html_body {
+                args.insert("html_body".to_string(), json!(h));
+            }
+            if let Some(id) = reply_to {
+                args.insert("reply_to_message_id".to_string(), json!(id));
+            }
+            args.insert("confirm".to_string(), json!(yes));
+            ("create_draft", args)
+        }
+        GoogleGmailTools::SendEmail {
+            to,
+            cc,
+            bcc,
+            subject,
+            body,
+            html_body,
+            reply_to,
+            yes,
+        } => {
+            let mut args = Map::new();
+            args.insert("to".to_string(), json!(to.join(",")));
+            if !cc.is_empty() {
+                args.insert("cc".to_string(), json!(cc.join(",")));
+            }
+            if !bcc.is_empty() {
+                args.insert("bcc".to_string(), json!(bcc.join(",")));
+            }
+            if let Some(s) = subject {
+                args.insert("subject".to_string(), json!(s));
+            }
+            if let Some(b) = body {
+                args.insert("body".to_string(), json!(b));
+            }
+            if let Some(h) = html_body {
+                args.insert("html_body".to_string(), json!(h));
+            }
+            if let Some(id) = reply_to {
+                args.insert("reply_to_message_id".to_string(), json!(id));
+            }
+            args.insert("confirm".to_string(), json!(yes));
+            ("send_email", args)
+        }
     };
 
     call_tool(cli, "google-gmail", tool_name, args).await
@@ -932,6 +1225,38 @@ pub async fn handle_google_scholar(cli: &Cli, tool: GoogleScholarTools) -> Resul
             args.insert("limit".to_string(), json!(limit));
             ("search_papers", args)
         }
+        GoogleScholarTools::Author {
+            author,
+            limit,
+            offset,
+            sort,
+        } => {
+            let mut args = Map::new();
+            args.insert("author".to_string(), json!(author));
+            args.insert("limit".to_string(), json!(limit));
+            args.insert("offset".to_string(), json!(offset));
+            if let Some(s) = sort {
+                args.insert("sort".to_string(), json!(s));
+            }
+            ("get_author", args)
+        }
+        GoogleScholarTools::CitedBy {
+            cites_id,
+            query,
+            limit,
+            offset,
+        } => {
+            let mut args = Map::new();
+            if let Some(id) = cites_id {
+                args.insert("cites_id".to_string(), json!(id));
+            }
+            if let Some(q) = query {
+                args.insert("query".to_string(), json!(q));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            args.insert("offset".to_string(), json!(offset));
+            ("get_cited_by", args)
+        }
     };
 
     call_tool(cli, "google-scholar", tool_name, args).await
@@ -944,6 +1269,7 @@ pub async fn handle_atlassian(cli: &Cli, tool: AtlassianTools) -> Result<()> {
         AtlassianTools::JiraSearch {
             jql,
             start_at,
+            next_page_token,
             max_results,
             fields,
         } => {
@@ -952,6 +1278,9 @@ pub async fn handle_atlassian(cli: &Cli, tool: AtlassianTools) -> Result<()> {
             if start_at > 0 {
                 args.insert("start_at".to_string(), json!(start_at));
             }
+            if let Some(token) = next_page_token {
+                args.insert("next_page_token".to_string(), json!(token));
+            }
             if max_results != 50 {
                 args.insert("max_results".to_string(), json!(max_results));
             }
@@ -968,6 +1297,97 @@ pub async fn handle_atlassian(cli: &Cli, tool: AtlassianTools) -> Result<()> {
             }
             ("jira_get_issue", args)
         }
+        AtlassianTools::JiraDetail {
+            key,
+            comments,
+            changelog,
+        } => {
+            let mut args = Map::new();
+            args.insert("key".to_string(), json!(key));
+            if comments == 0 {
+                args.insert("include_comments".to_string(), json!(false));
+            } else {
+                args.insert("comment_limit".to_string(), json!(comments));
+            }
+            if changelog == 0 {
+                args.insert("include_changelog".to_string(), json!(false));
+            } else {
+                args.insert("changelog_limit".to_string(), json!(changelog));
+            }
+            ("jira_get_issue_detail", args)
+        }
+        AtlassianTools::JiraTransitions { key } => {
+            let mut args = Map::new();
+            args.insert("key".to_string(), json!(key));
+            ("jira_list_transitions", args)
+        }
+        AtlassianTools::JiraComment { key, body, yes } => {
+            let mut args = Map::new();
+            args.insert("key".to_string(), json!(key));
+            args.insert("body".to_string(), json!(body));
+            args.insert("confirm".to_string(), json!(yes));
+            ("jira_add_comment", args)
+        }
+        AtlassianTools::JiraTransition {
+            key,
+            transition,
+            comment,
+            yes,
+        } => {
+            let mut args = Map::new();
+            args.insert("key".to_string(), json!(key));
+            args.insert("transition".to_string(), json!(transition));
+            if let Some(c) = comment {
+                args.insert("comment".to_string(), json!(c));
+            }
+            args.insert("confirm".to_string(), json!(yes));
+            ("jira_transition_issue", args)
+        }
+        AtlassianTools::JiraBoards {
+            project,
+            name,
+            board_type,
+            max_results,
+        } => {
+            let mut args = Map::new();
+            if let Some(p) = project {
+                args.insert("project".to_string(), json!(p));
+            }
+            if let Some(n) = name {
+                args.insert("name".to_string(), json!(n));
+            }
+            if let Some(t) = board_type {
+                args.insert("type".to_string(), json!(t));
+            }
+            args.insert("max_results".to_string(), json!(max_results));
+            ("jira_list_boards", args)
+        }
+        AtlassianTools::JiraSprints {
+            board_id,
+            state,
+            max_results,
+        } => {
+            let mut args = Map::new();
+            args.insert("board_id".to_string(), json!(board_id));
+            if let Some(s) = state {
+                args.insert("state".to_string(), json!(s));
+            }
+            args.insert("max_results".to_string(), json!(max_results));
+            ("jira_list_sprints", args)
+        }
+        AtlassianTools::JiraSprintIssues {
+            sprint_id,
+            jql,
+            max_results,
+        } => {
+            let mut args = Map::new();
+            args.insert("sprint_id".to_string(), json!(sprint_id));
+            if let Some(j) = jql {
+                args.insert("jql".to_string(), json!(j));
+            }
+            args.insert("max_results".to_string(), json!(max_results));
+            ("jira_sprint_issues", args)
+        }
         AtlassianTools::ConfSearch { cql, start, limit } => {
             let mut args = Map::new();
             args.insert("cql".to_string(), json!(cql));
@@ -1064,6 +1484,98 @@ pub async fn handle_microsoft_graph(cli: &Cli, tool: MicrosoftGraphTools) -> Res
             args.insert("body_text".to_string(), json!(body));
             ("create_draft", args)
         }
+        MicrosoftGraphTools::ListTeams { limit } => {
+            let mut args = Map::new();
+            args.insert("limit".to_string(), json!(limit));
+            ("list_teams", args)
+        }
+        MicrosoftGraphTools::ListChannels { team_id } => {
+            let mut args = Map::new();
+            args.insert("team_id".to_string(), json!(team_id));
+            ("list_channels", args)
+        }
+        MicrosoftGraphTools::ChannelMessages {
+            team_id,
+            channel_id,
+            limit,
+            replies,
+            next_link,
+        } => {
+            let mut args = Map::new();
+            args.insert("team_id".to_string(), json!(team_id));
+            args.insert("channel_id".to_string(), json!(channel_id));
+            args.insert("limit".to_string(), json!(limit));
+            if replies {
+                args.insert("include_replies".to_string(), json!(replies));
+            }
+            if let Some(nl) = next_link {
+                args.insert("next_link".to_string(), json!(nl));
+            }
+            ("list_channel_messages", args)
+        }
+        MicrosoftGraphTools::ListChats { limit, next_link } => {
+            let mut args = Map::new();
+            args.insert("limit".to_string(), json!(limit));
+            if let Some(nl) = next_link {
+                args.insert("next_link".to_string(), json!(nl));
+            }
+            ("list_chats", args)
+        }
+        MicrosoftGraphTools::ChatMessages {
+            chat_id,
+            limit,
+            next_link,
+        } => {
+            let mut args = Map::new();
+            args.insert("chat_id".to_string(), json!(chat_id));
+            args.insert("limit".to_string(), json!(limit));
+            if let Some(nl) = next_link {
+                args.insert("next_link".to_string(), json!(nl));
+            }
+            ("list_chat_messages", args)
+        }
+        MicrosoftGraphTools::SearchTeams { query, limit } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            args.insert("limit".to_string(), json!(limit));
+            ("search_teams_messages", args)
+        }
+        MicrosoftGraphTools::ListNotebooks { limit } => {
+            let mut args = Map::new();
+            args.insert("limit".to_string(), json!(limit));
+            ("list_notebooks", args)
+        }
+        MicrosoftGraphTools::ListSections { notebook_id, limit } => {
+            let mut args = Map::new();
+            if let Some(id) = notebook_id {
+                args.insert("notebook_id".to_string(), json!(id));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("list_sections", args)
+        }
+        MicrosoftGraphTools::ListPages {
+            section_id,
+            limit,
+            next_link,
+        } => {
+            let mut args = Map::new();
+            if let Some(id) = section_id {
+                args.insert("section_id".to_string(), json!(id));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            if let Some(nl) = next_link {
+                args.insert("next_link".to_string(), json!(nl));
+            }
+            ("list_pages", args)
+        }
+        MicrosoftGraphTools::GetPage { page_id, max_chars } => {
+            let mut args = Map::new();
+            args.insert("page_id".to_string(), json!(page_id));
+            if let Some(c) = max_chars {
+                args.insert("max_chars".to_string(), json!(c));
+            }
+            ("get_page", args)
+        }
         MicrosoftGraphTools::UploadAttachment {
             message_id,
             filename,
@@ -1196,6 +1708,37 @@ pub async fn handle_imap(cli: &Cli, tool: ImapTools) -> Result<()> {
             }
             ("get_message", args)
         }
+        ImapTools::GetAttachments {
+            mailbox,
+            uid,
+            select,
+            all,
+            include_data,
+            max_bytes,
+            max_chars,
+        } => {
+            let mut args = Map::new();
+            if let Some(m) = mailbox {
+                args.insert("mailbox".to_string(), json!(m));
+            }
+            args.insert("uid".to_string(), json!(uid));
+            if !select.is_empty() {
+                args.insert("select".to_string(), json!(select));
+            }
+            if all {
+                args.insert("all".to_string(), json!(all));
+            }
+            if include_data {
+                args.insert("include_data".to_string(), json!(include_data));
+            }
+            if let Some(b) = max_bytes {
+                args.insert("max_bytes".to_string(), json!(b));
+            }
+            if let Some(c) = max_chars {
+                args.insert("max_chars".to_string(), json!(c));
+            }
+            ("get_attachments", args)
+        }
         ImapTools::Search {
             mailbox,
             query,
@@ -1209,11 +1752,215 @@ pub async fn handle_imap(cli: &Cli, tool: ImapTools) -> Result<()> {
             args.insert("limit".to_string(), json!(limit));
             ("search", args)
         }
-    };
-
-    call_tool(cli, "imap", tool_name, args).await
-}
-
+        ImapTools::Idle {
+            mailbox,
+            timeout,
+            max_messages,
+            follow,
+        } => {
+            let mut args = Map::new();
+            if let Some(m) = mailbox {
+                args.insert("mailbox".to_string(), json!(m));
+            }
+            args.insert("timeout_seconds".to_string(), json!(timeout));
+            args.insert("max_messages".to_string(), json!(max_messages));
+            if follow {
+                // Each call returns on new mail; resume from its next_uid so
+                // nothing that lands between calls is missed
+                loop {
+                    let (payload, meta_value) = call_tool_raw("imap", "idle", args.clone()).await?;
+                    let has_mail = payload
+                        .get("messages")
+                        .and_then(|m| m.as_array())
+                        .is_some_and(|m| !m.is_empty());
+                    if has_mail {
+                        output_tool_result(cli, "imap", "idle", &payload, meta_value.as_ref())?;
+                    }
+                    if let Some(next_uid) = payload.get("next_uid") {
+                        args.insert("since_uid".to_string(), next_uid.clone());
+                    }
+                }
+            }
+            ("idle", args)
+        }
+    };
+
+    call_tool(cli, "imap", tool_name, args).await
+}
+
+/// Handle newsletters commands
+pub async fn handle_newsletters(cli: &Cli, tool: NewslettersTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        NewslettersTools::Set { key, value } => {
+            if !["mailbox", "senders", "exclude"].contains(&key.as_str()) {
+                return Err(crate::commands::CommandError::InvalidInput(format!(
+                    "Unknown key '{}'; expected mailbox, senders or exclude",
+                    key
+                )));
+            }
+            let store = FileAuthStore::new_default();
+            let mut auth = store.load("newsletters").unwrap_or_default();
+            auth.insert(key.clone(), value);
+            store.save("newsletters", &auth).map_err(|e| {
+                crate::commands::CommandError::InvalidConfig(format!("Failed to save: {}", e))
+            })?;
+            println!("{} {} saved", "Success!".green().bold(), key.cyan());
+            return Ok(());
+        }
+        NewslettersTools::List { days, mailbox } => {
+            let mut args = Map::new();
+            args.insert("days".to_string(), json!(days));
+            if let Some(m) = mailbox {
+                args.insert("mailbox".to_string(), json!(m));
+            }
+            ("list_newsletters", args)
+        }
+        NewslettersTools::Issues {
+            publication,
+            days,
+            limit,
+            mailbox,
+        } => {
+            let mut args = Map::new();
+            if let Some(p) = publication {
+                args.insert("publication".to_string(), json!(p));
+            }
+            args.insert("days".to_string(), json!(days));
+            args.insert("limit".to_string(), json!(limit));
+            if let Some(m) = mailbox {
+                args.insert("mailbox".to_string(), json!(m));
+            }
+            ("list_issues", args)
+        }
+        NewslettersTools::Search {
+            query,
+            publication,
+            days,
+            limit,
+            mailbox,
+        } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(p) = publication {
+                args.insert("publication".to_string(), json!(p));
+            }
+            args.insert("days".to_string(), json!(days));
+            args.insert("limit".to_string(), json!(limit));
+            if let Some(m) = mailbox {
+                args.insert("mailbox".to_string(), json!(m));
+            }
+            ("search_issues", args)
+        }
+        NewslettersTools::Read { uid, mailbox } => {
+            let mut args = Map::new();
+            args.insert("uid".to_string(), json!(uid));
+            if let Some(m) = mailbox {
+                args.insert("mailbox".to_string(), json!(m));
+            }
+            ("read_issue", args)
+        }
+    };
+
+    call_tool(cli, "newsletters", tool_name, args).await
+}
+
+/// Handle webhook inbox commands
+pub async fn handle_inbox(cli: &Cli, tool: InboxTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        InboxTools::Serve { listen } => {
+            let addr: std::net::SocketAddr = listen.parse().map_err(|e| {
+                crate::commands::CommandError::InvalidInput(format!(
+                    "Invalid listen address '{}': {}",
+                    listen, e
+                ))
+            })?;
+            #[cfg(feature = "inbox")]
+            {
+                let auth = FileAuthStore::new_default()
+                    .load("inbox")
+                    .unwrap_or_default();
+                println!(
+                    "{} webhooks on {} (POST to /github, /stripe or /<source>; Ctrl-C to stop)",
+                    "Receiving".green().bold(),
+                    format!("http://{}", addr).cyan()
+                );
+                arivu_core::connectors::inbox::serve(addr, auth).await?;
+                return Ok(());
+            }
+            #[cfg(not(feature = "inbox"))]
+            {
+                let _ = addr;
+                return Err(crate::commands::CommandError::ConnectorNotFound(
+                    "inbox".to_string(),
+                ));
+            }
+        }
+        InboxTools::Secret { source, secret } => {
+            let store = FileAuthStore::new_default();
+            let mut auth = store.load("inbox").unwrap_or_default();
+            let source = source.to_ascii_lowercase();
+            auth.insert(format!("secret.{}", source), secret);
+            store.save("inbox", &auth).map_err(|e| {
+                crate::commands::CommandError::InvalidConfig(format!("Failed to save: {}", e))
+            })?;
+            println!(
+                "{} Deliveries from {} must now be signed; sources without a secret are rejected",
+                "Success!".green().bold(),
+                source.cyan()
+            );
+            return Ok(());
+        }
+        InboxTools::List {
+            source,
+            event_type,
+            since,
+            unacked,
+            limit,
+        } => {
+            let mut args = Map::new();
+            if let Some(s) = source {
+                args.insert("source".to_string(), json!(s));
+            }
+            if let Some(t) = event_type {
+                args.insert("event_type".to_string(), json!(t));
+            }
+            if let Some(s) = since {
+                args.insert("since".to_string(), json!(s));
+            }
+            if unacked {
+                args.insert("unacked_only".to_string(), json!(true));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("list_events", args)
+        }
+        InboxTools::Search {
+            query,
+            source,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(s) = source {
+                args.insert("source".to_string(), json!(s));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("search_events", args)
+        }
+        InboxTools::Get { id } => {
+            let mut args = Map::new();
+            args.insert("id".to_string(), json!(id));
+            ("get_event", args)
+        }
+        InboxTools::Ack { ids } => {
+            let mut args = Map::new();
+            args.insert("ids".to_string(), json!(ids));
+            ("ack_events", args)
+        }
+    };
+
+    call_tool(cli, "inbox", tool_name, args).await
+}
+
 /// Handle localfs commands
 pub async fn handle_localfs(cli: &Cli, tool: LocalfsTools) -> Result<()> {
     let (tool_name, args) = match tool {
@@ -1284,6 +2031,69 @@ pub async fn handle_localfs(cli: &Cli, tool: LocalfsTools) -> Result<()> {
     call_tool(cli, "localfs", tool_name, args).await
 }
 
+/// Handle paper library commands
+pub async fn handle_paper_library(cli: &Cli, tool: PaperLibraryTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        PaperLibraryTools::Dir { path } => {
+            let store = FileAuthStore::new_default();
+            let mut auth = store.load("paper-library").unwrap_or_default();
+            auth.insert("dir".to_string(), path.clone());
+            store.save("paper-library", &auth).map_err(|e| {
+                crate::commands::CommandError::InvalidConfig(format!("Failed to save: {}", e))
+            })?;
+            println!(
+                "{} Paper library set to {}",
+                "Success!".green().bold(),
+                path.cyan()
+            );
+            return Ok(());
+        }
+        PaperLibraryTools::Search {
+            query,
+            section,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(s) = section {
+                args.insert("section".to_string(), json!(s));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("search_papers", args)
+        }
+        PaperLibraryTools::Get {
+            id,
+            sections,
+            max_chars,
+        } => {
+            let mut args = Map::new();
+            args.insert("id".to_string(), json!(id));
+            if !sections.is_empty() {
+                args.insert("sections".to_string(), json!(sections));
+            }
+            if let Some(m) = max_chars {
+                args.insert("max_chars".to_string(), json!(m));
+            }
+            ("get_paper", args)
+        }
+        PaperLibraryTools::List { sort, limit } => {
+            let mut args = Map::new();
+            args.insert("sort".to_string(), json!(sort));
+            args.insert("limit".to_string(), json!(limit));
+            ("list_papers", args)
+        }
+        PaperLibraryTools::Reindex { force } => {
+            let mut args = Map::new();
+            if force {
+                args.insert("force".to_string(), json!(true));
+            }
+            ("reindex", args)
+        }
+    };
+
+    call_tool(cli, "paper-library", tool_name, args).await
+}
+
 /// Handle youtube commands
 pub async fn handle_youtube(cli: &Cli, args: YoutubeArgs) -> Result<()> {
     let tool = match args.command {
@@ -1291,6 +2101,9 @@ pub async fn handle_youtube(cli: &Cli, args: YoutubeArgs) -> Result<()> {
         None => YoutubeTools::Get {
             id_or_url: args.id_or_url,
             id: None,
+            languages: Vec::new(),
+            prefer_auto: false,
+            translate_to: None,
         },
     };
 
@@ -1332,6 +2145,12 @@ pub async fn handle_youtube(cli: &Cli, args: YoutubeArgs) -> Result<()> {
             }
             call_tool(cli, "youtube", "list", tool_args).await
         }
+        YoutubeTools::ChannelInfo { channel, limit } => {
+            let mut tool_args = Map::new();
+            tool_args.insert("channel".to_string(), json!(channel));
+            tool_args.insert("recent_limit".to_string(), json!(limit));
+            call_tool(cli, "youtube", "get_channel", tool_args).await
+        }
         YoutubeTools::ResolveChannel {
             query,
             channel,
@@ -1349,7 +2168,13 @@ pub async fn handle_youtube(cli: &Cli, args: YoutubeArgs) -> Result<()> {
             tool_args.insert("prefer_verified".to_string(), json!(prefer_verified));
             call_tool(cli, "youtube", "resolve_channel", tool_args).await
         }
-        YoutubeTools::Get { id_or_url, id } => {
+        YoutubeTools::Get {
+            id_or_url,
+            id,
+            languages,
+            prefer_auto,
+            translate_to,
+        } => {
             let id = id_or_url.or(id).ok_or_else(|| {
                 crate::commands::CommandError::InvalidInput(
                     "Missing video ID/URL. Provide `arivu youtube <ID_OR_URL>` or `arivu youtube get --id <ID_OR_URL>`.".to_string(),
@@ -1359,8 +2184,51 @@ pub async fn handle_youtube(cli: &Cli, args: YoutubeArgs) -> Result<()> {
             let mut tool_args = Map::new();
             tool_args.insert("video_id".to_string(), json!(id));
             tool_args.insert("response_format".to_string(), json!("detailed"));
+            if !languages.is_empty() {
+                tool_args.insert("languages".to_string(), json!(languages));
+            }
+            if prefer_auto {
+                tool_args.insert("prefer_auto".to_string(), json!(true));
+            }
+            if let Some(target) = translate_to {
+                tool_args.insert("translate_to".to_string(), json!(target));
+            }
             call_tool(cli, "youtube", "get", tool_args).await
         }
+        YoutubeTools::SearchTranscript {
+            id_or_url,
+            query,
+            languages,
+            context,
+            limit,
+        } => {
+            let mut tool_args = Map::new();
+            tool_args.insert("video_id".to_string(), json!(id_or_url));
+            tool_args.insert("query".to_string(), json!(query));
+            if !languages.is_empty() {
+                tool_args.insert("languages".to_string(), json!(languages));
+            }
+            tool_args.insert("context".to_string(), json!(context));
+            tool_args.insert("limit".to_string(), json!(limit));
+            call_tool(cli, "youtube", "search_transcript", tool_args).await
+        }
+        YoutubeTools::Download {
+            id_or_url,
+            kind,
+            quality,
+            output_dir,
+            max_mb,
+        } => {
+            let mut tool_args = Map::new();
+            tool_args.insert("video_id".to_string(), json!(id_or_url));
+            tool_args.insert("kind".to_string(), json!(kind));
+            tool_args.insert("quality".to_string(), json!(quality));
+            if let Some(dir) = output_dir {
+                tool_args.insert("output_dir".to_string(), json!(dir));
+            }
+            tool_args.insert("max_mb".to_string(), json!(max_mb));
+            call_tool(cli, "youtube", "download", tool_args).await
+        }
         YoutubeTools::Transcript { id_or_url, id } => {
             let id = id_or_url.or(id).ok_or_else(|| {
                 crate::commands::CommandError::InvalidInput(
@@ -1412,11 +2280,28 @@ pub async fn handle_youtube(cli: &Cli, args: YoutubeArgs) -> Result<()> {
 /// Handle hackernews commands
 pub async fn handle_hackernews(cli: &Cli, tool: HackernewsTools) -> Result<()> {
     let (tool_name, args) = match tool {
-        HackernewsTools::Search { query, limit } => {
+        HackernewsTools::Search {
+            query,
+            limit,
+            since,
+            until,
+            recent,
+        } => {
             let mut args = Map::new();
             args.insert("query".to_string(), json!(query));
             args.insert("hitsPerPage".to_string(), json!(limit));
-            ("search_stories", args)
+            if let Some(since) = since {
+                args.insert("since".to_string(), json!(since));
+            }
+            if let Some(until) = until {
+                args.insert("until".to_string(), json!(until));
+            }
+            let tool_name = if recent {
+                "search_by_date"
+            } else {
+                "search_stories"
+            };
+            (tool_name, args)
         }
         HackernewsTools::Story { id } => {
             let mut args = Map::new();
@@ -1447,6 +2332,35 @@ pub async fn handle_hackernews(cli: &Cli, tool: HackernewsTools) -> Result<()> {
             args.insert("flatten".to_string(), json!(true));
             ("get_post", args)
         }
+        HackernewsTools::User {
+            username,
+            activity,
+            page,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("username".to_string(), json!(username));
+            args.insert("activity".to_string(), json!(activity));
+            args.insert("page".to_string(), json!(page));
+            args.insert("hitsPerPage".to_string(), json!(limit));
+            ("get_user", args)
+        }
+        HackernewsTools::MaxItem => ("get_max_item_id", Map::new()),
+        HackernewsTools::Updates => ("get_updates", Map::new()),
+        HackernewsTools::Since {
+            since_id,
+            limit,
+            types,
+        } => {
+            let mut args = Map::new();
+            args.insert("since_id".to_string(), json!(since_id));
+            args.insert("limit".to_string(), json!(limit));
+            if let Some(types) = types {
+                let types: Vec<&str> = types.split(',').map(str::trim).collect();
+                args.insert("types".to_string(), json!(types));
+            }
+            ("get_items_since", args)
+        }
     };
 
     call_tool(cli, "hackernews", tool_name, args).await
@@ -1455,11 +2369,19 @@ pub async fn handle_hackernews(cli: &Cli, tool: HackernewsTools) -> Result<()> {
 /// Handle arxiv commands
 pub async fn handle_arxiv(cli: &Cli, tool: ArxivTools) -> Result<()> {
     let (tool_name, args) = match tool {
-        ArxivTools::Search { query, limit, sort } => {
+        ArxivTools::Search {
+            query,
+            limit,
+            sort,
+            bibtex,
+        } => {
             let mut args = Map::new();
             args.insert("query".to_string(), json!(query));
             args.insert("limit".to_string(), json!(limit));
             args.insert("sort_by".to_string(), json!(sort));
+            if bibtex {
+                args.insert("bibtex".to_string(), json!(true));
+            }
             ("search", args)
         }
         ArxivTools::Paper { id } => {
@@ -1477,6 +2399,48 @@ pub async fn handle_arxiv(cli: &Cli, tool: ArxivTools) -> Result<()> {
             let pdf_url_only = payload.get("pdf_url").cloned().unwrap_or(Value::Null);
             return output_tool_result(cli, "arxiv", "pdf", &pdf_url_only, meta_value.as_ref());
         }
+        ArxivTools::New {
+            category,
+            since,
+            until,
+            limit,
+            no_cross_lists,
+        } => {
+            let mut args = Map::new();
+            args.insert("category".to_string(), json!(category));
+            args.insert("limit".to_string(), json!(limit));
+            if let Some(since) = since {
+                args.insert("since".to_string(), json!(since));
+            }
+            if let Some(until) = until {
+                args.insert("until".to_string(), json!(until));
+            }
+            if no_cross_lists {
+                args.insert("include_cross_lists".to_string(), json!(false));
+            }
+            ("list_new", args)
+        }
+        ArxivTools::Cite { ids, format } => {
+            let mut args = Map::new();
+            args.insert("paper_ids".to_string(), json!(ids));
+            args.insert("format".to_string(), json!(format));
+            ("export_citation", args)
+        }
+        ArxivTools::Fulltext {
+            id,
+            sections,
+            source,
+            max_chars,
+        } => {
+            let mut args = Map::new();
+            args.insert("paper_id".to_string(), json!(id));
+            args.insert("source".to_string(), json!(source));
+            args.insert("max_chars".to_string(), json!(max_chars));
+            if !sections.is_empty() {
+                args.insert("sections".to_string(), json!(sections));
+            }
+            ("get_fulltext", args)
+        }
     };
 
     call_tool(cli, "arxiv", tool_name, args).await
@@ -1501,17 +2465,44 @@ pub async fn handle_github(cli: &Cli, tool: GithubTools) -> Result<()> {
             args.insert("page".to_string(), json!(1));
             ("search_repositories", args)
         }
-        GithubTools::SearchCode { query, repo, limit } => {
+        GithubTools::SearchCode {
+            query,
+            repo,
+            org,
+            path,
+            language,
+            limit,
+        } => {
             let mut args = Map::new();
-            let query = if let Some(r) = repo {
-                format!("{} repo:{}", query, r)
-            } else {
-                query
-            };
             args.insert("query".to_string(), json!(query));
-            args.insert("per_page".to_string(), json!(limit));
+            for (key, value) in [
+                ("repo", repo),
+                ("org", org),
+                ("path", path),
+                ("language", language),
+            ] {
+                if let Some(value) = value {
+                    args.insert(key.to_string(), json!(value));
+                }
+            }
+            args.insert("per_page".to_string(), json!(limit.min(100)));
             args.insert("page".to_string(), json!(1));
-            ("code_search", args)
+            ("search_code", args)
+        }
+        GithubTools::File {
+            repo,
+            path,
+            reference,
+        } => {
+            let (owner, name) = split_owner_repo(&repo)?;
+            let mut args = Map::new();
+            args.insert("owner".to_string(), json!(owner));
+            args.insert("repo".to_string(), json!(name));
+            args.insert("path".to_string(), json!(path));
+            if let Some(reference) = reference {
+                args.insert("ref".to_string(), json!(reference));
+            }
+            ("get_file", args)
         }
         GithubTools::Issues { repo, state, limit } => {
             let (owner, name) = split_owner_repo(&repo)?;
@@ -1540,42 +2531,207 @@ pub async fn handle_github(cli: &Cli, tool: GithubTools) -> Result<()> {
             args.insert("repo".to_string(), json!(name));
             ("get_repository", args)
         }
-    };
-
-    call_tool(cli, "github", tool_name, args).await
-}
-
-/// Handle reddit commands
-pub async fn handle_reddit(cli: &Cli, tool: RedditTools) -> Result<()> {
-    let (tool_name, args) = match tool {
-        RedditTools::Search {
-            query,
-            subreddit,
-            sort,
-            time,
+        GithubTools::Runs {
+            repo,
+            branch,
+            status,
             limit,
         } => {
+            let (owner, name) = split_owner_repo(&repo)?;
             let mut args = Map::new();
-            args.insert("query".to_string(), json!(query));
-            if let Some(sub) = subreddit {
-                args.insert("subreddit".to_string(), json!(sub));
-            }
-            if sort != "relevance" {
-                args.insert("sort".to_string(), json!(sort));
+            args.insert("owner".to_string(), json!(owner));
+            args.insert("repo".to_string(), json!(name));
+            if let Some(branch) = branch {
+                args.insert("branch".to_string(), json!(branch));
             }
-            if time != "all" {
-                args.insert("time".to_string(), json!(time));
+            if let Some(status) = status {
+                args.insert("status".to_string(), json!(status));
             }
-            args.insert("limit".to_string(), json!(limit));
-            ("search", args)
+            args.insert("per_page".to_string(), json!(limit.min(100)));
+            ("list_workflow_runs", args)
         }
-        RedditTools::Hot { subreddit, limit } => {
+        GithubTools::Run { repo, run_id } => {
+            let (owner, name) = split_owner_repo(&repo)?;
             let mut args = Map::new();
-            args.insert("subreddit".to_string(), json!(subreddit));
-            args.insert("limit".to_string(), json!(limit));
-            args.insert("sort".to_string(), json!("hot"));
-            ("list", args)
-        }
+            args.insert("owner".to_string(), json!(owner));
+            args.insert("repo".to_string(), json!(name));
+            args.insert("run_id".to_string(), json!(run_id));
+            ("get_workflow_run", args)
+        }
+        GithubTools::Logs {
+            repo,
+            job,
+            run,
+            tail,
+        } => {
+            let (owner, name) = split_owner_repo(&repo)?;
+            let mut args = Map::new();
+            args.insert("owner".to_string(), json!(owner));
+            args.insert("repo".to_string(), json!(name));
+            if let Some(job) = job {
+                args.insert("job_id".to_string(), json!(job));
+            }
+            if let Some(run) = run {
+                args.insert("run_id".to_string(), json!(run));
+            }
+            args.insert("tail_lines".to_string(), json!(tail));
+            ("get_job_logs", args)
+        }
+        GithubTools::Discussions {
+            repo,
+            limit,
+            cursor,
+        } => {
+            let (owner, name) = split_owner_repo(&repo)?;
+            let mut args = Map::new();
+            args.insert("owner".to_string(), json!(owner));
+            args.insert("repo".to_string(), json!(name));
+            args.insert("per_page".to_string(), json!(limit.min(100)));
+            if let Some(cursor) = cursor {
+                args.insert("cursor".to_string(), json!(cursor));
+            }
+            ("list_discussions", args)
+        }
+        GithubTools::Discussion { repo, number } => {
+            let (owner, name) = split_owner_repo(&repo)?;
+            let mut args = Map::new();
+            args.insert("owner".to_string(), json!(owner));
+            args.insert("repo".to_string(), json!(name));
+            args.insert("number".to_string(), json!(number));
+            ("get_discussion", args)
+        }
+        GithubTools::Releases { repo, limit } => {
+            let (owner, name) = split_owner_repo(&repo)?;
+            let mut args = Map::new();
+            args.insert("owner".to_string(), json!(owner));
+            args.insert("repo".to_string(), json!(name));
+            args.insert("per_page".to_string(), json!(limit.min(100)));
+            ("list_releases", args)
+        }
+        GithubTools::Release { repo, tag } => {
+            let (owner, name) = split_owner_repo(&repo)?;
+            let mut args = Map::new();
+            args.insert("owner".to_string(), json!(owner));
+            args.insert("repo".to_string(), json!(name));
+            if let Some(tag) = tag {
+                args.insert("tag".to_string(), json!(tag));
+            }
+            ("get_release", args)
+        }
+        GithubTools::CreateIssue {
+            repo,
+            title,
+            body,
+            labels,
+            yes,
+        } => {
+            let (owner, name) = split_owner_repo(&repo)?;
+            let mut args = Map::new();
+            args.insert("owner".to_string(), json!(owner));
+            args.insert("repo".to_string(), json!(name));
+            args.insert("title".to_string(), json!(title));
+            if let Some(body) = body {
+                args.insert("body".to_string(), json!(body));
+            }
+            if !labels.is_empty() {
+                args.insert("labels".to_string(), json!(labels));
+            }
+            args.insert("confirm".to_string(), json!(yes));
+            ("create_issue", args)
+        }
+        GithubTools::Comment {
+            repo,
+            number,
+            body,
+            yes,
+        } => {
+            let (owner, name) = split_owner_repo(&repo)?;
+            let mut args = Map::new();
+            args.insert("owner".to_string(), json!(owner));
+            args.insert("repo".to_string(), json!(name));
+            args.insert("number".to_string(), json!(number));
+            args.insert("body".to_string(), json!(body));
+            args.insert("confirm".to_string(), json!(yes));
+            ("add_comment", args)
+        }
+    };
+
+    call_tool(cli, "github", tool_name, args).await
+}
+
+/// Handle reddit commands
+pub async fn handle_reddit(cli: &Cli, tool: RedditTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        RedditTools::Search {
+            query,
+            subreddit,
+            sort,
+            time,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(sub) = subreddit {
+                args.insert("subreddit".to_string(), json!(sub));
+            }
+            if sort != "relevance" {
+                args.insert("sort".to_string(), json!(sort));
+            }
+            if time != "all" {
+                args.insert("time".to_string(), json!(time));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("search", args)
+        }
+        RedditTools::Subreddits { query, limit, nsfw } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            args.insert("limit".to_string(), json!(limit));
+            if nsfw {
+                args.insert("include_nsfw".to_string(), json!(true));
+            }
+            ("find_subreddits", args)
+        }
+        RedditTools::Saved { limit } => {
+            let mut args = Map::new();
+            args.insert("limit".to_string(), json!(limit));
+            ("get_saved", args)
+        }
+        RedditTools::Upvoted { limit } => {
+            let mut args = Map::new();
+            args.insert("limit".to_string(), json!(limit));
+            ("get_upvoted", args)
+        }
+        RedditTools::Inbox { filter, limit } => {
+            let mut args = Map::new();
+            args.insert("filter".to_string(), json!(filter));
+            args.insert("limit".to_string(), json!(limit));
+            ("get_inbox", args)
+        }
+        RedditTools::SearchComments {
+            query,
+            subreddit,
+            time,
+            limit,
+            max_posts,
+        } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(sub) = subreddit {
+                args.insert("subreddit".to_string(), json!(sub));
+            }
+            args.insert("time".to_string(), json!(time));
+            args.insert("limit".to_string(), json!(limit));
+            args.insert("max_posts".to_string(), json!(max_posts));
+            ("search_comments", args)
+        }
+        RedditTools::Hot { subreddit, limit } => {
+            let mut args = Map::new();
+            args.insert("subreddit".to_string(), json!(subreddit));
+            args.insert("limit".to_string(), json!(limit));
+            args.insert("sort".to_string(), json!("hot"));
+            ("list", args)
+        }
         RedditTools::New { subreddit, limit } => {
             let mut args = Map::new();
             args.insert("subreddit".to_string(), json!(subreddit));
@@ -1613,6 +2769,32 @@ pub async fn handle_reddit(cli: &Cli, tool: RedditTools) -> Result<()> {
             }
             ("get", args)
         }
+        RedditTools::UserPosts {
+            username,
+            sort,
+            time,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("username".to_string(), json!(username));
+            args.insert("sort".to_string(), json!(sort));
+            args.insert("time".to_string(), json!(time));
+            args.insert("limit".to_string(), json!(limit));
+            ("get_user_posts", args)
+        }
+        RedditTools::UserComments {
+            username,
+            sort,
+            time,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("username".to_string(), json!(username));
+            args.insert("sort".to_string(), json!(sort));
+            args.insert("time".to_string(), json!(time));
+            args.insert("limit".to_string(), json!(limit));
+            ("get_user_comments", args)
+        }
     };
 
     call_tool(cli, "reddit", tool_name, args).await
@@ -1621,16 +2803,35 @@ pub async fn handle_reddit(cli: &Cli, tool: RedditTools) -> Result<()> {
 /// Handle web commands
 pub async fn handle_web(cli: &Cli, tool: WebTools) -> Result<()> {
     let (tool_name, args) = match tool {
-        WebTools::Scrape { url, format } => {
+        WebTools::Scrape {
+            url,
+            format,
+            render,
+            article,
+        } => {
             let mut args = Map::new();
             args.insert("url".to_string(), json!(url));
+            if render {
+                args.insert("render".to_string(), json!(true));
+            }
+            if article {
+                args.insert("extract".to_string(), json!("article"));
+            }
             let _ = format;
             ("scrape_url", args)
         }
-        WebTools::Extract { url, images, links } => {
+        WebTools::Extract {
+            url,
+            images,
+            links,
+            render,
+        } => {
             let _ = (images, links);
             let mut args = Map::new();
             args.insert("url".to_string(), json!(url));
+            if render {
+                args.insert("render".to_string(), json!(true));
+            }
             ("extract", args)
         }
         WebTools::Metadata { url } => {
@@ -1638,10 +2839,48 @@ pub async fn handle_web(cli: &Cli, tool: WebTools) -> Result<()> {
             args.insert("url".to_string(), json!(url));
             ("metadata", args)
         }
+        WebTools::Crawl {
+            url,
+            max_pages,
+            max_depth,
+            any_domain,
+            ignore_robots,
+            article,
+            max_chars,
+        } => {
+            let mut args = Map::new();
+            args.insert("url".to_string(), json!(url));
+            args.insert("max_pages".to_string(), json!(max_pages));
+            args.insert("max_depth".to_string(), json!(max_depth));
+            args.insert("same_domain".to_string(), json!(!any_domain));
+            args.insert("respect_robots".to_string(), json!(!ignore_robots));
+            if article {
+                args.insert("extract".to_string(), json!("article"));
+            }
+            if let Some(max_chars) = max_chars {
+                args.insert("max_chars_per_page".to_string(), json!(max_chars));
+            }
+            ("crawl", args)
+        }
+        WebTools::Discover {
+            domain,
+            limit,
+            no_probe,
+        } => {
+            let mut args = Map::new();
+            args.insert("domain".to_string(), json!(domain));
+            args.insert("max_urls".to_string(), json!(limit));
+            if no_probe {
+                args.insert("probe_feeds".to_string(), json!(false));
+            }
+            ("discover", args)
+        }
     };
 
     match tool_name {
         "scrape_url" => call_tool(cli, "web", "scrape_url", args).await,
+        "crawl" => call_tool(cli, "web", "crawl", args).await,
+        "discover" => call_tool(cli, "web", "discover", args).await,
         "extract" => {
             let (payload, meta_value) = call_tool_raw("web", "scrape_url", args).await?;
             let extracted = payload.get("content").cloned().unwrap_or(Value::Null);
@@ -1659,24 +2898,103 @@ pub async fn handle_web(cli: &Cli, tool: WebTools) -> Result<()> {
 /// Handle wikipedia commands
 pub async fn handle_wikipedia(cli: &Cli, tool: WikipediaTools) -> Result<()> {
     let (tool_name, args) = match tool {
-        WikipediaTools::Search { query, limit } => {
+        WikipediaTools::Search {
+            query,
+            limit,
+            language,
+        } => {
             let mut args = Map::new();
             args.insert("query".to_string(), json!(query));
             args.insert("limit".to_string(), json!(limit));
+            if let Some(language) = language {
+                args.insert("language".to_string(), json!(language));
+            }
             ("search", args)
         }
-        WikipediaTools::Article { title } => {
+        WikipediaTools::Article {
+            title,
+            section,
+            markdown,
+            language,
+        } => {
             let mut args = Map::new();
             args.insert("title".to_string(), json!(title));
             args.insert("response_format".to_string(), json!("detailed"));
+            if let Some(section) = section {
+                args.insert("section".to_string(), json!(section));
+            }
+            if markdown {
+                args.insert("format".to_string(), json!("markdown"));
+            }
+            if let Some(language) = language {
+                args.insert("language".to_string(), json!(language));
+            }
             ("get_article", args)
         }
-        WikipediaTools::Summary { title } => {
+        WikipediaTools::Summary { title, language } => {
             let mut args = Map::new();
             args.insert("title".to_string(), json!(title));
             args.insert("response_format".to_string(), json!("concise"));
+            if let Some(language) = language {
+                args.insert("language".to_string(), json!(language));
+            }
             ("get_article", args)
         }
+        WikipediaTools::Category {
+            category,
+            types,
+            limit,
+            cursor,
+            language,
+        } => {
+            let mut args = Map::new();
+            args.insert("category".to_string(), json!(category));
+            args.insert("limit".to_string(), json!(limit));
+            if !types.is_empty() {
+                args.insert("types".to_string(), json!(types));
+            }
+            if let Some(cursor) = cursor {
+                args.insert("cursor".to_string(), json!(cursor));
+            }
+            if let Some(language) = language {
+                args.insert("language".to_string(), json!(language));
+            }
+            ("list_category_members", args)
+        }
+        WikipediaTools::Links {
+            title,
+            limit,
+            cursor,
+            language,
+        } => {
+            let mut args = Map::new();
+            args.insert("title".to_string(), json!(title));
+            args.insert("limit".to_string(), json!(limit));
+            if let Some(cursor) = cursor {
+                args.insert("cursor".to_string(), json!(cursor));
+            }
+            if let Some(language) = language {
+                args.insert("language".to_string(), json!(language));
+            }
+            ("get_links", args)
+        }
+        WikipediaTools::Backlinks {
+            title,
+            limit,
+            cursor,
+            language,
+        } => {
+            let mut args = Map::new();
+            args.insert("title".to_string(), json!(title));
+            args.insert("limit".to_string(), json!(limit));
+            if let Some(cursor) = cursor {
+                args.insert("cursor".to_string(), json!(cursor));
+            }
+            if let Some(language) = language {
+                args.insert("language".to_string(), json!(language));
+            }
+            ("get_backlinks", args)
+        }
     };
 
     call_tool(cli, "wikipedia", tool_name, args).await
@@ -1685,10 +3003,35 @@ pub async fn handle_wikipedia(cli: &Cli, tool: WikipediaTools) -> Result<()> {
 /// Handle pubmed commands
 pub async fn handle_pubmed(cli: &Cli, tool: PubmedTools) -> Result<()> {
     let (tool_name, args) = match tool {
-        PubmedTools::Search { query, limit } => {
+        PubmedTools::Search {
+            query,
+            limit,
+            mesh_terms,
+            publication_types,
+            species,
+            start_date,
+            end_date,
+        } => {
             let mut args = Map::new();
-            args.insert("query".to_string(), json!(query));
+            if let Some(query) = query {
+                args.insert("query".to_string(), json!(query));
+            }
             args.insert("limit".to_string(), json!(limit));
+            if !mesh_terms.is_empty() {
+                args.insert("mesh_terms".to_string(), json!(mesh_terms));
+            }
+            if !publication_types.is_empty() {
+                args.insert("publication_types".to_string(), json!(publication_types));
+            }
+            if let Some(species) = species {
+                args.insert("species".to_string(), json!(species));
+            }
+            if let Some(start_date) = start_date {
+                args.insert("start_date".to_string(), json!(start_date));
+            }
+            if let Some(end_date) = end_date {
+                args.insert("end_date".to_string(), json!(end_date));
+            }
             ("search", args)
         }
         PubmedTools::Article { pmid } => {
@@ -1697,6 +3040,24 @@ pub async fn handle_pubmed(cli: &Cli, tool: PubmedTools) -> Result<()> {
             args.insert("response_format".to_string(), json!("detailed"));
             ("get", args)
         }
+        PubmedTools::Fulltext {
+            id,
+            sections,
+            max_chars,
+        } => {
+            let mut args = Map::new();
+            let key = if id.to_ascii_uppercase().starts_with("PMC") {
+                "pmcid"
+            } else {
+                "pmid"
+            };
+            args.insert(key.to_string(), json!(id));
+            args.insert("max_chars".to_string(), json!(max_chars));
+            if !sections.is_empty() {
+                args.insert("sections".to_string(), json!(sections));
+            }
+            ("get_fulltext", args)
+        }
     };
 
     call_tool(cli, "pubmed", tool_name, args).await
@@ -1717,18 +3078,57 @@ pub async fn handle_semantic_scholar(cli: &Cli, tool: SemanticScholarTools) -> R
             args.insert("paper_id".to_string(), json!(id));
             ("get_paper_details", args)
         }
-        SemanticScholarTools::Citations { id, limit } => {
+        SemanticScholarTools::Citations {
+            id,
+            limit,
+            offset,
+            fields,
+        } => {
             let mut args = Map::new();
             args.insert("paper_id".to_string(), json!(id));
             args.insert("limit".to_string(), json!(limit));
+            args.insert("offset".to_string(), json!(offset));
+            if !fields.is_empty() {
+                args.insert("fields".to_string(), json!(fields));
+            }
             ("get_citations", args)
         }
-        SemanticScholarTools::References { id, limit } => {
+        SemanticScholarTools::References {
+            id,
+            limit,
+            offset,
+            fields,
+        } => {
             let mut args = Map::new();
             args.insert("paper_id".to_string(), json!(id));
             args.insert("limit".to_string(), json!(limit));
+            args.insert("offset".to_string(), json!(offset));
+            if !fields.is_empty() {
+                args.insert("fields".to_string(), json!(fields));
+            }
             ("get_references", args)
         }
+        SemanticScholarTools::Recommend {
+            ids,
+            negative,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("positive_paper_ids".to_string(), json!(ids));
+            if !negative.is_empty() {
+                args.insert("negative_paper_ids".to_string(), json!(negative));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("recommend", args)
+        }
+        SemanticScholarTools::Batch { ids, fields } => {
+            let mut args = Map::new();
+            args.insert("paper_ids".to_string(), json!(ids));
+            if !fields.is_empty() {
+                args.insert("fields".to_string(), json!(fields));
+            }
+            ("batch_papers", args)
+        }
     };
 
     call_tool(cli, "semantic-scholar", tool_name, args).await
@@ -1737,12 +3137,19 @@ pub async fn handle_semantic_scholar(cli: &Cli, tool: SemanticScholarTools) -> R
 /// Handle slack commands
 pub async fn handle_slack(cli: &Cli, tool: SlackTools) -> Result<()> {
     let (tool_name, args) = match tool {
-        SlackTools::Channels { limit, cursor } => {
+        SlackTools::Channels {
+            limit,
+            cursor,
+            include_archived,
+        } => {
             let mut args = Map::new();
             args.insert("limit".to_string(), json!(limit));
             if let Some(c) = cursor {
                 args.insert("cursor".to_string(), json!(c));
             }
+            if include_archived {
+                args.insert("exclude_archived".to_string(), json!(false));
+            }
             ("list_channels", args)
         }
         SlackTools::Messages {
@@ -1758,17 +3165,113 @@ pub async fn handle_slack(cli: &Cli, tool: SlackTools) -> Result<()> {
             }
             ("list_messages", args)
         }
-        SlackTools::Search {
-            query,
+        SlackTools::Thread { channel, ts, limit } => {
+            let mut args = Map::new();
+            args.insert("channel".to_string(), json!(channel));
+            args.insert("thread_ts".to_string(), json!(ts));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("get_thread", args)
+        }
+        SlackTools::Files {
+            channel,
+            user,
+            types,
             limit,
-            page,
-            sort,
-            sort_dir,
+            cursor,
         } => {
             let mut args = Map::new();
-            args.insert("query".to_string(), json!(query));
-            args.insert("count".to_string(), json!(limit));
-            args.insert("page".to_string(), json!(page));
+            args.insert("limit".to_string(), json!(limit));
+            if let Some(ch) = channel {
+                args.insert("channel".to_string(), json!(ch));
+            }
+            if let Some(u) = user {
+                args.insert("user".to_string(), json!(u));
+            }
+            if let Some(t) = types {
+                args.insert("types".to_string(), json!(t));
+            }
+            if let Some(c) = cursor {
+                args.insert("cursor".to_string(), json!(c));
+            }
+            ("list_files", args)
+        }
+        SlackTools::File {
+            id,
+            max_bytes,
+            max_chars,
+        } => {
+            let mut args = Map::new();
+            args.insert("file_id".to_string(), json!(id));
+            if let Some(b) = max_bytes {
+                args.insert("max_bytes".to_string(), json!(b));
+            }
+            if let Some(c) = max_chars {
+                args.insert("max_chars".to_string(), json!(c));
+            }
+            ("get_file", args)
+        }
+        SlackTools::Post {
+            channel,
+            text,
+            thread_ts,
+            broadcast,
+            yes,
+        } => {
+            let mut args = Map::new();
+            args.insert("channel".to_string(), json!(channel));
+            args.insert("text".to_string(), json!(text));
+            if let Some(ts) = thread_ts {
+                args.insert("thread_ts".to_string(), json!(ts));
+            }
+            if broadcast {
+                args.insert("reply_broadcast".to_string(), json!(true));
+            }
+            args.insert("confirm".to_string(), json!(yes));
+            ("post_message", args)
+        }
+        SlackTools::Search {
+            query,
+            from,
+            in_channel,
+            before,
+            after,
+            has_link,
+            limit,
+            cursor,
+            page,
+            sort,
+            sort_dir,
+        } => {
+            let mut args = Map::new();
+            if let Some(q) = query {
+                args.insert("query".to_string(), json!(q));
+            }
+            if let Some(f) = from {
+                args.insert("from".to_string(), json!(f));
+            }
+            if let Some(c) = in_channel {
+                args.insert("in".to_string(), json!(c));
+            }
+            if let Some(b) = before {
+                args.insert("before".to_string(), json!(b));
+            }
+            if let Some(a) = after {
+                args.insert("after".to_string(), json!(a));
+            }
+            if has_link {
+                args.insert("has_link".to_string(), json!(true));
+            }
+            if let Some(p) = page {
+                args.insert("count".to_string(), json!(limit.min(100)));
+                args.insert("page".to_string(), json!(p));
+            } else {
+                args.insert("limit".to_string(), json!(limit));
+            }
+            if let Some(c) = cursor {
+                args.insert("cursor".to_string(), json!(c));
+            }
             if let Some(s) = sort {
                 args.insert("sort".to_string(), json!(s));
             }
@@ -1824,6 +3327,32 @@ pub async fn handle_x(cli: &Cli, tool: XTools) -> Result<()> {
             args.insert("tweet_id".to_string(), json!(tweet_id));
             ("get_tweet", args)
         }
+        XTools::Thread {
+            tweet_id,
+            replies,
+            max_replies,
+        } => {
+            let mut args = Map::new();
+            args.insert("tweet_id".to_string(), json!(tweet_id));
+            if replies {
+                args.insert("include_replies".to_string(), json!(replies));
+                args.insert("max_replies".to_string(), json!(max_replies));
+            }
+            ("get_thread", args)
+        }
+        XTools::ListTimeline {
+            list_id,
+            limit,
+            cursor,
+        } => {
+            let mut args = Map::new();
+            args.insert("list_id".to_string(), json!(list_id));
+            args.insert("limit".to_string(), json!(limit));
+            if let Some(c) = cursor {
+                args.insert("cursor".to_string(), json!(c));
+            }
+            ("get_list_timeline", args)
+        }
         XTools::Timeline {
             count,
             exclude_replies,
@@ -1908,109 +3437,1927 @@ pub async fn handle_discord(cli: &Cli, tool: DiscordTools) -> Result<()> {
         DiscordTools::Send {
             channel_id,
             content,
+            reply_to,
+            yes,
         } => {
             let mut args = Map::new();
             args.insert("channel_id".to_string(), json!(channel_id));
             args.insert("content".to_string(), json!(content));
+            if let Some(id) = reply_to {
+                args.insert("reply_to_message_id".to_string(), json!(id));
+            }
+            args.insert("confirm".to_string(), json!(yes));
             ("send_message", args)
         }
-        DiscordTools::Search {
-            channel_id,
-            query,
-            limit,
+        DiscordTools::Threads {
+            guild_id,
+            channel_id,
+            archived,
+            limit,
+        } => {
+            let mut args = Map::new();
+            if let Some(g) = guild_id {
+                args.insert("guild_id".to_string(), json!(g));
+            }
+            if let Some(c) = channel_id {
+                args.insert("channel_id".to_string(), json!(c));
+            }
+            if archived {
+                args.insert("archived".to_string(), json!(true));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("list_threads", args)
+        }
+        DiscordTools::Thread { thread_id, limit } => {
+            let mut args = Map::new();
+            args.insert("thread_id".to_string(), json!(thread_id));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("get_thread_messages", args)
+        }
+        DiscordTools::Search {
+            channel_id,
+            query,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("channel_id".to_string(), json!(channel_id));
+            args.insert("query".to_string(), json!(query));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("search_messages", args)
+        }
+    };
+
+    call_tool(cli, "discord", tool_name, args).await
+}
+
+/// Handle RSS commands
+pub async fn handle_rss(cli: &Cli, tool: RssTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        RssTools::Feed { url, limit } => {
+            let mut args = Map::new();
+            args.insert("url".to_string(), json!(url));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("get_feed", args)
+        }
+        RssTools::Entries { url, limit } => {
+            let mut args = Map::new();
+            args.insert("url".to_string(), json!(url));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("list_entries", args)
+        }
+        RssTools::Search { url, query, limit } => {
+            let mut args = Map::new();
+            args.insert("url".to_string(), json!(url));
+            args.insert("query".to_string(), json!(query));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("search_feed", args)
+        }
+        RssTools::Discover { url } => {
+            let mut args = Map::new();
+            args.insert("url".to_string(), json!(url));
+            ("discover_feeds", args)
+        }
+        RssTools::Subscribe {
+            url,
+            title,
+            category,
+        } => {
+            let mut args = Map::new();
+            args.insert("url".to_string(), json!(url));
+            if let Some(t) = title {
+                args.insert("title".to_string(), json!(t));
+            }
+            if let Some(c) = category {
+                args.insert("category".to_string(), json!(c));
+            }
+            ("subscribe", args)
+        }
+        RssTools::Unsubscribe { url } => {
+            let mut args = Map::new();
+            args.insert("url".to_string(), json!(url));
+            ("unsubscribe", args)
+        }
+        RssTools::Subscriptions { category } => {
+            let mut args = Map::new();
+            if let Some(c) = category {
+                args.insert("category".to_string(), json!(c));
+            }
+            ("list_subscriptions", args)
+        }
+        RssTools::Import { path } => {
+            let mut args = Map::new();
+            args.insert("path".to_string(), json!(path));
+            ("import_opml", args)
+        }
+        RssTools::Export { output } => {
+            let mut args = Map::new();
+            if let Some(o) = output {
+                args.insert("path".to_string(), json!(o));
+            }
+            ("export_opml", args)
+        }
+        RssTools::New {
+            url,
+            category,
+            limit,
+            peek,
+        } => {
+            let mut args = Map::new();
+            if let Some(u) = url {
+                args.insert("url".to_string(), json!(u));
+            }
+            if let Some(c) = category {
+                args.insert("category".to_string(), json!(c));
+            }
+            if let Some(l) = limit {
+                args.insert("limit_per_feed".to_string(), json!(l));
+            }
+            if peek {
+                args.insert("mark_seen".to_string(), json!(false));
+            }
+            ("fetch_new", args)
+        }
+    };
+
+    call_tool(cli, "rss", tool_name, args).await
+}
+
+/// Handle bioRxiv commands
+pub async fn handle_biorxiv(cli: &Cli, tool: BiorxivTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        BiorxivTools::Recent { server, count } => {
+            let mut args = Map::new();
+            args.insert("server".to_string(), json!(server));
+            if let Some(c) = count {
+                args.insert("count".to_string(), json!(c));
+            }
+            ("get_recent_preprints", args)
+        }
+        BiorxivTools::DateRange {
+            server,
+            start_date,
+            end_date,
+        } => {
+            let mut args = Map::new();
+            args.insert("server".to_string(), json!(server));
+            args.insert("start_date".to_string(), json!(start_date));
+            args.insert("end_date".to_string(), json!(end_date));
+            ("get_preprints_by_date", args)
+        }
+        BiorxivTools::Paper { server, doi } => {
+            let mut args = Map::new();
+            args.insert("server".to_string(), json!(server));
+            args.insert("doi".to_string(), json!(doi));
+            ("get_preprint_by_doi", args)
+        }
+    };
+
+    call_tool(cli, "biorxiv", tool_name, args).await
+}
+
+/// Handle Crossref commands
+pub async fn handle_crossref(cli: &Cli, tool: CrossrefTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        CrossrefTools::Work { doi } => {
+            let mut args = Map::new();
+            args.insert("doi".to_string(), json!(doi));
+            ("get_work", args)
+        }
+        CrossrefTools::Search {
+            query,
+            author,
+            from_date,
+            until_date,
+            work_type,
+            sort,
+            limit,
+            offset,
+        } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(a) = author {
+                args.insert("author".to_string(), json!(a));
+            }
+            if let Some(d) = from_date {
+                args.insert("from_date".to_string(), json!(d));
+            }
+            if let Some(d) = until_date {
+                args.insert("until_date".to_string(), json!(d));
+            }
+            if let Some(t) = work_type {
+                args.insert("work_type".to_string(), json!(t));
+            }
+            if let Some(s) = sort {
+                args.insert("sort".to_string(), json!(s));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            if let Some(o) = offset {
+                args.insert("offset".to_string(), json!(o));
+            }
+            ("search_works", args)
+        }
+        CrossrefTools::Funders { query, limit } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("search_funders", args)
+        }
+        CrossrefTools::FunderWorks {
+            funder_id,
+            query,
+            limit,
+            offset,
+        } => {
+            let mut args = Map::new();
+            args.insert("funder_id".to_string(), json!(funder_id));
+            if let Some(q) = query {
+                args.insert("query".to_string(), json!(q));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            if let Some(o) = offset {
+                args.insert("offset".to_string(), json!(o));
+            }
+            ("get_funder_works", args)
+        }
+        CrossrefTools::Journals { query, limit } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("search_journals", args)
+        }
+        CrossrefTools::JournalWorks {
+            issn,
+            query,
+            limit,
+            offset,
+        } => {
+            let mut args = Map::new();
+            args.insert("issn".to_string(), json!(issn));
+            if let Some(q) = query {
+                args.insert("query".to_string(), json!(q));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            if let Some(o) = offset {
+                args.insert("offset".to_string(), json!(o));
+            }
+            ("get_journal_works", args)
+        }
+    };
+
+    call_tool(cli, "crossref", tool_name, args).await
+}
+
+/// Handle DBLP commands
+pub async fn handle_dblp(cli: &Cli, tool: DblpTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        DblpTools::Search {
+            query,
+            year,
+            venue,
+            limit,
+            offset,
+            bibtex,
+        } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(y) = year {
+                args.insert("year".to_string(), json!(y));
+            }
+            if let Some(v) = venue {
+                args.insert("venue".to_string(), json!(v));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            if let Some(o) = offset {
+                args.insert("offset".to_string(), json!(o));
+            }
+            args.insert("bibtex".to_string(), json!(bibtex));
+            ("search_publications", args)
+        }
+        DblpTools::Authors { query, limit } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("search_authors", args)
+        }
+        DblpTools::AuthorPubs {
+            pid,
+            name,
+            limit,
+            bibtex,
+        } => {
+            let mut args = Map::new();
+            if let Some(p) = pid {
+                args.insert("pid".to_string(), json!(p));
+            }
+            if let Some(n) = name {
+                args.insert("name".to_string(), json!(n));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            args.insert("bibtex".to_string(), json!(bibtex));
+            ("get_author_publications", args)
+        }
+        DblpTools::Venues { query, limit } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("search_venues", args)
+        }
+        DblpTools::Venue {
+            venue,
+            year,
+            limit,
+            offset,
+        } => {
+            let mut args = Map::new();
+            args.insert("venue".to_string(), json!(venue));
+            if let Some(y) = year {
+                args.insert("year".to_string(), json!(y));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            if let Some(o) = offset {
+                args.insert("offset".to_string(), json!(o));
+            }
+            ("get_venue_publications", args)
+        }
+        DblpTools::Bibtex { key } => {
+            let mut args = Map::new();
+            args.insert("key".to_string(), json!(key));
+            ("get_bibtex", args)
+        }
+    };
+
+    call_tool(cli, "dblp", tool_name, args).await
+}
+
+/// Handle market data commands
+pub async fn handle_market_data(cli: &Cli, tool: MarketDataTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        MarketDataTools::Quote { symbols, source } => {
+            let mut args = Map::new();
+            args.insert("symbols".to_string(), json!(symbols));
+            if let Some(s) = source {
+                args.insert("source".to_string(), json!(s));
+            }
+            ("get_quote", args)
+        }
+        MarketDataTools::History {
+            symbol,
+            start_date,
+            end_date,
+            range,
+            interval,
+            source,
+        } => {
+            let mut args = Map::new();
+            args.insert("symbol".to_string(), json!(symbol));
+            if let Some(d) = start_date {
+                args.insert("start_date".to_string(), json!(d));
+            }
+            if let Some(d) = end_date {
+                args.insert("end_date".to_string(), json!(d));
+            }
+            if let Some(r) = range {
+                args.insert("range".to_string(), json!(r));
+            }
+            if let Some(i) = interval {
+                args.insert("interval".to_string(), json!(i));
+            }
+            if let Some(s) = source {
+                args.insert("source".to_string(), json!(s));
+            }
+            ("get_history", args)
+        }
+        MarketDataTools::Search { query, limit } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("search_symbols", args)
+        }
+    };
+
+    call_tool(cli, "market-data", tool_name, args).await
+}
+
+/// Handle CoinGecko commands
+pub async fn handle_coingecko(cli: &Cli, tool: CoingeckoTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        CoingeckoTools::Search { query, limit } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("search_coins", args)
+        }
+        CoingeckoTools::Price { ids, vs_currencies } => {
+            let mut args = Map::new();
+            args.insert("ids".to_string(), json!(ids));
+            if let Some(vs) = vs_currencies {
+                args.insert("vs_currencies".to_string(), json!(vs));
+            }
+            ("get_price", args)
+        }
+        CoingeckoTools::Markets {
+            vs_currency,
+            ids,
+            category,
+            limit,
+            page,
+        } => {
+            let mut args = Map::new();
+            if let Some(vs) = vs_currency {
+                args.insert("vs_currency".to_string(), json!(vs));
+            }
+            if let Some(i) = ids {
+                args.insert("ids".to_string(), json!(i));
+            }
+            if let Some(c) = category {
+                args.insert("category".to_string(), json!(c));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            if let Some(p) = page {
+                args.insert("page".to_string(), json!(p));
+            }
+            ("get_markets", args)
+        }
+        CoingeckoTools::Coin { id } => {
+            let mut args = Map::new();
+            args.insert("id".to_string(), json!(id));
+            ("get_coin", args)
+        }
+        CoingeckoTools::Chart {
+            id,
+            vs_currency,
+            days,
+            start_date,
+            end_date,
+        } => {
+            let mut args = Map::new();
+            args.insert("id".to_string(), json!(id));
+            if let Some(vs) = vs_currency {
+                args.insert("vs_currency".to_string(), json!(vs));
+            }
+            if let Some(d) = days {
+                args.insert("days".to_string(), json!(d));
+            }
+            if let Some(d) = start_date {
+                args.insert("start_date".to_string(), json!(d));
+            }
+            if let Some(d) = end_date {
+                args.insert("end_date".to_string(), json!(d));
+            }
+            ("get_market_chart", args)
+        }
+    };
+
+    call_tool(cli, "coingecko", tool_name, args).await
+}
+
+/// Handle Open Library commands
+pub async fn handle_openlibrary(cli: &Cli, tool: OpenlibraryTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        OpenlibraryTools::Search {
+            query,
+            title,
+            author,
+            isbn,
+            subject,
+            limit,
+            page,
+        } => {
+            let mut args = Map::new();
+            if let Some(q) = query {
+                args.insert("query".to_string(), json!(q));
+            }
+            if let Some(t) = title {
+                args.insert("title".to_string(), json!(t));
+            }
+            if let Some(a) = author {
+                args.insert("author".to_string(), json!(a));
+            }
+            if let Some(i) = isbn {
+                args.insert("isbn".to_string(), json!(i));
+            }
+            if let Some(s) = subject {
+                args.insert("subject".to_string(), json!(s));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            if let Some(p) = page {
+                args.insert("page".to_string(), json!(p));
+            }
+            ("search_books", args)
+        }
+        OpenlibraryTools::Work { id } => {
+            let mut args = Map::new();
+            args.insert("id".to_string(), json!(id));
+            ("get_work", args)
+        }
+        OpenlibraryTools::Edition { id, isbn } => {
+            let mut args = Map::new();
+            if let Some(i) = id {
+                args.insert("id".to_string(), json!(i));
+            }
+            if let Some(i) = isbn {
+                args.insert("isbn".to_string(), json!(i));
+            }
+            ("get_edition", args)
+        }
+        OpenlibraryTools::Authors { query, limit } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("search_authors", args)
+        }
+        OpenlibraryTools::Author { id } => {
+            let mut args = Map::new();
+            args.insert("id".to_string(), json!(id));
+            ("get_author", args)
+        }
+        OpenlibraryTools::AuthorWorks { id, limit, offset } => {
+            let mut args = Map::new();
+            args.insert("id".to_string(), json!(id));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            if let Some(o) = offset {
+                args.insert("offset".to_string(), json!(o));
+            }
+            ("get_author_works", args)
+        }
+    };
+
+    call_tool(cli, "openlibrary", tool_name, args).await
+}
+
+/// Handle podcast commands
+pub async fn handle_podcasts(cli: &Cli, tool: PodcastsTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        PodcastsTools::Search { query, limit } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("search_podcasts", args)
+        }
+        PodcastsTools::Episodes { query, limit } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("search_episodes", args)
+        }
+        PodcastsTools::List {
+            id,
+            feed_url,
+            limit,
+        } => {
+            let mut args = Map::new();
+            if let Some(i) = id {
+                args.insert("id".to_string(), json!(i));
+            }
+            if let Some(f) = feed_url {
+                args.insert("feed_url".to_string(), json!(f));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("list_episodes", args)
+        }
+        PodcastsTools::Episode {
+            id,
+            feed_url,
+            guid,
+            title,
+        } => {
+            let mut args = Map::new();
+            if let Some(i) = id {
+                args.insert("id".to_string(), json!(i));
+            }
+            if let Some(f) = feed_url {
+                args.insert("feed_url".to_string(), json!(f));
+            }
+            if let Some(g) = guid {
+                args.insert("guid".to_string(), json!(g));
+            }
+            if let Some(t) = title {
+                args.insert("title".to_string(), json!(t));
+            }
+            ("get_episode", args)
+        }
+        PodcastsTools::Transcript {
+            url,
+            id,
+            feed_url,
+            guid,
+            title,
+        } => {
+            let mut args = Map::new();
+            if let Some(u) = url {
+                args.insert("url".to_string(), json!(u));
+            }
+            if let Some(i) = id {
+                args.insert("id".to_string(), json!(i));
+            }
+            if let Some(f) = feed_url {
+                args.insert("feed_url".to_string(), json!(f));
+            }
+            if let Some(g) = guid {
+                args.insert("guid".to_string(), json!(g));
+            }
+            if let Some(t) = title {
+                args.insert("title".to_string(), json!(t));
+            }
+            ("get_transcript", args)
+        }
+    };
+
+    call_tool(cli, "podcasts", tool_name, args).await
+}
+
+/// Handle Twitch commands
+pub async fn handle_twitch(cli: &Cli, tool: TwitchTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        TwitchTools::Channels { query, live, limit } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            args.insert("live_only".to_string(), json!(live));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("search_channels", args)
+        }
+        TwitchTools::Streams {
+            channels,
+            game,
+            language,
+            limit,
+        } => {
+            let mut args = Map::new();
+            if let Some(c) = channels {
+                args.insert("channels".to_string(), json!(c));
+            }
+            if let Some(g) = game {
+                args.insert("game".to_string(), json!(g));
+            }
+            if let Some(l) = language {
+                args.insert("language".to_string(), json!(l));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("get_streams", args)
+        }
+        TwitchTools::Videos {
+            channel,
+            video_type,
+            sort,
+            limit,
+            cursor,
+        } => {
+            let mut args = Map::new();
+            args.insert("channel".to_string(), json!(channel));
+            if let Some(t) = video_type {
+                args.insert("video_type".to_string(), json!(t));
+            }
+            if let Some(s) = sort {
+                args.insert("sort".to_string(), json!(s));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            if let Some(c) = cursor {
+                args.insert("cursor".to_string(), json!(c));
+            }
+            ("list_videos", args)
+        }
+        TwitchTools::Clips {
+            channel,
+            game,
+            since,
+            until,
+            limit,
+            cursor,
+        } => {
+            let mut args = Map::new();
+            if let Some(c) = channel {
+                args.insert("channel".to_string(), json!(c));
+            }
+            if let Some(g) = game {
+                args.insert("game".to_string(), json!(g));
+            }
+            if let Some(s) = since {
+                args.insert("started_at".to_string(), json!(s));
+            }
+            if let Some(u) = until {
+                args.insert("ended_at".to_string(), json!(u));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            if let Some(c) = cursor {
+                args.insert("cursor".to_string(), json!(c));
+            }
+            ("search_clips", args)
+        }
+        TwitchTools::Chat {
+            video_id,
+            offset,
+            cursor,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("video_id".to_string(), json!(video_id));
+            if let Some(o) = offset {
+                args.insert("offset_seconds".to_string(), json!(o));
+            }
+            if let Some(c) = cursor {
+                args.insert("cursor".to_string(), json!(c));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("get_chat_replay", args)
+        }
+    };
+
+    call_tool(cli, "twitch", tool_name, args).await
+}
+
+/// Handle Vimeo commands
+pub async fn handle_vimeo(cli: &Cli, tool: VimeoTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        VimeoTools::Search { query, limit, sort } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            if let Some(s) = sort {
+                args.insert("sort".to_string(), json!(s));
+            }
+            ("search", args)
+        }
+        VimeoTools::Get {
+            video_id,
+            language,
+            detailed,
+        } => {
+            let mut args = Map::new();
+            args.insert("video_id".to_string(), json!(video_id));
+            if let Some(l) = language {
+                args.insert("language".to_string(), json!(l));
+            }
+            if detailed {
+                args.insert("response_format".to_string(), json!("detailed"));
+            }
+            ("get", args)
+        }
+        VimeoTools::Captions {
+            video_id,
+            language,
+            vtt,
+        } => {
+            let mut args = Map::new();
+            args.insert("video_id".to_string(), json!(video_id));
+            if let Some(l) = language {
+                args.insert("language".to_string(), json!(l));
+            }
+            if vtt {
+                args.insert("format".to_string(), json!("vtt"));
+            }
+            ("get_captions", args)
+        }
+    };
+
+    call_tool(cli, "vimeo", tool_name, args).await
+}
+
+/// Handle Salesforce commands
+pub async fn handle_salesforce(cli: &Cli, tool: SalesforceTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        SalesforceTools::Query { soql, max, all } => {
+            let mut args = Map::new();
+            args.insert("soql".to_string(), json!(soql));
+            if let Some(m) = max {
+                args.insert("max_records".to_string(), json!(m));
+            }
+            if all {
+                args.insert("include_deleted".to_string(), json!(true));
+            }
+            ("query", args)
+        }
+        SalesforceTools::Record { object, id, fields } => {
+            let mut args = Map::new();
+            args.insert("object".to_string(), json!(object));
+            args.insert("id".to_string(), json!(id));
+            if let Some(f) = fields {
+                args.insert("fields".to_string(), json!(f));
+            }
+            ("get_record", args)
+        }
+        SalesforceTools::Describe { object, picklists } => {
+            let mut args = Map::new();
+            args.insert("object".to_string(), json!(object));
+            args.insert("include_picklists".to_string(), json!(picklists));
+            ("describe_object", args)
+        }
+        SalesforceTools::Objects { filter, custom } => {
+            let mut args = Map::new();
+            if let Some(f) = filter {
+                args.insert("filter".to_string(), json!(f));
+            }
+            args.insert("custom_only".to_string(), json!(custom));
+            ("list_objects", args)
+        }
+    };
+
+    call_tool(cli, "salesforce", tool_name, args).await
+}
+
+/// Handle HubSpot commands
+pub async fn handle_hubspot(cli: &Cli, tool: HubspotTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        HubspotTools::Search {
+            object,
+            query,
+            filters,
+            properties,
+            sort,
+            limit,
+            after,
+        } => {
+            let mut args = Map::new();
+            args.insert("object".to_string(), json!(object));
+            if let Some(q) = query {
+                args.insert("query".to_string(), json!(q));
+            }
+            if !filters.is_empty() {
+                let mut parsed = Vec::new();
+                for filter in filters {
+                    let (property, value) = filter.split_once('=').ok_or_else(|| {
+                        crate::commands::CommandError::InvalidInput(format!(
+                            "Filter '{}' must be property=value",
+                            filter
+                        ))
+                    })?;
+                    parsed.push(json!({ "property": property, "value": value }));
+                }
+                args.insert("filters".to_string(), json!(parsed));
+            }
+            if let Some(p) = properties {
+                args.insert("properties".to_string(), json!(p));
+            }
+            if let Some(s) = sort {
+                args.insert("sort".to_string(), json!(s));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            if let Some(a) = after {
+                args.insert("after".to_string(), json!(a));
+            }
+            ("search_records", args)
+        }
+        HubspotTools::Get {
+            object,
+            id,
+            properties,
+            associations,
+        } => {
+            let mut args = Map::new();
+            args.insert("object".to_string(), json!(object));
+            args.insert("id".to_string(), json!(id));
+            if let Some(p) = properties {
+                args.insert("properties".to_string(), json!(p));
+            }
+            if let Some(a) = associations {
+                args.insert("associations".to_string(), json!(a));
+            }
+            ("get_record", args)
+        }
+        HubspotTools::Engagements {
+            object,
+            id,
+            types,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("object".to_string(), json!(object));
+            args.insert("id".to_string(), json!(id));
+            if let Some(t) = types {
+                args.insert("types".to_string(), json!(t));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("list_engagements", args)
+        }
+    };
+
+    call_tool(cli, "hubspot", tool_name, args).await
+}
+
+/// Handle Airtable commands
+pub async fn handle_airtable(cli: &Cli, tool: AirtableTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        AirtableTools::Bases => ("list_bases", Map::new()),
+        AirtableTools::Tables { base, no_fields } => {
+            let mut args = Map::new();
+            args.insert("base".to_string(), json!(base));
+            args.insert("include_fields".to_string(), json!(!no_fields));
+            ("list_tables", args)
+        }
+        AirtableTools::Records {
+            base,
+            table,
+            filter,
+            view,
+            fields,
+            sort,
+            limit,
+            offset,
+        } => {
+            let mut args = Map::new();
+            args.insert("base".to_string(), json!(base));
+            if let Some(t) = table {
+                args.insert("table".to_string(), json!(t));
+            }
+            if let Some(f) = filter {
+                args.insert("filter_formula".to_string(), json!(f));
+            }
+            if let Some(v) = view {
+                args.insert("view".to_string(), json!(v));
+            }
+            if let Some(f) = fields {
+                args.insert("fields".to_string(), json!(f));
+            }
+            if let Some(s) = sort {
+                args.insert("sort".to_string(), json!(s));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            if let Some(o) = offset {
+                args.insert("offset".to_string(), json!(o));
+            }
+            ("list_records", args)
+        }
+        AirtableTools::Record {
+            base,
+            table,
+            record_id,
+        } => {
+            let mut args = Map::new();
+            args.insert("base".to_string(), json!(base));
+            if let Some(t) = table {
+                args.insert("table".to_string(), json!(t));
+            }
+            if let Some(r) = record_id {
+                args.insert("record_id".to_string(), json!(r));
+            }
+            ("get_record", args)
+        }
+    };
+
+    call_tool(cli, "airtable", tool_name, args).await
+}
+
+/// Handle Box commands
+pub async fn handle_box(cli: &Cli, tool: BoxTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        BoxTools::ListFiles {
+            folder_id,
+            limit,
+            offset,
+        } => {
+            let mut args = Map::new();
+            if let Some(f) = folder_id {
+                args.insert("folder_id".to_string(), json!(f));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            if let Some(o) = offset {
+                args.insert("offset".to_string(), json!(o));
+            }
+            ("list_files", args)
+        }
+        BoxTools::Search {
+            query,
+            item_type,
+            file_extensions,
+            folder,
+            content_types,
+            limit,
+            offset,
+        } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(t) = item_type {
+                args.insert("type".to_string(), json!(t));
+            }
+            if let Some(e) = file_extensions {
+                args.insert("file_extensions".to_string(), json!(e));
+            }
+            if let Some(f) = folder {
+                args.insert("folder_id".to_string(), json!(f));
+            }
+            if let Some(c) = content_types {
+                args.insert("content_types".to_string(), json!(c));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            if let Some(o) = offset {
+                args.insert("offset".to_string(), json!(o));
+            }
+            ("search", args)
+        }
+        BoxTools::GetFile {
+            file_id,
+            response_format,
+        } => {
+            let mut args = Map::new();
+            args.insert("file_id".to_string(), json!(file_id));
+            args.insert("response_format".to_string(), json!(response_format));
+            ("get_file", args)
+        }
+        BoxTools::DownloadFile { file_id, max_bytes } => {
+            let mut args = Map::new();
+            args.insert("file_id".to_string(), json!(file_id));
+            if let Some(m) = max_bytes {
+                args.insert("max_bytes".to_string(), json!(m));
+            }
+            ("download_file", args)
+        }
+        BoxTools::ExtractText { file_id, max_chars } => {
+            let mut args = Map::new();
+            args.insert("file_id".to_string(), json!(file_id));
+            if let Some(m) = max_chars {
+                args.insert("max_chars".to_string(), json!(m));
+            }
+            ("extract_text", args)
+        }
+    };
+
+    call_tool(cli, "box", tool_name, args).await
+}
+
+/// Handle S3 commands
+pub async fn handle_s3(cli: &Cli, tool: S3Tools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        S3Tools::Buckets => ("list_buckets", Map::new()),
+        S3Tools::Ls {
+            bucket,
+            prefix,
+            recursive,
+            limit,
+            token,
+        } => {
+            let mut args = Map::new();
+            args.insert("bucket".to_string(), json!(bucket));
+            if let Some(p) = prefix {
+                args.insert("prefix".to_string(), json!(p));
+            }
+            args.insert("recursive".to_string(), json!(recursive));
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            if let Some(t) = token {
+                args.insert("continuation_token".to_string(), json!(t));
+            }
+            ("list_objects", args)
+        }
+        S3Tools::Head { bucket, key } => {
+            let mut args = Map::new();
+            args.insert("bucket".to_string(), json!(bucket));
+            if let Some(k) = key {
+                args.insert("key".to_string(), json!(k));
+            }
+            ("get_object_metadata", args)
+        }
+        S3Tools::Get {
+            bucket,
+            key,
+            max_bytes,
+            offset,
+            base64,
+        } => {
+            let mut args = Map::new();
+            args.insert("bucket".to_string(), json!(bucket));
+            if let Some(k) = key {
+                args.insert("key".to_string(), json!(k));
+            }
+            if let Some(m) = max_bytes {
+                args.insert("max_bytes".to_string(), json!(m));
+            }
+            if let Some(o) = offset {
+                args.insert("offset".to_string(), json!(o));
+            }
+            if base64 {
+                args.insert("encoding".to_string(), json!("base64"));
+            }
+            ("get_object", args)
+        }
+    };
+
+    call_tool(cli, "s3", tool_name, args).await
+}
+
+/// Handle SQL commands
+pub async fn handle_sql(cli: &Cli, tool: SqlTools) -> Result<()> {
+    use arivu_core::auth_store::{AuthStore, FileAuthStore};
+
+    let (tool_name, args) = match tool {
+        SqlTools::Connections => ("list_connections", Map::new()),
+        SqlTools::Add { name, url } => {
+            let scheme = url.split(':').next().unwrap_or_default();
+            if !["postgres", "postgresql", "mysql", "mariadb", "sqlite"].contains(&scheme) {
+                return Err(crate::commands::CommandError::InvalidInput(
+                    "URL must start with postgres://, mysql:// or sqlite:".to_string(),
+                ));
+            }
+            let store = FileAuthStore::new_default();
+            let mut auth = store.load("sql").unwrap_or_default();
+            auth.insert(format!("connection.{}", name), url);
+            store.save("sql", &auth).map_err(|e| {
+                crate::commands::CommandError::InvalidConfig(format!("Failed to save: {}", e))
+            })?;
+            println!(
+                "{} Connection {} saved",
+                "Success!".green().bold(),
+                name.cyan()
+            );
+            return Ok(());
+        }
+        SqlTools::Remove { name } => {
+            let store = FileAuthStore::new_default();
+            let mut auth = store.load("sql").unwrap_or_default();
+            if auth.remove(&format!("connection.{}", name)).is_none() {
+                return Err(crate::commands::CommandError::InvalidInput(format!(
+                    "No saved connection named '{}'",
+                    name
+                )));
+            }
+            store.save("sql", &auth).map_err(|e| {
+                crate::commands::CommandError::InvalidConfig(format!("Failed to save: {}", e))
+            })?;
+            println!(
+                "{} Connection {} removed",
+                "Success!".green().bold(),
+                name.cyan()
+            );
+            return Ok(());
+        }
+        SqlTools::Tables { connection, schema } => {
+            let mut args = Map::new();
+            args.insert("connection".to_string(), json!(connection));
+            if let Some(s) = schema {
+                args.insert("schema".to_string(), json!(s));
+            }
+            ("list_tables", args)
+        }
+        SqlTools::Describe { connection, table } => {
+            let mut args = Map::new();
+            args.insert("connection".to_string(), json!(connection));
+            args.insert("table".to_string(), json!(table));
+            ("describe_table", args)
+        }
+        SqlTools::Query {
+            connection,
+            sql,
+            params,
+            max_rows,
+        } => {
+            let mut args = Map::new();
+            args.insert("connection".to_string(), json!(connection));
+            args.insert("sql".to_string(), json!(sql));
+            if !params.is_empty() {
+                // Numbers, booleans and null bind with their JSON type; anything else is text.
+                let params: Vec<Value> = params
+                    .iter()
+                    .map(|p| match serde_json::from_str::<Value>(p) {
+                        Ok(v) if !v.is_object() && !v.is_array() => v,
+                        _ => json!(p),
+                    })
+                    .collect();
+                args.insert("params".to_string(), json!(params));
+            }
+            if let Some(n) = max_rows {
+                args.insert("max_rows".to_string(), json!(n));
+            }
+            ("query", args)
+        }
+    };
+
+    call_tool(cli, "sql", tool_name, args).await
+}
+
+/// Handle OpenAPI commands
+pub async fn handle_openapi(cli: &Cli, tool: OpenapiTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        OpenapiTools::Set { key, value } => {
+            const KEYS: &[&str] = &[
+                "spec",
+                "base_url",
+                "operations",
+                "allow_writes",
+                "api_key",
+                "auth_header",
+                "auth_query",
+            ];
+            if !KEYS.contains(&key.as_str()) {
+                return Err(crate::commands::CommandError::InvalidInput(format!(
+                    "Unknown key '{}'; expected one of: {}",
+                    key,
+                    KEYS.join(", ")
+                )));
+            }
+            let store = FileAuthStore::new_default();
+            let mut auth = store.load("openapi").unwrap_or_default();
+            auth.insert(key.clone(), value);
+            store.save("openapi", &auth).map_err(|e| {
+                crate::commands::CommandError::InvalidConfig(format!("Failed to save: {}", e))
+            })?;
+            println!("{} {} saved", "Success!".green().bold(), key.cyan());
+            return Ok(());
+        }
+        OpenapiTools::Operations { enabled } => {
+            let mut args = Map::new();
+            args.insert("enabled_only".to_string(), json!(enabled));
+            ("list_operations".to_string(), args)
+        }
+        OpenapiTools::Call {
+            operation,
+            params,
+            body,
+        } => {
+            let mut args = Map::new();
+            for param in params {
+                let (name, value) = param.split_once('=').ok_or_else(|| {
+                    crate::commands::CommandError::InvalidInput(format!(
+                        "Parameter '{}' must be name=value",
+                        param
+                    ))
+                })?;
+                let value = serde_json::from_str(value).unwrap_or_else(|_| json!(value));
+                args.insert(name.to_string(), value);
+            }
+            if let Some(b) = body {
+                let body: Value = serde_json::from_str(&b).map_err(|e| {
+                    crate::commands::CommandError::InvalidInput(format!(
+                        "--body is not valid JSON: {}",
+                        e
+                    ))
+                })?;
+                args.insert("body".to_string(), body);
+            }
+            (operation, args)
+        }
+    };
+
+    call_tool(cli, "openapi", &tool_name, args).await
+}
+
+/// Handle GraphQL commands
+pub async fn handle_graphql(cli: &Cli, tool: GraphqlTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        GraphqlTools::Set { .. }
+        | GraphqlTools::Header { .. }
+        | GraphqlTools::Add { .. }
+        | GraphqlTools::Remove { .. } => {
+            let store = FileAuthStore::new_default();
+            let mut auth = store.load("graphql").unwrap_or_default();
+            let message = match tool {
+                GraphqlTools::Set { key, value } => {
+                    if !["endpoint", "api_key", "allow_adhoc"].contains(&key.as_str()) {
+                        return Err(crate::commands::CommandError::InvalidInput(format!(
+                            "Unknown key '{}'; expected endpoint, api_key or allow_adhoc",
+                            key
+                        )));
+                    }
+                    auth.insert(key.clone(), value);
+                    format!("{} saved", key.cyan())
+                }
+                GraphqlTools::Header { name, value } => {
+                    auth.insert(format!("header.{}", name), value);
+                    format!("Header {} saved", name.cyan())
+                }
+                GraphqlTools::Add { name, document } => {
+                    let document = match document.strip_prefix('@') {
+                        Some(path) => std::fs::read_to_string(path).map_err(|e| {
+                            crate::commands::CommandError::InvalidInput(format!(
+                                "Cannot read {}: {}",
+                                path, e
+                            ))
+                        })?,
+                        None => document,
+                    };
+                    auth.insert(format!("query.{}", name), document);
+                    format!("Query {} saved", name.cyan())
+                }
+                GraphqlTools::Remove { name } => {
+                    if auth.remove(&format!("query.{}", name)).is_none() {
+                        return Err(crate::commands::CommandError::InvalidInput(format!(
+                            "No allowlisted query named '{}'",
+                            name
+                        )));
+                    }
+                    format!("Query {} removed", name.cyan())
+                }
+                _ => unreachable!(),
+            };
+            store.save("graphql", &auth).map_err(|e| {
+                crate::commands::CommandError::InvalidConfig(format!("Failed to save: {}", e))
+            })?;
+            println!("{} {}", "Success!".green().bold(), message);
+            return Ok(());
+        }
+        GraphqlTools::Schema { type_name } => {
+            let mut args = Map::new();
+            if let Some(t) = type_name {
+                args.insert("type_name".to_string(), json!(t));
+            }
+            ("introspect_schema", args)
+        }
+        GraphqlTools::Queries => ("list_queries", Map::new()),
+        GraphqlTools::Run {
+            name,
+            query,
+            vars,
+            variables,
+            operation,
+        } => {
+            let mut args = Map::new();
+            if let Some(n) = name {
+                args.insert("name".to_string(), json!(n));
+            }
+            if let Some(q) = query {
+                args.insert("query".to_string(), json!(q));
+            }
+            let mut parsed = match variables {
+                Some(v) => serde_json::from_str::<Map<String, Value>>(&v).map_err(|e| {
+                    crate::commands::CommandError::InvalidInput(format!(
+                        "--variables is not a JSON object: {}",
+                        e
+                    ))
+                })?,
+                None => Map::new(),
+            };
+            for var in vars {
+                let (key, value) = var.split_once('=').ok_or_else(|| {
+                    crate::commands::CommandError::InvalidInput(format!(
+                        "Variable '{}' must be name=value",
+                        var
+                    ))
+                })?;
+                let value = serde_json::from_str(value).unwrap_or_else(|_| json!(value));
+                parsed.insert(key.to_string(), value);
+            }
+            if !parsed.is_empty() {
+                args.insert("variables".to_string(), Value::Object(parsed));
+            }
+            if let Some(o) = operation {
+                args.insert("operation_name".to_string(), json!(o));
+            }
+            ("run_query", args)
+        }
+    };
+
+    call_tool(cli, "graphql", tool_name, args).await
+}
+
+/// Handle Sentry commands
+pub async fn handle_sentry(cli: &Cli, tool: SentryTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        SentryTools::Projects => ("list_projects", Map::new()),
+        SentryTools::Issues {
+            query,
+            project,
+            environment,
+            period,
+            sort,
+            limit,
+            cursor,
+        } => {
+            let mut args = Map::new();
+            if let Some(q) = query {
+                args.insert("query".to_string(), json!(q));
+            }
+            if let Some(p) = project {
+                args.insert("project".to_string(), json!(p));
+            }
+            if let Some(e) = environment {
+                args.insert("environment".to_string(), json!(e));
+            }
+            if let Some(p) = period {
+                args.insert("stats_period".to_string(), json!(p));
+            }
+            if let Some(s) = sort {
+                args.insert("sort".to_string(), json!(s));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            if let Some(c) = cursor {
+                args.insert("cursor".to_string(), json!(c));
+            }
+            ("search_issues", args)
+        }
+        SentryTools::Issue {
+            issue,
+            event,
+            in_app,
+        } => {
+            let mut args = Map::new();
+            args.insert("issue".to_string(), json!(issue));
+            if let Some(e) = event {
+                args.insert("event".to_string(), json!(e));
+            }
+            if in_app {
+                args.insert("in_app_only".to_string(), json!(true));
+            }
+            ("get_issue", args)
+        }
+        SentryTools::Releases {
+            project,
+            query,
+            period,
+            limit,
+        } => {
+            let mut args = Map::new();
+            if let Some(p) = project {
+                args.insert("project".to_string(), json!(p));
+            }
+            if let Some(q) = query {
+                args.insert("query".to_string(), json!(q));
+            }
+            if let Some(p) = period {
+                args.insert("stats_period".to_string(), json!(p));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("list_releases", args)
+        }
+        SentryTools::Release {
+            version,
+            project,
+            period,
+        } => {
+            let mut args = Map::new();
+            args.insert("version".to_string(), json!(version));
+            if let Some(p) = project {
+                args.insert("project".to_string(), json!(p));
+            }
+            if let Some(p) = period {
+                args.insert("stats_period".to_string(), json!(p));
+            }
+            ("get_release", args)
+        }
+    };
+
+    call_tool(cli, "sentry", tool_name, args).await
+}
+
+/// Handle PagerDuty commands
+pub async fn handle_pagerduty(cli: &Cli, tool: PagerdutyTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        PagerdutyTools::Incidents {
+            query,
+            statuses,
+            urgency,
+            service,
+            team,
+            since,
+            until,
+            limit,
+        } => {
+            let mut args = Map::new();
+            if let Some(q) = query {
+                args.insert("query".to_string(), json!(q));
+            }
+            if !statuses.is_empty() {
+                args.insert("statuses".to_string(), json!(statuses));
+            }
+            if let Some(u) = urgency {
+                args.insert("urgency".to_string(), json!(u));
+            }
+            if let Some(s) = service {
+                args.insert("service".to_string(), json!(s));
+            }
+            if let Some(t) = team {
+                args.insert("team".to_string(), json!(t));
+            }
+            if let Some(s) = since {
+                args.insert("since".to_string(), json!(s));
+            }
+            if let Some(u) = until {
+                args.insert("until".to_string(), json!(u));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("list_incidents", args)
+        }
+        PagerdutyTools::Incident {
+            incident,
+            no_timeline,
+        } => {
+            let mut args = Map::new();
+            args.insert("incident".to_string(), json!(incident));
+            if no_timeline {
+                args.insert("include_timeline".to_string(), json!(false));
+            }
+            ("get_incident", args)
+        }
+        PagerdutyTools::Oncall {
+            schedule,
+            escalation_policy,
+            since,
+            until,
+        } => {
+            let mut args = Map::new();
+            if let Some(s) = schedule {
+                args.insert("schedule".to_string(), json!(s));
+            }
+            if let Some(p) = escalation_policy {
+                args.insert("escalation_policy".to_string(), json!(p));
+            }
+            if let Some(s) = since {
+                args.insert("since".to_string(), json!(s));
+            }
+            if let Some(u) = until {
+                args.insert("until".to_string(), json!(u));
+            }
+            ("who_is_on_call", args)
+        }
+        PagerdutyTools::Schedules { query } => {
+            let mut args = Map::new();
+            if let Some(q) = query {
+                args.insert("query".to_string(), json!(q));
+            }
+            ("list_schedules", args)
+        }
+        PagerdutyTools::Schedule {
+            schedule,
+            since,
+            until,
+        } => {
+            let mut args = Map::new();
+            args.insert("schedule".to_string(), json!(schedule));
+            if let Some(s) = since {
+                args.insert("since".to_string(), json!(s));
+            }
+            if let Some(u) = until {
+                args.insert("until".to_string(), json!(u));
+            }
+            ("get_schedule", args)
+        }
+    };
+
+    call_tool(cli, "pagerduty", tool_name, args).await
+}
+
+/// Handle Confluence commands
+pub async fn handle_confluence(cli: &Cli, tool: ConfluenceTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        ConfluenceTools::Search {
+            query,
+            cql,
+            space,
+            limit,
+            start,
+        } => {
+            let mut args = Map::new();
+            if let Some(q) = query {
+                args.insert("query".to_string(), json!(q));
+            }
+            if let Some(c) = cql {
+                args.insert("cql".to_string(), json!(c));
+            }
+            if let Some(s) = space {
+                args.insert("space".to_string(), json!(s));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            args.insert("start".to_string(), json!(start));
+            ("search", args)
+        }
+        ConfluenceTools::Page { page, format } => {
+            let mut args = Map::new();
+            args.insert("page".to_string(), json!(page));
+            args.insert("format".to_string(), json!(format));
+            ("get_page", args)
+        }
+        ConfluenceTools::Spaces {
+            space_type,
+            limit,
+            start,
+        } => {
+            let mut args = Map::new();
+            if let Some(t) = space_type {
+                args.insert("space_type".to_string(), json!(t));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            args.insert("start".to_string(), json!(start));
+            ("list_spaces", args)
+        }
+        ConfluenceTools::Children { page, limit, start } => {
+            let mut args = Map::new();
+            args.insert("page".to_string(), json!(page));
+            args.insert("limit".to_string(), json!(limit));
+            args.insert("start".to_string(), json!(start));
+            ("get_children", args)
+        }
+        ConfluenceTools::Ancestors { page } => {
+            let mut args = Map::new();
+            args.insert("page".to_string(), json!(page));
+            ("get_ancestors", args)
+        }
+    };
+
+    call_tool(cli, "confluence", tool_name, args).await
+}
+
+/// Handle Zoom commands
+pub async fn handle_zoom(cli: &Cli, tool: ZoomTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        ZoomTools::Recordings {
+            from,
+            to,
+            user,
+            limit,
+        } => {
+            let mut args = Map::new();
+            if let Some(f) = from {
+                args.insert("from".to_string(), json!(f));
+            }
+            if let Some(t) = to {
+                args.insert("to".to_string(), json!(t));
+            }
+            if let Some(u) = user {
+                args.insert("user_id".to_string(), json!(u));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("list_recordings", args)
+        }
+        ZoomTools::Search {
+            query,
+            from,
+            to,
+            user,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            if let Some(f) = from {
+                args.insert("from".to_string(), json!(f));
+            }
+            if let Some(t) = to {
+                args.insert("to".to_string(), json!(t));
+            }
+            if let Some(u) = user {
+                args.insert("user_id".to_string(), json!(u));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("search_recordings", args)
+        }
+        ZoomTools::Transcript { meeting_id, format } => {
+            let mut args = Map::new();
+            args.insert("meeting_id".to_string(), json!(meeting_id));
+            args.insert("format".to_string(), json!(format));
+            ("get_transcript", args)
+        }
+    };
+
+    call_tool(cli, "zoom", tool_name, args).await
+}
+
+/// Handle crates.io commands
+pub async fn handle_crates_io(cli: &Cli, tool: CratesIoTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        CratesIoTools::Search {
+            query,
+            sort,
+            limit,
+            page,
+        } => {
+            let mut args = Map::new();
+            args.insert("query".to_string(), json!(query));
+            args.insert("sort".to_string(), json!(sort));
+            args.insert("limit".to_string(), json!(limit));
+            args.insert("page".to_string(), json!(page));
+            ("search_crates", args)
+        }
+        CratesIoTools::Info { name, version } => {
+            let mut args = Map::new();
+            args.insert("name".to_string(), json!(name));
+            if let Some(v) = version {
+                args.insert("version".to_string(), json!(v));
+            }
+            ("get_crate", args)
+        }
+        CratesIoTools::Rdeps { name, limit, page } => {
+            let mut args = Map::new();
+            args.insert("name".to_string(), json!(name));
+            args.insert("limit".to_string(), json!(limit));
+            args.insert("page".to_string(), json!(page));
+            ("get_reverse_dependencies", args)
+        }
+        CratesIoTools::Readme {
+            name,
+            version,
+            source,
         } => {
             let mut args = Map::new();
-            args.insert("channel_id".to_string(), json!(channel_id));
-            args.insert("query".to_string(), json!(query));
-            if let Some(l) = limit {
-                args.insert("limit".to_string(), json!(l));
+            args.insert("name".to_string(), json!(name));
+            if let Some(v) = version {
+                args.insert("version".to_string(), json!(v));
             }
-            ("search_messages", args)
+            args.insert("source".to_string(), json!(source));
+            ("get_readme", args)
         }
     };
 
-    call_tool(cli, "discord", tool_name, args).await
+    call_tool(cli, "crates-io", tool_name, args).await
 }
 
-/// Handle RSS commands
-pub async fn handle_rss(cli: &Cli, tool: RssTools) -> Result<()> {
+/// Handle npm commands
+pub async fn handle_npm(cli: &Cli, tool: NpmTools) -> Result<()> {
     let (tool_name, args) = match tool {
-        RssTools::Feed { url, limit } => {
+        NpmTools::Search {
+            query,
+            limit,
+            offset,
+        } => {
             let mut args = Map::new();
-            args.insert("url".to_string(), json!(url));
-            if let Some(l) = limit {
-                args.insert("limit".to_string(), json!(l));
+            args.insert("query".to_string(), json!(query));
+            args.insert("limit".to_string(), json!(limit));
+            args.insert("offset".to_string(), json!(offset));
+            ("search_packages", args)
+        }
+        NpmTools::Info { name, version } => {
+            let mut args = Map::new();
+            args.insert("name".to_string(), json!(name));
+            if let Some(v) = version {
+                args.insert("version".to_string(), json!(v));
             }
-            ("get_feed", args)
+            ("get_package", args)
         }
-        RssTools::Entries { url, limit } => {
+        NpmTools::Readme { name, version } => {
             let mut args = Map::new();
-            args.insert("url".to_string(), json!(url));
-            if let Some(l) = limit {
-                args.insert("limit".to_string(), json!(l));
+            args.insert("name".to_string(), json!(name));
+            if let Some(v) = version {
+                args.insert("version".to_string(), json!(v));
             }
-            ("list_entries", args)
+            ("get_readme", args)
         }
-        RssTools::Search { url, query, limit } => {
+    };
+
+    call_tool(cli, "npm", tool_name, args).await
+}
+
+/// Handle PyPI commands
+pub async fn handle_pypi(cli: &Cli, tool: PypiTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        PypiTools::Search { query, page, limit } => {
             let mut args = Map::new();
-            args.insert("url".to_string(), json!(url));
             args.insert("query".to_string(), json!(query));
-            if let Some(l) = limit {
-                args.insert("limit".to_string(), json!(l));
+            args.insert("page".to_string(), json!(page));
+            args.insert("limit".to_string(), json!(limit));
+            ("search_projects", args)
+        }
+        PypiTools::Info { name, version } => {
+            let mut args = Map::new();
+            args.insert("name".to_string(), json!(name));
+            if let Some(v) = version {
+                args.insert("version".to_string(), json!(v));
             }
-            ("search_feed", args)
+            ("get_project", args)
         }
-        RssTools::Discover { url } => {
+        PypiTools::Description { name, version } => {
             let mut args = Map::new();
-            args.insert("url".to_string(), json!(url));
-            ("discover_feeds", args)
+            args.insert("name".to_string(), json!(name));
+            if let Some(v) = version {
+                args.insert("version".to_string(), json!(v));
+            }
+            ("get_description", args)
         }
     };
 
-    call_tool(cli, "rss", tool_name, args).await
+    call_tool(cli, "pypi", tool_name, args).await
 }
 
-/// Handle bioRxiv commands
-pub async fn handle_biorxiv(cli: &Cli, tool: BiorxivTools) -> Result<()> {
+/// Handle Docker Hub commands
+pub async fn handle_dockerhub(cli: &Cli, tool: DockerhubTools) -> Result<()> {
     let (tool_name, args) = match tool {
-        BiorxivTools::Recent { server, count } => {
+        DockerhubTools::Search { query, page, limit } => {
             let mut args = Map::new();
-            args.insert("server".to_string(), json!(server));
-            if let Some(c) = count {
-                args.insert("count".to_string(), json!(c));
-            }
-            ("get_recent_preprints", args)
+            args.insert("query".to_string(), json!(query));
+            args.insert("page".to_string(), json!(page));
+            args.insert("limit".to_string(), json!(limit));
+            ("search_images", args)
         }
-        BiorxivTools::DateRange {
-            server,
-            start_date,
-            end_date,
+        DockerhubTools::Tags {
+            image,
+            filter,
+            page,
+            limit,
         } => {
             let mut args = Map::new();
-            args.insert("server".to_string(), json!(server));
-            args.insert("start_date".to_string(), json!(start_date));
-            args.insert("end_date".to_string(), json!(end_date));
-            ("get_preprints_by_date", args)
+            args.insert("image".to_string(), json!(image));
+            if let Some(f) = filter {
+                args.insert("filter".to_string(), json!(f));
+            }
+            args.insert("page".to_string(), json!(page));
+            args.insert("limit".to_string(), json!(limit));
+            ("list_tags", args)
         }
-        BiorxivTools::Paper { server, doi } => {
+        DockerhubTools::Info { image } => {
             let mut args = Map::new();
-            args.insert("server".to_string(), json!(server));
-            args.insert("doi".to_string(), json!(doi));
-            ("get_preprint_by_doi", args)
+            args.insert("image".to_string(), json!(image));
+            ("get_repository", args)
+        }
+        DockerhubTools::Dockerfile { image, platform } => {
+            let mut args = Map::new();
+            args.insert("image".to_string(), json!(image));
+            if let Some(p) = platform {
+                args.insert("platform".to_string(), json!(p));
+            }
+            ("get_dockerfile", args)
         }
     };
 
-    call_tool(cli, "biorxiv", tool_name, args).await
+    call_tool(cli, "dockerhub", tool_name, args).await
 }
 
 /// Handle Sci-Hub commands
 pub async fn handle_scihub(cli: &Cli, tool: ScihubTools) -> Result<()> {
     let (tool_name, args) = match tool {
-        ScihubTools::Paper { doi } => {
+        ScihubTools::Paper {
+            doi,
+            text,
+            sections,
+            max_chars,
+            mirror,
+        } => {
             let mut args = Map::new();
             args.insert("doi".to_string(), json!(doi));
+            if text {
+                args.insert("extract_text".to_string(), json!(true));
+            }
+            if sections {
+                args.insert("sections".to_string(), json!(true));
+            }
+            if let Some(n) = max_chars {
+                args.insert("max_chars".to_string(), json!(n));
+            }
+            if let Some(m) = mirror {
+                args.insert("mirror".to_string(), json!(m));
+            }
             ("get", args)
         }
+        ScihubTools::Mirrors { discover } => {
+            let mut args = Map::new();
+            args.insert("discover".to_string(), json!(discover));
+            ("check_mirrors", args)
+        }
     };
 
     call_tool(cli, "scihub", tool_name, args).await
@@ -2064,16 +5411,86 @@ pub async fn handle_macos(cli: &Cli, tool: MacosTools) -> Result<()> {
             args.insert("text".to_string(), json!(text));
             ("set_clipboard", args)
         }
-        MacosTools::Shortcut { name, input } => {
+        MacosTools::Shortcut {
+            name,
+            input,
+            input_path,
+            output_type,
+            timeout,
+        } => {
             let mut args = Map::new();
             args.insert("name".to_string(), json!(name));
-            if let Some(ref i) = input {
-                if let Ok(parsed) = serde_json::from_str::<Value>(i) {
-                    args.insert("input".to_string(), parsed);
-                }
+            if let Some(i) = input {
+                // JSON when it parses, otherwise plain text
+                let value = serde_json::from_str::<Value>(&i).unwrap_or(Value::String(i));
+                args.insert("input".to_string(), value);
+            }
+            if let Some(p) = input_path {
+                args.insert("input_path".to_string(), json!(p));
+            }
+            if let Some(t) = output_type {
+                args.insert("output_type".to_string(), json!(t));
+            }
+            if let Some(t) = timeout {
+                args.insert("timeout_seconds".to_string(), json!(t));
             }
             ("run_shortcut", args)
         }
+        MacosTools::Shortcuts { folder } => {
+            let mut args = Map::new();
+            if let Some(f) = folder {
+                args.insert("folder".to_string(), json!(f));
+            }
+            ("list_shortcuts", args)
+        }
+        MacosTools::Notes {
+            folder,
+            account,
+            query,
+            limit,
+        } => {
+            let mut args = Map::new();
+            if let Some(f) = folder {
+                args.insert("folder".to_string(), json!(f));
+            }
+            if let Some(a) = account {
+                args.insert("account".to_string(), json!(a));
+            }
+            if let Some(q) = query {
+                args.insert("query".to_string(), json!(q));
+            }
+            if let Some(l) = limit {
+                args.insert("limit".to_string(), json!(l));
+            }
+            ("list_notes", args)
+        }
+        MacosTools::Note { id, max_chars } => {
+            let mut args = Map::new();
+            args.insert("id".to_string(), json!(id));
+            if let Some(m) = max_chars {
+                args.insert("max_chars".to_string(), json!(m));
+            }
+            ("get_note", args)
+        }
+        MacosTools::NewNote {
+            title,
+            body,
+            folder,
+            account,
+        } => {
+            let mut args = Map::new();
+            args.insert("title".to_string(), json!(title));
+            if let Some(b) = body {
+                args.insert("body".to_string(), json!(b));
+            }
+            if let Some(f) = folder {
+                args.insert("folder".to_string(), json!(f));
+            }
+            if let Some(a) = account {
+                args.insert("account".to_string(), json!(a));
+            }
+            ("create_note", args)
+        }
     };
 
     call_tool(cli, "macos", tool_name, args).await
@@ -2146,9 +5563,46 @@ pub async fn handle_spotlight(cli: &Cli, tool: SpotlightTools) -> Result<()> {
             args.insert("limit".to_string(), json!(limit));
             ("search", args)
         }
-        SpotlightTools::Metadata { path } => {
+        SpotlightTools::Filter {
+            query,
+            kind,
+            author,
+            tags,
+            created_since,
+            created_until,
+            modified_since,
+            modified_until,
+            directory,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("mode".to_string(), json!("filter"));
+            for (key, value) in [
+                ("query", query),
+                ("kind", kind),
+                ("author", author),
+                ("created_since", created_since),
+                ("created_until", created_until),
+                ("modified_since", modified_since),
+                ("modified_until", modified_until),
+                ("directory", directory),
+            ] {
+                if let Some(v) = value {
+                    args.insert(key.to_string(), json!(v));
+                }
+            }
+            if !tags.is_empty() {
+                args.insert("tags".to_string(), json!(tags));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("search", args)
+        }
+        SpotlightTools::Metadata { path, attributes } => {
             let mut args = Map::new();
             args.insert("path".to_string(), json!(path));
+            if !attributes.is_empty() {
+                args.insert("attributes".to_string(), json!(attributes));
+            }
             ("get_metadata", args)
         }
         SpotlightTools::RawQuery {
@@ -2169,3 +5623,185 @@ pub async fn handle_spotlight(cli: &Cli, tool: SpotlightTools) -> Result<()> {
 
     call_tool(cli, "spotlight", tool_name, args).await
 }
+
+/// Handle Linux desktop search commands
+pub async fn handle_tracker(cli: &Cli, tool: TrackerTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        TrackerTools::SearchContent {
+            query,
+            directory,
+            kind,
+            mime,
+            backend,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("mode".to_string(), json!("content"));
+            args.insert("query".to_string(), json!(query));
+            if let Some(d) = directory {
+                args.insert("directory".to_string(), json!(d));
+            }
+            if let Some(k) = kind {
+                args.insert("kind".to_string(), json!(k));
+            }
+            if !mime.is_empty() {
+                args.insert("mime".to_string(), json!(mime));
+            }
+            if let Some(b) = backend {
+                args.insert("backend".to_string(), json!(b));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("search", args)
+        }
+        TrackerTools::SearchByName {
+            name,
+            directory,
+            backend,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("mode".to_string(), json!("name"));
+            args.insert("query".to_string(), json!(name));
+            if let Some(d) = directory {
+                args.insert("directory".to_string(), json!(d));
+            }
+            if let Some(b) = backend {
+                args.insert("backend".to_string(), json!(b));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("search", args)
+        }
+        TrackerTools::SearchByKind {
+            kind,
+            directory,
+            backend,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("mode".to_string(), json!("kind"));
+            args.insert("kind".to_string(), json!(kind));
+            if let Some(d) = directory {
+                args.insert("directory".to_string(), json!(d));
+            }
+            if let Some(b) = backend {
+                args.insert("backend".to_string(), json!(b));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("search", args)
+        }
+        TrackerTools::SearchRecent {
+            days,
+            kind,
+            directory,
+            backend,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("mode".to_string(), json!("recent"));
+            args.insert("days".to_string(), json!(days));
+            if let Some(k) = kind {
+                args.insert("kind".to_string(), json!(k));
+            }
+            if let Some(d) = directory {
+                args.insert("directory".to_string(), json!(d));
+            }
+            if let Some(b) = backend {
+                args.insert("backend".to_string(), json!(b));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("search", args)
+        }
+        TrackerTools::Metadata { path } => {
+            let mut args = Map::new();
+            args.insert("path".to_string(), json!(path));
+            ("get_metadata", args)
+        }
+        TrackerTools::RawQuery {
+            query,
+            backend,
+            limit,
+        } => {
+            let mut args = Map::new();
+            args.insert("mode".to_string(), json!("raw"));
+            args.insert("query".to_string(), json!(query));
+            if let Some(b) = backend {
+                args.insert("backend".to_string(), json!(b));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("search", args)
+        }
+    };
+
+    call_tool(cli, "tracker", tool_name, args).await
+}
+
+/// Handle browser history commands
+pub async fn handle_browser_history(cli: &Cli, tool: BrowserHistoryTools) -> Result<()> {
+    let (tool_name, args) = match tool {
+        BrowserHistoryTools::Browsers => ("list_browsers", Map::new()),
+        BrowserHistoryTools::Search {
+            query,
+            browser,
+            profile,
+            since,
+            until,
+            date_preset,
+            domains,
+            limit,
+        } => {
+            let mut args = Map::new();
+            if let Some(v) = query {
+                args.insert("query".to_string(), json!(v));
+            }
+            if let Some(v) = browser {
+                args.insert("browser".to_string(), json!(v));
+            }
+            if let Some(v) = profile {
+                args.insert("profile".to_string(), json!(v));
+            }
+            if let Some(v) = since {
+                args.insert("since".to_string(), json!(v));
+            }
+            if let Some(v) = until {
+                args.insert("until".to_string(), json!(v));
+            }
+            if let Some(v) = date_preset {
+                args.insert("date_preset".to_string(), json!(v));
+            }
+            if !domains.is_empty() {
+                args.insert("include_domains".to_string(), json!(domains));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("search_history", args)
+        }
+        BrowserHistoryTools::Top {
+            browser,
+            profile,
+            since,
+            until,
+            date_preset,
+            limit,
+        } => {
+            let mut args = Map::new();
+            if let Some(v) = browser {
+                args.insert("browser".to_string(), json!(v));
+            }
+            if let Some(v) = profile {
+                args.insert("profile".to_string(), json!(v));
+            }
+            if let Some(v) = since {
+                args.insert("since".to_string(), json!(v));
+            }
+            if let Some(v) = until {
+                args.insert("until".to_string(), json!(v));
+            }
+            if let Some(v) = date_preset {
+                args.insert("date_preset".to_string(), json!(v));
+            }
+            args.insert("limit".to_string(), json!(limit));
+            ("top_sites", args)
+        }
+    };
+
+    call_tool(cli, "browser-history", tool_name, args).await
+}
@@ -0,0 +1,140 @@
+use crate::cli::{Cli, ExportFormat};
+use crate::commands::{CommandError, Result};
+use arivu_core::document::Document;
+use arivu_core::export::{chunk_document, write_jsonl, ChunkOptions, RagChunk};
+use owo_colors::OwoColorize;
+use serde_json::Value;
+use std::io::{BufWriter, Read};
+use std::path::Path;
+
+/// Run the export command - chunk documents and write them for RAG ingestion
+pub async fn run(
+    cli: &Cli,
+    format: ExportFormat,
+    inputs: &[String],
+    out: Option<&Path>,
+    chunk_size: usize,
+    overlap: usize,
+    source: Option<&str>,
+) -> Result<()> {
+    let options = ChunkOptions::new(chunk_size, overlap)?;
+    let mut documents = if inputs.is_empty() {
+        index_documents()?
+    } else {
+        let mut documents = Vec::new();
+        for input in inputs {
+            documents.extend(read_documents(input)?);
+        }
+        documents
+    };
+    if let Some(source) = source {
+        documents.retain(|(_, doc)| doc.source == source);
+    }
+
+    let chunks: Vec<RagChunk> = match format {
+        ExportFormat::RagJsonl => documents
+            .iter()
+            .flat_map(|(doc_id, doc)| chunk_document(doc, doc_id, &options))
+            .collect(),
+    };
+
+    match out {
+        Some(path) => {
+            let file = std::fs::File::create(path)?;
+            write_jsonl(&chunks, BufWriter::new(file))?;
+            if matches!(cli.output, crate::cli::OutputFormat::Pretty) {
+                println!(
+                    "{} {} chunks from {} documents to {}",
+                    "Exported".green().bold(),
+                    chunks.len(),
+                    documents.len(),
+                    path.display().to_string().cyan()
+                );
+            }
+        }
+        None => {
+            write_jsonl(&chunks, std::io::stdout().lock())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Documents in a JSON file (`-` for stdin): one object, an array of them, or JSON Lines.
+/// Each is paired with its key, or `<input>:<n>` when it has none.
+fn read_documents(input: &str) -> Result<Vec<(String, Document)>> {
+    let mut text = String::new();
+    if input == "-" {
+        std::io::stdin().read_to_string(&mut text)?;
+    } else {
+        text = std::fs::read_to_string(input)
+            .map_err(|e| CommandError::InvalidInput(format!("Cannot read '{}': {}", input, e)))?;
+    }
+
+    let values: Vec<Value> = match serde_json::from_str::<Value>(&text) {
+        Ok(Value::Array(items)) => items,
+        Ok(value) => vec![value],
+        Err(_) => text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str::<Value>)
+            .collect::<std::result::Result<_, _>>()
+            .map_err(|_| {
+                CommandError::InvalidInput(format!("'{}' is not JSON or JSON Lines", input))
+            })?,
+    };
+
+    Ok(values
+        .iter()
+        .enumerate()
+        .map(|(n, value)| {
+            let doc = to_document(value);
+            let doc_id = doc.key().unwrap_or_else(|| format!("{}:{}", input, n));
+            (doc_id, doc)
+        })
+        .collect())
+}
+
+/// Read `arivu fetch --document` output as is; map anything else from its connector's shape.
+fn to_document(value: &Value) -> Document {
+    let is_document = value.get("source").is_some_and(Value::is_string)
+        && value.get("text").is_some_and(Value::is_string);
+    if is_document {
+        if let Ok(doc) = serde_json::from_value::<Document>(value.clone()) {
+            return doc;
+        }
+    }
+    // `arivu fetch` says which connector answered
+    let source = value
+        .pointer("/served_by/connector")
+        .or_else(|| value.pointer("/resolved/connector"))
+        .and_then(Value::as_str)
+        .unwrap_or("file");
+    Document::from_tool_output(source, value)
+}
+
+/// Every entry of the local index, in key order.
+#[cfg(feature = "index")]
+fn index_documents() -> Result<Vec<(String, Document)>> {
+    let index = arivu_core::index::ContentIndex::shared()?;
+    let mut documents: Vec<(String, Document)> = index
+        .entries()?
+        .into_iter()
+        .map(|entry| {
+            let mut doc = Document::new(entry.source, entry.title).with_text(entry.text);
+            doc.url = entry.url;
+            (entry.key, doc)
+        })
+        .collect();
+    documents.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(documents)
+}
+
+#[cfg(not(feature = "index"))]
+fn index_documents() -> Result<Vec<(String, Document)>> {
+    Err(CommandError::InvalidInput(
+        "No inputs given. Pass JSON from `arivu fetch --output json`, or build with \
+         --features index to export the local index"
+            .to_string(),
+    ))
+}
@@ -1,14 +1,42 @@
 use crate::cli::Cli;
 use crate::commands::{copy_to_clipboard, CommandError, Result};
 use crate::output::{format_output, OutputData};
-use arivu_core::resolver::{PatternInfo, ResolvedAction, SmartResolver};
-use arivu_core::CallToolRequestParam;
+use arivu_core::document::Document;
+use arivu_core::fetch_fallback::{
+    is_public_url, parse_chain, wayback_snapshot, FetchSource, ResponseCache, SEARCH_COPY_PROVIDERS,
+};
+use arivu_core::language::{process_document, TranslationBackend};
+use arivu_core::resolver::{extract_doi, PatternInfo, ResolvedAction, SmartResolver};
+use arivu_core::{CallToolRequestParam, ProviderRegistry};
 use owo_colors::OwoColorize;
 use serde_json::json;
 use std::io::{self, Write};
+use std::time::Duration;
 
 /// Run the fetch command - auto-detect input type and fetch content
-pub async fn run(cli: &Cli, input: &str) -> Result<()> {
+pub async fn run(
+    cli: &Cli,
+    input: &str,
+    fallback: &str,
+    max_age_hours: Option<u64>,
+    document: bool,
+    translate: Option<&str>,
+) -> Result<()> {
+    let chain = parse_chain(fallback).map_err(CommandError::InvalidInput)?;
+    // Fail before fetching when a translation can't happen
+    let translation = match translate.map(str::trim).filter(|t| !t.is_empty()) {
+        Some(target) => match TranslationBackend::from_env()? {
+            Some(backend) => Some((backend, target)),
+            None => {
+                return Err(CommandError::InvalidConfig(
+                    "--translate needs ARIVU_TRANSLATE_BACKEND (deepl or openai); see `arivu fetch --help`"
+                        .to_string(),
+                ))
+            }
+        },
+        None => None,
+    };
+    let max_age = max_age_hours.map(|h| Duration::from_secs(h * 3600));
     let resolver = SmartResolver::new();
 
     // Get all possible matches
@@ -65,7 +93,10 @@ pub async fn run(cli: &Cli, input: &str) -> Result<()> {
     }
 
     // Execute the action
-    execute_action(cli, &action).await
+    let translation = translation
+        .as_ref()
+        .map(|(backend, target)| (backend, *target));
+    execute_action(cli, &action, input, &chain, max_age, document, translation).await
 }
 
 /// Filter out low-priority matches when there's a clear winner
@@ -138,94 +169,89 @@ fn select_action(cli: &Cli, input: &str, actions: Vec<ResolvedAction>) -> Result
     }
 }
 
-/// Execute a resolved action against the registry
-async fn execute_action(cli: &Cli, action: &ResolvedAction) -> Result<()> {
+/// Execute a resolved action, walking the fallback chain until a source returns content
+async fn execute_action(
+    cli: &Cli,
+    action: &ResolvedAction,
+    input: &str,
+    chain: &[FetchSource],
+    max_age: Option<Duration>,
+    document: bool,
+    translation: Option<(&TranslationBackend, &str)>,
+) -> Result<()> {
     let registry = crate::commands::list::create_registry().await?;
+    let cache = ResponseCache::new_default();
+    let pretty = cli.output == crate::cli::OutputFormat::Pretty;
 
-    // Check if connector exists
-    let provider = registry
-        .get_provider(&action.connector)
-        .ok_or_else(|| CommandError::ConnectorNotFound(action.connector.clone()))?;
-
-    let connector = provider.lock().await;
+    let mut attempts: Vec<serde_json::Value> = Vec::new();
+    let mut live_error: Option<CommandError> = None;
+    let mut served = None;
 
-    // Build the tool request
-    // Convert string values to integers for parameters that are typically numeric IDs
-    // but keep other string values as-is (e.g., pmid for pubmed should stay as string)
-    let arguments = if action.arguments.is_empty() {
-        None
-    } else {
-        let mut args = serde_json::Map::new();
-        for (key, value) in &action.arguments {
-            // Only convert to integer for specific parameter names that connectors expect as numbers
-            // Keep pmid, paper_id, video_id etc. as strings since those connectors expect strings
-            let should_convert_to_int =
-                key == "id" || key == "number" || key == "item_id" || key == "channel_id";
-
-            if should_convert_to_int {
-                if let serde_json::Value::String(s) = value {
-                    if let Ok(num) = s.parse::<i64>() {
-                        args.insert(key.clone(), serde_json::Value::Number(num.into()));
-                        continue;
-                    }
+    for &source in chain {
+        if source.needs_url() && !is_public_url(input) {
+            continue;
+        }
+        let outcome = match source {
+            FetchSource::Live => call_tool(
+                &registry,
+                &action.connector,
+                &action.tool,
+                action.tool_arguments(),
+            )
+            .await
+            .map(|content| (content, json!({ "source": "live" }))),
+            FetchSource::Cache => match cache.get(input, max_age) {
+                Some(hit) => Ok((
+                    hit.content.clone(),
+                    json!({
+                        "source": "cache",
+                        "connector": hit.connector,
+                        "tool": hit.tool,
+                        "stored_at": hit.stored_at,
+                        "age_seconds": hit.age().as_secs(),
+                    }),
+                )),
+                None => Err(CommandError::ToolError(
+                    "no cached response for this input".to_string(),
+                )),
+            },
+            FetchSource::Wayback => wayback_copy(&registry, input).await,
+            FetchSource::Search => search_copy(&registry, input).await,
+        };
+
+        match outcome {
+            Ok((content, served_by)) => {
+                // Keep the live result around for the next time the source is unreachable
+                if source == FetchSource::Live && chain.contains(&FetchSource::Cache) {
+                    let _ = cache.put(input, &action.connector, &action.tool, &content);
                 }
+                served = Some((source, content, served_by));
+                break;
             }
-            args.insert(key.clone(), value.clone());
-        }
-        Some(args)
-    };
-
-    let request = CallToolRequestParam {
-        name: action.tool.clone().into(),
-        arguments,
-    };
-
-    // Call the tool
-    match connector.call_tool(request).await {
-        Ok(result) => {
-            // Prefer structured_content if present (most connectors use this)
-            let (output, json_value) = if let Some(sc) = result.structured_content {
-                (OutputData::ToolResult(sc.clone()), sc)
-            } else {
-                // Fall back to extracting text content from result.content
-                let text_content: Vec<String> = result
-                    .content
-                    .iter()
-                    .filter_map(|c| {
-                        if let arivu_core::RawContent::Text(t) = &c.raw {
-                            Some(t.text.clone())
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-
-                let combined = text_content.join("\n");
-
-                // Try to parse as JSON for pretty output
-                if let Ok(json_val) = serde_json::from_str::<serde_json::Value>(&combined) {
-                    (OutputData::ToolResult(json_val.clone()), json_val)
-                } else {
-                    let val = json!({ "content": combined });
-                    (OutputData::ToolResult(val.clone()), val)
+            Err(e) => {
+                if pretty && chain.len() > 1 {
+                    println!(
+                        "  {} {} failed: {}",
+                        "Fallback:".yellow(),
+                        source.as_str(),
+                        e.to_string().dimmed()
+                    );
+                }
+                attempts.push(json!({ "source": source.as_str(), "error": e.to_string() }));
+                if source == FetchSource::Live {
+                    live_error = Some(e);
                 }
-            };
-
-            format_output(&output, &cli.output)?;
-
-            // Copy to clipboard if requested
-            if cli.copy {
-                let text = serde_json::to_string_pretty(&json_value)?;
-                copy_to_clipboard(&text)?;
             }
         }
-        Err(e) => {
-            let error_str = e.to_string();
+    }
 
+    let Some((source, mut json_value, mut served_by)) = served else {
+        if let Some(e) = &live_error {
+            let error_str = e.to_string().to_lowercase();
             // Check for auth errors
-            if error_str.to_lowercase().contains("auth")
-                || error_str.to_lowercase().contains("token")
-                || error_str.to_lowercase().contains("credential")
+            if error_str.contains("auth")
+                || error_str.contains("token")
+                || error_str.contains("credential")
             {
                 println!();
                 println!(
@@ -239,15 +265,243 @@ async fn execute_action(cli: &Cli, action: &ResolvedAction) -> Result<()> {
                     format!("arivu setup {}", action.connector).cyan()
                 );
                 println!();
-            } else {
-                return Err(CommandError::ToolError(error_str));
+                return Ok(());
+            }
+        }
+        if attempts.len() == 1 {
+            if let Some(e) = live_error {
+                return Err(e);
             }
         }
+        let summary: Vec<String> = attempts
+            .iter()
+            .map(|a| {
+                format!(
+                    "{}: {}",
+                    a["source"].as_str().unwrap_or_default(),
+                    a["error"].as_str().unwrap_or_default()
+                )
+            })
+            .collect();
+        return Err(CommandError::ToolError(if summary.is_empty() {
+            "No fallback source applies to this input".to_string()
+        } else {
+            format!("All sources failed ({})", summary.join("; "))
+        }));
+    };
+
+    if pretty && source != FetchSource::Live {
+        println!(
+            "  {} {}",
+            "Served by:".bold().cyan(),
+            source.as_str().green()
+        );
+        println!();
+    }
+
+    // Any DOI in the input gets Crossref metadata attached, whichever connector handled it
+    if action.connector != "crossref" {
+        if let Some(doi) = extract_doi(input) {
+            if let (Some(meta), Some(obj)) = (
+                crossref_metadata(&registry, &doi).await,
+                json_value.as_object_mut(),
+            ) {
+                obj.insert("crossref".to_string(), meta);
+            }
+        }
+    }
+
+    // Content from a cache hit or a search copy was produced by a different connector
+    let producer = served_by
+        .get("connector")
+        .and_then(|c| c.as_str())
+        .unwrap_or(&action.connector);
+    let mut doc = Document::from_tool_output(producer, &json_value);
+    // A failed translation still leaves the fetched content worth returning
+    let translation_error = process_document(&mut doc, translation).await.err();
+    if let Some(e) = &translation_error {
+        if pretty {
+            println!(
+                "  {} {}",
+                "Translation failed:".yellow(),
+                e.to_string().dimmed()
+            );
+            println!();
+        }
+    }
+    if document {
+        json_value = serde_json::to_value(&doc)?;
+    } else if let Some(obj) = json_value.as_object_mut() {
+        if let Some(language) = &doc.language {
+            obj.insert("language".to_string(), serde_json::to_value(language)?);
+        }
+        if let Some(translated) = &doc.translation {
+            obj.insert("translation".to_string(), serde_json::to_value(translated)?);
+        }
+    }
+    if let (Some(e), Some(obj)) = (translation_error, json_value.as_object_mut()) {
+        obj.insert("translation_error".to_string(), json!(e.to_string()));
+    }
+
+    // Say where the input was routed and which source answered so scripts and agents can tell what ran
+    let resolved = json!({
+        "connector": action.connector,
+        "tool": action.tool,
+        "pattern": action.pattern,
+        "description": action.description,
+        "confidence": action.confidence,
+    });
+    if !attempts.is_empty() {
+        served_by["failed"] = json!(attempts);
+    }
+    match json_value.as_object_mut() {
+        Some(obj) => {
+            obj.insert("resolved".to_string(), resolved);
+            obj.insert("served_by".to_string(), served_by);
+        }
+        None => {
+            json_value =
+                json!({ "result": json_value, "resolved": resolved, "served_by": served_by })
+        }
+    }
+
+    let output = OutputData::ToolResult(json_value.clone());
+    format_output(&output, &cli.output)?;
+
+    // Copy to clipboard if requested
+    if cli.copy {
+        let text = serde_json::to_string_pretty(&json_value)?;
+        copy_to_clipboard(&text)?;
     }
 
     Ok(())
 }
 
+/// Call a connector tool and return its result as JSON
+async fn call_tool(
+    registry: &ProviderRegistry,
+    connector_name: &str,
+    tool: &str,
+    arguments: Option<serde_json::Map<String, serde_json::Value>>,
+) -> Result<serde_json::Value> {
+    let provider = registry
+        .get_provider(connector_name)
+        .ok_or_else(|| CommandError::ConnectorNotFound(connector_name.to_string()))?;
+
+    let request = CallToolRequestParam {
+        name: tool.to_string().into(),
+        arguments,
+    };
+    let result = arivu_core::call_locked_connector(provider, request)
+        .await
+        .map_err(|e| CommandError::ToolError(e.to_string()))?;
+
+    // Prefer structured_content if present (most connectors use this)
+    if let Some(sc) = result.structured_content {
+        return Ok(sc);
+    }
+
+    // Fall back to extracting text content from result.content
+    let text_content: Vec<String> = result
+        .content
+        .iter()
+        .filter_map(|c| {
+            if let arivu_core::RawContent::Text(t) = &c.raw {
+                Some(t.text.clone())
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let combined = text_content.join("\n");
+
+    // Try to parse as JSON for pretty output
+    Ok(serde_json::from_str::<serde_json::Value>(&combined)
+        .unwrap_or_else(|_| json!({ "content": combined })))
+}
+
+/// Scrape the closest Wayback Machine snapshot of a URL with the web connector
+async fn wayback_copy(
+    registry: &ProviderRegistry,
+    url: &str,
+) -> Result<(serde_json::Value, serde_json::Value)> {
+    let snapshot = wayback_snapshot(url)
+        .await?
+        .ok_or_else(|| CommandError::ToolError("no Wayback snapshot available".to_string()))?;
+
+    let mut args = serde_json::Map::new();
+    args.insert("url".to_string(), json!(snapshot.raw_url));
+    let content = call_tool(registry, "web", "scrape_url", Some(args)).await?;
+
+    Ok((
+        content,
+        json!({
+            "source": "wayback",
+            "connector": "web",
+            "snapshot_url": snapshot.url,
+            "timestamp": snapshot.timestamp,
+        }),
+    ))
+}
+
+/// Ask the first configured search provider for its crawled copy of a URL
+async fn search_copy(
+    registry: &ProviderRegistry,
+    url: &str,
+) -> Result<(serde_json::Value, serde_json::Value)> {
+    let mut last_error = None;
+    for &(provider, tool) in SEARCH_COPY_PROVIDERS {
+        if registry.get_provider(provider).is_none() {
+            continue;
+        }
+        let mut args = serde_json::Map::new();
+        args.insert("ids".to_string(), json!([url]));
+        args.insert("text".to_string(), json!(true));
+        match call_tool(registry, provider, tool, Some(args)).await {
+            Ok(content) => {
+                let copy = content
+                    .get("results")
+                    .and_then(|r| r.as_array())
+                    .and_then(|r| r.first())
+                    .cloned();
+                match copy {
+                    Some(copy) => {
+                        return Ok((copy, json!({ "source": "search", "connector": provider })))
+                    }
+                    None => {
+                        last_error = Some(CommandError::ToolError(format!(
+                            "{} has no copy of this URL",
+                            provider
+                        )))
+                    }
+                }
+            }
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(last_error.unwrap_or_else(|| {
+        CommandError::ToolError("no search provider with page copies is configured".to_string())
+    }))
+}
+
+/// Best-effort Crossref lookup used to enrich DOI inputs; failures are silently ignored.
+async fn crossref_metadata(registry: &ProviderRegistry, doi: &str) -> Option<serde_json::Value> {
+    let provider = registry.get_provider("crossref")?;
+
+    let mut args = serde_json::Map::new();
+    args.insert("doi".to_string(), json!(doi));
+    let request = CallToolRequestParam {
+        name: "get_work".into(),
+        arguments: Some(args),
+    };
+
+    arivu_core::call_locked_connector(provider, request)
+        .await
+        .ok()?
+        .structured_content
+}
+
 /// Show all supported formats/patterns
 pub async fn show_formats(cli: &Cli) -> Result<()> {
     let resolver = SmartResolver::new();
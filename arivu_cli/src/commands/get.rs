@@ -23,7 +23,6 @@ pub async fn run(cli: &Cli, connector_name: &str, id: &str) -> Result<()> {
         .ok_or_else(|| CommandError::ConnectorNotFound(connector_name.to_string()))?
         .clone();
 
-    let c = provider.lock().await;
     let (tool_name, arguments) = generic_get_tool_and_args(connector_name, id)?;
 
     let request = CallToolRequestParam {
@@ -31,7 +30,7 @@ pub async fn run(cli: &Cli, connector_name: &str, id: &str) -> Result<()> {
         arguments: Some(arguments),
     };
 
-    let response = c.call_tool(request).await?;
+    let response = arivu_core::call_locked_connector(&provider, request).await?;
     spinner.finish_and_clear();
 
     // Extract response data
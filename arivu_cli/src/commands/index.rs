@@ -0,0 +1,230 @@
+use crate::cli::{Cli, IndexAction};
+use crate::commands::{CommandError, Result};
+
+/// Run an index command - search, inspect or clear the local index
+#[cfg(feature = "index")]
+pub async fn run(cli: &Cli, action: IndexAction) -> Result<()> {
+    use crate::commands::copy_to_clipboard;
+    use crate::output::{format_output, OutputData};
+    use arivu_core::index::{ContentIndex, IndexConfig};
+    use owo_colors::OwoColorize;
+
+    let index = ContentIndex::shared()?;
+    match action {
+        IndexAction::Search {
+            query,
+            source,
+            limit,
+        } => {
+            let query = query.trim();
+            if query.is_empty() {
+                return Err(CommandError::InvalidInput(
+                    "Query cannot be empty".to_string(),
+                ));
+            }
+            let found = index.search(query, source.as_deref(), limit)?;
+            let found_json = serde_json::to_value(&found)?;
+
+            match cli.output {
+                crate::cli::OutputFormat::Pretty => format_pretty_hits(&found),
+                _ => format_output(&OutputData::ToolResult(found_json.clone()), &cli.output)?,
+            }
+
+            if cli.copy {
+                let text = serde_json::to_string_pretty(&found_json)?;
+                copy_to_clipboard(&text)?;
+            }
+        }
+        IndexAction::Semantic {
+            query,
+            source,
+            limit,
+        } => {
+            let query = query.trim();
+            if query.is_empty() {
+                return Err(CommandError::InvalidInput(
+                    "Query cannot be empty".to_string(),
+                ));
+            }
+            semantic(cli, index, query, source.as_deref(), limit).await?;
+        }
+        IndexAction::Stats => {
+            let config = IndexConfig::from_env();
+            let documents = index.num_docs()?;
+            match cli.output {
+                crate::cli::OutputFormat::Pretty => {
+                    println!("{} {}", "Index:".bold().cyan(), index.dir().display());
+                    println!("{} {}", "Entries:".bold(), documents);
+                    let ingest = if config.auto_ingest {
+                        "on".green().to_string()
+                    } else {
+                        "off (set ARIVU_INDEX=on to add tool results)"
+                            .dimmed()
+                            .to_string()
+                    };
+                    println!("{} {}", "Auto-ingest:".bold(), ingest);
+                }
+                _ => {
+                    let stats = serde_json::json!({
+                        "dir": index.dir(),
+                        "documents": documents,
+                        "auto_ingest": config.auto_ingest,
+                    });
+                    format_output(&OutputData::ToolResult(stats), &cli.output)?;
+                }
+            }
+        }
+        IndexAction::Clear => {
+            let documents = index.num_docs()?;
+            index.clear()?;
+            println!(
+                "{} {} entries from {}",
+                "Removed".green().bold(),
+                documents,
+                index.dir().display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Sync the vector store with the index, then search it
+#[cfg(feature = "embeddings")]
+async fn semantic(
+    cli: &Cli,
+    index: std::sync::Arc<arivu_core::index::ContentIndex>,
+    query: &str,
+    source: Option<&str>,
+    limit: usize,
+) -> Result<()> {
+    use crate::commands::copy_to_clipboard;
+    use crate::output::{format_output, OutputData};
+    use arivu_core::vector_store::{backend_from_env, semantic_search};
+    use indicatif::{ProgressBar, ProgressStyle};
+
+    let backend = backend_from_env()?;
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.green} {msg}")
+            .expect("Invalid progress template"),
+    );
+    spinner.set_message(format!("Embedding new entries with {}...", backend.name()));
+    spinner.enable_steady_tick(std::time::Duration::from_millis(100));
+    let found = semantic_search(index, &backend, query, source, limit).await;
+    spinner.finish_and_clear();
+    let found = found?;
+    let found_json = serde_json::to_value(&found)?;
+
+    match cli.output {
+        crate::cli::OutputFormat::Pretty => format_pretty_semantic(&found),
+        _ => format_output(&OutputData::ToolResult(found_json.clone()), &cli.output)?,
+    }
+
+    if cli.copy {
+        let text = serde_json::to_string_pretty(&found_json)?;
+        copy_to_clipboard(&text)?;
+    }
+    Ok(())
+}
+
+#[cfg(all(feature = "index", not(feature = "embeddings")))]
+async fn semantic(
+    _cli: &Cli,
+    _index: std::sync::Arc<arivu_core::index::ContentIndex>,
+    _query: &str,
+    _source: Option<&str>,
+    _limit: usize,
+) -> Result<()> {
+    Err(CommandError::InvalidConfig(
+        "Embeddings feature not enabled. Compile with --features embeddings".to_string(),
+    ))
+}
+
+#[cfg(not(feature = "index"))]
+pub async fn run(_cli: &Cli, _action: IndexAction) -> Result<()> {
+    Err(CommandError::InvalidConfig(
+        "Index feature not enabled. Compile with --features index".to_string(),
+    ))
+}
+
+/// Print hits with their source, link and matching passage.
+#[cfg(feature = "index")]
+fn format_pretty_hits(found: &arivu_core::index::IndexSearchResult) {
+    use owo_colors::OwoColorize;
+
+    println!(
+        "{} {} {}",
+        "Index:".bold().cyan(),
+        found.query.yellow(),
+        format!(
+            "({} of {} entries)",
+            found.hits.len(),
+            found.total_documents
+        )
+        .dimmed()
+    );
+    println!();
+
+    if found.hits.is_empty() {
+        println!("   {}", "No matches".dimmed());
+        return;
+    }
+    for (i, hit) in found.hits.iter().enumerate() {
+        println!(
+            " {:>3}. {} {}",
+            (i + 1).to_string().cyan().bold(),
+            hit.title.bold(),
+            format!("[{}]", hit.source).dimmed()
+        );
+        if let Some(ref url) = hit.url {
+            println!("      {}", url.blue());
+        }
+        if !hit.snippet.is_empty() {
+            println!("      {}", hit.snippet);
+        }
+        if let Some(ref at) = hit.indexed_at {
+            println!("      {}", format!("indexed {}", at).dimmed());
+        }
+        println!();
+    }
+}
+
+/// Print hits with their source, link, score and best passage.
+#[cfg(feature = "embeddings")]
+fn format_pretty_semantic(found: &arivu_core::vector_store::SemanticSearchResult) {
+    use owo_colors::OwoColorize;
+
+    println!(
+        "{} {} {}",
+        "Semantic:".bold().cyan(),
+        found.query.yellow(),
+        format!(
+            "({}, {} passages, {} newly embedded)",
+            found.model, found.total_passages, found.newly_embedded
+        )
+        .dimmed()
+    );
+    println!();
+
+    if found.hits.is_empty() {
+        println!("   {}", "No matches".dimmed());
+        return;
+    }
+    for (i, hit) in found.hits.iter().enumerate() {
+        println!(
+            " {:>3}. {} {} {}",
+            (i + 1).to_string().cyan().bold(),
+            hit.title.bold(),
+            format!("[{}]", hit.source).dimmed(),
+            format!("{:.3}", hit.score).dimmed()
+        );
+        if let Some(ref url) = hit.url {
+            println!("      {}", url.blue());
+        }
+        let passage: String = hit.passage.chars().take(300).collect();
+        println!("      {}", passage.replace('\n', " "));
+        println!();
+    }
+}
@@ -1,9 +1,12 @@
 pub mod config;
 pub mod connectors;
+pub mod export;
 pub mod fetch;
 pub mod get;
+pub mod index;
 pub mod list;
 pub mod pricing;
+pub mod research;
 pub mod search;
 pub mod setup;
 pub mod tool_mappings;
@@ -0,0 +1,139 @@
+use crate::cli::Cli;
+use crate::commands::usage_helpers::print_cost_summary;
+use crate::commands::{copy_to_clipboard, CommandError, Result};
+use crate::output::{format_output, OutputData};
+use arivu_core::research::{research, ResearchBundle, ResearchOptions};
+use indicatif::{ProgressBar, ProgressStyle};
+use owo_colors::OwoColorize;
+
+/// Run the research command - search, fetch and extract claims for a question
+pub async fn run(
+    cli: &Cli,
+    question: &str,
+    sources: Option<&str>,
+    limit: u32,
+    max_fetch: usize,
+    max_claims: usize,
+) -> Result<()> {
+    let question = question.trim();
+    if question.is_empty() {
+        return Err(CommandError::InvalidInput(
+            "Question cannot be empty".to_string(),
+        ));
+    }
+    let options = ResearchOptions {
+        sources: sources.map(|list| {
+            list.split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect()
+        }),
+        per_source_limit: limit,
+        max_fetch,
+        max_claims,
+    };
+
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.green} {msg}")
+            .expect("Invalid progress template"),
+    );
+    spinner.set_message(format!("Researching '{}'...", question));
+    spinner.enable_steady_tick(std::time::Duration::from_millis(100));
+
+    let registry = crate::commands::list::create_registry().await?;
+    let bundle = research(&registry, question, &options).await;
+
+    spinner.finish_and_clear();
+
+    let bundle_json =
+        serde_json::to_value(&bundle).map_err(|e| CommandError::Other(e.to_string()))?;
+
+    match cli.output {
+        crate::cli::OutputFormat::Pretty => format_pretty_bundle(&bundle),
+        _ => format_output(&OutputData::ToolResult(bundle_json.clone()), &cli.output)?,
+    }
+
+    if cli.copy {
+        let text = serde_json::to_string_pretty(&bundle_json)?;
+        copy_to_clipboard(&text)?;
+    }
+
+    print_cost_summary(&cli.output, None);
+
+    Ok(())
+}
+
+/// Print claims with their citations, then the numbered sources they cite.
+fn format_pretty_bundle(bundle: &ResearchBundle) {
+    println!("{} {}", "Research:".bold().cyan(), bundle.question.yellow());
+    for query in &bundle.queries {
+        println!(
+            "{} {} {}",
+            "Searched:".dimmed(),
+            query.connectors.join(", ").cyan(),
+            format!("for \"{}\"", query.query).dimmed()
+        );
+    }
+    println!();
+
+    println!("{}", "── Claims ──".green().bold());
+    println!();
+    if bundle.claims.is_empty() {
+        println!("   {}", "No claims matched the question".dimmed());
+        println!();
+    }
+    for (i, claim) in bundle.claims.iter().enumerate() {
+        println!(" {:>3}. {}", (i + 1).to_string().cyan().bold(), claim.text);
+        println!(
+            "      {}",
+            format!("[{}]", claim.sources.join(", ")).dimmed()
+        );
+        println!();
+    }
+
+    println!("{}", "── Sources ──".green().bold());
+    println!();
+    if bundle.sources.is_empty() {
+        println!("   {}", "No sources found".dimmed());
+        println!();
+    }
+    for source in &bundle.sources {
+        let mut found_in = vec![source.connector.as_str()];
+        found_in.extend(source.also_found_in.iter().map(String::as_str));
+        println!(
+            " {:>4} {} {}",
+            source.id.cyan().bold(),
+            source.title.bold(),
+            format!("[{}]", found_in.join(", ")).dimmed()
+        );
+        if let Some(ref url) = source.url {
+            println!("      {}", url.blue());
+        }
+        if let Some(ref tool) = source.fetched_with {
+            println!("      {}", format!("fetched with {}", tool).dimmed());
+        }
+        println!();
+    }
+
+    if !bundle.errors.is_empty() {
+        println!("{}", "⚠ Some searches or fetches failed:".yellow());
+        for err in &bundle.errors {
+            let timeout_marker = if err.is_timeout { " (timeout)" } else { "" };
+            println!(
+                "   {} {}: {}{}",
+                "•".dimmed(),
+                err.source.yellow(),
+                err.error.dimmed(),
+                timeout_marker.dimmed()
+            );
+        }
+        println!();
+    }
+
+    println!(
+        "{}",
+        format!("Completed in {}ms", bundle.duration_ms).dimmed()
+    );
+}
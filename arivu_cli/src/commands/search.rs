@@ -94,7 +94,6 @@ async fn run_single_search(cli: &Cli, connector_name: &str, query: &str, limit:
         .ok_or_else(|| CommandError::ConnectorNotFound(connector_name.to_string()))?
         .clone();
 
-    let c = provider.lock().await;
     let (tool_name, arguments) = generic_search_tool_and_args(connector_name, query, limit)?;
 
     // Prepare search request
@@ -103,7 +102,7 @@ async fn run_single_search(cli: &Cli, connector_name: &str, query: &str, limit:
         arguments: Some(arguments),
     };
 
-    let response = c.call_tool(request).await?;
+    let response = arivu_core::call_locked_connector(&provider, request).await?;
     spinner.finish_and_clear();
 
     // Extract response data
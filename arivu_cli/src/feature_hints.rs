@@ -34,6 +34,150 @@ pub fn hint_for_connector(name: &str) -> Option<ConnectorFeatureHint> {
             enabled: cfg!(feature = "biorxiv"),
             aliases: &[],
         },
+        ConnectorFeatureHint {
+            canonical: "crossref",
+            cargo_feature: "crossref",
+            enabled: cfg!(feature = "crossref"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "dblp",
+            cargo_feature: "dblp",
+            enabled: cfg!(feature = "dblp"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "market-data",
+            cargo_feature: "market-data",
+            enabled: cfg!(feature = "market-data"),
+            aliases: &["market_data", "stocks"],
+        },
+        ConnectorFeatureHint {
+            canonical: "coingecko",
+            cargo_feature: "coingecko",
+            enabled: cfg!(feature = "coingecko"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "openlibrary",
+            cargo_feature: "openlibrary",
+            enabled: cfg!(feature = "openlibrary"),
+            aliases: &["open-library"],
+        },
+        ConnectorFeatureHint {
+            canonical: "podcasts",
+            cargo_feature: "podcasts",
+            enabled: cfg!(feature = "podcasts"),
+            aliases: &["podcast"],
+        },
+        ConnectorFeatureHint {
+            canonical: "twitch",
+            cargo_feature: "twitch",
+            enabled: cfg!(feature = "twitch"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "vimeo",
+            cargo_feature: "vimeo",
+            enabled: cfg!(feature = "vimeo"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "salesforce",
+            cargo_feature: "salesforce",
+            enabled: cfg!(feature = "salesforce"),
+            aliases: &["sf"],
+        },
+        ConnectorFeatureHint {
+            canonical: "hubspot",
+            cargo_feature: "hubspot",
+            enabled: cfg!(feature = "hubspot"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "airtable",
+            cargo_feature: "airtable",
+            enabled: cfg!(feature = "airtable"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "box",
+            cargo_feature: "box",
+            enabled: cfg!(feature = "box"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "s3",
+            cargo_feature: "s3",
+            enabled: cfg!(feature = "s3"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "sql",
+            cargo_feature: "sql",
+            enabled: cfg!(feature = "sql"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "openapi",
+            cargo_feature: "openapi",
+            enabled: cfg!(feature = "openapi"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "graphql",
+            cargo_feature: "graphql",
+            enabled: cfg!(feature = "graphql"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "sentry",
+            cargo_feature: "sentry",
+            enabled: cfg!(feature = "sentry"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "pagerduty",
+            cargo_feature: "pagerduty",
+            enabled: cfg!(feature = "pagerduty"),
+            aliases: &["pd"],
+        },
+        ConnectorFeatureHint {
+            canonical: "confluence",
+            cargo_feature: "confluence",
+            enabled: cfg!(feature = "confluence"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "zoom",
+            cargo_feature: "zoom",
+            enabled: cfg!(feature = "zoom"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "crates-io",
+            cargo_feature: "crates-io",
+            enabled: cfg!(feature = "crates-io"),
+            aliases: &["crates_io", "crates"],
+        },
+        ConnectorFeatureHint {
+            canonical: "npm",
+            cargo_feature: "npm",
+            enabled: cfg!(feature = "npm"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "pypi",
+            cargo_feature: "pypi",
+            enabled: cfg!(feature = "pypi"),
+            aliases: &[],
+        },
+        ConnectorFeatureHint {
+            canonical: "dockerhub",
+            cargo_feature: "dockerhub",
+            enabled: cfg!(feature = "dockerhub"),
+            aliases: &["docker", "docker_hub"],
+        },
         ConnectorFeatureHint {
             canonical: "discord",
             cargo_feature: "discord",
@@ -64,12 +208,36 @@ pub fn hint_for_connector(name: &str) -> Option<ConnectorFeatureHint> {
             enabled: cfg!(feature = "imap"),
             aliases: &[],
         },
+        ConnectorFeatureHint {
+            canonical: "newsletters",
+            cargo_feature: "newsletters",
+            enabled: cfg!(feature = "newsletters"),
+            aliases: &["newsletter"],
+        },
+        ConnectorFeatureHint {
+            canonical: "inbox",
+            cargo_feature: "inbox",
+            enabled: cfg!(feature = "inbox"),
+            aliases: &["webhooks"],
+        },
         ConnectorFeatureHint {
             canonical: "localfs",
             cargo_feature: "localfs",
             enabled: cfg!(feature = "localfs"),
             aliases: &[],
         },
+        ConnectorFeatureHint {
+            canonical: "paper-library",
+            cargo_feature: "paper-library",
+            enabled: cfg!(feature = "paper-library"),
+            aliases: &["papers"],
+        },
+        ConnectorFeatureHint {
+            canonical: "browser-history",
+            cargo_feature: "browser-history",
+            enabled: cfg!(feature = "browser-history"),
+            aliases: &["history"],
+        },
         ConnectorFeatureHint {
             canonical: "pubmed",
             cargo_feature: "pubmed",
@@ -185,6 +353,18 @@ pub fn hint_for_connector(name: &str) -> Option<ConnectorFeatureHint> {
             enabled: cfg!(feature = "serpapi-search"),
             aliases: &[],
         },
+        ConnectorFeatureHint {
+            canonical: "brave-search",
+            cargo_feature: "brave-search",
+            enabled: cfg!(feature = "brave-search"),
+            aliases: &["brave"],
+        },
+        ConnectorFeatureHint {
+            canonical: "ddg-search",
+            cargo_feature: "ddg-search",
+            enabled: cfg!(feature = "ddg-search"),
+            aliases: &["ddg", "duckduckgo"],
+        },
         ConnectorFeatureHint {
             canonical: "firecrawl-search",
             cargo_feature: "firecrawl-search",
@@ -79,7 +79,60 @@ async fn main() {
                     .await
                 }
                 Some(Commands::Get { connector, id }) => get::run(&cli, connector, id).await,
-                Some(Commands::Fetch { input }) => fetch::run(&cli, input).await,
+                Some(Commands::Fetch {
+                    input,
+                    fallback,
+                    max_age,
+                    document,
+                    translate,
+                }) => {
+                    fetch::run(
+                        &cli,
+                        input,
+                        fallback,
+                        *max_age,
+                        *document,
+                        translate.as_deref(),
+                    )
+                    .await
+                }
+                Some(Commands::Research {
+                    question,
+                    sources,
+                    limit,
+                    max_fetch,
+                    max_claims,
+                }) => {
+                    research::run(
+                        &cli,
+                        question,
+                        sources.as_deref(),
+                        *limit,
+                        *max_fetch,
+                        *max_claims,
+                    )
+                    .await
+                }
+                Some(Commands::Index { action }) => index::run(&cli, action.clone()).await,
+                Some(Commands::Export {
+                    inputs,
+                    format,
+                    out,
+                    chunk_size,
+                    overlap,
+                    source,
+                }) => {
+                    export::run(
+                        &cli,
+                        *format,
+                        inputs,
+                        out.as_deref(),
+                        *chunk_size,
+                        *overlap,
+                        source.as_deref(),
+                    )
+                    .await
+                }
                 Some(Commands::Formats) => fetch::show_formats(&cli).await,
                 Some(Commands::Config { action }) => config::run(&cli, action.clone()).await,
                 Some(Commands::Connectors) => connectors::run(&cli).await,
@@ -161,6 +214,12 @@ async fn main() {
                 Some(Commands::ParallelSearch { tool }) => {
                     connectors::handle_parallel_search(&cli, tool.clone()).await
                 }
+                Some(Commands::BraveSearch { tool }) => {
+                    connectors::handle_brave_search(&cli, tool.clone()).await
+                }
+                Some(Commands::DdgSearch { tool }) => {
+                    connectors::handle_ddg_search(&cli, tool.clone()).await
+                }
 
                 // Productivity connectors
                 Some(Commands::Atlassian { tool }) => {
@@ -170,12 +229,21 @@ async fn main() {
                     connectors::handle_microsoft_graph(&cli, tool.clone()).await
                 }
                 Some(Commands::Imap { tool }) => connectors::handle_imap(&cli, tool.clone()).await,
+                Some(Commands::Newsletters { tool }) => {
+                    connectors::handle_newsletters(&cli, tool.clone()).await
+                }
+                Some(Commands::Inbox { tool }) => {
+                    connectors::handle_inbox(&cli, tool.clone()).await
+                }
 
                 // For now, other connectors fall back to the call command
                 // Connector-specific subcommands with proper CLI flags
                 Some(Commands::Localfs { tool }) => {
                     connectors::handle_localfs(&cli, tool.clone()).await
                 }
+                Some(Commands::PaperLibrary { tool }) => {
+                    connectors::handle_paper_library(&cli, tool.clone()).await
+                }
                 Some(Commands::Youtube { args }) => {
                     connectors::handle_youtube(&cli, args.clone()).await
                 }
@@ -212,6 +280,64 @@ async fn main() {
                 Some(Commands::Biorxiv { tool }) => {
                     connectors::handle_biorxiv(&cli, tool.clone()).await
                 }
+                Some(Commands::Crossref { tool }) => {
+                    connectors::handle_crossref(&cli, tool.clone()).await
+                }
+                Some(Commands::Dblp { tool }) => connectors::handle_dblp(&cli, tool.clone()).await,
+                Some(Commands::MarketData { tool }) => {
+                    connectors::handle_market_data(&cli, tool.clone()).await
+                }
+                Some(Commands::Coingecko { tool }) => {
+                    connectors::handle_coingecko(&cli, tool.clone()).await
+                }
+                Some(Commands::Openlibrary { tool }) => {
+                    connectors::handle_openlibrary(&cli, tool.clone()).await
+                }
+                Some(Commands::Podcasts { tool }) => {
+                    connectors::handle_podcasts(&cli, tool.clone()).await
+                }
+                Some(Commands::Twitch { tool }) => {
+                    connectors::handle_twitch(&cli, tool.clone()).await
+                }
+                Some(Commands::Vimeo { tool }) => {
+                    connectors::handle_vimeo(&cli, tool.clone()).await
+                }
+                Some(Commands::Salesforce { tool }) => {
+                    connectors::handle_salesforce(&cli, tool.clone()).await
+                }
+                Some(Commands::Hubspot { tool }) => {
+                    connectors::handle_hubspot(&cli, tool.clone()).await
+                }
+                Some(Commands::Airtable { tool }) => {
+                    connectors::handle_airtable(&cli, tool.clone()).await
+                }
+                Some(Commands::Box { tool }) => connectors::handle_box(&cli, tool.clone()).await,
+                Some(Commands::S3 { tool }) => connectors::handle_s3(&cli, tool.clone()).await,
+                Some(Commands::Sql { tool }) => connectors::handle_sql(&cli, tool.clone()).await,
+                Some(Commands::Openapi { tool }) => {
+                    connectors::handle_openapi(&cli, tool.clone()).await
+                }
+                Some(Commands::Graphql { tool }) => {
+                    connectors::handle_graphql(&cli, tool.clone()).await
+                }
+                Some(Commands::Sentry { tool }) => {
+                    connectors::handle_sentry(&cli, tool.clone()).await
+                }
+                Some(Commands::Pagerduty { tool }) => {
+                    connectors::handle_pagerduty(&cli, tool.clone()).await
+                }
+                Some(Commands::Confluence { tool }) => {
+                    connectors::handle_confluence(&cli, tool.clone()).await
+                }
+                Some(Commands::Zoom { tool }) => connectors::handle_zoom(&cli, tool.clone()).await,
+                Some(Commands::CratesIo { tool }) => {
+                    connectors::handle_crates_io(&cli, tool.clone()).await
+                }
+                Some(Commands::Npm { tool }) => connectors::handle_npm(&cli, tool.clone()).await,
+                Some(Commands::Pypi { tool }) => connectors::handle_pypi(&cli, tool.clone()).await,
+                Some(Commands::Dockerhub { tool }) => {
+                    connectors::handle_dockerhub(&cli, tool.clone()).await
+                }
                 Some(Commands::Scihub { tool }) => {
                     connectors::handle_scihub(&cli, tool.clone()).await
                 }
@@ -221,6 +347,12 @@ async fn main() {
                 Some(Commands::Spotlight { tool }) => {
                     connectors::handle_spotlight(&cli, tool.clone()).await
                 }
+                Some(Commands::Tracker { tool }) => {
+                    connectors::handle_tracker(&cli, tool.clone()).await
+                }
+                Some(Commands::BrowserHistory { tool }) => {
+                    connectors::handle_browser_history(&cli, tool.clone()).await
+                }
             }
         })
         .await;
@@ -1,9 +1,8 @@
 use arivu_core::{
     connectors::web::find_main_content,
     error::ConnectorError,
-    utils::{get_domain, strip_multiple_newlines},
+    utils::{get_domain, html_to_markdown_with, strip_multiple_newlines, MarkdownOptions},
 };
-use htmd::HtmlToMarkdown;
 use reqwest::blocking::Client;
 use rookie::{common::enums::CookieToString, firefox};
 use scraper::Html;
@@ -40,11 +39,11 @@ Chrome/117.0.0.0 Safari/537.36",
     let content_html = content_element;
     // println!("Content: {:#?}", content_html);
 
-    let converter = HtmlToMarkdown::builder()
-        .skip_tags(vec![
-            "script", "style", "nav", "footer", "header", "aside", "href", "img", "src",
-        ])
-        .build();
+    let options = MarkdownOptions {
+        images: false,
+        skip_tags: vec!["nav", "footer", "header", "aside"],
+        ..MarkdownOptions::default()
+    };
 
     let mut skin = MadSkin::default();
     skin.set_headers_fg(Color::Rgb {
@@ -86,9 +85,7 @@ Chrome/117.0.0.0 Safari/537.36",
     // skin.paragraph.set_fgbg(Color::Magenta, Color::Rgb { r: 30, g: 30, b: 40 });
     skin.bullet = StyledChar::from_fg_char(Color::Yellow, '•');
 
-    let markdown = converter
-        .convert(&content_html)
-        .unwrap_or_else(|_| content_html.clone());
+    let markdown = html_to_markdown_with(&content_html, &options);
     // println!("Markdown: {:?}", markdown);
     let markdown = markdown
         .replace("\n\n```", "\n```")
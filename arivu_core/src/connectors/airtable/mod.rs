@@ -0,0 +1,597 @@
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+const API_BASE: &str = "https://api.airtable.com/v0";
+const PAGE_SIZE: usize = 100;
+
+#[derive(Debug, Deserialize)]
+struct ListTablesArgs {
+    base: String,
+    #[serde(default)]
+    include_fields: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListRecordsArgs {
+    base: String,
+    #[serde(default)]
+    table: Option<String>,
+    #[serde(default)]
+    filter_formula: Option<String>,
+    #[serde(default)]
+    view: Option<String>,
+    #[serde(default)]
+    fields: Option<String>,
+    #[serde(default)]
+    sort: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+    #[serde(default)]
+    offset: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetRecordArgs {
+    base: String,
+    #[serde(default)]
+    table: Option<String>,
+    #[serde(default)]
+    record_id: Option<String>,
+}
+
+/// Ids pulled out of an `airtable.com/app…/tbl…/viw…/rec…` URL or a bare id.
+#[derive(Debug, Default, PartialEq)]
+struct AirtableRef {
+    base: Option<String>,
+    table: Option<String>,
+    view: Option<String>,
+    record: Option<String>,
+}
+
+fn parse_ref(input: &str) -> AirtableRef {
+    let mut out = AirtableRef::default();
+    let path = input
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_start_matches("airtable.com/");
+    for segment in path.split(['/', '?', '#']) {
+        let slot = match segment.get(..3) {
+            Some("app") => &mut out.base,
+            Some("tbl") => &mut out.table,
+            Some("viw") => &mut out.view,
+            Some("rec") => &mut out.record,
+            _ => continue,
+        };
+        if slot.is_none() && segment.len() == 17 {
+            *slot = Some(segment.to_string());
+        }
+    }
+    out
+}
+
+/// Resolve the base id and table (id or name), accepting either explicit
+/// arguments or an Airtable URL in `base`.
+fn resolve_table(base: &str, table: Option<&str>) -> Result<(String, String), ConnectorError> {
+    let parsed = parse_ref(base);
+    let base_id = parsed
+        .base
+        .ok_or_else(|| ConnectorError::InvalidParams(format!("No base id in '{}'", base)))?;
+    let table = table
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .or(parsed.table)
+        .ok_or_else(|| {
+            ConnectorError::InvalidParams(
+                "Missing 'table' (name or tbl… id, or pass a table URL as 'base')".to_string(),
+            )
+        })?;
+    Ok((base_id, table))
+}
+
+/// `-Priority,Name` -> Airtable's `sort[n][field]` / `sort[n][direction]` params.
+fn sort_params(sort: &str) -> Vec<(String, String)> {
+    sort.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .enumerate()
+        .flat_map(|(i, s)| {
+            let (field, direction) = match s.strip_prefix('-') {
+                Some(field) => (field, "desc"),
+                None => (s, "asc"),
+            };
+            [
+                (format!("sort[{}][field]", i), field.to_string()),
+                (format!("sort[{}][direction]", i), direction.to_string()),
+            ]
+        })
+        .collect()
+}
+
+fn format_record(record: &Value) -> Value {
+    json!({
+        "id": record["id"],
+        "created_time": record["createdTime"],
+        "fields": record["fields"],
+    })
+}
+
+pub struct AirtableConnector {
+    client: Client,
+    token: Option<String>,
+}
+
+impl AirtableConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+        let token = auth
+            .get("api_key")
+            .or_else(|| auth.get("token"))
+            .cloned()
+            .or_else(|| std::env::var("AIRTABLE_API_KEY").ok())
+            .or_else(|| std::env::var("AIRTABLE_TOKEN").ok())
+            .filter(|t| !t.trim().is_empty());
+
+        Ok(Self { client, token })
+    }
+
+    async fn get(&self, url: &str, params: &[(String, String)]) -> Result<Value, ConnectorError> {
+        let token = self.token.as_deref().ok_or_else(|| {
+            ConnectorError::Authentication(
+                "Airtable token not configured: arivu config set airtable --value <token>"
+                    .to_string(),
+            )
+        })?;
+
+        let response = self
+            .client
+            .get(url)
+            .query(params)
+            .bearer_auth(token)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+
+        let status = response.status();
+        if status.is_success() {
+            return response.json().await.map_err(ConnectorError::HttpRequest);
+        }
+        let body: Value = response.json().await.unwrap_or(Value::Null);
+        let message = body["error"]["message"]
+            .as_str()
+            .or_else(|| body["error"].as_str())
+            .unwrap_or_default()
+            .to_string();
+        Err(match status {
+            StatusCode::NOT_FOUND => ConnectorError::ResourceNotFound,
+            StatusCode::UNAUTHORIZED => {
+                ConnectorError::Authentication(format!("Airtable rejected the token: {}", message))
+            }
+            StatusCode::FORBIDDEN => ConnectorError::Authentication(format!(
+                "Airtable token lacks access to this base or scope: {}",
+                message
+            )),
+            StatusCode::UNPROCESSABLE_ENTITY | StatusCode::BAD_REQUEST => {
+                ConnectorError::InvalidParams(format!("Airtable: {}", message))
+            }
+            s => ConnectorError::Other(format!(
+                "Airtable API returned error status: {} {}",
+                s, message
+            )),
+        })
+    }
+
+    fn table_url(base: &str, table: &str, record: Option<&str>) -> String {
+        let mut url = format!("{}/{}/{}", API_BASE, base, urlencoding::encode(table));
+        if let Some(record) = record {
+            url.push('/');
+            url.push_str(record);
+        }
+        url
+    }
+}
+
+#[async_trait]
+impl Connector for AirtableConnector {
+    fn name(&self) -> &'static str {
+        "airtable"
+    }
+
+    fn description(&self) -> &'static str {
+        "Airtable bases, tables and records (filter formulas, views, sorting)."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        let mut auth = AuthDetails::new();
+        if let Some(token) = &self.token {
+            auth.insert("api_key".to_string(), token.clone());
+        }
+        Ok(auth)
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        *self = Self::new(details).await?;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.get(&format!("{}/meta/whoami", API_BASE), &[])
+            .await
+            .map(|_| ())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema {
+            fields: vec![Field {
+                name: "api_key".to_string(),
+                label: "Personal Access Token".to_string(),
+                field_type: FieldType::Secret,
+                required: true,
+                description: Some(
+                    "Airtable personal access token (pat...), or set AIRTABLE_API_KEY.".to_string(),
+                ),
+                options: None,
+            }],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Use `list_bases` then `list_tables` to discover schema, `list_records` with \
+`filter_formula`/`view` to query, and `get_record` for a single record. Airtable URLs are accepted \
+wherever a base id is expected."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let base_schema = json!({
+            "type": "string",
+            "description": "Base id (app...) or an airtable.com URL"
+        });
+        let table_schema = json!({
+            "type": "string",
+            "description": "Table name or id (tbl...); optional when 'base' is a table URL"
+        });
+
+        let tools = vec![
+            tool(
+                "list_bases",
+                "List bases the token can access, with permission level.",
+                json!({ "type": "object", "properties": {} }),
+            ),
+            tool(
+                "list_tables",
+                "List tables in a base with their views and (optionally) field schemas. Example: base=\"appXXXXXXXXXXXXXX\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "base": base_schema.clone(),
+                        "include_fields": { "type": "boolean", "description": "Include field names, types and options (default: true)" }
+                    },
+                    "required": ["base"]
+                }),
+            ),
+            tool(
+                "list_records",
+                "Query records with an optional filter formula, view, field selection and sort. \
+Example: table=\"Tasks\" filter_formula=\"AND({Status}='Open', {Priority}>2)\" sort=\"-Priority\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "base": base_schema.clone(),
+                        "table": table_schema.clone(),
+                        "filter_formula": { "type": "string", "description": "Airtable formula; records where it is truthy are returned" },
+                        "view": { "type": "string", "description": "View name or id; applies its filters and sort" },
+                        "fields": { "type": "string", "description": "Comma-separated field names to return" },
+                        "sort": { "type": "string", "description": "Comma-separated fields; prefix with '-' for descending" },
+                        "limit": { "type": "integer", "description": "Max records (default: 50, max: 1000)" },
+                        "offset": { "type": "string", "description": "Pagination offset from a previous call" }
+                    },
+                    "required": ["base"]
+                }),
+            ),
+            tool(
+                "get_record",
+                "Get a single record by id, or pass a record URL as 'base'.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "base": base_schema,
+                        "table": table_schema,
+                        "record_id": { "type": "string", "description": "Record id (rec...)" }
+                    },
+                    "required": ["base"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "list_bases" => {
+                let mut bases: Vec<Value> = Vec::new();
+                let mut offset: Option<String> = None;
+                loop {
+                    let params: Vec<(String, String)> = offset
+                        .iter()
+                        .map(|o| ("offset".to_string(), o.clone()))
+                        .collect();
+                    let body = self
+                        .get(&format!("{}/meta/bases", API_BASE), &params)
+                        .await?;
+                    bases.extend(body["bases"].as_array().into_iter().flatten().map(|b| {
+                        json!({
+                            "id": b["id"],
+                            "name": b["name"],
+                            "permission_level": b["permissionLevel"],
+                        })
+                    }));
+                    offset = body["offset"].as_str().map(str::to_string);
+                    if offset.is_none() {
+                        break;
+                    }
+                }
+
+                let data = json!({ "count": bases.len(), "bases": bases });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "list_tables" => {
+                let args: ListTablesArgs = parse_args(request.arguments)?;
+                let base = parse_ref(&args.base).base.ok_or_else(|| {
+                    ConnectorError::InvalidParams(format!("No base id in '{}'", args.base))
+                })?;
+                let include_fields = args.include_fields.unwrap_or(true);
+
+                let body = self
+                    .get(&format!("{}/meta/bases/{}/tables", API_BASE, base), &[])
+                    .await?;
+                let tables: Vec<Value> = body["tables"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|t| {
+                        let views: Vec<Value> = t["views"]
+                            .as_array()
+                            .into_iter()
+                            .flatten()
+                            .map(|v| json!({ "id": v["id"], "name": v["name"], "type": v["type"] }))
+                            .collect();
+                        let mut table = json!({
+                            "id": t["id"],
+                            "name": t["name"],
+                            "description": t["description"],
+                            "primary_field_id": t["primaryFieldId"],
+                            "views": views,
+                        });
+                        if include_fields {
+                            table["fields"] = json!(t["fields"]
+                                .as_array()
+                                .into_iter()
+                                .flatten()
+                                .map(|f| json!({
+                                    "id": f["id"],
+                                    "name": f["name"],
+                                    "type": f["type"],
+                                    "description": f["description"],
+                                    "options": f["options"],
+                                }))
+                                .collect::<Vec<_>>());
+                        }
+                        table
+                    })
+                    .collect();
+
+                let data = json!({ "base": base, "count": tables.len(), "tables": tables });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "list_records" => {
+                let args: ListRecordsArgs = parse_args(request.arguments)?;
+                let (base, table) = resolve_table(&args.base, args.table.as_deref())?;
+                let view = args.view.or_else(|| parse_ref(&args.base).view);
+                let limit = args.limit.unwrap_or(50).clamp(1, 1000);
+
+                let mut params: Vec<(String, String)> = Vec::new();
+                if let Some(formula) = &args.filter_formula {
+                    params.push(("filterByFormula".to_string(), formula.clone()));
+                }
+                if let Some(view) = &view {
+                    params.push(("view".to_string(), view.clone()));
+                }
+                if let Some(fields) = &args.fields {
+                    params.extend(
+                        fields
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|f| !f.is_empty())
+                            .map(|f| ("fields[]".to_string(), f.to_string())),
+                    );
+                }
+                if let Some(sort) = &args.sort {
+                    params.extend(sort_params(sort));
+                }
+
+                let url = Self::table_url(&base, &table, None);
+                let mut records: Vec<Value> = Vec::new();
+                let mut offset = args.offset;
+                loop {
+                    let mut page = params.clone();
+                    page.push((
+                        "pageSize".to_string(),
+                        (limit - records.len()).min(PAGE_SIZE).to_string(),
+                    ));
+                    if let Some(o) = &offset {
+                        page.push(("offset".to_string(), o.clone()));
+                    }
+                    let body = self.get(&url, &page).await?;
+                    records.extend(
+                        body["records"]
+                            .as_array()
+                            .into_iter()
+                            .flatten()
+                            .map(format_record),
+                    );
+                    offset = body["offset"].as_str().map(str::to_string);
+                    if offset.is_none() || records.len() >= limit {
+                        break;
+                    }
+                }
+                records.truncate(limit);
+
+                let data = json!({
+                    "base": base,
+                    "table": table,
+                    "view": view,
+                    "count": records.len(),
+                    "records": records,
+                    "offset": offset,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_record" => {
+                let args: GetRecordArgs = parse_args(request.arguments)?;
+                let (base, table) = resolve_table(&args.base, args.table.as_deref())?;
+                let record_id = args
+                    .record_id
+                    .map(|r| r.trim().to_string())
+                    .filter(|r| !r.is_empty())
+                    .or_else(|| parse_ref(&args.base).record)
+                    .ok_or_else(|| {
+                        ConnectorError::InvalidParams(
+                            "Missing 'record_id' (rec...) or a record URL".to_string(),
+                        )
+                    })?;
+
+                let record = self
+                    .get(&Self::table_url(&base, &table, Some(&record_id)), &[])
+                    .await?;
+                let mut data = format_record(&record);
+                data["base"] = json!(base);
+                data["table"] = json!(table);
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_airtable_urls() {
+        let parsed =
+            parse_ref("https://airtable.com/appAbCdEfGhIjKlMn/tblAbCdEfGhIjKlMn/viwAbCdEfGhIjKlMn");
+        assert_eq!(parsed.base.as_deref(), Some("appAbCdEfGhIjKlMn"));
+        assert_eq!(parsed.table.as_deref(), Some("tblAbCdEfGhIjKlMn"));
+        assert_eq!(parsed.view.as_deref(), Some("viwAbCdEfGhIjKlMn"));
+        assert_eq!(parsed.record, None);
+
+        let bare = parse_ref("appAbCdEfGhIjKlMn");
+        assert_eq!(bare.base.as_deref(), Some("appAbCdEfGhIjKlMn"));
+    }
+
+    #[test]
+    fn explicit_table_wins_over_url() {
+        let (base, table) = resolve_table(
+            "airtable.com/appAbCdEfGhIjKlMn/tblAbCdEfGhIjKlMn",
+            Some("Tasks"),
+        )
+        .unwrap();
+        assert_eq!(base, "appAbCdEfGhIjKlMn");
+        assert_eq!(table, "Tasks");
+        assert!(resolve_table("appAbCdEfGhIjKlMn", None).is_err());
+    }
+
+    #[test]
+    fn builds_sort_params() {
+        let params = sort_params("-Priority, Name");
+        assert_eq!(
+            params,
+            vec![
+                ("sort[0][field]".to_string(), "Priority".to_string()),
+                ("sort[0][direction]".to_string(), "desc".to_string()),
+                ("sort[1][field]".to_string(), "Name".to_string()),
+                ("sort[1][direction]".to_string(), "asc".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,679 @@
+// Apple Calendar Connector - Native Calendar.app integration via AppleScript
+// macOS only - events from iCloud, Exchange, Google and local calendars
+//
+// Reads whatever accounts are configured in Calendar.app, so no OAuth is needed:
+// - Calendars (with writability)
+// - Event search within a date range
+// - Event details and creation
+
+use crate::connectors::apple_common::{
+    apple_connector_capabilities, applescript_set_date, escape_applescript_string,
+    parse_local_datetime, run_applescript_output, unquote_applescript_string,
+    APPLESCRIPT_ISO_DATE_HANDLERS,
+};
+use crate::error::ConnectorError;
+use crate::utils::structured_result_with_text;
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime};
+use rmcp::model::*;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// Longest range a single search may cover; Calendar.app gets slow beyond this.
+const MAX_RANGE_DAYS: i64 = 366;
+
+/// Apple Calendar connector - interact with Calendar.app via AppleScript
+#[derive(Default)]
+pub struct AppleCalendarConnector;
+
+impl AppleCalendarConnector {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+// ============================================================================
+// Data Structures
+// ============================================================================
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CalendarInfo {
+    /// Calendar name (use when searching or creating events)
+    name: String,
+    /// Calendar description, if any
+    description: Option<String>,
+    /// Whether events can be created in this calendar
+    writable: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CalendarEvent {
+    /// Event UID (use for get_event)
+    uid: String,
+    /// Event title
+    summary: String,
+    /// Start, local time (YYYY-MM-DDTHH:MM)
+    start: String,
+    /// End, local time (YYYY-MM-DDTHH:MM)
+    end: String,
+    /// All-day event
+    all_day: bool,
+    /// Location
+    location: Option<String>,
+    /// Containing calendar name
+    calendar: String,
+    /// Notes (only returned by get_event)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notes: Option<String>,
+    /// URL (only returned by get_event)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    /// Recurrence rule, if the event repeats (only returned by get_event)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recurrence: Option<String>,
+}
+
+// ============================================================================
+// AppleScript Generators
+// ============================================================================
+
+fn script_list_calendars() -> String {
+    r#"
+tell application "Calendar"
+    set output to ""
+    repeat with c in calendars
+        set cName to name of c
+        set cDesc to ""
+        try
+            set cDesc to description of c
+            if cDesc is missing value then set cDesc to ""
+        end try
+        set cWritable to writable of c
+        if output is not "" then set output to output & "|||"
+        set output to output & cName & ":::" & cDesc & ":::" & cWritable
+    end repeat
+    return output
+end tell
+"#
+    .to_string()
+}
+
+/// Common per-event serialization; `detail` adds notes, url and recurrence.
+fn event_fields(detail: bool) -> &'static str {
+    if detail {
+        r#"
+            set eLoc to location of e
+            if eLoc is missing value then set eLoc to ""
+            set eNotes to description of e
+            if eNotes is missing value then set eNotes to ""
+            set eUrl to url of e
+            if eUrl is missing value then set eUrl to ""
+            set eRec to recurrence of e
+            if eRec is missing value then set eRec to ""
+            set output to output & (uid of e) & ":::" & (summary of e) & ":::" & my isoDate(start date of e) & ":::" & my isoDate(end date of e) & ":::" & (allday event of e) & ":::" & eLoc & ":::" & (name of c) & ":::" & eNotes & ":::" & eUrl & ":::" & eRec"#
+    } else {
+        r#"
+            set eLoc to location of e
+            if eLoc is missing value then set eLoc to ""
+            set output to output & (uid of e) & ":::" & (summary of e) & ":::" & my isoDate(start date of e) & ":::" & my isoDate(end date of e) & ":::" & (allday event of e) & ":::" & eLoc & ":::" & (name of c)"#
+    }
+}
+
+fn calendar_scope(calendar: Option<&str>) -> String {
+    match calendar {
+        Some(name) => format!(
+            r#"(calendars whose name is "{}")"#,
+            escape_applescript_string(name)
+        ),
+        None => "calendars".to_string(),
+    }
+}
+
+fn script_search_events(
+    query: Option<&str>,
+    calendar: Option<&str>,
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    limit: usize,
+) -> String {
+    // `contains` is case-insensitive in AppleScript. Filtering on summary in the
+    // `whose` clause keeps Calendar.app from materializing every event.
+    let text_filter = match query {
+        Some(q) if !q.trim().is_empty() => format!(
+            r#" and summary contains "{}""#,
+            escape_applescript_string(q.trim())
+        ),
+        _ => String::new(),
+    };
+    format!(
+        r#"
+{start_date}
+{end_date}
+tell application "Calendar"
+    set output to ""
+    set found to 0
+    repeat with c in {scope}
+        set evs to (every event of c whose start date < endDate and end date > startDate{text_filter})
+        repeat with e in evs
+            if found is greater than or equal to {limit} then exit repeat
+            if output is not "" then set output to output & "|||"{fields}
+            set found to found + 1
+        end repeat
+        if found is greater than or equal to {limit} then exit repeat
+    end repeat
+    return output
+end tell
+{handlers}"#,
+        start_date = applescript_set_date("startDate", start),
+        end_date = applescript_set_date("endDate", end),
+        scope = calendar_scope(calendar),
+        text_filter = text_filter,
+        limit = limit,
+        fields = event_fields(false),
+        handlers = APPLESCRIPT_ISO_DATE_HANDLERS,
+    )
+}
+
+fn script_get_event(uid: &str) -> String {
+    format!(
+        r#"
+tell application "Calendar"
+    set output to ""
+    repeat with c in calendars
+        set evs to (every event of c whose uid is "{uid}")
+        if (count of evs) > 0 then
+            set e to item 1 of evs{fields}
+            return output
+        end if
+    end repeat
+    return output
+end tell
+{handlers}"#,
+        uid = escape_applescript_string(uid),
+        fields = event_fields(true),
+        handlers = APPLESCRIPT_ISO_DATE_HANDLERS,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn script_create_event(
+    summary: &str,
+    calendar: Option<&str>,
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    all_day: bool,
+    location: Option<&str>,
+    notes: Option<&str>,
+    url: Option<&str>,
+) -> String {
+    let target = match calendar {
+        Some(name) => format!(r#"calendar "{}""#, escape_applescript_string(name)),
+        None => "first calendar whose writable is true".to_string(),
+    };
+    let mut props = vec![
+        format!(r#"summary:"{}""#, escape_applescript_string(summary)),
+        "start date:startDate".to_string(),
+        "end date:endDate".to_string(),
+        format!("allday event:{}", all_day),
+    ];
+    if let Some(l) = location.filter(|l| !l.is_empty()) {
+        props.push(format!(r#"location:"{}""#, escape_applescript_string(l)));
+    }
+    if let Some(n) = notes.filter(|n| !n.is_empty()) {
+        props.push(format!(r#"description:"{}""#, escape_applescript_string(n)));
+    }
+    if let Some(u) = url.filter(|u| !u.is_empty()) {
+        props.push(format!(r#"url:"{}""#, escape_applescript_string(u)));
+    }
+    format!(
+        r#"
+{start_date}
+{end_date}
+tell application "Calendar"
+    set targetCal to {target}
+    set newEvent to make new event at end of events of targetCal with properties {{{props}}}
+    return (uid of newEvent) & ":::" & (name of targetCal)
+end tell
+"#,
+        start_date = applescript_set_date("startDate", start),
+        end_date = applescript_set_date("endDate", end),
+        target = target,
+        props = props.join(", "),
+    )
+}
+
+// ============================================================================
+// Parsing Functions
+// ============================================================================
+
+fn non_empty(s: &str) -> Option<String> {
+    if s.is_empty() {
+        None
+    } else {
+        Some(s.to_string())
+    }
+}
+
+fn parse_calendars(output: &str) -> Vec<CalendarInfo> {
+    output
+        .split("|||")
+        .filter(|s| !s.is_empty())
+        .filter_map(|entry| {
+            let parts: Vec<&str> = entry.split(":::").collect();
+            if parts.len() >= 3 {
+                Some(CalendarInfo {
+                    name: parts[0].to_string(),
+                    description: non_empty(parts[1]),
+                    writable: parts[2] == "true",
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn parse_event(entry: &str) -> Option<CalendarEvent> {
+    let parts: Vec<&str> = entry.split(":::").collect();
+    if parts.len() < 7 {
+        return None;
+    }
+    Some(CalendarEvent {
+        uid: parts[0].to_string(),
+        summary: parts[1].to_string(),
+        start: parts[2].to_string(),
+        end: parts[3].to_string(),
+        all_day: parts[4] == "true",
+        location: non_empty(parts[5]),
+        calendar: parts[6].to_string(),
+        notes: parts.get(7).and_then(|s| non_empty(s)),
+        url: parts.get(8).and_then(|s| non_empty(s)),
+        recurrence: parts.get(9).and_then(|s| non_empty(s)),
+    })
+}
+
+fn parse_events(output: &str) -> Vec<CalendarEvent> {
+    let mut events: Vec<CalendarEvent> = output
+        .split("|||")
+        .filter(|s| !s.is_empty())
+        .filter_map(parse_event)
+        .collect();
+    // Calendar.app returns events per calendar in storage order.
+    events.sort_by(|a, b| a.start.cmp(&b.start));
+    events
+}
+
+fn required_datetime(
+    args: &serde_json::Map<String, serde_json::Value>,
+    key: &str,
+) -> Result<Option<NaiveDateTime>, ConnectorError> {
+    match args.get(key).and_then(|v| v.as_str()) {
+        None => Ok(None),
+        Some(s) => parse_local_datetime(s).map(Some).ok_or_else(|| {
+            ConnectorError::InvalidParams(format!(
+                "'{}' must be YYYY-MM-DD or YYYY-MM-DDTHH:MM, got '{}'",
+                key, s
+            ))
+        }),
+    }
+}
+
+// ============================================================================
+// Connector Implementation
+// ============================================================================
+
+#[async_trait]
+impl crate::Connector for AppleCalendarConnector {
+    fn name(&self) -> &'static str {
+        "apple-calendar"
+    }
+
+    fn description(&self) -> &'static str {
+        "Apple Calendar.app connector for macOS. Search events across every account configured in Calendar (iCloud, Exchange, Google, local) within a date range, read event details, and create events — no OAuth needed."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        apple_connector_capabilities()
+    }
+
+    async fn get_auth_details(&self) -> Result<crate::auth::AuthDetails, ConnectorError> {
+        Ok(crate::auth::AuthDetails::new())
+    }
+
+    async fn set_auth_details(
+        &mut self,
+        _details: crate::auth::AuthDetails,
+    ) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        let _ = run_applescript_output(r#"tell application "Calendar" to name"#).await?;
+        Ok(())
+    }
+
+    fn config_schema(&self) -> crate::capabilities::ConnectorConfigSchema {
+        crate::capabilities::ConnectorConfigSchema { fields: vec![] }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: Some("Apple Calendar".to_string()),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Native Calendar.app integration. Dates are local time as YYYY-MM-DD or YYYY-MM-DDTHH:MM. Repeating events are matched by their first occurrence only. First use triggers a Calendars permission prompt."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            Tool {
+                name: Cow::Borrowed("list_calendars"),
+                title: Some("List Calendars".to_string()),
+                description: Some(Cow::Borrowed(
+                    "List all calendars with their names and whether they accept new events.",
+                )),
+                input_schema: Arc::new(json!({"type": "object", "properties": {}}).as_object().unwrap().clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("search_events"),
+                title: Some("Search Events".to_string()),
+                description: Some(Cow::Borrowed(
+                    "Find events overlapping a date range, optionally matching a title and restricted to one calendar. Sorted by start time.",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "query": {
+                                "type": "string",
+                                "description": "Text the event title must contain (case-insensitive). Omit to list all events in range."
+                            },
+                            "start": {
+                                "type": "string",
+                                "description": "Range start, YYYY-MM-DD or YYYY-MM-DDTHH:MM. Default: today."
+                            },
+                            "end": {
+                                "type": "string",
+                                "description": "Range end (exclusive). Default: start + 7 days. At most 366 days after start."
+                            },
+                            "calendar": {
+                                "type": "string",
+                                "description": "Calendar name from list_calendars."
+                            },
+                            "limit": {
+                                "type": "integer",
+                                "description": "Maximum events. Default: 50.",
+                                "default": 50
+                            }
+                        }
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_event"),
+                title: Some("Get Event Details".to_string()),
+                description: Some(Cow::Borrowed(
+                    "Get an event by UID, including notes, URL and recurrence rule.",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "uid": {
+                                "type": "string",
+                                "description": "Event UID from search_events. Required."
+                            }
+                        },
+                        "required": ["uid"]
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("create_event"),
+                title: Some("Create Event".to_string()),
+                description: Some(Cow::Borrowed(
+                    "Create an event. Returns the new event's UID and calendar.",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "summary": {
+                                "type": "string",
+                                "description": "Event title. Required."
+                            },
+                            "start": {
+                                "type": "string",
+                                "description": "Start, YYYY-MM-DDTHH:MM (or YYYY-MM-DD with all_day). Required."
+                            },
+                            "end": {
+                                "type": "string",
+                                "description": "End. Default: start + 1 hour (or + 1 day for all-day events)."
+                            },
+                            "all_day": {
+                                "type": "boolean",
+                                "description": "All-day event. Default: false.",
+                                "default": false
+                            },
+                            "calendar": {
+                                "type": "string",
+                                "description": "Calendar name. Default: the first writable calendar."
+                            },
+                            "location": {
+                                "type": "string",
+                                "description": "Location."
+                            },
+                            "notes": {
+                                "type": "string",
+                                "description": "Notes/description."
+                            },
+                            "url": {
+                                "type": "string",
+                                "description": "URL, e.g. a meeting link."
+                            }
+                        },
+                        "required": ["summary", "start"]
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        let name = request.name.as_ref();
+        let args = request.arguments.unwrap_or_default();
+
+        match name {
+            "list_calendars" => {
+                let output = unquote_applescript_string(
+                    &run_applescript_output(&script_list_calendars()).await?,
+                );
+                let calendars = parse_calendars(&output);
+                structured_result_with_text(&calendars, None)
+            }
+
+            "search_events" => {
+                let query = args.get("query").and_then(|v| v.as_str());
+                let calendar = args.get("calendar").and_then(|v| v.as_str());
+                let limit = args.get("limit").and_then(|v| v.as_u64()).unwrap_or(50) as usize;
+                let start = required_datetime(&args, "start")?.unwrap_or_else(|| {
+                    chrono::Local::now()
+                        .date_naive()
+                        .and_hms_opt(0, 0, 0)
+                        .expect("midnight is valid")
+                });
+                let end = required_datetime(&args, "end")?.unwrap_or(start + Duration::days(7));
+                if end <= start {
+                    return Err(ConnectorError::InvalidParams(
+                        "'end' must be after 'start'".to_string(),
+                    ));
+                }
+                if end - start > Duration::days(MAX_RANGE_DAYS) {
+                    return Err(ConnectorError::InvalidParams(format!(
+                        "Date range is limited to {} days; narrow 'start'/'end'",
+                        MAX_RANGE_DAYS
+                    )));
+                }
+
+                let output = unquote_applescript_string(
+                    &run_applescript_output(&script_search_events(
+                        query, calendar, start, end, limit,
+                    ))
+                    .await?,
+                );
+                let events = parse_events(&output);
+                structured_result_with_text(
+                    &json!({
+                        "start": start.format("%Y-%m-%dT%H:%M").to_string(),
+                        "end": end.format("%Y-%m-%dT%H:%M").to_string(),
+                        "count": events.len(),
+                        "events": events,
+                    }),
+                    None,
+                )
+            }
+
+            "get_event" => {
+                let uid = args
+                    .get("uid")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| ConnectorError::InvalidParams("Missing 'uid'".to_string()))?;
+
+                let output = unquote_applescript_string(
+                    &run_applescript_output(&script_get_event(uid)).await?,
+                );
+                let event = parse_event(&output).ok_or(ConnectorError::ResourceNotFound)?;
+                structured_result_with_text(&event, None)
+            }
+
+            "create_event" => {
+                let summary = args
+                    .get("summary")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| {
+                        ConnectorError::InvalidParams("Missing 'summary'".to_string())
+                    })?;
+                let start = required_datetime(&args, "start")?
+                    .ok_or_else(|| ConnectorError::InvalidParams("Missing 'start'".to_string()))?;
+                let all_day = args
+                    .get("all_day")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let default_len = if all_day {
+                    Duration::days(1)
+                } else {
+                    Duration::hours(1)
+                };
+                let end = required_datetime(&args, "end")?.unwrap_or(start + default_len);
+                if end <= start {
+                    return Err(ConnectorError::InvalidParams(
+                        "'end' must be after 'start'".to_string(),
+                    ));
+                }
+
+                let output = run_applescript_output(&script_create_event(
+                    summary,
+                    args.get("calendar").and_then(|v| v.as_str()),
+                    start,
+                    end,
+                    all_day,
+                    args.get("location").and_then(|v| v.as_str()),
+                    args.get("notes").and_then(|v| v.as_str()),
+                    args.get("url").and_then(|v| v.as_str()),
+                ))
+                .await?;
+                let output = unquote_applescript_string(&output);
+                let (uid, calendar) = output.split_once(":::").unwrap_or((output.as_str(), ""));
+                structured_result_with_text(
+                    &json!({
+                        "success": true,
+                        "uid": uid,
+                        "calendar": calendar,
+                        "message": "Event created successfully",
+                    }),
+                    None,
+                )
+            }
+
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, _name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+}
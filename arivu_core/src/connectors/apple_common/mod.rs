@@ -89,6 +89,33 @@ pub fn escape_applescript_string(s: &str) -> String {
     s.replace('\\', "\\\\").replace('"', "\\\"")
 }
 
+/// Undo `osascript -s s` quoting of a string result (`"a \"b\""` → `a "b"`).
+/// Non-string results are returned unchanged.
+pub fn unquote_applescript_string(output: &str) -> String {
+    let trimmed = output.trim();
+    match trimmed.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        Some(inner) => {
+            let mut out = String::with_capacity(inner.len());
+            let mut chars = inner.chars();
+            while let Some(c) = chars.next() {
+                if c == '\\' {
+                    match chars.next() {
+                        Some('n') => out.push('\n'),
+                        Some('r') => out.push('\r'),
+                        Some('t') => out.push('\t'),
+                        Some(other) => out.push(other),
+                        None => out.push('\\'),
+                    }
+                } else {
+                    out.push(c);
+                }
+            }
+            out
+        }
+        None => trimmed.to_string(),
+    }
+}
+
 /// Parse AppleScript list output into a Vec of strings
 /// AppleScript returns lists like: {"item1", "item2", "item3"}
 pub fn parse_applescript_list(output: &str) -> Vec<String> {
@@ -188,6 +215,58 @@ impl AppleDate {
     }
 }
 
+/// Parse `YYYY-MM-DD`, `YYYY-MM-DD HH:MM[:SS]` or `YYYY-MM-DDTHH:MM[:SS]` as a
+/// local date-time. Date-only input means midnight.
+pub fn parse_local_datetime(s: &str) -> Option<chrono::NaiveDateTime> {
+    let s = s.trim();
+    for fmt in [
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+    ] {
+        if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(s, fmt) {
+            return Some(dt);
+        }
+    }
+    chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+}
+
+/// AppleScript lines that set `var` to `dt` component by component.
+/// `date "..."` literals are parsed with the user's locale, so they are avoided.
+pub fn applescript_set_date(var: &str, dt: chrono::NaiveDateTime) -> String {
+    use chrono::{Datelike, Timelike};
+    format!(
+        "set {v} to current date\n\
+         set day of {v} to 1\n\
+         set year of {v} to {y}\n\
+         set month of {v} to {m}\n\
+         set day of {v} to {d}\n\
+         set time of {v} to {t}",
+        v = var,
+        y = dt.year(),
+        m = dt.month(),
+        d = dt.day(),
+        t = dt.num_seconds_from_midnight()
+    )
+}
+
+/// AppleScript handlers formatting a date as `YYYY-MM-DDTHH:MM` (local time).
+/// Append outside any `tell` block and call as `my isoDate(d)`.
+pub const APPLESCRIPT_ISO_DATE_HANDLERS: &str = r#"
+on isoDate(d)
+    if d is missing value then return ""
+    set t to time of d
+    return (year of d as string) & "-" & my pad2(month of d as integer) & "-" & my pad2(day of d) & "T" & my pad2(t div 3600) & ":" & my pad2((t mod 3600) div 60)
+end isoDate
+
+on pad2(n)
+    return text -2 thru -1 of ("0" & (n as string))
+end pad2
+"#;
+
 /// Check if an app is running
 #[cfg(target_os = "macos")]
 pub async fn is_app_running(app_name: &str) -> Result<bool, ConnectorError> {
@@ -232,6 +311,33 @@ mod tests {
         assert!(items.is_empty());
     }
 
+    #[test]
+    fn test_unquote_applescript_string() {
+        assert_eq!(
+            unquote_applescript_string(r#""Lunch \"team\":::C:\\cal""#),
+            r#"Lunch "team":::C:\cal"#
+        );
+        assert_eq!(unquote_applescript_string("42"), "42");
+    }
+
+    #[test]
+    fn test_parse_local_datetime() {
+        let dt = parse_local_datetime("2025-03-09T14:30").unwrap();
+        assert_eq!(dt.to_string(), "2025-03-09 14:30:00");
+        let d = parse_local_datetime("2025-03-09").unwrap();
+        assert_eq!(d.to_string(), "2025-03-09 00:00:00");
+        assert!(parse_local_datetime("March 9").is_none());
+    }
+
+    #[test]
+    fn test_applescript_set_date() {
+        let dt = parse_local_datetime("2025-02-28 09:15").unwrap();
+        let script = applescript_set_date("startDate", dt);
+        assert!(script.starts_with("set startDate to current date\nset day of startDate to 1\n"));
+        assert!(script.contains("set month of startDate to 2\n"));
+        assert!(script.ends_with("set time of startDate to 33300"));
+    }
+
     #[test]
     fn test_escape_applescript_string() {
         let input = r#"Hello "World""#;
@@ -6,7 +6,8 @@
 
 #[cfg(target_os = "macos")]
 use crate::connectors::apple_common::{
-    apple_connector_capabilities, escape_applescript_string, run_applescript_output,
+    apple_connector_capabilities, applescript_set_date, escape_applescript_string,
+    parse_local_datetime, run_applescript_output,
 };
 use crate::error::ConnectorError;
 use crate::utils::structured_result_with_text;
@@ -17,6 +18,12 @@ use serde_json::json;
 use std::borrow::Cow;
 use std::sync::Arc;
 
+mod thread;
+
+/// Most messages considered when assembling a thread.
+#[cfg(target_os = "macos")]
+const MAX_THREAD_CANDIDATES: usize = 200;
+
 /// Apple Mail connector - interact with Mail.app via AppleScript
 #[derive(Default)]
 pub struct AppleMailConnector;
@@ -90,6 +97,32 @@ struct MailMessageContent {
     truncated: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct MailAttachment {
+    /// 1-based position among the message's attachments
+    index: usize,
+    name: String,
+    mime_type: String,
+    /// Size in bytes, when Mail.app knows it
+    size: u64,
+    /// Whether Mail.app has the attachment locally
+    downloaded: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ThreadMessage {
+    #[serde(flatten)]
+    message: MailMessage,
+    /// Message-ID header
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    in_reply_to: Option<String>,
+    /// Seconds since 1970 in local time, for ordering
+    #[serde(skip)]
+    received_epoch: i64,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct DraftResult {
     success: bool,
@@ -168,18 +201,70 @@ end tell
     }
 }
 
+/// Parse a date (or date-time) argument as local time.
 #[cfg(target_os = "macos")]
-fn script_list_messages(mailbox: &str, account: Option<&str>, limit: usize) -> String {
+fn parse_date_arg(
+    args: &serde_json::Map<String, serde_json::Value>,
+    key: &str,
+) -> Result<Option<chrono::NaiveDateTime>, ConnectorError> {
+    match args.get(key).and_then(|v| v.as_str()) {
+        Some(raw) => parse_local_datetime(raw).map(Some).ok_or_else(|| {
+            ConnectorError::InvalidParams(format!(
+                "'{}' must be YYYY-MM-DD or YYYY-MM-DD HH:MM, got '{}'",
+                key, raw
+            ))
+        }),
+        None => Ok(None),
+    }
+}
+
+/// AppleScript setting `epochDate` to 1970-01-01 local, so message dates can
+/// be returned as sortable seconds.
+#[cfg(target_os = "macos")]
+fn script_epoch_date() -> String {
+    applescript_set_date(
+        "epochDate",
+        chrono::NaiveDate::from_ymd_opt(1970, 1, 1)
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .expect("valid date"),
+    )
+}
+
+#[cfg(target_os = "macos")]
+fn script_list_messages(
+    mailbox: &str,
+    account: Option<&str>,
+    limit: usize,
+    since: Option<chrono::NaiveDateTime>,
+    before: Option<chrono::NaiveDateTime>,
+) -> String {
     let account_clause = match account {
         Some(acc) => format!(r#"of account "{}""#, escape_applescript_string(acc)),
         None => String::new(),
     };
 
+    let mut date_setup = Vec::new();
+    let mut conditions = Vec::new();
+    if let Some(since) = since {
+        date_setup.push(applescript_set_date("sinceDate", since));
+        conditions.push("date received ≥ sinceDate");
+    }
+    if let Some(before) = before {
+        date_setup.push(applescript_set_date("beforeDate", before));
+        conditions.push("date received < beforeDate");
+    }
+    let selection = if conditions.is_empty() {
+        "messages of mb".to_string()
+    } else {
+        format!("(messages of mb whose {})", conditions.join(" and "))
+    };
+
     format!(
         r#"
 tell application "Mail"
+    {}
     set mb to mailbox "{}" {}
-    set msgs to messages of mb
+    set msgs to {}
     set msgCount to count of msgs
     set maxCount to {limit}
     if msgCount < maxCount then set maxCount to msgCount
@@ -207,8 +292,10 @@ tell application "Mail"
     return output
 end tell
 "#,
+        date_setup.join("\n"),
         escape_applescript_string(mailbox),
         account_clause,
+        selection,
         limit = limit
     )
 }
@@ -252,6 +339,131 @@ end tell
     )
 }
 
+#[cfg(target_os = "macos")]
+fn script_list_attachments(message_id: &str) -> String {
+    format!(
+        r#"
+tell application "Mail"
+    set msg to message id {}
+    set output to ""
+    set i to 0
+    repeat with att in mail attachments of msg
+        set i to i + 1
+        set attType to ""
+        try
+            set attType to MIME type of att
+        end try
+        set attSize to 0
+        try
+            set attSize to file size of att
+        end try
+        set attDownloaded to false
+        try
+            set attDownloaded to downloaded of att
+        end try
+        if output is not "" then set output to output & "|||"
+        set output to output & i & ":::" & (name of att) & ":::" & attType & ":::" & attSize & ":::" & attDownloaded
+    end repeat
+    return output
+end tell
+"#,
+        message_id
+    )
+}
+
+/// Save attachments (by 1-based index) to the given paths, reporting
+/// `index:::ok` or `index:::<error>` for each.
+#[cfg(target_os = "macos")]
+fn script_save_attachments(message_id: &str, targets: &[(usize, String)]) -> String {
+    let saves: Vec<String> = targets
+        .iter()
+        .map(|(index, path)| {
+            format!(
+                r#"    try
+        save (item {i} of atts) in (POSIX file "{p}")
+        set output to output & "{i}:::ok|||"
+    on error errMsg
+        set output to output & "{i}:::" & errMsg & "|||"
+    end try"#,
+                i = index,
+                p = escape_applescript_string(path)
+            )
+        })
+        .collect();
+    format!(
+        r#"
+tell application "Mail"
+    set msg to message id {}
+    set atts to mail attachments of msg
+    set output to ""
+{}
+    return output
+end tell
+"#,
+        message_id,
+        saves.join("\n")
+    )
+}
+
+/// Fields shared by the thread scripts: id, subject, sender, date, read,
+/// local epoch seconds, mailbox, account, then the raw headers.
+#[cfg(target_os = "macos")]
+const THREAD_RECORD: &str = r#"set msgEpoch to ((date received of msg) - epochDate) as integer
+            set output to output & (id of msg) & ":::" & (subject of msg) & ":::" & (sender of msg) & ":::" & ((date received of msg) as string) & ":::" & (read status of msg) & ":::" & msgEpoch & ":::" & (name of mailbox of msg) & ":::" & (name of account of mailbox of msg) & ":::" & (all headers of msg) & "|||MSG|||""#;
+
+#[cfg(target_os = "macos")]
+fn script_thread_seed(message_id: &str) -> String {
+    format!(
+        r#"
+tell application "Mail"
+    {}
+    set output to ""
+    set msg to message id {}
+    {}
+    return output
+end tell
+"#,
+        script_epoch_date(),
+        message_id,
+        THREAD_RECORD
+    )
+}
+
+/// Messages whose subject contains `subject`, across every mailbox of
+/// `account` (or of all accounts).
+#[cfg(target_os = "macos")]
+fn script_thread_candidates(subject: &str, account: Option<&str>, limit: usize) -> String {
+    let accounts = match account {
+        Some(acc) => format!(r#"{{account "{}"}}"#, escape_applescript_string(acc)),
+        None => "accounts".to_string(),
+    };
+    format!(
+        r#"
+tell application "Mail"
+    {}
+    set output to ""
+    set found to 0
+    repeat with acc in {}
+        repeat with mb in mailboxes of acc
+            repeat with msg in (messages of mb whose subject contains "{}")
+                if found < {limit} then
+                    set found to found + 1
+                    {}
+                end if
+            end repeat
+        end repeat
+    end repeat
+    return output
+end tell
+"#,
+        script_epoch_date(),
+        accounts,
+        escape_applescript_string(subject),
+        THREAD_RECORD,
+        limit = limit
+    )
+}
+
 #[cfg(target_os = "macos")]
 fn script_search_messages(
     query: &str,
@@ -645,6 +857,109 @@ fn parse_message_content(output: &str, max_content_len: usize) -> Option<MailMes
     })
 }
 
+#[cfg(target_os = "macos")]
+fn parse_attachments(output: &str) -> Vec<MailAttachment> {
+    output
+        .split("|||")
+        .filter(|s| !s.is_empty())
+        .filter_map(|entry| {
+            let parts: Vec<&str> = entry.split(":::").collect();
+            if parts.len() >= 5 {
+                Some(MailAttachment {
+                    index: parts[0].trim().parse().ok()?,
+                    name: parts[1].to_string(),
+                    mime_type: parts[2].to_string(),
+                    size: parts[3].trim().parse().unwrap_or(0),
+                    downloaded: parts[4].trim() == "true",
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Thread records with their threading headers.
+#[cfg(target_os = "macos")]
+fn parse_thread_records(output: &str) -> Vec<(ThreadMessage, thread::ThreadHeaders)> {
+    output
+        .split("|||MSG|||")
+        .filter(|s| !s.trim().is_empty())
+        .filter_map(|entry| {
+            let parts: Vec<&str> = entry.splitn(9, ":::").collect();
+            if parts.len() < 9 {
+                return None;
+            }
+            let headers = thread::parse_headers(parts[8]);
+            let message = ThreadMessage {
+                message: MailMessage {
+                    id: parts[0].trim().to_string(),
+                    subject: parts[1].to_string(),
+                    sender: parts[2].to_string(),
+                    date_received: parts[3].to_string(),
+                    is_read: parts[4] == "true",
+                    is_flagged: false,
+                    recipients: Vec::new(),
+                    cc_recipients: Vec::new(),
+                    mailbox: parts[6].to_string(),
+                    account: parts[7].to_string(),
+                },
+                message_id: headers.message_id.clone(),
+                in_reply_to: headers.in_reply_to.first().cloned(),
+                received_epoch: parts[5].trim().parse().unwrap_or(0),
+            };
+            Some((message, headers))
+        })
+        .collect()
+}
+
+/// A file name safe to join onto the target directory.
+#[cfg(target_os = "macos")]
+fn safe_file_name(name: &str, index: usize) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '\0' => '_',
+            c => c,
+        })
+        .collect();
+    let cleaned = cleaned.trim().trim_start_matches('.').to_string();
+    if cleaned.is_empty() {
+        format!("attachment-{}", index)
+    } else {
+        cleaned
+    }
+}
+
+/// `path`, or `name (2).ext`, `name (3).ext`, ... if it exists or is
+/// already `taken` by another attachment in this call.
+#[cfg(target_os = "macos")]
+fn unique_path(
+    path: std::path::PathBuf,
+    taken: &std::collections::HashSet<std::path::PathBuf>,
+) -> std::path::PathBuf {
+    let free = |p: &std::path::PathBuf| !p.exists() && !taken.contains(p);
+    if free(&path) {
+        return path;
+    }
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let ext = path
+        .extension()
+        .map(|e| format!(".{}", e.to_string_lossy()))
+        .unwrap_or_default();
+    let mut n = 2;
+    loop {
+        let candidate = path.with_file_name(format!("{} ({}){}", stem, n, ext));
+        if free(&candidate) {
+            return candidate;
+        }
+        n += 1;
+    }
+}
+
 #[cfg(target_os = "macos")]
 fn parse_search_results(output: &str) -> Vec<MailMessage> {
     output
@@ -672,6 +987,26 @@ fn parse_search_results(output: &str) -> Vec<MailMessage> {
         .collect()
 }
 
+/// The `message_id` argument, which is spliced into scripts and so must be
+/// Mail.app's numeric id.
+#[cfg(target_os = "macos")]
+fn numeric_message_id(
+    args: &serde_json::Map<String, serde_json::Value>,
+) -> Result<&str, ConnectorError> {
+    let id = args
+        .get("message_id")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .ok_or_else(|| ConnectorError::InvalidParams("Missing 'message_id'".to_string()))?;
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ConnectorError::InvalidParams(format!(
+            "'message_id' must be a numeric Mail.app id, got '{}'",
+            id
+        )));
+    }
+    Ok(id)
+}
+
 // ============================================================================
 // Connector Implementation
 // ============================================================================
@@ -802,7 +1137,7 @@ Example: account=\"iCloud\".",
                 title: Some("List Messages".to_string()),
                 description: Some(Cow::Borrowed(
                     "List message summaries in a mailbox (requires explicit user permission). \
-Use get_message for full bodies. Example: mailbox=\"INBOX\" limit=20.",
+Use get_message for full bodies. Example: mailbox=\"INBOX\" since=\"2024-05-01\" limit=20.",
                 )),
                 input_schema: Arc::new(
                     json!({
@@ -810,7 +1145,9 @@ Use get_message for full bodies. Example: mailbox=\"INBOX\" limit=20.",
                         "properties": {
                             "mailbox": { "type": "string", "description": "Mailbox name (e.g., INBOX)." },
                             "account": { "type": "string", "description": "Optional account name (required if mailbox is ambiguous)." },
-                            "limit": { "type": "integer", "default": 20, "description": "Max messages (default 20, max 100)." }
+                            "limit": { "type": "integer", "default": 20, "description": "Max messages (default 20, max 100)." },
+                            "since": { "type": "string", "description": "Only messages received on or after this local date (YYYY-MM-DD, optionally HH:MM)." },
+                            "before": { "type": "string", "description": "Only messages received before this local date (YYYY-MM-DD, optionally HH:MM)." }
                         },
                         "required": ["mailbox"]
                     })
@@ -846,6 +1183,80 @@ max_content_length to keep output small. Example: message_id=\"123\" max_content
                 annotations: None,
                 icons: None,
             },
+            Tool {
+                name: Cow::Borrowed("list_attachments"),
+                title: Some("List Attachments".to_string()),
+                description: Some(Cow::Borrowed(
+                    "List a message's attachments with MIME type and size (requires explicit user \
+permission). Example: message_id=\"123\".",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "message_id": { "type": "string", "description": "Message ID from list_messages/search." }
+                        },
+                        "required": ["message_id"]
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("save_attachments"),
+                title: Some("Save Attachments".to_string()),
+                description: Some(Cow::Borrowed(
+                    "Save a message's attachments to a local folder (requires explicit user \
+permission). Existing files are never overwritten. Example: message_id=\"123\" \
+names=[\"invoice.pdf\"] directory=\"~/Downloads\".",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "message_id": { "type": "string", "description": "Message ID from list_messages/search." },
+                            "names": { "type": "array", "items": { "type": "string" }, "description": "Attachment names to save (default: all)." },
+                            "directory": { "type": "string", "description": "Folder to save into (default: ~/Downloads)." }
+                        },
+                        "required": ["message_id"]
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_thread"),
+                title: Some("Get Thread".to_string()),
+                description: Some(Cow::Borrowed(
+                    "Get every message in the conversation a message belongs to, across mailboxes \
+(including Sent), oldest first (requires explicit user permission). Example: message_id=\"123\".",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "message_id": { "type": "string", "description": "Any message in the thread." },
+                            "account": { "type": "string", "description": "Only look in this account (faster)." },
+                            "limit": { "type": "integer", "default": 50, "description": "Max messages (default 50)." }
+                        },
+                        "required": ["message_id"]
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
             Tool {
                 name: Cow::Borrowed("search"),
                 title: Some("Search Emails".to_string()),
@@ -978,9 +1389,20 @@ confirmation). If the user hasn't confirmed, use create_draft instead.",
                         .unwrap_or(20)
                         .min(100) as usize;
 
-                    let output =
-                        run_applescript_output(&script_list_messages(mailbox, account, limit))
-                            .await?;
+                    let since = parse_date_arg(&args, "since")?;
+                    let before = parse_date_arg(&args, "before")?;
+                    if let (Some(since), Some(before)) = (since, before) {
+                        if since >= before {
+                            return Err(ConnectorError::InvalidParams(
+                                "'since' must be earlier than 'before'".to_string(),
+                            ));
+                        }
+                    }
+
+                    let output = run_applescript_output(&script_list_messages(
+                        mailbox, account, limit, since, before,
+                    ))
+                    .await?;
                     let messages = parse_messages(&output, mailbox, account.unwrap_or(""));
                     structured_result_with_text(&messages, None)
                 }
@@ -1004,6 +1426,185 @@ confirmation). If the user hasn't confirmed, use create_draft instead.",
                     structured_result_with_text(&message, None)
                 }
 
+                "list_attachments" => {
+                    let message_id = numeric_message_id(&args)?;
+                    let output =
+                        run_applescript_output(&script_list_attachments(message_id)).await?;
+                    let attachments = parse_attachments(&output);
+                    structured_result_with_text(
+                        &json!({ "message_id": message_id, "attachments": attachments }),
+                        None,
+                    )
+                }
+
+                "save_attachments" => {
+                    let message_id = numeric_message_id(&args)?;
+                    let names: Vec<String> = args
+                        .get("names")
+                        .and_then(|v| v.as_array())
+                        .map(|a| {
+                            a.iter()
+                                .filter_map(|n| n.as_str())
+                                .map(|n| n.trim().to_string())
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    let directory = match args.get("directory").and_then(|v| v.as_str()) {
+                        Some(dir) => match dir.trim().strip_prefix("~/") {
+                            Some(rest) => dirs::home_dir()
+                                .ok_or_else(|| {
+                                    ConnectorError::Other("No home directory".to_string())
+                                })?
+                                .join(rest),
+                            None => std::path::PathBuf::from(dir.trim()),
+                        },
+                        None => dirs::download_dir()
+                            .or_else(|| dirs::home_dir().map(|h| h.join("Downloads")))
+                            .ok_or_else(|| {
+                                ConnectorError::Other("No Downloads directory".to_string())
+                            })?,
+                    };
+
+                    let output =
+                        run_applescript_output(&script_list_attachments(message_id)).await?;
+                    let attachments = parse_attachments(&output);
+                    let selected: Vec<&MailAttachment> = attachments
+                        .iter()
+                        .filter(|a| {
+                            names.is_empty()
+                                || names.iter().any(|n| n.eq_ignore_ascii_case(&a.name))
+                        })
+                        .collect();
+                    if selected.is_empty() {
+                        let available: Vec<&str> =
+                            attachments.iter().map(|a| a.name.as_str()).collect();
+                        return Err(ConnectorError::InvalidParams(if attachments.is_empty() {
+                            "Message has no attachments".to_string()
+                        } else {
+                            format!("No attachment matched; available: {}", available.join(", "))
+                        }));
+                    }
+
+                    std::fs::create_dir_all(&directory)?;
+                    let mut taken = std::collections::HashSet::new();
+                    let mut targets = Vec::new();
+                    for attachment in &selected {
+                        let path = unique_path(
+                            directory.join(safe_file_name(&attachment.name, attachment.index)),
+                            &taken,
+                        );
+                        taken.insert(path.clone());
+                        targets.push((attachment.index, path.to_string_lossy().into_owned()));
+                    }
+
+                    let output =
+                        run_applescript_output(&script_save_attachments(message_id, &targets))
+                            .await?;
+                    let mut saved = Vec::new();
+                    let mut failed = Vec::new();
+                    for result in output.split("|||").filter(|s| !s.is_empty()) {
+                        let Some((index, status)) = result.split_once(":::") else {
+                            continue;
+                        };
+                        let index: usize = index.trim().parse().unwrap_or(0);
+                        let Some(attachment) = selected.iter().find(|a| a.index == index) else {
+                            continue;
+                        };
+                        let Some((_, path)) = targets.iter().find(|(i, _)| *i == index) else {
+                            continue;
+                        };
+                        let size = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+                        if status == "ok" && size > 0 {
+                            saved.push(json!({
+                                "name": attachment.name,
+                                "mime_type": attachment.mime_type,
+                                "path": path,
+                                "size": size,
+                            }));
+                        } else {
+                            let _ = std::fs::remove_file(path);
+                            let error = if status == "ok" {
+                                "Nothing was written (attachment not downloaded?)"
+                            } else {
+                                status
+                            };
+                            failed.push(json!({ "name": attachment.name, "error": error }));
+                        }
+                    }
+                    structured_result_with_text(
+                        &json!({
+                            "message_id": message_id,
+                            "directory": directory,
+                            "saved": saved,
+                            "failed": failed,
+                        }),
+                        None,
+                    )
+                }
+
+                "get_thread" => {
+                    let message_id = numeric_message_id(&args)?;
+                    let account = args.get("account").and_then(|v| v.as_str());
+                    let limit = args
+                        .get("limit")
+                        .and_then(|v| v.as_u64())
+                        .unwrap_or(50)
+                        .clamp(1, MAX_THREAD_CANDIDATES as u64)
+                        as usize;
+
+                    let output = run_applescript_output(&script_thread_seed(message_id)).await?;
+                    let (seed, seed_headers) = parse_thread_records(&output)
+                        .into_iter()
+                        .next()
+                        .ok_or_else(|| {
+                            ConnectorError::Other("Failed to parse message".to_string())
+                        })?;
+
+                    let subject = thread::normalize_subject(&seed.message.subject);
+                    let mut records = vec![(seed, seed_headers.clone())];
+                    // Without a subject or a Message-ID there's nothing to link by
+                    if !subject.is_empty() && seed_headers.message_id.is_some() {
+                        let output = run_applescript_output(&script_thread_candidates(
+                            &subject,
+                            account,
+                            MAX_THREAD_CANDIDATES,
+                        ))
+                        .await?;
+                        records.extend(parse_thread_records(&output));
+                    }
+
+                    let headers: Vec<thread::ThreadHeaders> =
+                        records.iter().map(|(_, h)| h.clone()).collect();
+                    let members = thread::thread_members(&seed_headers, &headers);
+                    let mut messages: Vec<ThreadMessage> = records
+                        .into_iter()
+                        .enumerate()
+                        .filter(|(i, _)| *i == 0 || members.contains(i))
+                        .map(|(_, (m, _))| m)
+                        .collect();
+                    // The seed may also have come back as a candidate
+                    let mut ids = std::collections::HashSet::new();
+                    messages.retain(|m| {
+                        ids.insert(m.message_id.clone().unwrap_or_else(|| m.message.id.clone()))
+                    });
+                    messages.sort_by_key(|m| m.received_epoch);
+                    let total = messages.len();
+                    // Keep the latest messages when the thread is longer than the limit
+                    let skip = total.saturating_sub(limit);
+                    let messages: Vec<ThreadMessage> = messages.into_iter().skip(skip).collect();
+
+                    structured_result_with_text(
+                        &json!({
+                            "message_id": message_id,
+                            "subject": subject,
+                            "count": total,
+                            "truncated": skip > 0,
+                            "messages": messages,
+                        }),
+                        None,
+                    )
+                }
+
                 "search" => {
                     let query = args.get("query").and_then(|v| v.as_str()).ok_or_else(|| {
                         ConnectorError::InvalidParams("Missing 'query'".to_string())
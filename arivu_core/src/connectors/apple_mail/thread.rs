@@ -0,0 +1,159 @@
+// src/connectors/apple_mail/thread.rs
+//
+// Conversation threading for Mail.app, which doesn't expose its own thread
+// grouping to AppleScript. Candidates are found by subject, then kept only
+// if their Message-ID / In-Reply-To / References headers link them to the
+// starting message, directly or through another member.
+
+use std::collections::HashSet;
+
+/// The threading headers of one message.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct ThreadHeaders {
+    pub message_id: Option<String>,
+    pub in_reply_to: Vec<String>,
+    pub references: Vec<String>,
+}
+
+impl ThreadHeaders {
+    fn ids(&self) -> impl Iterator<Item = &String> {
+        self.message_id
+            .iter()
+            .chain(&self.in_reply_to)
+            .chain(&self.references)
+    }
+}
+
+/// `<id>` tokens of a header value, without the brackets.
+fn message_ids(value: &str) -> Vec<String> {
+    value
+        .split('<')
+        .skip(1)
+        .filter_map(|s| s.split_once('>').map(|(id, _)| id.trim()))
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Threading headers from a raw header block (`all headers` of a message).
+pub(super) fn parse_headers(raw: &str) -> ThreadHeaders {
+    // Unfold continuation lines first
+    let mut lines: Vec<String> = Vec::new();
+    for line in raw.lines() {
+        if line.starts_with([' ', '\t']) {
+            if let Some(last) = lines.last_mut() {
+                last.push(' ');
+                last.push_str(line.trim());
+                continue;
+            }
+        }
+        lines.push(line.to_string());
+    }
+
+    let mut headers = ThreadHeaders::default();
+    for line in &lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        match name.trim().to_ascii_lowercase().as_str() {
+            "message-id" if headers.message_id.is_none() => {
+                headers.message_id = message_ids(value).into_iter().next();
+            }
+            "in-reply-to" => headers.in_reply_to.extend(message_ids(value)),
+            "references" => headers.references.extend(message_ids(value)),
+            _ => {}
+        }
+    }
+    headers
+}
+
+/// Subject with reply/forward prefixes removed, for finding candidates.
+pub(super) fn normalize_subject(subject: &str) -> String {
+    const PREFIXES: &[&str] = &["re", "fw", "fwd", "aw", "sv", "wg", "antw"];
+    let mut s = subject.trim();
+    loop {
+        let Some((head, rest)) = s.split_once(':') else {
+            break;
+        };
+        // "Re[2]:" and "RE :" count too
+        let head = head.trim().to_ascii_lowercase();
+        let head = head.split('[').next().unwrap_or_default().trim();
+        if !PREFIXES.contains(&head) {
+            break;
+        }
+        s = rest.trim_start();
+    }
+    s.trim().to_string()
+}
+
+/// Indices of `candidates` linked to `seed`, skipping repeats of a
+/// Message-ID (the same message filed in two mailboxes).
+pub(super) fn thread_members(seed: &ThreadHeaders, candidates: &[ThreadHeaders]) -> Vec<usize> {
+    let mut known: HashSet<&String> = seed.ids().collect();
+    let mut included = vec![false; candidates.len()];
+    let mut seen_ids: HashSet<&String> = HashSet::new();
+    let mut members = Vec::new();
+    loop {
+        let mut changed = false;
+        for (i, candidate) in candidates.iter().enumerate() {
+            if included[i] || !candidate.ids().any(|id| known.contains(id)) {
+                continue;
+            }
+            included[i] = true;
+            changed = true;
+            if let Some(id) = &candidate.message_id {
+                if !seen_ids.insert(id) {
+                    continue;
+                }
+            }
+            known.extend(candidate.ids());
+            members.push(i);
+        }
+        if !changed {
+            break;
+        }
+    }
+    members.sort_unstable();
+    members
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(id: &str, refs: &[&str]) -> ThreadHeaders {
+        ThreadHeaders {
+            message_id: Some(id.to_string()),
+            in_reply_to: refs.last().map(|r| r.to_string()).into_iter().collect(),
+            references: refs.iter().map(|r| r.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn parses_headers_and_links_thread() {
+        let raw = "From: Ana <ana@example.com>\n\
+Subject: Re: Q3 plan\n\
+Message-Id: <c@example.com>\n\
+In-Reply-To: <b@example.com>\n\
+References: <a@example.com>\n\
+\t<b@example.com>\n";
+        assert_eq!(
+            parse_headers(raw),
+            headers("c@example.com", &["a@example.com", "b@example.com"])
+        );
+
+        assert_eq!(normalize_subject("RE: Fwd: Re[2]: Q3 plan"), "Q3 plan");
+        assert_eq!(normalize_subject("Agenda: Q3"), "Agenda: Q3");
+
+        let seed = headers("b", &["a"]);
+        let candidates = vec![
+            headers("a", &[]),
+            headers("x", &[]),    // same subject, unrelated
+            headers("d", &["c"]), // linked only through c
+            headers("c", &["a", "b"]),
+            headers("b", &["a"]), // seed again, from another mailbox
+            headers("b", &["a"]),
+        ];
+        assert_eq!(thread_members(&seed, &candidates), vec![0, 2, 3, 4]);
+    }
+}
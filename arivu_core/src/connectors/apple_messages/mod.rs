@@ -3,9 +3,8 @@
 //
 // Note: Messages.app has limited AppleScript support for privacy reasons.
 // - Sending messages: Fully supported
-// - Reading conversations: Limited (basic chat listing, requires Full Disk Access for history)
-// - The Messages SQLite database at ~/Library/Messages/chat.db contains full history
-//   but requires special permissions to access.
+// - Reading conversations: via the Messages SQLite database at ~/Library/Messages/chat.db,
+//   opened read-only with the sqlite3 CLI. Requires Full Disk Access for the host app.
 
 #[cfg(target_os = "macos")]
 use crate::connectors::apple_common::{
@@ -205,32 +204,243 @@ struct ChatListing {
     last_message_date: String,
 }
 
-// List chats from SQLite database - more reliable than AppleScript
+/// A single message row from chat.db
+#[derive(Debug, Serialize, Deserialize)]
+struct MessageRecord {
+    id: i64,
+    /// Conversation the message belongs to (phone/email or group id)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chat_identifier: Option<String>,
+    /// Local time the message was sent
+    date: String,
+    /// Handle of the other party (phone/email); empty for your own messages in 1:1 chats
+    sender: String,
+    is_from_me: bool,
+    text: String,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    has_attachments: bool,
+}
+
+// ============================================================================
+// Messages Database (read-only)
+// ============================================================================
+
+/// Seconds between the Unix epoch and Apple's reference date (2001-01-01 UTC).
+const APPLE_EPOCH_OFFSET: i64 = 978_307_200;
+
+/// Columns selected for every message query; rows are mapped by `message_from_row`.
+const MESSAGE_COLUMNS: &str = r#"
+    m.rowid AS id,
+    m.text,
+    hex(m.attributedBody) AS body_hex,
+    datetime(m.date/1000000000 + 978307200, 'unixepoch', 'localtime') AS date,
+    m.is_from_me,
+    COALESCE(h.id, '') AS sender,
+    c.chat_identifier,
+    m.cache_has_attachments AS has_attachments
+"#;
+
 #[cfg(target_os = "macos")]
-async fn list_chats_from_db(limit: usize) -> Result<Vec<ChatListing>, ConnectorError> {
+fn chat_db_path() -> Result<std::path::PathBuf, ConnectorError> {
+    Ok(dirs::home_dir()
+        .ok_or_else(|| ConnectorError::Other("Cannot find home directory".to_string()))?
+        .join("Library/Messages/chat.db"))
+}
+
+/// Error explaining how to grant Full Disk Access, naming the host app when known.
+#[cfg(target_os = "macos")]
+fn full_disk_access_error() -> ConnectorError {
+    let host = match std::env::var("TERM_PROGRAM").ok().as_deref() {
+        Some("Apple_Terminal") => "Terminal".to_string(),
+        Some("iTerm.app") => "iTerm".to_string(),
+        Some("vscode") => "Visual Studio Code".to_string(),
+        Some(other) if !other.is_empty() => other.to_string(),
+        _ => "the app running arivu (your terminal or MCP client)".to_string(),
+    };
+    ConnectorError::Other(format!(
+        "Cannot read the Messages database: Full Disk Access is not granted. Open System Settings > Privacy & Security > Full Disk Access, enable {}, then quit and reopen it and try again.",
+        host
+    ))
+}
+
+/// Check chat.db can be opened before shelling out to sqlite3.
+///
+/// Without Full Disk Access the file is hidden from `Path::exists`, so the
+/// open error kind is what tells a missing database apart from a missing grant.
+#[cfg(target_os = "macos")]
+fn check_chat_db_access(path: &std::path::Path) -> Result<(), ConnectorError> {
+    match std::fs::File::open(path) {
+        Ok(_) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+            Err(full_disk_access_error())
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(ConnectorError::Other(
+            "Messages database not found at ~/Library/Messages/chat.db. Make sure Messages.app has been used on this Mac.".to_string(),
+        )),
+        Err(e) => Err(ConnectorError::Other(format!(
+            "Cannot open Messages database: {}",
+            e
+        ))),
+    }
+}
+
+/// Run a query against chat.db in read-only mode and return the rows.
+#[cfg(target_os = "macos")]
+async fn query_chat_db(sql: &str) -> Result<Vec<serde_json::Value>, ConnectorError> {
     use tokio::process::Command;
 
-    let db_path = dirs::home_dir()
-        .ok_or_else(|| ConnectorError::Other("Cannot find home directory".to_string()))?
-        .join("Library/Messages/chat.db");
+    let db_path = chat_db_path()?;
+    check_chat_db_access(&db_path)?;
+
+    let output = Command::new("sqlite3")
+        .arg("-readonly")
+        .arg("-json")
+        .arg(&db_path)
+        .arg(sql)
+        .output()
+        .await
+        .map_err(|e| ConnectorError::Other(format!("Failed to query messages database: {}", e)))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("unable to open database")
+            || stderr.contains("authorization denied")
+            || stderr.contains("not authorized")
+        {
+            return Err(full_disk_access_error());
+        }
+        return Err(ConnectorError::Other(format!(
+            "Database query failed: {}",
+            stderr
+        )));
+    }
 
-    if !db_path.exists() {
-        return Err(ConnectorError::Other(
-            "Messages database not found. Make sure Messages.app has been used.".to_string(),
-        ));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if stdout.trim().is_empty() {
+        return Ok(Vec::new());
     }
 
-    // Get chats with their last message (truncated to 80 chars for preview)
+    serde_json::from_str(&stdout)
+        .map_err(|e| ConnectorError::Other(format!("Failed to parse query results: {}", e)))
+}
+
+/// Quote a value as an SQL string literal.
+fn sql_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+/// Build a `LIKE` pattern matching `s` anywhere, for use with `ESCAPE '\'`.
+fn sql_like_contains(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    sql_quote(&format!("%{}%", escaped))
+}
+
+/// Convert a local date/time into chat.db `date` units (nanoseconds since 2001-01-01 UTC).
+fn apple_message_timestamp(dt: chrono::NaiveDateTime) -> Option<i64> {
+    use chrono::TimeZone;
+    let local = chrono::Local.from_local_datetime(&dt).earliest()?;
+    Some((local.timestamp() - APPLE_EPOCH_OFFSET) * 1_000_000_000)
+}
+
+/// Parse an optional `since`/`until` argument into a chat.db timestamp.
+fn date_arg(
+    args: &serde_json::Map<String, serde_json::Value>,
+    key: &str,
+) -> Result<Option<i64>, ConnectorError> {
+    let Some(raw) = args.get(key).and_then(|v| v.as_str()) else {
+        return Ok(None);
+    };
+    crate::connectors::apple_common::parse_local_datetime(raw)
+        .and_then(apple_message_timestamp)
+        .map(Some)
+        .ok_or_else(|| {
+            ConnectorError::InvalidParams(format!(
+                "Invalid '{}': expected YYYY-MM-DD or YYYY-MM-DDTHH:MM",
+                key
+            ))
+        })
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Extract the plain text from a message's `attributedBody` typedstream.
+///
+/// Newer macOS releases often leave `message.text` NULL and only store the
+/// archived NSAttributedString, whose first NSString holds the message text.
+fn attributed_body_text(blob: &[u8]) -> Option<String> {
+    const MARKER: &[u8] = b"NSString";
+    let start = blob.windows(MARKER.len()).position(|w| w == MARKER)? + MARKER.len();
+    // Class version and the "+" type tag sit between the class name and the length.
+    let rest = blob.get(start + 5..)?;
+    let (len, offset) = match *rest.first()? {
+        0x81 => (
+            u16::from_le_bytes([*rest.get(1)?, *rest.get(2)?]) as usize,
+            3,
+        ),
+        0x82 => (
+            u32::from_le_bytes([*rest.get(1)?, *rest.get(2)?, *rest.get(3)?, *rest.get(4)?])
+                as usize,
+            5,
+        ),
+        n => (n as usize, 1),
+    };
+    let bytes = rest.get(offset..offset.checked_add(len)?)?;
+    Some(String::from_utf8_lossy(bytes).into_owned())
+}
+
+/// Message text, falling back to the decoded `attributedBody` hex column.
+fn message_text(text: Option<&str>, body_hex: Option<&str>) -> String {
+    match text {
+        Some(t) if !t.is_empty() => t.to_string(),
+        _ => body_hex
+            .and_then(decode_hex)
+            .and_then(|b| attributed_body_text(&b))
+            .unwrap_or_default(),
+    }
+}
+
+fn message_from_row(row: &serde_json::Value) -> MessageRecord {
+    MessageRecord {
+        id: row["id"].as_i64().unwrap_or_default(),
+        chat_identifier: row["chat_identifier"].as_str().map(str::to_string),
+        date: row["date"].as_str().unwrap_or("").to_string(),
+        sender: row["sender"].as_str().unwrap_or("").to_string(),
+        is_from_me: row["is_from_me"].as_i64().unwrap_or(0) != 0,
+        text: message_text(row["text"].as_str(), row["body_hex"].as_str()),
+        has_attachments: row["has_attachments"].as_i64().unwrap_or(0) != 0,
+    }
+}
+
+// List chats from SQLite database - more reliable than AppleScript
+#[cfg(target_os = "macos")]
+async fn list_chats_from_db(limit: usize) -> Result<Vec<ChatListing>, ConnectorError> {
+    // Get chats with their last message (text preview decoded in Rust when only
+    // attributedBody is populated)
     let query = format!(
         r#"
         SELECT
             c.chat_identifier,
             COALESCE(c.display_name, '') as display_name,
             COALESCE(c.service_name, '') as service_name,
-            COALESCE(substr((SELECT m.text FROM message m
+            (SELECT m.text FROM message m
+             JOIN chat_message_join cmj ON m.rowid = cmj.message_id
+             WHERE cmj.chat_id = c.rowid
+             ORDER BY m.date DESC LIMIT 1) as last_message,
+            (SELECT hex(m.attributedBody) FROM message m
              JOIN chat_message_join cmj ON m.rowid = cmj.message_id
              WHERE cmj.chat_id = c.rowid
-             ORDER BY m.date DESC LIMIT 1), 1, 80), '') as last_message,
+             ORDER BY m.date DESC LIMIT 1) as last_body_hex,
             COALESCE((SELECT datetime(m.date/1000000000 + 978307200, 'unixepoch', 'localtime')
              FROM message m
              JOIN chat_message_join cmj ON m.rowid = cmj.message_id
@@ -247,35 +457,7 @@ async fn list_chats_from_db(limit: usize) -> Result<Vec<ChatListing>, ConnectorE
         limit
     );
 
-    let output = Command::new("sqlite3")
-        .arg("-json")
-        .arg(&db_path)
-        .arg(&query)
-        .output()
-        .await
-        .map_err(|e| ConnectorError::Other(format!("Failed to query messages database: {}", e)))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        if stderr.contains("unable to open database") || stderr.contains("authorization denied") {
-            return Err(ConnectorError::Other(
-                "Cannot access Messages database. Grant Full Disk Access to your terminal/app in System Preferences > Security & Privacy > Privacy > Full Disk Access."
-                    .to_string(),
-            ));
-        }
-        return Err(ConnectorError::Other(format!(
-            "Database query failed: {}",
-            stderr
-        )));
-    }
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    if stdout.trim().is_empty() {
-        return Ok(Vec::new());
-    }
-
-    let raw_chats: Vec<serde_json::Value> = serde_json::from_str(&stdout)
-        .map_err(|e| ConnectorError::Other(format!("Failed to parse chats: {}", e)))?;
+    let raw_chats = query_chat_db(&query).await?;
 
     // Convert to our struct for better display
     let chats: Vec<ChatListing> = raw_chats
@@ -284,7 +466,10 @@ async fn list_chats_from_db(limit: usize) -> Result<Vec<ChatListing>, ConnectorE
             chat_identifier: c["chat_identifier"].as_str().unwrap_or("").to_string(),
             display_name: c["display_name"].as_str().unwrap_or("").to_string(),
             service: c["service_name"].as_str().unwrap_or("").to_string(),
-            last_message: c["last_message"].as_str().unwrap_or("").to_string(),
+            last_message: message_text(c["last_message"].as_str(), c["last_body_hex"].as_str())
+                .chars()
+                .take(80)
+                .collect(),
             last_message_date: c["last_message_date"].as_str().unwrap_or("").to_string(),
         })
         .collect();
@@ -297,92 +482,91 @@ async fn list_chats_from_db(limit: usize) -> Result<Vec<ChatListing>, ConnectorE
 #[cfg(target_os = "macos")]
 async fn read_recent_messages_from_db(
     chat_identifier: Option<&str>,
+    since: Option<i64>,
+    until: Option<i64>,
     limit: usize,
-) -> Result<Vec<serde_json::Value>, ConnectorError> {
-    use tokio::process::Command;
-
-    let db_path = dirs::home_dir()
-        .ok_or_else(|| ConnectorError::Other("Cannot find home directory".to_string()))?
-        .join("Library/Messages/chat.db");
-
-    if !db_path.exists() {
-        return Err(ConnectorError::Other(
-            "Messages database not found. Make sure Messages.app has been used.".to_string(),
-        ));
+) -> Result<Vec<MessageRecord>, ConnectorError> {
+    let mut conditions = vec!["(m.text IS NOT NULL OR m.attributedBody IS NOT NULL)".to_string()];
+    if let Some(chat) = chat_identifier {
+        conditions.push(format!("c.chat_identifier = {}", sql_quote(chat)));
+    }
+    if let Some(ts) = since {
+        conditions.push(format!("m.date >= {}", ts));
+    }
+    if let Some(ts) = until {
+        conditions.push(format!("m.date < {}", ts));
     }
 
-    let query = match chat_identifier {
-        Some(chat) => format!(
-            r#"
-            SELECT
-                m.rowid as id,
-                m.text,
-                datetime(m.date/1000000000 + 978307200, 'unixepoch', 'localtime') as date,
-                m.is_from_me,
-                h.id as sender
-            FROM message m
-            LEFT JOIN handle h ON m.handle_id = h.rowid
-            LEFT JOIN chat_message_join cmj ON m.rowid = cmj.message_id
-            LEFT JOIN chat c ON cmj.chat_id = c.rowid
-            WHERE c.chat_identifier = '{}'
-            ORDER BY m.date DESC
-            LIMIT {}
-            "#,
-            chat.replace('\'', "''"),
-            limit
-        ),
-        None => format!(
-            r#"
-            SELECT
-                m.rowid as id,
-                m.text,
-                datetime(m.date/1000000000 + 978307200, 'unixepoch', 'localtime') as date,
-                m.is_from_me,
-                h.id as sender,
-                c.chat_identifier
-            FROM message m
-            LEFT JOIN handle h ON m.handle_id = h.rowid
-            LEFT JOIN chat_message_join cmj ON m.rowid = cmj.message_id
-            LEFT JOIN chat c ON cmj.chat_id = c.rowid
-            WHERE m.text IS NOT NULL
-            ORDER BY m.date DESC
-            LIMIT {}
-            "#,
-            limit
-        ),
-    };
+    let query = format!(
+        r#"
+        SELECT {}
+        FROM message m
+        LEFT JOIN handle h ON m.handle_id = h.rowid
+        LEFT JOIN chat_message_join cmj ON m.rowid = cmj.message_id
+        LEFT JOIN chat c ON cmj.chat_id = c.rowid
+        WHERE {}
+        ORDER BY m.date DESC
+        LIMIT {}
+        "#,
+        MESSAGE_COLUMNS,
+        conditions.join(" AND "),
+        limit
+    );
 
-    let output = Command::new("sqlite3")
-        .arg("-json")
-        .arg(&db_path)
-        .arg(&query)
-        .output()
-        .await
-        .map_err(|e| ConnectorError::Other(format!("Failed to query messages database: {}", e)))?;
+    let rows = query_chat_db(&query).await?;
+    Ok(rows.iter().map(message_from_row).collect())
+}
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        if stderr.contains("unable to open database") || stderr.contains("authorization denied") {
-            return Err(ConnectorError::Other(
-                "Cannot access Messages database. Grant Full Disk Access to your terminal/app in System Preferences > Security & Privacy > Privacy > Full Disk Access."
-                    .to_string(),
-            ));
-        }
-        return Err(ConnectorError::Other(format!(
-            "Database query failed: {}",
-            stderr
-        )));
+/// Search message text across conversations, newest first.
+///
+/// `text` is matched with a case-insensitive LIKE; messages that only carry an
+/// attributedBody are matched byte-wise and re-checked after decoding.
+#[cfg(target_os = "macos")]
+async fn search_messages_in_db(
+    query: &str,
+    chat_identifier: Option<&str>,
+    since: Option<i64>,
+    until: Option<i64>,
+    limit: usize,
+) -> Result<Vec<MessageRecord>, ConnectorError> {
+    let mut conditions = vec![format!(
+        "(m.text LIKE {} ESCAPE '\\' OR (m.text IS NULL AND instr(m.attributedBody, CAST({} AS BLOB)) > 0))",
+        sql_like_contains(query),
+        sql_quote(query)
+    )];
+    if let Some(chat) = chat_identifier {
+        conditions.push(format!("c.chat_identifier = {}", sql_quote(chat)));
     }
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    if stdout.trim().is_empty() {
-        return Ok(Vec::new());
+    if let Some(ts) = since {
+        conditions.push(format!("m.date >= {}", ts));
+    }
+    if let Some(ts) = until {
+        conditions.push(format!("m.date < {}", ts));
     }
 
-    let messages: Vec<serde_json::Value> = serde_json::from_str(&stdout)
-        .map_err(|e| ConnectorError::Other(format!("Failed to parse messages: {}", e)))?;
+    let sql = format!(
+        r#"
+        SELECT {}
+        FROM message m
+        LEFT JOIN handle h ON m.handle_id = h.rowid
+        LEFT JOIN chat_message_join cmj ON m.rowid = cmj.message_id
+        LEFT JOIN chat c ON cmj.chat_id = c.rowid
+        WHERE {}
+        ORDER BY m.date DESC
+        LIMIT {}
+        "#,
+        MESSAGE_COLUMNS,
+        conditions.join(" AND "),
+        limit
+    );
 
-    Ok(messages)
+    let needle = query.to_lowercase();
+    let rows = query_chat_db(&sql).await?;
+    Ok(rows
+        .iter()
+        .map(message_from_row)
+        .filter(|m| m.text.to_lowercase().contains(&needle))
+        .collect())
 }
 
 // ============================================================================
@@ -444,7 +628,7 @@ impl crate::Connector for AppleMessagesConnector {
     }
 
     fn description(&self) -> &'static str {
-        "Apple Messages.app connector for macOS. Send iMessages and SMS. Read conversation history (requires Full Disk Access for message history). Works with phone numbers and email addresses."
+        "Apple Messages.app connector for macOS. List conversations, search message text and read history from the local Messages database (read-only, requires Full Disk Access). Send iMessages and SMS to phone numbers and email addresses."
     }
 
     async fn capabilities(&self) -> ServerCapabilities {
@@ -502,7 +686,7 @@ impl crate::Connector for AppleMessagesConnector {
                 website_url: None,
             },
             instructions: Some(
-                "Native Messages.app integration for iMessage and SMS. First use triggers permission prompts. Message history requires Full Disk Access."
+                "Native Messages.app integration for iMessage and SMS. Conversations are read from ~/Library/Messages/chat.db in read-only mode, which requires Full Disk Access for the host app; sending triggers an Automation prompt on first use."
                     .to_string(),
             ),
         })
@@ -586,7 +770,7 @@ impl crate::Connector for AppleMessagesConnector {
                 name: Cow::Borrowed("get_recent_messages"),
                 title: Some("Get Recent Messages".to_string()),
                 description: Some(Cow::Borrowed(
-                    "Read recent message history from Messages database. REQUIRES Full Disk Access permission granted to your terminal/app. Returns message text, sender, date, and direction (sent/received).",
+                    "Read recent message history from the Messages database (read-only), newest first. REQUIRES Full Disk Access permission granted to your terminal/app. Returns message text, sender, date, and direction (sent/received); optionally bounded by since/until.",
                 )),
                 input_schema: Arc::new(
                     json!({
@@ -596,6 +780,14 @@ impl crate::Connector for AppleMessagesConnector {
                                 "type": "string",
                                 "description": "Filter to specific chat (phone number like +1234567890 or email). If omitted, returns messages from all chats."
                             },
+                            "since": {
+                                "type": "string",
+                                "description": "Only messages on/after this local date or time (YYYY-MM-DD or YYYY-MM-DDTHH:MM)."
+                            },
+                            "until": {
+                                "type": "string",
+                                "description": "Only messages before this local date or time (YYYY-MM-DD or YYYY-MM-DDTHH:MM)."
+                            },
                             "limit": {
                                 "type": "integer",
                                 "description": "Maximum messages to return. Default: 50.",
@@ -611,6 +803,48 @@ impl crate::Connector for AppleMessagesConnector {
                 annotations: None,
                 icons: None,
             },
+            Tool {
+                name: Cow::Borrowed("search_messages"),
+                title: Some("Search Messages".to_string()),
+                description: Some(Cow::Borrowed(
+                    "Search message text across all conversations (or one chat), newest first. REQUIRES Full Disk Access. Returns chat_identifier, sender, date, direction and text for each match.",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "query": {
+                                "type": "string",
+                                "description": "Text to find (substring match, case-insensitive). Required."
+                            },
+                            "chat_identifier": {
+                                "type": "string",
+                                "description": "Restrict to one chat (phone number, email or group chat identifier from list_chats)."
+                            },
+                            "since": {
+                                "type": "string",
+                                "description": "Only messages on/after this local date or time (YYYY-MM-DD or YYYY-MM-DDTHH:MM)."
+                            },
+                            "until": {
+                                "type": "string",
+                                "description": "Only messages before this local date or time (YYYY-MM-DD or YYYY-MM-DDTHH:MM)."
+                            },
+                            "limit": {
+                                "type": "integer",
+                                "description": "Maximum matches to return. Default: 25.",
+                                "default": 25
+                            }
+                        },
+                        "required": ["query"]
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
             // Sending Messages
             Tool {
                 name: Cow::Borrowed("send_message"),
@@ -708,7 +942,7 @@ impl crate::Connector for AppleMessagesConnector {
             .filter(|t| {
                 matches!(
                     t.name.as_ref(),
-                    "list_chats" | "get_recent_messages" | "send_message"
+                    "list_chats" | "get_recent_messages" | "search_messages" | "send_message"
                 )
             })
             .collect();
@@ -806,12 +1040,43 @@ impl crate::Connector for AppleMessagesConnector {
 
                 "get_recent_messages" => {
                     let chat_identifier = args.get("chat_identifier").and_then(|v| v.as_str());
+                    let since = date_arg(&args, "since")?;
+                    let until = date_arg(&args, "until")?;
                     let limit = args.get("limit").and_then(|v| v.as_u64()).unwrap_or(50) as usize;
 
-                    let messages = read_recent_messages_from_db(chat_identifier, limit).await?;
+                    let messages =
+                        read_recent_messages_from_db(chat_identifier, since, until, limit).await?;
                     structured_result_with_text(&messages, None)
                 }
 
+                "search_messages" => {
+                    let query = args
+                        .get("query")
+                        .and_then(|v| v.as_str())
+                        .map(str::trim)
+                        .filter(|q| !q.is_empty())
+                        .ok_or_else(|| {
+                            ConnectorError::InvalidParams("Missing 'query'".to_string())
+                        })?;
+                    let chat_identifier = args.get("chat_identifier").and_then(|v| v.as_str());
+                    let since = date_arg(&args, "since")?;
+                    let until = date_arg(&args, "until")?;
+                    let limit = args
+                        .get("limit")
+                        .and_then(|v| v.as_u64())
+                        .unwrap_or(25)
+                        .clamp(1, 200) as usize;
+
+                    let messages =
+                        search_messages_in_db(query, chat_identifier, since, until, limit).await?;
+                    let output = json!({
+                        "query": query,
+                        "count": messages.len(),
+                        "messages": messages,
+                    });
+                    structured_result_with_text(&output, None)
+                }
+
                 "send_message" => {
                     let recipient =
                         args.get("recipient")
@@ -896,3 +1161,42 @@ impl crate::Connector for AppleMessagesConnector {
         Err(ConnectorError::ResourceNotFound)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attributed_body_text() {
+        let mut blob = b"\x04\x0bstreamtyped\x81\xe8\x03\x84\x01@\x84\x84\x84\x12NSAttributedString\x00\x84\x84\x08NSObject\x00\x85\x92\x84\x84\x84\x08NSString\x01\x94\x84\x01+".to_vec();
+        blob.push(5);
+        blob.extend_from_slice(b"hello\x86\x84\x02iI");
+        assert_eq!(attributed_body_text(&blob).as_deref(), Some("hello"));
+
+        let long = "x".repeat(300);
+        let mut blob = b"NSString\x01\x94\x84\x01+\x81".to_vec();
+        blob.extend_from_slice(&300u16.to_le_bytes());
+        blob.extend_from_slice(long.as_bytes());
+        assert_eq!(attributed_body_text(&blob), Some(long));
+
+        assert!(attributed_body_text(b"NSString\x01\x94\x84\x01+\x09short").is_none());
+        assert!(attributed_body_text(b"no marker").is_none());
+    }
+
+    #[test]
+    fn test_message_text_fallback() {
+        assert_eq!(message_text(Some("plain"), Some("ZZ")), "plain");
+        let hex: String = b"NSString\x01\x94\x84\x01+\x02hi"
+            .iter()
+            .map(|b| format!("{:02X}", b))
+            .collect();
+        assert_eq!(message_text(None, Some(&hex)), "hi");
+        assert_eq!(message_text(Some(""), None), "");
+    }
+
+    #[test]
+    fn test_sql_escaping() {
+        assert_eq!(sql_quote("it's"), "'it''s'");
+        assert_eq!(sql_like_contains("50%_off\\"), r"'%50\%\_off\\%'");
+    }
+}
@@ -7,9 +7,10 @@
 #[cfg(target_os = "macos")]
 use crate::connectors::apple_common::{
     apple_connector_capabilities, escape_applescript_string, run_applescript_output,
+    unquote_applescript_string,
 };
 use crate::error::ConnectorError;
-use crate::utils::structured_result_with_text;
+use crate::utils::{structured_result_with_text, truncate_chars};
 use async_trait::async_trait;
 use rmcp::model::*;
 use serde::{Deserialize, Serialize};
@@ -52,7 +53,7 @@ struct NotesFolder {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct NoteSummary {
+pub(crate) struct NoteSummary {
     /// Note ID (use for get/update/delete operations)
     id: String,
     /// Note title (first line or name)
@@ -68,7 +69,7 @@ struct NoteSummary {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct NoteContent {
+pub(crate) struct NoteContent {
     /// Note ID
     id: String,
     /// Note title/name
@@ -476,12 +477,7 @@ fn parse_note_content(output: &str, max_body_len: usize) -> Option<NoteContent>
     }
 
     let mut body = parts[1].to_string();
-    let truncated = if body.len() > max_body_len {
-        body.truncate(max_body_len);
-        true
-    } else {
-        false
-    };
+    let truncated = truncate_chars(&mut body, max_body_len);
 
     Some(NoteContent {
         id: meta_parts[0].to_string(),
@@ -495,6 +491,54 @@ fn parse_note_content(output: &str, max_body_len: usize) -> Option<NoteContent>
     })
 }
 
+// ============================================================================
+// Note Operations (shared with the macOS automation connector)
+// ============================================================================
+
+/// Run a Notes script and return its result without `osascript -s s` quoting.
+#[cfg(target_os = "macos")]
+async fn run_notes_script(script: &str) -> Result<String, ConnectorError> {
+    let output = run_applescript_output(script).await?;
+    Ok(unquote_applescript_string(&output))
+}
+
+/// Notes in the folder/account scope, or only those whose title or body contains `query`.
+#[cfg(target_os = "macos")]
+pub(crate) async fn list_notes(
+    query: Option<&str>,
+    folder: Option<&str>,
+    account: Option<&str>,
+    limit: usize,
+) -> Result<Vec<NoteSummary>, ConnectorError> {
+    let script = match query {
+        Some(query) => script_search_notes(query, folder, account, limit),
+        None => script_list_notes(folder, account, limit),
+    };
+    Ok(parse_note_summaries(&run_notes_script(&script).await?))
+}
+
+/// One note as plain text, its body cut to `max_body_chars`.
+#[cfg(target_os = "macos")]
+pub(crate) async fn get_note(
+    note_id: &str,
+    max_body_chars: usize,
+) -> Result<NoteContent, ConnectorError> {
+    let output = run_notes_script(&script_get_note(note_id)).await?;
+    parse_note_content(&output, max_body_chars)
+        .ok_or_else(|| ConnectorError::Other("Failed to parse note".to_string()))
+}
+
+/// Create a note and return its id.
+#[cfg(target_os = "macos")]
+pub(crate) async fn create_note(
+    title: &str,
+    body: &str,
+    folder: Option<&str>,
+    account: Option<&str>,
+) -> Result<String, ConnectorError> {
+    run_notes_script(&script_create_note(title, body, folder, account)).await
+}
+
 // ============================================================================
 // Connector Implementation
 // ============================================================================
@@ -767,14 +811,14 @@ get_note first if you need to preserve existing content.",
 
             match name {
                 "list_accounts" => {
-                    let output = run_applescript_output(&script_list_accounts()).await?;
+                    let output = run_notes_script(&script_list_accounts()).await?;
                     let accounts = parse_accounts(&output);
                     structured_result_with_text(&accounts, None)
                 }
 
                 "list_folders" => {
                     let account = args.get("account").and_then(|v| v.as_str());
-                    let output = run_applescript_output(&script_list_folders(account)).await?;
+                    let output = run_notes_script(&script_list_folders(account)).await?;
                     let folders = parse_folders(&output);
                     structured_result_with_text(&folders, None)
                 }
@@ -785,8 +829,7 @@ get_note first if you need to preserve existing content.",
                     })?;
                     let account = args.get("account").and_then(|v| v.as_str());
 
-                    let output =
-                        run_applescript_output(&script_create_folder(name, account)).await?;
+                    let output = run_notes_script(&script_create_folder(name, account)).await?;
                     structured_result_with_text(
                         &json!({"success": true, "folder_id": output}),
                         None,
@@ -802,9 +845,7 @@ get_note first if you need to preserve existing content.",
                         .unwrap_or(50)
                         .min(200) as usize;
 
-                    let output =
-                        run_applescript_output(&script_list_notes(folder, account, limit)).await?;
-                    let notes = parse_note_summaries(&output);
+                    let notes = list_notes(None, folder, account, limit).await?;
                     structured_result_with_text(&notes, None)
                 }
 
@@ -820,9 +861,7 @@ get_note first if you need to preserve existing content.",
                         .and_then(|v| v.as_u64())
                         .unwrap_or(50000) as usize;
 
-                    let output = run_applescript_output(&script_get_note(note_id)).await?;
-                    let note = parse_note_content(&output, max_len)
-                        .ok_or_else(|| ConnectorError::Other("Failed to parse note".to_string()))?;
+                    let note = get_note(note_id, max_len).await?;
                     structured_result_with_text(&note, None)
                 }
 
@@ -834,10 +873,7 @@ get_note first if you need to preserve existing content.",
                     let account = args.get("account").and_then(|v| v.as_str());
                     let limit = args.get("limit").and_then(|v| v.as_u64()).unwrap_or(20) as usize;
 
-                    let output =
-                        run_applescript_output(&script_search_notes(query, folder, account, limit))
-                            .await?;
-                    let results = parse_note_summaries(&output);
+                    let results = list_notes(Some(query), folder, account, limit).await?;
                     structured_result_with_text(&results, None)
                 }
 
@@ -851,12 +887,10 @@ get_note first if you need to preserve existing content.",
                     let folder = args.get("folder").and_then(|v| v.as_str());
                     let account = args.get("account").and_then(|v| v.as_str());
 
-                    let output =
-                        run_applescript_output(&script_create_note(title, body, folder, account))
-                            .await?;
+                    let note_id = create_note(title, body, folder, account).await?;
                     let result = CreateNoteResult {
                         success: true,
-                        note_id: Some(output),
+                        note_id: Some(note_id),
                         message: "Note created successfully".to_string(),
                     };
                     structured_result_with_text(&result, None)
@@ -873,7 +907,7 @@ get_note first if you need to preserve existing content.",
                         ConnectorError::InvalidParams("Missing 'body'".to_string())
                     })?;
 
-                    let output = run_applescript_output(&script_update_note(note_id, body)).await?;
+                    let output = run_notes_script(&script_update_note(note_id, body)).await?;
                     structured_result_with_text(&json!({"success": true, "message": output}), None)
                 }
 
@@ -888,8 +922,7 @@ get_note first if you need to preserve existing content.",
                         ConnectorError::InvalidParams("Missing 'text'".to_string())
                     })?;
 
-                    let output =
-                        run_applescript_output(&script_append_to_note(note_id, text)).await?;
+                    let output = run_notes_script(&script_append_to_note(note_id, text)).await?;
                     structured_result_with_text(&json!({"success": true, "message": output}), None)
                 }
 
@@ -901,7 +934,7 @@ get_note first if you need to preserve existing content.",
                                 ConnectorError::InvalidParams("Missing 'note_id'".to_string())
                             })?;
 
-                    let output = run_applescript_output(&script_delete_note(note_id)).await?;
+                    let output = run_notes_script(&script_delete_note(note_id)).await?;
                     structured_result_with_text(&json!({"success": true, "message": output}), None)
                 }
 
@@ -924,3 +957,28 @@ get_note first if you need to preserve existing content.",
         Err(ConnectorError::ResourceNotFound)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_unquoted_script_output() {
+        let output = unquote_applescript_string(
+            r#""x-coredata://A/ICNote/p1:::Plan \"Q3\":::Monday:::Tuesday:::Work:::iCloud""#,
+        );
+        let notes = parse_note_summaries(&output);
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].id, "x-coredata://A/ICNote/p1");
+        assert_eq!(notes[0].name, r#"Plan "Q3""#);
+        assert_eq!(notes[0].account, "iCloud");
+
+        // Bodies are cut on a character boundary
+        let meta = "p2:::Café:::Mon:::Tue:::Notes:::iCloud|||BODY|||";
+        let note = parse_note_content(&format!("{}café au lait", meta), 3).unwrap();
+        assert_eq!(note.body, "caf");
+        assert!(note.truncated);
+        let note = parse_note_content(&format!("{}éé", meta), 1).unwrap();
+        assert_eq!(note.body, "é");
+    }
+}
@@ -0,0 +1,216 @@
+// src/connectors/arxiv/citation.rs
+//
+// BibTeX and RIS records built from arXiv API metadata, following the shape
+// of arXiv's own "Export BibTeX citation" output.
+
+use super::ArxivPaper;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+
+static VERSION_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"v\d+$").expect("version regex"));
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CitationFormat {
+    #[default]
+    Bibtex,
+    Ris,
+}
+
+/// `1706.03762v7` → `1706.03762`
+pub(super) fn base_id(id: &str) -> &str {
+    VERSION_RE
+        .find(id)
+        .map_or(id, |version| &id[..version.start()])
+}
+
+pub(super) fn format_citation(paper: &ArxivPaper, format: CitationFormat) -> String {
+    match format {
+        CitationFormat::Bibtex => to_bibtex(paper),
+        CitationFormat::Ris => to_ris(paper),
+    }
+}
+
+pub(super) fn to_bibtex(paper: &ArxivPaper) -> String {
+    let id = base_id(&paper.id);
+    let year = year_of(paper);
+    let mut fields = vec![
+        ("title", bibtex_escape(&squash(&paper.title))),
+        (
+            "author",
+            paper
+                .authors
+                .iter()
+                .map(|a| bibtex_escape(a))
+                .collect::<Vec<_>>()
+                .join(" and "),
+        ),
+        ("year", year.clone()),
+        ("eprint", id.to_string()),
+        ("archivePrefix", "arXiv".to_string()),
+    ];
+    if let Some(primary) = paper.categories.first() {
+        fields.push(("primaryClass", primary.clone()));
+    }
+    if let Some(doi) = &paper.doi {
+        fields.push(("doi", doi.clone()));
+    }
+    if let Some(journal_ref) = &paper.journal_ref {
+        fields.push(("note", bibtex_escape(&squash(journal_ref))));
+    }
+    fields.push(("url", format!("https://arxiv.org/abs/{}", id)));
+
+    let body: Vec<String> = fields
+        .into_iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(name, value)| format!("      {}={{{}}},", name, value))
+        .collect();
+    format!(
+        "@misc{{{},\n{}\n}}",
+        cite_key(paper, &year),
+        body.join("\n")
+    )
+}
+
+pub(super) fn to_ris(paper: &ArxivPaper) -> String {
+    let id = base_id(&paper.id);
+    let mut lines = vec![format!(
+        "TY  - {}",
+        if paper.journal_ref.is_some() {
+            "JOUR"
+        } else {
+            "UNPB"
+        }
+    )];
+    lines.push(format!("TI  - {}", squash(&paper.title)));
+    for author in &paper.authors {
+        lines.push(format!("AU  - {}", last_first(author)));
+    }
+    let year = year_of(paper);
+    if !year.is_empty() {
+        lines.push(format!("PY  - {}", year));
+    }
+    if let Some(date) = paper.published.get(..10) {
+        lines.push(format!("DA  - {}", date.replace('-', "/")));
+    }
+    if let Some(journal_ref) = &paper.journal_ref {
+        lines.push(format!("JO  - {}", squash(journal_ref)));
+    }
+    if let Some(doi) = &paper.doi {
+        lines.push(format!("DO  - {}", doi));
+    }
+    for category in &paper.categories {
+        lines.push(format!("KW  - {}", category));
+    }
+    lines.push(format!("AB  - {}", squash(&paper.summary)));
+    lines.push(format!("UR  - https://arxiv.org/abs/{}", id));
+    lines.push(format!("N1  - arXiv:{}", id));
+    lines.push("ER  - ".to_string());
+    lines.join("\n")
+}
+
+fn year_of(paper: &ArxivPaper) -> String {
+    paper.published.get(..4).unwrap_or_default().to_string()
+}
+
+/// `vaswani2017attention`: first author's surname, year, first significant title word.
+fn cite_key(paper: &ArxivPaper, year: &str) -> String {
+    const SKIP: &[&str] = &[
+        "a", "an", "the", "on", "of", "in", "for", "and", "to", "with",
+    ];
+    let ascii = |s: &str| -> String {
+        s.chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_lowercase()
+    };
+    let surname = paper
+        .authors
+        .first()
+        .and_then(|a| a.split_whitespace().last())
+        .map(ascii)
+        .unwrap_or_default();
+    let word = paper
+        .title
+        .split_whitespace()
+        .map(ascii)
+        .find(|w| !w.is_empty() && !SKIP.contains(&w.as_str()))
+        .unwrap_or_default();
+    let key = format!("{}{}{}", surname, year, word);
+    if key.is_empty() {
+        base_id(&paper.id).replace('/', ":")
+    } else {
+        key
+    }
+}
+
+/// "Ashish Vaswani" → "Vaswani, Ashish"
+fn last_first(name: &str) -> String {
+    match name.trim().rsplit_once(' ') {
+        Some((first, last)) => format!("{}, {}", last, first),
+        None => name.trim().to_string(),
+    }
+}
+
+fn squash(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Escape BibTeX specials; `_` is left alone since titles may contain `$x_i$`.
+fn bibtex_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut prev = ' ';
+    for c in text.chars() {
+        if matches!(c, '&' | '%' | '#') && prev != '\\' {
+            out.push('\\');
+        }
+        out.push(c);
+        prev = c;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paper() -> ArxivPaper {
+        ArxivPaper {
+            id: "1706.03762v7".to_string(),
+            title: "Attention Is All\n  You Need".to_string(),
+            summary: "The dominant sequence transduction models...".to_string(),
+            authors: vec!["Ashish Vaswani".to_string(), "Noam Shazeer".to_string()],
+            published: "2017-06-12T17:57:34Z".to_string(),
+            updated: "2023-08-02T00:41:18Z".to_string(),
+            categories: vec!["cs.CL".to_string(), "cs.LG".to_string()],
+            links: Vec::new(),
+            doi: None,
+            journal_ref: None,
+            comment: None,
+        }
+    }
+
+    #[test]
+    fn formats_bibtex_and_ris() {
+        assert_eq!(
+            to_bibtex(&paper()),
+            "@misc{vaswani2017attention,\n      title={Attention Is All You Need},\n      \
+author={Ashish Vaswani and Noam Shazeer},\n      year={2017},\n      eprint={1706.03762},\n      \
+archivePrefix={arXiv},\n      primaryClass={cs.CL},\n      url={https://arxiv.org/abs/1706.03762},\n}"
+        );
+
+        let ris = to_ris(&paper());
+        assert!(
+            ris.starts_with("TY  - UNPB\nTI  - Attention Is All You Need\nAU  - Vaswani, Ashish\n")
+        );
+        assert!(ris.contains("\nDA  - 2017/06/12\n"));
+        assert!(ris.ends_with("N1  - arXiv:1706.03762\nER  - "));
+
+        assert_eq!(base_id("hep-th/9901001v2"), "hep-th/9901001");
+        assert_eq!(
+            bibtex_escape("R&D at 50% \\& more"),
+            "R\\&D at 50\\% \\& more"
+        );
+    }
+}
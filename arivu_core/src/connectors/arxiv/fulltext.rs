@@ -0,0 +1,295 @@
+// src/connectors/arxiv/fulltext.rs
+//
+// Full text of a paper split into sections. arXiv's own HTML rendering (and
+// the older ar5iv mirror) are LaTeXML documents with explicit section markup;
+// papers without one fall back to the PDF, where `crate::pdf` guesses headings
+// from numbered lines. Parsing runs on the CPU pool.
+
+use once_cell::sync::Lazy;
+use scraper::{ElementRef, Html, Node, Selector};
+use serde::Serialize;
+
+use crate::error::ConnectorError;
+use crate::pdf::{PdfOptions, PdfSection};
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FulltextSection {
+    pub heading: String,
+    /// 1 for top-level sections (and the abstract), 2+ for subsections
+    pub level: u8,
+    /// Starting page, for sections read from the PDF
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ParsedFulltext {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub sections: Vec<FulltextSection>,
+}
+
+static SECTION_SELECTOR: Lazy<Selector> = Lazy::new(|| {
+    Selector::parse(
+        "div.ltx_abstract, section.ltx_section, section.ltx_subsection, \
+section.ltx_subsubsection, section.ltx_appendix, section.ltx_bibliography",
+    )
+    .expect("section selector")
+});
+static TITLE_SELECTOR: Lazy<Selector> =
+    Lazy::new(|| Selector::parse("h1.ltx_title_document").expect("title selector"));
+
+/// Sections of a LaTeXML (arxiv.org/html or ar5iv) page. Returns `None` when
+/// the page is not a rendered paper, e.g. ar5iv's redirect to the abstract.
+pub(super) fn parse_latexml(html: &str) -> Option<ParsedFulltext> {
+    let doc = Html::parse_document(html);
+    let title = doc
+        .select(&TITLE_SELECTOR)
+        .next()
+        .map(|el| squash(&element_text(el)))
+        .filter(|t| !t.is_empty());
+
+    let sections: Vec<FulltextSection> = doc
+        .select(&SECTION_SELECTOR)
+        .filter_map(|el| {
+            let classes: Vec<&str> = el.value().classes().collect();
+            let level = if classes.contains(&"ltx_subsubsection") {
+                3
+            } else if classes.contains(&"ltx_subsection") {
+                2
+            } else {
+                1
+            };
+            let mut heading = String::new();
+            let mut body: Vec<String> = Vec::new();
+            for child in el.children().filter_map(ElementRef::wrap) {
+                let value = child.value();
+                // Nested sections are yielded by the selector on their own
+                if value.name() == "section" {
+                    continue;
+                }
+                if value.classes().any(|c| c == "ltx_title") {
+                    heading = squash(&element_text(child));
+                    continue;
+                }
+                let text = squash(&element_text(child));
+                if !text.is_empty() {
+                    body.push(text);
+                }
+            }
+            if classes.contains(&"ltx_abstract") && heading.is_empty() {
+                heading = "Abstract".to_string();
+            }
+            (!heading.is_empty() || !body.is_empty()).then(|| FulltextSection {
+                heading,
+                level,
+                page: None,
+                text: body.join("\n\n"),
+            })
+        })
+        .collect();
+
+    (!sections.is_empty()).then_some(ParsedFulltext { title, sections })
+}
+
+/// Text of an element with math rendered as `$alttext$` and footnotes dropped.
+fn element_text(el: ElementRef) -> String {
+    let mut out = String::new();
+    collect_text(el, &mut out);
+    out
+}
+
+fn collect_text(el: ElementRef, out: &mut String) {
+    let value = el.value();
+    if value.name() == "math" {
+        if let Some(tex) = value.attr("alttext") {
+            out.push_str(&format!("${}$", tex));
+        }
+        return;
+    }
+    if value
+        .classes()
+        .any(|c| c == "ltx_note" || c == "ltx_tag_note")
+    {
+        return;
+    }
+    let block = matches!(value.name(), "p" | "div" | "li" | "tr" | "br");
+    for child in el.children() {
+        match child.value() {
+            Node::Text(text) => out.push_str(text),
+            Node::Element(_) => {
+                if let Some(child) = ElementRef::wrap(child) {
+                    collect_text(child, out);
+                }
+            }
+            _ => {}
+        }
+    }
+    if block {
+        out.push('\n');
+    }
+}
+
+/// Collapse runs of spaces while keeping paragraph breaks.
+fn squash(text: &str) -> String {
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Sections of a PDF's extracted text, with the page each one starts on.
+pub(super) fn parse_pdf(bytes: &[u8]) -> Result<ParsedFulltext, ConnectorError> {
+    let options = PdfOptions {
+        max_pages: 200,
+        sections: true,
+        ..Default::default()
+    };
+    let pdf = crate::pdf::extract(bytes, &options)?;
+    Ok(ParsedFulltext {
+        title: None,
+        sections: pdf
+            .sections
+            .into_iter()
+            .map(FulltextSection::from)
+            .collect(),
+    })
+}
+
+impl From<PdfSection> for FulltextSection {
+    fn from(section: PdfSection) -> Self {
+        FulltextSection {
+            heading: section.heading,
+            level: section.level,
+            page: Some(section.page),
+            text: section.text,
+        }
+    }
+}
+
+/// Keep only sections whose heading contains one of `wanted`
+/// (case-insensitive), then cap the total text at `max_chars`.
+/// Returns whether anything was cut; later sections are dropped at the cut.
+pub(super) fn select_sections(
+    sections: &mut Vec<FulltextSection>,
+    wanted: &[String],
+    max_chars: usize,
+) -> bool {
+    if !wanted.is_empty() {
+        let wanted: Vec<String> = wanted.iter().map(|w| w.to_lowercase()).collect();
+        sections.retain(|s| {
+            let heading = s.heading.to_lowercase();
+            wanted.iter().any(|w| heading.contains(w.as_str()))
+        });
+    }
+
+    let mut budget = max_chars;
+    let cut_at = sections.iter().position(|s| {
+        let over = s.text.len() > budget;
+        budget = budget.saturating_sub(s.text.len());
+        over
+    });
+    let Some(index) = cut_at else {
+        return false;
+    };
+    let used: usize = sections[..index].iter().map(|s| s.text.len()).sum();
+    let section = &mut sections[index];
+    let mut cut = max_chars - used;
+    while !section.text.is_char_boundary(cut) {
+        cut -= 1;
+    }
+    section.text.truncate(cut);
+    let keep = if section.text.is_empty() {
+        index
+    } else {
+        index + 1
+    };
+    sections.truncate(keep);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_latexml_sections() {
+        let html = r#"<html><body><article class="ltx_document">
+<h1 class="ltx_title ltx_title_document">Attention Is All You Need</h1>
+<div class="ltx_abstract"><h6 class="ltx_title ltx_title_abstract">Abstract</h6>
+<p class="ltx_p">We propose the Transformer.</p></div>
+<section class="ltx_section"><h2 class="ltx_title ltx_title_section">1 Introduction</h2>
+<div class="ltx_para"><p class="ltx_p">Models with cost <math alttext="O(n^{2})"><mi>O</mi></math> dominate.<span class="ltx_note">footnote</span></p></div>
+<section class="ltx_subsection"><h3 class="ltx_title">1.1 Background</h3>
+<div class="ltx_para"><p class="ltx_p">Earlier work.</p></div></section>
+</section></article></body></html>"#;
+
+        let parsed = parse_latexml(html).unwrap();
+        assert_eq!(parsed.title.as_deref(), Some("Attention Is All You Need"));
+        assert_eq!(
+            parsed.sections,
+            vec![
+                FulltextSection {
+                    heading: "Abstract".to_string(),
+                    level: 1,
+                    page: None,
+                    text: "We propose the Transformer.".to_string()
+                },
+                FulltextSection {
+                    heading: "1 Introduction".to_string(),
+                    level: 1,
+                    page: None,
+                    text: "Models with cost $O(n^{2})$ dominate.".to_string()
+                },
+                FulltextSection {
+                    heading: "1.1 Background".to_string(),
+                    level: 2,
+                    page: None,
+                    text: "Earlier work.".to_string()
+                },
+            ]
+        );
+        assert!(parse_latexml("<html><body><p>arXiv abstract page</p></body></html>").is_none());
+    }
+
+    #[test]
+    fn splits_pdf_text_at_headings() {
+        let text = "A Paper Title\nAbstract\nWe study things.\n1 Introduction\nThings matter.\n\
+3 apples were used in total\n2 Method\n2.1 Setup\nWe set up.\n128 GPUs\nReferences\n[1] Someone.";
+        let page = crate::pdf::PdfPage {
+            number: 1,
+            text: text.to_string(),
+        };
+        let sections: Vec<FulltextSection> = crate::pdf::split_sections(&[page])
+            .into_iter()
+            .map(FulltextSection::from)
+            .collect();
+        let headings: Vec<(&str, u8)> = sections
+            .iter()
+            .map(|s| (s.heading.as_str(), s.level))
+            .collect();
+        assert_eq!(
+            headings,
+            vec![
+                ("", 1),
+                ("Abstract", 1),
+                ("1 Introduction", 1),
+                ("2 Method", 1),
+                ("2.1 Setup", 2),
+                ("References", 1)
+            ]
+        );
+        assert_eq!(
+            sections[2].text,
+            "Things matter.\n3 apples were used in total"
+        );
+
+        let mut selected = sections.clone();
+        let truncated = select_sections(&mut selected, &["intro".to_string()], 5);
+        assert!(truncated);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].text, "Thing");
+    }
+}
@@ -0,0 +1,179 @@
+// src/connectors/arxiv/listing.rs
+//
+// The daily announcement feed (rss.arxiv.org), parsed into `ArxivPaper`s.
+// Items carry an announce type: `new`, `cross` (cross-list), `replace` or
+// `replace-cross`.
+
+use super::ArxivPaper;
+use crate::error::ConnectorError;
+use chrono::DateTime;
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+
+#[derive(Debug, Clone)]
+pub(super) struct ListedPaper {
+    pub paper: ArxivPaper,
+    pub announce_type: String,
+}
+
+/// Category names as used in listings: `cs.CL`, `hep-th`, `astro-ph.CO`.
+pub(super) fn valid_category(category: &str) -> bool {
+    let (archive, subject) = match category.split_once('.') {
+        Some((archive, subject)) => (archive, Some(subject)),
+        None => (category, None),
+    };
+    let archive_ok =
+        !archive.is_empty() && archive.chars().all(|c| c.is_ascii_lowercase() || c == '-');
+    let subject_ok = subject.map_or(true, |s| {
+        !s.is_empty() && s.chars().all(|c| c.is_ascii_alphabetic() || c == '-')
+    });
+    archive_ok && subject_ok
+}
+
+pub(super) fn parse_rss(xml: &str) -> Result<Vec<ListedPaper>, ConnectorError> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+
+    let mut items = Vec::new();
+    let mut current: Option<ListedPaper> = None;
+    let mut current_tag: Option<String> = None;
+    let mut buffer = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buffer) {
+            Ok(Event::Start(ref e)) => {
+                let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if tag == "item" {
+                    current = Some(ListedPaper {
+                        paper: ArxivPaper {
+                            id: String::new(),
+                            title: String::new(),
+                            summary: String::new(),
+                            authors: Vec::new(),
+                            published: String::new(),
+                            updated: String::new(),
+                            categories: Vec::new(),
+                            links: Vec::new(),
+                            doi: None,
+                            journal_ref: None,
+                            comment: None,
+                        },
+                        announce_type: String::new(),
+                    });
+                } else if current.is_some() {
+                    current_tag = Some(tag);
+                }
+            }
+            Ok(Event::Text(ref e)) => {
+                let text = e
+                    .unescape()
+                    .map_err(|_| ConnectorError::ParseError)?
+                    .to_string();
+                apply_field(current.as_mut(), current_tag.as_deref(), text);
+            }
+            Ok(Event::CData(ref e)) => {
+                let text = String::from_utf8_lossy(e.as_ref()).to_string();
+                apply_field(current.as_mut(), current_tag.as_deref(), text);
+            }
+            Ok(Event::End(ref e)) => {
+                let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if tag == "item" {
+                    if let Some(item) = current.take().filter(|i| !i.paper.id.is_empty()) {
+                        items.push(item);
+                    }
+                }
+                current_tag = None;
+            }
+            Ok(Event::Eof) => break,
+            Err(_) => return Err(ConnectorError::ParseError),
+            _ => {}
+        }
+        buffer.clear();
+    }
+
+    Ok(items)
+}
+
+fn apply_field(item: Option<&mut ListedPaper>, tag: Option<&str>, text: String) {
+    let (Some(item), Some(tag)) = (item, tag) else {
+        return;
+    };
+    let paper = &mut item.paper;
+    match tag {
+        "title" => paper.title = text,
+        // "oai:arXiv.org:2410.12345v1"
+        "guid" => {
+            paper.id = text
+                .rsplit_once("arXiv.org:")
+                .map_or(text.as_str(), |(_, id)| id)
+                .to_string()
+        }
+        "link" if paper.id.is_empty() => {
+            paper.id = text.replace("https://arxiv.org/abs/", "");
+        }
+        // "arXiv:2410.12345v1 Announce Type: new \nAbstract: ..."
+        "description" => {
+            paper.summary = text
+                .split_once("Abstract:")
+                .map_or(text.as_str(), |(_, abstract_)| abstract_)
+                .trim()
+                .to_string()
+        }
+        "category" => paper.categories.push(text),
+        "dc:creator" => {
+            paper.authors = text
+                .split(',')
+                .map(|a| a.trim().trim_start_matches("and ").to_string())
+                .filter(|a| !a.is_empty())
+                .collect()
+        }
+        "pubDate" => {
+            paper.published = DateTime::parse_from_rfc2822(&text)
+                .map(|d| d.to_rfc3339())
+                .unwrap_or(text)
+        }
+        "arxiv:announce_type" => item.announce_type = text,
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_announcement_feed() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss xmlns:arxiv="http://arxiv.org/schemas/atom" xmlns:dc="http://purl.org/dc/elements/1.1/" version="2.0">
+<channel><title>cs.CL updates on arXiv.org</title>
+<item>
+  <title>Scaling Laws for Tokenizers</title>
+  <link>https://arxiv.org/abs/2610.01234</link>
+  <description>arXiv:2610.01234v1 Announce Type: new
+Abstract: We study tokenizers &amp; scaling.</description>
+  <guid isPermaLink="false">oai:arXiv.org:2610.01234v1</guid>
+  <category>cs.CL</category>
+  <category>cs.LG</category>
+  <pubDate>Thu, 15 Oct 2026 00:00:00 -0400</pubDate>
+  <arxiv:announce_type>new</arxiv:announce_type>
+  <dc:creator>Ada Lovelace, Alan Turing</dc:creator>
+</item>
+</channel></rss>"#;
+
+        let items = parse_rss(xml).unwrap();
+        assert_eq!(items.len(), 1);
+        let paper = &items[0].paper;
+        assert_eq!(paper.id, "2610.01234v1");
+        assert_eq!(paper.summary, "We study tokenizers & scaling.");
+        assert_eq!(paper.authors, vec!["Ada Lovelace", "Alan Turing"]);
+        assert_eq!(paper.categories, vec!["cs.CL", "cs.LG"]);
+        assert_eq!(paper.published, "2026-10-15T00:00:00-04:00");
+        assert_eq!(items[0].announce_type, "new");
+
+        assert!(valid_category("cs.CL"));
+        assert!(valid_category("astro-ph.CO"));
+        assert!(valid_category("hep-th"));
+        assert!(!valid_category("cs.CL/../x"));
+        assert!(!valid_category(""));
+    }
+}
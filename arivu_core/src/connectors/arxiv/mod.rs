@@ -1,4 +1,5 @@
 use crate::capabilities::ConnectorConfigSchema;
+use crate::cpu_pool;
 use crate::error::ConnectorError;
 use crate::utils::structured_result_with_text;
 use crate::{auth::AuthDetails, Connector};
@@ -14,6 +15,17 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use url::Url;
 
+mod citation;
+mod fulltext;
+mod listing;
+
+use citation::CitationFormat;
+
+const MAX_CITATION_IDS: usize = 50;
+const MAX_LIST_NEW: i32 = 500;
+// arXiv PDFs are rarely above a few MB; scanned theses can be much larger
+const MAX_PDF_BYTES: u64 = 50 * 1024 * 1024;
+
 // Define the structs for arXiv papers
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ArxivPaper {
@@ -61,6 +73,9 @@ struct SearchPapersArgs {
     sort_order: String,
     #[serde(default)]
     response_format: ResponseFormat,
+    /// Attach a `bibtex` field to each result
+    #[serde(default)]
+    bibtex: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -70,6 +85,74 @@ struct GetPaperDetailsArgs {
     response_format: ResponseFormat,
 }
 
+#[derive(Debug, Deserialize)]
+struct ExportCitationArgs {
+    #[serde(alias = "paper_id")]
+    paper_ids: PaperIds,
+    #[serde(default)]
+    format: CitationFormat,
+}
+
+/// One ID or a list of IDs
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum PaperIds {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Debug, Deserialize)]
+struct ListNewArgs {
+    category: String,
+    /// YYYY-MM-DD; without it the latest daily announcement is returned
+    #[serde(default)]
+    since: Option<String>,
+    /// YYYY-MM-DD (default: today)
+    #[serde(default)]
+    until: Option<String>,
+    #[serde(default = "default_list_new_limit")]
+    limit: i32,
+    #[serde(default = "default_true")]
+    include_cross_lists: bool,
+    #[serde(default)]
+    response_format: ResponseFormat,
+}
+
+fn default_list_new_limit() -> i32 {
+    50
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Where to read the full text from
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+enum FulltextSource {
+    /// arXiv HTML, then ar5iv, then the PDF
+    #[default]
+    Auto,
+    Html,
+    Pdf,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetFulltextArgs {
+    paper_id: String,
+    #[serde(default)]
+    source: FulltextSource,
+    /// Only sections whose heading contains one of these (case-insensitive)
+    #[serde(default)]
+    sections: Vec<String>,
+    #[serde(default = "default_max_chars")]
+    max_chars: usize,
+}
+
+fn default_max_chars() -> usize {
+    60_000
+}
+
 fn default_max_results() -> i32 {
     10
 }
@@ -167,10 +250,132 @@ impl ArxivConnector {
         Ok(papers[0].clone())
     }
 
+    // Helper method to fetch several papers in one API request
+    async fn get_papers(&self, paper_ids: &[String]) -> Result<Vec<ArxivPaper>, ConnectorError> {
+        let mut url = Url::parse("http://export.arxiv.org/api/query")
+            .map_err(|e| ConnectorError::InvalidInput(format!("Failed to parse URL: {}", e)))?;
+
+        url.query_pairs_mut()
+            .append_pair("id_list", &paper_ids.join(","))
+            .append_pair("max_results", &paper_ids.len().to_string());
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+
+        if !response.status().is_success() {
+            return Err(ConnectorError::Other(format!(
+                "arXiv API returned error status: {}",
+                response.status()
+            )));
+        }
+
+        let content = response.text().await.map_err(ConnectorError::HttpRequest)?;
+        self.parse_arxiv_response(&content)
+    }
+
+    // Helper method to fetch the latest daily announcement for a category
+    async fn fetch_announcements(
+        &self,
+        category: &str,
+    ) -> Result<Vec<listing::ListedPaper>, ConnectorError> {
+        let response = self
+            .client
+            .get(format!("https://rss.arxiv.org/rss/{}", category))
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+
+        if !response.status().is_success() {
+            return Err(ConnectorError::Other(format!(
+                "arXiv RSS returned error status: {}",
+                response.status()
+            )));
+        }
+
+        let content = response.text().await.map_err(ConnectorError::HttpRequest)?;
+        listing::parse_rss(&content)
+    }
+
     fn pdf_url(paper_id: &str) -> String {
         format!("https://arxiv.org/pdf/{}.pdf", paper_id)
     }
 
+    /// Full text as sections, with the source it came from and its URL.
+    async fn get_fulltext(
+        &self,
+        paper_id: &str,
+        source: FulltextSource,
+    ) -> Result<(&'static str, String, fulltext::ParsedFulltext), ConnectorError> {
+        if source != FulltextSource::Pdf {
+            for (name, url) in [
+                ("arxiv_html", format!("https://arxiv.org/html/{}", paper_id)),
+                (
+                    "ar5iv",
+                    format!("https://ar5iv.labs.arxiv.org/html/{}", paper_id),
+                ),
+            ] {
+                if let Some(parsed) = self.fetch_latexml(&url).await? {
+                    return Ok((name, url, parsed));
+                }
+            }
+            if source == FulltextSource::Html {
+                return Err(ConnectorError::ResourceNotFound);
+            }
+        }
+
+        let url = Self::pdf_url(paper_id);
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(ConnectorError::ResourceNotFound);
+        }
+        if !response.status().is_success() {
+            return Err(ConnectorError::Other(format!(
+                "arXiv PDF request returned error status: {}",
+                response.status()
+            )));
+        }
+        if response.content_length().unwrap_or(0) > MAX_PDF_BYTES {
+            return Err(ConnectorError::Other(format!(
+                "PDF is larger than {} MB",
+                MAX_PDF_BYTES / (1024 * 1024)
+            )));
+        }
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+        let parsed = cpu_pool::spawn_cpu(move || fulltext::parse_pdf(&bytes)).await?;
+        Ok(("pdf", url, parsed))
+    }
+
+    /// A LaTeXML rendering, or `None` when the paper has none. ar5iv redirects
+    /// unavailable papers to the abstract page instead of returning 404.
+    async fn fetch_latexml(
+        &self,
+        url: &str,
+    ) -> Result<Option<fulltext::ParsedFulltext>, ConnectorError> {
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+        if !response.status().is_success() || !response.url().path().contains("/html/") {
+            return Ok(None);
+        }
+        let html = response.text().await.map_err(ConnectorError::HttpRequest)?;
+        cpu_pool::spawn_cpu(move || Ok(fulltext::parse_latexml(&html))).await
+    }
+
     // Helper method to parse arXiv API response
     fn parse_arxiv_response(&self, xml_content: &str) -> Result<Vec<ArxivPaper>, ConnectorError> {
         let mut reader = Reader::from_str(xml_content);
@@ -408,6 +613,7 @@ impl Connector for ArxivConnector {
             sort_by: "relevance".to_string(),
             sort_order: "descending".to_string(),
             response_format: ResponseFormat::default(),
+            bibtex: false,
         };
 
         let papers = self.search_papers(&args).await?;
@@ -538,6 +744,10 @@ impl Connector for ArxivConnector {
                             "enum": ["concise", "detailed"],
                             "description": "Response verbosity: 'concise' (default) returns only id/title/summary, 'detailed' includes all metadata (authors, dates, links, etc.)",
                             "default": "concise"
+                        },
+                        "bibtex": {
+                            "type": "boolean",
+                            "description": "Include a BibTeX entry for each result (default: false)"
                         }
                     },
                     "required": ["query"]
@@ -573,6 +783,115 @@ impl Connector for ArxivConnector {
                 annotations: None,
                 icons: None,
             },
+            Tool {
+                name: Cow::Borrowed("list_new"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "List new papers in a category. Without since, returns the latest daily \
+announcement (new + cross-lists); with since/until, lists submissions in that date window. \
+Example: category=\"cs.CL\" or category=\"cs.CL\" since=\"2026-10-01\".",
+                )),
+                input_schema: Arc::new(json!({
+                    "type": "object",
+                    "properties": {
+                        "category": {
+                            "type": "string",
+                            "description": "arXiv category (e.g., 'cs.CL', 'hep-th', 'astro-ph.CO')"
+                        },
+                        "since": {
+                            "type": "string",
+                            "description": "Start of the submission window, YYYY-MM-DD. Omit for the latest announcement"
+                        },
+                        "until": {
+                            "type": "string",
+                            "description": "End of the submission window, YYYY-MM-DD (default: today)"
+                        },
+                        "limit": {
+                            "type": "integer",
+                            "description": "Maximum number of papers (default: 50, max: 500)"
+                        },
+                        "include_cross_lists": {
+                            "type": "boolean",
+                            "description": "Include papers whose primary category is elsewhere (default: true)"
+                        },
+                        "response_format": {
+                            "type": "string",
+                            "enum": ["concise", "detailed"],
+                            "description": "Response verbosity: 'concise' (default) returns only id/title/summary, 'detailed' includes all metadata",
+                            "default": "concise"
+                        }
+                    },
+                    "required": ["category"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("export_citation"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Export citations for one or more arXiv IDs as BibTeX (default) or RIS. The text \
+output is the ready-to-save bibliography. Example: paper_ids=[\"1706.03762\", \"1810.04805\"] format=\"bibtex\".",
+                )),
+                input_schema: Arc::new(json!({
+                    "type": "object",
+                    "properties": {
+                        "paper_ids": {
+                            "type": "array",
+                            "items": { "type": "string" },
+                            "description": "arXiv IDs (up to 50), e.g. ['1706.03762', 'hep-th/9901001']"
+                        },
+                        "format": {
+                            "type": "string",
+                            "enum": ["bibtex", "ris"],
+                            "description": "Citation format (default: 'bibtex')",
+                            "default": "bibtex"
+                        }
+                    },
+                    "required": ["paper_ids"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_fulltext"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Get a paper's full text split into sections (heading, level, text). Reads arXiv's \
+HTML rendering (or ar5iv), falling back to the PDF, whose sections also carry their starting page. Use sections=[\"method\"] to fetch only matching \
+headings. Example: paper_id=\"1706.03762\" sections=[\"introduction\", \"conclusion\"].",
+                )),
+                input_schema: Arc::new(json!({
+                    "type": "object",
+                    "properties": {
+                        "paper_id": {
+                            "type": "string",
+                            "description": "The arXiv ID of the paper (e.g., '1706.03762' or 'hep-th/9901001')"
+                        },
+                        "source": {
+                            "type": "string",
+                            "enum": ["auto", "html", "pdf"],
+                            "description": "'auto' (default) tries arXiv HTML, then ar5iv, then the PDF; 'html' never downloads the PDF",
+                            "default": "auto"
+                        },
+                        "sections": {
+                            "type": "array",
+                            "items": { "type": "string" },
+                            "description": "Only return sections whose heading contains one of these (case-insensitive)"
+                        },
+                        "max_chars": {
+                            "type": "integer",
+                            "description": "Cap on total section text; later sections are dropped once reached (default: 60000)"
+                        }
+                    },
+                    "required": ["paper_id"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
         ];
 
         Ok(ListToolsResult {
@@ -597,11 +916,15 @@ impl Connector for ArxivConnector {
                 let results: Vec<HashMap<String, Value>> = papers
                     .iter()
                     .map(|paper| {
-                        if args.response_format == ResponseFormat::Concise {
+                        let mut result = if args.response_format == ResponseFormat::Concise {
                             self.format_paper_concise(paper)
                         } else {
                             self.format_paper(paper)
+                        };
+                        if args.bibtex {
+                            result.insert("bibtex".to_string(), json!(citation::to_bibtex(paper)));
                         }
+                        result
                     })
                     .collect();
 
@@ -650,6 +973,230 @@ impl Connector for ArxivConnector {
                     Err(err) => Err(err),
                 }
             }
+            "list_new" => {
+                let args: ListNewArgs = serde_json::from_value(
+                    serde_json::to_value(request.arguments.unwrap_or_default())
+                        .map_err(ConnectorError::SerdeJson)?,
+                )
+                .map_err(|e| ConnectorError::InvalidParams(format!("Invalid arguments: {}", e)))?;
+
+                let category = args.category.trim();
+                if !listing::valid_category(category) {
+                    return Err(ConnectorError::InvalidParams(format!(
+                        "Invalid arXiv category: '{}' (expected e.g. 'cs.CL' or 'hep-th')",
+                        category
+                    )));
+                }
+                let limit = args.limit.clamp(1, MAX_LIST_NEW);
+                let format = |paper: &ArxivPaper| {
+                    if args.response_format == ResponseFormat::Concise {
+                        self.format_paper_concise(paper)
+                    } else {
+                        self.format_paper(paper)
+                    }
+                };
+
+                let data = match args.since.as_deref() {
+                    None => {
+                        // Replacements are revisions of older papers, not new work
+                        let papers: Vec<HashMap<String, Value>> = self
+                            .fetch_announcements(category)
+                            .await?
+                            .into_iter()
+                            .filter(|item| {
+                                item.announce_type == "new"
+                                    || (args.include_cross_lists && item.announce_type == "cross")
+                            })
+                            .take(limit as usize)
+                            .map(|item| {
+                                let mut result = format(&item.paper);
+                                result
+                                    .insert("announce_type".to_string(), json!(item.announce_type));
+                                result
+                            })
+                            .collect();
+                        let mut data = json!({
+                            "category": category,
+                            "source": "announcement",
+                            "count": papers.len(),
+                            "papers": papers,
+                        });
+                        if papers.is_empty() {
+                            data["note"] = json!(
+                                "No announcement today; arXiv does not announce on weekends and holidays. Pass since=YYYY-MM-DD for a date window."
+                            );
+                        }
+                        data
+                    }
+                    Some(since) => {
+                        let parse_date = |value: &str| {
+                            chrono::NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").map_err(
+                                |_| {
+                                    ConnectorError::InvalidParams(format!(
+                                        "Invalid date '{}': expected YYYY-MM-DD",
+                                        value
+                                    ))
+                                },
+                            )
+                        };
+                        let since = parse_date(since)?;
+                        let until = match args.until.as_deref() {
+                            Some(until) => parse_date(until)?,
+                            None => chrono::Utc::now().date_naive(),
+                        };
+                        if since > until {
+                            return Err(ConnectorError::InvalidParams(
+                                "since must not be after until".to_string(),
+                            ));
+                        }
+
+                        let search = SearchPapersArgs {
+                            query: format!(
+                                "cat:{} AND submittedDate:[{}0000 TO {}2359]",
+                                category,
+                                since.format("%Y%m%d"),
+                                until.format("%Y%m%d")
+                            ),
+                            limit,
+                            start: 0,
+                            sort_by: "submittedDate".to_string(),
+                            sort_order: "descending".to_string(),
+                            response_format: args.response_format,
+                            bibtex: false,
+                        };
+                        let papers: Vec<HashMap<String, Value>> = self
+                            .search_papers(&search)
+                            .await?
+                            .iter()
+                            .filter(|paper| {
+                                args.include_cross_lists
+                                    || paper.categories.first().map(String::as_str)
+                                        == Some(category)
+                            })
+                            .map(format)
+                            .collect();
+                        json!({
+                            "category": category,
+                            "source": "search",
+                            "since": since.to_string(),
+                            "until": until.to_string(),
+                            "count": papers.len(),
+                            "papers": papers,
+                        })
+                    }
+                };
+                let text = serde_json::to_string(&data).map_err(ConnectorError::SerdeJson)?;
+                Ok(structured_result_with_text(&data, Some(text))?)
+            }
+            "export_citation" => {
+                let args: ExportCitationArgs = serde_json::from_value(
+                    serde_json::to_value(request.arguments.unwrap_or_default())
+                        .map_err(ConnectorError::SerdeJson)?,
+                )
+                .map_err(|e| ConnectorError::InvalidParams(format!("Invalid arguments: {}", e)))?;
+
+                let requested: Vec<String> = match args.paper_ids {
+                    PaperIds::One(id) => vec![id],
+                    PaperIds::Many(ids) => ids,
+                }
+                .into_iter()
+                .map(|id| id.trim().to_string())
+                .filter(|id| !id.is_empty())
+                .collect();
+                if requested.is_empty() || requested.len() > MAX_CITATION_IDS {
+                    return Err(ConnectorError::InvalidParams(format!(
+                        "paper_ids must contain between 1 and {} IDs",
+                        MAX_CITATION_IDS
+                    )));
+                }
+
+                let papers = self.get_papers(&requested).await?;
+                // Match on the unversioned ID: the API reports the latest version
+                let citations: Vec<Value> = requested
+                    .iter()
+                    .filter_map(|id| {
+                        let wanted = citation::base_id(id);
+                        let paper = papers.iter().find(|p| citation::base_id(&p.id) == wanted)?;
+                        Some(json!({
+                            "id": id,
+                            "citation": citation::format_citation(paper, args.format),
+                        }))
+                    })
+                    .collect();
+                let missing: Vec<&String> = requested
+                    .iter()
+                    .filter(|id| !citations.iter().any(|c| c["id"] == json!(id)))
+                    .collect();
+
+                let text = citations
+                    .iter()
+                    .filter_map(|c| c["citation"].as_str())
+                    .collect::<Vec<_>>()
+                    .join("\n\n");
+                let data = json!({
+                    "format": match args.format {
+                        CitationFormat::Bibtex => "bibtex",
+                        CitationFormat::Ris => "ris",
+                    },
+                    "count": citations.len(),
+                    "citations": citations,
+                    "missing": missing,
+                });
+                Ok(structured_result_with_text(&data, Some(text))?)
+            }
+            "get_fulltext" => {
+                let args: GetFulltextArgs = serde_json::from_value(
+                    serde_json::to_value(request.arguments.unwrap_or_default())
+                        .map_err(ConnectorError::SerdeJson)?,
+                )
+                .map_err(|e| ConnectorError::InvalidParams(format!("Invalid arguments: {}", e)))?;
+
+                let paper_id = args.paper_id.trim();
+                let paper_id = paper_id
+                    .get(..6)
+                    .filter(|p| p.eq_ignore_ascii_case("arxiv:"))
+                    .map_or(paper_id, |_| &paper_id[6..]);
+                if paper_id.is_empty() {
+                    return Err(ConnectorError::InvalidParams(
+                        "paper_id must not be empty".to_string(),
+                    ));
+                }
+
+                let (source, url, mut parsed) = match self.get_fulltext(paper_id, args.source).await
+                {
+                    Ok(found) => found,
+                    Err(ConnectorError::ResourceNotFound) => {
+                        let data = json!({
+                            "paper_id": paper_id,
+                            "sections": [],
+                        });
+                        let text =
+                            serde_json::to_string(&data).map_err(ConnectorError::SerdeJson)?;
+                        return Ok(structured_result_with_text(&data, Some(text))?);
+                    }
+                    Err(err) => return Err(err),
+                };
+
+                let headings: Vec<String> =
+                    parsed.sections.iter().map(|s| s.heading.clone()).collect();
+                let truncated =
+                    fulltext::select_sections(&mut parsed.sections, &args.sections, args.max_chars);
+                let nothing_matched = parsed.sections.is_empty() && !args.sections.is_empty();
+                let mut data = json!({
+                    "paper_id": paper_id,
+                    "source": source,
+                    "url": url,
+                    "title": parsed.title,
+                    "sections": parsed.sections,
+                    "truncated": truncated,
+                });
+                // Let the caller pick an existing heading
+                if nothing_matched {
+                    data["available_sections"] = json!(headings);
+                }
+                let text = serde_json::to_string(&data).map_err(ConnectorError::SerdeJson)?;
+                Ok(structured_result_with_text(&data, Some(text))?)
+            }
             "get_pdf_url" | "get_paper_pdf" => {
                 let args: GetPaperDetailsArgs = serde_json::from_value(
                     serde_json::to_value(request.arguments.unwrap_or_default())
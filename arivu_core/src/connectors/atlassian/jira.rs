@@ -0,0 +1,346 @@
+// src/connectors/atlassian/jira.rs
+//
+// Jira response shaping. Cloud's v3 API returns descriptions and comment
+// bodies as Atlassian Document Format (ADF) trees; these helpers flatten
+// them to readable text and build the ADF documents that comment writes
+// expect.
+
+use serde_json::{json, Value};
+
+pub(super) const DEFAULT_SEARCH_FIELDS: &str =
+    "summary,status,issuetype,priority,assignee,reporter,created,updated,labels";
+pub(super) const DETAIL_FIELDS: &str = "summary,status,issuetype,priority,assignee,reporter,created,updated,labels,components,fixVersions,parent,description,resolution,duedate";
+pub(super) const DEFAULT_HISTORY_LIMIT: usize = 20;
+pub(super) const MAX_HISTORY_LIMIT: usize = 100;
+
+/// Plain text for an ADF document (or a v2-style string body). Lists keep
+/// `- ` / `1. ` markers, headings a leading `#`, and table cells are joined
+/// with ` | `.
+pub(super) fn adf_to_text(doc: &Value) -> String {
+    if let Some(s) = doc.as_str() {
+        return s.trim().to_string();
+    }
+    let mut out = String::new();
+    render(doc, &mut out, 0, false);
+    out.trim().to_string()
+}
+
+fn children(node: &Value) -> &[Value] {
+    node.get("content")
+        .and_then(|c| c.as_array())
+        .map(|c| c.as_slice())
+        .unwrap_or(&[])
+}
+
+fn attr<'a>(node: &'a Value, key: &str) -> Option<&'a str> {
+    node.pointer(&format!("/attrs/{}", key))
+        .and_then(|v| v.as_str())
+}
+
+/// Make `out` end with at least `newlines` line breaks (unless it is empty).
+fn end_block(out: &mut String, newlines: usize) {
+    if out.is_empty() {
+        return;
+    }
+    let existing = out.len() - out.trim_end_matches('\n').len();
+    for _ in existing..newlines {
+        out.push('\n');
+    }
+}
+
+fn render(node: &Value, out: &mut String, depth: usize, tight: bool) {
+    let kind = node.get("type").and_then(|t| t.as_str()).unwrap_or("");
+    match kind {
+        "text" => out.push_str(node.get("text").and_then(|t| t.as_str()).unwrap_or("")),
+        "hardBreak" => out.push('\n'),
+        "mention" | "status" => out.push_str(attr(node, "text").unwrap_or("")),
+        "emoji" => out.push_str(
+            attr(node, "text")
+                .or_else(|| attr(node, "shortName"))
+                .unwrap_or(""),
+        ),
+        "inlineCard" | "blockCard" | "embedCard" => out.push_str(attr(node, "url").unwrap_or("")),
+        "date" => {
+            let day = attr(node, "timestamp")
+                .and_then(|ms| ms.parse::<i64>().ok())
+                .and_then(chrono::DateTime::from_timestamp_millis)
+                .map(|d| d.format("%Y-%m-%d").to_string());
+            out.push_str(day.as_deref().unwrap_or(""));
+        }
+        "rule" => {
+            end_block(out, 2);
+            out.push_str("---");
+            end_block(out, 2);
+        }
+        "bulletList" | "orderedList" => {
+            let ordered = kind == "orderedList";
+            let start = node
+                .pointer("/attrs/order")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(1);
+            end_block(out, 1);
+            for (i, item) in children(node).iter().enumerate() {
+                end_block(out, 1);
+                out.push_str(&"  ".repeat(depth));
+                if ordered {
+                    out.push_str(&format!("{}. ", start + i as u64));
+                } else {
+                    out.push_str("- ");
+                }
+                for child in children(item) {
+                    render(child, out, depth + 1, true);
+                }
+            }
+            end_block(out, if tight { 1 } else { 2 });
+        }
+        "table" => {
+            end_block(out, 2);
+            for row in children(node) {
+                let cells: Vec<String> = children(row)
+                    .iter()
+                    .map(|cell| {
+                        let mut text = String::new();
+                        for child in children(cell) {
+                            render(child, &mut text, 0, true);
+                        }
+                        text.split_whitespace().collect::<Vec<_>>().join(" ")
+                    })
+                    .collect();
+                out.push_str(&cells.join(" | "));
+                out.push('\n');
+            }
+            end_block(out, 2);
+        }
+        "heading" | "paragraph" | "blockquote" | "codeBlock" | "panel" | "mediaSingle"
+        | "expand" => {
+            if kind == "heading" {
+                let level = node
+                    .pointer("/attrs/level")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(1)
+                    .clamp(1, 6);
+                out.push_str(&"#".repeat(level as usize));
+                out.push(' ');
+            }
+            if kind == "expand" {
+                if let Some(title) = attr(node, "title") {
+                    out.push_str(title);
+                    out.push('\n');
+                }
+            }
+            for child in children(node) {
+                render(child, out, depth, tight);
+            }
+            end_block(out, if tight { 1 } else { 2 });
+        }
+        _ => {
+            for child in children(node) {
+                render(child, out, depth, tight);
+            }
+        }
+    }
+}
+
+/// ADF document for plain text: blank lines separate paragraphs and single
+/// line breaks become hard breaks.
+pub(super) fn text_to_adf(text: &str) -> Value {
+    let paragraphs: Vec<Value> = text
+        .replace("\r\n", "\n")
+        .split("\n\n")
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            let mut content = Vec::new();
+            for (i, line) in p.lines().enumerate() {
+                if i > 0 {
+                    content.push(json!({"type": "hardBreak"}));
+                }
+                if !line.is_empty() {
+                    content.push(json!({"type": "text", "text": line}));
+                }
+            }
+            json!({"type": "paragraph", "content": content})
+        })
+        .collect();
+    json!({"type": "doc", "version": 1, "content": paragraphs})
+}
+
+fn name_of(v: Option<&Value>, key: &str) -> Value {
+    v.and_then(|v| v.get(key)).cloned().unwrap_or(Value::Null)
+}
+
+fn names(v: Option<&Value>) -> Vec<Value> {
+    v.and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|i| i.get("name").cloned())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Compact view of an issue: key fields flattened to names and the
+/// description as text. `browse_base` is the site URL for the issue link.
+pub(super) fn summarize_issue(issue: &Value, browse_base: Option<&str>) -> Value {
+    let key = issue.get("key").and_then(|k| k.as_str()).unwrap_or("");
+    let fields = issue.get("fields");
+    let field = |name: &str| fields.and_then(|f| f.get(name)).filter(|v| !v.is_null());
+    json!({
+        "key": key,
+        "id": issue.get("id"),
+        "url": browse_base.map(|b| format!("{}/browse/{}", b.trim_end_matches('/'), key)),
+        "summary": field("summary"),
+        "status": name_of(field("status"), "name"),
+        "status_category": field("status").and_then(|s| s.pointer("/statusCategory/name")),
+        "type": name_of(field("issuetype"), "name"),
+        "priority": name_of(field("priority"), "name"),
+        "assignee": name_of(field("assignee"), "displayName"),
+        "reporter": name_of(field("reporter"), "displayName"),
+        "resolution": name_of(field("resolution"), "name"),
+        "created": field("created"),
+        "updated": field("updated"),
+        "due": field("duedate"),
+        "parent": name_of(field("parent"), "key"),
+        "labels": field("labels").cloned().unwrap_or_else(|| json!([])),
+        "components": names(field("components")),
+        "fix_versions": names(field("fixVersions")),
+        "description": field("description").map(adf_to_text),
+    })
+}
+
+pub(super) fn summarize_comment(comment: &Value) -> Value {
+    json!({
+        "id": comment.get("id"),
+        "author": name_of(comment.get("author"), "displayName"),
+        "created": comment.get("created"),
+        "updated": comment.get("updated"),
+        "body": comment.get("body").map(adf_to_text).unwrap_or_default(),
+    })
+}
+
+/// One changelog entry: who changed which fields from what to what.
+pub(super) fn summarize_history(history: &Value) -> Value {
+    let items: Vec<Value> = history
+        .get("items")
+        .and_then(|i| i.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .map(|i| {
+                    json!({
+                        "field": i.get("field"),
+                        "from": i.get("fromString"),
+                        "to": i.get("toString"),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    json!({
+        "id": history.get("id"),
+        "author": name_of(history.get("author"), "displayName"),
+        "created": history.get("created"),
+        "items": items,
+    })
+}
+
+/// The transition matching `wanted` by id, transition name or target
+/// status name (case-insensitive).
+pub(super) fn find_transition<'a>(transitions: &'a [Value], wanted: &str) -> Option<&'a Value> {
+    let wanted = wanted.trim();
+    let text = |t: &Value, ptr: &str| t.pointer(ptr).and_then(|v| v.as_str()).map(str::to_string);
+    transitions
+        .iter()
+        .find(|t| text(t, "/id").as_deref() == Some(wanted))
+        .or_else(|| {
+            transitions.iter().find(|t| {
+                text(t, "/name").is_some_and(|n| n.eq_ignore_ascii_case(wanted))
+                    || text(t, "/to/name").is_some_and(|n| n.eq_ignore_ascii_case(wanted))
+            })
+        })
+}
+
+/// Jira's error text from a failed response body, if it carries one.
+pub(super) fn error_message(v: &Value) -> Option<String> {
+    let mut messages: Vec<String> = v
+        .get("errorMessages")
+        .and_then(|m| m.as_array())
+        .map(|m| {
+            m.iter()
+                .filter_map(|s| s.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    if let Some(errors) = v.get("errors").and_then(|e| e.as_object()) {
+        for (field, message) in errors {
+            messages.push(format!(
+                "{}: {}",
+                field,
+                message.as_str().unwrap_or("invalid")
+            ));
+        }
+    }
+    (!messages.is_empty()).then(|| messages.join("; "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flattens_adf_and_shapes_issues() {
+        let doc = json!({"type": "doc", "version": 1, "content": [
+            {"type": "heading", "attrs": {"level": 2}, "content": [{"type": "text", "text": "Steps"}]},
+            {"type": "orderedList", "content": [
+                {"type": "listItem", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "Open "}, {"type": "inlineCard", "attrs": {"url": "https://x.test/a"}}]}]},
+                {"type": "listItem", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "Ping "}, {"type": "mention", "attrs": {"text": "@Ada"}}]}]}
+            ]},
+            {"type": "paragraph", "content": [{"type": "text", "text": "line one"}, {"type": "hardBreak"}, {"type": "text", "text": "line two"}]}
+        ]});
+        assert_eq!(
+            adf_to_text(&doc),
+            "## Steps\n\n1. Open https://x.test/a\n2. Ping @Ada\n\nline one\nline two"
+        );
+        assert_eq!(
+            adf_to_text(&text_to_adf("line one\nline two\n\n\nnext")),
+            "line one\nline two\n\nnext"
+        );
+
+        let issue = json!({"id": "10001", "key": "DEMO-7", "fields": {
+            "summary": "Crash on save",
+            "status": {"name": "In Progress", "statusCategory": {"name": "In Progress"}},
+            "assignee": null,
+            "reporter": {"displayName": "Ada"},
+            "components": [{"name": "editor"}],
+            "description": "v2 plain text"
+        }});
+        let summary = summarize_issue(&issue, Some("https://acme.atlassian.net/"));
+        assert_eq!(summary["url"], "https://acme.atlassian.net/browse/DEMO-7");
+        assert_eq!(summary["status"], "In Progress");
+        assert_eq!(summary["assignee"], Value::Null);
+        assert_eq!(summary["reporter"], "Ada");
+        assert_eq!(summary["components"], json!(["editor"]));
+        assert_eq!(summary["description"], "v2 plain text");
+
+        let transitions = vec![
+            json!({"id": "11", "name": "Start work", "to": {"name": "In Progress"}}),
+            json!({"id": "31", "name": "Close", "to": {"name": "Done"}}),
+        ];
+        assert_eq!(find_transition(&transitions, "done").unwrap()["id"], "31");
+        assert_eq!(
+            find_transition(&transitions, "start WORK").unwrap()["id"],
+            "11"
+        );
+        assert_eq!(
+            find_transition(&transitions, "11").unwrap()["name"],
+            "Start work"
+        );
+        assert!(find_transition(&transitions, "Reopen").is_none());
+
+        assert_eq!(
+            error_message(&json!({"errorMessages": [], "errors": {"comment": "Comment body can not be empty!"}})).as_deref(),
+            Some("comment: Comment body can not be empty!")
+        );
+    }
+}
@@ -9,10 +9,12 @@ use crate::auth::AuthDetails;
 use crate::auth_store::{AuthStore, FileAuthStore};
 use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
 use crate::error::ConnectorError;
-use crate::utils::structured_result_with_text;
+use crate::utils::{structured_result_with_text, write_gate};
 use crate::Connector;
 use base64::Engine as _;
 
+mod jira;
+
 #[derive(Clone, Default)]
 pub struct AtlassianConnector {
     auth: AuthDetails,
@@ -93,6 +95,77 @@ impl AtlassianConnector {
         .await
     }
 
+    /// POST a JSON body to Jira. Transitions answer 204 with no body, which
+    /// comes back as `{}`; Jira's error bodies become errors.
+    async fn jira_post(&self, path: &str, body: &Value) -> Result<Value, ConnectorError> {
+        let base = self.jira_base().ok_or_else(|| {
+            ConnectorError::Authentication("jira_base not configured".to_string())
+        })?;
+        let url = format!(
+            "{}/{}",
+            base.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        );
+        let auth = self.basic_auth_header()?;
+        let v = self
+            .send_with_backoff(|client| {
+                client
+                    .post(&url)
+                    .header(reqwest::header::AUTHORIZATION, auth.clone())
+                    .json(body)
+            })
+            .await?;
+        if let Some(message) = jira::error_message(&v) {
+            return Err(ConnectorError::Other(format!("Jira: {}", message)));
+        }
+        Ok(v)
+    }
+
+    /// Available transitions for an issue from its current status.
+    async fn jira_transitions(&self, key: &str) -> Result<Vec<Value>, ConnectorError> {
+        let v = self
+            .jira_get(
+                &format!("rest/api/3/issue/{}/transitions", issue_path(key)),
+                &[],
+            )
+            .await?;
+        if let Some(message) = jira::error_message(&v) {
+            return Err(ConnectorError::Other(format!("Jira: {}", message)));
+        }
+        Ok(v.get("transitions")
+            .and_then(|t| t.as_array())
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    /// The last `limit` changelog entries, oldest first. The changelog
+    /// endpoint pages from the oldest entry, so a long history takes a
+    /// second request from the end.
+    async fn jira_changelog(&self, key: &str, limit: usize) -> Result<Value, ConnectorError> {
+        let path = format!("rest/api/3/issue/{}/changelog", issue_path(key));
+        let mut v = self
+            .jira_get(&path, &[("maxResults", limit.to_string())])
+            .await?;
+        let total = v.get("total").and_then(|t| t.as_u64()).unwrap_or(0) as usize;
+        if total > limit {
+            v = self
+                .jira_get(
+                    &path,
+                    &[
+                        ("startAt", (total - limit).to_string()),
+                        ("maxResults", limit.to_string()),
+                    ],
+                )
+                .await?;
+        }
+        let entries: Vec<Value> = v
+            .get("values")
+            .and_then(|h| h.as_array())
+            .map(|h| h.iter().map(jira::summarize_history).collect())
+            .unwrap_or_default();
+        Ok(json!({ "total": total, "entries": entries }))
+    }
+
     async fn confluence_get(
         &self,
         path: &str,
@@ -154,11 +227,12 @@ impl AtlassianConnector {
                         delay_ms = (delay_ms as f64 * 1.6) as u64;
                         continue;
                     }
-                    let v = r
-                        .json::<Value>()
-                        .await
-                        .map_err(ConnectorError::HttpRequest)?;
-                    return Ok(v);
+                    let body = r.text().await.map_err(ConnectorError::HttpRequest)?;
+                    // Writes such as Jira transitions answer 204 with no body
+                    if body.trim().is_empty() {
+                        return Ok(json!({}));
+                    }
+                    return serde_json::from_str(&body).map_err(ConnectorError::SerdeJson);
                 }
                 Err(e) => {
                     if attempt == MAX_RETRIES {
@@ -174,15 +248,24 @@ impl AtlassianConnector {
     }
 }
 
+/// Issue key or id as a URL path segment.
+fn issue_path(key: &str) -> String {
+    urlencoding::encode(key.trim()).into_owned()
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct JiraSearchInput {
     jql: String,
     #[serde(default)]
     start_at: Option<u32>,
     #[serde(default)]
+    next_page_token: Option<String>,
+    #[serde(default)]
     max_results: Option<u32>,
     #[serde(default)]
     fields: Option<String>,
+    #[serde(default)]
+    expand: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -192,6 +275,85 @@ struct JiraGetIssueInput {
     expand: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct JiraIssueDetailInput {
+    key: String,
+    #[serde(default = "default_true")]
+    include_comments: bool,
+    #[serde(default = "default_true")]
+    include_changelog: bool,
+    #[serde(default)]
+    comment_limit: Option<usize>,
+    #[serde(default)]
+    changelog_limit: Option<usize>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JiraKeyInput {
+    key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JiraAddCommentInput {
+    key: String,
+    body: String,
+    #[serde(default)]
+    confirm: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JiraTransitionInput {
+    key: String,
+    /// Transition id, transition name or target status name
+    transition: String,
+    #[serde(default)]
+    comment: Option<String>,
+    #[serde(default)]
+    confirm: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JiraListBoardsInput {
+    #[serde(default)]
+    project: Option<String>,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default, rename = "type")]
+    board_type: Option<String>,
+    #[serde(default)]
+    start_at: Option<u32>,
+    #[serde(default)]
+    max_results: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JiraListSprintsInput {
+    board_id: u64,
+    #[serde(default)]
+    state: Option<String>,
+    #[serde(default)]
+    start_at: Option<u32>,
+    #[serde(default)]
+    max_results: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JiraSprintIssuesInput {
+    sprint_id: u64,
+    #[serde(default)]
+    jql: Option<String>,
+    #[serde(default)]
+    start_at: Option<u32>,
+    #[serde(default)]
+    max_results: Option<u32>,
+    #[serde(default)]
+    fields: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct ConfluenceSearchInput {
     cql: String,
@@ -266,8 +428,16 @@ impl Connector for AtlassianConnector {
         let tools = vec![
             Tool { name: Cow::Borrowed("test_auth"), title: None, description: Some(Cow::Borrowed("Validate Jira/Confluence auth by fetching self info.")), input_schema: Arc::new(json!({"type":"object","properties":{}}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
             // Jira
-            Tool { name: Cow::Borrowed("jira_search_issues"), title: None, description: Some(Cow::Borrowed("Search issues with JQL.")), input_schema: Arc::new(json!({"type":"object","properties":{"jql":{"type":"string"},"start_at":{"type":"integer"},"max_results":{"type":"integer"},"fields":{"type":"string"}},"required":["jql"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("jira_search_issues"), title: None, description: Some(Cow::Borrowed("Search issues with raw JQL. Pages with next_page_token (returned as nextPageToken); start_at uses the older offset search instead. Example: jql=\"project = DEMO AND status = 'In Progress' ORDER BY updated DESC\".")), input_schema: Arc::new(json!({"type":"object","properties":{"jql":{"type":"string"},"next_page_token":{"type":"string","description":"nextPageToken from the previous page"},"start_at":{"type":"integer","description":"Offset paging (legacy search endpoint)"},"max_results":{"type":"integer"},"fields":{"type":"string","description":"Comma-separated fields; default summary, status, type, priority, people, dates and labels"},"expand":{"type":"string"}},"required":["jql"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
             Tool { name: Cow::Borrowed("jira_get_issue"), title: None, description: Some(Cow::Borrowed("Get a Jira issue with optional expand.")), input_schema: Arc::new(json!({"type":"object","properties":{"key":{"type":"string"},"expand":{"type":"string"}},"required":["key"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("jira_get_issue_detail"), title: None, description: Some(Cow::Borrowed("An issue's key fields and description as text, with its latest comments and changelog (who changed which field from what to what).")), input_schema: Arc::new(json!({"type":"object","properties":{"key":{"type":"string"},"include_comments":{"type":"boolean","description":"Default true"},"include_changelog":{"type":"boolean","description":"Default true"},"comment_limit":{"type":"integer","description":"Latest comments to return (default 20, max 100)"},"changelog_limit":{"type":"integer","description":"Latest changelog entries to return (default 20, max 100)"}},"required":["key"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("jira_list_transitions"), title: None, description: Some(Cow::Borrowed("Workflow transitions available for an issue from its current status.")), input_schema: Arc::new(json!({"type":"object","properties":{"key":{"type":"string"}},"required":["key"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("jira_add_comment"), title: None, description: Some(Cow::Borrowed("Add a plain-text comment to an issue. Writes to Jira: disabled unless allow_writes is configured; pass confirm=true after the user approves the text.")), input_schema: Arc::new(json!({"type":"object","properties":{"key":{"type":"string"},"body":{"type":"string","description":"Plain text; blank lines separate paragraphs"},"confirm":{"type":"boolean","description":"Must be true; confirms the user approved this change"}},"required":["key","body","confirm"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("jira_transition_issue"), title: None, description: Some(Cow::Borrowed("Move an issue through its workflow, optionally with a comment. transition is a transition id or name, or the target status (see jira_list_transitions). Writes to Jira: disabled unless allow_writes is configured; pass confirm=true after the user approves.")), input_schema: Arc::new(json!({"type":"object","properties":{"key":{"type":"string"},"transition":{"type":"string","description":"Transition id, transition name, or target status name"},"comment":{"type":"string"},"confirm":{"type":"boolean","description":"Must be true; confirms the user approved this change"}},"required":["key","transition","confirm"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            // Jira Software (agile)
+            Tool { name: Cow::Borrowed("jira_list_boards"), title: None, description: Some(Cow::Borrowed("List Scrum and Kanban boards, optionally for a project or by name.")), input_schema: Arc::new(json!({"type":"object","properties":{"project":{"type":"string","description":"Project key or id"},"name":{"type":"string","description":"Board name contains"},"type":{"type":"string","enum":["scrum","kanban","simple"]},"start_at":{"type":"integer"},"max_results":{"type":"integer"}}}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("jira_list_sprints"), title: None, description: Some(Cow::Borrowed("List a board's sprints with goals and dates.")), input_schema: Arc::new(json!({"type":"object","properties":{"board_id":{"type":"integer"},"state":{"type":"string","description":"Comma-separated: future, active, closed"},"start_at":{"type":"integer"},"max_results":{"type":"integer"}},"required":["board_id"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("jira_sprint_issues"), title: None, description: Some(Cow::Borrowed("Issues in a sprint, optionally narrowed with JQL.")), input_schema: Arc::new(json!({"type":"object","properties":{"sprint_id":{"type":"integer"},"jql":{"type":"string"},"start_at":{"type":"integer"},"max_results":{"type":"integer"},"fields":{"type":"string"}},"required":["sprint_id"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
             // Confluence
             Tool { name: Cow::Borrowed("conf_search_pages"), title: None, description: Some(Cow::Borrowed("Search Confluence with CQL.")), input_schema: Arc::new(json!({"type":"object","properties":{"cql":{"type":"string"},"start":{"type":"integer"},"limit":{"type":"integer"}},"required":["cql"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
             Tool { name: Cow::Borrowed("conf_get_page"), title: None, description: Some(Cow::Borrowed("Get a Confluence page (view/storage) with expand.")), input_schema: Arc::new(json!({"type":"object","properties":{"id":{"type":"string"},"expand":{"type":"string"}},"required":["id"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
@@ -307,16 +477,35 @@ impl Connector for AtlassianConnector {
                 let input: JiraSearchInput = serde_json::from_value(Value::Object(args_map))
                     .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
                 let mut params = vec![("jql", input.jql)];
-                if let Some(s) = input.start_at {
-                    params.push(("startAt", s.to_string()));
-                }
                 if let Some(m) = input.max_results {
                     params.push(("maxResults", m.to_string()));
                 }
-                if let Some(f) = input.fields {
-                    params.push(("fields", f));
+                // The token-paged search only returns issue ids unless fields are named
+                params.push((
+                    "fields",
+                    input
+                        .fields
+                        .unwrap_or_else(|| jira::DEFAULT_SEARCH_FIELDS.to_string()),
+                ));
+                if let Some(e) = input.expand {
+                    params.push(("expand", e));
+                }
+                let path = match input.start_at {
+                    Some(s) => {
+                        params.push(("startAt", s.to_string()));
+                        "rest/api/3/search"
+                    }
+                    None => {
+                        if let Some(token) = input.next_page_token {
+                            params.push(("nextPageToken", token));
+                        }
+                        "rest/api/3/search/jql"
+                    }
+                };
+                let v = self.jira_get(path, &params).await?;
+                if let Some(message) = jira::error_message(&v) {
+                    return Err(ConnectorError::InvalidParams(format!("JQL: {}", message)));
                 }
-                let v = self.jira_get("rest/api/3/search", &params).await?;
                 structured_result_with_text(&v, None)
             }
             "jira_get_issue" => {
@@ -331,6 +520,307 @@ impl Connector for AtlassianConnector {
                     .await?;
                 structured_result_with_text(&v, None)
             }
+            "jira_get_issue_detail" => {
+                let input: JiraIssueDetailInput =
+                    serde_json::from_value(Value::Object(args_map))
+                        .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                let key = issue_path(&input.key);
+                let issue = self
+                    .jira_get(
+                        &format!("rest/api/3/issue/{}", key),
+                        &[("fields", jira::DETAIL_FIELDS.to_string())],
+                    )
+                    .await?;
+                if issue.get("key").is_none() {
+                    return Err(match jira::error_message(&issue) {
+                        Some(message) => ConnectorError::Other(format!("Jira: {}", message)),
+                        None => ConnectorError::ResourceNotFound,
+                    });
+                }
+                let mut detail = jira::summarize_issue(&issue, self.jira_base().as_deref());
+
+                if input.include_comments {
+                    let limit = input
+                        .comment_limit
+                        .unwrap_or(jira::DEFAULT_HISTORY_LIMIT)
+                        .clamp(1, jira::MAX_HISTORY_LIMIT);
+                    let v = self
+                        .jira_get(
+                            &format!("rest/api/3/issue/{}/comment", key),
+                            &[
+                                ("orderBy", "-created".to_string()),
+                                ("maxResults", limit.to_string()),
+                            ],
+                        )
+                        .await?;
+                    // Newest first from the API; read in conversation order
+                    let mut comments: Vec<Value> = v
+                        .get("comments")
+                        .and_then(|c| c.as_array())
+                        .map(|c| c.iter().map(jira::summarize_comment).collect())
+                        .unwrap_or_default();
+                    comments.reverse();
+                    detail["comment_total"] = v.get("total").cloned().unwrap_or(Value::Null);
+                    detail["comments"] = json!(comments);
+                }
+                if input.include_changelog {
+                    let limit = input
+                        .changelog_limit
+                        .unwrap_or(jira::DEFAULT_HISTORY_LIMIT)
+                        .clamp(1, jira::MAX_HISTORY_LIMIT);
+                    detail["changelog"] = self.jira_changelog(&input.key, limit).await?;
+                }
+
+                let text = format!(
+                    "{} [{}] {}",
+                    detail["key"].as_str().unwrap_or(""),
+                    detail["status"].as_str().unwrap_or("?"),
+                    detail["summary"].as_str().unwrap_or("")
+                );
+                structured_result_with_text(&detail, Some(text))
+            }
+            "jira_list_transitions" => {
+                let input: JiraKeyInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                let transitions: Vec<Value> = self
+                    .jira_transitions(&input.key)
+                    .await?
+                    .iter()
+                    .map(|t| {
+                        json!({
+                            "id": t.get("id"),
+                            "name": t.get("name"),
+                            "to": t.pointer("/to/name"),
+                        })
+                    })
+                    .collect();
+                structured_result_with_text(
+                    &json!({"key": input.key, "transitions": transitions}),
+                    None,
+                )
+            }
+            "jira_add_comment" => {
+                let input: JiraAddCommentInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                write_gate(
+                    self.name(),
+                    "ARIVU_ATLASSIAN_ALLOW_WRITES",
+                    self.auth.get("allow_writes").map(String::as_str),
+                    input.confirm,
+                )?;
+                if input.body.trim().is_empty() {
+                    return Err(ConnectorError::InvalidParams(
+                        "body must not be empty".to_string(),
+                    ));
+                }
+                let v = self
+                    .jira_post(
+                        &format!("rest/api/3/issue/{}/comment", issue_path(&input.key)),
+                        &json!({ "body": jira::text_to_adf(&input.body) }),
+                    )
+                    .await?;
+                structured_result_with_text(
+                    &json!({"key": input.key, "comment_id": v.get("id"), "created": v.get("created")}),
+                    None,
+                )
+            }
+            "jira_transition_issue" => {
+                let input: JiraTransitionInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                write_gate(
+                    self.name(),
+                    "ARIVU_ATLASSIAN_ALLOW_WRITES",
+                    self.auth.get("allow_writes").map(String::as_str),
+                    input.confirm,
+                )?;
+                let transitions = self.jira_transitions(&input.key).await?;
+                let Some(transition) = jira::find_transition(&transitions, &input.transition)
+                else {
+                    let available: Vec<String> = transitions
+                        .iter()
+                        .filter_map(|t| {
+                            Some(format!(
+                                "{} ({} -> {})",
+                                t.get("id")?.as_str()?,
+                                t.get("name")?.as_str()?,
+                                t.pointer("/to/name")?.as_str()?
+                            ))
+                        })
+                        .collect();
+                    return Err(ConnectorError::InvalidParams(format!(
+                        "No transition '{}' for {}. Available: {}",
+                        input.transition,
+                        input.key,
+                        if available.is_empty() {
+                            "none".to_string()
+                        } else {
+                            available.join(", ")
+                        }
+                    )));
+                };
+                let mut body = json!({ "transition": { "id": transition.get("id") } });
+                if let Some(comment) = input.comment.as_deref().filter(|c| !c.trim().is_empty()) {
+                    body["update"] =
+                        json!({ "comment": [{ "add": { "body": jira::text_to_adf(comment) } }] });
+                }
+                self.jira_post(
+                    &format!("rest/api/3/issue/{}/transitions", issue_path(&input.key)),
+                    &body,
+                )
+                .await?;
+                structured_result_with_text(
+                    &json!({
+                        "key": input.key,
+                        "transition": transition.get("name"),
+                        "status": transition.pointer("/to/name"),
+                    }),
+                    None,
+                )
+            }
+            "jira_list_boards" => {
+                let input: JiraListBoardsInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                let mut params = vec![];
+                if let Some(p) = input.project {
+                    params.push(("projectKeyOrId", p));
+                }
+                if let Some(n) = input.name {
+                    params.push(("name", n));
+                }
+                if let Some(t) = input.board_type {
+                    params.push(("type", t));
+                }
+                if let Some(s) = input.start_at {
+                    params.push(("startAt", s.to_string()));
+                }
+                if let Some(m) = input.max_results {
+                    params.push(("maxResults", m.to_string()));
+                }
+                let v = self.jira_get("rest/agile/1.0/board", &params).await?;
+                let boards: Vec<Value> = v
+                    .get("values")
+                    .and_then(|b| b.as_array())
+                    .map(|b| {
+                        b.iter()
+                            .map(|b| {
+                                json!({
+                                    "id": b.get("id"),
+                                    "name": b.get("name"),
+                                    "type": b.get("type"),
+                                    "project": b.pointer("/location/projectKey"),
+                                })
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                structured_result_with_text(
+                    &json!({
+                        "boards": boards,
+                        "start_at": v.get("startAt"),
+                        "is_last": v.get("isLast"),
+                    }),
+                    None,
+                )
+            }
+            "jira_list_sprints" => {
+                let input: JiraListSprintsInput =
+                    serde_json::from_value(Value::Object(args_map))
+                        .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                let mut params = vec![];
+                if let Some(s) = input.state {
+                    params.push(("state", s));
+                }
+                if let Some(s) = input.start_at {
+                    params.push(("startAt", s.to_string()));
+                }
+                if let Some(m) = input.max_results {
+                    params.push(("maxResults", m.to_string()));
+                }
+                let v = self
+                    .jira_get(
+                        &format!("rest/agile/1.0/board/{}/sprint", input.board_id),
+                        &params,
+                    )
+                    .await?;
+                if let Some(message) = jira::error_message(&v) {
+                    return Err(ConnectorError::InvalidParams(format!("Jira: {}", message)));
+                }
+                let sprints: Vec<Value> = v
+                    .get("values")
+                    .and_then(|s| s.as_array())
+                    .map(|s| {
+                        s.iter()
+                            .map(|s| {
+                                json!({
+                                    "id": s.get("id"),
+                                    "name": s.get("name"),
+                                    "state": s.get("state"),
+                                    "goal": s.get("goal").filter(|g| g.as_str().is_some_and(|g| !g.is_empty())),
+                                    "start": s.get("startDate"),
+                                    "end": s.get("endDate"),
+                                    "completed": s.get("completeDate"),
+                                })
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                structured_result_with_text(
+                    &json!({
+                        "board_id": input.board_id,
+                        "sprints": sprints,
+                        "start_at": v.get("startAt"),
+                        "is_last": v.get("isLast"),
+                    }),
+                    None,
+                )
+            }
+            "jira_sprint_issues" => {
+                let input: JiraSprintIssuesInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                let mut params = vec![(
+                    "fields",
+                    input
+                        .fields
+                        .unwrap_or_else(|| jira::DEFAULT_SEARCH_FIELDS.to_string()),
+                )];
+                if let Some(j) = input.jql {
+                    params.push(("jql", j));
+                }
+                if let Some(s) = input.start_at {
+                    params.push(("startAt", s.to_string()));
+                }
+                if let Some(m) = input.max_results {
+                    params.push(("maxResults", m.to_string()));
+                }
+                let v = self
+                    .jira_get(
+                        &format!("rest/agile/1.0/sprint/{}/issue", input.sprint_id),
+                        &params,
+                    )
+                    .await?;
+                if let Some(message) = jira::error_message(&v) {
+                    return Err(ConnectorError::InvalidParams(format!("Jira: {}", message)));
+                }
+                let base = self.jira_base();
+                let issues: Vec<Value> = v
+                    .get("issues")
+                    .and_then(|i| i.as_array())
+                    .map(|i| {
+                        i.iter()
+                            .map(|i| jira::summarize_issue(i, base.as_deref()))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                structured_result_with_text(
+                    &json!({
+                        "sprint_id": input.sprint_id,
+                        "issues": issues,
+                        "total": v.get("total"),
+                        "start_at": v.get("startAt"),
+                    }),
+                    None,
+                )
+            }
             "conf_search_pages" => {
                 let input: ConfluenceSearchInput = serde_json::from_value(Value::Object(args_map))
                     .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
@@ -427,6 +917,14 @@ impl Connector for AtlassianConnector {
                     description: Some("Create token at id.atlassian.com/manage/api-tokens".into()),
                     options: None,
                 },
+                Field {
+                    name: "allow_writes".into(),
+                    label: "Allow Jira Writes".into(),
+                    field_type: FieldType::Boolean,
+                    required: false,
+                    description: Some("Enable jira_add_comment and jira_transition_issue (each call still needs confirm=true)".into()),
+                    options: None,
+                },
             ],
         }
     }
@@ -0,0 +1,392 @@
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::utils::{resolve_search_filters, structured_result_with_text};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT};
+use reqwest::Client;
+use rmcp::model::*;
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::sync::Arc;
+
+pub struct BraveSearchConnector {
+    client: Client,
+    api_key: Option<String>,
+}
+
+impl BraveSearchConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(|e| ConnectorError::Other(e.to_string()))?;
+        Ok(Self {
+            client,
+            api_key: key_from(&auth),
+        })
+    }
+}
+
+fn key_from(auth: &AuthDetails) -> Option<String> {
+    auth.get("api_key")
+        .cloned()
+        .or_else(|| std::env::var("BRAVE_API_KEY").ok())
+        .or_else(|| std::env::var("BRAVE_SEARCH_API_KEY").ok())
+}
+
+/// Map the unified date filters onto Brave's `freshness` parameter: the
+/// `pd`/`pw`/`pm`/`py` shorthands when a preset matches, otherwise a
+/// `YYYY-MM-DDtoYYYY-MM-DD` range.
+fn brave_freshness(
+    preset: Option<&str>,
+    since: Option<&str>,
+    until: Option<&str>,
+) -> Option<String> {
+    let shorthand = match preset.map(|p| p.to_lowercase()).as_deref() {
+        Some("last_24_hours" | "past_day") => Some("pd"),
+        Some("last_7_days" | "past_week") => Some("pw"),
+        Some("last_30_days" | "past_month") => Some("pm"),
+        Some("last_365_days" | "past_year") => Some("py"),
+        _ => None,
+    };
+    if let Some(s) = shorthand {
+        return Some(s.to_string());
+    }
+    match (since, until) {
+        (Some(s), Some(u)) => Some(format!("{}to{}", s, u)),
+        (Some(s), None) => Some(format!(
+            "{}to{}",
+            s,
+            chrono::Utc::now().date_naive().format("%Y-%m-%d")
+        )),
+        (None, Some(u)) => Some(format!("1970-01-01to{}", u)),
+        (None, None) => None,
+    }
+}
+
+/// Brave has no domain filter parameters, so allow/deny lists become `site:` operators.
+fn apply_domain_filters(query: &str, include: &[String], exclude: &[String]) -> String {
+    let mut q = query.to_string();
+    match include.len() {
+        0 => {}
+        1 => q.push_str(&format!(" site:{}", include[0])),
+        _ => {
+            let sites: Vec<String> = include.iter().map(|d| format!("site:{}", d)).collect();
+            q.push_str(&format!(" ({})", sites.join(" OR ")));
+        }
+    }
+    for d in exclude {
+        q.push_str(&format!(" -site:{}", d));
+    }
+    q
+}
+
+fn concise_results(items: Option<&Value>) -> Vec<Value> {
+    items
+        .and_then(|v| v.as_array())
+        .map(|a| {
+            a.iter()
+                .map(|r| {
+                    json!({
+                        "title": r["title"],
+                        "url": r["url"],
+                        "snippet": r["description"],
+                        "age": r["age"],
+                        "source": r["profile"]["name"],
+                        "extra_snippets": r["extra_snippets"],
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[async_trait]
+impl Connector for BraveSearchConnector {
+    fn name(&self) -> &'static str {
+        "brave-search"
+    }
+    fn description(&self) -> &'static str {
+        "Brave Search API (independent web index, JSON results)."
+    }
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: None,
+            ..Default::default()
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _r: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().into(),
+                version: "0.1.0".into(),
+                title: None,
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Use 'search' to call api.search.brave.com/res/v1/web/search.".into(),
+            ),
+        })
+    }
+    async fn list_resources(
+        &self,
+        _r: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+    async fn read_resource(
+        &self,
+        _r: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Ok(vec![])
+    }
+
+    async fn list_tools(
+        &self,
+        _r: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tool = Tool { name: Cow::Borrowed("search"), title: None, description: Some(Cow::Borrowed("Web search via Brave's independent index. Use for general web results, especially recent pages. Example: query=\"tokio select cancellation\" limit=5 date_preset=\"last_30_days\".")), input_schema: Arc::new(json!({
+            "type": "object",
+            "properties": {
+                "query": {"type": "string"},
+                "limit": {"type": "integer", "default": 10, "description": "Results to return (max 20)."},
+                "max_results": {"type": "integer", "description": "Alias for limit (deprecated)."},
+                "count": {"type": "integer", "description": "Alias for limit (Brave's name)."},
+                "offset": {"type": "integer", "description": "Page offset (0-9), in pages of `limit` results."},
+                "date_preset": {"type": "string", "description": "last_24_hours|last_7_days|last_30_days|this_month|past_year"},
+                "since": {"type": "string", "description": "YYYY-MM-DD"},
+                "until": {"type": "string", "description": "YYYY-MM-DD"},
+                "freshness": {"type": "string", "description": "Brave freshness: pd|pw|pm|py or YYYY-MM-DDtoYYYY-MM-DD (overrides date filters)"},
+                "locale": {"type": "string", "description": "Locale like en-US or fr-FR"},
+                "language": {"type": "string", "description": "BCP-47 language (search_lang)"},
+                "region": {"type": "string", "description": "Country code (country), e.g. US"},
+                "country": {"type": "string", "description": "Alias for region."},
+                "include_domains": {"type": "array", "items": {"type": "string"}},
+                "exclude_domains": {"type": "array", "items": {"type": "string"}},
+                "safesearch": {"type": "string", "enum": ["off","moderate","strict"], "default": "moderate"},
+                "response_format": {"type": "string", "enum": ["concise","detailed"], "default": "concise"}
+            },
+            "required": ["query"],
+            "additionalProperties": false
+        }).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None };
+        Ok(ListToolsResult {
+            tools: vec![tool],
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        if request.name.as_ref() != "search" {
+            return Err(ConnectorError::ToolNotFound);
+        }
+        let args = request.arguments.unwrap_or_default();
+        let query = args
+            .get("query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| ConnectorError::InvalidParams("Missing 'query'".into()))?;
+        let count = args
+            .get("limit")
+            .or_else(|| args.get("max_results"))
+            .or_else(|| args.get("count"))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(10)
+            .clamp(1, 20);
+        let offset = args
+            .get("offset")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0)
+            .min(9);
+        let detailed = args
+            .get("response_format")
+            .and_then(|v| v.as_str())
+            .map(|s| s == "detailed")
+            .unwrap_or(false);
+
+        let key = self.api_key.as_ref().ok_or_else(|| {
+            ConnectorError::InvalidInput(
+                "Missing credentials: set BRAVE_API_KEY or run `arivu config set brave-search --value <key>`."
+                    .into(),
+            )
+        })?;
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "X-Subscription-Token",
+            HeaderValue::from_str(key).map_err(|e| ConnectorError::Other(e.to_string()))?,
+        );
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+
+        let filters = resolve_search_filters(&args);
+        let q = apply_domain_filters(query, &filters.include_domains, &filters.exclude_domains);
+        let mut params: Vec<(&str, String)> = vec![
+            ("q", q),
+            ("count", count.to_string()),
+            ("offset", offset.to_string()),
+            (
+                "safesearch",
+                args.get("safesearch")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("moderate")
+                    .to_string(),
+            ),
+        ];
+        let freshness = args
+            .get("freshness")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .or_else(|| {
+                brave_freshness(
+                    args.get("date_preset").and_then(|v| v.as_str()),
+                    filters.since.as_deref(),
+                    filters.until.as_deref(),
+                )
+            });
+        if let Some(f) = &freshness {
+            params.push(("freshness", f.clone()));
+        }
+        if let Some(lang) = &filters.language {
+            params.push(("search_lang", lang.to_lowercase()));
+        }
+        let country = filters.region.clone().or_else(|| {
+            args.get("country")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+        });
+        if let Some(c) = &country {
+            params.push(("country", c.to_uppercase()));
+        }
+
+        let resp = self
+            .client
+            .get("https://api.search.brave.com/res/v1/web/search")
+            .headers(headers)
+            .query(&params)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+        let status = resp.status();
+        let value: Value = resp.json().await.map_err(ConnectorError::HttpRequest)?;
+        if !status.is_success() {
+            return Err(ConnectorError::Other(format!(
+                "Brave Search API error: {} - {}",
+                status, value
+            )));
+        }
+
+        let results = concise_results(value.pointer("/web/results"));
+        let news = concise_results(value.pointer("/news/results"));
+        let mut data = json!({
+            "provider": "brave",
+            "query": query,
+            "limit_hint": count,
+            "freshness": freshness,
+            "results": results,
+        });
+        if let Some(altered) = value.pointer("/query/altered").and_then(|v| v.as_str()) {
+            data["altered_query"] = json!(altered);
+        }
+        if !news.is_empty() {
+            data["news"] = json!(news);
+        }
+        if value.pointer("/query/more_results_available") == Some(&json!(true)) {
+            data["next_offset"] = json!(offset + 1);
+        }
+        if detailed {
+            data["raw"] = value;
+        }
+        Ok(structured_result_with_text(&data, None)?)
+    }
+
+    async fn list_prompts(
+        &self,
+        _r: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+    async fn get_prompt(&self, _name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::ToolNotFound)
+    }
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        let mut a = AuthDetails::new();
+        if let Some(v) = &self.api_key {
+            a.insert("api_key".into(), v.clone());
+        }
+        Ok(a)
+    }
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        self.api_key = key_from(&details);
+        Ok(())
+    }
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        if self
+            .api_key
+            .as_ref()
+            .map(|s| !s.is_empty())
+            .unwrap_or(false)
+        {
+            Ok(())
+        } else {
+            Err(ConnectorError::InvalidInput("Missing api_key".into()))
+        }
+    }
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema {
+            fields: vec![Field {
+                name: "api_key".into(),
+                label: "Brave Search API Key".into(),
+                field_type: FieldType::Secret,
+                required: true,
+                description: Some("Set BRAVE_API_KEY".into()),
+                options: None,
+            }],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn freshness_prefers_shorthand_then_range() {
+        assert_eq!(
+            brave_freshness(Some("last_7_days"), Some("2025-01-01"), None).as_deref(),
+            Some("pw")
+        );
+        assert_eq!(
+            brave_freshness(None, Some("2025-01-01"), Some("2025-02-01")).as_deref(),
+            Some("2025-01-01to2025-02-01")
+        );
+        assert_eq!(brave_freshness(None, None, None), None);
+    }
+
+    #[test]
+    fn domain_filters_become_site_operators() {
+        let inc = vec!["docs.rs".to_string(), "rust-lang.org".to_string()];
+        let exc = vec!["reddit.com".to_string()];
+        assert_eq!(
+            apply_domain_filters("tokio", &inc, &exc),
+            "tokio (site:docs.rs OR site:rust-lang.org) -site:reddit.com"
+        );
+        assert_eq!(
+            apply_domain_filters("tokio", &inc[..1], &[]),
+            "tokio site:docs.rs"
+        );
+    }
+}
@@ -0,0 +1,874 @@
+// Browser History Connector - local Chrome, Brave, Firefox and Safari history
+//
+// Each browser keeps history in a SQLite database inside its profile directory.
+// Browsers hold those files open (and locked) while running, so every query
+// works on a private snapshot copied to the temp dir; the original is never
+// opened. Safari's History.db is protected by TCC and needs Full Disk Access.
+
+use crate::error::ConnectorError;
+use crate::utils::{
+    match_browser, resolve_search_filters, structured_result_with_text, tool, Browser,
+};
+use crate::{auth::AuthDetails, capabilities::ConnectorConfigSchema, Connector};
+use async_trait::async_trait;
+use chrono::{Local, NaiveDate, NaiveDateTime, TimeZone};
+use rmcp::model::*;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{ConnectOptions, Connection, Row};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const DEFAULT_SEARCH_LIMIT: usize = 25;
+const DEFAULT_TOP_LIMIT: usize = 20;
+const MAX_LIMIT: usize = 200;
+/// Seconds between 1601-01-01 (Chromium/WebKit epoch) and the Unix epoch.
+const CHROMIUM_EPOCH_OFFSET: i64 = 11_644_473_600;
+/// Seconds between the Unix epoch and 2001-01-01 (Core Foundation epoch, used by Safari).
+const SAFARI_EPOCH_OFFSET: i64 = 978_307_200;
+
+/// A history database found in a browser profile.
+#[derive(Debug, Clone)]
+struct HistoryDb {
+    browser: Browser,
+    profile: String,
+    path: PathBuf,
+}
+
+#[derive(Debug, Serialize)]
+struct HistoryEntry {
+    url: String,
+    title: String,
+    domain: String,
+    /// Visits within the requested time range
+    visits: i64,
+    last_visited: String,
+    browser: &'static str,
+    profile: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum SortBy {
+    Recent,
+    MostVisited,
+}
+
+#[derive(Debug)]
+struct HistoryQuery {
+    /// `LIKE` pattern applied to URL and title
+    pattern: String,
+    since: i64,
+    until: i64,
+    /// Extra SQL restricting the URL column, already escaped
+    domain_sql: Option<String>,
+    order: SortBy,
+    limit: usize,
+}
+
+fn browser_label(browser: &Browser) -> &'static str {
+    match browser {
+        Browser::Chrome => "chrome",
+        Browser::Brave => "brave",
+        Browser::Firefox => "firefox",
+        Browser::Safari => "safari",
+    }
+}
+
+// ============================================================================
+// Profile detection
+// ============================================================================
+
+/// Chromium "User Data" directory for Chrome or Brave on this platform.
+fn chromium_root(browser: &Browser) -> Option<PathBuf> {
+    let vendor: &[&str] = match browser {
+        Browser::Chrome if cfg!(target_os = "linux") => &["google-chrome"],
+        Browser::Chrome => &["Google", "Chrome"],
+        Browser::Brave => &["BraveSoftware", "Brave-Browser"],
+        _ => return None,
+    };
+    let base = if cfg!(target_os = "windows") {
+        dirs::data_local_dir()?
+    } else {
+        dirs::config_dir()?
+    };
+    let mut root = vendor.iter().fold(base, |p, part| p.join(part));
+    if cfg!(target_os = "windows") {
+        root = root.join("User Data");
+    }
+    Some(root)
+}
+
+fn firefox_root() -> Option<PathBuf> {
+    if cfg!(target_os = "linux") {
+        Some(dirs::home_dir()?.join(".mozilla").join("firefox"))
+    } else if cfg!(target_os = "windows") {
+        Some(
+            dirs::config_dir()?
+                .join("Mozilla")
+                .join("Firefox")
+                .join("Profiles"),
+        )
+    } else {
+        Some(dirs::config_dir()?.join("Firefox").join("Profiles"))
+    }
+}
+
+/// Subdirectories of `root` that contain `file`, sorted by name.
+fn profiles_containing(root: &Path, file: &str) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return Vec::new();
+    };
+    let mut found: Vec<(String, PathBuf)> = entries
+        .flatten()
+        .filter(|e| e.path().is_dir())
+        .filter_map(|e| {
+            let path = e.path().join(file);
+            path.is_file()
+                .then(|| (e.file_name().to_string_lossy().into_owned(), path))
+        })
+        .collect();
+    found.sort();
+    found
+}
+
+/// Display name for a Firefox profile directory (`abcd1234.default-release` -> `default-release`).
+fn firefox_profile_name(dir: &str) -> String {
+    dir.split_once('.')
+        .map(|(_, name)| name.to_string())
+        .unwrap_or_else(|| dir.to_string())
+}
+
+fn detect_history_dbs(browser: &Browser) -> Vec<HistoryDb> {
+    let found = match browser {
+        Browser::Chrome | Browser::Brave => chromium_root(browser)
+            .map(|root| profiles_containing(&root, "History"))
+            .unwrap_or_default(),
+        Browser::Firefox => firefox_root()
+            .map(|root| {
+                profiles_containing(&root, "places.sqlite")
+                    .into_iter()
+                    .map(|(dir, path)| (firefox_profile_name(&dir), path))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        Browser::Safari => {
+            if !cfg!(target_os = "macos") {
+                return Vec::new();
+            }
+            // ~/Library/Safari is hidden without Full Disk Access, so don't probe it
+            // here; the permission error surfaces when the database is copied.
+            dirs::home_dir()
+                .map(|home| home.join("Library").join("Safari").join("History.db"))
+                .map(|path| vec![("Default".to_string(), path)])
+                .unwrap_or_default()
+        }
+    };
+    found
+        .into_iter()
+        .map(|(profile, path)| HistoryDb {
+            browser: browser.clone(),
+            profile,
+            path,
+        })
+        .collect()
+}
+
+fn all_browsers() -> Vec<Browser> {
+    vec![
+        Browser::Chrome,
+        Browser::Brave,
+        Browser::Firefox,
+        Browser::Safari,
+    ]
+}
+
+async fn selected_browsers(args: &Map<String, Value>) -> Result<Vec<Browser>, ConnectorError> {
+    match args.get("browser").and_then(|v| v.as_str()) {
+        None | Some("all") | Some("") => Ok(all_browsers()),
+        Some(name) => Ok(vec![match_browser(name.to_lowercase()).await?]),
+    }
+}
+
+// ============================================================================
+// Snapshots and queries
+// ============================================================================
+
+/// A private copy of a history database, removed on drop.
+struct Snapshot {
+    dir: PathBuf,
+    db: PathBuf,
+}
+
+impl Drop for Snapshot {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+static SNAPSHOT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+fn permission_error(db: &HistoryDb) -> ConnectorError {
+    match db.browser {
+        Browser::Safari => ConnectorError::Other(
+            "Cannot read Safari history: Full Disk Access is not granted. Open System Settings > Privacy & Security > Full Disk Access, enable the app running arivu (your terminal or MCP client), then quit and reopen it.".to_string(),
+        ),
+        _ => ConnectorError::Other(format!(
+            "Permission denied reading {}",
+            db.path.display()
+        )),
+    }
+}
+
+/// Copy the database (and its WAL, so recent visits are included) to a temp dir.
+fn snapshot(db: &HistoryDb) -> Result<Snapshot, ConnectorError> {
+    let dir = std::env::temp_dir().join(format!(
+        "arivu-history-{}-{}",
+        std::process::id(),
+        SNAPSHOT_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| ConnectorError::Other(format!("Cannot create temp dir: {}", e)))?;
+    let snap = Snapshot {
+        db: dir.join("history.sqlite"),
+        dir,
+    };
+    std::fs::copy(&db.path, &snap.db).map_err(|e| match e.kind() {
+        std::io::ErrorKind::PermissionDenied => permission_error(db),
+        std::io::ErrorKind::NotFound => ConnectorError::Other(format!(
+            "No {} history found at {}",
+            browser_label(&db.browser),
+            db.path.display()
+        )),
+        _ => ConnectorError::Other(format!("Cannot copy {}: {}", db.path.display(), e)),
+    })?;
+    let wal = PathBuf::from(format!("{}-wal", db.path.display()));
+    if wal.is_file() {
+        let _ = std::fs::copy(&wal, format!("{}-wal", snap.db.display()));
+    }
+    Ok(snap)
+}
+
+/// Per-browser SQL returning `url, title, visits, last_visit` (Unix seconds).
+///
+/// Binds: `?1` LIKE pattern, `?2`/`?3` Unix-second range, `?4` limit.
+fn history_sql(browser: &Browser, query: &HistoryQuery) -> String {
+    let order = match query.order {
+        SortBy::Recent => "last_visit DESC",
+        SortBy::MostVisited => "visits DESC, last_visit DESC",
+    };
+    let (body, url_col) = match browser {
+        Browser::Chrome | Browser::Brave => (
+            format!(
+                "SELECT u.url AS url, u.title AS title, COUNT(*) AS visits, \
+                 MAX(v.visit_time) / 1000000 - {off} AS last_visit \
+                 FROM visits v JOIN urls u ON u.id = v.url \
+                 WHERE v.visit_time >= (?2 + {off}) * 1000000 \
+                 AND v.visit_time < (?3 + {off}) * 1000000 \
+                 AND (u.url LIKE ?1 ESCAPE '\\' OR u.title LIKE ?1 ESCAPE '\\')",
+                off = CHROMIUM_EPOCH_OFFSET
+            ),
+            "u.url",
+        ),
+        Browser::Firefox => (
+            "SELECT p.url AS url, p.title AS title, COUNT(*) AS visits, \
+             MAX(v.visit_date) / 1000000 AS last_visit \
+             FROM moz_historyvisits v JOIN moz_places p ON p.id = v.place_id \
+             WHERE v.visit_date >= ?2 * 1000000 AND v.visit_date < ?3 * 1000000 \
+             AND (p.url LIKE ?1 ESCAPE '\\' OR p.title LIKE ?1 ESCAPE '\\')"
+                .to_string(),
+            "p.url",
+        ),
+        Browser::Safari => (
+            format!(
+                "SELECT i.url AS url, MAX(v.title) AS title, COUNT(*) AS visits, \
+                 CAST(MAX(v.visit_time) + {off} AS INTEGER) AS last_visit \
+                 FROM history_visits v JOIN history_items i ON i.id = v.history_item \
+                 WHERE v.visit_time >= ?2 - {off} AND v.visit_time < ?3 - {off} \
+                 AND (i.url LIKE ?1 ESCAPE '\\' OR v.title LIKE ?1 ESCAPE '\\')",
+                off = SAFARI_EPOCH_OFFSET
+            ),
+            "i.url",
+        ),
+    };
+    let domain = query
+        .domain_sql
+        .as_ref()
+        .map(|sql| format!(" AND {}", sql.replace("{url}", url_col)))
+        .unwrap_or_default();
+    let group = match browser {
+        Browser::Chrome | Browser::Brave => "u.id",
+        Browser::Firefox => "p.id",
+        Browser::Safari => "i.id",
+    };
+    format!(
+        "{}{} GROUP BY {} ORDER BY {} LIMIT ?4",
+        body, domain, group, order
+    )
+}
+
+async fn query_history_db(
+    db: &HistoryDb,
+    query: &HistoryQuery,
+) -> Result<Vec<HistoryEntry>, ConnectorError> {
+    let source = db.clone();
+    let snap = tokio::task::spawn_blocking(move || snapshot(&source))
+        .await
+        .map_err(|e| ConnectorError::Other(format!("Snapshot task failed: {}", e)))??;
+
+    let mut conn = SqliteConnectOptions::new()
+        .filename(&snap.db)
+        .connect()
+        .await
+        .map_err(|e| ConnectorError::Other(format!("Cannot open history database: {}", e)))?;
+    let rows = sqlx::query(&history_sql(&db.browser, query))
+        .bind(&query.pattern)
+        .bind(query.since)
+        .bind(query.until)
+        .bind(query.limit as i64)
+        .fetch_all(&mut conn)
+        .await
+        .map_err(|e| {
+            ConnectorError::Other(format!(
+                "Failed to query {} history ({}): {}",
+                browser_label(&db.browser),
+                db.profile,
+                e
+            ))
+        })?;
+    let _ = conn.close().await;
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let url: String = row.try_get("url").unwrap_or_default();
+            let last_visit: i64 = row.try_get("last_visit").unwrap_or_default();
+            HistoryEntry {
+                domain: url_domain(&url),
+                title: row
+                    .try_get::<Option<String>, _>("title")
+                    .ok()
+                    .flatten()
+                    .unwrap_or_default(),
+                visits: row.try_get("visits").unwrap_or_default(),
+                last_visited: format_timestamp(last_visit),
+                browser: browser_label(&db.browser),
+                profile: db.profile.clone(),
+                url,
+            }
+        })
+        .collect())
+}
+
+fn url_domain(url: &str) -> String {
+    url::Url::parse(url)
+        .ok()
+        .and_then(|u| {
+            u.host_str()
+                .map(|h| h.trim_start_matches("www.").to_string())
+        })
+        .unwrap_or_default()
+}
+
+fn format_timestamp(unix: i64) -> String {
+    Local
+        .timestamp_opt(unix, 0)
+        .single()
+        .map(|dt| dt.to_rfc3339())
+        .unwrap_or_default()
+}
+
+/// Build a `LIKE` pattern matching `s` anywhere, for use with `ESCAPE '\'`.
+fn like_contains(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{}%", escaped)
+}
+
+/// SQL restricting `{url}` to (or away from) the given domains and their subdomains.
+fn domain_filter_sql(include: &[String], exclude: &[String]) -> Option<String> {
+    let host_match = |domain: &str| {
+        let d = domain
+            .trim()
+            .trim_start_matches("www.")
+            .replace('\'', "''")
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+        ["://{d}", "://{d}/%", "://%.{d}", "://%.{d}/%"]
+            .iter()
+            .map(|p| format!("{{url}} LIKE '%{}' ESCAPE '\\'", p.replace("{d}", &d)))
+            .collect::<Vec<_>>()
+            .join(" OR ")
+    };
+    let mut parts = Vec::new();
+    let include: Vec<String> = include
+        .iter()
+        .filter(|d| !d.trim().is_empty())
+        .map(|d| host_match(d))
+        .collect();
+    if !include.is_empty() {
+        parts.push(format!("({})", include.join(" OR ")));
+    }
+    for d in exclude.iter().filter(|d| !d.trim().is_empty()) {
+        parts.push(format!("NOT ({})", host_match(d)));
+    }
+    (!parts.is_empty()).then(|| parts.join(" AND "))
+}
+
+/// Parse `since`/`until` values: RFC 3339, `YYYY-MM-DDTHH:MM[:SS]` or a bare date (local time).
+///
+/// A bare `until` date covers the whole day.
+fn parse_time_bound(value: &str, end_of_day: bool) -> Result<i64, ConnectorError> {
+    let value = value.trim();
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Ok(dt.timestamp());
+    }
+    let local = |naive: NaiveDateTime| {
+        Local
+            .from_local_datetime(&naive)
+            .earliest()
+            .map(|dt| dt.timestamp())
+    };
+    for fmt in ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M"] {
+        if let Ok(naive) = NaiveDateTime::parse_from_str(value, fmt) {
+            if let Some(ts) = local(naive) {
+                return Ok(ts);
+            }
+        }
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .map(|d| {
+            if end_of_day {
+                d.succ_opt().unwrap_or(d)
+            } else {
+                d
+            }
+        })
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .and_then(local)
+        .ok_or_else(|| {
+            ConnectorError::InvalidParams(format!(
+                "Invalid date '{}': expected YYYY-MM-DD, YYYY-MM-DDTHH:MM or RFC 3339",
+                value
+            ))
+        })
+}
+
+/// Resolve keyword, date range (`since`/`until`/`date_preset`), domains and limit.
+fn build_query(
+    args: &Map<String, Value>,
+    order: SortBy,
+    default_days: Option<i64>,
+    default_limit: usize,
+) -> Result<HistoryQuery, ConnectorError> {
+    let filters = resolve_search_filters(args);
+    let now = chrono::Utc::now().timestamp();
+    let since = match filters.since.as_deref() {
+        Some(s) => parse_time_bound(s, false)?,
+        None => default_days.map(|d| now - d * 86_400).unwrap_or(0),
+    };
+    let until = match filters.until.as_deref() {
+        Some(u) => parse_time_bound(u, true)?,
+        // A little slack so visits recorded "now" are not cut off by clock rounding.
+        None => now + 60,
+    };
+    if since >= until {
+        return Err(ConnectorError::InvalidParams(
+            "'since' must be earlier than 'until'".to_string(),
+        ));
+    }
+    let keyword = args
+        .get("query")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .unwrap_or("");
+    let limit = args
+        .get("limit")
+        .and_then(|v| v.as_u64())
+        .map(|l| l as usize)
+        .unwrap_or(default_limit)
+        .clamp(1, MAX_LIMIT);
+    Ok(HistoryQuery {
+        pattern: like_contains(keyword),
+        since,
+        until,
+        domain_sql: domain_filter_sql(&filters.include_domains, &filters.exclude_domains),
+        order,
+        limit,
+    })
+}
+
+#[derive(Default)]
+pub struct BrowserHistoryConnector;
+
+impl BrowserHistoryConnector {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn list_browsers(&self) -> Value {
+        let browsers: Vec<Value> = all_browsers()
+            .iter()
+            .map(|browser| {
+                let profiles: Vec<Value> = detect_history_dbs(browser)
+                    .into_iter()
+                    .map(|db| {
+                        let readable = std::fs::File::open(&db.path).is_ok();
+                        json!({
+                            "profile": db.profile,
+                            "path": db.path.display().to_string(),
+                            "readable": readable,
+                        })
+                    })
+                    .collect();
+                json!({
+                    "browser": browser_label(browser),
+                    "installed": !profiles.is_empty(),
+                    "profiles": profiles,
+                })
+            })
+            .collect();
+        json!({ "browsers": browsers })
+    }
+
+    /// Query every matching profile and merge results; per-profile failures are
+    /// reported alongside results unless nothing could be read at all.
+    async fn run(
+        &self,
+        args: &Map<String, Value>,
+        query: HistoryQuery,
+    ) -> Result<Value, ConnectorError> {
+        let profile = args.get("profile").and_then(|v| v.as_str());
+        let mut dbs = Vec::new();
+        for browser in selected_browsers(args).await? {
+            dbs.extend(
+                detect_history_dbs(&browser)
+                    .into_iter()
+                    .filter(|db| match profile {
+                        Some(p) => db.profile.eq_ignore_ascii_case(p),
+                        None => true,
+                    }),
+            );
+        }
+        if dbs.is_empty() {
+            return Err(ConnectorError::Other(
+                "No browser history databases found. Use list_browsers to see detected browsers and profiles."
+                    .to_string(),
+            ));
+        }
+
+        let mut entries = Vec::new();
+        let mut skipped = Vec::new();
+        let mut last_error = None;
+        for db in &dbs {
+            match query_history_db(db, &query).await {
+                Ok(rows) => entries.extend(rows),
+                Err(e) => {
+                    skipped.push(json!({
+                        "browser": browser_label(&db.browser),
+                        "profile": db.profile,
+                        "error": e.to_string(),
+                    }));
+                    last_error = Some(e);
+                }
+            }
+        }
+        if skipped.len() == dbs.len() {
+            if let Some(e) = last_error {
+                return Err(e);
+            }
+        }
+
+        match query.order {
+            SortBy::Recent => entries.sort_by(|a, b| b.last_visited.cmp(&a.last_visited)),
+            SortBy::MostVisited => entries.sort_by(|a, b| {
+                b.visits
+                    .cmp(&a.visits)
+                    .then_with(|| b.last_visited.cmp(&a.last_visited))
+            }),
+        }
+        entries.truncate(query.limit);
+
+        let mut out = json!({
+            "since": format_timestamp(query.since),
+            "until": format_timestamp(query.until),
+            "count": entries.len(),
+            "results": entries,
+        });
+        if !skipped.is_empty() {
+            out["skipped"] = json!(skipped);
+        }
+        Ok(out)
+    }
+}
+
+fn filter_properties() -> Map<String, Value> {
+    json!({
+        "browser": {
+            "type": "string",
+            "enum": ["all", "chrome", "brave", "firefox", "safari"],
+            "description": "Browser to read. Default: all detected browsers."
+        },
+        "profile": {
+            "type": "string",
+            "description": "Profile name from list_browsers (e.g. Default, Profile 1, default-release)."
+        },
+        "since": {
+            "type": "string",
+            "description": "Only visits on/after this time (YYYY-MM-DD, YYYY-MM-DDTHH:MM or RFC 3339; local time)."
+        },
+        "until": {
+            "type": "string",
+            "description": "Only visits before this time; a bare date includes that whole day."
+        },
+        "date_preset": {
+            "type": "string",
+            "enum": ["last_24_hours", "last_7_days", "last_30_days", "this_month", "last_365_days"],
+            "description": "Shortcut for since/until."
+        },
+        "include_domains": {
+            "type": "array",
+            "items": {"type": "string"},
+            "description": "Only these domains (subdomains included)."
+        },
+        "exclude_domains": {
+            "type": "array",
+            "items": {"type": "string"},
+            "description": "Drop these domains (subdomains included)."
+        }
+    })
+    .as_object()
+    .cloned()
+    .unwrap_or_default()
+}
+
+#[async_trait]
+impl Connector for BrowserHistoryConnector {
+    fn name(&self) -> &'static str {
+        "browser-history"
+    }
+
+    fn description(&self) -> &'static str {
+        "Local browser history (Chrome, Brave, Firefox, Safari): keyword and date-range search over visited pages and most-visited sites. Reads snapshots of the profile databases; Safari requires Full Disk Access."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        Ok(AuthDetails::new())
+    }
+
+    async fn set_auth_details(&mut self, _details: AuthDetails) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        if all_browsers()
+            .iter()
+            .any(|b| !detect_history_dbs(b).is_empty())
+        {
+            Ok(())
+        } else {
+            Err(ConnectorError::Other(
+                "No Chrome, Brave, Firefox or Safari profiles found".to_string(),
+            ))
+        }
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema { fields: vec![] }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: Some("Browser History".to_string()),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Personal data: only use with the user's explicit permission. `search_history` finds \
+visited pages by keyword and date range, `top_sites` lists the most visited pages, and \
+`list_browsers` shows detected browsers and profiles. Results merge all browsers unless \
+`browser`/`profile` is given."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let mut search_props = filter_properties();
+        search_props.insert(
+            "query".to_string(),
+            json!({
+                "type": "string",
+                "description": "Keyword matched against page URL and title (case-insensitive). Omit to list recent history."
+            }),
+        );
+        search_props.insert(
+            "limit".to_string(),
+            json!({"type": "integer", "description": "Maximum pages (default 25, max 200)."}),
+        );
+        let mut top_props = filter_properties();
+        top_props.insert(
+            "limit".to_string(),
+            json!({"type": "integer", "description": "Maximum pages (default 20, max 200)."}),
+        );
+
+        Ok(ListToolsResult {
+            tools: vec![
+                tool(
+                    "list_browsers",
+                    "List supported browsers with detected profiles, history database paths and whether they are readable.",
+                    json!({"type": "object", "properties": {}}),
+                ),
+                tool(
+                    "search_history",
+                    "Search visited pages by keyword and/or date range, newest first. Each result has url, title, domain, visits in range, last_visited, browser and profile.",
+                    json!({"type": "object", "properties": search_props}),
+                ),
+                tool(
+                    "top_sites",
+                    "Most visited pages within a date range (default: last 30 days), ranked by visit count.",
+                    json!({"type": "object", "properties": top_props}),
+                ),
+            ],
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        let args = request.arguments.unwrap_or_default();
+        let data = match request.name.as_ref() {
+            "list_browsers" => self.list_browsers(),
+            "search_history" => {
+                let query = build_query(&args, SortBy::Recent, None, DEFAULT_SEARCH_LIMIT)?;
+                self.run(&args, query).await?
+            }
+            "top_sites" => {
+                let query = build_query(&args, SortBy::MostVisited, Some(30), DEFAULT_TOP_LIMIT)?;
+                self.run(&args, query).await?
+            }
+            _ => return Err(ConnectorError::ToolNotFound),
+        };
+        structured_result_with_text(&data, None)
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(domain_sql: Option<String>) -> HistoryQuery {
+        HistoryQuery {
+            pattern: like_contains("rust"),
+            since: 0,
+            until: 1,
+            domain_sql,
+            order: SortBy::MostVisited,
+            limit: 5,
+        }
+    }
+
+    #[test]
+    fn builds_per_browser_sql() {
+        let chrome = history_sql(&Browser::Chrome, &query(None));
+        assert!(chrome.contains("FROM visits v JOIN urls u"));
+        assert!(chrome.contains("GROUP BY u.id ORDER BY visits DESC, last_visit DESC LIMIT ?4"));
+        let safari = history_sql(&Browser::Safari, &query(None));
+        assert!(safari.contains("?2 - 978307200"));
+
+        let domains = domain_filter_sql(
+            &["github.com".to_string()],
+            &["gist.github.com".to_string()],
+        );
+        let firefox = history_sql(&Browser::Firefox, &query(domains));
+        assert!(firefox.contains("p.url LIKE '%://%.github.com/%' ESCAPE '\\'"));
+        assert!(firefox.contains("AND NOT (p.url LIKE '%://gist.github.com'"));
+        assert!(!firefox.contains("{url}"));
+    }
+
+    #[test]
+    fn escapes_like_patterns() {
+        assert_eq!(like_contains("50%_off"), "%50\\%\\_off%");
+        assert_eq!(like_contains(""), "%%");
+        assert!(domain_filter_sql(&[], &[" ".to_string()]).is_none());
+    }
+
+    #[test]
+    fn parses_time_bounds() {
+        let start = parse_time_bound("2025-03-09", false).unwrap();
+        let end = parse_time_bound("2025-03-09", true).unwrap();
+        assert!(end - start >= 23 * 3600 && end - start <= 25 * 3600);
+        assert_eq!(
+            parse_time_bound("2025-03-09T00:00:00Z", false).unwrap(),
+            1741478400
+        );
+        assert!(parse_time_bound("yesterday", false).is_err());
+    }
+
+    #[test]
+    fn names_firefox_profiles() {
+        assert_eq!(
+            firefox_profile_name("abcd1234.default-release"),
+            "default-release"
+        );
+        assert_eq!(firefox_profile_name("custom"), "custom");
+    }
+}
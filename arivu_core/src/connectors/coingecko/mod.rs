@@ -0,0 +1,581 @@
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+const PUBLIC_BASE: &str = "https://api.coingecko.com/api/v3";
+const PRO_BASE: &str = "https://pro-api.coingecko.com/api/v3";
+
+#[derive(Debug, Deserialize)]
+struct SearchArgs {
+    query: String,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PriceArgs {
+    ids: String,
+    #[serde(default)]
+    vs_currencies: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MarketsArgs {
+    #[serde(default)]
+    vs_currency: Option<String>,
+    #[serde(default)]
+    ids: Option<String>,
+    #[serde(default)]
+    category: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    page: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CoinArgs {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChartArgs {
+    id: String,
+    #[serde(default)]
+    vs_currency: Option<String>,
+    #[serde(default)]
+    days: Option<String>,
+    #[serde(default)]
+    start_date: Option<String>,
+    #[serde(default)]
+    end_date: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Plan {
+    /// Keyless or demo key: public host, `x-cg-demo-api-key` header.
+    Demo,
+    /// Paid key: pro host, `x-cg-pro-api-key` header.
+    Pro,
+}
+
+pub struct CoinGeckoConnector {
+    client: Client,
+    api_key: Option<String>,
+    plan: Plan,
+}
+
+impl CoinGeckoConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(|e| ConnectorError::Other(e.to_string()))?;
+        let api_key = auth
+            .get("api_key")
+            .or_else(|| auth.get("token"))
+            .cloned()
+            .or_else(|| std::env::var("COINGECKO_API_KEY").ok())
+            .filter(|k| !k.trim().is_empty());
+        let plan = match auth
+            .get("plan")
+            .cloned()
+            .or_else(|| std::env::var("COINGECKO_PLAN").ok())
+            .as_deref()
+        {
+            Some("pro") => Plan::Pro,
+            _ => Plan::Demo,
+        };
+
+        Ok(Self {
+            client,
+            api_key,
+            plan,
+        })
+    }
+
+    async fn get(&self, path: &str, params: &[(&str, String)]) -> Result<Value, ConnectorError> {
+        let (base, header) = match self.plan {
+            Plan::Pro if self.api_key.is_some() => (PRO_BASE, "x-cg-pro-api-key"),
+            _ => (PUBLIC_BASE, "x-cg-demo-api-key"),
+        };
+        let url = format!("{}/{}", base, path.trim_start_matches('/'));
+
+        let mut request = self.client.get(&url).query(params);
+        if let Some(key) = &self.api_key {
+            request = request.header(header, key);
+        }
+
+        let response = request.send().await.map_err(ConnectorError::HttpRequest)?;
+        match response.status() {
+            StatusCode::NOT_FOUND => return Err(ConnectorError::ResourceNotFound),
+            StatusCode::TOO_MANY_REQUESTS => {
+                return Err(ConnectorError::Other(
+                    "CoinGecko rate limit reached. Wait a minute or configure an API key: arivu config set coingecko --value <key>".to_string(),
+                ))
+            }
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                return Err(ConnectorError::Authentication(
+                    "CoinGecko rejected the API key (check the key and plan)".to_string(),
+                ))
+            }
+            s if !s.is_success() => {
+                return Err(ConnectorError::Other(format!(
+                    "CoinGecko API returned error status: {}",
+                    s
+                )))
+            }
+            _ => {}
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| ConnectorError::Other(format!("Failed to parse JSON: {}", e)))
+    }
+}
+
+fn date_to_unix(value: &str, field: &str) -> Result<i64, ConnectorError> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc().timestamp())
+        .ok_or_else(|| {
+            ConnectorError::InvalidParams(format!("{} must be YYYY-MM-DD, got '{}'", field, value))
+        })
+}
+
+/// Convert CoinGecko's `[[ms_timestamp, value], ...]` series into `{date, value}` points.
+fn series_points(series: &Value) -> Vec<Value> {
+    series
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|point| {
+                    let ts = point[0].as_f64()? as i64;
+                    let value = point[1].as_f64()?;
+                    let time = chrono::DateTime::from_timestamp_millis(ts)?.to_rfc3339();
+                    Some(json!({ "time": time, "value": value }))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn format_market(coin: &Value) -> Value {
+    json!({
+        "id": coin["id"],
+        "symbol": coin["symbol"],
+        "name": coin["name"],
+        "rank": coin["market_cap_rank"],
+        "price": coin["current_price"],
+        "market_cap": coin["market_cap"],
+        "volume_24h": coin["total_volume"],
+        "change_24h_percent": coin["price_change_percentage_24h"],
+        "high_24h": coin["high_24h"],
+        "low_24h": coin["low_24h"],
+        "circulating_supply": coin["circulating_supply"],
+        "max_supply": coin["max_supply"],
+        "ath": coin["ath"],
+        "ath_date": coin["ath_date"],
+        "last_updated": coin["last_updated"],
+    })
+}
+
+#[async_trait]
+impl Connector for CoinGeckoConnector {
+    fn name(&self) -> &'static str {
+        "coingecko"
+    }
+
+    fn description(&self) -> &'static str {
+        "CoinGecko crypto market data: coin search, prices, market stats and historical charts."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        let mut auth = AuthDetails::new();
+        if let Some(key) = &self.api_key {
+            auth.insert("api_key".to_string(), key.clone());
+        }
+        if self.plan == Plan::Pro {
+            auth.insert("plan".to_string(), "pro".to_string());
+        }
+        Ok(auth)
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        *self = Self::new(details).await?;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.get("ping", &[]).await.map(|_| ())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        let plan_options: Vec<String> = ["demo", "pro"].into_iter().map(String::from).collect();
+
+        ConnectorConfigSchema {
+            fields: vec![
+                Field {
+                    name: "api_key".to_string(),
+                    label: "API Key".to_string(),
+                    field_type: FieldType::Secret,
+                    required: false,
+                    description: Some(
+                        "Optional CoinGecko demo or pro key for higher rate limits (or set COINGECKO_API_KEY)."
+                            .to_string(),
+                    ),
+                    options: None,
+                },
+                Field {
+                    name: "plan".to_string(),
+                    label: "Plan".to_string(),
+                    field_type: FieldType::Select {
+                        options: plan_options.clone(),
+                    },
+                    required: false,
+                    description: Some(
+                        "Use 'pro' for paid keys (pro-api.coingecko.com). Defaults to 'demo'."
+                            .to_string(),
+                    ),
+                    options: Some(plan_options),
+                },
+            ],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: Some("https://www.coingecko.com/en/api".to_string()),
+            },
+            instructions: Some(
+                "Use `search_coins` to map names/tickers to CoinGecko ids (e.g. bitcoin), then \
+`get_price`, `get_markets`, `get_coin` or `get_market_chart`."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            tool(
+                "search_coins",
+                "Find CoinGecko coin ids by name or ticker. Example: query=\"solana\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Coin name or ticker" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "get_price",
+                "Current price, market cap, 24h volume and change for coin ids. \
+Example: ids=\"bitcoin,ethereum\" vs_currencies=\"usd,eur\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "ids": { "type": "string", "description": "Comma-separated CoinGecko ids" },
+                        "vs_currencies": { "type": "string", "description": "Comma-separated quote currencies (default: usd)" }
+                    },
+                    "required": ["ids"]
+                }),
+            ),
+            tool(
+                "get_markets",
+                "Market data ranked by market cap (top coins, specific ids, or a category). \
+Example: limit=20 or ids=\"bitcoin,solana\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "vs_currency": { "type": "string", "description": "Quote currency (default: usd)" },
+                        "ids": { "type": "string", "description": "Comma-separated CoinGecko ids" },
+                        "category": { "type": "string", "description": "Category id (e.g. layer-1, decentralized-finance-defi)" },
+                        "limit": { "type": "integer", "description": "Results per page (default: 20, max: 250)" },
+                        "page": { "type": "integer", "description": "Page number (default: 1)" }
+                    }
+                }),
+            ),
+            tool(
+                "get_coin",
+                "Coin profile: description, links, categories and current market data. Example: id=\"bitcoin\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "string", "description": "CoinGecko coin id" }
+                    },
+                    "required": ["id"]
+                }),
+            ),
+            tool(
+                "get_market_chart",
+                "Historical price, market cap and volume series. Use days (e.g. 30, max) or start_date/end_date. \
+Example: id=\"bitcoin\" days=\"90\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "string", "description": "CoinGecko coin id" },
+                        "vs_currency": { "type": "string", "description": "Quote currency (default: usd)" },
+                        "days": { "type": "string", "description": "Lookback in days or 'max' (default: 30)" },
+                        "start_date": { "type": "string", "description": "Start date (YYYY-MM-DD)" },
+                        "end_date": { "type": "string", "description": "End date (YYYY-MM-DD, default: today)" }
+                    },
+                    "required": ["id"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "search_coins" => {
+                let args: SearchArgs = parse_args(request.arguments)?;
+                let response = self.get("search", &[("query", args.query.clone())]).await?;
+                let coins: Vec<Value> = response["coins"]
+                    .as_array()
+                    .map(|arr| {
+                        arr.iter()
+                            .take(args.limit.unwrap_or(10))
+                            .map(|c| {
+                                json!({
+                                    "id": c["id"],
+                                    "name": c["name"],
+                                    "symbol": c["symbol"],
+                                    "rank": c["market_cap_rank"],
+                                })
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let data = json!({
+                    "query": args.query,
+                    "count": coins.len(),
+                    "results": coins,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_price" => {
+                let args: PriceArgs = parse_args(request.arguments)?;
+                let params = [
+                    ("ids", args.ids.replace(' ', "")),
+                    (
+                        "vs_currencies",
+                        args.vs_currencies.unwrap_or_else(|| "usd".to_string()),
+                    ),
+                    ("include_market_cap", "true".to_string()),
+                    ("include_24hr_vol", "true".to_string()),
+                    ("include_24hr_change", "true".to_string()),
+                    ("include_last_updated_at", "true".to_string()),
+                ];
+                let prices = self.get("simple/price", &params).await?;
+                if prices.as_object().map(|o| o.is_empty()).unwrap_or(true) {
+                    return Err(ConnectorError::ResourceNotFound);
+                }
+
+                let data = json!({ "prices": prices });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_markets" => {
+                let args: MarketsArgs = parse_args(request.arguments)?;
+                let mut params = vec![
+                    (
+                        "vs_currency",
+                        args.vs_currency.unwrap_or_else(|| "usd".to_string()),
+                    ),
+                    ("order", "market_cap_desc".to_string()),
+                    (
+                        "per_page",
+                        args.limit.unwrap_or(20).clamp(1, 250).to_string(),
+                    ),
+                    ("page", args.page.unwrap_or(1).max(1).to_string()),
+                ];
+                if let Some(ids) = args.ids {
+                    params.push(("ids", ids.replace(' ', "")));
+                }
+                if let Some(category) = args.category {
+                    params.push(("category", category));
+                }
+
+                let markets = self.get("coins/markets", &params).await?;
+                let results: Vec<Value> = markets
+                    .as_array()
+                    .map(|arr| arr.iter().map(format_market).collect())
+                    .unwrap_or_default();
+
+                let data = json!({
+                    "count": results.len(),
+                    "results": results,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_coin" => {
+                let args: CoinArgs = parse_args(request.arguments)?;
+                let params = [
+                    ("localization", "false".to_string()),
+                    ("tickers", "false".to_string()),
+                    ("community_data", "false".to_string()),
+                    ("developer_data", "false".to_string()),
+                ];
+                let coin = self
+                    .get(&format!("coins/{}", args.id.trim()), &params)
+                    .await?;
+                let market = &coin["market_data"];
+
+                let data = json!({
+                    "id": coin["id"],
+                    "symbol": coin["symbol"],
+                    "name": coin["name"],
+                    "categories": coin["categories"],
+                    "description": coin["description"]["en"],
+                    "homepage": coin["links"]["homepage"][0],
+                    "genesis_date": coin["genesis_date"],
+                    "rank": coin["market_cap_rank"],
+                    "price_usd": market["current_price"]["usd"],
+                    "market_cap_usd": market["market_cap"]["usd"],
+                    "volume_24h_usd": market["total_volume"]["usd"],
+                    "change_24h_percent": market["price_change_percentage_24h"],
+                    "change_7d_percent": market["price_change_percentage_7d"],
+                    "change_30d_percent": market["price_change_percentage_30d"],
+                    "circulating_supply": market["circulating_supply"],
+                    "total_supply": market["total_supply"],
+                    "max_supply": market["max_supply"],
+                    "ath_usd": market["ath"]["usd"],
+                    "last_updated": coin["last_updated"],
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_market_chart" => {
+                let args: ChartArgs = parse_args(request.arguments)?;
+                let vs_currency = args.vs_currency.unwrap_or_else(|| "usd".to_string());
+                let id = args.id.trim().to_string();
+
+                let chart = if args.start_date.is_some() || args.end_date.is_some() {
+                    let to = match &args.end_date {
+                        Some(end) => date_to_unix(end, "end_date")? + 86_400,
+                        None => chrono::Utc::now().timestamp(),
+                    };
+                    let from = match &args.start_date {
+                        Some(start) => date_to_unix(start, "start_date")?,
+                        None => to - 30 * 86_400,
+                    };
+                    let params = [
+                        ("vs_currency", vs_currency.clone()),
+                        ("from", from.to_string()),
+                        ("to", to.to_string()),
+                    ];
+                    self.get(&format!("coins/{}/market_chart/range", id), &params)
+                        .await?
+                } else {
+                    let params = [
+                        ("vs_currency", vs_currency.clone()),
+                        ("days", args.days.unwrap_or_else(|| "30".to_string())),
+                    ];
+                    self.get(&format!("coins/{}/market_chart", id), &params)
+                        .await?
+                };
+
+                let prices = series_points(&chart["prices"]);
+                let data = json!({
+                    "id": id,
+                    "vs_currency": vs_currency,
+                    "count": prices.len(),
+                    "prices": prices,
+                    "market_caps": series_points(&chart["market_caps"]),
+                    "total_volumes": series_points(&chart["total_volumes"]),
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_series_points() {
+        let series = json!([[1704067200000.0, 42000.5], [1704153600000.0, 43000.0]]);
+        let points = series_points(&series);
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0]["value"], 42000.5);
+        assert!(points[0]["time"]
+            .as_str()
+            .unwrap()
+            .starts_with("2024-01-01"));
+    }
+}
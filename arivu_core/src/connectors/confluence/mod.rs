@@ -0,0 +1,741 @@
+use crate::auth_store::{AuthStore, FileAuthStore};
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::utils::{
+    html_to_markdown_with, parse_args, structured_result_with_text, tool, MarkdownOptions,
+};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use base64::Engine as _;
+use reqwest::Client;
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+const DEFAULT_LIMIT: u32 = 25;
+const MAX_LIMIT: u32 = 100;
+
+#[derive(Debug, Deserialize)]
+struct SearchArgs {
+    #[serde(default)]
+    cql: Option<String>,
+    #[serde(default)]
+    query: Option<String>,
+    #[serde(default)]
+    space: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    start: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PageArgs {
+    page: String,
+    #[serde(default)]
+    format: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListSpacesArgs {
+    #[serde(default)]
+    space_type: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    start: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChildrenArgs {
+    page: String,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    start: Option<u32>,
+}
+
+/// Accept a bare page id or any Confluence page URL
+/// (`/spaces/KEY/pages/123/Title`, `/pages/viewpage.action?pageId=123`).
+fn parse_page_id(input: &str) -> Option<String> {
+    let input = input.trim();
+    if !input.is_empty() && input.chars().all(|c| c.is_ascii_digit()) {
+        return Some(input.to_string());
+    }
+    let url = url::Url::parse(input).ok()?;
+    if let Some((_, id)) = url.query_pairs().find(|(k, _)| k == "pageId") {
+        return Some(id.into_owned());
+    }
+    let segments: Vec<&str> = url.path_segments()?.collect();
+    segments
+        .windows(2)
+        .find(|w| w[0] == "pages" && w[1].chars().all(|c| c.is_ascii_digit()))
+        .map(|w| w[1].to_string())
+}
+
+/// Cloud sites serve the REST API under `/wiki`; Server/Data Center sites are
+/// used as configured.
+fn api_root(base: &str) -> String {
+    let base = base.trim().trim_end_matches('/');
+    let is_cloud = url::Url::parse(base)
+        .ok()
+        .and_then(|u| u.host_str().map(|h| h.ends_with(".atlassian.net")))
+        .unwrap_or(false);
+    if is_cloud && !base.ends_with("/wiki") {
+        format!("{}/wiki", base)
+    } else {
+        base.to_string()
+    }
+}
+
+/// Build CQL from free text when the caller did not write CQL themselves.
+fn text_cql(query: &str, space: Option<&str>) -> String {
+    let escaped = query.replace('\\', "\\\\").replace('"', "\\\"");
+    let mut cql = format!("type = page AND text ~ \"{}\"", escaped);
+    if let Some(space) = space.filter(|s| !s.trim().is_empty()) {
+        cql.push_str(&format!(" AND space = \"{}\"", space.trim()));
+    }
+    cql.push_str(" ORDER BY lastmodified DESC");
+    cql
+}
+
+fn html_to_markdown(html: &str) -> String {
+    let options = MarkdownOptions {
+        images: false,
+        ..MarkdownOptions::default()
+    };
+    html_to_markdown_with(html, &options)
+}
+
+/// Strip the `@@@hl@@@` highlight markers Confluence puts in excerpts.
+fn clean_excerpt(excerpt: &str) -> String {
+    excerpt
+        .replace("@@@hl@@@", "")
+        .replace("@@@endhl@@@", "")
+        .trim()
+        .to_string()
+}
+
+fn page_summary(page: &Value, site: &str) -> Value {
+    let webui = page["_links"]["webui"].as_str().unwrap_or_default();
+    json!({
+        "id": page["id"],
+        "type": page["type"],
+        "title": page["title"],
+        "space": page["space"]["key"],
+        "version": page["version"]["number"],
+        "last_modified": page["version"]["when"],
+        "url": if webui.is_empty() { Value::Null } else { json!(format!("{}{}", site, webui)) },
+    })
+}
+
+pub struct ConfluenceConnector {
+    client: Client,
+    base_url: Option<String>,
+    user: Option<String>,
+    token: Option<String>,
+}
+
+impl ConfluenceConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        // Reuse credentials saved for the Atlassian connector when none are set here.
+        let atlassian = FileAuthStore::new_default()
+            .load("atlassian")
+            .unwrap_or_default();
+        let pick = |keys: &[&str], atlassian_key: &str, env: &[&str]| {
+            keys.iter()
+                .find_map(|k| auth.get(*k).cloned())
+                .or_else(|| env.iter().find_map(|e| std::env::var(e).ok()))
+                .or_else(|| atlassian.get(atlassian_key).cloned())
+                .filter(|v| !v.trim().is_empty())
+        };
+
+        let base_url = pick(
+            &["base_url", "confluence_base"],
+            "confluence_base",
+            &["CONFLUENCE_BASE_URL", "CONFLUENCE_URL"],
+        );
+        let user = pick(
+            &["user", "email"],
+            "user",
+            &["CONFLUENCE_EMAIL", "ATLASSIAN_EMAIL"],
+        );
+        let token = pick(
+            &["token", "api_key"],
+            "token",
+            &["CONFLUENCE_API_TOKEN", "ATLASSIAN_API_TOKEN"],
+        );
+
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+
+        Ok(Self {
+            client,
+            base_url,
+            user,
+            token,
+        })
+    }
+
+    fn site(&self) -> Result<String, ConnectorError> {
+        self.base_url.as_deref().map(api_root).ok_or_else(|| {
+            ConnectorError::Authentication(
+                "Confluence base_url not configured (e.g. https://your-domain.atlassian.net)"
+                    .to_string(),
+            )
+        })
+    }
+
+    /// Basic auth (email + API token) for Cloud; a token without a user is
+    /// sent as a Bearer personal access token for Server/Data Center.
+    fn auth_header(&self) -> Result<String, ConnectorError> {
+        let token = self.token.as_deref().ok_or_else(|| {
+            ConnectorError::Authentication("Confluence API token not configured".to_string())
+        })?;
+        Ok(match self.user.as_deref() {
+            Some(user) => format!(
+                "Basic {}",
+                base64::engine::general_purpose::STANDARD.encode(format!("{}:{}", user, token))
+            ),
+            None => format!("Bearer {}", token),
+        })
+    }
+
+    async fn get(&self, path: &str, params: &[(&str, String)]) -> Result<Value, ConnectorError> {
+        let url = format!("{}/rest/api/{}", self.site()?, path);
+        let response = self
+            .client
+            .get(&url)
+            .header(reqwest::header::AUTHORIZATION, self.auth_header()?)
+            .header(reqwest::header::ACCEPT, "application/json")
+            .query(params)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response.json::<Value>().await?);
+        }
+        let body = response.text().await.unwrap_or_default();
+        let message = serde_json::from_str::<Value>(&body)
+            .ok()
+            .and_then(|v| v["message"].as_str().map(str::to_string))
+            .unwrap_or(body);
+        match status.as_u16() {
+            401 | 403 => Err(ConnectorError::Authentication(format!(
+                "Confluence rejected the credentials ({}): {}",
+                status, message
+            ))),
+            404 => Err(ConnectorError::ResourceNotFound),
+            400 => Err(ConnectorError::InvalidParams(message)),
+            _ => Err(ConnectorError::Other(format!(
+                "Confluence API error {}: {}",
+                status, message
+            ))),
+        }
+    }
+
+    fn page_id(input: &str) -> Result<String, ConnectorError> {
+        parse_page_id(input).ok_or_else(|| {
+            ConnectorError::InvalidParams(format!(
+                "Expected a page id or Confluence page URL, got '{}'",
+                input
+            ))
+        })
+    }
+
+    async fn search(&self, args: SearchArgs) -> Result<Value, ConnectorError> {
+        let cql = match (args.cql, args.query) {
+            (Some(cql), _) if !cql.trim().is_empty() => cql,
+            (_, Some(query)) if !query.trim().is_empty() => text_cql(&query, args.space.as_deref()),
+            _ => {
+                return Err(ConnectorError::InvalidParams(
+                    "Provide either 'cql' or 'query'".to_string(),
+                ))
+            }
+        };
+        let limit = args.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        let start = args.start.unwrap_or(0);
+        let data = self
+            .get(
+                "search",
+                &[
+                    ("cql", cql.clone()),
+                    ("limit", limit.to_string()),
+                    ("start", start.to_string()),
+                    ("expand", "content.space,content.version".to_string()),
+                ],
+            )
+            .await?;
+
+        let site = self.site()?;
+        let results: Vec<Value> = data["results"]
+            .as_array()
+            .map(|items| {
+                items
+                    .iter()
+                    .map(|item| {
+                        let mut summary = if item["content"].is_object() {
+                            page_summary(&item["content"], &site)
+                        } else {
+                            json!({ "title": item["title"], "type": item["entityType"] })
+                        };
+                        summary["excerpt"] =
+                            json!(clean_excerpt(item["excerpt"].as_str().unwrap_or_default()));
+                        summary
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let total = data["totalSize"].as_u64();
+        let next_start = total
+            .filter(|t| u64::from(start) + (results.len() as u64) < *t)
+            .map(|_| start + results.len() as u32);
+
+        Ok(json!({
+            "cql": cql,
+            "total": total,
+            "count": results.len(),
+            "next_start": next_start,
+            "results": results,
+        }))
+    }
+
+    async fn get_page(&self, args: PageArgs) -> Result<Value, ConnectorError> {
+        let id = Self::page_id(&args.page)?;
+        let format = args.format.as_deref().unwrap_or("markdown");
+        let body_expand = match format {
+            "markdown" | "html" => "body.view",
+            "storage" => "body.storage",
+            other => {
+                return Err(ConnectorError::InvalidParams(format!(
+                    "Unknown format '{}'; use markdown, html or storage",
+                    other
+                )))
+            }
+        };
+        let page = self
+            .get(
+                &format!("content/{}", id),
+                &[("expand", format!("{},version,space,ancestors", body_expand))],
+            )
+            .await?;
+
+        let site = self.site()?;
+        let mut data = page_summary(&page, &site);
+        data["ancestors"] = json!(page["ancestors"]
+            .as_array()
+            .map(|a| a
+                .iter()
+                .map(|p| json!({ "id": p["id"], "title": p["title"] }))
+                .collect::<Vec<_>>())
+            .unwrap_or_default());
+        let content = match format {
+            "markdown" => {
+                html_to_markdown(page["body"]["view"]["value"].as_str().unwrap_or_default())
+            }
+            "html" => page["body"]["view"]["value"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            _ => page["body"]["storage"]["value"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        };
+        data["format"] = json!(format);
+        data["content"] = json!(content);
+        Ok(data)
+    }
+
+    async fn list_spaces(&self, args: ListSpacesArgs) -> Result<Value, ConnectorError> {
+        let limit = args.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        let start = args.start.unwrap_or(0);
+        let mut params = vec![
+            ("limit", limit.to_string()),
+            ("start", start.to_string()),
+            ("expand", "description.plain".to_string()),
+        ];
+        if let Some(space_type) = args.space_type {
+            params.push(("type", space_type));
+        }
+        let data = self.get("space", &params).await?;
+        let site = self.site()?;
+        let spaces: Vec<Value> = data["results"]
+            .as_array()
+            .map(|items| {
+                items
+                    .iter()
+                    .map(|s| {
+                        json!({
+                            "key": s["key"],
+                            "name": s["name"],
+                            "type": s["type"],
+                            "description": s["description"]["plain"]["value"],
+                            "url": s["_links"]["webui"].as_str().map(|w| format!("{}{}", site, w)),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let next_start = if data["_links"]["next"].is_string() {
+            Some(start + spaces.len() as u32)
+        } else {
+            None
+        };
+        Ok(json!({ "count": spaces.len(), "next_start": next_start, "spaces": spaces }))
+    }
+
+    async fn get_children(&self, args: ChildrenArgs) -> Result<Value, ConnectorError> {
+        let id = Self::page_id(&args.page)?;
+        let limit = args.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        let start = args.start.unwrap_or(0);
+        let data = self
+            .get(
+                &format!("content/{}/child/page", id),
+                &[
+                    ("limit", limit.to_string()),
+                    ("start", start.to_string()),
+                    ("expand", "version,space".to_string()),
+                ],
+            )
+            .await?;
+        let site = self.site()?;
+        let children: Vec<Value> = data["results"]
+            .as_array()
+            .map(|items| items.iter().map(|p| page_summary(p, &site)).collect())
+            .unwrap_or_default();
+        let next_start = if data["_links"]["next"].is_string() {
+            Some(start + children.len() as u32)
+        } else {
+            None
+        };
+        Ok(json!({
+            "parent_id": id,
+            "count": children.len(),
+            "next_start": next_start,
+            "children": children,
+        }))
+    }
+
+    async fn get_ancestors(&self, page: &str) -> Result<Value, ConnectorError> {
+        let id = Self::page_id(page)?;
+        let data = self
+            .get(
+                &format!("content/{}", id),
+                &[("expand", "ancestors,ancestors.space,space".to_string())],
+            )
+            .await?;
+        let site = self.site()?;
+        // The API lists ancestors root-first; the last entry is the direct parent.
+        let ancestors: Vec<Value> = data["ancestors"]
+            .as_array()
+            .map(|items| items.iter().map(|p| page_summary(p, &site)).collect())
+            .unwrap_or_default();
+        Ok(json!({
+            "id": id,
+            "title": data["title"],
+            "space": data["space"]["key"],
+            "depth": ancestors.len(),
+            "ancestors": ancestors,
+        }))
+    }
+}
+
+#[async_trait]
+impl Connector for ConfluenceConnector {
+    fn name(&self) -> &'static str {
+        "confluence"
+    }
+
+    fn description(&self) -> &'static str {
+        "Confluence pages and spaces: CQL search, page content as markdown, and page tree traversal."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        let mut details = AuthDetails::new();
+        if let Some(base_url) = &self.base_url {
+            details.insert("base_url".to_string(), base_url.clone());
+        }
+        if let Some(user) = &self.user {
+            details.insert("user".to_string(), user.clone());
+        }
+        if let Some(token) = &self.token {
+            details.insert("token".to_string(), token.clone());
+        }
+        Ok(details)
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        *self = Self::new(details).await?;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.get("space", &[("limit", "1".to_string())]).await?;
+        Ok(())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema {
+            fields: vec![
+                Field {
+                    name: "base_url".to_string(),
+                    label: "Site URL".to_string(),
+                    field_type: FieldType::Text,
+                    required: true,
+                    description: Some(
+                        "e.g. https://your-domain.atlassian.net (Cloud) or your Server/Data Center URL"
+                            .to_string(),
+                    ),
+                    options: None,
+                },
+                Field {
+                    name: "user".to_string(),
+                    label: "Email".to_string(),
+                    field_type: FieldType::Text,
+                    required: false,
+                    description: Some(
+                        "Atlassian account email (Cloud). Leave empty to use a Data Center personal access token."
+                            .to_string(),
+                    ),
+                    options: None,
+                },
+                Field {
+                    name: "token".to_string(),
+                    label: "API Token".to_string(),
+                    field_type: FieldType::Secret,
+                    required: true,
+                    description: Some(
+                        "Create at id.atlassian.com/manage-profile/security/api-tokens".to_string(),
+                    ),
+                    options: None,
+                },
+            ],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Use `search` with free text (`query`) or CQL, then `get_page` for markdown \
+content. `get_children` and `get_ancestors` walk the page tree; page arguments accept ids or URLs."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let page_schema = json!({
+            "type": "string",
+            "description": "Page id or Confluence page URL"
+        });
+
+        let tools = vec![
+            tool(
+                "search",
+                "Search pages with CQL or free text. Example: query=\"onboarding checklist\" space=\"ENG\", or cql=\"type = page AND label = runbook\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "cql": { "type": "string", "description": "CQL query (takes precedence over query)" },
+                        "query": { "type": "string", "description": "Free-text search across page content" },
+                        "space": { "type": "string", "description": "Space key to restrict a free-text search" },
+                        "limit": { "type": "integer", "description": "Max results (default: 25, max: 100)" },
+                        "start": { "type": "integer", "description": "Offset from a previous next_start" }
+                    }
+                }),
+            ),
+            tool(
+                "get_page",
+                "Get a page's content rendered to markdown, with version, space and ancestors.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "page": page_schema.clone(),
+                        "format": {
+                            "type": "string",
+                            "enum": ["markdown", "html", "storage"],
+                            "description": "Content format (default: markdown)"
+                        }
+                    },
+                    "required": ["page"]
+                }),
+            ),
+            tool(
+                "list_spaces",
+                "List spaces visible to the account.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "space_type": { "type": "string", "enum": ["global", "personal"], "description": "Filter by space type" },
+                        "limit": { "type": "integer", "description": "Max spaces (default: 25, max: 100)" },
+                        "start": { "type": "integer", "description": "Offset from a previous next_start" }
+                    }
+                }),
+            ),
+            tool(
+                "get_children",
+                "List the direct child pages of a page.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "page": page_schema.clone(),
+                        "limit": { "type": "integer", "description": "Max children (default: 25, max: 100)" },
+                        "start": { "type": "integer", "description": "Offset from a previous next_start" }
+                    },
+                    "required": ["page"]
+                }),
+            ),
+            tool(
+                "get_ancestors",
+                "List a page's ancestors from the space root down to its parent.",
+                json!({
+                    "type": "object",
+                    "properties": { "page": page_schema },
+                    "required": ["page"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        let data = match request.name.as_ref() {
+            "search" => self.search(parse_args(request.arguments)?).await?,
+            "get_page" => self.get_page(parse_args(request.arguments)?).await?,
+            "list_spaces" => self.list_spaces(parse_args(request.arguments)?).await?,
+            "get_children" => self.get_children(parse_args(request.arguments)?).await?,
+            "get_ancestors" => {
+                let args: PageArgs = parse_args(request.arguments)?;
+                self.get_ancestors(&args.page).await?
+            }
+            _ => return Err(ConnectorError::ToolNotFound),
+        };
+        Ok(structured_result_with_text(&data, None)?)
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_page_ids_from_urls() {
+        assert_eq!(parse_page_id("123456"), Some("123456".to_string()));
+        assert_eq!(
+            parse_page_id("https://acme.atlassian.net/wiki/spaces/ENG/pages/98765/Runbook"),
+            Some("98765".to_string())
+        );
+        assert_eq!(
+            parse_page_id("https://wiki.acme.com/pages/viewpage.action?pageId=4242"),
+            Some("4242".to_string())
+        );
+        assert_eq!(parse_page_id("Runbook"), None);
+    }
+
+    #[test]
+    fn renders_page_bodies_without_images() {
+        let html = r#"<h2>Runbook</h2>
+<p>Restart <code>api</code> via <a href="https://ci.acme.com">CI</a>.<img src="/download/a.png"></p>
+<table><tbody><tr><th>Env</th><th>Host</th></tr><tr><td>prod</td><td>api-1</td></tr></tbody></table>"#;
+        assert_eq!(
+            html_to_markdown(html),
+            "## Runbook\n\nRestart `api` via [CI](https://ci.acme.com).\n\n\
+| Env | Host |\n| --- | --- |\n| prod | api-1 |"
+        );
+    }
+
+    #[test]
+    fn resolves_api_root() {
+        assert_eq!(
+            api_root("https://acme.atlassian.net/"),
+            "https://acme.atlassian.net/wiki"
+        );
+        assert_eq!(
+            api_root("https://acme.atlassian.net/wiki"),
+            "https://acme.atlassian.net/wiki"
+        );
+        assert_eq!(
+            api_root("https://wiki.acme.com/confluence"),
+            "https://wiki.acme.com/confluence"
+        );
+    }
+
+    #[test]
+    fn builds_text_cql() {
+        assert_eq!(
+            text_cql("say \"hi\"", Some("ENG")),
+            "type = page AND text ~ \"say \\\"hi\\\"\" AND space = \"ENG\" ORDER BY lastmodified DESC"
+        );
+        assert_eq!(
+            clean_excerpt(" @@@hl@@@deploy@@@endhl@@@ steps "),
+            "deploy steps"
+        );
+    }
+}
@@ -0,0 +1,569 @@
+use crate::capabilities::ConnectorConfigSchema;
+use crate::error::ConnectorError;
+use crate::utils::{
+    html_to_markdown_with, parse_args, structured_result_with_text, tool, MarkdownOptions,
+};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use scraper::{Html, Selector};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+const API_BASE: &str = "https://crates.io/api/v1";
+const DOCS_RS: &str = "https://docs.rs";
+/// crates.io asks API clients to identify themselves with a contact URL.
+const USER_AGENT: &str = "rzn_datasourcer/0.1.0 (https://github.com/srv1n/arivu)";
+const MAX_PER_PAGE: u32 = 100;
+
+#[derive(Debug, Deserialize)]
+struct SearchArgs {
+    query: String,
+    #[serde(default)]
+    sort: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    page: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrateArgs {
+    name: String,
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(default)]
+    max_versions: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReverseDepsArgs {
+    name: String,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    page: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReadmeArgs {
+    name: String,
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(default)]
+    source: Option<String>,
+}
+
+fn html_to_markdown(html: &str) -> String {
+    let options = MarkdownOptions {
+        images: false,
+        ..MarkdownOptions::default()
+    };
+    html_to_markdown_with(html, &options)
+}
+
+/// Crate names are case-insensitive and treat `-` and `_` alike; rustdoc
+/// paths always use the underscore form.
+fn rustdoc_name(name: &str) -> String {
+    name.trim().replace('-', "_")
+}
+
+/// Extract the crate-level documentation block from a docs.rs rustdoc page.
+fn rustdoc_top_doc(html: &str) -> Option<String> {
+    let doc = Html::parse_document(html);
+    ["details.top-doc .docblock", "#main-content .docblock"]
+        .iter()
+        .filter_map(|s| Selector::parse(s).ok())
+        .find_map(|sel| doc.select(&sel).next().map(|el| el.html()))
+}
+
+fn summarize_crate(c: &Value) -> Value {
+    json!({
+        "name": c["name"],
+        "description": c["description"],
+        "max_version": c["max_stable_version"].as_str().or(c["max_version"].as_str()),
+        "downloads": c["downloads"],
+        "recent_downloads": c["recent_downloads"],
+        "updated_at": c["updated_at"],
+        "repository": c["repository"],
+        "documentation": c["documentation"],
+        "homepage": c["homepage"],
+        "url": format!("https://crates.io/crates/{}", c["name"].as_str().unwrap_or_default()),
+    })
+}
+
+pub struct CratesIoConnector {
+    client: Client,
+}
+
+impl CratesIoConnector {
+    pub async fn new(_auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+        Ok(Self { client })
+    }
+
+    async fn fetch(&self, url: &str, params: &[(&str, String)]) -> Result<String, ConnectorError> {
+        let response = self
+            .client
+            .get(url)
+            .query(params)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+        match response.status() {
+            s if s.is_success() => response.text().await.map_err(ConnectorError::HttpRequest),
+            StatusCode::NOT_FOUND => Err(ConnectorError::ResourceNotFound),
+            StatusCode::TOO_MANY_REQUESTS => Err(ConnectorError::Other(
+                "crates.io rate limit reached; retry in a minute".to_string(),
+            )),
+            s => {
+                let body = response.text().await.unwrap_or_default();
+                let detail = serde_json::from_str::<Value>(&body)
+                    .ok()
+                    .and_then(|v| v["errors"][0]["detail"].as_str().map(str::to_string))
+                    .unwrap_or(body);
+                Err(ConnectorError::Other(format!(
+                    "crates.io returned {}: {}",
+                    s, detail
+                )))
+            }
+        }
+    }
+
+    async fn get_json(
+        &self,
+        path: &str,
+        params: &[(&str, String)],
+    ) -> Result<Value, ConnectorError> {
+        let body = self.fetch(&format!("{}{}", API_BASE, path), params).await?;
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    async fn search(&self, args: SearchArgs) -> Result<Value, ConnectorError> {
+        let sort = args.sort.unwrap_or_else(|| "relevance".to_string());
+        if !matches!(
+            sort.as_str(),
+            "relevance" | "downloads" | "recent-downloads" | "recent-updates" | "new"
+        ) {
+            return Err(ConnectorError::InvalidParams(format!(
+                "Unknown sort '{}'; use relevance, downloads, recent-downloads, recent-updates or new",
+                sort
+            )));
+        }
+        let limit = args.limit.unwrap_or(10).clamp(1, MAX_PER_PAGE);
+        let page = args.page.unwrap_or(1).max(1);
+        let data = self
+            .get_json(
+                "/crates",
+                &[
+                    ("q", args.query.clone()),
+                    ("sort", sort),
+                    ("per_page", limit.to_string()),
+                    ("page", page.to_string()),
+                ],
+            )
+            .await?;
+        let crates: Vec<Value> = data["crates"]
+            .as_array()
+            .map(|c| c.iter().map(summarize_crate).collect())
+            .unwrap_or_default();
+        Ok(json!({
+            "query": args.query,
+            "total": data["meta"]["total"],
+            "page": page,
+            "count": crates.len(),
+            "crates": crates,
+        }))
+    }
+
+    async fn get_crate(&self, args: CrateArgs) -> Result<Value, ConnectorError> {
+        let name = args.name.trim();
+        let data = self.get_json(&format!("/crates/{}", name), &[]).await?;
+        let versions = data["versions"].as_array().cloned().unwrap_or_default();
+        let krate = &data["crate"];
+
+        // Newest first as returned; pick the requested version or the latest stable.
+        let selected = match args.version.as_deref() {
+            Some(v) => versions.iter().find(|ver| ver["num"] == v).ok_or_else(|| {
+                ConnectorError::InvalidParams(format!("{} has no version {}", name, v))
+            })?,
+            None => {
+                let target = krate["max_stable_version"]
+                    .as_str()
+                    .or(krate["max_version"].as_str())
+                    .unwrap_or_default();
+                versions
+                    .iter()
+                    .find(|ver| ver["num"] == target)
+                    .or_else(|| versions.first())
+                    .ok_or(ConnectorError::ResourceNotFound)?
+            }
+        };
+        let version = selected["num"].as_str().unwrap_or_default().to_string();
+
+        let deps = self
+            .get_json(&format!("/crates/{}/{}/dependencies", name, version), &[])
+            .await?;
+        let dependencies: Vec<Value> = deps["dependencies"]
+            .as_array()
+            .map(|d| {
+                d.iter()
+                    .map(|dep| {
+                        json!({
+                            "name": dep["crate_id"],
+                            "req": dep["req"],
+                            "kind": dep["kind"],
+                            "optional": dep["optional"],
+                            "default_features": dep["default_features"],
+                            "features": dep["features"],
+                            "target": dep["target"],
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let max_versions = args.max_versions.unwrap_or(20);
+        let history: Vec<Value> = versions
+            .iter()
+            .take(max_versions)
+            .map(|v| {
+                json!({
+                    "num": v["num"],
+                    "created_at": v["created_at"],
+                    "downloads": v["downloads"],
+                    "yanked": v["yanked"],
+                    "rust_version": v["rust_version"],
+                    "license": v["license"],
+                })
+            })
+            .collect();
+
+        let mut out = summarize_crate(krate);
+        out["categories"] = krate["categories"].clone();
+        out["keywords"] = krate["keywords"].clone();
+        out["created_at"] = krate["created_at"].clone();
+        out["version"] = json!({
+            "num": version,
+            "created_at": selected["created_at"],
+            "license": selected["license"],
+            "rust_version": selected["rust_version"],
+            "edition": selected["edition"],
+            "crate_size": selected["crate_size"],
+            "downloads": selected["downloads"],
+            "yanked": selected["yanked"],
+            "features": selected["features"],
+            "docs_rs": format!("{}/{}/{}", DOCS_RS, name, version),
+        });
+        out["dependencies"] = json!(dependencies);
+        out["version_count"] = json!(versions.len());
+        out["versions"] = json!(history);
+        Ok(out)
+    }
+
+    async fn reverse_dependencies(&self, args: ReverseDepsArgs) -> Result<Value, ConnectorError> {
+        let limit = args.limit.unwrap_or(20).clamp(1, MAX_PER_PAGE);
+        let page = args.page.unwrap_or(1).max(1);
+        let data = self
+            .get_json(
+                &format!("/crates/{}/reverse_dependencies", args.name.trim()),
+                &[("per_page", limit.to_string()), ("page", page.to_string())],
+            )
+            .await?;
+        // Each dependency row points at the dependent crate's version by id.
+        let versions: HashMap<u64, &Value> = data["versions"]
+            .as_array()
+            .map(|v| {
+                v.iter()
+                    .filter_map(|ver| ver["id"].as_u64().map(|id| (id, ver)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let dependents: Vec<Value> = data["dependencies"]
+            .as_array()
+            .map(|d| {
+                d.iter()
+                    .map(|dep| {
+                        let ver = dep["version_id"]
+                            .as_u64()
+                            .and_then(|id| versions.get(&id).copied());
+                        json!({
+                            "name": ver.map(|v| v["crate"].clone()),
+                            "version": ver.map(|v| v["num"].clone()),
+                            "downloads": dep["downloads"],
+                            "req": dep["req"],
+                            "kind": dep["kind"],
+                            "optional": dep["optional"],
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(json!({
+            "name": args.name,
+            "total": data["meta"]["total"],
+            "page": page,
+            "count": dependents.len(),
+            "dependents": dependents,
+        }))
+    }
+
+    async fn readme(&self, args: ReadmeArgs) -> Result<Value, ConnectorError> {
+        let name = args.name.trim();
+        let version = match args.version {
+            Some(v) => v,
+            None => {
+                let data = self.get_json(&format!("/crates/{}", name), &[]).await?;
+                data["crate"]["max_stable_version"]
+                    .as_str()
+                    .or(data["crate"]["max_version"].as_str())
+                    .unwrap_or("latest")
+                    .to_string()
+            }
+        };
+        let source = args.source.as_deref().unwrap_or("crates-io");
+        let (html, url) = match source {
+            "crates-io" => {
+                let url = format!("{}/crates/{}/{}/readme", API_BASE, name, version);
+                (self.fetch(&url, &[]).await?, url)
+            }
+            "docs-rs" => {
+                let url = format!("{}/{}/{}/{}/", DOCS_RS, name, version, rustdoc_name(name));
+                let page = self.fetch(&url, &[]).await?;
+                let html = rustdoc_top_doc(&page).ok_or_else(|| {
+                    ConnectorError::Other(format!(
+                        "No crate-level docs found on docs.rs for {} {} (the build may have failed)",
+                        name, version
+                    ))
+                })?;
+                (html, url)
+            }
+            other => {
+                return Err(ConnectorError::InvalidParams(format!(
+                    "Unknown source '{}'; use crates-io or docs-rs",
+                    other
+                )))
+            }
+        };
+        Ok(json!({
+            "name": name,
+            "version": version,
+            "source": source,
+            "url": url,
+            "content": html_to_markdown(&html),
+        }))
+    }
+}
+
+#[async_trait]
+impl Connector for CratesIoConnector {
+    fn name(&self) -> &'static str {
+        "crates-io"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rust crates from crates.io: search, versions, features, dependencies, reverse dependencies and READMEs (with docs.rs crate docs)."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        Ok(AuthDetails::new())
+    }
+
+    async fn set_auth_details(&mut self, _details: AuthDetails) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema { fields: vec![] }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Use `search_crates` to find crates, `get_crate` for versions/features/dependencies, \
+`get_reverse_dependencies` for adoption, and `get_readme` (source crates-io or docs-rs) for usage docs."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            tool(
+                "search_crates",
+                "Search crates.io. Example: query=\"async http client\" sort=\"downloads\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Search terms" },
+                        "sort": {
+                            "type": "string",
+                            "enum": ["relevance", "downloads", "recent-downloads", "recent-updates", "new"],
+                            "description": "Sort order (default: relevance)"
+                        },
+                        "limit": { "type": "integer", "description": "Results per page (default: 10, max: 100)" },
+                        "page": { "type": "integer", "description": "Page number (default: 1)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "get_crate",
+                "Crate metadata with downloads, version history, and the features and dependencies of one version (default: latest stable).",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string", "description": "Crate name, e.g. serde" },
+                        "version": { "type": "string", "description": "Version for features/dependencies (default: latest stable)" },
+                        "max_versions": { "type": "integer", "description": "Versions to include in history (default: 20)" }
+                    },
+                    "required": ["name"]
+                }),
+            ),
+            tool(
+                "get_reverse_dependencies",
+                "Crates that depend on this crate, most downloaded first.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string", "description": "Crate name" },
+                        "limit": { "type": "integer", "description": "Results per page (default: 20, max: 100)" },
+                        "page": { "type": "integer", "description": "Page number (default: 1)" }
+                    },
+                    "required": ["name"]
+                }),
+            ),
+            tool(
+                "get_readme",
+                "README as markdown from crates.io, or the crate-level docs from docs.rs.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string", "description": "Crate name" },
+                        "version": { "type": "string", "description": "Version (default: latest stable)" },
+                        "source": {
+                            "type": "string",
+                            "enum": ["crates-io", "docs-rs"],
+                            "description": "crates-io (published README) or docs-rs (crate-level rustdoc) (default: crates-io)"
+                        }
+                    },
+                    "required": ["name"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        let data = match request.name.as_ref() {
+            "search_crates" => self.search(parse_args(request.arguments)?).await?,
+            "get_crate" => self.get_crate(parse_args(request.arguments)?).await?,
+            "get_reverse_dependencies" => {
+                self.reverse_dependencies(parse_args(request.arguments)?)
+                    .await?
+            }
+            "get_readme" => self.readme(parse_args(request.arguments)?).await?,
+            _ => return Err(ConnectorError::ToolNotFound),
+        };
+        Ok(structured_result_with_text(&data, None)?)
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_rustdoc_top_doc() {
+        let html = r#"<html><body><section id="main-content"><details class="toggle top-doc" open><summary>Expand</summary><div class="docblock"><h1>Serde</h1><p>A framework.</p></div></details><div class="docblock">item</div></section></body></html>"#;
+        let doc = rustdoc_top_doc(html).unwrap();
+        assert!(doc.contains("A framework."));
+        assert!(!doc.contains("item"));
+        assert_eq!(rustdoc_name("tokio-util"), "tokio_util");
+    }
+
+    #[test]
+    fn summarizes_crate_with_stable_version() {
+        let c = json!({
+            "name": "serde",
+            "max_version": "2.0.0-alpha.1",
+            "max_stable_version": "1.0.210",
+            "downloads": 10
+        });
+        let s = summarize_crate(&c);
+        assert_eq!(s["max_version"], "1.0.210");
+        assert_eq!(s["url"], "https://crates.io/crates/serde");
+    }
+}
@@ -0,0 +1,679 @@
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::utils::{html_to_text, parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+const API_BASE: &str = "https://api.crossref.org";
+const MAX_ROWS: u32 = 100;
+
+#[derive(Debug, Deserialize)]
+struct GetWorkArgs {
+    doi: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchWorksArgs {
+    query: String,
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(default)]
+    from_date: Option<String>,
+    #[serde(default)]
+    until_date: Option<String>,
+    #[serde(default)]
+    work_type: Option<String>,
+    #[serde(default)]
+    sort: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    offset: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchEntitiesArgs {
+    query: String,
+    #[serde(default)]
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FunderWorksArgs {
+    funder_id: String,
+    #[serde(default)]
+    query: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    offset: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JournalWorksArgs {
+    issn: String,
+    #[serde(default)]
+    query: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    offset: Option<u32>,
+}
+
+pub struct CrossrefConnector {
+    client: Client,
+    mailto: Option<String>,
+}
+
+impl CrossrefConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let mailto = auth
+            .get("mailto")
+            .cloned()
+            .or_else(|| std::env::var("CROSSREF_MAILTO").ok())
+            .filter(|s| !s.trim().is_empty());
+
+        // Crossref asks clients to identify themselves; a mailto routes traffic to the
+        // "polite" pool with more reliable rate limits.
+        let user_agent = match &mailto {
+            Some(m) => format!("arivu/{} (mailto:{})", env!("CARGO_PKG_VERSION"), m),
+            None => format!("arivu/{}", env!("CARGO_PKG_VERSION")),
+        };
+
+        let client = Client::builder()
+            .user_agent(user_agent)
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+
+        Ok(Self { client, mailto })
+    }
+
+    /// Strip resolver prefixes (`doi:`, `https://doi.org/`) so bare DOIs reach the API.
+    fn normalize_doi(doi: &str) -> String {
+        let trimmed = doi.trim();
+        let lowered = trimmed.to_lowercase();
+        for prefix in [
+            "https://doi.org/",
+            "http://doi.org/",
+            "https://dx.doi.org/",
+            "http://dx.doi.org/",
+            "doi.org/",
+            "doi:",
+        ] {
+            if lowered.starts_with(prefix) {
+                return trimmed[prefix.len()..].to_string();
+            }
+        }
+        trimmed.to_string()
+    }
+
+    async fn get_json(
+        &self,
+        path: &str,
+        params: &[(&str, String)],
+    ) -> Result<Value, ConnectorError> {
+        let url = format!("{}/{}", API_BASE, path.trim_start_matches('/'));
+        let mut query: Vec<(&str, String)> = params.to_vec();
+        if let Some(m) = &self.mailto {
+            query.push(("mailto", m.clone()));
+        }
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&query)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(ConnectorError::ResourceNotFound);
+        }
+        if !response.status().is_success() {
+            return Err(ConnectorError::Other(format!(
+                "Crossref API returned error status: {}",
+                response.status()
+            )));
+        }
+
+        let body: Value = response
+            .json()
+            .await
+            .map_err(|e| ConnectorError::Other(format!("Failed to parse JSON: {}", e)))?;
+
+        Ok(body.get("message").cloned().unwrap_or(Value::Null))
+    }
+
+    fn page_params(limit: Option<u32>, offset: Option<u32>) -> Vec<(&'static str, String)> {
+        let rows = limit.unwrap_or(10).clamp(1, MAX_ROWS);
+        let mut params = vec![("rows", rows.to_string())];
+        if let Some(o) = offset {
+            params.push(("offset", o.to_string()));
+        }
+        params
+    }
+
+    async fn list_works(
+        &self,
+        path: &str,
+        params: Vec<(&str, String)>,
+    ) -> Result<Value, ConnectorError> {
+        let message = self.get_json(path, &params).await?;
+        let items: Vec<Value> = message
+            .get("items")
+            .and_then(|v| v.as_array())
+            .map(|items| items.iter().map(format_work).collect())
+            .unwrap_or_default();
+
+        Ok(json!({
+            "total_results": message.get("total-results").cloned().unwrap_or(Value::Null),
+            "count": items.len(),
+            "results": items,
+        }))
+    }
+}
+
+fn first_string(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::String(s) => Some(s.clone()),
+        Value::Array(arr) => arr.first().and_then(|v| v.as_str()).map(str::to_string),
+        _ => None,
+    }
+}
+
+fn date_from_parts(value: Option<&Value>) -> Option<String> {
+    let parts = value?
+        .get("date-parts")?
+        .as_array()?
+        .first()?
+        .as_array()?
+        .iter()
+        .filter_map(|p| p.as_i64())
+        .collect::<Vec<_>>();
+    match parts.as_slice() {
+        [y, m, d, ..] => Some(format!("{:04}-{:02}-{:02}", y, m, d)),
+        [y, m] => Some(format!("{:04}-{:02}", y, m)),
+        [y] => Some(format!("{:04}", y)),
+        _ => None,
+    }
+}
+
+/// Flatten a Crossref `work` record into the concise shape used across research connectors.
+fn format_work(work: &Value) -> Value {
+    let mut out = Map::new();
+
+    let doi = work.get("DOI").and_then(|v| v.as_str()).unwrap_or_default();
+    out.insert("doi".into(), json!(doi));
+    out.insert("title".into(), json!(first_string(work.get("title"))));
+    if let Some(subtitle) = first_string(work.get("subtitle")) {
+        out.insert("subtitle".into(), json!(subtitle));
+    }
+
+    let authors: Vec<String> = work
+        .get("author")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .map(|a| {
+                    let given = a.get("given").and_then(|v| v.as_str()).unwrap_or("");
+                    let family = a.get("family").and_then(|v| v.as_str()).unwrap_or("");
+                    let name = a.get("name").and_then(|v| v.as_str()).unwrap_or("");
+                    let full = format!("{} {}", given, family).trim().to_string();
+                    if full.is_empty() {
+                        name.to_string()
+                    } else {
+                        full
+                    }
+                })
+                .filter(|n| !n.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+    out.insert("authors".into(), json!(authors));
+
+    out.insert(
+        "journal".into(),
+        json!(first_string(work.get("container-title"))),
+    );
+    out.insert("publisher".into(), json!(work.get("publisher")));
+    out.insert("type".into(), json!(work.get("type")));
+
+    let published = date_from_parts(work.get("published"))
+        .or_else(|| date_from_parts(work.get("published-print")))
+        .or_else(|| date_from_parts(work.get("published-online")))
+        .or_else(|| date_from_parts(work.get("issued")));
+    out.insert("published".into(), json!(published));
+
+    for (src, dst) in [
+        ("volume", "volume"),
+        ("issue", "issue"),
+        ("page", "page"),
+        ("ISSN", "issn"),
+        ("subject", "subjects"),
+    ] {
+        if let Some(v) = work.get(src) {
+            out.insert(dst.into(), v.clone());
+        }
+    }
+
+    if let Some(abstract_jats) = work.get("abstract").and_then(|v| v.as_str()) {
+        out.insert("abstract".into(), json!(html_to_text(abstract_jats)));
+    }
+
+    out.insert(
+        "citation_count".into(),
+        json!(work.get("is-referenced-by-count")),
+    );
+    out.insert(
+        "reference_count".into(),
+        json!(work.get("references-count")),
+    );
+
+    if let Some(funders) = work.get("funder").and_then(|v| v.as_array()) {
+        let funders: Vec<Value> = funders
+            .iter()
+            .map(|f| {
+                json!({
+                    "name": f.get("name"),
+                    "funder_id": f.get("DOI"),
+                    "awards": f.get("award"),
+                })
+            })
+            .collect();
+        out.insert("funders".into(), json!(funders));
+    }
+
+    if let Some(license) = work
+        .get("license")
+        .and_then(|v| v.as_array())
+        .and_then(|a| a.first())
+        .and_then(|l| l.get("URL"))
+    {
+        out.insert("license".into(), license.clone());
+    }
+
+    let url = work
+        .get("URL")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("https://doi.org/{}", doi));
+    out.insert("url".into(), json!(url));
+
+    Value::Object(out)
+}
+
+#[async_trait]
+impl Connector for CrossrefConnector {
+    fn name(&self) -> &'static str {
+        "crossref"
+    }
+
+    fn description(&self) -> &'static str {
+        "Crossref scholarly metadata: DOI lookup, bibliographic search, funders and journals."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        let mut auth = AuthDetails::new();
+        if let Some(m) = &self.mailto {
+            auth.insert("mailto".to_string(), m.clone());
+        }
+        Ok(auth)
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        *self = Self::new(details).await?;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.get_json("works", &[("rows", "1".to_string())])
+            .await
+            .map(|_| ())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema {
+            fields: vec![Field {
+                name: "mailto".to_string(),
+                label: "Contact email".to_string(),
+                field_type: FieldType::Text,
+                required: false,
+                description: Some(
+                    "Optional. Sent with requests to use Crossref's polite pool (or set CROSSREF_MAILTO)."
+                        .to_string(),
+                ),
+                options: None,
+            }],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: Some("https://api.crossref.org".to_string()),
+            },
+            instructions: Some(
+                "Use `get_work` for DOI metadata and `search_works` for bibliographic search. \
+Use `search_funders`/`search_journals` to find IDs, then `get_funder_works`/`get_journal_works`."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            tool(
+                "get_work",
+                "Metadata for a DOI (title, authors, journal, dates, funders, citation count). \
+Example: doi=\"10.1038/nature12373\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "doi": { "type": "string", "description": "DOI, with or without doi.org prefix" }
+                    },
+                    "required": ["doi"]
+                }),
+            ),
+            tool(
+                "search_works",
+                "Bibliographic search over Crossref works. Use for citation strings or title \
+keywords. Example: query=\"attention is all you need\" limit=5.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Bibliographic query (title, citation string, keywords)" },
+                        "author": { "type": "string", "description": "Restrict to author name" },
+                        "from_date": { "type": "string", "description": "Earliest publication date (YYYY, YYYY-MM or YYYY-MM-DD)" },
+                        "until_date": { "type": "string", "description": "Latest publication date (YYYY, YYYY-MM or YYYY-MM-DD)" },
+                        "work_type": { "type": "string", "description": "Crossref work type, e.g. journal-article, book-chapter, proceedings-article" },
+                        "sort": { "type": "string", "enum": ["relevance", "published", "is-referenced-by-count"], "description": "Sort order (default: relevance)" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10, max: 100)" },
+                        "offset": { "type": "integer", "description": "Offset for pagination" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "search_funders",
+                "Find funding agencies in the Crossref Funder Registry. Example: query=\"National Science Foundation\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Funder name" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "get_funder_works",
+                "Works acknowledging a funder. Example: funder_id=\"100000001\" query=\"graphene\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "funder_id": { "type": "string", "description": "Funder Registry ID (e.g. 100000001 or 10.13039/100000001)" },
+                        "query": { "type": "string", "description": "Optional keyword filter" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10, max: 100)" },
+                        "offset": { "type": "integer", "description": "Offset for pagination" }
+                    },
+                    "required": ["funder_id"]
+                }),
+            ),
+            tool(
+                "search_journals",
+                "Find journals by title. Example: query=\"Nature Communications\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Journal title" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "get_journal_works",
+                "Works published in a journal by ISSN. Example: issn=\"2041-1723\" query=\"CRISPR\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "issn": { "type": "string", "description": "Journal ISSN" },
+                        "query": { "type": "string", "description": "Optional keyword filter" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10, max: 100)" },
+                        "offset": { "type": "integer", "description": "Offset for pagination" }
+                    },
+                    "required": ["issn"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "get_work" => {
+                let args: GetWorkArgs = parse_args(request.arguments)?;
+                let doi = Self::normalize_doi(&args.doi);
+                let path = format!("works/{}", urlencoding::encode(&doi));
+                let work = self.get_json(&path, &[]).await?;
+                let data = format_work(&work);
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "search_works" => {
+                let args: SearchWorksArgs = parse_args(request.arguments)?;
+                let mut params = Self::page_params(args.limit, args.offset);
+                params.push(("query.bibliographic", args.query.clone()));
+                if let Some(author) = args.author {
+                    params.push(("query.author", author));
+                }
+
+                let mut filters = Vec::new();
+                if let Some(from) = args.from_date {
+                    filters.push(format!("from-pub-date:{}", from));
+                }
+                if let Some(until) = args.until_date {
+                    filters.push(format!("until-pub-date:{}", until));
+                }
+                if let Some(t) = args.work_type {
+                    filters.push(format!("type:{}", t));
+                }
+                if !filters.is_empty() {
+                    params.push(("filter", filters.join(",")));
+                }
+                if let Some(sort) = args.sort.filter(|s| s != "relevance") {
+                    params.push(("sort", sort));
+                    params.push(("order", "desc".to_string()));
+                }
+
+                let mut data = self.list_works("works", params).await?;
+                data["query"] = json!(args.query);
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "search_funders" | "search_journals" => {
+                let args: SearchEntitiesArgs = parse_args(request.arguments)?;
+                let is_funders = request.name.as_ref() == "search_funders";
+                let path = if is_funders { "funders" } else { "journals" };
+                let rows = args.limit.unwrap_or(10).clamp(1, MAX_ROWS);
+                let message = self
+                    .get_json(
+                        path,
+                        &[("query", args.query.clone()), ("rows", rows.to_string())],
+                    )
+                    .await?;
+
+                let items: Vec<Value> = message
+                    .get("items")
+                    .and_then(|v| v.as_array())
+                    .map(|items| {
+                        items
+                            .iter()
+                            .map(|item| {
+                                if is_funders {
+                                    json!({
+                                        "funder_id": item.get("id"),
+                                        "name": item.get("name"),
+                                        "location": item.get("location"),
+                                        "alt_names": item.get("alt-names"),
+                                        "uri": item.get("uri"),
+                                    })
+                                } else {
+                                    json!({
+                                        "title": item.get("title"),
+                                        "publisher": item.get("publisher"),
+                                        "issn": item.get("ISSN"),
+                                        "subjects": item.get("subjects"),
+                                        "total_dois": item.get("counts").and_then(|c| c.get("total-dois")),
+                                    })
+                                }
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let data = json!({
+                    "query": args.query,
+                    "count": items.len(),
+                    "results": items,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_funder_works" => {
+                let args: FunderWorksArgs = parse_args(request.arguments)?;
+                let funder_id = args
+                    .funder_id
+                    .trim()
+                    .trim_start_matches("10.13039/")
+                    .to_string();
+                let mut params = Self::page_params(args.limit, args.offset);
+                if let Some(q) = args.query {
+                    params.push(("query", q));
+                }
+                let mut data = self
+                    .list_works(&format!("funders/{}/works", funder_id), params)
+                    .await?;
+                data["funder_id"] = json!(funder_id);
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_journal_works" => {
+                let args: JournalWorksArgs = parse_args(request.arguments)?;
+                let mut params = Self::page_params(args.limit, args.offset);
+                if let Some(q) = args.query {
+                    params.push(("query", q));
+                }
+                let mut data = self
+                    .list_works(&format!("journals/{}/works", args.issn.trim()), params)
+                    .await?;
+                data["issn"] = json!(args.issn);
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_doi_prefixes() {
+        assert_eq!(
+            CrossrefConnector::normalize_doi("https://doi.org/10.1038/nature12373"),
+            "10.1038/nature12373"
+        );
+        assert_eq!(
+            CrossrefConnector::normalize_doi("doi:10.1038/nature12373"),
+            "10.1038/nature12373"
+        );
+        assert_eq!(
+            CrossrefConnector::normalize_doi("10.1038/nature12373"),
+            "10.1038/nature12373"
+        );
+    }
+
+    #[test]
+    fn formats_work_record() {
+        let work = json!({
+            "DOI": "10.1038/nature12373",
+            "title": ["Nanometre-scale thermometry in a living cell"],
+            "author": [{"given": "G.", "family": "Kucsko"}, {"name": "Consortium"}],
+            "container-title": ["Nature"],
+            "published": {"date-parts": [[2013, 7, 31]]},
+            "is-referenced-by-count": 1200,
+            "abstract": "<jats:p>Short abstract.</jats:p>"
+        });
+
+        let formatted = format_work(&work);
+        assert_eq!(formatted["doi"], "10.1038/nature12373");
+        assert_eq!(formatted["journal"], "Nature");
+        assert_eq!(formatted["published"], "2013-07-31");
+        assert_eq!(formatted["authors"][0], "G. Kucsko");
+        assert_eq!(formatted["authors"][1], "Consortium");
+        assert_eq!(formatted["citation_count"], 1200);
+        assert_eq!(formatted["url"], "https://doi.org/10.1038/nature12373");
+    }
+}
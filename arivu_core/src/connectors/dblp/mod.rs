@@ -0,0 +1,714 @@
+use crate::capabilities::ConnectorConfigSchema;
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+const API_BASE: &str = "https://dblp.org";
+const MAX_HITS: u32 = 100;
+/// BibTeX export makes one request per hit, so keep it bounded.
+const MAX_BIBTEX_HITS: usize = 25;
+
+/// Record element names that appear inside `<r>` in dblp person XML.
+const RECORD_TAGS: &[&str] = &[
+    "article",
+    "inproceedings",
+    "proceedings",
+    "book",
+    "incollection",
+    "phdthesis",
+    "mastersthesis",
+    "www",
+    "data",
+];
+
+#[derive(Debug, Deserialize)]
+struct SearchPublicationsArgs {
+    query: String,
+    #[serde(default)]
+    year: Option<u32>,
+    #[serde(default)]
+    venue: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    offset: Option<u32>,
+    #[serde(default)]
+    bibtex: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchArgs {
+    query: String,
+    #[serde(default)]
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AuthorPublicationsArgs {
+    #[serde(default)]
+    pid: Option<String>,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    bibtex: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct VenuePublicationsArgs {
+    venue: String,
+    #[serde(default)]
+    year: Option<u32>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    offset: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetBibtexArgs {
+    key: String,
+}
+
+pub struct DblpConnector {
+    client: Client,
+}
+
+impl DblpConnector {
+    pub async fn new(_auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+
+        Ok(Self { client })
+    }
+
+    async fn get_text(
+        &self,
+        url: &str,
+        params: &[(&str, String)],
+    ) -> Result<String, ConnectorError> {
+        let response = self
+            .client
+            .get(url)
+            .query(params)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(ConnectorError::ResourceNotFound);
+        }
+        if !response.status().is_success() {
+            return Err(ConnectorError::Other(format!(
+                "DBLP returned error status: {}",
+                response.status()
+            )));
+        }
+
+        response.text().await.map_err(ConnectorError::HttpRequest)
+    }
+
+    /// Query one of the dblp search APIs (`publ`, `author`, `venue`) and return the hit list.
+    async fn search(
+        &self,
+        kind: &str,
+        query: &str,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<(u64, Vec<Value>), ConnectorError> {
+        let url = format!("{}/search/{}/api", API_BASE, kind);
+        let hits = limit.unwrap_or(10).clamp(1, MAX_HITS);
+        let params = [
+            ("q", query.to_string()),
+            ("format", "json".to_string()),
+            ("h", hits.to_string()),
+            ("f", offset.unwrap_or(0).to_string()),
+        ];
+
+        let body = self.get_text(&url, &params).await?;
+        let value: Value = serde_json::from_str(&body)
+            .map_err(|e| ConnectorError::Other(format!("Failed to parse DBLP response: {}", e)))?;
+
+        let hits = &value["result"]["hits"];
+        let total = hits["@total"]
+            .as_str()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0);
+        let infos = hits["hit"]
+            .as_array()
+            .map(|arr| arr.iter().map(|h| h["info"].clone()).collect())
+            .unwrap_or_default();
+
+        Ok((total, infos))
+    }
+
+    async fn fetch_bibtex(&self, key: &str) -> Result<String, ConnectorError> {
+        let url = format!("{}/rec/{}.bib", API_BASE, key.trim_matches('/'));
+        self.get_text(&url, &[]).await
+    }
+
+    /// Attach a `bibtex` field to each publication (best-effort, bounded).
+    async fn attach_bibtex(&self, publications: &mut [Value]) {
+        for publication in publications.iter_mut().take(MAX_BIBTEX_HITS) {
+            let Some(key) = publication["key"].as_str().map(str::to_string) else {
+                continue;
+            };
+            if let Ok(bib) = self.fetch_bibtex(&key).await {
+                publication["bibtex"] = json!(bib.trim());
+            }
+        }
+    }
+
+    async fn resolve_author_pid(&self, name: &str) -> Result<String, ConnectorError> {
+        let (_, hits) = self.search("author", name, Some(1), None).await?;
+        hits.first()
+            .and_then(|info| info["url"].as_str())
+            .and_then(pid_from_url)
+            .ok_or(ConnectorError::ResourceNotFound)
+    }
+}
+
+/// `https://dblp.org/pid/k/DonaldEKnuth` -> `k/DonaldEKnuth`
+fn pid_from_url(url: &str) -> Option<String> {
+    url.split("/pid/")
+        .nth(1)
+        .map(|s| {
+            s.trim_end_matches(".html")
+                .trim_end_matches('/')
+                .to_string()
+        })
+        .filter(|s| !s.is_empty())
+}
+
+/// dblp returns a bare object instead of a one-element array for single values.
+fn as_list(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(arr) => arr.iter().collect(),
+        Value::Null => vec![],
+        other => vec![other],
+    }
+}
+
+fn text_of(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Object(_) => value["text"].as_str().map(str::to_string),
+        _ => None,
+    }
+}
+
+fn format_publication_hit(info: &Value) -> Value {
+    let authors: Vec<String> = as_list(&info["authors"]["author"])
+        .into_iter()
+        .filter_map(text_of)
+        .collect();
+    let venue: Vec<String> = as_list(&info["venue"])
+        .into_iter()
+        .filter_map(text_of)
+        .collect();
+
+    json!({
+        "key": info["key"],
+        "title": info["title"].as_str().map(|t| t.trim_end_matches('.')),
+        "authors": authors,
+        "venue": venue.join(", "),
+        "year": info["year"].as_str().and_then(|y| y.parse::<u32>().ok()),
+        "type": info["type"],
+        "volume": info["volume"],
+        "pages": info["pages"],
+        "doi": info["doi"],
+        "ee": info["ee"],
+        "url": info["url"],
+    })
+}
+
+fn format_author_hit(info: &Value) -> Value {
+    let affiliations: Vec<String> = as_list(&info["notes"]["note"])
+        .into_iter()
+        .filter(|n| n["@type"].as_str() == Some("affiliation"))
+        .filter_map(text_of)
+        .collect();
+    let url = info["url"].as_str().unwrap_or_default();
+
+    json!({
+        "name": info["author"],
+        "pid": pid_from_url(url),
+        "affiliations": affiliations,
+        "url": url,
+    })
+}
+
+fn format_venue_hit(info: &Value) -> Value {
+    json!({
+        "venue": info["venue"],
+        "acronym": info["acronym"],
+        "type": info["type"],
+        "url": info["url"],
+    })
+}
+
+/// Parse a dblp person page (`/pid/<pid>.xml`) into the author's name and publication list.
+fn parse_person_xml(xml: &str) -> Result<(Option<String>, Vec<Value>), ConnectorError> {
+    let mut reader = Reader::from_str(xml);
+
+    let mut person_name: Option<String> = None;
+    let mut publications = Vec::new();
+
+    let mut in_r = false;
+    let mut record: Option<Map<String, Value>> = None;
+    let mut record_tag = String::new();
+    let mut authors: Vec<String> = Vec::new();
+    let mut field: Option<String> = None;
+    let mut text = String::new();
+
+    let mut buffer = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buffer) {
+            Ok(Event::Start(ref e)) => {
+                let tag_name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+
+                if tag_name == "dblpperson" {
+                    for attr in e.attributes().flatten() {
+                        if attr.key.as_ref() == b"name" {
+                            person_name = attr.unescape_value().ok().map(|v| v.to_string());
+                        }
+                    }
+                } else if tag_name == "r" {
+                    in_r = true;
+                } else if in_r && record.is_none() && RECORD_TAGS.contains(&tag_name.as_str()) {
+                    let mut map = Map::new();
+                    for attr in e.attributes().flatten() {
+                        if attr.key.as_ref() == b"key" {
+                            if let Ok(v) = attr.unescape_value() {
+                                map.insert("key".to_string(), json!(v.to_string()));
+                            }
+                        }
+                    }
+                    map.insert("type".to_string(), json!(tag_name));
+                    record_tag = tag_name;
+                    record = Some(map);
+                    authors.clear();
+                } else if record.is_some() && field.is_none() {
+                    // Nested markup (e.g. <i>, <sub> in titles) keeps the outer field.
+                    field = Some(tag_name);
+                    text.clear();
+                }
+            }
+            Ok(Event::Text(ref e)) => {
+                if field.is_some() {
+                    let chunk = e.unescape().map_err(|_| ConnectorError::ParseError)?;
+                    text.push_str(&chunk);
+                }
+            }
+            Ok(Event::End(ref e)) => {
+                let tag_name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+
+                if field.as_deref() == Some(tag_name.as_str()) {
+                    let value = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                    if let Some(map) = record.as_mut() {
+                        match tag_name.as_str() {
+                            "author" | "editor" => authors.push(value),
+                            "title" => {
+                                map.insert("title".to_string(), json!(value.trim_end_matches('.')));
+                            }
+                            "journal" | "booktitle" => {
+                                map.insert("venue".to_string(), json!(value));
+                            }
+                            "year" => {
+                                map.insert("year".to_string(), json!(value.parse::<u32>().ok()));
+                            }
+                            "ee" if !map.contains_key("ee") => {
+                                map.insert("ee".to_string(), json!(value));
+                            }
+                            "volume" | "pages" | "number" | "publisher" => {
+                                map.insert(tag_name.clone(), json!(value));
+                            }
+                            _ => {}
+                        }
+                    }
+                    field = None;
+                    text.clear();
+                } else if record.is_some() && tag_name == record_tag {
+                    if let Some(mut map) = record.take() {
+                        map.insert("authors".to_string(), json!(authors.clone()));
+                        if let Some(key) = map.get("key").and_then(|k| k.as_str()) {
+                            let url = format!("{}/rec/{}", API_BASE, key);
+                            map.insert("url".to_string(), json!(url));
+                        }
+                        publications.push(Value::Object(map));
+                    }
+                } else if tag_name == "r" {
+                    in_r = false;
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(_) => return Err(ConnectorError::ParseError),
+            _ => {}
+        }
+
+        buffer.clear();
+    }
+
+    Ok((person_name, publications))
+}
+
+#[async_trait]
+impl Connector for DblpConnector {
+    fn name(&self) -> &'static str {
+        "dblp"
+    }
+
+    fn description(&self) -> &'static str {
+        "DBLP computer science bibliography: publications, authors, venues and BibTeX."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        Ok(AuthDetails::new())
+    }
+
+    async fn set_auth_details(&mut self, _details: AuthDetails) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema { fields: vec![] }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: Some("https://dblp.org".to_string()),
+            },
+            instructions: Some(
+                "Use `search_publications` for title search (set bibtex=true to include BibTeX). \
+Use `search_authors` to find an author pid, then `get_author_publications`. \
+Use `search_venues` and `get_venue_publications` to browse conferences and journals."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            tool(
+                "search_publications",
+                "Search DBLP publications by title/keywords. Example: query=\"attention is all you need\" bibtex=true.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Title words or keywords" },
+                        "year": { "type": "integer", "description": "Restrict to publication year" },
+                        "venue": { "type": "string", "description": "Restrict to venue (e.g. NeurIPS, VLDB)" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10, max: 100)" },
+                        "offset": { "type": "integer", "description": "Offset for pagination" },
+                        "bibtex": { "type": "boolean", "description": "Include BibTeX for each hit (default: false)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "search_authors",
+                "Find DBLP authors and their pids. Example: query=\"Donald Knuth\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Author name" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "get_author_publications",
+                "List an author's publications (newest first). Provide pid (preferred) or name. \
+Example: pid=\"k/DonaldEKnuth\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "pid": { "type": "string", "description": "DBLP person id (from search_authors)" },
+                        "name": { "type": "string", "description": "Author name; resolved to the top search hit" },
+                        "limit": { "type": "integer", "description": "Max publications (default: 50)" },
+                        "bibtex": { "type": "boolean", "description": "Include BibTeX for each publication (default: false)" }
+                    }
+                }),
+            ),
+            tool(
+                "search_venues",
+                "Find conferences and journals. Example: query=\"ICML\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Venue name or acronym" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "get_venue_publications",
+                "Browse publications in a venue, optionally for one year. Example: venue=\"ICML\" year=2023.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "venue": { "type": "string", "description": "Venue name or acronym" },
+                        "year": { "type": "integer", "description": "Publication year" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10, max: 100)" },
+                        "offset": { "type": "integer", "description": "Offset for pagination" }
+                    },
+                    "required": ["venue"]
+                }),
+            ),
+            tool(
+                "get_bibtex",
+                "BibTeX for a DBLP record key. Example: key=\"conf/nips/VaswaniSPUJGKP17\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "key": { "type": "string", "description": "DBLP record key" }
+                    },
+                    "required": ["key"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "search_publications" => {
+                let args: SearchPublicationsArgs = parse_args(request.arguments)?;
+                let mut query = args.query.clone();
+                if let Some(venue) = &args.venue {
+                    query.push_str(&format!(" venue:{}:", venue));
+                }
+                if let Some(year) = args.year {
+                    query.push_str(&format!(" year:{}:", year));
+                }
+
+                let (total, hits) = self.search("publ", &query, args.limit, args.offset).await?;
+                let mut results: Vec<Value> = hits.iter().map(format_publication_hit).collect();
+                if args.bibtex {
+                    self.attach_bibtex(&mut results).await;
+                }
+
+                let data = json!({
+                    "query": args.query,
+                    "total": total,
+                    "count": results.len(),
+                    "results": results,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "search_authors" | "search_venues" => {
+                let args: SearchArgs = parse_args(request.arguments)?;
+                let authors = request.name.as_ref() == "search_authors";
+                let kind = if authors { "author" } else { "venue" };
+                let (total, hits) = self.search(kind, &args.query, args.limit, None).await?;
+                let results: Vec<Value> = hits
+                    .iter()
+                    .map(|h| {
+                        if authors {
+                            format_author_hit(h)
+                        } else {
+                            format_venue_hit(h)
+                        }
+                    })
+                    .collect();
+
+                let data = json!({
+                    "query": args.query,
+                    "total": total,
+                    "count": results.len(),
+                    "results": results,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_author_publications" => {
+                let args: AuthorPublicationsArgs = parse_args(request.arguments)?;
+                let pid = match (args.pid, args.name) {
+                    (Some(pid), _) => pid.trim().trim_matches('/').to_string(),
+                    (None, Some(name)) => self.resolve_author_pid(&name).await?,
+                    (None, None) => {
+                        return Err(ConnectorError::InvalidParams(
+                            "Provide either 'pid' or 'name'".to_string(),
+                        ))
+                    }
+                };
+
+                let xml = self
+                    .get_text(&format!("{}/pid/{}.xml", API_BASE, pid), &[])
+                    .await?;
+                let (name, mut publications) = parse_person_xml(&xml)?;
+                let total = publications.len();
+                publications.truncate(args.limit.unwrap_or(50) as usize);
+                if args.bibtex {
+                    self.attach_bibtex(&mut publications).await;
+                }
+
+                let data = json!({
+                    "pid": pid,
+                    "name": name,
+                    "total": total,
+                    "count": publications.len(),
+                    "publications": publications,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_venue_publications" => {
+                let args: VenuePublicationsArgs = parse_args(request.arguments)?;
+                let mut query = format!("venue:{}:", args.venue);
+                if let Some(year) = args.year {
+                    query.push_str(&format!(" year:{}:", year));
+                }
+
+                let (total, hits) = self.search("publ", &query, args.limit, args.offset).await?;
+                let results: Vec<Value> = hits.iter().map(format_publication_hit).collect();
+
+                let data = json!({
+                    "venue": args.venue,
+                    "year": args.year,
+                    "total": total,
+                    "count": results.len(),
+                    "results": results,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_bibtex" => {
+                let args: GetBibtexArgs = parse_args(request.arguments)?;
+                let bibtex = self.fetch_bibtex(&args.key).await?;
+                let data = json!({
+                    "key": args.key,
+                    "bibtex": bibtex.trim(),
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_publication_hit_with_single_author() {
+        let info = json!({
+            "authors": { "author": { "@pid": "k/DonaldEKnuth", "text": "Donald E. Knuth" } },
+            "title": "The Art of Computer Programming.",
+            "venue": "Addison-Wesley",
+            "year": "1968",
+            "key": "books/aw/Knuth68"
+        });
+
+        let formatted = format_publication_hit(&info);
+        assert_eq!(formatted["authors"][0], "Donald E. Knuth");
+        assert_eq!(formatted["title"], "The Art of Computer Programming");
+        assert_eq!(formatted["year"], 1968);
+    }
+
+    #[test]
+    fn parses_person_xml() {
+        let xml = r#"<?xml version="1.0"?>
+<dblpperson name="Ada Lovelace" pid="l/AdaLovelace" n="1">
+<person key="homepages/l/AdaLovelace"><author pid="l/AdaLovelace">Ada Lovelace</author></person>
+<r><article key="journals/x/Lovelace43" mdate="2020-01-01">
+<author pid="l/AdaLovelace">Ada Lovelace</author><author pid="b/CB">Charles Babbage</author>
+<title>Notes on the <i>Analytical</i> Engine.</title>
+<year>1843</year><journal>Sci. Mem.</journal><ee>https://example.org/notes</ee>
+</article></r>
+</dblpperson>"#;
+
+        let (name, pubs) = parse_person_xml(xml).unwrap();
+        assert_eq!(name.as_deref(), Some("Ada Lovelace"));
+        assert_eq!(pubs.len(), 1);
+        assert_eq!(pubs[0]["key"], "journals/x/Lovelace43");
+        assert_eq!(pubs[0]["title"], "Notes on the Analytical Engine");
+        assert_eq!(pubs[0]["authors"][1], "Charles Babbage");
+        assert_eq!(pubs[0]["venue"], "Sci. Mem.");
+        assert_eq!(pubs[0]["year"], 1843);
+    }
+
+    #[test]
+    fn extracts_pid_from_url() {
+        assert_eq!(
+            pid_from_url("https://dblp.org/pid/k/DonaldEKnuth").as_deref(),
+            Some("k/DonaldEKnuth")
+        );
+        assert_eq!(pid_from_url("https://dblp.org/db/conf/icml/"), None);
+    }
+}
@@ -0,0 +1,475 @@
+use crate::capabilities::ConnectorConfigSchema;
+use crate::error::ConnectorError;
+use crate::utils::{resolve_search_filters, structured_result_with_text};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use reqwest::Client;
+use rmcp::model::*;
+use scraper::{ElementRef, Html, Selector};
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::time::{sleep, Duration, Instant};
+
+const HTML_ENDPOINT: &str = "https://html.duckduckgo.com/html/";
+const LITE_ENDPOINT: &str = "https://lite.duckduckgo.com/lite/";
+const USER_AGENT: &str = "Mozilla/5.0 (X11; Linux x86_64; rv:128.0) Gecko/20100101 Firefox/128.0";
+/// Minimum gap between requests, shared by every instance in the process.
+const MIN_INTERVAL: Duration = Duration::from_millis(1500);
+/// Results per page on the HTML endpoint; used to turn a page number into `s=`.
+const PAGE_SIZE: u64 = 30;
+const MAX_RESULTS: usize = 30;
+
+static LAST_REQUEST: Lazy<Mutex<Option<Instant>>> = Lazy::new(|| Mutex::new(None));
+
+/// Wait until `MIN_INTERVAL` has passed since the previous request. Holding the
+/// lock while sleeping serializes concurrent callers.
+async fn throttle() {
+    let mut last = LAST_REQUEST.lock().await;
+    if let Some(prev) = *last {
+        let elapsed = prev.elapsed();
+        if elapsed < MIN_INTERVAL {
+            sleep(MIN_INTERVAL - elapsed).await;
+        }
+    }
+    *last = Some(Instant::now());
+}
+
+pub struct DdgSearchConnector {
+    client: Client,
+}
+
+impl DdgSearchConnector {
+    pub async fn new(_auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent(USER_AGENT)
+            .cookie_store(true)
+            .timeout(Duration::from_secs(20))
+            .build()
+            .map_err(|e| ConnectorError::Other(e.to_string()))?;
+        Ok(Self { client })
+    }
+
+    /// POST a search form. `Ok(None)` means DuckDuckGo served its bot challenge.
+    async fn fetch(
+        &self,
+        endpoint: &str,
+        form: &[(&str, String)],
+    ) -> Result<Option<String>, ConnectorError> {
+        throttle().await;
+        let resp = self
+            .client
+            .post(endpoint)
+            .header(reqwest::header::REFERER, endpoint)
+            .form(form)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+        let status = resp.status();
+        let body = resp.text().await.map_err(ConnectorError::HttpRequest)?;
+        // DuckDuckGo answers bot checks with 202 and an "anomaly" challenge page.
+        if status.as_u16() == 202 || is_challenge(&body) {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            return Err(ConnectorError::Other(format!(
+                "DuckDuckGo returned status: {}",
+                status
+            )));
+        }
+        Ok(Some(body))
+    }
+}
+
+fn rate_limited() -> ConnectorError {
+    ConnectorError::Other(
+        "DuckDuckGo is rate limiting this IP (bot challenge). Wait a few minutes or use a search connector with an API key."
+            .into(),
+    )
+}
+
+fn is_challenge(body: &str) -> bool {
+    body.contains("anomaly-modal") || body.contains("/anomaly.js")
+}
+
+/// `kl` region code, e.g. `us-en`; `wt-wt` means no region.
+fn ddg_region(language: Option<&str>, region: Option<&str>) -> String {
+    match region {
+        // DuckDuckGo uses `uk` rather than the ISO `gb`.
+        Some(r) if r.eq_ignore_ascii_case("gb") => {
+            format!("uk-{}", language.unwrap_or("en").to_lowercase())
+        }
+        Some(r) => format!(
+            "{}-{}",
+            r.to_lowercase(),
+            language.unwrap_or("en").to_lowercase()
+        ),
+        None => "wt-wt".to_string(),
+    }
+}
+
+/// `df` date filter: `d`/`w`/`m`/`y` for presets, otherwise a `since..until` range.
+fn ddg_date_filter(
+    preset: Option<&str>,
+    since: Option<&str>,
+    until: Option<&str>,
+) -> Option<String> {
+    let shorthand = match preset.map(|p| p.to_lowercase()).as_deref() {
+        Some("last_24_hours" | "past_day") => Some("d"),
+        Some("last_7_days" | "past_week") => Some("w"),
+        Some("last_30_days" | "past_month") => Some("m"),
+        Some("last_365_days" | "past_year") => Some("y"),
+        _ => None,
+    };
+    if let Some(s) = shorthand {
+        return Some(s.to_string());
+    }
+    match (since, until) {
+        (None, None) => None,
+        (s, u) => Some(format!(
+            "{}..{}",
+            s.unwrap_or("1990-01-01"),
+            u.map(str::to_string).unwrap_or_else(|| chrono::Utc::now()
+                .date_naive()
+                .format("%Y-%m-%d")
+                .to_string())
+        )),
+    }
+}
+
+fn apply_domain_filters(query: &str, include: &[String], exclude: &[String]) -> String {
+    let mut q = query.to_string();
+    match include.len() {
+        0 => {}
+        1 => q.push_str(&format!(" site:{}", include[0])),
+        _ => {
+            let sites: Vec<String> = include.iter().map(|d| format!("site:{}", d)).collect();
+            q.push_str(&format!(" ({})", sites.join(" OR ")));
+        }
+    }
+    for d in exclude {
+        q.push_str(&format!(" -site:{}", d));
+    }
+    q
+}
+
+/// Result links go through `//duckduckgo.com/l/?uddg=<target>`; return the target.
+/// Ad links (`/y.js`) yield `None`.
+fn unwrap_redirect(href: &str) -> Option<String> {
+    let absolute = if href.starts_with("//") {
+        format!("https:{}", href)
+    } else if href.starts_with('/') {
+        format!("https://duckduckgo.com{}", href)
+    } else {
+        href.to_string()
+    };
+    let url = url::Url::parse(&absolute).ok()?;
+    if url.domain().is_some_and(|d| d.ends_with("duckduckgo.com")) {
+        if url.path().starts_with("/y.js") {
+            return None;
+        }
+        if let Some((_, target)) = url.query_pairs().find(|(k, _)| k == "uddg") {
+            return Some(target.into_owned());
+        }
+    }
+    Some(absolute)
+}
+
+fn text_of(el: ElementRef) -> String {
+    el.text()
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn parse_html_results(html: &str) -> Vec<Value> {
+    let doc = Html::parse_document(html);
+    let result_sel = Selector::parse("div.result").unwrap();
+    let link_sel = Selector::parse("a.result__a").unwrap();
+    let snippet_sel = Selector::parse(".result__snippet").unwrap();
+    doc.select(&result_sel)
+        .filter(|r| !r.value().classes().any(|c| c == "result--ad"))
+        .filter_map(|r| {
+            let link = r.select(&link_sel).next()?;
+            let url = unwrap_redirect(link.value().attr("href")?)?;
+            Some(json!({
+                "title": text_of(link),
+                "url": url,
+                "snippet": r.select(&snippet_sel).next().map(text_of).unwrap_or_default(),
+            }))
+        })
+        .collect()
+}
+
+fn parse_lite_results(html: &str) -> Vec<Value> {
+    let doc = Html::parse_document(html);
+    let link_sel = Selector::parse("a.result-link").unwrap();
+    let snippet_sel = Selector::parse("td.result-snippet").unwrap();
+    let snippets: Vec<String> = doc.select(&snippet_sel).map(text_of).collect();
+    doc.select(&link_sel)
+        .zip(
+            snippets
+                .into_iter()
+                .map(Some)
+                .chain(std::iter::repeat(None)),
+        )
+        .filter_map(|(link, snippet)| {
+            let url = unwrap_redirect(link.value().attr("href")?)?;
+            Some(json!({
+                "title": text_of(link),
+                "url": url,
+                "snippet": snippet.unwrap_or_default(),
+            }))
+        })
+        .collect()
+}
+
+#[async_trait]
+impl Connector for DdgSearchConnector {
+    fn name(&self) -> &'static str {
+        "ddg-search"
+    }
+    fn description(&self) -> &'static str {
+        "DuckDuckGo web search without an API key (HTML/lite endpoints, throttled)."
+    }
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: None,
+            ..Default::default()
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _r: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().into(),
+                version: "0.1.0".into(),
+                title: None,
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Use 'search' for keyless web results from DuckDuckGo. Requests are spaced out; prefer a paid search connector for high volume."
+                    .into(),
+            ),
+        })
+    }
+    async fn list_resources(
+        &self,
+        _r: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+    async fn read_resource(
+        &self,
+        _r: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Ok(vec![])
+    }
+
+    async fn list_tools(
+        &self,
+        _r: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tool = Tool { name: Cow::Borrowed("search"), title: None, description: Some(Cow::Borrowed("Keyless web search via DuckDuckGo. Use as a fallback when no paid search connector is configured. Example: query=\"sqlite wal mode\" limit=5.")), input_schema: Arc::new(json!({
+            "type": "object",
+            "properties": {
+                "query": {"type": "string"},
+                "limit": {"type": "integer", "default": 10, "description": "Results to return (max 30)."},
+                "max_results": {"type": "integer", "description": "Alias for limit (deprecated)."},
+                "page": {"type": "integer", "default": 1, "description": "Results page (HTML endpoint only)."},
+                "date_preset": {"type": "string", "description": "last_24_hours|last_7_days|last_30_days|past_year"},
+                "since": {"type": "string", "description": "YYYY-MM-DD"},
+                "until": {"type": "string", "description": "YYYY-MM-DD"},
+                "locale": {"type": "string", "description": "Locale like en-US or fr-FR"},
+                "language": {"type": "string", "description": "BCP-47 language"},
+                "region": {"type": "string", "description": "Country code, e.g. US (default: no region)"},
+                "include_domains": {"type": "array", "items": {"type": "string"}},
+                "exclude_domains": {"type": "array", "items": {"type": "string"}},
+                "response_format": {"type": "string", "enum": ["concise","detailed"], "default": "concise"}
+            },
+            "required": ["query"],
+            "additionalProperties": false
+        }).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None };
+        Ok(ListToolsResult {
+            tools: vec![tool],
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        if request.name.as_ref() != "search" {
+            return Err(ConnectorError::ToolNotFound);
+        }
+        let args = request.arguments.unwrap_or_default();
+        let query = args
+            .get("query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| ConnectorError::InvalidParams("Missing 'query'".into()))?;
+        let limit = args
+            .get("limit")
+            .or_else(|| args.get("max_results"))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(10)
+            .clamp(1, MAX_RESULTS as u64) as usize;
+        let page = args
+            .get("page")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(1)
+            .max(1);
+        let detailed = args
+            .get("response_format")
+            .and_then(|v| v.as_str())
+            .map(|s| s == "detailed")
+            .unwrap_or(false);
+
+        let filters = resolve_search_filters(&args);
+        let q = apply_domain_filters(query, &filters.include_domains, &filters.exclude_domains);
+        let mut form: Vec<(&str, String)> = vec![
+            ("q", q),
+            (
+                "kl",
+                ddg_region(filters.language.as_deref(), filters.region.as_deref()),
+            ),
+        ];
+        if let Some(df) = ddg_date_filter(
+            args.get("date_preset").and_then(|v| v.as_str()),
+            filters.since.as_deref(),
+            filters.until.as_deref(),
+        ) {
+            form.push(("df", df));
+        }
+
+        let mut html_form = form.clone();
+        if page > 1 {
+            let offset = (page - 1) * PAGE_SIZE;
+            html_form.push(("s", offset.to_string()));
+            html_form.push(("dc", (offset + 1).to_string()));
+        }
+
+        // The HTML endpoint has richer snippets and paging; fall back to lite when
+        // it serves a bot challenge.
+        let (endpoint, mut results) = match self.fetch(HTML_ENDPOINT, &html_form).await? {
+            Some(body) => ("html", parse_html_results(&body)),
+            None if page == 1 => match self.fetch(LITE_ENDPOINT, &form).await? {
+                Some(body) => ("lite", parse_lite_results(&body)),
+                None => return Err(rate_limited()),
+            },
+            None => return Err(rate_limited()),
+        };
+        let total_on_page = results.len();
+        results.truncate(limit);
+
+        let mut data = json!({
+            "provider": "duckduckgo",
+            "endpoint": endpoint,
+            "query": query,
+            "page": page,
+            "limit_hint": limit,
+            "results": results,
+        });
+        if detailed {
+            let sent: serde_json::Map<String, Value> = html_form
+                .iter()
+                .map(|(k, v)| (k.to_string(), json!(v)))
+                .collect();
+            data["raw"] = json!({ "results_on_page": total_on_page, "form": sent });
+        }
+        Ok(structured_result_with_text(&data, None)?)
+    }
+
+    async fn list_prompts(
+        &self,
+        _r: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+    async fn get_prompt(&self, _name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::ToolNotFound)
+    }
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        Ok(AuthDetails::new())
+    }
+    async fn set_auth_details(&mut self, _details: AuthDetails) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema { fields: vec![] }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unwraps_result_redirects() {
+        assert_eq!(
+            unwrap_redirect("//duckduckgo.com/l/?uddg=https%3A%2F%2Fexample.com%2Fa%3Fb%3D1&rut=x")
+                .as_deref(),
+            Some("https://example.com/a?b=1")
+        );
+        assert_eq!(
+            unwrap_redirect("https://example.org/").as_deref(),
+            Some("https://example.org/")
+        );
+        assert_eq!(
+            unwrap_redirect("https://duckduckgo.com/y.js?ad_domain=x"),
+            None
+        );
+    }
+
+    #[test]
+    fn parses_html_and_lite_pages() {
+        let html = r#"<div class="result results_links web-result">
+              <a class="result__a" href="//duckduckgo.com/l/?uddg=https%3A%2F%2Fsqlite.org%2Fwal.html">Write-Ahead <b>Logging</b></a>
+              <a class="result__snippet">The default method  by which SQLite…</a></div>
+            <div class="result result--ad"><a class="result__a" href="https://duckduckgo.com/y.js?x=1">Ad</a></div>"#;
+        let results = parse_html_results(html);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["url"], "https://sqlite.org/wal.html");
+        assert_eq!(results[0]["title"], "Write-Ahead Logging");
+        assert_eq!(results[0]["snippet"], "The default method by which SQLite…");
+
+        let lite = r#"<table><tr><td>1.</td><td><a rel="nofollow" href="https://sqlite.org/wal.html" class='result-link'>WAL</a></td></tr>
+            <tr><td></td><td class='result-snippet'>Snippet one</td></tr></table>"#;
+        let results = parse_lite_results(lite);
+        assert_eq!(results[0]["url"], "https://sqlite.org/wal.html");
+        assert_eq!(results[0]["snippet"], "Snippet one");
+    }
+
+    #[test]
+    fn maps_region_and_dates() {
+        assert_eq!(ddg_region(Some("fr"), Some("FR")), "fr-fr");
+        assert_eq!(ddg_region(Some("en"), Some("GB")), "uk-en");
+        assert_eq!(ddg_region(Some("en"), None), "wt-wt");
+        assert_eq!(
+            ddg_date_filter(Some("last_7_days"), None, None).as_deref(),
+            Some("w")
+        );
+        assert_eq!(
+            ddg_date_filter(None, Some("2025-01-01"), Some("2025-01-31")).as_deref(),
+            Some("2025-01-01..2025-01-31")
+        );
+    }
+}
@@ -1,13 +1,14 @@
 use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
 use crate::error::ConnectorError;
-use crate::utils::structured_result_with_text;
+use crate::utils::{structured_result_with_text, write_gate};
 use crate::{auth::AuthDetails, Connector};
 use async_trait::async_trait;
 use rmcp::model::*;
 use serde::Deserialize;
 use serde_json::{json, Value};
-use serenity::http::Http;
-use serenity::model::id::{ChannelId, GuildId};
+use serenity::http::{Http, MessagePagination};
+use serenity::model::channel::{GuildChannel, Message};
+use serenity::model::id::{ChannelId, GuildId, MessageId};
 use std::borrow::Cow;
 use std::sync::Arc;
 
@@ -19,10 +20,39 @@ struct ReadMessagesArgs {
 
 #[derive(Debug, Deserialize)]
 struct SendMessageArgs {
+    /// A channel or thread ID; threads are channels on Discord
     channel_id: u64,
     content: String,
+    #[serde(default)]
+    reply_to_message_id: Option<u64>,
+    #[serde(default)]
+    confirm: bool,
 }
 
+#[derive(Debug, Deserialize)]
+struct ListThreadsArgs {
+    #[serde(default)]
+    guild_id: Option<u64>,
+    /// Parent channel; required for archived threads
+    #[serde(default)]
+    channel_id: Option<u64>,
+    #[serde(default)]
+    archived: bool,
+    #[serde(default)]
+    limit: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetThreadMessagesArgs {
+    thread_id: u64,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+/// Messages per request and the cap for one `get_thread_messages` call.
+const DISCORD_PAGE_SIZE: u8 = 100;
+const DISCORD_MAX_THREAD_MESSAGES: usize = 1_000;
+
 #[derive(Debug, Deserialize)]
 struct ListChannelsArgs {
     guild_id: u64,
@@ -43,6 +73,46 @@ struct SearchMessagesArgs {
 pub struct DiscordConnector {
     http: Option<Arc<Http>>,
     token: Option<String>,
+    allow_writes: Option<String>,
+}
+
+/// Message with author, reply target and attachment URLs.
+fn message_json(m: &Message) -> Value {
+    let attachments: Vec<Value> = m
+        .attachments
+        .iter()
+        .map(|a| {
+            json!({
+                "filename": a.filename,
+                "url": a.url,
+                "content_type": a.content_type,
+                "size": a.size,
+            })
+        })
+        .collect();
+    json!({
+        "id": m.id.get(),
+        "author": m.author.name,
+        "content": m.content,
+        "timestamp": m.timestamp.to_rfc3339(),
+        "reply_to": m.message_reference.as_ref().and_then(|r| r.message_id).map(|id| id.get()),
+        "attachments": attachments,
+    })
+}
+
+fn thread_json(t: &GuildChannel) -> Value {
+    let meta = t.thread_metadata.as_ref();
+    json!({
+        "id": t.id.get(),
+        "name": t.name,
+        "parent_id": t.parent_id.map(|id| id.get()),
+        "owner_id": t.owner_id.map(|id| id.get()),
+        "message_count": t.message_count,
+        "member_count": t.member_count,
+        "archived": meta.map(|m| m.archived),
+        "locked": meta.map(|m| m.locked),
+        "created_at": meta.and_then(|m| m.create_timestamp).map(|ts| ts.to_rfc3339()),
+    })
 }
 
 impl DiscordConnector {
@@ -50,6 +120,7 @@ impl DiscordConnector {
         let mut connector = Self {
             http: None,
             token: None,
+            allow_writes: None,
         };
         if !auth.is_empty() {
             connector.set_auth_details(auth).await?;
@@ -62,6 +133,39 @@ impl DiscordConnector {
             "Discord token not provided".to_string(),
         ))
     }
+
+    /// Up to `limit` messages of a thread, oldest first.
+    async fn thread_messages(
+        &self,
+        http: &Http,
+        thread_id: ChannelId,
+        limit: usize,
+    ) -> Result<(Vec<Message>, bool), ConnectorError> {
+        let mut out: Vec<Message> = Vec::new();
+        let mut before: Option<MessageId> = None;
+        let mut more = true;
+        while out.len() < limit {
+            let page = http
+                .get_messages(
+                    thread_id,
+                    before.map(MessagePagination::Before),
+                    Some(DISCORD_PAGE_SIZE),
+                )
+                .await
+                .map_err(|e| ConnectorError::Other(e.to_string()))?;
+            more = page.len() == DISCORD_PAGE_SIZE as usize;
+            // Pages come newest first
+            before = page.last().map(|m| m.id);
+            out.extend(page);
+            if !more {
+                break;
+            }
+        }
+        let truncated = out.len() > limit || more;
+        out.truncate(limit);
+        out.reverse();
+        Ok((out, truncated))
+    }
 }
 
 #[async_trait]
@@ -86,6 +190,9 @@ impl Connector for DiscordConnector {
         if let Some(token) = &self.token {
             auth.insert("token".to_string(), token.clone());
         }
+        if let Some(allow) = &self.allow_writes {
+            auth.insert("allow_writes".to_string(), allow.clone());
+        }
         Ok(auth)
     }
 
@@ -93,6 +200,7 @@ impl Connector for DiscordConnector {
         if let Some(token) = details.get("token").or(details.get("bot_token")) {
             self.token = Some(token.clone());
             self.http = Some(Arc::new(Http::new(token)));
+            self.allow_writes = details.get("allow_writes").cloned();
             Ok(())
         } else {
             // Maybe it's in env?
@@ -121,14 +229,26 @@ impl Connector for DiscordConnector {
 
     fn config_schema(&self) -> ConnectorConfigSchema {
         ConnectorConfigSchema {
-            fields: vec![Field {
-                name: "token".to_string(),
-                label: "Bot Token".to_string(),
-                field_type: FieldType::Secret,
-                required: true,
-                description: Some("Discord Bot Token".to_string()),
-                options: None,
-            }],
+            fields: vec![
+                Field {
+                    name: "token".to_string(),
+                    label: "Bot Token".to_string(),
+                    field_type: FieldType::Secret,
+                    required: true,
+                    description: Some("Discord Bot Token".to_string()),
+                    options: None,
+                },
+                Field {
+                    name: "allow_writes".to_string(),
+                    label: "Allow Sending".to_string(),
+                    field_type: FieldType::Boolean,
+                    required: false,
+                    description: Some(
+                        "Enable send_message (each call still needs confirm=true)".to_string(),
+                    ),
+                    options: None,
+                },
+            ],
         }
     }
 
@@ -207,7 +327,7 @@ impl Connector for DiscordConnector {
                 name: Cow::Borrowed("read_messages"),
                 title: None,
                 description: Some(Cow::Borrowed(
-                    "Read recent messages in a channel. Use when you want context. Example: channel_id=456 limit=50.",
+                    "Read recent messages in a channel or thread, with attachment URLs. Use when you want context. Example: channel_id=456 limit=50.",
                 )),
                 input_schema: Arc::new(json!({
                     "type": "object",
@@ -225,15 +345,54 @@ impl Connector for DiscordConnector {
                 name: Cow::Borrowed("send_message"),
                 title: None,
                 description: Some(Cow::Borrowed(
-                    "Send a message to a channel. Use when you need to post as the bot. Example: channel_id=456 content=\"hello\".",
+                    "Send a message to a channel or thread as the bot, optionally as a reply. Writes to Discord: disabled unless allow_writes is configured; only call after the user has approved the content, and pass confirm=true. Example: channel_id=456 content=\"hello\" confirm=true.",
                 )),
                 input_schema: Arc::new(json!({
                     "type": "object",
                     "properties": {
-                        "channel_id": { "type": "integer", "description": "ID of the channel" },
-                        "content": { "type": "string", "description": "Message content" }
+                        "channel_id": { "type": "integer", "description": "ID of the channel or thread" },
+                        "content": { "type": "string", "description": "Message content" },
+                        "reply_to_message_id": { "type": "integer", "description": "Reply to this message" },
+                        "confirm": { "type": "boolean", "description": "Must be true; confirms the user approved this message" }
+                    },
+                    "required": ["channel_id", "content", "confirm"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("list_threads"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "List threads: active threads in a server (guild_id, optionally filtered by channel_id), or archived public threads of a channel (channel_id, archived=true).",
+                )),
+                input_schema: Arc::new(json!({
+                    "type": "object",
+                    "properties": {
+                        "guild_id": { "type": "integer", "description": "Server/guild ID (active threads)" },
+                        "channel_id": { "type": "integer", "description": "Parent channel ID" },
+                        "archived": { "type": "boolean", "description": "List archived public threads of channel_id" },
+                        "limit": { "type": "integer", "description": "Maximum archived threads (max 100)" }
+                    }
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_thread_messages"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Read a thread's messages oldest first, paging past 100, with attachment URLs. Example: thread_id=789.",
+                )),
+                input_schema: Arc::new(json!({
+                    "type": "object",
+                    "properties": {
+                        "thread_id": { "type": "integer", "description": "ID of the thread" },
+                        "limit": { "type": "integer", "description": "Maximum messages (default 200, max 1000)" }
                     },
-                    "required": ["channel_id", "content"]
+                    "required": ["thread_id"]
                 }).as_object().expect("Schema object").clone()),
                 output_schema: None,
                 annotations: None,
@@ -360,17 +519,7 @@ impl Connector for DiscordConnector {
                     .await
                     .map_err(|e| ConnectorError::Other(e.to_string()))?;
 
-                let data: Vec<Value> = messages
-                    .iter()
-                    .map(|m| {
-                        json!({
-                            "id": m.id.get(),
-                            "author": m.author.name,
-                            "content": m.content,
-                            "timestamp": m.timestamp.to_rfc3339(),
-                        })
-                    })
-                    .collect();
+                let data: Vec<Value> = messages.iter().map(message_json).collect();
                 Ok(structured_result_with_text(
                     &data,
                     Some(serde_json::to_string(&data)?),
@@ -383,7 +532,23 @@ impl Connector for DiscordConnector {
                 )
                 .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
 
-                let map = json!({ "content": args.content });
+                write_gate(
+                    self.name(),
+                    "ARIVU_DISCORD_ALLOW_WRITES",
+                    self.allow_writes.as_deref(),
+                    args.confirm,
+                )?;
+                let mut map = json!({
+                    "content": args.content,
+                    // Never ping people from an agent-written message
+                    "allowed_mentions": { "parse": [] },
+                });
+                if let Some(reply_to) = args.reply_to_message_id {
+                    map["message_reference"] = json!({
+                        "message_id": reply_to.to_string(),
+                        "fail_if_not_exists": false,
+                    });
+                }
                 // Serenity 0.12: send_message(channel_id, files, map)
                 let msg = http
                     .send_message(
@@ -396,6 +561,7 @@ impl Connector for DiscordConnector {
 
                 let data = json!({
                     "id": msg.id.get(),
+                    "channel_id": msg.channel_id.get(),
                     "content": msg.content,
                     "status": "sent"
                 });
@@ -404,6 +570,85 @@ impl Connector for DiscordConnector {
                     Some(serde_json::to_string(&data)?),
                 )?)
             }
+            "list_threads" => {
+                let args: ListThreadsArgs = serde_json::from_value(
+                    serde_json::to_value(request.arguments.unwrap_or_default())
+                        .map_err(ConnectorError::SerdeJson)?,
+                )
+                .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+
+                let (threads, has_more) = match (args.archived, args.guild_id, args.channel_id) {
+                    (true, _, Some(channel_id)) => {
+                        let data = http
+                            .get_channel_archived_public_threads(
+                                ChannelId::new(channel_id),
+                                None,
+                                Some(args.limit.unwrap_or(50).clamp(1, 100)),
+                            )
+                            .await
+                            .map_err(|e| ConnectorError::Other(e.to_string()))?;
+                        (data.threads, data.has_more)
+                    }
+                    (true, _, None) => {
+                        return Err(ConnectorError::InvalidParams(
+                            "archived=true needs channel_id".to_string(),
+                        ))
+                    }
+                    (false, Some(guild_id), channel_id) => {
+                        let data = http
+                            .get_guild_active_threads(GuildId::new(guild_id))
+                            .await
+                            .map_err(|e| ConnectorError::Other(e.to_string()))?;
+                        let threads = data
+                            .threads
+                            .into_iter()
+                            .filter(|t| {
+                                channel_id.map_or(true, |c| t.parent_id.map(|p| p.get()) == Some(c))
+                            })
+                            .collect();
+                        (threads, false)
+                    }
+                    (false, None, _) => {
+                        return Err(ConnectorError::InvalidParams(
+                            "Provide guild_id for active threads, or channel_id with archived=true"
+                                .to_string(),
+                        ))
+                    }
+                };
+
+                let data = json!({
+                    "threads": threads.iter().map(thread_json).collect::<Vec<_>>(),
+                    "has_more": has_more,
+                });
+                Ok(structured_result_with_text(
+                    &data,
+                    Some(serde_json::to_string(&data)?),
+                )?)
+            }
+            "get_thread_messages" => {
+                let args: GetThreadMessagesArgs = serde_json::from_value(
+                    serde_json::to_value(request.arguments.unwrap_or_default())
+                        .map_err(ConnectorError::SerdeJson)?,
+                )
+                .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+
+                let limit = args
+                    .limit
+                    .unwrap_or(200)
+                    .clamp(1, DISCORD_MAX_THREAD_MESSAGES);
+                let (messages, truncated) = self
+                    .thread_messages(http, ChannelId::new(args.thread_id), limit)
+                    .await?;
+                let data = json!({
+                    "thread_id": args.thread_id,
+                    "messages": messages.iter().map(message_json).collect::<Vec<_>>(),
+                    "truncated": truncated,
+                });
+                Ok(structured_result_with_text(
+                    &data,
+                    Some(serde_json::to_string(&data)?),
+                )?)
+            }
             "search_messages" => {
                 let args: SearchMessagesArgs = serde_json::from_value(
                     serde_json::to_value(request.arguments.unwrap_or_default())
@@ -421,14 +666,7 @@ impl Connector for DiscordConnector {
                 let filtered_messages: Vec<Value> = messages
                     .iter()
                     .filter(|m| m.content.to_lowercase().contains(&query_lower))
-                    .map(|m| {
-                        json!({
-                            "id": m.id.get(),
-                            "author": m.author.name,
-                            "content": m.content,
-                            "timestamp": m.timestamp.to_rfc3339(),
-                        })
-                    })
+                    .map(message_json)
                     .collect();
                 Ok(structured_result_with_text(
                     &json!({"query": args.query, "results": filtered_messages}),
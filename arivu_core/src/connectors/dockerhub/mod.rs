@@ -0,0 +1,726 @@
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+const HUB_API: &str = "https://hub.docker.com/v2";
+const REGISTRY: &str = "https://registry-1.docker.io/v2";
+const AUTH_URL: &str = "https://auth.docker.io/token";
+const MANIFEST_ACCEPT: &str = "application/vnd.oci.image.index.v1+json, \
+application/vnd.docker.distribution.manifest.list.v2+json, \
+application/vnd.oci.image.manifest.v1+json, \
+application/vnd.docker.distribution.manifest.v2+json";
+const MAX_PAGE_SIZE: u32 = 100;
+
+#[derive(Debug, Deserialize)]
+struct SearchArgs {
+    query: String,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    page: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TagsArgs {
+    image: String,
+    #[serde(default)]
+    filter: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    page: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RepositoryArgs {
+    image: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DockerfileArgs {
+    image: String,
+    #[serde(default)]
+    platform: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct ImageRef {
+    namespace: String,
+    repository: String,
+    /// Tag or `sha256:` digest.
+    reference: String,
+}
+
+impl ImageRef {
+    fn path(&self) -> String {
+        format!("{}/{}", self.namespace, self.repository)
+    }
+}
+
+/// Parse `nginx`, `bitnami/redis:7.2`, `docker.io/library/nginx@sha256:…` or a
+/// hub.docker.com URL. Official images live in the `library` namespace.
+fn parse_image(input: &str) -> Result<ImageRef, ConnectorError> {
+    let mut s = input.trim();
+    for prefix in [
+        "https://hub.docker.com/r/",
+        "https://hub.docker.com/_/",
+        "docker.io/",
+        "index.docker.io/",
+        "registry-1.docker.io/",
+    ] {
+        if let Some(rest) = s.strip_prefix(prefix) {
+            s = rest;
+        }
+    }
+    let s = s.trim_end_matches('/');
+
+    let (name, reference) = if let Some((name, digest)) = s.split_once('@') {
+        (name, digest.to_string())
+    } else {
+        match s.rsplit_once(':') {
+            Some((name, tag)) if !tag.contains('/') => (name, tag.to_string()),
+            _ => (s, "latest".to_string()),
+        }
+    };
+    let parts: Vec<&str> = name.split('/').filter(|p| !p.is_empty()).collect();
+    let (namespace, repository) = match parts.as_slice() {
+        [repo] => ("library", *repo),
+        [ns, repo] => (*ns, *repo),
+        _ => {
+            return Err(ConnectorError::InvalidParams(format!(
+                "'{}' is not a Docker Hub image (expected name, namespace/name, optionally :tag)",
+                input
+            )))
+        }
+    };
+    Ok(ImageRef {
+        namespace: namespace.to_lowercase(),
+        repository: repository.to_lowercase(),
+        reference,
+    })
+}
+
+/// Turn image config history into Dockerfile-style instructions.
+fn history_to_dockerfile(history: &[Value]) -> Vec<String> {
+    history
+        .iter()
+        .filter_map(|h| h["created_by"].as_str())
+        .map(|cmd| {
+            let cmd = cmd.trim().trim_end_matches("# buildkit").trim();
+            if let Some(rest) = cmd.strip_prefix("/bin/sh -c #(nop)") {
+                rest.trim().to_string()
+            } else if let Some(rest) = cmd
+                .strip_prefix("/bin/sh -c ")
+                .or_else(|| cmd.strip_prefix("RUN /bin/sh -c "))
+            {
+                format!("RUN {}", rest.trim())
+            } else if let Some(rest) = cmd.strip_prefix("|") {
+                // BuildKit prefixes RUN steps that use build args with `|N ARG=… `.
+                let run = rest.find("/bin/sh -c ").map(|i| &rest[i + 11..]);
+                format!("RUN {}", run.unwrap_or(rest).trim())
+            } else {
+                cmd.to_string()
+            }
+        })
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Pick the manifest for `os/arch[/variant]` from an image index.
+fn select_platform<'a>(manifests: &'a [Value], platform: &str) -> Option<&'a Value> {
+    let mut parts = platform.split('/');
+    let os = parts.next().unwrap_or("linux");
+    let arch = parts.next().unwrap_or("amd64");
+    let variant = parts.next();
+    manifests.iter().find(|m| {
+        let p = &m["platform"];
+        p["os"] == os && p["architecture"] == arch && variant.map_or(true, |v| p["variant"] == v)
+    })
+}
+
+fn available_platforms(manifests: &[Value]) -> Vec<String> {
+    manifests
+        .iter()
+        .filter_map(|m| {
+            let p = &m["platform"];
+            let os = p["os"].as_str()?;
+            let arch = p["architecture"].as_str()?;
+            if os == "unknown" {
+                return None;
+            }
+            Some(match p["variant"].as_str() {
+                Some(v) => format!("{}/{}/{}", os, arch, v),
+                None => format!("{}/{}", os, arch),
+            })
+        })
+        .collect()
+}
+
+pub struct DockerHubConnector {
+    client: Client,
+    username: Option<String>,
+    token: Option<String>,
+}
+
+impl DockerHubConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let pick = |keys: &[&str], env: &str| {
+            keys.iter()
+                .find_map(|k| auth.get(*k).cloned())
+                .or_else(|| std::env::var(env).ok())
+                .filter(|v| !v.trim().is_empty())
+        };
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+        Ok(Self {
+            client,
+            username: pick(&["username"], "DOCKERHUB_USERNAME"),
+            token: pick(&["token", "api_key"], "DOCKERHUB_TOKEN"),
+        })
+    }
+
+    async fn send_json(&self, request: reqwest::RequestBuilder) -> Result<Value, ConnectorError> {
+        let response = request.send().await.map_err(ConnectorError::HttpRequest)?;
+        match response.status() {
+            s if s.is_success() => response.json().await.map_err(ConnectorError::HttpRequest),
+            StatusCode::NOT_FOUND => Err(ConnectorError::ResourceNotFound),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                Err(ConnectorError::Authentication(
+                    "Docker Hub denied access (private repository or invalid token)".to_string(),
+                ))
+            }
+            StatusCode::TOO_MANY_REQUESTS => Err(ConnectorError::Other(
+                "Docker Hub rate limit reached; set a username and access token to raise it"
+                    .to_string(),
+            )),
+            s => Err(ConnectorError::Other(format!(
+                "Docker Hub returned error status: {}",
+                s
+            ))),
+        }
+    }
+
+    async fn hub_get(
+        &self,
+        path: &str,
+        params: &[(&str, String)],
+    ) -> Result<Value, ConnectorError> {
+        self.send_json(
+            self.client
+                .get(format!("{}{}", HUB_API, path))
+                .query(params),
+        )
+        .await
+    }
+
+    /// Pull-scoped bearer token for the registry; anonymous unless credentials are set.
+    async fn registry_token(&self, image: &ImageRef) -> Result<String, ConnectorError> {
+        let mut request = self.client.get(AUTH_URL).query(&[
+            ("service", "registry.docker.io".to_string()),
+            ("scope", format!("repository:{}:pull", image.path())),
+        ]);
+        if let (Some(user), Some(token)) = (&self.username, &self.token) {
+            request = request.basic_auth(user, Some(token));
+        }
+        let body = self.send_json(request).await?;
+        body["token"]
+            .as_str()
+            .or(body["access_token"].as_str())
+            .map(str::to_string)
+            .ok_or_else(|| ConnectorError::Authentication("No registry token returned".to_string()))
+    }
+
+    async fn registry_get(
+        &self,
+        image: &ImageRef,
+        token: &str,
+        path: &str,
+    ) -> Result<Value, ConnectorError> {
+        self.send_json(
+            self.client
+                .get(format!("{}/{}/{}", REGISTRY, image.path(), path))
+                .bearer_auth(token)
+                .header(reqwest::header::ACCEPT, MANIFEST_ACCEPT),
+        )
+        .await
+    }
+
+    async fn search(&self, args: SearchArgs) -> Result<Value, ConnectorError> {
+        let limit = args.limit.unwrap_or(10).clamp(1, MAX_PAGE_SIZE);
+        let page = args.page.unwrap_or(1).max(1);
+        let data = self
+            .hub_get(
+                "/search/repositories/",
+                &[
+                    ("query", args.query.clone()),
+                    ("page_size", limit.to_string()),
+                    ("page", page.to_string()),
+                ],
+            )
+            .await?;
+        let images: Vec<Value> = data["results"]
+            .as_array()
+            .map(|r| {
+                r.iter()
+                    .map(|repo| {
+                        let name = repo["repo_name"].as_str().unwrap_or_default();
+                        let url = if name.contains('/') {
+                            format!("https://hub.docker.com/r/{}", name)
+                        } else {
+                            format!("https://hub.docker.com/_/{}", name)
+                        };
+                        json!({
+                            "name": name,
+                            "description": repo["short_description"],
+                            "stars": repo["star_count"],
+                            "pulls": repo["pull_count"],
+                            "official": repo["is_official"],
+                            "url": url,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(json!({
+            "query": args.query,
+            "total": data["count"],
+            "page": page,
+            "count": images.len(),
+            "images": images,
+        }))
+    }
+
+    async fn list_tags(&self, args: TagsArgs) -> Result<Value, ConnectorError> {
+        let image = parse_image(&args.image)?;
+        let limit = args.limit.unwrap_or(25).clamp(1, MAX_PAGE_SIZE);
+        let page = args.page.unwrap_or(1).max(1);
+        let mut params = vec![
+            ("page_size", limit.to_string()),
+            ("page", page.to_string()),
+            ("ordering", "last_updated".to_string()),
+        ];
+        if let Some(filter) = args.filter {
+            params.push(("name", filter));
+        }
+        let data = self
+            .hub_get(
+                &format!(
+                    "/namespaces/{}/repositories/{}/tags",
+                    image.namespace, image.repository
+                ),
+                &params,
+            )
+            .await?;
+        let tags: Vec<Value> = data["results"]
+            .as_array()
+            .map(|r| {
+                r.iter()
+                    .map(|tag| {
+                        let platforms: Vec<Value> = tag["images"]
+                            .as_array()
+                            .map(|imgs| {
+                                imgs.iter()
+                                    .filter(|i| i["os"] != "unknown")
+                                    .map(|i| {
+                                        json!({
+                                            "os": i["os"],
+                                            "architecture": i["architecture"],
+                                            "variant": i["variant"],
+                                            "digest": i["digest"],
+                                            "size": i["size"],
+                                        })
+                                    })
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+                        json!({
+                            "name": tag["name"],
+                            "digest": tag["digest"],
+                            "full_size": tag["full_size"],
+                            "last_updated": tag["last_updated"],
+                            "platforms": platforms,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(json!({
+            "image": image.path(),
+            "total": data["count"],
+            "page": page,
+            "has_more": data["next"].is_string(),
+            "count": tags.len(),
+            "tags": tags,
+        }))
+    }
+
+    async fn get_repository(&self, args: RepositoryArgs) -> Result<Value, ConnectorError> {
+        let image = parse_image(&args.image)?;
+        let repo = self
+            .hub_get(
+                &format!(
+                    "/namespaces/{}/repositories/{}",
+                    image.namespace, image.repository
+                ),
+                &[],
+            )
+            .await?;
+        Ok(json!({
+            "image": image.path(),
+            "description": repo["description"],
+            "stars": repo["star_count"],
+            "pulls": repo["pull_count"],
+            "last_updated": repo["last_updated"],
+            "registered": repo["date_registered"],
+            "categories": repo["categories"],
+            "private": repo["is_private"],
+            "full_description": repo["full_description"],
+        }))
+    }
+
+    async fn get_dockerfile(&self, args: DockerfileArgs) -> Result<Value, ConnectorError> {
+        let image = parse_image(&args.image)?;
+        let platform = args.platform.unwrap_or_else(|| "linux/amd64".to_string());
+        let token = self.registry_token(&image).await?;
+
+        let mut manifest = self
+            .registry_get(&image, &token, &format!("manifests/{}", image.reference))
+            .await?;
+        let mut platform_digest = Value::Null;
+        if let Some(manifests) = manifest["manifests"].as_array() {
+            let entry = select_platform(manifests, &platform).ok_or_else(|| {
+                ConnectorError::InvalidParams(format!(
+                    "{}:{} has no {} image; available: {}",
+                    image.path(),
+                    image.reference,
+                    platform,
+                    available_platforms(manifests).join(", ")
+                ))
+            })?;
+            platform_digest = entry["digest"].clone();
+            let digest = entry["digest"].as_str().unwrap_or_default().to_string();
+            manifest = self
+                .registry_get(&image, &token, &format!("manifests/{}", digest))
+                .await?;
+        }
+
+        let config_digest = manifest["config"]["digest"].as_str().ok_or_else(|| {
+            ConnectorError::Other("Image manifest has no config blob".to_string())
+        })?;
+        let config = self
+            .registry_get(&image, &token, &format!("blobs/{}", config_digest))
+            .await?;
+        let history = config["history"].as_array().cloned().unwrap_or_default();
+        let instructions = history_to_dockerfile(&history);
+        let c = &config["config"];
+
+        Ok(json!({
+            "image": image.path(),
+            "reference": image.reference,
+            "platform": platform,
+            "digest": platform_digest,
+            "created": config["created"],
+            "layers": manifest["layers"].as_array().map(Vec::len),
+            "config": {
+                "entrypoint": c["Entrypoint"],
+                "cmd": c["Cmd"],
+                "env": c["Env"],
+                "exposed_ports": c["ExposedPorts"].as_object().map(|p| p.keys().cloned().collect::<Vec<_>>()),
+                "working_dir": c["WorkingDir"],
+                "user": c["User"],
+                "labels": c["Labels"],
+            },
+            // Reconstructed from image history; base image layers come first.
+            "dockerfile": instructions.join("\n"),
+        }))
+    }
+}
+
+#[async_trait]
+impl Connector for DockerHubConnector {
+    fn name(&self) -> &'static str {
+        "dockerhub"
+    }
+
+    fn description(&self) -> &'static str {
+        "Docker Hub images: search, tags with digests and sizes, repository descriptions, and Dockerfile reconstruction from image history."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        let mut details = AuthDetails::new();
+        if let Some(username) = &self.username {
+            details.insert("username".to_string(), username.clone());
+        }
+        if let Some(token) = &self.token {
+            details.insert("token".to_string(), token.clone());
+        }
+        Ok(details)
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        *self = Self::new(details).await?;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.registry_token(&parse_image("library/alpine")?).await?;
+        Ok(())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema {
+            fields: vec![
+                Field {
+                    name: "username".to_string(),
+                    label: "Docker Hub username".to_string(),
+                    field_type: FieldType::Text,
+                    required: false,
+                    description: Some(
+                        "Optional; raises pull rate limits and allows private repositories"
+                            .to_string(),
+                    ),
+                    options: None,
+                },
+                Field {
+                    name: "token".to_string(),
+                    label: "Access token".to_string(),
+                    field_type: FieldType::Secret,
+                    required: false,
+                    description: Some(
+                        "Read-only personal access token from Account settings → Personal access tokens"
+                            .to_string(),
+                    ),
+                    options: None,
+                },
+            ],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Use `search_images`, then `list_tags` for tags/digests/sizes, `get_repository` for \
+the README, and `get_dockerfile` to see how an image tag was built. Images look like nginx, \
+bitnami/redis:7.2 or nginx@sha256:…"
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            tool(
+                "search_images",
+                "Search Docker Hub repositories. Example: query=\"postgres\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Search terms" },
+                        "limit": { "type": "integer", "description": "Results per page (default: 10, max: 100)" },
+                        "page": { "type": "integer", "description": "Page number (default: 1)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "list_tags",
+                "List tags, newest first, with digests, sizes and platforms.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "image": { "type": "string", "description": "Image, e.g. nginx or bitnami/redis" },
+                        "filter": { "type": "string", "description": "Only tags containing this text, e.g. alpine" },
+                        "limit": { "type": "integer", "description": "Tags per page (default: 25, max: 100)" },
+                        "page": { "type": "integer", "description": "Page number (default: 1)" }
+                    },
+                    "required": ["image"]
+                }),
+            ),
+            tool(
+                "get_repository",
+                "Repository stats and full description (the README shown on Docker Hub, markdown).",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "image": { "type": "string", "description": "Image, e.g. nginx or bitnami/redis" }
+                    },
+                    "required": ["image"]
+                }),
+            ),
+            tool(
+                "get_dockerfile",
+                "Reconstruct the Dockerfile of an image tag from its layer history, with entrypoint, cmd, env, ports and labels.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "image": { "type": "string", "description": "Image with optional tag or digest, e.g. python:3.12-slim" },
+                        "platform": { "type": "string", "description": "os/arch[/variant] for multi-platform images (default: linux/amd64)" }
+                    },
+                    "required": ["image"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        let data = match request.name.as_ref() {
+            "search_images" => self.search(parse_args(request.arguments)?).await?,
+            "list_tags" => self.list_tags(parse_args(request.arguments)?).await?,
+            "get_repository" => self.get_repository(parse_args(request.arguments)?).await?,
+            "get_dockerfile" => self.get_dockerfile(parse_args(request.arguments)?).await?,
+            _ => return Err(ConnectorError::ToolNotFound),
+        };
+        Ok(structured_result_with_text(&data, None)?)
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(ns: &str, repo: &str, reference: &str) -> ImageRef {
+        ImageRef {
+            namespace: ns.to_string(),
+            repository: repo.to_string(),
+            reference: reference.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_image_references() {
+        assert_eq!(
+            parse_image("nginx").unwrap(),
+            image("library", "nginx", "latest")
+        );
+        assert_eq!(
+            parse_image("bitnami/redis:7.2").unwrap(),
+            image("bitnami", "redis", "7.2")
+        );
+        assert_eq!(
+            parse_image("docker.io/library/alpine@sha256:abc").unwrap(),
+            image("library", "alpine", "sha256:abc")
+        );
+        assert_eq!(
+            parse_image("https://hub.docker.com/_/postgres").unwrap(),
+            image("library", "postgres", "latest")
+        );
+        assert!(parse_image("ghcr.io/owner/repo/sub").is_err());
+    }
+
+    #[test]
+    fn reconstructs_dockerfile_from_history() {
+        let history = vec![
+            json!({ "created_by": "/bin/sh -c #(nop) ADD file:abc in / " }),
+            json!({ "created_by": "/bin/sh -c #(nop)  CMD [\"bash\"]", "empty_layer": true }),
+            json!({ "created_by": "/bin/sh -c apt-get update" }),
+            json!({ "created_by": "ENV PATH=/usr/local/bin:$PATH", "comment": "buildkit.dockerfile.v0" }),
+            json!({ "created_by": "RUN /bin/sh -c pip install x # buildkit" }),
+            json!({ "created_by": "|1 VERSION=3 /bin/sh -c make install" }),
+        ];
+        assert_eq!(
+            history_to_dockerfile(&history),
+            vec![
+                "ADD file:abc in /",
+                "CMD [\"bash\"]",
+                "RUN apt-get update",
+                "ENV PATH=/usr/local/bin:$PATH",
+                "RUN pip install x",
+                "RUN make install",
+            ]
+        );
+    }
+
+    #[test]
+    fn selects_platform_manifest() {
+        let manifests = vec![
+            json!({ "digest": "a", "platform": { "os": "linux", "architecture": "amd64" } }),
+            json!({ "digest": "b", "platform": { "os": "linux", "architecture": "arm64", "variant": "v8" } }),
+            json!({ "digest": "c", "platform": { "os": "unknown", "architecture": "unknown" } }),
+        ];
+        assert_eq!(
+            select_platform(&manifests, "linux/arm64").unwrap()["digest"],
+            "b"
+        );
+        assert_eq!(
+            select_platform(&manifests, "linux/amd64").unwrap()["digest"],
+            "a"
+        );
+        assert!(select_platform(&manifests, "windows/amd64").is_none());
+        assert_eq!(
+            available_platforms(&manifests),
+            vec!["linux/amd64", "linux/arm64/v8"]
+        );
+    }
+}
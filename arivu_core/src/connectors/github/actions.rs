@@ -0,0 +1,188 @@
+// src/connectors/github/actions.rs
+//
+// GitHub Actions runs, jobs and logs trimmed down to what a CI-debugging
+// agent needs: which run failed, which job and step, and the log lines
+// around the error.
+
+use serde_json::{json, Value};
+
+/// Lines that mark a failure in Actions logs.
+const ERROR_MARKERS: &[&str] = &["##[error]", "error:", "error[", "FAILED", "panicked at"];
+const MAX_ERROR_LINES: usize = 50;
+
+pub(super) fn summarize_run(run: &Value) -> Value {
+    json!({
+        "id": run.get("id"),
+        "name": run.get("name"),
+        "workflow_id": run.get("workflow_id"),
+        "run_number": run.get("run_number"),
+        "run_attempt": run.get("run_attempt"),
+        "event": run.get("event"),
+        "head_branch": run.get("head_branch"),
+        "head_sha": run.get("head_sha"),
+        "status": run.get("status"),
+        "conclusion": run.get("conclusion"),
+        "created_at": run.get("created_at"),
+        "updated_at": run.get("updated_at"),
+        "html_url": run.get("html_url"),
+    })
+}
+
+/// A job with the names of the steps that failed.
+pub(super) fn summarize_job(job: &Value) -> Value {
+    let failed_steps: Vec<&str> = job
+        .get("steps")
+        .and_then(|s| s.as_array())
+        .map(|steps| {
+            steps
+                .iter()
+                .filter(|s| s.get("conclusion").and_then(|c| c.as_str()) == Some("failure"))
+                .filter_map(|s| s.get("name").and_then(|n| n.as_str()))
+                .collect()
+        })
+        .unwrap_or_default();
+    json!({
+        "id": job.get("id"),
+        "name": job.get("name"),
+        "status": job.get("status"),
+        "conclusion": job.get("conclusion"),
+        "started_at": job.get("started_at"),
+        "completed_at": job.get("completed_at"),
+        "html_url": job.get("html_url"),
+        "failed_steps": failed_steps,
+    })
+}
+
+/// First job that failed (or was cancelled/timed out) in a run's job list.
+pub(super) fn failing_job_id(jobs: &[Value]) -> Option<u64> {
+    jobs.iter()
+        .find(|j| {
+            matches!(
+                j.get("conclusion").and_then(|c| c.as_str()),
+                Some("failure") | Some("timed_out") | Some("cancelled")
+            )
+        })
+        .and_then(|j| j.get("id"))
+        .and_then(|id| id.as_u64())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct TrimmedLog {
+    pub total_lines: usize,
+    pub error_lines: Vec<String>,
+    pub tail: String,
+    pub truncated: bool,
+}
+
+/// Strip timestamps and ANSI colours, pick out error lines, and keep the
+/// last `tail_lines` lines, capped at `max_bytes`.
+pub(super) fn trim_job_log(log: &str, tail_lines: usize, max_bytes: usize) -> TrimmedLog {
+    let lines: Vec<String> = log
+        .lines()
+        .map(|line| strip_ansi(strip_timestamp(line)))
+        .collect();
+
+    let error_lines: Vec<String> = lines
+        .iter()
+        .filter(|line| ERROR_MARKERS.iter().any(|m| line.contains(m)))
+        .take(MAX_ERROR_LINES)
+        .cloned()
+        .collect();
+
+    let start = lines.len().saturating_sub(tail_lines);
+    let mut tail = lines[start..].join("\n");
+    let mut truncated = start > 0;
+    if tail.len() > max_bytes {
+        // Keep the end of the log; that is where the failure is
+        let mut cut = tail.len() - max_bytes;
+        while !tail.is_char_boundary(cut) {
+            cut += 1;
+        }
+        tail = tail[cut..].to_string();
+        truncated = true;
+    }
+
+    TrimmedLog {
+        total_lines: lines.len(),
+        error_lines,
+        tail,
+        truncated,
+    }
+}
+
+/// "2024-05-01T12:00:00.1234567Z message" → "message"
+fn strip_timestamp(line: &str) -> &str {
+    match line.split_once(' ') {
+        Some((stamp, rest))
+            if stamp.len() >= 20
+                && stamp.ends_with('Z')
+                && stamp.as_bytes().get(10) == Some(&b'T')
+                && stamp
+                    .get(..4)
+                    .is_some_and(|p| p.bytes().all(|b| b.is_ascii_digit())) =>
+        {
+            rest
+        }
+        _ => line,
+    }
+}
+
+fn strip_ansi(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' && chars.peek() == Some(&'[') {
+            chars.next();
+            // Parameters up to the final letter
+            for next in chars.by_ref() {
+                if next.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            continue;
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trims_logs_and_finds_failures() {
+        let log = "2026-10-15T08:00:00.0000000Z ##[group]Run cargo test\n\
+2026-10-15T08:00:01.0000000Z \u{1b}[1m\u{1b}[31merror[E0425]\u{1b}[0m: cannot find value `x`\n\
+2026-10-15T08:00:02.0000000Z ##[error]Process completed with exit code 101.\n\
+2026-10-15T08:00:03.0000000Z Post job cleanup.";
+        let trimmed = trim_job_log(log, 2, 1024);
+        assert_eq!(trimmed.total_lines, 4);
+        assert_eq!(
+            trimmed.error_lines,
+            vec![
+                "error[E0425]: cannot find value `x`",
+                "##[error]Process completed with exit code 101."
+            ]
+        );
+        assert_eq!(
+            trimmed.tail,
+            "##[error]Process completed with exit code 101.\nPost job cleanup."
+        );
+        assert!(trimmed.truncated);
+        assert_eq!(trim_job_log(log, 10, 8).tail, "cleanup.");
+        // A character straddling byte 4 is not a timestamp (and must not panic)
+        let line = "abcé-10-1T08:00:00.0000Z message";
+        assert_eq!(strip_timestamp(line), line);
+
+        let jobs = vec![
+            json!({"id": 1, "conclusion": "success"}),
+            json!({"id": 2, "conclusion": "failure", "steps": [
+                {"name": "Build", "conclusion": "success"},
+                {"name": "Test", "conclusion": "failure"}
+            ]}),
+        ];
+        assert_eq!(failing_job_id(&jobs), Some(2));
+        assert_eq!(summarize_job(&jobs[1])["failed_steps"], json!(["Test"]));
+    }
+}
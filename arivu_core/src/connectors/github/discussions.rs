@@ -0,0 +1,189 @@
+// src/connectors/github/discussions.rs
+//
+// Discussions are only exposed through the GraphQL API. The queries live here
+// together with the flattening of their nested `nodes` into plain JSON.
+
+use serde_json::{json, Value};
+
+pub(super) const LIST_QUERY: &str = r#"
+query($owner: String!, $repo: String!, $first: Int!, $after: String) {
+  repository(owner: $owner, name: $repo) {
+    discussions(first: $first, after: $after, orderBy: {field: UPDATED_AT, direction: DESC}) {
+      totalCount
+      pageInfo { hasNextPage endCursor }
+      nodes {
+        number title url createdAt updatedAt isAnswered upvoteCount
+        author { login }
+        category { name }
+        comments { totalCount }
+      }
+    }
+  }
+}"#;
+
+pub(super) const GET_QUERY: &str = r#"
+query($owner: String!, $repo: String!, $number: Int!, $comments: Int!) {
+  repository(owner: $owner, name: $repo) {
+    discussion(number: $number) {
+      number title url body createdAt updatedAt isAnswered upvoteCount
+      author { login }
+      category { name }
+      answer { id }
+      comments(first: $comments) {
+        totalCount
+        nodes {
+          id body createdAt upvoteCount isAnswer
+          author { login }
+          replies(first: 20) {
+            totalCount
+            nodes { body createdAt author { login } }
+          }
+        }
+      }
+    }
+  }
+}"#;
+
+fn login(node: &Value) -> Value {
+    node.pointer("/author/login")
+        .cloned()
+        .unwrap_or(Value::Null)
+}
+
+/// One page of discussions plus the cursor for the next page.
+pub(super) fn flatten_list(data: &Value) -> Value {
+    let connection = data.pointer("/repository/discussions");
+    let discussions: Vec<Value> = connection
+        .and_then(|c| c.get("nodes"))
+        .and_then(|n| n.as_array())
+        .map(|nodes| {
+            nodes
+                .iter()
+                .map(|d| {
+                    json!({
+                        "number": d.get("number"),
+                        "title": d.get("title"),
+                        "url": d.get("url"),
+                        "author": login(d),
+                        "category": d.pointer("/category/name"),
+                        "is_answered": d.get("isAnswered"),
+                        "upvotes": d.get("upvoteCount"),
+                        "comments": d.pointer("/comments/totalCount"),
+                        "created_at": d.get("createdAt"),
+                        "updated_at": d.get("updatedAt"),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let has_next = connection
+        .and_then(|c| c.pointer("/pageInfo/hasNextPage"))
+        .and_then(|h| h.as_bool())
+        .unwrap_or(false);
+    let next_cursor = if has_next {
+        connection
+            .and_then(|c| c.pointer("/pageInfo/endCursor"))
+            .cloned()
+            .unwrap_or(Value::Null)
+    } else {
+        Value::Null
+    };
+    json!({
+        "total_count": connection.and_then(|c| c.get("totalCount")),
+        "discussions": discussions,
+        "next_cursor": next_cursor,
+    })
+}
+
+/// A discussion with its comments and their replies; `None` when the
+/// number does not exist.
+pub(super) fn flatten_discussion(data: &Value) -> Option<Value> {
+    let d = data
+        .pointer("/repository/discussion")
+        .filter(|d| !d.is_null())?;
+    let answer_id = d.pointer("/answer/id");
+    let comments: Vec<Value> = d
+        .pointer("/comments/nodes")
+        .and_then(|n| n.as_array())
+        .map(|nodes| {
+            nodes
+                .iter()
+                .map(|c| {
+                    let replies: Vec<Value> = c
+                        .pointer("/replies/nodes")
+                        .and_then(|n| n.as_array())
+                        .map(|replies| {
+                            replies
+                                .iter()
+                                .map(|r| {
+                                    json!({
+                                        "author": login(r),
+                                        "body": r.get("body"),
+                                        "created_at": r.get("createdAt"),
+                                    })
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    json!({
+                        "author": login(c),
+                        "body": c.get("body"),
+                        "created_at": c.get("createdAt"),
+                        "upvotes": c.get("upvoteCount"),
+                        "is_answer": c.get("isAnswer").and_then(|a| a.as_bool()).unwrap_or(false)
+                            || (answer_id.is_some() && c.get("id") == answer_id),
+                        "replies": replies,
+                        "reply_count": c.pointer("/replies/totalCount"),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    Some(json!({
+        "number": d.get("number"),
+        "title": d.get("title"),
+        "url": d.get("url"),
+        "author": login(d),
+        "category": d.pointer("/category/name"),
+        "is_answered": d.get("isAnswered"),
+        "upvotes": d.get("upvoteCount"),
+        "created_at": d.get("createdAt"),
+        "updated_at": d.get("updatedAt"),
+        "body": d.get("body"),
+        "comment_count": d.pointer("/comments/totalCount"),
+        "comments": comments,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flattens_discussion_pages() {
+        let list = json!({"repository": {"discussions": {
+            "totalCount": 2,
+            "pageInfo": {"hasNextPage": true, "endCursor": "Y3Vyc29y"},
+            "nodes": [{"number": 7, "title": "Roadmap?", "author": {"login": "ada"},
+                       "category": {"name": "Q&A"}, "comments": {"totalCount": 3}}]
+        }}});
+        let flat = flatten_list(&list);
+        assert_eq!(flat["next_cursor"], "Y3Vyc29y");
+        assert_eq!(flat["discussions"][0]["author"], "ada");
+        assert_eq!(flat["discussions"][0]["category"], "Q&A");
+
+        let one = json!({"repository": {"discussion": {
+            "number": 7, "title": "Roadmap?", "body": "When 1.0?",
+            "author": null, "answer": {"id": "C2"},
+            "comments": {"totalCount": 1, "nodes": [{
+                "id": "C2", "body": "Soon", "author": {"login": "bob"},
+                "replies": {"totalCount": 1, "nodes": [{"body": "Thanks", "author": {"login": "ada"}}]}
+            }]}
+        }}});
+        let flat = flatten_discussion(&one).unwrap();
+        assert_eq!(flat["author"], Value::Null);
+        assert_eq!(flat["comments"][0]["is_answer"], true);
+        assert_eq!(flat["comments"][0]["replies"][0]["author"], "ada");
+        assert!(flatten_discussion(&json!({"repository": {"discussion": null}})).is_none());
+    }
+}
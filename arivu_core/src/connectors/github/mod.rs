@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use base64::Engine as _;
 use rmcp::model::*;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
@@ -9,9 +10,12 @@ use crate::auth::AuthDetails;
 use crate::auth_store::{AuthStore, FileAuthStore};
 use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
 use crate::error::ConnectorError;
-use crate::utils::structured_result_with_text;
+use crate::utils::{structured_result_with_text, write_gate};
 use crate::Connector;
 
+mod actions;
+mod discussions;
+
 #[derive(Clone)]
 pub struct GitHubConnector {
     auth: AuthDetails,
@@ -43,6 +47,77 @@ impl GitHubConnector {
     }
 
     async fn send_with_backoff<F>(&self, build: F) -> Result<Value, ConnectorError>
+    where
+        F: Fn(&reqwest::Client) -> reqwest::RequestBuilder,
+    {
+        self.send_raw_with_backoff(build)
+            .await?
+            .json::<Value>()
+            .await
+            .map_err(ConnectorError::HttpRequest)
+    }
+
+    /// POST a GraphQL query and return its `data`, surfacing `errors`.
+    async fn graphql(
+        &self,
+        token: &str,
+        query: &str,
+        variables: Value,
+    ) -> Result<Value, ConnectorError> {
+        let body = json!({ "query": query, "variables": variables });
+        let v = self
+            .send_with_backoff(|client| {
+                client
+                    .post("https://api.github.com/graphql")
+                    .bearer_auth(token)
+                    .json(&body)
+            })
+            .await?;
+        if let Some(errors) = v.get("errors").and_then(|e| e.as_array()) {
+            let messages: Vec<&str> = errors
+                .iter()
+                .filter_map(|e| e.get("message").and_then(|m| m.as_str()))
+                .collect();
+            if errors
+                .iter()
+                .any(|e| e.get("type").and_then(|t| t.as_str()) == Some("NOT_FOUND"))
+            {
+                return Err(ConnectorError::ResourceNotFound);
+            }
+            return Err(ConnectorError::Other(format!(
+                "GitHub GraphQL error: {}",
+                messages.join("; ")
+            )));
+        }
+        match v.get("data") {
+            Some(data) if !data.is_null() => Ok(data.clone()),
+            _ => Err(api_error("GraphQL query", &v)),
+        }
+    }
+
+    async fn run_jobs(
+        &self,
+        token: &str,
+        owner: &str,
+        repo: &str,
+        run_id: u64,
+    ) -> Result<Vec<Value>, ConnectorError> {
+        let url = format!(
+            "https://api.github.com/repos/{}/{}/actions/runs/{}/jobs?per_page=100",
+            owner, repo, run_id
+        );
+        let v = self
+            .send_with_backoff(|client| client.get(&url).bearer_auth(token))
+            .await?;
+        match v.get("jobs").and_then(|j| j.as_array()) {
+            Some(jobs) => Ok(jobs.clone()),
+            None => Err(api_error("list jobs", &v)),
+        }
+    }
+
+    /// Like `send_with_backoff`, but hands back the response for non-JSON
+    /// bodies (diffs, job logs).
+    async fn send_raw_with_backoff<F>(&self, build: F) -> Result<reqwest::Response, ConnectorError>
     where
         F: Fn(&reqwest::Client) -> reqwest::RequestBuilder,
     {
@@ -86,11 +161,7 @@ impl GitHubConnector {
                         delay_ms = (delay_ms as f64 * 1.6) as u64;
                         continue;
                     }
-                    let v = r
-                        .json::<Value>()
-                        .await
-                        .map_err(ConnectorError::HttpRequest)?;
-                    return Ok(v);
+                    return Ok(r);
                 }
                 Err(e) => {
                     if attempt == MAX_RETRIES {
@@ -170,6 +241,30 @@ struct CodeSearchInput {
     response_format: ResponseFormat,
 }
 
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SearchCodeInput {
+    #[serde(default)]
+    query: String,
+    #[serde(default)]
+    repo: Option<String>,
+    #[serde(default)]
+    org: Option<String>,
+    #[serde(default)]
+    user: Option<String>,
+    #[serde(default)]
+    path: Option<String>,
+    #[serde(default)]
+    language: Option<String>,
+    #[serde(default)]
+    filename: Option<String>,
+    #[serde(default)]
+    extension: Option<String>,
+    #[serde(default)]
+    per_page: Option<u8>,
+    #[serde(default)]
+    page: Option<u32>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct RepoSearchInput {
     query: String,
@@ -194,6 +289,274 @@ struct GetFileInput {
     path: String,
     #[serde(default)]
     r#ref: Option<String>,
+    #[serde(default)]
+    max_kb: Option<usize>,
+}
+
+const DEFAULT_FILE_KB: usize = 256;
+const DEFAULT_LOG_TAIL_LINES: usize = 200;
+const DEFAULT_LOG_KB: usize = 64;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ListRunsInput {
+    owner: String,
+    repo: String,
+    #[serde(default)]
+    branch: Option<String>,
+    /// queued, in_progress, completed, success, failure, ...
+    #[serde(default)]
+    status: Option<String>,
+    #[serde(default)]
+    event: Option<String>,
+    #[serde(default)]
+    per_page: Option<u8>,
+    #[serde(default)]
+    page: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CreateIssueInput {
+    owner: String,
+    repo: String,
+    title: String,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    assignees: Vec<String>,
+    #[serde(default)]
+    confirm: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AddCommentInput {
+    owner: String,
+    repo: String,
+    /// Issue or pull request number
+    number: u64,
+    body: String,
+    #[serde(default)]
+    confirm: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ListDiscussionsInput {
+    owner: String,
+    repo: String,
+    #[serde(default)]
+    per_page: Option<u8>,
+    #[serde(default)]
+    cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetDiscussionInput {
+    owner: String,
+    repo: String,
+    number: u64,
+    #[serde(default)]
+    max_comments: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ListReleasesInput {
+    owner: String,
+    repo: String,
+    #[serde(default)]
+    per_page: Option<u8>,
+    #[serde(default)]
+    page: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetReleaseInput {
+    owner: String,
+    repo: String,
+    /// Tag name; omitted means the latest published release
+    #[serde(default)]
+    tag: Option<String>,
+    #[serde(default)]
+    release_id: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetRunInput {
+    owner: String,
+    repo: String,
+    run_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetJobLogsInput {
+    owner: String,
+    repo: String,
+    #[serde(default)]
+    job_id: Option<u64>,
+    #[serde(default)]
+    run_id: Option<u64>,
+    #[serde(default)]
+    tail_lines: Option<usize>,
+    #[serde(default)]
+    max_kb: Option<usize>,
+}
+
+fn api_error(action: &str, v: &Value) -> ConnectorError {
+    match v.get("message").and_then(|m| m.as_str()) {
+        Some("Not Found") => ConnectorError::ResourceNotFound,
+        Some(message) => ConnectorError::Other(format!("GitHub {} failed: {}", action, message)),
+        None => ConnectorError::Other(format!("GitHub {} failed: unexpected response", action)),
+    }
+}
+
+/// Release metadata with its assets; the notes are included on request.
+fn summarize_release(release: &Value, with_body: bool) -> Value {
+    let assets: Vec<Value> = release
+        .get("assets")
+        .and_then(|a| a.as_array())
+        .map(|assets| {
+            assets
+                .iter()
+                .map(|a| {
+                    json!({
+                        "name": a.get("name"),
+                        "size": a.get("size"),
+                        "content_type": a.get("content_type"),
+                        "download_count": a.get("download_count"),
+                        "browser_download_url": a.get("browser_download_url"),
+                        "updated_at": a.get("updated_at"),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let mut out = json!({
+        "id": release.get("id"),
+        "tag_name": release.get("tag_name"),
+        "name": release.get("name"),
+        "draft": release.get("draft"),
+        "prerelease": release.get("prerelease"),
+        "target_commitish": release.get("target_commitish"),
+        "author": release.pointer("/author/login"),
+        "created_at": release.get("created_at"),
+        "published_at": release.get("published_at"),
+        "html_url": release.get("html_url"),
+        "assets": assets,
+    });
+    if with_body {
+        out["body"] = release.get("body").cloned().unwrap_or(Value::Null);
+    }
+    out
+}
+
+/// Search terms plus GitHub qualifiers (`repo:`, `org:`, `path:`, ...).
+/// Values with spaces are quoted.
+fn build_code_query(input: &SearchCodeInput) -> Result<String, ConnectorError> {
+    let mut parts: Vec<String> = Vec::new();
+    if !input.query.trim().is_empty() {
+        parts.push(input.query.trim().to_string());
+    }
+    let qualifiers = [
+        ("repo", &input.repo),
+        ("org", &input.org),
+        ("user", &input.user),
+        ("path", &input.path),
+        ("language", &input.language),
+        ("filename", &input.filename),
+        ("extension", &input.extension),
+    ];
+    for (name, value) in qualifiers {
+        let Some(value) = value.as_deref().map(str::trim).filter(|v| !v.is_empty()) else {
+            continue;
+        };
+        if value.contains(' ') {
+            parts.push(format!("{}:\"{}\"", name, value.replace('"', "")));
+        } else {
+            parts.push(format!("{}:{}", name, value));
+        }
+    }
+    if input.query.trim().is_empty() && input.filename.is_none() && input.extension.is_none() {
+        return Err(ConnectorError::InvalidParams(
+            "search_code needs search terms in 'query' (or a filename/extension to match)"
+                .to_string(),
+        ));
+    }
+    Ok(parts.join(" "))
+}
+
+/// Path, repository and matched fragments of each code search hit
+/// (fragments come from the text-match media type).
+fn summarize_code_hits(items: &[Value]) -> Vec<Value> {
+    items
+        .iter()
+        .map(|item| {
+            let fragments: Vec<&str> = item
+                .get("text_matches")
+                .and_then(|m| m.as_array())
+                .map(|matches| {
+                    matches
+                        .iter()
+                        .filter(|m| m.get("property").and_then(|p| p.as_str()) == Some("content"))
+                        .filter_map(|m| m.get("fragment").and_then(|f| f.as_str()))
+                        .collect()
+                })
+                .unwrap_or_default();
+            json!({
+                "path": item.get("path"),
+                "repository": item.pointer("/repository/full_name"),
+                "sha": item.get("sha"),
+                "html_url": item.get("html_url"),
+                "fragments": fragments,
+            })
+        })
+        .collect()
+}
+
+/// Always listed so clients can discover them; calls are rejected until
+/// writes are enabled.
+fn write_tools() -> Vec<Tool> {
+    vec![
+        Tool {
+            name: Cow::Borrowed("create_issue"),
+            title: None,
+            description: Some(Cow::Borrowed("Create an issue. Writes to GitHub: disabled unless allow_writes is configured; only call after the user has approved the title and body, and pass confirm=true.")),
+            input_schema: Arc::new(json!({
+                "type":"object",
+                "properties":{
+                    "owner":{"type":"string"},
+                    "repo":{"type":"string"},
+                    "title":{"type":"string"},
+                    "body":{"type":"string","description":"Markdown body"},
+                    "labels":{"type":"array","items":{"type":"string"}},
+                    "assignees":{"type":"array","items":{"type":"string"}},
+                    "confirm":{"type":"boolean","description":"Must be true; confirms the user approved this write"}
+                },
+                "required":["owner","repo","title","confirm"]
+            }).as_object().expect("Schema object").clone()),
+            output_schema: None,
+            annotations: None,
+            icons: None,
+        },
+        Tool {
+            name: Cow::Borrowed("add_comment"),
+            title: None,
+            description: Some(Cow::Borrowed("Comment on an issue or pull request. Writes to GitHub: disabled unless allow_writes is configured; only call after the user has approved the text, and pass confirm=true.")),
+            input_schema: Arc::new(json!({
+                "type":"object",
+                "properties":{
+                    "owner":{"type":"string"},
+                    "repo":{"type":"string"},
+                    "number":{"type":"integer","description":"Issue or pull request number"},
+                    "body":{"type":"string","description":"Markdown comment"},
+                    "confirm":{"type":"boolean","description":"Must be true; confirms the user approved this write"}
+                },
+                "required":["owner","repo","number","body","confirm"]
+            }).as_object().expect("Schema object").clone()),
+            output_schema: None,
+            annotations: None,
+            icons: None,
+        },
+    ]
 }
 
 #[async_trait]
@@ -203,7 +566,7 @@ impl Connector for GitHubConnector {
     }
 
     fn description(&self) -> &'static str {
-        "GitHub issues/PRs/discussions, code search, and file fetch (read-only)."
+        "GitHub issues/PRs/discussions, code search, and file fetch (read-only by default; opt-in issue and comment writes)."
     }
 
     async fn capabilities(&self) -> ServerCapabilities {
@@ -249,7 +612,7 @@ impl Connector for GitHubConnector {
         &self,
         _request: Option<PaginatedRequestParam>,
     ) -> Result<ListToolsResult, ConnectorError> {
-        let tools = vec![
+        let mut tools = vec![
             Tool {
                 name: Cow::Borrowed("test_auth"),
                 title: None,
@@ -404,6 +767,159 @@ impl Connector for GitHubConnector {
                 annotations: None,
                 icons: None,
             },
+            Tool {
+                name: Cow::Borrowed("search_code"),
+                title: None,
+                description: Some(Cow::Borrowed("Search code with structured filters (repo, org, path, language). Returns file path, repository and the matched fragments; pass owner/repo/path to get_file for the whole file.")),
+                input_schema: Arc::new(json!({
+                    "type":"object",
+                    "properties":{
+                        "query":{"type":"string","description":"Search terms (e.g., 'tokio::spawn'); qualifiers may also be written inline"},
+                        "repo":{"type":"string","description":"Restrict to one repository, owner/name"},
+                        "org":{"type":"string","description":"Restrict to an organization's repositories"},
+                        "user":{"type":"string","description":"Restrict to a user's repositories"},
+                        "path":{"type":"string","description":"Restrict to files under this path (e.g., 'src/connectors')"},
+                        "language":{"type":"string","description":"Language (e.g., 'rust')"},
+                        "filename":{"type":"string","description":"Match file name (e.g., 'Cargo.toml')"},
+                        "extension":{"type":"string","description":"Match file extension without the dot (e.g., 'rs')"},
+                        "per_page":{"type":"integer","minimum":1,"maximum":100,"description":"Results per page (default 20)"},
+                        "page":{"type":"integer","minimum":1}
+                    }
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("list_workflow_runs"),
+                title: None,
+                description: Some(Cow::Borrowed("List GitHub Actions workflow runs for a repository, newest first, optionally for one branch/status/event. Returns id, workflow name, branch, sha, status and conclusion.")),
+                input_schema: Arc::new(json!({
+                    "type":"object",
+                    "properties":{
+                        "owner":{"type":"string"},
+                        "repo":{"type":"string"},
+                        "branch":{"type":"string"},
+                        "status":{"type":"string","description":"queued, in_progress, completed, or a conclusion such as success/failure/cancelled"},
+                        "event":{"type":"string","description":"Triggering event, e.g. push or pull_request"},
+                        "per_page":{"type":"integer","minimum":1,"maximum":100,"description":"Default 20"},
+                        "page":{"type":"integer","minimum":1}
+                    },
+                    "required":["owner","repo"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_workflow_run"),
+                title: None,
+                description: Some(Cow::Borrowed("Get a workflow run's status and conclusion with its jobs and their failed steps. Follow with get_job_logs for a failing job.")),
+                input_schema: Arc::new(json!({
+                    "type":"object",
+                    "properties":{
+                        "owner":{"type":"string"},
+                        "repo":{"type":"string"},
+                        "run_id":{"type":"integer"}
+                    },
+                    "required":["owner","repo","run_id"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_job_logs"),
+                title: None,
+                description: Some(Cow::Borrowed("Download a job's log, trimmed: error lines plus the last lines of output, without timestamps or colour codes. Pass job_id, or run_id to pick the run's first failing job.")),
+                input_schema: Arc::new(json!({
+                    "type":"object",
+                    "properties":{
+                        "owner":{"type":"string"},
+                        "repo":{"type":"string"},
+                        "job_id":{"type":"integer"},
+                        "run_id":{"type":"integer","description":"Used when job_id is omitted: the first failed job of this run"},
+                        "tail_lines":{"type":"integer","minimum":1,"description":"Lines kept from the end of the log (default 200)"},
+                        "max_kb":{"type":"integer","minimum":1,"description":"Cap on the returned tail in KB (default 64)"}
+                    },
+                    "required":["owner","repo"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("list_discussions"),
+                title: None,
+                description: Some(Cow::Borrowed("List a repository's GitHub Discussions, most recently updated first (GraphQL). Returns number, title, category, answered state and comment count; page with next_cursor.")),
+                input_schema: Arc::new(json!({
+                    "type":"object",
+                    "properties":{
+                        "owner":{"type":"string"},
+                        "repo":{"type":"string"},
+                        "per_page":{"type":"integer","minimum":1,"maximum":100,"description":"Default 20"},
+                        "cursor":{"type":"string","description":"next_cursor from a previous call"}
+                    },
+                    "required":["owner","repo"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_discussion"),
+                title: None,
+                description: Some(Cow::Borrowed("Get a discussion by number with its body, comments (answer marked) and replies.")),
+                input_schema: Arc::new(json!({
+                    "type":"object",
+                    "properties":{
+                        "owner":{"type":"string"},
+                        "repo":{"type":"string"},
+                        "number":{"type":"integer"},
+                        "max_comments":{"type":"integer","minimum":1,"maximum":100,"description":"Default 50"}
+                    },
+                    "required":["owner","repo","number"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("list_releases"),
+                title: None,
+                description: Some(Cow::Borrowed("List releases newest first with tag, dates, prerelease flag and asset metadata (name, size, downloads, URL).")),
+                input_schema: Arc::new(json!({
+                    "type":"object",
+                    "properties":{
+                        "owner":{"type":"string"},
+                        "repo":{"type":"string"},
+                        "per_page":{"type":"integer","minimum":1,"maximum":100,"description":"Default 20"},
+                        "page":{"type":"integer","minimum":1}
+                    },
+                    "required":["owner","repo"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_release"),
+                title: None,
+                description: Some(Cow::Borrowed("Get one release with its notes and assets, by tag, by release_id, or the latest release when neither is given.")),
+                input_schema: Arc::new(json!({
+                    "type":"object",
+                    "properties":{
+                        "owner":{"type":"string"},
+                        "repo":{"type":"string"},
+                        "tag":{"type":"string","description":"Tag name, e.g. v1.2.0"},
+                        "release_id":{"type":"integer"}
+                    },
+                    "required":["owner","repo"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
             Tool {
                 name: Cow::Borrowed("search_repositories"),
                 title: None,
@@ -443,14 +959,15 @@ impl Connector for GitHubConnector {
             Tool {
                 name: Cow::Borrowed("get_file"),
                 title: None,
-                description: Some(Cow::Borrowed("Get file contents by path/ref (base64 decoding when text).")),
+                description: Some(Cow::Borrowed("Get file contents at a branch, tag or commit, decoded as text (binary files report size only). A directory path lists its entries.")),
                 input_schema: Arc::new(json!({
                     "type":"object",
                     "properties":{
                         "owner":{"type":"string"},
                         "repo":{"type":"string"},
                         "path":{"type":"string"},
-                        "ref":{"type":"string"}
+                        "ref":{"type":"string","description":"Branch, tag or commit SHA (default: the default branch)"},
+                        "max_kb":{"type":"integer","description":"Max text to return in KB (default 256)"}
                     },
                     "required":["owner","repo","path"]
                 }).as_object().expect("Schema object").clone()),
@@ -459,6 +976,7 @@ impl Connector for GitHubConnector {
                 icons: None,
             },
         ];
+        tools.extend(write_tools());
         Ok(ListToolsResult {
             tools,
             next_cursor: None,
@@ -687,6 +1205,347 @@ impl Connector for GitHubConnector {
                     )
                 }
             }
+            "search_code" => {
+                let input: SearchCodeInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                let query = build_code_query(&input)?;
+                let token = self.resolve_token().ok_or_else(|| {
+                    ConnectorError::Authentication("GitHub token not configured".to_string())
+                })?;
+                let per_page = input.per_page.unwrap_or(20).clamp(1, 100).to_string();
+                let page = input.page.unwrap_or(1).max(1).to_string();
+                let v = self
+                    .send_with_backoff(|client| {
+                        client
+                            .get("https://api.github.com/search/code")
+                            .query(&[
+                                ("q", query.as_str()),
+                                ("per_page", per_page.as_str()),
+                                ("page", page.as_str()),
+                            ])
+                            .header(
+                                reqwest::header::ACCEPT,
+                                "application/vnd.github.text-match+json",
+                            )
+                            .bearer_auth(&token)
+                    })
+                    .await?;
+                let Some(items) = v.get("items").and_then(|i| i.as_array()) else {
+                    let message = v
+                        .get("message")
+                        .and_then(|m| m.as_str())
+                        .unwrap_or("unexpected response");
+                    return Err(ConnectorError::Other(format!(
+                        "GitHub code search failed: {}",
+                        message
+                    )));
+                };
+                structured_result_with_text(
+                    &json!({
+                        "query": query,
+                        "total_count": v.get("total_count"),
+                        "incomplete_results": v.get("incomplete_results"),
+                        "items": summarize_code_hits(items),
+                    }),
+                    None,
+                )
+            }
+            "list_workflow_runs" => {
+                let input: ListRunsInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                let token = self.resolve_token().ok_or_else(|| {
+                    ConnectorError::Authentication("GitHub token not configured".to_string())
+                })?;
+                let url = format!(
+                    "https://api.github.com/repos/{}/{}/actions/runs",
+                    input.owner, input.repo
+                );
+                let mut query: Vec<(&str, String)> = vec![
+                    (
+                        "per_page",
+                        input.per_page.unwrap_or(20).clamp(1, 100).to_string(),
+                    ),
+                    ("page", input.page.unwrap_or(1).max(1).to_string()),
+                ];
+                for (key, value) in [
+                    ("branch", &input.branch),
+                    ("status", &input.status),
+                    ("event", &input.event),
+                ] {
+                    if let Some(value) = value {
+                        query.push((key, value.clone()));
+                    }
+                }
+                let v = self
+                    .send_with_backoff(|client| client.get(&url).query(&query).bearer_auth(&token))
+                    .await?;
+                let Some(runs) = v.get("workflow_runs").and_then(|r| r.as_array()) else {
+                    return Err(api_error("list workflow runs", &v));
+                };
+                let runs: Vec<Value> = runs.iter().map(actions::summarize_run).collect();
+                structured_result_with_text(
+                    &json!({"total_count": v.get("total_count"), "runs": runs}),
+                    None,
+                )
+            }
+            "get_workflow_run" => {
+                let input: GetRunInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                let token = self.resolve_token().ok_or_else(|| {
+                    ConnectorError::Authentication("GitHub token not configured".to_string())
+                })?;
+                let url = format!(
+                    "https://api.github.com/repos/{}/{}/actions/runs/{}",
+                    input.owner, input.repo, input.run_id
+                );
+                let run = self
+                    .send_with_backoff(|client| client.get(&url).bearer_auth(&token))
+                    .await?;
+                if run.get("id").is_none() {
+                    return Err(api_error("get workflow run", &run));
+                }
+                let jobs = self
+                    .run_jobs(&token, &input.owner, &input.repo, input.run_id)
+                    .await?;
+                let mut out = actions::summarize_run(&run);
+                out["jobs"] = json!(jobs.iter().map(actions::summarize_job).collect::<Vec<_>>());
+                out["failing_job_id"] = json!(actions::failing_job_id(&jobs));
+                structured_result_with_text(&out, None)
+            }
+            "get_job_logs" => {
+                let input: GetJobLogsInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                let token = self.resolve_token().ok_or_else(|| {
+                    ConnectorError::Authentication("GitHub token not configured".to_string())
+                })?;
+                let job_id = match (input.job_id, input.run_id) {
+                    (Some(job_id), _) => job_id,
+                    (None, Some(run_id)) => {
+                        let jobs = self
+                            .run_jobs(&token, &input.owner, &input.repo, run_id)
+                            .await?;
+                        actions::failing_job_id(&jobs).ok_or_else(|| {
+                            ConnectorError::InvalidParams(format!(
+                                "Run {} has no failed jobs; pass job_id to read another job's log",
+                                run_id
+                            ))
+                        })?
+                    }
+                    (None, None) => {
+                        return Err(ConnectorError::InvalidParams(
+                            "Provide job_id or run_id".to_string(),
+                        ))
+                    }
+                };
+                // Redirects to a short-lived plain-text download
+                let url = format!(
+                    "https://api.github.com/repos/{}/{}/actions/jobs/{}/logs",
+                    input.owner, input.repo, job_id
+                );
+                let resp = self
+                    .send_raw_with_backoff(|client| client.get(&url).bearer_auth(&token))
+                    .await?;
+                let status = resp.status();
+                if status == reqwest::StatusCode::NOT_FOUND || status == reqwest::StatusCode::GONE {
+                    return Err(ConnectorError::ResourceNotFound);
+                }
+                if !status.is_success() {
+                    return Err(ConnectorError::Other(format!(
+                        "GitHub job log download failed: HTTP {}",
+                        status
+                    )));
+                }
+                let log = resp.text().await.map_err(ConnectorError::HttpRequest)?;
+                let trimmed = actions::trim_job_log(
+                    &log,
+                    input.tail_lines.unwrap_or(DEFAULT_LOG_TAIL_LINES).max(1),
+                    input.max_kb.unwrap_or(DEFAULT_LOG_KB).max(1) * 1024,
+                );
+                let text = if trimmed.error_lines.is_empty() {
+                    trimmed.tail.clone()
+                } else {
+                    format!(
+                        "Errors:\n{}\n\nLast lines:\n{}",
+                        trimmed.error_lines.join("\n"),
+                        trimmed.tail
+                    )
+                };
+                structured_result_with_text(
+                    &json!({
+                        "job_id": job_id,
+                        "total_lines": trimmed.total_lines,
+                        "error_lines": trimmed.error_lines,
+                        "tail": trimmed.tail,
+                        "truncated": trimmed.truncated,
+                    }),
+                    Some(text),
+                )
+            }
+            "create_issue" => {
+                let input: CreateIssueInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                write_gate(
+                    self.name(),
+                    "ARIVU_GITHUB_ALLOW_WRITES",
+                    self.auth.get("allow_writes").map(String::as_str),
+                    input.confirm,
+                )?;
+                let token = self.resolve_token().ok_or_else(|| {
+                    ConnectorError::Authentication("GitHub token not configured".to_string())
+                })?;
+                let url = format!(
+                    "https://api.github.com/repos/{}/{}/issues",
+                    input.owner, input.repo
+                );
+                let mut body = json!({ "title": input.title });
+                if let Some(text) = &input.body {
+                    body["body"] = json!(text);
+                }
+                if !input.labels.is_empty() {
+                    body["labels"] = json!(input.labels);
+                }
+                if !input.assignees.is_empty() {
+                    body["assignees"] = json!(input.assignees);
+                }
+                let v = self
+                    .send_with_backoff(|client| client.post(&url).bearer_auth(&token).json(&body))
+                    .await?;
+                if v.get("number").is_none() {
+                    return Err(api_error("create issue", &v));
+                }
+                structured_result_with_text(
+                    &json!({"number": v.get("number"), "html_url": v.get("html_url"), "state": v.get("state")}),
+                    None,
+                )
+            }
+            "add_comment" => {
+                let input: AddCommentInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                write_gate(
+                    self.name(),
+                    "ARIVU_GITHUB_ALLOW_WRITES",
+                    self.auth.get("allow_writes").map(String::as_str),
+                    input.confirm,
+                )?;
+                if input.body.trim().is_empty() {
+                    return Err(ConnectorError::InvalidParams(
+                        "body must not be empty".to_string(),
+                    ));
+                }
+                let token = self.resolve_token().ok_or_else(|| {
+                    ConnectorError::Authentication("GitHub token not configured".to_string())
+                })?;
+                // Pull requests share the issue comments endpoint
+                let url = format!(
+                    "https://api.github.com/repos/{}/{}/issues/{}/comments",
+                    input.owner, input.repo, input.number
+                );
+                let body = json!({ "body": input.body });
+                let v = self
+                    .send_with_backoff(|client| client.post(&url).bearer_auth(&token).json(&body))
+                    .await?;
+                if v.get("id").is_none() {
+                    return Err(api_error("add comment", &v));
+                }
+                structured_result_with_text(
+                    &json!({"id": v.get("id"), "html_url": v.get("html_url")}),
+                    None,
+                )
+            }
+            "list_discussions" => {
+                let input: ListDiscussionsInput =
+                    serde_json::from_value(Value::Object(args_map))
+                        .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                let token = self.resolve_token().ok_or_else(|| {
+                    ConnectorError::Authentication("GitHub token not configured".to_string())
+                })?;
+                let data = self
+                    .graphql(
+                        &token,
+                        discussions::LIST_QUERY,
+                        json!({
+                            "owner": input.owner,
+                            "repo": input.repo,
+                            "first": input.per_page.unwrap_or(20).clamp(1, 100),
+                            "after": input.cursor,
+                        }),
+                    )
+                    .await?;
+                if data.get("repository").map_or(true, Value::is_null) {
+                    return Err(ConnectorError::ResourceNotFound);
+                }
+                structured_result_with_text(&discussions::flatten_list(&data), None)
+            }
+            "get_discussion" => {
+                let input: GetDiscussionInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                let token = self.resolve_token().ok_or_else(|| {
+                    ConnectorError::Authentication("GitHub token not configured".to_string())
+                })?;
+                let data = self
+                    .graphql(
+                        &token,
+                        discussions::GET_QUERY,
+                        json!({
+                            "owner": input.owner,
+                            "repo": input.repo,
+                            "number": input.number,
+                            "comments": input.max_comments.unwrap_or(50).clamp(1, 100),
+                        }),
+                    )
+                    .await?;
+                let discussion = discussions::flatten_discussion(&data)
+                    .ok_or(ConnectorError::ResourceNotFound)?;
+                structured_result_with_text(&discussion, None)
+            }
+            "list_releases" => {
+                let input: ListReleasesInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                let token = self.resolve_token().ok_or_else(|| {
+                    ConnectorError::Authentication("GitHub token not configured".to_string())
+                })?;
+                let url = format!(
+                    "https://api.github.com/repos/{}/{}/releases?per_page={}&page={}",
+                    input.owner,
+                    input.repo,
+                    input.per_page.unwrap_or(20).clamp(1, 100),
+                    input.page.unwrap_or(1).max(1)
+                );
+                let v = self
+                    .send_with_backoff(|client| client.get(&url).bearer_auth(&token))
+                    .await?;
+                let Some(releases) = v.as_array() else {
+                    return Err(api_error("list releases", &v));
+                };
+                let releases: Vec<Value> = releases
+                    .iter()
+                    .map(|r| summarize_release(r, false))
+                    .collect();
+                structured_result_with_text(&json!({ "releases": releases }), None)
+            }
+            "get_release" => {
+                let input: GetReleaseInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                let token = self.resolve_token().ok_or_else(|| {
+                    ConnectorError::Authentication("GitHub token not configured".to_string())
+                })?;
+                let which = match (&input.release_id, &input.tag) {
+                    (Some(id), _) => id.to_string(),
+                    (None, Some(tag)) => format!("tags/{}", urlencoding::encode(tag)),
+                    (None, None) => "latest".to_string(),
+                };
+                let url = format!(
+                    "https://api.github.com/repos/{}/{}/releases/{}",
+                    input.owner, input.repo, which
+                );
+                let v = self
+                    .send_with_backoff(|client| client.get(&url).bearer_auth(&token))
+                    .await?;
+                if v.get("tag_name").is_none() {
+                    return Err(api_error("get release", &v));
+                }
+                structured_result_with_text(&summarize_release(&v, true), None)
+            }
             "search_repositories" => {
                 let input: RepoSearchInput = serde_json::from_value(Value::Object(args_map))
                     .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
@@ -805,17 +1664,80 @@ impl Connector for GitHubConnector {
                 let token = self.resolve_token().ok_or_else(|| {
                     ConnectorError::Authentication("GitHub token not configured".to_string())
                 })?;
+                let path = input
+                    .path
+                    .trim_matches('/')
+                    .split('/')
+                    .map(|segment| urlencoding::encode(segment).into_owned())
+                    .collect::<Vec<_>>()
+                    .join("/");
                 let mut url = format!(
                     "https://api.github.com/repos/{}/{}/contents/{}",
-                    input.owner, input.repo, input.path
+                    input.owner, input.repo, path
                 );
-                if let Some(reference) = input.r#ref {
-                    url.push_str(&format!("?ref={}", reference));
+                if let Some(reference) = &input.r#ref {
+                    url.push_str(&format!("?ref={}", urlencoding::encode(reference)));
                 }
                 let v = self
                     .send_with_backoff(|client| client.get(&url).bearer_auth(&token))
                     .await?;
-                structured_result_with_text(&v, None)
+
+                // Directories come back as an array of entries
+                if let Some(entries) = v.as_array() {
+                    let entries: Vec<Value> = entries
+                        .iter()
+                        .map(|e| json!({"name": e.get("name"), "path": e.get("path"), "type": e.get("type"), "size": e.get("size")}))
+                        .collect();
+                    return structured_result_with_text(
+                        &json!({"path": input.path, "ref": input.r#ref, "type": "dir", "entries": entries}),
+                        None,
+                    );
+                }
+                if v.get("type").and_then(|t| t.as_str()) != Some("file") {
+                    let message = v
+                        .get("message")
+                        .and_then(|m| m.as_str())
+                        .unwrap_or("not a file");
+                    return Err(ConnectorError::Other(format!(
+                        "GitHub get_file failed: {}",
+                        message
+                    )));
+                }
+
+                // Base64 with embedded newlines; files over 1 MB arrive without content
+                let encoded: String = v
+                    .get("content")
+                    .and_then(|c| c.as_str())
+                    .unwrap_or_default()
+                    .split_whitespace()
+                    .collect();
+                let bytes = base64::engine::general_purpose::STANDARD
+                    .decode(encoded)
+                    .map_err(|e| ConnectorError::Other(format!("Invalid file encoding: {}", e)))?;
+                let max_bytes = input.max_kb.unwrap_or(DEFAULT_FILE_KB).max(1) * 1024;
+                let mut out = json!({
+                    "path": v.get("path"),
+                    "ref": input.r#ref,
+                    "sha": v.get("sha"),
+                    "size": v.get("size"),
+                    "html_url": v.get("html_url"),
+                });
+                match String::from_utf8(bytes) {
+                    Ok(mut text) => {
+                        let truncated = text.len() > max_bytes;
+                        if truncated {
+                            let mut cut = max_bytes;
+                            while !text.is_char_boundary(cut) {
+                                cut -= 1;
+                            }
+                            text.truncate(cut);
+                        }
+                        out["content"] = json!(text);
+                        out["truncated"] = json!(truncated);
+                    }
+                    Err(_) => out["binary"] = json!(true),
+                }
+                structured_result_with_text(&out, None)
             }
             _ => Err(ConnectorError::ToolNotFound),
         }
@@ -865,6 +1787,47 @@ impl Connector for GitHubConnector {
             Field { name: "token".into(), label: "GitHub Token (fine-grained PAT)".into(), field_type: FieldType::Secret, required: false, description: Some("Provide a PAT with repo read and metadata; for private code search add code read.".into()), options: None },
             Field { name: "client_id".into(), label: "OAuth Client ID".into(), field_type: FieldType::Text, required: false, description: Some("For device-code flow.".into()), options: None },
             Field { name: "client_secret".into(), label: "OAuth Client Secret".into(), field_type: FieldType::Secret, required: false, description: Some("Optional for device-code token exchange.".into()), options: None },
+            Field { name: "allow_writes".into(), label: "Allow Writes".into(), field_type: FieldType::Boolean, required: false, description: Some("Expose create_issue and add_comment (each call still needs confirm=true). Needs a token with issues write.".into()), options: None },
         ] }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_code_query_and_fragments() {
+        let input = SearchCodeInput {
+            query: "spawn_cpu".to_string(),
+            repo: Some("srv1n/arivu".to_string()),
+            path: Some("arivu_core/src".to_string()),
+            language: Some("rust".to_string()),
+            filename: Some("my file.rs".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            build_code_query(&input).unwrap(),
+            "spawn_cpu repo:srv1n/arivu path:arivu_core/src language:rust filename:\"my file.rs\""
+        );
+        assert!(build_code_query(&SearchCodeInput {
+            repo: Some("srv1n/arivu".to_string()),
+            ..Default::default()
+        })
+        .is_err());
+
+        let items = vec![json!({
+            "path": "src/lib.rs",
+            "sha": "abc",
+            "html_url": "https://github.com/o/r/blob/abc/src/lib.rs",
+            "repository": {"full_name": "o/r"},
+            "text_matches": [
+                {"property": "content", "fragment": "fn spawn_cpu()"},
+                {"property": "path", "fragment": "src/lib.rs"}
+            ]
+        })];
+        let hits = summarize_code_hits(&items);
+        assert_eq!(hits[0]["repository"], "o/r");
+        assert_eq!(hits[0]["fragments"], json!(["fn spawn_cpu()"]));
+    }
+}
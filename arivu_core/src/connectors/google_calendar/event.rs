@@ -0,0 +1,279 @@
+// src/connectors/google_calendar/event.rs
+//
+// Request bodies for create_event/update_event and the free/busy merge.
+// Times are RFC 3339 for timed events or YYYY-MM-DD for all-day ones.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::{json, Map, Value};
+
+use crate::error::ConnectorError;
+
+/// `{"dateTime": ..}` or, for a bare date, an all-day `{"date": ..}`.
+fn event_time(value: &str, time_zone: Option<&str>) -> Result<Value, ConnectorError> {
+    if NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok() {
+        return Ok(json!({ "date": value }));
+    }
+    DateTime::parse_from_rfc3339(value).map_err(|e| {
+        ConnectorError::InvalidParams(format!(
+            "'{}' is neither RFC3339 nor YYYY-MM-DD: {}",
+            value, e
+        ))
+    })?;
+    let mut time = json!({ "dateTime": value });
+    if let Some(tz) = time_zone {
+        time["timeZone"] = json!(tz);
+    }
+    Ok(time)
+}
+
+/// Attendee emails from a comma-separated string or an array.
+pub(super) fn attendees(value: Option<&Value>) -> Result<Vec<Value>, ConnectorError> {
+    let emails: Vec<String> = match value {
+        Some(Value::String(s)) => s.split(',').map(|e| e.trim().to_string()).collect(),
+        Some(Value::Array(items)) => items
+            .iter()
+            .filter_map(|v| v.as_str())
+            .map(|e| e.trim().to_string())
+            .collect(),
+        _ => Vec::new(),
+    };
+    emails
+        .into_iter()
+        .filter(|e| !e.is_empty())
+        .map(|email| {
+            if email.contains('@') {
+                Ok(json!({ "email": email }))
+            } else {
+                Err(ConnectorError::InvalidParams(format!(
+                    "Invalid attendee email '{}'",
+                    email
+                )))
+            }
+        })
+        .collect()
+}
+
+/// The Event resource fields present in `args`. `require_times` is set for
+/// inserts; patches send only what changed. A Google Meet link is requested
+/// with `conference=true`, keyed by `request_id` so retries don't duplicate it.
+pub(super) fn event_body(
+    args: &Map<String, Value>,
+    require_times: bool,
+    request_id: &str,
+) -> Result<Value, ConnectorError> {
+    let text = |key: &str| args.get(key).and_then(|v| v.as_str());
+    let time_zone = text("time_zone");
+    let mut body = Map::new();
+    for (arg, field) in [
+        ("summary", "summary"),
+        ("description", "description"),
+        ("location", "location"),
+    ] {
+        if let Some(v) = text(arg) {
+            body.insert(field.to_string(), json!(v));
+        }
+    }
+    let start = text("start");
+    let end = text("end");
+    if require_times && (start.is_none() || end.is_none()) {
+        return Err(ConnectorError::InvalidParams(
+            "start and end are required".to_string(),
+        ));
+    }
+    if let (Some(s), Some(e)) = (start, end) {
+        if s.len() != e.len() && (s.len() == 10 || e.len() == 10) {
+            return Err(ConnectorError::InvalidParams(
+                "start and end must both be dates (all-day) or both be date-times".to_string(),
+            ));
+        }
+        if let (Ok(s), Ok(e)) = (
+            DateTime::parse_from_rfc3339(s),
+            DateTime::parse_from_rfc3339(e),
+        ) {
+            if e <= s {
+                return Err(ConnectorError::InvalidParams(
+                    "end must be after start".to_string(),
+                ));
+            }
+        }
+    }
+    if let Some(s) = start {
+        body.insert("start".to_string(), event_time(s, time_zone)?);
+    }
+    if let Some(e) = end {
+        body.insert("end".to_string(), event_time(e, time_zone)?);
+    }
+    if args.contains_key("attendees") {
+        body.insert(
+            "attendees".to_string(),
+            json!(attendees(args.get("attendees"))?),
+        );
+    }
+    if args.get("conference").and_then(|v| v.as_bool()) == Some(true) {
+        body.insert(
+            "conferenceData".to_string(),
+            json!({"createRequest": {
+                "requestId": request_id,
+                "conferenceSolutionKey": {"type": "hangoutsMeet"}
+            }}),
+        );
+    }
+    if body.is_empty() {
+        return Err(ConnectorError::InvalidParams(
+            "Nothing to change: pass summary, description, location, start/end, attendees or conference"
+                .to_string(),
+        ));
+    }
+    Ok(Value::Object(body))
+}
+
+/// The fields agents need from an Event resource.
+pub(super) fn summarize_event(event: &Value) -> Value {
+    let meet = event.get("hangoutLink").cloned().or_else(|| {
+        event
+            .pointer("/conferenceData/entryPoints")
+            .and_then(|e| e.as_array())
+            .and_then(|points| {
+                points
+                    .iter()
+                    .find(|p| p.get("entryPointType") == Some(&json!("video")))
+            })
+            .and_then(|p| p.get("uri").cloned())
+    });
+    let attendees: Vec<Value> = event
+        .get("attendees")
+        .and_then(|a| a.as_array())
+        .map(|list| {
+            list.iter()
+                .map(|a| json!({"email": a.get("email"), "response": a.get("responseStatus")}))
+                .collect()
+        })
+        .unwrap_or_default();
+    json!({
+        "id": event.get("id"),
+        "status": event.get("status"),
+        "summary": event.get("summary"),
+        "start": event.get("start"),
+        "end": event.get("end"),
+        "location": event.get("location"),
+        "attendees": attendees,
+        "meet_link": meet,
+        "html_link": event.get("htmlLink"),
+    })
+}
+
+/// Gaps of at least `min_minutes` in `[from, to)` not covered by any busy
+/// interval, across all calendars.
+pub(super) fn free_slots(
+    busy: &[(DateTime<Utc>, DateTime<Utc>)],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    min_minutes: i64,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let mut busy: Vec<_> = busy.to_vec();
+    busy.sort();
+    let mut slots = Vec::new();
+    let mut cursor = from;
+    for (start, end) in busy {
+        if start > cursor && (start.min(to) - cursor).num_minutes() >= min_minutes {
+            slots.push((cursor, start.min(to)));
+        }
+        cursor = cursor.max(end);
+        if cursor >= to {
+            return slots;
+        }
+    }
+    if (to - cursor).num_minutes() >= min_minutes {
+        slots.push((cursor, to));
+    }
+    slots
+}
+
+/// Every busy interval in a freeBusy.query response.
+pub(super) fn busy_intervals(response: &Value) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let parse = |v: Option<&Value>| {
+        v.and_then(|s| s.as_str())
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+            .map(|d| d.with_timezone(&Utc))
+    };
+    response
+        .get("calendars")
+        .and_then(|c| c.as_object())
+        .into_iter()
+        .flat_map(|calendars| calendars.values())
+        .filter_map(|cal| cal.get("busy").and_then(|b| b.as_array()))
+        .flatten()
+        .filter_map(|b| Some((parse(b.get("start"))?, parse(b.get("end"))?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_event_bodies() {
+        let args = json!({
+            "summary": "Planning",
+            "start": "2025-03-04T10:00:00-05:00",
+            "end": "2025-03-04T10:30:00-05:00",
+            "time_zone": "America/New_York",
+            "attendees": "ada@example.com, bob@example.com",
+            "conference": true
+        });
+        let body = event_body(args.as_object().unwrap(), true, "req-1").unwrap();
+        assert_eq!(body["start"]["timeZone"], "America/New_York");
+        assert_eq!(body["attendees"][1]["email"], "bob@example.com");
+        assert_eq!(
+            body["conferenceData"]["createRequest"]["requestId"],
+            "req-1"
+        );
+
+        let all_day = json!({"summary": "Offsite", "start": "2025-03-04", "end": "2025-03-05"});
+        let body = event_body(all_day.as_object().unwrap(), true, "r").unwrap();
+        assert_eq!(body["start"], json!({"date": "2025-03-04"}));
+
+        let patch = json!({"location": "Room 4"});
+        let body = event_body(patch.as_object().unwrap(), false, "r").unwrap();
+        assert_eq!(body, json!({"location": "Room 4"}));
+
+        for bad in [
+            json!({"summary": "x", "start": "2025-03-04T10:00:00Z"}),
+            json!({"start": "2025-03-04T10:00:00Z", "end": "2025-03-04T09:00:00Z"}),
+            json!({"start": "2025-03-04", "end": "2025-03-04T09:00:00Z"}),
+            json!({"attendees": ["not-an-email"]}),
+        ] {
+            assert!(event_body(bad.as_object().unwrap(), true, "r").is_err());
+        }
+        assert!(event_body(&Map::new(), false, "r").is_err());
+    }
+
+    #[test]
+    fn finds_free_slots_between_busy_blocks() {
+        let t = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+        let response = json!({"calendars": {
+            "primary": {"busy": [
+                {"start": "2025-03-04T10:00:00Z", "end": "2025-03-04T11:00:00Z"}
+            ]},
+            "ada@example.com": {"busy": [
+                {"start": "2025-03-04T10:30:00Z", "end": "2025-03-04T11:30:00Z"},
+                {"start": "2025-03-04T11:40:00Z", "end": "2025-03-04T12:00:00Z"}
+            ]}
+        }});
+        let busy = busy_intervals(&response);
+        assert_eq!(busy.len(), 3);
+        let slots = free_slots(
+            &busy,
+            t("2025-03-04T09:00:00Z"),
+            t("2025-03-04T13:00:00Z"),
+            30,
+        );
+        assert_eq!(
+            slots,
+            vec![
+                (t("2025-03-04T09:00:00Z"), t("2025-03-04T10:00:00Z")),
+                (t("2025-03-04T12:00:00Z"), t("2025-03-04T13:00:00Z")),
+            ]
+        );
+    }
+}
@@ -1,3 +1,5 @@
+mod event;
+
 use async_trait::async_trait;
 use rmcp::model::*;
 use std::borrow::Cow;
@@ -7,11 +9,17 @@ use crate::auth::AuthDetails;
 use crate::auth_store::{AuthStore, FileAuthStore};
 use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
 use crate::error::ConnectorError;
-use crate::utils::{collect_paginated_with_cursor, structured_result_with_text, Page};
+use crate::utils::{collect_paginated_with_cursor, structured_result_with_text, write_gate, Page};
 use crate::Connector;
 #[allow(unused_imports)]
 use google_calendar3 as calendar3;
 
+use event::{busy_intervals, event_body, free_slots, summarize_event};
+
+const CALENDAR_API_BASE: &str = "https://www.googleapis.com/calendar/v3";
+/// freeBusy.query accepts at most this many calendars
+const FREEBUSY_MAX_CALENDARS: usize = 50;
+
 pub struct GoogleCalendarConnector {
     auth: AuthDetails,
 }
@@ -19,6 +27,79 @@ impl GoogleCalendarConnector {
     pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
         Ok(Self { auth })
     }
+
+    fn access_token(&self) -> Result<String, ConnectorError> {
+        if let Some(token) = self.auth.get("access_token") {
+            return Ok(token.clone());
+        }
+        let store = FileAuthStore::new_default();
+        let auth = store
+            .load("google-calendar")
+            .or_else(|| store.load("google-common"))
+            .ok_or_else(|| ConnectorError::Authentication("No tokens stored".to_string()))?;
+        auth.get("access_token")
+            .cloned()
+            .ok_or_else(|| ConnectorError::Authentication("Missing access_token".to_string()))
+    }
+
+    async fn api(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<&serde_json::Value>,
+    ) -> Result<serde_json::Value, ConnectorError> {
+        let token = self.access_token()?;
+        let mut req = reqwest::Client::new()
+            .request(method, format!("{}{}", CALENDAR_API_BASE, path))
+            .bearer_auth(token)
+            .query(query);
+        if let Some(body) = body {
+            req = req.json(body);
+        }
+        let resp = req.send().await.map_err(ConnectorError::HttpRequest)?;
+        let status = resp.status();
+        // events.delete answers 204 with no body
+        let v: serde_json::Value = resp.json().await.unwrap_or(serde_json::json!({}));
+        if !status.is_success() {
+            let message = v
+                .pointer("/error/message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("request failed");
+            return Err(match status.as_u16() {
+                401 | 403 => ConnectorError::Authentication(format!("calendar: {}", message)),
+                404 => ConnectorError::ResourceNotFound,
+                _ => ConnectorError::Other(format!("calendar error {}: {}", status, message)),
+            });
+        }
+        Ok(v)
+    }
+}
+
+/// `path` segment for a calendar id such as "primary" or an email address.
+fn calendar_path(args: &serde_json::Map<String, serde_json::Value>) -> String {
+    let id = args
+        .get("calendar_id")
+        .and_then(|v| v.as_str())
+        .unwrap_or("primary");
+    format!("/calendars/{}", urlencoding::encode(id))
+}
+
+/// Schema properties shared by create_event and update_event.
+fn event_properties() -> serde_json::Value {
+    serde_json::json!({
+        "calendar_id":{"type":"string","description":"Default primary"},
+        "summary":{"type":"string"},
+        "description":{"type":"string"},
+        "location":{"type":"string"},
+        "start":{"type":"string","description":"RFC3339, or YYYY-MM-DD for an all-day event"},
+        "end":{"type":"string","description":"RFC3339, or YYYY-MM-DD (exclusive) for an all-day event"},
+        "time_zone":{"type":"string","description":"IANA zone, e.g. Europe/Berlin"},
+        "attendees":{"type":["string","array"],"items":{"type":"string"},"description":"Attendee emails; on update, replaces the list"},
+        "conference":{"type":"boolean","description":"Add a Google Meet link"},
+        "send_updates":{"type":"string","enum":["all","externalOnly","none"],"description":"Email attendees about the change (default none)"},
+        "confirm":{"type":"boolean","description":"Must be true; confirms the user approved this change"}
+    })
 }
 
 #[async_trait]
@@ -27,7 +108,7 @@ impl Connector for GoogleCalendarConnector {
         "google-calendar"
     }
     fn description(&self) -> &'static str {
-        "Google Calendar connector (list events)."
+        "Google Calendar connector (list events, free/busy, opt-in scheduling)."
     }
     async fn capabilities(&self) -> ServerCapabilities {
         ServerCapabilities {
@@ -63,25 +144,15 @@ impl Connector for GoogleCalendarConnector {
         let mut tools = vec![
 Tool { name: Cow::Borrowed("list_events"), title: None, description: Some(Cow::Borrowed("List events (requires explicit user permission).")), input_schema: Arc::new(serde_json::json!({"type":"object","properties":{"max_results":{"type":"integer","minimum":1,"maximum":5000},"page_token":{"type":"string","description":"Optional cursor from a previous response (nextPageToken)."},"time_min":{"type":"string","description":"RFC3339"},"response_format":{"type":"string","enum":["concise","detailed"],"description":"Default concise."}},"required":[]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
         ];
-        tools.push(Tool { name: std::borrow::Cow::Borrowed("create_event"), title: None, description: Some(std::borrow::Cow::Borrowed("Create an event (requires explicit user permission).")), input_schema: std::sync::Arc::new(serde_json::json!({"type":"object","properties":{"summary":{"type":"string"},"start":{"type":"string","description":"RFC3339"},"end":{"type":"string","description":"RFC3339"}},"required":["summary","start","end"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
+        let mut create_props = event_properties();
+        create_props["summary"]["description"] = serde_json::json!("Event title");
+        tools.push(Tool { name: std::borrow::Cow::Borrowed("create_event"), title: None, description: Some(std::borrow::Cow::Borrowed("Create an event with optional attendees and Google Meet link. Changes the calendar: disabled unless allow_writes is configured; pass confirm=true after the user approves.")), input_schema: std::sync::Arc::new(serde_json::json!({"type":"object","properties":create_props,"required":["summary","start","end","confirm"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
         tools.push(Tool { name: std::borrow::Cow::Borrowed("sync_events"), title: None, description: Some(std::borrow::Cow::Borrowed("Incremental sync (requires explicit user permission).")), input_schema: std::sync::Arc::new(serde_json::json!({"type":"object","properties":{"sync_token":{"type":"string"},"max_results":{"type":"integer","minimum":1,"maximum":250}} ,"required":["sync_token"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
-        tools.push(Tool { name: std::borrow::Cow::Borrowed("update_event"), title: None, description: Some(std::borrow::Cow::Borrowed("Update an event (requires explicit user permission).")), input_schema: std::sync::Arc::new(serde_json::json!({"type":"object","properties":{"event_id":{"type":"string"},"summary":{"type":"string"},"start":{"type":"string","description":"RFC3339"},"end":{"type":"string","description":"RFC3339"}},"required":["event_id"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
-        tools.push(Tool {
-            name: std::borrow::Cow::Borrowed("delete_event"),
-            title: None,
-            description: Some(std::borrow::Cow::Borrowed(
-                "Delete an event (requires explicit user permission).",
-            )),
-            input_schema: std::sync::Arc::new(
-                serde_json::json!({"type":"object","properties":{"event_id":{"type":"string"}}})
-                    .as_object()
-                    .expect("Schema object")
-                    .clone(),
-            ),
-            output_schema: None,
-            annotations: None,
-            icons: None,
-        });
+        let mut update_props = event_properties();
+        update_props["event_id"] = serde_json::json!({"type":"string"});
+        tools.push(Tool { name: std::borrow::Cow::Borrowed("update_event"), title: None, description: Some(std::borrow::Cow::Borrowed("Change only the given fields of an event. Changes the calendar: disabled unless allow_writes is configured; pass confirm=true after the user approves.")), input_schema: std::sync::Arc::new(serde_json::json!({"type":"object","properties":update_props,"required":["event_id","confirm"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
+        tools.push(Tool { name: std::borrow::Cow::Borrowed("delete_event"), title: None, description: Some(std::borrow::Cow::Borrowed("Delete an event. Changes the calendar: disabled unless allow_writes is configured; pass confirm=true after the user approves.")), input_schema: std::sync::Arc::new(serde_json::json!({"type":"object","properties":{"event_id":{"type":"string"},"calendar_id":{"type":"string","description":"Default primary"},"send_updates":{"type":"string","enum":["all","externalOnly","none"]},"confirm":{"type":"boolean","description":"Must be true; confirms the user approved this change"}},"required":["event_id","confirm"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
+        tools.push(Tool { name: std::borrow::Cow::Borrowed("freebusy"), title: None, description: Some(std::borrow::Cow::Borrowed("Busy blocks for up to 50 calendars (your own or colleagues' emails) between time_min and time_max, plus the free slots of at least min_minutes common to all of them (requires explicit user permission).")), input_schema: std::sync::Arc::new(serde_json::json!({"type":"object","properties":{"time_min":{"type":"string","description":"RFC3339"},"time_max":{"type":"string","description":"RFC3339"},"calendars":{"type":["string","array"],"items":{"type":"string"},"description":"Calendar ids or emails (default primary)"},"time_zone":{"type":"string"},"min_minutes":{"type":"integer","minimum":1,"description":"Shortest free slot to report (default 30)"}},"required":["time_min","time_max"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
         tools.push(Tool { name: std::borrow::Cow::Borrowed("watch_events"), title: None, description: Some(std::borrow::Cow::Borrowed("Start calendar webhook (requires explicit user permission).")), input_schema: std::sync::Arc::new(serde_json::json!({"type":"object","properties":{"address":{"type":"string"},"id":{"type":"string"},"token":{"type":"string"}},"required":["address"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
         tools.push(Tool { name: std::borrow::Cow::Borrowed("stop_channel"), title: None, description: Some(std::borrow::Cow::Borrowed("Stop webhook channel (requires explicit user permission).")), input_schema: std::sync::Arc::new(serde_json::json!({"type":"object","properties":{"id":{"type":"string"},"resource_id":{"type":"string"}},"required":["id","resource_id"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
         if !crate::oauth_client::admin_tools_enabled() {
@@ -190,55 +261,33 @@ Tool { name: Cow::Borrowed("list_events"), title: None, description: Some(Cow::B
                 structured_result_with_text(&v, None)
             }
             "create_event" => {
-                let summary = args.get("summary").and_then(|v| v.as_str()).ok_or(
-                    ConnectorError::InvalidParams("summary is required".to_string()),
-                )?;
-                let start_str = args.get("start").and_then(|v| v.as_str()).ok_or(
-                    ConnectorError::InvalidParams("start is required".to_string()),
+                let confirm = args
+                    .get("confirm")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                write_gate(
+                    self.name(),
+                    "ARIVU_CALENDAR_ALLOW_WRITES",
+                    self.auth.get("allow_writes").map(String::as_str),
+                    confirm,
                 )?;
-                let end_str = args
-                    .get("end")
+                let body = event_body(&args, true, &uuid::Uuid::new_v4().to_string())?;
+                let send_updates = args
+                    .get("send_updates")
                     .and_then(|v| v.as_str())
-                    .ok_or(ConnectorError::InvalidParams("end is required".to_string()))?;
-                let store = FileAuthStore::new_default();
-                let auth = store
-                    .load("google-calendar")
-                    .or_else(|| store.load("google-common"))
-                    .ok_or_else(|| {
-                        ConnectorError::Authentication("No tokens stored".to_string())
-                    })?;
-                let token = auth.get("access_token").cloned().ok_or_else(|| {
-                    ConnectorError::Authentication("Missing access_token".to_string())
-                })?;
-                let client = crate::oauth_client::google_client::new_https_client();
-                let hub = calendar3::CalendarHub::new(client, token.clone());
-                let start_dt = chrono::DateTime::parse_from_rfc3339(start_str)
-                    .map(|d| d.with_timezone(&chrono::Utc))
-                    .map_err(|e| ConnectorError::InvalidParams(format!("invalid start: {}", e)))?;
-                let end_dt = chrono::DateTime::parse_from_rfc3339(end_str)
-                    .map(|d| d.with_timezone(&chrono::Utc))
-                    .map_err(|e| ConnectorError::InvalidParams(format!("invalid end: {}", e)))?;
-                let ev = calendar3::api::Event {
-                    summary: Some(summary.to_string()),
-                    start: Some(calendar3::api::EventDateTime {
-                        date_time: Some(start_dt),
-                        ..Default::default()
-                    }),
-                    end: Some(calendar3::api::EventDateTime {
-                        date_time: Some(end_dt),
-                        ..Default::default()
-                    }),
-                    ..Default::default()
-                };
-                let (_, created) = hub
-                    .events()
-                    .insert(ev, "primary")
-                    .doit()
-                    .await
-                    .map_err(|e| ConnectorError::Other(format!("calendar insert error: {}", e)))?;
-                let v = serde_json::to_value(&created)
-                    .map_err(|e| ConnectorError::Other(format!("serde: {}", e)))?;
-                structured_result_with_text(&v, None)
+                    .unwrap_or("none");
+                let created = self
+                    .api(
+                        reqwest::Method::POST,
+                        &format!("{}/events", calendar_path(&args)),
+                        &[
+                            ("sendUpdates", send_updates),
+                            ("conferenceDataVersion", "1"),
+                        ],
+                        Some(&body),
+                    )
+                    .await?;
+                structured_result_with_text(&summarize_event(&created), None)
             }
             "sync_events" => {
                 let sync_token = args.get("sync_token").and_then(|v| v.as_str()).ok_or(
@@ -276,71 +325,147 @@ Tool { name: Cow::Borrowed("list_events"), title: None, description: Some(Cow::B
                 let event_id = args.get("event_id").and_then(|v| v.as_str()).ok_or(
                     ConnectorError::InvalidParams("event_id is required".to_string()),
                 )?;
-                let store = FileAuthStore::new_default();
-                let auth = store
-                    .load("google-calendar")
-                    .or_else(|| store.load("google-common"))
-                    .ok_or_else(|| {
-                        ConnectorError::Authentication("No tokens stored".to_string())
-                    })?;
-                let token = auth.get("access_token").cloned().ok_or_else(|| {
-                    ConnectorError::Authentication("Missing access_token".to_string())
-                })?;
-                let client = crate::oauth_client::google_client::new_https_client();
-                let hub = calendar3::CalendarHub::new(client, token.clone());
-                let mut ev = calendar3::api::Event::default();
-                if let Some(s) = args.get("summary").and_then(|v| v.as_str()) {
-                    ev.summary = Some(s.to_string());
-                }
-                if let Some(start) = args.get("start").and_then(|v| v.as_str()) {
-                    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(start) {
-                        ev.start = Some(calendar3::api::EventDateTime {
-                            date_time: Some(dt.with_timezone(&chrono::Utc)),
-                            ..Default::default()
-                        });
-                    }
-                }
-                if let Some(end) = args.get("end").and_then(|v| v.as_str()) {
-                    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(end) {
-                        ev.end = Some(calendar3::api::EventDateTime {
-                            date_time: Some(dt.with_timezone(&chrono::Utc)),
-                            ..Default::default()
-                        });
-                    }
-                }
-                let (_, updated) = hub
-                    .events()
-                    .patch(ev, "primary", event_id)
-                    .doit()
-                    .await
-                    .map_err(|e| ConnectorError::Other(format!("calendar patch error: {}", e)))?;
-                let v = serde_json::to_value(&updated)
-                    .map_err(|e| ConnectorError::Other(format!("serde: {}", e)))?;
-                structured_result_with_text(&v, None)
+                let confirm = args
+                    .get("confirm")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                write_gate(
+                    self.name(),
+                    "ARIVU_CALENDAR_ALLOW_WRITES",
+                    self.auth.get("allow_writes").map(String::as_str),
+                    confirm,
+                )?;
+                let body = event_body(&args, false, &uuid::Uuid::new_v4().to_string())?;
+                let send_updates = args
+                    .get("send_updates")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("none");
+                let updated = self
+                    .api(
+                        reqwest::Method::PATCH,
+                        &format!(
+                            "{}/events/{}",
+                            calendar_path(&args),
+                            urlencoding::encode(event_id)
+                        ),
+                        &[
+                            ("sendUpdates", send_updates),
+                            ("conferenceDataVersion", "1"),
+                        ],
+                        Some(&body),
+                    )
+                    .await?;
+                structured_result_with_text(&summarize_event(&updated), None)
             }
             "delete_event" => {
                 let event_id = args.get("event_id").and_then(|v| v.as_str()).ok_or(
                     ConnectorError::InvalidParams("event_id is required".to_string()),
                 )?;
-                let store = FileAuthStore::new_default();
-                let auth = store
-                    .load("google-calendar")
-                    .or_else(|| store.load("google-common"))
-                    .ok_or_else(|| {
-                        ConnectorError::Authentication("No tokens stored".to_string())
+                let confirm = args
+                    .get("confirm")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                write_gate(
+                    self.name(),
+                    "ARIVU_CALENDAR_ALLOW_WRITES",
+                    self.auth.get("allow_writes").map(String::as_str),
+                    confirm,
+                )?;
+                let send_updates = args
+                    .get("send_updates")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("none");
+                self.api(
+                    reqwest::Method::DELETE,
+                    &format!(
+                        "{}/events/{}",
+                        calendar_path(&args),
+                        urlencoding::encode(event_id)
+                    ),
+                    &[("sendUpdates", send_updates)],
+                    None,
+                )
+                .await?;
+                structured_result_with_text(
+                    &serde_json::json!({"status":"deleted","event_id":event_id}),
+                    None,
+                )
+            }
+            "freebusy" => {
+                let parse = |key: &str| {
+                    let raw = args.get(key).and_then(|v| v.as_str()).ok_or_else(|| {
+                        ConnectorError::InvalidParams(format!("{} is required", key))
                     })?;
-                let token = auth.get("access_token").cloned().ok_or_else(|| {
-                    ConnectorError::Authentication("Missing access_token".to_string())
-                })?;
-                let client = crate::oauth_client::google_client::new_https_client();
-                let hub = calendar3::CalendarHub::new(client, token.clone());
-                let _ = hub
-                    .events()
-                    .delete("primary", event_id)
-                    .doit()
-                    .await
-                    .map_err(|e| ConnectorError::Other(format!("calendar delete error: {}", e)))?;
-                structured_result_with_text(&serde_json::json!({"status":"deleted"}), None)
+                    chrono::DateTime::parse_from_rfc3339(raw)
+                        .map(|d| d.with_timezone(&chrono::Utc))
+                        .map_err(|e| {
+                            ConnectorError::InvalidParams(format!("invalid {}: {}", key, e))
+                        })
+                };
+                let time_min = parse("time_min")?;
+                let time_max = parse("time_max")?;
+                if time_max <= time_min {
+                    return Err(ConnectorError::InvalidParams(
+                        "time_max must be after time_min".to_string(),
+                    ));
+                }
+                let mut calendars: Vec<String> = match args.get("calendars") {
+                    Some(serde_json::Value::String(s)) => s
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|s| !s.is_empty())
+                        .map(str::to_string)
+                        .collect(),
+                    Some(serde_json::Value::Array(items)) => items
+                        .iter()
+                        .filter_map(|v| v.as_str())
+                        .map(str::to_string)
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                if calendars.is_empty() {
+                    calendars.push("primary".to_string());
+                }
+                if calendars.len() > FREEBUSY_MAX_CALENDARS {
+                    return Err(ConnectorError::InvalidParams(format!(
+                        "At most {} calendars per query",
+                        FREEBUSY_MAX_CALENDARS
+                    )));
+                }
+                let min_minutes = args
+                    .get("min_minutes")
+                    .and_then(|v| v.as_i64())
+                    .unwrap_or(30)
+                    .max(1);
+                let mut body = serde_json::json!({
+                    "timeMin": time_min.to_rfc3339(),
+                    "timeMax": time_max.to_rfc3339(),
+                    "items": calendars.iter().map(|id| serde_json::json!({"id": id})).collect::<Vec<_>>(),
+                });
+                if let Some(tz) = args.get("time_zone").and_then(|v| v.as_str()) {
+                    body["timeZone"] = serde_json::json!(tz);
+                }
+                let resp = self
+                    .api(reqwest::Method::POST, "/freeBusy", &[], Some(&body))
+                    .await?;
+                let free: Vec<serde_json::Value> =
+                    free_slots(&busy_intervals(&resp), time_min, time_max, min_minutes)
+                        .into_iter()
+                        .map(|(start, end)| {
+                            serde_json::json!({
+                                "start": start.to_rfc3339(),
+                                "end": end.to_rfc3339(),
+                                "minutes": (end - start).num_minutes(),
+                            })
+                        })
+                        .collect();
+                let v = serde_json::json!({
+                    "time_min": time_min.to_rfc3339(),
+                    "time_max": time_max.to_rfc3339(),
+                    "calendars": resp.get("calendars"),
+                    "free": free,
+                });
+                structured_result_with_text(&v, None)
             }
             "watch_events" => {
                 let address = args.get("address").and_then(|v| v.as_str()).ok_or(
@@ -463,16 +588,29 @@ Tool { name: Cow::Borrowed("list_events"), title: None, description: Some(Cow::B
     }
     fn config_schema(&self) -> ConnectorConfigSchema {
         ConnectorConfigSchema {
-            fields: vec![Field {
-                name: "scopes".to_string(),
-                label: "Scopes".to_string(),
-                field_type: FieldType::Text,
-                required: false,
-                description: Some(
-                    "Use scope https://www.googleapis.com/auth/calendar.readonly".to_string(),
-                ),
-                options: None,
-            }],
+            fields: vec![
+                Field {
+                    name: "scopes".to_string(),
+                    label: "Scopes".to_string(),
+                    field_type: FieldType::Text,
+                    required: false,
+                    description: Some(
+                        "Use scope https://www.googleapis.com/auth/calendar.readonly; writes need https://www.googleapis.com/auth/calendar.events".to_string(),
+                    ),
+                    options: None,
+                },
+                Field {
+                    name: "allow_writes".to_string(),
+                    label: "Allow writes".to_string(),
+                    field_type: FieldType::Boolean,
+                    required: false,
+                    description: Some(
+                        "Enable create_event, update_event and delete_event (each call still needs confirm=true)."
+                            .to_string(),
+                    ),
+                    options: None,
+                },
+            ],
         }
     }
 }
@@ -0,0 +1,156 @@
+// src/connectors/google_drive/export.rs
+//
+// Text conversions for the `export` tool: Docs go through Drive's markdown
+// export, Slides are read per slide from the Slides API and Sheets ranges
+// from the Sheets values API, rendered here as CSV. Uploaded PDFs are read
+// page by page through `crate::pdf`.
+
+use serde_json::{json, Value};
+
+pub(super) const DOC_MIME: &str = "application/vnd.google-apps.document";
+pub(super) const SLIDES_MIME: &str = "application/vnd.google-apps.presentation";
+pub(super) const SHEET_MIME: &str = "application/vnd.google-apps.spreadsheet";
+pub(super) const PDF_MIME: &str = "application/pdf";
+
+/// Text of every shape and table cell on each slide, in page order, with
+/// the speaker notes kept apart.
+pub(super) fn slide_texts(presentation: &Value) -> Vec<Value> {
+    presentation
+        .get("slides")
+        .and_then(|s| s.as_array())
+        .map(|slides| {
+            slides
+                .iter()
+                .enumerate()
+                .map(|(i, slide)| {
+                    let mut lines = Vec::new();
+                    for element in elements(slide.get("pageElements")) {
+                        element_text(element, &mut lines);
+                    }
+                    let mut notes = Vec::new();
+                    for element in
+                        elements(slide.pointer("/slideProperties/notesPage/pageElements"))
+                    {
+                        // The notes page also holds a thumbnail of the slide itself
+                        if element.pointer("/shape/placeholder/type") == Some(&json!("BODY")) {
+                            element_text(element, &mut notes);
+                        }
+                    }
+                    json!({
+                        "slide": i + 1,
+                        "object_id": slide.get("objectId"),
+                        "text": lines.join("\n"),
+                        "notes": notes.join("\n"),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn elements(value: Option<&Value>) -> impl Iterator<Item = &Value> {
+    value.and_then(|v| v.as_array()).into_iter().flatten()
+}
+
+fn element_text(element: &Value, out: &mut Vec<String>) {
+    if let Some(text) = element.pointer("/shape/text") {
+        push_text(text, out);
+    }
+    for row in elements(element.pointer("/table/tableRows")) {
+        let cells: Vec<String> = elements(row.get("tableCells"))
+            .map(|cell| {
+                let mut parts = Vec::new();
+                if let Some(text) = cell.get("text") {
+                    push_text(text, &mut parts);
+                }
+                parts.join(" ")
+            })
+            .collect();
+        if cells.iter().any(|c| !c.is_empty()) {
+            out.push(cells.join(" | "));
+        }
+    }
+    for child in elements(element.pointer("/elementGroup/children")) {
+        element_text(child, out);
+    }
+}
+
+fn push_text(text: &Value, out: &mut Vec<String>) {
+    let joined: String = elements(text.get("textElements"))
+        .filter_map(|e| e.pointer("/textRun/content").and_then(|c| c.as_str()))
+        .collect();
+    let joined = joined.trim();
+    if !joined.is_empty() {
+        out.push(joined.to_string());
+    }
+}
+
+/// RFC 4180 CSV of a values.get response (`values` is rows of cells).
+pub(super) fn values_to_csv(range: &Value) -> String {
+    let mut out = String::new();
+    for row in elements(range.get("values")) {
+        let cells: Vec<String> = elements(Some(row))
+            .map(|cell| match cell {
+                Value::String(s) => csv_field(s),
+                Value::Null => String::new(),
+                other => csv_field(&other.to_string()),
+            })
+            .collect();
+        out.push_str(&cells.join(","));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_slides_and_sheet_ranges() {
+        let deck = json!({"slides": [
+            {"objectId": "p1", "pageElements": [
+                {"shape": {"text": {"textElements": [
+                    {"paragraphMarker": {}},
+                    {"textRun": {"content": "Roadmap\n"}}
+                ]}}},
+                {"table": {"tableRows": [
+                    {"tableCells": [
+                        {"text": {"textElements": [{"textRun": {"content": "Q1\n"}}]}},
+                        {"text": {"textElements": [{"textRun": {"content": "Ship\n"}}]}}
+                    ]}
+                ]}}
+            ],
+             "slideProperties": {"notesPage": {"pageElements": [
+                {"shape": {"shapeType": "RECTANGLE", "placeholder": {"type": "SLIDE_IMAGE"}}},
+                {"shape": {"placeholder": {"type": "BODY"}, "text": {"textElements": [
+                    {"textRun": {"content": "Mention hiring\n"}}
+                ]}}}
+             ]}}},
+            {"objectId": "p2"}
+        ]});
+        let slides = slide_texts(&deck);
+        assert_eq!(slides.len(), 2);
+        assert_eq!(slides[0]["text"], "Roadmap\nQ1 | Ship");
+        assert_eq!(slides[0]["notes"], "Mention hiring");
+        assert_eq!(slides[1]["slide"], 2);
+        assert_eq!(slides[1]["text"], "");
+
+        let range = json!({"range": "Sheet1!A1:C2", "values": [
+            ["name", "note", "n"],
+            ["Ada", "says \"hi\", twice", 3]
+        ]});
+        assert_eq!(
+            values_to_csv(&range),
+            "name,note,n\r\nAda,\"says \"\"hi\"\", twice\",3\r\n"
+        );
+    }
+}
@@ -1,3 +1,6 @@
+mod export;
+mod tree;
+
 use async_trait::async_trait;
 use rmcp::model::*;
 use serde_json::json;
@@ -13,13 +16,27 @@ use yup_oauth2 as oauth2;
 use crate::auth::AuthDetails;
 use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
 use crate::error::ConnectorError;
-use crate::utils::{collect_paginated_with_cursor, structured_result_with_text, Page};
+use crate::pdf::PdfOptions;
+use crate::utils::{
+    collect_paginated_with_cursor, structured_result_with_text, truncate_chars, Page,
+};
 use crate::Connector;
 use crate::{
     auth_store::{AuthStore, FileAuthStore},
     oauth,
 };
 use base64::Engine as _;
+use export::{slide_texts, values_to_csv, DOC_MIME, PDF_MIME, SHEET_MIME, SLIDES_MIME};
+use tree::{
+    change_in_folders, is_folder, summarize_change, tree_entry, CHANGE_FIELDS, TREE_FILE_FIELDS,
+};
+
+const DEFAULT_EXPORT_MAX_CHARS: usize = 200_000;
+const DEFAULT_TREE_DEPTH: u64 = 3;
+const MAX_TREE_DEPTH: u64 = 10;
+const DEFAULT_TREE_ITEMS: u64 = 1_000;
+const MAX_TREE_ITEMS: u64 = 10_000;
+const DEFAULT_CHANGES_LIMIT: u64 = 1_000;
 
 #[derive(Clone, Default)]
 pub struct DriveConnector {
@@ -27,6 +44,44 @@ pub struct DriveConnector {
 }
 
 impl DriveConnector {
+    fn access_token(&self) -> Result<String, ConnectorError> {
+        if let Some(token) = self.auth.get("access_token") {
+            return Ok(token.clone());
+        }
+        let store = FileAuthStore::new_default();
+        let auth = store
+            .load(self.name())
+            .or_else(|| store.load("google-common"))
+            .ok_or_else(|| ConnectorError::Authentication("No tokens stored".to_string()))?;
+        auth.get("access_token")
+            .cloned()
+            .ok_or_else(|| ConnectorError::Authentication("Missing access_token".to_string()))
+    }
+
+    /// GET against the Slides/Sheets REST APIs, which google-drive3 doesn't cover.
+    async fn google_get(&self, url: &str) -> Result<serde_json::Value, ConnectorError> {
+        let resp = reqwest::Client::new()
+            .get(url)
+            .bearer_auth(self.access_token()?)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+        let status = resp.status();
+        let v: serde_json::Value = resp.json().await.unwrap_or(json!({}));
+        if !status.is_success() {
+            let message = v
+                .pointer("/error/message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("request failed");
+            return Err(match status.as_u16() {
+                401 | 403 => ConnectorError::Authentication(format!("google: {}", message)),
+                404 => ConnectorError::ResourceNotFound,
+                _ => ConnectorError::Other(format!("google error {}: {}", status, message)),
+            });
+        }
+        Ok(v)
+    }
+
     pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
         Ok(Self { auth })
     }
@@ -96,6 +151,9 @@ impl Connector for DriveConnector {
         tools.push(Tool { name: Cow::Borrowed("get_file"), title: None, description: Some(Cow::Borrowed("Get file metadata (requires explicit user permission).")), input_schema: Arc::new(serde_json::json!({"type":"object","properties":{"file_id":{"type":"string"},"response_format":{"type":"string","enum":["concise","detailed"]}},"required":["file_id"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
         tools.push(Tool { name: Cow::Borrowed("download_file"), title: None, description: Some(Cow::Borrowed("Download file content (requires explicit user permission).")), input_schema: Arc::new(serde_json::json!({"type":"object","properties":{"file_id":{"type":"string"},"max_bytes":{"type":"integer","description":"Optional cap to avoid huge responses"}},"required":["file_id"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
         tools.push(Tool { name: Cow::Borrowed("export_file"), title: None, description: Some(Cow::Borrowed("Export Docs/Sheets/Slides (requires explicit user permission).")), input_schema: Arc::new(serde_json::json!({"type":"object","properties":{"file_id":{"type":"string"},"mime_type":{"type":"string","description":"Target MIME type"}},"required":["file_id","mime_type"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
+        tools.push(Tool { name: Cow::Borrowed("export"), title: None, description: Some(Cow::Borrowed("Read a Google Doc as markdown, Slides as per-slide text with speaker notes, a Sheet (optionally an A1 range such as 'Budget!A1:F50') as CSV, or an uploaded PDF as per-page text (requires explicit user permission).")), input_schema: Arc::new(serde_json::json!({"type":"object","properties":{"file_id":{"type":"string"},"range":{"type":"string","description":"Sheets only: A1 range or sheet name; default is the first sheet"},"sections":{"type":"boolean","description":"PDFs only: also split the text into sections with their starting page"},"max_chars":{"type":"integer","minimum":1,"description":"Truncate the text (default 200000)"}},"required":["file_id"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
+        tools.push(Tool { name: Cow::Borrowed("list_folder_tree"), title: None, description: Some(Cow::Borrowed("Walk a folder recursively (breadth first, depth-limited) and return every file and subfolder with its path, parent and checksum (requires explicit user permission).")), input_schema: Arc::new(serde_json::json!({"type":"object","properties":{"folder_id":{"type":"string","description":"Folder to walk (default: root of My Drive)"},"max_depth":{"type":"integer","minimum":1,"maximum":10,"description":"1 lists direct children only (default 3)"},"max_items":{"type":"integer","minimum":1,"maximum":10000,"description":"Stop after this many entries (default 1000)"}},"required":[]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
+        tools.push(Tool { name: Cow::Borrowed("list_changes"), title: None, description: Some(Cow::Borrowed("Changes since a saved page token. Without page_token, returns a start token to save as the baseline. Returns new_start_page_token once caught up, or next_page_token if limit was reached (requires explicit user permission).")), input_schema: Arc::new(serde_json::json!({"type":"object","properties":{"page_token":{"type":"string","description":"Token from a previous call (next_page_token or new_start_page_token)"},"folder_ids":{"type":"array","items":{"type":"string"},"description":"Only changes to direct children of these folders (e.g. ids from list_folder_tree); removals are always kept"},"limit":{"type":"integer","minimum":1,"maximum":10000,"description":"Stop after scanning this many changes (default 1000)"},"page_size":{"type":"integer","minimum":1,"maximum":1000}},"required":[]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
         tools.push(Tool { name: Cow::Borrowed("upload_file"), title: None, description: Some(Cow::Borrowed("Upload file via base64 (requires explicit user permission).")), input_schema: Arc::new(serde_json::json!({"type":"object","properties":{"name":{"type":"string"},"mime_type":{"type":"string"},"data_base64":{"type":"string"},"parents":{"type":"array","items":{"type":"string"}}},"required":["name","mime_type","data_base64"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
         tools.push(Tool { name: Cow::Borrowed("upload_file_resumable"), title: None, description: Some(Cow::Borrowed("Resumable upload (requires explicit user permission).")), input_schema: Arc::new(serde_json::json!({"type":"object","properties":{"name":{"type":"string"},"mime_type":{"type":"string"},"data_base64":{"type":"string"},"parents":{"type":"array","items":{"type":"string"}}},"required":["name","mime_type","data_base64"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None });
         // Auth helpers
@@ -420,6 +478,137 @@ impl Connector for DriveConnector {
                 let v = json!({ "file_id": file_id, "mime_type": mime_type, "filename": filename, "data_base64": b64 });
                 structured_result_with_text(&v, None)
             }
+            "export" => {
+                let file_id = args.get("file_id").and_then(|v| v.as_str()).ok_or(
+                    ConnectorError::InvalidParams("file_id is required".to_string()),
+                )?;
+                let range = args.get("range").and_then(|v| v.as_str());
+                let sections = args
+                    .get("sections")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let max_chars = args
+                    .get("max_chars")
+                    .and_then(|v| v.as_u64())
+                    .map(|n| n as usize)
+                    .unwrap_or(DEFAULT_EXPORT_MAX_CHARS);
+                let client = crate::oauth_client::google_client::new_https_client();
+                let hub = drive3::DriveHub::new(client, self.access_token()?);
+                let (_, meta) = hub
+                    .files()
+                    .get(file_id)
+                    .param("fields", "id,name,mimeType")
+                    .doit()
+                    .await
+                    .map_err(|e| ConnectorError::Other(format!("drive get meta: {}", e)))?;
+                let src_mime = meta.mime_type.unwrap_or_default();
+                let id = urlencoding::encode(file_id);
+
+                let mut v = json!({
+                    "file_id": file_id,
+                    "name": meta.name.unwrap_or_default(),
+                    "source_mime": src_mime,
+                });
+                match src_mime.as_str() {
+                    SLIDES_MIME => {
+                        let deck = self
+                            .google_get(&format!(
+                                "https://slides.googleapis.com/v1/presentations/{}",
+                                id
+                            ))
+                            .await?;
+                        // Per-slide text is bounded by the whole deck's budget
+                        let mut budget = max_chars;
+                        let mut truncated = false;
+                        let mut slides = slide_texts(&deck);
+                        for slide in slides.iter_mut() {
+                            for field in ["text", "notes"] {
+                                let mut text =
+                                    slide[field].as_str().unwrap_or_default().to_string();
+                                truncated |= truncate_chars(&mut text, budget);
+                                budget -= text.chars().count();
+                                slide[field] = json!(text);
+                            }
+                        }
+                        v["format"] = json!("slides");
+                        v["slides"] = json!(slides);
+                        v["truncated"] = json!(truncated);
+                    }
+                    SHEET_MIME | DOC_MIME => {
+                        let (format, mut text) = match (src_mime.as_str(), range) {
+                            (SHEET_MIME, Some(range)) => {
+                                let values = self
+                                    .google_get(&format!(
+                                        "https://sheets.googleapis.com/v4/spreadsheets/{}/values/{}",
+                                        id,
+                                        urlencoding::encode(range)
+                                    ))
+                                    .await?;
+                                v["range"] = values.get("range").cloned().unwrap_or(json!(range));
+                                ("csv", values_to_csv(&values))
+                            }
+                            (mime, _) => {
+                                // Drive's CSV export covers only the first sheet
+                                let (format, target) = if mime == SHEET_MIME {
+                                    ("csv", "text/csv")
+                                } else {
+                                    ("markdown", "text/markdown")
+                                };
+                                let mut resp = hub
+                                    .files()
+                                    .export(file_id, target)
+                                    .doit()
+                                    .await
+                                    .map_err(|e| {
+                                        ConnectorError::Other(format!("drive export error: {}", e))
+                                    })?;
+                                let bytes = hyper::body::to_bytes(resp.body_mut())
+                                    .await
+                                    .map_err(|e| ConnectorError::Other(format!("read body: {}", e)))?;
+                                (format, String::from_utf8_lossy(&bytes).into_owned())
+                            }
+                        };
+                        let truncated = truncate_chars(&mut text, max_chars);
+                        v["format"] = json!(format);
+                        v["text"] = json!(text);
+                        v["truncated"] = json!(truncated);
+                    }
+                    PDF_MIME => {
+                        let (mut resp, _) = hub
+                            .files()
+                            .get(file_id)
+                            .param("alt", "media")
+                            .doit()
+                            .await
+                            .map_err(|e| {
+                                ConnectorError::Other(format!("drive download error: {}", e))
+                            })?;
+                        let bytes = hyper::body::to_bytes(resp.body_mut())
+                            .await
+                            .map_err(|e| ConnectorError::Other(format!("read body: {}", e)))?;
+                        let options = PdfOptions {
+                            max_chars,
+                            sections,
+                            ..Default::default()
+                        };
+                        let pdf = crate::pdf::extract_on_pool(bytes, options).await?;
+                        v["format"] = json!("pdf");
+                        v["page_count"] = json!(pdf.page_count);
+                        v["pages"] = json!(pdf.pages);
+                        if sections {
+                            v["sections"] = json!(pdf.sections);
+                        }
+                        v["truncated"] = json!(pdf.truncated);
+                    }
+                    _ => {
+                        return Err(ConnectorError::InvalidParams(format!(
+                            "export handles Google Docs, Slides, Sheets and PDFs, not '{}'; use download_file or export_file instead.",
+                            src_mime
+                        )))
+                    }
+                }
+                structured_result_with_text(&v, None)
+            }
             "upload_file" => {
                 use base64::Engine as _;
                 let name = args.get("name").and_then(|v| v.as_str()).ok_or(
@@ -657,36 +846,192 @@ impl Connector for DriveConnector {
                 structured_result_with_text(&v, None)
             }
             "list_changes" => {
-                let page_token = args.get("page_token").and_then(|v| v.as_str()).ok_or(
-                    ConnectorError::InvalidParams("page_token is required".to_string()),
-                )?;
+                let folder_ids: std::collections::HashSet<String> = args
+                    .get("folder_ids")
+                    .and_then(|v| v.as_array())
+                    .map(|ids| {
+                        ids.iter()
+                            .filter_map(|v| v.as_str())
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let limit = args
+                    .get("limit")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(DEFAULT_CHANGES_LIMIT)
+                    .clamp(1, 10_000) as usize;
                 let page_size = args
                     .get("page_size")
                     .and_then(|v| v.as_i64())
-                    .unwrap_or(100);
-                let store = FileAuthStore::new_default();
-                let auth = store
-                    .load(self.name())
-                    .or_else(|| store.load("google-common"))
-                    .ok_or_else(|| {
-                        ConnectorError::Authentication("No tokens stored".to_string())
-                    })?;
-                let token = auth.get("access_token").cloned().ok_or_else(|| {
-                    ConnectorError::Authentication("Missing access_token".to_string())
-                })?;
+                    .unwrap_or(1_000)
+                    .clamp(1, 1_000);
                 let client = crate::oauth_client::google_client::new_https_client();
-                let hub = drive3::DriveHub::new(client, token.clone());
-                let (_, changes) = hub
-                    .changes()
-                    .list(page_token)
-                    .page_size(page_size as i32)
+                let hub = drive3::DriveHub::new(client, self.access_token()?);
+
+                let Some(mut page_token) = args
+                    .get("page_token")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string)
+                else {
+                    let (_, start) =
+                        hub.changes()
+                            .get_start_page_token()
+                            .doit()
+                            .await
+                            .map_err(|e| {
+                                ConnectorError::Other(format!(
+                                    "drive getStartPageToken error: {}",
+                                    e
+                                ))
+                            })?;
+                    let v = json!({
+                        "changes": [],
+                        "next_page_token": null,
+                        "new_start_page_token": start.start_page_token,
+                        "note": "Baseline only; pass new_start_page_token as page_token to get later changes.",
+                    });
+                    return structured_result_with_text(&v, None);
+                };
+
+                let mut changes = Vec::new();
+                let mut scanned = 0usize;
+                let mut new_start = None;
+                let mut next_page_token = None;
+                loop {
+                    let (_, list) = hub
+                        .changes()
+                        .list(&page_token)
+                        .page_size(page_size as i32)
+                        .param("fields", CHANGE_FIELDS)
+                        .doit()
+                        .await
+                        .map_err(|e| {
+                            ConnectorError::Other(format!("drive changes.list error: {}", e))
+                        })?;
+                    for change in list.changes.unwrap_or_default() {
+                        scanned += 1;
+                        let change = serde_json::to_value(&change)
+                            .map_err(|e| ConnectorError::Other(format!("serde: {}", e)))?;
+                        if change_in_folders(&change, &folder_ids) {
+                            changes.push(summarize_change(&change));
+                        }
+                    }
+                    if let Some(token) = list.new_start_page_token {
+                        new_start = Some(token);
+                        break;
+                    }
+                    match list.next_page_token {
+                        Some(token) if scanned < limit => page_token = token,
+                        token => {
+                            next_page_token = token;
+                            break;
+                        }
+                    }
+                }
+                let v = json!({
+                    "changes": changes,
+                    "scanned": scanned,
+                    "next_page_token": next_page_token,
+                    "new_start_page_token": new_start,
+                });
+                structured_result_with_text(&v, None)
+            }
+            "list_folder_tree" => {
+                let folder_id = args
+                    .get("folder_id")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("root");
+                let max_depth = args
+                    .get("max_depth")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(DEFAULT_TREE_DEPTH)
+                    .clamp(1, MAX_TREE_DEPTH) as usize;
+                let max_items = args
+                    .get("max_items")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(DEFAULT_TREE_ITEMS)
+                    .clamp(1, MAX_TREE_ITEMS) as usize;
+                let client = crate::oauth_client::google_client::new_https_client();
+                let hub = drive3::DriveHub::new(client, self.access_token()?);
+                let (_, root) = hub
+                    .files()
+                    .get(folder_id)
+                    .param("fields", "id,name,mimeType")
                     .doit()
                     .await
-                    .map_err(|e| {
-                        ConnectorError::Other(format!("drive changes.list error: {}", e))
-                    })?;
-                let v = serde_json::to_value(&changes)
+                    .map_err(|e| ConnectorError::Other(format!("drive get meta: {}", e)))?;
+                let root = serde_json::to_value(&root)
                     .map_err(|e| ConnectorError::Other(format!("serde: {}", e)))?;
+                if !is_folder(&root) {
+                    return Err(ConnectorError::InvalidParams(format!(
+                        "{} is not a folder",
+                        folder_id
+                    )));
+                }
+
+                let mut items = Vec::new();
+                let mut truncated = false;
+                let mut queue = std::collections::VecDeque::new();
+                queue.push_back((
+                    root.get("id")
+                        .and_then(|i| i.as_str())
+                        .unwrap_or(folder_id)
+                        .to_string(),
+                    String::new(),
+                    0usize,
+                ));
+                'walk: while let Some((parent_id, parent_path, depth)) = queue.pop_front() {
+                    let q = format!(
+                        "'{}' in parents and trashed = false",
+                        parent_id.replace('\\', "\\\\").replace('\'', "\\'")
+                    );
+                    let mut cursor: Option<String> = None;
+                    loop {
+                        let mut call = hub
+                            .files()
+                            .list()
+                            .q(&q)
+                            .page_size(1_000)
+                            .param("fields", TREE_FILE_FIELDS);
+                        if let Some(t) = &cursor {
+                            call = call.param("pageToken", t);
+                        }
+                        let (_, list) = call
+                            .doit()
+                            .await
+                            .map_err(|e| ConnectorError::Other(format!("drive error: {}", e)))?;
+                        for file in list.files.unwrap_or_default() {
+                            if items.len() >= max_items {
+                                truncated = true;
+                                break 'walk;
+                            }
+                            let file = serde_json::to_value(&file)
+                                .map_err(|e| ConnectorError::Other(format!("serde: {}", e)))?;
+                            let entry = tree_entry(&file, &parent_id, &parent_path, depth + 1);
+                            if is_folder(&file) && depth + 1 < max_depth {
+                                if let Some(id) = file.get("id").and_then(|i| i.as_str()) {
+                                    queue.push_back((
+                                        id.to_string(),
+                                        entry["path"].as_str().unwrap_or_default().to_string(),
+                                        depth + 1,
+                                    ));
+                                }
+                            }
+                            items.push(entry);
+                        }
+                        cursor = list.next_page_token;
+                        if cursor.is_none() {
+                            break;
+                        }
+                    }
+                }
+                let v = json!({
+                    "root": {"id": root.get("id"), "name": root.get("name")},
+                    "max_depth": max_depth,
+                    "items": items,
+                    "truncated": truncated,
+                });
                 structured_result_with_text(&v, None)
             }
             "upload_file_from_path" => {
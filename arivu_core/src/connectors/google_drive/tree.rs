@@ -0,0 +1,106 @@
+// src/connectors/google_drive/tree.rs
+//
+// Shapes for `list_folder_tree` and `list_changes`: flat tree entries with
+// slash-joined paths, and concise change records that can be filtered to
+// the folders a caller is mirroring.
+
+use std::collections::HashSet;
+
+use serde_json::{json, Value};
+
+pub(super) const FOLDER_MIME: &str = "application/vnd.google-apps.folder";
+
+pub(super) const TREE_FILE_FIELDS: &str =
+    "nextPageToken,files(id,name,mimeType,modifiedTime,size,md5Checksum)";
+pub(super) const CHANGE_FIELDS: &str = "nextPageToken,newStartPageToken,changes(fileId,removed,time,changeType,file(id,name,mimeType,parents,trashed,modifiedTime,size,md5Checksum))";
+
+pub(super) fn is_folder(file: &Value) -> bool {
+    file.get("mimeType").and_then(|m| m.as_str()) == Some(FOLDER_MIME)
+}
+
+/// One row of the flattened tree; `parent_path` is empty at the root.
+pub(super) fn tree_entry(file: &Value, parent_id: &str, parent_path: &str, depth: usize) -> Value {
+    let name = file
+        .get("name")
+        .and_then(|n| n.as_str())
+        .unwrap_or_default();
+    let path = if parent_path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}/{}", parent_path, name)
+    };
+    json!({
+        "id": file.get("id"),
+        "name": name,
+        "path": path,
+        "parent_id": parent_id,
+        "depth": depth,
+        "is_folder": is_folder(file),
+        "mime_type": file.get("mimeType"),
+        "size": file.get("size"),
+        "modified_time": file.get("modifiedTime"),
+        "md5": file.get("md5Checksum"),
+    })
+}
+
+/// Whether a change concerns a direct child of one of `folders`. Removals
+/// carry no file, so they always pass; callers match them by file id.
+pub(super) fn change_in_folders(change: &Value, folders: &HashSet<String>) -> bool {
+    if folders.is_empty() {
+        return true;
+    }
+    match change.pointer("/file/parents").and_then(|p| p.as_array()) {
+        Some(parents) => parents
+            .iter()
+            .filter_map(|p| p.as_str())
+            .any(|p| folders.contains(p)),
+        None => change.get("removed").and_then(|r| r.as_bool()) == Some(true),
+    }
+}
+
+pub(super) fn summarize_change(change: &Value) -> Value {
+    let file = change.get("file");
+    let field = |name: &str| file.and_then(|f| f.get(name)).cloned();
+    json!({
+        "file_id": change.get("fileId"),
+        "time": change.get("time"),
+        "removed": change.get("removed").and_then(|r| r.as_bool()).unwrap_or(false),
+        "trashed": field("trashed"),
+        "name": field("name"),
+        "mime_type": field("mimeType"),
+        "parents": field("parents"),
+        "modified_time": field("modifiedTime"),
+        "size": field("size"),
+        "md5": field("md5Checksum"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_paths_and_filters_changes() {
+        let folder = json!({"id": "f2", "name": "2024", "mimeType": FOLDER_MIME});
+        let entry = tree_entry(&folder, "f1", "Reports", 2);
+        assert_eq!(entry["path"], "Reports/2024");
+        assert_eq!(entry["is_folder"], true);
+        let top = tree_entry(&json!({"id": "d1", "name": "a.txt"}), "root", "", 1);
+        assert_eq!(top["path"], "a.txt");
+
+        let folders: HashSet<String> = ["f2".to_string()].into_iter().collect();
+        let inside = json!({"fileId": "d9", "removed": false,
+            "file": {"id": "d9", "name": "q1.pdf", "parents": ["f2"], "trashed": false}});
+        let outside = json!({"fileId": "d8", "file": {"id": "d8", "parents": ["f7"]}});
+        let removed = json!({"fileId": "d7", "removed": true});
+        assert!(change_in_folders(&inside, &folders));
+        assert!(!change_in_folders(&outside, &folders));
+        assert!(change_in_folders(&removed, &folders));
+        assert!(change_in_folders(&outside, &HashSet::new()));
+
+        let summary = summarize_change(&inside);
+        assert_eq!(summary["file_id"], "d9");
+        assert_eq!(summary["name"], "q1.pdf");
+        assert_eq!(summarize_change(&removed)["removed"], true);
+    }
+}
@@ -0,0 +1,106 @@
+// src/connectors/google_gmail/attachments.rs
+//
+// Finding attachments in a message's MIME part tree. Gmail's attachmentId
+// changes between fetches, so callers select by part_id or filename.
+
+use serde_json::{json, Value};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct AttachmentRef {
+    pub part_id: String,
+    pub filename: String,
+    pub mime_type: Option<String>,
+    pub size: u64,
+    pub attachment_id: Option<String>,
+    /// Small attachments come inline as base64url instead of by id
+    pub inline_data: Option<String>,
+}
+
+impl AttachmentRef {
+    pub fn matches(&self, selector: &str) -> bool {
+        self.part_id == selector || self.filename.eq_ignore_ascii_case(selector)
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "part_id": self.part_id,
+            "filename": self.filename,
+            "mime_type": self.mime_type,
+            "size": self.size,
+        })
+    }
+}
+
+/// Every part with a filename, depth first.
+pub(super) fn collect_attachments(payload: &Value) -> Vec<AttachmentRef> {
+    let mut out = Vec::new();
+    walk(payload, &mut out);
+    out
+}
+
+fn walk(part: &Value, out: &mut Vec<AttachmentRef>) {
+    let filename = part
+        .get("filename")
+        .and_then(|f| f.as_str())
+        .unwrap_or_default();
+    if !filename.is_empty() {
+        let body = part.get("body");
+        out.push(AttachmentRef {
+            part_id: part
+                .get("partId")
+                .and_then(|p| p.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            filename: filename.to_string(),
+            mime_type: part
+                .get("mimeType")
+                .and_then(|m| m.as_str())
+                .map(str::to_string),
+            size: body
+                .and_then(|b| b.get("size"))
+                .and_then(|s| s.as_u64())
+                .unwrap_or(0),
+            attachment_id: body
+                .and_then(|b| b.get("attachmentId"))
+                .and_then(|a| a.as_str())
+                .map(str::to_string),
+            inline_data: body
+                .and_then(|b| b.get("data"))
+                .and_then(|d| d.as_str())
+                .map(str::to_string),
+        });
+    }
+    if let Some(parts) = part.get("parts").and_then(|p| p.as_array()) {
+        for child in parts {
+            walk(child, out);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_nested_attachments() {
+        let payload = json!({
+            "partId": "", "mimeType": "multipart/mixed", "filename": "",
+            "parts": [
+                {"partId": "0", "mimeType": "multipart/alternative", "filename": "", "parts": [
+                    {"partId": "0.0", "mimeType": "text/plain", "filename": "", "body": {"size": 12, "data": "SGk="}}
+                ]},
+                {"partId": "1", "mimeType": "application/pdf", "filename": "Invoice.pdf",
+                 "body": {"size": 48213, "attachmentId": "ANGjdJ8"}},
+                {"partId": "2", "mimeType": "text/csv", "filename": "rows.csv",
+                 "body": {"size": 9, "data": "YSxiCjEsMgo="}}
+            ]
+        });
+        let found = collect_attachments(&payload);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].attachment_id.as_deref(), Some("ANGjdJ8"));
+        assert!(found[0].matches("invoice.pdf"));
+        assert!(found[1].matches("2"));
+        assert_eq!(found[1].inline_data.as_deref(), Some("YSxiCjEsMgo="));
+        assert_eq!(found[0].to_json()["size"], 48213);
+    }
+}
@@ -0,0 +1,206 @@
+// src/connectors/google_gmail/mime.rs
+//
+// RFC 5322 message assembly for send_email/create_draft. Gmail takes the
+// whole message base64url-encoded in `raw`, so headers, threading and the
+// text/HTML alternative parts are built here.
+
+use base64::Engine as _;
+use serde_json::Value;
+
+use crate::error::ConnectorError;
+
+#[derive(Debug, Default, Clone)]
+pub(super) struct OutgoingEmail {
+    pub to: Vec<String>,
+    pub cc: Vec<String>,
+    pub bcc: Vec<String>,
+    pub subject: String,
+    pub text: Option<String>,
+    pub html: Option<String>,
+    /// Message-ID of the message being answered
+    pub in_reply_to: Option<String>,
+    pub references: Option<String>,
+}
+
+/// Recipients from a comma-separated string or an array of strings.
+pub(super) fn recipients(value: Option<&Value>) -> Result<Vec<String>, ConnectorError> {
+    let raw: Vec<String> = match value {
+        None | Some(Value::Null) => Vec::new(),
+        Some(Value::String(s)) => s.split(',').map(str::to_string).collect(),
+        Some(Value::Array(items)) => items
+            .iter()
+            .filter_map(|v| v.as_str())
+            .map(str::to_string)
+            .collect(),
+        Some(other) => {
+            return Err(ConnectorError::InvalidParams(format!(
+                "Recipients must be a string or array of strings, got {}",
+                other
+            )))
+        }
+    };
+    let mut out = Vec::new();
+    for address in raw {
+        let address = address.trim();
+        if address.is_empty() {
+            continue;
+        }
+        if !address.contains('@') || address.contains(['\r', '\n']) {
+            return Err(ConnectorError::InvalidParams(format!(
+                "Invalid email address '{}'",
+                address
+            )));
+        }
+        out.push(address.to_string());
+    }
+    Ok(out)
+}
+
+/// "Re: " unless the subject already has it.
+pub(super) fn reply_subject(subject: &str) -> String {
+    let subject = subject.trim();
+    if subject
+        .get(..3)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("re:"))
+    {
+        subject.to_string()
+    } else {
+        format!("Re: {}", subject)
+    }
+}
+
+/// RFC 2047 encoded-word for non-ASCII header text.
+fn encode_header(text: &str) -> String {
+    if text.is_ascii() {
+        text.to_string()
+    } else {
+        format!(
+            "=?UTF-8?B?{}?=",
+            base64::engine::general_purpose::STANDARD.encode(text)
+        )
+    }
+}
+
+fn base64_body(text: &str) -> String {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    encoded
+        .as_bytes()
+        .chunks(76)
+        .map(|line| String::from_utf8_lossy(line).into_owned())
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+fn part(content_type: &str, body: &str) -> String {
+    format!(
+        "Content-Type: {}; charset=\"UTF-8\"\r\nContent-Transfer-Encoding: base64\r\n\r\n{}\r\n",
+        content_type,
+        base64_body(body)
+    )
+}
+
+/// The full message with CRLF line endings.
+pub(super) fn build_message(
+    email: &OutgoingEmail,
+    boundary: &str,
+) -> Result<String, ConnectorError> {
+    if email.to.is_empty() && email.cc.is_empty() && email.bcc.is_empty() {
+        return Err(ConnectorError::InvalidParams(
+            "At least one recipient (to, cc or bcc) is required".to_string(),
+        ));
+    }
+    let header_values = [
+        Some(email.subject.as_str()),
+        email.in_reply_to.as_deref(),
+        email.references.as_deref(),
+    ];
+    if header_values
+        .iter()
+        .flatten()
+        .any(|v| v.contains(['\r', '\n']))
+    {
+        return Err(ConnectorError::InvalidParams(
+            "Subject and threading headers must be a single line".to_string(),
+        ));
+    }
+
+    let mut out = String::new();
+    for (name, list) in [("To", &email.to), ("Cc", &email.cc), ("Bcc", &email.bcc)] {
+        if !list.is_empty() {
+            out.push_str(&format!("{}: {}\r\n", name, list.join(", ")));
+        }
+    }
+    out.push_str(&format!("Subject: {}\r\n", encode_header(&email.subject)));
+    if let Some(id) = &email.in_reply_to {
+        out.push_str(&format!("In-Reply-To: {}\r\n", id));
+    }
+    if let Some(refs) = email.references.as_ref().or(email.in_reply_to.as_ref()) {
+        out.push_str(&format!("References: {}\r\n", refs));
+    }
+    out.push_str("MIME-Version: 1.0\r\n");
+
+    match (&email.text, &email.html) {
+        (Some(text), Some(html)) => {
+            out.push_str(&format!(
+                "Content-Type: multipart/alternative; boundary=\"{}\"\r\n\r\n",
+                boundary
+            ));
+            out.push_str(&format!("--{}\r\n{}", boundary, part("text/plain", text)));
+            out.push_str(&format!("--{}\r\n{}", boundary, part("text/html", html)));
+            out.push_str(&format!("--{}--\r\n", boundary));
+        }
+        (None, Some(html)) => out.push_str(&part("text/html", html)),
+        (Some(text), None) => out.push_str(&part("text/plain", text)),
+        (None, None) => {
+            return Err(ConnectorError::InvalidParams(
+                "Provide 'body' (plain text), 'html_body', or both".to_string(),
+            ))
+        }
+    }
+    Ok(out)
+}
+
+/// base64url for Gmail's `raw` field.
+pub(super) fn encode_raw(message: &str) -> String {
+    base64::engine::general_purpose::URL_SAFE.encode(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn builds_threaded_alternative_message() {
+        let email = OutgoingEmail {
+            to: recipients(Some(&json!("ada@example.com, bob@example.com"))).unwrap(),
+            cc: recipients(Some(&json!(["carol@example.com"]))).unwrap(),
+            bcc: Vec::new(),
+            subject: reply_subject("Déjà vu"),
+            text: Some("Hi".to_string()),
+            html: Some("<p>Hi</p>".to_string()),
+            in_reply_to: Some("<abc@mail.example.com>".to_string()),
+            references: None,
+        };
+        let message = build_message(&email, "b1").unwrap();
+        assert!(message.starts_with(
+            "To: ada@example.com, bob@example.com\r\nCc: carol@example.com\r\nSubject: =?UTF-8?B?"
+        ));
+        assert!(message.contains(
+            "In-Reply-To: <abc@mail.example.com>\r\nReferences: <abc@mail.example.com>\r\n"
+        ));
+        assert!(message.contains("multipart/alternative; boundary=\"b1\""));
+        assert!(message.contains("--b1\r\nContent-Type: text/html; charset=\"UTF-8\""));
+        assert!(message.ends_with("--b1--\r\n"));
+        assert_eq!(reply_subject("RE: hello"), "RE: hello");
+
+        assert!(recipients(Some(&json!("not-an-address"))).is_err());
+        let injected = OutgoingEmail {
+            to: vec!["ada@example.com".to_string()],
+            subject: "hi\r\nBcc: eve@example.com".to_string(),
+            text: Some("x".to_string()),
+            ..Default::default()
+        };
+        assert!(build_message(&injected, "b").is_err());
+    }
+}
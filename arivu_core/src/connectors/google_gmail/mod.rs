@@ -8,12 +8,25 @@ use std::sync::Arc;
 use crate::auth::AuthDetails;
 use crate::auth_store::{AuthStore, FileAuthStore};
 use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::doc_text::DocumentKind;
 use crate::error::ConnectorError;
-use crate::utils::{collect_paginated_with_cursor, structured_result_with_text, Page};
+use crate::utils::{collect_paginated_with_cursor, structured_result_with_text, write_gate, Page};
 use crate::Connector;
 #[allow(unused_imports)]
 use google_gmail1 as gmail1;
 
+mod attachments;
+mod mime;
+
+use attachments::collect_attachments;
+use mime::{build_message, encode_raw, recipients, reply_subject, OutgoingEmail};
+
+const GMAIL_API_BASE: &str = "https://gmail.googleapis.com/gmail/v1/users/me";
+const DEFAULT_ATTACHMENT_MAX_BYTES: u64 = 10 * 1024 * 1024;
+const DEFAULT_ATTACHMENT_MAX_CHARS: usize = 50_000;
+/// messages.batchModify accepts at most this many ids per call
+const BATCH_MODIFY_MAX_IDS: usize = 1_000;
+
 pub struct GmailConnector {
     auth: AuthDetails,
 }
@@ -22,6 +35,208 @@ impl GmailConnector {
     pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
         Ok(Self { auth })
     }
+
+    fn access_token(&self) -> Result<String, ConnectorError> {
+        if let Some(token) = self.auth.get("access_token") {
+            return Ok(token.clone());
+        }
+        let store = FileAuthStore::new_default();
+        let auth = store
+            .load("google-gmail")
+            .or_else(|| store.load("google-common"))
+            .ok_or_else(|| ConnectorError::Authentication("No tokens stored".to_string()))?;
+        auth.get("access_token")
+            .cloned()
+            .ok_or_else(|| ConnectorError::Authentication("Missing access_token".to_string()))
+    }
+
+    async fn api(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<&serde_json::Value>,
+    ) -> Result<serde_json::Value, ConnectorError> {
+        let token = self.access_token()?;
+        let mut req = reqwest::Client::new()
+            .request(method, format!("{}{}", GMAIL_API_BASE, path))
+            .bearer_auth(token)
+            .query(query);
+        if let Some(body) = body {
+            req = req.json(body);
+        }
+        let resp = req.send().await.map_err(ConnectorError::HttpRequest)?;
+        let status = resp.status();
+        let v: serde_json::Value = resp.json().await.unwrap_or(json!({}));
+        if !status.is_success() {
+            let message = v
+                .pointer("/error/message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("request failed");
+            return Err(match status.as_u16() {
+                401 | 403 => ConnectorError::Authentication(format!("gmail: {}", message)),
+                404 => ConnectorError::ResourceNotFound,
+                _ => ConnectorError::Other(format!("gmail error {}: {}", status, message)),
+            });
+        }
+        Ok(v)
+    }
+
+    /// Label ids for names or ids, resolved against the mailbox's labels.
+    async fn resolve_labels(&self, wanted: &[String]) -> Result<Vec<String>, ConnectorError> {
+        if wanted.is_empty() {
+            return Ok(Vec::new());
+        }
+        let labels = self.api(reqwest::Method::GET, "/labels", &[], None).await?;
+        resolve_label_ids(wanted, &labels)
+    }
+
+    /// Build the outgoing message, threading it onto `reply_to_message_id`
+    /// when given. Returns the raw message and the thread to file it under.
+    async fn compose(
+        &self,
+        args: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<(String, Option<String>), ConnectorError> {
+        let mut email = OutgoingEmail {
+            to: recipients(args.get("to"))?,
+            cc: recipients(args.get("cc"))?,
+            bcc: recipients(args.get("bcc"))?,
+            subject: args
+                .get("subject")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            text: args
+                .get("body")
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            html: args
+                .get("html_body")
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            ..Default::default()
+        };
+
+        let mut thread_id = None;
+        if let Some(id) = args.get("reply_to_message_id").and_then(|v| v.as_str()) {
+            let original = self
+                .api(
+                    reqwest::Method::GET,
+                    &format!("/messages/{}", urlencoding::encode(id)),
+                    &[
+                        ("format", "metadata"),
+                        ("metadataHeaders", "Message-ID"),
+                        ("metadataHeaders", "References"),
+                        ("metadataHeaders", "Subject"),
+                    ],
+                    None,
+                )
+                .await?;
+            let header = |name: &str| {
+                original
+                    .pointer("/payload/headers")
+                    .and_then(|h| h.as_array())
+                    .and_then(|headers| {
+                        headers.iter().find(|h| {
+                            h.get("name")
+                                .and_then(|n| n.as_str())
+                                .is_some_and(|n| n.eq_ignore_ascii_case(name))
+                        })
+                    })
+                    .and_then(|h| h.get("value"))
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string)
+            };
+            let message_id = header("Message-ID");
+            email.references = match (header("References"), &message_id) {
+                (Some(refs), Some(mid)) => Some(format!("{} {}", refs, mid)),
+                (None, mid) => mid.clone(),
+                (refs, None) => refs,
+            };
+            email.in_reply_to = message_id;
+            if email.subject.trim().is_empty() {
+                email.subject = reply_subject(&header("Subject").unwrap_or_default());
+            }
+            thread_id = original
+                .get("threadId")
+                .and_then(|t| t.as_str())
+                .map(str::to_string);
+        }
+
+        let boundary = format!(
+            "arivu-{}",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        );
+        Ok((build_message(&email, &boundary)?, thread_id))
+    }
+}
+
+/// Strings from a comma-separated string or an array.
+fn string_list(value: Option<&serde_json::Value>) -> Vec<String> {
+    match value {
+        Some(serde_json::Value::String(s)) => s
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect(),
+        Some(serde_json::Value::Array(items)) => items
+            .iter()
+            .filter_map(|v| v.as_str())
+            .map(str::to_string)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Match each wanted label by id or, case-insensitively, by name.
+fn resolve_label_ids(
+    wanted: &[String],
+    labels: &serde_json::Value,
+) -> Result<Vec<String>, ConnectorError> {
+    let labels = labels
+        .get("labels")
+        .and_then(|l| l.as_array())
+        .cloned()
+        .unwrap_or_default();
+    wanted
+        .iter()
+        .map(|w| {
+            labels
+                .iter()
+                .find(|l| {
+                    l.get("id").and_then(|i| i.as_str()) == Some(w.as_str())
+                        || l.get("name")
+                            .and_then(|n| n.as_str())
+                            .is_some_and(|n| n.eq_ignore_ascii_case(w))
+                })
+                .and_then(|l| l.get("id").and_then(|i| i.as_str()))
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    ConnectorError::InvalidParams(format!("Unknown label '{}'; see list_labels", w))
+                })
+        })
+        .collect()
+}
+
+fn compose_schema() -> serde_json::Map<String, serde_json::Value> {
+    json!({
+        "type": "object",
+        "properties": {
+            "to": { "type": ["string", "array"], "items": { "type": "string" }, "description": "Recipients (comma-separated or array)" },
+            "cc": { "type": ["string", "array"], "items": { "type": "string" } },
+            "bcc": { "type": ["string", "array"], "items": { "type": "string" } },
+            "subject": { "type": "string", "description": "Defaults to \"Re: <original>\" when replying" },
+            "body": { "type": "string", "description": "Plain-text body" },
+            "html_body": { "type": "string", "description": "HTML body; sent as an alternative when body is also set" },
+            "reply_to_message_id": { "type": "string", "description": "Gmail message id to reply to (sets threadId, In-Reply-To and References)" },
+            "confirm": { "type": "boolean", "description": "Must be true; confirms the user approved this email" }
+        },
+        "required": ["confirm"]
+    })
+    .as_object()
+    .expect("Schema object")
+    .clone()
 }
 
 #[async_trait]
@@ -30,7 +245,7 @@ impl Connector for GmailConnector {
         "google-gmail"
     }
     fn description(&self) -> &'static str {
-        "Gmail connector (list messages)."
+        "Gmail connector (list and read messages; opt-in send and drafts)."
     }
     async fn capabilities(&self) -> ServerCapabilities {
         ServerCapabilities {
@@ -78,10 +293,15 @@ impl Connector for GmailConnector {
         _r: Option<PaginatedRequestParam>,
     ) -> Result<ListToolsResult, ConnectorError> {
         let tools = vec![
-            Tool { name: Cow::Borrowed("list_messages"), title: None, description: Some(Cow::Borrowed("List messages (requires explicit user permission).")), input_schema: Arc::new(json!({"type":"object","properties":{"q":{"type":"string"},"max_results":{"type":"integer","minimum":1,"maximum":5000},"page_token":{"type":"string","description":"Optional cursor from a previous response (nextPageToken)."},"response_format":{"type":"string","enum":["concise","detailed"]}},"required":[]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("list_messages"), title: None, description: Some(Cow::Borrowed("List messages, optionally only those carrying all of label_ids (requires explicit user permission).")), input_schema: Arc::new(json!({"type":"object","properties":{"q":{"type":"string"},"label_ids":{"type":["string","array"],"items":{"type":"string"},"description":"Label ids or names, e.g. INBOX, UNREAD, Receipts"},"max_results":{"type":"integer","minimum":1,"maximum":5000},"page_token":{"type":"string","description":"Optional cursor from a previous response (nextPageToken)."},"response_format":{"type":"string","enum":["concise","detailed"]}},"required":[]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
             Tool { name: Cow::Borrowed("decode_message_raw"), title: None, description: Some(Cow::Borrowed("Decode a raw message (requires explicit user permission).")), input_schema: Arc::new(json!({"type":"object","properties":{"raw_base64url":{"type":"string"}},"required":["raw_base64url"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
             Tool { name: Cow::Borrowed("get_message"), title: None, description: Some(Cow::Borrowed("Get a message by id (requires explicit user permission).")), input_schema: Arc::new(json!({"type":"object","properties":{"id":{"type":"string"},"format":{"type":"string"},"response_format":{"type":"string","enum":["concise","detailed"]}},"required":["id"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
             Tool { name: Cow::Borrowed("get_thread"), title: None, description: Some(Cow::Borrowed("Get a thread by id (requires explicit user permission).")), input_schema: Arc::new(json!({"type":"object","properties":{"id":{"type":"string"}},"required":["id"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("list_labels"), title: None, description: Some(Cow::Borrowed("List the mailbox's system and user labels; with_counts adds total/unread message counts (requires explicit user permission).")), input_schema: Arc::new(json!({"type":"object","properties":{"with_counts":{"type":"boolean"}}}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("batch_modify"), title: None, description: Some(Cow::Borrowed("Add/remove labels or mark read/unread/archived on up to 1000 message ids in one call. Changes the mailbox: disabled unless allow_writes is configured; pass confirm=true after the user approves.")), input_schema: Arc::new(json!({"type":"object","properties":{"ids":{"type":"array","items":{"type":"string"},"description":"Message ids"},"add_labels":{"type":["string","array"],"items":{"type":"string"},"description":"Label ids or names to add"},"remove_labels":{"type":["string","array"],"items":{"type":"string"},"description":"Label ids or names to remove"},"mark_read":{"type":"boolean","description":"true removes UNREAD, false adds it"},"archive":{"type":"boolean","description":"Remove INBOX"},"confirm":{"type":"boolean","description":"Must be true; confirms the user approved this change"}},"required":["ids","confirm"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("get_attachments"), title: None, description: Some(Cow::Borrowed("List a message's attachments, and download selected ones (by part_id or filename, or all=true) with text extracted from PDF, DOCX, HTML and text files (requires explicit user permission).")), input_schema: Arc::new(json!({"type":"object","properties":{"id":{"type":"string","description":"Message id"},"select":{"type":"array","items":{"type":"string"},"description":"part_ids or filenames to download"},"all":{"type":"boolean","description":"Download every attachment"},"max_bytes":{"type":"integer","minimum":1,"description":"Skip attachments larger than this (default 10 MiB)"},"max_chars":{"type":"integer","minimum":1,"description":"Truncate each extracted text (default 50000)"},"sections":{"type":"boolean","description":"Also split PDF text into sections with their starting page"}},"required":["id"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("create_draft"), title: None, description: Some(Cow::Borrowed("Create a draft (To/CC/BCC, plain and/or HTML body, optional reply threading) for the user to review in Gmail. Prefer this over send_email. Disabled unless allow_writes is configured; pass confirm=true after the user approves.")), input_schema: Arc::new(compose_schema()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("send_email"), title: None, description: Some(Cow::Borrowed("Send an email immediately (To/CC/BCC, plain and/or HTML body, reply threading via reply_to_message_id). Disabled unless allow_writes is configured; only call after the user has explicitly confirmed the recipients and text, and pass confirm=true. Otherwise use create_draft.")), input_schema: Arc::new(compose_schema()), output_schema: None, annotations: None, icons: None },
         ];
         Ok(ListToolsResult {
             tools,
@@ -118,6 +338,9 @@ impl Connector for GmailConnector {
                     args.get("response_format").and_then(|v| v.as_str()),
                     Some("detailed")
                 );
+                let label_ids = self
+                    .resolve_labels(&string_list(args.get("label_ids")))
+                    .await?;
 
                 let desired = (max.max(1) as u32).clamp(1, 5_000) as usize;
                 let collected = collect_paginated_with_cursor(
@@ -127,12 +350,16 @@ impl Connector for GmailConnector {
                     |cursor, remaining| {
                         let hub = hub.clone();
                         let q = q.to_string();
+                        let label_ids = label_ids.clone();
                         async move {
                             let per_page = (remaining as u32).clamp(1, 500);
                             let mut call = hub.users().messages_list("me").max_results(per_page);
                             if !q.is_empty() {
                                 call = call.q(&q);
                             }
+                            for label in &label_ids {
+                                call = call.add_label_ids(label);
+                            }
                             if let Some(t) = cursor {
                                 call = call.page_token(&t);
                             }
@@ -231,6 +458,296 @@ impl Connector for GmailConnector {
                     .map_err(|e| ConnectorError::Other(format!("serde: {}", e)))?;
                 structured_result_with_text(&v, None)
             }
+            "list_labels" => {
+                let with_counts = args
+                    .get("with_counts")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let listed = self.api(reqwest::Method::GET, "/labels", &[], None).await?;
+                let mut labels = Vec::new();
+                for label in listed
+                    .get("labels")
+                    .and_then(|l| l.as_array())
+                    .cloned()
+                    .unwrap_or_default()
+                {
+                    // Counts are only returned by labels.get
+                    let label = match (with_counts, label.get("id").and_then(|i| i.as_str())) {
+                        (true, Some(id)) => {
+                            self.api(
+                                reqwest::Method::GET,
+                                &format!("/labels/{}", urlencoding::encode(id)),
+                                &[],
+                                None,
+                            )
+                            .await?
+                        }
+                        _ => label,
+                    };
+                    labels.push(json!({
+                        "id": label.get("id"),
+                        "name": label.get("name"),
+                        "type": label.get("type"),
+                        "messagesTotal": label.get("messagesTotal"),
+                        "messagesUnread": label.get("messagesUnread"),
+                    }));
+                }
+                structured_result_with_text(&json!({ "labels": labels }), None)
+            }
+            "batch_modify" => {
+                let confirm = args
+                    .get("confirm")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                write_gate(
+                    self.name(),
+                    "ARIVU_GMAIL_ALLOW_WRITES",
+                    self.auth.get("allow_writes").map(String::as_str),
+                    confirm,
+                )?;
+                let ids = string_list(args.get("ids"));
+                if ids.is_empty() || ids.len() > BATCH_MODIFY_MAX_IDS {
+                    return Err(ConnectorError::InvalidParams(format!(
+                        "ids must hold 1-{} message ids",
+                        BATCH_MODIFY_MAX_IDS
+                    )));
+                }
+                let mut add = self
+                    .resolve_labels(&string_list(args.get("add_labels")))
+                    .await?;
+                let mut remove = self
+                    .resolve_labels(&string_list(args.get("remove_labels")))
+                    .await?;
+                match args.get("mark_read").and_then(|v| v.as_bool()) {
+                    Some(true) => remove.push("UNREAD".to_string()),
+                    Some(false) => add.push("UNREAD".to_string()),
+                    None => {}
+                }
+                if args.get("archive").and_then(|v| v.as_bool()) == Some(true) {
+                    remove.push("INBOX".to_string());
+                }
+                if add.is_empty() && remove.is_empty() {
+                    return Err(ConnectorError::InvalidParams(
+                        "Nothing to change: pass add_labels, remove_labels, mark_read or archive"
+                            .to_string(),
+                    ));
+                }
+                add.sort();
+                add.dedup();
+                remove.sort();
+                remove.dedup();
+                let body = json!({
+                    "ids": ids,
+                    "addLabelIds": add,
+                    "removeLabelIds": remove,
+                });
+                self.api(
+                    reqwest::Method::POST,
+                    "/messages/batchModify",
+                    &[],
+                    Some(&body),
+                )
+                .await?;
+                let v = json!({
+                    "modified": ids.len(),
+                    "addLabelIds": add,
+                    "removeLabelIds": remove,
+                });
+                structured_result_with_text(&v, None)
+            }
+            "get_attachments" => {
+                use base64::Engine as _;
+
+                let id = args
+                    .get("id")
+                    .and_then(|v| v.as_str())
+                    .ok_or(ConnectorError::InvalidParams("id is required".to_string()))?;
+                let selectors: Vec<String> = args
+                    .get("select")
+                    .and_then(|v| v.as_array())
+                    .map(|items| {
+                        items
+                            .iter()
+                            .filter_map(|v| v.as_str())
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let all = args.get("all").and_then(|v| v.as_bool()).unwrap_or(false);
+                let max_bytes = args
+                    .get("max_bytes")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(DEFAULT_ATTACHMENT_MAX_BYTES);
+                let max_chars = args
+                    .get("max_chars")
+                    .and_then(|v| v.as_u64())
+                    .map(|n| n as usize)
+                    .unwrap_or(DEFAULT_ATTACHMENT_MAX_CHARS);
+                let sections = args
+                    .get("sections")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+
+                let message_path = format!("/messages/{}", urlencoding::encode(id));
+                let message = self
+                    .api(
+                        reqwest::Method::GET,
+                        &message_path,
+                        &[("format", "full")],
+                        None,
+                    )
+                    .await?;
+                let found = message
+                    .get("payload")
+                    .map(collect_attachments)
+                    .unwrap_or_default();
+
+                let mut results = Vec::new();
+                for attachment in &found {
+                    let mut entry = attachment.to_json();
+                    let wanted = all || selectors.iter().any(|s| attachment.matches(s));
+                    if !wanted {
+                        results.push(entry);
+                        continue;
+                    }
+                    if attachment.size > max_bytes {
+                        entry["error"] = json!(format!(
+                            "{} bytes, over max_bytes={}",
+                            attachment.size, max_bytes
+                        ));
+                        results.push(entry);
+                        continue;
+                    }
+                    let data = match (&attachment.inline_data, &attachment.attachment_id) {
+                        (Some(data), _) => data.clone(),
+                        (None, Some(attachment_id)) => {
+                            let body = self
+                                .api(
+                                    reqwest::Method::GET,
+                                    &format!(
+                                        "{}/attachments/{}",
+                                        message_path,
+                                        urlencoding::encode(attachment_id)
+                                    ),
+                                    &[],
+                                    None,
+                                )
+                                .await?;
+                            body.get("data")
+                                .and_then(|d| d.as_str())
+                                .unwrap_or_default()
+                                .to_string()
+                        }
+                        (None, None) => String::new(),
+                    };
+                    let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+                        .decode(data.trim_end_matches('='))
+                        .map_err(|e| {
+                            ConnectorError::Other(format!("attachment decode error: {}", e))
+                        })?;
+
+                    let filename = attachment.filename.clone();
+                    let mime_type = attachment.mime_type.clone();
+                    let extracted = crate::cpu_pool::spawn_cpu(move || {
+                        Ok(crate::doc_text::extract_text(
+                            &bytes,
+                            &filename,
+                            mime_type.as_deref(),
+                            max_chars,
+                        )
+                        .map(|doc| {
+                            let pdf_sections = if sections {
+                                doc.pdf_sections()
+                            } else {
+                                Vec::new()
+                            };
+                            (doc, pdf_sections)
+                        }))
+                    })
+                    .await?;
+                    match extracted {
+                        Ok((doc, pdf_sections)) => {
+                            entry["format"] = json!(doc.kind.as_str());
+                            if doc.kind == DocumentKind::Pdf {
+                                entry["pages"] = json!(doc.pages.len());
+                            }
+                            if !pdf_sections.is_empty() {
+                                entry["sections"] = json!(pdf_sections);
+                            }
+                            entry["text"] = json!(doc.text);
+                            entry["truncated"] = json!(doc.truncated);
+                        }
+                        Err(e) => entry["error"] = json!(e.to_string()),
+                    }
+                    results.push(entry);
+                }
+
+                let v = json!({
+                    "id": id,
+                    "threadId": message.get("threadId"),
+                    "attachments": results,
+                });
+                structured_result_with_text(&v, None)
+            }
+            "send_email" => {
+                let confirm = args
+                    .get("confirm")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                write_gate(
+                    self.name(),
+                    "ARIVU_GMAIL_ALLOW_WRITES",
+                    self.auth.get("allow_writes").map(String::as_str),
+                    confirm,
+                )?;
+                let (message, thread_id) = self.compose(&args).await?;
+                let mut body = json!({ "raw": encode_raw(&message) });
+                if let Some(thread_id) = &thread_id {
+                    body["threadId"] = json!(thread_id);
+                }
+                let sent = self
+                    .api(reqwest::Method::POST, "/messages/send", &[], Some(&body))
+                    .await?;
+                let v = json!({
+                    "id": sent.get("id"),
+                    "threadId": sent.get("threadId"),
+                    "labelIds": sent.get("labelIds"),
+                    "status": "sent",
+                });
+                structured_result_with_text(&v, None)
+            }
+            "create_draft" => {
+                let confirm = args
+                    .get("confirm")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                write_gate(
+                    self.name(),
+                    "ARIVU_GMAIL_ALLOW_WRITES",
+                    self.auth.get("allow_writes").map(String::as_str),
+                    confirm,
+                )?;
+                let (message, thread_id) = self.compose(&args).await?;
+                let mut draft_message = json!({ "raw": encode_raw(&message) });
+                if let Some(thread_id) = &thread_id {
+                    draft_message["threadId"] = json!(thread_id);
+                }
+                let draft = self
+                    .api(
+                        reqwest::Method::POST,
+                        "/drafts",
+                        &[],
+                        Some(&json!({ "message": draft_message })),
+                    )
+                    .await?;
+                let v = json!({
+                    "draftId": draft.get("id"),
+                    "messageId": draft.pointer("/message/id"),
+                    "threadId": draft.pointer("/message/threadId"),
+                    "status": "draft_created",
+                });
+                structured_result_with_text(&v, None)
+            }
             "decode_message_raw" => {
                 let raw_base64url = args.get("raw_base64url").and_then(|v| v.as_str()).ok_or(
                     ConnectorError::InvalidParams("raw_base64url is required".to_string()),
@@ -318,6 +835,30 @@ impl Connector for GmailConnector {
         }
     }
     fn config_schema(&self) -> ConnectorConfigSchema {
-        ConnectorConfigSchema { fields: vec![Field { name: "scopes".to_string(), label: "Scopes".to_string(), field_type: FieldType::Text, required: false, description: Some("Use Drive connector auth_start with Gmail scopes: https://www.googleapis.com/auth/gmail.readonly".to_string()), options: None }] }
+        ConnectorConfigSchema {
+            fields: vec![
+                Field { name: "scopes".to_string(), label: "Scopes".to_string(), field_type: FieldType::Text, required: false, description: Some("Use Drive connector auth_start with Gmail scopes: https://www.googleapis.com/auth/gmail.readonly (add https://www.googleapis.com/auth/gmail.compose for send_email/create_draft)".to_string()), options: None },
+                Field { name: "allow_writes".to_string(), label: "Allow Sending".to_string(), field_type: FieldType::Boolean, required: false, description: Some("Enable send_email, create_draft and batch_modify (each call still needs confirm=true).".to_string()), options: None },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_labels_by_id_or_name() {
+        let labels = json!({"labels": [
+            {"id": "INBOX", "name": "INBOX", "type": "system"},
+            {"id": "Label_12", "name": "Receipts", "type": "user"}
+        ]});
+        let wanted = string_list(Some(&json!("receipts, INBOX")));
+        assert_eq!(
+            resolve_label_ids(&wanted, &labels).unwrap(),
+            vec!["Label_12", "INBOX"]
+        );
+        assert!(resolve_label_ids(&["Travel".to_string()], &labels).is_err());
     }
 }
@@ -3,14 +3,54 @@ use crate::error::ConnectorError;
 use crate::utils::structured_result_with_text;
 use crate::{auth::AuthDetails, Connector};
 use async_trait::async_trait;
+use once_cell::sync::Lazy;
 use reqwest::Client;
 use rmcp::model::*;
-use scraper::{Html, Selector};
 use serde::Deserialize;
 use serde_json::{json, Value};
 use std::borrow::Cow;
 use std::sync::Arc;
-use tokio::time::{self, Duration};
+use tokio::sync::Mutex;
+use tokio::time::{sleep, Duration, Instant};
+
+mod parse;
+
+const BASE_URL: &str = "https://scholar.google.com";
+/// Minimum gap between requests, shared by every instance in the process.
+const MIN_INTERVAL: Duration = Duration::from_secs(3);
+/// Retries after a 429/503 or CAPTCHA page, waiting `BACKOFF` and doubling.
+const MAX_RETRIES: u32 = 2;
+const BACKOFF: Duration = Duration::from_secs(10);
+const MAX_RETRY_AFTER_SECS: u64 = 60;
+/// Results per Scholar results page.
+const RESULTS_PAGE_SIZE: usize = 10;
+const MAX_CITED_BY: usize = 50;
+const DEFAULT_AUTHOR_PUBLICATIONS: usize = 20;
+/// Largest `pagesize` the profile page accepts.
+const MAX_AUTHOR_PUBLICATIONS: usize = 100;
+
+static LAST_REQUEST: Lazy<Mutex<Option<Instant>>> = Lazy::new(|| Mutex::new(None));
+
+/// Wait until `MIN_INTERVAL` has passed since the previous request. Holding the
+/// lock while sleeping serializes concurrent callers.
+async fn throttle() {
+    let mut last = LAST_REQUEST.lock().await;
+    if let Some(prev) = *last {
+        let elapsed = prev.elapsed();
+        if elapsed < MIN_INTERVAL {
+            sleep(MIN_INTERVAL - elapsed).await;
+        }
+    }
+    *last = Some(Instant::now());
+}
+
+fn blocked() -> ConnectorError {
+    ConnectorError::Other(
+        "Google Scholar is rate limiting this IP (CAPTCHA). Wait a while before retrying, or use \
+the semantic-scholar connector."
+            .into(),
+    )
+}
 
 #[derive(Debug, Deserialize)]
 struct SearchPapersArgs {
@@ -18,6 +58,22 @@ struct SearchPapersArgs {
     limit: Option<usize>,
 }
 
+#[derive(Debug, Deserialize)]
+struct GetAuthorArgs {
+    author: String,
+    limit: Option<usize>,
+    offset: Option<usize>,
+    sort: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetCitedByArgs {
+    cites_id: Option<String>,
+    query: Option<String>,
+    limit: Option<usize>,
+    offset: Option<usize>,
+}
+
 pub struct GoogleScholarConnector {
     client: Client,
 }
@@ -33,83 +89,196 @@ impl GoogleScholarConnector {
         })
     }
 
+    /// GET a Scholar page, throttled, retrying with backoff when Scholar
+    /// rate limits or serves its CAPTCHA page.
+    async fn fetch(&self, url: &str) -> Result<String, ConnectorError> {
+        let mut delay = BACKOFF;
+        for attempt in 0..=MAX_RETRIES {
+            throttle().await;
+            let response = self
+                .client
+                .get(url)
+                .send()
+                .await
+                .map_err(ConnectorError::HttpRequest)?;
+            let status = response.status();
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|h| h.to_str().ok())
+                .and_then(|s| s.parse::<u64>().ok())
+                .map(|secs| Duration::from_secs(secs.min(MAX_RETRY_AFTER_SECS)));
+            let final_url = response.url().to_string();
+            let body = response.text().await.map_err(ConnectorError::HttpRequest)?;
+
+            let limited =
+                matches!(status.as_u16(), 429 | 503) || parse::is_blocked(&final_url, &body);
+            if limited {
+                if attempt == MAX_RETRIES {
+                    return Err(blocked());
+                }
+                sleep(retry_after.unwrap_or(delay)).await;
+                delay *= 2;
+                continue;
+            }
+            if status.as_u16() == 404 {
+                return Err(ConnectorError::ResourceNotFound);
+            }
+            if !status.is_success() {
+                return Err(ConnectorError::Other(format!(
+                    "Scholar returned status: {}",
+                    status
+                )));
+            }
+            return Ok(body);
+        }
+        Err(blocked())
+    }
+
     async fn search(&self, query: &str, limit: usize) -> Result<Vec<Value>, ConnectorError> {
-        time::sleep(Duration::from_secs(3)).await; // Rate limit Google Scholar
         let url = format!(
-            "https://scholar.google.com/scholar?q={}&hl=en",
+            "{}/scholar?q={}&hl=en",
+            BASE_URL,
             urlencoding::encode(query)
         );
+        let html = self.fetch(&url).await?;
+        Ok(parse::parse_results(&html, limit))
+    }
 
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(ConnectorError::HttpRequest)?;
+    /// Profile id for an id, profile URL or author name (best match).
+    /// Returns the other name matches alongside.
+    async fn resolve_author(&self, author: &str) -> Result<(String, Vec<Value>), ConnectorError> {
+        if let Some(id) = parse::author_id(author) {
+            return Ok((id, Vec::new()));
+        }
+        let url = format!(
+            "{}/citations?view_op=search_authors&mauthors={}&hl=en",
+            BASE_URL,
+            urlencoding::encode(author.trim())
+        );
+        let html = self.fetch(&url).await?;
+        let mut matches = parse::parse_author_search(&html);
+        if matches.is_empty() {
+            return Err(ConnectorError::InvalidParams(format!(
+                "No Google Scholar profile found for '{}'",
+                author.trim()
+            )));
+        }
+        let best = matches.remove(0);
+        let id = best["author_id"].as_str().unwrap_or_default().to_string();
+        matches.truncate(4);
+        Ok((id, matches))
+    }
 
-        if !response.status().is_success() {
+    async fn get_author(&self, args: GetAuthorArgs) -> Result<Value, ConnectorError> {
+        let (author_id, other_matches) = self.resolve_author(&args.author).await?;
+        let limit = args
+            .limit
+            .unwrap_or(DEFAULT_AUTHOR_PUBLICATIONS)
+            .clamp(1, MAX_AUTHOR_PUBLICATIONS);
+        let offset = args.offset.unwrap_or(0);
+        let sort = match args.sort.as_deref().unwrap_or("citations") {
+            "citations" | "cited" => "",
+            "year" | "date" | "pubdate" => "&sortby=pubdate",
+            other => {
+                return Err(ConnectorError::InvalidParams(format!(
+                    "Invalid sort '{}': use 'citations' or 'year'",
+                    other
+                )))
+            }
+        };
+        let url = format!(
+            "{}/citations?user={}&hl=en&cstart={}&pagesize={}{}",
+            BASE_URL,
+            urlencoding::encode(&author_id),
+            offset,
+            limit,
+            sort
+        );
+        let html = self.fetch(&url).await?;
+        let (mut profile, has_more) = parse::parse_author(&html);
+        if profile["name"].is_null() {
             return Err(ConnectorError::Other(format!(
-                "Scholar returned status: {}",
-                response.status()
+                "Could not read the Scholar profile for '{}'",
+                author_id
             )));
         }
 
-        let html_content = response.text().await.map_err(ConnectorError::HttpRequest)?;
-        let document = Html::parse_document(&html_content);
-
-        // Selectors
-        let result_sel = Selector::parse(".gs_r.gs_or.gs_scl").unwrap();
-        let title_sel = Selector::parse(".gs_rt").unwrap();
-        let link_sel = Selector::parse(".gs_rt a").unwrap();
-        let meta_sel = Selector::parse(".gs_a").unwrap();
-        let snippet_sel = Selector::parse(".gs_rs").unwrap();
-
-        let mut papers = Vec::new();
-
-        for element in document.select(&result_sel).take(limit) {
-            let title = element
-                .select(&title_sel)
-                .next()
-                .map(|e| e.text().collect::<String>())
-                .unwrap_or_default();
-            let link = element
-                .select(&link_sel)
-                .next()
-                .and_then(|e| e.value().attr("href"))
-                .map(|s| s.to_string());
-            let meta = element
-                .select(&meta_sel)
-                .next()
-                .map(|e| e.text().collect::<String>())
-                .unwrap_or_default();
-            let snippet = element
-                .select(&snippet_sel)
-                .next()
-                .map(|e| e.text().collect::<String>())
-                .unwrap_or_default();
-
-            // Extract year roughly from meta (e.g., "Author - Venue, 2023 - source")
-            let year = meta.split(" - ").find_map(|part| {
-                part.split(',').find_map(|s| {
-                    let trimmed = s.trim();
-                    if trimmed.len() == 4 && trimmed.chars().all(char::is_numeric) {
-                        Some(trimmed.to_string())
-                    } else {
-                        None
-                    }
-                })
-            });
-
-            papers.push(json!({
-                "title": title,
-                "link": link,
-                "authors_venue_year": meta,
-                "year": year,
-                "snippet": snippet
-            }));
+        let count = profile["publications"].as_array().map_or(0, |p| p.len());
+        profile["author_id"] = json!(author_id);
+        profile["profile_url"] = json!(format!("{}/citations?user={}", BASE_URL, author_id));
+        profile["offset"] = json!(offset);
+        profile["count"] = json!(count);
+        profile["next"] = json!((has_more && count > 0).then_some(offset + count));
+        if !other_matches.is_empty() {
+            profile["other_matches"] = json!(other_matches);
+        }
+        Ok(profile)
+    }
+
+    async fn get_cited_by(&self, args: GetCitedByArgs) -> Result<Value, ConnectorError> {
+        let cites_id = match (
+            args.cites_id.as_deref().map(str::trim),
+            args.query.as_deref(),
+        ) {
+            (Some(id), _) if !id.is_empty() => id.to_string(),
+            (_, Some(query)) if !query.trim().is_empty() => {
+                let top = self.search(query, 1).await?;
+                top.first()
+                    .and_then(|p| p["cites_id"].as_str())
+                    .map(str::to_string)
+                    .ok_or_else(|| {
+                        ConnectorError::Other(format!(
+                            "No cited-by list found for '{}' (no match, or no citations yet)",
+                            query.trim()
+                        ))
+                    })?
+            }
+            _ => {
+                return Err(ConnectorError::InvalidParams(
+                    "Provide 'cites_id' (from search_papers or get_author) or 'query'".into(),
+                ))
+            }
+        };
+
+        let limit = args
+            .limit
+            .unwrap_or(RESULTS_PAGE_SIZE)
+            .clamp(1, MAX_CITED_BY);
+        let offset = args.offset.unwrap_or(0);
+        let mut results: Vec<Value> = Vec::new();
+        let mut total = None;
+        let mut exhausted = false;
+        while results.len() < limit {
+            let url = format!(
+                "{}/scholar?cites={}&hl=en&start={}",
+                BASE_URL,
+                urlencoding::encode(&cites_id),
+                offset + results.len()
+            );
+            let html = self.fetch(&url).await?;
+            if total.is_none() {
+                total = parse::total_results(&html);
+            }
+            let page = parse::parse_results(&html, RESULTS_PAGE_SIZE);
+            let page_len = page.len();
+            results.extend(page.into_iter().take(limit - results.len()));
+            if page_len < RESULTS_PAGE_SIZE {
+                exhausted = true;
+                break;
+            }
         }
 
-        Ok(papers)
+        let next = offset + results.len();
+        let more = !exhausted && total.map_or(true, |t| (next as u64) < t);
+        Ok(json!({
+            "cites_id": cites_id,
+            "total": total,
+            "offset": offset,
+            "count": results.len(),
+            "results": results,
+            "next": more.then_some(next),
+        }))
     }
 }
 
@@ -161,7 +330,7 @@ impl Connector for GoogleScholarConnector {
                 website_url: Some("https://scholar.google.com".to_string()),
             },
             instructions: Some(
-                "Search Google Scholar by scraping. This method is unofficial, subject to Google's Terms of Service, and may be unreliable due to CAPTCHAs or HTML changes. Use with caution.".to_string(),
+                "Search Google Scholar by scraping, read author profiles (get_author) and citing papers (get_cited_by). This method is unofficial, subject to Google's Terms of Service, and may be unreliable due to CAPTCHAs or HTML changes. Requests are throttled to one every few seconds. Use with caution.".to_string(),
             ),
         })
     }
@@ -175,7 +344,7 @@ impl Connector for GoogleScholarConnector {
             Tool {
                 name: Cow::Borrowed("search_papers"),
                 title: None,
-                description: Some(Cow::Borrowed("Search Google Scholar papers.")),
+                description: Some(Cow::Borrowed("Search Google Scholar papers. Results include cited_by counts and a cites_id for get_cited_by.")),
                 input_schema: Arc::new(json!({
                     "type": "object",
                     "properties": {
@@ -188,6 +357,41 @@ impl Connector for GoogleScholarConnector {
                 annotations: None,
                 icons: None,
             },
+            Tool {
+                name: Cow::Borrowed("get_author"),
+                title: None,
+                description: Some(Cow::Borrowed("Get a Google Scholar author profile: affiliation, interests, citation metrics (citations, h-index, i10-index) and a page of publications.")),
+                input_schema: Arc::new(json!({
+                    "type": "object",
+                    "properties": {
+                        "author": { "type": "string", "description": "Profile id (e.g. WLN3QrAAAAAJ), profile URL, or author name (best match)" },
+                        "limit": { "type": "integer", "description": "Publications per page (default 20, max 100)" },
+                        "offset": { "type": "integer", "description": "Publication offset; pass the previous response's next" },
+                        "sort": { "type": "string", "enum": ["citations", "year"], "description": "Publication order (default citations)" }
+                    },
+                    "required": ["author"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_cited_by"),
+                title: None,
+                description: Some(Cow::Borrowed("List papers citing a paper on Google Scholar, by cites_id (from search_papers or get_author) or a title query.")),
+                input_schema: Arc::new(json!({
+                    "type": "object",
+                    "properties": {
+                        "cites_id": { "type": "string", "description": "Scholar cites id of the cited paper" },
+                        "query": { "type": "string", "description": "Title to look up when cites_id is unknown (top search result)" },
+                        "limit": { "type": "integer", "description": "Max results (default 10, max 50)" },
+                        "offset": { "type": "integer", "description": "Result offset; pass the previous response's next" }
+                    }
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
         ];
 
         Ok(ListToolsResult {
@@ -221,6 +425,32 @@ impl Connector for GoogleScholarConnector {
                     Some(serde_json::to_string(&data)?),
                 )?)
             }
+            "get_author" => {
+                let args: GetAuthorArgs = serde_json::from_value(
+                    serde_json::to_value(request.arguments.unwrap_or_default())
+                        .map_err(ConnectorError::SerdeJson)?,
+                )
+                .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+
+                let data = self.get_author(args).await?;
+                Ok(structured_result_with_text(
+                    &data,
+                    Some(serde_json::to_string(&data)?),
+                )?)
+            }
+            "get_cited_by" => {
+                let args: GetCitedByArgs = serde_json::from_value(
+                    serde_json::to_value(request.arguments.unwrap_or_default())
+                        .map_err(ConnectorError::SerdeJson)?,
+                )
+                .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+
+                let data = self.get_cited_by(args).await?;
+                Ok(structured_result_with_text(
+                    &data,
+                    Some(serde_json::to_string(&data)?),
+                )?)
+            }
             _ => Err(ConnectorError::ToolNotFound),
         }
     }
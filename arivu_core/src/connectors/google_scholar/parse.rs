@@ -0,0 +1,307 @@
+// src/connectors/google_scholar/parse.rs
+//
+// HTML parsing for Scholar's result lists, author profiles and author
+// search pages. Scholar has no API, so these follow the page markup; every
+// field is optional so a markup change degrades to missing data rather than
+// an error.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+use serde_json::{json, Map, Value};
+
+static RE_CITES: Lazy<Regex> = Lazy::new(|| Regex::new(r"[?&]cites=([\d,]+)").unwrap());
+static RE_USER: Lazy<Regex> = Lazy::new(|| Regex::new(r"[?&]user=([\w-]+)").unwrap());
+static RE_AUTHOR_ID: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[\w-]{12}$").unwrap());
+static RE_NUMBER: Lazy<Regex> = Lazy::new(|| Regex::new(r"\d[\d,.]*").unwrap());
+
+fn sel(css: &str) -> Selector {
+    Selector::parse(css).unwrap()
+}
+
+fn text(el: ElementRef) -> String {
+    el.text().collect::<String>().trim().to_string()
+}
+
+fn first_text(el: ElementRef, selector: &Selector) -> Option<String> {
+    el.select(selector)
+        .next()
+        .map(text)
+        .filter(|t| !t.is_empty())
+}
+
+/// First integer in `s`, ignoring thousands separators ("Cited by 1,234").
+fn number(s: &str) -> Option<u64> {
+    RE_NUMBER
+        .find(s)
+        .and_then(|m| m.as_str().replace([',', '.'], "").parse().ok())
+}
+
+/// The `cites=` id of a "Cited by" link, used by get_cited_by.
+fn cites_id(href: &str) -> Option<String> {
+    RE_CITES.captures(href).map(|c| c[1].to_string())
+}
+
+fn absolute(href: &str) -> String {
+    if href.starts_with('/') {
+        format!("https://scholar.google.com{}", href)
+    } else {
+        href.to_string()
+    }
+}
+
+/// A Scholar profile id from a profile URL or a bare id (`JicYPdAAAAAJ`).
+pub(super) fn author_id(input: &str) -> Option<String> {
+    let input = input.trim();
+    if let Some(c) = RE_USER.captures(input) {
+        return Some(c[1].to_string());
+    }
+    RE_AUTHOR_ID.is_match(input).then(|| input.to_string())
+}
+
+/// Whether Scholar answered with its CAPTCHA / unusual-traffic page.
+pub(super) fn is_blocked(final_url: &str, body: &str) -> bool {
+    final_url.contains("/sorry/")
+        || body.contains("gs_captcha_f")
+        || body.contains("id=\"recaptcha\"")
+        || body.contains("unusual traffic from your computer network")
+}
+
+/// Result entries of a search or "Cited by" page.
+pub(super) fn parse_results(html: &str, limit: usize) -> Vec<Value> {
+    let document = Html::parse_document(html);
+    let result_sel = sel(".gs_r.gs_or.gs_scl");
+    let title_sel = sel(".gs_rt");
+    let link_sel = sel(".gs_rt a");
+    let meta_sel = sel(".gs_a");
+    let snippet_sel = sel(".gs_rs");
+    let footer_link_sel = sel(".gs_fl a");
+
+    let mut papers = Vec::new();
+    for element in document.select(&result_sel).take(limit) {
+        let title = element
+            .select(&title_sel)
+            .next()
+            .map(text)
+            .unwrap_or_default();
+        let link = element
+            .select(&link_sel)
+            .next()
+            .and_then(|e| e.value().attr("href"))
+            .map(|s| s.to_string());
+        let meta = first_text(element, &meta_sel).unwrap_or_default();
+        let snippet = first_text(element, &snippet_sel).unwrap_or_default();
+
+        // Extract year roughly from meta (e.g., "Author - Venue, 2023 - source")
+        let year = meta.split(" - ").find_map(|part| {
+            part.split(',').find_map(|s| {
+                let trimmed = s.trim();
+                if trimmed.len() == 4 && trimmed.chars().all(char::is_numeric) {
+                    Some(trimmed.to_string())
+                } else {
+                    None
+                }
+            })
+        });
+
+        let cited = element.select(&footer_link_sel).find_map(|a| {
+            let href = a.value().attr("href")?;
+            let id = cites_id(href)?;
+            Some((number(&text(a)), id))
+        });
+
+        papers.push(json!({
+            "title": title,
+            "link": link,
+            "authors_venue_year": meta,
+            "year": year,
+            "snippet": snippet,
+            "cited_by": cited.as_ref().and_then(|(n, _)| *n),
+            "cites_id": cited.map(|(_, id)| id),
+        }));
+    }
+    papers
+}
+
+/// The "About 1,234 results" count of a results page.
+pub(super) fn total_results(html: &str) -> Option<u64> {
+    let document = Html::parse_document(html);
+    let summary = document.select(&sel("#gs_ab_md")).next().map(text)?;
+    number(&summary)
+}
+
+/// A profile page (`/citations?user=...`): details, citation metrics and one
+/// page of publications. `has_more` reports whether another page exists.
+pub(super) fn parse_author(html: &str) -> (Value, bool) {
+    let document = Html::parse_document(html);
+    let root = document.root_element();
+
+    let name = first_text(root, &sel("#gsc_prf_in"));
+    let affiliation = first_text(root, &sel("#gsc_prf_i .gsc_prf_il"));
+    let email = first_text(root, &sel("#gsc_prf_ivh")).map(|t| {
+        t.trim_end_matches("Homepage")
+            .trim_end_matches(['-', ' '])
+            .to_string()
+    });
+    let homepage = root
+        .select(&sel("#gsc_prf_ivh a"))
+        .next()
+        .and_then(|a| a.value().attr("href"))
+        .map(str::to_string);
+    let interests: Vec<String> = root.select(&sel("#gsc_prf_int a")).map(text).collect();
+
+    // Metrics table: a header row ("", "All", "Since 2020") and one row
+    // each for Citations, h-index and i10-index
+    let mut metrics = Map::new();
+    let headers: Vec<String> = root.select(&sel("#gsc_rsb_st th")).map(text).collect();
+    if let Some(since) = headers.get(2) {
+        metrics.insert("since_year".to_string(), json!(number(since)));
+    }
+    for row in root.select(&sel("#gsc_rsb_st tbody tr")) {
+        let Some(label) = first_text(row, &sel("td.gsc_rsb_sc1")) else {
+            continue;
+        };
+        let key = match label.to_lowercase().as_str() {
+            "citations" => "citations",
+            "h-index" => "h_index",
+            "i10-index" => "i10_index",
+            _ => continue,
+        };
+        let values: Vec<Option<u64>> = row
+            .select(&sel("td.gsc_rsb_std"))
+            .map(|td| number(&text(td)))
+            .collect();
+        metrics.insert(
+            key.to_string(),
+            json!({
+                "all": values.first().copied().flatten(),
+                "since": values.get(1).copied().flatten(),
+            }),
+        );
+    }
+
+    let gray_sel = sel(".gs_gray");
+    let publications: Vec<Value> = root
+        .select(&sel("tr.gsc_a_tr"))
+        .map(|row| {
+            let title_link = row.select(&sel("a.gsc_a_at")).next();
+            let gray: Vec<String> = row.select(&gray_sel).map(text).collect();
+            let year = first_text(row, &sel(".gsc_a_y span"));
+            let venue = gray.get(1).map(|v| {
+                let v = match &year {
+                    Some(y) => v.trim_end_matches(y.as_str()).trim_end_matches([',', ' ']),
+                    None => v.as_str(),
+                };
+                v.to_string()
+            });
+            let cited = row.select(&sel("a.gsc_a_ac")).next();
+            json!({
+                "title": title_link.map(text),
+                "link": title_link
+                    .and_then(|a| a.value().attr("href"))
+                    .map(absolute),
+                "authors": gray.first(),
+                "venue": venue.filter(|v| !v.is_empty()),
+                "year": year,
+                "cited_by": cited.and_then(|a| number(&text(a))),
+                "cites_id": cited
+                    .and_then(|a| a.value().attr("href"))
+                    .and_then(cites_id),
+            })
+        })
+        .collect();
+
+    let has_more = root
+        .select(&sel("#gsc_bpf_more"))
+        .next()
+        .is_some_and(|b| b.value().attr("disabled").is_none());
+
+    let profile = json!({
+        "name": name,
+        "affiliation": affiliation,
+        "email": email.filter(|e| !e.is_empty()),
+        "homepage": homepage,
+        "interests": interests,
+        "metrics": metrics,
+        "publications": publications,
+    });
+    (profile, has_more)
+}
+
+/// Profiles listed on an author search page.
+pub(super) fn parse_author_search(html: &str) -> Vec<Value> {
+    let document = Html::parse_document(html);
+    let name_sel = sel(".gs_ai_name a");
+    document
+        .select(&sel(".gs_ai"))
+        .filter_map(|card| {
+            let link = card.select(&name_sel).next()?;
+            let id = author_id(link.value().attr("href")?)?;
+            Some(json!({
+                "author_id": id,
+                "name": text(link),
+                "affiliation": first_text(card, &sel(".gs_ai_aff")),
+                "cited_by": first_text(card, &sel(".gs_ai_cby")).and_then(|t| number(&t)),
+            }))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_results_profiles_and_ids() {
+        let results = r#"<div id="gs_ab_md"><div class="gs_ab_mdw">About 1,230 results (0.05 sec)</div></div>
+<div class="gs_r gs_or gs_scl"><h3 class="gs_rt"><a href="https://example.org/p1">Deep learning</a></h3>
+<div class="gs_a">Y LeCun, Y Bengio - nature, 2015 - nature.com</div>
+<div class="gs_rs">Deep learning allows ...</div>
+<div class="gs_fl"><a href="/scholar?q=related:x">Related articles</a><a href="/scholar?cites=5362332738201102290&amp;as_sdt=2005&amp;hl=en">Cited by 75,312</a></div></div>"#;
+        let papers = parse_results(results, 10);
+        assert_eq!(papers.len(), 1);
+        assert_eq!(papers[0]["year"], "2015");
+        assert_eq!(papers[0]["cited_by"], 75312);
+        assert_eq!(papers[0]["cites_id"], "5362332738201102290");
+        assert_eq!(total_results(results), Some(1230));
+
+        let profile = r#"<div id="gsc_prf_i"><div id="gsc_prf_in">Yann LeCun</div>
+<div class="gsc_prf_il"><a href="/x">Professor, New York University</a></div>
+<div class="gsc_prf_il" id="gsc_prf_ivh">Verified email at cs.nyu.edu - <a href="http://yann.lecun.com/">Homepage</a></div>
+<div class="gsc_prf_il" id="gsc_prf_int"><a class="gsc_prf_inta">AI</a><a class="gsc_prf_inta">Machine Learning</a></div></div>
+<table id="gsc_rsb_st"><thead><tr><th class="gsc_rsb_sth"></th><th class="gsc_rsb_sth">All</th><th class="gsc_rsb_sth">Since 2020</th></tr></thead>
+<tbody><tr><td class="gsc_rsb_sc1"><a>Citations</a></td><td class="gsc_rsb_std">400123</td><td class="gsc_rsb_std">250000</td></tr>
+<tr><td class="gsc_rsb_sc1"><a>h-index</a></td><td class="gsc_rsb_std">150</td><td class="gsc_rsb_std">110</td></tr></tbody></table>
+<table><tbody id="gsc_a_b"><tr class="gsc_a_tr"><td class="gsc_a_t">
+<a href="/citations?view_op=view_citation&amp;user=WLN3QrAAAAAJ&amp;citation_for_view=WLN3QrAAAAAJ:u5HHmVD_uO8C" class="gsc_a_at">Deep learning</a>
+<div class="gs_gray">Y LeCun, Y Bengio, G Hinton</div><div class="gs_gray">nature 521 (7553), 436-444<span class="gs_oph">, 2015</span></div></td>
+<td class="gsc_a_c"><a href="https://scholar.google.com/scholar?oi=bibs&amp;hl=en&amp;cites=5362332738201102290" class="gsc_a_ac gs_ibl">75312</a></td>
+<td class="gsc_a_y"><span class="gsc_a_h gsc_a_hc gs_ibl">2015</span></td></tr></tbody></table>
+<button id="gsc_bpf_more" disabled="">Show more</button>"#;
+        let (author, has_more) = parse_author(profile);
+        assert!(!has_more);
+        assert_eq!(author["name"], "Yann LeCun");
+        assert_eq!(author["email"], "Verified email at cs.nyu.edu");
+        assert_eq!(author["homepage"], "http://yann.lecun.com/");
+        assert_eq!(author["interests"], json!(["AI", "Machine Learning"]));
+        assert_eq!(author["metrics"]["since_year"], 2020);
+        assert_eq!(
+            author["metrics"]["h_index"],
+            json!({ "all": 150, "since": 110 })
+        );
+        let publication = &author["publications"][0];
+        assert_eq!(publication["venue"], "nature 521 (7553), 436-444");
+        assert_eq!(publication["cited_by"], 75312);
+        assert_eq!(publication["cites_id"], "5362332738201102290");
+
+        assert_eq!(
+            author_id("https://scholar.google.com/citations?hl=en&user=WLN3QrAAAAAJ").as_deref(),
+            Some("WLN3QrAAAAAJ")
+        );
+        assert_eq!(author_id("Yann LeCun"), None);
+        assert!(is_blocked(
+            "https://www.google.com/sorry/index?continue=x",
+            ""
+        ));
+    }
+}
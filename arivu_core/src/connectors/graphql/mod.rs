@@ -0,0 +1,727 @@
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+/// Config keys holding allowlisted documents: `query.<name>`.
+const QUERY_PREFIX: &str = "query.";
+/// Config keys holding extra request headers: `header.<Name>`.
+const HEADER_PREFIX: &str = "header.";
+/// Allowlisted entries of the form `sha256:<hash>` are sent as persisted-query ids.
+const PERSISTED_PREFIX: &str = "sha256:";
+
+const INTROSPECTION_QUERY: &str = r#"
+query IntrospectionQuery {
+  __schema {
+    queryType { name }
+    mutationType { name }
+    subscriptionType { name }
+    types {
+      kind
+      name
+      description
+      fields(includeDeprecated: false) {
+        name
+        description
+        args { name description type { ...TypeRef } defaultValue }
+        type { ...TypeRef }
+      }
+      inputFields { name description type { ...TypeRef } defaultValue }
+      enumValues(includeDeprecated: false) { name description }
+      possibleTypes { name }
+    }
+  }
+}
+
+fragment TypeRef on __Type {
+  kind
+  name
+  ofType { kind name ofType { kind name ofType { kind name ofType { kind name } } } }
+}
+"#;
+
+#[derive(Debug, Deserialize)]
+struct SchemaArgs {
+    #[serde(default)]
+    type_name: Option<String>,
+    #[serde(default)]
+    include_builtin: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RunQueryArgs {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    query: Option<String>,
+    #[serde(default)]
+    variables: Option<Map<String, Value>>,
+    #[serde(default)]
+    operation_name: Option<String>,
+}
+
+/// Render an introspection type reference as SDL (`[String!]!`).
+fn type_ref(t: &Value) -> String {
+    match t["kind"].as_str() {
+        Some("NON_NULL") => format!("{}!", type_ref(&t["ofType"])),
+        Some("LIST") => format!("[{}]", type_ref(&t["ofType"])),
+        _ => t["name"].as_str().unwrap_or("?").to_string(),
+    }
+}
+
+fn summarize_args(args: &Value) -> Vec<Value> {
+    args.as_array()
+        .into_iter()
+        .flatten()
+        .map(|a| {
+            json!({
+                "name": a["name"],
+                "type": type_ref(&a["type"]),
+                "default": a["defaultValue"],
+                "description": a["description"],
+            })
+        })
+        .collect()
+}
+
+/// Full detail for one type.
+fn describe_type(t: &Value) -> Value {
+    let fields: Vec<Value> = t["fields"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|f| {
+            json!({
+                "name": f["name"],
+                "type": type_ref(&f["type"]),
+                "args": summarize_args(&f["args"]),
+                "description": f["description"],
+            })
+        })
+        .collect();
+    let mut out = json!({
+        "name": t["name"],
+        "kind": t["kind"],
+        "description": t["description"],
+    });
+    if !fields.is_empty() {
+        out["fields"] = json!(fields);
+    }
+    if t["inputFields"].is_array() {
+        out["input_fields"] = json!(summarize_args(&t["inputFields"]));
+    }
+    if let Some(values) = t["enumValues"].as_array() {
+        out["enum_values"] = json!(values.iter().map(|v| &v["name"]).collect::<Vec<_>>());
+    }
+    if let Some(types) = t["possibleTypes"].as_array() {
+        out["possible_types"] = json!(types.iter().map(|v| &v["name"]).collect::<Vec<_>>());
+    }
+    out
+}
+
+/// Root operations as `name(args): Type` signatures.
+fn root_fields(types: &[Value], root: Option<&str>) -> Vec<String> {
+    let Some(root) = root else {
+        return Vec::new();
+    };
+    types
+        .iter()
+        .find(|t| t["name"].as_str() == Some(root))
+        .and_then(|t| t["fields"].as_array())
+        .into_iter()
+        .flatten()
+        .map(|f| {
+            let args: Vec<String> = f["args"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(|a| {
+                    format!(
+                        "{}: {}",
+                        a["name"].as_str().unwrap_or_default(),
+                        type_ref(&a["type"])
+                    )
+                })
+                .collect();
+            let name = f["name"].as_str().unwrap_or_default();
+            let ty = type_ref(&f["type"]);
+            if args.is_empty() {
+                format!("{}: {}", name, ty)
+            } else {
+                format!("{}({}): {}", name, args.join(", "), ty)
+            }
+        })
+        .collect()
+}
+
+/// One operation definition found at the top level of a document.
+#[derive(Debug, PartialEq)]
+struct OperationInfo {
+    kind: String,
+    name: Option<String>,
+    variables: Vec<(String, String)>,
+}
+
+/// Scan a GraphQL document for its operation definitions, skipping strings,
+/// comments and fragment definitions.
+fn parse_operations(doc: &str) -> Vec<OperationInfo> {
+    let chars: Vec<char> = doc.chars().collect();
+    let mut ops = Vec::new();
+    let mut depth = 0usize;
+    let mut header = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '#' => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            '"' => {
+                // Skip "..." and """..."""
+                let block = chars.get(i + 1) == Some(&'"') && chars.get(i + 2) == Some(&'"');
+                i += if block { 3 } else { 1 };
+                while i < chars.len() {
+                    if chars[i] == '\\' {
+                        i += 2;
+                        continue;
+                    }
+                    if chars[i] == '"' {
+                        if !block {
+                            break;
+                        }
+                        if chars.get(i + 1) == Some(&'"') && chars.get(i + 2) == Some(&'"') {
+                            i += 2;
+                            break;
+                        }
+                    }
+                    i += 1;
+                }
+            }
+            '{' => {
+                if depth == 0 {
+                    if let Some(op) = operation_from_header(&header) {
+                        ops.push(op);
+                    }
+                    header.clear();
+                }
+                depth += 1;
+            }
+            '}' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => header.push(c),
+            _ => {}
+        }
+        i += 1;
+    }
+    ops
+}
+
+/// `query Name($id: ID!, $n: Int = 5) @dir` -> operation info; `None` for fragments.
+fn operation_from_header(header: &str) -> Option<OperationInfo> {
+    let header = header.trim();
+    let mut words = header.split(|c: char| !(c.is_alphanumeric() || c == '_'));
+    let kind = words.find(|w| !w.is_empty()).unwrap_or("query");
+    if kind == "fragment" {
+        return None;
+    }
+    if header.is_empty() {
+        // Shorthand `{ ... }` query
+        return Some(OperationInfo {
+            kind: "query".to_string(),
+            name: None,
+            variables: Vec::new(),
+        });
+    }
+    let rest = header[kind.len()..].trim_start();
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    let variables = match (rest.find('('), rest.rfind(')')) {
+        (Some(start), Some(end)) if end > start => rest[start + 1..end]
+            .split('$')
+            .filter_map(|def| {
+                let (var, ty) = def.split_once(':')?;
+                let ty = ty
+                    .split('=')
+                    .next()
+                    .unwrap_or_default()
+                    .trim()
+                    .trim_end_matches(',')
+                    .trim();
+                Some((var.trim().to_string(), ty.to_string()))
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    Some(OperationInfo {
+        kind: kind.to_string(),
+        name: (!name.is_empty()).then_some(name),
+        variables,
+    })
+}
+
+pub struct GraphqlConnector {
+    client: Client,
+    auth: AuthDetails,
+}
+
+impl GraphqlConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+        Ok(Self { client, auth })
+    }
+
+    fn setting(&self, key: &str, env: &str) -> Option<String> {
+        self.auth
+            .get(key)
+            .cloned()
+            .or_else(|| std::env::var(env).ok())
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    }
+
+    fn endpoint(&self) -> Result<String, ConnectorError> {
+        self.setting("endpoint", "ARIVU_GRAPHQL_ENDPOINT")
+            .ok_or_else(|| {
+                ConnectorError::Authentication(
+                    "GraphQL endpoint not configured: arivu graphql set endpoint <url>".to_string(),
+                )
+            })
+    }
+
+    /// Ad-hoc documents are refused unless `allow_adhoc` is set; even then only queries run.
+    fn allow_adhoc(&self) -> bool {
+        self.setting("allow_adhoc", "ARIVU_GRAPHQL_ALLOW_ADHOC")
+            .is_some_and(|v| matches!(v.to_ascii_lowercase().as_str(), "true" | "1" | "yes"))
+    }
+
+    /// Allowlisted documents from config (`query.<name>`).
+    fn queries(&self) -> BTreeMap<String, String> {
+        self.auth
+            .iter()
+            .filter_map(|(key, doc)| {
+                key.strip_prefix(QUERY_PREFIX)
+                    .filter(|_| !doc.trim().is_empty())
+                    .map(|name| (name.to_string(), doc.clone()))
+            })
+            .collect()
+    }
+
+    async fn post(&self, body: Value) -> Result<Value, ConnectorError> {
+        let mut request = self.client.post(self.endpoint()?).json(&body);
+        let mut has_authorization = false;
+        for (key, value) in &self.auth {
+            if let Some(name) = key.strip_prefix(HEADER_PREFIX) {
+                has_authorization |= name.eq_ignore_ascii_case("authorization");
+                request = request.header(name, value);
+            }
+        }
+        if !has_authorization {
+            if let Some(token) = self.setting("api_key", "ARIVU_GRAPHQL_TOKEN") {
+                request = request.bearer_auth(token);
+            }
+        }
+
+        let response = request.send().await.map_err(ConnectorError::HttpRequest)?;
+        let status = response.status();
+        let text = response.text().await.map_err(ConnectorError::HttpRequest)?;
+        let body: Value = serde_json::from_str(&text).unwrap_or(Value::Null);
+        // GraphQL servers often answer 4xx with a JSON `errors` payload; keep it
+        if status.is_success() || body.get("errors").is_some() {
+            return Ok(body);
+        }
+        let detail: String = text.chars().take(500).collect();
+        Err(match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => ConnectorError::Authentication(
+                format!("GraphQL endpoint rejected the credentials: {}", detail),
+            ),
+            s => ConnectorError::Other(format!("GraphQL endpoint returned {}: {}", s, detail)),
+        })
+    }
+}
+
+#[async_trait]
+impl Connector for GraphqlConnector {
+    fn name(&self) -> &'static str {
+        "graphql"
+    }
+
+    fn description(&self) -> &'static str {
+        "Any GraphQL endpoint: schema introspection and allowlisted queries with variables."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        Ok(self.auth.clone())
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        self.auth = details;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        let body = self.post(json!({ "query": "{ __typename }" })).await?;
+        match body.get("errors") {
+            Some(errors) if matches!(body.get("data"), None | Some(Value::Null)) => Err(
+                ConnectorError::Other(format!("GraphQL endpoint returned errors: {}", errors)),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema {
+            fields: vec![
+                Field {
+                    name: "endpoint".to_string(),
+                    label: "Endpoint URL".to_string(),
+                    field_type: FieldType::Text,
+                    required: true,
+                    description: Some(
+                        "GraphQL endpoint, e.g. https://api.internal/graphql (or ARIVU_GRAPHQL_ENDPOINT)."
+                            .to_string(),
+                    ),
+                    options: None,
+                },
+                Field {
+                    name: "api_key".to_string(),
+                    label: "Bearer Token".to_string(),
+                    field_type: FieldType::Secret,
+                    required: false,
+                    description: Some(
+                        "Sent as Authorization: Bearer unless a header.Authorization entry is set."
+                            .to_string(),
+                    ),
+                    options: None,
+                },
+                Field {
+                    name: "allow_adhoc".to_string(),
+                    label: "Allow Ad-hoc Queries".to_string(),
+                    field_type: FieldType::Boolean,
+                    required: false,
+                    description: Some(
+                        "Let run_query accept documents that are not allowlisted (queries only)."
+                            .to_string(),
+                    ),
+                    options: None,
+                },
+            ],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Use `list_queries` to see the allowlisted operations and their variables, then \
+`run_query` with `name` and `variables`. `introspect_schema` lists root fields and types; pass \
+`type_name` for one type's fields. Ad-hoc documents run only when the connector allows them, and \
+never as mutations."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            tool(
+                "introspect_schema",
+                "Summarize the endpoint's schema: root query/mutation fields and type names by kind. \
+Pass type_name for one type's fields, arguments and enum values. Example: type_name=\"Order\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "type_name": { "type": "string", "description": "Describe only this type" },
+                        "include_builtin": { "type": "boolean", "description": "Include __* introspection types (default: false)" }
+                    }
+                }),
+            ),
+            tool(
+                "list_queries",
+                "List allowlisted (persisted) operations with their kind and variable definitions.",
+                json!({ "type": "object", "properties": {} }),
+            ),
+            tool(
+                "run_query",
+                "Run an allowlisted operation by name with variables, or an ad-hoc query when \
+allowed. Example: name=\"orderById\" variables={\"id\": \"42\"}.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string", "description": "Allowlisted operation name from list_queries" },
+                        "query": { "type": "string", "description": "Ad-hoc GraphQL query document (only if allow_adhoc is enabled)" },
+                        "variables": { "type": "object", "description": "Operation variables" },
+                        "operation_name": { "type": "string", "description": "Operation to run when the document defines several" }
+                    }
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "introspect_schema" => {
+                let args: SchemaArgs = parse_args(request.arguments)?;
+                let body = self.post(json!({ "query": INTROSPECTION_QUERY })).await?;
+                let schema = &body["data"]["__schema"];
+                if schema.is_null() {
+                    return Err(ConnectorError::Other(format!(
+                        "Introspection failed (it may be disabled on this endpoint): {}",
+                        body.get("errors").unwrap_or(&Value::Null)
+                    )));
+                }
+                let include_builtin = args.include_builtin.unwrap_or(false);
+                let types: Vec<Value> = schema["types"]
+                    .as_array()
+                    .cloned()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|t| {
+                        include_builtin || !t["name"].as_str().unwrap_or("").starts_with("__")
+                    })
+                    .collect();
+
+                if let Some(type_name) = args.type_name.as_deref() {
+                    let t = types
+                        .iter()
+                        .find(|t| t["name"].as_str() == Some(type_name))
+                        .ok_or_else(|| {
+                            ConnectorError::InvalidParams(format!(
+                                "No type named '{}' in the schema",
+                                type_name
+                            ))
+                        })?;
+                    return structured_result_with_text(&describe_type(t), None);
+                }
+
+                let mut by_kind: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+                for t in &types {
+                    by_kind
+                        .entry(t["kind"].as_str().unwrap_or("OTHER").to_ascii_lowercase())
+                        .or_default()
+                        .push(t["name"].clone());
+                }
+                let query_type = schema["queryType"]["name"].as_str();
+                let mutation_type = schema["mutationType"]["name"].as_str();
+                let data = json!({
+                    "query_type": query_type,
+                    "mutation_type": mutation_type,
+                    "subscription_type": schema["subscriptionType"]["name"],
+                    "queries": root_fields(&types, query_type),
+                    "mutations": root_fields(&types, mutation_type),
+                    "type_count": types.len(),
+                    "types": by_kind,
+                });
+                structured_result_with_text(&data, None)
+            }
+            "list_queries" => {
+                let queries: Vec<Value> = self
+                    .queries()
+                    .into_iter()
+                    .map(|(name, doc)| {
+                        if let Some(hash) = doc.trim().strip_prefix(PERSISTED_PREFIX) {
+                            return json!({ "name": name, "persisted_hash": hash });
+                        }
+                        let ops: Vec<Value> = parse_operations(&doc)
+                            .into_iter()
+                            .map(|op| {
+                                json!({
+                                    "kind": op.kind,
+                                    "operation_name": op.name,
+                                    "variables": op
+                                        .variables
+                                        .into_iter()
+                                        .map(|(var, ty)| json!({ "name": var, "type": ty }))
+                                        .collect::<Vec<_>>(),
+                                })
+                            })
+                            .collect();
+                        json!({ "name": name, "operations": ops, "document": doc })
+                    })
+                    .collect();
+                let data = json!({
+                    "allow_adhoc": self.allow_adhoc(),
+                    "count": queries.len(),
+                    "queries": queries,
+                });
+                structured_result_with_text(&data, None)
+            }
+            "run_query" => {
+                let args: RunQueryArgs = parse_args(request.arguments)?;
+                let variables = Value::Object(args.variables.unwrap_or_default());
+
+                let mut body = match (args.name.as_deref(), args.query.as_deref()) {
+                    (Some(name), _) => {
+                        let doc = self.queries().remove(name).ok_or_else(|| {
+                            ConnectorError::InvalidParams(format!(
+                                "'{}' is not an allowlisted query; see list_queries",
+                                name
+                            ))
+                        })?;
+                        match doc.trim().strip_prefix(PERSISTED_PREFIX) {
+                            Some(hash) => json!({
+                                "variables": variables,
+                                "extensions": {
+                                    "persistedQuery": { "version": 1, "sha256Hash": hash }
+                                }
+                            }),
+                            None => json!({ "query": doc, "variables": variables }),
+                        }
+                    }
+                    (None, Some(doc)) => {
+                        if !self.allow_adhoc() {
+                            return Err(ConnectorError::InvalidParams(
+                                "Ad-hoc queries are disabled; run an allowlisted query by 'name' \
+(or set allow_adhoc=true)"
+                                    .to_string(),
+                            ));
+                        }
+                        let ops = parse_operations(doc);
+                        if ops.is_empty() {
+                            return Err(ConnectorError::InvalidParams(
+                                "No operation found in 'query'".to_string(),
+                            ));
+                        }
+                        if let Some(op) = ops.iter().find(|op| op.kind != "query") {
+                            return Err(ConnectorError::InvalidParams(format!(
+                                "Ad-hoc documents may only contain queries (found {}); allowlist it instead",
+                                op.kind
+                            )));
+                        }
+                        json!({ "query": doc, "variables": variables })
+                    }
+                    (None, None) => {
+                        return Err(ConnectorError::InvalidParams(
+                            "Provide 'name' (allowlisted query) or 'query'".to_string(),
+                        ))
+                    }
+                };
+                if let Some(op) = &args.operation_name {
+                    body["operationName"] = json!(op);
+                }
+
+                let response = self.post(body).await?;
+                let data = json!({
+                    "name": args.name,
+                    "data": response["data"],
+                    "errors": response.get("errors"),
+                });
+                structured_result_with_text(&data, None)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, _name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_operation_headers() {
+        let doc = r#"
+# a comment with { braces
+query OrderById($id: ID!, $limit: Int = 5) {
+  order(id: $id) { id note(format: "{not a brace}") }
+}
+fragment F on Order { id }
+mutation { cancel(id: 1) { ok } }
+"#;
+        let ops = parse_operations(doc);
+        assert_eq!(ops.len(), 2);
+        assert_eq!(ops[0].kind, "query");
+        assert_eq!(ops[0].name.as_deref(), Some("OrderById"));
+        assert_eq!(
+            ops[0].variables,
+            vec![
+                ("id".to_string(), "ID!".to_string()),
+                ("limit".to_string(), "Int".to_string())
+            ]
+        );
+        assert_eq!(ops[1].kind, "mutation");
+        assert_eq!(ops[1].name, None);
+
+        assert_eq!(parse_operations("{ viewer { login } }")[0].kind, "query");
+    }
+
+    #[test]
+    fn renders_type_refs() {
+        let t = json!({
+            "kind": "NON_NULL",
+            "ofType": { "kind": "LIST", "ofType": { "kind": "NON_NULL", "ofType": { "kind": "SCALAR", "name": "String" } } }
+        });
+        assert_eq!(type_ref(&t), "[String!]!");
+    }
+}
@@ -9,14 +9,24 @@ use std::sync::Arc;
 use crate::auth::AuthDetails;
 use crate::capabilities::ConnectorConfigSchema;
 use crate::error::ConnectorError;
+use crate::tokens;
 use crate::utils::structured_result_with_text;
 use crate::Connector;
+use futures::stream::{self, StreamExt};
 use rmcp::model::*;
 use urlencoding;
 
 // Import the types module
 mod types;
-pub use types::{AlgoliaHit, HackerNewsItem, ItemType, SimpleItem};
+pub use types::{
+    AlgoliaHit, FirebaseItem, HackerNewsItem, HackerNewsUpdates, HackerNewsUser, ItemType,
+    PollOption, SimpleItem,
+};
+
+const MAX_USER_ACTIVITY_PER_PAGE: i64 = 100;
+const FIREBASE_BASE_URL: &str = "https://hacker-news.firebaseio.com/v0";
+const MAX_ITEMS_SINCE: i64 = 500;
+const ITEMS_SINCE_CONCURRENCY: usize = 8;
 
 const DEFAULT_STORY_FIELDS: &[&str] = &["title", "text"];
 const DEFAULT_COMMENT_FIELDS: &[&str] = &["text"];
@@ -173,13 +183,14 @@ fn story_item_to_payload(
                 }
             }
             "options" => {
-                if let Some(options) = &item.options {
-                    map.insert("options".to_string(), json!(options));
+                if let Some(options) = poll_options_payload(item) {
+                    map.insert("options".to_string(), options);
                 }
             }
             _ => {}
         }
     }
+    insert_type_specific_fields(item, &mut map);
 
     let comments = item
         .children
@@ -297,6 +308,49 @@ fn get_field_sets_for_format(
     }
 }
 
+/// Poll options as `{id, text, points}` in display order
+fn poll_options_payload(item: &HackerNewsItem) -> Option<Value> {
+    let options = item.options.as_ref()?;
+    let options: Vec<Value> = options
+        .iter()
+        .map(|option| match option {
+            PollOption::Item(opt) => json!({
+                "id": opt.id,
+                "text": opt.text.clone().unwrap_or_default(),
+                "points": opt.points.unwrap_or(0),
+            }),
+            PollOption::Id(id) => json!({ "id": id }),
+        })
+        .collect();
+    Some(Value::Array(options))
+}
+
+/// Fields that only polls and jobs carry; these are kept in every response
+/// format because a poll without its options (or a job without its link) is
+/// not useful.
+fn insert_type_specific_fields(item: &HackerNewsItem, map: &mut serde_json::Map<String, Value>) {
+    match item.r#type {
+        Some(ItemType::Poll) => {
+            map.insert("type".to_string(), json!(ItemType::Poll));
+            if let Some(options) = poll_options_payload(item) {
+                let total: i64 = options
+                    .as_array()
+                    .map(|opts| opts.iter().filter_map(|o| o["points"].as_i64()).sum())
+                    .unwrap_or(0);
+                map.insert("options".to_string(), options);
+                map.insert("total_votes".to_string(), json!(total));
+            }
+        }
+        Some(ItemType::Job) => {
+            map.insert("type".to_string(), json!(ItemType::Job));
+            if let Some(url) = &item.url {
+                map.insert("url".to_string(), json!(url));
+            }
+        }
+        _ => {}
+    }
+}
+
 /// Create concise story payload (minimal fields for token efficiency)
 fn story_item_to_concise_payload(item: &HackerNewsItem) -> Value {
     let mut map = serde_json::Map::new();
@@ -312,6 +366,7 @@ fn story_item_to_concise_payload(item: &HackerNewsItem) -> Value {
         "text".to_string(),
         json!(item.text.clone().unwrap_or_default()),
     );
+    insert_type_specific_fields(item, &mut map);
 
     // Include comments but in concise form
     let comments = item
@@ -355,6 +410,59 @@ fn comment_item_to_concise_payload(item: &HackerNewsItem) -> Value {
     Value::Object(map)
 }
 
+/// Trim a story's comment tree to about `max_tokens` of title and comment text: the deepest
+/// replies go first, then the last top-level comments, so the top of each thread survives.
+fn fit_comment_tree(item: &mut HackerNewsItem, max_tokens: usize) {
+    let mut total = tree_tokens(item);
+    let mut depth = reply_depth(item);
+    while total > max_tokens && depth > 1 {
+        depth -= 1;
+        limit_reply_depth(item, depth);
+        total = tree_tokens(item);
+    }
+    if let Some(children) = item.children.as_mut() {
+        while total > max_tokens {
+            let Some(last) = children.pop() else { break };
+            total = total.saturating_sub(tree_tokens(&last));
+        }
+    }
+}
+
+/// Tokens of title and text over the whole tree, with a few per item for the JSON around them.
+fn tree_tokens(item: &HackerNewsItem) -> usize {
+    let own = [&item.title, &item.text]
+        .iter()
+        .filter_map(|t| t.as_deref())
+        .map(tokens::count_tokens)
+        .sum::<usize>()
+        + 8;
+    own + item
+        .children
+        .iter()
+        .flatten()
+        .map(tree_tokens)
+        .sum::<usize>()
+}
+
+fn reply_depth(item: &HackerNewsItem) -> usize {
+    item.children
+        .iter()
+        .flatten()
+        .map(|child| 1 + reply_depth(child))
+        .max()
+        .unwrap_or(0)
+}
+
+fn limit_reply_depth(item: &mut HackerNewsItem, depth: usize) {
+    if depth == 0 {
+        item.children = None;
+        return;
+    }
+    for child in item.children.iter_mut().flatten() {
+        limit_reply_depth(child, depth - 1);
+    }
+}
+
 /// Flatten comments into concise format
 fn flatten_comments_concise(item: &HackerNewsItem, out: &mut Vec<Value>) {
     if let Some(children) = &item.children {
@@ -763,7 +871,24 @@ impl HackerNewsConnector {
     // Helper: fetch a Hacker News item by ID using Algolia API
     async fn get_item(&self, item_id: i64) -> Result<HackerNewsItem, ConnectorError> {
         let url = format!("https://hn.algolia.com/api/v1/items/{}", item_id);
-        self.fetch_typed::<HackerNewsItem>(&url).await
+        let mut item = self.fetch_typed::<HackerNewsItem>(&url).await?;
+        self.resolve_poll_options(&mut item).await?;
+        Ok(item)
+    }
+
+    // Helper: replace bare poll option ids with the pollopt items (text and votes)
+    async fn resolve_poll_options(&self, item: &mut HackerNewsItem) -> Result<(), ConnectorError> {
+        let Some(options) = item.options.as_mut() else {
+            return Ok(());
+        };
+        for option in options.iter_mut() {
+            if let PollOption::Id(id) = *option {
+                let url = format!("https://hn.algolia.com/api/v1/items/{}", id);
+                let opt = self.fetch_typed::<HackerNewsItem>(&url).await?;
+                *option = PollOption::Item(Box::new(opt));
+            }
+        }
+        Ok(())
     }
 
     // Helper: build a stub story item with just an ID
@@ -830,6 +955,51 @@ impl HackerNewsConnector {
         Ok(self.hits_to_items(response.hits.unwrap_or_default()))
     }
 
+    // Helper: fetch a user profile from Firebase (null when the user does not exist)
+    async fn get_user(&self, username: &str) -> Result<HackerNewsUser, ConnectorError> {
+        let url = format!(
+            "https://hacker-news.firebaseio.com/v0/user/{}.json",
+            urlencoding::encode(username)
+        );
+        self.fetch_typed::<Option<HackerNewsUser>>(&url)
+            .await?
+            .ok_or(ConnectorError::ResourceNotFound)
+    }
+
+    // Helper: current largest item id (Firebase)
+    async fn get_max_item_id(&self) -> Result<i64, ConnectorError> {
+        self.fetch_typed(&format!("{}/maxitem.json", FIREBASE_BASE_URL))
+            .await
+    }
+
+    // Helper: fetch items with ids in `(since_id, until_id]` from Firebase, oldest first.
+    // Ids that have not propagated yet come back as null and are skipped.
+    async fn get_items_range(
+        &self,
+        since_id: i64,
+        until_id: i64,
+    ) -> Result<Vec<FirebaseItem>, ConnectorError> {
+        let results: Vec<Result<Option<FirebaseItem>, ConnectorError>> =
+            stream::iter((since_id + 1)..=until_id)
+                .map(|id| async move {
+                    self.fetch_typed::<Option<FirebaseItem>>(&format!(
+                        "{}/item/{}.json",
+                        FIREBASE_BASE_URL, id
+                    ))
+                    .await
+                })
+                .buffered(ITEMS_SINCE_CONCURRENCY)
+                .collect()
+                .await;
+        let mut items = Vec::new();
+        for result in results {
+            if let Some(item) = result? {
+                items.push(item);
+            }
+        }
+        Ok(items)
+    }
+
     // Helper: convert Algolia hits to HackerNewsItems
     fn hits_to_items(&self, hits: Vec<AlgoliaHit>) -> Vec<HackerNewsItem> {
         hits.into_iter()
@@ -858,6 +1028,110 @@ impl HackerNewsConnector {
     }
 }
 
+// Helper: parse a since/until bound into epoch seconds. Accepts RFC3339, a plain
+// date (midnight UTC), epoch seconds, or a relative age such as "7d" or "12h".
+fn parse_time_bound(
+    value: &str,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<i64, ConnectorError> {
+    let value = value.trim();
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Ok(dt.timestamp());
+    }
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date
+            .and_hms_opt(0, 0, 0)
+            .map_or(0, |dt| dt.and_utc().timestamp()));
+    }
+    if let Ok(epoch) = value.parse::<i64>() {
+        return Ok(epoch);
+    }
+
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+    let seconds_per_unit = match unit.trim().to_ascii_lowercase().as_str() {
+        "m" | "min" | "mins" => 60,
+        "h" | "hr" | "hrs" => 3_600,
+        "d" | "day" | "days" => 86_400,
+        "w" | "wk" | "week" | "weeks" => 7 * 86_400,
+        "mo" | "month" | "months" => 30 * 86_400,
+        "y" | "yr" | "year" | "years" => 365 * 86_400,
+        _ => 0,
+    };
+    match amount.parse::<i64>() {
+        Ok(amount) if seconds_per_unit > 0 => {
+            Ok(now.timestamp() - amount.saturating_mul(seconds_per_unit))
+        }
+        _ => Err(ConnectorError::InvalidParams(format!(
+            "Invalid time '{}'. Use RFC3339, YYYY-MM-DD, or a relative age like '7d', '12h', '2w'",
+            value
+        ))),
+    }
+}
+
+// Helper: combine `numericFilters` with the `since`/`until` bounds
+fn numeric_filters(
+    args: &serde_json::Map<String, Value>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<Option<String>, ConnectorError> {
+    let mut filters: Vec<String> = args
+        .get("numericFilters")
+        .and_then(|v| v.as_str())
+        .filter(|f| !f.is_empty())
+        .map(|f| vec![f.to_string()])
+        .unwrap_or_default();
+    if let Some(since) = args.get("since").and_then(|v| v.as_str()) {
+        filters.push(format!("created_at_i>={}", parse_time_bound(since, now)?));
+    }
+    if let Some(until) = args.get("until").and_then(|v| v.as_str()) {
+        filters.push(format!("created_at_i<{}", parse_time_bound(until, now)?));
+    }
+    Ok((!filters.is_empty()).then(|| filters.join(",")))
+}
+
+// Helper: whether a Firebase item's type is in the requested set (all when unset)
+fn item_type_matches(item: &FirebaseItem, types: Option<&HashSet<String>>) -> bool {
+    let Some(types) = types else {
+        return true;
+    };
+    item.r#type
+        .as_ref()
+        .and_then(|t| serde_json::to_value(t).ok())
+        .and_then(|t| t.as_str().map(|t| types.contains(t)))
+        .unwrap_or(false)
+}
+
+// Helper: a user's story or comment from Algolia, with the parent story for comments
+fn activity_hit_payload(hit: &AlgoliaHit) -> Value {
+    let id = hit
+        .object_id
+        .as_deref()
+        .and_then(|id| id.parse::<i64>().ok());
+    if hit.is_comment() {
+        json!({
+            "type": "comment",
+            "id": id,
+            "text": hit.comment_text,
+            "created_at": hit.created_at,
+            "story_id": hit.story_id,
+            "story_title": hit.story_title,
+            "parent_id": hit.parent_id,
+        })
+    } else {
+        json!({
+            "type": if hit.tags.as_ref().is_some_and(|t| t.iter().any(|t| t == "poll")) { "poll" } else { "story" },
+            "id": id,
+            "title": hit.title,
+            "url": hit.url,
+            "text": hit.story_text,
+            "points": hit.points,
+            "created_at": hit.created_at,
+        })
+    }
+}
+
 // Helper function to flatten comments recursively
 #[async_trait]
 impl Connector for HackerNewsConnector {
@@ -954,11 +1228,19 @@ Example: query=\"rust\" hitsPerPage=10.",
                         "hitsPerPage": { "type": "integer", "description": "Results per page", "default": 20 },
                         "tags": {
                             "type": "string",
-                            "description": "Filter on specific tags (e.g., 'story', 'comment', 'poll', 'pollopt', 'show_hn', 'ask_hn', 'front_page', 'author_:USERNAME', 'story_:ID')"
+                            "description": "Filter on specific tags (e.g., 'story', 'comment', 'poll', 'pollopt', 'show_hn', 'ask_hn', 'front_page', 'author_USERNAME', 'story_ID')"
                         },
                         "numericFilters": {
                             "type": "string",
                             "description": "Filter on numerical conditions (e.g., 'points>10', 'num_comments>5', 'created_at_i>1600000000')"
+                        },
+                        "since": {
+                            "type": "string",
+                            "description": "Only items created at or after this time: RFC3339, YYYY-MM-DD, or relative like '7d', '12h', '2w'"
+                        },
+                        "until": {
+                            "type": "string",
+                            "description": "Only items created before this time (same formats as 'since')"
                         }
                     },
                     "required": ["query"]
@@ -982,11 +1264,19 @@ more than relevance.",
                         "hitsPerPage": { "type": "integer", "description": "Results per page", "default": 20 },
                         "tags": {
                             "type": "string",
-                            "description": "Filter on specific tags (e.g., 'story', 'comment', 'poll', 'pollopt', 'show_hn', 'ask_hn', 'front_page', 'author_:USERNAME', 'story_:ID')"
+                            "description": "Filter on specific tags (e.g., 'story', 'comment', 'poll', 'pollopt', 'show_hn', 'ask_hn', 'front_page', 'author_USERNAME', 'story_ID')"
                         },
                         "numericFilters": {
                             "type": "string",
                             "description": "Filter on numerical conditions (e.g., 'points>10', 'num_comments>5', 'created_at_i>1600000000')"
+                        },
+                        "since": {
+                            "type": "string",
+                            "description": "Only items created at or after this time: RFC3339, YYYY-MM-DD, or relative like '7d', '12h', '2w'"
+                        },
+                        "until": {
+                            "type": "string",
+                            "description": "Only items created before this time (same formats as 'since')"
                         }
                     },
                     "required": ["query"]
@@ -1049,6 +1339,11 @@ more than relevance.",
                             "description": "Return comments as a flat array instead of nested tree structure",
                             "default": false
                         },
+                        "max_tokens": {
+                            "type": "integer",
+                            "minimum": 1,
+                            "description": "Approximate token budget for the story and its comments. Deep replies are dropped first, then the last top-level comments."
+                        },
                         "response_format": {
                             "type": "string",
                             "enum": ["concise", "detailed"],
@@ -1072,41 +1367,87 @@ more than relevance.",
                 annotations: None,
                 icons: None,
             },
-            //  Tool {
-            //      name: Cow::Borrowed("get_user"),
-            //      description: Some(Cow::Borrowed("Get Hacker News user details by username")),
-            //      annotations: None,
-            //      input_schema: Arc::new(json!({
-            //          "type": "object",
-            //          "properties": {
-            //              "id": { "type": "string", "description": "The Hacker News username (case-sensitive)" }
-            //          },
-            //          "required": ["id"]
-            //      }).as_object().expect("Schema object").clone()),
-            //      output_schema: None,
-            //  },
-            //  Tool {
-            //      name: Cow::Borrowed("get_max_item_id"),
-            //      description: Some(Cow::Borrowed("Get the current largest item id on Hacker News")),
-            //      annotations: None,
-            //      input_schema: Arc::new(json!({
-            //          "type": "object",
-            //          "properties": {},
-            //          "required": []
-            //      }).as_object().expect("Schema object").clone()),
-            //      output_schema: None,
-            //  },
-            //  Tool {
-            //      name: Cow::Borrowed("get_updates"),
-            //      description: Some(Cow::Borrowed("Get the latest item and profile changes on Hacker News")),
-            //      annotations: None,
-            //      input_schema: Arc::new(json!({
-            //          "type": "object",
-            //          "properties": {},
-            //          "required": []
-            //      }).as_object().expect("Schema object").clone()),
-            //      output_schema: None,
-            //  }
+            Tool {
+                name: Cow::Borrowed("get_user"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "User profile (karma, about, account age) plus recent submissions and comments, \
+newest first. Page through activity with `page`. Example: username=\"pg\" activity=\"comments\".",
+                )),
+                input_schema: Arc::new(json!({
+                    "type": "object",
+                    "properties": {
+                        "username": { "type": "string", "description": "The Hacker News username (case-sensitive)" },
+                        "activity": {
+                            "type": "string",
+                            "enum": ["all", "stories", "comments", "none"],
+                            "description": "Which recent activity to include; 'none' returns only the profile",
+                            "default": "all"
+                        },
+                        "page": { "type": "integer", "description": "Activity page number (0-based)", "default": 0 },
+                        "hitsPerPage": { "type": "integer", "minimum": 1, "maximum": 100, "description": "Activity items per page", "default": 20 }
+                    },
+                    "required": ["username"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_max_item_id"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "The newest item id on Hacker News. Store it and pass it to get_items_since later \
+to tail new items.",
+                )),
+                input_schema: Arc::new(json!({
+                    "type": "object",
+                    "properties": {},
+                    "required": []
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_updates"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Item ids and usernames that changed recently (new votes, edits, comments).",
+                )),
+                input_schema: Arc::new(json!({
+                    "type": "object",
+                    "properties": {},
+                    "required": []
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_items_since"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Items created after since_id, oldest first, straight from the real-time API. Call again \
+with the returned next_since_id to keep tailing. Example: since_id=41000000 types=[\"story\"].",
+                )),
+                input_schema: Arc::new(json!({
+                    "type": "object",
+                    "properties": {
+                        "since_id": { "type": "integer", "description": "Return items with ids greater than this (from get_max_item_id or a previous call)" },
+                        "limit": { "type": "integer", "minimum": 1, "maximum": 500, "description": "Maximum ids to scan in this call", "default": 100 },
+                        "types": {
+                            "type": "array",
+                            "items": { "type": "string", "enum": ["story", "comment", "job", "poll", "pollopt"] },
+                            "description": "Only return these item types (default: all)"
+                        }
+                    },
+                    "required": ["since_id"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
         ];
 
         Ok(ListToolsResult {
@@ -1153,11 +1494,11 @@ more than relevance.",
                 //       // Default to last 30 days if no date range specified
                 //       url.push_str("&dateRange=all");
                 //   }
-                // Add numeric filters if provided
-                if let Some(numeric_filters) = args.get("numericFilters").and_then(|v| v.as_str()) {
+                // Add numeric filters (explicit ones plus since/until) if provided
+                if let Some(numeric_filters) = numeric_filters(&args, chrono::Utc::now())? {
                     url.push_str(&format!(
                         "&numericFilters={}",
-                        urlencoding::encode(numeric_filters)
+                        urlencoding::encode(&numeric_filters)
                     ));
                 }
 
@@ -1192,11 +1533,11 @@ more than relevance.",
                     url.push_str("&tags=story");
                 }
 
-                // Add numeric filters if provided
-                if let Some(numeric_filters) = args.get("numericFilters").and_then(|v| v.as_str()) {
+                // Add numeric filters (explicit ones plus since/until) if provided
+                if let Some(numeric_filters) = numeric_filters(&args, chrono::Utc::now())? {
                     url.push_str(&format!(
                         "&numericFilters={}",
-                        urlencoding::encode(numeric_filters)
+                        urlencoding::encode(&numeric_filters)
                     ));
                 }
 
@@ -1266,17 +1607,25 @@ more than relevance.",
                     .and_then(|v| v.as_str())
                     .unwrap_or("concise");
 
-                // Use the Algolia items endpoint directly
-                let url = format!("https://hn.algolia.com/api/v1/items/{}", id);
-                let result = self.fetch_typed::<HackerNewsItem>(&url).await?;
+                let mut result = self.get_item(id).await?;
+                if let Some(max_tokens) = tokens::budget_arg(&args, "max_tokens") {
+                    fit_comment_tree(&mut result, max_tokens);
+                }
 
                 if response_format == "concise" {
                     if flatten {
                         // Concise + flatten: just text content as flat list
-                        let mut flattened = vec![json!({
-                            "title": result.title.clone().unwrap_or_default(),
-                            "text": result.text.clone().unwrap_or_default()
-                        })];
+                        let mut header = serde_json::Map::new();
+                        header.insert(
+                            "title".to_string(),
+                            json!(result.title.clone().unwrap_or_default()),
+                        );
+                        header.insert(
+                            "text".to_string(),
+                            json!(result.text.clone().unwrap_or_default()),
+                        );
+                        insert_type_specific_fields(&result, &mut header);
+                        let mut flattened = vec![Value::Object(header)];
                         flatten_comments_concise(&result, &mut flattened);
                         let text = serde_json::to_string(&flattened)?;
                         Ok(structured_result_with_text(&flattened, Some(text))?)
@@ -1304,6 +1653,131 @@ more than relevance.",
                     }
                 }
             }
+            "get_user" => {
+                let username = args
+                    .get("username")
+                    .or_else(|| args.get("id"))
+                    .and_then(|v| v.as_str())
+                    .map(str::trim)
+                    .filter(|u| !u.is_empty())
+                    .ok_or(ConnectorError::InvalidParams(
+                        "Missing 'username' parameter".to_string(),
+                    ))?;
+                let activity = args
+                    .get("activity")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("all");
+                let type_tag = match activity {
+                    "all" => Some("(story,comment,poll)"),
+                    "stories" => Some("story"),
+                    "comments" => Some("comment"),
+                    "none" => None,
+                    _ => {
+                        return Err(ConnectorError::InvalidParams(format!(
+                            "Invalid activity '{}'. Valid values: all, stories, comments, none",
+                            activity
+                        )));
+                    }
+                };
+                let page = args
+                    .get("page")
+                    .and_then(|v| v.as_i64())
+                    .unwrap_or(0)
+                    .max(0);
+                let hits_per_page = args
+                    .get("hitsPerPage")
+                    .and_then(|v| v.as_i64())
+                    .unwrap_or(20)
+                    .clamp(1, MAX_USER_ACTIVITY_PER_PAGE);
+
+                let user = self.get_user(username).await?;
+                let mut result = json!({
+                    "username": user.id,
+                    "karma": user.karma,
+                    "created": user.created,
+                    "about": user.about,
+                    "submitted_count": user.submitted.as_ref().map_or(0, Vec::len),
+                    "url": format!("https://news.ycombinator.com/user?id={}", user.id),
+                });
+
+                if let Some(type_tag) = type_tag {
+                    // Algolia tags are ANDed by comma; usernames are stored as author_<name>
+                    let tags = format!("author_{},{}", user.id, type_tag);
+                    let url = format!(
+                        "https://hn.algolia.com/api/v1/search_by_date?tags={}&page={}&hitsPerPage={}",
+                        urlencoding::encode(&tags),
+                        page,
+                        hits_per_page
+                    );
+                    let response = self.fetch_algolia_search(&url).await?;
+                    let nb_pages = response.nb_pages.unwrap_or(0);
+                    let activity: Vec<Value> = response
+                        .hits
+                        .unwrap_or_default()
+                        .iter()
+                        .map(activity_hit_payload)
+                        .collect();
+                    result["activity"] = json!(activity);
+                    result["page"] = json!(page);
+                    result["total_activity"] = json!(response.nb_hits);
+                    result["next_page"] = if page + 1 < nb_pages {
+                        json!(page + 1)
+                    } else {
+                        Value::Null
+                    };
+                }
+
+                let text = serde_json::to_string(&result)?;
+                Ok(structured_result_with_text(&result, Some(text))?)
+            }
+            "get_max_item_id" => {
+                let result = json!({ "max_item_id": self.get_max_item_id().await? });
+                let text = serde_json::to_string(&result)?;
+                Ok(structured_result_with_text(&result, Some(text))?)
+            }
+            "get_updates" => {
+                let updates: HackerNewsUpdates = self
+                    .fetch_typed(&format!("{}/updates.json", FIREBASE_BASE_URL))
+                    .await?;
+                let text = serde_json::to_string(&updates)?;
+                Ok(structured_result_with_text(&updates, Some(text))?)
+            }
+            "get_items_since" => {
+                let since_id = args.get("since_id").and_then(|v| v.as_i64()).ok_or(
+                    ConnectorError::InvalidParams("Missing 'since_id' parameter".to_string()),
+                )?;
+                let limit = args
+                    .get("limit")
+                    .and_then(|v| v.as_i64())
+                    .unwrap_or(100)
+                    .clamp(1, MAX_ITEMS_SINCE);
+                let types: Option<HashSet<String>> = args
+                    .get("types")
+                    .map(|v| parse_field_list(Some(v), &[]))
+                    .filter(|t| !t.is_empty());
+
+                let max_item_id = self.get_max_item_id().await?;
+                let until_id = max_item_id.min(since_id.saturating_add(limit));
+                let items = if until_id > since_id {
+                    self.get_items_range(since_id, until_id).await?
+                } else {
+                    Vec::new()
+                };
+                let items: Vec<&FirebaseItem> = items
+                    .iter()
+                    .filter(|item| !item.deleted && !item.dead)
+                    .filter(|item| item_type_matches(item, types.as_ref()))
+                    .collect();
+
+                let result = json!({
+                    "items": items,
+                    "next_since_id": until_id.max(since_id),
+                    "max_item_id": max_item_id,
+                    "has_more": until_id < max_item_id,
+                });
+                let text = serde_json::to_string(&result)?;
+                Ok(structured_result_with_text(&result, Some(text))?)
+            }
             _ => Err(ConnectorError::ToolNotFound),
         }
     }
@@ -1324,3 +1798,151 @@ more than relevance.",
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_user_activity_hits() {
+        let comment: AlgoliaHit = serde_json::from_value(json!({
+            "objectID": "42",
+            "_tags": ["comment", "author_pg", "story_7"],
+            "comment_text": "Good point.",
+            "story_id": 7,
+            "story_title": "Ask HN: Something",
+            "parent_id": 7
+        }))
+        .unwrap();
+        let payload = activity_hit_payload(&comment);
+        assert_eq!(payload["type"], "comment");
+        assert_eq!(payload["id"], 42);
+        assert_eq!(payload["story_title"], "Ask HN: Something");
+
+        let story: AlgoliaHit = serde_json::from_value(json!({
+            "objectID": "7",
+            "_tags": ["story", "author_pg"],
+            "title": "Show HN: Thing",
+            "points": 120
+        }))
+        .unwrap();
+        let payload = activity_hit_payload(&story);
+        assert_eq!(payload["type"], "story");
+        assert_eq!(payload["points"], 120);
+    }
+
+    #[test]
+    fn converts_since_until_to_numeric_filters() {
+        let now = chrono::DateTime::parse_from_rfc3339("2024-01-08T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        assert_eq!(parse_time_bound("7d", now).unwrap(), 1_704_067_200);
+        assert_eq!(parse_time_bound("2024-01-01", now).unwrap(), 1_704_067_200);
+        assert_eq!(
+            parse_time_bound("2024-01-01T00:00:00+00:00", now).unwrap(),
+            1_704_067_200
+        );
+        assert!(parse_time_bound("last week", now).is_err());
+
+        let args = json!({ "numericFilters": "points>10", "since": "24h", "until": "2024-01-08" });
+        let filters = numeric_filters(args.as_object().unwrap(), now).unwrap();
+        assert_eq!(
+            filters.as_deref(),
+            Some("points>10,created_at_i>=1704585600,created_at_i<1704672000")
+        );
+        assert_eq!(numeric_filters(&serde_json::Map::new(), now).unwrap(), None);
+    }
+
+    #[test]
+    fn fits_comment_tree_to_token_budget() {
+        let text = "word ".repeat(50);
+        let comment =
+            |children: Vec<Value>| json!({"type": "comment", "text": text, "children": children});
+        let mut story: HackerNewsItem = serde_json::from_value(json!({
+            "id": 1,
+            "type": "story",
+            "title": "Show HN: Something",
+            "children": [
+                comment(vec![comment(vec![comment(vec![])])]),
+                comment(vec![comment(vec![])]),
+                comment(vec![]),
+            ]
+        }))
+        .unwrap();
+        assert_eq!(reply_depth(&story), 3);
+
+        let full = tree_tokens(&story);
+        fit_comment_tree(&mut story, full);
+        assert_eq!(tree_tokens(&story), full);
+
+        // Room for four comments: replies go a level at a time, so all three threads stay
+        let one = tree_tokens(&story.children.as_ref().unwrap()[2]);
+        fit_comment_tree(&mut story, full - 2 * one);
+        assert_eq!(reply_depth(&story), 1);
+        assert_eq!(story.children.as_ref().unwrap().len(), 3);
+
+        fit_comment_tree(&mut story, 100);
+        let payload = story_item_to_concise_payload(&story);
+        assert_eq!(payload["title"], "Show HN: Something");
+        assert_eq!(payload["comments"].as_array().unwrap().len(), 1);
+        assert_eq!(payload["comments"][0]["comments"], json!([]));
+    }
+
+    #[test]
+    fn keeps_poll_options_and_job_links() {
+        let poll: HackerNewsItem = serde_json::from_value(json!({
+            "id": 1,
+            "type": "poll",
+            "title": "Favourite editor?",
+            "options": [
+                { "id": 2, "type": "pollopt", "text": "vim", "points": 30 },
+                { "id": 3, "type": "pollopt", "text": "emacs", "points": 12 }
+            ],
+            "children": []
+        }))
+        .unwrap();
+        let payload = story_item_to_concise_payload(&poll);
+        assert_eq!(payload["type"], "poll");
+        assert_eq!(payload["options"][0]["text"], "vim");
+        assert_eq!(payload["options"][1]["points"], 12);
+        assert_eq!(payload["total_votes"], 42);
+
+        let bare: HackerNewsItem =
+            serde_json::from_value(json!({ "id": 1, "type": "poll", "options": [2, 3] })).unwrap();
+        assert!(matches!(
+            bare.options.as_deref(),
+            Some([PollOption::Id(2), PollOption::Id(3)])
+        ));
+
+        let job: HackerNewsItem = serde_json::from_value(json!({
+            "id": 4,
+            "type": "job",
+            "title": "Acme is hiring",
+            "url": "https://acme.example/jobs"
+        }))
+        .unwrap();
+        let (story_fields, comment_fields) = parse_field_sets(&serde_json::Map::new());
+        let payload = story_item_to_payload(&job, &story_fields, &comment_fields);
+        assert_eq!(payload["type"], "job");
+        assert_eq!(payload["url"], "https://acme.example/jobs");
+    }
+
+    #[test]
+    fn filters_items_since_by_type() {
+        let item: FirebaseItem = serde_json::from_value(json!({
+            "id": 41000001,
+            "type": "comment",
+            "by": "dang",
+            "time": 1_720_000_000,
+            "parent": 41000000,
+            "text": "Please keep it civil."
+        }))
+        .unwrap();
+        assert!(item_type_matches(&item, None));
+        let comments: HashSet<String> = ["comment".to_string()].into_iter().collect();
+        assert!(item_type_matches(&item, Some(&comments)));
+        let stories: HashSet<String> = ["story".to_string()].into_iter().collect();
+        assert!(!item_type_matches(&item, Some(&stories)));
+        assert!(!item.deleted);
+    }
+}
@@ -49,9 +49,9 @@ pub struct HackerNewsItem {
     /// The story id this item belongs to
     #[serde(skip_serializing_if = "Option::is_none")]
     pub story_id: Option<i64>,
-    /// Additional options (from Algolia API)
+    /// Poll options (from Algolia API), either full pollopt items or bare ids
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub options: Option<Vec<String>>,
+    pub options: Option<Vec<PollOption>>,
     /// Nested children comments
     #[serde(skip_serializing_if = "Option::is_none")]
     pub children: Option<Vec<HackerNewsItem>>,
@@ -59,9 +59,17 @@ pub struct HackerNewsItem {
 
 impl HackerNewsItem {}
 
+/// A poll option as returned by Algolia: usually the full pollopt item, but
+/// older polls only carry the option ids.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PollOption {
+    Item(Box<HackerNewsItem>),
+    Id(i64),
+}
+
 /// Represents a Hacker News user
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[allow(dead_code)]
 pub struct HackerNewsUser {
     /// The user's unique username. Case-sensitive.
     pub id: String,
@@ -79,7 +87,6 @@ pub struct HackerNewsUser {
 
 /// Represents updates to items and profiles
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[allow(dead_code)]
 pub struct HackerNewsUpdates {
     /// List of updated item IDs
     pub items: Vec<i64>,
@@ -87,6 +94,40 @@ pub struct HackerNewsUpdates {
     pub profiles: Vec<String>,
 }
 
+/// An item as served by the official Firebase API, which is updated in real
+/// time (Algolia can lag by a few minutes)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FirebaseItem {
+    pub id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<ItemType>,
+    /// The username of the item's author
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by: Option<String>,
+    /// Creation date of the item, in Unix Time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The comment, story or poll text. HTML.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<i64>,
+    /// The comment's parent: another comment or the story
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<i64>,
+    /// Total comment count for stories and polls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descendants: Option<i64>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deleted: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dead: bool,
+}
+
 /// A simplified story response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SimpleStory {
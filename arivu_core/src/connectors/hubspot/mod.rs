@@ -0,0 +1,731 @@
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::utils::{html_to_text, parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use reqwest::{Client, Method, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+const API_BASE: &str = "https://api.hubapi.com";
+const CRM_OBJECTS: &[&str] = &["contacts", "companies", "deals", "tickets"];
+const ENGAGEMENT_TYPES: &[&str] = &["notes", "emails", "calls", "meetings"];
+
+#[derive(Debug, Deserialize)]
+struct Filter {
+    property: String,
+    #[serde(default)]
+    operator: Option<String>,
+    #[serde(default)]
+    value: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchArgs {
+    object: String,
+    #[serde(default)]
+    query: Option<String>,
+    #[serde(default)]
+    filters: Vec<Filter>,
+    #[serde(default)]
+    properties: Option<String>,
+    #[serde(default)]
+    sort: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    after: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetRecordArgs {
+    object: String,
+    id: String,
+    #[serde(default)]
+    properties: Option<String>,
+    #[serde(default)]
+    associations: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EngagementArgs {
+    object: String,
+    id: String,
+    #[serde(default)]
+    types: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+pub struct HubSpotConnector {
+    client: Client,
+    token: Option<String>,
+}
+
+impl HubSpotConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+        let token = auth
+            .get("api_key")
+            .or_else(|| auth.get("token"))
+            .cloned()
+            .or_else(|| std::env::var("HUBSPOT_ACCESS_TOKEN").ok())
+            .filter(|t| !t.trim().is_empty());
+
+        Ok(Self { client, token })
+    }
+
+    async fn request(
+        &self,
+        method: Method,
+        path: &str,
+        params: &[(&str, String)],
+        body: Option<Value>,
+    ) -> Result<Value, ConnectorError> {
+        let token = self.token.as_deref().ok_or_else(|| {
+            ConnectorError::Authentication(
+                "HubSpot private app token not configured: arivu config set hubspot --value <token>"
+                    .to_string(),
+            )
+        })?;
+
+        let mut request = self
+            .client
+            .request(method, format!("{}{}", API_BASE, path))
+            .query(params)
+            .bearer_auth(token);
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+        let response = request.send().await.map_err(ConnectorError::HttpRequest)?;
+
+        let status = response.status();
+        if status.is_success() {
+            return response.json().await.map_err(ConnectorError::HttpRequest);
+        }
+        let body: Value = response.json().await.unwrap_or(Value::Null);
+        let message = body["message"].as_str().unwrap_or_default().to_string();
+        Err(match status {
+            StatusCode::NOT_FOUND => ConnectorError::ResourceNotFound,
+            StatusCode::UNAUTHORIZED => {
+                ConnectorError::Authentication(format!("HubSpot rejected the token: {}", message))
+            }
+            StatusCode::FORBIDDEN => ConnectorError::Authentication(format!(
+                "HubSpot token is missing a required scope: {}",
+                message
+            )),
+            StatusCode::BAD_REQUEST => {
+                ConnectorError::InvalidParams(format!("HubSpot: {}", message))
+            }
+            s => ConnectorError::Other(format!(
+                "HubSpot API returned error status: {} {}",
+                s, message
+            )),
+        })
+    }
+
+    async fn engagements(
+        &self,
+        object: &str,
+        id: &str,
+        kind: &str,
+        limit: usize,
+    ) -> Result<Vec<Value>, ConnectorError> {
+        let associations = self
+            .request(
+                Method::GET,
+                &format!("/crm/v4/objects/{}/{}/associations/{}", object, id, kind),
+                &[("limit", "500".to_string())],
+                None,
+            )
+            .await?;
+        let ids: Vec<Value> = associations["results"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|r| match &r["toObjectId"] {
+                Value::Number(n) => Some(n.to_string()),
+                Value::String(s) => Some(s.clone()),
+                _ => None,
+            })
+            .map(|id| json!({ "id": id }))
+            .collect();
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut items: Vec<Value> = Vec::new();
+        // Batch read accepts at most 100 ids per call.
+        for chunk in ids.chunks(100) {
+            let body = self
+                .request(
+                    Method::POST,
+                    &format!("/crm/v3/objects/{}/batch/read", kind),
+                    &[],
+                    Some(json!({
+                        "inputs": chunk,
+                        "properties": engagement_properties(kind),
+                    })),
+                )
+                .await?;
+            items.extend(
+                body["results"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|r| format_engagement(kind, r)),
+            );
+        }
+
+        // Newest first, by engagement timestamp.
+        items.sort_by(|a, b| b["timestamp"].as_str().cmp(&a["timestamp"].as_str()));
+        items.truncate(limit);
+        Ok(items)
+    }
+}
+
+fn normalize_object(object: &str) -> Result<String, ConnectorError> {
+    let lower = object.trim().to_lowercase();
+    let plural = match lower.as_str() {
+        "contact" => "contacts",
+        "company" => "companies",
+        "deal" => "deals",
+        "ticket" => "tickets",
+        other => other,
+    };
+    if CRM_OBJECTS.contains(&plural) {
+        Ok(plural.to_string())
+    } else {
+        Err(ConnectorError::InvalidParams(format!(
+            "Unsupported object '{}'. Use one of: {}",
+            object,
+            CRM_OBJECTS.join(", ")
+        )))
+    }
+}
+
+fn default_properties(object: &str) -> &'static [&'static str] {
+    match object {
+        "contacts" => &[
+            "firstname",
+            "lastname",
+            "email",
+            "phone",
+            "company",
+            "jobtitle",
+            "lifecyclestage",
+            "hs_lastmodifieddate",
+        ],
+        "companies" => &[
+            "name",
+            "domain",
+            "industry",
+            "city",
+            "country",
+            "numberofemployees",
+            "annualrevenue",
+            "hs_lastmodifieddate",
+        ],
+        "deals" => &[
+            "dealname",
+            "amount",
+            "dealstage",
+            "pipeline",
+            "closedate",
+            "hubspot_owner_id",
+            "hs_lastmodifieddate",
+        ],
+        "tickets" => &[
+            "subject",
+            "content",
+            "hs_pipeline_stage",
+            "hs_ticket_priority",
+            "createdate",
+            "hs_lastmodifieddate",
+        ],
+        _ => &[],
+    }
+}
+
+fn engagement_properties(kind: &str) -> &'static [&'static str] {
+    match kind {
+        "notes" => &["hs_note_body", "hs_timestamp"],
+        "emails" => &[
+            "hs_email_subject",
+            "hs_email_text",
+            "hs_email_html",
+            "hs_email_direction",
+            "hs_email_sender_email",
+            "hs_email_to_email",
+            "hs_timestamp",
+        ],
+        "calls" => &[
+            "hs_call_title",
+            "hs_call_body",
+            "hs_call_direction",
+            "hs_call_duration",
+            "hs_timestamp",
+        ],
+        "meetings" => &[
+            "hs_meeting_title",
+            "hs_meeting_body",
+            "hs_meeting_start_time",
+            "hs_meeting_end_time",
+            "hs_timestamp",
+        ],
+        _ => &["hs_timestamp"],
+    }
+}
+
+fn property_list(input: Option<&str>, object: &str) -> Vec<String> {
+    match input {
+        Some(list) => list
+            .split(',')
+            .map(|p| p.trim().to_string())
+            .filter(|p| !p.is_empty())
+            .collect(),
+        None => default_properties(object)
+            .iter()
+            .map(|p| p.to_string())
+            .collect(),
+    }
+}
+
+fn rich_text(value: &Value) -> Option<String> {
+    value
+        .as_str()
+        .filter(|s| !s.trim().is_empty())
+        .map(html_to_text)
+}
+
+fn format_engagement(kind: &str, record: &Value) -> Value {
+    let p = &record["properties"];
+    let (title, body) = match kind {
+        "notes" => (None, rich_text(&p["hs_note_body"])),
+        "emails" => (
+            p["hs_email_subject"].as_str().map(str::to_string),
+            p["hs_email_text"]
+                .as_str()
+                .filter(|s| !s.trim().is_empty())
+                .map(str::to_string)
+                .or_else(|| rich_text(&p["hs_email_html"])),
+        ),
+        "calls" => (
+            p["hs_call_title"].as_str().map(str::to_string),
+            rich_text(&p["hs_call_body"]),
+        ),
+        "meetings" => (
+            p["hs_meeting_title"].as_str().map(str::to_string),
+            rich_text(&p["hs_meeting_body"]),
+        ),
+        _ => (None, None),
+    };
+    let mut out = json!({
+        "id": record["id"],
+        "type": kind.trim_end_matches('s'),
+        "timestamp": p["hs_timestamp"],
+        "title": title,
+        "body": body,
+    });
+    if kind == "emails" {
+        out["direction"] = p["hs_email_direction"].clone();
+        out["from"] = p["hs_email_sender_email"].clone();
+        out["to"] = p["hs_email_to_email"].clone();
+    }
+    if kind == "calls" {
+        out["direction"] = p["hs_call_direction"].clone();
+        out["duration_ms"] = p["hs_call_duration"].clone();
+    }
+    if kind == "meetings" {
+        out["start_time"] = p["hs_meeting_start_time"].clone();
+        out["end_time"] = p["hs_meeting_end_time"].clone();
+    }
+    out
+}
+
+fn format_record(record: &Value) -> Value {
+    let mut out = json!({
+        "id": record["id"],
+        "properties": record["properties"],
+        "updated_at": record["updatedAt"],
+    });
+    if let Some(assoc) = record["associations"].as_object() {
+        let mapped: Map<String, Value> = assoc
+            .iter()
+            .map(|(kind, v)| {
+                let ids: Vec<Value> = v["results"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|r| r["id"].clone())
+                    .collect();
+                (kind.clone(), json!(ids))
+            })
+            .collect();
+        out["associations"] = Value::Object(mapped);
+    }
+    out
+}
+
+#[async_trait]
+impl Connector for HubSpotConnector {
+    fn name(&self) -> &'static str {
+        "hubspot"
+    }
+
+    fn description(&self) -> &'static str {
+        "HubSpot CRM: contacts, companies, deals and tickets, plus notes/emails/calls/meetings."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        let mut auth = AuthDetails::new();
+        if let Some(token) = &self.token {
+            auth.insert("api_key".to_string(), token.clone());
+        }
+        Ok(auth)
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        *self = Self::new(details).await?;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.request(
+            Method::GET,
+            "/crm/v3/objects/contacts",
+            &[("limit", "1".to_string())],
+            None,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema {
+            fields: vec![Field {
+                name: "api_key".to_string(),
+                label: "Private App Token".to_string(),
+                field_type: FieldType::Secret,
+                required: true,
+                description: Some(
+                    "HubSpot private app access token (pat-...), or set HUBSPOT_ACCESS_TOKEN."
+                        .to_string(),
+                ),
+                options: None,
+            }],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Use `search_records` to find contacts/companies/deals/tickets, `get_record` for \
+details and associations, and `list_engagements` for a record's notes, emails, calls and meetings."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let object_schema = json!({
+            "type": "string",
+            "enum": CRM_OBJECTS,
+            "description": "CRM object type"
+        });
+
+        let tools = vec![
+            tool(
+                "search_records",
+                "Search contacts, companies, deals or tickets by text and/or property filters. \
+Example: object=\"deals\" filters=[{\"property\":\"dealstage\",\"operator\":\"EQ\",\"value\":\"closedwon\"}].",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "object": object_schema.clone(),
+                        "query": { "type": "string", "description": "Free-text search (name, email, domain, ...)" },
+                        "filters": {
+                            "type": "array",
+                            "description": "Property filters (ANDed)",
+                            "items": {
+                                "type": "object",
+                                "properties": {
+                                    "property": { "type": "string" },
+                                    "operator": { "type": "string", "description": "EQ, NEQ, LT, LTE, GT, GTE, CONTAINS_TOKEN, HAS_PROPERTY, ... (default: EQ)" },
+                                    "value": { "description": "Comparison value" }
+                                },
+                                "required": ["property"]
+                            }
+                        },
+                        "properties": { "type": "string", "description": "Comma-separated properties to return" },
+                        "sort": { "type": "string", "description": "Property to sort by; prefix with '-' for descending" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10, max: 100)" },
+                        "after": { "type": "string", "description": "Pagination cursor from a previous call" }
+                    },
+                    "required": ["object"]
+                }),
+            ),
+            tool(
+                "get_record",
+                "Get one record with properties and associated record ids. Example: object=\"companies\" id=\"12345\" associations=\"contacts,deals\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "object": object_schema.clone(),
+                        "id": { "type": "string", "description": "Record id" },
+                        "properties": { "type": "string", "description": "Comma-separated properties to return" },
+                        "associations": { "type": "string", "description": "Comma-separated object types to list associated ids for" }
+                    },
+                    "required": ["object", "id"]
+                }),
+            ),
+            tool(
+                "list_engagements",
+                "Notes, emails, calls and meetings attached to a record, newest first. Example: object=\"contacts\" id=\"51\" types=\"notes,emails\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "object": object_schema.clone(),
+                        "id": { "type": "string", "description": "Record id" },
+                        "types": { "type": "string", "description": "Comma-separated: notes, emails, calls, meetings (default: notes,emails)" },
+                        "limit": { "type": "integer", "description": "Max engagements (default: 25)" }
+                    },
+                    "required": ["object", "id"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "search_records" => {
+                let args: SearchArgs = parse_args(request.arguments)?;
+                let object = normalize_object(&args.object)?;
+
+                let filters: Vec<Value> = args
+                    .filters
+                    .iter()
+                    .map(|f| {
+                        let mut filter = json!({
+                            "propertyName": f.property,
+                            "operator": f.operator.as_deref().unwrap_or("EQ").to_uppercase(),
+                        });
+                        if let Some(value) = &f.value {
+                            filter["value"] = match value {
+                                Value::String(_) => value.clone(),
+                                other => json!(other.to_string()),
+                            };
+                        }
+                        filter
+                    })
+                    .collect();
+                let mut body = json!({
+                    "properties": property_list(args.properties.as_deref(), &object),
+                    "limit": args.limit.unwrap_or(10).clamp(1, 100),
+                });
+                if let Some(query) = &args.query {
+                    body["query"] = json!(query);
+                }
+                if !filters.is_empty() {
+                    body["filterGroups"] = json!([{ "filters": filters }]);
+                }
+                if let Some(sort) = &args.sort {
+                    let (name, direction) = match sort.strip_prefix('-') {
+                        Some(name) => (name, "DESCENDING"),
+                        None => (sort.as_str(), "ASCENDING"),
+                    };
+                    body["sorts"] = json!([{ "propertyName": name, "direction": direction }]);
+                }
+                if let Some(after) = &args.after {
+                    body["after"] = json!(after);
+                }
+
+                let response = self
+                    .request(
+                        Method::POST,
+                        &format!("/crm/v3/objects/{}/search", object),
+                        &[],
+                        Some(body),
+                    )
+                    .await?;
+                let results: Vec<Value> = response["results"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(format_record)
+                    .collect();
+
+                let data = json!({
+                    "object": object,
+                    "total": response["total"],
+                    "count": results.len(),
+                    "results": results,
+                    "after": response["paging"]["next"]["after"],
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_record" => {
+                let args: GetRecordArgs = parse_args(request.arguments)?;
+                let object = normalize_object(&args.object)?;
+                let mut params = vec![(
+                    "properties",
+                    property_list(args.properties.as_deref(), &object).join(","),
+                )];
+                if let Some(assoc) = args.associations {
+                    params.push(("associations", assoc));
+                }
+
+                let record = self
+                    .request(
+                        Method::GET,
+                        &format!("/crm/v3/objects/{}/{}", object, args.id.trim()),
+                        &params,
+                        None,
+                    )
+                    .await?;
+                let mut data = format_record(&record);
+                data["object"] = json!(object);
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "list_engagements" => {
+                let args: EngagementArgs = parse_args(request.arguments)?;
+                let object = normalize_object(&args.object)?;
+                let types: Vec<String> = args
+                    .types
+                    .as_deref()
+                    .unwrap_or("notes,emails")
+                    .split(',')
+                    .map(|t| t.trim().to_lowercase())
+                    .filter(|t| !t.is_empty())
+                    .collect();
+                if let Some(bad) = types
+                    .iter()
+                    .find(|t| !ENGAGEMENT_TYPES.contains(&t.as_str()))
+                {
+                    return Err(ConnectorError::InvalidParams(format!(
+                        "Unsupported engagement type '{}'. Use: {}",
+                        bad,
+                        ENGAGEMENT_TYPES.join(", ")
+                    )));
+                }
+                let limit = args.limit.unwrap_or(25);
+
+                let mut items: Vec<Value> = Vec::new();
+                for kind in &types {
+                    items.extend(
+                        self.engagements(&object, args.id.trim(), kind, limit)
+                            .await?,
+                    );
+                }
+                items.sort_by(|a, b| b["timestamp"].as_str().cmp(&a["timestamp"].as_str()));
+                items.truncate(limit);
+
+                let data = json!({
+                    "object": object,
+                    "id": args.id,
+                    "count": items.len(),
+                    "engagements": items,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_object_names() {
+        assert_eq!(normalize_object("Contact").unwrap(), "contacts");
+        assert_eq!(normalize_object("companies").unwrap(), "companies");
+        assert!(normalize_object("invoices").is_err());
+    }
+
+    #[test]
+    fn formats_email_engagement() {
+        let record = json!({
+            "id": "9",
+            "properties": {
+                "hs_email_subject": "Renewal",
+                "hs_email_text": "",
+                "hs_email_html": "<p>See you <b>Friday</b></p>",
+                "hs_timestamp": "2024-05-01T10:00:00Z"
+            }
+        });
+        let email = format_engagement("emails", &record);
+        assert_eq!(email["type"], "email");
+        assert_eq!(email["title"], "Renewal");
+        assert_eq!(email["body"], "See you Friday");
+    }
+}
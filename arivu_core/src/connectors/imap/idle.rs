@@ -0,0 +1,196 @@
+// src/connectors/imap/idle.rs
+//
+// The `idle` tool: hold a selected mailbox open with IMAP IDLE (RFC 2177)
+// and return messages as they arrive. IDLE is re-issued in short slices so
+// cancellation and the overall timeout are noticed promptly; servers without
+// IDLE are polled with NOOP on the same schedule. Each arrival is pushed to
+// MCP clients as `notifications/resources/updated` for the mailbox.
+
+use std::time::{Duration, Instant};
+
+use imap::types::UnsolicitedResponse;
+use imap::{Connection as ImapConnection, Session};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::{build_message_summary, map_imap_error, MessageSummary};
+use crate::error::ConnectorError;
+use crate::tool_context::ToolCallContext;
+
+pub(super) const DEFAULT_IDLE_SECS: u64 = 300;
+pub(super) const MAX_IDLE_SECS: u64 = 3_600;
+/// Longest single IDLE/NOOP wait between checks for cancellation.
+const IDLE_SLICE: Duration = Duration::from_secs(20);
+
+#[derive(Debug, Deserialize)]
+pub(super) struct IdleArgs {
+    #[serde(default)]
+    pub mailbox: Option<String>,
+    /// Report messages with UIDs at or above this immediately; pass the
+    /// previous call's `next_uid` to miss nothing between calls
+    #[serde(default)]
+    pub since_uid: Option<u32>,
+    #[serde(default)]
+    pub timeout_seconds: Option<u64>,
+    /// Return once this many new messages have arrived
+    #[serde(default)]
+    pub max_messages: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct IdleResponse {
+    mailbox: String,
+    messages: Vec<MessageSummary>,
+    /// UIDVALIDITY; UIDs are only comparable while it is unchanged
+    uid_validity: Option<u32>,
+    /// Pass as `since_uid` to resume watching
+    next_uid: u32,
+    /// Whether the server supports IDLE (else NOOP polling was used)
+    idle_supported: bool,
+    timed_out: bool,
+    cancelled: bool,
+}
+
+/// Wait on `mailbox` until `max_messages` new messages have arrived, the
+/// timeout passes or the call is cancelled.
+pub(super) fn wait_for_mail(
+    session: &mut Session<ImapConnection>,
+    mailbox: String,
+    since_uid: Option<u32>,
+    timeout: Duration,
+    max_messages: usize,
+    ctx: &ToolCallContext,
+) -> Result<IdleResponse, ConnectorError> {
+    let idle_supported = session
+        .capabilities()
+        .map_err(map_imap_error)?
+        .has_str("IDLE");
+    let selected = session.select(&mailbox).map_err(map_imap_error)?;
+    let mut next_uid = since_uid.or(selected.uid_next).unwrap_or(1);
+    let deadline = Instant::now() + timeout;
+    let resource_uri = format!("imap://mailbox/{}", urlencoding::encode(&mailbox));
+
+    let mut messages: Vec<MessageSummary> = Vec::new();
+    let mut timed_out = false;
+    loop {
+        for message in new_messages(session, next_uid)? {
+            if let Some(uid) = message.uid {
+                next_uid = advance_next_uid(next_uid, uid);
+            }
+            ctx.notify(
+                "notifications/resources/updated",
+                json!({ "uri": resource_uri }),
+            );
+            ctx.report_progress(
+                (messages.len() + 1) as f64,
+                Some(max_messages as f64),
+                format!(
+                    "New mail in {} from {}: {}",
+                    mailbox,
+                    message.from.join(", "),
+                    message.subject.as_deref().unwrap_or("(no subject)")
+                ),
+            );
+            messages.push(message);
+        }
+        if messages.len() >= max_messages || ctx.is_cancelled() {
+            break;
+        }
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            timed_out = true;
+            break;
+        }
+
+        let slice = remaining.min(IDLE_SLICE);
+        if idle_supported {
+            let mut idle = session.idle();
+            idle.timeout(slice);
+            idle.keepalive(false);
+            // Stop waiting as soon as the server announces a new message
+            idle.wait_while(|response| {
+                !matches!(
+                    response,
+                    UnsolicitedResponse::Exists(_) | UnsolicitedResponse::Recent(_)
+                )
+            })
+            .map_err(map_imap_error)?;
+        } else {
+            std::thread::sleep(slice);
+            session.noop().map_err(map_imap_error)?;
+        }
+    }
+
+    Ok(IdleResponse {
+        mailbox,
+        messages,
+        uid_validity: selected.uid_validity,
+        next_uid,
+        idle_supported,
+        timed_out,
+        cancelled: ctx.is_cancelled(),
+    })
+}
+
+/// Summaries of messages with UID >= `from_uid`, oldest first.
+fn new_messages(
+    session: &mut Session<ImapConnection>,
+    from_uid: u32,
+) -> Result<Vec<MessageSummary>, ConnectorError> {
+    let found = session
+        .uid_search(format!("UID {}:*", from_uid))
+        .map_err(map_imap_error)?;
+    let uids = uids_since(found, from_uid);
+    if uids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let sequence = uids
+        .iter()
+        .map(|uid| uid.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    let fetches = session
+        .uid_fetch(&sequence, "(UID ENVELOPE FLAGS INTERNALDATE RFC822.SIZE)")
+        .map_err(map_imap_error)?;
+    let mut messages: Vec<MessageSummary> = fetches.iter().map(build_message_summary).collect();
+    messages.sort_by_key(|m| m.uid);
+    Ok(messages)
+}
+
+/// Search hits at or above `from_uid`, oldest first. `n:*` always matches the
+/// newest message even when its UID is below n, so that one is dropped.
+fn uids_since(found: impl IntoIterator<Item = u32>, from_uid: u32) -> Vec<u32> {
+    let mut uids: Vec<u32> = found.into_iter().filter(|uid| *uid >= from_uid).collect();
+    uids.sort_unstable();
+    uids
+}
+
+/// The UID to resume from once `uid` has been reported.
+fn advance_next_uid(next_uid: u32, uid: u32) -> u32 {
+    next_uid.max(uid.saturating_add(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_only_uids_since_the_last_call() {
+        assert_eq!(uids_since([9, 5, 7, 3], 5), vec![5, 7, 9]);
+        // `UID 12:*` on a mailbox whose newest UID is 11
+        assert!(uids_since([11], 12).is_empty());
+        assert!(uids_since(Vec::new(), 1).is_empty());
+    }
+
+    #[test]
+    fn next_uid_moves_past_reported_messages() {
+        let mut next_uid = 5;
+        for uid in uids_since([7, 5, 6], next_uid) {
+            next_uid = advance_next_uid(next_uid, uid);
+        }
+        assert_eq!(next_uid, 8);
+        // An older UID never moves it backwards
+        assert_eq!(advance_next_uid(next_uid, 3), 8);
+        assert_eq!(advance_next_uid(1, u32::MAX), u32::MAX);
+    }
+}
@@ -0,0 +1,185 @@
+// src/connectors/imap/mime.rs
+//
+// Walking a parsed message's MIME tree. Leaf parts are numbered the way
+// IMAP numbers body sections ("1", "2.1", ...), so a part_id shown by
+// get_message can be passed straight back to get_attachments. Body text
+// comes only from parts that aren't attachments, so a .txt attachment is
+// never mistaken for the message itself.
+
+use mailparse::{DispositionType, ParsedMail};
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(super) struct MimePart {
+    pub part_id: String,
+    pub content_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charset: Option<String>,
+    /// inline or attachment
+    pub disposition: String,
+    /// Decoded size in bytes
+    pub size: usize,
+    pub is_attachment: bool,
+}
+
+impl MimePart {
+    pub fn matches(&self, selector: &str) -> bool {
+        self.part_id == selector
+            || self
+                .filename
+                .as_deref()
+                .is_some_and(|f| f.eq_ignore_ascii_case(selector))
+    }
+}
+
+/// Leaf parts with their IMAP section numbers, depth first.
+pub(super) fn leaf_parts<'m, 'a>(mail: &'m ParsedMail<'a>) -> Vec<(String, &'m ParsedMail<'a>)> {
+    let mut out = Vec::new();
+    if mail.subparts.is_empty() {
+        // A single-part message's body is section 1
+        out.push(("1".to_string(), mail));
+    } else {
+        walk(mail, "", &mut out);
+    }
+    out
+}
+
+fn walk<'m, 'a>(
+    part: &'m ParsedMail<'a>,
+    prefix: &str,
+    out: &mut Vec<(String, &'m ParsedMail<'a>)>,
+) {
+    for (i, child) in part.subparts.iter().enumerate() {
+        let id = if prefix.is_empty() {
+            (i + 1).to_string()
+        } else {
+            format!("{}.{}", prefix, i + 1)
+        };
+        if child.subparts.is_empty() {
+            out.push((id, child));
+        } else {
+            walk(child, &id, out);
+        }
+    }
+}
+
+/// Describe a leaf part without keeping its body.
+pub(super) fn describe(part_id: &str, part: &ParsedMail<'_>) -> MimePart {
+    let disposition = part.get_content_disposition();
+    let filename = disposition
+        .params
+        .get("filename")
+        .or_else(|| part.ctype.params.get("name"))
+        .map(|f| f.trim().to_string())
+        .filter(|f| !f.is_empty());
+    let explicit_attachment = matches!(disposition.disposition, DispositionType::Attachment);
+    MimePart {
+        part_id: part_id.to_string(),
+        content_type: part.ctype.mimetype.to_ascii_lowercase(),
+        charset: part
+            .ctype
+            .mimetype
+            .starts_with("text/")
+            .then(|| part.ctype.charset.clone()),
+        disposition: if explicit_attachment {
+            "attachment"
+        } else {
+            "inline"
+        }
+        .to_string(),
+        size: part.get_body_raw().map(|b| b.len()).unwrap_or(0),
+        // Named parts (inline images, forwarded files) count as attachments
+        is_attachment: explicit_attachment || filename.is_some(),
+        filename,
+    }
+}
+
+/// Every leaf part of the message.
+pub(super) fn collect_parts(mail: &ParsedMail<'_>) -> Vec<MimePart> {
+    leaf_parts(mail)
+        .into_iter()
+        .map(|(id, part)| describe(&id, part))
+        .collect()
+}
+
+/// Decoded text of the first non-attachment part of type `mime`.
+pub(super) fn body_text(mail: &ParsedMail<'_>, mime: &str) -> Option<String> {
+    leaf_parts(mail)
+        .into_iter()
+        .filter(|(id, part)| {
+            part.ctype.mimetype.eq_ignore_ascii_case(mime) && !describe(id, part).is_attachment
+        })
+        .find_map(|(_, part)| part.get_body().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mailparse::parse_mail;
+
+    const MESSAGE: &[u8] = b"From: a@example.com\r\n\
+Subject: Report\r\n\
+MIME-Version: 1.0\r\n\
+Content-Type: multipart/mixed; boundary=\"outer\"\r\n\
+\r\n\
+--outer\r\n\
+Content-Type: multipart/alternative; boundary=\"inner\"\r\n\
+\r\n\
+--inner\r\n\
+Content-Type: text/plain; charset=utf-8\r\n\
+\r\n\
+See attached.\r\n\
+--inner\r\n\
+Content-Type: text/html; charset=utf-8\r\n\
+\r\n\
+<p>See <b>attached</b>.</p>\r\n\
+--inner--\r\n\
+--outer\r\n\
+Content-Type: text/plain; name=\"notes.txt\"\r\n\
+Content-Disposition: attachment; filename=\"notes.txt\"\r\n\
+\r\n\
+not the body\r\n\
+--outer\r\n\
+Content-Type: application/pdf\r\n\
+Content-Disposition: attachment; filename=\"Q3.pdf\"\r\n\
+Content-Transfer-Encoding: base64\r\n\
+\r\n\
+JVBERi0xLjQK\r\n\
+--outer--\r\n";
+
+    #[test]
+    fn numbers_parts_and_skips_attachments_for_body() {
+        let mail = parse_mail(MESSAGE).unwrap();
+        let parts = collect_parts(&mail);
+        let ids: Vec<(&str, &str)> = parts
+            .iter()
+            .map(|p| (p.part_id.as_str(), p.content_type.as_str()))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                ("1.1", "text/plain"),
+                ("1.2", "text/html"),
+                ("2", "text/plain"),
+                ("3", "application/pdf"),
+            ]
+        );
+        assert!(!parts[0].is_attachment);
+        assert!(parts[2].is_attachment);
+        assert_eq!(parts[3].size, 9);
+        assert!(parts[3].matches("q3.pdf"));
+        assert!(parts[2].matches("2"));
+
+        assert_eq!(
+            body_text(&mail, "text/plain").unwrap().trim(),
+            "See attached."
+        );
+        assert!(body_text(&mail, "text/html").unwrap().contains("<b>"));
+
+        let single = parse_mail(b"Subject: hi\r\n\r\nplain body").unwrap();
+        assert_eq!(collect_parts(&single)[0].part_id, "1");
+        assert_eq!(body_text(&single, "text/plain").unwrap(), "plain body");
+    }
+}
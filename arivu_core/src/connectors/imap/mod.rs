@@ -1,15 +1,17 @@
 use async_trait::async_trait;
 use base64::Engine;
+use futures::future::BoxFuture;
 use imap::Error as ImapError;
 use imap::{ClientBuilder, Connection as ImapConnection, ConnectionMode, Session};
 use imap_proto::types::NameAttribute;
-use mailparse::{parse_mail, ParsedMail};
+use mailparse::parse_mail;
 use rmcp::model::*;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::borrow::Cow;
 use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tokio::task;
 use tracing::debug;
 
@@ -19,6 +21,12 @@ use crate::error::ConnectorError;
 use crate::utils::{collect_paginated_with_cursor, structured_result_with_text, Page};
 use crate::Connector;
 
+mod idle;
+mod mime;
+
+const DEFAULT_ATTACHMENT_MAX_BYTES: u64 = 10 * 1024 * 1024;
+const DEFAULT_ATTACHMENT_MAX_CHARS: usize = 50_000;
+
 #[derive(Clone, Debug)]
 struct ImapConfig {
     host: String,
@@ -90,13 +98,20 @@ impl ImapConnector {
         Ok(connector)
     }
 
+    /// Mailbox used when a tool call doesn't name one.
+    pub(crate) fn default_mailbox(&self) -> Option<String> {
+        self.config.as_ref().map(|c| c.default_mailbox.clone())
+    }
+
     fn ensure_config(&self) -> Result<&ImapConfig, ConnectorError> {
         self.config.as_ref().ok_or_else(|| {
             ConnectorError::Authentication("IMAP credentials are not configured".to_string())
         })
     }
 
-    async fn with_session<F, T>(&self, f: F) -> Result<T, ConnectorError>
+    /// Run `f` on a fresh logged-in session on the blocking pool. Shared with the
+    /// newsletters connector, which layers over these credentials.
+    pub(crate) async fn with_session<F, T>(&self, f: F) -> Result<T, ConnectorError>
     where
         F: FnOnce(&mut Session<ImapConnection>) -> Result<T, ConnectorError> + Send + 'static,
         T: Send + 'static,
@@ -401,7 +416,7 @@ impl ImapConnector {
 
             let summary = build_message_summary(fetch);
             let raw_body = fetch.body().map(|b| b.to_vec());
-            let (text_body, html_body, headers) = raw_body
+            let (text_body, html_body, headers, parts) = raw_body
                 .as_ref()
                 .map(|body| parse_message_bodies(body))
                 .unwrap_or_default();
@@ -443,6 +458,7 @@ impl ImapConnector {
                 summary,
                 content,
                 content_source,
+                parts,
                 // Only include headers if explicitly requested
                 headers: if include_headers { Some(headers) } else { None },
                 // Only include text_body if different from content (for debugging)
@@ -455,6 +471,114 @@ impl ImapConnector {
         .await
     }
 
+    async fn get_attachments(
+        &self,
+        args: GetAttachmentsArgs,
+    ) -> Result<AttachmentsResponse, ConnectorError> {
+        let config = self.ensure_config()?;
+        let mailbox = args
+            .mailbox
+            .unwrap_or_else(|| config.default_mailbox.clone());
+        let uid = args.uid;
+
+        let selected = mailbox.clone();
+        let raw = self
+            .with_session(move |session| {
+                session.select(&selected).map_err(map_imap_error)?;
+                let fetches = session
+                    .uid_fetch(uid.to_string(), "(UID BODY.PEEK[])")
+                    .map_err(map_imap_error)?;
+                let fetch = fetches
+                    .iter()
+                    .next()
+                    .ok_or(ConnectorError::ResourceNotFound)?;
+                Ok(fetch.body().map(|b| b.to_vec()).unwrap_or_default())
+            })
+            .await?;
+
+        let selectors = args.select;
+        let all = args.all;
+        let include_data = args.include_data;
+        let max_bytes = args.max_bytes.unwrap_or(DEFAULT_ATTACHMENT_MAX_BYTES);
+        let max_chars = args.max_chars.unwrap_or(DEFAULT_ATTACHMENT_MAX_CHARS);
+        let attachments = crate::cpu_pool::spawn_cpu(move || {
+            let parsed = parse_mail(&raw).map_err(|err| {
+                ConnectorError::Other(format!("Failed to parse message: {}", err))
+            })?;
+            let mut results = Vec::new();
+            for (part_id, part) in mime::leaf_parts(&parsed) {
+                let info = mime::describe(&part_id, part);
+                if !info.is_attachment {
+                    continue;
+                }
+                let mut entry = serde_json::to_value(&info)?;
+                let wanted = all || selectors.iter().any(|s| info.matches(s));
+                if !wanted {
+                    results.push(entry);
+                    continue;
+                }
+                if info.size as u64 > max_bytes {
+                    entry["error"] =
+                        json!(format!("{} bytes, over max_bytes={}", info.size, max_bytes));
+                    results.push(entry);
+                    continue;
+                }
+                let bytes = part.get_body_raw().map_err(|err| {
+                    ConnectorError::Other(format!("attachment decode error: {}", err))
+                })?;
+                if include_data {
+                    entry["data"] = json!(base64::engine::general_purpose::STANDARD.encode(&bytes));
+                }
+                let filename = info.filename.clone().unwrap_or_default();
+                match crate::doc_text::extract_text(
+                    &bytes,
+                    &filename,
+                    Some(&info.content_type),
+                    max_chars,
+                ) {
+                    Ok(doc) => {
+                        entry["format"] = json!(doc.kind.as_str());
+                        entry["text"] = json!(doc.text);
+                        entry["truncated"] = json!(doc.truncated);
+                    }
+                    // Images and the like: the bytes are the answer
+                    Err(_) if include_data => {}
+                    Err(err) => entry["error"] = json!(err.to_string()),
+                }
+                results.push(entry);
+            }
+            Ok(results)
+        })
+        .await?;
+
+        Ok(AttachmentsResponse {
+            mailbox,
+            uid,
+            attachments,
+        })
+    }
+
+    async fn idle(&self, args: idle::IdleArgs) -> Result<idle::IdleResponse, ConnectorError> {
+        let config = self.ensure_config()?;
+        let mailbox = args
+            .mailbox
+            .unwrap_or_else(|| config.default_mailbox.clone());
+        let timeout = Duration::from_secs(
+            args.timeout_seconds
+                .unwrap_or(idle::DEFAULT_IDLE_SECS)
+                .clamp(1, idle::MAX_IDLE_SECS),
+        );
+        let max_messages = args.max_messages.unwrap_or(1).max(1);
+        let since_uid = args.since_uid;
+        // The session runs on the blocking pool, outside the call's task-local
+        let ctx = crate::tool_context::current();
+
+        self.with_session(move |session| {
+            idle::wait_for_mail(session, mailbox, since_uid, timeout, max_messages, &ctx)
+        })
+        .await
+    }
+
     async fn search(&self, args: SearchArgs) -> Result<SearchResults, ConnectorError> {
         let config = self.ensure_config()?;
         let mailbox = args
@@ -660,7 +784,8 @@ fn attribute_to_string(attr: &NameAttribute<'_>) -> String {
     }
 }
 
-fn parse_message_bodies(raw: &[u8]) -> (Option<String>, Option<String>, Vec<HeaderLine>) {
+/// Plain and HTML bodies (never from attachments), headers and MIME parts.
+fn parse_message_bodies(raw: &[u8]) -> ParsedBodies {
     if let Ok(parsed) = parse_mail(raw) {
         let headers = parsed
             .headers
@@ -670,26 +795,20 @@ fn parse_message_bodies(raw: &[u8]) -> (Option<String>, Option<String>, Vec<Head
                 value: header.get_value(),
             })
             .collect();
-        let plain = extract_body_by_mime(&parsed, "text/plain").or_else(|| parsed.get_body().ok());
-        let html = extract_body_by_mime(&parsed, "text/html");
-        (plain, html, headers)
+        let plain = mime::body_text(&parsed, "text/plain");
+        let html = mime::body_text(&parsed, "text/html");
+        (plain, html, headers, mime::collect_parts(&parsed))
     } else {
-        (None, None, Vec::new())
+        Default::default()
     }
 }
 
-fn extract_body_by_mime(parsed: &ParsedMail<'_>, target: &str) -> Option<String> {
-    if parsed.ctype.mimetype.eq_ignore_ascii_case(target) {
-        return parsed.get_body().ok();
-    }
-
-    for part in &parsed.subparts {
-        if let Some(body) = extract_body_by_mime(part, target) {
-            return Some(body);
-        }
-    }
-    None
-}
+type ParsedBodies = (
+    Option<String>,
+    Option<String>,
+    Vec<HeaderLine>,
+    Vec<mime::MimePart>,
+);
 
 #[derive(Debug, Deserialize)]
 struct ListMailboxesArgs {
@@ -828,6 +947,8 @@ struct MessageDetails {
     content: Option<String>,
     /// Content source: "text", "html_converted", or "none"
     content_source: String,
+    /// Leaf MIME parts; attachments are fetched with get_attachments
+    parts: Vec<mime::MimePart>,
     /// Headers - only included if specifically requested
     #[serde(skip_serializing_if = "Option::is_none")]
     headers: Option<Vec<HeaderLine>>,
@@ -841,6 +962,33 @@ struct MessageDetails {
     raw: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct GetAttachmentsArgs {
+    uid: u32,
+    #[serde(default)]
+    mailbox: Option<String>,
+    /// part_ids or filenames to download
+    #[serde(default)]
+    select: Vec<String>,
+    #[serde(default)]
+    all: bool,
+    /// Also return the decoded bytes as base64
+    #[serde(default)]
+    include_data: bool,
+    #[serde(default)]
+    max_bytes: Option<u64>,
+    #[serde(default)]
+    max_chars: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+struct AttachmentsResponse {
+    mailbox: String,
+    uid: u32,
+    /// Every attachment; selected ones also carry text (and data)
+    attachments: Vec<Value>,
+}
+
 #[derive(Debug, Serialize)]
 struct SearchResults {
     mailbox: String,
@@ -848,7 +996,7 @@ struct SearchResults {
     uids: Vec<u32>,
 }
 
-fn map_imap_error(err: ImapError) -> ConnectorError {
+pub(crate) fn map_imap_error(err: ImapError) -> ConnectorError {
     match err {
         ImapError::Io(inner) => ConnectorError::Io(inner),
         ImapError::Validate(inner) => ConnectorError::InvalidInput(inner.to_string()),
@@ -1058,7 +1206,7 @@ impl Connector for ImapConnector {
                 name: Cow::Borrowed("get_message"),
                 title: None,
                 description: Some(Cow::Borrowed(
-                    "Fetch a message by UID. Returns clean text content by default.",
+                    "Fetch a message by UID. Returns clean text content by default (text/plain, falling back to HTML converted to text; attachments are never used as the body) plus the list of MIME parts. Download attachments with get_attachments.",
                 )),
                 input_schema: Arc::new(
                     json!({
@@ -1080,6 +1228,34 @@ impl Connector for ImapConnector {
                 annotations: None,
                 icons: None,
             },
+            Tool {
+                name: Cow::Borrowed("get_attachments"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "List a message's attachments, and download selected ones (by part_id or filename, or all=true) with text extracted from PDF, DOCX, EPUB, HTML and text files. Set include_data for the base64 bytes, e.g. for images.",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "mailbox": { "type": "string", "description": "Mailbox containing the message." },
+                            "uid": { "type": "integer", "description": "Message UID." },
+                            "select": { "type": "array", "items": { "type": "string" }, "description": "part_ids (from get_message parts) or filenames to download." },
+                            "all": { "type": "boolean", "description": "Download every attachment." },
+                            "include_data": { "type": "boolean", "description": "Also return each downloaded attachment as base64 (default: false)." },
+                            "max_bytes": { "type": "integer", "minimum": 1, "description": "Skip attachments larger than this (default 10 MiB)." },
+                            "max_chars": { "type": "integer", "minimum": 1, "description": "Truncate each extracted text (default 50000)." }
+                        },
+                        "required": ["uid"]
+                    })
+                    .as_object()
+                    .expect("Schema object")
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
             Tool {
                 name: Cow::Borrowed("search"),
                 title: None,
@@ -1104,6 +1280,30 @@ impl Connector for ImapConnector {
                 annotations: None,
                 icons: None,
             },
+            Tool {
+                name: Cow::Borrowed("idle"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Wait for new mail with IMAP IDLE instead of polling. Returns as soon as max_messages new messages arrive (default 1), or empty when timeout_seconds passes. Over MCP each arrival is also sent as notifications/resources/updated for imap://mailbox/<name> plus a progress notification. Pass the returned next_uid as since_uid on the next call so nothing is missed between calls.",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "mailbox": { "type": "string", "description": "Mailbox to watch (defaults to INBOX)." },
+                            "since_uid": { "type": "integer", "description": "Report messages with UID >= this immediately (use next_uid from the previous call)." },
+                            "timeout_seconds": { "type": "integer", "description": "How long to wait (default 300, max 3600).", "minimum": 1, "maximum": 3600 },
+                            "max_messages": { "type": "integer", "description": "Return after this many new messages (default 1).", "minimum": 1 }
+                        }
+                    })
+                    .as_object()
+                    .expect("Schema object")
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
             Tool {
                 name: Cow::Borrowed("create_draft"),
                 title: None,
@@ -1166,12 +1366,24 @@ impl Connector for ImapConnector {
                 let message = self.get_message(parsed).await?;
                 structured_result_with_text(&message, None)
             }
+            "get_attachments" => {
+                let parsed: GetAttachmentsArgs = serde_json::from_value(args_value)
+                    .map_err(|err| ConnectorError::InvalidParams(err.to_string()))?;
+                let result = self.get_attachments(parsed).await?;
+                structured_result_with_text(&result, None)
+            }
             "search" => {
                 let parsed: SearchArgs = serde_json::from_value(args_value)
                     .map_err(|err| ConnectorError::InvalidParams(err.to_string()))?;
                 let results = self.search(parsed).await?;
                 structured_result_with_text(&results, None)
             }
+            "idle" => {
+                let parsed: idle::IdleArgs = serde_json::from_value(args_value)
+                    .map_err(|err| ConnectorError::InvalidParams(err.to_string()))?;
+                let result = self.idle(parsed).await?;
+                structured_result_with_text(&result, None)
+            }
             "create_draft" => {
                 let parsed: CreateDraftArgs = serde_json::from_value(args_value)
                     .map_err(|err| ConnectorError::InvalidParams(err.to_string()))?;
@@ -1182,6 +1394,27 @@ impl Connector for ImapConnector {
         }
     }
 
+    /// `idle` can wait up to an hour on its own session; run it without the connector's lock
+    /// so other IMAP calls aren't queued behind it.
+    fn detached_call(
+        &self,
+        request: &CallToolRequestParam,
+    ) -> Option<BoxFuture<'static, Result<CallToolResult, ConnectorError>>> {
+        if request.name != "idle" {
+            return None;
+        }
+        let connector = Self {
+            config: self.config.clone(),
+        };
+        let args_value = Value::Object(request.arguments.clone().unwrap_or_default());
+        Some(Box::pin(async move {
+            let parsed: idle::IdleArgs = serde_json::from_value(args_value)
+                .map_err(|err| ConnectorError::InvalidParams(err.to_string()))?;
+            let result = connector.idle(parsed).await?;
+            structured_result_with_text(&result, None)
+        }))
+    }
+
     async fn list_prompts(
         &self,
         _request: Option<PaginatedRequestParam>,
@@ -0,0 +1,506 @@
+mod server;
+mod store;
+
+pub use server::serve;
+pub use store::{InboxEvent, InboxStore};
+
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashSet};
+
+#[derive(Debug, Deserialize)]
+struct ListEventsArgs {
+    #[serde(default)]
+    source: Option<String>,
+    #[serde(default)]
+    event_type: Option<String>,
+    #[serde(default)]
+    since: Option<String>,
+    #[serde(default)]
+    unacked_only: Option<bool>,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchEventsArgs {
+    query: String,
+    #[serde(default)]
+    source: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetEventArgs {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AckEventsArgs {
+    ids: Vec<String>,
+}
+
+pub(crate) fn store_from(auth: &AuthDetails) -> InboxStore {
+    match auth.get("dir").filter(|d| !d.trim().is_empty()) {
+        Some(dir) => InboxStore::new(dir),
+        None => InboxStore::new(InboxStore::default_dir()),
+    }
+}
+
+/// One line describing what happened, from the fields senders commonly use.
+fn summarize(event: &InboxEvent) -> Option<String> {
+    let p = &event.payload;
+    let text = |v: &Value| v.as_str().map(str::to_string);
+    let summary = match event.source.as_str() {
+        "github" => {
+            let repo = p["repository"]["full_name"].as_str().unwrap_or_default();
+            let subject = text(&p["pull_request"]["title"])
+                .or_else(|| text(&p["issue"]["title"]))
+                .or_else(|| text(&p["head_commit"]["message"]))
+                .or_else(|| text(&p["release"]["name"]))
+                .or_else(|| text(&p["workflow_run"]["name"]))
+                .or_else(|| text(&p["ref"]));
+            let actor = p["sender"]["login"].as_str();
+            Some(
+                [
+                    Some(repo),
+                    subject.as_deref().map(|s| s.lines().next().unwrap_or(s)),
+                    actor,
+                ]
+                .into_iter()
+                .flatten()
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<_>>()
+                .join(" · "),
+            )
+        }
+        "stripe" => {
+            let object = &p["data"]["object"];
+            let amount = object["amount"]
+                .as_i64()
+                .or_else(|| object["amount_due"].as_i64())
+                .zip(object["currency"].as_str())
+                .map(|(amount, currency)| {
+                    format!("{:.2} {}", amount as f64 / 100.0, currency.to_uppercase())
+                });
+            Some(
+                [text(&object["id"]), amount, text(&object["customer"])]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join(" · "),
+            )
+        }
+        _ => {
+            let issue = &p["issue"];
+            text(&issue["key"])
+                .zip(text(&issue["fields"]["summary"]))
+                .map(|(key, summary)| format!("{} {}", key, summary))
+                .or_else(|| {
+                    ["title", "summary", "message", "text", "description", "name"]
+                        .iter()
+                        .find_map(|key| text(&p[*key]))
+                })
+        }
+    };
+    summary
+        .filter(|s| !s.is_empty())
+        .map(|s| s.chars().take(200).collect())
+}
+
+fn brief(event: &InboxEvent, acked: &HashSet<String>) -> Value {
+    json!({
+        "id": event.id,
+        "received_at": event.received_at,
+        "source": event.source,
+        "event_type": event.event_type,
+        "summary": summarize(event),
+        "verified": event.verified,
+        "acked": acked.contains(&event.id),
+    })
+}
+
+pub struct InboxConnector {
+    store: InboxStore,
+}
+
+impl InboxConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        Ok(Self {
+            store: store_from(&auth),
+        })
+    }
+
+    /// Events newest first, with the set of acknowledged ids.
+    async fn load(&self) -> Result<(Vec<InboxEvent>, HashSet<String>), ConnectorError> {
+        let store = self.store.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut events = store.load()?;
+            events.reverse();
+            Ok((events, store.acked()?))
+        })
+        .await
+        .map_err(|e| ConnectorError::Other(format!("Inbox task failed: {}", e)))?
+        .map_err(ConnectorError::Io)
+    }
+}
+
+#[async_trait]
+impl Connector for InboxConnector {
+    fn name(&self) -> &'static str {
+        "inbox"
+    }
+
+    fn description(&self) -> &'static str {
+        "Webhook inbox: events pushed by GitHub, Stripe, Jira and custom senders, queued locally."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        let mut auth = AuthDetails::new();
+        auth.insert("dir".to_string(), self.store.dir().display().to_string());
+        Ok(auth)
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        *self = Self::new(details).await?;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.load().await.map(|_| ())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema {
+            fields: vec![Field {
+                name: "dir".to_string(),
+                label: "Inbox Directory".to_string(),
+                field_type: FieldType::Text,
+                required: false,
+                description: Some(
+                    "Where events are stored (default: ~/.arivu/inbox, or ARIVU_INBOX_DIR). \
+Signing secrets are stored as secret.<source>."
+                        .to_string(),
+                ),
+                options: None,
+            }],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Events arrive while `arivu inbox serve` is running. Poll with `list_events` \
+(`unacked_only=true` for new work), inspect with `get_event`, and mark handled events with \
+`ack_events` so they are not picked up again. `search_events` matches text anywhere in the payload."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let source = json!({ "type": "string", "description": "Source, e.g. github, stripe, jira or a custom path name" });
+        let limit =
+            json!({ "type": "integer", "description": "Max events (default: 25, max: 500)" });
+
+        let tools = vec![
+            tool(
+                "list_events",
+                "Received webhook events, newest first, with a one-line summary and per-source counts. \
+Example: source=\"github\" event_type=\"pull_request\" unacked_only=true.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "source": source.clone(),
+                        "event_type": { "type": "string", "description": "Event type prefix, e.g. pull_request or invoice." },
+                        "since": { "type": "string", "description": "Only events received at or after this time (RFC 3339)" },
+                        "unacked_only": { "type": "boolean", "description": "Skip acknowledged events (default: false)" },
+                        "limit": limit.clone()
+                    }
+                }),
+            ),
+            tool(
+                "search_events",
+                "Find events whose payload contains the text (case-insensitive). Example: query=\"cus_N1x\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Text to find in the payload" },
+                        "source": source,
+                        "limit": limit
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "get_event",
+                "Full event: payload, kept headers, delivery id and signature status.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "string", "description": "Event id (evt_...)" }
+                    },
+                    "required": ["id"]
+                }),
+            ),
+            tool(
+                "ack_events",
+                "Mark events as handled so unacked_only listings skip them.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "ids": { "type": "array", "items": { "type": "string" }, "description": "Event ids" }
+                    },
+                    "required": ["ids"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "list_events" => {
+                let args: ListEventsArgs = parse_args(request.arguments)?;
+                let limit = args.limit.unwrap_or(25).clamp(1, 500);
+                let since = args
+                    .since
+                    .as_deref()
+                    .map(|s| {
+                        chrono::DateTime::parse_from_rfc3339(s).map_err(|e| {
+                            ConnectorError::InvalidParams(format!("Invalid 'since': {}", e))
+                        })
+                    })
+                    .transpose()?;
+                let (events, acked) = self.load().await?;
+
+                let mut sources: BTreeMap<&str, usize> = BTreeMap::new();
+                let matching: Vec<&InboxEvent> = events
+                    .iter()
+                    .filter(|e| match &args.source {
+                        Some(source) => e.source.eq_ignore_ascii_case(source),
+                        None => true,
+                    })
+                    .filter(|e| match &args.event_type {
+                        Some(kind) => e
+                            .event_type
+                            .as_deref()
+                            .unwrap_or_default()
+                            .starts_with(kind.as_str()),
+                        None => true,
+                    })
+                    .filter(|e| match since {
+                        Some(since) => chrono::DateTime::parse_from_rfc3339(&e.received_at)
+                            .is_ok_and(|at| at >= since),
+                        None => true,
+                    })
+                    .filter(|e| !(args.unacked_only.unwrap_or(false) && acked.contains(&e.id)))
+                    .collect();
+                for event in &matching {
+                    *sources.entry(event.source.as_str()).or_default() += 1;
+                }
+                let listed: Vec<Value> = matching
+                    .iter()
+                    .take(limit)
+                    .map(|e| brief(e, &acked))
+                    .collect();
+
+                let data = json!({
+                    "total": matching.len(),
+                    "count": listed.len(),
+                    "by_source": sources,
+                    "events": listed,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "search_events" => {
+                let args: SearchEventsArgs = parse_args(request.arguments)?;
+                let limit = args.limit.unwrap_or(25).clamp(1, 500);
+                let query = args.query.to_lowercase();
+                let (events, acked) = self.load().await?;
+
+                let listed: Vec<Value> = events
+                    .iter()
+                    .filter(|e| match &args.source {
+                        Some(source) => e.source.eq_ignore_ascii_case(source),
+                        None => true,
+                    })
+                    .filter(|e| {
+                        e.payload.to_string().to_lowercase().contains(&query)
+                            || e.event_type
+                                .as_deref()
+                                .is_some_and(|t| t.to_lowercase().contains(&query))
+                    })
+                    .take(limit)
+                    .map(|e| brief(e, &acked))
+                    .collect();
+
+                let data = json!({
+                    "query": args.query,
+                    "count": listed.len(),
+                    "events": listed,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_event" => {
+                let args: GetEventArgs = parse_args(request.arguments)?;
+                let (events, acked) = self.load().await?;
+                let event = events
+                    .iter()
+                    .find(|e| e.id == args.id.trim())
+                    .ok_or(ConnectorError::ResourceNotFound)?;
+
+                let mut data = serde_json::to_value(event)?;
+                data["summary"] = json!(summarize(event));
+                data["acked"] = json!(acked.contains(&event.id));
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "ack_events" => {
+                let args: AckEventsArgs = parse_args(request.arguments)?;
+                let (events, acked) = self.load().await?;
+                let known: HashSet<&str> = events.iter().map(|e| e.id.as_str()).collect();
+                let (found, missing): (Vec<String>, Vec<String>) = args
+                    .ids
+                    .into_iter()
+                    .map(|id| id.trim().to_string())
+                    .partition(|id| known.contains(id.as_str()));
+                let new: Vec<String> = found.into_iter().filter(|id| !acked.contains(id)).collect();
+
+                let store = self.store.clone();
+                let to_write = new.clone();
+                tokio::task::spawn_blocking(move || store.ack(&to_write))
+                    .await
+                    .map_err(|e| ConnectorError::Other(format!("Inbox task failed: {}", e)))?
+                    .map_err(ConnectorError::Io)?;
+
+                let data = json!({
+                    "acked": new,
+                    "not_found": missing,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(source: &str, payload: Value) -> InboxEvent {
+        InboxEvent {
+            id: "evt_1".to_string(),
+            received_at: "2025-07-01T09:00:00Z".to_string(),
+            source: source.to_string(),
+            event_type: None,
+            delivery_id: None,
+            verified: None,
+            headers: BTreeMap::new(),
+            payload,
+        }
+    }
+
+    #[test]
+    fn summarizes_common_payloads() {
+        let github = event(
+            "github",
+            json!({
+                "repository": { "full_name": "srv1n/arivu" },
+                "pull_request": { "title": "Add inbox connector" },
+                "sender": { "login": "octocat" }
+            }),
+        );
+        assert_eq!(
+            summarize(&github).as_deref(),
+            Some("srv1n/arivu · Add inbox connector · octocat")
+        );
+
+        let stripe = event(
+            "stripe",
+            json!({ "data": { "object": { "id": "in_1", "amount_due": 1999, "currency": "usd", "customer": "cus_9" } } }),
+        );
+        assert_eq!(
+            summarize(&stripe).as_deref(),
+            Some("in_1 · 19.99 USD · cus_9")
+        );
+
+        let jira = event(
+            "jsm",
+            json!({ "issue": { "key": "HELP-12", "fields": { "summary": "VPN down" } } }),
+        );
+        assert_eq!(summarize(&jira).as_deref(), Some("HELP-12 VPN down"));
+
+        assert_eq!(summarize(&event("custom", json!({ "count": 3 }))), None);
+    }
+}
@@ -0,0 +1,423 @@
+use super::store::{InboxEvent, InboxStore};
+use crate::auth::AuthDetails;
+use crate::error::ConnectorError;
+use hmac::{Hmac, Mac};
+use hyper::header::HeaderMap;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use serde_json::Value;
+use sha2::Sha256;
+use std::collections::{BTreeMap, HashMap};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+type HmacSha256 = Hmac<Sha256>;
+
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+/// Stripe's default replay window for signed timestamps.
+const STRIPE_TOLERANCE_SECS: i64 = 300;
+
+/// Headers worth keeping with an event; signatures and cookies are dropped.
+const KEPT_HEADERS: &[&str] = &[
+    "content-type",
+    "user-agent",
+    "x-github-event",
+    "x-github-delivery",
+    "x-github-hook-id",
+    "x-gitlab-event",
+    "x-shopify-topic",
+    "x-shopify-shop-domain",
+    "x-atlassian-webhook-identifier",
+    "x-event-key",
+    "x-request-id",
+];
+
+/// Source, event type and delivery id recognized from headers and payload.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Detected {
+    pub source: String,
+    pub event_type: Option<String>,
+    pub delivery_id: Option<String>,
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+fn payload_str(payload: &Value, key: &str) -> Option<String> {
+    payload[key].as_str().map(str::to_string)
+}
+
+/// Known senders are recognized by their headers; anything else takes its source
+/// from the first path segment (`POST /deploys` -> `deploys`).
+pub(crate) fn detect(headers: &HeaderMap, path: &str, payload: &Value) -> Detected {
+    let path_source = path
+        .trim_matches('/')
+        .split('/')
+        .next()
+        .filter(|s| !s.is_empty())
+        .map(str::to_ascii_lowercase);
+
+    if let Some(event) = header(headers, "x-github-event") {
+        let event_type = match payload["action"].as_str() {
+            Some(action) => format!("{}.{}", event, action),
+            None => event.to_string(),
+        };
+        return Detected {
+            source: "github".to_string(),
+            event_type: Some(event_type),
+            delivery_id: header(headers, "x-github-delivery").map(str::to_string),
+        };
+    }
+    if headers.contains_key("stripe-signature") {
+        return Detected {
+            source: "stripe".to_string(),
+            event_type: payload_str(payload, "type"),
+            delivery_id: payload_str(payload, "id"),
+        };
+    }
+    if let Some(event) = header(headers, "x-gitlab-event") {
+        return Detected {
+            source: "gitlab".to_string(),
+            event_type: payload_str(payload, "object_kind").or_else(|| Some(event.to_string())),
+            delivery_id: header(headers, "x-gitlab-event-uuid").map(str::to_string),
+        };
+    }
+    if let Some(topic) = header(headers, "x-shopify-topic") {
+        return Detected {
+            source: "shopify".to_string(),
+            event_type: Some(topic.to_string()),
+            delivery_id: header(headers, "x-shopify-webhook-id").map(str::to_string),
+        };
+    }
+    // Jira and Jira Service Management send `webhookEvent` in the body
+    if headers.contains_key("x-atlassian-webhook-identifier") || payload["webhookEvent"].is_string()
+    {
+        return Detected {
+            source: path_source.unwrap_or_else(|| "jira".to_string()),
+            event_type: payload_str(payload, "webhookEvent"),
+            delivery_id: header(headers, "x-atlassian-webhook-identifier").map(str::to_string),
+        };
+    }
+
+    Detected {
+        source: path_source.unwrap_or_else(|| "webhook".to_string()),
+        event_type: ["type", "event", "event_type", "action"]
+            .iter()
+            .find_map(|key| payload_str(payload, key))
+            .or_else(|| header(headers, "x-event-key").map(str::to_string)),
+        delivery_id: ["x-request-id", "x-delivery-id", "x-webhook-id"]
+            .iter()
+            .find_map(|name| header(headers, name))
+            .map(str::to_string),
+    }
+}
+
+fn hmac_matches(secret: &str, message: &[u8], expected_hex: &str) -> bool {
+    let Ok(expected) = hex::decode(expected_hex.trim()) else {
+        return false;
+    };
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(message);
+    mac.verify_slice(&expected).is_ok()
+}
+
+/// Check the sender's signature: GitHub-style `sha256=<hex>` headers (also used by
+/// many custom senders) or Stripe's `t=<ts>,v1=<hex>`.
+pub(crate) fn verify(headers: &HeaderMap, body: &[u8], secret: &str, now: i64) -> bool {
+    if let Some(signature) = header(headers, "stripe-signature") {
+        let mut timestamp = None;
+        let mut candidates = Vec::new();
+        for part in signature.split(',') {
+            match part.trim().split_once('=') {
+                Some(("t", t)) => timestamp = t.parse::<i64>().ok(),
+                Some(("v1", v)) => candidates.push(v),
+                _ => {}
+            }
+        }
+        let Some(timestamp) = timestamp else {
+            return false;
+        };
+        if (now - timestamp).abs() > STRIPE_TOLERANCE_SECS {
+            return false;
+        }
+        let mut signed = format!("{}.", timestamp).into_bytes();
+        signed.extend_from_slice(body);
+        return candidates.iter().any(|c| hmac_matches(secret, &signed, c));
+    }
+    ["x-hub-signature-256", "x-signature-256", "x-signature"]
+        .iter()
+        .find_map(|name| header(headers, name))
+        .map(|sig| hmac_matches(secret, body, sig.trim_start_matches("sha256=")))
+        .unwrap_or(false)
+}
+
+/// Without secrets every delivery is stored unverified. Once any secret is configured,
+/// only signed deliveries from sources that have one are accepted: the source comes from
+/// headers and the path, which the caller controls, so it cannot decide whether to check.
+pub(crate) fn authorize(
+    secrets: &HashMap<String, String>,
+    source: &str,
+    headers: &HeaderMap,
+    body: &[u8],
+    now: i64,
+) -> Result<Option<bool>, (StatusCode, &'static str)> {
+    if secrets.is_empty() {
+        return Ok(None);
+    }
+    match secrets.get(source) {
+        Some(secret) if verify(headers, body, secret, now) => Ok(Some(true)),
+        Some(_) => Err((StatusCode::UNAUTHORIZED, "signature mismatch")),
+        None => Err((StatusCode::FORBIDDEN, "unknown source")),
+    }
+}
+
+struct State {
+    store: InboxStore,
+    /// `secret.<source>` entries from the inbox config
+    secrets: HashMap<String, String>,
+}
+
+fn reply(status: StatusCode, text: &str) -> Response<Body> {
+    let mut response = Response::new(Body::from(format!("{}\n", text)));
+    *response.status_mut() = status;
+    response
+}
+
+async fn handle(state: Arc<State>, request: Request<Body>) -> Result<Response<Body>, Infallible> {
+    match *request.method() {
+        Method::GET | Method::HEAD => return Ok(reply(StatusCode::OK, "arivu inbox")),
+        Method::POST | Method::PUT => {}
+        _ => return Ok(reply(StatusCode::METHOD_NOT_ALLOWED, "POST webhooks here")),
+    }
+
+    let too_large = request
+        .headers()
+        .get(hyper::header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok()?.parse::<usize>().ok())
+        .is_some_and(|len| len > MAX_BODY_BYTES);
+    if too_large {
+        return Ok(reply(StatusCode::PAYLOAD_TOO_LARGE, "payload too large"));
+    }
+    let (parts, body) = request.into_parts();
+    let body = match hyper::body::to_bytes(body).await {
+        Ok(bytes) if bytes.len() <= MAX_BODY_BYTES => bytes,
+        Ok(_) => return Ok(reply(StatusCode::PAYLOAD_TOO_LARGE, "payload too large")),
+        Err(e) => {
+            debug!("inbox: failed to read body: {}", e);
+            return Ok(reply(StatusCode::BAD_REQUEST, "unreadable body"));
+        }
+    };
+
+    let payload: Value = serde_json::from_slice(&body)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&body).into_owned()));
+    let detected = detect(&parts.headers, parts.uri.path(), &payload);
+
+    let now = chrono::Utc::now().timestamp();
+    let verified = match authorize(&state.secrets, &detected.source, &parts.headers, &body, now) {
+        Ok(verified) => verified,
+        Err((status, text)) => {
+            warn!("inbox: rejected {} delivery: {}", detected.source, text);
+            return Ok(reply(status, text));
+        }
+    };
+
+    let store = state.store.clone();
+    let result = tokio::task::spawn_blocking(move || {
+        if let Some(delivery) = &detected.delivery_id {
+            if store.contains_delivery(&detected.source, delivery)? {
+                return Ok::<_, std::io::Error>(None);
+            }
+        }
+        let headers: BTreeMap<String, String> = KEPT_HEADERS
+            .iter()
+            .filter_map(|name| Some((name.to_string(), header(&parts.headers, name)?.to_string())))
+            .collect();
+        let event = InboxEvent {
+            id: InboxEvent::new_id(),
+            received_at: chrono::Utc::now().to_rfc3339(),
+            source: detected.source,
+            event_type: detected.event_type,
+            delivery_id: detected.delivery_id,
+            verified,
+            headers,
+            payload,
+        };
+        store.append(&event)?;
+        Ok(Some(event.id))
+    })
+    .await;
+
+    Ok(match result {
+        Ok(Ok(Some(id))) => reply(StatusCode::ACCEPTED, &id),
+        Ok(Ok(None)) => reply(StatusCode::OK, "duplicate delivery"),
+        Ok(Err(e)) => {
+            warn!("inbox: failed to store event: {}", e);
+            reply(StatusCode::INTERNAL_SERVER_ERROR, "failed to store event")
+        }
+        Err(e) => {
+            warn!("inbox: store task failed: {}", e);
+            reply(StatusCode::INTERNAL_SERVER_ERROR, "failed to store event")
+        }
+    })
+}
+
+/// Receive webhooks on `addr` until the process exits. Deliveries are appended to
+/// the inbox configured in `auth` (`dir`, `secret.<source>`).
+pub async fn serve(addr: SocketAddr, auth: AuthDetails) -> Result<(), ConnectorError> {
+    let secrets = auth
+        .iter()
+        .filter_map(|(k, v)| Some((k.strip_prefix("secret.")?.to_ascii_lowercase(), v.clone())))
+        .collect();
+    let state = Arc::new(State {
+        store: super::store_from(&auth),
+        secrets,
+    });
+
+    let make_service = make_service_fn(move |_| {
+        let state = state.clone();
+        async move { Ok::<_, Infallible>(service_fn(move |request| handle(state.clone(), request))) }
+    });
+    Server::try_bind(&addr)
+        .map_err(|e| ConnectorError::Other(format!("Cannot listen on {}: {}", addr, e)))?
+        .serve(make_service)
+        .await
+        .map_err(|e| ConnectorError::Other(format!("Inbox listener failed: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hyper::header::HeaderValue;
+    use serde_json::json;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        map
+    }
+
+    fn sign(secret: &str, message: &[u8]) -> String {
+        let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(message);
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    #[test]
+    fn detects_known_senders() {
+        let github = detect(
+            &headers(&[
+                ("x-github-event", "pull_request"),
+                ("x-github-delivery", "abc"),
+            ]),
+            "/",
+            &json!({ "action": "opened" }),
+        );
+        assert_eq!(github.source, "github");
+        assert_eq!(github.event_type.as_deref(), Some("pull_request.opened"));
+        assert_eq!(github.delivery_id.as_deref(), Some("abc"));
+
+        let stripe = detect(
+            &headers(&[("stripe-signature", "t=1,v1=00")]),
+            "/stripe",
+            &json!({ "id": "evt_1", "type": "invoice.paid" }),
+        );
+        assert_eq!(stripe.source, "stripe");
+        assert_eq!(stripe.event_type.as_deref(), Some("invoice.paid"));
+
+        let jsm = detect(
+            &HeaderMap::new(),
+            "/jsm",
+            &json!({ "webhookEvent": "jira:issue_created" }),
+        );
+        assert_eq!(jsm.source, "jsm");
+        assert_eq!(jsm.event_type.as_deref(), Some("jira:issue_created"));
+
+        let custom = detect(
+            &HeaderMap::new(),
+            "/Deploys/prod",
+            &json!({ "event": "finished" }),
+        );
+        assert_eq!(custom.source, "deploys");
+        assert_eq!(custom.event_type.as_deref(), Some("finished"));
+    }
+
+    #[test]
+    fn verifies_github_and_stripe_signatures() {
+        let body = br#"{"zen":"Keep it logically awesome."}"#;
+        let good = format!("sha256={}", sign("s3cret", body));
+        assert!(verify(
+            &headers(&[("x-hub-signature-256", &good)]),
+            body,
+            "s3cret",
+            0
+        ));
+        assert!(!verify(
+            &headers(&[("x-hub-signature-256", &good)]),
+            body,
+            "other",
+            0
+        ));
+        assert!(!verify(&HeaderMap::new(), body, "s3cret", 0));
+
+        let signed = [b"1700000000.".as_slice(), body].concat();
+        let stripe = format!("t=1700000000,v1={}", sign("whsec", &signed));
+        let stripe_headers = headers(&[("stripe-signature", &stripe)]);
+        assert!(verify(&stripe_headers, body, "whsec", 1_700_000_100));
+        // Outside the replay window
+        assert!(!verify(&stripe_headers, body, "whsec", 1_700_001_000));
+    }
+
+    #[test]
+    fn secrets_reject_unsigned_and_unknown_sources() {
+        let body = br#"{"ref":"refs/heads/main"}"#;
+        let signed = format!("sha256={}", sign("s3cret", body));
+        let signed = headers(&[("x-hub-signature-256", &signed)]);
+        assert_eq!(
+            authorize(&HashMap::new(), "deploys", &HeaderMap::new(), body, 0),
+            Ok(None)
+        );
+
+        let secrets = HashMap::from([("github".to_string(), "s3cret".to_string())]);
+        assert_eq!(
+            authorize(&secrets, "github", &signed, body, 0),
+            Ok(Some(true))
+        );
+        assert_eq!(
+            authorize(&secrets, "github", &HeaderMap::new(), body, 0),
+            Err((StatusCode::UNAUTHORIZED, "signature mismatch"))
+        );
+        assert_eq!(
+            authorize(&secrets, "deploys", &HeaderMap::new(), body, 0),
+            Err((StatusCode::FORBIDDEN, "unknown source"))
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_deliveries_posted_around_a_configured_secret() {
+        let dir = std::env::temp_dir().join(format!("arivu-inbox-{}", InboxEvent::new_id()));
+        let state = Arc::new(State {
+            store: InboxStore::new(&dir),
+            secrets: HashMap::from([("github".to_string(), "s3cret".to_string())]),
+        });
+        let post = |path: &str| {
+            Request::builder()
+                .method(Method::POST)
+                .uri(path)
+                .body(Body::from(r#"{"event":"finished"}"#))
+                .unwrap()
+        };
+
+        let response = handle(state.clone(), post("/deploys")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let response = handle(state.clone(), post("/github")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert!(state.store.load().unwrap().is_empty());
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
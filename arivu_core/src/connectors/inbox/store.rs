@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+const EVENTS_FILE: &str = "events.jsonl";
+const ACKED_FILE: &str = "acked.txt";
+
+static SEQUENCE: AtomicU32 = AtomicU32::new(0);
+
+/// A webhook delivery as stored in the inbox.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InboxEvent {
+    pub id: String,
+    pub received_at: String,
+    /// `github`, `stripe`, `jira`, … or the path segment it was posted to
+    pub source: String,
+    #[serde(default)]
+    pub event_type: Option<String>,
+    /// Sender's delivery id, used to drop redelivered duplicates
+    #[serde(default)]
+    pub delivery_id: Option<String>,
+    /// `Some(true)` when a configured secret verified the signature
+    #[serde(default)]
+    pub verified: Option<bool>,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// Parsed JSON, or the raw body as a string when it isn't JSON
+    pub payload: Value,
+}
+
+impl InboxEvent {
+    pub fn new_id() -> String {
+        let now = chrono::Utc::now();
+        format!(
+            "evt_{}_{:04}",
+            now.format("%Y%m%d%H%M%S%3f"),
+            SEQUENCE.fetch_add(1, Ordering::Relaxed) % 10_000
+        )
+    }
+}
+
+/// Append-only JSONL queue under `~/.arivu/inbox`, with acknowledged ids kept
+/// in a side file so the listener never rewrites the event log.
+#[derive(Debug, Clone)]
+pub struct InboxStore {
+    dir: PathBuf,
+}
+
+impl InboxStore {
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn default_dir() -> PathBuf {
+        std::env::var("ARIVU_INBOX_DIR")
+            .ok()
+            .filter(|d| !d.trim().is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| {
+                dirs::home_dir()
+                    .unwrap_or_else(|| PathBuf::from("."))
+                    .join(".arivu")
+                    .join("inbox")
+            })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn open_append(&self, name: &str) -> io::Result<File> {
+        fs::create_dir_all(&self.dir)?;
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.dir.join(name))
+    }
+
+    pub fn append(&self, event: &InboxEvent) -> io::Result<()> {
+        let mut line = serde_json::to_string(event)?;
+        line.push('\n');
+        // One write per line so concurrent appends don't interleave
+        self.open_append(EVENTS_FILE)?.write_all(line.as_bytes())
+    }
+
+    /// All events, oldest first. Unparseable lines are skipped.
+    pub fn load(&self) -> io::Result<Vec<InboxEvent>> {
+        let file = match File::open(self.dir.join(EVENTS_FILE)) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut events = Vec::new();
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Ok(event) = serde_json::from_str(&line) {
+                events.push(event);
+            }
+        }
+        Ok(events)
+    }
+
+    pub fn contains_delivery(&self, source: &str, delivery_id: &str) -> io::Result<bool> {
+        Ok(self
+            .load()?
+            .iter()
+            .any(|e| e.source == source && e.delivery_id.as_deref() == Some(delivery_id)))
+    }
+
+    pub fn acked(&self) -> io::Result<HashSet<String>> {
+        match fs::read_to_string(self.dir.join(ACKED_FILE)) {
+            Ok(text) => Ok(text
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .map(str::to_string)
+                .collect()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(HashSet::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn ack(&self, ids: &[String]) -> io::Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+        let mut text = ids.join("\n");
+        text.push('\n');
+        self.open_append(ACKED_FILE)?.write_all(text.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn appends_loads_and_acks() {
+        let dir = std::env::temp_dir().join(format!("arivu-inbox-{}", InboxEvent::new_id()));
+        let store = InboxStore::new(&dir);
+        assert!(store.load().unwrap().is_empty());
+
+        let event = InboxEvent {
+            id: InboxEvent::new_id(),
+            received_at: "2025-07-01T09:00:00Z".to_string(),
+            source: "github".to_string(),
+            event_type: Some("push".to_string()),
+            delivery_id: Some("d-1".to_string()),
+            verified: Some(true),
+            headers: BTreeMap::new(),
+            payload: json!({ "ref": "refs/heads/main" }),
+        };
+        store.append(&event).unwrap();
+        let loaded = store.load().unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].payload["ref"], "refs/heads/main");
+        assert!(store.contains_delivery("github", "d-1").unwrap());
+        assert!(!store.contains_delivery("stripe", "d-1").unwrap());
+
+        store.ack(&[event.id.clone()]).unwrap();
+        assert!(store.acked().unwrap().contains(&event.id));
+        fs::remove_dir_all(dir).ok();
+    }
+}
@@ -7,9 +7,10 @@ use std::sync::Arc;
 use crate::auth::AuthDetails;
 use crate::capabilities::ConnectorConfigSchema;
 use crate::error::ConnectorError;
-use crate::utils::structured_result_with_text;
+use crate::utils::{
+    html_to_markdown_with, structured_result_with_text, truncate_chars, MarkdownOptions,
+};
 use crate::Connector;
-use htmd::HtmlToMarkdown;
 use rmcp::model::*;
 
 mod extractors;
@@ -19,29 +20,13 @@ pub use extractors::{detect_file_type, get_extractor_for_path, Extractor};
 pub use types::*;
 
 fn html_to_markdown(html: &str) -> String {
-    let converter = HtmlToMarkdown::builder()
-        .skip_tags(vec![
-            "script", "style", "nav", "footer", "header", "aside", "img", "a", "href", "src",
-        ])
-        .build();
-    converter.convert(html).unwrap_or_else(|_| html.to_string())
-}
-
-fn truncate_to_chars(s: &str, max_chars: usize) -> (String, bool) {
-    if max_chars == 0 {
-        return (String::new(), !s.is_empty());
-    }
-    if s.chars().count() <= max_chars {
-        return (s.to_string(), false);
-    }
-    let mut end = 0;
-    for (i, (idx, _)) in s.char_indices().enumerate() {
-        if i == max_chars {
-            end = idx;
-            break;
-        }
-    }
-    (s[..end].to_string(), true)
+    let options = MarkdownOptions {
+        links: false,
+        images: false,
+        skip_tags: vec!["nav", "footer", "header", "aside"],
+        ..MarkdownOptions::default()
+    };
+    html_to_markdown_with(html, &options)
 }
 
 /// Expand `~` to the user's home directory
@@ -292,9 +277,7 @@ impl LocalFsConnector {
 
         if let Some(limit) = max_chars {
             let original = text_content.char_count;
-            let (truncated, did_truncate) = truncate_to_chars(&text_content.content, limit);
-            if did_truncate {
-                text_content.content = truncated;
+            if truncate_chars(&mut text_content.content, limit) {
                 text_content.original_char_count = Some(original);
                 text_content.char_count = text_content.content.chars().count();
                 text_content.word_count = text_content.content.split_whitespace().count();
@@ -360,9 +343,7 @@ impl LocalFsConnector {
             .map(|n| n as usize);
         if let Some(limit) = max_chars {
             let original = section_content.content.chars().count();
-            let (truncated, did_truncate) = truncate_to_chars(&section_content.content, limit);
-            if did_truncate {
-                section_content.content = truncated;
+            if truncate_chars(&mut section_content.content, limit) {
                 section_content.original_char_count = Some(original);
                 section_content.word_count = section_content.content.split_whitespace().count();
                 section_content.truncated = true;
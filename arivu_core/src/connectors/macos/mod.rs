@@ -3,10 +3,13 @@
 
 use async_trait::async_trait;
 use rmcp::model::*;
-use serde_json::json;
+use serde_json::{json, Value};
 use std::borrow::Cow;
 use std::process::Stdio;
 use std::sync::Arc;
+use std::time::Duration;
+
+mod shortcuts;
 
 use crate::auth::AuthDetails;
 use crate::capabilities::ConnectorConfigSchema;
@@ -120,6 +123,125 @@ impl MacOsAutomationConnector {
         Ok(None)
     }
 
+    /// Run `program` with `args`, optionally feeding `stdin`, killing it
+    /// after `timeout`.
+    #[cfg(target_os = "macos")]
+    async fn run_command(
+        &self,
+        program: &str,
+        args: &[String],
+        stdin: Option<&str>,
+        timeout: Duration,
+    ) -> Result<(String, String, i32), ConnectorError> {
+        use tokio::io::AsyncWriteExt;
+        use tokio::process::Command;
+
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| ConnectorError::Other(e.to_string()))?;
+        if let Some(mut pipe) = child.stdin.take() {
+            if let Some(input) = stdin {
+                pipe.write_all(input.as_bytes())
+                    .await
+                    .map_err(|e| ConnectorError::Other(e.to_string()))?;
+            }
+        }
+        let output = tokio::time::timeout(timeout, child.wait_with_output())
+            .await
+            .map_err(|_| {
+                ConnectorError::Other(format!(
+                    "{} timed out after {}s",
+                    program,
+                    timeout.as_secs()
+                ))
+            })?
+            .map_err(|e| ConnectorError::Other(e.to_string()))?;
+        Ok((
+            String::from_utf8_lossy(&output.stdout).to_string(),
+            String::from_utf8_lossy(&output.stderr).to_string(),
+            output.status.code().unwrap_or(-1),
+        ))
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    async fn run_command(
+        &self,
+        _program: &str,
+        _args: &[String],
+        _stdin: Option<&str>,
+        _timeout: Duration,
+    ) -> Result<(String, String, i32), ConnectorError> {
+        Err(ConnectorError::Other(
+            "macOS-only tool called on non-macOS".to_string(),
+        ))
+    }
+
+    /// `list_notes`, `get_note` and `create_note`, run with the Apple Notes connector's scripts.
+    #[cfg(target_os = "macos")]
+    async fn call_notes_tool(
+        &self,
+        name: &str,
+        args: &JsonObject,
+    ) -> Result<CallToolResult, ConnectorError> {
+        use crate::connectors::apple_notes;
+
+        const DEFAULT_LIST_LIMIT: usize = 20;
+        const MAX_LIST_LIMIT: usize = 200;
+        const DEFAULT_NOTE_MAX_CHARS: usize = 20_000;
+
+        let str_arg = |key: &str| args.get(key).and_then(|v| v.as_str());
+        let usize_arg = |key: &str| args.get(key).and_then(|v| v.as_u64()).map(|n| n as usize);
+        match name {
+            "list_notes" => {
+                let limit = usize_arg("limit")
+                    .unwrap_or(DEFAULT_LIST_LIMIT)
+                    .clamp(1, MAX_LIST_LIMIT);
+                let notes = apple_notes::list_notes(
+                    str_arg("query"),
+                    str_arg("folder"),
+                    str_arg("account"),
+                    limit,
+                )
+                .await?;
+                structured_result_with_text(&json!({ "count": notes.len(), "notes": notes }), None)
+            }
+            "get_note" => {
+                let id = str_arg("id")
+                    .ok_or_else(|| ConnectorError::InvalidParams("Missing 'id'".to_string()))?;
+                let max_chars = usize_arg("max_chars").unwrap_or(DEFAULT_NOTE_MAX_CHARS);
+                let note = apple_notes::get_note(id, max_chars).await?;
+                structured_result_with_text(&note, None)
+            }
+            "create_note" => {
+                let title = str_arg("title")
+                    .filter(|t| !t.trim().is_empty())
+                    .ok_or_else(|| ConnectorError::InvalidParams("Missing 'title'".to_string()))?;
+                let body = str_arg("body").unwrap_or("");
+                let id =
+                    apple_notes::create_note(title, body, str_arg("folder"), str_arg("account"))
+                        .await?;
+                structured_result_with_text(&json!({ "ok": true, "id": id }), None)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    async fn call_notes_tool(
+        &self,
+        _name: &str,
+        _args: &JsonObject,
+    ) -> Result<CallToolResult, ConnectorError> {
+        Err(ConnectorError::Other(
+            "macOS-only tool called on non-macOS".to_string(),
+        ))
+    }
+
     #[cfg(target_os = "macos")]
     async fn pbpaste(&self) -> Result<String, ConnectorError> {
         use tokio::process::Command;
@@ -173,7 +295,7 @@ impl crate::Connector for MacOsAutomationConnector {
     }
 
     fn description(&self) -> &'static str {
-        "macOS automation connector providing AppleScript/JXA execution and common helpers (notifications, Finder, clipboard, Shortcuts)."
+        "macOS automation connector providing AppleScript/JXA execution and common helpers (notifications, Finder, clipboard, Shortcuts, Apple Notes)."
     }
 
     async fn capabilities(&self) -> ServerCapabilities {
@@ -390,15 +512,20 @@ copy output for manual paste.",
                 name: Cow::Borrowed("run_shortcut"),
                 title: Some("Run Shortcut".to_string()),
                 description: Some(Cow::Borrowed(
-                    "Run an Apple Shortcut by name via the shortcuts CLI (requires explicit \
-user permission). Use when the user has an existing Shortcut workflow. Example: name=\"Daily Brief\".",
+                    "Run an Apple Shortcut by name via the shortcuts CLI and capture its result \
+(requires explicit user permission). Use when the user has an existing Shortcut workflow; \
+list_shortcuts shows the names. Example: name=\"Daily Brief\" input=\"today\".",
                 )),
                 input_schema: Arc::new(
                     json!({
                         "type": "object",
                         "properties": {
-                            "name": {"type": "string"},
-                            "input": {"type": ["string","object","array","number","boolean","null"], "description": "Optional input; passed as text via stdin"}
+                            "name": {"type": "string", "description": "Shortcut name as shown by list_shortcuts"},
+                            "input": {"type": ["string","object","array","number","boolean","null"], "description": "Optional input; strings are passed as text, other values as JSON"},
+                            "input_path": {"type": "string", "description": "Optional file to pass as input instead (e.g. an image)"},
+                            "output_type": {"type": "string", "description": "Optional UTI to coerce the result to (e.g. public.plain-text)"},
+                            "timeout_seconds": {"type": "integer", "minimum": 1, "description": "Kill the shortcut after this long (default 120, max 1800)"},
+                            "max_output_chars": {"type": "integer", "minimum": 1, "description": "Limit for text output (default 20000)"}
                         },
                         "required": ["name"]
                     })
@@ -408,12 +535,118 @@ user permission). Use when the user has an existing Shortcut workflow. Example:
                 ),
                 output_schema: Some(Arc::new(json!({
                     "type": "object",
-                    "properties": {"ok": {"type":"boolean"}, "stdout": {"type":"string"}, "stderr": {"type":"string"}, "exit_code": {"type":"integer"}},
+                    "properties": {
+                        "ok": {"type":"boolean"},
+                        "output": {"type":"string"},
+                        "output_base64": {"type":"string"},
+                        "output_bytes": {"type":"integer"},
+                        "output_truncated": {"type":"boolean"},
+                        "stdout": {"type":"string"},
+                        "stderr": {"type":"string"},
+                        "exit_code": {"type":"integer"}
+                    },
                     "required": ["ok","exit_code"]
                 }).as_object().expect("Schema object").clone())),
                 annotations: None,
                 icons: None,
             },
+            Tool {
+                name: Cow::Borrowed("list_shortcuts"),
+                title: Some("List Shortcuts".to_string()),
+                description: Some(Cow::Borrowed(
+                    "List the user's Shortcuts by name, optionally within one folder. Example: \
+folder=\"Work\".",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "folder": {"type": "string", "description": "Only shortcuts in this Shortcuts folder"}
+                        }
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("list_notes"),
+                title: Some("List Notes".to_string()),
+                description: Some(Cow::Borrowed(
+                    "List Apple Notes in Notes.app order, optionally by folder or text match \
+(requires explicit user permission). Example: folder=\"Work\" query=\"standup\" limit=10.",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "folder": {"type": "string", "description": "Folder name"},
+                            "account": {"type": "string", "description": "Account name (e.g. iCloud)"},
+                            "query": {"type": "string", "description": "Only notes whose title or text contains this"},
+                            "limit": {"type": "integer", "minimum": 1, "description": "Max notes (default 20, max 200)"}
+                        }
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_note"),
+                title: Some("Get Note".to_string()),
+                description: Some(Cow::Borrowed(
+                    "Read one Apple Note as plain text (requires explicit user permission). \
+Example: id=\"x-coredata://.../ICNote/p123\".",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "id": {"type": "string", "description": "Note id from list_notes"},
+                            "max_chars": {"type": "integer", "minimum": 1, "description": "Limit for the note text (default 20000)"}
+                        },
+                        "required": ["id"]
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("create_note"),
+                title: Some("Create Note".to_string()),
+                description: Some(Cow::Borrowed(
+                    "Create an Apple Note from a title and plain-text body (requires explicit user \
+permission). Example: title=\"Groceries\" body=\"milk\\neggs\" folder=\"Personal\".",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "title": {"type": "string"},
+                            "body": {"type": "string", "description": "Plain text; lines become paragraphs"},
+                            "folder": {"type": "string", "description": "Folder name (default: the account's default folder)"},
+                            "account": {"type": "string", "description": "Account name (default: the default account)"}
+                        },
+                        "required": ["title"]
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
         ];
 
         Ok(ListToolsResult {
@@ -565,55 +798,98 @@ user permission). Use when the user has an existing Shortcut workflow. Example:
                 structured_result_with_text(&payload, None)
             }
             "run_shortcut" => {
-                #[cfg(target_os = "macos")]
-                {
-                    use tokio::io::AsyncWriteExt;
-                    use tokio::process::Command;
-                    let name = args.get("name").and_then(|v| v.as_str()).ok_or_else(|| {
-                        ConnectorError::InvalidParams("Missing 'name'".to_string())
-                    })?;
-                    let input_opt = args.get("input").cloned();
-                    let mut cmd = Command::new("/usr/bin/shortcuts");
-                    cmd.arg("run").arg(name);
-                    // The CLI accepts "-i -" to read stdin; we stringify JSON if provided.
-                    let mut child = cmd
-                        .stdin(Stdio::piped())
-                        .stdout(Stdio::piped())
-                        .stderr(Stdio::piped())
-                        .spawn()
-                        .map_err(|e| ConnectorError::Other(e.to_string()))?;
-                    if let (Some(mut stdin), Some(input)) = (child.stdin.take(), input_opt) {
-                        let text = if input.is_string() {
-                            input.as_str().unwrap_or("").to_string()
-                        } else {
-                            serde_json::to_string(&input)
-                                .map_err(|e| ConnectorError::Other(e.to_string()))?
-                        };
-                        stdin
-                            .write_all(text.as_bytes())
-                            .await
-                            .map_err(|e| ConnectorError::Other(e.to_string()))?;
+                let name = args
+                    .get("name")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| ConnectorError::InvalidParams("Missing 'name'".to_string()))?;
+                let timeout = args
+                    .get("timeout_seconds")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(shortcuts::DEFAULT_TIMEOUT_SECS)
+                    .clamp(1, shortcuts::MAX_TIMEOUT_SECS);
+                let max_chars = args
+                    .get("max_output_chars")
+                    .and_then(|v| v.as_u64())
+                    .map(|n| n as usize)
+                    .unwrap_or(shortcuts::DEFAULT_MAX_OUTPUT_CHARS);
+
+                let mut cli_args = vec!["run".to_string(), name.to_string()];
+                let mut input_file = None;
+                if let Some(path) = args.get("input_path").and_then(|v| v.as_str()) {
+                    cli_args.extend(["--input-path".to_string(), path.to_string()]);
+                } else if let Some(input) = args.get("input").filter(|v| !v.is_null()) {
+                    let path = shortcuts::temp_path("input.txt");
+                    std::fs::write(&path, shortcuts::input_text(input))?;
+                    cli_args.extend([
+                        "--input-path".to_string(),
+                        path.to_string_lossy().into_owned(),
+                    ]);
+                    input_file = Some(path);
+                }
+                let output_file = shortcuts::temp_path("output");
+                cli_args.extend([
+                    "--output-path".to_string(),
+                    output_file.to_string_lossy().into_owned(),
+                ]);
+                if let Some(uti) = args.get("output_type").and_then(|v| v.as_str()) {
+                    cli_args.extend(["--output-type".to_string(), uti.to_string()]);
+                }
+
+                let result = self
+                    .run_command(
+                        shortcuts::SHORTCUTS_CLI,
+                        &cli_args,
+                        None,
+                        Duration::from_secs(timeout),
+                    )
+                    .await;
+                if let Some(path) = input_file {
+                    let _ = std::fs::remove_file(path);
+                }
+                let output = shortcuts::take_file(&output_file);
+                let (stdout, stderr, code) = result?;
+
+                let mut payload = json!({
+                    "ok": code == 0,
+                    "name": name,
+                    "stdout": stdout,
+                    "stderr": stderr,
+                    "exit_code": code
+                });
+                if !output.is_empty() {
+                    if let (Some(payload), Value::Object(fields)) = (
+                        payload.as_object_mut(),
+                        shortcuts::output_value(&output, max_chars),
+                    ) {
+                        payload.extend(fields);
                     }
-                    let out = child
-                        .wait_with_output()
-                        .await
-                        .map_err(|e| ConnectorError::Other(e.to_string()))?;
-                    let code = out.status.code().unwrap_or(-1);
-                    let payload = json!({
-                        "ok": code==0,
-                        "stdout": String::from_utf8_lossy(&out.stdout),
-                        "stderr": String::from_utf8_lossy(&out.stderr),
-                        "exit_code": code
-                    });
-                    return structured_result_with_text(&payload, None);
                 }
-                #[cfg(not(target_os = "macos"))]
-                {
-                    return Err(ConnectorError::Other(
-                        "Shortcuts CLI not available on non-macOS".to_string(),
-                    ));
+                structured_result_with_text(&payload, None)
+            }
+            "list_shortcuts" => {
+                let mut cli_args = vec!["list".to_string()];
+                if let Some(folder) = args.get("folder").and_then(|v| v.as_str()) {
+                    cli_args.extend(["--folder-name".to_string(), folder.to_string()]);
+                }
+                let (stdout, stderr, code) = self
+                    .run_command(
+                        shortcuts::SHORTCUTS_CLI,
+                        &cli_args,
+                        None,
+                        Duration::from_secs(30),
+                    )
+                    .await?;
+                if code != 0 {
+                    return Err(ConnectorError::Other(format!(
+                        "shortcuts list failed: {}",
+                        stderr.trim()
+                    )));
                 }
+                let names = shortcuts::parse_list(&stdout);
+                let payload = json!({"count": names.len(), "shortcuts": names});
+                structured_result_with_text(&payload, None)
             }
+            "list_notes" | "get_note" | "create_note" => self.call_notes_tool(name, &args).await,
             _ => Err(ConnectorError::ToolNotFound),
         }
     }
@@ -0,0 +1,100 @@
+// src/connectors/macos/shortcuts.rs
+//
+// Running Shortcuts through the `shortcuts` CLI. Input and output go through
+// temporary files (`--input-path` / `--output-path`): the CLI doesn't read
+// stdin, and a file also captures non-text results such as images.
+
+use base64::Engine;
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub(super) const SHORTCUTS_CLI: &str = "/usr/bin/shortcuts";
+pub(super) const DEFAULT_TIMEOUT_SECS: u64 = 120;
+pub(super) const MAX_TIMEOUT_SECS: u64 = 1_800;
+pub(super) const DEFAULT_MAX_OUTPUT_CHARS: usize = 20_000;
+/// Binary results larger than this are reported but not returned.
+const MAX_BINARY_OUTPUT_BYTES: usize = 5 * 1024 * 1024;
+
+/// A unique path in the temp directory.
+pub(super) fn temp_path(label: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    std::env::temp_dir().join(format!(
+        "arivu-shortcut-{}-{}-{}",
+        std::process::id(),
+        nanos,
+        label
+    ))
+}
+
+/// Text handed to the shortcut: strings as-is, anything else as JSON.
+pub(super) fn input_text(input: &Value) -> String {
+    match input {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Shortcut names from `shortcuts list`.
+pub(super) fn parse_list(stdout: &str) -> Vec<String> {
+    stdout
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// The shortcut's result: text when it is UTF-8 (truncated to `max_chars`),
+/// otherwise base64.
+pub(super) fn output_value(bytes: &[u8], max_chars: usize) -> Value {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => {
+            let truncated = text.chars().count() > max_chars;
+            let text: String = text.chars().take(max_chars).collect();
+            json!({ "output": text, "output_truncated": truncated })
+        }
+        Err(_) if bytes.len() > MAX_BINARY_OUTPUT_BYTES => json!({
+            "output_bytes": bytes.len(),
+            "output_truncated": true,
+        }),
+        Err(_) => json!({
+            "output_base64": base64::engine::general_purpose::STANDARD.encode(bytes),
+            "output_bytes": bytes.len(),
+            "output_truncated": false,
+        }),
+    }
+}
+
+/// Read and delete a temp file; a missing file means no output.
+pub(super) fn take_file(path: &Path) -> Vec<u8> {
+    let bytes = std::fs::read(path).unwrap_or_default();
+    let _ = std::fs::remove_file(path);
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_input_and_output() {
+        assert_eq!(input_text(&json!("hello")), "hello");
+        assert_eq!(input_text(&json!({ "a": 1 })), r#"{"a":1}"#);
+        assert_eq!(
+            parse_list("Daily Brief\n\n  Resize Image \n"),
+            vec!["Daily Brief", "Resize Image"]
+        );
+
+        let text = output_value("héllo world".as_bytes(), 5);
+        assert_eq!(text["output"], "héllo");
+        assert_eq!(text["output_truncated"], true);
+
+        let binary = output_value(&[0x89, b'P', b'N', b'G', 0xff], 100);
+        assert_eq!(binary["output_base64"], "iVBOR/8=");
+        assert_eq!(binary["output_bytes"], 5);
+    }
+}
@@ -0,0 +1,666 @@
+use crate::capabilities::ConnectorConfigSchema;
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDate, Utc};
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+const YAHOO_BASE: &str = "https://query1.finance.yahoo.com";
+const STOOQ_BASE: &str = "https://stooq.com";
+// Yahoo rejects obviously non-browser user agents on the chart endpoints.
+const USER_AGENT: &str =
+    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko)";
+
+#[derive(Debug, Deserialize)]
+struct QuoteArgs {
+    symbols: String,
+    #[serde(default)]
+    source: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HistoryArgs {
+    symbol: String,
+    #[serde(default)]
+    start_date: Option<String>,
+    #[serde(default)]
+    end_date: Option<String>,
+    #[serde(default)]
+    range: Option<String>,
+    #[serde(default)]
+    interval: Option<String>,
+    #[serde(default)]
+    source: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchArgs {
+    query: String,
+    #[serde(default)]
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Source {
+    Yahoo,
+    Stooq,
+}
+
+impl Source {
+    fn parse(value: Option<&str>) -> Result<Self, ConnectorError> {
+        match value.unwrap_or("yahoo") {
+            "yahoo" => Ok(Source::Yahoo),
+            "stooq" => Ok(Source::Stooq),
+            other => Err(ConnectorError::InvalidParams(format!(
+                "Unknown source '{}': expected 'yahoo' or 'stooq'",
+                other
+            ))),
+        }
+    }
+}
+
+pub struct MarketDataConnector {
+    client: Client,
+}
+
+impl MarketDataConnector {
+    pub async fn new(_auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+
+        Ok(Self { client })
+    }
+
+    async fn get(&self, url: &str, params: &[(&str, String)]) -> Result<String, ConnectorError> {
+        let response = self
+            .client
+            .get(url)
+            .query(params)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(ConnectorError::ResourceNotFound);
+        }
+        if !response.status().is_success() {
+            return Err(ConnectorError::Other(format!(
+                "Market data request failed with status: {}",
+                response.status()
+            )));
+        }
+
+        response.text().await.map_err(ConnectorError::HttpRequest)
+    }
+
+    async fn yahoo_chart(
+        &self,
+        symbol: &str,
+        params: &[(&str, String)],
+    ) -> Result<Value, ConnectorError> {
+        let url = format!(
+            "{}/v8/finance/chart/{}",
+            YAHOO_BASE,
+            urlencoding::encode(symbol)
+        );
+        let body = self.get(&url, params).await?;
+        let value: Value = serde_json::from_str(&body)?;
+
+        if let Some(err) = value["chart"]["error"].as_object() {
+            let message = err
+                .get("description")
+                .and_then(|d| d.as_str())
+                .unwrap_or("unknown error");
+            return Err(ConnectorError::Other(format!(
+                "Yahoo Finance error for {}: {}",
+                symbol, message
+            )));
+        }
+
+        value["chart"]["result"]
+            .as_array()
+            .and_then(|r| r.first())
+            .cloned()
+            .ok_or(ConnectorError::ResourceNotFound)
+    }
+
+    async fn yahoo_quote(&self, symbol: &str) -> Result<Value, ConnectorError> {
+        let chart = self
+            .yahoo_chart(
+                symbol,
+                &[("range", "1d".to_string()), ("interval", "1d".to_string())],
+            )
+            .await?;
+        Ok(format_yahoo_quote(&chart["meta"]))
+    }
+
+    async fn stooq_quote(&self, symbol: &str) -> Result<Value, ConnectorError> {
+        let url = format!("{}/q/l/", STOOQ_BASE);
+        let params = [
+            ("s", stooq_symbol(symbol)),
+            ("f", "sd2t2ohlcv".to_string()),
+            ("h", String::new()),
+            ("e", "csv".to_string()),
+        ];
+        let body = self.get(&url, &params).await?;
+        let rows = parse_csv(&body);
+        let row = rows.first().ok_or(ConnectorError::ResourceNotFound)?;
+        if row.get("Close").map(|c| c == "N/D").unwrap_or(true) {
+            return Err(ConnectorError::ResourceNotFound);
+        }
+
+        Ok(json!({
+            "symbol": symbol.to_uppercase(),
+            "price": row.get("Close").and_then(|v| v.parse::<f64>().ok()),
+            "open": row.get("Open").and_then(|v| v.parse::<f64>().ok()),
+            "day_high": row.get("High").and_then(|v| v.parse::<f64>().ok()),
+            "day_low": row.get("Low").and_then(|v| v.parse::<f64>().ok()),
+            "volume": row.get("Volume").and_then(|v| v.parse::<f64>().ok()),
+            "date": row.get("Date"),
+            "time": row.get("Time"),
+            "source": "stooq",
+        }))
+    }
+
+    async fn quote(&self, symbol: &str, source: Source) -> Result<Value, ConnectorError> {
+        match source {
+            Source::Stooq => self.stooq_quote(symbol).await,
+            // Yahoo changes its unauthenticated endpoints often; Stooq is the fallback.
+            Source::Yahoo => match self.yahoo_quote(symbol).await {
+                Ok(q) => Ok(q),
+                Err(_) => self.stooq_quote(symbol).await,
+            },
+        }
+    }
+
+    async fn yahoo_history(
+        &self,
+        symbol: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+        range: Option<&str>,
+        interval: &str,
+    ) -> Result<Vec<Value>, ConnectorError> {
+        let mut params = vec![
+            ("interval", interval.to_string()),
+            ("events", "div,splits".to_string()),
+        ];
+        match (start, end) {
+            (None, None) => params.push(("range", range.unwrap_or("1mo").to_string())),
+            (start, end) => {
+                let end = end.unwrap_or_else(|| Utc::now().date_naive());
+                let start = start.unwrap_or(end - Duration::days(30));
+                params.push(("period1", unix_timestamp(start).to_string()));
+                // period2 is exclusive; include the end date itself.
+                params.push((
+                    "period2",
+                    unix_timestamp(end + Duration::days(1)).to_string(),
+                ));
+            }
+        }
+
+        let chart = self.yahoo_chart(symbol, &params).await?;
+        Ok(parse_yahoo_candles(&chart))
+    }
+
+    async fn stooq_history(
+        &self,
+        symbol: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+        interval: &str,
+    ) -> Result<Vec<Value>, ConnectorError> {
+        let end = end.unwrap_or_else(|| Utc::now().date_naive());
+        let start = start.unwrap_or(end - Duration::days(30));
+        let stooq_interval = match interval {
+            "1wk" => "w",
+            "1mo" => "m",
+            _ => "d",
+        };
+
+        let url = format!("{}/q/d/l/", STOOQ_BASE);
+        let params = [
+            ("s", stooq_symbol(symbol)),
+            ("d1", start.format("%Y%m%d").to_string()),
+            ("d2", end.format("%Y%m%d").to_string()),
+            ("i", stooq_interval.to_string()),
+        ];
+        let body = self.get(&url, &params).await?;
+
+        Ok(parse_csv(&body)
+            .iter()
+            .map(|row| {
+                json!({
+                    "date": row.get("Date"),
+                    "open": row.get("Open").and_then(|v| v.parse::<f64>().ok()),
+                    "high": row.get("High").and_then(|v| v.parse::<f64>().ok()),
+                    "low": row.get("Low").and_then(|v| v.parse::<f64>().ok()),
+                    "close": row.get("Close").and_then(|v| v.parse::<f64>().ok()),
+                    "volume": row.get("Volume").and_then(|v| v.parse::<f64>().ok()),
+                })
+            })
+            .collect())
+    }
+}
+
+fn unix_timestamp(date: NaiveDate) -> i64 {
+    date.and_hms_opt(0, 0, 0)
+        .map(|dt| dt.and_utc().timestamp())
+        .unwrap_or_default()
+}
+
+fn parse_date(value: Option<&str>, field: &str) -> Result<Option<NaiveDate>, ConnectorError> {
+    value
+        .map(|v| {
+            NaiveDate::parse_from_str(v, "%Y-%m-%d").map_err(|_| {
+                ConnectorError::InvalidParams(format!("{} must be YYYY-MM-DD, got '{}'", field, v))
+            })
+        })
+        .transpose()
+}
+
+/// Stooq expects lower-case symbols with a market suffix; bare tickers are assumed to be US listings.
+fn stooq_symbol(symbol: &str) -> String {
+    let lower = symbol.trim().to_lowercase();
+    if lower.contains('.') || lower.starts_with('^') {
+        lower
+    } else {
+        format!("{}.us", lower)
+    }
+}
+
+/// Minimal CSV parser for Stooq responses (no quoted fields).
+fn parse_csv(body: &str) -> Vec<std::collections::HashMap<String, String>> {
+    let mut lines = body.lines().filter(|l| !l.trim().is_empty());
+    let Some(header) = lines.next() else {
+        return vec![];
+    };
+    let headers: Vec<&str> = header.split(',').map(str::trim).collect();
+    if headers.len() < 2 {
+        // "No data" and similar plain-text responses
+        return vec![];
+    }
+
+    lines
+        .map(|line| {
+            headers
+                .iter()
+                .zip(line.split(','))
+                .map(|(h, v)| (h.to_string(), v.trim().to_string()))
+                .collect()
+        })
+        .collect()
+}
+
+fn format_yahoo_quote(meta: &Value) -> Value {
+    let price = meta["regularMarketPrice"].as_f64();
+    let previous = meta["chartPreviousClose"]
+        .as_f64()
+        .or_else(|| meta["previousClose"].as_f64());
+    let change = price.zip(previous).map(|(p, c)| p - c);
+    let change_percent = change
+        .zip(previous)
+        .filter(|(_, c)| *c != 0.0)
+        .map(|(d, c)| d / c * 100.0);
+    let market_time = meta["regularMarketTime"]
+        .as_i64()
+        .and_then(|t| chrono::DateTime::from_timestamp(t, 0))
+        .map(|dt| dt.to_rfc3339());
+
+    json!({
+        "symbol": meta["symbol"],
+        "name": meta.get("longName").or_else(|| meta.get("shortName")),
+        "price": price,
+        "previous_close": previous,
+        "change": change,
+        "change_percent": change_percent,
+        "currency": meta["currency"],
+        "exchange": meta.get("fullExchangeName").or_else(|| meta.get("exchangeName")),
+        "instrument_type": meta["instrumentType"],
+        "day_high": meta["regularMarketDayHigh"],
+        "day_low": meta["regularMarketDayLow"],
+        "volume": meta["regularMarketVolume"],
+        "fifty_two_week_high": meta["fiftyTwoWeekHigh"],
+        "fifty_two_week_low": meta["fiftyTwoWeekLow"],
+        "market_time": market_time,
+        "source": "yahoo",
+    })
+}
+
+fn parse_yahoo_candles(chart: &Value) -> Vec<Value> {
+    let empty = vec![];
+    let timestamps = chart["timestamp"].as_array().unwrap_or(&empty);
+    let quote = &chart["indicators"]["quote"][0];
+    let adjclose = &chart["indicators"]["adjclose"][0]["adjclose"];
+
+    timestamps
+        .iter()
+        .enumerate()
+        .filter_map(|(i, ts)| {
+            let close = quote["close"][i].as_f64()?;
+            let date = ts
+                .as_i64()
+                .and_then(|t| chrono::DateTime::from_timestamp(t, 0))
+                .map(|dt| dt.format("%Y-%m-%d").to_string());
+            Some(json!({
+                "date": date,
+                "open": quote["open"][i].as_f64(),
+                "high": quote["high"][i].as_f64(),
+                "low": quote["low"][i].as_f64(),
+                "close": close,
+                "adj_close": adjclose[i].as_f64(),
+                "volume": quote["volume"][i].as_f64(),
+            }))
+        })
+        .collect()
+}
+
+#[async_trait]
+impl Connector for MarketDataConnector {
+    fn name(&self) -> &'static str {
+        "market-data"
+    }
+
+    fn description(&self) -> &'static str {
+        "Stock, ETF, index and FX market data: quotes, historical OHLCV, and ticker search."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        Ok(AuthDetails::new())
+    }
+
+    async fn set_auth_details(&mut self, _details: AuthDetails) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema { fields: vec![] }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Use `search_symbols` to find a ticker, `get_quote` for the latest price, and \
+`get_history` for daily/weekly/monthly OHLCV. Data is delayed and for research only."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            tool(
+                "get_quote",
+                "Latest (delayed) quote for one or more tickers. Example: symbols=\"AAPL,MSFT,^GSPC\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "symbols": { "type": "string", "description": "Comma-separated tickers (Yahoo style, e.g. AAPL, BTC-USD, EURUSD=X)" },
+                        "source": { "type": "string", "enum": ["yahoo", "stooq"], "description": "Data source (default: yahoo, falls back to stooq)" }
+                    },
+                    "required": ["symbols"]
+                }),
+            ),
+            tool(
+                "get_history",
+                "Historical OHLCV candles. Use start_date/end_date (YYYY-MM-DD) or range. \
+Example: symbol=\"AAPL\" start_date=\"2024-01-01\" end_date=\"2024-03-31\" interval=\"1d\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "symbol": { "type": "string", "description": "Ticker symbol" },
+                        "start_date": { "type": "string", "description": "Start date (YYYY-MM-DD)" },
+                        "end_date": { "type": "string", "description": "End date inclusive (YYYY-MM-DD, default: today)" },
+                        "range": { "type": "string", "enum": ["5d", "1mo", "3mo", "6mo", "1y", "2y", "5y", "10y", "ytd", "max"], "description": "Relative range when no dates are given (default: 1mo)" },
+                        "interval": { "type": "string", "enum": ["1d", "1wk", "1mo"], "description": "Candle interval (default: 1d)" },
+                        "source": { "type": "string", "enum": ["yahoo", "stooq"], "description": "Data source (default: yahoo)" }
+                    },
+                    "required": ["symbol"]
+                }),
+            ),
+            tool(
+                "search_symbols",
+                "Find ticker symbols by company or fund name. Example: query=\"nvidia\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Company, fund or index name" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "get_quote" => {
+                let args: QuoteArgs = parse_args(request.arguments)?;
+                let source = Source::parse(args.source.as_deref())?;
+
+                let mut quotes = Vec::new();
+                let mut errors = Vec::new();
+                for symbol in args
+                    .symbols
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                {
+                    match self.quote(symbol, source).await {
+                        Ok(q) => quotes.push(q),
+                        Err(e) => errors.push(json!({ "symbol": symbol, "error": e.to_string() })),
+                    }
+                }
+
+                if quotes.is_empty() && !errors.is_empty() {
+                    return Err(ConnectorError::Other(format!(
+                        "No quotes found for '{}'",
+                        args.symbols
+                    )));
+                }
+
+                let data = json!({
+                    "quotes": quotes,
+                    "errors": errors,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_history" => {
+                let args: HistoryArgs = parse_args(request.arguments)?;
+                let source = Source::parse(args.source.as_deref())?;
+                let start = parse_date(args.start_date.as_deref(), "start_date")?;
+                let end = parse_date(args.end_date.as_deref(), "end_date")?;
+                let interval = args.interval.as_deref().unwrap_or("1d");
+                if !matches!(interval, "1d" | "1wk" | "1mo") {
+                    return Err(ConnectorError::InvalidParams(
+                        "interval must be one of 1d, 1wk, 1mo".to_string(),
+                    ));
+                }
+
+                let (candles, used_source) = match source {
+                    Source::Stooq => (
+                        self.stooq_history(&args.symbol, start, end, interval)
+                            .await?,
+                        "stooq",
+                    ),
+                    Source::Yahoo => (
+                        self.yahoo_history(
+                            &args.symbol,
+                            start,
+                            end,
+                            args.range.as_deref(),
+                            interval,
+                        )
+                        .await?,
+                        "yahoo",
+                    ),
+                };
+
+                let data = json!({
+                    "symbol": args.symbol,
+                    "interval": interval,
+                    "source": used_source,
+                    "count": candles.len(),
+                    "candles": candles,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "search_symbols" => {
+                let args: SearchArgs = parse_args(request.arguments)?;
+                let limit = args.limit.unwrap_or(10).clamp(1, 50);
+                let url = format!("{}/v1/finance/search", YAHOO_BASE);
+                let params = [
+                    ("q", args.query.clone()),
+                    ("quotesCount", limit.to_string()),
+                    ("newsCount", "0".to_string()),
+                ];
+                let body = self.get(&url, &params).await?;
+                let value: Value = serde_json::from_str(&body)?;
+
+                let results: Vec<Value> = value["quotes"]
+                    .as_array()
+                    .map(|arr| {
+                        arr.iter()
+                            .filter(|q| q.get("symbol").is_some())
+                            .map(|q| {
+                                json!({
+                                    "symbol": q["symbol"],
+                                    "name": q.get("longname").or_else(|| q.get("shortname")),
+                                    "type": q["quoteType"],
+                                    "exchange": q.get("exchDisp").or_else(|| q.get("exchange")),
+                                })
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let data = json!({
+                    "query": args.query,
+                    "count": results.len(),
+                    "results": results,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_stooq_csv() {
+        let body =
+            "Date,Open,High,Low,Close,Volume\n2024-01-02,187.15,188.44,183.885,185.64,82488674\n";
+        let rows = parse_csv(body);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0]["Close"], "185.64");
+        assert!(parse_csv("No data").is_empty());
+    }
+
+    #[test]
+    fn maps_stooq_symbols() {
+        assert_eq!(stooq_symbol("AAPL"), "aapl.us");
+        assert_eq!(stooq_symbol("vod.uk"), "vod.uk");
+        assert_eq!(stooq_symbol("^SPX"), "^spx");
+    }
+
+    #[test]
+    fn parses_yahoo_candles_skipping_gaps() {
+        let chart = json!({
+            "timestamp": [1704205800, 1704292200],
+            "indicators": {
+                "quote": [{
+                    "open": [187.15, null],
+                    "high": [188.44, null],
+                    "low": [183.89, null],
+                    "close": [185.64, null],
+                    "volume": [82488674, null]
+                }],
+                "adjclose": [{ "adjclose": [184.9, null] }]
+            }
+        });
+
+        let candles = parse_yahoo_candles(&chart);
+        assert_eq!(candles.len(), 1);
+        assert_eq!(candles[0]["date"], "2024-01-02");
+        assert_eq!(candles[0]["close"], 185.64);
+    }
+}
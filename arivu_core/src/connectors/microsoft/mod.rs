@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use rmcp::model::*;
-use serde_json::json;
+use serde_json::{json, Value};
 use std::borrow::Cow;
 use std::sync::Arc;
 
@@ -24,6 +24,12 @@ use graph_rs_sdk::http::traits::AsyncIterator;
 #[allow(unused_imports)]
 use graph_rs_sdk::http::NextSession;
 
+mod onenote;
+mod teams;
+
+const GRAPH_BASE: &str = "https://graph.microsoft.com/v1.0";
+const DEFAULT_PAGE_MAX_CHARS: usize = 50_000;
+
 #[derive(Clone, Default)]
 pub struct GraphConnector {
     auth: AuthDetails,
@@ -52,6 +58,114 @@ impl GraphConnector {
     }
 }
 
+/// GET a Graph path (or an absolute @odata.nextLink) and return the body.
+async fn graph_get_text(
+    http: &reqwest::Client,
+    token: &str,
+    path_or_url: &str,
+) -> Result<String, ConnectorError> {
+    let url = if path_or_url.starts_with("https://") {
+        path_or_url.to_string()
+    } else {
+        format!("{}{}", GRAPH_BASE, path_or_url)
+    };
+    let resp = http
+        .get(&url)
+        .bearer_auth(token)
+        .send()
+        .await
+        .map_err(ConnectorError::HttpRequest)?;
+    graph_body(resp).await
+}
+
+async fn graph_get(
+    http: &reqwest::Client,
+    token: &str,
+    path_or_url: &str,
+) -> Result<Value, ConnectorError> {
+    let body = graph_get_text(http, token, path_or_url).await?;
+    Ok(serde_json::from_str(&body)?)
+}
+
+/// The response body, or Graph's error message mapped to a ConnectorError.
+async fn graph_body(resp: reqwest::Response) -> Result<String, ConnectorError> {
+    let status = resp.status();
+    let body = resp.text().await.map_err(ConnectorError::HttpRequest)?;
+    if status.is_success() {
+        return Ok(body);
+    }
+    let message = serde_json::from_str::<Value>(&body)
+        .ok()
+        .and_then(|v| {
+            v.pointer("/error/message")
+                .and_then(|m| m.as_str())
+                .map(str::to_string)
+        })
+        .unwrap_or(body);
+    Err(match status.as_u16() {
+        401 => ConnectorError::Authentication(format!("Microsoft Graph: {}", message)),
+        403 => ConnectorError::Authentication(format!(
+            "Microsoft Graph denied access (is the scope granted?): {}",
+            message
+        )),
+        404 => ConnectorError::ResourceNotFound,
+        _ => ConnectorError::Other(format!("graph error {}: {}", status, message)),
+    })
+}
+
+/// Follow @odata.nextLink from `path` (or `start_link`) until `desired` items;
+/// `concise` projects items and drops those `project` rejects.
+async fn graph_list(
+    token: &str,
+    path: &str,
+    start_link: Option<String>,
+    desired: usize,
+    concise: bool,
+    project: fn(&Value) -> Option<Value>,
+) -> Result<(Vec<Value>, Option<String>), ConnectorError> {
+    let http = reqwest::Client::new();
+    let collected = collect_paginated_with_cursor(
+        desired,
+        100,
+        start_link,
+        |cursor, _remaining| {
+            let http = http.clone();
+            async move {
+                let url = cursor.unwrap_or_else(|| path.to_string());
+                let v = graph_get(&http, token, &url).await?;
+                let items = v
+                    .get("value")
+                    .and_then(|vv| vv.as_array())
+                    .cloned()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|item| if concise { project(&item) } else { Some(item) })
+                    .collect::<Vec<_>>();
+                Ok::<_, ConnectorError>(Page {
+                    items,
+                    next_cursor: v
+                        .get("@odata.nextLink")
+                        .and_then(|s| s.as_str())
+                        .map(str::to_string),
+                })
+            }
+        },
+        |item: &Value| item.get("id").and_then(|x| x.as_str()).map(str::to_string),
+    )
+    .await?;
+    Ok((collected.items, collected.next_cursor))
+}
+
+fn required_str<'a>(
+    args: &'a serde_json::Map<String, Value>,
+    key: &str,
+) -> Result<&'a str, ConnectorError> {
+    args.get(key)
+        .and_then(|v| v.as_str())
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| ConnectorError::InvalidParams(format!("{} is required", key)))
+}
+
 #[async_trait]
 impl Connector for GraphConnector {
     fn name(&self) -> &'static str {
@@ -59,7 +173,7 @@ impl Connector for GraphConnector {
     }
 
     fn description(&self) -> &'static str {
-        "Microsoft 365 via Microsoft Graph: Outlook Mail/Calendar, SharePoint/Drive, and Teams (scaffold)."
+        "Microsoft 365 via Microsoft Graph: Outlook Mail/Calendar, Teams chats and channels, and OneNote."
     }
 
     async fn capabilities(&self) -> ServerCapabilities {
@@ -163,6 +277,16 @@ impl Connector for GraphConnector {
             Tool { name: Cow::Borrowed("create_draft"), title: None, description: Some(Cow::Borrowed("Create draft email (requires explicit user permission).")), input_schema: Arc::new(json!({"type":"object","properties":{"to":{"type":"array","items":{"type":"string"}},"subject":{"type":"string"},"body_text":{"type":"string"}},"required":["to","subject","body_text"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
             Tool { name: Cow::Borrowed("upload_attachment_large"), title: None, description: Some(Cow::Borrowed("Upload attachment to draft (requires explicit user permission).")), input_schema: Arc::new(json!({"type":"object","properties":{"message_id":{"type":"string"},"filename":{"type":"string"},"mime_type":{"type":"string"},"data_base64":{"type":"string"}},"required":["message_id","filename","mime_type","data_base64"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
             Tool { name: Cow::Borrowed("send_draft"), title: None, description: Some(Cow::Borrowed("Send draft email (requires explicit user permission).")), input_schema: Arc::new(json!({"type":"object","properties":{"message_id":{"type":"string"}},"required":["message_id"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("list_teams"), title: None, description: Some(Cow::Borrowed("List the Teams teams you belong to (requires explicit user permission; scope Team.ReadBasic.All).")), input_schema: Arc::new(json!({"type":"object","properties":{"limit":{"type":"integer","minimum":1,"maximum":1000,"description":"Default 50"}}}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("list_channels"), title: None, description: Some(Cow::Borrowed("List a team's channels (requires explicit user permission; scope Channel.ReadBasic.All).")), input_schema: Arc::new(json!({"type":"object","properties":{"team_id":{"type":"string"}},"required":["team_id"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("list_channel_messages"), title: None, description: Some(Cow::Borrowed("List top-level messages in a Teams channel, with bodies rendered to text (requires explicit user permission; scope ChannelMessage.Read.All).")), input_schema: Arc::new(json!({"type":"object","properties":{"team_id":{"type":"string"},"channel_id":{"type":"string"},"limit":{"type":"integer","minimum":1,"maximum":1000,"description":"Default 25"},"include_replies":{"type":"boolean","description":"Include each thread's replies"},"next_link":{"type":"string","description":"Cursor from a previous response (nextLink)"},"response_format":{"type":"string","enum":["concise","detailed"],"description":"Default concise."}},"required":["team_id","channel_id"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("list_chats"), title: None, description: Some(Cow::Borrowed("List your Teams chats (1:1, group and meeting) with members, most recently updated first (requires explicit user permission; scope Chat.Read).")), input_schema: Arc::new(json!({"type":"object","properties":{"limit":{"type":"integer","minimum":1,"maximum":1000,"description":"Default 25"},"next_link":{"type":"string"}}}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("list_chat_messages"), title: None, description: Some(Cow::Borrowed("List recent messages in a Teams chat, newest first, with bodies rendered to text (requires explicit user permission; scope Chat.Read).")), input_schema: Arc::new(json!({"type":"object","properties":{"chat_id":{"type":"string"},"limit":{"type":"integer","minimum":1,"maximum":1000,"description":"Default 25"},"next_link":{"type":"string"},"response_format":{"type":"string","enum":["concise","detailed"],"description":"Default concise."}},"required":["chat_id"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("search_teams_messages"), title: None, description: Some(Cow::Borrowed("Search Teams chat and channel messages with Microsoft Search (KQL, e.g. 'rollout from:ana') (requires explicit user permission; scopes Chat.Read, ChannelMessage.Read.All).")), input_schema: Arc::new(json!({"type":"object","properties":{"query":{"type":"string"},"limit":{"type":"integer","minimum":1,"maximum":200,"description":"Default 25"}},"required":["query"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("list_notebooks"), title: None, description: Some(Cow::Borrowed("List OneNote notebooks (requires explicit user permission; scope Notes.Read).")), input_schema: Arc::new(json!({"type":"object","properties":{"limit":{"type":"integer","minimum":1,"maximum":1000,"description":"Default 50"}}}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("list_sections"), title: None, description: Some(Cow::Borrowed("List OneNote sections, in one notebook or across all (requires explicit user permission; scope Notes.Read).")), input_schema: Arc::new(json!({"type":"object","properties":{"notebook_id":{"type":"string","description":"Omit for every section"},"limit":{"type":"integer","minimum":1,"maximum":1000,"description":"Default 50"}}}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("list_pages"), title: None, description: Some(Cow::Borrowed("List OneNote pages, in one section or across all, most recently modified first (requires explicit user permission; scope Notes.Read).")), input_schema: Arc::new(json!({"type":"object","properties":{"section_id":{"type":"string","description":"Omit for every page"},"limit":{"type":"integer","minimum":1,"maximum":1000,"description":"Default 25"},"next_link":{"type":"string"}}}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
+            Tool { name: Cow::Borrowed("get_page"), title: None, description: Some(Cow::Borrowed("Get a OneNote page rendered to text, with image and file placeholders (requires explicit user permission; scope Notes.Read).")), input_schema: Arc::new(json!({"type":"object","properties":{"page_id":{"type":"string"},"max_chars":{"type":"integer","minimum":1,"description":"Default 50000"}},"required":["page_id"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
             Tool { name: Cow::Borrowed("upload_attachment_large_from_path"), title: None, description: Some(Cow::Borrowed("Upload attachment from file path (requires explicit user permission).")), input_schema: Arc::new(json!({"type":"object","properties":{"message_id":{"type":"string"},"file_path":{"type":"string"},"filename":{"type":"string"},"mime_type":{"type":"string"}},"required":["message_id","file_path"]}).as_object().expect("Schema object").clone()), output_schema: None, annotations: None, icons: None },
             Tool {
                 name: Cow::Borrowed("auth_start"),
@@ -861,6 +985,195 @@ impl Connector for GraphConnector {
                     .map_err(|e| ConnectorError::Other(format!("graph send draft error: {}", e)))?;
                 structured_result_with_text(&json!({"status":"sent"}), None)
             }
+            "list_teams" | "list_notebooks" | "list_sections" | "list_chats" => {
+                let limit = args
+                    .get("limit")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(if request.name == "list_chats" { 25 } else { 50 })
+                    .clamp(1, 1_000) as usize;
+                let start_link = args
+                    .get("next_link")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string);
+                let (key, path, project): (&str, String, fn(&Value) -> Option<Value>) =
+                    match request.name.as_ref() {
+                        "list_teams" => ("teams", "/me/joinedTeams".to_string(), teams::concise_team),
+                        "list_chats" => (
+                            "chats",
+                            "/me/chats?$expand=members&$orderby=lastMessagePreview/createdDateTime desc&$top=50".to_string(),
+                            teams::concise_chat,
+                        ),
+                        "list_notebooks" => (
+                            "notebooks",
+                            "/me/onenote/notebooks?$orderby=lastModifiedDateTime desc".to_string(),
+                            onenote::concise_notebook,
+                        ),
+                        _ => (
+                            "sections",
+                            match args.get("notebook_id").and_then(|v| v.as_str()) {
+                                Some(id) => format!(
+                                    "/me/onenote/notebooks/{}/sections",
+                                    urlencoding::encode(id)
+                                ),
+                                None => "/me/onenote/sections?$expand=parentNotebook($select=displayName)".to_string(),
+                            },
+                            onenote::concise_section,
+                        ),
+                    };
+                let token = self.access_token().await?;
+                let (items, next_link) =
+                    graph_list(&token, &path, start_link, limit, true, project).await?;
+                let v = json!({ key: items, "nextLink": next_link });
+                structured_result_with_text(&v, None)
+            }
+            "list_channels" => {
+                let team_id = required_str(&args, "team_id")?;
+                let token = self.access_token().await?;
+                let path = format!("/teams/{}/channels", urlencoding::encode(team_id));
+                let (channels, _) =
+                    graph_list(&token, &path, None, 1_000, true, teams::concise_channel).await?;
+                structured_result_with_text(
+                    &json!({ "team_id": team_id, "channels": channels }),
+                    None,
+                )
+            }
+            "list_channel_messages" | "list_chat_messages" => {
+                let limit = args
+                    .get("limit")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(25)
+                    .clamp(1, 1_000) as usize;
+                let start_link = args
+                    .get("next_link")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string);
+                let concise = !matches!(
+                    args.get("response_format").and_then(|v| v.as_str()),
+                    Some("detailed")
+                );
+                // Both endpoints cap $top at 50
+                let path = if request.name == "list_chat_messages" {
+                    let chat_id = required_str(&args, "chat_id")?;
+                    format!(
+                        "/chats/{}/messages?$top=50&$orderby=createdDateTime desc",
+                        urlencoding::encode(chat_id)
+                    )
+                } else {
+                    let team_id = required_str(&args, "team_id")?;
+                    let channel_id = required_str(&args, "channel_id")?;
+                    let replies = args
+                        .get("include_replies")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(false);
+                    format!(
+                        "/teams/{}/channels/{}/messages?$top=50{}",
+                        urlencoding::encode(team_id),
+                        urlencoding::encode(channel_id),
+                        if replies { "&$expand=replies" } else { "" }
+                    )
+                };
+                let token = self.access_token().await?;
+                let (messages, next_link) = graph_list(
+                    &token,
+                    &path,
+                    start_link,
+                    limit,
+                    concise,
+                    teams::concise_message,
+                )
+                .await?;
+                let v = json!({ "messages": messages, "nextLink": next_link });
+                structured_result_with_text(&v, None)
+            }
+            "search_teams_messages" => {
+                let query = required_str(&args, "query")?;
+                let limit = args
+                    .get("limit")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(25)
+                    .clamp(1, 200) as usize;
+                let token = self.access_token().await?;
+                let http = reqwest::Client::new();
+                let mut hits: Vec<Value> = Vec::new();
+                loop {
+                    // Microsoft Search pages chatMessage hits 25 at a time
+                    let size = (limit - hits.len()).min(25);
+                    let resp = http
+                        .post(format!("{}/search/query", GRAPH_BASE))
+                        .bearer_auth(&token)
+                        .json(&teams::search_request(query, hits.len(), size))
+                        .send()
+                        .await
+                        .map_err(ConnectorError::HttpRequest)?;
+                    let body: Value = serde_json::from_str(&graph_body(resp).await?)?;
+                    let (page, more) = teams::search_hits(&body);
+                    let done = page.is_empty() || !more;
+                    hits.extend(page);
+                    if done || hits.len() >= limit {
+                        break;
+                    }
+                }
+                hits.truncate(limit);
+                structured_result_with_text(&json!({ "query": query, "hits": hits }), None)
+            }
+            "list_pages" => {
+                let limit = args
+                    .get("limit")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(25)
+                    .clamp(1, 1_000) as usize;
+                let start_link = args
+                    .get("next_link")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string);
+                let path = match args.get("section_id").and_then(|v| v.as_str()) {
+                    Some(id) => format!(
+                        "/me/onenote/sections/{}/pages?$top=100&$orderby=lastModifiedDateTime desc",
+                        urlencoding::encode(id)
+                    ),
+                    None => "/me/onenote/pages?$top=100&$orderby=lastModifiedDateTime desc&$expand=parentSection($select=displayName)".to_string(),
+                };
+                let token = self.access_token().await?;
+                let (pages, next_link) = graph_list(
+                    &token,
+                    &path,
+                    start_link,
+                    limit,
+                    true,
+                    onenote::concise_page,
+                )
+                .await?;
+                structured_result_with_text(&json!({ "pages": pages, "nextLink": next_link }), None)
+            }
+            "get_page" => {
+                let page_id = required_str(&args, "page_id")?;
+                let max_chars = args
+                    .get("max_chars")
+                    .and_then(|v| v.as_u64())
+                    .map(|n| n as usize)
+                    .unwrap_or(DEFAULT_PAGE_MAX_CHARS);
+                let token = self.access_token().await?;
+                let http = reqwest::Client::new();
+                let path = format!("/me/onenote/pages/{}", urlencoding::encode(page_id));
+                let meta = graph_get(&http, &token, &path).await?;
+                let html = graph_get_text(&http, &token, &format!("{}/content", path)).await?;
+                let (title, mut text) = onenote::page_text(&html);
+                let truncated = match text.char_indices().nth(max_chars) {
+                    Some((idx, _)) => {
+                        text.truncate(idx);
+                        true
+                    }
+                    None => false,
+                };
+                let mut v =
+                    onenote::concise_page(&meta).unwrap_or_else(|| json!({ "id": page_id }));
+                if v.get("title").map_or(true, |t| t.is_null()) {
+                    v["title"] = json!(title);
+                }
+                v["text"] = json!(text);
+                v["truncated"] = json!(truncated);
+                structured_result_with_text(&v, None)
+            }
             "auth_start" => {
                 let tenant = args.get("tenant_id").and_then(|v| v.as_str()).unwrap_or("");
                 let client_id = args.get("client_id").and_then(|v| v.as_str()).ok_or(
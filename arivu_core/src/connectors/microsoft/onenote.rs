@@ -0,0 +1,103 @@
+// src/connectors/microsoft/onenote.rs
+//
+// Concise shapes for OneNote notebooks, sections and pages, and page HTML
+// rendered to text. Page content is XHTML with the title in <head>; images
+// and attached files are kept as placeholders so the text reads in order.
+
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use serde_json::{json, Value};
+
+static RE_TITLE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<title>(.*?)</title>").unwrap());
+static RE_HEAD: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<head>.*?</head>").unwrap());
+static RE_IMG: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<img\b[^>]*>").unwrap());
+static RE_OBJECT: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<object\b[^>]*>").unwrap());
+static RE_ATTR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)\b(alt|data-attachment)\s*=\s*"([^"]*)""#).unwrap());
+
+fn web_url(v: &Value) -> Option<&Value> {
+    v.pointer("/links/oneNoteWebUrl/href")
+}
+
+pub(super) fn concise_notebook(n: &Value) -> Option<Value> {
+    Some(json!({
+        "id": n.get("id")?,
+        "displayName": n.get("displayName"),
+        "lastModifiedDateTime": n.get("lastModifiedDateTime"),
+        "webUrl": web_url(n),
+    }))
+}
+
+pub(super) fn concise_section(s: &Value) -> Option<Value> {
+    Some(json!({
+        "id": s.get("id")?,
+        "displayName": s.get("displayName"),
+        "notebook": s.pointer("/parentNotebook/displayName"),
+        "lastModifiedDateTime": s.get("lastModifiedDateTime"),
+    }))
+}
+
+pub(super) fn concise_page(p: &Value) -> Option<Value> {
+    Some(json!({
+        "id": p.get("id")?,
+        "title": p.get("title"),
+        "section": p.pointer("/parentSection/displayName"),
+        "createdDateTime": p.get("createdDateTime"),
+        "lastModifiedDateTime": p.get("lastModifiedDateTime"),
+        "webUrl": web_url(p),
+    }))
+}
+
+fn attr(tag: &str, name: &str) -> Option<String> {
+    RE_ATTR
+        .captures_iter(tag)
+        .find(|c| c[1].eq_ignore_ascii_case(name))
+        .map(|c| c[2].trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// Title and plain text of a page's HTML content.
+pub(super) fn page_text(html: &str) -> (Option<String>, String) {
+    let title = RE_TITLE
+        .captures(html)
+        .map(|c| crate::utils::html_to_text(&c[1]))
+        .filter(|t| !t.is_empty());
+    let body = RE_HEAD.replace(html, "");
+    let body = RE_IMG.replace_all(&body, |c: &Captures| match attr(&c[0], "alt") {
+        Some(alt) => format!("[image: {}]", alt),
+        None => "[image]".to_string(),
+    });
+    let body = RE_OBJECT.replace_all(&body, |c: &Captures| match attr(&c[0], "data-attachment") {
+        Some(name) => format!("[attachment: {}]", name),
+        None => String::new(),
+    });
+    (title, crate::utils::html_to_text(&body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_page_html() {
+        let html = r#"<html lang="en-US"><head><title>Q3 planning</title>
+            <meta name="created" content="2024-05-01T09:00:00.0000000" /></head>
+            <body data-absolute-enabled="true"><div><h1>Goals</h1><p>Ship the <b>sync</b> rewrite</p>
+            <img alt="Roadmap diagram" src="https://graph.microsoft.com/v1.0/resources/1/$value" />
+            <object data-attachment="budget.xlsx" type="application/vnd.ms-excel" data="https://graph.microsoft.com/v1.0/resources/2/$value" />
+            </div></body></html>"#;
+        let (title, text) = page_text(html);
+        assert_eq!(title.as_deref(), Some("Q3 planning"));
+        assert_eq!(
+            text,
+            "Goals\n\nShip the sync rewrite\n\n[image: Roadmap diagram]\n[attachment: budget.xlsx]"
+        );
+
+        let page = json!({ "id": "1-abc", "title": "Q3 planning",
+            "parentSection": { "displayName": "Work" },
+            "links": { "oneNoteWebUrl": { "href": "https://onenote.example/p" } } });
+        let concise = concise_page(&page).unwrap();
+        assert_eq!(concise["section"], "Work");
+        assert_eq!(concise["webUrl"], "https://onenote.example/p");
+    }
+}
@@ -0,0 +1,223 @@
+// src/connectors/microsoft/teams.rs
+//
+// Concise shapes for Teams teams, channels, chats and messages. Message
+// bodies arrive as Teams HTML (mentions, cards, emoji images) and are
+// rendered to text; system events and deleted messages are dropped.
+
+use serde_json::{json, Value};
+
+fn str_at<'a>(v: &'a Value, pointer: &str) -> Option<&'a str> {
+    v.pointer(pointer).and_then(|s| s.as_str())
+}
+
+/// Display name of whoever sent a message: a user, an app, or (in search
+/// hits) an email address.
+fn sender(from: &Value) -> Option<String> {
+    str_at(from, "/user/displayName")
+        .or_else(|| str_at(from, "/application/displayName"))
+        .or_else(|| str_at(from, "/emailAddress/name"))
+        .or_else(|| str_at(from, "/emailAddress/address"))
+        .map(str::to_string)
+}
+
+/// The message body as plain text.
+pub(super) fn body_text(body: &Value) -> String {
+    let content = str_at(body, "/content").unwrap_or_default();
+    match str_at(body, "/contentType") {
+        Some(t) if t.eq_ignore_ascii_case("html") => crate::utils::html_to_text(content),
+        _ => content.trim().to_string(),
+    }
+}
+
+pub(super) fn concise_team(t: &Value) -> Option<Value> {
+    Some(json!({
+        "id": t.get("id")?,
+        "displayName": t.get("displayName"),
+        "description": t.get("description"),
+    }))
+}
+
+pub(super) fn concise_channel(c: &Value) -> Option<Value> {
+    Some(json!({
+        "id": c.get("id")?,
+        "displayName": c.get("displayName"),
+        "description": c.get("description"),
+        "membershipType": c.get("membershipType"),
+        "webUrl": c.get("webUrl"),
+    }))
+}
+
+pub(super) fn concise_chat(c: &Value) -> Option<Value> {
+    let members: Vec<&str> = c
+        .get("members")
+        .and_then(|m| m.as_array())
+        .map(|m| {
+            m.iter()
+                .filter_map(|member| str_at(member, "/displayName"))
+                .collect()
+        })
+        .unwrap_or_default();
+    Some(json!({
+        "id": c.get("id")?,
+        "topic": c.get("topic"),
+        "chatType": c.get("chatType"),
+        "members": members,
+        "lastUpdatedDateTime": c.get("lastUpdatedDateTime"),
+        "webUrl": c.get("webUrl"),
+    }))
+}
+
+/// A user message as {id, from, createdDateTime, text, ...}; None for
+/// system events and deleted messages.
+pub(super) fn concise_message(m: &Value) -> Option<Value> {
+    if str_at(m, "/messageType").is_some_and(|t| t != "message")
+        || m.get("deletedDateTime").is_some_and(|d| !d.is_null())
+    {
+        return None;
+    }
+    let mut out = json!({
+        "id": m.get("id")?,
+        "from": m.get("from").and_then(sender),
+        "createdDateTime": m.get("createdDateTime"),
+        "text": m.get("body").map(body_text).unwrap_or_default(),
+    });
+    if let Some(subject) = str_at(m, "/subject").filter(|s| !s.is_empty()) {
+        out["subject"] = json!(subject);
+    }
+    if let Some(reply_to) = m.get("replyToId").filter(|r| !r.is_null()) {
+        out["replyToId"] = reply_to.clone();
+    }
+    if let Some(url) = m.get("webUrl").filter(|u| !u.is_null()) {
+        out["webUrl"] = url.clone();
+    }
+    let attachments: Vec<Value> = m
+        .get("attachments")
+        .and_then(|a| a.as_array())
+        .map(|a| {
+            a.iter()
+                .filter(|att| att.get("name").is_some_and(|n| !n.is_null()))
+                .map(|att| {
+                    json!({
+                        "name": att.get("name"),
+                        "contentType": att.get("contentType"),
+                        "contentUrl": att.get("contentUrl"),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    if !attachments.is_empty() {
+        out["attachments"] = json!(attachments);
+    }
+    let replies: Vec<Value> = m
+        .get("replies")
+        .and_then(|r| r.as_array())
+        .map(|r| r.iter().filter_map(concise_message).collect())
+        .unwrap_or_default();
+    if !replies.is_empty() {
+        out["replies"] = json!(replies);
+    }
+    Some(out)
+}
+
+/// Request body for a Microsoft Search query over Teams messages.
+pub(super) fn search_request(query: &str, from: usize, size: usize) -> Value {
+    json!({
+        "requests": [{
+            "entityTypes": ["chatMessage"],
+            "query": { "queryString": query },
+            "from": from,
+            "size": size,
+        }]
+    })
+}
+
+/// Hits of a search response, and whether more are available.
+pub(super) fn search_hits(response: &Value) -> (Vec<Value>, bool) {
+    let mut hits = Vec::new();
+    let mut more = false;
+    let containers = response
+        .pointer("/value")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v.get("hitsContainers").and_then(|c| c.as_array()))
+        .flatten();
+    for container in containers {
+        more |= container
+            .get("moreResultsAvailable")
+            .and_then(|m| m.as_bool())
+            .unwrap_or(false);
+        for hit in container
+            .get("hits")
+            .and_then(|h| h.as_array())
+            .into_iter()
+            .flatten()
+        {
+            let resource = hit.get("resource").cloned().unwrap_or(Value::Null);
+            // Highlights come back as <c0>…</c0> markup
+            let summary = str_at(hit, "/summary").map(crate::utils::html_to_text);
+            hits.push(json!({
+                "id": hit.get("hitId"),
+                "summary": summary,
+                "from": resource.get("from").and_then(sender),
+                "createdDateTime": resource.get("createdDateTime"),
+                "chatId": resource.get("chatId"),
+                "teamId": resource.pointer("/channelIdentity/teamId"),
+                "channelId": resource.pointer("/channelIdentity/channelId"),
+                "webUrl": resource.get("webUrl"),
+            }));
+        }
+    }
+    (hits, more)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_messages_and_search_hits() {
+        let message = json!({
+            "id": "1700000000000",
+            "messageType": "message",
+            "createdDateTime": "2024-05-01T09:00:00Z",
+            "deletedDateTime": null,
+            "replyToId": null,
+            "from": { "user": { "displayName": "Ana Lima" }, "application": null },
+            "body": { "contentType": "html", "content": "<p>Hi <at id=\"0\">Raj</at>, see <a href=\"https://example.com/spec\">the spec</a></p>" },
+            "attachments": [],
+            "replies": [
+                { "id": "1700000000001", "messageType": "message",
+                  "from": { "application": { "displayName": "Build Bot" } },
+                  "body": { "contentType": "text", "content": "Green " } },
+                { "id": "1700000000002", "messageType": "systemEventMessage", "body": { "content": "" } }
+            ]
+        });
+        let concise = concise_message(&message).unwrap();
+        assert_eq!(concise["from"], "Ana Lima");
+        assert_eq!(
+            concise["text"],
+            "Hi Raj, see [the spec](https://example.com/spec)"
+        );
+        assert!(concise.get("replyToId").is_none());
+        assert_eq!(concise["replies"].as_array().unwrap().len(), 1);
+        assert_eq!(concise["replies"][0]["from"], "Build Bot");
+        assert_eq!(concise["replies"][0]["text"], "Green");
+
+        let deleted = json!({ "id": "9", "messageType": "message", "deletedDateTime": "2024-05-02T00:00:00Z" });
+        assert!(concise_message(&deleted).is_none());
+
+        let response = json!({ "value": [{ "hitsContainers": [{
+            "moreResultsAvailable": true,
+            "hits": [{ "hitId": "AAMk", "summary": "the <c0>rollout</c0> plan",
+                "resource": { "from": { "emailAddress": { "name": "Ana Lima" } },
+                    "chatId": "19:abc@thread.v2", "createdDateTime": "2024-05-01T09:00:00Z" } }]
+        }]}]});
+        let (hits, more) = search_hits(&response);
+        assert!(more);
+        assert_eq!(hits[0]["summary"], "the rollout plan");
+        assert_eq!(hits[0]["from"], "Ana Lima");
+        assert_eq!(hits[0]["chatId"], "19:abc@thread.v2");
+    }
+}
@@ -5,26 +5,80 @@ pub mod federated;
 #[cfg(feature = "localfs")]
 pub mod localfs;
 
+#[cfg(feature = "airtable")]
+pub mod airtable;
 #[cfg(feature = "arxiv")]
 pub mod arxiv;
 #[cfg(feature = "atlassian")]
 pub mod atlassian;
 #[cfg(feature = "biorxiv")]
 pub mod biorxiv;
+#[cfg(feature = "box")]
+pub mod box_com;
+#[cfg(feature = "browser-history")]
+pub mod browser_history;
+#[cfg(feature = "coingecko")]
+pub mod coingecko;
+#[cfg(feature = "confluence")]
+pub mod confluence;
+#[cfg(feature = "crates-io")]
+pub mod crates_io;
+#[cfg(feature = "crossref")]
+pub mod crossref;
+#[cfg(feature = "dblp")]
+pub mod dblp;
 #[cfg(feature = "discord")]
 pub mod discord;
+#[cfg(feature = "dockerhub")]
+pub mod dockerhub;
 #[cfg(feature = "github")]
 pub mod github;
 #[cfg(feature = "google-scholar")]
 pub mod google_scholar;
+#[cfg(feature = "graphql")]
+pub mod graphql;
 #[cfg(feature = "hackernews")]
 pub mod hackernews;
+#[cfg(feature = "hubspot")]
+pub mod hubspot;
 #[cfg(feature = "imap")]
 pub mod imap;
+#[cfg(feature = "inbox")]
+pub mod inbox;
 #[cfg(feature = "macos-automation")]
 pub mod macos;
+#[cfg(feature = "market-data")]
+pub mod market_data;
+#[cfg(feature = "newsletters")]
+pub mod newsletters;
+#[cfg(feature = "npm")]
+pub mod npm;
+#[cfg(feature = "openlibrary")]
+pub mod openlibrary;
+#[cfg(feature = "pagerduty")]
+pub mod pagerduty;
+#[cfg(feature = "paper-library")]
+pub mod paper_library;
+#[cfg(feature = "podcasts")]
+pub mod podcasts;
+#[cfg(feature = "pypi")]
+pub mod pypi;
+#[cfg(feature = "s3")]
+pub mod s3;
+#[cfg(feature = "salesforce")]
+pub mod salesforce;
 #[cfg(all(target_os = "macos", feature = "macos-spotlight"))]
 pub mod spotlight;
+#[cfg(feature = "sql")]
+pub mod sql;
+#[cfg(all(target_os = "linux", feature = "tracker"))]
+pub mod tracker;
+#[cfg(feature = "twitch")]
+pub mod twitch;
+#[cfg(feature = "vimeo")]
+pub mod vimeo;
+#[cfg(feature = "zoom")]
+pub mod zoom;
 // EXPERIMENTAL - NOT READY: HealthKit data store not available on macOS
 // See: arivu_core/src/connectors/apple_health/NOT_READY.md
 // #[cfg(all(target_os = "macos", feature = "apple-health"))]
@@ -39,6 +93,8 @@ pub mod rss;
 pub mod scihub;
 #[cfg(feature = "semantic-scholar")]
 pub mod semantic_scholar;
+#[cfg(feature = "sentry")]
+pub mod sentry;
 #[cfg(feature = "slack")]
 pub mod slack;
 #[cfg(any(feature = "web", feature = "web-lite"))]
@@ -53,6 +109,10 @@ pub mod youtube;
 // LLM provider web search
 #[cfg(feature = "anthropic-search")]
 pub mod anthropic_search;
+#[cfg(feature = "brave-search")]
+pub mod brave_search;
+#[cfg(feature = "ddg-search")]
+pub mod ddg_search;
 #[cfg(feature = "exa-search")]
 pub mod exa_search;
 #[cfg(feature = "firecrawl-search")]
@@ -61,6 +121,8 @@ pub mod firecrawl_search;
 pub mod gemini_search;
 #[cfg(feature = "openai-search")]
 pub mod openai_search;
+#[cfg(feature = "openapi")]
+pub mod openapi;
 #[cfg(feature = "parallel-search")]
 pub mod parallel_search;
 #[cfg(feature = "perplexity-search")]
@@ -95,18 +157,30 @@ pub mod microsoft;
         feature = "apple-notes",
         feature = "apple-messages",
         feature = "apple-reminders",
-        feature = "apple-contacts"
+        feature = "apple-contacts",
+        feature = "apple-calendar",
+        feature = "macos-automation"
     )
 ))]
 pub mod apple_common;
 
+#[cfg(all(target_os = "macos", feature = "apple-calendar"))]
+pub mod apple_calendar;
 #[cfg(all(target_os = "macos", feature = "apple-contacts"))]
 pub mod apple_contacts;
 #[cfg(all(target_os = "macos", feature = "apple-mail"))]
 pub mod apple_mail;
 #[cfg(all(target_os = "macos", feature = "apple-messages"))]
 pub mod apple_messages;
-#[cfg(all(target_os = "macos", feature = "apple-notes"))]
+// The macOS automation connector's Notes tools use its scripts
+#[cfg(all(
+    target_os = "macos",
+    any(feature = "apple-notes", feature = "macos-automation")
+))]
 pub mod apple_notes;
 #[cfg(all(target_os = "macos", feature = "apple-reminders"))]
 pub mod apple_reminders;
+
+// Windows desktop apps - native integrations via PowerShell COM automation
+#[cfg(all(target_os = "windows", feature = "outlook-desktop"))]
+pub mod outlook_desktop;
@@ -0,0 +1,774 @@
+use async_trait::async_trait;
+use mailparse::{parse_headers, parse_mail, MailHeader, MailHeaderMap, ParsedMail};
+use rmcp::model::*;
+use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+use crate::auth::AuthDetails;
+use crate::auth_store::{AuthStore, FileAuthStore};
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::connectors::imap::{map_imap_error, ImapConnector};
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, strip_multiple_newlines, structured_result_with_text, tool};
+use crate::Connector;
+
+/// Newest messages inspected per call; newsletters are picked from this window.
+const MAX_SCAN: usize = 500;
+
+/// Sending platforms, matched against From, List-Id, List-Unsubscribe and X-Mailer.
+const PLATFORMS: &[(&str, &str)] = &[
+    ("substack", "Substack"),
+    ("beehiiv", "beehiiv"),
+    ("buttondown", "Buttondown"),
+    ("convertkit", "Kit"),
+    ("ck.page", "Kit"),
+    ("mailchimp", "Mailchimp"),
+    ("mcsv.net", "Mailchimp"),
+    ("list-manage.com", "Mailchimp"),
+    ("ghost.io", "Ghost"),
+    ("mailerlite", "MailerLite"),
+    ("medium.com", "Medium"),
+    ("campaign-monitor", "Campaign Monitor"),
+    ("createsend", "Campaign Monitor"),
+    ("sendfox", "SendFox"),
+    ("revue", "Revue"),
+];
+
+/// Content containers used by common newsletter platforms, tried in order.
+const CONTENT_SELECTORS: &[&str] = &[
+    ".body.markup",
+    ".post",
+    "#content-blocks",
+    ".gh-content",
+    ".post-content",
+    "article",
+    "[role=article]",
+    ".email-body",
+];
+
+const WEB_VERSION_LABELS: &[&str] = &[
+    "view in browser",
+    "view online",
+    "view this email in your browser",
+    "view this post on the web",
+    "read online",
+    "read in browser",
+    "open in browser",
+    "web version",
+];
+
+const FOOTER_MARKERS: &[&str] = &[
+    "unsubscribe",
+    "manage your subscription",
+    "update your preferences",
+    "you are receiving this",
+    "you're receiving this",
+    "why did i get this",
+];
+
+#[derive(Debug, Clone, Default)]
+struct Settings {
+    mailbox: Option<String>,
+    /// Addresses or domains always treated as newsletters
+    senders: Vec<String>,
+    /// Addresses or domains never treated as newsletters
+    exclude: Vec<String>,
+}
+
+fn split_list(value: Option<&String>) -> Vec<String> {
+    value
+        .map(|v| {
+            v.split([',', '\n'])
+                .map(|s| s.trim().to_ascii_lowercase())
+                .filter(|s| !s.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `a@news.example.com` matches `a@news.example.com`, `news.example.com` and `example.com`.
+fn sender_matches(address: &str, patterns: &[String]) -> bool {
+    let address = address.to_ascii_lowercase();
+    let domain = address.rsplit('@').next().unwrap_or_default();
+    patterns
+        .iter()
+        .any(|p| address == *p || domain == p.as_str() || domain.ends_with(&format!(".{}", p)))
+}
+
+/// Split `Name <addr@host>` into (name, address).
+fn split_from(from: &str) -> (Option<String>, String) {
+    match (from.rfind('<'), from.rfind('>')) {
+        (Some(start), Some(end)) if end > start => {
+            let name = from[..start].trim().trim_matches('"').trim();
+            (
+                (!name.is_empty()).then(|| name.to_string()),
+                from[start + 1..end].trim().to_ascii_lowercase(),
+            )
+        }
+        _ => (None, from.trim().to_ascii_lowercase()),
+    }
+}
+
+fn detect_platform(haystack: &str) -> Option<&'static str> {
+    let haystack = haystack.to_ascii_lowercase();
+    PLATFORMS
+        .iter()
+        .find(|(needle, _)| haystack.contains(needle))
+        .map(|(_, name)| *name)
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct Issue {
+    uid: u32,
+    publication: String,
+    sender: String,
+    subject: Option<String>,
+    date: Option<String>,
+    platform: Option<&'static str>,
+    #[serde(skip)]
+    timestamp: i64,
+}
+
+/// Classify a message from its header block; `None` when it isn't a newsletter.
+fn classify(uid: u32, raw_headers: &[u8], settings: &Settings) -> Option<Issue> {
+    let (headers, _) = parse_headers(raw_headers).ok()?;
+    let issue = describe(uid, &headers);
+    if sender_matches(&issue.sender, &settings.exclude) {
+        return None;
+    }
+    // A mailing-list id, a newsletter platform, or an explicit allowlist entry;
+    // List-Unsubscribe alone is too common on receipts and notifications.
+    let list_id = headers.get_first_value("List-Id").is_some();
+    let list_unsubscribe = headers.get_first_value("List-Unsubscribe").is_some();
+    let newsletter = sender_matches(&issue.sender, &settings.senders)
+        || list_id
+        || (issue.platform.is_some() && list_unsubscribe);
+    newsletter.then_some(issue)
+}
+
+/// Issue metadata from headers, without deciding whether it's a newsletter.
+fn describe(uid: u32, headers: &[MailHeader]) -> Issue {
+    let from = headers.get_first_value("From").unwrap_or_default();
+    let (name, sender) = split_from(&from);
+    let platform = detect_platform(&format!(
+        "{} {} {} {}",
+        sender,
+        headers.get_first_value("List-Id").unwrap_or_default(),
+        headers
+            .get_first_value("List-Unsubscribe")
+            .unwrap_or_default(),
+        headers.get_first_value("X-Mailer").unwrap_or_default()
+    ));
+    let date = headers.get_first_value("Date");
+    let timestamp = date
+        .as_deref()
+        .and_then(|d| mailparse::dateparse(d).ok())
+        .unwrap_or(0);
+    let publication = name.unwrap_or_else(|| {
+        sender
+            .rsplit('@')
+            .next()
+            .unwrap_or(sender.as_str())
+            .to_string()
+    });
+    Issue {
+        uid,
+        publication,
+        sender,
+        subject: headers.get_first_value("Subject"),
+        date: chrono::DateTime::from_timestamp(timestamp, 0)
+            .filter(|_| timestamp > 0)
+            .map(|d| d.to_rfc3339())
+            .or(date),
+        platform,
+        timestamp,
+    }
+}
+
+fn publication_matches(issue: &Issue, filter: Option<&str>) -> bool {
+    let Some(filter) = filter.map(str::to_ascii_lowercase) else {
+        return true;
+    };
+    issue.publication.to_ascii_lowercase().contains(&filter) || issue.sender.contains(&filter)
+}
+
+fn find_part<'a>(parsed: &'a ParsedMail<'a>, mime: &str) -> Option<&'a ParsedMail<'a>> {
+    if parsed.ctype.mimetype.eq_ignore_ascii_case(mime) {
+        return Some(parsed);
+    }
+    parsed
+        .subparts
+        .iter()
+        .find_map(|part| find_part(part, mime))
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Article {
+    markdown: String,
+    web_url: Option<String>,
+}
+
+/// Pull the article out of newsletter HTML: the platform's content container
+/// (or the body), converted to Markdown with the footer trimmed.
+fn extract_article(html: &str) -> Article {
+    let document = Html::parse_document(html);
+
+    let link_selector = Selector::parse("a[href]").expect("valid selector");
+    let web_url = document
+        .select(&link_selector)
+        .find(|a| {
+            let text = a.text().collect::<String>().to_ascii_lowercase();
+            let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            WEB_VERSION_LABELS.iter().any(|label| text.contains(label))
+        })
+        .and_then(|a| a.value().attr("href"))
+        .filter(|href| href.starts_with("http"))
+        .map(str::to_string);
+
+    let content_html = CONTENT_SELECTORS
+        .iter()
+        .filter_map(|s| Selector::parse(s).ok())
+        .find_map(|selector| {
+            document
+                .select(&selector)
+                .find(|el| el.text().map(str::len).sum::<usize>() >= 200)
+                .map(|el| el.html())
+        })
+        .or_else(|| {
+            let body = Selector::parse("body").expect("valid selector");
+            document.select(&body).next().map(|el| el.inner_html())
+        })
+        .unwrap_or_else(|| html.to_string());
+
+    let options = crate::utils::MarkdownOptions {
+        images: false,
+        ..Default::default()
+    };
+    let markdown = crate::utils::html_to_markdown_with(&content_html, &options);
+
+    Article {
+        markdown: trim_boilerplate(&markdown),
+        web_url,
+    }
+}
+
+/// Drop "view in browser" lines and everything from the footer onwards.
+fn trim_boilerplate(markdown: &str) -> String {
+    let lines: Vec<&str> = markdown.lines().collect();
+    // Only look for the footer in the last third so an early mention doesn't cut the issue
+    let footer_from = lines.len() * 2 / 3;
+    let end = lines
+        .iter()
+        .enumerate()
+        .skip(footer_from)
+        .find(|(_, line)| {
+            let lower = line.to_ascii_lowercase();
+            FOOTER_MARKERS.iter().any(|m| lower.contains(m))
+        })
+        .map(|(i, _)| i)
+        .unwrap_or(lines.len());
+    let kept: Vec<&str> = lines[..end]
+        .iter()
+        .filter(|line| {
+            let lower = line.to_ascii_lowercase();
+            !WEB_VERSION_LABELS.iter().any(|label| lower.contains(label))
+        })
+        .copied()
+        .collect();
+    strip_multiple_newlines(kept.join("\n").trim())
+}
+
+#[derive(Debug, Deserialize)]
+struct ListArgs {
+    #[serde(default)]
+    days: Option<i64>,
+    #[serde(default)]
+    publication: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+    #[serde(default)]
+    mailbox: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchArgs {
+    query: String,
+    #[serde(default)]
+    days: Option<i64>,
+    #[serde(default)]
+    publication: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+    #[serde(default)]
+    mailbox: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReadArgs {
+    uid: u32,
+    #[serde(default)]
+    mailbox: Option<String>,
+}
+
+/// Escape a value for an IMAP quoted string.
+fn imap_quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+pub struct NewslettersConnector {
+    imap: ImapConnector,
+    settings: Settings,
+}
+
+impl NewslettersConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        // Reuse the imap connector's saved account unless credentials are given here
+        let imap_auth = if auth.contains_key("host") {
+            auth.clone()
+        } else {
+            FileAuthStore::new_default()
+                .load("imap")
+                .unwrap_or_default()
+        };
+        Ok(Self {
+            imap: ImapConnector::new(imap_auth).await?,
+            settings: Settings {
+                mailbox: auth.get("mailbox").cloned().filter(|m| !m.is_empty()),
+                senders: split_list(auth.get("senders")),
+                exclude: split_list(auth.get("exclude")),
+            },
+        })
+    }
+
+    fn mailbox(&self, requested: Option<String>) -> String {
+        requested
+            .or_else(|| self.settings.mailbox.clone())
+            .or_else(|| self.imap.default_mailbox())
+            .unwrap_or_else(|| "INBOX".to_string())
+    }
+
+    /// Newsletter issues among the newest messages since `days` ago, newest first.
+    async fn scan(
+        &self,
+        mailbox: String,
+        days: i64,
+        text: Option<String>,
+    ) -> Result<Vec<Issue>, ConnectorError> {
+        let since = (chrono::Utc::now() - chrono::Duration::days(days.max(1)))
+            .format("%d-%b-%Y")
+            .to_string();
+        let mut criteria = format!("SINCE {}", since);
+        if let Some(text) = text {
+            criteria.push_str(&format!(" TEXT {}", imap_quote(&text)));
+        }
+        let settings = self.settings.clone();
+
+        let mut issues = self
+            .imap
+            .with_session(move |session| {
+                session.select(&mailbox).map_err(map_imap_error)?;
+                let mut uids: Vec<u32> = session
+                    .uid_search(&criteria)
+                    .map_err(map_imap_error)?
+                    .into_iter()
+                    .collect();
+                uids.sort_unstable_by(|a, b| b.cmp(a));
+                uids.truncate(MAX_SCAN);
+                if uids.is_empty() {
+                    return Ok(Vec::new());
+                }
+                let sequence = uids
+                    .iter()
+                    .map(u32::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",");
+                let fetches = session
+                    .uid_fetch(&sequence, "(UID BODY.PEEK[HEADER])")
+                    .map_err(map_imap_error)?;
+                Ok(fetches
+                    .iter()
+                    .filter_map(|fetch| classify(fetch.uid?, fetch.header()?, &settings))
+                    .collect::<Vec<_>>())
+            })
+            .await?;
+        issues.sort_by(|a, b| b.timestamp.cmp(&a.timestamp).then(b.uid.cmp(&a.uid)));
+        Ok(issues)
+    }
+}
+
+#[async_trait]
+impl Connector for NewslettersConnector {
+    fn name(&self) -> &'static str {
+        "newsletters"
+    }
+
+    fn description(&self) -> &'static str {
+        "Email newsletters from your IMAP mailbox: publications, issues, search and clean article text."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        let mut auth = AuthDetails::new();
+        if let Some(mailbox) = &self.settings.mailbox {
+            auth.insert("mailbox".to_string(), mailbox.clone());
+        }
+        if !self.settings.senders.is_empty() {
+            auth.insert("senders".to_string(), self.settings.senders.join(","));
+        }
+        if !self.settings.exclude.is_empty() {
+            auth.insert("exclude".to_string(), self.settings.exclude.join(","));
+        }
+        Ok(auth)
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        *self = Self::new(details).await?;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.imap.test_auth().await
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        let text = |name: &str, label: &str, description: &str| Field {
+            name: name.to_string(),
+            label: label.to_string(),
+            field_type: FieldType::Text,
+            required: false,
+            description: Some(description.to_string()),
+            options: None,
+        };
+        ConnectorConfigSchema {
+            fields: vec![
+                text(
+                    "mailbox",
+                    "Mailbox",
+                    "Folder holding newsletters (default: the IMAP default mailbox).",
+                ),
+                text(
+                    "senders",
+                    "Newsletter Senders",
+                    "Comma-separated addresses or domains always treated as newsletters.",
+                ),
+                text(
+                    "exclude",
+                    "Excluded Senders",
+                    "Comma-separated addresses or domains never treated as newsletters.",
+                ),
+            ],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Personal data: only use with the user's permission. Uses the imap connector's \
+account (Gmail works via IMAP with an app password). `list_newsletters` groups recent issues by \
+publication, `list_issues`/`search_issues` return issue ids (uid), and `read_issue` returns the \
+article as Markdown without the email chrome."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let days = |default: i64| json!({ "type": "integer", "description": format!("Look back this many days (default: {})", default) });
+        let publication = json!({ "type": "string", "description": "Publication name, sender address or domain (substring match)" });
+        let limit = json!({ "type": "integer", "description": "Max issues (default: 25)" });
+        let mailbox =
+            json!({ "type": "string", "description": "IMAP folder (default: configured mailbox)" });
+        let tools = vec![
+            tool(
+                "list_newsletters",
+                "Publications that sent newsletters recently, with issue counts and the latest subject.",
+                json!({
+                    "type": "object",
+                    "properties": { "days": days(30), "mailbox": mailbox.clone() }
+                }),
+            ),
+            tool(
+                "list_issues",
+                "Recent newsletter issues, newest first. Example: publication=\"stratechery\" days=14.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "publication": publication.clone(),
+                        "days": days(30),
+                        "limit": limit.clone(),
+                        "mailbox": mailbox.clone()
+                    }
+                }),
+            ),
+            tool(
+                "search_issues",
+                "Full-text search across newsletter issues. Example: query=\"rate cuts\" days=90.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Text to find in the issue" },
+                        "publication": publication,
+                        "days": days(90),
+                        "limit": limit,
+                        "mailbox": mailbox.clone()
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "read_issue",
+                "Read one issue as Markdown (article body only) with its web version link.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "uid": { "type": "integer", "description": "Issue uid from list_issues/search_issues" },
+                        "mailbox": mailbox
+                    },
+                    "required": ["uid"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "list_newsletters" => {
+                let args: ListArgs = parse_args(request.arguments)?;
+                let days = args.days.unwrap_or(30);
+                let issues = self.scan(self.mailbox(args.mailbox), days, None).await?;
+
+                // Issues are newest first, so the first one seen per sender is the latest
+                let mut by_sender: BTreeMap<String, Value> = BTreeMap::new();
+                for issue in &issues {
+                    let entry = by_sender.entry(issue.sender.clone()).or_insert_with(|| {
+                        json!({
+                            "publication": issue.publication,
+                            "sender": issue.sender,
+                            "platform": issue.platform,
+                            "issues": 0,
+                            "latest_subject": issue.subject,
+                            "latest_date": issue.date,
+                            "latest_uid": issue.uid,
+                        })
+                    });
+                    entry["issues"] = json!(entry["issues"].as_u64().unwrap_or(0) + 1);
+                }
+                let mut newsletters: Vec<Value> = by_sender.into_values().collect();
+                newsletters.sort_by_key(|n| std::cmp::Reverse(n["issues"].as_u64()));
+                let data = json!({
+                    "days": days,
+                    "count": newsletters.len(),
+                    "newsletters": newsletters,
+                });
+                structured_result_with_text(&data, None)
+            }
+            "list_issues" => {
+                let args: ListArgs = parse_args(request.arguments)?;
+                let limit = args.limit.unwrap_or(25).clamp(1, 200);
+                let mut issues = self
+                    .scan(self.mailbox(args.mailbox), args.days.unwrap_or(30), None)
+                    .await?;
+                issues.retain(|i| publication_matches(i, args.publication.as_deref()));
+                issues.truncate(limit);
+                let data = json!({ "count": issues.len(), "issues": issues });
+                structured_result_with_text(&data, None)
+            }
+            "search_issues" => {
+                let args: SearchArgs = parse_args(request.arguments)?;
+                let limit = args.limit.unwrap_or(25).clamp(1, 200);
+                let mut issues = self
+                    .scan(
+                        self.mailbox(args.mailbox),
+                        args.days.unwrap_or(90),
+                        Some(args.query.clone()),
+                    )
+                    .await?;
+                issues.retain(|i| publication_matches(i, args.publication.as_deref()));
+                issues.truncate(limit);
+                let data = json!({
+                    "query": args.query,
+                    "count": issues.len(),
+                    "issues": issues,
+                });
+                structured_result_with_text(&data, None)
+            }
+            "read_issue" => {
+                let args: ReadArgs = parse_args(request.arguments)?;
+                let mailbox = self.mailbox(args.mailbox);
+                let uid = args.uid;
+                let raw = self
+                    .imap
+                    .with_session(move |session| {
+                        session.select(&mailbox).map_err(map_imap_error)?;
+                        let fetches = session
+                            .uid_fetch(uid.to_string(), "(UID BODY.PEEK[])")
+                            .map_err(map_imap_error)?;
+                        fetches
+                            .iter()
+                            .next()
+                            .and_then(|f| f.body())
+                            .map(|b| b.to_vec())
+                            .ok_or(ConnectorError::ResourceNotFound)
+                    })
+                    .await?;
+
+                let parsed = parse_mail(&raw)
+                    .map_err(|e| ConnectorError::Other(format!("Cannot parse message: {}", e)))?;
+                let issue = describe(uid, &parsed.headers);
+
+                let html = find_part(&parsed, "text/html").and_then(|p| p.get_body().ok());
+                let (article, source) = match html {
+                    Some(html) => (extract_article(&html), "html"),
+                    None => {
+                        let text = find_part(&parsed, "text/plain")
+                            .and_then(|p| p.get_body().ok())
+                            .unwrap_or_default();
+                        (
+                            Article {
+                                markdown: trim_boilerplate(&text),
+                                web_url: None,
+                            },
+                            "text",
+                        )
+                    }
+                };
+                let mut data = json!({
+                    "uid": uid,
+                    "content": article.markdown,
+                    "content_source": source,
+                    "word_count": article.markdown.split_whitespace().count(),
+                    "web_url": article.web_url,
+                });
+                data["publication"] = json!(issue.publication);
+                data["sender"] = json!(issue.sender);
+                data["subject"] = json!(issue.subject);
+                data["date"] = json!(issue.date);
+                data["platform"] = json!(issue.platform);
+                structured_result_with_text(&data, None)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, _name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADERS: &str = "From: \"Tech Weekly\" <hello@techweekly.substack.com>\r\n\
+Subject: Issue 42\r\n\
+Date: Tue, 1 Jul 2025 09:00:00 +0000\r\n\
+List-Unsubscribe: <https://techweekly.substack.com/action/disable_email>\r\n\r\n";
+
+    #[test]
+    fn classifies_newsletters() {
+        let issue = classify(7, HEADERS.as_bytes(), &Settings::default()).unwrap();
+        assert_eq!(issue.publication, "Tech Weekly");
+        assert_eq!(issue.sender, "hello@techweekly.substack.com");
+        assert_eq!(issue.platform, Some("Substack"));
+        assert_eq!(issue.date.as_deref(), Some("2025-07-01T09:00:00+00:00"));
+
+        let receipt = "From: shop@example.com\r\nList-Unsubscribe: <mailto:u@example.com>\r\n\r\n";
+        assert!(classify(8, receipt.as_bytes(), &Settings::default()).is_none());
+        let allowlisted = Settings {
+            senders: vec!["example.com".to_string()],
+            ..Default::default()
+        };
+        assert!(classify(8, receipt.as_bytes(), &allowlisted).is_some());
+        let excluded = Settings {
+            exclude: vec!["substack.com".to_string()],
+            ..Default::default()
+        };
+        assert!(classify(7, HEADERS.as_bytes(), &excluded).is_none());
+    }
+
+    #[test]
+    fn extracts_article_body() {
+        let body = "Interest rates are the story this week. ".repeat(10);
+        let html = format!(
+            r#"<html><body>
+<a href="https://example.com/p/issue-42">View in browser</a>
+<div class="header">Tech Weekly</div>
+<div class="body markup"><h2>The big story</h2><p>{}</p></div>
+<div class="footer"><a href="https://example.com/unsub">Unsubscribe</a></div>
+</body></html>"#,
+            body
+        );
+        let article = extract_article(&html);
+        assert_eq!(
+            article.web_url.as_deref(),
+            Some("https://example.com/p/issue-42")
+        );
+        assert!(article.markdown.starts_with("## The big story"));
+        assert!(!article.markdown.contains("Unsubscribe"));
+        assert!(!article.markdown.contains("Tech Weekly"));
+    }
+
+    #[test]
+    fn trims_footer_and_browser_links() {
+        let text = "View online\nPara one\nPara two\nPara three\nUnsubscribe here\nAddress";
+        assert_eq!(trim_boilerplate(text), "Para one\nPara two\nPara three");
+    }
+}
@@ -0,0 +1,473 @@
+use crate::capabilities::ConnectorConfigSchema;
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+const REGISTRY: &str = "https://registry.npmjs.org";
+const DOWNLOADS_API: &str = "https://api.npmjs.org/downloads/point";
+const UNPKG: &str = "https://unpkg.com";
+const MAX_SEARCH_SIZE: u32 = 250;
+
+#[derive(Debug, Deserialize)]
+struct SearchArgs {
+    query: String,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    offset: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PackageArgs {
+    name: String,
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(default)]
+    max_versions: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReadmeArgs {
+    name: String,
+    #[serde(default)]
+    version: Option<String>,
+}
+
+/// Registry document paths need the scope separator escaped (`@scope%2Fname`).
+fn registry_path(name: &str) -> String {
+    name.trim().replacen('/', "%2F", 1)
+}
+
+fn validate_name(name: &str) -> Result<&str, ConnectorError> {
+    let name = name.trim();
+    let valid = !name.is_empty()
+        && name.len() <= 214
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "@/._-~".contains(c))
+        && name.matches('/').count() <= usize::from(name.starts_with('@'));
+    if valid {
+        Ok(name)
+    } else {
+        Err(ConnectorError::InvalidParams(format!(
+            "'{}' is not a valid npm package name",
+            name
+        )))
+    }
+}
+
+/// Versions newest first by publish time, skipping the `created`/`modified` keys.
+fn version_history(packument: &Value) -> Vec<(String, String)> {
+    let mut history: Vec<(String, String)> = packument["time"]
+        .as_object()
+        .map(|t| {
+            t.iter()
+                .filter(|(k, _)| k.as_str() != "created" && k.as_str() != "modified")
+                .filter_map(|(k, v)| v.as_str().map(|d| (k.clone(), d.to_string())))
+                .collect()
+        })
+        .unwrap_or_default();
+    history.sort_by(|a, b| b.1.cmp(&a.1));
+    history
+}
+
+fn repository_url(value: &Value) -> Value {
+    match value {
+        Value::String(s) => json!(s),
+        Value::Object(o) => o.get("url").cloned().unwrap_or(Value::Null),
+        _ => Value::Null,
+    }
+}
+
+pub struct NpmConnector {
+    client: Client,
+}
+
+impl NpmConnector {
+    pub async fn new(_auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+        Ok(Self { client })
+    }
+
+    async fn fetch(&self, url: &str, params: &[(&str, String)]) -> Result<String, ConnectorError> {
+        let response = self
+            .client
+            .get(url)
+            .query(params)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+        match response.status() {
+            s if s.is_success() => response.text().await.map_err(ConnectorError::HttpRequest),
+            StatusCode::NOT_FOUND => Err(ConnectorError::ResourceNotFound),
+            StatusCode::TOO_MANY_REQUESTS => Err(ConnectorError::Other(
+                "npm registry rate limit reached; retry shortly".to_string(),
+            )),
+            s => Err(ConnectorError::Other(format!(
+                "npm registry returned error status: {}",
+                s
+            ))),
+        }
+    }
+
+    async fn get_json(
+        &self,
+        url: &str,
+        params: &[(&str, String)],
+    ) -> Result<Value, ConnectorError> {
+        Ok(serde_json::from_str(&self.fetch(url, params).await?)?)
+    }
+
+    async fn packument(&self, name: &str) -> Result<Value, ConnectorError> {
+        self.get_json(&format!("{}/{}", REGISTRY, registry_path(name)), &[])
+            .await
+    }
+
+    async fn search(&self, args: SearchArgs) -> Result<Value, ConnectorError> {
+        let limit = args.limit.unwrap_or(10).clamp(1, MAX_SEARCH_SIZE);
+        let offset = args.offset.unwrap_or(0);
+        let data = self
+            .get_json(
+                &format!("{}/-/v1/search", REGISTRY),
+                &[
+                    ("text", args.query.clone()),
+                    ("size", limit.to_string()),
+                    ("from", offset.to_string()),
+                ],
+            )
+            .await?;
+        let packages: Vec<Value> = data["objects"]
+            .as_array()
+            .map(|objects| {
+                objects
+                    .iter()
+                    .map(|o| {
+                        let p = &o["package"];
+                        json!({
+                            "name": p["name"],
+                            "version": p["version"],
+                            "description": p["description"],
+                            "keywords": p["keywords"],
+                            "published": p["date"],
+                            "publisher": p["publisher"]["username"],
+                            "repository": p["links"]["repository"],
+                            "homepage": p["links"]["homepage"],
+                            "weekly_downloads": o["downloads"]["weekly"],
+                            "score": o["score"]["final"],
+                            "url": p["links"]["npm"],
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(json!({
+            "query": args.query,
+            "total": data["total"],
+            "offset": offset,
+            "count": packages.len(),
+            "packages": packages,
+        }))
+    }
+
+    async fn get_package(&self, args: PackageArgs) -> Result<Value, ConnectorError> {
+        let name = validate_name(&args.name)?;
+        let doc = self.packument(name).await?;
+        let dist_tags = doc["dist-tags"].clone();
+        let version = match args.version.as_deref() {
+            // Accept dist-tags such as `next` as well as exact versions.
+            Some(v) => dist_tags[v].as_str().unwrap_or(v).to_string(),
+            None => dist_tags["latest"]
+                .as_str()
+                .ok_or(ConnectorError::ResourceNotFound)?
+                .to_string(),
+        };
+        let manifest = doc["versions"]
+            .get(&version)
+            .ok_or_else(|| {
+                ConnectorError::InvalidParams(format!("{} has no version {}", name, version))
+            })?
+            .clone();
+
+        let history = version_history(&doc);
+        let max_versions = args.max_versions.unwrap_or(20);
+        let versions: Vec<Value> = history
+            .iter()
+            .take(max_versions)
+            .map(|(v, published)| {
+                json!({
+                    "version": v,
+                    "published": published,
+                    "deprecated": doc["versions"][v]["deprecated"],
+                })
+            })
+            .collect();
+
+        // Download counts come from a separate API; a failure there is not fatal.
+        let weekly_downloads = self
+            .get_json(&format!("{}/last-week/{}", DOWNLOADS_API, name), &[])
+            .await
+            .map(|d| d["downloads"].clone())
+            .unwrap_or(Value::Null);
+
+        Ok(json!({
+            "name": doc["name"],
+            "description": doc["description"],
+            "dist_tags": dist_tags,
+            "license": manifest["license"].clone(),
+            "homepage": doc["homepage"],
+            "repository": repository_url(&doc["repository"]),
+            "keywords": doc["keywords"],
+            "maintainers": doc["maintainers"],
+            "created": doc["time"]["created"],
+            "modified": doc["time"]["modified"],
+            "weekly_downloads": weekly_downloads,
+            "version": {
+                "version": version,
+                "published": doc["time"][&version],
+                "deprecated": manifest["deprecated"],
+                "engines": manifest["engines"],
+                "types": manifest["types"].as_str().or(manifest["typings"].as_str()),
+                "module_type": manifest["type"],
+                "dependencies": manifest["dependencies"],
+                "peer_dependencies": manifest["peerDependencies"],
+                "optional_dependencies": manifest["optionalDependencies"],
+                "dev_dependencies": manifest["devDependencies"],
+                "unpacked_size": manifest["dist"]["unpackedSize"],
+                "file_count": manifest["dist"]["fileCount"],
+                "tarball": manifest["dist"]["tarball"],
+            },
+            "version_count": history.len(),
+            "versions": versions,
+            "url": format!("https://www.npmjs.com/package/{}", name),
+        }))
+    }
+
+    async fn readme(&self, args: ReadmeArgs) -> Result<Value, ConnectorError> {
+        let name = validate_name(&args.name)?;
+        let doc = self.packument(name).await?;
+        let latest = doc["dist-tags"]["latest"].as_str().unwrap_or_default();
+        let version = args
+            .version
+            .as_deref()
+            .map(|v| doc["dist-tags"][v].as_str().unwrap_or(v))
+            .unwrap_or(latest)
+            .to_string();
+
+        // The packument carries the README of the latest release only; other
+        // versions are read from the published tarball contents via unpkg.
+        let readme = doc["readme"].as_str().unwrap_or_default();
+        let (content, source) = if version == latest && !readme.trim().is_empty() {
+            (readme.to_string(), format!("{}/{}", REGISTRY, name))
+        } else {
+            let filename = doc["versions"][&version]["readmeFilename"]
+                .as_str()
+                .or(doc["readmeFilename"].as_str())
+                .unwrap_or("README.md");
+            let url = format!("{}/{}@{}/{}", UNPKG, name, version, filename);
+            (self.fetch(&url, &[]).await?, url)
+        };
+
+        Ok(json!({
+            "name": name,
+            "version": version,
+            "source": source,
+            "content": content,
+        }))
+    }
+}
+
+#[async_trait]
+impl Connector for NpmConnector {
+    fn name(&self) -> &'static str {
+        "npm"
+    }
+
+    fn description(&self) -> &'static str {
+        "npm registry packages: search, metadata, version history, dependencies and READMEs."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        Ok(AuthDetails::new())
+    }
+
+    async fn set_auth_details(&mut self, _details: AuthDetails) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema { fields: vec![] }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Use `search_packages` to find packages, `get_package` for versions, dependencies \
+and downloads, and `get_readme` for usage docs. Scoped names look like @scope/name."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            tool(
+                "search_packages",
+                "Search the npm registry. Supports qualifiers like keywords:react, author:name, scope:types. Example: query=\"date formatting\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Search text with optional qualifiers" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10, max: 250)" },
+                        "offset": { "type": "integer", "description": "Result offset for paging" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "get_package",
+                "Package metadata, dist-tags, version history, weekly downloads, and the dependencies/engines of one version (default: latest).",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string", "description": "Package name, e.g. react or @types/node" },
+                        "version": { "type": "string", "description": "Exact version or dist-tag (default: latest)" },
+                        "max_versions": { "type": "integer", "description": "Versions to include in history (default: 20)" }
+                    },
+                    "required": ["name"]
+                }),
+            ),
+            tool(
+                "get_readme",
+                "Package README (markdown) for the latest or a specific version.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string", "description": "Package name" },
+                        "version": { "type": "string", "description": "Exact version or dist-tag (default: latest)" }
+                    },
+                    "required": ["name"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        let data = match request.name.as_ref() {
+            "search_packages" => self.search(parse_args(request.arguments)?).await?,
+            "get_package" => self.get_package(parse_args(request.arguments)?).await?,
+            "get_readme" => self.readme(parse_args(request.arguments)?).await?,
+            _ => return Err(ConnectorError::ToolNotFound),
+        };
+        Ok(structured_result_with_text(&data, None)?)
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_scoped_names() {
+        assert_eq!(registry_path("@types/node"), "@types%2Fnode");
+        assert_eq!(registry_path("react"), "react");
+        assert!(validate_name("@types/node").is_ok());
+        assert!(validate_name("left-pad").is_ok());
+        assert!(validate_name("a/b").is_err());
+        assert!(validate_name("../etc").is_err());
+    }
+
+    #[test]
+    fn orders_versions_by_publish_time() {
+        let doc = json!({
+            "time": {
+                "created": "2020-01-01T00:00:00.000Z",
+                "modified": "2024-06-01T00:00:00.000Z",
+                "1.0.0": "2020-01-01T00:00:00.000Z",
+                "2.0.0": "2023-01-01T00:00:00.000Z",
+                "1.1.0": "2021-01-01T00:00:00.000Z"
+            }
+        });
+        let versions: Vec<String> = version_history(&doc).into_iter().map(|v| v.0).collect();
+        assert_eq!(versions, vec!["2.0.0", "1.1.0", "1.0.0"]);
+        assert_eq!(
+            repository_url(&json!({ "type": "git", "url": "git+https://github.com/a/b.git" })),
+            "git+https://github.com/a/b.git"
+        );
+    }
+}
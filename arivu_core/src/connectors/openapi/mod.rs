@@ -0,0 +1,1118 @@
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::utils::structured_result_with_text;
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use base64::Engine;
+use reqwest::{Client, Method, StatusCode};
+use rmcp::model::*;
+use serde_json::{json, Map, Value};
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+
+/// Upper bound on generated tools so a large spec doesn't flood the tool list.
+const MAX_TOOLS: usize = 128;
+const MAX_TOOL_NAME: usize = 64;
+/// `$ref` chains deeper than this are replaced by an untyped schema.
+const MAX_REF_DEPTH: usize = 8;
+const MAX_TEXT_RESPONSE: usize = 100_000;
+const HTTP_METHODS: &[&str] = &["get", "put", "post", "delete", "options", "head", "patch"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ParamIn {
+    Path,
+    Query,
+    Header,
+}
+
+#[derive(Debug, Clone)]
+struct Param {
+    name: String,
+    location: ParamIn,
+    required: bool,
+    schema: Value,
+    description: Option<String>,
+}
+
+/// One spec operation exposed (or exposable) as an MCP tool.
+#[derive(Debug, Clone)]
+struct Operation {
+    tool_name: String,
+    operation_id: Option<String>,
+    method: String,
+    path: String,
+    summary: Option<String>,
+    params: Vec<Param>,
+    body: Option<Value>,
+    body_required: bool,
+    enabled: bool,
+}
+
+/// Where the configured credential goes on each request.
+#[derive(Debug, Clone, PartialEq)]
+enum AuthPlacement {
+    Header { name: String, bearer: bool },
+    Query { name: String },
+    Basic,
+}
+
+#[derive(Debug)]
+struct ApiSpec {
+    title: Option<String>,
+    version: Option<String>,
+    base_url: Option<String>,
+    auth: AuthPlacement,
+    operations: Vec<Operation>,
+}
+
+impl ApiSpec {
+    fn enabled(&self) -> impl Iterator<Item = &Operation> {
+        self.operations.iter().filter(|op| op.enabled)
+    }
+}
+
+/// Connector settings read from config (and `ARIVU_OPENAPI_*` env vars).
+#[derive(Debug, Clone, Default)]
+struct Settings {
+    spec: Option<String>,
+    base_url: Option<String>,
+    operations: Vec<String>,
+    allow_writes: bool,
+    api_key: Option<String>,
+    auth_header: Option<String>,
+    auth_query: Option<String>,
+}
+
+impl Settings {
+    fn from_auth(auth: &AuthDetails) -> Self {
+        let get = |key: &str, env: &str| {
+            auth.get(key)
+                .cloned()
+                .or_else(|| std::env::var(env).ok())
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+        Self {
+            spec: get("spec", "ARIVU_OPENAPI_SPEC"),
+            base_url: get("base_url", "ARIVU_OPENAPI_BASE_URL"),
+            operations: get("operations", "ARIVU_OPENAPI_OPERATIONS")
+                .map(|ops| {
+                    ops.split([',', '\n'])
+                        .map(str::trim)
+                        .filter(|op| !op.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            allow_writes: get("allow_writes", "ARIVU_OPENAPI_ALLOW_WRITES")
+                .is_some_and(|v| matches!(v.to_ascii_lowercase().as_str(), "true" | "1" | "yes")),
+            api_key: get("api_key", "ARIVU_OPENAPI_API_KEY"),
+            auth_header: get("auth_header", "ARIVU_OPENAPI_AUTH_HEADER"),
+            auth_query: get("auth_query", "ARIVU_OPENAPI_AUTH_QUERY"),
+        }
+    }
+
+    /// An operation is exposed when it is listed in `operations` (by operationId,
+    /// tool name or `METHOD /path`); with no list, read-only methods are exposed
+    /// unless `allow_writes` is set.
+    fn selects(&self, op: &Operation) -> bool {
+        if self.operations.is_empty() {
+            return self.allow_writes || matches!(op.method.as_str(), "GET" | "HEAD");
+        }
+        let route = format!("{} {}", op.method, op.path);
+        self.operations.iter().any(|sel| {
+            op.operation_id.as_deref() == Some(sel.as_str())
+                || op.tool_name == *sel
+                || route.eq_ignore_ascii_case(sel)
+        })
+    }
+}
+
+/// `listPetsById` / `GET /pets/{id}` -> `list_pets_by_id` / `get_pets_id`.
+fn tool_name_for(operation_id: Option<&str>, method: &str, path: &str) -> String {
+    let source = match operation_id {
+        Some(id) if !id.trim().is_empty() => id.to_string(),
+        _ => format!("{} {}", method, path),
+    };
+    let mut out = String::new();
+    let mut prev_lower = false;
+    for c in source.chars() {
+        if c.is_ascii_alphanumeric() {
+            if c.is_ascii_uppercase() && prev_lower {
+                out.push('_');
+            }
+            prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+            out.push(c.to_ascii_lowercase());
+        } else {
+            if !out.ends_with('_') {
+                out.push('_');
+            }
+            prev_lower = false;
+        }
+    }
+    let mut name = out.trim_matches('_').to_string();
+    name.truncate(MAX_TOOL_NAME);
+    if name.is_empty() {
+        name = "operation".to_string();
+    }
+    name
+}
+
+/// Follow a local `#/…` reference.
+fn resolve_ref<'a>(doc: &'a Value, value: &'a Value) -> &'a Value {
+    let mut current = value;
+    for _ in 0..MAX_REF_DEPTH {
+        match current.get("$ref").and_then(Value::as_str) {
+            Some(r) => match r.strip_prefix('#').and_then(|ptr| doc.pointer(ptr)) {
+                Some(target) => current = target,
+                None => return current,
+            },
+            None => return current,
+        }
+    }
+    current
+}
+
+/// Copy a schema with local `$ref`s inlined so tool input schemas are self-contained.
+/// `depth` counts followed references, which bounds recursive schemas.
+fn inline_refs(doc: &Value, value: &Value, depth: usize) -> Value {
+    if depth > MAX_REF_DEPTH {
+        return json!({});
+    }
+    match value {
+        Value::Object(map) => {
+            if map.contains_key("$ref") {
+                let target = resolve_ref(doc, value);
+                if std::ptr::eq(target, value) {
+                    return json!({});
+                }
+                return inline_refs(doc, target, depth + 1);
+            }
+            Value::Object(
+                map.iter()
+                    .map(|(k, v)| (k.clone(), inline_refs(doc, v, depth)))
+                    .collect(),
+            )
+        }
+        Value::Array(items) => {
+            Value::Array(items.iter().map(|v| inline_refs(doc, v, depth)).collect())
+        }
+        other => other.clone(),
+    }
+}
+
+/// A spec parameter, or the schema of a Swagger 2.0 `in: body` parameter.
+enum ParsedParam {
+    Param(Param),
+    Body(Value),
+}
+
+fn parse_param(doc: &Value, raw: &Value) -> Option<ParsedParam> {
+    let p = resolve_ref(doc, raw);
+    let name = p.get("name")?.as_str()?.to_string();
+    let location = match p.get("in")?.as_str()? {
+        "path" => ParamIn::Path,
+        "query" => ParamIn::Query,
+        "header" => ParamIn::Header,
+        "body" => {
+            return Some(ParsedParam::Body(
+                p.get("schema").cloned().unwrap_or(json!({})),
+            ))
+        }
+        _ => return None,
+    };
+    // Swagger 2.0 puts type/enum/items on the parameter itself
+    let schema = match p.get("schema") {
+        Some(schema) => inline_refs(doc, schema, 0),
+        None => {
+            let mut schema = Map::new();
+            for key in ["type", "format", "enum", "items", "default"] {
+                if let Some(v) = p.get(key) {
+                    schema.insert(key.to_string(), inline_refs(doc, v, 0));
+                }
+            }
+            Value::Object(schema)
+        }
+    };
+    Some(ParsedParam::Param(Param {
+        name,
+        required: location == ParamIn::Path
+            || p.get("required").and_then(Value::as_bool).unwrap_or(false),
+        location,
+        schema,
+        description: p
+            .get("description")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+    }))
+}
+
+fn json_body_schema(doc: &Value, request_body: &Value) -> Option<(Value, bool)> {
+    let body = resolve_ref(doc, request_body);
+    let content = body.get("content")?.as_object()?;
+    let media = content
+        .iter()
+        .find(|(mime, _)| mime.contains("json"))
+        .or_else(|| content.iter().next())?
+        .1;
+    let schema = media
+        .get("schema")
+        .map(|s| inline_refs(doc, s, 0))
+        .unwrap_or(json!({}));
+    let required = body
+        .get("required")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    Some((schema, required))
+}
+
+fn base_url_from(doc: &Value, spec_location: &str) -> Option<String> {
+    // OpenAPI 3: first server, with variables at their defaults
+    if let Some(server) = doc.pointer("/servers/0") {
+        let mut url = server.get("url")?.as_str()?.to_string();
+        if let Some(vars) = server.get("variables").and_then(Value::as_object) {
+            for (name, var) in vars {
+                if let Some(default) = var.get("default").and_then(Value::as_str) {
+                    url = url.replace(&format!("{{{}}}", name), default);
+                }
+            }
+        }
+        if url.starts_with("http://") || url.starts_with("https://") {
+            return Some(url);
+        }
+        // Relative server URLs resolve against the spec's own URL
+        return url::Url::parse(spec_location)
+            .ok()
+            .and_then(|base| base.join(&url).ok())
+            .map(|u| u.to_string());
+    }
+    // Swagger 2.0
+    let host = doc.get("host")?.as_str()?;
+    let scheme = doc
+        .pointer("/schemes/0")
+        .and_then(Value::as_str)
+        .unwrap_or("https");
+    let base_path = doc.get("basePath").and_then(Value::as_str).unwrap_or("");
+    Some(format!("{}://{}{}", scheme, host, base_path))
+}
+
+fn auth_from(doc: &Value, settings: &Settings) -> AuthPlacement {
+    if let Some(name) = &settings.auth_header {
+        return AuthPlacement::Header {
+            bearer: name.eq_ignore_ascii_case("authorization"),
+            name: name.clone(),
+        };
+    }
+    if let Some(name) = &settings.auth_query {
+        return AuthPlacement::Query { name: name.clone() };
+    }
+    let schemes = doc
+        .pointer("/components/securitySchemes")
+        .or_else(|| doc.get("securityDefinitions"))
+        .and_then(Value::as_object);
+    // Prefer the scheme named by the global security requirement
+    let preferred = doc
+        .pointer("/security/0")
+        .and_then(Value::as_object)
+        .and_then(|req| req.keys().next().cloned());
+    let scheme = schemes.and_then(|s| {
+        preferred
+            .as_ref()
+            .and_then(|name| s.get(name))
+            .or_else(|| s.values().next())
+            .map(|scheme| resolve_ref(doc, scheme))
+    });
+    let default = AuthPlacement::Header {
+        name: "Authorization".to_string(),
+        bearer: true,
+    };
+    let Some(scheme) = scheme else {
+        return default;
+    };
+    let field = |key: &str| scheme.get(key).and_then(Value::as_str).unwrap_or_default();
+    match field("type") {
+        "apiKey" if field("in") == "query" => AuthPlacement::Query {
+            name: field("name").to_string(),
+        },
+        "apiKey" => AuthPlacement::Header {
+            name: field("name").to_string(),
+            bearer: false,
+        },
+        // Swagger 2.0 uses type: basic; OpenAPI 3 uses type: http, scheme: basic
+        "basic" => AuthPlacement::Basic,
+        "http" if field("scheme").eq_ignore_ascii_case("basic") => AuthPlacement::Basic,
+        _ => default,
+    }
+}
+
+/// Build the operation list from a parsed OpenAPI 3 / Swagger 2 document.
+fn parse_spec(doc: &Value, settings: &Settings, spec_location: &str) -> ApiSpec {
+    let mut operations = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    seen.insert("list_operations".to_string());
+
+    if let Some(paths) = doc.get("paths").and_then(Value::as_object) {
+        for (path, item) in paths {
+            let item = resolve_ref(doc, item);
+            let shared: Vec<Value> = item
+                .get("parameters")
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default();
+            for method in HTTP_METHODS {
+                let Some(op) = item.get(*method) else {
+                    continue;
+                };
+                let method = method.to_ascii_uppercase();
+                let operation_id = op
+                    .get("operationId")
+                    .and_then(Value::as_str)
+                    .map(str::to_string);
+
+                let mut base_name = tool_name_for(operation_id.as_deref(), &method, path);
+                if seen.contains(&base_name) {
+                    // Leave room for a numeric suffix
+                    base_name.truncate(MAX_TOOL_NAME - 4);
+                }
+                let mut tool_name = base_name.clone();
+                let mut n = 2;
+                while seen.contains(&tool_name) {
+                    tool_name = format!("{}_{}", base_name, n);
+                    n += 1;
+                }
+                seen.insert(tool_name.clone());
+
+                // Operation-level parameters override path-level ones with the same name
+                let mut params: Vec<Param> = Vec::new();
+                let mut body = None;
+                let mut body_required = false;
+                let own = op.get("parameters").and_then(Value::as_array);
+                for raw in own.into_iter().flatten().chain(shared.iter()) {
+                    match parse_param(doc, raw) {
+                        Some(ParsedParam::Param(param)) => {
+                            if !params
+                                .iter()
+                                .any(|p| p.name == param.name && p.location == param.location)
+                            {
+                                params.push(param);
+                            }
+                        }
+                        Some(ParsedParam::Body(schema)) if body.is_none() => {
+                            body = Some(inline_refs(doc, &schema, 0));
+                            body_required = true;
+                        }
+                        _ => {}
+                    }
+                }
+                if let Some((schema, required)) = op
+                    .get("requestBody")
+                    .and_then(|rb| json_body_schema(doc, rb))
+                {
+                    body = Some(schema);
+                    body_required = required;
+                }
+
+                let summary = op
+                    .get("summary")
+                    .or_else(|| op.get("description"))
+                    .and_then(Value::as_str)
+                    .map(|s| s.trim().to_string());
+
+                let mut operation = Operation {
+                    tool_name,
+                    operation_id,
+                    method,
+                    path: path.clone(),
+                    summary,
+                    params,
+                    body,
+                    body_required,
+                    enabled: false,
+                };
+                operation.enabled = settings.selects(&operation);
+                operations.push(operation);
+            }
+        }
+    }
+
+    // Respect the tool cap in spec order
+    let mut enabled = 0;
+    for op in operations.iter_mut().filter(|op| op.enabled) {
+        enabled += 1;
+        op.enabled = enabled <= MAX_TOOLS;
+    }
+
+    ApiSpec {
+        title: doc
+            .pointer("/info/title")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        version: doc
+            .pointer("/info/version")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        base_url: settings
+            .base_url
+            .clone()
+            .or_else(|| base_url_from(doc, spec_location)),
+        auth: auth_from(doc, settings),
+        operations,
+    }
+}
+
+fn input_schema(op: &Operation) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for param in &op.params {
+        let mut schema = match &param.schema {
+            Value::Object(map) => map.clone(),
+            _ => Map::new(),
+        };
+        let location = match param.location {
+            ParamIn::Path => "path",
+            ParamIn::Query => "query",
+            ParamIn::Header => "header",
+        };
+        let description = match &param.description {
+            Some(d) => format!("{} ({} parameter)", d, location),
+            None => format!("{} parameter", location),
+        };
+        schema.insert("description".to_string(), json!(description));
+        properties.insert(param.name.clone(), Value::Object(schema));
+        if param.required {
+            required.push(json!(param.name));
+        }
+    }
+    if let Some(body) = &op.body {
+        let mut schema = match body {
+            Value::Object(map) => map.clone(),
+            _ => Map::new(),
+        };
+        schema
+            .entry("description".to_string())
+            .or_insert(json!("JSON request body"));
+        properties.insert("body".to_string(), Value::Object(schema));
+        if op.body_required {
+            required.push(json!("body"));
+        }
+    }
+    json!({ "type": "object", "properties": properties, "required": required })
+}
+
+fn tool_description(op: &Operation) -> String {
+    match &op.summary {
+        Some(summary) if !summary.is_empty() => {
+            format!("{} ({} {})", summary, op.method, op.path)
+        }
+        _ => format!("{} {}", op.method, op.path),
+    }
+}
+
+/// Render a scalar argument for a path segment, query string or header.
+fn arg_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// Substitute `{name}` path parameters, percent-encoding the values.
+fn fill_path(op: &Operation, args: &Map<String, Value>) -> Result<String, ConnectorError> {
+    let mut path = op.path.clone();
+    for param in op.params.iter().filter(|p| p.location == ParamIn::Path) {
+        let value = args.get(&param.name).ok_or_else(|| {
+            ConnectorError::InvalidParams(format!("Missing path parameter '{}'", param.name))
+        })?;
+        path = path.replace(
+            &format!("{{{}}}", param.name),
+            &urlencoding::encode(&arg_string(value)),
+        );
+    }
+    Ok(path)
+}
+
+fn query_pairs(op: &Operation, args: &Map<String, Value>) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    for param in op.params.iter().filter(|p| p.location == ParamIn::Query) {
+        match args.get(&param.name) {
+            Some(Value::Array(items)) => {
+                pairs.extend(items.iter().map(|v| (param.name.clone(), arg_string(v))))
+            }
+            Some(Value::Null) | None => {}
+            Some(value) => pairs.push((param.name.clone(), arg_string(value))),
+        }
+    }
+    pairs
+}
+
+async fn load_document(client: &Client, location: &str) -> Result<Value, ConnectorError> {
+    let text = if location.starts_with("http://") || location.starts_with("https://") {
+        let response = client
+            .get(location)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+        if !response.status().is_success() {
+            return Err(ConnectorError::Other(format!(
+                "Fetching OpenAPI spec {} returned {}",
+                location,
+                response.status()
+            )));
+        }
+        response.text().await.map_err(ConnectorError::HttpRequest)?
+    } else {
+        let path = match location.strip_prefix("~/") {
+            Some(rest) => dirs::home_dir()
+                .map(|home| home.join(rest))
+                .unwrap_or_else(|| location.into()),
+            None => location.into(),
+        };
+        std::fs::read_to_string(&path).map_err(|e| {
+            ConnectorError::Other(format!(
+                "Cannot read OpenAPI spec {}: {}",
+                path.display(),
+                e
+            ))
+        })?
+    };
+    serde_json::from_str(&text)
+        .or_else(|_| serde_yaml::from_str(&text))
+        .map_err(|e| ConnectorError::Other(format!("Invalid OpenAPI document: {}", e)))
+}
+
+pub struct OpenApiConnector {
+    client: Client,
+    settings: Settings,
+    spec: OnceCell<Arc<ApiSpec>>,
+}
+
+impl OpenApiConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+        Ok(Self {
+            client,
+            settings: Settings::from_auth(&auth),
+            spec: OnceCell::new(),
+        })
+    }
+
+    /// Load and parse the configured spec once per connector instance.
+    async fn spec(&self) -> Result<Arc<ApiSpec>, ConnectorError> {
+        self.spec
+            .get_or_try_init(|| async {
+                let location = self.settings.spec.as_deref().ok_or_else(|| {
+                    ConnectorError::Authentication(
+                        "OpenAPI spec not configured: arivu config set openapi (spec URL or file path)"
+                            .to_string(),
+                    )
+                })?;
+                let doc = load_document(&self.client, location).await?;
+                if doc.get("openapi").is_none() && doc.get("swagger").is_none() {
+                    return Err(ConnectorError::Other(format!(
+                        "{} is not an OpenAPI/Swagger document",
+                        location
+                    )));
+                }
+                Ok(Arc::new(parse_spec(&doc, &self.settings, location)))
+            })
+            .await
+            .cloned()
+    }
+
+    async fn invoke(
+        &self,
+        spec: &ApiSpec,
+        op: &Operation,
+        args: Map<String, Value>,
+    ) -> Result<Value, ConnectorError> {
+        let base_url = spec.base_url.as_deref().ok_or_else(|| {
+            ConnectorError::InvalidInput(
+                "The spec declares no server URL; set base_url in the openapi config".to_string(),
+            )
+        })?;
+        let url = format!(
+            "{}{}",
+            base_url.trim_end_matches('/'),
+            fill_path(op, &args)?
+        );
+        let method = Method::from_bytes(op.method.as_bytes())
+            .map_err(|e| ConnectorError::Other(e.to_string()))?;
+
+        let mut query = query_pairs(op, &args);
+        let mut request = self.client.request(method, &url);
+        for param in op.params.iter().filter(|p| p.location == ParamIn::Header) {
+            if let Some(value) = args.get(&param.name) {
+                request = request.header(param.name.as_str(), arg_string(value));
+            }
+        }
+        if let Some(key) = &self.settings.api_key {
+            request = match &spec.auth {
+                AuthPlacement::Header { name, bearer } => {
+                    let value = if *bearer && !key.contains(' ') {
+                        format!("Bearer {}", key)
+                    } else {
+                        key.clone()
+                    };
+                    request.header(name.as_str(), value)
+                }
+                AuthPlacement::Query { name } => {
+                    query.push((name.clone(), key.clone()));
+                    request
+                }
+                AuthPlacement::Basic => request.header(
+                    "Authorization",
+                    format!(
+                        "Basic {}",
+                        base64::engine::general_purpose::STANDARD.encode(key)
+                    ),
+                ),
+            };
+        }
+        if !query.is_empty() {
+            request = request.query(&query);
+        }
+        if op.body.is_some() {
+            if let Some(body) = args.get("body") {
+                request = request.json(body);
+            }
+        }
+
+        let response = request.send().await.map_err(ConnectorError::HttpRequest)?;
+        let status = response.status();
+        let text = response.text().await.map_err(ConnectorError::HttpRequest)?;
+        let data = serde_json::from_str::<Value>(&text).unwrap_or_else(|_| {
+            let mut text = text;
+            if text.len() > MAX_TEXT_RESPONSE {
+                let mut end = MAX_TEXT_RESPONSE;
+                while !text.is_char_boundary(end) {
+                    end -= 1;
+                }
+                text.truncate(end);
+            }
+            Value::String(text)
+        });
+
+        if !status.is_success() {
+            let detail = match &data {
+                Value::String(s) => s.chars().take(500).collect::<String>(),
+                other => other.to_string().chars().take(500).collect(),
+            };
+            return Err(match status {
+                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => ConnectorError::Authentication(
+                    format!("{} {} returned {}: {}", op.method, op.path, status, detail),
+                ),
+                StatusCode::NOT_FOUND => ConnectorError::ResourceNotFound,
+                s => ConnectorError::Other(format!(
+                    "{} {} returned {}: {}",
+                    op.method, op.path, s, detail
+                )),
+            });
+        }
+
+        Ok(json!({
+            "operation": op.tool_name,
+            "method": op.method,
+            "url": url,
+            "status": status.as_u16(),
+            "data": data,
+        }))
+    }
+}
+
+#[async_trait]
+impl Connector for OpenApiConnector {
+    fn name(&self) -> &'static str {
+        "openapi"
+    }
+
+    fn description(&self) -> &'static str {
+        "Any REST API described by an OpenAPI document; selected operations become tools."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        let s = &self.settings;
+        let mut auth = AuthDetails::new();
+        let mut put = |key: &str, value: &Option<String>| {
+            if let Some(v) = value {
+                auth.insert(key.to_string(), v.clone());
+            }
+        };
+        put("spec", &s.spec);
+        put("base_url", &s.base_url);
+        put("api_key", &s.api_key);
+        put("auth_header", &s.auth_header);
+        put("auth_query", &s.auth_query);
+        if !s.operations.is_empty() {
+            auth.insert("operations".to_string(), s.operations.join(","));
+        }
+        if s.allow_writes {
+            auth.insert("allow_writes".to_string(), "true".to_string());
+        }
+        Ok(auth)
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        *self = Self::new(details).await?;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.spec().await.map(|_| ())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        let text = |name: &str, label: &str, required: bool, description: &str| Field {
+            name: name.to_string(),
+            label: label.to_string(),
+            field_type: FieldType::Text,
+            required,
+            description: Some(description.to_string()),
+            options: None,
+        };
+        ConnectorConfigSchema {
+            fields: vec![
+                text(
+                    "spec",
+                    "OpenAPI Spec",
+                    true,
+                    "URL or file path of an OpenAPI 3 / Swagger 2 document (JSON or YAML).",
+                ),
+                text(
+                    "base_url",
+                    "Base URL",
+                    false,
+                    "Overrides the spec's first server URL.",
+                ),
+                text(
+                    "operations",
+                    "Operations",
+                    false,
+                    "Comma-separated operationIds or 'METHOD /path' entries to expose. Default: all GET operations.",
+                ),
+                Field {
+                    name: "allow_writes".to_string(),
+                    label: "Allow Writes".to_string(),
+                    field_type: FieldType::Boolean,
+                    required: false,
+                    description: Some(
+                        "Expose POST/PUT/PATCH/DELETE operations when 'operations' is empty."
+                            .to_string(),
+                    ),
+                    options: None,
+                },
+                Field {
+                    name: "api_key".to_string(),
+                    label: "API Key / Token".to_string(),
+                    field_type: FieldType::Secret,
+                    required: false,
+                    description: Some(
+                        "Sent as the spec's security scheme (bearer, API key header/query, or basic user:pass)."
+                            .to_string(),
+                    ),
+                    options: None,
+                },
+                text(
+                    "auth_header",
+                    "Auth Header",
+                    false,
+                    "Send the key in this header instead of the spec's scheme (Authorization adds Bearer).",
+                ),
+                text(
+                    "auth_query",
+                    "Auth Query Parameter",
+                    false,
+                    "Send the key as this query parameter instead.",
+                ),
+            ],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Tools are generated from the configured OpenAPI document: one per selected \
+operation, named after its operationId. Path, query and header parameters are top-level arguments; \
+JSON request bodies go in `body`. Use `list_operations` to see every operation in the spec and \
+which ones are enabled."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let mut tools = vec![Tool {
+            name: Cow::Borrowed("list_operations"),
+            title: None,
+            description: Some(Cow::Borrowed(
+                "List operations in the configured OpenAPI spec with their tool names and whether \
+they are enabled. Example: enabled_only=false.",
+            )),
+            input_schema: Arc::new(
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "enabled_only": { "type": "boolean", "description": "Only operations exposed as tools (default: false)" }
+                    }
+                })
+                .as_object()
+                .expect("Schema object")
+                .clone(),
+            ),
+            output_schema: None,
+            annotations: None,
+            icons: None,
+        }];
+
+        // A missing or broken spec still lists list_operations, which reports the error
+        if let Ok(spec) = self.spec().await {
+            tools.extend(spec.enabled().map(|op| Tool {
+                name: Cow::Owned(op.tool_name.clone()),
+                title: None,
+                description: Some(Cow::Owned(tool_description(op))),
+                input_schema: Arc::new(
+                    input_schema(op).as_object().expect("Schema object").clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            }));
+        }
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        let spec = self.spec().await?;
+        let args = request.arguments.unwrap_or_default();
+
+        if request.name == "list_operations" {
+            let enabled_only = args
+                .get("enabled_only")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            let operations: Vec<Value> = spec
+                .operations
+                .iter()
+                .filter(|op| op.enabled || !enabled_only)
+                .map(|op| {
+                    json!({
+                        "tool": op.tool_name,
+                        "operation_id": op.operation_id,
+                        "method": op.method,
+                        "path": op.path,
+                        "summary": op.summary,
+                        "enabled": op.enabled,
+                    })
+                })
+                .collect();
+            let data = json!({
+                "title": spec.title,
+                "version": spec.version,
+                "base_url": spec.base_url,
+                "enabled": spec.enabled().count(),
+                "count": operations.len(),
+                "operations": operations,
+            });
+            return structured_result_with_text(&data, None);
+        }
+
+        let op = spec
+            .enabled()
+            .find(|op| op.tool_name == request.name.as_ref())
+            .ok_or(ConnectorError::ToolNotFound)?;
+        if op.body_required && !args.contains_key("body") {
+            return Err(ConnectorError::InvalidParams(format!(
+                "{} requires a JSON 'body'",
+                op.tool_name
+            )));
+        }
+        for param in op.params.iter().filter(|p| p.required) {
+            if !args.contains_key(&param.name) {
+                return Err(ConnectorError::InvalidParams(format!(
+                    "Missing required parameter '{}'",
+                    param.name
+                )));
+            }
+        }
+
+        let data = self.invoke(&spec, op, args).await?;
+        structured_result_with_text(&data, None)
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, _name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn petstore() -> Value {
+        serde_yaml::from_str(
+            r#"
+openapi: 3.0.0
+info: { title: Petstore, version: "1.0" }
+servers:
+  - url: https://{region}.example.com/v1
+    variables:
+      region: { default: eu }
+components:
+  securitySchemes:
+    key: { type: apiKey, in: header, name: X-API-Key }
+  parameters:
+    PetId: { name: petId, in: path, required: true, schema: { type: string } }
+  schemas:
+    Pet:
+      type: object
+      properties:
+        name: { type: string }
+paths:
+  /pets:
+    get:
+      operationId: listPets
+      summary: List pets
+      parameters:
+        - { name: limit, in: query, schema: { type: integer } }
+    post:
+      operationId: createPet
+      requestBody:
+        required: true
+        content:
+          application/json:
+            schema: { $ref: '#/components/schemas/Pet' }
+  /pets/{petId}:
+    parameters:
+      - $ref: '#/components/parameters/PetId'
+    get:
+      operationId: showPetById
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_operations_and_defaults_to_reads() {
+        let spec = parse_spec(&petstore(), &Settings::default(), "spec.yaml");
+        assert_eq!(spec.base_url.as_deref(), Some("https://eu.example.com/v1"));
+        assert_eq!(
+            spec.auth,
+            AuthPlacement::Header {
+                name: "X-API-Key".to_string(),
+                bearer: false
+            }
+        );
+        let enabled: Vec<&str> = spec.enabled().map(|op| op.tool_name.as_str()).collect();
+        assert_eq!(enabled, vec!["list_pets", "show_pet_by_id"]);
+
+        let show = &spec.operations[2];
+        assert_eq!(show.params[0].name, "petId");
+        assert!(show.params[0].required);
+        let create = &spec.operations[1];
+        assert!(create.body_required);
+        assert_eq!(
+            create.body.as_ref().unwrap()["properties"]["name"]["type"],
+            "string"
+        );
+    }
+
+    #[test]
+    fn selects_configured_operations() {
+        let settings = Settings {
+            operations: vec!["createPet".to_string(), "get /pets/{petId}".to_string()],
+            ..Default::default()
+        };
+        let spec = parse_spec(&petstore(), &settings, "spec.yaml");
+        let enabled: Vec<&str> = spec.enabled().map(|op| op.tool_name.as_str()).collect();
+        assert_eq!(enabled, vec!["create_pet", "show_pet_by_id"]);
+    }
+
+    #[test]
+    fn builds_tool_names_and_requests() {
+        assert_eq!(
+            tool_name_for(Some("getHTTPStatus2"), "GET", "/"),
+            "get_httpstatus2"
+        );
+        assert_eq!(
+            tool_name_for(None, "GET", "/users/{id}/repos"),
+            "get_users_id_repos"
+        );
+
+        let spec = parse_spec(&petstore(), &Settings::default(), "spec.yaml");
+        let mut args = Map::new();
+        args.insert("petId".to_string(), json!("a b"));
+        assert_eq!(
+            fill_path(&spec.operations[2], &args).unwrap(),
+            "/pets/a%20b"
+        );
+
+        args.insert("limit".to_string(), json!(5));
+        assert_eq!(
+            query_pairs(&spec.operations[0], &args),
+            vec![("limit".to_string(), "5".to_string())]
+        );
+        let schema = input_schema(&spec.operations[1]);
+        assert_eq!(schema["required"], json!(["body"]));
+    }
+}
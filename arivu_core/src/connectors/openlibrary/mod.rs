@@ -0,0 +1,614 @@
+use crate::capabilities::ConnectorConfigSchema;
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+const API_BASE: &str = "https://openlibrary.org";
+const COVERS_BASE: &str = "https://covers.openlibrary.org";
+const SEARCH_FIELDS: &str = "key,title,subtitle,author_name,author_key,first_publish_year,\
+edition_count,isbn,cover_i,subject,publisher,language,number_of_pages_median";
+
+#[derive(Debug, Deserialize)]
+struct SearchBooksArgs {
+    #[serde(default)]
+    query: Option<String>,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(default)]
+    isbn: Option<String>,
+    #[serde(default)]
+    subject: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    page: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IdArgs {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct EditionArgs {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    isbn: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchAuthorsArgs {
+    query: String,
+    #[serde(default)]
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AuthorWorksArgs {
+    id: String,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    offset: Option<u32>,
+}
+
+pub struct OpenLibraryConnector {
+    client: Client,
+}
+
+impl OpenLibraryConnector {
+    pub async fn new(_auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+
+        Ok(Self { client })
+    }
+
+    async fn get_json(
+        &self,
+        path: &str,
+        params: &[(&str, String)],
+    ) -> Result<Value, ConnectorError> {
+        let url = format!("{}{}", API_BASE, path);
+        let response = self
+            .client
+            .get(&url)
+            .query(params)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(ConnectorError::ResourceNotFound);
+        }
+        if !response.status().is_success() {
+            return Err(ConnectorError::Other(format!(
+                "Open Library API returned error status: {}",
+                response.status()
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| ConnectorError::Other(format!("Failed to parse JSON: {}", e)))
+    }
+
+    /// Resolve `/authors/OL..A` references to display names (best-effort).
+    async fn author_names(&self, refs: &[String]) -> Vec<Value> {
+        let mut authors = Vec::new();
+        for key in refs {
+            let name = self
+                .get_json(&format!("{}.json", key), &[])
+                .await
+                .ok()
+                .and_then(|a| a["name"].as_str().map(str::to_string));
+            authors.push(json!({ "key": key, "name": name }));
+        }
+        authors
+    }
+}
+
+/// Extract an Open Library id (`OL123W`, `OL123M`, `OL123A`) from an id, key path or URL.
+fn olid(input: &str, kind: char) -> Option<String> {
+    input
+        .split(|c: char| c == '/' || c == '?' || c == '#' || c.is_whitespace())
+        .find(|part| {
+            part.len() > 3
+                && part.starts_with("OL")
+                && part.ends_with(kind)
+                && part[2..part.len() - 1].chars().all(|c| c.is_ascii_digit())
+        })
+        .map(str::to_string)
+}
+
+/// Open Library stores free text either as a string or as `{type, value}`.
+fn text_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Object(obj) => obj
+            .get("value")
+            .and_then(|v| v.as_str())
+            .map(str::to_string),
+        _ => None,
+    }
+}
+
+fn cover_url(cover_id: Option<i64>) -> Option<String> {
+    cover_id
+        .filter(|id| *id > 0)
+        .map(|id| format!("{}/b/id/{}-L.jpg", COVERS_BASE, id))
+}
+
+fn format_search_doc(doc: &Value) -> Value {
+    let isbns: Vec<Value> = doc["isbn"]
+        .as_array()
+        .map(|a| a.iter().take(5).cloned().collect())
+        .unwrap_or_default();
+    let subjects: Vec<Value> = doc["subject"]
+        .as_array()
+        .map(|a| a.iter().take(10).cloned().collect())
+        .unwrap_or_default();
+    let key = doc["key"].as_str().unwrap_or_default();
+
+    json!({
+        "work_id": olid(key, 'W'),
+        "title": doc["title"],
+        "subtitle": doc["subtitle"],
+        "authors": doc["author_name"],
+        "author_ids": doc["author_key"],
+        "first_publish_year": doc["first_publish_year"],
+        "edition_count": doc["edition_count"],
+        "pages_median": doc["number_of_pages_median"],
+        "isbn": isbns,
+        "subjects": subjects,
+        "cover_url": cover_url(doc["cover_i"].as_i64()),
+        "url": format!("{}{}", API_BASE, key),
+    })
+}
+
+fn reference_keys(list: &Value, field: Option<&str>) -> Vec<String> {
+    list.as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|item| {
+                    let target = match field {
+                        Some(f) => &item[f],
+                        None => item,
+                    };
+                    target["key"].as_str().map(str::to_string)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[async_trait]
+impl Connector for OpenLibraryConnector {
+    fn name(&self) -> &'static str {
+        "openlibrary"
+    }
+
+    fn description(&self) -> &'static str {
+        "Open Library books: search by title/author/ISBN, work and edition details, author bibliographies."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        Ok(AuthDetails::new())
+    }
+
+    async fn set_auth_details(&mut self, _details: AuthDetails) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema { fields: vec![] }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: Some("https://openlibrary.org/developers/api".to_string()),
+            },
+            instructions: Some(
+                "Use `search_books` (query/title/author/isbn), then `get_work` for the book and \
+`get_edition` for a specific printing. Use `search_authors` + `get_author_works` for bibliographies."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            tool(
+                "search_books",
+                "Search books by free text, title, author, ISBN or subject. \
+Example: title=\"dune\" author=\"herbert\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Free-text query" },
+                        "title": { "type": "string", "description": "Title filter" },
+                        "author": { "type": "string", "description": "Author filter" },
+                        "isbn": { "type": "string", "description": "ISBN-10 or ISBN-13" },
+                        "subject": { "type": "string", "description": "Subject filter" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10, max: 100)" },
+                        "page": { "type": "integer", "description": "Page number (default: 1)" }
+                    }
+                }),
+            ),
+            tool(
+                "get_work",
+                "Work (book) details: description, subjects, authors, first publish date. Example: id=\"OL45804W\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "string", "description": "Work id (OL...W), key or URL" }
+                    },
+                    "required": ["id"]
+                }),
+            ),
+            tool(
+                "get_edition",
+                "Edition details (publisher, pages, ISBNs) by edition id or ISBN. Example: isbn=\"9780441013593\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "string", "description": "Edition id (OL...M), key or URL" },
+                        "isbn": { "type": "string", "description": "ISBN-10 or ISBN-13" }
+                    }
+                }),
+            ),
+            tool(
+                "search_authors",
+                "Find authors and their ids. Example: query=\"ursula le guin\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Author name" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "get_author",
+                "Author profile (bio, dates, top work). Example: id=\"OL26320A\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "string", "description": "Author id (OL...A), key or URL" }
+                    },
+                    "required": ["id"]
+                }),
+            ),
+            tool(
+                "get_author_works",
+                "Author bibliography (works). Example: id=\"OL26320A\" limit=50.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "string", "description": "Author id (OL...A), key or URL" },
+                        "limit": { "type": "integer", "description": "Max works (default: 50, max: 1000)" },
+                        "offset": { "type": "integer", "description": "Offset for pagination" }
+                    },
+                    "required": ["id"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "search_books" => {
+                let args: SearchBooksArgs = parse_args(request.arguments)?;
+                let mut params = vec![
+                    ("fields", SEARCH_FIELDS.to_string()),
+                    ("limit", args.limit.unwrap_or(10).clamp(1, 100).to_string()),
+                    ("page", args.page.unwrap_or(1).max(1).to_string()),
+                ];
+                let filters = [
+                    ("q", args.query),
+                    ("title", args.title),
+                    ("author", args.author),
+                    ("isbn", args.isbn.map(|i| i.replace('-', ""))),
+                    ("subject", args.subject),
+                ];
+                let mut has_filter = false;
+                for (key, value) in filters {
+                    if let Some(v) = value.filter(|v| !v.trim().is_empty()) {
+                        params.push((key, v));
+                        has_filter = true;
+                    }
+                }
+                if !has_filter {
+                    return Err(ConnectorError::InvalidParams(
+                        "Provide at least one of query, title, author, isbn or subject".to_string(),
+                    ));
+                }
+
+                let response = self.get_json("/search.json", &params).await?;
+                let results: Vec<Value> = response["docs"]
+                    .as_array()
+                    .map(|docs| docs.iter().map(format_search_doc).collect())
+                    .unwrap_or_default();
+
+                let data = json!({
+                    "total": response["numFound"],
+                    "count": results.len(),
+                    "results": results,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_work" => {
+                let args: IdArgs = parse_args(request.arguments)?;
+                let id = olid(&args.id, 'W').ok_or_else(|| {
+                    ConnectorError::InvalidParams(format!("Not a work id: {}", args.id))
+                })?;
+                let work = self.get_json(&format!("/works/{}.json", id), &[]).await?;
+                let authors = self
+                    .author_names(&reference_keys(&work["authors"], Some("author")))
+                    .await;
+
+                let data = json!({
+                    "work_id": id,
+                    "title": work["title"],
+                    "subtitle": work["subtitle"],
+                    "authors": authors,
+                    "description": text_value(&work["description"]),
+                    "first_publish_date": work["first_publish_date"],
+                    "subjects": work["subjects"],
+                    "subject_places": work["subject_places"],
+                    "subject_times": work["subject_times"],
+                    "cover_url": cover_url(work["covers"][0].as_i64()),
+                    "url": format!("{}/works/{}", API_BASE, id),
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_edition" => {
+                let args: EditionArgs = parse_args(request.arguments)?;
+                let path = match (args.id, args.isbn) {
+                    (Some(id), _) => {
+                        let id = olid(&id, 'M').ok_or_else(|| {
+                            ConnectorError::InvalidParams(format!("Not an edition id: {}", id))
+                        })?;
+                        format!("/books/{}.json", id)
+                    }
+                    (None, Some(isbn)) => format!("/isbn/{}.json", isbn.replace('-', "").trim()),
+                    (None, None) => {
+                        return Err(ConnectorError::InvalidParams(
+                            "Provide either 'id' or 'isbn'".to_string(),
+                        ))
+                    }
+                };
+
+                let edition = self.get_json(&path, &[]).await?;
+                let edition_key = edition["key"].as_str().unwrap_or_default();
+                let authors = self
+                    .author_names(&reference_keys(&edition["authors"], None))
+                    .await;
+                let works: Vec<Option<String>> = reference_keys(&edition["works"], None)
+                    .iter()
+                    .map(|k| olid(k, 'W'))
+                    .collect();
+
+                let data = json!({
+                    "edition_id": olid(edition_key, 'M'),
+                    "work_ids": works,
+                    "title": edition["title"],
+                    "subtitle": edition["subtitle"],
+                    "authors": authors,
+                    "publishers": edition["publishers"],
+                    "publish_date": edition["publish_date"],
+                    "number_of_pages": edition["number_of_pages"],
+                    "isbn_10": edition["isbn_10"],
+                    "isbn_13": edition["isbn_13"],
+                    "physical_format": edition["physical_format"],
+                    "description": text_value(&edition["description"]),
+                    "cover_url": cover_url(edition["covers"][0].as_i64()),
+                    "url": format!("{}{}", API_BASE, edition_key),
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "search_authors" => {
+                let args: SearchAuthorsArgs = parse_args(request.arguments)?;
+                let params = [
+                    ("q", args.query.clone()),
+                    ("limit", args.limit.unwrap_or(10).clamp(1, 100).to_string()),
+                ];
+                let response = self.get_json("/search/authors.json", &params).await?;
+                let results: Vec<Value> = response["docs"]
+                    .as_array()
+                    .map(|docs| {
+                        docs.iter()
+                            .map(|d| {
+                                json!({
+                                    "author_id": d["key"],
+                                    "name": d["name"],
+                                    "birth_date": d["birth_date"],
+                                    "death_date": d["death_date"],
+                                    "top_work": d["top_work"],
+                                    "work_count": d["work_count"],
+                                })
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let data = json!({
+                    "query": args.query,
+                    "total": response["numFound"],
+                    "count": results.len(),
+                    "results": results,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_author" => {
+                let args: IdArgs = parse_args(request.arguments)?;
+                let id = olid(&args.id, 'A').ok_or_else(|| {
+                    ConnectorError::InvalidParams(format!("Not an author id: {}", args.id))
+                })?;
+                let author = self.get_json(&format!("/authors/{}.json", id), &[]).await?;
+
+                let data = json!({
+                    "author_id": id,
+                    "name": author["name"],
+                    "personal_name": author["personal_name"],
+                    "alternate_names": author["alternate_names"],
+                    "birth_date": author["birth_date"],
+                    "death_date": author["death_date"],
+                    "bio": text_value(&author["bio"]),
+                    "wikipedia": author["wikipedia"],
+                    "links": author["links"],
+                    "url": format!("{}/authors/{}", API_BASE, id),
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_author_works" => {
+                let args: AuthorWorksArgs = parse_args(request.arguments)?;
+                let id = olid(&args.id, 'A').ok_or_else(|| {
+                    ConnectorError::InvalidParams(format!("Not an author id: {}", args.id))
+                })?;
+                let params = [
+                    ("limit", args.limit.unwrap_or(50).clamp(1, 1000).to_string()),
+                    ("offset", args.offset.unwrap_or(0).to_string()),
+                ];
+                let response = self
+                    .get_json(&format!("/authors/{}/works.json", id), &params)
+                    .await?;
+                let works: Vec<Value> = response["entries"]
+                    .as_array()
+                    .map(|entries| {
+                        entries
+                            .iter()
+                            .map(|w| {
+                                let key = w["key"].as_str().unwrap_or_default();
+                                json!({
+                                    "work_id": olid(key, 'W'),
+                                    "title": w["title"],
+                                    "first_publish_date": w["first_publish_date"],
+                                    "subjects": w["subjects"],
+                                })
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let data = json!({
+                    "author_id": id,
+                    "total": response["size"],
+                    "count": works.len(),
+                    "works": works,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_olids() {
+        assert_eq!(olid("OL45804W", 'W').as_deref(), Some("OL45804W"));
+        assert_eq!(olid("/works/OL45804W", 'W').as_deref(), Some("OL45804W"));
+        assert_eq!(
+            olid(
+                "https://openlibrary.org/works/OL45804W/Fantastic_Mr_Fox",
+                'W'
+            )
+            .as_deref(),
+            Some("OL45804W")
+        );
+        assert_eq!(olid("/authors/OL34184A", 'A').as_deref(), Some("OL34184A"));
+        assert_eq!(olid("/authors/OL34184A", 'W'), None);
+    }
+
+    #[test]
+    fn reads_text_values() {
+        assert_eq!(text_value(&json!("plain")).as_deref(), Some("plain"));
+        assert_eq!(
+            text_value(&json!({"type": "/type/text", "value": "typed"})).as_deref(),
+            Some("typed")
+        );
+        assert_eq!(text_value(&Value::Null), None);
+    }
+}
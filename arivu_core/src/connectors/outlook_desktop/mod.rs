@@ -0,0 +1,742 @@
+// Outlook Desktop Connector - classic Outlook for Windows via COM/MAPI
+// Windows only - uses the local Outlook profile with all of its accounts
+//
+// Mirrors the Apple Mail connector: where that one drives Mail.app with
+// AppleScript, this one drives `Outlook.Application` through PowerShell COM
+// automation and reads JSON back. No separate credentials are needed.
+//
+// Notes:
+// - Requires classic Outlook (Office/Microsoft 365 desktop). The new Outlook for
+//   Windows has no COM interface.
+// - Outlook's object model guard may prompt before exposing addresses and bodies
+//   when no up-to-date antivirus is registered.
+
+use crate::error::ConnectorError;
+use crate::utils::structured_result_with_text;
+use async_trait::async_trait;
+use base64::Engine;
+use rmcp::model::*;
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::process::Stdio;
+use std::sync::Arc;
+
+/// Outlook Desktop connector - interact with classic Outlook via COM
+#[derive(Default)]
+pub struct OutlookDesktopConnector;
+
+impl OutlookDesktopConnector {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Marker written to stderr when the Outlook COM server cannot be created.
+const UNAVAILABLE_MARKER: &str = "ARIVU_OUTLOOK_UNAVAILABLE";
+
+// ============================================================================
+// PowerShell Execution
+// ============================================================================
+
+/// Run a PowerShell script and return its stdout.
+///
+/// The script is passed with `-EncodedCommand` (UTF-16LE, base64) so multi-line
+/// functions and quoting survive intact.
+async fn run_powershell(script: &str) -> Result<String, ConnectorError> {
+    use tokio::process::Command;
+
+    let utf16: Vec<u8> = script
+        .encode_utf16()
+        .flat_map(|u| u.to_le_bytes())
+        .collect();
+    let encoded = base64::engine::general_purpose::STANDARD.encode(utf16);
+
+    let output = Command::new("powershell.exe")
+        .args([
+            "-NoProfile",
+            "-NonInteractive",
+            "-ExecutionPolicy",
+            "Bypass",
+            "-EncodedCommand",
+        ])
+        .arg(encoded)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| ConnectorError::Other(format!("Failed to run PowerShell: {}", e)))?;
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if stderr.contains(UNAVAILABLE_MARKER) {
+        return Err(ConnectorError::Other(
+            "Outlook is not available. Install classic Outlook for Windows and sign in to a profile; the new Outlook app has no COM interface.".to_string(),
+        ));
+    }
+    if !output.status.success() {
+        return Err(ConnectorError::Other(format!(
+            "Outlook script failed: {}",
+            stderr.trim()
+        )));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .trim_start_matches('\u{feff}')
+        .trim()
+        .to_string())
+}
+
+/// Run a script that prints JSON and parse the result.
+async fn run_outlook_json(body: &str) -> Result<Value, ConnectorError> {
+    let script = format!("{}\n{}", SCRIPT_PRELUDE, body);
+    let stdout = run_powershell(&script).await?;
+    if stdout.is_empty() {
+        return Ok(Value::Null);
+    }
+    serde_json::from_str(&stdout)
+        .map_err(|e| ConnectorError::Other(format!("Failed to parse Outlook output: {}", e)))
+}
+
+/// Quote a value as a PowerShell single-quoted string literal. PowerShell also
+/// ends these literals at the typographic quotes U+2018-U+201B, so double those too.
+fn ps_quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('\'');
+    for c in s.chars() {
+        if matches!(c, '\'' | '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}') {
+            quoted.push(c);
+        }
+        quoted.push(c);
+    }
+    quoted.push('\'');
+    quoted
+}
+
+/// Shared setup: UTF-8 output, the MAPI namespace, folder lookup and message
+/// summaries. Folder paths look like `\\user@example.com\Inbox\Receipts`; the
+/// bare names inbox/sent/drafts/deleted/junk/outbox map to the default folders.
+const SCRIPT_PRELUDE: &str = r#"
+$ErrorActionPreference = 'Stop'
+[Console]::OutputEncoding = [System.Text.Encoding]::UTF8
+try {
+    $outlook = New-Object -ComObject Outlook.Application
+    $ns = $outlook.GetNamespace('MAPI')
+} catch {
+    [Console]::Error.WriteLine('ARIVU_OUTLOOK_UNAVAILABLE')
+    exit 3
+}
+
+function Get-Folder([string]$path) {
+    switch ($path.ToLower()) {
+        '' { return $ns.GetDefaultFolder(6) }
+        'inbox' { return $ns.GetDefaultFolder(6) }
+        'sent' { return $ns.GetDefaultFolder(5) }
+        'drafts' { return $ns.GetDefaultFolder(16) }
+        'deleted' { return $ns.GetDefaultFolder(3) }
+        'junk' { return $ns.GetDefaultFolder(23) }
+        'outbox' { return $ns.GetDefaultFolder(4) }
+    }
+    $parts = $path.Trim('\').Split('\')
+    $folder = $null
+    foreach ($store in $ns.Folders) { if ($store.Name -eq $parts[0]) { $folder = $store; break } }
+    for ($i = 1; $folder -and $i -lt $parts.Length; $i++) {
+        $next = $null
+        foreach ($f in $folder.Folders) { if ($f.Name -eq $parts[$i]) { $next = $f; break } }
+        $folder = $next
+    }
+    if (-not $folder) { throw "Folder not found: $path (use list_folders for paths)" }
+    return $folder
+}
+
+function Get-SenderEmail($m) {
+    if ($m.SenderEmailType -eq 'EX') {
+        try { $u = $m.Sender.GetExchangeUser(); if ($u) { return $u.PrimarySmtpAddress } } catch {}
+    }
+    return $m.SenderEmailAddress
+}
+
+function ConvertTo-Summary($m) {
+    [pscustomobject]@{
+        id = $m.EntryID
+        store_id = $m.Parent.StoreID
+        subject = $m.Subject
+        sender = $m.SenderName
+        sender_email = (Get-SenderEmail $m)
+        to = $m.To
+        received = $m.ReceivedTime.ToString('s')
+        is_read = -not $m.UnRead
+        is_flagged = ($m.FlagStatus -eq 2)
+        has_attachments = ($m.Attachments.Count -gt 0)
+        folder = $m.Parent.FolderPath
+    }
+}
+
+function Select-Messages($items, [int]$limit, [bool]$unreadOnly) {
+    $items.Sort('[ReceivedTime]', $true)
+    $out = New-Object System.Collections.ArrayList
+    foreach ($m in $items) {
+        if ($m.Class -ne 43) { continue }
+        if ($unreadOnly -and -not $m.UnRead) { continue }
+        [void]$out.Add((ConvertTo-Summary $m))
+        if ($out.Count -ge $limit) { break }
+    }
+    return ,$out
+}
+
+function Get-MailFolders($folder, [int]$depth) {
+    $result = New-Object System.Collections.ArrayList
+    foreach ($f in $folder.Folders) {
+        if ($f.DefaultItemType -ne 0) { continue }
+        [void]$result.Add($f)
+        if ($depth -gt 0) { foreach ($c in (Get-MailFolders $f ($depth - 1))) { [void]$result.Add($c) } }
+    }
+    return ,$result
+}
+"#;
+
+// ============================================================================
+// Script Generators
+// ============================================================================
+
+fn script_list_accounts() -> String {
+    r#"
+$out = foreach ($a in $ns.Accounts) {
+    [pscustomobject]@{
+        name = $a.DisplayName
+        email = $a.SmtpAddress
+        type = $a.AccountType
+        store = $a.DeliveryStore.DisplayName
+    }
+}
+ConvertTo-Json -InputObject @($out) -Depth 3 -Compress
+"#
+    .to_string()
+}
+
+fn script_list_folders(account: Option<&str>, depth: u32) -> String {
+    format!(
+        r#"
+$account = {}
+$out = New-Object System.Collections.ArrayList
+foreach ($store in $ns.Folders) {{
+    if ($account -and $store.Name -ne $account) {{ continue }}
+    foreach ($f in (Get-MailFolders $store {})) {{
+        [void]$out.Add([pscustomobject]@{{
+            name = $f.Name
+            path = $f.FolderPath
+            account = $store.Name
+            unread_count = $f.UnReadItemCount
+            message_count = $f.Items.Count
+        }})
+    }}
+}}
+ConvertTo-Json -InputObject @($out) -Depth 3 -Compress
+"#,
+        ps_quote(account.unwrap_or("")),
+        depth
+    )
+}
+
+fn script_list_messages(folder: &str, limit: usize, unread_only: bool) -> String {
+    format!(
+        r#"
+$folder = Get-Folder {}
+$out = Select-Messages $folder.Items {} ${}
+ConvertTo-Json -InputObject @($out) -Depth 3 -Compress
+"#,
+        ps_quote(folder),
+        limit,
+        unread_only
+    )
+}
+
+fn script_get_message(id: &str, store_id: Option<&str>) -> String {
+    let lookup = match store_id {
+        Some(store) => format!("$ns.GetItemFromID({}, {})", ps_quote(id), ps_quote(store)),
+        None => format!("$ns.GetItemFromID({})", ps_quote(id)),
+    };
+    format!(
+        r#"
+$m = {}
+$msg = ConvertTo-Summary $m
+$msg | Add-Member -NotePropertyName cc -NotePropertyValue $m.CC
+$msg | Add-Member -NotePropertyName categories -NotePropertyValue $m.Categories
+$msg | Add-Member -NotePropertyName attachments -NotePropertyValue @(foreach ($a in $m.Attachments) {{ $a.FileName }})
+$msg | Add-Member -NotePropertyName body -NotePropertyValue $m.Body
+ConvertTo-Json -InputObject $msg -Depth 3 -Compress
+"#,
+        lookup
+    )
+}
+
+/// DASL filter matching `query` in subject, body, sender name or sender address.
+fn dasl_text_filter(query: &str) -> String {
+    // DASL string literals are single-quoted; `%` cannot be escaped, so drop it.
+    let q = query.replace('\'', "''").replace('%', "");
+    let fields = [
+        "urn:schemas:httpmail:subject",
+        "urn:schemas:httpmail:textdescription",
+        "urn:schemas:httpmail:fromname",
+        "urn:schemas:httpmail:fromemail",
+    ];
+    let clauses: Vec<String> = fields
+        .iter()
+        .map(|f| format!("\"{}\" LIKE '%{}%'", f, q))
+        .collect();
+    format!("@SQL=({})", clauses.join(" OR "))
+}
+
+fn script_search(
+    query: &str,
+    folder: Option<&str>,
+    all_folders: bool,
+    since: Option<&str>,
+    limit: usize,
+) -> String {
+    format!(
+        r#"
+$filter = {}
+$since = {}
+$folders = New-Object System.Collections.ArrayList
+if (${}) {{
+    foreach ($store in $ns.Folders) {{ foreach ($f in (Get-MailFolders $store 8)) {{ [void]$folders.Add($f) }} }}
+}} else {{
+    [void]$folders.Add((Get-Folder {}))
+}}
+$out = New-Object System.Collections.ArrayList
+foreach ($f in $folders) {{
+    $items = $f.Items
+    if ($since) {{
+        $d = [datetime]::Parse($since, [Globalization.CultureInfo]::InvariantCulture)
+        $items = $items.Restrict("[ReceivedTime] >= '" + $d.ToString('g') + "'")
+    }}
+    $items = $items.Restrict($filter)
+    foreach ($m in (Select-Messages $items ({} - $out.Count) $false)) {{ [void]$out.Add($m) }}
+    if ($out.Count -ge {}) {{ break }}
+}}
+$sorted = @($out | Sort-Object -Property received -Descending)
+ConvertTo-Json -InputObject $sorted -Depth 3 -Compress
+"#,
+        ps_quote(&dasl_text_filter(query)),
+        ps_quote(since.unwrap_or("")),
+        all_folders,
+        ps_quote(folder.unwrap_or("")),
+        limit,
+        limit
+    )
+}
+
+/// Validate a `since` argument and normalise it to `YYYY-MM-DDTHH:MM:SS`.
+fn normalize_since(value: &str) -> Result<String, ConnectorError> {
+    let value = value.trim();
+    let parsed = chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
+        .or_else(|_| chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M"))
+        .or_else(|_| {
+            chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map(|d| d.and_hms_opt(0, 0, 0).unwrap_or_default())
+        })
+        .map_err(|_| {
+            ConnectorError::InvalidParams(
+                "Invalid 'since': expected YYYY-MM-DD or YYYY-MM-DDTHH:MM".to_string(),
+            )
+        })?;
+    Ok(parsed.format("%Y-%m-%dT%H:%M:%S").to_string())
+}
+
+/// Truncate the `body` field of a message to `max_len` characters.
+fn truncate_body(message: &mut Value, max_len: usize) {
+    let Some(body) = message.get("body").and_then(|b| b.as_str()) else {
+        return;
+    };
+    if body.chars().count() > max_len {
+        let truncated: String = body.chars().take(max_len).collect();
+        message["body"] = json!(truncated);
+        message["truncated"] = json!(true);
+    } else {
+        message["truncated"] = json!(false);
+    }
+}
+
+fn as_list(value: Value) -> Value {
+    match value {
+        Value::Null => json!([]),
+        Value::Array(_) => value,
+        other => json!([other]),
+    }
+}
+
+// ============================================================================
+// Connector Implementation
+// ============================================================================
+
+#[async_trait]
+impl crate::Connector for OutlookDesktopConnector {
+    fn name(&self) -> &'static str {
+        "outlook-desktop"
+    }
+
+    fn description(&self) -> &'static str {
+        "Classic Outlook for Windows connector (COM/MAPI). Access every account in the local Outlook profile without separate credentials: list folders, list and search messages, and read message bodies."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<crate::auth::AuthDetails, ConnectorError> {
+        Ok(crate::auth::AuthDetails::new())
+    }
+
+    async fn set_auth_details(
+        &mut self,
+        _details: crate::auth::AuthDetails,
+    ) -> Result<(), ConnectorError> {
+        Ok(()) // No auth needed - uses the Outlook profile's accounts
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        // Just verify the Outlook COM server starts and has a profile
+        let _ = run_outlook_json("ConvertTo-Json -InputObject $ns.CurrentProfileName").await?;
+        Ok(())
+    }
+
+    fn config_schema(&self) -> crate::capabilities::ConnectorConfigSchema {
+        crate::capabilities::ConnectorConfigSchema { fields: vec![] }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: Some("Outlook Desktop".to_string()),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Native classic Outlook integration on Windows. Works with all accounts in the Outlook profile. Outlook may show a security prompt the first time addresses or bodies are read."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            Tool {
+                name: Cow::Borrowed("list_accounts"),
+                title: Some("List Accounts".to_string()),
+                description: Some(Cow::Borrowed(
+                    "List accounts in the Outlook profile (requires explicit user permission).",
+                )),
+                input_schema: Arc::new(
+                    json!({ "type": "object", "properties": {} })
+                        .as_object()
+                        .unwrap()
+                        .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("list_folders"),
+                title: Some("List Folders".to_string()),
+                description: Some(Cow::Borrowed(
+                    "List mail folders with unread/total counts (requires explicit user permission). \
+Returns folder paths like \\\\user@example.com\\Inbox for use with list_messages and search.",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "account": {
+                                "type": "string",
+                                "description": "Optional store/account name filter (e.g., \"user@example.com\")."
+                            },
+                            "depth": {
+                                "type": "integer",
+                                "default": 2,
+                                "description": "Subfolder levels to include below the top level."
+                            }
+                        }
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("list_messages"),
+                title: Some("List Messages".to_string()),
+                description: Some(Cow::Borrowed(
+                    "List the newest messages in a folder (requires explicit user permission). \
+Example: folder=\"inbox\" unread_only=true limit=10.",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "folder": {
+                                "type": "string",
+                                "description": "Folder path from list_folders, or inbox/sent/drafts/deleted/junk/outbox. Default: inbox."
+                            },
+                            "unread_only": { "type": "boolean", "default": false },
+                            "limit": { "type": "integer", "default": 20, "description": "Max results (max 100)." }
+                        }
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_message"),
+                title: Some("Read Message".to_string()),
+                description: Some(Cow::Borrowed(
+                    "Read a message's headers, recipients, attachment names and plain-text body \
+(requires explicit user permission). Pass id and store_id from list_messages or search.",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "id": { "type": "string", "description": "Message EntryID." },
+                            "store_id": { "type": "string", "description": "StoreID of the message's store (recommended)." },
+                            "max_content_length": { "type": "integer", "default": 10000 }
+                        },
+                        "required": ["id"]
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("search"),
+                title: Some("Search Emails".to_string()),
+                description: Some(Cow::Borrowed(
+                    "Search messages by keyword in subject, body and sender (requires explicit user \
+permission). Searches the inbox unless folder or all_folders is given. Example: query=\"invoice\" since=\"2025-01-01\".",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "query": { "type": "string", "description": "Keyword query." },
+                            "folder": { "type": "string", "description": "Optional folder path or well-known name." },
+                            "all_folders": { "type": "boolean", "default": false, "description": "Search every mail folder in every store (slower)." },
+                            "since": { "type": "string", "description": "Only messages received on/after this date (YYYY-MM-DD)." },
+                            "limit": { "type": "integer", "default": 20, "description": "Max results (max 100)." }
+                        },
+                        "required": ["query"]
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        let name = request.name.as_ref();
+        let args = request.arguments.unwrap_or_default();
+        let limit = args
+            .get("limit")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(20)
+            .clamp(1, 100) as usize;
+
+        match name {
+            "list_accounts" => {
+                let accounts = as_list(run_outlook_json(&script_list_accounts()).await?);
+                structured_result_with_text(&accounts, None)
+            }
+
+            "list_folders" => {
+                let account = args.get("account").and_then(|v| v.as_str());
+                let depth = args
+                    .get("depth")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(2)
+                    .min(8) as u32;
+                let folders =
+                    as_list(run_outlook_json(&script_list_folders(account, depth)).await?);
+                structured_result_with_text(&folders, None)
+            }
+
+            "list_messages" => {
+                let folder = args.get("folder").and_then(|v| v.as_str()).unwrap_or("");
+                let unread_only = args
+                    .get("unread_only")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let messages = as_list(
+                    run_outlook_json(&script_list_messages(folder, limit, unread_only)).await?,
+                );
+                structured_result_with_text(&messages, None)
+            }
+
+            "get_message" => {
+                let id = args
+                    .get("id")
+                    .or_else(|| args.get("message_id"))
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| ConnectorError::InvalidParams("Missing 'id'".to_string()))?;
+                let store_id = args.get("store_id").and_then(|v| v.as_str());
+                let max_len = args
+                    .get("max_content_length")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(10000) as usize;
+
+                let mut message = run_outlook_json(&script_get_message(id, store_id)).await?;
+                truncate_body(&mut message, max_len);
+                structured_result_with_text(&message, None)
+            }
+
+            "search" => {
+                let query = args
+                    .get("query")
+                    .and_then(|v| v.as_str())
+                    .map(str::trim)
+                    .filter(|q| !q.is_empty())
+                    .ok_or_else(|| ConnectorError::InvalidParams("Missing 'query'".to_string()))?;
+                let folder = args.get("folder").and_then(|v| v.as_str());
+                let all_folders = args
+                    .get("all_folders")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let since = args
+                    .get("since")
+                    .and_then(|v| v.as_str())
+                    .map(normalize_since)
+                    .transpose()?;
+
+                let messages = as_list(
+                    run_outlook_json(&script_search(
+                        query,
+                        folder,
+                        all_folders,
+                        since.as_deref(),
+                        limit,
+                    ))
+                    .await?,
+                );
+                structured_result_with_text(&messages, None)
+            }
+
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, _name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_dasl_filter() {
+        let filter = dasl_text_filter("O'Brien 100%");
+        assert!(filter.starts_with("@SQL=(\"urn:schemas:httpmail:subject\" LIKE '%O''Brien 100%'"));
+        assert_eq!(filter.matches(" OR ").count(), 3);
+        assert_eq!(ps_quote("it's"), "'it''s'");
+        // A lone ’ would close the literal and run the rest as script
+        assert_eq!(
+            ps_quote("x’; Remove-Item C:\\ -Recurse; ’"),
+            "'x’’; Remove-Item C:\\ -Recurse; ’’'"
+        );
+    }
+
+    #[test]
+    fn quotes_every_powershell_quote_character() {
+        for quote in ['\'', '\u{2018}', '\u{2019}', '\u{201A}', '\u{201B}'] {
+            let quoted = ps_quote(&format!("a{}b", quote));
+            assert_eq!(quoted, format!("'a{}{}b'", quote, quote));
+        }
+    }
+
+    #[test]
+    fn normalizes_since() {
+        assert_eq!(
+            normalize_since("2025-01-31").unwrap(),
+            "2025-01-31T00:00:00"
+        );
+        assert_eq!(
+            normalize_since("2025-01-31T08:30").unwrap(),
+            "2025-01-31T08:30:00"
+        );
+        assert!(normalize_since("last week").is_err());
+    }
+
+    #[test]
+    fn truncates_body() {
+        let mut msg = json!({"subject": "hi", "body": "héllo world"});
+        truncate_body(&mut msg, 5);
+        assert_eq!(msg["body"], "héllo");
+        assert_eq!(msg["truncated"], true);
+        assert_eq!(as_list(Value::Null), json!([]));
+        assert_eq!(as_list(json!({"a": 1})), json!([{"a": 1}]));
+    }
+}
@@ -0,0 +1,777 @@
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+const API_BASE: &str = "https://api.pagerduty.com";
+const PAGE_SIZE: usize = 100;
+/// Incidents scanned when filtering by text; the API has no full-text search.
+const MAX_SCAN: usize = 1000;
+
+#[derive(Debug, Deserialize)]
+struct ListIncidentsArgs {
+    #[serde(default)]
+    query: Option<String>,
+    #[serde(default)]
+    statuses: Option<Vec<String>>,
+    #[serde(default)]
+    urgency: Option<String>,
+    #[serde(default)]
+    service: Option<String>,
+    #[serde(default)]
+    team: Option<String>,
+    #[serde(default)]
+    since: Option<String>,
+    #[serde(default)]
+    until: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetIncidentArgs {
+    incident: String,
+    #[serde(default)]
+    include_timeline: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListSchedulesArgs {
+    #[serde(default)]
+    query: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OnCallArgs {
+    #[serde(default)]
+    schedule: Option<String>,
+    #[serde(default)]
+    escalation_policy: Option<String>,
+    #[serde(default)]
+    since: Option<String>,
+    #[serde(default)]
+    until: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetScheduleArgs {
+    schedule: String,
+    #[serde(default)]
+    since: Option<String>,
+    #[serde(default)]
+    until: Option<String>,
+}
+
+/// PagerDuty object ids are 7+ uppercase alphanumerics starting with `P` or `Q`
+/// (e.g. `PABC123`, `Q0RIJJZL24RC6W`); anything else is treated as a name.
+fn looks_like_id(input: &str) -> bool {
+    input.len() >= 7
+        && input.starts_with(['P', 'Q'])
+        && input
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+/// Incident id or number from an id, `#1234`, or an incident URL.
+fn parse_incident_ref(input: &str) -> Option<String> {
+    let input = input.trim();
+    let input = match input.split_once("/incidents/") {
+        Some((_, rest)) => rest.split(['/', '?', '#']).next()?,
+        None => input.trim_start_matches('#'),
+    };
+    let valid = !input.is_empty() && input.chars().all(|c| c.is_ascii_alphanumeric());
+    valid.then(|| input.to_string())
+}
+
+fn format_incident(incident: &Value) -> Value {
+    json!({
+        "id": incident["id"],
+        "number": incident["incident_number"],
+        "title": incident["title"],
+        "status": incident["status"],
+        "urgency": incident["urgency"],
+        "priority": incident["priority"]["summary"],
+        "service": incident["service"]["summary"],
+        "created_at": incident["created_at"],
+        "last_status_change_at": incident["last_status_change_at"],
+        "assignees": incident["assignments"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|a| a["assignee"]["summary"].as_str())
+            .collect::<Vec<_>>(),
+        "escalation_policy": incident["escalation_policy"]["summary"],
+        "url": incident["html_url"],
+    })
+}
+
+/// Timeline entry from a log entry (`trigger_log_entry`, `acknowledge_log_entry`, …).
+fn format_log_entry(entry: &Value) -> Value {
+    let kind = entry["type"]
+        .as_str()
+        .unwrap_or_default()
+        .trim_end_matches("_reference")
+        .trim_end_matches("_log_entry");
+    json!({
+        "at": entry["created_at"],
+        "type": kind,
+        "summary": entry["summary"],
+        "agent": entry["agent"]["summary"],
+        "channel": entry["channel"]["type"],
+    })
+}
+
+fn format_oncall(oncall: &Value) -> Value {
+    json!({
+        "user": oncall["user"]["summary"],
+        "user_id": oncall["user"]["id"],
+        "schedule": oncall["schedule"]["summary"],
+        "escalation_policy": oncall["escalation_policy"]["summary"],
+        "escalation_level": oncall["escalation_level"],
+        "start": oncall["start"],
+        "end": oncall["end"],
+    })
+}
+
+fn matches_query(incident: &Value, query: &str) -> bool {
+    let query = query.to_lowercase();
+    ["title", "description", "summary"]
+        .iter()
+        .filter_map(|key| incident[*key].as_str())
+        .chain(incident["service"]["summary"].as_str())
+        .any(|text| text.to_lowercase().contains(&query))
+        || incident["incident_number"].to_string() == query.trim_start_matches('#')
+}
+
+pub struct PagerDutyConnector {
+    client: Client,
+    token: Option<String>,
+}
+
+impl PagerDutyConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+        let token = auth
+            .get("api_key")
+            .or_else(|| auth.get("token"))
+            .cloned()
+            .or_else(|| std::env::var("PAGERDUTY_API_KEY").ok())
+            .or_else(|| std::env::var("PAGERDUTY_TOKEN").ok())
+            .filter(|t| !t.trim().is_empty());
+
+        Ok(Self { client, token })
+    }
+
+    async fn get(&self, path: &str, params: &[(String, String)]) -> Result<Value, ConnectorError> {
+        let token = self.token.as_deref().ok_or_else(|| {
+            ConnectorError::Authentication(
+                "PagerDuty API key not configured: arivu config set pagerduty --value <key>"
+                    .to_string(),
+            )
+        })?;
+
+        let response = self
+            .client
+            .get(format!("{}/{}", API_BASE, path))
+            .query(params)
+            .header("Authorization", format!("Token token={}", token))
+            .header("Accept", "application/vnd.pagerduty+json;version=2")
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+
+        let status = response.status();
+        if status.is_success() {
+            return response.json().await.map_err(ConnectorError::HttpRequest);
+        }
+        let body: Value = response.json().await.unwrap_or(Value::Null);
+        let mut message = body["error"]["message"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+        if let Some(errors) = body["error"]["errors"].as_array() {
+            let details: Vec<&str> = errors.iter().filter_map(Value::as_str).collect();
+            if !details.is_empty() {
+                message = format!("{} ({})", message, details.join("; "));
+            }
+        }
+        Err(match status {
+            StatusCode::NOT_FOUND => ConnectorError::ResourceNotFound,
+            StatusCode::UNAUTHORIZED => {
+                ConnectorError::Authentication(format!("PagerDuty rejected the key: {}", message))
+            }
+            StatusCode::FORBIDDEN => ConnectorError::Authentication(format!(
+                "PagerDuty key lacks access to this resource: {}",
+                message
+            )),
+            StatusCode::BAD_REQUEST => {
+                ConnectorError::InvalidParams(format!("PagerDuty: {}", message))
+            }
+            StatusCode::TOO_MANY_REQUESTS => {
+                ConnectorError::Other("PagerDuty rate limit exceeded; retry shortly".to_string())
+            }
+            s => ConnectorError::Other(format!(
+                "PagerDuty API returned error status: {} {}",
+                s, message
+            )),
+        })
+    }
+
+    /// Follow offset pagination for a list endpoint until `limit` items are collected.
+    async fn get_all(
+        &self,
+        path: &str,
+        key: &str,
+        params: &[(String, String)],
+        limit: usize,
+    ) -> Result<(Vec<Value>, bool), ConnectorError> {
+        let mut items: Vec<Value> = Vec::new();
+        loop {
+            let mut page = params.to_vec();
+            page.push(("limit".to_string(), PAGE_SIZE.to_string()));
+            page.push(("offset".to_string(), items.len().to_string()));
+            let body = self.get(path, &page).await?;
+            let batch = body[key].as_array().cloned().unwrap_or_default();
+            let fetched = batch.len();
+            items.extend(batch);
+            let more = body["more"].as_bool().unwrap_or(false);
+            if !more || fetched == 0 || items.len() >= limit {
+                items.truncate(limit);
+                return Ok((items, more));
+            }
+        }
+    }
+
+    /// Id for a service, team, schedule or escalation policy given its id or name.
+    async fn resolve_id(&self, collection: &str, input: &str) -> Result<String, ConnectorError> {
+        let input = input.trim();
+        if looks_like_id(input) {
+            return Ok(input.to_string());
+        }
+        let body = self
+            .get(collection, &[("query".to_string(), input.to_string())])
+            .await?;
+        let candidates = body[collection].as_array().cloned().unwrap_or_default();
+        candidates
+            .iter()
+            .find(|c| {
+                c["name"]
+                    .as_str()
+                    .is_some_and(|n| n.eq_ignore_ascii_case(input))
+            })
+            .or_else(|| candidates.first())
+            .and_then(|c| c["id"].as_str())
+            .map(str::to_string)
+            .ok_or_else(|| {
+                ConnectorError::InvalidParams(format!(
+                    "Nothing in {} matches '{}'",
+                    collection.replace('_', " "),
+                    input
+                ))
+            })
+    }
+}
+
+#[async_trait]
+impl Connector for PagerDutyConnector {
+    fn name(&self) -> &'static str {
+        "pagerduty"
+    }
+
+    fn description(&self) -> &'static str {
+        "PagerDuty incidents (search, notes, timeline) and on-call schedules."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        let mut auth = AuthDetails::new();
+        if let Some(token) = &self.token {
+            auth.insert("api_key".to_string(), token.clone());
+        }
+        Ok(auth)
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        *self = Self::new(details).await?;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.get("abilities", &[]).await.map(|_| ())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema {
+            fields: vec![Field {
+                name: "api_key".to_string(),
+                label: "API Key".to_string(),
+                field_type: FieldType::Secret,
+                required: true,
+                description: Some(
+                    "PagerDuty REST API key (read-only is enough), or set PAGERDUTY_API_KEY."
+                        .to_string(),
+                ),
+                options: None,
+            }],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Use `list_incidents` (defaults to open incidents; `query` filters by title) and \
+`get_incident` for notes and the timeline. For on-call, `who_is_on_call` answers who is paged now; \
+`list_schedules` and `get_schedule` show rotations. Services, teams, schedules and escalation \
+policies accept names or ids."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let since = json!({ "type": "string", "description": "Start time (ISO 8601)" });
+        let until = json!({ "type": "string", "description": "End time (ISO 8601)" });
+        let schedule = json!({ "type": "string", "description": "Schedule name or id" });
+
+        let tools = vec![
+            tool(
+                "list_incidents",
+                "List or search incidents, newest first. Defaults to open (triggered + acknowledged). \
+Example: query=\"checkout\" statuses=[\"resolved\"] since=\"2025-06-01\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Text to match in title/description/service, or an incident number" },
+                        "statuses": {
+                            "type": "array",
+                            "items": { "type": "string", "enum": ["triggered", "acknowledged", "resolved"] },
+                            "description": "Statuses to include (default: triggered, acknowledged)"
+                        },
+                        "urgency": { "type": "string", "enum": ["high", "low"] },
+                        "service": { "type": "string", "description": "Service name or id" },
+                        "team": { "type": "string", "description": "Team name or id" },
+                        "since": since.clone(),
+                        "until": until.clone(),
+                        "limit": { "type": "integer", "description": "Max incidents (default: 25, max: 500)" }
+                    }
+                }),
+            ),
+            tool(
+                "get_incident",
+                "Incident detail with notes and the timeline (triggers, acks, escalations, resolution).",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "incident": { "type": "string", "description": "Incident id, number, or incident URL" },
+                        "include_timeline": { "type": "boolean", "description": "Include the log-entry timeline (default: true)" }
+                    },
+                    "required": ["incident"]
+                }),
+            ),
+            tool(
+                "who_is_on_call",
+                "Who is on call now (or in a window), per escalation level. Example: schedule=\"Primary SRE\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "schedule": schedule.clone(),
+                        "escalation_policy": { "type": "string", "description": "Escalation policy name or id" },
+                        "since": since.clone(),
+                        "until": until.clone()
+                    }
+                }),
+            ),
+            tool(
+                "list_schedules",
+                "List on-call schedules with their time zone and users.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Filter by schedule name" }
+                    }
+                }),
+            ),
+            tool(
+                "get_schedule",
+                "A schedule's rendered shifts in a window (default: the next 7 days).",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "schedule": schedule,
+                        "since": since,
+                        "until": until
+                    },
+                    "required": ["schedule"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "list_incidents" => {
+                let args: ListIncidentsArgs = parse_args(request.arguments)?;
+                let limit = args.limit.unwrap_or(25).clamp(1, 500);
+                let statuses = args
+                    .statuses
+                    .unwrap_or_else(|| vec!["triggered".to_string(), "acknowledged".to_string()]);
+
+                let mut params: Vec<(String, String)> = statuses
+                    .iter()
+                    .map(|s| ("statuses[]".to_string(), s.clone()))
+                    .collect();
+                params.push(("sort_by".to_string(), "created_at:desc".to_string()));
+                if let Some(urgency) = args.urgency {
+                    params.push(("urgencies[]".to_string(), urgency));
+                }
+                if let Some(service) = &args.service {
+                    let id = self.resolve_id("services", service).await?;
+                    params.push(("service_ids[]".to_string(), id));
+                }
+                if let Some(team) = &args.team {
+                    let id = self.resolve_id("teams", team).await?;
+                    params.push(("team_ids[]".to_string(), id));
+                }
+                if let Some(since) = args.since {
+                    params.push(("since".to_string(), since));
+                }
+                if let Some(until) = args.until {
+                    params.push(("until".to_string(), until));
+                }
+                // Resolved incidents need an explicit window; without one, search all time
+                if statuses.iter().any(|s| s == "resolved")
+                    && !params.iter().any(|(k, _)| k == "since")
+                {
+                    params.push(("date_range".to_string(), "all".to_string()));
+                }
+
+                let scan = if args.query.is_some() {
+                    MAX_SCAN
+                } else {
+                    limit
+                };
+                let (incidents, more) = self
+                    .get_all("incidents", "incidents", &params, scan)
+                    .await?;
+                let incidents: Vec<Value> = incidents
+                    .iter()
+                    .filter(|i| match &args.query {
+                        Some(query) => matches_query(i, query),
+                        None => true,
+                    })
+                    .take(limit)
+                    .map(format_incident)
+                    .collect();
+
+                let data = json!({
+                    "query": args.query,
+                    "statuses": statuses,
+                    "count": incidents.len(),
+                    "more": more,
+                    "incidents": incidents,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_incident" => {
+                let args: GetIncidentArgs = parse_args(request.arguments)?;
+                let reference = parse_incident_ref(&args.incident).ok_or_else(|| {
+                    ConnectorError::InvalidParams(format!(
+                        "'{}' is not an incident id, number or URL",
+                        args.incident
+                    ))
+                })?;
+
+                // The incident endpoint accepts ids and numbers; notes and log entries need the id
+                let body = self.get(&format!("incidents/{}", reference), &[]).await?;
+                let incident = &body["incident"];
+                let id = incident["id"]
+                    .as_str()
+                    .ok_or(ConnectorError::ResourceNotFound)?
+                    .to_string();
+
+                let notes_body = self.get(&format!("incidents/{}/notes", id), &[]).await?;
+                let notes: Vec<Value> = notes_body["notes"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|n| {
+                        json!({
+                            "at": n["created_at"],
+                            "user": n["user"]["summary"],
+                            "content": n["content"],
+                        })
+                    })
+                    .collect();
+
+                let mut data = format_incident(incident);
+                data["description"] = incident["description"].clone();
+                data["resolved_at"] = incident["resolved_at"].clone();
+                data["acknowledgements"] = json!(incident["acknowledgements"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|a| json!({ "at": a["at"], "by": a["acknowledger"]["summary"] }))
+                    .collect::<Vec<_>>());
+                data["notes"] = json!(notes);
+
+                if args.include_timeline.unwrap_or(true) {
+                    let params = vec![
+                        ("is_overview".to_string(), "true".to_string()),
+                        ("time_zone".to_string(), "UTC".to_string()),
+                    ];
+                    let (entries, _) = self
+                        .get_all(
+                            &format!("incidents/{}/log_entries", id),
+                            "log_entries",
+                            &params,
+                            500,
+                        )
+                        .await?;
+                    // Log entries come newest first; a timeline reads oldest first
+                    data["timeline"] = json!(entries
+                        .iter()
+                        .rev()
+                        .map(format_log_entry)
+                        .collect::<Vec<_>>());
+                }
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "who_is_on_call" => {
+                let args: OnCallArgs = parse_args(request.arguments)?;
+                let mut params = Vec::new();
+                if let Some(schedule) = &args.schedule {
+                    let id = self.resolve_id("schedules", schedule).await?;
+                    params.push(("schedule_ids[]".to_string(), id));
+                }
+                if let Some(policy) = &args.escalation_policy {
+                    let id = self.resolve_id("escalation_policies", policy).await?;
+                    params.push(("escalation_policy_ids[]".to_string(), id));
+                }
+                if args.since.is_none() && args.until.is_none() {
+                    // Only the current shift per level, not every overlapping one
+                    params.push(("earliest".to_string(), "true".to_string()));
+                }
+                if let Some(since) = args.since {
+                    params.push(("since".to_string(), since));
+                }
+                if let Some(until) = args.until {
+                    params.push(("until".to_string(), until));
+                }
+
+                let (oncalls, _) = self.get_all("oncalls", "oncalls", &params, 500).await?;
+                let mut oncalls: Vec<Value> = oncalls.iter().map(format_oncall).collect();
+                oncalls.sort_by_key(|o| {
+                    (
+                        o["escalation_policy"]
+                            .as_str()
+                            .unwrap_or_default()
+                            .to_string(),
+                        o["escalation_level"].as_u64().unwrap_or(u64::MAX),
+                    )
+                });
+
+                let data = json!({ "count": oncalls.len(), "oncalls": oncalls });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "list_schedules" => {
+                let args: ListSchedulesArgs = parse_args(request.arguments)?;
+                let params: Vec<(String, String)> = args
+                    .query
+                    .into_iter()
+                    .map(|q| ("query".to_string(), q))
+                    .collect();
+                let (schedules, _) = self.get_all("schedules", "schedules", &params, 500).await?;
+                let schedules: Vec<Value> = schedules
+                    .iter()
+                    .map(|s| {
+                        json!({
+                            "id": s["id"],
+                            "name": s["name"],
+                            "description": s["description"],
+                            "time_zone": s["time_zone"],
+                            "users": s["users"]
+                                .as_array()
+                                .into_iter()
+                                .flatten()
+                                .filter_map(|u| u["summary"].as_str())
+                                .collect::<Vec<_>>(),
+                            "url": s["html_url"],
+                        })
+                    })
+                    .collect();
+
+                let data = json!({ "count": schedules.len(), "schedules": schedules });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_schedule" => {
+                let args: GetScheduleArgs = parse_args(request.arguments)?;
+                let id = self.resolve_id("schedules", &args.schedule).await?;
+                let now = chrono::Utc::now();
+                let since = args.since.unwrap_or_else(|| now.to_rfc3339());
+                let until = args
+                    .until
+                    .unwrap_or_else(|| (now + chrono::Duration::days(7)).to_rfc3339());
+
+                let body = self
+                    .get(
+                        &format!("schedules/{}", id),
+                        &[
+                            ("since".to_string(), since.clone()),
+                            ("until".to_string(), until.clone()),
+                        ],
+                    )
+                    .await?;
+                let schedule = &body["schedule"];
+                let shifts: Vec<Value> = schedule["final_schedule"]["rendered_schedule_entries"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|e| {
+                        json!({
+                            "user": e["user"]["summary"],
+                            "start": e["start"],
+                            "end": e["end"],
+                        })
+                    })
+                    .collect();
+
+                let data = json!({
+                    "id": schedule["id"],
+                    "name": schedule["name"],
+                    "time_zone": schedule["time_zone"],
+                    "since": since,
+                    "until": until,
+                    "shifts": shifts,
+                    "url": schedule["html_url"],
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_incident_references() {
+        assert_eq!(
+            parse_incident_ref("Q0RIJJZL24RC6W").as_deref(),
+            Some("Q0RIJJZL24RC6W")
+        );
+        assert_eq!(parse_incident_ref("#1234").as_deref(), Some("1234"));
+        assert_eq!(
+            parse_incident_ref("https://acme.pagerduty.com/incidents/PT4KHLK?utm=x").as_deref(),
+            Some("PT4KHLK")
+        );
+        assert_eq!(parse_incident_ref("not an id"), None);
+    }
+
+    #[test]
+    fn distinguishes_ids_from_names() {
+        assert!(looks_like_id("PABC123"));
+        assert!(!looks_like_id("Primary SRE"));
+        assert!(!looks_like_id("Payments"));
+    }
+
+    #[test]
+    fn formats_timeline_entries() {
+        let entry = json!({
+            "type": "acknowledge_log_entry",
+            "created_at": "2025-06-01T10:02:00Z",
+            "summary": "Acknowledged by Ada",
+            "agent": { "summary": "Ada Lovelace" },
+            "channel": { "type": "mobile" }
+        });
+        let formatted = format_log_entry(&entry);
+        assert_eq!(formatted["type"], "acknowledge");
+        assert_eq!(formatted["agent"], "Ada Lovelace");
+        assert_eq!(formatted["channel"], "mobile");
+    }
+
+    #[test]
+    fn matches_incidents_by_text_or_number() {
+        let incident = json!({
+            "incident_number": 1234,
+            "title": "Checkout latency above SLO",
+            "service": { "summary": "payments-api" }
+        });
+        assert!(matches_query(&incident, "checkout"));
+        assert!(matches_query(&incident, "payments"));
+        assert!(matches_query(&incident, "#1234"));
+        assert!(!matches_query(&incident, "search"));
+    }
+}
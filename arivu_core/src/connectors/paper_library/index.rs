@@ -0,0 +1,646 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// Bump when the extracted layout changes so stale indexes are rebuilt.
+const INDEX_VERSION: u32 = 1;
+const MAX_FILES: usize = 5_000;
+const MAX_PAGES: u32 = 200;
+const MAX_TEXT_CHARS: usize = 400_000;
+
+/// Canonical section names and the headings that map to them.
+const SECTION_HEADINGS: &[(&str, &[&str])] = &[
+    ("abstract", &["abstract", "summary"]),
+    ("introduction", &["introduction", "motivation"]),
+    ("background", &["background", "preliminaries"]),
+    (
+        "related work",
+        &[
+            "related work",
+            "related works",
+            "prior work",
+            "literature review",
+        ],
+    ),
+    (
+        "methods",
+        &[
+            "method",
+            "methods",
+            "methodology",
+            "materials and methods",
+            "methods and materials",
+            "approach",
+            "our approach",
+            "proposed method",
+        ],
+    ),
+    (
+        "experiments",
+        &[
+            "experiments",
+            "experimental setup",
+            "experimental results",
+            "experiments and results",
+            "evaluation",
+        ],
+    ),
+    (
+        "results",
+        &["results", "findings", "results and discussion"],
+    ),
+    ("discussion", &["discussion", "general discussion"]),
+    ("limitations", &["limitations"]),
+    (
+        "conclusion",
+        &[
+            "conclusion",
+            "conclusions",
+            "concluding remarks",
+            "conclusion and future work",
+            "conclusions and future work",
+        ],
+    ),
+    (
+        "acknowledgments",
+        &[
+            "acknowledgments",
+            "acknowledgements",
+            "acknowledgment",
+            "acknowledgement",
+        ],
+    ),
+    (
+        "references",
+        &[
+            "references",
+            "bibliography",
+            "literature cited",
+            "works cited",
+        ],
+    ),
+    (
+        "appendix",
+        &["appendix", "appendices", "supplementary material"],
+    ),
+];
+
+static RE_NUMBERING: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?:\d+(?:\.\d+)*|[IVXLC]+)[.)]?\s+").unwrap());
+static RE_DOI: Lazy<Regex> = Lazy::new(|| Regex::new(r#"\b(10\.\d{4,9}/[^\s"<>]+)"#).unwrap());
+static RE_ARXIV: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)arxiv:\s*(\d{4}\.\d{4,5}|[a-z-]+(?:\.[a-z]{2})?/\d{7})(?:v\d+)?").unwrap()
+});
+static RE_ARXIV_FILE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(\d{4}\.\d{4,5})(?:v\d+)?$").unwrap());
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaperSection {
+    /// Canonical name (`abstract`, `methods`, …); `front` for text before the first heading
+    pub name: String,
+    /// Heading as printed in the paper
+    pub heading: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Paper {
+    /// Path relative to the library directory
+    pub id: String,
+    pub title: Option<String>,
+    pub doi: Option<String>,
+    pub arxiv_id: Option<String>,
+    pub pages: Option<usize>,
+    pub size_bytes: u64,
+    /// Modification time, seconds since the epoch
+    pub modified: i64,
+    #[serde(default)]
+    pub sections: Vec<PaperSection>,
+    /// Set when extraction failed; the file is retried once it changes
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LibraryIndex {
+    #[serde(default)]
+    pub version: u32,
+    #[serde(default)]
+    pub root: PathBuf,
+    #[serde(default)]
+    pub papers: BTreeMap<String, Paper>,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+pub struct RefreshStats {
+    pub added: usize,
+    pub updated: usize,
+    pub removed: usize,
+    pub unchanged: usize,
+    pub failed: usize,
+}
+
+impl RefreshStats {
+    pub fn changed(&self) -> bool {
+        self.added + self.updated + self.removed > 0
+    }
+}
+
+impl LibraryIndex {
+    /// Load the cached index, discarding it if it was built for another directory
+    /// or by an older layout.
+    pub fn load(path: &Path, root: &Path) -> Self {
+        let index: Option<LibraryIndex> = fs::read(path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok());
+        match index {
+            Some(index) if index.version == INDEX_VERSION && index.root == root => index,
+            _ => LibraryIndex {
+                version: INDEX_VERSION,
+                root: root.to_path_buf(),
+                papers: BTreeMap::new(),
+            },
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_vec(self)?)?;
+        fs::rename(tmp, path)
+    }
+
+    /// Re-extract new and modified PDFs under the root and drop deleted ones.
+    pub fn refresh(&mut self, force: bool) -> io::Result<RefreshStats> {
+        let mut stats = RefreshStats::default();
+        let mut files = Vec::new();
+        collect_pdfs(&self.root, &mut files)?;
+        files.sort();
+        files.truncate(MAX_FILES);
+
+        let mut seen = BTreeMap::new();
+        for path in files {
+            let meta = match fs::metadata(&path) {
+                Ok(meta) => meta,
+                Err(_) => continue,
+            };
+            let id = path
+                .strip_prefix(&self.root)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            let modified = meta
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or_default();
+            let size_bytes = meta.len();
+
+            let existing = self.papers.remove(&id);
+            let paper = match existing {
+                Some(paper)
+                    if !force && paper.modified == modified && paper.size_bytes == size_bytes =>
+                {
+                    stats.unchanged += 1;
+                    paper
+                }
+                existing => {
+                    if existing.is_some() {
+                        stats.updated += 1;
+                    } else {
+                        stats.added += 1;
+                    }
+                    let mut paper = extract_paper(&path, &id);
+                    paper.modified = modified;
+                    paper.size_bytes = size_bytes;
+                    if paper.error.is_some() {
+                        stats.failed += 1;
+                    }
+                    paper
+                }
+            };
+            seen.insert(id, paper);
+        }
+        stats.removed = self.papers.len();
+        self.papers = seen;
+        Ok(stats)
+    }
+}
+
+fn collect_pdfs(dir: &Path, out: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        if name.to_string_lossy().starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            // Unreadable subdirectories shouldn't fail the whole library
+            let _ = collect_pdfs(&path, out);
+        } else if file_type.is_file()
+            && path
+                .extension()
+                .is_some_and(|e| e.eq_ignore_ascii_case("pdf"))
+        {
+            out.push(path);
+        }
+        if out.len() >= MAX_FILES {
+            break;
+        }
+    }
+    Ok(())
+}
+
+fn extract_paper(path: &Path, id: &str) -> Paper {
+    let mut paper = Paper {
+        id: id.to_string(),
+        title: None,
+        doi: None,
+        arxiv_id: None,
+        pages: None,
+        size_bytes: 0,
+        modified: 0,
+        sections: Vec::new(),
+        error: None,
+    };
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let doc = match lopdf::Document::load(path) {
+        Ok(doc) => doc,
+        Err(e) => {
+            paper.title = Some(title_from_file_name(&stem));
+            paper.error = Some(format!("Failed to load PDF: {}", e));
+            return paper;
+        }
+    };
+    let page_count = doc.get_pages().len();
+    paper.pages = Some(page_count);
+
+    // Page by page so one bad font table doesn't lose the rest of the paper
+    let mut text = String::new();
+    for page in 1..=(page_count as u32).min(MAX_PAGES) {
+        if let Ok(page_text) = doc.extract_text(&[page]) {
+            text.push_str(&page_text);
+            text.push('\n');
+        }
+        if text.len() > MAX_TEXT_CHARS {
+            break;
+        }
+    }
+    let text = normalize_text(&text);
+    if text.trim().is_empty() {
+        paper.error = Some("No extractable text (scanned PDF?)".to_string());
+    }
+
+    paper.sections = split_sections(&text);
+    // Only look before the bibliography: references are full of other papers' DOIs
+    let body: String = paper
+        .sections
+        .iter()
+        .take_while(|s| s.name != "references")
+        .map(|s| s.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let info = info_strings(&doc);
+
+    paper.doi = info
+        .iter()
+        .filter(|(key, _)| *key != "Title")
+        .find_map(|(_, value)| find_doi(value))
+        .or_else(|| find_doi(&body));
+    paper.arxiv_id = RE_ARXIV_FILE
+        .captures(&stem)
+        .map(|c| c[1].to_string())
+        .or_else(|| find_arxiv_id(&body));
+    paper.title = info
+        .iter()
+        .find(|(key, _)| *key == "Title")
+        .map(|(_, value)| value.trim().to_string())
+        .filter(|t| usable_title(t))
+        .or_else(|| {
+            paper
+                .sections
+                .first()
+                .filter(|s| s.name == "front")
+                .and_then(|s| guess_title(&s.text))
+        })
+        .or_else(|| Some(title_from_file_name(&stem)));
+    paper
+}
+
+/// Title, Subject and Keywords from the document information dictionary.
+fn info_strings(doc: &lopdf::Document) -> Vec<(&'static str, String)> {
+    let dict = doc
+        .trailer
+        .get(b"Info")
+        .ok()
+        .and_then(|info| doc.dereference(info).ok())
+        .and_then(|(_, info)| info.as_dict().ok());
+    let dict = match dict {
+        Some(dict) => dict,
+        None => return Vec::new(),
+    };
+    ["Title", "Subject", "Keywords", "doi"]
+        .iter()
+        .filter_map(|key| {
+            let value = dict.get(key.as_bytes()).ok()?;
+            let (_, value) = doc.dereference(value).ok()?;
+            Some((*key, decode_pdf_string(value.as_str().ok()?)))
+        })
+        .collect()
+}
+
+/// PDF text strings are UTF-16BE with a byte-order mark, or PDFDocEncoding
+/// (close enough to Latin-1 for titles).
+pub(crate) fn decode_pdf_string(bytes: &[u8]) -> String {
+    if bytes.starts_with(&[0xFE, 0xFF]) {
+        let units: Vec<u16> = bytes[2..]
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .collect();
+        String::from_utf16_lossy(&units)
+    } else if let Ok(text) = std::str::from_utf8(bytes) {
+        text.to_string()
+    } else {
+        bytes.iter().map(|&b| b as char).collect()
+    }
+}
+
+fn normalize_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut blank_run = 0;
+    for line in text.lines() {
+        let line = line.trim_end();
+        if line.trim().is_empty() {
+            blank_run += 1;
+            if blank_run > 1 {
+                continue;
+            }
+        } else {
+            blank_run = 0;
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+    out
+}
+
+/// Recognize a section heading line, returning its canonical name and any text
+/// that followed it on the same line (`Abstract—We propose …`).
+pub(crate) fn heading_name(line: &str) -> Option<(&'static str, String)> {
+    let line = line.trim();
+    if line.is_empty() {
+        return None;
+    }
+
+    // Run-in abstracts share the line with their first sentence
+    if line
+        .get(..8)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("abstract"))
+    {
+        let rest = &line[8..];
+        let trimmed = rest.trim_start_matches(|c: char| {
+            matches!(c, '.' | ':' | '—' | '–' | '-') || c.is_whitespace()
+        });
+        if trimmed.len() < rest.len() && trimmed.len() > 40 {
+            return Some(("abstract", trimmed.to_string()));
+        }
+    }
+
+    if line.chars().count() > 60 {
+        return None;
+    }
+    let unnumbered = RE_NUMBERING.replace(line, "");
+    let name = unnumbered
+        .trim()
+        .trim_end_matches(|c: char| c == '.' || c == ':')
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    if name.starts_with("appendix") && name.split_whitespace().count() <= 6 {
+        return Some(("appendix", String::new()));
+    }
+    SECTION_HEADINGS
+        .iter()
+        .find(|(_, aliases)| aliases.contains(&name.as_str()))
+        .map(|(canonical, _)| (*canonical, String::new()))
+}
+
+/// Split extracted text at recognized headings. Text before the first heading
+/// (title, authors, affiliations) becomes the `front` section.
+pub(crate) fn split_sections(text: &str) -> Vec<PaperSection> {
+    let mut sections = vec![PaperSection {
+        name: "front".to_string(),
+        heading: String::new(),
+        text: String::new(),
+    }];
+    for line in text.lines() {
+        if let Some((name, rest)) = heading_name(line) {
+            // A one-word "Summary" late in a paper is usually a subsection, not the abstract
+            let is_late_summary = name == "abstract"
+                && line.trim().eq_ignore_ascii_case("summary")
+                && sections.len() > 1;
+            if !is_late_summary {
+                sections.push(PaperSection {
+                    name: name.to_string(),
+                    heading: if rest.is_empty() {
+                        line.trim().to_string()
+                    } else {
+                        "Abstract".to_string()
+                    },
+                    text: rest,
+                });
+                continue;
+            }
+        }
+        let current = sections.last_mut().expect("at least one section");
+        if !current.text.is_empty() {
+            current.text.push('\n');
+        }
+        current.text.push_str(line);
+    }
+    for section in &mut sections {
+        section.text = section.text.trim().to_string();
+    }
+    sections.retain(|s| s.name != "front" || !s.text.is_empty());
+    sections
+}
+
+pub(crate) fn find_doi(text: &str) -> Option<String> {
+    RE_DOI.captures(text).map(|c| {
+        c[1].trim_end_matches(|ch: char| matches!(ch, '.' | ',' | ';' | ')' | ']' | '}'))
+            .to_string()
+    })
+}
+
+pub(crate) fn find_arxiv_id(text: &str) -> Option<String> {
+    RE_ARXIV.captures(text).map(|c| c[1].to_string())
+}
+
+fn usable_title(title: &str) -> bool {
+    let lower = title.to_lowercase();
+    title.split_whitespace().count() >= 2
+        && title.chars().count() <= 300
+        && !["untitled", "microsoft word", ".doc", ".pdf", ".tex", ".dvi"]
+            .iter()
+            .any(|junk| lower.contains(junk))
+}
+
+/// First substantial line of the front matter, joined with a wrapped second line.
+pub(crate) fn guess_title(front: &str) -> Option<String> {
+    let is_noise = |line: &str| {
+        let lower = line.to_lowercase();
+        [
+            "arxiv",
+            "doi",
+            "http",
+            "www.",
+            "@",
+            "©",
+            "preprint",
+            "proceedings",
+            "journal",
+            "vol.",
+            "volume",
+            "conference",
+            "published",
+            "accepted",
+            "received",
+            "license",
+        ]
+        .iter()
+        .any(|noise| lower.contains(noise))
+    };
+    let lines: Vec<&str> = front
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .take(12)
+        .collect();
+    let start = lines.iter().position(|line| {
+        line.split_whitespace().count() >= 3 && line.chars().count() <= 200 && !is_noise(line)
+    })?;
+    let mut title = lines[start].to_string();
+    if let Some(next) = lines.get(start + 1) {
+        let wraps = next.chars().next().is_some_and(|c| c.is_lowercase())
+            || title.ends_with(':')
+            || title.ends_with('-');
+        if wraps && !is_noise(next) && title.chars().count() + next.chars().count() <= 250 {
+            title = format!("{} {}", title.trim_end_matches('-'), next);
+        }
+    }
+    Some(title)
+}
+
+pub(crate) fn title_from_file_name(stem: &str) -> String {
+    stem.replace(['_', '-'], " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "Attention Is All You Need\n\
+Ashish Vaswani, Noam Shazeer\n\
+Google Brain\n\
+Abstract\n\
+The dominant sequence transduction models are based on recurrent networks.\n\
+1 Introduction\n\
+Recurrent neural networks have been established as state of the art.\n\
+3.1 Encoder and Decoder Stacks\n\
+The encoder is composed of a stack of identical layers.\n\
+7 Conclusion\n\
+We presented the Transformer.\n\
+References\n\
+[1] Ba et al. Layer normalization. doi:10.48550/arXiv.1607.06450.\n";
+
+    #[test]
+    fn splits_sections_at_known_headings() {
+        let sections = split_sections(SAMPLE);
+        let names: Vec<&str> = sections.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "front",
+                "abstract",
+                "introduction",
+                "conclusion",
+                "references"
+            ]
+        );
+        assert!(sections[2].text.contains("Encoder and Decoder Stacks"));
+        assert_eq!(sections[2].heading, "1 Introduction");
+        assert_eq!(
+            guess_title(&sections[0].text).as_deref(),
+            Some("Attention Is All You Need")
+        );
+    }
+
+    #[test]
+    fn recognizes_heading_variants() {
+        assert_eq!(heading_name("II. RELATED WORK").unwrap().0, "related work");
+        assert_eq!(
+            heading_name("2.  Materials and Methods").unwrap().0,
+            "methods"
+        );
+        assert_eq!(heading_name("Appendix A: Proofs").unwrap().0, "appendix");
+        assert_eq!(
+            heading_name("Acknowledgements").unwrap().0,
+            "acknowledgments"
+        );
+        assert!(heading_name("3.1 Encoder and Decoder Stacks").is_none());
+        assert!(heading_name("The results in Table 2 show").is_none());
+
+        let (name, rest) = heading_name(
+            "Abstract—We propose a new simple network architecture based solely on attention.",
+        )
+        .unwrap();
+        assert_eq!(name, "abstract");
+        assert!(rest.starts_with("We propose"));
+    }
+
+    #[test]
+    fn finds_identifiers() {
+        assert_eq!(
+            find_doi("https://doi.org/10.1038/nature14539.").as_deref(),
+            Some("10.1038/nature14539")
+        );
+        assert_eq!(
+            find_arxiv_id("arXiv:1706.03762v7 [cs.CL] 2 Aug 2023").as_deref(),
+            Some("1706.03762")
+        );
+        assert_eq!(
+            find_arxiv_id("arXiv:hep-th/9711200").as_deref(),
+            Some("hep-th/9711200")
+        );
+        assert!(find_doi("no identifiers here").is_none());
+    }
+
+    #[test]
+    fn decodes_pdf_strings_and_titles() {
+        let utf16 = [0xFE, 0xFF, 0x00, b'H', 0x00, b'i'];
+        assert_eq!(decode_pdf_string(&utf16), "Hi");
+        assert_eq!(decode_pdf_string(b"Plain title"), "Plain title");
+        assert!(!usable_title("Microsoft Word - draft3.docx"));
+        assert!(!usable_title("paper"));
+        assert!(usable_title("Deep Residual Learning for Image Recognition"));
+        assert_eq!(title_from_file_name("he_2016-resnet"), "he 2016 resnet");
+    }
+}
@@ -0,0 +1,736 @@
+mod index;
+
+pub use index::{LibraryIndex, Paper, PaperSection, RefreshStats};
+
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+const DEFAULT_MAX_CHARS: usize = 20_000;
+const SNIPPET_CHARS: usize = 240;
+
+#[derive(Debug, Deserialize)]
+struct ReindexArgs {
+    #[serde(default)]
+    force: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListPapersArgs {
+    #[serde(default)]
+    sort: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+    #[serde(default)]
+    offset: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchPapersArgs {
+    query: String,
+    #[serde(default)]
+    section: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetPaperArgs {
+    id: String,
+    #[serde(default)]
+    sections: Option<Vec<String>>,
+    #[serde(default)]
+    max_chars: Option<usize>,
+}
+
+/// Expand `~` to the user's home directory
+fn expand_path(path: &str) -> PathBuf {
+    match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()
+            .map(|home| home.join(rest))
+            .unwrap_or_else(|| PathBuf::from(path)),
+        None => PathBuf::from(path),
+    }
+}
+
+fn library_dir(auth: &AuthDetails) -> PathBuf {
+    auth.get("dir")
+        .cloned()
+        .or_else(|| std::env::var("ARIVU_PAPERS_DIR").ok())
+        .filter(|d| !d.trim().is_empty())
+        .map(|d| expand_path(d.trim()))
+        .unwrap_or_else(|| {
+            dirs::home_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("Papers")
+        })
+}
+
+fn index_path() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".arivu")
+        .join("paper-library")
+        .join("index.json")
+}
+
+fn modified_rfc3339(paper: &Paper) -> Option<String> {
+    chrono::DateTime::from_timestamp(paper.modified, 0).map(|t| t.to_rfc3339())
+}
+
+fn brief(paper: &Paper) -> Value {
+    json!({
+        "id": paper.id,
+        "title": paper.title,
+        "doi": paper.doi,
+        "arxiv_id": paper.arxiv_id,
+        "pages": paper.pages,
+        "modified": modified_rfc3339(paper),
+        "error": paper.error,
+    })
+}
+
+/// Calls on the scholarly connectors that enrich a local paper.
+fn lookups(paper: &Paper) -> Vec<String> {
+    let mut out = Vec::new();
+    if let Some(doi) = &paper.doi {
+        out.push(format!("crossref/get_work doi=\"{}\"", doi));
+        out.push(format!(
+            "semantic-scholar/get_paper_details paper_id=\"DOI:{}\"",
+            doi
+        ));
+    }
+    if let Some(arxiv_id) = &paper.arxiv_id {
+        out.push(format!("arxiv/get_paper_details paper_id=\"{}\"", arxiv_id));
+        if paper.doi.is_none() {
+            out.push(format!(
+                "semantic-scholar/get_paper_details paper_id=\"arXiv:{}\"",
+                arxiv_id
+            ));
+        }
+    }
+    out
+}
+
+fn normalize_doi(value: &str) -> &str {
+    let value = value.trim();
+    [
+        "https://doi.org/",
+        "http://doi.org/",
+        "https://dx.doi.org/",
+        "doi:",
+    ]
+    .iter()
+    .find_map(|prefix| {
+        value
+            .get(..prefix.len())
+            .filter(|p| p.eq_ignore_ascii_case(prefix))
+            .map(|_| &value[prefix.len()..])
+    })
+    .unwrap_or(value)
+}
+
+/// Match by library path, DOI, arXiv id, or file name.
+fn find_paper<'a>(index: &'a LibraryIndex, key: &str) -> Option<&'a Paper> {
+    let key = key.trim();
+    if let Some(paper) = index.papers.get(key) {
+        return Some(paper);
+    }
+    let doi = normalize_doi(key);
+    let arxiv = key
+        .get(..6)
+        .filter(|p| p.eq_ignore_ascii_case("arxiv:"))
+        .map(|_| &key[6..])
+        .unwrap_or(key);
+    let arxiv = match arxiv.rfind('v') {
+        Some(i) if i > 0 && arxiv[i + 1..].chars().all(|c| c.is_ascii_digit()) => &arxiv[..i],
+        _ => arxiv,
+    };
+    index.papers.values().find(|paper| {
+        paper
+            .doi
+            .as_deref()
+            .is_some_and(|d| d.eq_ignore_ascii_case(doi))
+            || paper.arxiv_id.as_deref() == Some(arxiv)
+            || Path::new(&paper.id)
+                .file_stem()
+                .is_some_and(|stem| stem.to_string_lossy().eq_ignore_ascii_case(key))
+    })
+}
+
+fn char_boundary_at_or_before(text: &str, mut index: usize) -> usize {
+    index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+fn snippet(text: &str, at: usize) -> String {
+    let start = char_boundary_at_or_before(text, at.saturating_sub(SNIPPET_CHARS / 3));
+    let end = char_boundary_at_or_before(text, at + SNIPPET_CHARS);
+    let mut out = text[start..end]
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if start > 0 {
+        out.insert_str(0, "…");
+    }
+    if end < text.len() {
+        out.push('…');
+    }
+    out
+}
+
+/// Rank papers containing every term. Title hits weigh most, then the abstract,
+/// then the body; references only count when searched explicitly.
+pub(crate) fn search(
+    index: &LibraryIndex,
+    query: &str,
+    section: Option<&str>,
+    limit: usize,
+) -> Vec<Value> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .map(|t| t.to_ascii_lowercase())
+        .collect();
+    if terms.is_empty() {
+        return Vec::new();
+    }
+    let section = section.map(|s| s.trim().to_ascii_lowercase());
+
+    let mut hits: Vec<(usize, Value)> = Vec::new();
+    for paper in index.papers.values() {
+        let title = paper
+            .title
+            .as_deref()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let sections: Vec<(&PaperSection, String)> = paper
+            .sections
+            .iter()
+            .filter(|s| match &section {
+                Some(name) => &s.name == name,
+                None => s.name != "references",
+            })
+            .map(|s| (s, s.text.to_ascii_lowercase()))
+            .collect();
+
+        let mut score = 0;
+        let mut all_found = true;
+        for term in &terms {
+            let in_title = section.is_none() && title.contains(term.as_str());
+            let mut in_abstract = 0;
+            let mut in_body = 0;
+            for (s, text) in &sections {
+                let count = text.matches(term.as_str()).count();
+                if s.name == "abstract" {
+                    in_abstract += count;
+                } else {
+                    in_body += count;
+                }
+            }
+            if !in_title && in_abstract + in_body == 0 {
+                all_found = false;
+                break;
+            }
+            score += usize::from(in_title) * 10 + in_abstract.min(5) * 3 + in_body.min(20);
+        }
+        if !all_found {
+            continue;
+        }
+
+        let first = &terms[0];
+        let matched = sections
+            .iter()
+            .find_map(|(s, text)| text.find(first.as_str()).map(|at| (s, at)));
+        let mut hit = brief(paper);
+        hit["score"] = json!(score);
+        if let Some((s, at)) = matched {
+            hit["section"] = json!(s.name);
+            hit["snippet"] = json!(snippet(&s.text, at));
+        }
+        hits.push((score, hit));
+    }
+    hits.sort_by(|a, b| b.0.cmp(&a.0));
+    hits.into_iter().take(limit).map(|(_, hit)| hit).collect()
+}
+
+pub struct PaperLibraryConnector {
+    root: PathBuf,
+    index_path: PathBuf,
+    index: Arc<Mutex<Option<LibraryIndex>>>,
+}
+
+impl PaperLibraryConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        Ok(Self {
+            root: library_dir(&auth),
+            index_path: index_path(),
+            index: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Bring the index up to date with the directory, then run `f` on it.
+    /// Unchanged files are skipped, so this is cheap after the first build.
+    async fn with_index<R>(
+        &self,
+        force: bool,
+        f: impl FnOnce(&LibraryIndex, RefreshStats) -> R,
+    ) -> Result<R, ConnectorError> {
+        if !self.root.is_dir() {
+            return Err(ConnectorError::InvalidInput(format!(
+                "Paper library directory not found: {}. Set it with `arivu paper-library dir <path>` or ARIVU_PAPERS_DIR.",
+                self.root.display()
+            )));
+        }
+        let mut guard = self.index.lock().await;
+        let cached = guard.take();
+        let root = self.root.clone();
+        let index_path = self.index_path.clone();
+        let (index, stats) = tokio::task::spawn_blocking(move || {
+            let mut index = cached.unwrap_or_else(|| LibraryIndex::load(&index_path, &root));
+            let stats = index.refresh(force)?;
+            if stats.changed() || force {
+                index.save(&index_path)?;
+            }
+            Ok::<_, std::io::Error>((index, stats))
+        })
+        .await
+        .map_err(|e| ConnectorError::Other(format!("Indexing task failed: {}", e)))??;
+        let result = f(&index, stats);
+        *guard = Some(index);
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl Connector for PaperLibraryConnector {
+    fn name(&self) -> &'static str {
+        "paper-library"
+    }
+
+    fn description(&self) -> &'static str {
+        "Local paper library: search and read sections of downloaded PDFs, with DOIs and arXiv ids for online lookups."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        let mut auth = AuthDetails::new();
+        auth.insert("dir".to_string(), self.root.display().to_string());
+        Ok(auth)
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        *self = Self::new(details).await?;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        if self.root.is_dir() {
+            Ok(())
+        } else {
+            Err(ConnectorError::InvalidInput(format!(
+                "Paper library directory not found: {}",
+                self.root.display()
+            )))
+        }
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema {
+            fields: vec![Field {
+                name: "dir".to_string(),
+                label: "Library Directory".to_string(),
+                field_type: FieldType::Text,
+                required: false,
+                description: Some(
+                    "Folder of PDFs, searched recursively (default: ~/Papers, or ARIVU_PAPERS_DIR)"
+                        .to_string(),
+                ),
+                options: None,
+            }],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Search downloaded papers with `search_papers`, then read what you need with \
+`get_paper` (pass `sections`, e.g. [\"abstract\", \"methods\"], to keep it short). The index updates \
+itself for new or changed PDFs; the first call on a large library takes a while. Use the returned \
+`lookups` to fetch citations and metadata from crossref, semantic-scholar or arxiv."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            tool(
+                "search_papers",
+                "Full-text search over local PDFs, ranked by title, abstract and body matches. \
+Example: query=\"contrastive pretraining\" section=\"methods\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Words that must all appear (case-insensitive)" },
+                        "section": { "type": "string", "description": "Only search this section: abstract, introduction, methods, results, discussion, conclusion, references, …" },
+                        "limit": { "type": "integer", "description": "Max papers (default: 10, max: 100)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "get_paper",
+                "A paper's metadata, section outline and text. Example: id=\"10.1038/nature14539\" sections=[\"abstract\",\"conclusion\"].",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "string", "description": "Library path, DOI, arXiv id, or file name" },
+                        "sections": { "type": "array", "items": { "type": "string" }, "description": "Section names to return (default: all except references)" },
+                        "max_chars": { "type": "integer", "description": "Max characters of section text (default: 20000)" }
+                    },
+                    "required": ["id"]
+                }),
+            ),
+            tool(
+                "list_papers",
+                "Papers in the library with title, DOI and arXiv id.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "sort": { "type": "string", "enum": ["recent", "title", "path"], "description": "Order (default: recent)" },
+                        "limit": { "type": "integer", "description": "Max papers (default: 50, max: 500)" },
+                        "offset": { "type": "integer", "description": "Skip this many papers" }
+                    }
+                }),
+            ),
+            tool(
+                "reindex",
+                "Scan the library for new, changed and deleted PDFs. force=true re-extracts every file.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "force": { "type": "boolean", "description": "Re-extract unchanged files too (default: false)" }
+                    }
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "search_papers" => {
+                let args: SearchPapersArgs = parse_args(request.arguments)?;
+                let limit = args.limit.unwrap_or(10).clamp(1, 100);
+                let data = self
+                    .with_index(false, |index, _| {
+                        let results = search(index, &args.query, args.section.as_deref(), limit);
+                        json!({
+                            "query": args.query,
+                            "library_size": index.papers.len(),
+                            "count": results.len(),
+                            "papers": results,
+                        })
+                    })
+                    .await?;
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_paper" => {
+                let args: GetPaperArgs = parse_args(request.arguments)?;
+                let max_chars = args.max_chars.unwrap_or(DEFAULT_MAX_CHARS).max(500);
+                let wanted: Option<Vec<String>> = args.sections.map(|names| {
+                    names
+                        .iter()
+                        .map(|n| n.trim().to_ascii_lowercase())
+                        .collect()
+                });
+                let root = self.root.clone();
+                let data = self
+                    .with_index(false, |index, _| {
+                        let paper = find_paper(index, &args.id)?;
+                        let outline: Vec<Value> = paper
+                            .sections
+                            .iter()
+                            .map(|s| {
+                                json!({
+                                    "name": s.name,
+                                    "heading": s.heading,
+                                    "chars": s.text.chars().count(),
+                                })
+                            })
+                            .collect();
+
+                        let mut remaining = max_chars;
+                        let mut truncated = false;
+                        let mut sections = Vec::new();
+                        for s in &paper.sections {
+                            let selected = match &wanted {
+                                Some(names) => names.contains(&s.name),
+                                None => s.name != "references",
+                            };
+                            if !selected {
+                                continue;
+                            }
+                            if remaining == 0 {
+                                truncated = true;
+                                break;
+                            }
+                            let text: String = s.text.chars().take(remaining).collect();
+                            remaining -= text.chars().count();
+                            truncated |= text.len() < s.text.len();
+                            sections.push(json!({
+                                "name": s.name,
+                                "heading": s.heading,
+                                "text": text,
+                            }));
+                        }
+
+                        let mut data = brief(paper);
+                        data["path"] = json!(root.join(&paper.id).display().to_string());
+                        data["outline"] = json!(outline);
+                        data["sections"] = json!(sections);
+                        data["truncated"] = json!(truncated);
+                        data["lookups"] = json!(lookups(paper));
+                        Some(data)
+                    })
+                    .await?
+                    .ok_or(ConnectorError::ResourceNotFound)?;
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "list_papers" => {
+                let args: ListPapersArgs = parse_args(request.arguments)?;
+                let limit = args.limit.unwrap_or(50).clamp(1, 500);
+                let offset = args.offset.unwrap_or(0);
+                let sort = args.sort.unwrap_or_else(|| "recent".to_string());
+                let data = self
+                    .with_index(false, |index, _| {
+                        let mut papers: Vec<&Paper> = index.papers.values().collect();
+                        match sort.as_str() {
+                            "title" => papers.sort_by_key(|p| {
+                                p.title.as_deref().unwrap_or_default().to_lowercase()
+                            }),
+                            "path" => {}
+                            _ => papers.sort_by(|a, b| b.modified.cmp(&a.modified)),
+                        }
+                        let listed: Vec<Value> = papers
+                            .iter()
+                            .skip(offset)
+                            .take(limit)
+                            .map(|p| brief(p))
+                            .collect();
+                        json!({
+                            "total": papers.len(),
+                            "count": listed.len(),
+                            "offset": offset,
+                            "papers": listed,
+                        })
+                    })
+                    .await?;
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "reindex" => {
+                let args: ReindexArgs = parse_args(request.arguments)?;
+                let root = self.root.display().to_string();
+                let data = self
+                    .with_index(args.force.unwrap_or(false), |index, stats| {
+                        let failed: Vec<Value> = index
+                            .papers
+                            .values()
+                            .filter_map(|p| {
+                                p.error.as_ref().map(|e| json!({ "id": p.id, "error": e }))
+                            })
+                            .collect();
+                        json!({
+                            "library": root,
+                            "papers": index.papers.len(),
+                            "stats": stats,
+                            "failed": failed,
+                        })
+                    })
+                    .await?;
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn paper(id: &str, title: &str, doi: Option<&str>, sections: &[(&str, &str)]) -> Paper {
+        Paper {
+            id: id.to_string(),
+            title: Some(title.to_string()),
+            doi: doi.map(str::to_string),
+            arxiv_id: None,
+            pages: Some(10),
+            size_bytes: 1,
+            modified: 0,
+            sections: sections
+                .iter()
+                .map(|(name, text)| PaperSection {
+                    name: name.to_string(),
+                    heading: name.to_string(),
+                    text: text.to_string(),
+                })
+                .collect(),
+            error: None,
+        }
+    }
+
+    fn library() -> LibraryIndex {
+        let mut papers = BTreeMap::new();
+        for p in [
+            paper(
+                "vision/resnet.pdf",
+                "Deep Residual Learning for Image Recognition",
+                Some("10.1109/CVPR.2016.90"),
+                &[
+                    ("abstract", "We present a residual learning framework."),
+                    ("methods", "Residual blocks with identity shortcuts."),
+                    ("references", "Transformer attention"),
+                ],
+            ),
+            paper(
+                "nlp/attention.pdf",
+                "Attention Is All You Need",
+                None,
+                &[
+                    (
+                        "abstract",
+                        "A network based solely on attention mechanisms.",
+                    ),
+                    ("results", "Residual dropout helps the transformer."),
+                ],
+            ),
+        ] {
+            papers.insert(p.id.clone(), p);
+        }
+        LibraryIndex {
+            version: 1,
+            root: PathBuf::from("/papers"),
+            papers,
+        }
+    }
+
+    #[test]
+    fn ranks_title_and_abstract_matches_first() {
+        let index = library();
+        let results = search(&index, "residual", None, 10);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["id"], "vision/resnet.pdf");
+        assert_eq!(results[0]["section"], "abstract");
+
+        // Every term must match; references are skipped unless asked for
+        assert!(search(&index, "residual mechanisms", None, 10)
+            .iter()
+            .all(|r| r["id"] == "nlp/attention.pdf"));
+        assert!(
+            search(&index, "transformer", Some("references"), 10)[0]["id"]
+                .as_str()
+                .is_some_and(|id| id == "vision/resnet.pdf")
+        );
+        assert_eq!(search(&index, "transformer", None, 10).len(), 1);
+    }
+
+    #[test]
+    fn finds_papers_by_path_doi_or_file_name() {
+        let index = library();
+        assert!(find_paper(&index, "nlp/attention.pdf").is_some());
+        assert_eq!(
+            find_paper(&index, "https://doi.org/10.1109/cvpr.2016.90").map(|p| p.id.as_str()),
+            Some("vision/resnet.pdf")
+        );
+        assert_eq!(
+            find_paper(&index, "attention").map(|p| p.id.as_str()),
+            Some("nlp/attention.pdf")
+        );
+        assert!(find_paper(&index, "missing").is_none());
+    }
+
+    #[test]
+    fn suggests_online_lookups() {
+        let index = library();
+        let resnet = &index.papers["vision/resnet.pdf"];
+        let calls = lookups(resnet);
+        assert!(calls[0].starts_with("crossref/get_work"));
+        assert!(calls[1].contains("DOI:10.1109/CVPR.2016.90"));
+        assert!(lookups(&index.papers["nlp/attention.pdf"]).is_empty());
+    }
+}
@@ -0,0 +1,805 @@
+use crate::capabilities::ConnectorConfigSchema;
+use crate::error::ConnectorError;
+use crate::utils::{
+    html_to_text, parse_args, structured_result_with_text, subtitles_to_text, tool,
+};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+const ITUNES_BASE: &str = "https://itunes.apple.com";
+
+#[derive(Debug, Deserialize)]
+struct SearchArgs {
+    query: String,
+    #[serde(default)]
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FeedArgs {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    feed_url: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EpisodeArgs {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    feed_url: Option<String>,
+    #[serde(default)]
+    guid: Option<String>,
+    #[serde(default)]
+    title: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranscriptArgs {
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    feed_url: Option<String>,
+    #[serde(default)]
+    guid: Option<String>,
+    #[serde(default)]
+    title: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct Transcript {
+    url: String,
+    mime: Option<String>,
+    language: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct Episode {
+    guid: Option<String>,
+    title: Option<String>,
+    description: Option<String>,
+    published: Option<String>,
+    duration: Option<String>,
+    link: Option<String>,
+    audio_url: Option<String>,
+    audio_type: Option<String>,
+    audio_length: Option<u64>,
+    season: Option<String>,
+    episode: Option<String>,
+    transcripts: Vec<Transcript>,
+}
+
+#[derive(Debug, Default)]
+struct Feed {
+    title: Option<String>,
+    description: Option<String>,
+    author: Option<String>,
+    link: Option<String>,
+    image: Option<String>,
+    language: Option<String>,
+    episodes: Vec<Episode>,
+}
+
+impl Episode {
+    fn to_json(&self, include_description: bool) -> Value {
+        let transcripts: Vec<Value> = self
+            .transcripts
+            .iter()
+            .map(|t| json!({ "url": t.url, "type": t.mime, "language": t.language }))
+            .collect();
+        let mut value = json!({
+            "guid": self.guid,
+            "title": self.title,
+            "published": self.published,
+            "duration": self.duration,
+            "season": self.season,
+            "episode": self.episode,
+            "link": self.link,
+            "audio_url": self.audio_url,
+            "audio_type": self.audio_type,
+            "audio_length": self.audio_length,
+            "transcripts": transcripts,
+        });
+        if include_description {
+            value["description"] = json!(self.description.as_deref().map(html_to_text));
+        }
+        value
+    }
+}
+
+pub struct PodcastsConnector {
+    client: Client,
+}
+
+impl PodcastsConnector {
+    pub async fn new(_auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+
+        Ok(Self { client })
+    }
+
+    async fn get_text(
+        &self,
+        url: &str,
+        params: &[(&str, String)],
+    ) -> Result<String, ConnectorError> {
+        let response = self
+            .client
+            .get(url)
+            .query(params)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(ConnectorError::ResourceNotFound);
+        }
+        if !response.status().is_success() {
+            return Err(ConnectorError::Other(format!(
+                "Podcast request failed with status: {}",
+                response.status()
+            )));
+        }
+
+        response.text().await.map_err(ConnectorError::HttpRequest)
+    }
+
+    async fn itunes(
+        &self,
+        path: &str,
+        params: &[(&str, String)],
+    ) -> Result<Vec<Value>, ConnectorError> {
+        let body = self
+            .get_text(&format!("{}/{}", ITUNES_BASE, path), params)
+            .await?;
+        let value: Value = serde_json::from_str(&body)?;
+        Ok(value["results"].as_array().cloned().unwrap_or_default())
+    }
+
+    /// Resolve either an explicit feed URL or an iTunes collection id to an RSS feed URL.
+    async fn resolve_feed_url(
+        &self,
+        id: Option<&str>,
+        feed_url: Option<&str>,
+    ) -> Result<String, ConnectorError> {
+        if let Some(url) = feed_url {
+            return Ok(url.to_string());
+        }
+        let id = id.ok_or_else(|| {
+            ConnectorError::InvalidParams("Provide either 'id' or 'feed_url'".to_string())
+        })?;
+
+        let results = self
+            .itunes("lookup", &[("id", id.trim().to_string())])
+            .await?;
+        results
+            .iter()
+            .find_map(|r| r["feedUrl"].as_str().map(str::to_string))
+            .ok_or(ConnectorError::ResourceNotFound)
+    }
+
+    async fn load_feed(
+        &self,
+        id: Option<&str>,
+        feed_url: Option<&str>,
+    ) -> Result<(String, Feed), ConnectorError> {
+        let url = self.resolve_feed_url(id, feed_url).await?;
+        let xml = self.get_text(&url, &[]).await?;
+        let feed = parse_feed(&xml)?;
+        Ok((url, feed))
+    }
+
+    async fn find_episode(
+        &self,
+        id: Option<&str>,
+        feed_url: Option<&str>,
+        guid: Option<&str>,
+        title: Option<&str>,
+    ) -> Result<(String, Feed, Episode), ConnectorError> {
+        let (url, feed) = self.load_feed(id, feed_url).await?;
+        let episode = match (guid, title) {
+            (Some(guid), _) => feed
+                .episodes
+                .iter()
+                .find(|e| e.guid.as_deref() == Some(guid)),
+            (None, Some(title)) => {
+                let needle = title.to_lowercase();
+                feed.episodes.iter().find(|e| {
+                    e.title
+                        .as_deref()
+                        .map(|t| t.to_lowercase().contains(&needle))
+                        .unwrap_or(false)
+                })
+            }
+            // Default to the latest episode.
+            (None, None) => feed.episodes.first(),
+        }
+        .cloned()
+        .ok_or(ConnectorError::ResourceNotFound)?;
+
+        Ok((url, feed, episode))
+    }
+}
+
+fn attr(e: &BytesStart, name: &[u8]) -> Option<String> {
+    e.attributes()
+        .flatten()
+        .find(|a| a.key.as_ref() == name)
+        .and_then(|a| a.unescape_value().ok().map(|v| v.to_string()))
+}
+
+/// Parse a podcast RSS feed, including `<enclosure>` and `<podcast:transcript>` tags.
+fn parse_feed(xml: &str) -> Result<Feed, ConnectorError> {
+    let mut reader = Reader::from_str(xml);
+
+    let mut feed = Feed::default();
+    let mut current: Option<Episode> = None;
+    let mut path: Vec<String> = Vec::new();
+    let mut text = String::new();
+
+    let mut buffer = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buffer) {
+            Ok(Event::Start(ref e)) => {
+                let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if tag == "item" {
+                    current = Some(Episode::default());
+                }
+                handle_empty(e, &tag, &mut feed, current.as_mut());
+                path.push(tag);
+                text.clear();
+            }
+            Ok(Event::Empty(ref e)) => {
+                let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                handle_empty(e, &tag, &mut feed, current.as_mut());
+            }
+            Ok(Event::Text(ref e)) => {
+                let chunk = e.unescape().map_err(|_| ConnectorError::ParseError)?;
+                text.push_str(&chunk);
+            }
+            Ok(Event::CData(ref e)) => {
+                text.push_str(&String::from_utf8_lossy(e.as_ref()));
+            }
+            Ok(Event::End(ref e)) => {
+                let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                let value = text.trim().to_string();
+                let parent = path.len().checked_sub(2).and_then(|i| path.get(i));
+
+                if tag == "item" {
+                    if let Some(episode) = current.take() {
+                        feed.episodes.push(episode);
+                    }
+                } else if !value.is_empty() {
+                    match (current.as_mut(), parent.map(String::as_str)) {
+                        (Some(ep), Some("item")) => match tag.as_str() {
+                            "guid" => ep.guid = Some(value),
+                            "title" => ep.title = Some(value),
+                            "description" | "content:encoded" if ep.description.is_none() => {
+                                ep.description = Some(value)
+                            }
+                            "pubDate" => ep.published = Some(value),
+                            "itunes:duration" => ep.duration = Some(value),
+                            "itunes:season" => ep.season = Some(value),
+                            "itunes:episode" => ep.episode = Some(value),
+                            "link" => ep.link = Some(value),
+                            _ => {}
+                        },
+                        (None, Some("channel")) => match tag.as_str() {
+                            "title" => feed.title = Some(value),
+                            "description" => feed.description = Some(value),
+                            "itunes:author" => feed.author = Some(value),
+                            "link" => feed.link = Some(value),
+                            "language" => feed.language = Some(value),
+                            _ => {}
+                        },
+                        (None, Some("image")) if tag == "url" && feed.image.is_none() => {
+                            feed.image = Some(value)
+                        }
+                        _ => {}
+                    }
+                }
+                path.pop();
+                text.clear();
+            }
+            Ok(Event::Eof) => break,
+            Err(_) => return Err(ConnectorError::ParseError),
+            _ => {}
+        }
+
+        buffer.clear();
+    }
+
+    Ok(feed)
+}
+
+/// Attribute-only tags (`enclosure`, `podcast:transcript`, `itunes:image`).
+fn handle_empty(e: &BytesStart, tag: &str, feed: &mut Feed, episode: Option<&mut Episode>) {
+    match (tag, episode) {
+        ("enclosure", Some(ep)) => {
+            ep.audio_url = attr(e, b"url");
+            ep.audio_type = attr(e, b"type");
+            ep.audio_length = attr(e, b"length").and_then(|l| l.parse().ok());
+        }
+        ("podcast:transcript", Some(ep)) => {
+            if let Some(url) = attr(e, b"url") {
+                ep.transcripts.push(Transcript {
+                    url,
+                    mime: attr(e, b"type"),
+                    language: attr(e, b"language"),
+                });
+            }
+        }
+        ("itunes:image", None) => {
+            if feed.image.is_none() {
+                feed.image = attr(e, b"href");
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Preference order when an episode offers several transcript formats.
+fn transcript_rank(mime: Option<&str>) -> u8 {
+    match mime.unwrap_or_default() {
+        "application/json" => 0,
+        "text/vtt" => 1,
+        "application/x-subrip" | "application/srt" => 2,
+        "text/plain" => 3,
+        "text/html" => 4,
+        _ => 5,
+    }
+}
+
+fn guess_mime(url: &str) -> Option<&'static str> {
+    let lower = url.to_lowercase();
+    let path = lower.split('?').next().unwrap_or_default();
+    if path.ends_with(".vtt") {
+        Some("text/vtt")
+    } else if path.ends_with(".srt") {
+        Some("application/x-subrip")
+    } else if path.ends_with(".json") {
+        Some("application/json")
+    } else if path.ends_with(".html") || path.ends_with(".htm") {
+        Some("text/html")
+    } else if path.ends_with(".txt") {
+        Some("text/plain")
+    } else {
+        None
+    }
+}
+
+/// Convert a transcript in any of the Podcasting 2.0 formats to plain text.
+fn transcript_to_text(body: &str, mime: Option<&str>) -> String {
+    match mime.unwrap_or_default() {
+        "application/json" => {
+            let Ok(value) = serde_json::from_str::<Value>(body) else {
+                return body.to_string();
+            };
+            let mut out = String::new();
+            let mut last_speaker: Option<String> = None;
+            for segment in value["segments"].as_array().into_iter().flatten() {
+                let Some(text) = segment["body"].as_str() else {
+                    continue;
+                };
+                let speaker = segment["speaker"].as_str().map(str::to_string);
+                if speaker.is_some() && speaker != last_speaker {
+                    if !out.is_empty() {
+                        out.push_str("\n\n");
+                    }
+                    out.push_str(&format!("{}: ", speaker.as_deref().unwrap_or_default()));
+                    last_speaker = speaker;
+                } else if !out.is_empty() {
+                    out.push(' ');
+                }
+                out.push_str(text.trim());
+            }
+            out
+        }
+        "text/vtt" | "application/x-subrip" | "application/srt" => subtitles_to_text(body),
+        "text/html" => html_to_text(body),
+        _ => body.trim().to_string(),
+    }
+}
+
+fn format_itunes_show(r: &Value) -> Value {
+    json!({
+        "id": r["collectionId"],
+        "title": r["collectionName"],
+        "author": r["artistName"],
+        "feed_url": r["feedUrl"],
+        "genres": r["genres"],
+        "episode_count": r["trackCount"],
+        "latest_release": r["releaseDate"],
+        "artwork": r["artworkUrl600"],
+        "url": r["collectionViewUrl"],
+    })
+}
+
+fn format_itunes_episode(r: &Value) -> Value {
+    json!({
+        "podcast_id": r["collectionId"],
+        "podcast": r["collectionName"],
+        "guid": r["episodeGuid"],
+        "title": r["trackName"],
+        "description": r["description"],
+        "published": r["releaseDate"],
+        "duration_seconds": r["trackTimeMillis"].as_u64().map(|ms| ms / 1000),
+        "audio_url": r["episodeUrl"],
+        "feed_url": r["feedUrl"],
+        "url": r["trackViewUrl"],
+    })
+}
+
+#[async_trait]
+impl Connector for PodcastsConnector {
+    fn name(&self) -> &'static str {
+        "podcasts"
+    }
+
+    fn description(&self) -> &'static str {
+        "Podcast search (iTunes directory), episode metadata from RSS feeds, and Podcasting 2.0 transcripts."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        Ok(AuthDetails::new())
+    }
+
+    async fn set_auth_details(&mut self, _details: AuthDetails) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema { fields: vec![] }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Use `search_podcasts` or `search_episodes` to find shows, then `list_episodes` \
+(by podcast id or feed_url). `get_transcript` returns plain text when the feed publishes \
+<podcast:transcript> tags."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let feed_props = json!({
+            "id": { "type": "string", "description": "Podcast id (iTunes collection id)" },
+            "feed_url": { "type": "string", "description": "RSS feed URL (alternative to id)" }
+        });
+        let episode_props = {
+            let mut props = feed_props.clone();
+            props["guid"] = json!({ "type": "string", "description": "Episode GUID" });
+            props["title"] = json!({ "type": "string", "description": "Episode title substring (alternative to guid)" });
+            props
+        };
+        let transcript_props = {
+            let mut props = episode_props.clone();
+            props["url"] =
+                json!({ "type": "string", "description": "Transcript URL (skips feed lookup)" });
+            props
+        };
+        let list_props = {
+            let mut props = feed_props.clone();
+            props["limit"] =
+                json!({ "type": "integer", "description": "Max episodes (default: 20)" });
+            props
+        };
+
+        let tools = vec![
+            tool(
+                "search_podcasts",
+                "Search podcast shows. Example: query=\"hardcore history\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Show name or topic" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10, max: 200)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "search_episodes",
+                "Search podcast episodes across shows. Example: query=\"rust programming\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Episode topic or guest" },
+                        "limit": { "type": "integer", "description": "Max results (default: 10, max: 200)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "list_episodes",
+                "Show metadata and recent episodes from the RSS feed, with enclosure URLs and transcript links. \
+Example: id=\"1150510297\" limit=10.",
+                json!({ "type": "object", "properties": list_props }),
+            ),
+            tool(
+                "get_episode",
+                "One episode's metadata, audio enclosure and transcript links (latest if no guid/title). \
+Example: feed_url=\"https://feeds.example.com/show.xml\" title=\"episode 42\".",
+                json!({ "type": "object", "properties": episode_props }),
+            ),
+            tool(
+                "get_transcript",
+                "Episode transcript as plain text (from <podcast:transcript>: JSON, VTT, SRT, HTML or text). \
+Example: id=\"1150510297\" title=\"episode 42\".",
+                json!({ "type": "object", "properties": transcript_props }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "search_podcasts" | "search_episodes" => {
+                let shows = request.name.as_ref() == "search_podcasts";
+                let args: SearchArgs = parse_args(request.arguments)?;
+                let params = [
+                    ("term", args.query.clone()),
+                    ("media", "podcast".to_string()),
+                    (
+                        "entity",
+                        if shows { "podcast" } else { "podcastEpisode" }.to_string(),
+                    ),
+                    ("limit", args.limit.unwrap_or(10).clamp(1, 200).to_string()),
+                ];
+                let results = self.itunes("search", &params).await?;
+                let results: Vec<Value> = results
+                    .iter()
+                    .map(|r| {
+                        if shows {
+                            format_itunes_show(r)
+                        } else {
+                            format_itunes_episode(r)
+                        }
+                    })
+                    .collect();
+
+                let data = json!({
+                    "query": args.query,
+                    "count": results.len(),
+                    "results": results,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "list_episodes" => {
+                let args: FeedArgs = parse_args(request.arguments)?;
+                let (feed_url, feed) = self
+                    .load_feed(args.id.as_deref(), args.feed_url.as_deref())
+                    .await?;
+                let episodes: Vec<Value> = feed
+                    .episodes
+                    .iter()
+                    .take(args.limit.unwrap_or(20))
+                    .map(|e| e.to_json(false))
+                    .collect();
+
+                let data = json!({
+                    "feed_url": feed_url,
+                    "title": feed.title,
+                    "author": feed.author,
+                    "description": feed.description.as_deref().map(html_to_text),
+                    "link": feed.link,
+                    "image": feed.image,
+                    "language": feed.language,
+                    "total_episodes": feed.episodes.len(),
+                    "count": episodes.len(),
+                    "episodes": episodes,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_episode" => {
+                let args: EpisodeArgs = parse_args(request.arguments)?;
+                let (feed_url, feed, episode) = self
+                    .find_episode(
+                        args.id.as_deref(),
+                        args.feed_url.as_deref(),
+                        args.guid.as_deref(),
+                        args.title.as_deref(),
+                    )
+                    .await?;
+
+                let mut data = episode.to_json(true);
+                data["podcast"] = json!(feed.title);
+                data["feed_url"] = json!(feed_url);
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_transcript" => {
+                let args: TranscriptArgs = parse_args(request.arguments)?;
+                let (transcript, episode_title) = match args.url {
+                    Some(url) => (
+                        Transcript {
+                            mime: guess_mime(&url).map(str::to_string),
+                            url,
+                            language: None,
+                        },
+                        None,
+                    ),
+                    None => {
+                        let (_, _, episode) = self
+                            .find_episode(
+                                args.id.as_deref(),
+                                args.feed_url.as_deref(),
+                                args.guid.as_deref(),
+                                args.title.as_deref(),
+                            )
+                            .await?;
+                        let best = episode
+                            .transcripts
+                            .iter()
+                            .min_by_key(|t| transcript_rank(t.mime.as_deref()))
+                            .cloned()
+                            .ok_or_else(|| {
+                                ConnectorError::Other(format!(
+                                    "Episode '{}' does not publish a transcript",
+                                    episode.title.as_deref().unwrap_or("unknown")
+                                ))
+                            })?;
+                        (best, episode.title)
+                    }
+                };
+
+                let body = self.get_text(&transcript.url, &[]).await?;
+                let mime = transcript
+                    .mime
+                    .clone()
+                    .or_else(|| guess_mime(&transcript.url).map(str::to_string));
+                let text = transcript_to_text(&body, mime.as_deref());
+
+                let data = json!({
+                    "episode": episode_title,
+                    "transcript_url": transcript.url,
+                    "format": mime,
+                    "language": transcript.language,
+                    "text": text,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_feed_with_transcripts() {
+        let xml = r#"<?xml version="1.0"?>
+<rss xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd" xmlns:podcast="https://podcastindex.org/namespace/1.0">
+<channel>
+  <title>Example Show</title>
+  <itunes:author>Host</itunes:author>
+  <itunes:image href="https://example.com/art.jpg"/>
+  <item>
+    <title>Episode 1</title>
+    <guid>ep-1</guid>
+    <description><![CDATA[<p>Hello</p>]]></description>
+    <enclosure url="https://example.com/ep1.mp3" type="audio/mpeg" length="1234"/>
+    <podcast:transcript url="https://example.com/ep1.vtt" type="text/vtt"/>
+    <podcast:transcript url="https://example.com/ep1.json" type="application/json" language="en"/>
+  </item>
+</channel>
+</rss>"#;
+
+        let feed = parse_feed(xml).unwrap();
+        assert_eq!(feed.title.as_deref(), Some("Example Show"));
+        assert_eq!(feed.author.as_deref(), Some("Host"));
+        assert_eq!(feed.image.as_deref(), Some("https://example.com/art.jpg"));
+        assert_eq!(feed.episodes.len(), 1);
+
+        let ep = &feed.episodes[0];
+        assert_eq!(ep.guid.as_deref(), Some("ep-1"));
+        assert_eq!(ep.audio_url.as_deref(), Some("https://example.com/ep1.mp3"));
+        assert_eq!(ep.audio_length, Some(1234));
+        assert_eq!(ep.transcripts.len(), 2);
+        let best = ep
+            .transcripts
+            .iter()
+            .min_by_key(|t| transcript_rank(t.mime.as_deref()))
+            .unwrap();
+        assert_eq!(best.url, "https://example.com/ep1.json");
+    }
+
+    #[test]
+    fn converts_vtt_and_json_transcripts() {
+        let vtt = "WEBVTT\n\n1\n00:00:00.000 --> 00:00:02.000\n<v Alice>Hello there\n\n2\n00:00:02.000 --> 00:00:04.000\nGeneral Kenobi\n";
+        assert_eq!(
+            transcript_to_text(vtt, Some("text/vtt")),
+            "Hello there General Kenobi"
+        );
+
+        let json_body = r#"{"segments":[{"speaker":"A","body":"Hi."},{"speaker":"A","body":"Welcome."},{"speaker":"B","body":"Thanks."}]}"#;
+        assert_eq!(
+            transcript_to_text(json_body, Some("application/json")),
+            "A: Hi. Welcome.\n\nB: Thanks."
+        );
+    }
+}
@@ -30,6 +30,8 @@ fn parse_response_format(s: Option<&str>) -> ResponseFormat {
 }
 
 mod parse;
+mod pmc;
+mod query;
 use parse::{parse_pubmed_search_document, SearchParseInput};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -146,6 +148,7 @@ pub struct PubMedConnector {
 
 const MAX_SEARCH_LIMIT: usize = 5_000;
 const MAX_SEARCH_PAGES: usize = 100;
+const DEFAULT_FULLTEXT_CHARS: usize = 60_000;
 
 impl PubMedConnector {
     pub async fn new() -> Result<Self, ConnectorError> {
@@ -182,8 +185,8 @@ impl PubMedConnector {
         limit: usize,
         date_range: Option<(u32, u32)>,
     ) -> Result<PubMedSearchResult, ConnectorError> {
-        // URL encode the query
-        let encoded_query = query.replace(" ", "+");
+        // Compiled queries carry quotes and brackets, so encode fully
+        let encoded_query = urlencoding::encode(query);
 
         // Build the URL with date range if provided
         let url = if let Some((start_year, end_year)) = date_range {
@@ -362,6 +365,53 @@ impl PubMedConnector {
         })
     }
 
+    /// Map a PMID or PMCID to both identifiers with the NCBI ID converter.
+    async fn convert_ids(&self, id: &str) -> Result<pmc::IdRecord, ConnectorError> {
+        let content = self
+            .client
+            .get("https://www.ncbi.nlm.nih.gov/pmc/utils/idconv/v1.0/")
+            .query(&[("ids", id), ("format", "json"), ("tool", "arivu")])
+            .send()
+            .await
+            .map_err(|e| ConnectorError::Other(e.to_string()))?
+            .error_for_status()
+            .map_err(|e| ConnectorError::Other(e.to_string()))?
+            .text()
+            .await
+            .map_err(|e| ConnectorError::Other(e.to_string()))?;
+        pmc::parse_idconv(&content)
+    }
+
+    /// Fetch a PMC article as JATS XML and parse it on the CPU pool.
+    async fn get_pmc_article(&self, pmcid: &str) -> Result<pmc::PmcArticle, ConnectorError> {
+        let content = self
+            .client
+            .get("https://eutils.ncbi.nlm.nih.gov/entrez/eutils/efetch.fcgi")
+            .query(&[
+                ("db", "pmc"),
+                ("id", pmcid.trim_start_matches("PMC")),
+                ("retmode", "xml"),
+                ("tool", "arivu"),
+            ])
+            .send()
+            .await
+            .map_err(|e| ConnectorError::Other(e.to_string()))?
+            .error_for_status()
+            .map_err(|e| ConnectorError::Other(e.to_string()))?
+            .text()
+            .await
+            .map_err(|e| ConnectorError::Other(e.to_string()))?;
+
+        debug!(
+            target: "connector.pubmed",
+            pmcid,
+            bytes = content.len(),
+            queue_depth = cpu_pool::queue_depth(),
+            "dispatching pmc article parse to datasourcer cpu pool"
+        );
+        cpu_pool::spawn_cpu(move || pmc::parse_jats(&content)).await
+    }
+
     async fn get_article_abstract(&self, pmid: &str) -> Result<PubMedAbstract, ConnectorError> {
         let url = format!("https://pubmed.ncbi.nlm.nih.gov/{}/", pmid);
 
@@ -845,8 +895,10 @@ impl Connector for PubMedConnector {
                     name: Cow::Borrowed("search"),
                     title: None,
                     description: Some(Cow::Borrowed(
-                        "Search PubMed by query. Use when you want PMIDs to pass into \
-get. Tip: keep limit small for concise output. Example: query=\"CRISPR AND off-target\".",
+                        "Search PubMed by query and/or structured filters (MeSH terms, \
+publication types, species, dates) compiled into PubMed field tags. Use when you want PMIDs to \
+pass into get. Tip: keep limit small for concise output. Example: query=\"CRISPR AND \
+off-target\", publication_types=[\"review\"].",
                     )),
                     input_schema: Arc::new(json!({
                         "type": "object",
@@ -865,6 +917,29 @@ get. Tip: keep limit small for concise output. Example: query=\"CRISPR AND off-t
 	                                "minimum": 0,
 	                                "maximum": 5000
 	                            },
+                            "mesh_terms": {
+                                "type": "array",
+                                "items": {"type": "string"},
+                                "description": "MeSH headings that must all apply, e.g. [\"Sleep Apnea, Obstructive\"]. Compiled to \"term\"[mh] (includes narrower terms)."
+                            },
+                            "publication_types": {
+                                "type": "array",
+                                "items": {"type": "string"},
+                                "description": "Publication types, any of which may match: rct, clinical trial, meta-analysis, systematic review, review, case reports, guideline, or any PubMed [pt] value"
+                            },
+                            "species": {
+                                "type": "string",
+                                "enum": ["humans", "animals"],
+                                "description": "Restrict to human studies, or to animal studies excluding humans"
+                            },
+                            "start_date": {
+                                "type": "string",
+                                "description": "Earliest publication date: YYYY, YYYY/MM or YYYY/MM/DD"
+                            },
+                            "end_date": {
+                                "type": "string",
+                                "description": "Latest publication date: YYYY, YYYY/MM or YYYY/MM/DD"
+                            },
                             "start_year": {
                                 "type": "integer",
                                 "description": "Start year for publication date range filter"
@@ -879,8 +954,7 @@ get. Tip: keep limit small for concise output. Example: query=\"CRISPR AND off-t
                                 "description": "Response verbosity: 'concise' returns only pmid/title/authors, 'detailed' includes all metadata",
                                 "default": "concise"
                             }
-                        },
-                        "required": ["query"]
+                        }
                     }).as_object().expect("Schema object").clone()),
                     output_schema: None,
                     annotations: None,
@@ -913,6 +987,40 @@ only need title+abstract_text. Example: pmid=\"34762503\".",
                     annotations: None,
                     icons: None,
                 },
+                Tool {
+                    name: Cow::Borrowed("get_fulltext"),
+                    title: None,
+                    description: Some(Cow::Borrowed(
+                        "Get full text by PMID or PMCID when the article is in PubMed Central: \
+sections (heading, level, text) plus figure and table captions. Falls back to the abstract \
+otherwise (source='abstract'). Example: pmid=\"23193287\" sections=[\"methods\"].",
+                    )),
+                    input_schema: Arc::new(json!({
+                        "type": "object",
+                        "properties": {
+                            "pmid": {
+                                "type": "string",
+                                "description": "The PubMed ID (PMID) of the article (e.g., '23193287')"
+                            },
+                            "pmcid": {
+                                "type": "string",
+                                "description": "The PubMed Central ID (e.g., 'PMC3531190'); used instead of pmid"
+                            },
+                            "sections": {
+                                "type": "array",
+                                "items": { "type": "string" },
+                                "description": "Only return sections whose heading contains one of these (case-insensitive)"
+                            },
+                            "max_chars": {
+                                "type": "integer",
+                                "description": "Cap on total section text; later sections are dropped once reached (default: 60000)"
+                            }
+                        }
+                    }).as_object().expect("Schema object").clone()),
+                    output_schema: None,
+                    annotations: None,
+                    icons: None,
+                },
             ],
             next_cursor: None,
         })
@@ -926,9 +1034,32 @@ only need title+abstract_text. Example: pmid=\"34762503\".",
 
         match request.name.as_ref() {
             "search" => {
-                let query = args.get("query").and_then(|v| v.as_str()).ok_or(
-                    ConnectorError::InvalidParams("Missing 'query' parameter".to_string()),
-                )?;
+                let strings = |key: &str| -> Vec<String> {
+                    match args.get(key) {
+                        Some(serde_json::Value::Array(items)) => items
+                            .iter()
+                            .filter_map(|v| v.as_str().map(str::to_string))
+                            .collect(),
+                        Some(serde_json::Value::String(item)) => vec![item.clone()],
+                        _ => Vec::new(),
+                    }
+                };
+                let string = |key: &str| {
+                    args.get(key)
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string)
+                        .filter(|s| !s.trim().is_empty())
+                };
+                let filters = query::SearchFilters {
+                    mesh_terms: strings("mesh_terms"),
+                    publication_types: strings("publication_types"),
+                    species: string("species"),
+                    start_date: string("start_date"),
+                    end_date: string("end_date"),
+                };
+                let text = args.get("query").and_then(|v| v.as_str()).unwrap_or("");
+                let compiled = query::build_query(text, &filters)?;
+                let query = compiled.as_str();
 
                 // Make all parameters optional
                 let page = args.get("page").and_then(|v| v.as_u64()).unwrap_or(1) as usize;
@@ -974,10 +1105,14 @@ only need title+abstract_text. Example: pmid=\"34762503\".",
                             })
                         })
                         .collect();
-                    let concise_result = json!({
+                    let mut concise_result = json!({
                         "articles": concise_articles,
                         "total_results": result.total_results
                     });
+                    // Echo the compiled query so callers can refine it by hand
+                    if !filters.is_empty() {
+                        concise_result["query"] = json!(compiled);
+                    }
                     let text = serde_json::to_string(&concise_result)?;
                     Ok(structured_result_with_text(&concise_result, Some(text))?)
                 } else {
@@ -1014,6 +1149,137 @@ only need title+abstract_text. Example: pmid=\"34762503\".",
                     Ok(structured_result_with_text(&abstract_data, Some(text))?)
                 }
             }
+            "get_fulltext" => {
+                let pmid = args
+                    .get("pmid")
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty());
+                let pmcid = match args.get("pmcid").and_then(|v| v.as_str()) {
+                    Some(raw) => Some(pmc::normalize_pmcid(raw).ok_or_else(|| {
+                        ConnectorError::InvalidParams(format!(
+                            "Invalid PMCID '{}'. Expected e.g. 'PMC3531190'",
+                            raw
+                        ))
+                    })?),
+                    None => None,
+                };
+                if pmid.is_none() && pmcid.is_none() {
+                    return Err(ConnectorError::InvalidParams(
+                        "Missing 'pmid' or 'pmcid' parameter. Expected a PubMed ID (e.g., '23193287')"
+                            .to_string(),
+                    ));
+                }
+                if pmid
+                    .as_deref()
+                    .is_some_and(|p| !p.chars().all(|c| c.is_ascii_digit()))
+                {
+                    return Err(ConnectorError::InvalidParams(
+                        "Invalid 'pmid': expected digits only (e.g., '23193287')".to_string(),
+                    ));
+                }
+                let wanted: Vec<String> = args
+                    .get("sections")
+                    .and_then(|v| v.as_array())
+                    .map(|items| {
+                        items
+                            .iter()
+                            .filter_map(|v| v.as_str().map(str::to_string))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let max_chars = args
+                    .get("max_chars")
+                    .and_then(|v| v.as_u64())
+                    .map(|n| n as usize)
+                    .unwrap_or(DEFAULT_FULLTEXT_CHARS);
+
+                // Fill in whichever identifier is missing; not every PMID has a PMCID
+                let ids = match (&pmid, &pmcid) {
+                    (Some(id), None) | (None, Some(id)) => {
+                        self.convert_ids(id).await.unwrap_or_else(|e| {
+                            error!("PMC ID conversion failed: {}", e);
+                            pmc::IdRecord::default()
+                        })
+                    }
+                    _ => pmc::IdRecord::default(),
+                };
+                let pmid = pmid.or(ids.pmid);
+                let pmcid = pmcid.or(ids.pmcid);
+
+                let article = match pmcid.as_deref() {
+                    Some(pmcid) => match self.get_pmc_article(pmcid).await {
+                        Ok(article) => Some(article),
+                        Err(e) => {
+                            error!("PMC fetch failed for {}: {}", pmcid, e);
+                            None
+                        }
+                    },
+                    None => None,
+                }
+                // Publishers can withhold the body from the XML; treat abstract-only as missing
+                .filter(|a| a.sections.iter().any(|s| s.heading != "Abstract"));
+
+                let (source, url, title, mut sections, figures, note) = match (article, &pmid) {
+                    (Some(article), _) => (
+                        "pmc",
+                        format!(
+                            "https://pmc.ncbi.nlm.nih.gov/articles/{}/",
+                            pmcid.as_deref().unwrap_or_default()
+                        ),
+                        article.title,
+                        article.sections,
+                        article.figures,
+                        None,
+                    ),
+                    (None, Some(pmid)) => {
+                        let abstract_data = self.get_article_abstract(pmid).await?;
+                        (
+                            "abstract",
+                            format!("https://pubmed.ncbi.nlm.nih.gov/{}/", pmid),
+                            abstract_data.title,
+                            vec![pmc::PmcSection {
+                                heading: "Abstract".to_string(),
+                                level: 1,
+                                text: abstract_data.abstract_text,
+                            }],
+                            Vec::new(),
+                            Some("Full text is not available in PubMed Central; returning the abstract."),
+                        )
+                    }
+                    (None, None) => {
+                        let data = json!({
+                            "pmcid": pmcid,
+                            "sections": [],
+                        });
+                        let text = serde_json::to_string(&data)?;
+                        return Ok(structured_result_with_text(&data, Some(text))?);
+                    }
+                };
+
+                let headings: Vec<String> = sections.iter().map(|s| s.heading.clone()).collect();
+                let truncated = pmc::select_sections(&mut sections, &wanted, max_chars);
+                let nothing_matched = sections.is_empty() && !wanted.is_empty();
+                let mut data = json!({
+                    "pmid": pmid,
+                    "pmcid": pmcid,
+                    "source": source,
+                    "url": url,
+                    "title": title,
+                    "sections": sections,
+                    "figures": figures,
+                    "truncated": truncated,
+                });
+                if let Some(note) = note {
+                    data["note"] = json!(note);
+                }
+                // Let the caller pick an existing heading
+                if nothing_matched {
+                    data["available_sections"] = json!(headings);
+                }
+                let text = serde_json::to_string(&data)?;
+                Ok(structured_result_with_text(&data, Some(text))?)
+            }
             _ => Err(ConnectorError::ToolNotFound),
         }
     }
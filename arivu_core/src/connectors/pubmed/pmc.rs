@@ -0,0 +1,428 @@
+// src/connectors/pubmed/pmc.rs
+//
+// Full text from PubMed Central. PMIDs are mapped to PMCIDs with the NCBI ID
+// converter, the article is fetched as JATS XML through EFetch, and the body is
+// flattened into sections plus figure/table captions.
+
+use super::ConnectorError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PmcSection {
+    pub heading: String,
+    /// 1 for top-level sections (and the abstract), 2+ for subsections
+    pub level: u8,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PmcCaption {
+    /// "figure" or "table"
+    pub kind: &'static str,
+    /// e.g. "Figure 2"
+    pub label: String,
+    pub caption: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PmcArticle {
+    pub title: String,
+    pub sections: Vec<PmcSection>,
+    pub figures: Vec<PmcCaption>,
+}
+
+/// One record from the ID converter.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(super) struct IdRecord {
+    #[serde(default)]
+    pub pmid: Option<String>,
+    #[serde(default)]
+    pub pmcid: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IdConvResponse {
+    #[serde(default)]
+    records: Vec<IdRecord>,
+}
+
+pub(super) fn parse_idconv(json: &str) -> Result<IdRecord, ConnectorError> {
+    let response: IdConvResponse = serde_json::from_str(json)?;
+    Ok(response.records.into_iter().next().unwrap_or_default())
+}
+
+/// "PMC1234567", "pmc1234567" or "1234567" → "PMC1234567"
+pub(super) fn normalize_pmcid(id: &str) -> Option<String> {
+    let id = id.trim();
+    let digits = if id.len() > 3 && id[..3].eq_ignore_ascii_case("pmc") {
+        &id[3..]
+    } else {
+        id
+    };
+    (!digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()))
+        .then(|| format!("PMC{}", digits))
+}
+
+enum Capture {
+    None,
+    ArticleTitle,
+    Heading,
+    Paragraph,
+    Abstract,
+    FigureLabel,
+    FigureCaption,
+}
+
+struct PendingFigure {
+    kind: &'static str,
+    label: String,
+    caption: String,
+}
+
+/// Flatten a JATS `<article>` into title, abstract + body sections and captions.
+/// Back matter (references) and sub-articles (e.g. review letters) are skipped.
+pub(super) fn parse_jats(xml: &str) -> Result<PmcArticle, ConnectorError> {
+    // Subtrees whose text is noise in running prose
+    const SKIP: &[&[u8]] = &[
+        b"back",
+        b"sub-article",
+        b"table",
+        b"mml:math",
+        b"tex-math",
+        b"graphic",
+        b"inline-graphic",
+        b"object-id",
+        b"ref-list",
+    ];
+
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(false);
+
+    let mut article = PmcArticle::default();
+    let mut stack: Vec<Vec<u8>> = Vec::new();
+    let mut skip_depth: Option<usize> = None;
+    let mut sec_depth = 0u8;
+    let mut seen_abstract = false;
+    let mut in_abstract = false;
+    let mut figure: Option<PendingFigure> = None;
+    let mut capture = Capture::None;
+    let mut buffer = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buffer) {
+            Ok(Event::Start(ref e)) => {
+                let name = e.name().as_ref().to_vec();
+                stack.push(name.clone());
+                if skip_depth.is_some() {
+                    buffer.clear();
+                    continue;
+                }
+                if SKIP.contains(&name.as_slice()) {
+                    skip_depth = Some(stack.len());
+                    buffer.clear();
+                    continue;
+                }
+                let parent = stack.iter().rev().nth(1).map(Vec::as_slice);
+                match name.as_slice() {
+                    b"article-title"
+                        if article.title.is_empty() && within(&stack, b"title-group") =>
+                    {
+                        capture = Capture::ArticleTitle;
+                    }
+                    b"abstract" if !seen_abstract && !is_special_abstract(e) => {
+                        seen_abstract = true;
+                        in_abstract = true;
+                        article.sections.push(PmcSection {
+                            heading: "Abstract".to_string(),
+                            level: 1,
+                            text: String::new(),
+                        });
+                        capture = Capture::Abstract;
+                    }
+                    // Holds any text that precedes the first section
+                    b"body" => article.sections.push(PmcSection {
+                        heading: String::new(),
+                        level: 1,
+                        text: String::new(),
+                    }),
+                    b"sec" if within(&stack, b"body") => {
+                        sec_depth += 1;
+                        article.sections.push(PmcSection {
+                            heading: String::new(),
+                            level: sec_depth,
+                            text: String::new(),
+                        });
+                        capture = Capture::None;
+                    }
+                    b"title" if parent == Some(&b"sec"[..]) && within(&stack, b"body") => {
+                        capture = Capture::Heading;
+                    }
+                    b"fig" | b"table-wrap" if within(&stack, b"body") => {
+                        figure = Some(PendingFigure {
+                            kind: if name == b"fig" { "figure" } else { "table" },
+                            label: String::new(),
+                            caption: String::new(),
+                        });
+                    }
+                    b"label" if figure.is_some() => capture = Capture::FigureLabel,
+                    b"caption" if figure.is_some() => capture = Capture::FigureCaption,
+                    b"p" if figure.is_none() && within(&stack, b"body") => {
+                        capture = Capture::Paragraph;
+                    }
+                    _ => {}
+                }
+            }
+            Ok(Event::Empty(ref e)) if skip_depth.is_none() && e.name().as_ref() == b"break" => {
+                push_text(&mut article, &mut figure, &capture, "\n");
+            }
+            Ok(Event::Text(ref e)) if skip_depth.is_none() => {
+                let text = e.unescape().map_err(|_| ConnectorError::ParseError)?;
+                push_text(&mut article, &mut figure, &capture, &text);
+            }
+            Ok(Event::CData(ref e)) if skip_depth.is_none() => {
+                let text = String::from_utf8_lossy(e.as_ref()).to_string();
+                push_text(&mut article, &mut figure, &capture, &text);
+            }
+            Ok(Event::End(ref e)) => {
+                let name = e.name().as_ref().to_vec();
+                let depth = stack.len();
+                stack.pop();
+                if let Some(d) = skip_depth {
+                    if d == depth {
+                        skip_depth = None;
+                    }
+                    buffer.clear();
+                    continue;
+                }
+                match name.as_slice() {
+                    b"article-title" if matches!(capture, Capture::ArticleTitle) => {
+                        article.title = squash(&article.title);
+                        capture = Capture::None;
+                    }
+                    b"abstract" if in_abstract => {
+                        in_abstract = false;
+                        capture = Capture::None;
+                    }
+                    b"sec" if within(&stack, b"body") => {
+                        sec_depth = sec_depth.saturating_sub(1);
+                    }
+                    b"title" if matches!(capture, Capture::Heading) => {
+                        if let Some(section) = article.sections.last_mut() {
+                            section.heading = squash(&section.heading);
+                        }
+                        capture = Capture::None;
+                    }
+                    // Titles and paragraphs inside a structured abstract
+                    b"p" | b"title" if in_abstract => {
+                        if let Some(section) = article.sections.last_mut() {
+                            section.text.push_str("\n\n");
+                        }
+                    }
+                    b"p" if matches!(capture, Capture::Paragraph) => {
+                        if let Some(section) = article.sections.last_mut() {
+                            section.text.push_str("\n\n");
+                        }
+                        // Paragraphs nest inside lists and boxed text
+                        if !within(&stack, b"p") {
+                            capture = Capture::None;
+                        }
+                    }
+                    // Caption titles and paragraphs run together otherwise
+                    b"title" | b"p" if matches!(capture, Capture::FigureCaption) => {
+                        if let Some(figure) = figure.as_mut() {
+                            figure.caption.push(' ');
+                        }
+                    }
+                    b"label" | b"caption" if figure.is_some() => capture = Capture::None,
+                    b"fig" | b"table-wrap" => {
+                        if let Some(pending) = figure.take() {
+                            if !pending.caption.trim().is_empty() || !pending.label.is_empty() {
+                                article.figures.push(PmcCaption {
+                                    kind: pending.kind,
+                                    label: squash(&pending.label),
+                                    caption: squash(&pending.caption),
+                                });
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(_) => return Err(ConnectorError::ParseError),
+            _ => {}
+        }
+        buffer.clear();
+    }
+
+    for section in article.sections.iter_mut() {
+        section.text = section
+            .text
+            .split("\n\n")
+            .map(squash)
+            .filter(|p| !p.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+    }
+    article
+        .sections
+        .retain(|s| !s.text.is_empty() || !s.heading.is_empty());
+    Ok(article)
+}
+
+/// Keep sections whose heading contains one of `wanted` (case-insensitive) and
+/// cap the total text at `max_chars`. Returns whether anything was cut.
+pub(super) fn select_sections(
+    sections: &mut Vec<PmcSection>,
+    wanted: &[String],
+    max_chars: usize,
+) -> bool {
+    if !wanted.is_empty() {
+        let wanted: Vec<String> = wanted.iter().map(|w| w.to_lowercase()).collect();
+        sections.retain(|s| {
+            let heading = s.heading.to_lowercase();
+            wanted.iter().any(|w| heading.contains(w.as_str()))
+        });
+    }
+
+    let mut remaining = max_chars;
+    let Some(index) = sections.iter().position(|s| {
+        let over = s.text.len() > remaining;
+        remaining = remaining.saturating_sub(s.text.len());
+        over
+    }) else {
+        return false;
+    };
+    let used: usize = sections[..index].iter().map(|s| s.text.len()).sum();
+    let section = &mut sections[index];
+    let mut cut = max_chars - used;
+    while !section.text.is_char_boundary(cut) {
+        cut -= 1;
+    }
+    section.text.truncate(cut);
+    let keep = if section.text.is_empty() {
+        index
+    } else {
+        index + 1
+    };
+    sections.truncate(keep);
+    true
+}
+
+fn push_text(
+    article: &mut PmcArticle,
+    figure: &mut Option<PendingFigure>,
+    capture: &Capture,
+    text: &str,
+) {
+    match capture {
+        Capture::None => {}
+        Capture::ArticleTitle => article.title.push_str(text),
+        Capture::Heading => {
+            if let Some(section) = article.sections.last_mut() {
+                section.heading.push_str(text);
+            }
+        }
+        Capture::Paragraph | Capture::Abstract => {
+            if let Some(section) = article.sections.last_mut() {
+                section.text.push_str(text);
+            }
+        }
+        Capture::FigureLabel => {
+            if let Some(figure) = figure.as_mut() {
+                figure.label.push_str(text);
+            }
+        }
+        Capture::FigureCaption => {
+            if let Some(figure) = figure.as_mut() {
+                figure.caption.push_str(text);
+            }
+        }
+    }
+}
+
+fn within(stack: &[Vec<u8>], name: &[u8]) -> bool {
+    stack.iter().any(|n| n.as_slice() == name)
+}
+
+/// Graphical abstracts, teasers and author summaries are not the abstract.
+fn is_special_abstract(e: &BytesStart) -> bool {
+    e.attributes()
+        .filter_map(Result::ok)
+        .any(|a| a.key.as_ref() == b"abstract-type")
+}
+
+fn squash(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_jats_article() {
+        let xml = r#"<?xml version="1.0"?>
+<pmc-articleset><article>
+<front><article-meta>
+  <title-group><article-title>Gut <italic>microbiome</italic> and sleep</article-title></title-group>
+  <abstract><sec><title>Background</title><p>Sleep matters.</p></sec></abstract>
+  <abstract abstract-type="graphical"><p>Picture.</p></abstract>
+</article-meta></front>
+<body>
+  <sec><title>Introduction</title>
+    <p>Microbes <xref ref-type="bibr">[1]</xref> affect sleep <inline-formula><mml:math><mml:mi>x</mml:mi></mml:math></inline-formula>.</p>
+    <fig id="f1"><label>Figure 1</label><caption><title>Design.</title><p>Study flow.</p></caption><graphic/></fig>
+    <sec><title>Cohort</title><p>We enrolled 40 adults.</p></sec>
+  </sec>
+  <sec><title>Results</title>
+    <table-wrap><label>Table 1</label><caption><p>Baseline.</p></caption><table><tr><td>42</td></tr></table></table-wrap>
+    <p>Sleep improved.</p>
+  </sec>
+</body>
+<back><ref-list><ref>Someone 2020</ref></ref-list></back>
+</article></pmc-articleset>"#;
+
+        let article = parse_jats(xml).unwrap();
+        assert_eq!(article.title, "Gut microbiome and sleep");
+        let sections: Vec<(&str, u8, &str)> = article
+            .sections
+            .iter()
+            .map(|s| (s.heading.as_str(), s.level, s.text.as_str()))
+            .collect();
+        assert_eq!(
+            sections,
+            vec![
+                ("Abstract", 1, "Background\n\nSleep matters."),
+                ("Introduction", 1, "Microbes [1] affect sleep ."),
+                ("Cohort", 2, "We enrolled 40 adults."),
+                ("Results", 1, "Sleep improved."),
+            ]
+        );
+        assert_eq!(
+            article.figures,
+            vec![
+                PmcCaption {
+                    kind: "figure",
+                    label: "Figure 1".to_string(),
+                    caption: "Design. Study flow.".to_string()
+                },
+                PmcCaption {
+                    kind: "table",
+                    label: "Table 1".to_string(),
+                    caption: "Baseline.".to_string()
+                },
+            ]
+        );
+
+        assert_eq!(normalize_pmcid("pmc123").as_deref(), Some("PMC123"));
+        assert_eq!(normalize_pmcid("123").as_deref(), Some("PMC123"));
+        assert!(normalize_pmcid("PMCabc").is_none());
+        let record =
+            parse_idconv(r#"{"status":"ok","records":[{"pmcid":"PMC3531190","pmid":"23193287"}]}"#)
+                .unwrap();
+        assert_eq!(record.pmcid.as_deref(), Some("PMC3531190"));
+    }
+}
@@ -0,0 +1,178 @@
+// src/connectors/pubmed/query.rs
+//
+// Structured search filters compiled into PubMed field-tag syntax, so callers
+// can ask for MeSH headings, publication types, species and dates without
+// knowing `[mh]`/`[pt]`/`[dp]`.
+
+use super::ConnectorError;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(super) struct SearchFilters {
+    pub mesh_terms: Vec<String>,
+    pub publication_types: Vec<String>,
+    pub species: Option<String>,
+    /// YYYY, YYYY/MM or YYYY/MM/DD (dashes accepted)
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+}
+
+impl SearchFilters {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// Friendly names for common publication types.
+fn publication_type(name: &str) -> String {
+    match name.trim().to_lowercase().as_str() {
+        "rct" | "randomized controlled trial" | "randomised controlled trial" => {
+            "Randomized Controlled Trial".to_string()
+        }
+        "clinical trial" | "trial" => "Clinical Trial".to_string(),
+        "meta-analysis" | "meta analysis" | "metaanalysis" => "Meta-Analysis".to_string(),
+        "systematic review" => "Systematic Review".to_string(),
+        "review" => "Review".to_string(),
+        "case report" | "case reports" => "Case Reports".to_string(),
+        "guideline" | "practice guideline" => "Practice Guideline".to_string(),
+        "observational study" => "Observational Study".to_string(),
+        _ => name.trim().to_string(),
+    }
+}
+
+/// Quote a term for a field tag; quotes and brackets would end the term early.
+fn tagged(term: &str, tag: &str) -> Result<String, ConnectorError> {
+    let term = term.trim();
+    if term.is_empty() || term.contains(['"', '[', ']']) {
+        return Err(ConnectorError::InvalidParams(format!(
+            "Invalid term '{}': must be non-empty and free of quotes and brackets",
+            term
+        )));
+    }
+    Ok(format!("\"{}\"[{}]", term, tag))
+}
+
+/// "2020" → "2020", "2020-3-5" → "2020/03/05"
+fn pubmed_date(value: &str) -> Result<String, ConnectorError> {
+    let parts: Vec<&str> = value.trim().split(['/', '-']).collect();
+    let valid = !parts.is_empty()
+        && parts.len() <= 3
+        && parts[0].len() == 4
+        && parts
+            .iter()
+            .all(|p| !p.is_empty() && p.len() <= 4 && p.chars().all(|c| c.is_ascii_digit()));
+    if !valid {
+        return Err(ConnectorError::InvalidParams(format!(
+            "Invalid date '{}': expected YYYY, YYYY/MM or YYYY/MM/DD",
+            value
+        )));
+    }
+    Ok(parts
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            if i == 0 {
+                p.to_string()
+            } else {
+                format!("{:0>2}", p)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/"))
+}
+
+/// Combine free text and filters into one PubMed query; every part is ANDed.
+pub(super) fn build_query(text: &str, filters: &SearchFilters) -> Result<String, ConnectorError> {
+    let mut parts: Vec<String> = Vec::new();
+    let text = text.trim();
+    if !text.is_empty() {
+        parts.push(if filters.is_empty() {
+            text.to_string()
+        } else {
+            format!("({})", text)
+        });
+    }
+
+    for term in &filters.mesh_terms {
+        parts.push(tagged(term, "mh")?);
+    }
+
+    if !filters.publication_types.is_empty() {
+        let types = filters
+            .publication_types
+            .iter()
+            .map(|t| tagged(&publication_type(t), "pt"))
+            .collect::<Result<Vec<_>, _>>()?;
+        parts.push(if types.len() == 1 {
+            types.into_iter().next().expect("one type")
+        } else {
+            format!("({})", types.join(" OR "))
+        });
+    }
+
+    if let Some(species) = filters.species.as_deref() {
+        parts.push(match species.trim().to_lowercase().as_str() {
+            "human" | "humans" => "\"humans\"[mh]".to_string(),
+            // PubMed's "Other Animals" filter
+            "animal" | "animals" => "(\"animals\"[mh] NOT \"humans\"[mh])".to_string(),
+            other => {
+                return Err(ConnectorError::InvalidParams(format!(
+                    "Invalid species '{}': expected 'humans' or 'animals'",
+                    other
+                )))
+            }
+        });
+    }
+
+    if filters.start_date.is_some() || filters.end_date.is_some() {
+        let start = match filters.start_date.as_deref() {
+            Some(date) => pubmed_date(date)?,
+            None => "1800".to_string(),
+        };
+        let end = match filters.end_date.as_deref() {
+            Some(date) => pubmed_date(date)?,
+            None => "3000".to_string(),
+        };
+        parts.push(format!("{}:{}[dp]", start, end));
+    }
+
+    if parts.is_empty() {
+        return Err(ConnectorError::InvalidParams(
+            "Provide a 'query' or at least one filter (mesh_terms, publication_types, species, start_date/end_date)"
+                .to_string(),
+        ));
+    }
+    Ok(parts.join(" AND "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compiles_structured_filters() {
+        let filters = SearchFilters {
+            mesh_terms: vec!["Sleep Apnea, Obstructive".to_string()],
+            publication_types: vec!["rct".to_string(), "meta-analysis".to_string()],
+            species: Some("Humans".to_string()),
+            start_date: Some("2020-3".to_string()),
+            end_date: None,
+        };
+        assert_eq!(
+            build_query("cpap adherence", &filters).unwrap(),
+            "(cpap adherence) AND \"Sleep Apnea, Obstructive\"[mh] AND \
+(\"Randomized Controlled Trial\"[pt] OR \"Meta-Analysis\"[pt]) AND \"humans\"[mh] AND 2020/03:3000[dp]"
+        );
+
+        assert_eq!(
+            build_query("crispr", &SearchFilters::default()).unwrap(),
+            "crispr"
+        );
+        assert!(build_query("", &SearchFilters::default()).is_err());
+        let bad = SearchFilters {
+            mesh_terms: vec!["x\"[tiab]".to_string()],
+            ..Default::default()
+        };
+        assert!(build_query("", &bad).is_err());
+        assert!(pubmed_date("20-01").is_err());
+    }
+}
@@ -0,0 +1,498 @@
+use crate::capabilities::ConnectorConfigSchema;
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use scraper::{Html, Selector};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+const BASE_URL: &str = "https://pypi.org";
+
+#[derive(Debug, Deserialize)]
+struct SearchArgs {
+    query: String,
+    #[serde(default)]
+    page: Option<u32>,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProjectArgs {
+    name: String,
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(default)]
+    max_versions: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DescriptionArgs {
+    name: String,
+    #[serde(default)]
+    version: Option<String>,
+}
+
+/// PEP 503 normalized project name: lowercase, runs of `-`, `_`, `.` become `-`.
+fn normalize_name(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut last_sep = false;
+    for c in name.trim().chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !last_sep {
+                out.push('-');
+            }
+            last_sep = true;
+        } else {
+            out.push(c.to_ascii_lowercase());
+            last_sep = false;
+        }
+    }
+    out
+}
+
+/// Parse result cards from the pypi.org search page (there is no JSON search API).
+fn parse_search_page(html: &str) -> Vec<Value> {
+    let doc = Html::parse_document(html);
+    let sel = |s: &str| Selector::parse(s).expect("valid selector");
+    let card = sel("a.package-snippet");
+    let name = sel(".package-snippet__name");
+    let version = sel(".package-snippet__version");
+    let description = sel(".package-snippet__description");
+    let created = sel(".package-snippet__created time");
+    let text = |el: scraper::ElementRef, s: &Selector| {
+        el.select(s)
+            .next()
+            .map(|e| e.text().collect::<String>().trim().to_string())
+    };
+
+    doc.select(&card)
+        .filter_map(|el| {
+            let project = text(el, &name)?;
+            Some(json!({
+                "name": project,
+                "version": text(el, &version),
+                "summary": text(el, &description),
+                "released": el
+                    .select(&created)
+                    .next()
+                    .and_then(|t| t.value().attr("datetime")),
+                "url": el.value().attr("href").map(|h| format!("{}{}", BASE_URL, h)),
+            }))
+        })
+        .collect()
+}
+
+/// Versions newest first by their earliest file upload time.
+fn release_history(releases: &Value) -> Vec<(String, Option<String>, bool)> {
+    let mut history: Vec<(String, Option<String>, bool)> = releases
+        .as_object()
+        .map(|r| {
+            r.iter()
+                .map(|(version, files)| {
+                    let files = files.as_array().cloned().unwrap_or_default();
+                    let uploaded = files
+                        .iter()
+                        .filter_map(|f| f["upload_time_iso_8601"].as_str())
+                        .min()
+                        .map(str::to_string);
+                    let yanked = !files.is_empty() && files.iter().all(|f| f["yanked"] == true);
+                    (version.clone(), uploaded, yanked)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    history.sort_by(|a, b| b.1.cmp(&a.1));
+    history
+}
+
+pub struct PypiConnector {
+    client: Client,
+}
+
+impl PypiConnector {
+    pub async fn new(_auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+        Ok(Self { client })
+    }
+
+    async fn fetch(&self, url: &str, params: &[(&str, String)]) -> Result<String, ConnectorError> {
+        let response = self
+            .client
+            .get(url)
+            .query(params)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+        match response.status() {
+            s if s.is_success() => response.text().await.map_err(ConnectorError::HttpRequest),
+            StatusCode::NOT_FOUND => Err(ConnectorError::ResourceNotFound),
+            s => Err(ConnectorError::Other(format!(
+                "PyPI returned error status: {}",
+                s
+            ))),
+        }
+    }
+
+    /// JSON API document for a project, or for one release when `version` is set.
+    async fn project_json(
+        &self,
+        name: &str,
+        version: Option<&str>,
+    ) -> Result<Value, ConnectorError> {
+        let name = normalize_name(name);
+        if name.is_empty() {
+            return Err(ConnectorError::InvalidParams(
+                "name must not be empty".to_string(),
+            ));
+        }
+        let url = match version {
+            Some(v) => format!("{}/pypi/{}/{}/json", BASE_URL, name, v.trim()),
+            None => format!("{}/pypi/{}/json", BASE_URL, name),
+        };
+        Ok(serde_json::from_str(&self.fetch(&url, &[]).await?)?)
+    }
+
+    async fn search(&self, args: SearchArgs) -> Result<Value, ConnectorError> {
+        let page = args.page.unwrap_or(1).max(1);
+        let limit = args.limit.unwrap_or(20).clamp(1, 20);
+        let html = self
+            .fetch(
+                &format!("{}/search/", BASE_URL),
+                &[("q", args.query.clone()), ("page", page.to_string())],
+            )
+            .await?;
+        let mut projects = parse_search_page(&html);
+
+        // An exact project name match is the most useful hit and the search
+        // page can be unavailable to scripted clients, so look it up directly.
+        if page == 1 && !args.query.contains(char::is_whitespace) {
+            let wanted = normalize_name(&args.query);
+            let listed = projects
+                .iter()
+                .any(|p| normalize_name(p["name"].as_str().unwrap_or_default()) == wanted);
+            if !listed {
+                if let Ok(doc) = self.project_json(&args.query, None).await {
+                    let info = &doc["info"];
+                    projects.insert(
+                        0,
+                        json!({
+                            "name": info["name"],
+                            "version": info["version"],
+                            "summary": info["summary"],
+                            "released": Value::Null,
+                            "url": info["package_url"],
+                        }),
+                    );
+                }
+            }
+        }
+        projects.truncate(limit);
+
+        Ok(json!({
+            "query": args.query,
+            "page": page,
+            "count": projects.len(),
+            "projects": projects,
+        }))
+    }
+
+    async fn get_project(&self, args: ProjectArgs) -> Result<Value, ConnectorError> {
+        // The unversioned document carries the full release list.
+        let project = self.project_json(&args.name, None).await?;
+        let doc = match args.version.as_deref() {
+            Some(v) if project["info"]["version"] != v => {
+                self.project_json(&args.name, Some(v)).await?
+            }
+            _ => project.clone(),
+        };
+        let info = &doc["info"];
+
+        let files: Vec<Value> = doc["urls"]
+            .as_array()
+            .map(|urls| {
+                urls.iter()
+                    .map(|f| {
+                        json!({
+                            "filename": f["filename"],
+                            "packagetype": f["packagetype"],
+                            "python_version": f["python_version"],
+                            "requires_python": f["requires_python"],
+                            "size": f["size"],
+                            "uploaded": f["upload_time_iso_8601"],
+                            "sha256": f["digests"]["sha256"],
+                            "url": f["url"],
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let history = release_history(&project["releases"]);
+        let versions: Vec<Value> = history
+            .iter()
+            .take(args.max_versions.unwrap_or(20))
+            .map(|(version, uploaded, yanked)| {
+                json!({ "version": version, "uploaded": uploaded, "yanked": yanked })
+            })
+            .collect();
+
+        Ok(json!({
+            "name": info["name"],
+            "version": info["version"],
+            "summary": info["summary"],
+            "author": info["author"].as_str().filter(|s| !s.is_empty()).or(info["author_email"].as_str()),
+            "maintainer": info["maintainer"],
+            "license": info["license_expression"].as_str().or(info["license"].as_str()),
+            "requires_python": info["requires_python"],
+            "requires_dist": info["requires_dist"],
+            "provides_extra": info["provides_extra"],
+            "classifiers": info["classifiers"],
+            "keywords": info["keywords"],
+            "project_urls": info["project_urls"],
+            "home_page": info["home_page"],
+            "yanked": info["yanked"],
+            "yanked_reason": info["yanked_reason"],
+            "files": files,
+            "version_count": history.len(),
+            "versions": versions,
+            "url": format!("{}/project/{}/{}/", BASE_URL, normalize_name(&args.name), info["version"].as_str().unwrap_or_default()),
+        }))
+    }
+
+    async fn description(&self, args: DescriptionArgs) -> Result<Value, ConnectorError> {
+        let doc = self
+            .project_json(&args.name, args.version.as_deref())
+            .await?;
+        let info = &doc["info"];
+        let content = info["description"].as_str().unwrap_or_default();
+        if content.trim().is_empty() || content.trim() == "UNKNOWN" {
+            return Err(ConnectorError::Other(format!(
+                "{} {} has no long description",
+                info["name"].as_str().unwrap_or(&args.name),
+                info["version"].as_str().unwrap_or_default()
+            )));
+        }
+        Ok(json!({
+            "name": info["name"],
+            "version": info["version"],
+            // text/markdown, text/x-rst or text/plain (PyPI's default when unset is rST).
+            "content_type": info["description_content_type"]
+                .as_str()
+                .unwrap_or("text/x-rst"),
+            "content": content,
+        }))
+    }
+}
+
+#[async_trait]
+impl Connector for PypiConnector {
+    fn name(&self) -> &'static str {
+        "pypi"
+    }
+
+    fn description(&self) -> &'static str {
+        "Python packages from PyPI: project search, release metadata and files, and long descriptions."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        Ok(AuthDetails::new())
+    }
+
+    async fn set_auth_details(&mut self, _details: AuthDetails) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema { fields: vec![] }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Use `search_projects` to find packages, `get_project` for release metadata \
+(requires_python, requires_dist, files, versions), and `get_description` for the README."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            tool(
+                "search_projects",
+                "Search PyPI projects. Example: query=\"pdf table extraction\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Search terms or a project name" },
+                        "page": { "type": "integer", "description": "Results page (default: 1)" },
+                        "limit": { "type": "integer", "description": "Max results (default: 20, max: 20)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "get_project",
+                "Release metadata: dependencies, Python requirement, classifiers, URLs, distribution files, and version history.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string", "description": "Project name, e.g. requests" },
+                        "version": { "type": "string", "description": "Release version (default: latest)" },
+                        "max_versions": { "type": "integer", "description": "Versions to include in history (default: 20)" }
+                    },
+                    "required": ["name"]
+                }),
+            ),
+            tool(
+                "get_description",
+                "Project long description (the README shown on PyPI) with its content type.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string", "description": "Project name" },
+                        "version": { "type": "string", "description": "Release version (default: latest)" }
+                    },
+                    "required": ["name"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        let data = match request.name.as_ref() {
+            "search_projects" => self.search(parse_args(request.arguments)?).await?,
+            "get_project" => self.get_project(parse_args(request.arguments)?).await?,
+            "get_description" => self.description(parse_args(request.arguments)?).await?,
+            _ => return Err(ConnectorError::ToolNotFound),
+        };
+        Ok(structured_result_with_text(&data, None)?)
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_project_names() {
+        assert_eq!(
+            normalize_name("Django_REST.framework"),
+            "django-rest-framework"
+        );
+        assert_eq!(normalize_name("zope.interface"), "zope-interface");
+        assert_eq!(normalize_name("a__-b"), "a-b");
+    }
+
+    #[test]
+    fn parses_search_results() {
+        let html = r#"<ul><li><a class="package-snippet" href="/project/pdfplumber/">
+            <h3 class="package-snippet__title">
+              <span class="package-snippet__name">pdfplumber</span>
+              <span class="package-snippet__version">0.11.4</span>
+              <span class="package-snippet__created"><time datetime="2024-08-16T12:00:00+0000">Aug 16, 2024</time></span>
+            </h3>
+            <p class="package-snippet__description">Plumb a PDF for detailed information.</p>
+          </a></li></ul>"#;
+        let results = parse_search_page(html);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["name"], "pdfplumber");
+        assert_eq!(results[0]["version"], "0.11.4");
+        assert_eq!(results[0]["url"], "https://pypi.org/project/pdfplumber/");
+    }
+
+    #[test]
+    fn orders_releases_by_upload_time() {
+        let releases = json!({
+            "1.0": [{ "upload_time_iso_8601": "2020-01-01T00:00:00Z", "yanked": false }],
+            "2.0": [{ "upload_time_iso_8601": "2022-01-01T00:00:00Z", "yanked": true }],
+            "1.5": []
+        });
+        let history = release_history(&releases);
+        assert_eq!(
+            history[0],
+            (
+                "2.0".to_string(),
+                Some("2022-01-01T00:00:00Z".to_string()),
+                true
+            )
+        );
+        assert_eq!(history[1].0, "1.0");
+        assert_eq!(history[2].1, None);
+    }
+}
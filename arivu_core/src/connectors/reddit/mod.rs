@@ -1,27 +1,25 @@
 use async_trait::async_trait;
-use roux::subreddit::response::AccountsActive;
 use serde_json::{json, Value};
 
 use chrono;
 use reqwest;
-use roux::util::{FeedOption, TimePeriod};
-use roux::{Reddit, Subreddit, User};
 use std::borrow::Cow;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
 
 use crate::auth::AuthDetails;
 use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
 use crate::error::ConnectorError;
+use crate::oauth;
 use crate::utils::{collect_paginated, structured_result_with_text, Page};
 use crate::Connector;
 use rmcp::model::*;
 
-pub struct RedditConnector {
-    client: Option<Reddit>,
-}
-
 const REDDIT_USER_AGENT: &str = "rzn_datasourcer/0.1.0";
+const PUBLIC_BASE_URL: &str = "https://www.reddit.com";
+const OAUTH_BASE_URL: &str = "https://oauth.reddit.com";
 const DEFAULT_COMMENT_LIMIT: u32 = 25;
 // Soft limit to prevent runaway fetches when callers pass extremely large values.
 const MAX_COMMENT_LIMIT: u32 = 5_000;
@@ -31,6 +29,8 @@ const MAX_SEARCH_REQUESTS: usize = 50;
 const MORECHILDREN_BATCH_SIZE: usize = 100;
 const MAX_MORECHILDREN_REQUESTS: usize = 100;
 const MAX_TOTAL_COMMENTS: usize = 50_000;
+const DEFAULT_COMMENT_SEARCH_POSTS: usize = 10;
+const MAX_COMMENT_SEARCH_POSTS: usize = 50;
 
 #[derive(Debug, Clone)]
 struct RedditSearchCursor {
@@ -38,13 +38,426 @@ struct RedditSearchCursor {
     count: usize,
 }
 
+/// Installed-app (or web-app) OAuth credentials. Requests go to
+/// oauth.reddit.com with a bearer token minted from the refresh token.
+#[derive(Debug, Clone)]
+struct OAuthCredentials {
+    client_id: String,
+    client_secret: String,
+    refresh_token: String,
+}
+
+#[derive(Debug, Clone)]
+struct CachedToken {
+    token: String,
+    expires_at: Instant,
+}
+
+pub struct RedditConnector {
+    http: reqwest::Client,
+    credentials: Option<OAuthCredentials>,
+    token: Arc<Mutex<Option<CachedToken>>>,
+}
+
 impl RedditConnector {
     pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
-        let mut connector = RedditConnector { client: None };
+        let mut connector = RedditConnector {
+            http: reqwest::Client::builder()
+                .user_agent(REDDIT_USER_AGENT)
+                .build()
+                .map_err(ConnectorError::HttpRequest)?,
+            credentials: None,
+            token: Arc::new(Mutex::new(None)),
+        };
         connector.set_auth_details(auth).await?;
 
         Ok(connector)
     }
+
+    fn is_authenticated(&self) -> bool {
+        self.credentials.is_some()
+    }
+
+    async fn access_token(&self, force_refresh: bool) -> Result<String, ConnectorError> {
+        let credentials = self.credentials.as_ref().ok_or_else(|| {
+            ConnectorError::Authentication("Reddit OAuth is not configured".to_string())
+        })?;
+        let mut cached = self.token.lock().await;
+        if !force_refresh {
+            if let Some(t) = cached.as_ref().filter(|t| Instant::now() < t.expires_at) {
+                return Ok(t.token.clone());
+            }
+        }
+
+        let tokens = oauth::reddit_refresh_token(
+            &credentials.client_id,
+            &credentials.client_secret,
+            &credentials.refresh_token,
+        )
+        .await?;
+        // Renew a minute early so a request never races the expiry
+        let expires_in = tokens.expires_in.unwrap_or(3600).max(120) as u64;
+        *cached = Some(CachedToken {
+            token: tokens.access_token.clone(),
+            expires_at: Instant::now() + Duration::from_secs(expires_in - 60),
+        });
+        Ok(tokens.access_token)
+    }
+
+    /// GET a Reddit API path such as `/r/rust/hot`. With OAuth this goes to
+    /// oauth.reddit.com (higher rate limits, account data); otherwise to the
+    /// public `.json` endpoints.
+    async fn api_get(
+        &self,
+        path: &str,
+        params: &[(&str, String)],
+    ) -> Result<Value, ConnectorError> {
+        let mut retried = false;
+        loop {
+            let request = if self.is_authenticated() {
+                let token = self.access_token(retried).await?;
+                self.http
+                    .get(format!("{}{}", OAUTH_BASE_URL, path))
+                    .bearer_auth(token)
+            } else {
+                self.http.get(format!("{}{}.json", PUBLIC_BASE_URL, path))
+            };
+            let response = request
+                .query(params)
+                .query(&[("raw_json", "1")])
+                .send()
+                .await
+                .map_err(|e| ConnectorError::Other(format!("Failed to send request: {}", e)))?;
+
+            match response.status().as_u16() {
+                // A revoked or expired token: mint a fresh one once
+                401 if self.is_authenticated() && !retried => {
+                    retried = true;
+                    continue;
+                }
+                401 | 403 => {
+                    return Err(ConnectorError::Authentication(format!(
+                        "Reddit denied access to {} ({})",
+                        path,
+                        response.status()
+                    )))
+                }
+                404 => return Err(ConnectorError::ResourceNotFound),
+                429 => {
+                    return Err(ConnectorError::Other(
+                        "Reddit rate limit reached; try again shortly".to_string(),
+                    ))
+                }
+                _ => {}
+            }
+
+            return response
+                .json()
+                .await
+                .map_err(|e| ConnectorError::Other(format!("Failed to parse JSON: {}", e)));
+        }
+    }
+
+    async fn subreddit_posts(
+        &self,
+        subreddit: &str,
+        sort: &str,
+        time: Option<&str>,
+        limit: u32,
+    ) -> Result<Vec<Value>, ConnectorError> {
+        let mut params = vec![("limit", limit.clamp(1, 100).to_string())];
+        if let Some(t) = time {
+            params.push(("t", t.to_string()));
+        }
+        let listing = self
+            .api_get(&format!("/r/{}/{}", subreddit, sort), &params)
+            .await?;
+        Ok(listing["data"]["children"]
+            .as_array()
+            .map(|children| children.iter().map(post_summary).collect())
+            .unwrap_or_default())
+    }
+
+    /// Follow a listing's `after` cursor until `desired_limit` items are
+    /// collected or the listing ends.
+    async fn collect_listing(
+        &self,
+        path: &str,
+        params: Vec<(&'static str, String)>,
+        desired_limit: usize,
+    ) -> Result<Vec<Value>, ConnectorError> {
+        collect_paginated(
+            desired_limit,
+            MAX_SEARCH_REQUESTS,
+            None::<RedditSearchCursor>,
+            |cursor, remaining| {
+                let mut params = params.clone();
+                async move {
+                    params.push(("limit", remaining.min(SEARCH_PAGE_SIZE_MAX).to_string()));
+                    let mut count = 0usize;
+                    if let Some(c) = cursor {
+                        count = c.count;
+                        params.push(("after", c.after));
+                        params.push(("count", count.to_string()));
+                    }
+
+                    let listing = self.api_get(path, &params).await?;
+                    let data = listing.get("data").ok_or_else(|| {
+                        ConnectorError::Other("Invalid response format".to_string())
+                    })?;
+                    let children = data
+                        .get("children")
+                        .and_then(|c| c.as_array())
+                        .ok_or(ConnectorError::Other("Invalid response format".to_string()))?;
+
+                    let after = data.get("after").and_then(|v| v.as_str()).unwrap_or("");
+                    let next_cursor = if after.is_empty() {
+                        None
+                    } else {
+                        Some(RedditSearchCursor {
+                            after: after.to_string(),
+                            count: count.saturating_add(children.len()),
+                        })
+                    };
+
+                    Ok::<_, ConnectorError>(Page {
+                        items: children.clone(),
+                        next_cursor,
+                    })
+                }
+            },
+            |thing: &Value| thing["data"]["name"].as_str().map(str::to_string),
+        )
+        .await
+    }
+
+    /// Page through `/user/{name}/{kind}` (`submitted` or `comments`).
+    async fn user_activity(
+        &self,
+        kind: &str,
+        args: &serde_json::Map<String, Value>,
+    ) -> Result<Vec<Value>, ConnectorError> {
+        let username =
+            args.get("username")
+                .and_then(|v| v.as_str())
+                .ok_or(ConnectorError::InvalidParams(
+                    "Missing 'username' parameter".to_string(),
+                ))?;
+        let username = strip_user_prefix(username.trim());
+        let sort = args
+            .get("sort")
+            .and_then(|v| v.as_str())
+            .unwrap_or("new")
+            .to_lowercase();
+        let mut params = vec![("sort", sort.clone())];
+        if user_listing_sort(&sort)? {
+            let time = args
+                .get("time")
+                .and_then(|v| v.as_str())
+                .unwrap_or("all")
+                .to_lowercase();
+            params.push(("t", time_param(&time)?.to_string()));
+        }
+        let desired_limit = args
+            .get("limit")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(25)
+            .clamp(1, i64::from(MAX_SEARCH_LIMIT)) as usize;
+
+        let things = self
+            .collect_listing(
+                &format!("/user/{}/{}", username, kind),
+                params,
+                desired_limit,
+            )
+            .await?;
+        let summarize: fn(&Value) -> Value = if kind == "comments" {
+            comment_summary
+        } else {
+            post_summary
+        };
+        Ok(things.iter().take(desired_limit).map(summarize).collect())
+    }
+
+    /// The signed-in user's saved/upvoted items or inbox. Read-only: the
+    /// inbox is fetched with `mark=false` so nothing is marked as read.
+    async fn account_listing(
+        &self,
+        tool: &str,
+        args: &serde_json::Map<String, Value>,
+    ) -> Result<Vec<Value>, ConnectorError> {
+        if !self.is_authenticated() {
+            return Err(ConnectorError::Authentication(format!(
+                "'{}' needs Reddit OAuth; run `arivu setup reddit`",
+                tool
+            )));
+        }
+        let desired_limit = args
+            .get("limit")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(25)
+            .clamp(1, i64::from(MAX_SEARCH_LIMIT)) as usize;
+
+        let (path, params) = if tool == "get_inbox" {
+            let filter = args
+                .get("filter")
+                .and_then(|v| v.as_str())
+                .unwrap_or("inbox");
+            let folder = match filter {
+                "inbox" | "all" => "inbox",
+                "unread" => "unread",
+                "messages" => "messages",
+                "comments" => "comments",
+                "mentions" => "mentions",
+                _ => {
+                    return Err(ConnectorError::InvalidParams(
+                        "filter must be one of: inbox, unread, messages, comments, mentions"
+                            .to_string(),
+                    ))
+                }
+            };
+            (
+                format!("/message/{}", folder),
+                vec![("mark", "false".to_string())],
+            )
+        } else {
+            let me = self.api_get("/api/v1/me", &[]).await?;
+            let username = me["name"].as_str().ok_or_else(|| {
+                ConnectorError::Other("Reddit did not return the account name".to_string())
+            })?;
+            let kind = if tool == "get_saved" {
+                "saved"
+            } else {
+                "upvoted"
+            };
+            (format!("/user/{}/{}", username, kind), Vec::new())
+        };
+
+        let things = self.collect_listing(&path, params, desired_limit).await?;
+        Ok(things
+            .iter()
+            .take(desired_limit)
+            .map(|thing| match thing["kind"].as_str() {
+                Some("t1") => comment_summary(thing),
+                Some("t4") => message_summary(thing),
+                _ => post_summary(thing),
+            })
+            .collect())
+    }
+}
+
+fn post_summary(post: &Value) -> Value {
+    let data = &post["data"];
+    json!({
+        "title": data["title"],
+        "url": data["url"],
+        "author": data["author"],
+        "subreddit": data["subreddit"],
+        "score": data["score"],
+        "num_comments": data["num_comments"],
+        "permalink": format!("{}{}", PUBLIC_BASE_URL, data["permalink"].as_str().unwrap_or_default()),
+        "created_utc": data["created_utc"],
+    })
+}
+
+fn subreddit_summary(subreddit: &Value) -> Value {
+    let data = &subreddit["data"];
+    json!({
+        "name": data["display_name"],
+        "title": data["title"],
+        "description": data["public_description"],
+        "subscribers": data["subscribers"],
+        "active_users": data["active_user_count"].as_i64().or_else(|| data["accounts_active"].as_i64()),
+        "url": format!("{}{}", PUBLIC_BASE_URL, data["url"].as_str().unwrap_or_default()),
+        "created_utc": data["created_utc"],
+        "over18": data["over18"],
+    })
+}
+
+fn message_summary(message: &Value) -> Value {
+    let data = &message["data"];
+    json!({
+        "kind": "message",
+        "subject": data["subject"],
+        "author": data["author"],
+        "body": data["body"],
+        "unread": data["new"],
+        "created_utc": data["created_utc"],
+    })
+}
+
+fn comment_summary(comment: &Value) -> Value {
+    let data = &comment["data"];
+    json!({
+        "body": data["body"],
+        "author": data["author"],
+        "subreddit": data["subreddit"],
+        "score": data["score"],
+        "link_title": data["link_title"],
+        // Already an absolute URL in user listings
+        "link_url": data["link_permalink"],
+        "permalink": format!("{}{}", PUBLIC_BASE_URL, data["permalink"].as_str().unwrap_or_default()),
+        "created_utc": data["created_utc"],
+    })
+}
+
+/// Validate a user listing sort; `top` and `controversial` take a time window.
+fn user_listing_sort(sort: &str) -> Result<bool, ConnectorError> {
+    match sort {
+        "new" | "hot" => Ok(false),
+        "top" | "controversial" => Ok(true),
+        _ => Err(ConnectorError::InvalidParams(
+            "sort must be one of: new, hot, top, controversial".to_string(),
+        )),
+    }
+}
+
+fn time_param(time: &str) -> Result<&'static str, ConnectorError> {
+    match time {
+        "hour" | "now" => Ok("hour"),
+        "day" | "today" => Ok("day"),
+        "week" => Ok("week"),
+        "month" => Ok("month"),
+        "year" => Ok("year"),
+        "all" | "alltime" => Ok("all"),
+        _ => Err(ConnectorError::InvalidParams(format!(
+            "Invalid 'time' value: '{}'. Expected one of: hour, day, week, month, year, all.",
+            time
+        ))),
+    }
+}
+
+fn strip_subreddit_prefix(name: &str) -> &str {
+    name.strip_prefix("/r/")
+        .or_else(|| name.strip_prefix("r/"))
+        .unwrap_or(name)
+}
+
+fn strip_user_prefix(name: &str) -> &str {
+    name.strip_prefix("/u/")
+        .or_else(|| name.strip_prefix("u/"))
+        .unwrap_or(name)
+}
+
+/// Split a comment search query into lowercase terms; quoted phrases stay whole.
+fn comment_search_terms(query: &str) -> Vec<String> {
+    query
+        .split('"')
+        .enumerate()
+        .flat_map(|(i, part)| {
+            if i % 2 == 1 {
+                vec![part.trim().to_lowercase()]
+            } else {
+                part.split_whitespace().map(str::to_lowercase).collect()
+            }
+        })
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+fn comment_matches(body: &str, terms: &[String]) -> bool {
+    let body = body.to_lowercase();
+    terms.iter().all(|t| body.contains(t.as_str()))
 }
 
 #[async_trait]
@@ -54,7 +467,7 @@ impl Connector for RedditConnector {
     }
 
     fn description(&self) -> &'static str {
-        "A connector for interacting with Reddit using the roux crate."
+        "Reddit posts, comments, users and subreddits, anonymously or via OAuth."
     }
 
     async fn capabilities(&self) -> ServerCapabilities {
@@ -69,58 +482,41 @@ impl Connector for RedditConnector {
     }
 
     async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
-        // Check if we have credentials
-        if let (Some(username), Some(password), Some(client_id), Some(client_secret)) = (
-            details.get("username"),
-            details.get("password"),
-            details.get("client_id"),
-            details.get("client_secret"),
+        let get = |key: &str, var: &str| {
+            details
+                .get(key)
+                .cloned()
+                .or_else(|| std::env::var(var).ok())
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+
+        // Without a refresh token we stay anonymous: public content still works
+        self.credentials = match (
+            get("client_id", "REDDIT_CLIENT_ID"),
+            get("refresh_token", "REDDIT_REFRESH_TOKEN"),
         ) {
-            // Authenticated client
-            let client_builder = Reddit::new(
+            (Some(client_id), Some(refresh_token)) => Some(OAuthCredentials {
                 client_id,
-                client_secret,
-                &format!("rzn_datasourcer/0.1.0 (by /u/{})", username),
-            )
-            .username(username)
-            .password(password);
-
-            // We'll store the client builder, not the authenticated client
-            self.client = Some(client_builder.clone());
-
-            // Test the authentication
-            let me = client_builder
-                .login()
-                .await
-                .map_err(|e| ConnectorError::Other(format!("Failed to authenticate: {}", e)))?;
-
-            // Just to verify it works, we don't need to store the result
-            match me.me().await {
-                Ok(user) => tracing::debug!(user = %user.id, "Reddit authentication succeeded"),
-                Err(e) => tracing::warn!(error = %e, "Reddit authentication verification failed"),
-            }
-        } else {
-            // Anonymous client - no login needed
-            let client = Reddit::new(
-                "rzn_datasourcer/0.1.0 (anonymous)",
-                "CLIENT_ID_NOT_NEEDED_FOR_ANONYMOUS",
-                "CLIENT_SECRET_NOT_NEEDED_FOR_ANONYMOUS",
-            );
-
-            self.client = Some(client);
-        }
+                // Installed apps have no secret
+                client_secret: get("client_secret", "REDDIT_CLIENT_SECRET").unwrap_or_default(),
+                refresh_token,
+            }),
+            _ => None,
+        };
+        *self.token.lock().await = None;
 
         Ok(())
     }
 
     async fn test_auth(&self) -> Result<(), ConnectorError> {
-        // Test by fetching a known user
-        let user = User::new("spez");
-        let _about = user
-            .about(None)
-            .await
-            .map_err(|e| ConnectorError::Other(format!("Failed to fetch user: {}", e)))?;
-
+        if self.is_authenticated() {
+            let me = self.api_get("/api/v1/me", &[]).await?;
+            tracing::debug!(user = %me["name"].as_str().unwrap_or_default(), "Reddit authentication succeeded");
+        } else {
+            // Anonymous: fetch a known user
+            self.api_get("/user/spez/about", &[]).await?;
+        }
         Ok(())
     }
 
@@ -128,43 +524,34 @@ impl Connector for RedditConnector {
         ConnectorConfigSchema {
             fields: vec![
                 Field {
-                    name: "username".to_string(),
+                    name: "client_id".to_string(),
                     field_type: FieldType::Text,
                     description: Some(
-                        "Reddit username (optional for anonymous access)".to_string(),
+                        "Reddit app client ID (optional for anonymous access)".to_string(),
                     ),
                     required: false,
-                    label: "Username".to_string(),
+                    label: "Client ID".to_string(),
                     options: None,
                 },
                 Field {
-                    name: "password".to_string(),
+                    name: "client_secret".to_string(),
                     field_type: FieldType::Secret,
                     description: Some(
-                        "Reddit password (optional for anonymous access)".to_string(),
+                        "Reddit app client secret (leave empty for installed apps)".to_string(),
                     ),
                     required: false,
-                    label: "Password".to_string(),
-                    options: None,
-                },
-                Field {
-                    name: "client_id".to_string(),
-                    field_type: FieldType::Text,
-                    description: Some(
-                        "Reddit API client ID (optional for anonymous access)".to_string(),
-                    ),
-                    required: false,
-                    label: "Client ID".to_string(),
+                    label: "Client Secret".to_string(),
                     options: None,
                 },
                 Field {
-                    name: "client_secret".to_string(),
+                    name: "refresh_token".to_string(),
                     field_type: FieldType::Secret,
                     description: Some(
-                        "Reddit API client secret (optional for anonymous access)".to_string(),
+                        "OAuth refresh token from `arivu setup reddit` (optional for anonymous access)"
+                            .to_string(),
                     ),
                     required: false,
-                    label: "Client Secret".to_string(),
+                    label: "Refresh Token".to_string(),
                     options: None,
                 },
             ],
@@ -214,7 +601,7 @@ impl Connector for RedditConnector {
     ) -> Result<ListToolsResult, ConnectorError> {
         // Keep the surface small to reduce ambiguity and context bloat for agents.
         // Back-compat: legacy tools are still accepted in call_tool(), but not listed here.
-        let tools = vec![
+        let mut tools = vec![
             Tool {
                 name: Cow::Borrowed("list"),
                 title: None,
@@ -305,8 +692,159 @@ impl Connector for RedditConnector {
                 annotations: None,
                 icons: None,
             },
+            Tool {
+                name: Cow::Borrowed("find_subreddits"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Find subreddits by topic with subscriber counts, description and activity. Use before list/search to pick the right community. Example: query=\"home lab\" limit=5.",
+                )),
+                input_schema: Arc::new(json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Topic or keywords." },
+                        "limit": { "type": "integer", "minimum": 1, "maximum": 100, "default": 10 },
+                        "include_nsfw": { "type": "boolean", "default": false }
+                    },
+                    "required": ["query"]
+                })
+                .as_object()
+                .expect("Schema object")
+                .clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("search_comments"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Find comments mentioning a term. Searches matching posts, then scans their comment threads (all terms must appear; quote phrases). Example: query=\"borrow checker\" subreddit=\"rust\" limit=20.",
+                )),
+                input_schema: Arc::new(json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Terms that must all appear in the comment body." },
+                        "subreddit": { "type": "string", "description": "Optional subreddit filter (e.g., \"rust\" or \"r/rust\")." },
+                        "time": { "type": "string", "enum": ["hour", "day", "week", "month", "year", "all"], "default": "all", "description": "Age window for the posts that are scanned." },
+                        "limit": { "type": "integer", "minimum": 1, "maximum": 5000, "default": 25, "description": "Max comments to return." },
+                        "max_posts": { "type": "integer", "minimum": 1, "maximum": 50, "default": 10, "description": "How many matching posts to scan (one request each)." },
+                        "comment_sort": { "type": "string", "enum": ["best", "top", "new", "controversial", "old", "qa"], "default": "top" }
+                    },
+                    "required": ["query"]
+                })
+                .as_object()
+                .expect("Schema object")
+                .clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_user_posts"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "List posts submitted by a user, paginating internally for large limits. Example: username=\"spez\" sort=\"top\" time=\"year\" limit=50.",
+                )),
+                input_schema: Arc::new(json!({
+                    "type": "object",
+                    "properties": {
+                        "username": { "type": "string", "description": "Reddit username, with or without u/ prefix." },
+                        "sort": { "type": "string", "enum": ["new", "hot", "top", "controversial"], "default": "new" },
+                        "time": { "type": "string", "enum": ["hour", "day", "week", "month", "year", "all"], "default": "all", "description": "Only applies when sort is 'top' or 'controversial'." },
+                        "limit": { "type": "integer", "minimum": 1, "maximum": 5000, "default": 25 }
+                    },
+                    "required": ["username"]
+                })
+                .as_object()
+                .expect("Schema object")
+                .clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_user_comments"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "List comments written by a user with the parent post title and link. Example: username=\"spez\" sort=\"new\" limit=100.",
+                )),
+                input_schema: Arc::new(json!({
+                    "type": "object",
+                    "properties": {
+                        "username": { "type": "string", "description": "Reddit username, with or without u/ prefix." },
+                        "sort": { "type": "string", "enum": ["new", "hot", "top", "controversial"], "default": "new" },
+                        "time": { "type": "string", "enum": ["hour", "day", "week", "month", "year", "all"], "default": "all", "description": "Only applies when sort is 'top' or 'controversial'." },
+                        "limit": { "type": "integer", "minimum": 1, "maximum": 5000, "default": 25 }
+                    },
+                    "required": ["username"]
+                })
+                .as_object()
+                .expect("Schema object")
+                .clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
         ];
 
+        // Account tools only make sense with OAuth credentials
+        if self.is_authenticated() {
+            let limit_schema =
+                json!({ "type": "integer", "minimum": 1, "maximum": 5000, "default": 25 });
+            for (name, description) in [
+                (
+                    "get_saved",
+                    "List posts and comments the signed-in user saved (requires explicit user permission).",
+                ),
+                (
+                    "get_upvoted",
+                    "List posts the signed-in user upvoted (requires explicit user permission).",
+                ),
+            ] {
+                tools.push(Tool {
+                    name: Cow::Borrowed(name),
+                    title: None,
+                    description: Some(Cow::Borrowed(description)),
+                    input_schema: Arc::new(
+                        json!({
+                            "type": "object",
+                            "properties": { "limit": limit_schema },
+                            "required": []
+                        })
+                        .as_object()
+                        .expect("Schema object")
+                        .clone(),
+                    ),
+                    output_schema: None,
+                    annotations: None,
+                    icons: None,
+                });
+            }
+            tools.push(Tool {
+                name: Cow::Borrowed("get_inbox"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Read the signed-in user's inbox: private messages, comment replies and mentions. Does not mark anything as read (requires explicit user permission).",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "filter": { "type": "string", "enum": ["inbox", "unread", "messages", "comments", "mentions"], "default": "inbox" },
+                            "limit": limit_schema
+                        },
+                        "required": []
+                    })
+                    .as_object()
+                    .expect("Schema object")
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            });
+        }
+
         Ok(ListToolsResult {
             tools,
             next_cursor: None,
@@ -326,7 +864,7 @@ impl Connector for RedditConnector {
                 let subreddit_name = args.get("subreddit").and_then(|v| v.as_str()).ok_or(
                     ConnectorError::InvalidParams("Missing 'subreddit' parameter".to_string()),
                 )?;
-                let subreddit_name = subreddit_name.strip_prefix("r/").unwrap_or(subreddit_name);
+                let subreddit_name = strip_subreddit_prefix(subreddit_name);
                 let limit = args.get("limit").and_then(|v| v.as_i64()).unwrap_or(10) as u32;
                 let sort = args
                     .get("sort")
@@ -334,113 +872,85 @@ impl Connector for RedditConnector {
                     .unwrap_or("hot")
                     .to_lowercase();
 
-                match sort.as_str() {
-                    "hot" => {
-                        let subreddit = Subreddit::new(subreddit_name);
-                        let posts = subreddit.hot(limit, None).await.map_err(|e| {
-                            ConnectorError::Other(format!("Failed to fetch hot posts: {}", e))
-                        })?;
-
-                        let results: Vec<_> = posts
-                            .data
-                            .children
-                            .iter()
-                            .map(|post| {
-                                json!({
-                                    "title": post.data.title,
-                                    "url": post.data.url,
-                                    "author": post.data.author,
-                                    "score": post.data.score,
-                                    "num_comments": post.data.num_comments,
-                                    "permalink": format!("https://www.reddit.com{}", post.data.permalink),
-                                    "created_utc": post.data.created_utc,
-                                })
-                            })
-                            .collect();
-
-                        let text = serde_json::to_string(&results)?;
-                        Ok(structured_result_with_text(&results, Some(text))?)
-                    }
-                    "new" => {
-                        let subreddit = Subreddit::new(subreddit_name);
-                        let posts = subreddit.latest(limit, None).await.map_err(|e| {
-                            ConnectorError::Other(format!("Failed to fetch new posts: {}", e))
-                        })?;
-
-                        let results: Vec<_> = posts
-                            .data
-                            .children
-                            .iter()
-                            .map(|post| {
-                                json!({
-                                    "title": post.data.title,
-                                    "url": post.data.url,
-                                    "author": post.data.author,
-                                    "score": post.data.score,
-                                    "num_comments": post.data.num_comments,
-                                    "permalink": format!("https://www.reddit.com{}", post.data.permalink),
-                                    "created_utc": post.data.created_utc,
-                                })
-                            })
-                            .collect();
-
-                        let text = serde_json::to_string(&results)?;
-                        Ok(structured_result_with_text(&results, Some(text))?)
-                    }
-                    "top" => {
-                        let subreddit = Subreddit::new(subreddit_name);
-                        let time = args
+                let time = match sort.as_str() {
+                    "hot" | "new" => None,
+                    "top" => Some(time_param(
+                        &args
                             .get("time")
                             .and_then(|v| v.as_str())
                             .unwrap_or("day")
-                            .to_lowercase();
-
-                        let period = match time.as_str() {
-                            "hour" => TimePeriod::Now,
-                            "day" => TimePeriod::Today,
-                            "week" => TimePeriod::ThisWeek,
-                            "month" => TimePeriod::ThisMonth,
-                            "year" => TimePeriod::ThisYear,
-                            "all" => TimePeriod::AllTime,
-                            _ => {
-                                return Err(ConnectorError::InvalidParams(format!(
-                                    "Invalid 'time' value: '{}'. Expected one of: hour, day, week, month, year, all.",
-                                    time
-                                )));
-                            }
-                        };
-
-                        let posts = subreddit
-                            .top(limit, Some(FeedOption::new().period(period)))
-                            .await
-                            .map_err(|e| {
-                                ConnectorError::Other(format!("Failed to fetch top posts: {}", e))
-                            })?;
-
-                        let results: Vec<_> = posts
-                            .data
-                            .children
-                            .iter()
-                            .map(|post| {
-                                json!({
-                                    "title": post.data.title,
-                                    "url": post.data.url,
-                                    "author": post.data.author,
-                                    "score": post.data.score,
-                                    "num_comments": post.data.num_comments,
-                                    "permalink": format!("https://www.reddit.com{}", post.data.permalink),
-                                    "created_utc": post.data.created_utc,
-                                })
-                            })
-                            .collect();
-
-                        let text = serde_json::to_string(&results)?;
-                        Ok(structured_result_with_text(&results, Some(text))?)
+                            .to_lowercase(),
+                    )?),
+                    _ => {
+                        return Err(ConnectorError::InvalidParams(
+                            "sort must be one of: hot, new, top".to_string(),
+                        ))
                     }
-                    _ => Err(ConnectorError::InvalidParams(
-                        "sort must be one of: hot, new, top".to_string(),
-                    )),
-                }
+                };
+
+                let results = self
+                    .subreddit_posts(subreddit_name, &sort, time, limit)
+                    .await?;
+                let text = serde_json::to_string(&results)?;
+                Ok(structured_result_with_text(&results, Some(text))?)
+            }
+            "get_user_posts" | "get_user_comments" => {
+                let kind = if name == "get_user_posts" {
+                    "submitted"
+                } else {
+                    "comments"
+                };
+                let results = self.user_activity(kind, &args).await?;
+                let text = serde_json::to_string(&results)?;
+                Ok(structured_result_with_text(&results, Some(text))?)
+            }
+            "find_subreddits" => {
+                let query = args
+                    .get("query")
+                    .and_then(|v| v.as_str())
+                    .map(str::trim)
+                    .filter(|q| !q.is_empty())
+                    .ok_or(ConnectorError::InvalidParams(
+                        "Missing 'query' parameter".to_string(),
+                    ))?;
+                let limit = args
+                    .get("limit")
+                    .and_then(|v| v.as_i64())
+                    .unwrap_or(10)
+                    .clamp(1, 100) as usize;
+                let include_nsfw = args
+                    .get("include_nsfw")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+
+                let subreddits = self
+                    .collect_listing(
+                        "/subreddits/search",
+                        vec![
+                            ("q", query.to_string()),
+                            ("include_over_18", include_nsfw.to_string()),
+                        ],
+                        limit,
+                    )
+                    .await?;
+                let results: Vec<Value> = subreddits
+                    .iter()
+                    .filter(|s| include_nsfw || s["data"]["over18"].as_bool() != Some(true))
+                    .take(limit)
+                    .map(subreddit_summary)
+                    .collect();
+                let text = serde_json::to_string(&results)?;
+                Ok(structured_result_with_text(&results, Some(text))?)
+            }
+            "get_saved" | "get_upvoted" | "get_inbox" => {
+                let results = self.account_listing(name, &args).await?;
+                let text = serde_json::to_string(&results)?;
+                Ok(structured_result_with_text(&results, Some(text))?)
+            }
+            "search_comments" => {
+                let result = self.search_comments(&args).await?;
+                let text = serde_json::to_string(&result)?;
+                Ok(structured_result_with_text(&result, Some(text))?)
             }
             "search" | "search_posts" => {
                 let request = CallToolRequestParam {
@@ -462,178 +972,61 @@ impl Connector for RedditConnector {
                 let username = args.get("username").and_then(|v| v.as_str()).ok_or(
                     ConnectorError::InvalidParams("Missing 'username' parameter".to_string()),
                 )?;
-                // Strip "u/", "/u/", or leading "/" from username
-                let username = username
-                    .strip_prefix("/u/")
-                    .or_else(|| username.strip_prefix("u/"))
-                    .unwrap_or(username);
-
-                let user = User::new(username);
-                let about = user
-                    .about(None)
-                    .await
-                    .map_err(|e| ConnectorError::Other(format!("Failed to fetch user: {}", e)))?;
-
-                let data = &about.data;
+                let username = strip_user_prefix(username);
+
+                let about = self
+                    .api_get(&format!("/user/{}/about", username), &[])
+                    .await?;
+
+                let data = &about["data"];
                 let result = json!({
-                    "name": data.name,
-                    "id": data.id,
-                    "link_karma": data.link_karma,
-                    "comment_karma": data.comment_karma,
-                    "created_utc": data.created_utc,
-                    "is_gold": data.is_gold,
-                    "is_mod": data.is_mod,
-                    "verified": data.verified,
+                    "name": data["name"],
+                    "id": data["id"],
+                    "link_karma": data["link_karma"],
+                    "comment_karma": data["comment_karma"],
+                    "created_utc": data["created_utc"],
+                    "is_gold": data["is_gold"],
+                    "is_mod": data["is_mod"],
+                    "verified": data["verified"],
                 });
 
                 let text = serde_json::to_string(&result)?;
                 Ok(structured_result_with_text(&result, Some(text))?)
             }
-            "get_subreddit_top_posts" => {
-                let subreddit_name = args.get("subreddit").and_then(|v| v.as_str()).ok_or(
-                    ConnectorError::InvalidParams("Missing 'subreddit' parameter".to_string()),
-                )?;
-                // Strip "r/" prefix if present
-                let subreddit_name = subreddit_name.strip_prefix("r/").unwrap_or(subreddit_name);
-                let limit = args.get("limit").and_then(|v| v.as_i64()).unwrap_or(10) as u32;
-
-                let subreddit = Subreddit::new(subreddit_name);
-                let time = args
-                    .get("time")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("day")
-                    .to_lowercase();
-
-                let period = match time.as_str() {
-                    "hour" | "now" => TimePeriod::Now,
-                    "day" | "today" => TimePeriod::Today,
-                    "week" => TimePeriod::ThisWeek,
-                    "month" => TimePeriod::ThisMonth,
-                    "year" => TimePeriod::ThisYear,
-                    "all" | "alltime" => TimePeriod::AllTime,
-                    _ => {
-                        return Err(ConnectorError::InvalidParams(format!(
-                            "Invalid 'time' value: '{}'. Expected one of: hour, day, week, month, year, all.",
-                            time
-                        )));
-                    }
+            "get_subreddit_top_posts" | "get_subreddit_hot_posts" | "get_subreddit_new_posts" => {
+                let mut args = args;
+                let sort = match name {
+                    "get_subreddit_top_posts" => "top",
+                    "get_subreddit_hot_posts" => "hot",
+                    _ => "new",
                 };
-
-                let posts = subreddit
-                    .top(limit, Some(FeedOption::new().period(period)))
-                    .await
-                    .map_err(|e| {
-                        ConnectorError::Other(format!("Failed to fetch top posts: {}", e))
-                    })?;
-
-                let results: Vec<_> = posts
-                    .data
-                    .children
-                    .iter()
-                    .map(|post| {
-                        json!({
-                            "title": post.data.title,
-                            "url": post.data.url,
-                            "author": post.data.author,
-                            "score": post.data.score,
-                            "num_comments": post.data.num_comments,
-                            "permalink": format!("https://www.reddit.com{}", post.data.permalink),
-                            "created_utc": post.data.created_utc,
-                        })
-                    })
-                    .collect();
-
-                let text = serde_json::to_string(&results)?;
-                Ok(structured_result_with_text(&results, Some(text))?)
-            }
-            "get_subreddit_hot_posts" => {
-                let subreddit_name = args.get("subreddit").and_then(|v| v.as_str()).ok_or(
-                    ConnectorError::InvalidParams("Missing 'subreddit' parameter".to_string()),
-                )?;
-                // Strip "r/" prefix if present
-                let subreddit_name = subreddit_name.strip_prefix("r/").unwrap_or(subreddit_name);
-                let limit = args.get("limit").and_then(|v| v.as_i64()).unwrap_or(10) as u32;
-
-                let subreddit = Subreddit::new(subreddit_name);
-                let posts = subreddit.hot(limit, None).await.map_err(|e| {
-                    ConnectorError::Other(format!("Failed to fetch hot posts: {}", e))
-                })?;
-
-                let results: Vec<_> = posts
-                    .data
-                    .children
-                    .iter()
-                    .map(|post| {
-                        json!({
-                            "title": post.data.title,
-                            "url": post.data.url,
-                            "author": post.data.author,
-                            "score": post.data.score,
-                            "num_comments": post.data.num_comments,
-                            "permalink": format!("https://www.reddit.com{}", post.data.permalink),
-                            "created_utc": post.data.created_utc,
-                        })
-                    })
-                    .collect();
-
-                let text = serde_json::to_string(&results)?;
-                Ok(structured_result_with_text(&results, Some(text))?)
-            }
-            "get_subreddit_new_posts" => {
-                let subreddit_name = args.get("subreddit").and_then(|v| v.as_str()).ok_or(
-                    ConnectorError::InvalidParams("Missing 'subreddit' parameter".to_string()),
-                )?;
-                // Strip "r/" prefix if present
-                let subreddit_name = subreddit_name.strip_prefix("r/").unwrap_or(subreddit_name);
-                let limit = args.get("limit").and_then(|v| v.as_i64()).unwrap_or(10) as u32;
-
-                let subreddit = Subreddit::new(subreddit_name);
-                let posts = subreddit.latest(limit, None).await.map_err(|e| {
-                    ConnectorError::Other(format!("Failed to fetch new posts: {}", e))
-                })?;
-
-                let results: Vec<_> = posts
-                    .data
-                    .children
-                    .iter()
-                    .map(|post| {
-                        json!({
-                            "title": post.data.title,
-                            "url": post.data.url,
-                            "author": post.data.author,
-                            "score": post.data.score,
-                            "num_comments": post.data.num_comments,
-                            "permalink": format!("https://www.reddit.com{}", post.data.permalink),
-                            "created_utc": post.data.created_utc,
-                        })
-                    })
-                    .collect();
-
-                let text = serde_json::to_string(&results)?;
-                Ok(structured_result_with_text(&results, Some(text))?)
+                args.insert("sort".to_string(), json!(sort));
+                let request = CallToolRequestParam {
+                    name: "list".into(),
+                    arguments: Some(args),
+                };
+                self.call_tool(request).await
             }
             "get_subreddit_info" => {
                 let subreddit_name = args.get("subreddit").and_then(|v| v.as_str()).ok_or(
                     ConnectorError::InvalidParams("Missing 'subreddit' parameter".to_string()),
                 )?;
-                // Strip "r/" prefix if present
-                let subreddit_name = subreddit_name.strip_prefix("r/").unwrap_or(subreddit_name);
+                let subreddit_name = strip_subreddit_prefix(subreddit_name);
 
-                let subreddit = Subreddit::new(subreddit_name);
-                let about = subreddit.about().await.map_err(|e| {
-                    ConnectorError::Other(format!("Failed to fetch subreddit info: {}", e))
-                })?;
+                let about = self
+                    .api_get(&format!("/r/{}/about", subreddit_name), &[])
+                    .await?;
 
-                let data = &about;
+                let data = &about["data"];
                 let result = json!({
-                    "display_name": data.display_name,
-                    "title": data.title,
-                    "description": data.public_description,
-                    "subscribers": data.subscribers,
-                    "active_users": format!("{:#?}", data.active_user_count.as_ref().unwrap_or(&AccountsActive::Number(0))),
-                    "url": data.url.as_ref().map_or("".to_string(), |url| format!("https://www.reddit.com{}", url)),
-                    "created_utc": data.created_utc,
-                    "over18": data.over18,
+                    "display_name": data["display_name"],
+                    "title": data["title"],
+                    "description": data["public_description"],
+                    "subscribers": data["subscribers"],
+                    "active_users": data["active_user_count"].as_i64().or_else(|| data["accounts_active"].as_i64()).unwrap_or(0),
+                    "url": data["url"].as_str().map_or("".to_string(), |url| format!("{}{}", PUBLIC_BASE_URL, url)),
+                    "created_utc": data["created_utc"],
+                    "over18": data["over18"],
                 });
 
                 let text = serde_json::to_string(&result)?;
@@ -742,9 +1135,6 @@ impl Connector for RedditConnector {
                     .and_then(|v| v.as_bool())
                     .unwrap_or(false);
 
-                // Use reqwest to directly call the Reddit search API
-                let client = reqwest::Client::new();
-                let base_url = "https://www.reddit.com/";
                 let desired_limit =
                     args.get("limit")
                         .and_then(|v| v.as_i64())
@@ -767,73 +1157,18 @@ impl Connector for RedditConnector {
                     }
                 };
 
-                let posts = collect_paginated(
-                    desired_limit,
-                    MAX_SEARCH_REQUESTS,
-                    None::<RedditSearchCursor>,
-                    |cursor, remaining| {
-                        let client = client.clone();
-                        let search_query = search_query.clone();
-                        async move {
-                            let page_limit = remaining.min(SEARCH_PAGE_SIZE_MAX);
-
-                            let mut params: Vec<(String, String)> = vec![
-                                ("q".to_string(), search_query.clone()),
-                                ("limit".to_string(), page_limit.to_string()),
-                                ("include_over_18".to_string(), include_nsfw.to_string()),
-                                ("sort".to_string(), sort_param.to_string()),
-                                ("t".to_string(), time_param.to_string()),
-                                ("raw_json".to_string(), "1".to_string()),
-                            ];
-
-                            let mut count = 0usize;
-                            if let Some(c) = cursor {
-                                count = c.count;
-                                params.push(("after".to_string(), c.after));
-                                params.push(("count".to_string(), count.to_string()));
-                            }
-
-                            let response = client
-                                .get(format!("{base_url}search.json"))
-                                .header("User-Agent", REDDIT_USER_AGENT)
-                                .query(&params)
-                                .send()
-                                .await
-                                .map_err(|e| {
-                                    ConnectorError::Other(format!("Failed to send request: {}", e))
-                                })?;
-
-                            let search_results: Value = response.json().await.map_err(|e| {
-                                ConnectorError::Other(format!("Failed to parse JSON: {}", e))
-                            })?;
-
-                            let data = search_results.get("data").ok_or_else(|| {
-                                ConnectorError::Other("Invalid response format".to_string())
-                            })?;
-
-                            let children = data.get("children").and_then(|c| c.as_array()).ok_or(
-                                ConnectorError::Other("Invalid response format".to_string()),
-                            )?;
-
-                            let after = data.get("after").and_then(|v| v.as_str()).unwrap_or("");
-                            let next_cursor = if after.is_empty() {
-                                None
-                            } else {
-                                Some(RedditSearchCursor {
-                                    after: after.to_string(),
-                                    count: count.saturating_add(children.len()),
-                                })
-                            };
-
-                            Ok::<_, ConnectorError>(Page {
-                                items: children.clone(),
-                                next_cursor,
-                            })
-                        }
-                    },
-                    |post: &Value| post["data"]["id"].as_str().map(str::to_string),
-                )
-                .await?;
+                let posts = self
+                    .collect_listing(
+                        "/search",
+                        vec![
+                            ("q", search_query),
+                            ("include_over_18", include_nsfw.to_string()),
+                            ("sort", sort_param.to_string()),
+                            ("t", time_param.to_string()),
+                        ],
+                        desired_limit,
+                    )
+                    .await?;
 
                 let mut img_results = Vec::new();
                 let mut text_results = Vec::new();
@@ -844,7 +1179,7 @@ impl Connector for RedditConnector {
 
                     let title = data["title"].as_str().unwrap_or("").to_string();
                     let permalink = data["permalink"].as_str().unwrap_or("").to_string();
-                    let full_url = format!("{}{}", base_url.trim_end_matches('/'), permalink);
+                    let full_url = format!("{}{}", PUBLIC_BASE_URL, permalink);
 
                     // Check if thumbnail is a valid URL
                     let thumbnail = data["thumbnail"].as_str().unwrap_or("").to_string();
@@ -907,25 +1242,19 @@ impl Connector for RedditConnector {
                     ConnectorError::InvalidParams("Invalid post URL format".to_string()),
                 )?;
 
-                // Construct the API URL to fetch post details with comments
-                let api_url = format!(
-                    "https://www.reddit.com/r/{}/comments/{}.json?limit={}&sort={}&raw_json=1",
-                    post_info.subreddit, post_info.post_id, comment_limit, comment_sort
-                );
-
-                // Make the request to Reddit API
-                let client = reqwest::Client::new();
-                let response = client
-                    .get(&api_url)
-                    .header("User-Agent", REDDIT_USER_AGENT)
-                    .send()
-                    .await
-                    .map_err(|e| ConnectorError::Other(format!("Failed to send request: {}", e)))?;
-
-                let post_data: Vec<Value> = response
-                    .json()
-                    .await
-                    .map_err(|e| ConnectorError::Other(format!("Failed to parse JSON: {}", e)))?;
+                let post_data = self
+                    .api_get(
+                        &format!("/r/{}/comments/{}", post_info.subreddit, post_info.post_id),
+                        &[
+                            ("limit", comment_limit.to_string()),
+                            ("sort", comment_sort.to_string()),
+                        ],
+                    )
+                    .await?;
+                let post_data = match post_data {
+                    Value::Array(items) => items,
+                    _ => Vec::new(),
+                };
 
                 if post_data.len() < 2 {
                     return Err(ConnectorError::Other("Invalid response format".to_string()));
@@ -936,14 +1265,14 @@ impl Connector for RedditConnector {
 
                 // Extract comments from the second element
                 let link_fullname = format!("t3_{}", post_info.post_id);
-                let comments = Self::fetch_comment_tree_with_more(
-                    &client,
-                    &post_data[1]["data"]["children"],
-                    &link_fullname,
-                    comment_limit,
-                    comment_sort,
-                )
-                .await?;
+                let comments = self
+                    .fetch_comment_tree_with_more(
+                        &post_data[1]["data"]["children"],
+                        &link_fullname,
+                        comment_limit,
+                        comment_sort,
+                    )
+                    .await?;
 
                 // Build the result
                 let result = json!({
@@ -1031,6 +1360,131 @@ impl RedditConnector {
         None
     }
 
+    /// Reddit's API has no comment search, so find posts matching the query
+    /// and scan each post's comment thread for the terms.
+    async fn search_comments(
+        &self,
+        args: &serde_json::Map<String, Value>,
+    ) -> Result<Value, ConnectorError> {
+        let query = args
+            .get("query")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+            .ok_or(ConnectorError::InvalidParams(
+                "Missing 'query' parameter".to_string(),
+            ))?;
+        let terms = comment_search_terms(query);
+        let limit = args
+            .get("limit")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(25)
+            .clamp(1, i64::from(MAX_SEARCH_LIMIT)) as usize;
+        let max_posts = args
+            .get("max_posts")
+            .and_then(|v| v.as_u64())
+            .map_or(DEFAULT_COMMENT_SEARCH_POSTS, |n| n as usize)
+            .clamp(1, MAX_COMMENT_SEARCH_POSTS);
+        let comment_sort = args
+            .get("comment_sort")
+            .and_then(|v| v.as_str())
+            .unwrap_or("top")
+            .to_lowercase();
+        let time = time_param(
+            &args
+                .get("time")
+                .and_then(|v| v.as_str())
+                .unwrap_or("all")
+                .to_lowercase(),
+        )?;
+
+        let mut search_query = query.to_string();
+        if let Some(subreddit) = args
+            .get("subreddit")
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
+        {
+            search_query = format!(
+                "{} subreddit:{}",
+                search_query,
+                strip_subreddit_prefix(subreddit)
+            );
+        }
+        let posts = self
+            .collect_listing(
+                "/search",
+                vec![
+                    ("q", search_query),
+                    ("sort", "relevance".to_string()),
+                    ("t", time.to_string()),
+                ],
+                max_posts,
+            )
+            .await?;
+
+        let mut matches = Vec::new();
+        let mut posts_scanned = 0usize;
+        for post in posts.iter().take(max_posts) {
+            if matches.len() >= limit {
+                break;
+            }
+            let data = &post["data"];
+            let Some(post_id) = data["id"].as_str() else {
+                continue;
+            };
+            if data["num_comments"].as_u64() == Some(0) {
+                continue;
+            }
+            let thread = self
+                .api_get(
+                    &format!("/comments/{}", post_id),
+                    &[("limit", "500".to_string()), ("sort", comment_sort.clone())],
+                )
+                .await?;
+            posts_scanned += 1;
+
+            let link_fullname = format!("t3_{}", post_id);
+            let mut order: u64 = 0;
+            let mut comments_by_id = HashMap::new();
+            // Collapsed "more" stubs are not expanded here; that would cost a
+            // request per stub for every scanned post.
+            let mut more_queue = VecDeque::new();
+            let mut seen = HashSet::new();
+            Self::collect_from_listing(
+                &thread[1]["data"]["children"],
+                &link_fullname,
+                &mut order,
+                &mut comments_by_id,
+                &mut more_queue,
+                &mut seen,
+            );
+
+            let mut found: Vec<CollectedComment> = comments_by_id
+                .into_values()
+                .filter(|c| comment_matches(&c.body, &terms))
+                .collect();
+            found.sort_by_key(|c| c.order);
+            for comment in found.into_iter().take(limit - matches.len()) {
+                matches.push(json!({
+                    "body": comment.body,
+                    "author": comment.author,
+                    "score": comment.score,
+                    "created_utc": comment.created_utc,
+                    "permalink": format!("{}{}", PUBLIC_BASE_URL, comment.permalink),
+                    "post_title": data["title"],
+                    "post_url": format!("{}{}", PUBLIC_BASE_URL, data["permalink"].as_str().unwrap_or_default()),
+                    "subreddit": data["subreddit"],
+                }));
+            }
+        }
+
+        Ok(json!({
+            "query": query,
+            "posts_scanned": posts_scanned,
+            "comments": matches,
+        }))
+    }
+
     fn sort_for_morechildren(comment_sort: &str) -> &str {
         match comment_sort {
             // Reddit's /api/morechildren uses "confidence" instead of "best".
@@ -1046,7 +1500,7 @@ impl RedditConnector {
     }
 
     async fn fetch_comment_tree_with_more(
-        client: &reqwest::Client,
+        &self,
         initial_children: &Value,
         link_fullname: &str,
         top_level_limit: u32,
@@ -1112,14 +1566,9 @@ impl RedditConnector {
                     continue;
                 }
 
-                let things = Self::fetch_morechildren_things(
-                    client,
-                    link_fullname,
-                    &unfetched,
-                    comment_sort,
-                    more.depth,
-                )
-                .await?;
+                let things = self
+                    .fetch_morechildren_things(link_fullname, &unfetched, comment_sort, more.depth)
+                    .await?;
                 more_requests += 1;
 
                 Self::collect_from_things(
@@ -1254,13 +1703,12 @@ impl RedditConnector {
     }
 
     async fn fetch_morechildren_things(
-        client: &reqwest::Client,
+        &self,
         link_fullname: &str,
         children: &[String],
         comment_sort: &str,
         depth: i64,
     ) -> Result<Vec<Value>, ConnectorError> {
-        let url = "https://www.reddit.com/api/morechildren.json";
         let params = [
             ("api_type", "json".to_string()),
             ("link_id", link_fullname.to_string()),
@@ -1270,22 +1718,10 @@ impl RedditConnector {
                 "sort",
                 Self::sort_for_morechildren(comment_sort).to_string(),
             ),
-            ("raw_json", "1".to_string()),
             ("depth", depth.to_string()),
         ];
 
-        let response = client
-            .get(url)
-            .header("User-Agent", REDDIT_USER_AGENT)
-            .query(&params)
-            .send()
-            .await
-            .map_err(|e| ConnectorError::Other(format!("Failed to send request: {}", e)))?;
-
-        let data: Value = response
-            .json()
-            .await
-            .map_err(|e| ConnectorError::Other(format!("Failed to parse JSON: {}", e)))?;
+        let data = self.api_get("/api/morechildren", &params).await?;
 
         let things = data["json"]["data"]["things"]
             .as_array()
@@ -1438,4 +1874,103 @@ mod tests {
         assert_eq!(tree[1]["replies"][0]["id"], "r1");
         assert_eq!(tree[1]["replies"][0]["depth"], 1);
     }
+
+    #[test]
+    fn maps_listing_params_and_posts() {
+        assert_eq!(time_param("today").unwrap(), "day");
+        assert_eq!(time_param("alltime").unwrap(), "all");
+        assert!(time_param("decade").is_err());
+        assert_eq!(strip_subreddit_prefix("/r/rust"), "rust");
+        assert_eq!(strip_subreddit_prefix("r/rust"), "rust");
+
+        let post = json!({ "kind": "t3", "data": { "title": "t", "permalink": "/r/rust/comments/abc/t/", "score": 5 } });
+        let summary = post_summary(&post);
+        assert_eq!(
+            summary["permalink"],
+            "https://www.reddit.com/r/rust/comments/abc/t/"
+        );
+        assert_eq!(summary["score"], 5);
+    }
+
+    #[test]
+    fn summarizes_user_comments() {
+        assert!(!user_listing_sort("new").unwrap());
+        assert!(user_listing_sort("controversial").unwrap());
+        assert!(user_listing_sort("best").is_err());
+        assert_eq!(strip_user_prefix("/u/spez"), "spez");
+        assert_eq!(strip_user_prefix("u/spez"), "spez");
+
+        let comment = json!({ "kind": "t1", "data": {
+            "body": "hello",
+            "subreddit": "rust",
+            "link_title": "t",
+            "link_permalink": "https://www.reddit.com/r/rust/comments/abc/t/",
+            "permalink": "/r/rust/comments/abc/t/def/",
+        } });
+        let summary = comment_summary(&comment);
+        assert_eq!(summary["body"], "hello");
+        assert_eq!(
+            summary["link_url"],
+            "https://www.reddit.com/r/rust/comments/abc/t/"
+        );
+        assert_eq!(
+            summary["permalink"],
+            "https://www.reddit.com/r/rust/comments/abc/t/def/"
+        );
+    }
+
+    #[test]
+    fn matches_comment_search_terms() {
+        let terms = comment_search_terms("Tokio \"work stealing\"  runtime");
+        assert_eq!(terms, vec!["tokio", "work stealing", "runtime"]);
+        assert!(comment_matches(
+            "The tokio runtime uses work stealing.",
+            &terms
+        ));
+        assert!(!comment_matches("tokio's runtime steals work", &terms));
+    }
+
+    #[test]
+    fn summarizes_subreddits() {
+        let sub = json!({ "kind": "t5", "data": {
+            "display_name": "rust",
+            "public_description": "A place for all things Rust",
+            "subscribers": 300000,
+            "accounts_active": 1200,
+            "url": "/r/rust/",
+        } });
+        let summary = subreddit_summary(&sub);
+        assert_eq!(summary["name"], "rust");
+        assert_eq!(summary["active_users"], 1200);
+        assert_eq!(summary["url"], "https://www.reddit.com/r/rust/");
+    }
+
+    #[tokio::test]
+    async fn account_tools_require_oauth() {
+        let connector = RedditConnector {
+            http: reqwest::Client::new(),
+            credentials: None,
+            token: Arc::new(Mutex::new(None)),
+        };
+        let tools = connector.list_tools(None).await.unwrap().tools;
+        assert!(!tools.iter().any(|t| t.name == "get_inbox"));
+
+        let err = connector
+            .account_listing("get_saved", &serde_json::Map::new())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ConnectorError::Authentication(_)));
+
+        let connector = RedditConnector {
+            credentials: Some(OAuthCredentials {
+                client_id: "id".to_string(),
+                client_secret: String::new(),
+                refresh_token: "token".to_string(),
+            }),
+            ..connector
+        };
+        let tools = connector.list_tools(None).await.unwrap().tools;
+        assert!(tools.iter().any(|t| t.name == "get_saved"));
+        assert!(tools.iter().any(|t| t.name == "get_inbox"));
+    }
 }
@@ -1,17 +1,40 @@
 use crate::capabilities::ConnectorConfigSchema;
 use crate::error::ConnectorError;
-use crate::utils::structured_result_with_text;
+use crate::utils::{html_to_markdown, structured_result_with_text};
 use crate::{auth::AuthDetails, Connector};
 use async_trait::async_trait;
 use feed_rs::parser;
+use futures::stream::{self, StreamExt};
 use reqwest::Client;
 use rmcp::model::*;
 use scraper::{Html, Selector};
 use serde::Deserialize;
 use serde_json::{json, Value};
 use std::borrow::Cow;
+use std::collections::HashSet;
 use std::io::Cursor;
+use std::path::PathBuf;
 use std::sync::Arc;
+use tokio::sync::Mutex;
+
+mod opml;
+mod store;
+
+use opml::OpmlFeed;
+use store::{item_key, Subscription, Subscriptions};
+
+/// Feeds fetched at once by fetch_new.
+const FETCH_CONCURRENCY: usize = 8;
+const DEFAULT_NEW_PER_FEED: usize = 20;
+
+/// Entry summaries and content are usually HTML; return them as markdown.
+fn text_markdown(text: &str) -> String {
+    if text.contains('<') {
+        html_to_markdown(text)
+    } else {
+        text.trim().to_string()
+    }
+}
 
 #[derive(Debug, Deserialize)]
 struct GetFeedArgs {
@@ -37,8 +60,76 @@ struct DiscoverFeedsArgs {
     url: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct SubscribeArgs {
+    url: String,
+    title: Option<String>,
+    category: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UnsubscribeArgs {
+    url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListSubscriptionsArgs {
+    category: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ImportOpmlArgs {
+    path: Option<String>,
+    opml: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExportOpmlArgs {
+    path: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FetchNewArgs {
+    url: Option<String>,
+    category: Option<String>,
+    limit_per_feed: Option<usize>,
+    mark_seen: Option<bool>,
+}
+
+fn expand_path(path: &str) -> PathBuf {
+    match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()
+            .map(|home| home.join(rest))
+            .unwrap_or_else(|| PathBuf::from(path)),
+        None => PathBuf::from(path),
+    }
+}
+
+fn same_category(feed: &Subscription, category: Option<&str>) -> bool {
+    category.map_or(true, |c| {
+        feed.category
+            .as_deref()
+            .is_some_and(|f| f.eq_ignore_ascii_case(c.trim()))
+    })
+}
+
+fn subscription_json(feed: &Subscription) -> Value {
+    json!({
+        "url": feed.url,
+        "title": feed.title,
+        "site_url": feed.site_url,
+        "category": feed.category,
+        "added_at": feed.added_at,
+        "last_checked": feed.last_checked,
+        "seen_items": feed.seen.len(),
+    })
+}
+
 pub struct RssConnector {
     client: Client,
+    store_path: PathBuf,
+    /// Serializes read-modify-write cycles on the subscription file
+    store_lock: Mutex<()>,
 }
 
 impl RssConnector {
@@ -48,6 +139,8 @@ impl RssConnector {
                 .user_agent("arivu-rss-connector/0.1.0")
                 .build()
                 .map_err(ConnectorError::HttpRequest)?,
+            store_path: Subscriptions::default_path(),
+            store_lock: Mutex::new(()),
         })
     }
 
@@ -75,6 +168,124 @@ impl RssConnector {
         parser::parse(cursor)
             .map_err(|e| ConnectorError::Other(format!("Failed to parse feed: {}", e)))
     }
+
+    /// Items of subscribed feeds not returned by an earlier call, newest
+    /// first per feed. Unless `mark_seen` is false, returned items are
+    /// remembered and each feed's last_checked is updated.
+    async fn fetch_new(&self, args: FetchNewArgs) -> Result<Value, ConnectorError> {
+        let _guard = self.store_lock.lock().await;
+        let mut subs = Subscriptions::load(&self.store_path)?;
+        let targets: Vec<String> = subs
+            .feeds
+            .iter()
+            .filter(|f| args.url.as_deref().map_or(true, |u| f.url == u.trim()))
+            .filter(|f| same_category(f, args.category.as_deref()))
+            .map(|f| f.url.clone())
+            .collect();
+        if let Some(url) = &args.url {
+            if targets.is_empty() {
+                return Err(ConnectorError::InvalidParams(format!(
+                    "Not subscribed to {}; use subscribe first",
+                    url
+                )));
+            }
+        }
+
+        let limit = args.limit_per_feed.unwrap_or(DEFAULT_NEW_PER_FEED).max(1);
+        let mark_seen = args.mark_seen.unwrap_or(true);
+        let fetched: Vec<(String, Result<feed_rs::model::Feed, ConnectorError>)> =
+            stream::iter(targets)
+                .map(|url| async move {
+                    let result = self.fetch_and_parse(&url).await;
+                    (url, result)
+                })
+                .buffered(FETCH_CONCURRENCY)
+                .collect()
+                .await;
+
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut items = Vec::new();
+        let mut feeds = Vec::new();
+        let mut errors = Vec::new();
+        // The same post syndicated by several feeds is reported once
+        let mut links: HashSet<String> = HashSet::new();
+        for (url, result) in fetched {
+            let feed = match result {
+                Ok(feed) => feed,
+                Err(e) => {
+                    errors.push(json!({ "url": url, "error": e.to_string() }));
+                    continue;
+                }
+            };
+            let Some(sub) = subs.get_mut(&url) else {
+                continue;
+            };
+            if sub.title.is_none() {
+                sub.title = feed.title.as_ref().map(|t| t.content.clone());
+            }
+
+            // Keys to remember: items returned plus duplicates of them
+            let mut returned: Vec<String> = Vec::new();
+            let mut new_items = 0;
+            let mut remaining = 0;
+            for entry in &feed.entries {
+                let link = entry.links.first().map(|l| l.href.as_str());
+                let title = entry.title.as_ref().map(|t| t.content.as_str());
+                let Some(key) = item_key(&entry.id, link, title) else {
+                    continue;
+                };
+                if sub.has_seen(&key) || returned.contains(&key) {
+                    continue;
+                }
+                if link.is_some_and(|l| links.contains(l)) {
+                    returned.push(key);
+                    continue;
+                }
+                if new_items >= limit {
+                    remaining += 1;
+                    continue;
+                }
+                if let Some(link) = link {
+                    links.insert(link.to_string());
+                }
+                items.push(json!({
+                    "feed": sub.url,
+                    "feed_title": sub.title,
+                    "key": key,
+                    "title": title,
+                    "link": link,
+                    "published": entry.published.map(|d| d.to_rfc3339()),
+                    "updated": entry.updated.map(|d| d.to_rfc3339()),
+                    "summary": entry.summary.as_ref().map(|s| text_markdown(&s.content)),
+                    "authors": entry.authors.iter().map(|a| a.name.clone()).collect::<Vec<_>>(),
+                }));
+                returned.push(key);
+                new_items += 1;
+            }
+            feeds.push(json!({
+                "url": sub.url,
+                "title": sub.title,
+                "new_items": new_items,
+                // Unseen items past limit_per_feed, returned by the next call
+                "remaining": remaining,
+            }));
+            if mark_seen {
+                sub.mark_seen(returned);
+                sub.last_checked = Some(now.clone());
+            }
+        }
+        if mark_seen {
+            subs.save(&self.store_path)?;
+        }
+
+        Ok(json!({
+            "checked_at": now,
+            "count": items.len(),
+            "items": items,
+            "feeds": feeds,
+            "errors": errors,
+        }))
+    }
 }
 
 #[async_trait]
@@ -84,7 +295,7 @@ impl Connector for RssConnector {
     }
 
     fn description(&self) -> &'static str {
-        "Fetch and parse RSS/Atom feeds"
+        "Fetch and parse RSS/Atom feeds, and poll subscribed feeds for new items"
     }
 
     async fn capabilities(&self) -> ServerCapabilities {
@@ -124,7 +335,11 @@ impl Connector for RssConnector {
                 icons: None,
                 website_url: None,
             },
-            instructions: Some("Fetch and read RSS/Atom/JSON feeds.".to_string()),
+            instructions: Some(
+                "Fetch and read RSS/Atom/JSON feeds. Subscribe to feeds (or import OPML) \
+and call fetch_new to get only items you haven't seen."
+                    .to_string(),
+            ),
         })
     }
 
@@ -253,6 +468,184 @@ URL, not a feed URL. Example: url=\"https://blog.rust-lang.org\".",
                 annotations: None,
                 icons: None,
             },
+            Tool {
+                name: Cow::Borrowed("subscribe"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Subscribe to a feed so fetch_new can poll it. The feed is fetched once to \
+check it parses and to record its title. Example: url=\"https://blog.rust-lang.org/feed.xml\" \
+category=\"Rust\".",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "url": {
+                                "type": "string",
+                                "description": "URL of the RSS/Atom feed"
+                            },
+                            "title": {
+                                "type": "string",
+                                "description": "Display title (default: the feed's own title)"
+                            },
+                            "category": {
+                                "type": "string",
+                                "description": "Folder/category to file the feed under"
+                            }
+                        },
+                        "required": ["url"]
+                    })
+                    .as_object()
+                    .expect("Schema object")
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("unsubscribe"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Remove a feed subscription and its read history. Example: \
+url=\"https://blog.rust-lang.org/feed.xml\".",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "url": {
+                                "type": "string",
+                                "description": "URL of the subscribed feed"
+                            }
+                        },
+                        "required": ["url"]
+                    })
+                    .as_object()
+                    .expect("Schema object")
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("list_subscriptions"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "List subscribed feeds with category, last check time and how many items \
+have been seen. Example: category=\"Rust\".",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "category": {
+                                "type": "string",
+                                "description": "Only feeds in this category"
+                            }
+                        }
+                    })
+                    .as_object()
+                    .expect("Schema object")
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("import_opml"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Subscribe to every feed in an OPML file (as exported by most feed readers). \
+Folders become categories; existing subscriptions are kept. Example: path=\"~/Downloads/feeds.opml\".",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "path": {
+                                "type": "string",
+                                "description": "Path to an OPML file"
+                            },
+                            "opml": {
+                                "type": "string",
+                                "description": "OPML document text (instead of path)"
+                            }
+                        }
+                    })
+                    .as_object()
+                    .expect("Schema object")
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("export_opml"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Export subscriptions as OPML 2.0, grouped by category. Writes to path if \
+given, otherwise returns the document. Example: path=\"~/feeds.opml\".",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "path": {
+                                "type": "string",
+                                "description": "File to write (default: return the OPML text)"
+                            }
+                        }
+                    })
+                    .as_object()
+                    .expect("Schema object")
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("fetch_new"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Poll subscribed feeds and return only items not returned before, deduped \
+across polls and across feeds. Use for \"what's new in my feeds\". Example: category=\"Rust\" \
+limit_per_feed=10.",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "url": {
+                                "type": "string",
+                                "description": "Poll only this subscribed feed (default: all)"
+                            },
+                            "category": {
+                                "type": "string",
+                                "description": "Poll only feeds in this category"
+                            },
+                            "limit_per_feed": {
+                                "type": "integer",
+                                "description": "Most new items per feed (default: 20); the rest come on the next call"
+                            },
+                            "mark_seen": {
+                                "type": "boolean",
+                                "description": "Remember returned items and update last_checked (default: true); false previews"
+                            }
+                        }
+                    })
+                    .as_object()
+                    .expect("Schema object")
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
         ];
 
         Ok(ListToolsResult {
@@ -288,7 +681,7 @@ URL, not a feed URL. Example: url=\"https://blog.rust-lang.org\".",
                             "title": e.title.as_ref().map(|t| t.content.clone()),
                             "link": e.links.first().map(|l| l.href.clone()),
                             "published": e.published.map(|d| d.to_rfc3339()),
-                            "summary": e.summary.as_ref().map(|s| s.content.clone()),
+                            "summary": e.summary.as_ref().map(|s| text_markdown(&s.content)),
                         })
                     })
                     .collect();
@@ -323,8 +716,8 @@ URL, not a feed URL. Example: url=\"https://blog.rust-lang.org\".",
                         "link": e.links.first().map(|l| l.href.clone()),
                         "published": e.published.map(|d| d.to_rfc3339()),
                         "updated": e.updated.map(|d| d.to_rfc3339()),
-                        "summary": e.summary.as_ref().map(|s| s.content.clone()),
-                        "content": e.content.as_ref().map(|c| c.body.clone().unwrap_or_default()),
+                        "summary": e.summary.as_ref().map(|s| text_markdown(&s.content)),
+                        "content": e.content.as_ref().map(|c| text_markdown(c.body.as_deref().unwrap_or_default())),
                         "authors": e.authors.iter().map(|a| a.name.clone()).collect::<Vec<_>>()
                     })
                 }).collect();
@@ -371,8 +764,8 @@ URL, not a feed URL. Example: url=\"https://blog.rust-lang.org\".",
                         "link": e.links.first().map(|l| l.href.clone()),
                         "published": e.published.map(|d| d.to_rfc3339()),
                         "updated": e.updated.map(|d| d.to_rfc3339()),
-                        "summary": e.summary.as_ref().map(|s| s.content.clone()),
-                        "content": e.content.as_ref().map(|c| c.body.clone().unwrap_or_default()),
+                        "summary": e.summary.as_ref().map(|s| text_markdown(&s.content)),
+                        "content": e.content.as_ref().map(|c| text_markdown(c.body.as_deref().unwrap_or_default())),
                         "authors": e.authors.iter().map(|a| a.name.clone()).collect::<Vec<_>>()
                     })
                 }).collect();
@@ -436,6 +829,185 @@ URL, not a feed URL. Example: url=\"https://blog.rust-lang.org\".",
                     Some(serde_json::to_string(&data)?),
                 )?)
             }
+            "subscribe" => {
+                let args: SubscribeArgs = serde_json::from_value(
+                    serde_json::to_value(request.arguments.unwrap_or_default())
+                        .map_err(ConnectorError::SerdeJson)?,
+                )
+                .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+
+                let feed = self.fetch_and_parse(args.url.trim()).await?;
+                let mut subscription = Subscription::new(&args.url);
+                subscription.title = args
+                    .title
+                    .or_else(|| feed.title.as_ref().map(|t| t.content.clone()));
+                subscription.site_url = feed.links.first().map(|l| l.href.clone());
+                subscription.category = args.category;
+
+                let _guard = self.store_lock.lock().await;
+                let mut subs = Subscriptions::load(&self.store_path)?;
+                let added = subs.add(subscription);
+                subs.save(&self.store_path)?;
+                let data = json!({
+                    "subscribed": added,
+                    "already_subscribed": !added,
+                    "feed": subs.get(&args.url).map(subscription_json),
+                    "entries_count": feed.entries.len(),
+                });
+
+                Ok(structured_result_with_text(
+                    &data,
+                    Some(serde_json::to_string(&data)?),
+                )?)
+            }
+            "unsubscribe" => {
+                let args: UnsubscribeArgs = serde_json::from_value(
+                    serde_json::to_value(request.arguments.unwrap_or_default())
+                        .map_err(ConnectorError::SerdeJson)?,
+                )
+                .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+
+                let _guard = self.store_lock.lock().await;
+                let mut subs = Subscriptions::load(&self.store_path)?;
+                if !subs.remove(&args.url) {
+                    return Err(ConnectorError::InvalidParams(format!(
+                        "Not subscribed to {}",
+                        args.url
+                    )));
+                }
+                subs.save(&self.store_path)?;
+                let data = json!({ "unsubscribed": args.url.trim() });
+
+                Ok(structured_result_with_text(
+                    &data,
+                    Some(serde_json::to_string(&data)?),
+                )?)
+            }
+            "list_subscriptions" => {
+                let args: ListSubscriptionsArgs = serde_json::from_value(
+                    serde_json::to_value(request.arguments.unwrap_or_default())
+                        .map_err(ConnectorError::SerdeJson)?,
+                )
+                .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+
+                let subs = Subscriptions::load(&self.store_path)?;
+                let feeds: Vec<Value> = subs
+                    .feeds
+                    .iter()
+                    .filter(|f| same_category(f, args.category.as_deref()))
+                    .map(subscription_json)
+                    .collect();
+                let data = json!({
+                    "count": feeds.len(),
+                    "subscriptions": feeds,
+                });
+
+                Ok(structured_result_with_text(
+                    &data,
+                    Some(serde_json::to_string(&data)?),
+                )?)
+            }
+            "import_opml" => {
+                let args: ImportOpmlArgs = serde_json::from_value(
+                    serde_json::to_value(request.arguments.unwrap_or_default())
+                        .map_err(ConnectorError::SerdeJson)?,
+                )
+                .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+
+                let text = match (args.opml, args.path) {
+                    (Some(text), _) => text,
+                    (None, Some(path)) => std::fs::read_to_string(expand_path(path.trim()))?,
+                    (None, None) => {
+                        return Err(ConnectorError::InvalidParams(
+                            "Provide path or opml".to_string(),
+                        ))
+                    }
+                };
+                let outlines = opml::parse(&text);
+                if outlines.is_empty() {
+                    return Err(ConnectorError::InvalidInput(
+                        "No feed outlines (xmlUrl) found in the OPML".to_string(),
+                    ));
+                }
+
+                let _guard = self.store_lock.lock().await;
+                let mut subs = Subscriptions::load(&self.store_path)?;
+                let mut imported = Vec::new();
+                let mut existing = 0;
+                for outline in outlines {
+                    let mut subscription = Subscription::new(&outline.url);
+                    subscription.title = outline.title;
+                    subscription.site_url = outline.site_url;
+                    subscription.category = outline.category;
+                    if subs.add(subscription) {
+                        imported.push(outline.url);
+                    } else {
+                        existing += 1;
+                    }
+                }
+                subs.save(&self.store_path)?;
+                let data = json!({
+                    "imported": imported.len(),
+                    "already_subscribed": existing,
+                    "total_subscriptions": subs.feeds.len(),
+                    "feeds": imported,
+                });
+
+                Ok(structured_result_with_text(
+                    &data,
+                    Some(serde_json::to_string(&data)?),
+                )?)
+            }
+            "export_opml" => {
+                let args: ExportOpmlArgs = serde_json::from_value(
+                    serde_json::to_value(request.arguments.unwrap_or_default())
+                        .map_err(ConnectorError::SerdeJson)?,
+                )
+                .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+
+                let subs = Subscriptions::load(&self.store_path)?;
+                let outlines: Vec<OpmlFeed> = subs
+                    .feeds
+                    .iter()
+                    .map(|f| OpmlFeed {
+                        url: f.url.clone(),
+                        title: f.title.clone(),
+                        site_url: f.site_url.clone(),
+                        category: f.category.clone(),
+                    })
+                    .collect();
+                let document = opml::export(&outlines, "arivu subscriptions");
+                let data = match args.path {
+                    Some(path) => {
+                        let path = expand_path(path.trim());
+                        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                            std::fs::create_dir_all(parent)?;
+                        }
+                        std::fs::write(&path, &document)?;
+                        json!({ "path": path, "feeds": outlines.len() })
+                    }
+                    None => json!({ "feeds": outlines.len(), "opml": document }),
+                };
+
+                Ok(structured_result_with_text(
+                    &data,
+                    Some(serde_json::to_string(&data)?),
+                )?)
+            }
+            "fetch_new" => {
+                let args: FetchNewArgs = serde_json::from_value(
+                    serde_json::to_value(request.arguments.unwrap_or_default())
+                        .map_err(ConnectorError::SerdeJson)?,
+                )
+                .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+
+                let data = self.fetch_new(args).await?;
+
+                Ok(structured_result_with_text(
+                    &data,
+                    Some(serde_json::to_string(&data)?),
+                )?)
+            }
             _ => Err(ConnectorError::ToolNotFound),
         }
     }
@@ -0,0 +1,174 @@
+// src/connectors/rss/opml.rs
+//
+// OPML 1.0/2.0 subscription lists. Outlines with an xmlUrl are feeds; an
+// outline without one is a folder, and its text becomes the category of the
+// feeds nested under it (innermost folder wins).
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static RE_OUTLINE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?is)<outline\b([^>]*?)(/?)>|</outline\s*>").unwrap());
+static RE_ATTR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?s)([A-Za-z_:][\w:.-]*)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap());
+
+/// A feed outline from an OPML document.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct OpmlFeed {
+    pub url: String,
+    pub title: Option<String>,
+    pub site_url: Option<String>,
+    pub category: Option<String>,
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn attr(attrs: &str, name: &str) -> Option<String> {
+    RE_ATTR
+        .captures_iter(attrs)
+        .find(|c| c[1].eq_ignore_ascii_case(name))
+        .and_then(|c| c.get(2).or_else(|| c.get(3)))
+        .map(|v| unescape(v.as_str().trim()))
+        .filter(|v| !v.is_empty())
+}
+
+/// Feeds listed in an OPML document, in document order.
+pub(super) fn parse(opml: &str) -> Vec<OpmlFeed> {
+    let mut feeds = Vec::new();
+    // Open folders; None for feed outlines that weren't self-closed
+    let mut folders: Vec<Option<String>> = Vec::new();
+    for tag in RE_OUTLINE.captures_iter(opml) {
+        let Some(attrs) = tag.get(1) else {
+            folders.pop();
+            continue;
+        };
+        let attrs = attrs.as_str();
+        let self_closing = tag.get(2).is_some_and(|m| !m.as_str().is_empty());
+        let text = attr(attrs, "text").or_else(|| attr(attrs, "title"));
+        let is_feed = match attr(attrs, "xmlUrl") {
+            Some(url) => {
+                feeds.push(OpmlFeed {
+                    url,
+                    title: text.clone(),
+                    site_url: attr(attrs, "htmlUrl"),
+                    category: folders.iter().rev().find_map(|f| f.clone()),
+                });
+                true
+            }
+            None => false,
+        };
+        if !self_closing {
+            folders.push(if is_feed { None } else { text });
+        }
+    }
+    feeds
+}
+
+/// An OPML 2.0 document with feeds grouped into one folder per category.
+pub(super) fn export(feeds: &[OpmlFeed], title: &str) -> String {
+    let mut out =
+        String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<opml version=\"2.0\">\n");
+    out.push_str(&format!(
+        "  <head>\n    <title>{}</title>\n    <dateCreated>{}</dateCreated>\n  </head>\n  <body>\n",
+        escape(title),
+        chrono::Utc::now().to_rfc2822()
+    ));
+
+    let outline = |feed: &OpmlFeed, indent: &str| {
+        let text = escape(feed.title.as_deref().unwrap_or(&feed.url));
+        let mut line = format!(
+            "{}<outline type=\"rss\" text=\"{}\" title=\"{}\" xmlUrl=\"{}\"",
+            indent,
+            text,
+            text,
+            escape(&feed.url)
+        );
+        if let Some(site) = &feed.site_url {
+            line.push_str(&format!(" htmlUrl=\"{}\"", escape(site)));
+        }
+        line.push_str("/>\n");
+        line
+    };
+
+    let mut categories: Vec<&str> = Vec::new();
+    for feed in feeds {
+        match feed.category.as_deref() {
+            Some(c) if !categories.contains(&c) => categories.push(c),
+            Some(_) => {}
+            None => out.push_str(&outline(feed, "    ")),
+        }
+    }
+    for category in categories {
+        let name = escape(category);
+        out.push_str(&format!(
+            "    <outline text=\"{}\" title=\"{}\">\n",
+            name, name
+        ));
+        for feed in feeds
+            .iter()
+            .filter(|f| f.category.as_deref() == Some(category))
+        {
+            out.push_str(&outline(feed, "      "));
+        }
+        out.push_str("    </outline>\n");
+    }
+    out.push_str("  </body>\n</opml>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_folders_and_round_trips() {
+        let opml = r#"<?xml version="1.0"?>
+<opml version="1.0"><head><title>Reader export</title></head><body>
+  <outline text="Rust" title="Rust">
+    <outline type="rss" text="Rust Blog" xmlUrl="https://blog.rust-lang.org/feed.xml" htmlUrl="https://blog.rust-lang.org/"/>
+    <outline text="Nested"><outline type="rss" text="This Week in Rust" xmlUrl='https://this-week-in-rust.org/rss.xml'/></outline>
+  </outline>
+  <outline type="rss" text="Q&amp;A &quot;Daily&quot;" xmlUrl="https://example.com/feed?a=1&amp;b=2"></outline>
+  <outline type="rss" text="Loose" xmlUrl="https://example.org/atom.xml"/>
+</body></opml>"#;
+        let feeds = parse(opml);
+        let summary: Vec<(&str, Option<&str>)> = feeds
+            .iter()
+            .map(|f| (f.url.as_str(), f.category.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("https://blog.rust-lang.org/feed.xml", Some("Rust")),
+                ("https://this-week-in-rust.org/rss.xml", Some("Nested")),
+                ("https://example.com/feed?a=1&b=2", None),
+                ("https://example.org/atom.xml", None),
+            ]
+        );
+        assert_eq!(feeds[2].title.as_deref(), Some("Q&A \"Daily\""));
+        assert_eq!(
+            feeds[0].site_url.as_deref(),
+            Some("https://blog.rust-lang.org/")
+        );
+
+        let reparsed = parse(&export(&feeds, "arivu"));
+        assert_eq!(reparsed.len(), feeds.len());
+        for feed in &feeds {
+            assert!(reparsed.contains(feed), "missing {:?}", feed);
+        }
+    }
+}
@@ -0,0 +1,178 @@
+// src/connectors/rss/store.rs
+//
+// Feed subscriptions persisted as one JSON file under `~/.arivu/rss`
+// (override with ARIVU_RSS_DIR). Each feed remembers the keys of the items
+// already returned by fetch_new, so polling only reports what's new.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const STORE_FILE: &str = "subscriptions.json";
+/// Item keys remembered per feed; well above what any feed keeps listed.
+const MAX_SEEN_PER_FEED: usize = 1_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) struct Subscription {
+    pub url: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub site_url: Option<String>,
+    #[serde(default)]
+    pub category: Option<String>,
+    pub added_at: String,
+    #[serde(default)]
+    pub last_checked: Option<String>,
+    /// Keys of items already returned, oldest first
+    #[serde(default)]
+    pub seen: Vec<String>,
+}
+
+impl Subscription {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.trim().to_string(),
+            title: None,
+            site_url: None,
+            category: None,
+            added_at: chrono::Utc::now().to_rfc3339(),
+            last_checked: None,
+            seen: Vec::new(),
+        }
+    }
+
+    pub fn has_seen(&self, key: &str) -> bool {
+        self.seen.iter().any(|k| k == key)
+    }
+
+    /// Remember `keys`, dropping the oldest once the cap is reached.
+    pub fn mark_seen<I: IntoIterator<Item = String>>(&mut self, keys: I) {
+        let mut known: HashSet<String> = self.seen.iter().cloned().collect();
+        for key in keys {
+            if known.insert(key.clone()) {
+                self.seen.push(key);
+            }
+        }
+        if self.seen.len() > MAX_SEEN_PER_FEED {
+            let excess = self.seen.len() - MAX_SEEN_PER_FEED;
+            self.seen.drain(..excess);
+        }
+    }
+}
+
+/// The dedupe key of a feed item: its id, else its link, else its title.
+pub(super) fn item_key(id: &str, link: Option<&str>, title: Option<&str>) -> Option<String> {
+    [Some(id), link, title]
+        .into_iter()
+        .flatten()
+        .map(str::trim)
+        .find(|k| !k.is_empty())
+        .map(str::to_string)
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(super) struct Subscriptions {
+    #[serde(default)]
+    pub feeds: Vec<Subscription>,
+}
+
+impl Subscriptions {
+    pub fn default_path() -> PathBuf {
+        std::env::var("ARIVU_RSS_DIR")
+            .ok()
+            .filter(|d| !d.trim().is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| {
+                dirs::home_dir()
+                    .unwrap_or_else(|| PathBuf::from("."))
+                    .join(".arivu")
+                    .join("rss")
+            })
+            .join(STORE_FILE)
+    }
+
+    /// Load the store; a missing file is an empty store.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read(path) {
+            Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_vec_pretty(self)?)?;
+        fs::rename(tmp, path)
+    }
+
+    pub fn get(&self, url: &str) -> Option<&Subscription> {
+        self.feeds.iter().find(|f| f.url == url.trim())
+    }
+
+    pub fn get_mut(&mut self, url: &str) -> Option<&mut Subscription> {
+        self.feeds.iter_mut().find(|f| f.url == url.trim())
+    }
+
+    /// Add a feed; an existing subscription only has missing details filled
+    /// in. Returns whether the feed was new.
+    pub fn add(&mut self, feed: Subscription) -> bool {
+        match self.get_mut(&feed.url) {
+            Some(existing) => {
+                existing.title = existing.title.take().or(feed.title);
+                existing.site_url = existing.site_url.take().or(feed.site_url);
+                existing.category = existing.category.take().or(feed.category);
+                false
+            }
+            None => {
+                self.feeds.push(feed);
+                true
+            }
+        }
+    }
+
+    pub fn remove(&mut self, url: &str) -> bool {
+        let before = self.feeds.len();
+        self.feeds.retain(|f| f.url != url.trim());
+        self.feeds.len() != before
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dedupes_subscriptions_and_seen_items() {
+        let mut subs = Subscriptions::default();
+        assert!(subs.add(Subscription::new("https://example.com/feed.xml ")));
+        let mut again = Subscription::new("https://example.com/feed.xml");
+        again.title = Some("Example".to_string());
+        assert!(!subs.add(again));
+        assert_eq!(subs.feeds.len(), 1);
+        assert_eq!(subs.feeds[0].title.as_deref(), Some("Example"));
+
+        let feed = subs.get_mut("https://example.com/feed.xml").unwrap();
+        feed.mark_seen(["a".to_string(), "b".to_string(), "a".to_string()]);
+        assert_eq!(feed.seen, ["a", "b"]);
+        feed.mark_seen((0..MAX_SEEN_PER_FEED).map(|i| i.to_string()));
+        assert_eq!(feed.seen.len(), MAX_SEEN_PER_FEED);
+        assert!(!feed.has_seen("a"));
+        assert!(feed.has_seen("999"));
+
+        assert_eq!(
+            item_key(" ", Some("https://example.com/p/1"), Some("Post")).as_deref(),
+            Some("https://example.com/p/1")
+        );
+        assert_eq!(item_key("", None, None), None);
+
+        assert!(subs.remove("https://example.com/feed.xml"));
+        assert!(subs.get("https://example.com/feed.xml").is_none());
+    }
+}
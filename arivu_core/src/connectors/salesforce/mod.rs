@@ -0,0 +1,644 @@
+use crate::auth_store::{AuthStore, FileAuthStore};
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, oauth, Connector};
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+const DEFAULT_API_VERSION: &str = "v60.0";
+
+#[derive(Debug, Deserialize)]
+struct QueryArgs {
+    soql: String,
+    #[serde(default)]
+    max_records: Option<usize>,
+    #[serde(default)]
+    include_deleted: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetRecordArgs {
+    object: String,
+    id: String,
+    #[serde(default)]
+    fields: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DescribeArgs {
+    object: String,
+    #[serde(default)]
+    include_picklists: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListObjectsArgs {
+    #[serde(default)]
+    filter: Option<String>,
+    #[serde(default)]
+    custom_only: Option<bool>,
+}
+
+/// Access token + instance URL currently in use.
+#[derive(Clone)]
+struct Session {
+    access_token: String,
+    instance_url: String,
+}
+
+pub struct SalesforceConnector {
+    client: Client,
+    auth: AuthDetails,
+    api_version: String,
+    session: Arc<Mutex<Option<Session>>>,
+}
+
+impl SalesforceConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+
+        let mut auth = auth;
+        for (key, var) in [
+            ("instance_url", "SALESFORCE_INSTANCE_URL"),
+            ("access_token", "SALESFORCE_ACCESS_TOKEN"),
+            ("refresh_token", "SALESFORCE_REFRESH_TOKEN"),
+            ("client_id", "SALESFORCE_CLIENT_ID"),
+            ("client_secret", "SALESFORCE_CLIENT_SECRET"),
+            ("login_url", "SALESFORCE_LOGIN_URL"),
+        ] {
+            if !auth.contains_key(key) {
+                if let Ok(value) = std::env::var(var) {
+                    if !value.trim().is_empty() {
+                        auth.insert(key.to_string(), value);
+                    }
+                }
+            }
+        }
+        let api_version = auth
+            .get("api_version")
+            .cloned()
+            .map(|v| {
+                if v.starts_with('v') {
+                    v
+                } else {
+                    format!("v{}", v)
+                }
+            })
+            .unwrap_or_else(|| DEFAULT_API_VERSION.to_string());
+
+        Ok(Self {
+            client,
+            auth,
+            api_version,
+            session: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Stored tokens (refreshed ones persisted by a previous run) merged under explicit config.
+    fn merged_auth(&self) -> AuthDetails {
+        let mut auth = FileAuthStore::new_default()
+            .load(self.name())
+            .unwrap_or_default();
+        for (k, v) in self.auth.iter() {
+            auth.entry(k.clone()).or_insert(v.clone());
+        }
+        auth
+    }
+
+    async fn session(&self, force_refresh: bool) -> Result<Session, ConnectorError> {
+        let mut cached = self.session.lock().await;
+        if !force_refresh {
+            if let Some(session) = cached.as_ref() {
+                return Ok(session.clone());
+            }
+        }
+
+        let mut auth = self.merged_auth();
+        let has_token = auth.contains_key("access_token") && auth.contains_key("instance_url");
+        if force_refresh || !has_token {
+            if !auth.contains_key("refresh_token") {
+                return Err(ConnectorError::Authentication(
+                    "Salesforce auth not configured: set instance_url + access_token, or client_id + refresh_token (arivu setup salesforce)"
+                        .to_string(),
+                ));
+            }
+            oauth::refresh_salesforce_access(&mut auth).await?;
+            if crate::oauth_client::should_persist_tokens() {
+                let _ = FileAuthStore::new_default().save(self.name(), &auth);
+            }
+        }
+
+        let session = Session {
+            access_token: auth.get("access_token").cloned().unwrap_or_default(),
+            instance_url: auth
+                .get("instance_url")
+                .map(|u| u.trim_end_matches('/').to_string())
+                .ok_or_else(|| {
+                    ConnectorError::Authentication("Missing Salesforce instance_url".to_string())
+                })?,
+        };
+        *cached = Some(session.clone());
+        Ok(session)
+    }
+
+    /// GET a REST path (relative to the instance, e.g. `/services/data/v60.0/...`),
+    /// refreshing the access token once if the session has expired.
+    async fn get(&self, path: &str, params: &[(&str, String)]) -> Result<Value, ConnectorError> {
+        let mut session = self.session(false).await?;
+        for attempt in 0..2 {
+            let response = self
+                .client
+                .get(format!("{}{}", session.instance_url, path))
+                .query(params)
+                .bearer_auth(&session.access_token)
+                .send()
+                .await
+                .map_err(ConnectorError::HttpRequest)?;
+
+            let status = response.status();
+            if status == StatusCode::UNAUTHORIZED && attempt == 0 {
+                session = self.session(true).await?;
+                continue;
+            }
+            if status.is_success() {
+                return response.json().await.map_err(ConnectorError::HttpRequest);
+            }
+
+            let body: Value = response.json().await.unwrap_or(Value::Null);
+            let message = body[0]["message"].as_str().unwrap_or_default().to_string();
+            return Err(match status {
+                StatusCode::NOT_FOUND => ConnectorError::ResourceNotFound,
+                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                    ConnectorError::Authentication(format!("Salesforce: {}", message))
+                }
+                StatusCode::BAD_REQUEST => {
+                    ConnectorError::InvalidParams(format!("Salesforce: {}", message))
+                }
+                s => ConnectorError::Other(format!(
+                    "Salesforce API returned error status: {} {}",
+                    s, message
+                )),
+            });
+        }
+        Err(ConnectorError::Authentication(
+            "Salesforce session refresh failed".to_string(),
+        ))
+    }
+
+    fn data_path(&self, rest: &str) -> String {
+        format!("/services/data/{}/{}", self.api_version, rest)
+    }
+}
+
+/// Drop the `attributes` envelope Salesforce adds to every record (recursively).
+fn strip_attributes(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.remove("attributes");
+            for v in map.values_mut() {
+                strip_attributes(v);
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(strip_attributes),
+        _ => {}
+    }
+}
+
+/// Reject anything that is not a single SOQL SELECT.
+fn validate_soql(soql: &str) -> Result<(), ConnectorError> {
+    let trimmed = soql.trim().trim_end_matches(';');
+    if !trimmed.to_ascii_lowercase().starts_with("select ") {
+        return Err(ConnectorError::InvalidParams(
+            "Only SOQL SELECT statements are supported".to_string(),
+        ));
+    }
+    if trimmed.contains(';') {
+        return Err(ConnectorError::InvalidParams(
+            "Provide a single SOQL statement".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn valid_api_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[async_trait]
+impl Connector for SalesforceConnector {
+    fn name(&self) -> &'static str {
+        "salesforce"
+    }
+
+    fn description(&self) -> &'static str {
+        "Salesforce CRM: SOQL queries, record retrieval and object metadata."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        Ok(self.auth.clone())
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        *self = Self::new(details).await?;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.get(&self.data_path("limits"), &[]).await.map(|_| ())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        let text = |name: &str, label: &str, required: bool, description: &str| Field {
+            name: name.to_string(),
+            label: label.to_string(),
+            field_type: FieldType::Text,
+            required,
+            description: Some(description.to_string()),
+            options: None,
+        };
+        let secret = |name: &str, label: &str, description: &str| Field {
+            name: name.to_string(),
+            label: label.to_string(),
+            field_type: FieldType::Secret,
+            required: false,
+            description: Some(description.to_string()),
+            options: None,
+        };
+
+        ConnectorConfigSchema {
+            fields: vec![
+                text(
+                    "client_id",
+                    "Client ID",
+                    true,
+                    "Connected App consumer key (or set SALESFORCE_CLIENT_ID).",
+                ),
+                secret(
+                    "client_secret",
+                    "Client Secret",
+                    "Connected App consumer secret (or set SALESFORCE_CLIENT_SECRET).",
+                ),
+                secret(
+                    "refresh_token",
+                    "Refresh Token",
+                    "OAuth refresh token; used to mint access tokens (or set SALESFORCE_REFRESH_TOKEN).",
+                ),
+                text(
+                    "instance_url",
+                    "Instance URL",
+                    false,
+                    "e.g. https://yourorg.my.salesforce.com (filled in automatically on refresh).",
+                ),
+                text(
+                    "login_url",
+                    "Login URL",
+                    false,
+                    "https://login.salesforce.com (default) or https://test.salesforce.com for sandboxes.",
+                ),
+                text(
+                    "api_version",
+                    "API Version",
+                    false,
+                    "REST API version (default: 60.0).",
+                ),
+            ],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Use `list_objects` and `describe_object` to learn field API names, then `query` \
+with SOQL. `get_record` fetches one record by id."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            tool(
+                "query",
+                "Run a SOQL SELECT and return records (follows pagination up to max_records). \
+Example: soql=\"SELECT Id, Name, StageName FROM Opportunity WHERE IsClosed = false LIMIT 20\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "soql": { "type": "string", "description": "SOQL SELECT statement" },
+                        "max_records": { "type": "integer", "description": "Max records to return across pages (default: 200, max: 2000)" },
+                        "include_deleted": { "type": "boolean", "description": "Include deleted/archived records (queryAll)" }
+                    },
+                    "required": ["soql"]
+                }),
+            ),
+            tool(
+                "get_record",
+                "Get a record by id. Example: object=\"Account\" id=\"001xx000003DGb2AAG\" fields=\"Name,Industry\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "object": { "type": "string", "description": "SObject API name (e.g. Account, Contact, Case)" },
+                        "id": { "type": "string", "description": "15 or 18 character record id" },
+                        "fields": { "type": "string", "description": "Comma-separated field API names (default: all)" }
+                    },
+                    "required": ["object", "id"]
+                }),
+            ),
+            tool(
+                "describe_object",
+                "Fields (API names, types, references) and child relationships for an object. Example: object=\"Opportunity\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "object": { "type": "string", "description": "SObject API name" },
+                        "include_picklists": { "type": "boolean", "description": "Include picklist values (default: false)" }
+                    },
+                    "required": ["object"]
+                }),
+            ),
+            tool(
+                "list_objects",
+                "List queryable objects in the org. Example: filter=\"invoice\" custom_only=true.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "filter": { "type": "string", "description": "Case-insensitive substring of name or label" },
+                        "custom_only": { "type": "boolean", "description": "Only custom objects (default: false)" }
+                    }
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "query" => {
+                let args: QueryArgs = parse_args(request.arguments)?;
+                validate_soql(&args.soql)?;
+                let max_records = args.max_records.unwrap_or(200).clamp(1, 2000);
+                let endpoint = if args.include_deleted.unwrap_or(false) {
+                    "queryAll"
+                } else {
+                    "query"
+                };
+
+                let mut page = self
+                    .get(
+                        &self.data_path(endpoint),
+                        &[("q", args.soql.trim().trim_end_matches(';').to_string())],
+                    )
+                    .await?;
+                let total = page["totalSize"].as_u64();
+                let mut records: Vec<Value> = Vec::new();
+                loop {
+                    records.extend(page["records"].as_array().cloned().unwrap_or_default());
+                    let next = page["nextRecordsUrl"].as_str().map(str::to_string);
+                    match next {
+                        Some(next) if records.len() < max_records => {
+                            page = self.get(&next, &[]).await?;
+                        }
+                        _ => break,
+                    }
+                }
+                let truncated = records.len() > max_records
+                    || total.map(|t| t as usize > max_records).unwrap_or(false);
+                records.truncate(max_records);
+                let mut records = Value::Array(records);
+                strip_attributes(&mut records);
+
+                let data = json!({
+                    "total_size": total,
+                    "count": records.as_array().map(Vec::len),
+                    "truncated": truncated,
+                    "records": records,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_record" => {
+                let args: GetRecordArgs = parse_args(request.arguments)?;
+                if !valid_api_name(&args.object) || !valid_api_name(&args.id) {
+                    return Err(ConnectorError::InvalidParams(
+                        "object and id must be API names / record ids".to_string(),
+                    ));
+                }
+                let mut params = Vec::new();
+                if let Some(fields) = args.fields {
+                    params.push(("fields", fields));
+                }
+                let mut record = self
+                    .get(
+                        &self.data_path(&format!("sobjects/{}/{}", args.object, args.id)),
+                        &params,
+                    )
+                    .await?;
+                strip_attributes(&mut record);
+                Ok(structured_result_with_text(&record, None)?)
+            }
+            "describe_object" => {
+                let args: DescribeArgs = parse_args(request.arguments)?;
+                if !valid_api_name(&args.object) {
+                    return Err(ConnectorError::InvalidParams(format!(
+                        "Invalid object name: {}",
+                        args.object
+                    )));
+                }
+                let include_picklists = args.include_picklists.unwrap_or(false);
+                let body = self
+                    .get(
+                        &self.data_path(&format!("sobjects/{}/describe", args.object)),
+                        &[],
+                    )
+                    .await?;
+
+                let fields: Vec<Value> = body["fields"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|f| {
+                        let mut field = json!({
+                            "name": f["name"],
+                            "label": f["label"],
+                            "type": f["type"],
+                            "nillable": f["nillable"],
+                            "custom": f["custom"],
+                        });
+                        if f["referenceTo"].as_array().is_some_and(|r| !r.is_empty()) {
+                            field["reference_to"] = f["referenceTo"].clone();
+                            field["relationship_name"] = f["relationshipName"].clone();
+                        }
+                        if include_picklists && f["type"] == "picklist" {
+                            field["picklist_values"] = json!(f["picklistValues"]
+                                .as_array()
+                                .into_iter()
+                                .flatten()
+                                .filter(|v| v["active"].as_bool().unwrap_or(true))
+                                .filter_map(|v| v["value"].as_str())
+                                .collect::<Vec<_>>());
+                        }
+                        field
+                    })
+                    .collect();
+                let children: Vec<Value> = body["childRelationships"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter(|r| r["relationshipName"].is_string())
+                    .map(|r| {
+                        json!({
+                            "object": r["childSObject"],
+                            "field": r["field"],
+                            "relationship_name": r["relationshipName"],
+                        })
+                    })
+                    .collect();
+
+                let data = json!({
+                    "name": body["name"],
+                    "label": body["label"],
+                    "custom": body["custom"],
+                    "queryable": body["queryable"],
+                    "field_count": fields.len(),
+                    "fields": fields,
+                    "child_relationships": children,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "list_objects" => {
+                let args: ListObjectsArgs = parse_args(request.arguments)?;
+                let body = self.get(&self.data_path("sobjects"), &[]).await?;
+                let needle = args.filter.map(|f| f.to_lowercase());
+                let custom_only = args.custom_only.unwrap_or(false);
+
+                let objects: Vec<Value> = body["sobjects"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter(|o| o["queryable"].as_bool().unwrap_or(false))
+                    .filter(|o| !custom_only || o["custom"].as_bool().unwrap_or(false))
+                    .filter(|o| match &needle {
+                        Some(n) => [&o["name"], &o["label"]].iter().any(|v| {
+                            v.as_str()
+                                .map(|s| s.to_lowercase().contains(n))
+                                .unwrap_or(false)
+                        }),
+                        None => true,
+                    })
+                    .map(|o| {
+                        json!({
+                            "name": o["name"],
+                            "label": o["label"],
+                            "custom": o["custom"],
+                        })
+                    })
+                    .collect();
+
+                let data = json!({
+                    "count": objects.len(),
+                    "objects": objects,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_soql() {
+        assert!(validate_soql("SELECT Id FROM Account LIMIT 5;").is_ok());
+        assert!(validate_soql("  select Name from Contact").is_ok());
+        assert!(validate_soql("DELETE FROM Account").is_err());
+        assert!(validate_soql("SELECT Id FROM Account; SELECT Id FROM Case").is_err());
+    }
+
+    #[test]
+    fn strips_nested_attributes() {
+        let mut value = json!([{
+            "attributes": { "type": "Contact" },
+            "Name": "Ada",
+            "Account": { "attributes": { "type": "Account" }, "Name": "Acme" }
+        }]);
+        strip_attributes(&mut value);
+        assert_eq!(
+            value,
+            json!([{ "Name": "Ada", "Account": { "Name": "Acme" } }])
+        );
+    }
+}
@@ -0,0 +1,152 @@
+// src/connectors/scihub/mirrors.rs
+//
+// Sci-Hub mirror bookkeeping. Mirrors come from configuration, a built-in
+// list, and (when those all fail) the links on the Sci-Hub Wikipedia
+// article. Each mirror's last health check is remembered for a while so
+// lookups try responsive mirrors first.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+pub(super) const DEFAULT_MIRRORS: &[&str] = &[
+    "https://sci-hub.se",
+    "https://sci-hub.st",
+    "https://sci-hub.ru",
+];
+/// Page listing the currently operating domains.
+pub(super) const DISCOVERY_URL: &str = "https://en.wikipedia.org/wiki/Sci-Hub";
+/// How long a health check result is trusted.
+pub(super) const HEALTH_TTL: Duration = Duration::from_secs(10 * 60);
+
+static RE_MIRROR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)https?://(?:www\.)?(sci-hub\.[a-z]{2,6})\b").unwrap());
+
+#[derive(Debug, Clone, Serialize)]
+pub(super) struct MirrorStatus {
+    pub url: String,
+    pub healthy: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip)]
+    pub checked_at: Option<Instant>,
+}
+
+impl MirrorStatus {
+    pub fn is_fresh(&self) -> bool {
+        self.checked_at.is_some_and(|t| t.elapsed() < HEALTH_TTL)
+    }
+}
+
+/// `https://host` for a mirror given as a URL or bare host, without any
+/// path or trailing slash.
+pub(super) fn normalize(mirror: &str) -> Option<String> {
+    let mirror = mirror.trim();
+    if mirror.is_empty() {
+        return None;
+    }
+    let with_scheme = if mirror.contains("://") {
+        mirror.to_string()
+    } else {
+        format!("https://{}", mirror)
+    };
+    let parsed = url::Url::parse(&with_scheme).ok()?;
+    let host = parsed.host_str()?;
+    Some(format!(
+        "{}://{}",
+        parsed.scheme(),
+        host.to_ascii_lowercase()
+    ))
+}
+
+/// Sci-Hub domains linked from a page, in order of first appearance.
+pub(super) fn discover(html: &str) -> Vec<String> {
+    let mut mirrors: Vec<String> = Vec::new();
+    for capture in RE_MIRROR.captures_iter(html) {
+        let mirror = format!("https://{}", capture[1].to_ascii_lowercase());
+        if !mirrors.contains(&mirror) {
+            mirrors.push(mirror);
+        }
+    }
+    mirrors
+}
+
+/// Mirrors in the order to try them: recently healthy ones by latency,
+/// then unchecked or stale ones as listed, then recently failing ones.
+pub(super) fn rank(mirrors: &[String], health: &HashMap<String, MirrorStatus>) -> Vec<String> {
+    let fresh = |m: &String| health.get(m).filter(|s| s.is_fresh());
+    let mut healthy: Vec<(u64, &String)> = mirrors
+        .iter()
+        .filter_map(|m| {
+            fresh(m)
+                .filter(|s| s.healthy)
+                .map(|s| (s.latency_ms.unwrap_or(u64::MAX), m))
+        })
+        .collect();
+    healthy.sort_by_key(|(latency, _)| *latency);
+
+    let unknown = mirrors.iter().filter(|&m| fresh(m).is_none());
+    let failing = mirrors
+        .iter()
+        .filter(|&m| fresh(m).is_some_and(|s| !s.healthy));
+
+    healthy
+        .into_iter()
+        .map(|(_, m)| m)
+        .chain(unknown)
+        .chain(failing)
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(url: &str, healthy: bool, latency_ms: u64) -> (String, MirrorStatus) {
+        (
+            url.to_string(),
+            MirrorStatus {
+                url: url.to_string(),
+                healthy,
+                latency_ms: Some(latency_ms),
+                error: None,
+                checked_at: Some(Instant::now()),
+            },
+        )
+    }
+
+    #[test]
+    fn normalizes_discovers_and_ranks_mirrors() {
+        assert_eq!(
+            normalize("Sci-Hub.ST/").as_deref(),
+            Some("https://sci-hub.st")
+        );
+        assert_eq!(
+            normalize("http://sci-hub.ru/10.1038/x").as_deref(),
+            Some("http://sci-hub.ru")
+        );
+        assert_eq!(normalize("  "), None);
+
+        let page = r#"<td class="infobox-data"><a href="https://sci-hub.se/">sci-hub.se</a>,
+<a href="https://www.Sci-Hub.ru">sci-hub.ru</a>, <a href="https://sci-hub.se/about">about</a></td>"#;
+        assert_eq!(
+            discover(page),
+            vec!["https://sci-hub.se", "https://sci-hub.ru"]
+        );
+
+        let mirrors: Vec<String> = ["a", "b", "c", "d"].iter().map(|m| m.to_string()).collect();
+        let health: HashMap<String, MirrorStatus> = [
+            status("a", false, 10),
+            status("c", true, 900),
+            status("d", true, 120),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(rank(&mirrors, &health), vec!["d", "c", "b", "a"]);
+    }
+}
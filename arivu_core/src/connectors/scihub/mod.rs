@@ -1,5 +1,6 @@
 use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
 use crate::error::ConnectorError;
+use crate::pdf::{PdfOptions, PdfSection};
 use crate::utils::structured_result_with_text;
 use crate::{auth::AuthDetails, Connector};
 use async_trait::async_trait;
@@ -9,7 +10,22 @@ use scraper::{Html, Selector};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+mod mirrors;
+
+use mirrors::MirrorStatus;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(20);
+const HEALTH_TIMEOUT: Duration = Duration::from_secs(8);
+/// Tries per mirror for network errors and server errors.
+const ATTEMPTS_PER_MIRROR: u32 = 2;
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+const DEFAULT_MAX_CHARS: usize = 50_000;
+const MAX_PDF_BYTES: usize = 50 * 1024 * 1024;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SciHubResult {
@@ -21,20 +37,78 @@ pub struct SciHubResult {
     pub year: Option<String>,
     pub success: bool,
     pub message: String,
+    /// Mirror that served the article page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mirror: Option<String>,
+    /// Failed tries before the result, as "mirror: reason"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attempts: Vec<String>,
+    /// Text extracted from the PDF when requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pages: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_truncated: Option<bool>,
+    /// The text split at headings, each with its starting page, when requested
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sections: Vec<PdfSection>,
+}
+
+impl SciHubResult {
+    fn failed(doi: &str, message: String, attempts: Vec<String>) -> Self {
+        SciHubResult {
+            doi: doi.to_string(),
+            pdf_url: None,
+            title: None,
+            authors: None,
+            journal: None,
+            year: None,
+            success: false,
+            message,
+            mirror: None,
+            attempts,
+            text: None,
+            pages: None,
+            text_truncated: None,
+            sections: Vec::new(),
+        }
+    }
+}
+
+/// What one mirror said about a DOI.
+enum ArticlePage {
+    Found { pdf_url: String, citation: String },
+    NotFound,
+}
+
+#[derive(Default)]
+struct MirrorState {
+    health: HashMap<String, MirrorStatus>,
+    /// Mirrors found on the discovery page; None until discovery has run
+    discovered: Option<Vec<String>>,
 }
 
 pub struct SciHubConnector {
     client: reqwest::Client,
     headers: HeaderMap,
     base_url: String,
+    /// Extra mirrors from configuration, tried after `base_url`
+    mirrors: Vec<String>,
+    state: Mutex<MirrorState>,
 }
 
 impl SciHubConnector {
     pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
         let mut connector = SciHubConnector {
-            client: reqwest::Client::new(),
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .map_err(ConnectorError::HttpRequest)?,
             headers: HeaderMap::new(),
-            base_url: "https://sci-hub.se".to_string(),
+            base_url: mirrors::DEFAULT_MIRRORS[0].to_string(),
+            mirrors: Vec::new(),
+            state: Mutex::new(MirrorState::default()),
         };
 
         // Set default user agent
@@ -49,94 +123,325 @@ impl SciHubConnector {
         Ok(connector)
     }
 
-    async fn search_scihub(&self, doi: &str) -> Result<SciHubResult, ConnectorError> {
-        // Construct the URL
-        let url = format!("{}/{}", self.base_url, doi);
+    /// Configured, built-in and (once run) discovered mirrors, deduplicated.
+    async fn known_mirrors(&self) -> Vec<String> {
+        let discovered = self.state.lock().await.discovered.clone();
+        let mut all: Vec<String> = Vec::new();
+        let configured = std::iter::once(self.base_url.as_str())
+            .chain(self.mirrors.iter().map(String::as_str))
+            .chain(mirrors::DEFAULT_MIRRORS.iter().copied())
+            .filter_map(mirrors::normalize);
+        for mirror in configured.chain(discovered.unwrap_or_default()) {
+            if !all.contains(&mirror) {
+                all.push(mirror);
+            }
+        }
+        all
+    }
 
-        // Make the HTTP request
-        let response = self
+    /// Read the discovery page once and remember the mirrors it links.
+    async fn discover_mirrors(&self) -> Vec<String> {
+        if let Some(found) = self.state.lock().await.discovered.clone() {
+            return found;
+        }
+        let found = match self
             .client
-            .get(&url)
+            .get(mirrors::DISCOVERY_URL)
             .headers(self.headers.clone())
             .send()
             .await
-            .map_err(|e| ConnectorError::Other(e.to_string()))?;
+        {
+            Ok(resp) if resp.status().is_success() => resp
+                .text()
+                .await
+                .map(|html| mirrors::discover(&html))
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+        self.state.lock().await.discovered = Some(found.clone());
+        found
+    }
 
-        // Check if the request was successful
-        if !response.status().is_success() {
-            return Ok(SciHubResult {
-                doi: doi.to_string(),
-                pdf_url: None,
-                title: None,
-                authors: None,
-                journal: None,
-                year: None,
-                success: false,
-                message: format!(
-                    "Failed to retrieve paper: HTTP status {}",
-                    response.status()
-                ),
-            });
+    async fn record_health(
+        &self,
+        mirror: &str,
+        healthy: bool,
+        latency: Option<Duration>,
+        error: Option<String>,
+    ) {
+        self.state.lock().await.health.insert(
+            mirror.to_string(),
+            MirrorStatus {
+                url: mirror.to_string(),
+                healthy,
+                latency_ms: latency.map(|d| d.as_millis() as u64),
+                error,
+                checked_at: Some(Instant::now()),
+            },
+        );
+    }
+
+    /// Load a mirror's front page and record whether it looks like Sci-Hub.
+    async fn check_mirror(&self, mirror: &str) -> MirrorStatus {
+        let started = Instant::now();
+        let outcome = match self
+            .client
+            .get(mirror)
+            .headers(self.headers.clone())
+            .timeout(HEALTH_TIMEOUT)
+            .send()
+            .await
+        {
+            Ok(resp) if resp.status().is_success() => match resp.text().await {
+                Ok(body) if body.to_lowercase().contains("sci-hub") => Ok(()),
+                Ok(_) => Err("page does not look like Sci-Hub".to_string()),
+                Err(e) => Err(e.to_string()),
+            },
+            Ok(resp) => Err(format!("HTTP status {}", resp.status())),
+            Err(e) => Err(e.to_string()),
+        };
+        let latency = started.elapsed();
+        let healthy = outcome.is_ok();
+        self.record_health(mirror, healthy, Some(latency), outcome.err())
+            .await;
+        self.state.lock().await.health[mirror].clone()
+    }
+
+    /// Check every known mirror concurrently (after discovery when asked).
+    async fn check_mirrors(&self, discover: bool) -> Vec<MirrorStatus> {
+        if discover {
+            self.discover_mirrors().await;
         }
+        let mirrors = self.known_mirrors().await;
+        let mut statuses =
+            futures::future::join_all(mirrors.iter().map(|m| self.check_mirror(m))).await;
+        statuses.sort_by_key(|s| (!s.healthy, s.latency_ms.unwrap_or(u64::MAX)));
+        statuses
+    }
 
-        // Get the HTML content
-        let content = response
-            .text()
+    /// Fetch `{mirror}/{doi}` and find the PDF link on it.
+    async fn fetch_article(&self, mirror: &str, doi: &str) -> Result<ArticlePage, String> {
+        let url = format!("{}/{}", mirror, doi);
+        let started = Instant::now();
+        let response = self
+            .client
+            .get(&url)
+            .headers(self.headers.clone())
+            .send()
             .await
-            .map_err(|e| ConnectorError::Other(e.to_string()))?;
+            .map_err(|e| e.to_string())?;
+        let status = response.status();
+        if status.as_u16() == 404 {
+            return Ok(ArticlePage::NotFound);
+        }
+        if !status.is_success() {
+            return Err(format!("HTTP status {}", status));
+        }
+        let content = response.text().await.map_err(|e| e.to_string())?;
+        self.record_health(mirror, true, Some(started.elapsed()), None)
+            .await;
 
-        // Parse the HTML document
         let html = Html::parse_document(&content);
+        let pdf_selector = Selector::parse(
+            "embed[type='application/pdf'], object[type='application/pdf'], iframe#pdf, embed#pdf, #pdf",
+        )
+        .map_err(|e| e.to_string())?;
+        let citation_selector = Selector::parse("div#citation").map_err(|e| e.to_string())?;
 
-        // Define CSS selectors for the elements we want to extract
-        let embed_selector = Selector::parse("embed[type='application/pdf']")
-            .map_err(|e| ConnectorError::Other(e.to_string()))?;
-        let citation_selector =
-            Selector::parse("div#citation").map_err(|e| ConnectorError::Other(e.to_string()))?;
-
-        // Extract PDF URL
         let pdf_url = html
-            .select(&embed_selector)
-            .next()
-            .and_then(|el| el.value().attr("src"))
+            .select(&pdf_selector)
+            .find_map(|el| el.value().attr("src").or_else(|| el.value().attr("data")))
+            .filter(|src| !src.trim().is_empty())
             .map(|src| {
                 if src.starts_with("//") {
                     format!("https:{}", src)
-                } else if src.starts_with("/") {
-                    format!("{}{}", self.base_url, src)
+                } else if src.starts_with('/') {
+                    format!("{}{}", mirror, src)
                 } else {
                     src.to_string()
                 }
             });
 
-        // Extract citation information
-        let citation_text = html
+        let citation = html
             .select(&citation_selector)
             .next()
             .map(|el| el.text().collect::<Vec<_>>().join(" ").trim().to_string())
             .unwrap_or_default();
 
-        // Parse citation text to extract title, authors, journal, and year
-        let (title, authors, journal, year) = self.parse_citation(&citation_text);
+        Ok(match pdf_url {
+            Some(pdf_url) => ArticlePage::Found { pdf_url, citation },
+            None => ArticlePage::NotFound,
+        })
+    }
 
-        // Determine success and message
-        let success = pdf_url.is_some();
-        let message = if success {
-            "Successfully found PDF".to_string()
-        } else {
+    /// Resolve a DOI to a PDF link, trying mirrors in health order with
+    /// retries, then any newly discovered mirrors.
+    async fn search_scihub(
+        &self,
+        doi: &str,
+        mirror: Option<&str>,
+    ) -> Result<SciHubResult, ConnectorError> {
+        let doi = doi.trim();
+        let forced = mirror.is_some();
+        let mut queue: VecDeque<String> = match mirror {
+            Some(m) => VecDeque::from(vec![mirrors::normalize(m).ok_or_else(|| {
+                ConnectorError::InvalidParams(format!("Invalid mirror '{}'", m))
+            })?]),
+            None => {
+                let known = self.known_mirrors().await;
+                let state = self.state.lock().await;
+                mirrors::rank(&known, &state.health).into()
+            }
+        };
+        let mut tried: Vec<String> = Vec::new();
+        let mut attempts: Vec<String> = Vec::new();
+        let mut not_found = 0usize;
+
+        loop {
+            let Some(mirror) = queue.pop_front() else {
+                // Everything known failed: look for mirrors we haven't tried, once
+                let already_discovered = self.state.lock().await.discovered.is_some();
+                if forced || already_discovered {
+                    break;
+                }
+                queue = self
+                    .discover_mirrors()
+                    .await
+                    .into_iter()
+                    .filter(|m| !tried.contains(m))
+                    .collect();
+                if queue.is_empty() {
+                    break;
+                }
+                continue;
+            };
+            tried.push(mirror.clone());
+
+            for attempt in 1..=ATTEMPTS_PER_MIRROR {
+                match self.fetch_article(&mirror, doi).await {
+                    Ok(ArticlePage::Found { pdf_url, citation }) => {
+                        let (title, authors, journal, year) = self.parse_citation(&citation);
+                        return Ok(SciHubResult {
+                            doi: doi.to_string(),
+                            pdf_url: Some(pdf_url),
+                            title,
+                            authors,
+                            journal,
+                            year,
+                            success: true,
+                            message: "Successfully found PDF".to_string(),
+                            mirror: Some(mirror),
+                            attempts,
+                            text: None,
+                            pages: None,
+                            text_truncated: None,
+                            sections: Vec::new(),
+                        });
+                    }
+                    Ok(ArticlePage::NotFound) => {
+                        not_found += 1;
+                        attempts.push(format!("{}: no PDF for this DOI", mirror));
+                        break;
+                    }
+                    Err(e) => {
+                        attempts.push(format!("{}: {}", mirror, e));
+                        if attempt == ATTEMPTS_PER_MIRROR {
+                            self.record_health(&mirror, false, None, Some(e)).await;
+                        } else {
+                            tokio::time::sleep(RETRY_DELAY * attempt).await;
+                        }
+                    }
+                }
+            }
+        }
+
+        let message = if not_found > 0 {
             "No PDF found for this DOI".to_string()
+        } else {
+            "No Sci-Hub mirror could be reached".to_string()
         };
+        Ok(SciHubResult::failed(doi, message, attempts))
+    }
 
-        Ok(SciHubResult {
-            doi: doi.to_string(),
-            pdf_url,
-            title,
-            authors,
-            journal,
-            year,
-            success,
-            message,
-        })
+    /// Download the PDF, retrying once, and extract its text.
+    async fn extract_pdf_text(
+        &self,
+        result: &mut SciHubResult,
+        max_chars: usize,
+        sections: bool,
+    ) -> Result<(), ConnectorError> {
+        let Some(pdf_url) = result.pdf_url.clone() else {
+            return Ok(());
+        };
+        let mut last_error = String::new();
+        let mut bytes = None;
+        for attempt in 1..=ATTEMPTS_PER_MIRROR {
+            let response = self
+                .client
+                .get(&pdf_url)
+                .headers(self.headers.clone())
+                .send()
+                .await;
+            match response {
+                Ok(resp) if resp.status().is_success() => {
+                    if resp
+                        .content_length()
+                        .is_some_and(|len| len as usize > MAX_PDF_BYTES)
+                    {
+                        last_error =
+                            format!("PDF is larger than {} MB", MAX_PDF_BYTES / (1024 * 1024));
+                        break;
+                    }
+                    match resp.bytes().await {
+                        Ok(b) => {
+                            bytes = Some(b);
+                            break;
+                        }
+                        Err(e) => last_error = e.to_string(),
+                    }
+                }
+                Ok(resp) => last_error = format!("HTTP status {}", resp.status()),
+                Err(e) => last_error = e.to_string(),
+            }
+            if attempt < ATTEMPTS_PER_MIRROR {
+                tokio::time::sleep(RETRY_DELAY).await;
+            }
+        }
+
+        let bytes = match bytes {
+            // Mirrors sometimes answer with a CAPTCHA page instead of the file
+            Some(b) if b.len() > MAX_PDF_BYTES => {
+                result.message = format!(
+                    "Found PDF link, but the PDF is larger than {} MB",
+                    MAX_PDF_BYTES / (1024 * 1024)
+                );
+                return Ok(());
+            }
+            Some(b) if crate::pdf::is_pdf(&b) => b,
+            Some(_) => {
+                result.message =
+                    "Found PDF link, but the download was not a PDF (possibly a CAPTCHA)"
+                        .to_string();
+                return Ok(());
+            }
+            None => {
+                result.message = format!("Found PDF link, but the download failed: {}", last_error);
+                return Ok(());
+            }
+        };
+
+        let options = PdfOptions {
+            max_chars,
+            sections,
+            ..Default::default()
+        };
+        let pdf = crate::pdf::extract_on_pool(bytes, options).await?;
+        result.pages = Some(pdf.page_count as usize);
+        result.text_truncated = Some(pdf.truncated);
+        result.text = Some(pdf.text());
+        result.sections = pdf.sections;
+        result.message = "Successfully found PDF and extracted text".to_string();
+        Ok(())
     }
 
     fn parse_citation(
@@ -212,6 +517,9 @@ impl Connector for SciHubConnector {
         if let Some(base_url) = details.get("base_url") {
             self.base_url = base_url.to_string();
         }
+        if let Some(list) = details.get("mirrors") {
+            self.mirrors = list.split(',').filter_map(mirrors::normalize).collect();
+        }
 
         Ok(())
     }
@@ -219,23 +527,35 @@ impl Connector for SciHubConnector {
     async fn test_auth(&self) -> Result<(), ConnectorError> {
         // Test a simple search to verify connectivity
         let _result = self
-            .search_scihub("10.1046/j.1365-2125.2003.02007.x")
+            .search_scihub("10.1046/j.1365-2125.2003.02007.x", None)
             .await?;
         Ok(())
     }
 
     fn config_schema(&self) -> ConnectorConfigSchema {
         ConnectorConfigSchema {
-            fields: vec![Field {
-                name: "base_url".to_string(),
-                label: "Sci-Hub Base URL".to_string(),
-                field_type: FieldType::Text,
-                required: false,
-                description: Some(
-                    "The base URL for Sci-Hub (default: https://sci-hub.se)".to_string(),
-                ),
-                options: None,
-            }],
+            fields: vec![
+                Field {
+                    name: "base_url".to_string(),
+                    label: "Sci-Hub Base URL".to_string(),
+                    field_type: FieldType::Text,
+                    required: false,
+                    description: Some(
+                        "The base URL for Sci-Hub (default: https://sci-hub.se)".to_string(),
+                    ),
+                    options: None,
+                },
+                Field {
+                    name: "mirrors".to_string(),
+                    label: "Additional Mirrors".to_string(),
+                    field_type: FieldType::Text,
+                    required: false,
+                    description: Some(
+                        "Comma-separated mirror URLs tried after the base URL".to_string(),
+                    ),
+                    options: None,
+                },
+            ],
         }
     }
 
@@ -298,7 +618,7 @@ impl Connector for SciHubConnector {
             }
             let doi = parts[3];
 
-            let result = self.search_scihub(doi).await?;
+            let result = self.search_scihub(doi, None).await?;
 
             if !result.success {
                 return Err(ConnectorError::ResourceNotFound);
@@ -316,12 +636,14 @@ impl Connector for SciHubConnector {
         _request: Option<PaginatedRequestParam>,
     ) -> Result<ListToolsResult, ConnectorError> {
         Ok(ListToolsResult {
-            tools: vec![Tool {
+            tools: vec![
+                Tool {
                 name: Cow::Borrowed("get"),
                 title: None,
                 description: Some(Cow::Borrowed(
                     "Fetch a paper by DOI via Sci-Hub (may be unlawful; ensure you have rights \
-to access the work). Example: doi=\"10.1038/nature12373\".",
+to access the work). Tries mirrors in health order with retries. Set extract_text=true to \
+return the PDF's text. Example: doi=\"10.1038/nature12373\".",
                 )),
                 input_schema: Arc::new(
                     json!({
@@ -330,6 +652,22 @@ to access the work). Example: doi=\"10.1038/nature12373\".",
                             "doi": {
                                 "type": "string",
                                 "description": "The DOI (Digital Object Identifier) of the paper"
+                            },
+                            "extract_text": {
+                                "type": "boolean",
+                                "description": "Download the PDF and return its text (default false)"
+                            },
+                            "max_chars": {
+                                "type": "integer",
+                                "description": "Maximum characters of extracted text (default 50000)"
+                            },
+                            "sections": {
+                                "type": "boolean",
+                                "description": "With extract_text, also split the text into sections (heading, level, starting page)"
+                            },
+                            "mirror": {
+                                "type": "string",
+                                "description": "Use only this mirror (e.g. https://sci-hub.st)"
                             }
                         },
                         "required": ["doi"]
@@ -341,6 +679,31 @@ to access the work). Example: doi=\"10.1038/nature12373\".",
                 output_schema: None,
                 annotations: None,
                 icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("check_mirrors"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Check which Sci-Hub mirrors respond, with latency. Set discover=true to \
+also look up currently listed mirror domains.",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "discover": {
+                                "type": "boolean",
+                                "description": "Look up mirror domains before checking (default false)"
+                            }
+                        }
+                    })
+                    .as_object()
+                    .expect("Schema object")
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
             }],
             next_cursor: None,
         })
@@ -358,10 +721,44 @@ to access the work). Example: doi=\"10.1038/nature12373\".",
                     ConnectorError::InvalidParams("Missing 'doi' parameter".to_string()),
                 )?;
 
-                let result = self.search_scihub(doi).await?;
+                let mirror = args.get("mirror").and_then(|v| v.as_str());
+                let extract = args
+                    .get("extract_text")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let max_chars = args
+                    .get("max_chars")
+                    .and_then(|v| v.as_u64())
+                    .map(|n| n as usize)
+                    .unwrap_or(DEFAULT_MAX_CHARS);
+
+                let sections = args
+                    .get("sections")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+
+                let mut result = self.search_scihub(doi, mirror).await?;
+                if extract && result.success {
+                    self.extract_pdf_text(&mut result, max_chars, sections)
+                        .await?;
+                }
                 let text = serde_json::to_string(&result)?;
                 Ok(structured_result_with_text(&result, Some(text))?)
             }
+            "check_mirrors" => {
+                let discover = args
+                    .get("discover")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                let mirrors = self.check_mirrors(discover).await;
+                let data = json!({
+                    "healthy": mirrors.iter().filter(|m| m.healthy).count(),
+                    "count": mirrors.len(),
+                    "mirrors": mirrors,
+                });
+                let text = serde_json::to_string(&data)?;
+                Ok(structured_result_with_text(&data, Some(text))?)
+            }
             _ => Err(ConnectorError::ToolNotFound),
         }
     }
@@ -1,13 +1,13 @@
 use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
 use crate::error::ConnectorError;
 use crate::utils::structured_result_with_text;
-use crate::utils::{collect_paginated, Page};
+use crate::utils::{collect_paginated, collect_paginated_with_cursor, Page};
 use crate::{auth::AuthDetails, Connector};
 use async_trait::async_trait;
 use reqwest::StatusCode;
 use rmcp::model::*;
 use serde::{Deserialize, Serialize};
-use serde_json::{json, Value};
+use serde_json::{json, Map, Value};
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -119,6 +119,24 @@ struct GetPaperEdgesArgs {
     limit: i32,
     #[serde(default)]
     offset: Option<i32>,
+    #[serde(default)]
+    fields: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RecommendArgs {
+    positive_paper_ids: Vec<String>,
+    #[serde(default)]
+    negative_paper_ids: Vec<String>,
+    #[serde(default = "default_page_size")]
+    limit: i32,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchPapersArgs {
+    paper_ids: Vec<String>,
+    #[serde(default)]
+    fields: Option<Vec<String>>,
 }
 
 fn default_page_size() -> i32 {
@@ -150,6 +168,26 @@ pub struct SemanticScholarConnector {
 const MAX_LIMIT: i32 = 5_000;
 const MAX_LIMIT_PER_REQUEST: i32 = 100;
 const MAX_REQUESTS: usize = 100;
+const MAX_BATCH_IDS: usize = 500;
+const MAX_RECOMMENDATIONS: i32 = 500;
+const DEFAULT_PAPER_FIELDS: &str = "paperId,title,abstract,url,venue,year,publicationDate,publicationTypes,authors,citationCount,influentialCitationCount,openAccessPdf,fieldsOfStudy,externalIds";
+
+/// Comma-joined `fields` parameter; names are camelCase and may be dotted
+/// (`authors.name`).
+fn fields_param(fields: Option<&[String]>) -> Result<String, ConnectorError> {
+    let Some(fields) = fields.filter(|f| !f.is_empty()) else {
+        return Ok(DEFAULT_PAPER_FIELDS.to_string());
+    };
+    for field in fields {
+        if field.is_empty() || !field.chars().all(|c| c.is_ascii_alphanumeric() || c == '.') {
+            return Err(ConnectorError::InvalidParams(format!(
+                "Invalid field '{}': expected names like 'title' or 'authors.name'",
+                field
+            )));
+        }
+    }
+    Ok(fields.join(","))
+}
 
 impl SemanticScholarConnector {
     pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
@@ -177,7 +215,8 @@ impl SemanticScholarConnector {
         );
 
         // Add fields parameter to get comprehensive paper details
-        url.push_str("&fields=paperId,title,abstract,url,venue,year,publicationDate,publicationTypes,authors,citationCount,influentialCitationCount,openAccessPdf,fieldsOfStudy,externalIds");
+        url.push_str("&fields=");
+        url.push_str(DEFAULT_PAPER_FIELDS);
 
         // Add sort parameter if not default
         if args.sort != "relevance" {
@@ -359,16 +398,61 @@ impl SemanticScholarConnector {
         Ok(recommendations)
     }
 
+    /// Papers like the positive seeds and unlike the negative ones.
+    async fn recommend(
+        &self,
+        positive: &[String],
+        negative: &[String],
+        limit: i32,
+    ) -> Result<RecommendationsResponse, ConnectorError> {
+        let url = format!(
+            "https://api.semanticscholar.org/recommendations/v1/papers?fields={}&limit={}",
+            DEFAULT_PAPER_FIELDS, limit
+        );
+
+        let body = json!({
+            "positivePaperIds": positive,
+            "negativePaperIds": negative,
+        });
+        let mut request = self.client.post(&url).json(&body);
+        if let Some(api_key) = &self.api_key {
+            request = request.header("x-api-key", api_key);
+        }
+
+        let response = request.send().await.map_err(ConnectorError::HttpRequest)?;
+        if response.status() == StatusCode::BAD_REQUEST
+            || response.status() == StatusCode::NOT_FOUND
+        {
+            let body = response.text().await.unwrap_or_default();
+            return Err(ConnectorError::InvalidParams(format!(
+                "Semantic Scholar rejected the seed papers: {}",
+                body
+            )));
+        }
+        if !response.status().is_success() {
+            return Err(ConnectorError::Other(format!(
+                "Semantic Scholar API returned error status: {}",
+                response.status()
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| ConnectorError::Other(format!("Failed to parse JSON response: {}", e)))
+    }
+
     async fn get_paper_edges(
         &self,
         paper_id: &str,
         edge: &str,
+        fields: &str,
         limit: i32,
         offset: i32,
     ) -> Result<Value, ConnectorError> {
         let url = format!(
-            "https://api.semanticscholar.org/graph/v1/paper/{}/{}?fields=paperId,title,abstract,url,venue,year,publicationDate,publicationTypes,authors,citationCount,influentialCitationCount,openAccessPdf,fieldsOfStudy,externalIds&limit={}&offset={}",
-            paper_id, edge, limit, offset
+            "https://api.semanticscholar.org/graph/v1/paper/{}/{}?fields={}&limit={}&offset={}",
+            paper_id, edge, fields, limit, offset
         );
 
         let mut request = self.client.get(&url);
@@ -393,25 +477,29 @@ impl SemanticScholarConnector {
             .map_err(|e| ConnectorError::Other(format!("Failed to parse JSON response: {}", e)))
     }
 
+    /// Pages through citations/references from `offset`; the result has the
+    /// API's own `{offset, next, data}` shape, with `next` absent at the end.
     async fn get_paper_edges_all(
         &self,
         paper_id: &str,
         edge: &str,
+        fields: &str,
         total_limit: i32,
+        offset: i32,
     ) -> Result<Value, ConnectorError> {
         let desired = total_limit.clamp(1, MAX_LIMIT) as usize;
 
-        let items = collect_paginated(
+        let collected = collect_paginated_with_cursor(
             desired,
             MAX_REQUESTS,
-            Some(0i32),
+            Some(offset),
             |cursor, remaining| async move {
                 let offset = cursor.unwrap_or(0);
                 let remaining_i32 = i32::try_from(remaining).unwrap_or(MAX_LIMIT_PER_REQUEST);
                 let page_limit = remaining_i32.clamp(1, MAX_LIMIT_PER_REQUEST);
 
                 let page = self
-                    .get_paper_edges(paper_id, edge, page_limit, offset)
+                    .get_paper_edges(paper_id, edge, fields, page_limit, offset)
                     .await?;
                 let data = page
                     .get("data")
@@ -421,7 +509,9 @@ impl SemanticScholarConnector {
                 let next_cursor = if data.is_empty() {
                     None
                 } else {
-                    Some(offset.saturating_add(page_limit))
+                    page.get("next")
+                        .and_then(|n| n.as_i64())
+                        .and_then(|n| i32::try_from(n).ok())
                 };
 
                 Ok::<_, ConnectorError>(Page {
@@ -439,7 +529,48 @@ impl SemanticScholarConnector {
         )
         .await?;
 
-        Ok(json!({ "data": items }))
+        let mut payload = json!({ "offset": offset, "data": collected.items });
+        if let Some(next) = collected.next_cursor {
+            payload["next"] = json!(next);
+        }
+        Ok(payload)
+    }
+
+    /// Up to 500 papers in one POST; unknown IDs come back as `null`.
+    async fn batch_papers(
+        &self,
+        paper_ids: &[String],
+        fields: &str,
+    ) -> Result<Vec<Value>, ConnectorError> {
+        let url = format!(
+            "https://api.semanticscholar.org/graph/v1/paper/batch?fields={}",
+            fields
+        );
+
+        let mut request = self.client.post(&url).json(&json!({ "ids": paper_ids }));
+        if let Some(api_key) = &self.api_key {
+            request = request.header("x-api-key", api_key);
+        }
+
+        let response = request.send().await.map_err(ConnectorError::HttpRequest)?;
+        if response.status() == StatusCode::BAD_REQUEST {
+            let body = response.text().await.unwrap_or_default();
+            return Err(ConnectorError::InvalidParams(format!(
+                "Semantic Scholar rejected the batch request: {}",
+                body
+            )));
+        }
+        if !response.status().is_success() {
+            return Err(ConnectorError::Other(format!(
+                "Semantic Scholar API returned error status: {}",
+                response.status()
+            )));
+        }
+
+        response
+            .json::<Vec<Value>>()
+            .await
+            .map_err(|e| ConnectorError::Other(format!("Failed to parse JSON response: {}", e)))
     }
 
     fn format_paper(&self, paper: &Paper) -> HashMap<String, Value> {
@@ -515,6 +646,26 @@ impl SemanticScholarConnector {
     }
 }
 
+fn edges_schema(paper_key: &str) -> Map<String, Value> {
+    json!({
+        "type": "object",
+        "properties": {
+            "paper_id": { "type": "string", "description": "Paper ID (S2 ID or prefixed, e.g. DOI:..., ARXIV:...)" },
+            "limit": { "type": "integer", "description": "Max results (default: 10)", "minimum": 1, "maximum": 5000 },
+            "offset": { "type": "integer", "description": "Offset to start from; use `next` from a previous call (default: 0)" },
+            "fields": {
+                "type": "array",
+                "items": { "type": "string" },
+                "description": format!("Fields for each {} (default: id, title, abstract, venue, year, authors, citation counts, open-access PDF, external IDs). Edge fields 'contexts', 'intents' and 'isInfluential' are also accepted.", paper_key)
+            }
+        },
+        "required": ["paper_id"]
+    })
+    .as_object()
+    .expect("Schema object")
+    .clone()
+}
+
 #[async_trait]
 impl Connector for SemanticScholarConnector {
     fn name(&self) -> &'static str {
@@ -596,7 +747,7 @@ impl Connector for SemanticScholarConnector {
                 website_url: None,
             },
             instructions: Some(
-                "Use `search_papers` to find paper IDs, then `get_paper_details`/`get_citations`/`get_references`. Optional: set an API key via `arivu config set semantic-scholar --value <key>` for higher rate limits."
+                "Use `search_papers` to find paper IDs, then `get_paper_details`/`get_citations`/`get_references`; `recommend` expands a set of seed papers. Optional: set an API key via `arivu config set semantic-scholar --value <key>` for higher rate limits."
                     .to_string(),
             ),
         })
@@ -745,38 +896,87 @@ impl Connector for SemanticScholarConnector {
                 icons: None,
             },
             Tool {
-                name: Cow::Borrowed("get_citations"),
+                name: Cow::Borrowed("recommend"),
                 title: None,
                 description: Some(Cow::Borrowed(
-                    "Citations for a paper (papers that cite this paper).",
+                    "Recommended papers from seed papers: similar to positive_paper_ids and \
+dissimilar to negative_paper_ids. Use to expand a reading list.",
                 )),
                 input_schema: Arc::new(json!({
                     "type": "object",
                     "properties": {
-                        "paper_id": { "type": "string", "description": "Paper ID" },
-                        "limit": { "type": "integer", "description": "Max results (default: 10)" },
-                        "offset": { "type": "integer", "description": "Offset for pagination (default: 0)" }
+                        "positive_paper_ids": {
+                            "type": "array",
+                            "items": { "type": "string" },
+                            "description": "Seed papers to find more like (S2 IDs or prefixed, e.g. ARXIV:1706.03762)",
+                            "minItems": 1
+                        },
+                        "negative_paper_ids": {
+                            "type": "array",
+                            "items": { "type": "string" },
+                            "description": "Papers to steer away from (optional)"
+                        },
+                        "limit": {
+                            "type": "integer",
+                            "description": "Maximum number of recommendations (default: 10)",
+                            "minimum": 1,
+                            "maximum": 500
+                        }
                     },
-                    "required": ["paper_id"]
+                    "required": ["positive_paper_ids"]
                 }).as_object().expect("Schema object").clone()),
                 output_schema: None,
                 annotations: None,
                 icons: None,
             },
+            Tool {
+                name: Cow::Borrowed("get_citations"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Citations for a paper (papers that cite this paper). Pages internally up to \
+`limit`; pass the returned `next` as `offset` to continue.",
+                )),
+                input_schema: Arc::new(edges_schema("citingPaper")),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
             Tool {
                 name: Cow::Borrowed("get_references"),
                 title: None,
                 description: Some(Cow::Borrowed(
-                    "References for a paper (papers this paper cites).",
+                    "References for a paper (papers this paper cites). Pages internally up to \
+`limit`; pass the returned `next` as `offset` to continue.",
+                )),
+                input_schema: Arc::new(edges_schema("citedPaper")),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("batch_papers"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Details for up to 500 papers in one request, e.g. to expand IDs collected \
+from get_citations/get_references.",
                 )),
                 input_schema: Arc::new(json!({
                     "type": "object",
                     "properties": {
-                        "paper_id": { "type": "string", "description": "Paper ID" },
-                        "limit": { "type": "integer", "description": "Max results (default: 10)" },
-                        "offset": { "type": "integer", "description": "Offset for pagination (default: 0)" }
+                        "paper_ids": {
+                            "type": "array",
+                            "items": { "type": "string" },
+                            "description": "Paper IDs: S2 IDs or prefixed IDs such as DOI:10.18653/v1/N18-3011, ARXIV:2106.15928, PMID:19872477, CorpusId:215416146",
+                            "minItems": 1,
+                            "maxItems": 500
+                        },
+                        "fields": {
+                            "type": "array",
+                            "items": { "type": "string" },
+                            "description": "Paper fields to return (default: id, title, abstract, venue, year, authors, citation counts, open-access PDF, external IDs). Example: [\"title\", \"year\", \"authors.name\"]"
+                        }
                     },
-                    "required": ["paper_id"]
+                    "required": ["paper_ids"]
                 }).as_object().expect("Schema object").clone()),
                 output_schema: None,
                 annotations: None,
@@ -871,30 +1071,91 @@ impl Connector for SemanticScholarConnector {
                     Err(err) => Err(err),
                 }
             }
+            "recommend" => {
+                let args: RecommendArgs = serde_json::from_value(json!(args)).map_err(|e| {
+                    ConnectorError::InvalidParams(format!("Invalid arguments: {}", e))
+                })?;
+                if args.positive_paper_ids.is_empty() {
+                    return Err(ConnectorError::InvalidParams(
+                        "positive_paper_ids must contain at least one paper ID".to_string(),
+                    ));
+                }
+                let blank = |ids: &[String]| ids.iter().any(|id| id.trim().is_empty());
+                if blank(&args.positive_paper_ids) || blank(&args.negative_paper_ids) {
+                    return Err(ConnectorError::InvalidParams(
+                        "Paper IDs must not be blank".to_string(),
+                    ));
+                }
+                let limit = args.limit.clamp(1, MAX_RECOMMENDATIONS);
+
+                let recommendations = self
+                    .recommend(&args.positive_paper_ids, &args.negative_paper_ids, limit)
+                    .await?;
+                let papers: Vec<HashMap<String, Value>> = recommendations
+                    .recommended_papers
+                    .iter()
+                    .map(|paper| self.format_paper(paper))
+                    .collect();
+
+                let text = serde_json::to_string(&papers)?;
+                Ok(structured_result_with_text(&papers, Some(text))?)
+            }
             "get_citations" => {
                 let args: GetPaperEdgesArgs = serde_json::from_value(json!(args)).map_err(|e| {
                     ConnectorError::InvalidParams(format!("Invalid arguments: {}", e))
                 })?;
-                let payload = if let Some(offset) = args.offset {
-                    self.get_paper_edges(&args.paper_id, "citations", args.limit, offset)
-                        .await?
-                } else {
-                    self.get_paper_edges_all(&args.paper_id, "citations", args.limit)
-                        .await?
-                };
+                let fields = fields_param(args.fields.as_deref())?;
+                let payload = self
+                    .get_paper_edges_all(
+                        &args.paper_id,
+                        "citations",
+                        &fields,
+                        args.limit,
+                        args.offset.unwrap_or(0).max(0),
+                    )
+                    .await?;
                 Ok(structured_result_with_text(&payload, None)?)
             }
             "get_references" => {
                 let args: GetPaperEdgesArgs = serde_json::from_value(json!(args)).map_err(|e| {
                     ConnectorError::InvalidParams(format!("Invalid arguments: {}", e))
                 })?;
-                let payload = if let Some(offset) = args.offset {
-                    self.get_paper_edges(&args.paper_id, "references", args.limit, offset)
-                        .await?
-                } else {
-                    self.get_paper_edges_all(&args.paper_id, "references", args.limit)
-                        .await?
-                };
+                let fields = fields_param(args.fields.as_deref())?;
+                let payload = self
+                    .get_paper_edges_all(
+                        &args.paper_id,
+                        "references",
+                        &fields,
+                        args.limit,
+                        args.offset.unwrap_or(0).max(0),
+                    )
+                    .await?;
+                Ok(structured_result_with_text(&payload, None)?)
+            }
+            "batch_papers" => {
+                let args: BatchPapersArgs = serde_json::from_value(json!(args)).map_err(|e| {
+                    ConnectorError::InvalidParams(format!("Invalid arguments: {}", e))
+                })?;
+                if args.paper_ids.is_empty() || args.paper_ids.len() > MAX_BATCH_IDS {
+                    return Err(ConnectorError::InvalidParams(format!(
+                        "paper_ids must contain between 1 and {} IDs",
+                        MAX_BATCH_IDS
+                    )));
+                }
+                let fields = fields_param(args.fields.as_deref())?;
+                let results = self.batch_papers(&args.paper_ids, &fields).await?;
+
+                // Results are positional, so a null marks an unknown ID
+                let mut papers = Vec::new();
+                let mut missing = Vec::new();
+                for (id, paper) in args.paper_ids.iter().zip(results) {
+                    if paper.is_null() {
+                        missing.push(id.clone());
+                    } else {
+                        papers.push(paper);
+                    }
+                }
+                let payload = json!({ "papers": papers, "missing": missing });
                 Ok(structured_result_with_text(&payload, None)?)
             }
             _ => Err(ConnectorError::ToolNotFound),
@@ -950,6 +1211,16 @@ impl Connector for SemanticScholarConnector {
 mod tests {
     use super::*;
 
+    #[test]
+    fn validates_fields_param() {
+        assert_eq!(fields_param(None).unwrap(), DEFAULT_PAPER_FIELDS);
+        assert_eq!(
+            fields_param(Some(&["title".to_string(), "authors.name".to_string()][..])).unwrap(),
+            "title,authors.name"
+        );
+        assert!(fields_param(Some(&["title&limit=1".to_string()][..])).is_err());
+    }
+
     #[test]
     fn builds_search_url_with_filters() {
         let args = SearchPapersArgs {
@@ -969,4 +1240,59 @@ mod tests {
         assert!(url.contains("&year=2020-2023"));
         assert!(url.contains("&fieldsOfStudy=Computer%20Science"));
     }
+
+    #[test]
+    fn parses_recommendations() {
+        let response: RecommendationsResponse = serde_json::from_value(json!({
+            "recommendedPapers": [{
+                "paperId": "204e3073870fae3d05bcbc2f6a8e263d9b72e776",
+                "externalIds": { "ArXiv": "1706.03762", "CorpusId": 13756489 },
+                "title": "Attention is All you Need",
+                "year": 2017,
+                "citationCount": 100000,
+                "authors": [{ "authorId": "40348417", "name": "Ashish Vaswani" }],
+                "fieldsOfStudy": ["Computer Science"]
+            }]
+        }))
+        .unwrap();
+        assert_eq!(response.recommended_papers.len(), 1);
+
+        let connector = SemanticScholarConnector {
+            client: reqwest::Client::new(),
+            api_key: None,
+        };
+        let paper = connector.format_paper(&response.recommended_papers[0]);
+        assert_eq!(paper["title"], "Attention is All you Need");
+        assert_eq!(paper["arxiv_id"], "1706.03762");
+        assert_eq!(paper["authors"], json!(["Ashish Vaswani"]));
+        assert_eq!(paper["year"], 2017);
+    }
+
+    #[tokio::test]
+    async fn recommend_rejects_empty_and_invalid_paper_ids() {
+        let connector = SemanticScholarConnector::new(AuthDetails::new())
+            .await
+            .unwrap();
+        let call = |args: Value| CallToolRequestParam {
+            name: Cow::Borrowed("recommend"),
+            arguments: args.as_object().cloned(),
+        };
+
+        for args in [
+            json!({}),
+            json!({ "positive_paper_ids": [] }),
+            json!({ "positive_paper_ids": "ARXIV:1706.03762" }),
+            json!({ "positive_paper_ids": [42] }),
+            json!({ "positive_paper_ids": ["  "] }),
+            json!({ "positive_paper_ids": ["ARXIV:1706.03762"], "negative_paper_ids": [""] }),
+        ] {
+            let err = connector.call_tool(call(args.clone())).await.unwrap_err();
+            assert!(
+                matches!(err, ConnectorError::InvalidParams(_)),
+                "{} -> {:?}",
+                args,
+                err
+            );
+        }
+    }
 }
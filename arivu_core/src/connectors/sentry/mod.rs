@@ -0,0 +1,881 @@
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use reqwest::{header::LINK, Client, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+const DEFAULT_BASE_URL: &str = "https://sentry.io";
+const MAX_FRAMES: usize = 40;
+
+#[derive(Debug, Deserialize)]
+struct SearchIssuesArgs {
+    #[serde(default)]
+    query: Option<String>,
+    #[serde(default)]
+    project: Option<String>,
+    #[serde(default)]
+    environment: Option<String>,
+    #[serde(default)]
+    stats_period: Option<String>,
+    #[serde(default)]
+    sort: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+    #[serde(default)]
+    cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetIssueArgs {
+    issue: String,
+    #[serde(default)]
+    event: Option<String>,
+    #[serde(default)]
+    in_app_only: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListReleasesArgs {
+    #[serde(default)]
+    project: Option<String>,
+    #[serde(default)]
+    query: Option<String>,
+    #[serde(default)]
+    stats_period: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetReleaseArgs {
+    version: String,
+    #[serde(default)]
+    project: Option<String>,
+    #[serde(default)]
+    stats_period: Option<String>,
+}
+
+/// How an issue was referenced: numeric group id, short id (`WEB-1A2`), or a URL.
+#[derive(Debug, PartialEq)]
+enum IssueRef {
+    Id(String),
+    ShortId(String),
+}
+
+fn parse_issue_ref(input: &str) -> Option<IssueRef> {
+    let input = input.trim();
+    // https://acme.sentry.io/issues/4501234567/ or .../organizations/acme/issues/4501234567/
+    if input.contains("/issues/") {
+        let id = input
+            .split("/issues/")
+            .nth(1)?
+            .split(['/', '?', '#'])
+            .next()?;
+        return parse_issue_ref(id);
+    }
+    if !input.is_empty() && input.chars().all(|c| c.is_ascii_digit()) {
+        return Some(IssueRef::Id(input.to_string()));
+    }
+    let (project, suffix) = input.rsplit_once('-')?;
+    if project.is_empty() || suffix.is_empty() || !suffix.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        return None;
+    }
+    Some(IssueRef::ShortId(input.to_ascii_uppercase()))
+}
+
+/// Cursor for the next page from Sentry's `Link` header, if that page has results.
+fn next_cursor(link: &str) -> Option<String> {
+    link.split(',')
+        .find(|part| part.contains("rel=\"next\"") && part.contains("results=\"true\""))?
+        .split(';')
+        .find_map(|attr| attr.trim().strip_prefix("cursor=\""))
+        .and_then(|c| c.strip_suffix('"'))
+        .map(str::to_string)
+}
+
+fn format_issue(issue: &Value) -> Value {
+    json!({
+        "id": issue["id"],
+        "short_id": issue["shortId"],
+        "title": issue["title"],
+        "culprit": issue["culprit"],
+        "level": issue["level"],
+        "status": issue["status"],
+        "substatus": issue["substatus"],
+        "project": issue["project"]["slug"],
+        "count": issue["count"],
+        "user_count": issue["userCount"],
+        "first_seen": issue["firstSeen"],
+        "last_seen": issue["lastSeen"],
+        "assigned_to": issue["assignedTo"]["name"],
+        "is_unhandled": issue["isUnhandled"],
+        "permalink": issue["permalink"],
+    })
+}
+
+/// One exception in a chain, innermost frame first with source context for in-app frames.
+fn format_exception(exception: &Value, in_app_only: bool) -> Value {
+    let frames: Vec<&Value> = exception["stacktrace"]["frames"]
+        .as_array()
+        .map(|frames| frames.iter().rev().collect())
+        .unwrap_or_default();
+    let total = frames.len();
+    let frames: Vec<Value> = frames
+        .into_iter()
+        .filter(|f| !in_app_only || f["inApp"].as_bool().unwrap_or(false))
+        .take(MAX_FRAMES)
+        .map(|f| {
+            let mut frame = json!({
+                "function": f["function"],
+                "file": f["filename"].as_str().or_else(|| f["absPath"].as_str()),
+                "module": f["module"],
+                "line": f["lineNo"],
+                "column": f["colNo"],
+                "in_app": f["inApp"],
+            });
+            if f["inApp"].as_bool().unwrap_or(false) {
+                let context: Vec<String> = f["context"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|pair| {
+                        Some(format!("{:>5} {}", pair[0].as_i64()?, pair[1].as_str()?))
+                    })
+                    .collect();
+                if !context.is_empty() {
+                    frame["context"] = json!(context.join("\n"));
+                }
+            }
+            frame
+        })
+        .collect();
+    json!({
+        "type": exception["type"],
+        "value": exception["value"],
+        "mechanism": exception["mechanism"]["type"],
+        "handled": exception["mechanism"]["handled"],
+        "frames_total": total,
+        "frames": frames,
+    })
+}
+
+/// The useful parts of an event: exceptions (stack traces), message, tags, contexts
+/// and the last breadcrumbs.
+fn format_event(event: &Value, in_app_only: bool) -> Value {
+    let entries = event["entries"].as_array().cloned().unwrap_or_default();
+    let entry = |kind: &str| entries.iter().find(|e| e["type"] == kind);
+
+    // Sentry lists chained exceptions outermost last; show the raised one first
+    let exceptions: Vec<Value> = entry("exception")
+        .and_then(|e| e["data"]["values"].as_array())
+        .map(|values| {
+            values
+                .iter()
+                .rev()
+                .map(|x| format_exception(x, in_app_only))
+                .collect()
+        })
+        .unwrap_or_default();
+    let breadcrumbs: Vec<Value> = entry("breadcrumbs")
+        .and_then(|e| e["data"]["values"].as_array())
+        .map(|values| {
+            let skip = values.len().saturating_sub(10);
+            values
+                .iter()
+                .skip(skip)
+                .map(|b| {
+                    json!({
+                        "timestamp": b["timestamp"],
+                        "category": b["category"],
+                        "level": b["level"],
+                        "message": b["message"],
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let tags: Map<String, Value> = event["tags"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|t| Some((t["key"].as_str()?.to_string(), t["value"].clone())))
+        .collect();
+
+    json!({
+        "event_id": event["eventID"],
+        "date": event["dateCreated"],
+        "message": event["message"],
+        "platform": event["platform"],
+        "release": event["release"]["version"],
+        "environment": tags.get("environment"),
+        "user": event["user"],
+        "request": entry("request").map(|r| json!({
+            "method": r["data"]["method"],
+            "url": r["data"]["url"],
+        })),
+        "exceptions": exceptions,
+        "tags": tags,
+        "contexts": event["contexts"],
+        "breadcrumbs": breadcrumbs,
+    })
+}
+
+fn format_release(release: &Value) -> Value {
+    let projects: Vec<Value> = release["projects"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|p| {
+            let health = &p["healthData"];
+            json!({
+                "project": p["slug"],
+                "new_issues": p["newGroups"],
+                "crash_free_sessions": health["crashFreeSessions"],
+                "crash_free_users": health["crashFreeUsers"],
+                "sessions": health["totalSessions"],
+                "sessions_crashed": health["sessionsCrashed"],
+                "sessions_errored": health["sessionsErrored"],
+                "adoption": health["adoption"],
+                "adoption_stage": p["adoptionStages"],
+            })
+        })
+        .collect();
+    json!({
+        "version": release["version"],
+        "short_version": release["shortVersion"],
+        "date_created": release["dateCreated"],
+        "date_released": release["dateReleased"],
+        "first_event": release["firstEvent"],
+        "last_event": release["lastEvent"],
+        "new_issues": release["newGroups"],
+        "commit_count": release["commitCount"],
+        "deploy_count": release["deployCount"],
+        "last_deploy": release["lastDeploy"]["environment"],
+        "projects": projects,
+    })
+}
+
+pub struct SentryConnector {
+    client: Client,
+    token: Option<String>,
+    organization: Option<String>,
+    base_url: String,
+}
+
+impl SentryConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+        let token = auth
+            .get("api_key")
+            .or_else(|| auth.get("token"))
+            .cloned()
+            .or_else(|| std::env::var("SENTRY_AUTH_TOKEN").ok())
+            .filter(|t| !t.trim().is_empty());
+        let organization = auth
+            .get("organization")
+            .cloned()
+            .or_else(|| std::env::var("SENTRY_ORG").ok())
+            .filter(|o| !o.trim().is_empty());
+        // Self-hosted installs and the EU region (de.sentry.io) use their own host
+        let base_url = auth
+            .get("base_url")
+            .cloned()
+            .or_else(|| std::env::var("SENTRY_URL").ok())
+            .filter(|u| !u.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+            .trim_end_matches('/')
+            .to_string();
+
+        Ok(Self {
+            client,
+            token,
+            organization,
+            base_url,
+        })
+    }
+
+    fn org(&self) -> Result<&str, ConnectorError> {
+        self.organization.as_deref().ok_or_else(|| {
+            ConnectorError::InvalidParams(
+                "Sentry organization not configured: set 'organization' or SENTRY_ORG".to_string(),
+            )
+        })
+    }
+
+    /// GET `/api/0/{path}`, returning the body and the next-page cursor.
+    async fn get(
+        &self,
+        path: &str,
+        params: &[(String, String)],
+    ) -> Result<(Value, Option<String>), ConnectorError> {
+        let token = self.token.as_deref().ok_or_else(|| {
+            ConnectorError::Authentication(
+                "Sentry auth token not configured: arivu setup sentry (or SENTRY_AUTH_TOKEN)"
+                    .to_string(),
+            )
+        })?;
+
+        let response = self
+            .client
+            .get(format!("{}/api/0/{}", self.base_url, path))
+            .query(params)
+            .bearer_auth(token)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+
+        let status = response.status();
+        if status.is_success() {
+            let cursor = response
+                .headers()
+                .get(LINK)
+                .and_then(|v| v.to_str().ok())
+                .and_then(next_cursor);
+            let body = response.json().await.map_err(ConnectorError::HttpRequest)?;
+            return Ok((body, cursor));
+        }
+        let body: Value = response.json().await.unwrap_or(Value::Null);
+        let message = body["detail"]
+            .as_str()
+            .map(str::to_string)
+            .unwrap_or_else(|| body.to_string());
+        Err(match status {
+            StatusCode::NOT_FOUND => ConnectorError::ResourceNotFound,
+            StatusCode::UNAUTHORIZED => {
+                ConnectorError::Authentication(format!("Sentry rejected the token: {}", message))
+            }
+            StatusCode::FORBIDDEN => ConnectorError::Authentication(format!(
+                "Sentry token lacks the required scope (org:read, project:read, event:read): {}",
+                message
+            )),
+            StatusCode::BAD_REQUEST => {
+                ConnectorError::InvalidParams(format!("Sentry: {}", message))
+            }
+            StatusCode::TOO_MANY_REQUESTS => {
+                ConnectorError::Other("Sentry rate limit exceeded; retry shortly".to_string())
+            }
+            s => ConnectorError::Other(format!(
+                "Sentry API returned error status: {} {}",
+                s, message
+            )),
+        })
+    }
+
+    /// Numeric project id for a slug; release endpoints filter by id only.
+    async fn project_id(&self, slug: &str) -> Result<String, ConnectorError> {
+        let (project, _) = self
+            .get(
+                &format!(
+                    "projects/{}/{}/",
+                    self.org()?,
+                    urlencoding::encode(slug.trim())
+                ),
+                &[],
+            )
+            .await?;
+        project["id"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or(ConnectorError::ResourceNotFound)
+    }
+
+    async fn resolve_issue(&self, input: &str) -> Result<String, ConnectorError> {
+        match parse_issue_ref(input) {
+            Some(IssueRef::Id(id)) => Ok(id),
+            Some(IssueRef::ShortId(short_id)) => {
+                let (resolved, _) = self
+                    .get(
+                        &format!("organizations/{}/shortids/{}/", self.org()?, short_id),
+                        &[],
+                    )
+                    .await?;
+                resolved["groupId"]
+                    .as_str()
+                    .map(str::to_string)
+                    .ok_or(ConnectorError::ResourceNotFound)
+            }
+            None => Err(ConnectorError::InvalidParams(format!(
+                "'{}' is not an issue id, short id (PROJ-1A2) or issue URL",
+                input
+            ))),
+        }
+    }
+}
+
+#[async_trait]
+impl Connector for SentryConnector {
+    fn name(&self) -> &'static str {
+        "sentry"
+    }
+
+    fn description(&self) -> &'static str {
+        "Sentry error tracking: issue search, stack traces from the latest event, and release health."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        let mut auth = AuthDetails::new();
+        if let Some(token) = &self.token {
+            auth.insert("api_key".to_string(), token.clone());
+        }
+        if let Some(organization) = &self.organization {
+            auth.insert("organization".to_string(), organization.clone());
+        }
+        if self.base_url != DEFAULT_BASE_URL {
+            auth.insert("base_url".to_string(), self.base_url.clone());
+        }
+        Ok(auth)
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        *self = Self::new(details).await?;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.get(&format!("organizations/{}/", self.org()?), &[])
+            .await
+            .map(|_| ())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema {
+            fields: vec![
+                Field {
+                    name: "api_key".to_string(),
+                    label: "Auth Token".to_string(),
+                    field_type: FieldType::Secret,
+                    required: true,
+                    description: Some(
+                        "Organization or user auth token with org:read, project:read and \
+event:read, or set SENTRY_AUTH_TOKEN."
+                            .to_string(),
+                    ),
+                    options: None,
+                },
+                Field {
+                    name: "organization".to_string(),
+                    label: "Organization Slug".to_string(),
+                    field_type: FieldType::Text,
+                    required: true,
+                    description: Some("Organization slug, or set SENTRY_ORG.".to_string()),
+                    options: None,
+                },
+                Field {
+                    name: "base_url".to_string(),
+                    label: "Sentry URL".to_string(),
+                    field_type: FieldType::Text,
+                    required: false,
+                    description: Some(
+                        "Self-hosted or regional URL (default: https://sentry.io).".to_string(),
+                    ),
+                    options: None,
+                },
+            ],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Triage flow: `search_issues` (Sentry search syntax, e.g. `is:unresolved \
+level:error`) to find issues, then `get_issue` for the latest event's stack trace, tags and \
+breadcrumbs. Use `list_releases`/`get_release` for crash-free rates and adoption per release. \
+Issues accept numeric ids, short ids (WEB-1A2) or issue URLs."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let project = json!({ "type": "string", "description": "Project slug" });
+        let stats_period = json!({
+            "type": "string",
+            "description": "Time window such as 24h, 7d or 14d (default: 14d for issues, 24h for release health)"
+        });
+
+        let tools = vec![
+            tool(
+                "list_projects",
+                "List projects in the organization with their platform and slug.",
+                json!({ "type": "object", "properties": {} }),
+            ),
+            tool(
+                "search_issues",
+                "Search issues with Sentry search syntax. Example: query=\"is:unresolved level:error release:1.4.2\" project=\"web\" sort=\"freq\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Sentry search query (default: is:unresolved)" },
+                        "project": project.clone(),
+                        "environment": { "type": "string", "description": "Environment, e.g. production" },
+                        "stats_period": stats_period.clone(),
+                        "sort": { "type": "string", "enum": ["date", "new", "freq", "user", "trends"], "description": "date = last seen (default), new = first seen, freq = events, user = users affected" },
+                        "limit": { "type": "integer", "description": "Max issues (default: 25, max: 100)" },
+                        "cursor": { "type": "string", "description": "Pagination cursor from a previous call" }
+                    }
+                }),
+            ),
+            tool(
+                "get_issue",
+                "Issue detail plus one event (latest by default) with its stack trace, tags, contexts and recent breadcrumbs.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "issue": { "type": "string", "description": "Issue id, short id (WEB-1A2) or issue URL" },
+                        "event": { "type": "string", "description": "latest (default), oldest, recommended, or an event id" },
+                        "in_app_only": { "type": "boolean", "description": "Only include in-app frames (default: false)" }
+                    },
+                    "required": ["issue"]
+                }),
+            ),
+            tool(
+                "list_releases",
+                "Recent releases with health: crash-free sessions/users, adoption and new issues.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "project": project.clone(),
+                        "query": { "type": "string", "description": "Filter by version substring" },
+                        "stats_period": stats_period.clone(),
+                        "limit": { "type": "integer", "description": "Max releases (default: 10, max: 100)" }
+                    }
+                }),
+            ),
+            tool(
+                "get_release",
+                "One release's health, deploys and commit count. Example: version=\"web@1.4.2\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "version": { "type": "string", "description": "Release version" },
+                        "project": project,
+                        "stats_period": stats_period
+                    },
+                    "required": ["version"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "list_projects" => {
+                let org = self.org()?;
+                let mut projects: Vec<Value> = Vec::new();
+                let mut cursor: Option<String> = None;
+                loop {
+                    let params: Vec<(String, String)> = cursor
+                        .iter()
+                        .map(|c| ("cursor".to_string(), c.clone()))
+                        .collect();
+                    let (body, next) = self
+                        .get(&format!("organizations/{}/projects/", org), &params)
+                        .await?;
+                    projects.extend(body.as_array().into_iter().flatten().map(|p| {
+                        json!({
+                            "id": p["id"],
+                            "slug": p["slug"],
+                            "name": p["name"],
+                            "platform": p["platform"],
+                            "first_event": p["firstEvent"],
+                        })
+                    }));
+                    cursor = next;
+                    if cursor.is_none() {
+                        break;
+                    }
+                }
+
+                let data = json!({ "count": projects.len(), "projects": projects });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "search_issues" => {
+                let args: SearchIssuesArgs = parse_args(request.arguments)?;
+                let org = self.org()?;
+                let query = args.query.unwrap_or_else(|| "is:unresolved".to_string());
+                let limit = args.limit.unwrap_or(25).clamp(1, 100);
+
+                let mut params = vec![
+                    ("query".to_string(), query.clone()),
+                    ("limit".to_string(), limit.to_string()),
+                    (
+                        "statsPeriod".to_string(),
+                        args.stats_period.unwrap_or_else(|| "14d".to_string()),
+                    ),
+                ];
+                if let Some(project) = &args.project {
+                    params.push(("project".to_string(), self.project_id(project).await?));
+                }
+                if let Some(environment) = args.environment {
+                    params.push(("environment".to_string(), environment));
+                }
+                if let Some(sort) = args.sort {
+                    params.push(("sort".to_string(), sort));
+                }
+                if let Some(cursor) = args.cursor {
+                    params.push(("cursor".to_string(), cursor));
+                }
+
+                let (body, next) = self
+                    .get(&format!("organizations/{}/issues/", org), &params)
+                    .await?;
+                let issues: Vec<Value> = body
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(format_issue)
+                    .collect();
+
+                let data = json!({
+                    "query": query,
+                    "count": issues.len(),
+                    "issues": issues,
+                    "next_cursor": next,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_issue" => {
+                let args: GetIssueArgs = parse_args(request.arguments)?;
+                let org = self.org()?;
+                let id = self.resolve_issue(&args.issue).await?;
+                let event_id = args.event.unwrap_or_else(|| "latest".to_string());
+
+                let (issue, _) = self
+                    .get(&format!("organizations/{}/issues/{}/", org, id), &[])
+                    .await?;
+                let (event, _) = self
+                    .get(
+                        &format!(
+                            "organizations/{}/issues/{}/events/{}/",
+                            org,
+                            id,
+                            urlencoding::encode(&event_id)
+                        ),
+                        &[],
+                    )
+                    .await?;
+
+                let mut data = format_issue(&issue);
+                data["metadata"] = issue["metadata"].clone();
+                data["first_release"] = issue["firstRelease"]["version"].clone();
+                data["last_release"] = issue["lastRelease"]["version"].clone();
+                data["event"] = format_event(&event, args.in_app_only.unwrap_or(false));
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "list_releases" => {
+                let args: ListReleasesArgs = parse_args(request.arguments)?;
+                let org = self.org()?;
+                let limit = args.limit.unwrap_or(10).clamp(1, 100);
+
+                let mut params = vec![
+                    ("health".to_string(), "1".to_string()),
+                    ("per_page".to_string(), limit.to_string()),
+                    (
+                        "summaryStatsPeriod".to_string(),
+                        args.stats_period.unwrap_or_else(|| "24h".to_string()),
+                    ),
+                ];
+                if let Some(project) = &args.project {
+                    params.push(("project".to_string(), self.project_id(project).await?));
+                }
+                if let Some(query) = args.query {
+                    params.push(("query".to_string(), query));
+                }
+
+                let (body, _) = self
+                    .get(&format!("organizations/{}/releases/", org), &params)
+                    .await?;
+                let releases: Vec<Value> = body
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .take(limit)
+                    .map(format_release)
+                    .collect();
+
+                let data = json!({ "count": releases.len(), "releases": releases });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_release" => {
+                let args: GetReleaseArgs = parse_args(request.arguments)?;
+                let org = self.org()?;
+
+                let mut params = vec![
+                    ("health".to_string(), "1".to_string()),
+                    (
+                        "summaryStatsPeriod".to_string(),
+                        args.stats_period.unwrap_or_else(|| "24h".to_string()),
+                    ),
+                ];
+                if let Some(project) = &args.project {
+                    params.push(("project".to_string(), self.project_id(project).await?));
+                }
+
+                let (release, _) = self
+                    .get(
+                        &format!(
+                            "organizations/{}/releases/{}/",
+                            org,
+                            urlencoding::encode(&args.version)
+                        ),
+                        &params,
+                    )
+                    .await?;
+                let mut data = format_release(&release);
+                data["url"] = release["url"].clone();
+                data["authors"] = json!(release["authors"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|a| a["name"].as_str())
+                    .collect::<Vec<_>>());
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_issue_references() {
+        assert_eq!(
+            parse_issue_ref("4501234567"),
+            Some(IssueRef::Id("4501234567".to_string()))
+        );
+        assert_eq!(
+            parse_issue_ref("web-1a2"),
+            Some(IssueRef::ShortId("WEB-1A2".to_string()))
+        );
+        assert_eq!(
+            parse_issue_ref("https://acme.sentry.io/issues/4501234567/?project=12"),
+            Some(IssueRef::Id("4501234567".to_string()))
+        );
+        assert_eq!(
+            parse_issue_ref("https://sentry.io/organizations/acme/issues/42/events/latest/"),
+            Some(IssueRef::Id("42".to_string()))
+        );
+        assert_eq!(parse_issue_ref("not an issue"), None);
+    }
+
+    #[test]
+    fn reads_next_cursor_from_link_header() {
+        let link = "<https://sentry.io/api/0/organizations/acme/issues/?cursor=0:0:1>; \
+rel=\"previous\"; results=\"false\"; cursor=\"0:0:1\", \
+<https://sentry.io/api/0/organizations/acme/issues/?cursor=0:25:0>; \
+rel=\"next\"; results=\"true\"; cursor=\"0:25:0\"";
+        assert_eq!(next_cursor(link).as_deref(), Some("0:25:0"));
+        assert_eq!(
+            next_cursor(&link.replace("results=\"true\"", "results=\"false\"")),
+            None
+        );
+    }
+
+    #[test]
+    fn formats_stack_trace_innermost_first() {
+        let event = json!({
+            "eventID": "abc",
+            "tags": [{ "key": "environment", "value": "production" }],
+            "entries": [{
+                "type": "exception",
+                "data": { "values": [{
+                    "type": "TypeError",
+                    "value": "x is undefined",
+                    "stacktrace": { "frames": [
+                        { "function": "main", "filename": "node_modules/lib.js", "lineNo": 1, "inApp": false },
+                        { "function": "render", "filename": "src/app.js", "lineNo": 12, "inApp": true,
+                          "context": [[11, "const a = 1;"], [12, "x.y();"]] }
+                    ]}
+                }]}
+            }]
+        });
+        let formatted = format_event(&event, false);
+        assert_eq!(formatted["environment"], "production");
+        let frames = &formatted["exceptions"][0]["frames"];
+        assert_eq!(frames[0]["function"], "render");
+        assert_eq!(frames[0]["context"], "   11 const a = 1;\n   12 x.y();");
+        assert_eq!(frames[1]["function"], "main");
+
+        let in_app = format_event(&event, true);
+        assert_eq!(
+            in_app["exceptions"][0]["frames"].as_array().unwrap().len(),
+            1
+        );
+        assert_eq!(in_app["exceptions"][0]["frames_total"], 2);
+    }
+}
@@ -0,0 +1,111 @@
+// src/connectors/slack/files.rs
+//
+// Deciding how a shared file or canvas turns into text, and the metadata
+// kept alongside it. Downloads themselves live on the connector.
+
+use serde_json::{json, Value};
+
+/// Filetypes Slack serves as plain text (snippets, code, data files).
+const TEXT_FILETYPES: &[&str] = &[
+    "text",
+    "markdown",
+    "csv",
+    "tsv",
+    "json",
+    "xml",
+    "yaml",
+    "toml",
+    "javascript",
+    "typescript",
+    "python",
+    "rust",
+    "go",
+    "java",
+    "kotlin",
+    "swift",
+    "ruby",
+    "php",
+    "shell",
+    "sql",
+    "diff",
+    "c",
+    "cpp",
+    "csharp",
+    "css",
+    "dockerfile",
+    "ini",
+    "log",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TextKind {
+    Plain,
+    /// Canvases and HTML files, flattened with `html_to_text`
+    Html,
+    Unsupported,
+}
+
+pub(super) fn text_kind(file: &Value) -> TextKind {
+    let filetype = file
+        .get("filetype")
+        .and_then(|f| f.as_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let mimetype = file
+        .get("mimetype")
+        .and_then(|m| m.as_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    // Canvases report filetype "quip" and download as HTML
+    if matches!(filetype.as_str(), "quip" | "canvas" | "html") || mimetype == "text/html" {
+        TextKind::Html
+    } else if TEXT_FILETYPES.contains(&filetype.as_str())
+        || mimetype.starts_with("text/")
+        || mimetype == "application/json"
+    {
+        TextKind::Plain
+    } else {
+        TextKind::Unsupported
+    }
+}
+
+pub(super) fn summarize_file(file: &Value) -> Value {
+    json!({
+        "id": file.get("id"),
+        "name": file.get("name"),
+        "title": file.get("title"),
+        "filetype": file.get("filetype"),
+        "pretty_type": file.get("pretty_type"),
+        "mimetype": file.get("mimetype"),
+        "size": file.get("size"),
+        "user": file.get("user"),
+        "created": file.get("created"),
+        "channels": file.get("channels"),
+        "permalink": file.get("permalink"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_text_conversion_per_file() {
+        assert_eq!(
+            text_kind(&json!({"filetype": "quip", "pretty_type": "Canvas"})),
+            TextKind::Html
+        );
+        assert_eq!(
+            text_kind(&json!({"filetype": "python", "mimetype": "text/plain"})),
+            TextKind::Plain
+        );
+        assert_eq!(
+            text_kind(&json!({"filetype": "csv", "mimetype": "application/octet-stream"})),
+            TextKind::Plain
+        );
+        assert_eq!(
+            text_kind(&json!({"filetype": "png", "mimetype": "image/png"})),
+            TextKind::Unsupported
+        );
+    }
+}
@@ -9,13 +9,27 @@ use crate::auth::AuthDetails;
 use crate::auth_store::{AuthStore, FileAuthStore};
 use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
 use crate::error::ConnectorError;
-use crate::utils::{collect_paginated_with_cursor, structured_result_with_text, Page};
+use crate::utils::{
+    collect_paginated_with_cursor, html_to_text, structured_result_with_text, truncate_chars,
+    write_gate, Page,
+};
 use crate::Connector;
 
+mod files;
+mod search;
+
+use files::{summarize_file, text_kind, TextKind};
+use search::{build_search_query, search_next_cursor, summarize_match, SearchModifiers};
+
 const SLACK_API_BASE: &str = "https://slack.com/api";
 const SLACK_MAX_PER_REQUEST: u32 = 200;
 const SLACK_MAX_TOTAL: u32 = 5_000;
 const SLACK_MAX_REQUESTS: usize = 100;
+/// search.messages returns at most 100 matches per page
+const SLACK_SEARCH_PER_REQUEST: u32 = 100;
+const SLACK_SEARCH_DEFAULT: u32 = 20;
+const DEFAULT_FILE_MAX_BYTES: u64 = 5 * 1024 * 1024;
+const DEFAULT_FILE_MAX_CHARS: usize = 100_000;
 
 #[derive(Clone)]
 pub struct SlackConnector {
@@ -42,6 +56,49 @@ impl SlackConnector {
             .and_then(|m| m.get("token").cloned())
     }
 
+    async fn api_post(&self, method: &str, body: &Value) -> Result<Value, ConnectorError> {
+        let token = self.resolve_token().ok_or_else(|| {
+            ConnectorError::Authentication("Slack token not configured".to_string())
+        })?;
+        let url = format!("{}/{}", SLACK_API_BASE, method);
+        self.send_with_backoff(|client| client.post(&url).bearer_auth(&token).json(body))
+            .await
+    }
+
+    /// Download a file's private URL, refusing bodies over `max_bytes`.
+    async fn download_private(&self, url: &str, max_bytes: u64) -> Result<Vec<u8>, ConnectorError> {
+        let token = self.resolve_token().ok_or_else(|| {
+            ConnectorError::Authentication("Slack token not configured".to_string())
+        })?;
+        let resp = self
+            .client
+            .get(url)
+            .bearer_auth(&token)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+        if !resp.status().is_success() {
+            return Err(ConnectorError::Other(format!(
+                "Slack file download failed: HTTP {}",
+                resp.status().as_u16()
+            )));
+        }
+        if resp.content_length().is_some_and(|len| len > max_bytes) {
+            return Err(ConnectorError::InvalidParams(format!(
+                "file is over max_bytes={}",
+                max_bytes
+            )));
+        }
+        let bytes = resp.bytes().await.map_err(ConnectorError::HttpRequest)?;
+        if bytes.len() as u64 > max_bytes {
+            return Err(ConnectorError::InvalidParams(format!(
+                "file is over max_bytes={}",
+                max_bytes
+            )));
+        }
+        Ok(bytes.to_vec())
+    }
+
     async fn api_get(
         &self,
         method: &str,
@@ -147,6 +204,12 @@ struct ListChannelsInput {
     cursor: Option<String>,
     #[serde(default)]
     limit: Option<u32>, // 1..=200
+    #[serde(default = "default_true")]
+    exclude_archived: bool,
+}
+
+fn default_true() -> bool {
+    true
 }
 
 fn default_types() -> String {
@@ -171,6 +234,7 @@ struct ListMessagesInput {
 #[derive(Debug, Serialize, Deserialize)]
 struct GetThreadInput {
     channel: String,
+    #[serde(alias = "ts")]
     thread_ts: String,
     #[serde(default)]
     cursor: Option<String>,
@@ -178,13 +242,44 @@ struct GetThreadInput {
     limit: Option<u32>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct PostMessageInput {
+    channel: String,
+    text: String,
+    /// Reply in this thread instead of the channel
+    #[serde(default)]
+    thread_ts: Option<String>,
+    /// Also show a thread reply in the channel
+    #[serde(default)]
+    reply_broadcast: bool,
+    #[serde(default)]
+    confirm: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct SearchMessagesInput {
+    #[serde(default)]
     query: String,
     #[serde(default)]
+    from: Option<String>,
+    #[serde(default, rename = "in")]
+    in_channel: Option<String>,
+    #[serde(default)]
+    before: Option<String>,
+    #[serde(default)]
+    after: Option<String>,
+    #[serde(default)]
+    has_link: bool,
+    #[serde(default)]
     sort: Option<String>, // score|timestamp
     #[serde(default)]
     sort_dir: Option<String>, // asc|desc
+    /// Total matches to return across pages
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    cursor: Option<String>,
+    // Page-number pagination, kept for older callers
     #[serde(default)]
     count: Option<u32>, // results per page
     #[serde(default)]
@@ -201,12 +296,24 @@ struct ListFilesInput {
     ts_from: Option<String>,
     #[serde(default)]
     ts_to: Option<String>,
+    /// Comma-separated: spaces, snippets, images, gdocs, zips, pdfs, canvas
+    #[serde(default)]
+    types: Option<String>,
     #[serde(default)]
     cursor: Option<String>,
     #[serde(default)]
     limit: Option<u32>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct GetFileInput {
+    file_id: String,
+    #[serde(default)]
+    max_bytes: Option<u64>,
+    #[serde(default)]
+    max_chars: Option<usize>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct ListUsersInput {
     #[serde(default)]
@@ -309,7 +416,7 @@ impl Connector for SlackConnector {
     }
 
     fn description(&self) -> &'static str {
-        "Slack Web API: channels/DMs/threads/messages/files (read-only MVP)."
+        "Slack Web API: channels/DMs/threads/messages/files, plus opt-in message posting."
     }
 
     async fn capabilities(&self) -> ServerCapabilities {
@@ -379,7 +486,8 @@ impl Connector for SlackConnector {
                     "type":"object",
                     "properties":{
                         "types": {"type":"string","description":"public_channel,private_channel,im,mpim"},
-                        "cursor": {"type":"string"},
+                        "exclude_archived": {"type":"boolean","description":"Skip archived channels (default true)"},
+                        "cursor": {"type":"string","description":"response_metadata.next_cursor from a previous call"},
 	                        "limit": {"type":"integer","minimum":1,"maximum":5000}
 	                    }
 	                }).as_object().expect("Schema object").clone()),
@@ -410,7 +518,7 @@ impl Connector for SlackConnector {
 	            Tool {
 	                name: Cow::Borrowed("get_thread"),
                 title: None,
-                description: Some(Cow::Borrowed("Fetch a thread (root + replies) by channel and thread_ts.")),
+                description: Some(Cow::Borrowed("Fetch a thread (root + all replies, paging internally) by channel and the parent message's thread_ts. Set limit to cap long threads.")),
 	                input_schema: Arc::new(json!({
                     "type":"object",
                     "properties":{
@@ -425,20 +533,45 @@ impl Connector for SlackConnector {
                 annotations: None,
                 icons: None,
             },
+            Tool {
+                name: Cow::Borrowed("post_message"),
+                title: None,
+                description: Some(Cow::Borrowed("Post a message to a channel, or reply in a thread with thread_ts. Writes to Slack: disabled unless allow_writes is configured; only call after the user has approved the text, and pass confirm=true.")),
+                input_schema: Arc::new(json!({
+                    "type":"object",
+                    "properties":{
+                        "channel":{"type":"string","description":"Channel or DM ID"},
+                        "text":{"type":"string","description":"Message text (Slack mrkdwn)"},
+                        "thread_ts":{"type":"string","description":"Reply in this thread"},
+                        "reply_broadcast":{"type":"boolean","description":"Also post the thread reply to the channel"},
+                        "confirm":{"type":"boolean","description":"Must be true; confirms the user approved this message"}
+                    },
+                    "required":["channel","text","confirm"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
             Tool {
                 name: Cow::Borrowed("search_messages"),
                 title: None,
-                description: Some(Cow::Borrowed("Search Slack messages across accessible conversations.")),
+                description: Some(Cow::Borrowed("Search messages across the workspace (needs a user token with search:read). Structured from/in/before/after/has_link modifiers are compiled into Slack's query syntax; returns matches with permalinks and next_cursor.")),
                 input_schema: Arc::new(json!({
                     "type":"object",
                     "properties":{
-                        "query":{"type":"string"},
+                        "query":{"type":"string","description":"Free text; raw Slack modifiers are allowed too"},
+                        "from":{"type":"string","description":"Author user ID (U…) or @handle"},
+                        "in":{"type":"string","description":"Channel ID (C…) or #name"},
+                        "before":{"type":"string","description":"Only messages before this date (YYYY-MM-DD)"},
+                        "after":{"type":"string","description":"Only messages after this date (YYYY-MM-DD)"},
+                        "has_link":{"type":"boolean","description":"Only messages containing a link"},
                         "sort": {"type":"string","enum":["score","timestamp"]},
                         "sort_dir": {"type":"string","enum":["asc","desc"]},
-                        "count": {"type":"integer","minimum":1,"maximum":100},
-                        "page": {"type":"integer","minimum":1}
-                    },
-                    "required":["query"]
+                        "limit": {"type":"integer","minimum":1,"maximum":1000,"description":"Total matches to return (default 20)"},
+                        "cursor": {"type":"string","description":"next_cursor from a previous call"},
+                        "count": {"type":"integer","minimum":1,"maximum":100,"description":"Page size for page-number pagination"},
+                        "page": {"type":"integer","minimum":1,"description":"Page number (disables cursor pagination)"}
+                    }
                 }).as_object().expect("Schema object").clone()),
                 output_schema: None,
                 annotations: None,
@@ -447,7 +580,7 @@ impl Connector for SlackConnector {
 	            Tool {
 	                name: Cow::Borrowed("list_files"),
                 title: None,
-                description: Some(Cow::Borrowed("List files by channel/user/time window.")),
+                description: Some(Cow::Borrowed("List files shared in a channel or by a user, optionally within a time window or of given types (e.g. canvas, pdfs, snippets).")),
 	                input_schema: Arc::new(json!({
                     "type":"object",
                     "properties":{
@@ -455,6 +588,7 @@ impl Connector for SlackConnector {
                         "user":{"type":"string"},
                         "ts_from":{"type":"string"},
                         "ts_to":{"type":"string"},
+                        "types":{"type":"string","description":"Comma-separated: spaces, snippets, images, gdocs, zips, pdfs, canvas"},
                         "cursor": {"type":"string"},
 	                        "limit": {"type":"integer","minimum":1,"maximum":5000}
 	                    }
@@ -462,6 +596,23 @@ impl Connector for SlackConnector {
                 output_schema: None,
                 annotations: None,
                 icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_file"),
+                title: None,
+                description: Some(Cow::Borrowed("Fetch a file or canvas by ID and return its text: canvases and HTML are flattened, snippets and text files returned as-is. Other types (images, PDFs) return metadata and permalink only.")),
+                input_schema: Arc::new(json!({
+                    "type":"object",
+                    "properties":{
+                        "file_id":{"type":"string","description":"File or canvas ID (F…)"},
+                        "max_bytes":{"type":"integer","minimum":1,"description":"Refuse downloads larger than this (default 5 MiB)"},
+                        "max_chars":{"type":"integer","minimum":1,"description":"Truncate the returned text (default 100000)"}
+                    },
+                    "required":["file_id"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
             },
 	            Tool {
 	                name: Cow::Borrowed("get_thread_by_permalink"),
@@ -525,6 +676,7 @@ impl Connector for SlackConnector {
                     .unwrap_or(SLACK_MAX_PER_REQUEST)
                     .clamp(1, SLACK_MAX_TOTAL) as usize;
                 let types = input.types.clone();
+                let exclude_archived = input.exclude_archived;
 
                 let collected = collect_paginated_with_cursor(
                     desired,
@@ -534,8 +686,11 @@ impl Connector for SlackConnector {
                         let types = types.clone();
                         async move {
                             let per_page = (remaining as u32).clamp(1, SLACK_MAX_PER_REQUEST);
-                            let mut params =
-                                vec![("types", types), ("limit", per_page.to_string())];
+                            let mut params = vec![
+                                ("types", types),
+                                ("limit", per_page.to_string()),
+                                ("exclude_archived", exclude_archived.to_string()),
+                            ];
                             if let Some(c) = cursor {
                                 params.push(("cursor", c));
                             }
@@ -625,9 +780,10 @@ impl Connector for SlackConnector {
                 let input: GetThreadInput = serde_json::from_value(Value::Object(args_map))
                     .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
 
+                // The whole thread unless the caller caps it
                 let desired = input
                     .limit
-                    .unwrap_or(SLACK_MAX_PER_REQUEST)
+                    .unwrap_or(SLACK_MAX_TOTAL)
                     .clamp(1, SLACK_MAX_TOTAL) as usize;
                 let channel = input.channel.clone();
                 let ts = input.thread_ts.clone();
@@ -671,26 +827,128 @@ impl Connector for SlackConnector {
                 });
                 structured_result_with_text(&out, None)
             }
+            "post_message" => {
+                let input: PostMessageInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                write_gate(
+                    self.name(),
+                    "ARIVU_SLACK_ALLOW_WRITES",
+                    self.auth.get("allow_writes").map(String::as_str),
+                    input.confirm,
+                )?;
+                if input.text.trim().is_empty() {
+                    return Err(ConnectorError::InvalidParams(
+                        "text must not be empty".to_string(),
+                    ));
+                }
+                let mut body = json!({ "channel": input.channel, "text": input.text });
+                if let Some(thread_ts) = &input.thread_ts {
+                    body["thread_ts"] = json!(thread_ts);
+                    if input.reply_broadcast {
+                        body["reply_broadcast"] = json!(true);
+                    }
+                }
+                let v = self.api_post("chat.postMessage", &body).await?;
+                let out = json!({
+                    "channel": v.get("channel"),
+                    "ts": v.get("ts"),
+                    "thread_ts": input.thread_ts,
+                });
+                structured_result_with_text(&out, None)
+            }
             "search_messages" => {
                 let input: SearchMessagesInput = serde_json::from_value(Value::Object(args_map))
                     .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
-                let mut params = vec![("query", input.query)];
+                let modifiers = SearchModifiers {
+                    from: input.from.clone(),
+                    in_channel: input.in_channel.clone(),
+                    before: input.before.clone(),
+                    after: input.after.clone(),
+                    has_link: input.has_link,
+                };
+                let query = build_search_query(&input.query, &modifiers)?;
+                let mut base = vec![("query", query.clone()), ("highlight", "false".to_string())];
                 if let Some(s) = input.sort {
-                    params.push(("sort", s));
+                    base.push(("sort", s));
                 }
                 if let Some(sd) = input.sort_dir {
-                    params.push(("sort_dir", sd));
-                }
-                if let Some(c) = input.count {
-                    params.push(("count", c.to_string()));
+                    base.push(("sort_dir", sd));
                 }
+
                 if let Some(p) = input.page {
+                    let mut params = base;
                     params.push(("page", p.to_string()));
+                    if let Some(c) = input.count.or(input.limit) {
+                        params.push(("count", c.clamp(1, SLACK_SEARCH_PER_REQUEST).to_string()));
+                    }
+                    let v = self.api_get("search.messages", &params).await?;
+                    let matches: Vec<Value> = v
+                        .pointer("/messages/matches")
+                        .and_then(|m| m.as_array())
+                        .map(|m| m.iter().map(summarize_match).collect())
+                        .unwrap_or_default();
+                    let out = json!({
+                        "query": query,
+                        "total": v.pointer("/messages/total"),
+                        "matches": matches,
+                        "paging": v.pointer("/messages/paging"),
+                    });
+                    return structured_result_with_text(&out, None);
                 }
-                let v = self.api_get("search.messages", &params).await?;
-                // Structure: { messages: { matches: [...], pagination/ paging }, ... }
+
+                let desired = input
+                    .limit
+                    .or(input.count)
+                    .unwrap_or(SLACK_SEARCH_DEFAULT)
+                    .clamp(1, 1_000) as usize;
+                let total = std::sync::Mutex::new(None::<Value>);
+                // "*" starts cursormark pagination
+                let start = Some(input.cursor.unwrap_or_else(|| "*".to_string()));
+
+                let collected = collect_paginated_with_cursor(
+                    desired,
+                    SLACK_MAX_REQUESTS,
+                    start,
+                    |cursor, remaining| {
+                        let mut params = base.clone();
+                        let total = &total;
+                        async move {
+                            let per_page = (remaining as u32).clamp(1, SLACK_SEARCH_PER_REQUEST);
+                            params.push(("count", per_page.to_string()));
+                            if let Some(c) = cursor {
+                                params.push(("cursor", c));
+                            }
+                            let v = self.api_get("search.messages", &params).await?;
+                            if let Ok(mut t) = total.lock() {
+                                t.get_or_insert_with(|| {
+                                    v.pointer("/messages/total").cloned().unwrap_or(Value::Null)
+                                });
+                            }
+                            let items = v
+                                .pointer("/messages/matches")
+                                .and_then(|x| x.as_array())
+                                .cloned()
+                                .unwrap_or_default();
+                            Ok::<_, ConnectorError>(Page {
+                                items,
+                                next_cursor: search_next_cursor(&v),
+                            })
+                        }
+                    },
+                    |m: &Value| {
+                        let channel = m.pointer("/channel/id").and_then(|v| v.as_str())?;
+                        let ts = m.get("ts").and_then(|v| v.as_str())?;
+                        Some(format!("{}:{}", channel, ts))
+                    },
+                )
+                .await?;
+
+                let matches: Vec<Value> = collected.items.iter().map(summarize_match).collect();
                 let out = json!({
-                    "messages": v.get("messages").cloned().unwrap_or(json!({})),
+                    "query": query,
+                    "total": total.into_inner().ok().flatten(),
+                    "matches": matches,
+                    "next_cursor": collected.next_cursor,
                 });
                 structured_result_with_text(&out, None)
             }
@@ -706,6 +964,7 @@ impl Connector for SlackConnector {
                 let user = input.user.clone();
                 let ts_from = input.ts_from.clone();
                 let ts_to = input.ts_to.clone();
+                let types = input.types.clone();
 
                 let collected = collect_paginated_with_cursor(
                     desired,
@@ -716,6 +975,7 @@ impl Connector for SlackConnector {
                         let user = user.clone();
                         let ts_from = ts_from.clone();
                         let ts_to = ts_to.clone();
+                        let types = types.clone();
                         async move {
                             let per_page = (remaining as u32).clamp(1, SLACK_MAX_PER_REQUEST);
                             let mut params: Vec<(&str, String)> =
@@ -732,6 +992,9 @@ impl Connector for SlackConnector {
                             if let Some(t) = ts_to {
                                 params.push(("ts_to", t));
                             }
+                            if let Some(t) = types {
+                                params.push(("types", t));
+                            }
                             if let Some(c) = cursor {
                                 params.push(("cursor", c));
                             }
@@ -758,6 +1021,63 @@ impl Connector for SlackConnector {
                 });
                 structured_result_with_text(&out, None)
             }
+            "get_file" => {
+                let input: GetFileInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                let max_bytes = input.max_bytes.unwrap_or(DEFAULT_FILE_MAX_BYTES);
+                let max_chars = input.max_chars.unwrap_or(DEFAULT_FILE_MAX_CHARS);
+
+                let v = self
+                    .api_get("files.info", &[("file", input.file_id.trim().to_string())])
+                    .await?;
+                let file = v.get("file").cloned().unwrap_or(json!({}));
+                let kind = text_kind(&file);
+                let url = file
+                    .get("url_private_download")
+                    .or_else(|| file.get("url_private"))
+                    .and_then(|u| u.as_str());
+
+                let (mut text, note) = match (kind, url) {
+                    (TextKind::Unsupported, _) => (
+                        None,
+                        Some("No text conversion for this file type; open the permalink"),
+                    ),
+                    (_, None) => (None, Some("File has no downloadable URL")),
+                    (_, Some(_))
+                        if file
+                            .get("size")
+                            .and_then(|s| s.as_u64())
+                            .is_some_and(|size| size > max_bytes) =>
+                    {
+                        (
+                            None,
+                            Some("File is over max_bytes; raise max_bytes to fetch it"),
+                        )
+                    }
+                    (kind, Some(url)) => {
+                        let bytes = self.download_private(url, max_bytes).await?;
+                        let raw = String::from_utf8_lossy(&bytes).into_owned();
+                        let text = if kind == TextKind::Html {
+                            html_to_text(&raw)
+                        } else {
+                            raw
+                        };
+                        (Some(text), None)
+                    }
+                };
+                let truncated = text
+                    .as_mut()
+                    .map(|t| truncate_chars(t, max_chars))
+                    .unwrap_or(false);
+
+                let out = json!({
+                    "file": summarize_file(&file),
+                    "text": text,
+                    "truncated": truncated,
+                    "note": note,
+                });
+                structured_result_with_text(&out, None)
+            }
             "get_thread_by_permalink" => {
                 let input: GetThreadByPermalinkInput =
                     serde_json::from_value(Value::Object(args_map))
@@ -894,14 +1214,38 @@ impl Connector for SlackConnector {
 
     fn config_schema(&self) -> ConnectorConfigSchema {
         ConnectorConfigSchema {
-            fields: vec![Field {
-                name: "token".to_string(),
-                label: "Slack Token (xoxb/xoxp)".to_string(),
-                field_type: FieldType::Secret,
-                required: false,
-                description: Some("Provide a bot (xoxb) or user (xoxp) token with read scopes (conversations:read, channels:history, groups:history, im:history, mpim:history, users:read, files:read, search:read).".to_string()),
-                options: None,
-            }],
+            fields: vec![
+                Field {
+                    name: "token".to_string(),
+                    label: "Slack Token (xoxb/xoxp)".to_string(),
+                    field_type: FieldType::Secret,
+                    required: false,
+                    description: Some("Provide a bot (xoxb) or user (xoxp) token with read scopes (conversations:read, channels:history, groups:history, im:history, mpim:history, users:read, files:read, search:read).".to_string()),
+                    options: None,
+                },
+                Field {
+                    name: "allow_writes".to_string(),
+                    label: "Allow Posting".to_string(),
+                    field_type: FieldType::Boolean,
+                    required: false,
+                    description: Some("Enable post_message (each call still needs confirm=true). The token needs the chat:write scope.".to_string()),
+                    options: None,
+                },
+            ],
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_thread_and_channel_inputs() {
+        let input: GetThreadInput =
+            serde_json::from_value(json!({"channel": "C1", "ts": "1700000000.000100"})).unwrap();
+        assert_eq!(input.thread_ts, "1700000000.000100");
+        let input: ListChannelsInput = serde_json::from_value(json!({})).unwrap();
+        assert!(input.exclude_archived);
+    }
+}
@@ -0,0 +1,174 @@
+// src/connectors/slack/search.rs
+//
+// Structured `search.messages` modifiers compiled into Slack's query syntax
+// (`from:`, `in:`, `before:`/`after:`, `has:link`), and matches trimmed to
+// what an agent needs to cite them.
+
+use serde_json::{json, Value};
+
+use super::ConnectorError;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(super) struct SearchModifiers {
+    /// User ID (U…/W…) or @handle
+    pub from: Option<String>,
+    /// Channel ID (C…/G…) or #name
+    pub in_channel: Option<String>,
+    /// YYYY-MM-DD, exclusive
+    pub before: Option<String>,
+    /// YYYY-MM-DD, exclusive
+    pub after: Option<String>,
+    pub has_link: bool,
+}
+
+fn is_slack_id(value: &str, prefixes: &[char]) -> bool {
+    value.len() >= 9
+        && value.starts_with(prefixes)
+        && value
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+fn single_token(name: &str, value: &str) -> Result<String, ConnectorError> {
+    let value = value.trim();
+    if value.is_empty() || value.contains(char::is_whitespace) {
+        return Err(ConnectorError::InvalidParams(format!(
+            "Invalid {} '{}': expected a single ID or name",
+            name, value
+        )));
+    }
+    Ok(value.to_string())
+}
+
+fn search_date(name: &str, value: &str) -> Result<String, ConnectorError> {
+    let value = value.trim();
+    let valid = value.len() == 10
+        && value.char_indices().all(|(i, c)| match i {
+            4 | 7 => c == '-',
+            _ => c.is_ascii_digit(),
+        });
+    if !valid {
+        return Err(ConnectorError::InvalidParams(format!(
+            "Invalid {} '{}': expected YYYY-MM-DD",
+            name, value
+        )));
+    }
+    Ok(value.to_string())
+}
+
+/// Free text followed by the modifiers, space-separated as Slack expects.
+pub(super) fn build_search_query(
+    text: &str,
+    modifiers: &SearchModifiers,
+) -> Result<String, ConnectorError> {
+    let mut parts: Vec<String> = Vec::new();
+    let text = text.trim();
+    if !text.is_empty() {
+        parts.push(text.to_string());
+    }
+    if let Some(from) = modifiers.from.as_deref() {
+        let from = single_token("from", from)?;
+        let from = from.trim_start_matches('@');
+        parts.push(if is_slack_id(from, &['U', 'W']) {
+            format!("from:<@{}>", from)
+        } else {
+            format!("from:@{}", from)
+        });
+    }
+    if let Some(channel) = modifiers.in_channel.as_deref() {
+        let channel = single_token("in", channel)?;
+        let channel = channel.trim_start_matches('#');
+        parts.push(if is_slack_id(channel, &['C', 'G', 'D']) {
+            format!("in:<#{}>", channel)
+        } else {
+            format!("in:#{}", channel)
+        });
+    }
+    if let Some(before) = modifiers.before.as_deref() {
+        parts.push(format!("before:{}", search_date("before", before)?));
+    }
+    if let Some(after) = modifiers.after.as_deref() {
+        parts.push(format!("after:{}", search_date("after", after)?));
+    }
+    if modifiers.has_link {
+        parts.push("has:link".to_string());
+    }
+    if parts.is_empty() {
+        return Err(ConnectorError::InvalidParams(
+            "Provide a 'query' or at least one modifier (from, in, before, after, has_link)"
+                .to_string(),
+        ));
+    }
+    Ok(parts.join(" "))
+}
+
+/// A search match with its permalink and where it was posted.
+pub(super) fn summarize_match(m: &Value) -> Value {
+    json!({
+        "ts": m.get("ts"),
+        "channel_id": m.pointer("/channel/id"),
+        "channel_name": m.pointer("/channel/name"),
+        "user": m.get("user"),
+        "username": m.get("username"),
+        "text": m.get("text"),
+        "thread_ts": m.get("thread_ts"),
+        "permalink": m.get("permalink"),
+    })
+}
+
+/// Cursormark pagination reports the next cursor in `response_metadata`, or
+/// under `messages.pagination` on some workspaces.
+pub(super) fn search_next_cursor(v: &Value) -> Option<String> {
+    super::slack_next_cursor(v).or_else(|| {
+        v.pointer("/messages/pagination/next_cursor")
+            .and_then(|s| s.as_str())
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compiles_search_modifiers() {
+        let modifiers = SearchModifiers {
+            from: Some("U0123ABCD".to_string()),
+            in_channel: Some("#eng-infra".to_string()),
+            before: Some("2026-10-01".to_string()),
+            after: Some("2026-09-01".to_string()),
+            has_link: true,
+        };
+        assert_eq!(
+            build_search_query("deploy rollback", &modifiers).unwrap(),
+            "deploy rollback from:<@U0123ABCD> in:#eng-infra before:2026-10-01 after:2026-09-01 has:link"
+        );
+
+        let by_name = SearchModifiers {
+            from: Some("@ada".to_string()),
+            in_channel: Some("C0456EFGH".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            build_search_query("", &by_name).unwrap(),
+            "from:@ada in:<#C0456EFGH>"
+        );
+
+        assert!(build_search_query(" ", &SearchModifiers::default()).is_err());
+        let bad_date = SearchModifiers {
+            after: Some("2026/09/01".to_string()),
+            ..Default::default()
+        };
+        assert!(build_search_query("x", &bad_date).is_err());
+        let bad_user = SearchModifiers {
+            from: Some("ada in:#secret".to_string()),
+            ..Default::default()
+        };
+        assert!(build_search_query("x", &bad_user).is_err());
+
+        let page = json!({"ok": true, "messages": {"pagination": {"next_cursor": "bmV4dA=="}}});
+        assert_eq!(search_next_cursor(&page).as_deref(), Some("bmV4dA=="));
+    }
+}
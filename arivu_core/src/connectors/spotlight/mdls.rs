@@ -0,0 +1,138 @@
+// src/connectors/spotlight/mdls.rs
+//
+// Parser for `mdls` output. Attributes are `key = value` lines; arrays span
+// several lines between `(` and `)`, and strings inside arrays are only
+// quoted when they contain spaces or punctuation.
+
+use serde_json::{Map, Value};
+
+/// All attributes from `mdls` output, skipping `(null)` values. Dates
+/// become RFC 3339 strings and numbers stay numeric.
+pub(super) fn parse(output: &str) -> Map<String, Value> {
+    let mut attributes = Map::new();
+    let mut lines = output.lines();
+    while let Some(line) = lines.next() {
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        let value = value.trim();
+        if key.is_empty() || key.contains(char::is_whitespace) || value == "(null)" {
+            continue;
+        }
+
+        let parsed = if value == "(" {
+            let mut items = Vec::new();
+            for item in lines.by_ref() {
+                let item = item.trim();
+                if item == ")" {
+                    break;
+                }
+                let item = item.strip_suffix(',').unwrap_or(item);
+                if !item.is_empty() {
+                    items.push(scalar(item));
+                }
+            }
+            Value::Array(items)
+        } else if value == "()" {
+            Value::Array(Vec::new())
+        } else {
+            scalar(value)
+        };
+        attributes.insert(key.to_string(), parsed);
+    }
+    attributes
+}
+
+fn scalar(value: &str) -> Value {
+    if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        return Value::String(unescape(&value[1..value.len() - 1]));
+    }
+    if let Ok(n) = value.parse::<i64>() {
+        return n.into();
+    }
+    if let Some(n) = value
+        .parse::<f64>()
+        .ok()
+        .and_then(serde_json::Number::from_f64)
+    {
+        return Value::Number(n);
+    }
+    if let Ok(date) = chrono::DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S %z") {
+        return Value::String(date.to_rfc3339());
+    }
+    Value::String(unescape(value))
+}
+
+/// Undo the backslash escapes mdls uses, including `\U00e9` for non-ASCII
+/// characters inside arrays.
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('U') | Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    Some(decoded) => out.push(decoded),
+                    None => {
+                        out.push_str("\\U");
+                        out.push_str(&hex);
+                    }
+                }
+            }
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_scalars_arrays_and_dates() {
+        let output = r#"_kMDItemDisplayNameWithExtensions = "Q1 report.pdf"
+kMDItemAuthors                     = (
+    "Jane Doe",
+    "Jos\U00e9 Alvarez"
+)
+kMDItemContentCreationDate         = 2025-01-05 10:22:33 +0000
+kMDItemContentTypeTree             = (
+    "com.adobe.pdf",
+    "public.data"
+)
+kMDItemFSSize                      = 482113
+kMDItemKeywords                    = (null)
+kMDItemUserTags                    = (
+    Work
+)
+kMDItemVersion                     = 1.7
+kMDItemWhereFroms                  = (
+)
+"#;
+        let attributes = parse(output);
+        assert_eq!(
+            Value::Object(attributes),
+            json!({
+                "_kMDItemDisplayNameWithExtensions": "Q1 report.pdf",
+                "kMDItemAuthors": ["Jane Doe", "José Alvarez"],
+                "kMDItemContentCreationDate": "2025-01-05T10:22:33+00:00",
+                "kMDItemContentTypeTree": ["com.adobe.pdf", "public.data"],
+                "kMDItemFSSize": 482113,
+                "kMDItemUserTags": ["Work"],
+                "kMDItemVersion": 1.7,
+                "kMDItemWhereFroms": []
+            })
+        );
+    }
+}
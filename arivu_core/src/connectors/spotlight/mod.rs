@@ -6,6 +6,7 @@ use async_trait::async_trait;
 use rmcp::model::*;
 use serde_json::{json, Value};
 use std::borrow::Cow;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use crate::auth::AuthDetails;
@@ -13,6 +14,76 @@ use crate::capabilities::ConnectorConfigSchema;
 use crate::error::ConnectorError;
 use crate::utils::structured_result_with_text;
 
+mod mdls;
+mod query;
+
+use query::{kind_clause, parse_time_bound, QueryFilters};
+
+fn expand_path(path: &str) -> PathBuf {
+    match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()
+            .map(|home| home.join(rest))
+            .unwrap_or_else(|| PathBuf::from(path)),
+        None => PathBuf::from(path),
+    }
+}
+
+fn string_arg(args: &serde_json::Map<String, Value>, key: &str) -> Option<String> {
+    args.get(key)
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+/// A list argument given as an array or a comma-separated string.
+fn list_arg(args: &serde_json::Map<String, Value>, key: &str) -> Vec<String> {
+    let items: Vec<String> = match args.get(key) {
+        Some(Value::Array(items)) => items
+            .iter()
+            .filter_map(|v| v.as_str())
+            .map(str::to_string)
+            .collect(),
+        Some(Value::String(s)) => s.split(',').map(str::to_string).collect(),
+        _ => Vec::new(),
+    };
+    items
+        .into_iter()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Structured filters from tool arguments (`query` is the content match).
+fn filters_from_args(
+    args: &serde_json::Map<String, Value>,
+) -> Result<QueryFilters, ConnectorError> {
+    let now = chrono::Utc::now();
+    let bound = |key: &str, end_of_day: bool| {
+        string_arg(args, key)
+            .map(|value| {
+                parse_time_bound(&value, end_of_day, now).ok_or_else(|| {
+                    ConnectorError::InvalidParams(format!(
+                        "Invalid '{}': '{}'. Use YYYY-MM-DD, YYYY-MM-DDTHH:MM, RFC 3339, \
+or a relative age like '7d', '12h', '2w'",
+                        key, value
+                    ))
+                })
+            })
+            .transpose()
+    };
+    Ok(QueryFilters {
+        text: string_arg(args, "query"),
+        kind: string_arg(args, "kind"),
+        author: string_arg(args, "author"),
+        tags: list_arg(args, "tags"),
+        created_since: bound("created_since", false)?,
+        created_until: bound("created_until", true)?,
+        modified_since: bound("modified_since", false)?,
+        modified_until: bound("modified_until", true)?,
+    })
+}
+
 /// macOS Spotlight connector for searching indexed files and content.
 ///
 /// Uses the `mdfind` CLI which queries Spotlight's NSMetadataQuery under the hood.
@@ -50,7 +121,7 @@ impl SpotlightConnector {
         }
 
         if let Some(dir) = only_in {
-            cmd.arg("-onlyin").arg(dir);
+            cmd.arg("-onlyin").arg(expand_path(dir));
         }
 
         cmd.arg(query);
@@ -92,15 +163,21 @@ impl SpotlightConnector {
         ))
     }
 
-    /// Get metadata for a file using mdls
+    /// Get metadata for a file using mdls, optionally only the named attributes
     #[cfg(target_os = "macos")]
-    async fn get_file_metadata(&self, path: &str) -> Result<Value, ConnectorError> {
+    async fn get_file_metadata(
+        &self,
+        path: &str,
+        attributes: &[String],
+    ) -> Result<Value, ConnectorError> {
         use tokio::process::Command;
 
-        let output = Command::new("/usr/bin/mdls")
-            .arg("-plist")
-            .arg("-")
-            .arg(path)
+        let mut cmd = Command::new("/usr/bin/mdls");
+        for name in attributes {
+            cmd.arg("-name").arg(name);
+        }
+        let output = cmd
+            .arg(expand_path(path))
             .output()
             .await
             .map_err(|e| ConnectorError::Other(format!("Failed to run mdls: {}", e)))?;
@@ -110,127 +187,20 @@ impl SpotlightConnector {
             return Err(ConnectorError::Other(format!("mdls failed: {}", stderr)));
         }
 
-        // Parse plist output - for simplicity, use the raw format instead
-        let output = Command::new("/usr/bin/mdls")
-            .arg(path)
-            .output()
-            .await
-            .map_err(|e| ConnectorError::Other(format!("Failed to run mdls: {}", e)))?;
-
         let stdout = String::from_utf8_lossy(&output.stdout);
-
-        // Parse the key = value format from mdls
-        let mut metadata = serde_json::Map::new();
-        for line in stdout.lines() {
-            if let Some((key, value)) = line.split_once(" = ") {
-                let key = key.trim();
-                let value = value.trim();
-
-                // Skip null values
-                if value == "(null)" {
-                    continue;
-                }
-
-                // Parse value type
-                let parsed_value = if value.starts_with('"') && value.ends_with('"') {
-                    // String value
-                    Value::String(value[1..value.len() - 1].to_string())
-                } else if value == "1" || value == "0" {
-                    // Boolean-ish
-                    Value::Number(value.parse::<i64>().unwrap_or(0).into())
-                } else if let Ok(num) = value.parse::<i64>() {
-                    Value::Number(num.into())
-                } else if let Ok(num) = value.parse::<f64>() {
-                    Value::Number(serde_json::Number::from_f64(num).unwrap_or(0.into()))
-                } else if value.starts_with('(') && value.ends_with(')') {
-                    // Array - simplified parsing
-                    Value::String(value.to_string())
-                } else {
-                    Value::String(value.to_string())
-                };
-
-                metadata.insert(key.to_string(), parsed_value);
-            }
-        }
-
-        Ok(Value::Object(metadata))
+        Ok(Value::Object(mdls::parse(&stdout)))
     }
 
     #[cfg(not(target_os = "macos"))]
-    async fn get_file_metadata(&self, _path: &str) -> Result<Value, ConnectorError> {
+    async fn get_file_metadata(
+        &self,
+        _path: &str,
+        _attributes: &[String],
+    ) -> Result<Value, ConnectorError> {
         Err(ConnectorError::Other(
             "File metadata is only available on macOS".to_string(),
         ))
     }
-
-    /// Build a Spotlight query from structured parameters
-    fn build_query(
-        &self,
-        content: Option<&str>,
-        kind: Option<&str>,
-        author: Option<&str>,
-        date_from: Option<&str>,
-        date_to: Option<&str>,
-    ) -> String {
-        let mut parts = Vec::new();
-
-        if let Some(content) = content {
-            // Full-text content search
-            parts.push(format!("kMDItemTextContent == \"*{}*\"cd", content));
-        }
-
-        if let Some(kind) = kind {
-            // Map common kinds to Spotlight types
-            let kind_query = match kind.to_lowercase().as_str() {
-                "pdf" => "kMDItemContentType == \"com.adobe.pdf\"",
-                "image" | "images" => "kMDItemContentTypeTree == \"public.image\"",
-                "video" | "videos" => "kMDItemContentTypeTree == \"public.movie\"",
-                "audio" | "music" => "kMDItemContentTypeTree == \"public.audio\"",
-                "document" | "documents" => "kMDItemContentTypeTree == \"public.content\"",
-                "email" | "emails" => "kMDItemContentType == \"com.apple.mail.emlx\"",
-                "presentation" | "presentations" => "kMDItemContentType == \"com.apple.keynote.key\" || kMDItemContentType == \"org.openxmlformats.presentationml.presentation\" || kMDItemContentType == \"com.microsoft.powerpoint.ppt\"",
-                "spreadsheet" | "spreadsheets" => "kMDItemContentType == \"com.apple.numbers.numbers\" || kMDItemContentType == \"org.openxmlformats.spreadsheetml.sheet\" || kMDItemContentType == \"com.microsoft.excel.xls\"",
-                "code" | "source" => "kMDItemContentTypeTree == \"public.source-code\"",
-                "text" => "kMDItemContentTypeTree == \"public.plain-text\"",
-                "folder" | "directory" => "kMDItemContentType == \"public.folder\"",
-                "application" | "app" => "kMDItemContentType == \"com.apple.application-bundle\"",
-                "markdown" | "md" => "kMDItemContentType == \"net.daringfireball.markdown\"",
-                _ => {
-                    // Use as-is if it looks like a UTI, otherwise search display name
-                    if kind.contains('.') {
-                        return format!("kMDItemContentType == \"{}\"", kind);
-                    } else {
-                        return format!("kMDItemKind == \"*{}*\"cd", kind);
-                    }
-                }
-            };
-            parts.push(kind_query.to_string());
-        }
-
-        if let Some(author) = author {
-            parts.push(format!("kMDItemAuthors == \"*{}*\"cd", author));
-        }
-
-        if let Some(from) = date_from {
-            parts.push(format!(
-                "kMDItemContentModificationDate >= $time.iso({})",
-                from
-            ));
-        }
-
-        if let Some(to) = date_to {
-            parts.push(format!(
-                "kMDItemContentModificationDate <= $time.iso({})",
-                to
-            ));
-        }
-
-        if parts.is_empty() {
-            "*".to_string() // Match all
-        } else {
-            parts.join(" && ")
-        }
-    }
 }
 
 #[async_trait]
@@ -337,8 +307,9 @@ impl crate::Connector for SpotlightConnector {
                 name: Cow::Borrowed("search"),
                 title: Some("Search Spotlight".to_string()),
                 description: Some(Cow::Borrowed(
-                    "Search Spotlight index by content/name/kind/recent/raw. Use mode to choose \
-the search type. Example: mode=\"content\" query=\"invoice\" directory=\"~/Documents\" limit=20.",
+                    "Search Spotlight index by content/name/kind/recent/filter/raw. Use mode to choose \
+the search type; mode=filter combines query, kind, author, tags and created/modified date ranges. \
+Example: mode=\"filter\" kind=\"pdf\" author=\"Jane\" modified_since=\"30d\" directory=\"~/Documents\".",
                 )),
                 input_schema: Arc::new(
                     json!({
@@ -346,13 +317,13 @@ the search type. Example: mode=\"content\" query=\"invoice\" directory=\"~/Docum
                         "properties": {
                             "mode": {
                                 "type": "string",
-                                "enum": ["content", "name", "kind", "recent", "raw"],
-                                "description": "Search mode. Use 'content' for full-text, 'name' for file names, 'kind' for file types, 'recent' for modified files, 'raw' for mdfind syntax.",
+                                "enum": ["content", "name", "kind", "recent", "filter", "raw"],
+                                "description": "Search mode. Use 'content' for full-text, 'name' for file names, 'kind' for file types, 'recent' for modified files, 'filter' for structured metadata filters, 'raw' for mdfind syntax.",
                                 "default": "content"
                             },
                             "query": {
                                 "type": "string",
-                                "description": "Search query text. Required for mode=content/name/raw."
+                                "description": "Search query text. Required for mode=content/name/raw; optional content match for mode=filter."
                             },
                             "directory": {
                                 "type": "string",
@@ -360,7 +331,7 @@ the search type. Example: mode=\"content\" query=\"invoice\" directory=\"~/Docum
                             },
                             "kind": {
                                 "type": "string",
-                                "description": "File type filter for mode=content/recent OR required file type for mode=kind.",
+                                "description": "File type filter for mode=content/recent/filter OR required file type for mode=kind.",
                                 "enum": [
                                     "pdf",
                                     "image",
@@ -382,6 +353,31 @@ the search type. Example: mode=\"content\" query=\"invoice\" directory=\"~/Docum
                                 "description": "Only for mode=recent: modified within N days (default: 7).",
                                 "default": 7
                             },
+                            "author": {
+                                "type": "string",
+                                "description": "Only for mode=filter: author name (partial, case-insensitive)."
+                            },
+                            "tags": {
+                                "type": "array",
+                                "items": { "type": "string" },
+                                "description": "Only for mode=filter: Finder tags the file must all carry."
+                            },
+                            "created_since": {
+                                "type": "string",
+                                "description": "Only for mode=filter: created at or after (YYYY-MM-DD, YYYY-MM-DDTHH:MM, RFC 3339, or relative like '7d')."
+                            },
+                            "created_until": {
+                                "type": "string",
+                                "description": "Only for mode=filter: created at or before (a bare date covers the whole day)."
+                            },
+                            "modified_since": {
+                                "type": "string",
+                                "description": "Only for mode=filter: modified at or after (YYYY-MM-DD, YYYY-MM-DDTHH:MM, RFC 3339, or relative like '7d')."
+                            },
+                            "modified_until": {
+                                "type": "string",
+                                "description": "Only for mode=filter: modified at or before (a bare date covers the whole day)."
+                            },
                             "limit": {
                                 "type": "integer",
                                 "description": "Maximum number of results (default: 50)",
@@ -402,8 +398,8 @@ the search type. Example: mode=\"content\" query=\"invoice\" directory=\"~/Docum
                 name: Cow::Borrowed("get_metadata"),
                 title: Some("Get File Metadata".to_string()),
                 description: Some(Cow::Borrowed(
-                    "Get Spotlight metadata for a file path. Use when you already have a path \
-and want its indexed attributes.",
+                    "Get all Spotlight (mdls) metadata attributes for a file path. Use when you already \
+have a path and want its indexed attributes; pass attributes to fetch only some.",
                 )),
                 input_schema: Arc::new(
                     json!({
@@ -412,6 +408,11 @@ and want its indexed attributes.",
                             "path": {
                                 "type": "string",
                                 "description": "Full path to the file"
+                            },
+                            "attributes": {
+                                "type": "array",
+                                "items": { "type": "string" },
+                                "description": "Optional: only these attributes (e.g. [\"kMDItemAuthors\", \"kMDItemWhereFroms\"])"
                             }
                         },
                         "required": ["path"]
@@ -501,6 +502,11 @@ and want its indexed attributes.",
                         mapped.insert("query".to_string(), query);
                         "raw_query"
                     }
+                    "filter" => {
+                        mapped = args.clone();
+                        mapped.remove("mode");
+                        "search_filtered"
+                    }
                     _ => {
                         return Err(ConnectorError::InvalidInput(format!(
                             "Invalid 'mode': {}",
@@ -530,7 +536,12 @@ and want its indexed attributes.",
                     .unwrap_or(50);
 
                 // Build the query
-                let query = self.build_query(Some(query_text), kind, None, None, None);
+                let query = QueryFilters {
+                    text: Some(query_text.to_string()),
+                    kind: kind.map(str::to_string),
+                    ..Default::default()
+                }
+                .compile();
 
                 let results = self
                     .run_mdfind(&query, directory, Some(limit), false)
@@ -587,7 +598,7 @@ and want its indexed attributes.",
                     .map(|n| n as usize)
                     .unwrap_or(50);
 
-                let query = self.build_query(None, Some(kind), None, None, None);
+                let query = kind_clause(kind);
 
                 let results = self
                     .run_mdfind(&query, directory, Some(limit), false)
@@ -622,8 +633,7 @@ and want its indexed attributes.",
                 )];
 
                 if let Some(kind) = kind {
-                    let kind_query = self.build_query(None, Some(kind), None, None, None);
-                    query_parts.push(kind_query);
+                    query_parts.push(kind_clause(kind));
                 }
 
                 let query = query_parts.join(" && ");
@@ -644,16 +654,51 @@ and want its indexed attributes.",
                 structured_result_with_text(&payload, None)
             }
 
+            "search_filtered" => {
+                let filters = filters_from_args(&args)?;
+                if filters.is_empty() {
+                    return Err(ConnectorError::InvalidInput(
+                        "mode=filter needs at least one of query, kind, author, tags, \
+created_since/created_until or modified_since/modified_until"
+                            .to_string(),
+                    ));
+                }
+
+                let directory = args.get("directory").and_then(|v| v.as_str());
+                let limit = args
+                    .get("limit")
+                    .and_then(|v| v.as_u64())
+                    .map(|n| n as usize)
+                    .unwrap_or(50);
+
+                let query = filters.compile();
+
+                let results = self
+                    .run_mdfind(&query, directory, Some(limit), false)
+                    .await?;
+
+                let payload = json!({
+                    "spotlight_query": query,
+                    "directory": directory,
+                    "count": results.len(),
+                    "files": results
+                });
+
+                structured_result_with_text(&payload, None)
+            }
+
             "get_metadata" => {
                 let path = args
                     .get("path")
                     .and_then(|v| v.as_str())
                     .ok_or_else(|| ConnectorError::InvalidInput("Missing 'path'".to_string()))?;
+                let attributes = list_arg(&args, "attributes");
 
-                let metadata = self.get_file_metadata(path).await?;
+                let metadata = self.get_file_metadata(path, &attributes).await?;
 
                 let payload = json!({
                     "path": path,
+                    "attribute_count": metadata.as_object().map_or(0, |m| m.len()),
                     "metadata": metadata
                 });
 
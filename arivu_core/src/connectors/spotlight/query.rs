@@ -0,0 +1,202 @@
+// src/connectors/spotlight/query.rs
+//
+// Structured search filters compiled to mdfind's query language, so callers
+// can ask for "PDFs by Jane tagged Work modified this month" without writing
+// `kMDItem*` expressions by hand.
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+/// Filters for a structured Spotlight search. Every filter that is set must
+/// match.
+#[derive(Debug, Clone, Default)]
+pub(super) struct QueryFilters {
+    /// Full-text content match
+    pub text: Option<String>,
+    pub kind: Option<String>,
+    pub author: Option<String>,
+    /// Finder tags; files must carry all of them
+    pub tags: Vec<String>,
+    pub created_since: Option<DateTime<Utc>>,
+    pub created_until: Option<DateTime<Utc>>,
+    pub modified_since: Option<DateTime<Utc>>,
+    pub modified_until: Option<DateTime<Utc>>,
+}
+
+impl QueryFilters {
+    pub fn is_empty(&self) -> bool {
+        self.text.is_none()
+            && self.kind.is_none()
+            && self.author.is_none()
+            && self.tags.is_empty()
+            && self.created_since.is_none()
+            && self.created_until.is_none()
+            && self.modified_since.is_none()
+            && self.modified_until.is_none()
+    }
+
+    /// The mdfind query for these filters; `*` matches everything.
+    pub fn compile(&self) -> String {
+        let mut parts = Vec::new();
+
+        if let Some(text) = &self.text {
+            parts.push(format!("kMDItemTextContent == \"*{}*\"cd", escape(text)));
+        }
+        if let Some(kind) = &self.kind {
+            parts.push(kind_clause(kind));
+        }
+        if let Some(author) = &self.author {
+            parts.push(format!("kMDItemAuthors == \"*{}*\"cd", escape(author)));
+        }
+        for tag in &self.tags {
+            parts.push(format!("kMDItemUserTags == \"{}\"cd", escape(tag)));
+        }
+
+        let ranges = [
+            ("kMDItemContentCreationDate", ">=", self.created_since),
+            ("kMDItemContentCreationDate", "<=", self.created_until),
+            ("kMDItemContentModificationDate", ">=", self.modified_since),
+            ("kMDItemContentModificationDate", "<=", self.modified_until),
+        ];
+        for (attr, op, bound) in ranges {
+            if let Some(bound) = bound {
+                parts.push(format!(
+                    "{} {} $time.iso({})",
+                    attr,
+                    op,
+                    bound.format("%Y-%m-%dT%H:%M:%SZ")
+                ));
+            }
+        }
+
+        if parts.is_empty() {
+            "*".to_string()
+        } else {
+            parts.join(" && ")
+        }
+    }
+}
+
+/// Quote-safe value for inside a `"..."` query string.
+fn escape(value: &str) -> String {
+    value.trim().replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// The query clause for a file kind: a known name (pdf, image, code, ...),
+/// a UTI such as `public.python-script`, or else a match on the Finder kind.
+pub(super) fn kind_clause(kind: &str) -> String {
+    let clause = match kind.trim().to_lowercase().as_str() {
+        "pdf" => "kMDItemContentType == \"com.adobe.pdf\"",
+        "image" | "images" => "kMDItemContentTypeTree == \"public.image\"",
+        "video" | "videos" => "kMDItemContentTypeTree == \"public.movie\"",
+        "audio" | "music" => "kMDItemContentTypeTree == \"public.audio\"",
+        "document" | "documents" => "kMDItemContentTypeTree == \"public.content\"",
+        "email" | "emails" => "kMDItemContentType == \"com.apple.mail.emlx\"",
+        "presentation" | "presentations" => "(kMDItemContentType == \"com.apple.keynote.key\" || kMDItemContentType == \"org.openxmlformats.presentationml.presentation\" || kMDItemContentType == \"com.microsoft.powerpoint.ppt\")",
+        "spreadsheet" | "spreadsheets" => "(kMDItemContentType == \"com.apple.numbers.numbers\" || kMDItemContentType == \"org.openxmlformats.spreadsheetml.sheet\" || kMDItemContentType == \"com.microsoft.excel.xls\")",
+        "code" | "source" => "kMDItemContentTypeTree == \"public.source-code\"",
+        "text" => "kMDItemContentTypeTree == \"public.plain-text\"",
+        "folder" | "directory" => "kMDItemContentType == \"public.folder\"",
+        "application" | "app" => "kMDItemContentType == \"com.apple.application-bundle\"",
+        "markdown" | "md" => "kMDItemContentType == \"net.daringfireball.markdown\"",
+        _ if kind.contains('.') => {
+            return format!("kMDItemContentTypeTree == \"{}\"", escape(kind));
+        }
+        _ => return format!("kMDItemKind == \"*{}*\"cd", escape(kind)),
+    };
+    clause.to_string()
+}
+
+/// Parse a date bound: RFC 3339, `YYYY-MM-DDTHH:MM[:SS]` or a bare date in
+/// local time, or a relative age such as `7d`, `12h` or `2w` before `now`.
+/// A bare date used as an upper bound (`end_of_day`) covers the whole day.
+pub(super) fn parse_time_bound(
+    value: &str,
+    end_of_day: bool,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    let local = |naive: NaiveDateTime| {
+        Local
+            .from_local_datetime(&naive)
+            .earliest()
+            .map(|dt| dt.with_timezone(&Utc))
+    };
+    for fmt in ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M"] {
+        if let Ok(naive) = NaiveDateTime::parse_from_str(value, fmt) {
+            return local(naive);
+        }
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        let time = if end_of_day {
+            date.and_hms_opt(23, 59, 59)
+        } else {
+            date.and_hms_opt(0, 0, 0)
+        };
+        return time.and_then(local);
+    }
+
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+    let seconds_per_unit: i64 = match unit.trim().to_ascii_lowercase().as_str() {
+        "m" | "min" | "mins" => 60,
+        "h" | "hr" | "hrs" => 3_600,
+        "d" | "day" | "days" => 86_400,
+        "w" | "wk" | "week" | "weeks" => 7 * 86_400,
+        "mo" | "month" | "months" => 30 * 86_400,
+        "y" | "yr" | "year" | "years" => 365 * 86_400,
+        _ => return None,
+    };
+    let amount: i64 = amount.parse().ok()?;
+    let age = chrono::Duration::try_seconds(amount.saturating_mul(seconds_per_unit))?;
+    now.checked_sub_signed(age)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compiles_filters_to_mdfind_syntax() {
+        assert_eq!(QueryFilters::default().compile(), "*");
+
+        let now = DateTime::parse_from_rfc3339("2025-03-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let filters = QueryFilters {
+            text: Some("say \"hi\"".to_string()),
+            kind: Some("spreadsheet".to_string()),
+            author: Some("Jane".to_string()),
+            tags: vec!["Work".to_string(), "Q1".to_string()],
+            created_since: parse_time_bound("2025-01-01T08:30:00+01:00", false, now),
+            modified_since: parse_time_bound("7d", false, now),
+            ..Default::default()
+        };
+        assert_eq!(
+            filters.compile(),
+            "kMDItemTextContent == \"*say \\\"hi\\\"*\"cd && \
+(kMDItemContentType == \"com.apple.numbers.numbers\" || \
+kMDItemContentType == \"org.openxmlformats.spreadsheetml.sheet\" || \
+kMDItemContentType == \"com.microsoft.excel.xls\") && \
+kMDItemAuthors == \"*Jane*\"cd && \
+kMDItemUserTags == \"Work\"cd && kMDItemUserTags == \"Q1\"cd && \
+kMDItemContentCreationDate >= $time.iso(2025-01-01T07:30:00Z) && \
+kMDItemContentModificationDate >= $time.iso(2025-03-03T12:00:00Z)"
+        );
+
+        assert_eq!(
+            kind_clause("public.python-script"),
+            "kMDItemContentTypeTree == \"public.python-script\""
+        );
+        assert_eq!(kind_clause("Keynote"), "kMDItemKind == \"*Keynote*\"cd");
+
+        let start = parse_time_bound("2025-01-31", false, now).unwrap();
+        let end = parse_time_bound("2025-01-31", true, now).unwrap();
+        assert_eq!((end - start).num_seconds(), 86_399);
+        assert!(parse_time_bound("last week", false, now).is_none());
+    }
+}
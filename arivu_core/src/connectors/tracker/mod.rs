@@ -0,0 +1,786 @@
+// src/connectors/tracker/mod.rs
+// Linux desktop search connector using GNOME Tracker (tracker3) or Recoll (recollq)
+// Mirrors the macOS Spotlight connector so local search has the same tool surface
+
+use async_trait::async_trait;
+use rmcp::model::*;
+use serde_json::{json, Map, Value};
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::auth::AuthDetails;
+use crate::capabilities::ConnectorConfigSchema;
+use crate::error::ConnectorError;
+use crate::utils::structured_result_with_text;
+
+const TRACKER_SERVICE: &str = "org.freedesktop.Tracker3.Miner.Files";
+
+/// Linux desktop search connector for indexed files and content.
+///
+/// Uses whichever index is available:
+/// - GNOME Tracker 3 (`tracker3 sparql` against the Files miner)
+/// - Recoll (`recollq`)
+///
+/// Can search by content, file name, kind/MIME type and modification date.
+/// Only available on Linux.
+#[derive(Default)]
+pub struct TrackerConnector;
+
+/// Index backend used for a query.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Backend {
+    Tracker,
+    Recoll,
+}
+
+impl Backend {
+    fn name(self) -> &'static str {
+        match self {
+            Backend::Tracker => "tracker",
+            Backend::Recoll => "recoll",
+        }
+    }
+
+    fn binary(self) -> &'static str {
+        match self {
+            Backend::Tracker => "tracker3",
+            Backend::Recoll => "recollq",
+        }
+    }
+}
+
+/// Structured search, translated to SPARQL or the Recoll query language.
+#[derive(Debug, Default)]
+struct SearchSpec {
+    content: Option<String>,
+    name: Option<String>,
+    /// MIME types; a trailing `/*` matches the whole family (e.g. `image/*`)
+    mimes: Vec<String>,
+    directory: Option<PathBuf>,
+    modified_since: Option<chrono::NaiveDate>,
+    limit: usize,
+}
+
+/// Map a kind shorthand (or a literal MIME type) to MIME patterns.
+fn kind_to_mimes(kind: &str) -> Vec<String> {
+    let mimes: &[&str] = match kind.to_lowercase().as_str() {
+        "pdf" => &["application/pdf"],
+        "image" | "images" => &["image/*"],
+        "video" | "videos" => &["video/*"],
+        "audio" | "music" => &["audio/*"],
+        "document" | "documents" => &[
+            "application/pdf",
+            "application/msword",
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+            "application/vnd.oasis.opendocument.text",
+            "application/rtf",
+            "application/epub+zip",
+        ],
+        "email" | "emails" => &["message/rfc822"],
+        "presentation" | "presentations" => &[
+            "application/vnd.ms-powerpoint",
+            "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+            "application/vnd.oasis.opendocument.presentation",
+        ],
+        "spreadsheet" | "spreadsheets" => &[
+            "application/vnd.ms-excel",
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+            "application/vnd.oasis.opendocument.spreadsheet",
+            "text/csv",
+        ],
+        "code" | "source" => &[
+            "text/x-csrc",
+            "text/x-c++src",
+            "text/x-python",
+            "text/x-rust",
+            "text/x-java",
+            "text/x-go",
+            "text/x-script.python",
+            "application/javascript",
+            "application/x-shellscript",
+        ],
+        "text" => &["text/plain"],
+        "markdown" | "md" => &["text/markdown"],
+        "folder" | "directory" => &["inode/directory"],
+        "application" | "app" => &["application/x-desktop"],
+        _ => {
+            // Use as-is if it looks like a MIME type
+            return if kind.contains('/') {
+                vec![kind.to_string()]
+            } else {
+                Vec::new()
+            };
+        }
+    };
+    mimes.iter().map(|m| m.to_string()).collect()
+}
+
+/// Escape a value for a double-quoted SPARQL string literal.
+fn sparql_string(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', " ");
+    format!("\"{}\"", escaped)
+}
+
+fn tracker_sparql(spec: &SearchSpec) -> String {
+    let mut patterns = vec!["?f a nfo:FileDataObject ; nie:url ?url .".to_string()];
+    let mut filters = Vec::new();
+
+    if spec.content.is_some() || !spec.mimes.is_empty() {
+        patterns.push("?ie nie:isStoredAs ?f .".to_string());
+    }
+    if let Some(content) = &spec.content {
+        patterns.push(format!("?ie fts:match {} .", sparql_string(content)));
+    }
+    if !spec.mimes.is_empty() {
+        patterns.push("?ie nie:mimeType ?mime .".to_string());
+        let clauses: Vec<String> = spec
+            .mimes
+            .iter()
+            .map(|m| match m.strip_suffix('*') {
+                Some(prefix) => format!("STRSTARTS(?mime, {})", sparql_string(prefix)),
+                None => format!("?mime = {}", sparql_string(m)),
+            })
+            .collect();
+        filters.push(clauses.join(" || "));
+    }
+    if let Some(name) = &spec.name {
+        patterns.push("?f nfo:fileName ?name .".to_string());
+        filters.push(format!(
+            "CONTAINS(LCASE(?name), LCASE({}))",
+            sparql_string(name)
+        ));
+    }
+    if let Some(dir) = &spec.directory {
+        if let Ok(url) = url::Url::from_directory_path(dir) {
+            filters.push(format!("STRSTARTS(?url, {})", sparql_string(url.as_str())));
+        }
+    }
+    if let Some(since) = spec.modified_since {
+        patterns.push("?f nfo:fileLastModified ?mod .".to_string());
+        filters.push(format!(
+            "?mod >= \"{}T00:00:00Z\"^^xsd:dateTime",
+            since.format("%Y-%m-%d")
+        ));
+    }
+
+    let order = if spec.content.is_some() {
+        " ORDER BY DESC(fts:rank(?ie))"
+    } else if spec.modified_since.is_some() {
+        " ORDER BY DESC(?mod)"
+    } else {
+        ""
+    };
+    let filters: String = filters.iter().map(|f| format!(" FILTER({})", f)).collect();
+    format!(
+        "SELECT DISTINCT ?url WHERE {{ {}{} }}{} LIMIT {}",
+        patterns.join(" "),
+        filters,
+        order,
+        spec.limit
+    )
+}
+
+/// Quote a Recoll query term when it contains spaces or quotes.
+fn recoll_term(s: &str) -> String {
+    if s.chars().any(|c| c.is_whitespace() || c == '"') {
+        format!("\"{}\"", s.replace('"', ""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn recoll_query(spec: &SearchSpec) -> String {
+    let mut parts = Vec::new();
+    if let Some(content) = &spec.content {
+        parts.push(content.clone());
+    }
+    if let Some(name) = &spec.name {
+        parts.push(format!("filename:{}", recoll_term(&format!("*{}*", name))));
+    }
+    // Several mime: clauses are ORed by Recoll
+    for mime in &spec.mimes {
+        parts.push(format!("mime:{}", mime));
+    }
+    if let Some(dir) = &spec.directory {
+        parts.push(format!("dir:{}", recoll_term(&dir.to_string_lossy())));
+    }
+    if let Some(since) = spec.modified_since {
+        parts.push(format!("date:{}/", since.format("%Y-%m-%d")));
+    }
+    parts.join(" ")
+}
+
+/// Convert `file://` URLs from either backend to local paths.
+fn parse_file_urls(output: &str, limit: usize) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with("file://"))
+        .filter_map(|line| {
+            // recollq may append fields after the URL; tracker prints one URL per line
+            let raw = line.split_whitespace().next()?;
+            let url = url::Url::parse(raw).ok()?;
+            url.to_file_path()
+                .ok()
+                .map(|p| p.to_string_lossy().into_owned())
+        })
+        .take(limit)
+        .collect()
+}
+
+/// Parse `tracker3 info` output (`'prefix:property' = 'value'` lines).
+fn parse_tracker_info(output: &str) -> Map<String, Value> {
+    let mut metadata = Map::new();
+    for line in output.lines() {
+        let Some((key, value)) = line.split_once(" = ") else {
+            continue;
+        };
+        let key = key.trim().trim_matches('\'').to_string();
+        let value = Value::String(value.trim().trim_matches('\'').to_string());
+        if key.is_empty() {
+            continue;
+        }
+        // Repeated properties (e.g. rdf:type) become arrays
+        match metadata.get_mut(&key) {
+            Some(Value::Array(values)) => values.push(value),
+            Some(existing) => {
+                let first = existing.take();
+                *existing = Value::Array(vec![first, value]);
+            }
+            None => {
+                metadata.insert(key, value);
+            }
+        }
+    }
+    metadata
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()
+            .map(|home| home.join(rest))
+            .unwrap_or_else(|| PathBuf::from(path)),
+        None if path == "~" => dirs::home_dir().unwrap_or_else(|| PathBuf::from(path)),
+        None => PathBuf::from(path),
+    }
+}
+
+fn find_in_path(binary: &str) -> Option<PathBuf> {
+    let paths = std::env::var_os("PATH")?;
+    std::env::split_paths(&paths)
+        .map(|dir| dir.join(binary))
+        .find(|candidate| candidate.is_file())
+}
+
+impl TrackerConnector {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Pick the backend: explicit choice, else Tracker when installed, else Recoll.
+    fn backend(&self, requested: Option<&str>) -> Result<Backend, ConnectorError> {
+        let wanted = match requested.unwrap_or("auto") {
+            "auto" => vec![Backend::Tracker, Backend::Recoll],
+            "tracker" => vec![Backend::Tracker],
+            "recoll" => vec![Backend::Recoll],
+            other => {
+                return Err(ConnectorError::InvalidInput(format!(
+                    "Invalid 'backend': {} (use auto, tracker or recoll)",
+                    other
+                )))
+            }
+        };
+        wanted
+            .into_iter()
+            .find(|b| find_in_path(b.binary()).is_some())
+            .ok_or_else(|| {
+                ConnectorError::Other(
+                    "No desktop search index found. Install GNOME Tracker 3 (tracker3, with the tracker-miners files indexer) or Recoll (recollq) and let it index your files."
+                        .to_string(),
+                )
+            })
+    }
+
+    async fn run(&self, binary: &str, args: &[&str]) -> Result<String, ConnectorError> {
+        use tokio::process::Command;
+
+        let output = Command::new(binary)
+            .args(args)
+            .output()
+            .await
+            .map_err(|e| ConnectorError::Other(format!("Failed to run {}: {}", binary, e)))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(ConnectorError::Other(format!(
+                "{} failed: {}",
+                binary,
+                stderr.trim()
+            )));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Run a native query (SPARQL for Tracker, query language for Recoll)
+    async fn run_query(
+        &self,
+        backend: Backend,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<String>, ConnectorError> {
+        let output = match backend {
+            Backend::Tracker => {
+                self.run(
+                    "tracker3",
+                    &[
+                        "sparql",
+                        "--dbus-service",
+                        TRACKER_SERVICE,
+                        "--query",
+                        query,
+                    ],
+                )
+                .await?
+            }
+            Backend::Recoll => {
+                let count = limit.to_string();
+                self.run("recollq", &["-b", "-n", &count, query]).await?
+            }
+        };
+        Ok(parse_file_urls(&output, limit))
+    }
+
+    async fn search_spec(
+        &self,
+        backend: Backend,
+        spec: &SearchSpec,
+    ) -> Result<(String, Vec<String>), ConnectorError> {
+        let query = match backend {
+            Backend::Tracker => tracker_sparql(spec),
+            Backend::Recoll => recoll_query(spec),
+        };
+        let files = self.run_query(backend, &query, spec.limit).await?;
+        Ok((query, files))
+    }
+
+    /// File-system metadata plus the index's attributes when Tracker is available
+    async fn get_file_metadata(&self, path: &Path) -> Result<Value, ConnectorError> {
+        let meta = std::fs::metadata(path).map_err(|e| {
+            ConnectorError::InvalidInput(format!("Cannot read {}: {}", path.display(), e))
+        })?;
+        let modified = meta
+            .modified()
+            .ok()
+            .map(|t| chrono::DateTime::<chrono::Utc>::from(t).to_rfc3339());
+
+        let mut payload = json!({
+            "size": meta.len(),
+            "is_dir": meta.is_dir(),
+            "modified": modified,
+        });
+        if find_in_path("tracker3").is_some() {
+            let path_str = path.to_string_lossy();
+            if let Ok(output) = self.run("tracker3", &["info", &path_str]).await {
+                payload["index"] = Value::Object(parse_tracker_info(&output));
+            }
+        }
+        Ok(payload)
+    }
+}
+
+#[async_trait]
+impl crate::Connector for TrackerConnector {
+    fn name(&self) -> &'static str {
+        "tracker"
+    }
+
+    fn description(&self) -> &'static str {
+        "Linux desktop search connector (GNOME Tracker or Recoll). Search files by content, name, \
+         kind/MIME type, or modification date. Only available on Linux."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: None,
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        Ok(AuthDetails::new())
+    }
+
+    async fn set_auth_details(&mut self, _details: AuthDetails) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.backend(None).map(|_| ())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema { fields: vec![] }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: Some("Linux Desktop Search".to_string()),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Linux desktop search connector, the counterpart of Spotlight on macOS. Use search \
+                 with mode=content for full-text search, mode=name for file names, or mode=kind \
+                 (or mime) for type-specific searches."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, _name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            Tool {
+                name: Cow::Borrowed("search"),
+                title: Some("Search Desktop Index".to_string()),
+                description: Some(Cow::Borrowed(
+                    "Search the Tracker/Recoll index by content/name/kind/recent/raw. Use mode to choose \
+the search type. Example: mode=\"content\" query=\"invoice\" directory=\"~/Documents\" mime=\"application/pdf\".",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "mode": {
+                                "type": "string",
+                                "enum": ["content", "name", "kind", "recent", "raw"],
+                                "description": "Search mode. Use 'content' for full-text, 'name' for file names, 'kind' for file types, 'recent' for modified files, 'raw' for native syntax (SPARQL selecting ?url for Tracker, Recoll query language).",
+                                "default": "content"
+                            },
+                            "query": {
+                                "type": "string",
+                                "description": "Search query text. Required for mode=content/name/raw."
+                            },
+                            "directory": {
+                                "type": "string",
+                                "description": "Optional: limit search to this directory"
+                            },
+                            "kind": {
+                                "type": "string",
+                                "description": "File type filter for mode=content/name/recent OR required file type for mode=kind.",
+                                "enum": [
+                                    "pdf",
+                                    "image",
+                                    "video",
+                                    "audio",
+                                    "document",
+                                    "email",
+                                    "code",
+                                    "text",
+                                    "markdown",
+                                    "spreadsheet",
+                                    "presentation",
+                                    "application",
+                                    "folder"
+                                ]
+                            },
+                            "mime": {
+                                "type": "array",
+                                "items": {"type": "string"},
+                                "description": "MIME type filter, e.g. [\"application/pdf\", \"image/*\"]. Combined with kind."
+                            },
+                            "days": {
+                                "type": "integer",
+                                "description": "Only for mode=recent: modified within N days (default: 7).",
+                                "default": 7
+                            },
+                            "backend": {
+                                "type": "string",
+                                "enum": ["auto", "tracker", "recoll"],
+                                "description": "Index to query (default: auto - Tracker if installed, else Recoll)."
+                            },
+                            "limit": {
+                                "type": "integer",
+                                "description": "Maximum number of results (default: 50)",
+                                "default": 50
+                            }
+                        },
+                        "required": []
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_metadata"),
+                title: Some("Get File Metadata".to_string()),
+                description: Some(Cow::Borrowed(
+                    "Get metadata for a file path: size and modification time, plus Tracker's \
+indexed attributes when Tracker is installed.",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "path": {
+                                "type": "string",
+                                "description": "Full path to the file"
+                            }
+                        },
+                        "required": ["path"]
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        let name = request.name.as_ref();
+        let args = request.arguments.unwrap_or_default();
+
+        match name {
+            "search" => {
+                let mode = args
+                    .get("mode")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("content");
+                let backend = self.backend(args.get("backend").and_then(|v| v.as_str()))?;
+                let query = args.get("query").and_then(|v| v.as_str());
+                let directory = args.get("directory").and_then(|v| v.as_str());
+                let kind = args.get("kind").and_then(|v| v.as_str());
+                let limit = args
+                    .get("limit")
+                    .and_then(|v| v.as_u64())
+                    .map(|n| n as usize)
+                    .unwrap_or(50)
+                    .max(1);
+
+                let mut spec = SearchSpec {
+                    directory: directory.map(expand_home),
+                    limit,
+                    ..Default::default()
+                };
+                if let Some(kind) = kind {
+                    let mimes = kind_to_mimes(kind);
+                    if mimes.is_empty() {
+                        return Err(ConnectorError::InvalidInput(format!(
+                            "Unknown 'kind': {} (use a listed kind or a MIME type)",
+                            kind
+                        )));
+                    }
+                    spec.mimes.extend(mimes);
+                }
+                if let Some(mimes) = args.get("mime") {
+                    match mimes {
+                        Value::String(m) => spec.mimes.push(m.clone()),
+                        Value::Array(ms) => spec
+                            .mimes
+                            .extend(ms.iter().filter_map(|m| m.as_str().map(str::to_string))),
+                        _ => {}
+                    }
+                }
+
+                let require_query = |mode: &str| {
+                    query.map(str::to_string).ok_or_else(|| {
+                        ConnectorError::InvalidInput(format!("Missing 'query' for mode={}", mode))
+                    })
+                };
+                let days = args.get("days").and_then(|v| v.as_u64()).unwrap_or(7) as i64;
+
+                let (native_query, files) = match mode {
+                    "content" => {
+                        spec.content = Some(require_query("content")?);
+                        self.search_spec(backend, &spec).await?
+                    }
+                    "name" => {
+                        spec.name = Some(
+                            query
+                                .or_else(|| args.get("name").and_then(|v| v.as_str()))
+                                .map(str::to_string)
+                                .ok_or_else(|| {
+                                    ConnectorError::InvalidInput(
+                                        "Missing 'query' for mode=name".into(),
+                                    )
+                                })?,
+                        );
+                        self.search_spec(backend, &spec).await?
+                    }
+                    "kind" => {
+                        if spec.mimes.is_empty() {
+                            return Err(ConnectorError::InvalidInput(
+                                "Missing 'kind' (or 'mime') for mode=kind".into(),
+                            ));
+                        }
+                        self.search_spec(backend, &spec).await?
+                    }
+                    "recent" => {
+                        spec.modified_since =
+                            Some(chrono::Local::now().date_naive() - chrono::Duration::days(days));
+                        self.search_spec(backend, &spec).await?
+                    }
+                    "raw" => {
+                        let raw = require_query("raw")?;
+                        let files = self.run_query(backend, &raw, limit).await?;
+                        (raw, files)
+                    }
+                    _ => {
+                        return Err(ConnectorError::InvalidInput(format!(
+                            "Invalid 'mode': {}",
+                            mode
+                        )));
+                    }
+                };
+
+                let payload = json!({
+                    "mode": mode,
+                    "query": query,
+                    "backend": backend.name(),
+                    "native_query": native_query,
+                    "directory": directory,
+                    "count": files.len(),
+                    "files": files
+                });
+
+                structured_result_with_text(&payload, None)
+            }
+
+            "get_metadata" => {
+                let path = args
+                    .get("path")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| ConnectorError::InvalidInput("Missing 'path'".to_string()))?;
+
+                let metadata = self.get_file_metadata(&expand_home(path)).await?;
+
+                let payload = json!({
+                    "path": path,
+                    "metadata": metadata
+                });
+
+                structured_result_with_text(&payload, None)
+            }
+
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_tracker_sparql() {
+        let spec = SearchSpec {
+            content: Some("quarterly \"report\"".to_string()),
+            mimes: kind_to_mimes("image"),
+            directory: Some(PathBuf::from("/home/me/My Docs")),
+            limit: 10,
+            ..Default::default()
+        };
+        let q = tracker_sparql(&spec);
+        assert!(q.contains("?ie fts:match \"quarterly \\\"report\\\"\" ."));
+        assert!(q.contains("FILTER(STRSTARTS(?mime, \"image/\"))"));
+        assert!(q.contains("STRSTARTS(?url, \"file:///home/me/My%20Docs/\")"));
+        assert!(q.ends_with("ORDER BY DESC(fts:rank(?ie)) LIMIT 10"));
+    }
+
+    #[test]
+    fn builds_recoll_query() {
+        let spec = SearchSpec {
+            name: Some("budget".to_string()),
+            mimes: kind_to_mimes("pdf"),
+            directory: Some(PathBuf::from("/home/me/My Docs")),
+            modified_since: chrono::NaiveDate::from_ymd_opt(2025, 1, 2),
+            limit: 5,
+            ..Default::default()
+        };
+        assert_eq!(
+            recoll_query(&spec),
+            "filename:*budget* mime:application/pdf dir:\"/home/me/My Docs\" date:2025-01-02/"
+        );
+    }
+
+    #[test]
+    fn parses_results_and_info() {
+        let out = "Results:\n  file:///home/me/a%20b.pdf\n  file:///home/me/c.txt\n";
+        assert_eq!(parse_file_urls(out, 1), vec!["/home/me/a b.pdf"]);
+        assert_eq!(parse_file_urls(out, 10).len(), 2);
+
+        let info = "Querying information for entity: 'file:///x'\n  'rdf:type' = 'nfo:FileDataObject'\n  'rdf:type' = 'nie:DataObject'\n  'nfo:fileSize' = '42'\n";
+        let meta = parse_tracker_info(info);
+        assert_eq!(meta["nfo:fileSize"], "42");
+        assert_eq!(meta["rdf:type"].as_array().map(Vec::len), Some(2));
+    }
+
+    #[test]
+    fn maps_kinds_to_mimes() {
+        assert_eq!(kind_to_mimes("PDF"), vec!["application/pdf"]);
+        assert_eq!(kind_to_mimes("text/html"), vec!["text/html"]);
+        assert!(kind_to_mimes("nonsense").is_empty());
+    }
+}
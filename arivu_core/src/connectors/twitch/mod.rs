@@ -0,0 +1,772 @@
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+const HELIX_BASE: &str = "https://api.twitch.tv/helix";
+const TOKEN_URL: &str = "https://id.twitch.tv/oauth2/token";
+const GQL_URL: &str = "https://gql.twitch.tv/gql";
+/// Public client id used by the twitch.tv web player; chat replay is only exposed over GQL.
+const GQL_CLIENT_ID: &str = "kimne78kx3ncx6brgo4mv6wki5h1ko";
+const VIDEO_COMMENTS_HASH: &str =
+    "b70a3591ff0f4e0313d126c6a1502d79a1c02baebb288227c582044aa76adf6a";
+
+#[derive(Debug, Deserialize)]
+struct SearchChannelsArgs {
+    query: String,
+    #[serde(default)]
+    live_only: Option<bool>,
+    #[serde(default)]
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StreamsArgs {
+    #[serde(default)]
+    channels: Option<String>,
+    #[serde(default)]
+    game: Option<String>,
+    #[serde(default)]
+    language: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VideosArgs {
+    channel: String,
+    #[serde(default)]
+    video_type: Option<String>,
+    #[serde(default)]
+    sort: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClipsArgs {
+    #[serde(default)]
+    channel: Option<String>,
+    #[serde(default)]
+    game: Option<String>,
+    #[serde(default)]
+    started_at: Option<String>,
+    #[serde(default)]
+    ended_at: Option<String>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatReplayArgs {
+    video_id: String,
+    #[serde(default)]
+    offset_seconds: Option<u64>,
+    #[serde(default)]
+    cursor: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+pub struct TwitchConnector {
+    client: Client,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    /// Cached app access token and its expiry.
+    token: Arc<Mutex<Option<(String, Instant)>>>,
+}
+
+impl TwitchConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+        let client_id = auth
+            .get("client_id")
+            .cloned()
+            .or_else(|| std::env::var("TWITCH_CLIENT_ID").ok())
+            .filter(|v| !v.trim().is_empty());
+        let client_secret = auth
+            .get("client_secret")
+            .cloned()
+            .or_else(|| std::env::var("TWITCH_CLIENT_SECRET").ok())
+            .filter(|v| !v.trim().is_empty());
+
+        Ok(Self {
+            client,
+            client_id,
+            client_secret,
+            token: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    fn credentials(&self) -> Result<(&str, &str), ConnectorError> {
+        match (&self.client_id, &self.client_secret) {
+            (Some(id), Some(secret)) => Ok((id.as_str(), secret.as_str())),
+            _ => Err(ConnectorError::Authentication(
+                "Twitch client_id and client_secret not configured: arivu setup twitch".to_string(),
+            )),
+        }
+    }
+
+    /// App access token via the client-credentials grant, cached until shortly before expiry.
+    async fn app_token(&self) -> Result<String, ConnectorError> {
+        let mut cached = self.token.lock().await;
+        if let Some((token, expires)) = cached.as_ref() {
+            if Instant::now() < *expires {
+                return Ok(token.clone());
+            }
+        }
+
+        let (client_id, client_secret) = self.credentials()?;
+        let response = self
+            .client
+            .post(TOKEN_URL)
+            .form(&[
+                ("client_id", client_id),
+                ("client_secret", client_secret),
+                ("grant_type", "client_credentials"),
+            ])
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+
+        if !response.status().is_success() {
+            return Err(ConnectorError::Authentication(format!(
+                "Twitch token request failed with status: {}",
+                response.status()
+            )));
+        }
+
+        let body: Value = response.json().await.map_err(ConnectorError::HttpRequest)?;
+        let token = body["access_token"]
+            .as_str()
+            .ok_or_else(|| {
+                ConnectorError::Authentication("Twitch returned no access token".to_string())
+            })?
+            .to_string();
+        let ttl = body["expires_in"]
+            .as_u64()
+            .unwrap_or(3600)
+            .saturating_sub(60);
+        *cached = Some((token.clone(), Instant::now() + Duration::from_secs(ttl)));
+
+        Ok(token)
+    }
+
+    async fn helix(&self, path: &str, params: &[(&str, String)]) -> Result<Value, ConnectorError> {
+        let token = self.app_token().await?;
+        let (client_id, _) = self.credentials()?;
+
+        let response = self
+            .client
+            .get(format!("{}/{}", HELIX_BASE, path))
+            .query(params)
+            .header("Client-Id", client_id)
+            .bearer_auth(&token)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+
+        match response.status() {
+            StatusCode::NOT_FOUND => return Err(ConnectorError::ResourceNotFound),
+            StatusCode::UNAUTHORIZED => {
+                // Token revoked or expired early; drop it so the next call re-authenticates.
+                *self.token.lock().await = None;
+                return Err(ConnectorError::Authentication(
+                    "Twitch rejected the app access token".to_string(),
+                ));
+            }
+            s if !s.is_success() => {
+                return Err(ConnectorError::Other(format!(
+                    "Twitch API returned error status: {}",
+                    s
+                )))
+            }
+            _ => {}
+        }
+
+        response.json().await.map_err(ConnectorError::HttpRequest)
+    }
+
+    async fn user_id(&self, login: &str) -> Result<String, ConnectorError> {
+        let login = channel_login(login);
+        let body = self.helix("users", &[("login", login)]).await?;
+        body["data"][0]["id"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or(ConnectorError::ResourceNotFound)
+    }
+
+    async fn game_id(&self, name: &str) -> Result<String, ConnectorError> {
+        let body = self.helix("games", &[("name", name.to_string())]).await?;
+        body["data"][0]["id"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or(ConnectorError::ResourceNotFound)
+    }
+
+    async fn chat_page(
+        &self,
+        video_id: &str,
+        offset: Option<u64>,
+        cursor: Option<&str>,
+    ) -> Result<Value, ConnectorError> {
+        let mut variables = json!({ "videoID": video_id });
+        match cursor {
+            Some(c) => variables["cursor"] = json!(c),
+            None => variables["contentOffsetSeconds"] = json!(offset.unwrap_or(0)),
+        }
+        let payload = json!([{
+            "operationName": "VideoCommentsByOffsetOrCursor",
+            "variables": variables,
+            "extensions": {
+                "persistedQuery": { "version": 1, "sha256Hash": VIDEO_COMMENTS_HASH }
+            }
+        }]);
+
+        let response = self
+            .client
+            .post(GQL_URL)
+            .header("Client-Id", GQL_CLIENT_ID)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+
+        if !response.status().is_success() {
+            return Err(ConnectorError::Other(format!(
+                "Twitch chat replay request failed with status: {}",
+                response.status()
+            )));
+        }
+
+        let body: Value = response.json().await.map_err(ConnectorError::HttpRequest)?;
+        let comments = &body[0]["data"]["video"]["comments"];
+        if comments.is_null() {
+            if body[0]["data"]["video"].is_null() {
+                return Err(ConnectorError::ResourceNotFound);
+            }
+            return Err(ConnectorError::Other(
+                "Twitch did not return chat replay for this video (it may be subscriber-only or chat was disabled)"
+                    .to_string(),
+            ));
+        }
+        Ok(comments.clone())
+    }
+}
+
+/// Accept `name`, `@name` or a twitch.tv channel URL.
+fn channel_login(input: &str) -> String {
+    let trimmed = input.trim().trim_start_matches('@');
+    let path = trimmed
+        .split("twitch.tv/")
+        .nth(1)
+        .unwrap_or(trimmed)
+        .split(['/', '?'])
+        .next()
+        .unwrap_or_default();
+    path.to_lowercase()
+}
+
+/// Accept a numeric id, `v123`, or a twitch.tv/videos URL.
+fn video_id(input: &str) -> Option<String> {
+    let trimmed = input.trim();
+    let candidate = trimmed
+        .split("/videos/")
+        .nth(1)
+        .unwrap_or(trimmed)
+        .split(['?', '/'])
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches('v');
+    (!candidate.is_empty() && candidate.chars().all(|c| c.is_ascii_digit()))
+        .then(|| candidate.to_string())
+}
+
+fn format_chat_message(node: &Value) -> Value {
+    let text: String = node["message"]["fragments"]
+        .as_array()
+        .map(|fragments| {
+            fragments
+                .iter()
+                .filter_map(|f| f["text"].as_str())
+                .collect::<String>()
+        })
+        .unwrap_or_default();
+    json!({
+        "offset_seconds": node["contentOffsetSeconds"],
+        "created_at": node["createdAt"],
+        "user": node["commenter"]["displayName"],
+        "login": node["commenter"]["login"],
+        "message": text,
+    })
+}
+
+fn format_video(v: &Value) -> Value {
+    json!({
+        "id": v["id"],
+        "title": v["title"],
+        "channel": v["user_login"],
+        "type": v["type"],
+        "duration": v["duration"],
+        "views": v["view_count"],
+        "created_at": v["created_at"],
+        "url": v["url"],
+    })
+}
+
+fn format_clip(c: &Value) -> Value {
+    json!({
+        "id": c["id"],
+        "title": c["title"],
+        "channel": c["broadcaster_name"],
+        "creator": c["creator_name"],
+        "video_id": c["video_id"],
+        "vod_offset": c["vod_offset"],
+        "duration": c["duration"],
+        "views": c["view_count"],
+        "created_at": c["created_at"],
+        "url": c["url"],
+    })
+}
+
+#[async_trait]
+impl Connector for TwitchConnector {
+    fn name(&self) -> &'static str {
+        "twitch"
+    }
+
+    fn description(&self) -> &'static str {
+        "Twitch channels, live streams, VODs, clips and VOD chat replay."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        let mut auth = AuthDetails::new();
+        if let Some(id) = &self.client_id {
+            auth.insert("client_id".to_string(), id.clone());
+        }
+        if let Some(secret) = &self.client_secret {
+            auth.insert("client_secret".to_string(), secret.clone());
+        }
+        Ok(auth)
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        *self = Self::new(details).await?;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.app_token().await.map(|_| ())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema {
+            fields: vec![
+                Field {
+                    name: "client_id".to_string(),
+                    label: "Client ID".to_string(),
+                    field_type: FieldType::Text,
+                    required: true,
+                    description: Some(
+                        "Twitch application client ID (or set TWITCH_CLIENT_ID).".to_string(),
+                    ),
+                    options: None,
+                },
+                Field {
+                    name: "client_secret".to_string(),
+                    label: "Client Secret".to_string(),
+                    field_type: FieldType::Secret,
+                    required: true,
+                    description: Some(
+                        "Twitch application client secret (or set TWITCH_CLIENT_SECRET)."
+                            .to_string(),
+                    ),
+                    options: None,
+                },
+            ],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Channel, stream, VOD and clip tools use the Helix API and need client_id/client_secret. \
+`get_chat_replay` works without credentials; page with the returned cursor."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            tool(
+                "search_channels",
+                "Search channels by name or stream title. Example: query=\"speedrun\" live_only=true.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Search text" },
+                        "live_only": { "type": "boolean", "description": "Only channels that are live (default: false)" },
+                        "limit": { "type": "integer", "description": "Max results (default: 20, max: 100)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "get_streams",
+                "Live streams, by channel and/or game, sorted by viewers. Example: game=\"Chess\" language=\"en\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "channels": { "type": "string", "description": "Comma-separated channel logins" },
+                        "game": { "type": "string", "description": "Game/category name" },
+                        "language": { "type": "string", "description": "Stream language (ISO 639-1)" },
+                        "limit": { "type": "integer", "description": "Max results (default: 20, max: 100)" }
+                    }
+                }),
+            ),
+            tool(
+                "list_videos",
+                "List a channel's VODs. Example: channel=\"gamesdonequick\" video_type=\"archive\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "channel": { "type": "string", "description": "Channel login or URL" },
+                        "video_type": { "type": "string", "enum": ["all", "archive", "highlight", "upload"], "description": "Video type (default: all)" },
+                        "sort": { "type": "string", "enum": ["time", "trending", "views"], "description": "Sort order (default: time)" },
+                        "limit": { "type": "integer", "description": "Max results (default: 20, max: 100)" },
+                        "cursor": { "type": "string", "description": "Pagination cursor from a previous call" }
+                    },
+                    "required": ["channel"]
+                }),
+            ),
+            tool(
+                "search_clips",
+                "Top clips for a channel or game, optionally within a date range. Example: channel=\"xqc\" started_at=\"2024-01-01T00:00:00Z\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "channel": { "type": "string", "description": "Channel login or URL" },
+                        "game": { "type": "string", "description": "Game/category name (alternative to channel)" },
+                        "started_at": { "type": "string", "description": "RFC 3339 start time" },
+                        "ended_at": { "type": "string", "description": "RFC 3339 end time" },
+                        "limit": { "type": "integer", "description": "Max results (default: 20, max: 100)" },
+                        "cursor": { "type": "string", "description": "Pagination cursor from a previous call" }
+                    }
+                }),
+            ),
+            tool(
+                "get_chat_replay",
+                "Chat messages replayed for a VOD, from an offset or cursor. Example: video_id=\"1234567890\" offset_seconds=3600.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "video_id": { "type": "string", "description": "VOD id or twitch.tv/videos URL" },
+                        "offset_seconds": { "type": "integer", "description": "Start offset in seconds (default: 0)" },
+                        "cursor": { "type": "string", "description": "Pagination cursor from a previous call" },
+                        "limit": { "type": "integer", "description": "Max messages (default: 100, max: 1000)" }
+                    },
+                    "required": ["video_id"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "search_channels" => {
+                let args: SearchChannelsArgs = parse_args(request.arguments)?;
+                let params = [
+                    ("query", args.query.clone()),
+                    ("live_only", args.live_only.unwrap_or(false).to_string()),
+                    ("first", args.limit.unwrap_or(20).clamp(1, 100).to_string()),
+                ];
+                let body = self.helix("search/channels", &params).await?;
+                let channels: Vec<Value> = body["data"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|c| {
+                        json!({
+                            "id": c["id"],
+                            "login": c["broadcaster_login"],
+                            "name": c["display_name"],
+                            "is_live": c["is_live"],
+                            "title": c["title"],
+                            "game": c["game_name"],
+                            "language": c["broadcaster_language"],
+                            "started_at": c["started_at"],
+                            "url": c["broadcaster_login"].as_str().map(|l| format!("https://www.twitch.tv/{}", l)),
+                        })
+                    })
+                    .collect();
+
+                let data = json!({
+                    "query": args.query,
+                    "count": channels.len(),
+                    "channels": channels,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_streams" => {
+                let args: StreamsArgs = parse_args(request.arguments)?;
+                let mut params: Vec<(&str, String)> =
+                    vec![("first", args.limit.unwrap_or(20).clamp(1, 100).to_string())];
+                for login in args.channels.iter().flat_map(|c| c.split(',')) {
+                    let login = channel_login(login);
+                    if !login.is_empty() {
+                        params.push(("user_login", login));
+                    }
+                }
+                if let Some(game) = &args.game {
+                    params.push(("game_id", self.game_id(game).await?));
+                }
+                if let Some(language) = args.language {
+                    params.push(("language", language));
+                }
+
+                let body = self.helix("streams", &params).await?;
+                let streams: Vec<Value> = body["data"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|s| {
+                        json!({
+                            "channel": s["user_login"],
+                            "name": s["user_name"],
+                            "title": s["title"],
+                            "game": s["game_name"],
+                            "viewers": s["viewer_count"],
+                            "language": s["language"],
+                            "started_at": s["started_at"],
+                            "url": s["user_login"].as_str().map(|l| format!("https://www.twitch.tv/{}", l)),
+                        })
+                    })
+                    .collect();
+
+                let data = json!({
+                    "count": streams.len(),
+                    "streams": streams,
+                    "cursor": body["pagination"]["cursor"],
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "list_videos" => {
+                let args: VideosArgs = parse_args(request.arguments)?;
+                let user_id = self.user_id(&args.channel).await?;
+                let mut params = vec![
+                    ("user_id", user_id),
+                    ("type", args.video_type.unwrap_or_else(|| "all".to_string())),
+                    ("sort", args.sort.unwrap_or_else(|| "time".to_string())),
+                    ("first", args.limit.unwrap_or(20).clamp(1, 100).to_string()),
+                ];
+                if let Some(cursor) = args.cursor {
+                    params.push(("after", cursor));
+                }
+
+                let body = self.helix("videos", &params).await?;
+                let videos: Vec<Value> = body["data"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(format_video)
+                    .collect();
+
+                let data = json!({
+                    "channel": channel_login(&args.channel),
+                    "count": videos.len(),
+                    "videos": videos,
+                    "cursor": body["pagination"]["cursor"],
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "search_clips" => {
+                let args: ClipsArgs = parse_args(request.arguments)?;
+                let mut params =
+                    vec![("first", args.limit.unwrap_or(20).clamp(1, 100).to_string())];
+                match (&args.channel, &args.game) {
+                    (Some(channel), _) => {
+                        params.push(("broadcaster_id", self.user_id(channel).await?))
+                    }
+                    (None, Some(game)) => params.push(("game_id", self.game_id(game).await?)),
+                    (None, None) => {
+                        return Err(ConnectorError::InvalidParams(
+                            "Provide either 'channel' or 'game'".to_string(),
+                        ))
+                    }
+                }
+                if let Some(started_at) = args.started_at {
+                    params.push(("started_at", started_at));
+                }
+                if let Some(ended_at) = args.ended_at {
+                    params.push(("ended_at", ended_at));
+                }
+                if let Some(cursor) = args.cursor {
+                    params.push(("after", cursor));
+                }
+
+                let body = self.helix("clips", &params).await?;
+                let clips: Vec<Value> = body["data"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(format_clip)
+                    .collect();
+
+                let data = json!({
+                    "count": clips.len(),
+                    "clips": clips,
+                    "cursor": body["pagination"]["cursor"],
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_chat_replay" => {
+                let args: ChatReplayArgs = parse_args(request.arguments)?;
+                let id = video_id(&args.video_id).ok_or_else(|| {
+                    ConnectorError::InvalidParams(format!(
+                        "Invalid Twitch video id: {}",
+                        args.video_id
+                    ))
+                })?;
+                let limit = args.limit.unwrap_or(100).clamp(1, 1000);
+
+                let mut messages: Vec<Value> = Vec::new();
+                let mut cursor = args.cursor.clone();
+                let mut has_more = true;
+                while messages.len() < limit && has_more {
+                    let page = self
+                        .chat_page(&id, args.offset_seconds, cursor.as_deref())
+                        .await?;
+                    let edges = page["edges"].as_array().cloned().unwrap_or_default();
+                    for edge in &edges {
+                        messages.push(format_chat_message(&edge["node"]));
+                    }
+                    cursor = edges
+                        .last()
+                        .and_then(|e| e["cursor"].as_str())
+                        .map(str::to_string);
+                    has_more = page["pageInfo"]["hasNextPage"].as_bool().unwrap_or(false)
+                        && cursor.is_some();
+                }
+                messages.truncate(limit);
+
+                let data = json!({
+                    "video_id": id,
+                    "count": messages.len(),
+                    "messages": messages,
+                    "cursor": if has_more { cursor } else { None },
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_channel_and_video_ids() {
+        assert_eq!(channel_login("@Shroud"), "shroud");
+        assert_eq!(channel_login("https://www.twitch.tv/xQc/videos"), "xqc");
+        assert_eq!(
+            video_id("https://www.twitch.tv/videos/1234567?t=1h"),
+            Some("1234567".to_string())
+        );
+        assert_eq!(video_id("v987"), Some("987".to_string()));
+        assert_eq!(video_id("not-a-video"), None);
+    }
+
+    #[test]
+    fn joins_chat_fragments() {
+        let node = json!({
+            "contentOffsetSeconds": 42,
+            "commenter": { "displayName": "Viewer", "login": "viewer" },
+            "message": { "fragments": [{ "text": "hello " }, { "text": "Kappa" }] }
+        });
+        let msg = format_chat_message(&node);
+        assert_eq!(msg["message"], "hello Kappa");
+        assert_eq!(msg["offset_seconds"], 42);
+    }
+}
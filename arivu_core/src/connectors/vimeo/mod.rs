@@ -0,0 +1,575 @@
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::utils::{parse_args, structured_result_with_text, subtitles_to_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+const API_BASE: &str = "https://api.vimeo.com";
+const OEMBED_URL: &str = "https://vimeo.com/api/oembed.json";
+const API_ACCEPT: &str = "application/vnd.vimeo.*+json;version=3.4";
+const VIDEO_FIELDS: &str = "uri,name,description,link,duration,created_time,release_time,language,\
+user.name,user.link,stats.plays,metadata.connections.likes.total,pictures.base_link,tags.name";
+
+#[derive(Debug, Deserialize, Default, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+enum ResponseFormat {
+    #[default]
+    Concise,
+    Detailed,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetArgs {
+    video_id: String,
+    #[serde(default)]
+    language: Option<String>,
+    #[serde(default)]
+    response_format: ResponseFormat,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchArgs {
+    query: String,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    sort: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CaptionsArgs {
+    video_id: String,
+    #[serde(default)]
+    language: Option<String>,
+    #[serde(default)]
+    format: Option<String>,
+}
+
+pub struct VimeoConnector {
+    client: Client,
+    access_token: Option<String>,
+}
+
+impl VimeoConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+        let access_token = auth
+            .get("api_key")
+            .or_else(|| auth.get("token"))
+            .cloned()
+            .or_else(|| std::env::var("VIMEO_ACCESS_TOKEN").ok())
+            .filter(|t| !t.trim().is_empty());
+
+        Ok(Self {
+            client,
+            access_token,
+        })
+    }
+
+    fn token(&self) -> Result<&str, ConnectorError> {
+        self.access_token.as_deref().ok_or_else(|| {
+            ConnectorError::Authentication(
+                "Vimeo access token not configured: arivu config set vimeo --value <token>"
+                    .to_string(),
+            )
+        })
+    }
+
+    async fn api(&self, path: &str, params: &[(&str, String)]) -> Result<Value, ConnectorError> {
+        let token = self.token()?;
+        let response = self
+            .client
+            .get(format!("{}{}", API_BASE, path))
+            .query(params)
+            .header("Accept", API_ACCEPT)
+            .bearer_auth(token)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+
+        match response.status() {
+            StatusCode::NOT_FOUND => return Err(ConnectorError::ResourceNotFound),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                return Err(ConnectorError::Authentication(
+                    "Vimeo rejected the access token (check the token and its 'public' scope)"
+                        .to_string(),
+                ))
+            }
+            s if !s.is_success() => {
+                return Err(ConnectorError::Other(format!(
+                    "Vimeo API returned error status: {}",
+                    s
+                )))
+            }
+            _ => {}
+        }
+
+        response.json().await.map_err(ConnectorError::HttpRequest)
+    }
+
+    /// Public metadata via oEmbed; used when no access token is configured.
+    async fn oembed(&self, video_id: &str) -> Result<Value, ConnectorError> {
+        let url = match video_id.split_once(':') {
+            Some((id, hash)) => format!("https://vimeo.com/{}/{}", id, hash),
+            None => format!("https://vimeo.com/{}", video_id),
+        };
+        let response = self
+            .client
+            .get(OEMBED_URL)
+            .query(&[("url", url)])
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+
+        match response.status() {
+            StatusCode::NOT_FOUND | StatusCode::FORBIDDEN => Err(ConnectorError::ResourceNotFound),
+            s if !s.is_success() => Err(ConnectorError::Other(format!(
+                "Vimeo oEmbed returned error status: {}",
+                s
+            ))),
+            _ => response.json().await.map_err(ConnectorError::HttpRequest),
+        }
+    }
+
+    async fn text_tracks(&self, video_id: &str) -> Result<Vec<Value>, ConnectorError> {
+        let body = self
+            .api(&format!("/videos/{}/texttracks", video_id), &[])
+            .await?;
+        Ok(body["data"].as_array().cloned().unwrap_or_default())
+    }
+
+    async fn download_track(&self, link: &str) -> Result<String, ConnectorError> {
+        let response = self
+            .client
+            .get(link)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+        if !response.status().is_success() {
+            return Err(ConnectorError::Other(format!(
+                "Vimeo text track download failed with status: {}",
+                response.status()
+            )));
+        }
+        response.text().await.map_err(ConnectorError::HttpRequest)
+    }
+}
+
+/// Extract the numeric id (plus `:hash` for unlisted videos) from an id or vimeo.com URL.
+fn extract_video_id(input: &str) -> Option<String> {
+    let trimmed = input.trim();
+    let path = trimmed
+        .split_once("vimeo.com/")
+        .map(|(_, rest)| rest)
+        .unwrap_or(trimmed)
+        .split(['?', '#'])
+        .next()
+        .unwrap_or_default();
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    let pos = segments
+        .iter()
+        .position(|s| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()))?;
+    let id = segments[pos];
+    match segments.get(pos + 1) {
+        Some(hash) if hash.chars().all(|c| c.is_ascii_alphanumeric()) => {
+            Some(format!("{}:{}", id, hash))
+        }
+        _ => Some(id.to_string()),
+    }
+}
+
+/// Pick the best text track: requested language first, then active captions over subtitles.
+fn pick_track<'a>(tracks: &'a [Value], language: Option<&str>) -> Option<&'a Value> {
+    let lang_matches = |t: &Value| {
+        language
+            .map(|l| {
+                t["language"]
+                    .as_str()
+                    .map(|tl| tl.eq_ignore_ascii_case(l) || tl.starts_with(&format!("{}-", l)))
+                    .unwrap_or(false)
+            })
+            .unwrap_or(true)
+    };
+    tracks.iter().filter(|t| lang_matches(t)).max_by_key(|t| {
+        (
+            t["active"].as_bool().unwrap_or(false),
+            t["type"].as_str() == Some("captions"),
+        )
+    })
+}
+
+fn format_track(t: &Value) -> Value {
+    json!({
+        "language": t["language"],
+        "name": t["name"],
+        "type": t["type"],
+        "active": t["active"],
+    })
+}
+
+fn format_video(v: &Value, format: ResponseFormat) -> Value {
+    let id = v["uri"]
+        .as_str()
+        .and_then(|u| u.rsplit('/').next())
+        .map(str::to_string);
+    let mut out = json!({
+        "id": id,
+        "title": v["name"],
+        "url": v["link"],
+        "author": v["user"]["name"],
+        "duration_seconds": v["duration"],
+        "created_at": v["created_time"],
+        "plays": v["stats"]["plays"],
+    });
+    if format == ResponseFormat::Detailed {
+        out["description"] = v["description"].clone();
+        out["author_url"] = v["user"]["link"].clone();
+        out["likes"] = v["metadata"]["connections"]["likes"]["total"].clone();
+        out["language"] = v["language"].clone();
+        out["thumbnail"] = v["pictures"]["base_link"].clone();
+        out["tags"] = json!(v["tags"]
+            .as_array()
+            .map(|tags| tags
+                .iter()
+                .filter_map(|t| t["name"].as_str())
+                .collect::<Vec<_>>())
+            .unwrap_or_default());
+    }
+    out
+}
+
+fn required_video_id(input: &str) -> Result<String, ConnectorError> {
+    extract_video_id(input)
+        .ok_or_else(|| ConnectorError::InvalidParams(format!("Invalid Vimeo video id: {}", input)))
+}
+
+#[async_trait]
+impl Connector for VimeoConnector {
+    fn name(&self) -> &'static str {
+        "vimeo"
+    }
+
+    fn description(&self) -> &'static str {
+        "Vimeo video search, metadata, and caption/subtitle text tracks."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        let mut auth = AuthDetails::new();
+        if let Some(token) = &self.access_token {
+            auth.insert("api_key".to_string(), token.clone());
+        }
+        Ok(auth)
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        *self = Self::new(details).await?;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.api("/me", &[("fields", "uri".to_string())])
+            .await
+            .map(|_| ())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema {
+            fields: vec![Field {
+                name: "api_key".to_string(),
+                label: "Access Token".to_string(),
+                field_type: FieldType::Secret,
+                required: false,
+                description: Some(
+                    "Vimeo personal access token with 'public' scope (or set VIMEO_ACCESS_TOKEN). \
+Needed for search and captions; metadata works without it."
+                        .to_string(),
+                ),
+                options: None,
+            }],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Use `search` to find videos, then `get` with a video ID or URL for metadata and \
+transcript. `get_captions` lists text tracks and downloads one as text or VTT."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            tool(
+                "get",
+                "Get title/description plus transcript (from captions, when available). Input is a \
+video ID or URL. Example: video_id=\"76979871\" response_format=\"concise\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "video_id": { "type": "string", "description": "Vimeo video ID or URL" },
+                        "language": { "type": "string", "description": "Preferred caption language (e.g. en)" },
+                        "response_format": { "type": "string", "enum": ["concise", "detailed"], "description": "Output detail (default: concise)" }
+                    },
+                    "required": ["video_id"]
+                }),
+            ),
+            tool(
+                "search",
+                "Search Vimeo videos. Returns IDs/URLs you can pass to get. Example: query=\"short film\" limit=5.",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Search query" },
+                        "limit": { "type": "integer", "description": "Max results (default: 5, max: 100)" },
+                        "sort": { "type": "string", "enum": ["relevant", "date", "plays", "likes", "duration"], "description": "Sort order (default: relevant)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "get_captions",
+                "List a video's text tracks and download one as plain text or raw VTT. Example: video_id=\"76979871\" language=\"en\" format=\"text\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "video_id": { "type": "string", "description": "Vimeo video ID or URL" },
+                        "language": { "type": "string", "description": "Caption language (default: best available)" },
+                        "format": { "type": "string", "enum": ["text", "vtt"], "description": "Output format (default: text)" }
+                    },
+                    "required": ["video_id"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        match request.name.as_ref() {
+            "get" | "get_video_details" => {
+                let args: GetArgs = parse_args(request.arguments)?;
+                let video_id = required_video_id(&args.video_id)?;
+
+                if self.access_token.is_none() {
+                    let embed = self.oembed(&video_id).await?;
+                    let mut data = json!({
+                        "id": embed["video_id"],
+                        "title": embed["title"],
+                        "url": format!("https://vimeo.com/{}", video_id.replace(':', "/")),
+                        "author": embed["author_name"],
+                        "duration_seconds": embed["duration"],
+                        "transcript": Value::Null,
+                        "note": "Configure a Vimeo access token for transcripts.",
+                    });
+                    if args.response_format == ResponseFormat::Detailed {
+                        data["description"] = embed["description"].clone();
+                        data["author_url"] = embed["author_url"].clone();
+                        data["thumbnail"] = embed["thumbnail_url"].clone();
+                        data["created_at"] = embed["upload_date"].clone();
+                    }
+                    return Ok(structured_result_with_text(&data, None)?);
+                }
+
+                let video = self
+                    .api(
+                        &format!("/videos/{}", video_id),
+                        &[("fields", VIDEO_FIELDS.to_string())],
+                    )
+                    .await?;
+                let mut data = format_video(&video, args.response_format);
+
+                // Transcript is best-effort: owners can disable text track access.
+                let tracks = self.text_tracks(&video_id).await.unwrap_or_default();
+                let transcript = match pick_track(&tracks, args.language.as_deref())
+                    .and_then(|t| t["link"].as_str())
+                {
+                    Some(link) => match self.download_track(link).await {
+                        Ok(body) => Some(subtitles_to_text(&body)),
+                        Err(e) => {
+                            tracing::warn!(
+                                error = %e,
+                                video_id = %video_id,
+                                "Failed to fetch Vimeo text track"
+                            );
+                            None
+                        }
+                    },
+                    None => None,
+                };
+                data["transcript"] = json!(transcript);
+                if args.response_format == ResponseFormat::Detailed {
+                    data["text_tracks"] =
+                        json!(tracks.iter().map(format_track).collect::<Vec<_>>());
+                }
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "search" | "search_videos" => {
+                let args: SearchArgs = parse_args(request.arguments)?;
+                let params = [
+                    ("query", args.query.clone()),
+                    (
+                        "per_page",
+                        args.limit.unwrap_or(5).clamp(1, 100).to_string(),
+                    ),
+                    ("sort", args.sort.unwrap_or_else(|| "relevant".to_string())),
+                    ("fields", VIDEO_FIELDS.to_string()),
+                ];
+                let body = self.api("/videos", &params).await?;
+                let results: Vec<Value> = body["data"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|v| format_video(v, ResponseFormat::Concise))
+                    .collect();
+
+                let data = json!({
+                    "query": args.query,
+                    "total": body["total"],
+                    "count": results.len(),
+                    "results": results,
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            "get_captions" => {
+                let args: CaptionsArgs = parse_args(request.arguments)?;
+                let video_id = required_video_id(&args.video_id)?;
+                let tracks = self.text_tracks(&video_id).await?;
+                let track = pick_track(&tracks, args.language.as_deref()).ok_or_else(|| {
+                    ConnectorError::Other(match &args.language {
+                        Some(lang) => format!("No '{}' text track for video {}", lang, video_id),
+                        None => format!("Video {} has no text tracks", video_id),
+                    })
+                })?;
+                let link = track["link"]
+                    .as_str()
+                    .ok_or(ConnectorError::ResourceNotFound)?;
+                let body = self.download_track(link).await?;
+                let raw = args.format.as_deref() == Some("vtt");
+
+                let data = json!({
+                    "video_id": video_id,
+                    "track": format_track(track),
+                    "available": tracks.iter().map(format_track).collect::<Vec<_>>(),
+                    "format": if raw { "vtt" } else { "text" },
+                    "content": if raw { body } else { subtitles_to_text(&body) },
+                });
+                Ok(structured_result_with_text(&data, None)?)
+            }
+            _ => Err(ConnectorError::ToolNotFound),
+        }
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_video_ids() {
+        assert_eq!(extract_video_id("76979871"), Some("76979871".to_string()));
+        assert_eq!(
+            extract_video_id("https://vimeo.com/76979871?share=copy"),
+            Some("76979871".to_string())
+        );
+        assert_eq!(
+            extract_video_id("https://player.vimeo.com/video/76979871"),
+            Some("76979871".to_string())
+        );
+        assert_eq!(
+            extract_video_id("https://vimeo.com/channels/staffpicks/76979871"),
+            Some("76979871".to_string())
+        );
+        assert_eq!(
+            extract_video_id("https://vimeo.com/76979871/abc123def"),
+            Some("76979871:abc123def".to_string())
+        );
+        assert_eq!(extract_video_id("https://vimeo.com/staffpicks"), None);
+    }
+
+    #[test]
+    fn picks_requested_language_track() {
+        let tracks = vec![
+            json!({ "language": "fr", "type": "subtitles", "active": true }),
+            json!({ "language": "en-US", "type": "captions", "active": true }),
+            json!({ "language": "en", "type": "subtitles", "active": false }),
+        ];
+        assert_eq!(
+            pick_track(&tracks, Some("en")).unwrap()["language"],
+            "en-US"
+        );
+        assert_eq!(pick_track(&tracks, Some("fr")).unwrap()["language"], "fr");
+        assert!(pick_track(&tracks, Some("de")).is_none());
+    }
+}
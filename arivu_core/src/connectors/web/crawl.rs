@@ -0,0 +1,210 @@
+// src/connectors/web/crawl.rs
+//
+// Pieces of the `crawl` tool that don't touch the network: robots.txt
+// rules, link discovery and the same-site check. The breadth-first walk
+// itself lives on WebConnector.
+
+use regex::Regex;
+use scraper::{Html, Selector};
+use url::Url;
+
+/// Product token matched against robots.txt User-agent lines.
+pub(super) const ROBOTS_AGENT: &str = "arivu";
+
+/// Paths that are never HTML; skipped rather than fetched as text.
+const BINARY_EXTENSIONS: &[&str] = &[
+    "pdf", "zip", "gz", "tar", "png", "jpg", "jpeg", "gif", "webp", "svg", "ico", "mp3", "mp4",
+    "mov", "avi", "webm", "woff", "woff2", "ttf", "css", "js", "json", "xml", "exe", "dmg",
+];
+
+/// Allow/Disallow rules of the robots.txt group that applies to us.
+#[derive(Debug, Default)]
+pub(super) struct Robots {
+    /// (allow, pattern, length of the original rule)
+    rules: Vec<(bool, Regex, usize)>,
+}
+
+impl Robots {
+    /// Rules for `agent` if a group names it, else the `*` group.
+    pub(super) fn parse(body: &str, agent: &str) -> Self {
+        let agent = agent.to_ascii_lowercase();
+        let mut specific = Vec::new();
+        let mut wildcard = Vec::new();
+        let mut group_agents: Vec<String> = Vec::new();
+        let mut in_rules = false;
+        for line in body.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let key = key.trim().to_ascii_lowercase();
+            let value = value.trim();
+            match key.as_str() {
+                "user-agent" => {
+                    // A User-agent after rules starts a new group
+                    if in_rules {
+                        group_agents.clear();
+                        in_rules = false;
+                    }
+                    group_agents.push(value.to_ascii_lowercase());
+                }
+                "allow" | "disallow" => {
+                    in_rules = true;
+                    // An empty Disallow allows everything
+                    if value.is_empty() {
+                        continue;
+                    }
+                    let Some(pattern) = rule_pattern(value) else {
+                        continue;
+                    };
+                    let rule = (key == "allow", pattern, value.len());
+                    if group_agents
+                        .iter()
+                        .any(|a| !a.is_empty() && a != "*" && agent.contains(a.as_str()))
+                    {
+                        specific.push(rule);
+                    } else if group_agents.iter().any(|a| a == "*") {
+                        wildcard.push(rule);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Robots {
+            rules: if specific.is_empty() {
+                wildcard
+            } else {
+                specific
+            },
+        }
+    }
+
+    /// Longest matching rule wins; Allow wins a tie.
+    pub(super) fn allows(&self, url: &Url) -> bool {
+        let mut path = url.path().to_string();
+        if let Some(query) = url.query() {
+            path.push('?');
+            path.push_str(query);
+        }
+        self.rules
+            .iter()
+            .filter(|(_, pattern, _)| pattern.is_match(&path))
+            .max_by_key(|(allow, _, len)| (*len, *allow))
+            .map_or(true, |(allow, _, _)| *allow)
+    }
+}
+
+/// Anchored regex for a robots.txt path rule (`*` wildcard, `$` end).
+fn rule_pattern(rule: &str) -> Option<Regex> {
+    let (rule, anchored) = match rule.strip_suffix('$') {
+        Some(rest) => (rest, true),
+        None => (rule, false),
+    };
+    let body = rule
+        .split('*')
+        .map(regex::escape)
+        .collect::<Vec<_>>()
+        .join(".*");
+    Regex::new(&format!("^{}{}", body, if anchored { "$" } else { "" })).ok()
+}
+
+/// Distinct http(s) links on the page, resolved against `base`, without
+/// fragments, skipping nofollow links and obvious non-HTML files.
+pub(super) fn page_links(html: &str, base: &Url) -> Vec<Url> {
+    let doc = Html::parse_document(html);
+    let Ok(anchors) = Selector::parse("a[href]") else {
+        return Vec::new();
+    };
+    let mut links: Vec<Url> = Vec::new();
+    for a in doc.select(&anchors) {
+        let nofollow = a
+            .value()
+            .attr("rel")
+            .is_some_and(|rel| rel.split_whitespace().any(|r| r == "nofollow"));
+        let Some(mut link) = a
+            .value()
+            .attr("href")
+            .filter(|_| !nofollow)
+            .and_then(|href| base.join(href.trim()).ok())
+        else {
+            continue;
+        };
+        if !matches!(link.scheme(), "http" | "https") || is_binary(&link) {
+            continue;
+        }
+        link.set_fragment(None);
+        if !links.contains(&link) {
+            links.push(link);
+        }
+    }
+    links
+}
+
+fn is_binary(url: &Url) -> bool {
+    url.path()
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .is_some_and(|ext| BINARY_EXTENSIONS.contains(&ext.as_str()))
+}
+
+/// Same host, treating `www.` as insignificant.
+pub(super) fn same_site(a: &Url, b: &Url) -> bool {
+    let host = |u: &Url| {
+        u.host_str()
+            .map(|h| h.trim_start_matches("www.").to_ascii_lowercase())
+    };
+    host(a).is_some() && host(a) == host(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_robots_rules() {
+        let body =
+            "User-agent: *\nDisallow: /private/\nAllow: /private/press\nDisallow: /*.php$\n\n\
+                    User-agent: OtherBot\nDisallow: /\n";
+        let robots = Robots::parse(body, ROBOTS_AGENT);
+        let url = |p: &str| Url::parse(&format!("https://example.com{}", p)).unwrap();
+        assert!(robots.allows(&url("/blog/post")));
+        assert!(!robots.allows(&url("/private/notes")));
+        assert!(robots.allows(&url("/private/press/2024")));
+        assert!(!robots.allows(&url("/index.php")));
+        assert!(robots.allows(&url("/index.php?x=1")));
+
+        let ours = Robots::parse(
+            "User-agent: *\nDisallow:\n\nUser-agent: arivu\nDisallow: /\n",
+            ROBOTS_AGENT,
+        );
+        assert!(!ours.allows(&url("/anything")));
+        assert!(Robots::parse("", ROBOTS_AGENT).allows(&url("/x")));
+    }
+
+    #[test]
+    fn discovers_crawlable_links() {
+        let base = Url::parse("https://www.example.com/docs/intro").unwrap();
+        let html = r#"<a href="guide#setup">Guide</a> <a href="/docs/guide">Guide again</a>
+            <a href="https://example.com/faq">FAQ</a> <a href="mailto:a@example.com">Mail</a>
+            <a href="/files/manual.pdf">PDF</a> <a rel="nofollow" href="/login">Login</a>
+            <a href="https://other.org/">Other</a>"#;
+        let links: Vec<String> = page_links(html, &base)
+            .iter()
+            .map(|u| u.to_string())
+            .collect();
+        assert_eq!(
+            links,
+            vec![
+                "https://www.example.com/docs/guide",
+                "https://example.com/faq",
+                "https://other.org/",
+            ]
+        );
+        let other = Url::parse("https://other.org/").unwrap();
+        assert!(same_site(
+            &base,
+            &Url::parse("https://example.com/faq").unwrap()
+        ));
+        assert!(!same_site(&base, &other));
+    }
+}
@@ -0,0 +1,235 @@
+// src/connectors/web/discover.rs
+//
+// Parsing for the `discover` tool: Sitemap lines in robots.txt, sitemap
+// and sitemap index XML, and RSS/Atom/JSON feed links on a home page.
+
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+use scraper::{Html, Selector};
+use serde::Serialize;
+use url::Url;
+
+/// Where sites put sitemaps when robots.txt doesn't say.
+pub(super) const DEFAULT_SITEMAP_PATHS: &[&str] = &["/sitemap.xml", "/sitemap_index.xml"];
+/// Conventional feed paths tried when the home page links none.
+pub(super) const COMMON_FEED_PATHS: &[&str] =
+    &["/feed", "/rss.xml", "/atom.xml", "/feed.xml", "/index.xml"];
+
+#[derive(Debug, Default, PartialEq)]
+pub(super) struct Sitemap {
+    /// A sitemap index lists other sitemaps rather than pages
+    pub is_index: bool,
+    pub entries: Vec<SitemapEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(super) struct SitemapEntry {
+    pub loc: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lastmod: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(super) struct FeedCandidate {
+    pub url: String,
+    pub title: Option<String>,
+    /// rss, atom or json
+    pub format: &'static str,
+    /// link (advertised by the page) or probe (found at a common path)
+    pub source: &'static str,
+}
+
+/// `Sitemap:` URLs listed in robots.txt.
+pub(super) fn robots_sitemaps(body: &str) -> Vec<String> {
+    body.lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.trim()
+                .eq_ignore_ascii_case("sitemap")
+                .then(|| value.trim().to_string())
+        })
+        .filter(|url| url.starts_with("http"))
+        .collect()
+}
+
+/// `<loc>`/`<lastmod>` pairs of a `<urlset>` or `<sitemapindex>`; None when
+/// the body is neither.
+pub(super) fn parse_sitemap(xml: &str) -> Option<Sitemap> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+    let mut buf = Vec::new();
+    let mut sitemap: Option<Sitemap> = None;
+    let mut field: Option<&'static str> = None;
+    let mut loc = None;
+    let mut lastmod = None;
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) => match e.local_name().as_ref() {
+                b"urlset" => sitemap = Some(Sitemap::default()),
+                b"sitemapindex" => {
+                    sitemap = Some(Sitemap {
+                        is_index: true,
+                        ..Sitemap::default()
+                    })
+                }
+                b"loc" => field = Some("loc"),
+                b"lastmod" => field = Some("lastmod"),
+                _ => {}
+            },
+            Ok(Event::Text(e)) => {
+                let text = e.unescape().unwrap_or_default().trim().to_string();
+                match field {
+                    Some("loc") => loc = Some(text),
+                    Some("lastmod") => lastmod = Some(text),
+                    _ => {}
+                }
+            }
+            Ok(Event::CData(e)) if field == Some("loc") => {
+                loc = Some(String::from_utf8_lossy(&e).trim().to_string());
+            }
+            Ok(Event::End(e)) => match e.local_name().as_ref() {
+                b"loc" | b"lastmod" => field = None,
+                b"url" | b"sitemap" => {
+                    if let (Some(map), Some(loc)) = (sitemap.as_mut(), loc.take()) {
+                        map.entries.push(SitemapEntry {
+                            loc,
+                            lastmod: lastmod.take(),
+                        });
+                    }
+                    lastmod = None;
+                }
+                _ => {}
+            },
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    sitemap
+}
+
+fn feed_format(mime: &str) -> Option<&'static str> {
+    let mime = mime.to_ascii_lowercase();
+    if mime.contains("rss") {
+        Some("rss")
+    } else if mime.contains("atom") {
+        Some("atom")
+    } else if mime.contains("feed+json") {
+        Some("json")
+    } else {
+        None
+    }
+}
+
+/// Feeds advertised with `<link rel="alternate">`, resolved against `base`.
+pub(super) fn feed_links(html: &str, base: &Url) -> Vec<FeedCandidate> {
+    let doc = Html::parse_document(html);
+    let Ok(selector) = Selector::parse("link[rel~='alternate'][type][href]") else {
+        return Vec::new();
+    };
+    let mut feeds: Vec<FeedCandidate> = Vec::new();
+    for link in doc.select(&selector) {
+        let el = link.value();
+        let Some(format) = el.attr("type").and_then(feed_format) else {
+            continue;
+        };
+        let Some(url) = el.attr("href").and_then(|h| base.join(h.trim()).ok()) else {
+            continue;
+        };
+        if feeds.iter().any(|f| f.url == url.as_str()) {
+            continue;
+        }
+        feeds.push(FeedCandidate {
+            url: url.to_string(),
+            title: el.attr("title").map(|t| t.trim().to_string()),
+            format,
+            source: "link",
+        });
+    }
+    feeds
+}
+
+/// The feed format of a fetched body, judged by its root element.
+pub(super) fn sniff_feed(body: &str) -> Option<&'static str> {
+    let start = body.trim_start();
+    if start.starts_with('{') {
+        return start.contains("jsonfeed.org/version").then_some("json");
+    }
+    let head: String = start.chars().take(1024).collect();
+    if head.contains("<rss") || head.contains("<rdf:RDF") {
+        Some("rss")
+    } else if head.contains("<feed") {
+        Some("atom")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_sitemaps_and_robots() {
+        let robots = "User-agent: *\nDisallow: /admin\nSitemap: https://example.com/sitemap_index.xml\nsitemap: https://example.com/news.xml\n";
+        assert_eq!(
+            robots_sitemaps(robots),
+            vec![
+                "https://example.com/sitemap_index.xml",
+                "https://example.com/news.xml"
+            ]
+        );
+
+        let index = r#"<?xml version="1.0"?><sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+            <sitemap><loc>https://example.com/posts.xml</loc><lastmod>2024-05-01</lastmod></sitemap>
+            <sitemap><loc>https://example.com/pages.xml</loc></sitemap></sitemapindex>"#;
+        let parsed = parse_sitemap(index).unwrap();
+        assert!(parsed.is_index);
+        assert_eq!(parsed.entries.len(), 2);
+        assert_eq!(parsed.entries[0].lastmod.as_deref(), Some("2024-05-01"));
+        assert_eq!(parsed.entries[1].lastmod, None);
+
+        let urlset = r#"<urlset><url><loc>https://example.com/a?x=1&amp;y=2</loc></url></urlset>"#;
+        let parsed = parse_sitemap(urlset).unwrap();
+        assert!(!parsed.is_index);
+        assert_eq!(parsed.entries[0].loc, "https://example.com/a?x=1&y=2");
+        assert_eq!(parse_sitemap("<html><body>Not found</body></html>"), None);
+    }
+
+    #[test]
+    fn finds_feed_links() {
+        let base = Url::parse("https://example.com/blog/").unwrap();
+        let html = r#"<head>
+            <link rel="alternate" type="application/rss+xml" title="Posts" href="/feed.xml">
+            <link rel="alternate" type="application/atom+xml" href="atom.xml">
+            <link rel="alternate" type="application/feed+json" href="https://example.com/feed.json">
+            <link rel="alternate" hreflang="de" type="text/html" href="/de/">
+            <link rel="stylesheet" type="text/css" href="/site.css">
+        </head>"#;
+        let feeds = feed_links(html, &base);
+        let found: Vec<(&str, &str)> = feeds.iter().map(|f| (f.url.as_str(), f.format)).collect();
+        assert_eq!(
+            found,
+            vec![
+                ("https://example.com/feed.xml", "rss"),
+                ("https://example.com/blog/atom.xml", "atom"),
+                ("https://example.com/feed.json", "json"),
+            ]
+        );
+        assert_eq!(feeds[0].title.as_deref(), Some("Posts"));
+
+        assert_eq!(
+            sniff_feed("<?xml version=\"1.0\"?>\n<rss version=\"2.0\">"),
+            Some("rss")
+        );
+        assert_eq!(
+            sniff_feed("<feed xmlns=\"http://www.w3.org/2005/Atom\">"),
+            Some("atom")
+        );
+        assert_eq!(
+            sniff_feed(r#"{"version": "https://jsonfeed.org/version/1.1"}"#),
+            Some("json")
+        );
+        assert_eq!(sniff_feed("<!doctype html><html>"), None);
+    }
+}
@@ -0,0 +1,114 @@
+// src/connectors/web/document.rs
+//
+// URLs that serve a PDF, DOCX or EPUB instead of a page. The response type
+// is taken from Content-Type, falling back to magic bytes and the URL's
+// extension for generic types; the text itself comes from `doc_text`.
+
+use crate::doc_text::{self, DocumentKind, DocumentText};
+
+/// Larger responses are refused rather than buffered.
+pub(super) const MAX_DOCUMENT_BYTES: u64 = 50 * 1024 * 1024;
+/// Text budget per document, shared across its pages.
+pub(super) const MAX_DOCUMENT_CHARS: usize = 200_000;
+
+/// Whether the Content-Type is one we read as a page.
+pub(super) fn is_page_type(content_type: Option<&str>) -> bool {
+    let mime = mime(content_type);
+    mime == "text/html" || mime == "application/xhtml+xml" || mime.starts_with("text/")
+}
+
+/// The document kind of a non-page response, if we can extract it.
+pub(super) fn document_kind(
+    url: &str,
+    content_type: Option<&str>,
+    head: &[u8],
+) -> Option<DocumentKind> {
+    let supported = |kind: DocumentKind| {
+        matches!(
+            kind,
+            DocumentKind::Pdf | DocumentKind::Docx | DocumentKind::Epub
+        )
+        .then_some(kind)
+    };
+    let mime = mime(content_type);
+    if let Some(kind) = doc_text::document_kind("", Some(&mime)).and_then(supported) {
+        return Some(kind);
+    }
+    if head.starts_with(b"%PDF-") {
+        return Some(DocumentKind::Pdf);
+    }
+    // DOCX and EPUB are both zip containers; the extension tells them apart
+    if head.starts_with(b"PK\x03\x04") {
+        let path = url::Url::parse(url)
+            .map(|u| u.path().to_string())
+            .unwrap_or_default();
+        return doc_text::document_kind(&path, None).and_then(supported);
+    }
+    None
+}
+
+fn mime(content_type: Option<&str>) -> String {
+    content_type
+        .unwrap_or_default()
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+/// The document's text as markdown, one heading per PDF page or EPUB
+/// chapter so page references survive.
+pub(super) fn document_markdown(doc: &DocumentText) -> String {
+    if doc.pages.is_empty() {
+        return doc.text.trim().to_string();
+    }
+    let label = match doc.kind {
+        DocumentKind::Epub => "Chapter",
+        _ => "Page",
+    };
+    doc.pages
+        .iter()
+        .enumerate()
+        .filter(|(_, page)| !page.trim().is_empty())
+        .map(|(i, page)| format!("## {} {}\n\n{}", label, i + 1, page.trim()))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_documents_and_renders_pages() {
+        let url = "https://example.com/files/report";
+        assert_eq!(
+            document_kind(url, Some("application/pdf; qs=0.1"), b""),
+            Some(DocumentKind::Pdf)
+        );
+        assert_eq!(
+            document_kind(url, Some("application/octet-stream"), b"%PDF-1.7\n"),
+            Some(DocumentKind::Pdf)
+        );
+        assert_eq!(
+            document_kind("https://example.com/book.epub", None, b"PK\x03\x04rest"),
+            Some(DocumentKind::Epub)
+        );
+        assert_eq!(document_kind(url, Some("image/png"), b"\x89PNG"), None);
+        assert!(is_page_type(Some("text/html; charset=utf-8")));
+        assert!(!is_page_type(Some("application/pdf")));
+
+        let doc = DocumentText {
+            kind: DocumentKind::Pdf,
+            title: None,
+            text: "Intro\nBody".to_string(),
+            pages: vec!["Intro".to_string(), " ".to_string(), "Body".to_string()],
+            truncated: false,
+        };
+        assert_eq!(
+            document_markdown(&doc),
+            "## Page 1\n\nIntro\n\n## Page 3\n\nBody"
+        );
+    }
+}
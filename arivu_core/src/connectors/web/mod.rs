@@ -1,25 +1,78 @@
 use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::doc_text::DocumentText;
 use crate::error::ConnectorError;
 use crate::utils::{
-    get_cookies, get_domain, get_user_agent, match_browser, strip_multiple_newlines, Browser,
+    get_cookies, get_domain, get_user_agent, html_to_markdown_with, match_browser,
+    strip_multiple_newlines, Browser, MarkdownOptions,
 };
 use crate::{auth::AuthDetails, Connector};
 use async_trait::async_trait;
-use htmd::HtmlToMarkdown;
-use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, ACCEPT_LANGUAGE, COOKIE, USER_AGENT};
+use reqwest::header::{
+    HeaderMap, HeaderValue, ACCEPT, ACCEPT_LANGUAGE, CONTENT_TYPE, COOKIE, USER_AGENT,
+};
 use rmcp::model::*;
 use scraper::{Html, Selector};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::Mutex;
 use tracing::{debug, info};
 
+mod crawl;
+mod discover;
+mod document;
+mod readability;
+#[cfg(feature = "web-render")]
+mod render;
+
+pub use readability::{extract_article, Article};
+
+const DISCOVER_DEFAULT_URLS: u64 = 200;
+const DISCOVER_MAX_URLS: u64 = 5_000;
+const DISCOVER_DEFAULT_SITEMAPS: u64 = 10;
+const DISCOVER_MAX_SITEMAPS: u64 = 50;
+const CRAWL_DEFAULT_PAGES: u64 = 20;
+const CRAWL_MAX_PAGES: u64 = 200;
+const CRAWL_DEFAULT_DEPTH: u64 = 2;
+const CRAWL_MAX_DEPTH: u64 = 5;
+const CRAWL_DEFAULT_PAGE_CHARS: u64 = 5_000;
+/// Pause between page fetches so a crawl doesn't hammer the site.
+const CRAWL_DELAY: Duration = Duration::from_millis(250);
+
 const DEFAULT_USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/133.0.0.0 Safari/537.36";
 
+/// WebContent for a PDF, DOCX or EPUB URL.
+fn document_content(url: &str, doc: DocumentText, content_type: Option<String>) -> WebContent {
+    WebContent {
+        url: url.to_string(),
+        content: document::document_markdown(&doc),
+        document: Some(DocumentInfo {
+            kind: doc.kind.as_str().to_string(),
+            content_type,
+            pages: doc.pages.len(),
+            truncated: doc.truncated,
+        }),
+        title: doc.title,
+        metadata: WebMetadata::default(),
+    }
+}
+
+/// `render` is opt-in and slow, so a value that isn't a boolean is rejected
+/// rather than read as "off".
+fn render_arg(args: &JsonObject) -> Result<bool, ConnectorError> {
+    match args.get("render") {
+        None | Some(serde_json::Value::Null) => Ok(false),
+        Some(serde_json::Value::Bool(render)) => Ok(*render),
+        Some(other) => Err(ConnectorError::InvalidParams(format!(
+            "'render' must be true or false, got {}",
+            other
+        ))),
+    }
+}
+
 fn browser_identifier(browser: &Browser) -> &'static str {
     match browser {
         Browser::Firefox => "firefox",
@@ -63,6 +116,28 @@ pub struct WebContent {
     pub title: Option<String>,
     pub content: String,
     pub metadata: WebMetadata,
+    /// Set when the URL served a PDF, DOCX or EPUB rather than a page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document: Option<DocumentInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DocumentInfo {
+    /// pdf, docx or epub
+    pub kind: String,
+    pub content_type: Option<String>,
+    /// PDF pages or EPUB chapters; 0 for DOCX
+    pub pages: usize,
+    pub truncated: bool,
+}
+
+/// A fetched URL: page HTML, or a document already parsed to text.
+enum Fetched {
+    Html(String),
+    Document {
+        doc: DocumentText,
+        content_type: Option<String>,
+    },
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
@@ -79,6 +154,9 @@ pub struct WebConnector {
     pub headers: HeaderMap,
     pub browser: Browser,
     cookie_cache: Arc<Mutex<HashMap<String, String>>>,
+    /// Chrome/Chromium binary for `render=true`; discovered on PATH when unset
+    #[cfg_attr(not(feature = "web-render"), allow(dead_code))]
+    chrome_path: Option<String>,
 }
 
 impl WebConnector {
@@ -114,6 +192,7 @@ impl WebConnector {
             client,
             headers,
             cookie_cache: Arc::new(Mutex::new(HashMap::new())),
+            chrome_path: None,
         };
 
         connector.set_auth_details(auth).await?;
@@ -125,18 +204,51 @@ impl WebConnector {
         url: &str,
         browser: &Browser,
         cookies: Option<&str>,
+        render: bool,
     ) -> Result<WebContent, ConnectorError> {
-        let user_agent = self
-            .headers
-            .get(USER_AGENT)
-            .and_then(|value| value.to_str().ok())
-            .map(|ua| ua.to_string())
-            .unwrap_or_else(|| get_user_agent(browser.clone()));
+        match self.fetch_page(url, browser, cookies, render).await? {
+            Fetched::Html(html) => self.process_html(url, &html),
+            Fetched::Document { doc, content_type } => Ok(document_content(url, doc, content_type)),
+        }
+    }
+
+    /// The page's HTML, fetched over HTTP or rendered in headless Chrome.
+    async fn fetch_page(
+        &self,
+        url: &str,
+        browser: &Browser,
+        cookies: Option<&str>,
+        render: bool,
+    ) -> Result<Fetched, ConnectorError> {
+        let user_agent = self.user_agent(browser);
+
+        if render {
+            let t0 = std::time::Instant::now();
+            let html = self.render_html(url, &user_agent).await?;
+            debug!(
+                target: "connector.web",
+                url = %url,
+                total_ms = %t0.elapsed().as_millis(),
+                "rendered url"
+            );
+            Ok(Fetched::Html(html))
+        } else {
+            self.fetch(url, &user_agent, cookies).await
+        }
+    }
 
+    /// GET `url`. HTML and text come back as a page; PDF, DOCX and EPUB
+    /// responses are parsed to text on the CPU pool.
+    async fn fetch(
+        &self,
+        url: &str,
+        user_agent: &str,
+        cookies: Option<&str>,
+    ) -> Result<Fetched, ConnectorError> {
         let mut request = self.client.get(url);
         request = request.header(
             USER_AGENT,
-            HeaderValue::from_str(&user_agent).map_err(|e| ConnectorError::Other(e.to_string()))?,
+            HeaderValue::from_str(user_agent).map_err(|e| ConnectorError::Other(e.to_string()))?,
         );
 
         if let Some(cookie_header) = cookies {
@@ -155,30 +267,101 @@ impl WebConnector {
             .await
             .map_err(|e| ConnectorError::Other(e.to_string()))?;
         let t1 = std::time::Instant::now();
-        let response = resp
-            .text()
+        let content_type = resp
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        if document::is_page_type(content_type.as_deref()) {
+            let response = resp
+                .text()
+                .await
+                .map_err(|e| ConnectorError::Other(e.to_string()))?;
+            let t2 = std::time::Instant::now();
+
+            debug!(
+                target: "connector.web",
+                url = %url,
+                connect_send_ms = %((t1 - t0).as_millis()),
+                read_body_ms = %((t2 - t1).as_millis()),
+                total_ms = %((t2 - t0).as_millis()),
+                "scraped url"
+            );
+            return Ok(Fetched::Html(response));
+        }
+
+        if resp
+            .content_length()
+            .is_some_and(|len| len > document::MAX_DOCUMENT_BYTES)
+        {
+            return Err(ConnectorError::Other(format!(
+                "{} is larger than {} MB",
+                url,
+                document::MAX_DOCUMENT_BYTES / (1024 * 1024)
+            )));
+        }
+        let bytes = resp
+            .bytes()
             .await
             .map_err(|e| ConnectorError::Other(e.to_string()))?;
-        let t2 = std::time::Instant::now();
+        let head = &bytes[..bytes.len().min(8)];
+        let Some(kind) = document::document_kind(url, content_type.as_deref(), head) else {
+            // Unlabelled or mislabelled pages still get the HTML treatment
+            return Ok(Fetched::Html(String::from_utf8_lossy(&bytes).into_owned()));
+        };
+        if bytes.len() as u64 > document::MAX_DOCUMENT_BYTES {
+            return Err(ConnectorError::Other(format!(
+                "{} is larger than {} MB",
+                url,
+                document::MAX_DOCUMENT_BYTES / (1024 * 1024)
+            )));
+        }
+        let mime = content_type.clone();
+        let filename = format!("document.{}", kind.as_str());
+        let doc = crate::cpu_pool::spawn_cpu(move || {
+            crate::doc_text::extract_text(
+                &bytes,
+                &filename,
+                mime.as_deref(),
+                document::MAX_DOCUMENT_CHARS,
+            )
+        })
+        .await?;
 
         debug!(
             target: "connector.web",
             url = %url,
-            connect_send_ms = %((t1 - t0).as_millis()),
-            read_body_ms = %((t2 - t1).as_millis()),
-            total_ms = %((t2 - t0).as_millis()),
-            "scraped url"
+            kind = kind.as_str(),
+            pages = doc.pages.len(),
+            total_ms = %t0.elapsed().as_millis(),
+            "extracted document"
         );
+        Ok(Fetched::Document { doc, content_type })
+    }
+
+    #[cfg(feature = "web-render")]
+    async fn render_html(&self, url: &str, user_agent: &str) -> Result<String, ConnectorError> {
+        render::render_html(url, user_agent, self.chrome_path.as_deref()).await
+    }
+
+    #[cfg(not(feature = "web-render"))]
+    async fn render_html(&self, _url: &str, _user_agent: &str) -> Result<String, ConnectorError> {
+        Err(ConnectorError::InvalidParams(
+            "render=true needs arivu built with the web-render feature".to_string(),
+        ))
+    }
 
+    /// Extract title, metadata and main content as markdown from a page's HTML.
+    fn process_html(&self, url: &str, response: &str) -> Result<WebContent, ConnectorError> {
         let t3 = std::time::Instant::now();
-        let content = strip_multiple_newlines(&response);
+        let content = strip_multiple_newlines(response);
         let t4 = std::time::Instant::now();
 
         let html = Html::parse_document(&content);
         let t5 = std::time::Instant::now();
         let main_html = find_main_content(&html);
         let t6 = std::time::Instant::now();
-        let content = html_to_markdown(&main_html);
+        let content = page_markdown(&main_html, url);
         let t7 = std::time::Instant::now();
         let metadata = self.extract_metadata(&html)?;
         let t8 = std::time::Instant::now();
@@ -207,9 +390,324 @@ impl WebConnector {
             title,
             content,
             metadata,
+            document: None,
         })
     }
 
+    /// Breadth-first crawl from a seed URL. Pages are fetched over plain
+    /// HTTP without cookies; robots.txt is read once per origin. Stops early,
+    /// returning what it has, when the caller cancels the tool call.
+    async fn crawl(
+        &self,
+        args: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<serde_json::Value, ConnectorError> {
+        let seed_arg = args
+            .get("url")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| ConnectorError::InvalidParams("Missing 'url' parameter".to_string()))?;
+        let seed = url::Url::parse(seed_arg)
+            .map_err(|e| ConnectorError::InvalidParams(format!("Invalid url: {}", e)))?;
+        let number = |key: &str, default: u64, max: u64| {
+            args.get(key)
+                .and_then(|v| v.as_u64())
+                .unwrap_or(default)
+                .min(max)
+        };
+        let flag =
+            |key: &str, default: bool| args.get(key).and_then(|v| v.as_bool()).unwrap_or(default);
+        let max_pages = number("max_pages", CRAWL_DEFAULT_PAGES, CRAWL_MAX_PAGES).max(1) as usize;
+        let max_depth = number("max_depth", CRAWL_DEFAULT_DEPTH, CRAWL_MAX_DEPTH) as usize;
+        let max_chars = number("max_chars_per_page", CRAWL_DEFAULT_PAGE_CHARS, u64::MAX) as usize;
+        let same_domain = flag("same_domain", true);
+        let respect_robots = flag("respect_robots", true);
+        let article = match args.get("extract").and_then(|v| v.as_str()) {
+            None | Some("main") => false,
+            Some("article") => true,
+            Some(other) => {
+                return Err(ConnectorError::InvalidParams(format!(
+                    "extract must be 'main' or 'article', got '{}'",
+                    other
+                )))
+            }
+        };
+        let user_agent = self.user_agent(&self.browser);
+
+        let mut robots: HashMap<String, crawl::Robots> = HashMap::new();
+        let mut seen: HashSet<url::Url> = HashSet::from([seed.clone()]);
+        let mut queue = VecDeque::from([(seed.clone(), 0usize)]);
+        let mut pages = Vec::new();
+        let mut links = Vec::new();
+        let mut skipped = Vec::new();
+        let mut errors = Vec::new();
+        let mut cancelled = false;
+
+        while let Some((page_url, depth)) = queue.pop_front() {
+            if crate::tool_context::is_cancelled() {
+                cancelled = true;
+                break;
+            }
+            if pages.len() >= max_pages {
+                break;
+            }
+            if respect_robots {
+                let origin = page_url.origin().ascii_serialization();
+                if !robots.contains_key(&origin) {
+                    let rules = self.fetch_robots(&origin, &user_agent).await;
+                    robots.insert(origin.clone(), rules);
+                }
+                if !robots[&origin].allows(&page_url) {
+                    skipped.push(json!({"url": page_url.as_str(), "reason": "robots.txt"}));
+                    continue;
+                }
+            }
+            if !pages.is_empty() || !errors.is_empty() {
+                tokio::time::sleep(CRAWL_DELAY).await;
+            }
+
+            let fetched = match self.fetch(page_url.as_str(), &user_agent, None).await {
+                Ok(fetched) => fetched,
+                Err(e) => {
+                    errors.push(json!({"url": page_url.as_str(), "error": e.to_string()}));
+                    continue;
+                }
+            };
+            let (title, mut content, out_links) = match fetched {
+                Fetched::Html(html) if article => {
+                    let a = extract_article(&html, page_url.as_str());
+                    (a.title, a.content, crawl::page_links(&html, &page_url))
+                }
+                Fetched::Html(html) => {
+                    let c = self.process_html(page_url.as_str(), &html)?;
+                    (c.title, c.content, crawl::page_links(&html, &page_url))
+                }
+                Fetched::Document { doc, .. } => (
+                    doc.title.clone(),
+                    document::document_markdown(&doc),
+                    Vec::new(),
+                ),
+            };
+            let truncated = match content.char_indices().nth(max_chars) {
+                Some((idx, _)) => {
+                    content.truncate(idx);
+                    true
+                }
+                None => false,
+            };
+
+            let mut followed = 0;
+            for link in out_links {
+                if same_domain && !crawl::same_site(&seed, &link) {
+                    continue;
+                }
+                followed += 1;
+                links.push(json!({"from": page_url.as_str(), "to": link.as_str()}));
+                if depth < max_depth && seen.insert(link.clone()) {
+                    queue.push_back((link, depth + 1));
+                }
+            }
+            pages.push(json!({
+                "url": page_url.as_str(),
+                "depth": depth,
+                "title": title,
+                "content": content,
+                "content_truncated": truncated,
+                "links": followed,
+            }));
+            crate::tool_context::report_progress(
+                pages.len() as f64,
+                Some(max_pages as f64),
+                format!("crawled {}", page_url),
+            );
+        }
+
+        Ok(json!({
+            "seed": seed.as_str(),
+            "pages": pages,
+            "links": links,
+            "skipped": skipped,
+            "errors": errors,
+            "queued": queue.len(),
+            "cancelled": cancelled,
+        }))
+    }
+
+    /// robots.txt rules for an origin; anything but a successful fetch
+    /// (missing file, network error) allows everything.
+    async fn fetch_robots(&self, origin: &str, user_agent: &str) -> crawl::Robots {
+        match self
+            .get_text(&format!("{}/robots.txt", origin), user_agent)
+            .await
+        {
+            Some(body) => crawl::Robots::parse(&body, crawl::ROBOTS_AGENT),
+            None => crawl::Robots::default(),
+        }
+    }
+
+    /// Body of a successful GET, or None for error statuses and failures.
+    async fn get_text(&self, url: &str, user_agent: &str) -> Option<String> {
+        let resp = self
+            .client
+            .get(url)
+            .header(USER_AGENT, user_agent)
+            .send()
+            .await
+            .ok()?;
+        if !resp.status().is_success() {
+            return None;
+        }
+        resp.text().await.ok()
+    }
+
+    /// Configured User-Agent header, else one matching the browser profile.
+    fn user_agent(&self, browser: &Browser) -> String {
+        self.headers
+            .get(USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(|ua| ua.to_string())
+            .unwrap_or_else(|| get_user_agent(browser.clone()))
+    }
+
+    /// Sitemaps (from robots.txt or the usual paths, following sitemap
+    /// indexes) and feeds (linked from the page, else probed at common
+    /// paths) for a domain.
+    async fn discover(
+        &self,
+        args: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<serde_json::Value, ConnectorError> {
+        let input = args
+            .get("domain")
+            .or_else(|| args.get("url"))
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .ok_or_else(|| {
+                ConnectorError::InvalidParams("Missing 'domain' parameter".to_string())
+            })?;
+        let page = if input.contains("://") {
+            url::Url::parse(input)
+        } else {
+            url::Url::parse(&format!("https://{}", input))
+        }
+        .map_err(|e| ConnectorError::InvalidParams(format!("Invalid domain: {}", e)))?;
+        let origin = page.origin().ascii_serialization();
+        let number = |key: &str, default: u64, max: u64| {
+            args.get(key)
+                .and_then(|v| v.as_u64())
+                .unwrap_or(default)
+                .min(max) as usize
+        };
+        let max_urls = number("max_urls", DISCOVER_DEFAULT_URLS, DISCOVER_MAX_URLS);
+        let max_sitemaps = number(
+            "max_sitemaps",
+            DISCOVER_DEFAULT_SITEMAPS,
+            DISCOVER_MAX_SITEMAPS,
+        );
+        let probe_feeds = args
+            .get("probe_feeds")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+        let user_agent = self.user_agent(&self.browser);
+
+        // Sitemaps, breadth-first through any indexes
+        let listed = match self
+            .get_text(&format!("{}/robots.txt", origin), &user_agent)
+            .await
+        {
+            Some(body) => discover::robots_sitemaps(&body),
+            None => Vec::new(),
+        };
+        let mut queue: VecDeque<(String, &str)> = if listed.is_empty() {
+            discover::DEFAULT_SITEMAP_PATHS
+                .iter()
+                .map(|path| (format!("{}{}", origin, path), "default"))
+                .collect()
+        } else {
+            listed.into_iter().map(|url| (url, "robots")).collect()
+        };
+        let mut seen = HashSet::new();
+        let mut sitemaps = Vec::new();
+        let mut urls = Vec::new();
+        let mut truncated = false;
+        while let Some((sitemap_url, source)) = queue.pop_front() {
+            if !seen.insert(sitemap_url.clone()) {
+                continue;
+            }
+            if sitemaps.len() >= max_sitemaps || urls.len() >= max_urls {
+                truncated = true;
+                break;
+            }
+            if sitemap_url.ends_with(".gz") {
+                sitemaps.push(json!({
+                    "url": sitemap_url, "source": source, "error": "gzip sitemaps are not read"
+                }));
+                continue;
+            }
+            let parsed = self
+                .get_text(&sitemap_url, &user_agent)
+                .await
+                .and_then(|body| discover::parse_sitemap(&body));
+            let Some(parsed) = parsed else {
+                // Guessed locations are expected to be missing
+                if source != "default" {
+                    sitemaps.push(json!({
+                        "url": sitemap_url, "source": source, "error": "missing or not a sitemap"
+                    }));
+                }
+                continue;
+            };
+            sitemaps.push(json!({
+                "url": sitemap_url,
+                "source": source,
+                "kind": if parsed.is_index { "index" } else { "urlset" },
+                "entries": parsed.entries.len(),
+            }));
+            for entry in parsed.entries {
+                if parsed.is_index {
+                    queue.push_back((entry.loc, "index"));
+                } else if urls.len() < max_urls {
+                    urls.push(json!({
+                        "loc": entry.loc,
+                        "lastmod": entry.lastmod,
+                        "sitemap": sitemap_url,
+                    }));
+                } else {
+                    truncated = true;
+                }
+            }
+        }
+
+        // Feeds advertised by the page, else conventional paths
+        let mut feeds = match self.get_text(page.as_str(), &user_agent).await {
+            Some(html) => discover::feed_links(&html, &page),
+            None => Vec::new(),
+        };
+        if feeds.is_empty() && probe_feeds {
+            for path in discover::COMMON_FEED_PATHS {
+                let url = format!("{}{}", origin, path);
+                let format = self
+                    .get_text(&url, &user_agent)
+                    .await
+                    .and_then(|body| discover::sniff_feed(&body));
+                if let Some(format) = format {
+                    feeds.push(discover::FeedCandidate {
+                        url,
+                        title: None,
+                        format,
+                        source: "probe",
+                    });
+                }
+            }
+        }
+
+        Ok(json!({
+            "origin": origin,
+            "sitemaps": sitemaps,
+            "urls": urls,
+            "feeds": feeds,
+            "truncated": truncated,
+        }))
+    }
+
     async fn resolve_browser_override(
         &self,
         browser_name: Option<&str>,
@@ -487,6 +985,12 @@ impl Connector for WebConnector {
             self.headers.insert(COOKIE, cookie_header);
         }
 
+        self.chrome_path = details
+            .get("chrome_path")
+            .cloned()
+            .or_else(|| std::env::var("ARIVU_CHROME_PATH").ok())
+            .filter(|p| !p.trim().is_empty());
+
         self.cookie_cache.lock().await.clear();
         Ok(())
     }
@@ -497,7 +1001,7 @@ impl Connector for WebConnector {
 
     async fn test_auth(&self) -> Result<(), ConnectorError> {
         // Test scraping a simple website without requiring cookies
-        self.scrape_url("https://example.com", &self.browser, None)
+        self.scrape_url("https://example.com", &self.browser, None, false)
             .await?;
         Ok(())
     }
@@ -543,6 +1047,17 @@ impl Connector for WebConnector {
                     ),
                     options: None,
                 },
+                Field {
+                    name: "chrome_path".to_string(),
+                    label: "Chrome Path".to_string(),
+                    field_type: FieldType::Text,
+                    required: false,
+                    description: Some(
+                        "Chrome/Chromium binary for render=true (web-render builds). Defaults to ARIVU_CHROME_PATH, then PATH lookup."
+                            .into(),
+                    ),
+                    options: None,
+                },
             ],
         }
     }
@@ -558,7 +1073,8 @@ impl Connector for WebConnector {
                     title: None,
                     description: Some(Cow::Borrowed(
                         "Extract readable text + basic metadata from a URL. Use when you want \
-the main page content (not structured scraping). Example: url=\"https://example.com\".",
+the main page content (not structured scraping). PDF, DOCX and EPUB URLs are detected and \
+returned as text with one heading per page or chapter. Example: url=\"https://example.com\".",
                     )),
                     annotations: None,
                     input_schema: Arc::new(json!({
@@ -578,6 +1094,17 @@ the main page content (not structured scraping). Example: url=\"https://example.
                             "description": "Override the browser profile used to resolve cookies and user agent",
                             "enum": ["firefox", "chrome", "safari", "brave"],
                             "default": "firefox"
+                        },
+                        "render": {
+                            "type": "boolean",
+                            "description": "Render with headless Chrome so JavaScript-built pages have content; slower, and cookies are not sent (needs the web-render build feature)",
+                            "default": false
+                        },
+                        "extract": {
+                            "type": "string",
+                            "enum": ["main", "article"],
+                            "description": "main: the page's main container as markdown. article: readability extraction returning title, byline, published_date, site_name, excerpt and a clean markdown body",
+                            "default": "main"
                         }
                     },
                         "required": ["url"]
@@ -652,12 +1179,113 @@ you need specific fields (e.g., title/price) and scrape_url is too noisy.",
                                 "type": "string",
                                 "description": "Override the browser profile used to resolve cookies and user agent",
                                 "enum": ["firefox", "chrome", "safari", "brave"]
+                            },
+                            "render": {
+                                "type": "boolean",
+                                "description": "Render with headless Chrome before applying selectors (needs the web-render build feature)",
+                                "default": false
                             }
                         },
                         "required": ["tool"]
                     }).as_object().expect("Schema object").clone()),
                     output_schema: None,
                     icons: None,
+                },
+                Tool {
+                    name: Cow::Borrowed("crawl"),
+                    title: None,
+                    description: Some(Cow::Borrowed(
+                        "Crawl a site breadth-first from a seed URL and return each page's \
+extracted content plus the link graph between pages. Stays on the seed's domain and honours \
+robots.txt by default. Reports progress and stops early (returning partial results) when the \
+call is cancelled. Example: url=\"https://docs.example.com\" max_pages=30 max_depth=2.",
+                    )),
+                    annotations: None,
+                    input_schema: Arc::new(json!({
+                        "type": "object",
+                        "properties": {
+                            "url": {
+                                "type": "string",
+                                "description": "Seed URL"
+                            },
+                            "max_pages": {
+                                "type": "integer",
+                                "description": "Pages to fetch (max 200)",
+                                "minimum": 1,
+                                "maximum": 200,
+                                "default": 20
+                            },
+                            "max_depth": {
+                                "type": "integer",
+                                "description": "Link hops from the seed (0 fetches only the seed; max 5)",
+                                "minimum": 0,
+                                "maximum": 5,
+                                "default": 2
+                            },
+                            "same_domain": {
+                                "type": "boolean",
+                                "description": "Only follow links on the seed's host (www. ignored)",
+                                "default": true
+                            },
+                            "respect_robots": {
+                                "type": "boolean",
+                                "description": "Skip pages robots.txt disallows",
+                                "default": true
+                            },
+                            "extract": {
+                                "type": "string",
+                                "enum": ["main", "article"],
+                                "description": "Per-page extraction, as in scrape_url",
+                                "default": "main"
+                            },
+                            "max_chars_per_page": {
+                                "type": "integer",
+                                "description": "Truncate each page's content to this many characters",
+                                "default": 5000
+                            }
+                        },
+                        "required": ["url"]
+                    }).as_object().expect("Schema object").clone()),
+                    output_schema: None,
+                    icons: None,
+                },
+                Tool {
+                    name: Cow::Borrowed("discover"),
+                    title: None,
+                    description: Some(Cow::Borrowed(
+                        "Find a site's sitemap URLs and RSS/Atom/JSON feeds. Reads Sitemap lines \
+in robots.txt (else /sitemap.xml), follows sitemap indexes, and collects feeds linked from the \
+page or found at common paths. Use the URLs to pick pages for crawl/scrape_url and the feeds \
+with the rss connector. Example: domain=\"blog.rust-lang.org\".",
+                    )),
+                    annotations: None,
+                    input_schema: Arc::new(json!({
+                        "type": "object",
+                        "properties": {
+                            "domain": {
+                                "type": "string",
+                                "description": "Domain or URL; a URL with a path is also checked for feed links"
+                            },
+                            "max_urls": {
+                                "type": "integer",
+                                "description": "Sitemap page URLs to return (max 5000)",
+                                "default": 200
+                            },
+                            "max_sitemaps": {
+                                "type": "integer",
+                                "description": "Sitemap files to read, including indexes (max 50)",
+                                "default": 10
+                            },
+                            "probe_feeds": {
+                                "type": "boolean",
+                                "description": "Try /feed, /rss.xml, /atom.xml, /feed.xml and /index.xml when the page links no feed",
+                                "default": true
+                            }
+                        },
+                        "required": ["domain"]
+                    }).as_object().expect("Schema object").clone()),
+                    output_schema: None,
+                    icons: None,
                 }
             ],
             next_cursor: None,
@@ -702,9 +1330,45 @@ you need specific fields (e.g., title/price) and scrape_url is too noisy.",
                     "executing scrape"
                 );
 
-                let content = self.scrape_url(url, &browser, cookies.as_deref()).await?;
-
-                let text = serde_json::to_string(&content)?;
+                let render = render_arg(&args)?;
+                let text = match args.get("extract").and_then(|v| v.as_str()) {
+                    None | Some("main") => {
+                        let content = self
+                            .scrape_url(url, &browser, cookies.as_deref(), render)
+                            .await?;
+                        serde_json::to_string(&content)?
+                    }
+                    Some("article") => {
+                        let fetched = self
+                            .fetch_page(url, &browser, cookies.as_deref(), render)
+                            .await?;
+                        let article = match fetched {
+                            Fetched::Html(html) => {
+                                let url = url.to_string();
+                                crate::cpu_pool::spawn_cpu(move || Ok(extract_article(&html, &url)))
+                                    .await?
+                            }
+                            // Documents have no boilerplate to strip
+                            Fetched::Document { doc, .. } => {
+                                let content = document::document_markdown(&doc);
+                                Article {
+                                    url: url.to_string(),
+                                    title: doc.title,
+                                    word_count: content.split_whitespace().count(),
+                                    content,
+                                    ..Article::default()
+                                }
+                            }
+                        };
+                        serde_json::to_string(&article)?
+                    }
+                    Some(other) => {
+                        return Err(ConnectorError::InvalidParams(format!(
+                            "extract must be 'main' or 'article', got '{}'",
+                            other
+                        )))
+                    }
+                };
                 Ok(CallToolResult::success(text.into_contents()))
             }
             "scrape_with_config" => {
@@ -753,7 +1417,10 @@ you need specific fields (e.g., title/price) and scrape_url is too noisy.",
                     "executing scrape"
                 );
 
-                let content = self.scrape_url(&url, &browser, cookies.as_deref()).await?;
+                let render = render_arg(&args)?;
+                let content = self
+                    .scrape_url(&url, &browser, cookies.as_deref(), render)
+                    .await?;
 
                 let html = content.content.clone();
 
@@ -867,6 +1534,16 @@ you need specific fields (e.g., title/price) and scrape_url is too noisy.",
                 let text = serde_json::to_string(&results)?;
                 Ok(CallToolResult::success(text.into_contents()))
             }
+            "crawl" => {
+                let result = self.crawl(&args).await?;
+                let text = serde_json::to_string(&result)?;
+                Ok(CallToolResult::success(text.into_contents()))
+            }
+            "discover" => {
+                let result = self.discover(&args).await?;
+                let text = serde_json::to_string(&result)?;
+                Ok(CallToolResult::success(text.into_contents()))
+            }
             _ => Err(ConnectorError::ToolNotFound),
         }
     }
@@ -987,11 +1664,53 @@ pub fn find_main_content(html: &Html) -> String {
     String::new()
 }
 
-pub fn html_to_markdown(html: &str) -> String {
-    let converter = HtmlToMarkdown::builder()
-        .skip_tags(vec![
-            "script", "style", "nav", "footer", "header", "aside", "img", "a", "href", "src",
-        ])
-        .build();
-    converter.convert(html).unwrap_or_else(|_| html.to_string())
+/// Page content as markdown, without site chrome or images; links are resolved against the page URL.
+fn page_markdown(html: &str, url: &str) -> String {
+    let options = MarkdownOptions {
+        images: false,
+        base_url: url::Url::parse(url).ok(),
+        skip_tags: vec!["nav", "footer", "header", "aside"],
+        ..MarkdownOptions::default()
+    };
+    html_to_markdown_with(html, &options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_render_arg() {
+        let args = |value: serde_json::Value| value.as_object().cloned().unwrap();
+        assert!(!render_arg(&args(json!({}))).unwrap());
+        assert!(!render_arg(&args(json!({ "render": null }))).unwrap());
+        assert!(render_arg(&args(json!({ "render": true }))).unwrap());
+        for bad in [json!("yes"), json!(1), json!({})] {
+            assert!(matches!(
+                render_arg(&args(json!({ "render": bad }))),
+                Err(ConnectorError::InvalidParams(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn render_without_a_browser_fails_with_a_hint() {
+        let auth = AuthDetails::from([(
+            "chrome_path".to_string(),
+            "/nonexistent/arivu-chrome".to_string(),
+        )]);
+        let connector = WebConnector::new(auth).await.unwrap();
+        let request = CallToolRequestParam {
+            name: Cow::Borrowed("scrape_url"),
+            arguments: json!({ "url": "https://example.com/", "render": true })
+                .as_object()
+                .cloned(),
+        };
+        // Without the web-render feature this is the build hint, with it the
+        // launch failure; either way no plain HTTP fetch is attempted instead.
+        match connector.call_tool(request).await {
+            Err(ConnectorError::Other(message)) => assert!(message.contains("render=true")),
+            other => panic!("expected a render error, got {:?}", other.map(|_| ())),
+        }
+    }
 }
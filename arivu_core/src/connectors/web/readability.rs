@@ -0,0 +1,372 @@
+// src/connectors/web/readability.rs
+//
+// Readability-style article extraction. Paragraph-like blocks score their
+// ancestors by length and comma count, class/id hints and link density
+// adjust the scores, and the best container plus its related siblings is
+// cleaned of boilerplate and converted to markdown.
+
+use std::collections::HashMap;
+
+use scraper::{ElementRef, Html, Node, Selector};
+use serde::{Deserialize, Serialize};
+
+/// Shorter blocks are captions, buttons and labels rather than prose.
+const MIN_PARAGRAPH_CHARS: usize = 25;
+
+const NEGATIVE_HINTS: &[&str] = &[
+    "comment",
+    "footer",
+    "sidebar",
+    "widget",
+    "nav",
+    "menu",
+    "share",
+    "social",
+    "related",
+    "promo",
+    "sponsor",
+    "advert",
+    "banner",
+    "cookie",
+    "subscribe",
+    "newsletter",
+    "popup",
+    "breadcrumb",
+    "masthead",
+    "pagination",
+    "skip",
+];
+const POSITIVE_HINTS: &[&str] = &[
+    "article", "body", "content", "entry", "main", "post", "story", "text", "prose",
+];
+const DROP_TAGS: &[&str] = &[
+    "script", "style", "noscript", "nav", "aside", "footer", "header", "form", "button", "iframe",
+    "svg", "input", "select", "textarea", "object", "embed", "template",
+];
+const VOID_TAGS: &[&str] = &["br", "hr", "img", "wbr"];
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct Article {
+    pub url: String,
+    pub title: Option<String>,
+    pub byline: Option<String>,
+    pub published_date: Option<String>,
+    pub site_name: Option<String>,
+    pub excerpt: Option<String>,
+    /// Article body as markdown
+    pub content: String,
+    pub word_count: usize,
+}
+
+pub fn extract_article(html: &str, url: &str) -> Article {
+    let doc = Html::parse_document(html);
+    let body_html = best_content(&doc);
+    let content = article_markdown(&body_html, url);
+    Article {
+        url: url.to_string(),
+        title: title(&doc),
+        byline: byline(&doc),
+        published_date: first_attr(
+            &doc,
+            &[
+                ("meta[property='article:published_time']", "content"),
+                ("meta[itemprop='datePublished']", "content"),
+                ("meta[name='date']", "content"),
+                ("meta[name='parsely-pub-date']", "content"),
+                ("time[datetime]", "datetime"),
+            ],
+        ),
+        site_name: first_attr(&doc, &[("meta[property='og:site_name']", "content")]),
+        excerpt: first_attr(
+            &doc,
+            &[
+                ("meta[name='description']", "content"),
+                ("meta[property='og:description']", "content"),
+            ],
+        ),
+        word_count: content.split_whitespace().count(),
+        content,
+    }
+}
+
+fn select_first<'a>(doc: &'a Html, selector: &str) -> Option<ElementRef<'a>> {
+    Selector::parse(selector)
+        .ok()
+        .and_then(|s| doc.select(&s).next())
+}
+
+fn first_attr(doc: &Html, candidates: &[(&str, &str)]) -> Option<String> {
+    candidates.iter().find_map(|(selector, attr)| {
+        select_first(doc, selector)
+            .and_then(|el| el.value().attr(attr))
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    })
+}
+
+fn element_text(el: ElementRef) -> String {
+    el.text()
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// og:title, else the longest segment of `<title>` (sites append their
+/// name after a separator), else the first h1.
+fn title(doc: &Html) -> Option<String> {
+    if let Some(og) = first_attr(doc, &[("meta[property='og:title']", "content")]) {
+        return Some(og);
+    }
+    let raw = select_first(doc, "title").map(element_text);
+    if let Some(raw) = raw.filter(|t| !t.is_empty()) {
+        let separators = [" | ", " - ", " – ", " — ", " :: ", " · "];
+        return Some(
+            separators
+                .iter()
+                .find(|sep| raw.contains(**sep))
+                .and_then(|sep| {
+                    raw.split(*sep)
+                        .map(str::trim)
+                        .max_by_key(|part| part.chars().count())
+                })
+                .unwrap_or(raw.as_str())
+                .to_string(),
+        );
+    }
+    select_first(doc, "h1")
+        .map(element_text)
+        .filter(|t| !t.is_empty())
+}
+
+fn byline(doc: &Html) -> Option<String> {
+    if let Some(author) = first_attr(
+        doc,
+        &[
+            ("meta[name='author']", "content"),
+            ("meta[property='article:author']", "content"),
+        ],
+    )
+    .filter(|a| !a.starts_with("http"))
+    {
+        return Some(author);
+    }
+    [
+        "[rel='author']",
+        "[itemprop='author']",
+        ".byline",
+        ".author",
+    ]
+    .iter()
+    .filter_map(|s| select_first(doc, s))
+    .map(element_text)
+    .find(|t| !t.is_empty() && t.chars().count() <= 100)
+}
+
+fn hint_weight(el: ElementRef) -> f64 {
+    let hint = format!(
+        "{} {}",
+        el.value().attr("class").unwrap_or_default(),
+        el.value().attr("id").unwrap_or_default()
+    )
+    .to_ascii_lowercase();
+    let mut weight = 0.0;
+    if NEGATIVE_HINTS.iter().any(|h| hint.contains(h)) {
+        weight -= 25.0;
+    }
+    if POSITIVE_HINTS.iter().any(|h| hint.contains(h)) {
+        weight += 25.0;
+    }
+    weight
+}
+
+fn initial_score(el: ElementRef) -> f64 {
+    let base = match el.value().name() {
+        "article" => 10.0,
+        "div" | "section" | "main" => 5.0,
+        "pre" | "td" | "blockquote" => 3.0,
+        "form" | "ol" | "ul" | "dl" | "li" => -3.0,
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "th" => -5.0,
+        _ => 0.0,
+    };
+    base + hint_weight(el)
+}
+
+fn text_len(el: ElementRef) -> usize {
+    el.text().map(|t| t.trim().chars().count()).sum()
+}
+
+fn link_density(el: ElementRef) -> f64 {
+    let total = text_len(el);
+    if total == 0 {
+        return 0.0;
+    }
+    let links: usize = Selector::parse("a")
+        .map(|a| el.select(&a).map(text_len).sum())
+        .unwrap_or(0);
+    links as f64 / total as f64
+}
+
+fn parent_element(el: ElementRef) -> Option<ElementRef> {
+    el.parent().and_then(ElementRef::wrap)
+}
+
+/// Cleaned HTML of the highest-scoring container and its related siblings;
+/// the whole body when nothing scores.
+fn best_content(doc: &Html) -> String {
+    let Ok(blocks) = Selector::parse("p, pre, blockquote") else {
+        return String::new();
+    };
+    let mut scores = HashMap::new();
+    for block in doc.select(&blocks) {
+        let text = block.text().collect::<String>();
+        let len = text.trim().chars().count();
+        if len < MIN_PARAGRAPH_CHARS {
+            continue;
+        }
+        let score = 1.0 + text.matches(',').count() as f64 + (len / 100).min(3) as f64;
+        let mut ancestor = parent_element(block);
+        for divider in [1.0, 2.0, 3.0] {
+            let Some(el) = ancestor else { break };
+            if matches!(el.value().name(), "body" | "html") {
+                break;
+            }
+            *scores.entry(el.id()).or_insert_with(|| initial_score(el)) += score / divider;
+            ancestor = parent_element(el);
+        }
+    }
+
+    let scored = |el: ElementRef| -> Option<f64> {
+        let score = *scores.get(&el.id())?;
+        Some(score * (1.0 - link_density(el)))
+    };
+    let top = scores
+        .keys()
+        .filter_map(|id| doc.tree.get(*id).and_then(ElementRef::wrap))
+        .filter_map(|el| Some((el, scored(el)?)))
+        .max_by(|a, b| a.1.total_cmp(&b.1));
+    let Some((top, top_score)) = top else {
+        return select_first(doc, "body")
+            .map(clean_html)
+            .unwrap_or_default();
+    };
+
+    let threshold = (top_score * 0.2).max(10.0);
+    let mut out = String::new();
+    let siblings = top
+        .parent()
+        .map(|p| {
+            p.children()
+                .filter_map(ElementRef::wrap)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_else(|| vec![top]);
+    for sibling in siblings {
+        let related = sibling.id() == top.id()
+            || scored(sibling).is_some_and(|s| s >= threshold)
+            || (sibling.value().name() == "p"
+                && text_len(sibling) > 80
+                && link_density(sibling) < 0.25);
+        if related {
+            out.push_str(&clean_html(sibling));
+        }
+    }
+    out
+}
+
+fn clean_html(el: ElementRef) -> String {
+    let mut out = String::new();
+    clean_into(el, &mut out);
+    out
+}
+
+/// Re-serialize `el` without boilerplate elements or attributes other than
+/// link targets.
+fn clean_into(el: ElementRef, out: &mut String) {
+    let name = el.value().name();
+    if DROP_TAGS.contains(&name) {
+        return;
+    }
+    let boilerplate = hint_weight(el) < 0.0 && (link_density(el) > 0.3 || text_len(el) < 200);
+    if boilerplate {
+        return;
+    }
+    match (name, el.value().attr("href")) {
+        ("a", Some(href)) => out.push_str(&format!(
+            "<a href=\"{}\">",
+            html_escape::encode_double_quoted_attribute(href)
+        )),
+        _ => out.push_str(&format!("<{}>", name)),
+    }
+    if VOID_TAGS.contains(&name) {
+        return;
+    }
+    for child in el.children() {
+        match child.value() {
+            Node::Text(text) => out.push_str(&html_escape::encode_text(&**text)),
+            Node::Element(_) => {
+                if let Some(child) = ElementRef::wrap(child) {
+                    clean_into(child, out);
+                }
+            }
+            _ => {}
+        }
+    }
+    out.push_str(&format!("</{}>", name));
+}
+
+fn article_markdown(html: &str, url: &str) -> String {
+    let options = crate::utils::MarkdownOptions {
+        images: false,
+        base_url: url::Url::parse(url).ok(),
+        ..Default::default()
+    };
+    crate::utils::html_to_markdown_with(html, &options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_article_without_boilerplate() {
+        let html = r#"<html><head>
+            <title>Why tides happen | The Example Times</title>
+            <meta name="author" content="Ada Lovelace">
+            <meta property="article:published_time" content="2024-05-01T08:00:00Z">
+            <meta property="og:site_name" content="The Example Times">
+        </head><body>
+            <nav><a href="/">Home</a> <a href="/world">World</a> <a href="/science">Science</a></nav>
+            <div class="layout">
+              <div class="sidebar-widget">
+                <a href="/a">Ten facts about the moon you never knew about</a>
+                <a href="/b">Subscribe to our newsletter today for more</a>
+              </div>
+              <div class="article-body">
+                <h2>The pull of the moon</h2>
+                <p>Tides are caused by the gravitational pull of the moon, and to a lesser extent the sun, acting on the oceans.</p>
+                <p>Because the pull is strongest on the side facing the moon, water bulges there, and a second bulge forms opposite it.</p>
+                <p>As the earth rotates, coastlines pass through both bulges, which is why most places see two high tides a day, as <a href="https://example.org/noaa">NOAA explains</a>.</p>
+                <div class="share-buttons"><a href="/share">Share</a></div>
+              </div>
+            </div>
+            <footer>Copyright, all rights reserved, and more legal text here</footer>
+        </body></html>"#;
+        let article = extract_article(html, "https://example.com/tides");
+        assert_eq!(article.title.as_deref(), Some("Why tides happen"));
+        assert_eq!(article.byline.as_deref(), Some("Ada Lovelace"));
+        assert_eq!(
+            article.published_date.as_deref(),
+            Some("2024-05-01T08:00:00Z")
+        );
+        assert_eq!(article.site_name.as_deref(), Some("The Example Times"));
+        assert!(article.content.contains("gravitational pull of the moon"));
+        assert!(article.content.contains("two high tides a day"));
+        assert!(article.content.contains("(https://example.org/noaa)"));
+        for noise in ["Home", "Ten facts", "newsletter", "Share", "Copyright"] {
+            assert!(!article.content.contains(noise), "kept {}", noise);
+        }
+        assert!(article.word_count > 40);
+    }
+}
@@ -0,0 +1,76 @@
+// src/connectors/web/render.rs
+//
+// Headless Chrome rendering for pages that only build their content with
+// JavaScript. Compiled with the `web-render` feature; needs a local Chrome
+// or Chromium, found on PATH unless a path is configured.
+
+use std::time::Duration;
+
+use chromiumoxide::browser::{Browser, BrowserConfig};
+use futures::StreamExt;
+
+use crate::error::ConnectorError;
+
+/// Whole-render budget: launch, navigation, settle and serialization.
+const RENDER_TIMEOUT: Duration = Duration::from_secs(45);
+/// Time after the load event for late XHR-driven content to arrive.
+const SETTLE: Duration = Duration::from_millis(1_500);
+
+/// The page's serialized DOM after scripts have run.
+pub(super) async fn render_html(
+    url: &str,
+    user_agent: &str,
+    chrome_path: Option<&str>,
+) -> Result<String, ConnectorError> {
+    let mut config = BrowserConfig::builder()
+        .request_timeout(RENDER_TIMEOUT)
+        .arg(format!("--user-agent={}", user_agent));
+    if let Some(path) = chrome_path {
+        config = config.chrome_executable(path);
+    }
+    let config = config
+        .build()
+        .map_err(|e| ConnectorError::Other(format!("headless browser config: {}", e)))?;
+
+    let (mut browser, mut handler) = Browser::launch(config).await.map_err(|e| {
+        ConnectorError::Other(format!(
+            "could not launch Chrome/Chromium for render=true (set chrome_path or ARIVU_CHROME_PATH): {}",
+            e
+        ))
+    })?;
+    let events = tokio::spawn(async move {
+        while let Some(event) = handler.next().await {
+            if event.is_err() {
+                break;
+            }
+        }
+    });
+
+    let rendered = tokio::time::timeout(RENDER_TIMEOUT, async {
+        let page = browser
+            .new_page(url)
+            .await
+            .map_err(|e| ConnectorError::Other(format!("render {}: {}", url, e)))?;
+        page.wait_for_navigation()
+            .await
+            .map_err(|e| ConnectorError::Other(format!("render {}: {}", url, e)))?;
+        tokio::time::sleep(SETTLE).await;
+        page.content()
+            .await
+            .map_err(|e| ConnectorError::Other(format!("render {}: {}", url, e)))
+    })
+    .await
+    .unwrap_or_else(|_| {
+        Err(ConnectorError::Other(format!(
+            "render {} timed out after {}s",
+            url,
+            RENDER_TIMEOUT.as_secs()
+        )))
+    });
+
+    // Always reap the Chrome process, even when rendering failed
+    let _ = browser.close().await;
+    let _ = browser.wait().await;
+    events.abort();
+    rendered
+}
@@ -1,6 +1,8 @@
 use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
 use crate::error::ConnectorError;
-use crate::utils::{collect_paginated, structured_result_with_text, Page};
+use crate::utils::{
+    collect_paginated, collect_paginated_with_cursor, structured_result_with_text, Collected, Page,
+};
 use crate::{auth::AuthDetails, Connector};
 use async_trait::async_trait;
 use reqwest::Client;
@@ -11,6 +13,8 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+mod wikitext;
+
 /// Response format for controlling output verbosity
 #[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
@@ -28,6 +32,8 @@ struct SearchArgs {
     limit: u32,
     #[serde(default)]
     response_format: ResponseFormat,
+    #[serde(default)]
+    language: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -36,6 +42,19 @@ struct GeoSearchArgs {
     longitude: f64,
     #[serde(default = "default_radius")]
     radius: u16,
+    #[serde(default)]
+    language: Option<String>,
+}
+
+/// Text rendering for article content
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+enum ArticleFormat {
+    /// Plain text extract (`== Heading ==` section markers)
+    #[default]
+    Text,
+    /// Wikitext converted to markdown (links, emphasis, lists, headings)
+    Markdown,
 }
 
 #[derive(Debug, Deserialize)]
@@ -43,12 +62,51 @@ struct GetArticleArgs {
     title: String,
     #[serde(default)]
     response_format: ResponseFormat,
+    #[serde(default)]
+    language: Option<String>,
+    /// Section heading to return instead of the whole article
+    #[serde(default)]
+    section: Option<String>,
+    #[serde(default)]
+    format: ArticleFormat,
+}
+
+#[derive(Debug, Deserialize)]
+struct CategoryMembersArgs {
+    category: String,
+    #[serde(default = "default_graph_limit")]
+    limit: u32,
+    /// Member kinds: any of "page", "subcat", "file"
+    #[serde(default)]
+    types: Option<Vec<String>>,
+    #[serde(default)]
+    cursor: Option<String>,
+    #[serde(default)]
+    language: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LinksArgs {
+    title: String,
+    #[serde(default = "default_graph_limit")]
+    limit: u32,
+    /// Namespace id to restrict to (default: 0, articles)
+    #[serde(default)]
+    namespace: Option<i64>,
+    #[serde(default)]
+    cursor: Option<String>,
+    #[serde(default)]
+    language: Option<String>,
 }
 
 fn default_limit() -> u32 {
     10
 }
 
+fn default_graph_limit() -> u32 {
+    100
+}
+
 fn default_radius() -> u16 {
     1000
 }
@@ -63,6 +121,10 @@ pub struct WikipediaConnector {
 const MAX_SEARCH_LIMIT: u32 = 5_000;
 const MAX_SEARCH_REQUESTS: usize = 100;
 const MAX_SR_LIMIT_PER_REQUEST: u32 = 50;
+// Link-graph listings: anonymous clients get up to 500 per request
+const MAX_GRAPH_LIMIT: u32 = 5_000;
+const MAX_GRAPH_LIMIT_PER_REQUEST: usize = 500;
+const MAX_GRAPH_REQUESTS: usize = 20;
 
 impl WikipediaConnector {
     pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
@@ -85,8 +147,87 @@ impl WikipediaConnector {
     }
 
     // Helper method to get the base API URL
-    fn base_url(&self) -> String {
-        format!("https://{}.wikipedia.org/w/api.php", self.language)
+    fn base_url(&self, language: &str) -> String {
+        format!("https://{}.wikipedia.org/w/api.php", language)
+    }
+
+    /// The language edition for a call: the `language` argument when given,
+    /// otherwise the configured default.
+    fn language_for(&self, requested: Option<&str>) -> Result<String, ConnectorError> {
+        let language = requested
+            .map(|l| l.trim().to_lowercase())
+            .filter(|l| !l.is_empty())
+            .unwrap_or_else(|| self.language.clone());
+        // Edition codes look like "en", "simple", "zh-yue", "be-tarask"
+        let valid = language.len() <= 16
+            && language.starts_with(|c: char| c.is_ascii_lowercase())
+            && language.chars().all(|c| c.is_ascii_lowercase() || c == '-');
+        if !valid {
+            return Err(ConnectorError::InvalidParams(format!(
+                "Invalid Wikipedia language code: '{}'",
+                language
+            )));
+        }
+        Ok(language)
+    }
+
+    /// Page through a `list=` or `prop=` query, following the API's
+    /// `continue` object. `cursor` resumes a previous call.
+    async fn query_graph(
+        &self,
+        language: &str,
+        params: Vec<(&'static str, String)>,
+        limit_param: &'static str,
+        limit: u32,
+        cursor: Option<String>,
+        extract: fn(&Value) -> Vec<Value>,
+    ) -> Result<Collected<Value, String>, ConnectorError> {
+        let desired = limit.clamp(1, MAX_GRAPH_LIMIT) as usize;
+        collect_paginated_with_cursor(
+            desired,
+            MAX_GRAPH_REQUESTS,
+            cursor,
+            |cursor, remaining| {
+                let mut params: Vec<(String, String)> = params
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.clone()))
+                    .collect();
+                async move {
+                    params.push((
+                        limit_param.to_string(),
+                        remaining.min(MAX_GRAPH_LIMIT_PER_REQUEST).to_string(),
+                    ));
+                    params.push(("action".to_string(), "query".to_string()));
+                    params.push(("format".to_string(), "json".to_string()));
+                    params.push(("formatversion".to_string(), "2".to_string()));
+                    params.extend(cursor.as_deref().map(decode_continue).unwrap_or_default());
+
+                    let data: Value = self
+                        .client
+                        .get(self.base_url(language))
+                        .query(&params)
+                        .send()
+                        .await
+                        .map_err(ConnectorError::HttpRequest)?
+                        .json()
+                        .await
+                        .map_err(ConnectorError::HttpRequest)?;
+                    if let Some(error) = data.get("error") {
+                        return Err(ConnectorError::InvalidParams(format!(
+                            "Wikipedia API error: {}",
+                            error["info"].as_str().unwrap_or("unknown")
+                        )));
+                    }
+
+                    Ok::<_, ConnectorError>(Page {
+                        items: extract(&data),
+                        next_cursor: encode_continue(&data),
+                    })
+                }
+            },
+            |item: &Value| item["title"].as_str().map(str::to_string),
+        )
+        .await
     }
 
     // Helper method to format article content
@@ -113,6 +254,7 @@ impl WikipediaConnector {
         &self,
         query: &str,
         limit: u32,
+        language: &str,
     ) -> Result<Vec<String>, ConnectorError> {
         if limit == 0 {
             return Ok(Vec::new());
@@ -143,7 +285,7 @@ impl WikipediaConnector {
 
                 let response = self
                     .client
-                    .get(self.base_url())
+                    .get(self.base_url(language))
                     .query(&params)
                     .send()
                     .await
@@ -166,6 +308,7 @@ impl WikipediaConnector {
         latitude: f64,
         longitude: f64,
         radius: u16,
+        language: &str,
     ) -> Result<Vec<String>, ConnectorError> {
         if !(-90.0..=90.0).contains(&latitude) {
             return Err(ConnectorError::InvalidParams(
@@ -194,7 +337,7 @@ impl WikipediaConnector {
 
         let response = self
             .client
-            .get(self.base_url())
+            .get(self.base_url(language))
             .query(&params)
             .send()
             .await
@@ -221,10 +364,15 @@ impl WikipediaConnector {
     }
 
     // Get article content
-    async fn get_article_content(&self, title: &str) -> Result<String, ConnectorError> {
+    async fn get_article_content(
+        &self,
+        title: &str,
+        language: &str,
+    ) -> Result<String, ConnectorError> {
         let params = [
             ("prop", "extracts"),
             ("explaintext", ""),
+            ("exsectionformat", "wiki"),
             ("redirects", ""),
             ("titles", title),
             ("format", "json"),
@@ -233,7 +381,7 @@ impl WikipediaConnector {
 
         let response = self
             .client
-            .get(self.base_url())
+            .get(self.base_url(language))
             .query(&params)
             .send()
             .await
@@ -269,7 +417,11 @@ impl WikipediaConnector {
     }
 
     // Get article summary
-    async fn get_article_summary(&self, title: &str) -> Result<String, ConnectorError> {
+    async fn get_article_summary(
+        &self,
+        title: &str,
+        language: &str,
+    ) -> Result<String, ConnectorError> {
         let params = [
             ("prop", "extracts"),
             ("explaintext", ""),
@@ -282,7 +434,7 @@ impl WikipediaConnector {
 
         let response = self
             .client
-            .get(self.base_url())
+            .get(self.base_url(language))
             .query(&params)
             .send()
             .await
@@ -310,6 +462,181 @@ impl WikipediaConnector {
 
         Ok(summary)
     }
+
+    /// Raw wikitext of an article (following redirects) with its resolved title.
+    async fn get_article_wikitext(
+        &self,
+        title: &str,
+        language: &str,
+    ) -> Result<(String, String), ConnectorError> {
+        let params = [
+            ("action", "parse"),
+            ("page", title),
+            ("prop", "wikitext"),
+            ("redirects", "1"),
+            ("format", "json"),
+            ("formatversion", "2"),
+        ];
+
+        let data: Value = self
+            .client
+            .get(self.base_url(language))
+            .query(&params)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?
+            .json()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+
+        if let Some(error) = data.get("error") {
+            return match error["code"].as_str() {
+                Some("missingtitle") | Some("invalidtitle") => {
+                    Err(ConnectorError::ResourceNotFound)
+                }
+                _ => Err(ConnectorError::Other(format!(
+                    "Wikipedia parse error: {}",
+                    error["info"].as_str().unwrap_or("unknown")
+                ))),
+            };
+        }
+
+        let parse = &data["parse"];
+        let wikitext = parse["wikitext"]
+            .as_str()
+            .ok_or_else(|| ConnectorError::Other("Invalid response format".to_string()))?;
+        let resolved = parse["title"].as_str().unwrap_or(title);
+        Ok((resolved.to_string(), wikitext.to_string()))
+    }
+
+    /// One section of an article by heading. The inner `Err` lists the
+    /// available headings when no section matches.
+    async fn get_article_section(
+        &self,
+        title: &str,
+        heading: &str,
+        format: ArticleFormat,
+        language: &str,
+    ) -> Result<Result<String, Vec<String>>, ConnectorError> {
+        let source = match format {
+            ArticleFormat::Text => self.get_article_content(title, language).await?,
+            ArticleFormat::Markdown => self.get_article_wikitext(title, language).await?.1,
+        };
+        Ok(match wikitext::slice_section(&source, heading) {
+            Some(body) if format == ArticleFormat::Markdown => {
+                Ok(wikitext::to_markdown(&body, &wiki_base(language)))
+            }
+            Some(body) => Ok(body),
+            None => Err(wikitext::headings(&source)
+                .into_iter()
+                .map(|h| h.title)
+                .collect()),
+        })
+    }
+}
+
+/// Input schema shared by get_links and get_backlinks.
+fn links_schema() -> serde_json::Map<String, Value> {
+    json!({
+        "type": "object",
+        "properties": {
+            "title": {
+                "type": "string",
+                "description": "Exact article title (e.g., 'Rust (programming language)')"
+            },
+            "limit": {
+                "type": "integer",
+                "description": "Maximum links to return (default: 100, max: 5000)"
+            },
+            "namespace": {
+                "type": "integer",
+                "description": "Namespace id to restrict to (default: 0, articles; 14 for categories)"
+            },
+            "cursor": {
+                "type": "string",
+                "description": "next_cursor from a previous call"
+            },
+            "language": {
+                "type": "string",
+                "description": "Wikipedia language edition (e.g., 'de', 'fr', 'simple'); defaults to the configured language"
+            }
+        },
+        "required": ["title"]
+    })
+    .as_object()
+    .expect("Schema object")
+    .clone()
+}
+
+/// Serialize the API's `continue` object as an opaque query-string cursor.
+fn encode_continue(data: &Value) -> Option<String> {
+    let fields = data.get("continue")?.as_object()?;
+    let pairs: Vec<String> = fields
+        .iter()
+        .map(|(k, v)| {
+            let v = v
+                .as_str()
+                .map(str::to_string)
+                .unwrap_or_else(|| v.to_string());
+            format!("{}={}", urlencoding::encode(k), urlencoding::encode(&v))
+        })
+        .collect();
+    (!pairs.is_empty()).then(|| pairs.join("&"))
+}
+
+fn decode_continue(cursor: &str) -> Vec<(String, String)> {
+    cursor
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .filter_map(|(k, v)| {
+            Some((
+                urlencoding::decode(k).ok()?.into_owned(),
+                urlencoding::decode(v).ok()?.into_owned(),
+            ))
+        })
+        .collect()
+}
+
+fn extract_category_members(data: &Value) -> Vec<Value> {
+    data["query"]["categorymembers"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|m| json!({ "title": m["title"], "type": m["type"] }))
+        .collect()
+}
+
+fn extract_links(data: &Value) -> Vec<Value> {
+    data["query"]["pages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .flat_map(|page| page["links"].as_array().into_iter().flatten())
+        .map(|l| json!({ "title": l["title"] }))
+        .collect()
+}
+
+fn extract_backlinks(data: &Value) -> Vec<Value> {
+    data["query"]["backlinks"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|l| json!({ "title": l["title"] }))
+        .collect()
+}
+
+/// Prefix bare category names; localized prefixes ("Kategorie:") pass through.
+fn category_title(category: &str) -> String {
+    let category = category.trim().trim_start_matches(':');
+    if category.contains(':') {
+        category.to_string()
+    } else {
+        format!("Category:{}", category)
+    }
+}
+
+fn wiki_base(language: &str) -> String {
+    format!("https://{}.wikipedia.org/wiki/", language)
 }
 
 fn extract_search_titles(data: &Value) -> Result<Vec<String>, ConnectorError> {
@@ -356,6 +683,55 @@ mod tests {
         assert_eq!(titles, vec!["A".to_string(), "B".to_string()]);
         assert_eq!(extract_search_continue_offset(&data), Some(50));
     }
+
+    #[test]
+    fn extracts_graph_items_and_cursor() {
+        let data = json!({
+            "continue": { "cmcontinue": "page|4142|123", "continue": "-||" },
+            "query": { "categorymembers": [
+                { "ns": 0, "title": "Rust (programming language)", "type": "page" },
+                { "ns": 14, "title": "Category:Mozilla", "type": "subcat" }
+            ]}
+        });
+        let members = extract_category_members(&data);
+        assert_eq!(
+            members[1],
+            json!({ "title": "Category:Mozilla", "type": "subcat" })
+        );
+
+        let cursor = encode_continue(&data).unwrap();
+        let mut decoded = decode_continue(&cursor);
+        decoded.sort();
+        assert_eq!(
+            decoded,
+            vec![
+                ("cmcontinue".to_string(), "page|4142|123".to_string()),
+                ("continue".to_string(), "-||".to_string())
+            ]
+        );
+
+        let links = json!({ "query": { "pages": [
+            { "title": "Rust", "links": [{ "ns": 0, "title": "Cargo" }, { "ns": 0, "title": "LLVM" }] }
+        ]}});
+        assert_eq!(extract_links(&links).len(), 2);
+        assert_eq!(
+            category_title("Programming languages"),
+            "Category:Programming languages"
+        );
+        assert_eq!(
+            category_title("Kategorie:Programmiersprache"),
+            "Kategorie:Programmiersprache"
+        );
+    }
+
+    #[tokio::test]
+    async fn validates_language_editions() {
+        let connector = WikipediaConnector::new(AuthDetails::new()).await.unwrap();
+        assert_eq!(connector.language_for(None).unwrap(), "en");
+        assert_eq!(connector.language_for(Some(" DE ")).unwrap(), "de");
+        assert_eq!(connector.language_for(Some("zh-yue")).unwrap(), "zh-yue");
+        assert!(connector.language_for(Some("en.evil.com/")).is_err());
+    }
 }
 
 #[async_trait]
@@ -400,7 +776,7 @@ impl Connector for WikipediaConnector {
     async fn test_auth(&self) -> Result<(), ConnectorError> {
         // Simple test to check if the API is accessible
         tracing::debug!("Testing Wikipedia connector auth");
-        self.search_articles("test", 1).await?;
+        self.search_articles("test", 1, &self.language).await?;
         tracing::debug!("Wikipedia auth test succeeded");
         Ok(())
     }
@@ -488,7 +864,7 @@ impl Connector for WikipediaConnector {
             }
             let title = parts[3];
 
-            let content = self.get_article_content(title).await?;
+            let content = self.get_article_content(title, &self.language).await?;
             let article_data = self.format_article(title, &content, None);
             let _json_content = serde_json::to_string(&article_data)?;
 
@@ -528,6 +904,10 @@ pass into get_article. Example: query=\"rust language\" limit=5.",
                             "enum": ["concise", "detailed"],
                             "description": "Response verbosity: 'concise' returns only article titles, 'detailed' includes query metadata",
                             "default": "concise"
+                        },
+                        "language": {
+                            "type": "string",
+                            "description": "Wikipedia language edition (e.g., 'de', 'fr', 'simple'); defaults to the configured language"
                         }
                     },
                     "required": ["query"]
@@ -557,6 +937,10 @@ Example: latitude=37.77 longitude=-122.42 radius=1000.",
                         "radius": {
                             "type": "integer",
                             "description": "Search radius in meters (default: 1000)."
+                        },
+                        "language": {
+                            "type": "string",
+                            "description": "Wikipedia language edition (e.g., 'de', 'fr', 'simple'); defaults to the configured language"
                         }
                     },
                     "required": ["latitude", "longitude"]
@@ -570,7 +954,8 @@ Example: latitude=37.77 longitude=-122.42 radius=1000.",
                 title: None,
                 description: Some(Cow::Borrowed(
                     "Get article content by exact title. Use response_format='concise' to keep \
-tokens down. Example: title=\"Rust (programming language)\".",
+tokens down, or section=\"History\" for one section. format=\"markdown\" returns clean markdown \
+instead of plain text; language selects the edition. Example: title=\"Rust (programming language)\".",
                 )),
                 input_schema: Arc::new(json!({
                     "type": "object",
@@ -584,6 +969,20 @@ tokens down. Example: title=\"Rust (programming language)\".",
                             "enum": ["concise", "detailed"],
                             "description": "Response verbosity: 'concise' returns only title and summary (first paragraph), 'detailed' includes full content",
                             "default": "concise"
+                        },
+                        "section": {
+                            "type": "string",
+                            "description": "Return only this section, by heading (e.g., 'History'); subsections are included. Unknown headings return available_sections"
+                        },
+                        "format": {
+                            "type": "string",
+                            "enum": ["text", "markdown"],
+                            "description": "Content rendering: 'text' is the plain extract, 'markdown' converts the wikitext (links, emphasis, lists)",
+                            "default": "text"
+                        },
+                        "language": {
+                            "type": "string",
+                            "description": "Wikipedia language edition (e.g., 'de', 'fr', 'simple'); defaults to the configured language"
                         }
                     },
                     "required": ["title"]
@@ -592,6 +991,68 @@ tokens down. Example: title=\"Rust (programming language)\".",
                 annotations: None,
                 icons: None,
             },
+            Tool {
+                name: Cow::Borrowed("list_category_members"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "List the pages and subcategories in a category. Use to walk a topic tree; \
+pass next_cursor back to continue. Example: category=\"Programming languages\" types=[\"subcat\"].",
+                )),
+                input_schema: Arc::new(json!({
+                    "type": "object",
+                    "properties": {
+                        "category": {
+                            "type": "string",
+                            "description": "Category name, with or without the 'Category:' prefix"
+                        },
+                        "types": {
+                            "type": "array",
+                            "items": { "type": "string", "enum": ["page", "subcat", "file"] },
+                            "description": "Member kinds to include (default: page and subcat)"
+                        },
+                        "limit": {
+                            "type": "integer",
+                            "description": "Maximum members to return (default: 100, max: 5000)"
+                        },
+                        "cursor": {
+                            "type": "string",
+                            "description": "next_cursor from a previous call"
+                        },
+                        "language": {
+                            "type": "string",
+                            "description": "Wikipedia language edition (e.g., 'de', 'fr', 'simple'); defaults to the configured language"
+                        }
+                    },
+                    "required": ["category"]
+                }).as_object().expect("Schema object").clone()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_links"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "List the articles an article links to (outgoing links). Use to expand from \
+one topic to related ones. Example: title=\"Rust (programming language)\" limit=50.",
+                )),
+                input_schema: Arc::new(links_schema()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_backlinks"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "List the articles that link to an article (incoming links, redirects \
+excluded). Use to find pages that depend on a topic. Example: title=\"Borrow checker\".",
+                )),
+                input_schema: Arc::new(links_schema()),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
         ];
 
         Ok(ListToolsResult {
@@ -613,7 +1074,10 @@ tokens down. Example: title=\"Rust (programming language)\".",
                     ConnectorError::InvalidParams(format!("Invalid arguments: {}", e))
                 })?;
 
-                let results = self.search_articles(&args.query, args.limit).await?;
+                let language = self.language_for(args.language.as_deref())?;
+                let results = self
+                    .search_articles(&args.query, args.limit, &language)
+                    .await?;
 
                 // Return concise or detailed based on response_format
                 let data = if args.response_format == ResponseFormat::Concise {
@@ -634,8 +1098,9 @@ tokens down. Example: title=\"Rust (programming language)\".",
                     ConnectorError::InvalidParams(format!("Invalid arguments: {}", e))
                 })?;
 
+                let language = self.language_for(args.language.as_deref())?;
                 let results = self
-                    .geo_search(args.latitude, args.longitude, args.radius)
+                    .geo_search(args.latitude, args.longitude, args.radius, &language)
                     .await?;
                 let data = json!({
                     "latitude": args.latitude,
@@ -652,9 +1117,50 @@ tokens down. Example: title=\"Rust (programming language)\".",
                     ConnectorError::InvalidParams(format!("Invalid arguments: {}", e))
                 })?;
 
-                match self.get_article_content(&args.title).await {
+                let language = self.language_for(args.language.as_deref())?;
+
+                if let Some(section) = args.section.as_deref() {
+                    let payload = match self
+                        .get_article_section(&args.title, section, args.format, &language)
+                        .await
+                    {
+                        Ok(Ok(content)) => json!({
+                            "title": args.title,
+                            "language": language,
+                            "section": section,
+                            "content": content,
+                        }),
+                        // Let the caller pick an existing heading
+                        Ok(Err(sections)) => json!({
+                            "title": args.title,
+                            "language": language,
+                            "section": section,
+                            "content": serde_json::Value::Null,
+                            "available_sections": sections,
+                        }),
+                        Err(ConnectorError::ResourceNotFound) => json!({
+                            "title": args.title,
+                            "language": language,
+                            "section": section,
+                            "content": serde_json::Value::Null,
+                        }),
+                        Err(err) => return Err(err),
+                    };
+                    let text = serde_json::to_string(&payload)?;
+                    return Ok(structured_result_with_text(&payload, Some(text))?);
+                }
+
+                let content = match (args.format, args.response_format) {
+                    (ArticleFormat::Markdown, ResponseFormat::Detailed) => {
+                        self.get_article_wikitext(&args.title, &language).await.map(
+                            |(_, wikitext)| wikitext::to_markdown(&wikitext, &wiki_base(&language)),
+                        )
+                    }
+                    _ => self.get_article_content(&args.title, &language).await,
+                };
+                match content {
                     Ok(content) => {
-                        let summary = self.get_article_summary(&args.title).await.ok();
+                        let summary = self.get_article_summary(&args.title, &language).await.ok();
 
                         // Return concise or detailed based on response_format
                         let article_data = if args.response_format == ResponseFormat::Concise {
@@ -683,6 +1189,95 @@ tokens down. Example: title=\"Rust (programming language)\".",
                     Err(err) => Err(err),
                 }
             }
+            "list_category_members" => {
+                let args: CategoryMembersArgs =
+                    serde_json::from_value(json!(args)).map_err(|e| {
+                        ConnectorError::InvalidParams(format!("Invalid arguments: {}", e))
+                    })?;
+
+                let language = self.language_for(args.language.as_deref())?;
+                let category = category_title(&args.category);
+                let types = args
+                    .types
+                    .filter(|t| !t.is_empty())
+                    .map(|t| t.join("|"))
+                    .unwrap_or_else(|| "page|subcat".to_string());
+                let collected = self
+                    .query_graph(
+                        &language,
+                        vec![
+                            ("list", "categorymembers".to_string()),
+                            ("cmtitle", category.clone()),
+                            ("cmtype", types),
+                            ("cmprop", "title|type".to_string()),
+                        ],
+                        "cmlimit",
+                        args.limit,
+                        args.cursor,
+                        extract_category_members,
+                    )
+                    .await?;
+                let data = json!({
+                    "category": category,
+                    "language": language,
+                    "members": collected.items,
+                    "count": collected.items.len(),
+                    "next_cursor": collected.next_cursor,
+                });
+                let text = serde_json::to_string(&data)?;
+                Ok(structured_result_with_text(&data, Some(text))?)
+            }
+            "get_links" | "get_backlinks" => {
+                let args: LinksArgs = serde_json::from_value(json!(args)).map_err(|e| {
+                    ConnectorError::InvalidParams(format!("Invalid arguments: {}", e))
+                })?;
+
+                let language = self.language_for(args.language.as_deref())?;
+                let namespace = args.namespace.unwrap_or(0).to_string();
+                let (params, limit_param, extract): (_, _, fn(&Value) -> Vec<Value>) =
+                    if name == "get_links" {
+                        (
+                            vec![
+                                ("prop", "links".to_string()),
+                                ("titles", args.title.clone()),
+                                ("plnamespace", namespace),
+                                ("redirects", "1".to_string()),
+                            ],
+                            "pllimit",
+                            extract_links,
+                        )
+                    } else {
+                        (
+                            vec![
+                                ("list", "backlinks".to_string()),
+                                ("bltitle", args.title.clone()),
+                                ("blnamespace", namespace),
+                                ("blfilterredir", "nonredirects".to_string()),
+                            ],
+                            "bllimit",
+                            extract_backlinks,
+                        )
+                    };
+                let collected = self
+                    .query_graph(
+                        &language,
+                        params,
+                        limit_param,
+                        args.limit,
+                        args.cursor,
+                        extract,
+                    )
+                    .await?;
+                let data = json!({
+                    "title": args.title,
+                    "language": language,
+                    "links": collected.items,
+                    "count": collected.items.len(),
+                    "next_cursor": collected.next_cursor,
+                });
+                let text = serde_json::to_string(&data)?;
+                Ok(structured_result_with_text(&data, Some(text))?)
+            }
             _ => Err(ConnectorError::ToolNotFound),
         }
     }
@@ -0,0 +1,351 @@
+// src/connectors/wikipedia/wikitext.rs
+//
+// Section slicing for article text and a best-effort wikitext → markdown
+// conversion. Templates, references and media are dropped; links, emphasis,
+// headings, lists and tables are kept.
+
+use crate::utils::clean_html_entities;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static HEADING_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(={2,6})\s*(.+?)\s*={2,6}\s*$").expect("heading regex"));
+
+/// A `== Heading ==` in article text, with its level (2 for top-level sections).
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Heading {
+    pub level: usize,
+    pub title: String,
+}
+
+fn parse_heading(line: &str) -> Option<Heading> {
+    let caps = HEADING_RE.captures(line.trim_end())?;
+    Some(Heading {
+        level: caps[1].len(),
+        title: strip_markup(&caps[2]),
+    })
+}
+
+/// All section headings, in order.
+pub(super) fn headings(text: &str) -> Vec<Heading> {
+    text.lines().filter_map(parse_heading).collect()
+}
+
+/// The body of the section titled `heading` (case-insensitive), including its
+/// subsections, without the heading line itself.
+pub(super) fn slice_section(text: &str, heading: &str) -> Option<String> {
+    let wanted = heading.trim().trim_matches('=').trim().to_lowercase();
+    let lines: Vec<&str> = text.lines().collect();
+    let (start, level) = lines.iter().enumerate().find_map(|(i, line)| {
+        parse_heading(line)
+            .filter(|h| h.title.to_lowercase() == wanted)
+            .map(|h| (i, h.level))
+    })?;
+    let end = lines[start + 1..]
+        .iter()
+        .position(|line| parse_heading(line).is_some_and(|h| h.level <= level))
+        .map(|offset| start + 1 + offset)
+        .unwrap_or(lines.len());
+    Some(lines[start + 1..end].join("\n").trim().to_string())
+}
+
+/// Convert wikitext to markdown. Internal links point at `wiki_base`
+/// (e.g. `https://en.wikipedia.org/wiki/`).
+pub(super) fn to_markdown(wikitext: &str, wiki_base: &str) -> String {
+    static COMMENT_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?s)<!--.*?-->").expect("comment regex"));
+    static REF_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?is)<ref[^>/]*/>|<ref[^>]*>.*?</ref>").expect("ref regex"));
+    static BR_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)<br\s*/?>").expect("br regex"));
+    static TAG_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"</?[a-zA-Z][^>]*>").expect("tag regex"));
+    static EXTERNAL_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"\[(https?://[^\s\]]+)(?:\s+([^\]]+))?\]").expect("external link regex")
+    });
+
+    let text = COMMENT_RE.replace_all(wikitext, "");
+    let text = REF_RE.replace_all(&text, "");
+    let text = remove_nested(&text, "{{", "}}");
+    let text = convert_tables(&text, wiki_base);
+    let text = BR_RE.replace_all(&text, "\n");
+    let text = TAG_RE.replace_all(&text, "");
+    let text = convert_links(&text, wiki_base);
+    let text = EXTERNAL_RE.replace_all(&text, |caps: &regex::Captures| match caps.get(2) {
+        Some(label) => format!("[{}]({})", label.as_str(), &caps[1]),
+        None => format!("<{}>", &caps[1]),
+    });
+
+    let mut out: Vec<String> = Vec::new();
+    for line in text.lines() {
+        let line = line.trim_end();
+        if let Some(h) = parse_heading(line) {
+            out.push(String::new());
+            out.push(format!("{} {}", "#".repeat(h.level), h.title));
+            out.push(String::new());
+            continue;
+        }
+        let bullets: String = line.chars().take_while(|c| "*#:;".contains(*c)).collect();
+        if bullets.is_empty() {
+            out.push(emphasis(line));
+            continue;
+        }
+        let rest = emphasis(line[bullets.len()..].trim());
+        let indent = "  ".repeat(bullets.len() - 1);
+        out.push(match bullets.chars().last() {
+            Some('*') => format!("{}- {}", indent, rest),
+            Some('#') => format!("{}1. {}", indent, rest),
+            Some(';') => format!("**{}**", rest),
+            _ => rest,
+        });
+    }
+
+    let joined = clean_html_entities(&out.join("\n")).replace('\u{a0}', " ");
+    static BLANKS_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\n{3,}").expect("blank regex"));
+    BLANKS_RE.replace_all(joined.trim(), "\n\n").to_string()
+}
+
+/// Wiki markup that can appear inside a heading, rendered as plain text.
+fn strip_markup(text: &str) -> String {
+    convert_links(text, "")
+        .replace("'''", "")
+        .replace("''", "")
+        .trim()
+        .to_string()
+}
+
+fn emphasis(line: &str) -> String {
+    line.replace("'''''", "***")
+        .replace("'''", "**")
+        .replace("''", "*")
+}
+
+/// Drop balanced `open ... close` spans (templates nest arbitrarily).
+fn remove_nested(text: &str, open: &str, close: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut depth = 0usize;
+    let mut rest = text;
+    while !rest.is_empty() {
+        if rest.starts_with(open) {
+            depth += 1;
+            rest = &rest[open.len()..];
+        } else if depth > 0 && rest.starts_with(close) {
+            depth -= 1;
+            rest = &rest[close.len()..];
+        } else {
+            let ch = rest.chars().next().expect("non-empty");
+            if depth == 0 {
+                out.push(ch);
+            }
+            rest = &rest[ch.len_utf8()..];
+        }
+    }
+    out
+}
+
+/// Render top-level `{| ... |}` tables as markdown tables through the shared
+/// HTML converter. Tables nested inside cells are dropped.
+fn convert_tables(text: &str, wiki_base: &str) -> String {
+    let mut out: Vec<String> = Vec::new();
+    let mut table: Vec<&str> = Vec::new();
+    let mut depth = 0usize;
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("{|") {
+            depth += 1;
+        }
+        if depth == 0 {
+            out.push(line.to_string());
+            continue;
+        }
+        if depth == 1 {
+            table.push(trimmed);
+        }
+        if trimmed.starts_with("|}") {
+            depth -= 1;
+            if depth == 0 {
+                out.push(String::new());
+                out.push(table_markdown(&table, wiki_base));
+                out.push(String::new());
+                table.clear();
+            }
+        }
+    }
+    // An unterminated table still renders what it has
+    if !table.is_empty() {
+        out.push(table_markdown(&table, wiki_base));
+    }
+    out.join("\n")
+}
+
+/// One table's lines (`{|`, `|+`, `|-`, `!`, `|`, `|}` and continuations) as markdown.
+fn table_markdown(lines: &[&str], wiki_base: &str) -> String {
+    let mut caption = String::new();
+    let mut rows: Vec<Vec<(bool, String)>> = vec![Vec::new()];
+    for line in lines {
+        if line.starts_with("{|") || line.starts_with("|}") {
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix("|+") {
+            caption = table_cell(rest, wiki_base);
+        } else if line.starts_with("|-") {
+            rows.push(Vec::new());
+        } else if let Some(rest) = line.strip_prefix('!') {
+            let row = rows.last_mut().expect("at least one row");
+            let rest = convert_links(rest, wiki_base);
+            for cell in rest.split("!!").flat_map(|c| c.split("||")) {
+                row.push((true, table_cell(cell, "")));
+            }
+        } else if let Some(rest) = line.strip_prefix('|') {
+            let row = rows.last_mut().expect("at least one row");
+            let rest = convert_links(rest, wiki_base);
+            for cell in rest.split("||") {
+                row.push((false, table_cell(cell, "")));
+            }
+        } else if let Some((_, text)) = rows.last_mut().and_then(|row| row.last_mut()) {
+            text.push(' ');
+            text.push_str(&convert_links(line, wiki_base));
+        }
+    }
+
+    let mut html = String::new();
+    if !caption.is_empty() {
+        html.push_str(&format!("<p>{}</p>", caption));
+    }
+    html.push_str("<table>");
+    for row in rows.iter().filter(|row| !row.is_empty()) {
+        html.push_str("<tr>");
+        for (header, text) in row {
+            let tag = if *header { "th" } else { "td" };
+            html.push_str(&format!("<{tag}>{text}</{tag}>"));
+        }
+        html.push_str("</tr>");
+    }
+    html.push_str("</table>");
+    crate::utils::html_to_markdown(&html)
+}
+
+/// Cell content without its `style="..." |` attribute prefix.
+fn table_cell(cell: &str, wiki_base: &str) -> String {
+    let cell = convert_links(cell, wiki_base);
+    let content = match cell.split_once('|') {
+        Some((attrs, content)) if attrs.contains('=') => content,
+        _ => cell.as_str(),
+    };
+    content.trim().to_string()
+}
+
+/// Rewrite `[[Target|label]]` as markdown links (plain labels when `wiki_base`
+/// is empty), dropping files and categories.
+fn convert_links(text: &str, wiki_base: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("[[") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        // Find the matching `]]`, allowing nested links inside file captions
+        let mut depth = 1;
+        let mut end = None;
+        let mut i = 0;
+        while i < after.len() {
+            if after[i..].starts_with("[[") {
+                depth += 1;
+                i += 2;
+            } else if after[i..].starts_with("]]") {
+                depth -= 1;
+                if depth == 0 {
+                    end = Some(i);
+                    break;
+                }
+                i += 2;
+            } else {
+                i += after[i..].chars().next().map_or(1, char::len_utf8);
+            }
+        }
+        let Some(end) = end else {
+            out.push_str(&rest[start..]);
+            return out;
+        };
+        let inner = &after[..end];
+        rest = &after[end + 2..];
+
+        let (target, label) = match inner.split_once('|') {
+            Some((target, label)) => (target.trim(), label.trim()),
+            None => (inner.trim(), inner.trim()),
+        };
+        let namespace = target
+            .split_once(':')
+            .map(|(ns, _)| ns.trim().to_lowercase())
+            .unwrap_or_default();
+        if matches!(namespace.as_str(), "file" | "image" | "category" | "media") {
+            continue;
+        }
+        let label = label.trim_start_matches(':');
+        if wiki_base.is_empty() {
+            out.push_str(label);
+        } else {
+            let page = target.trim_start_matches(':').replace(' ', "_");
+            out.push_str(&format!(
+                "[{}]({}{})",
+                label,
+                wiki_base,
+                urlencoding::encode(&page).replace("%2F", "/")
+            ));
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARTICLE: &str = "Intro text.\n\n== History ==\nEarly days.\n\n=== 2010s ===\nGrowth.\n\n== Design ==\nSafe.";
+
+    #[test]
+    fn slices_sections_with_subsections() {
+        assert_eq!(
+            slice_section(ARTICLE, "history").as_deref(),
+            Some("Early days.\n\n=== 2010s ===\nGrowth.")
+        );
+        assert_eq!(slice_section(ARTICLE, "2010s").as_deref(), Some("Growth."));
+        assert_eq!(
+            slice_section(ARTICLE, "== Design ==").as_deref(),
+            Some("Safe.")
+        );
+        assert!(slice_section(ARTICLE, "Reception").is_none());
+        assert_eq!(
+            headings(ARTICLE)
+                .into_iter()
+                .map(|h| (h.level, h.title))
+                .collect::<Vec<_>>(),
+            vec![
+                (2, "History".to_string()),
+                (3, "2010s".to_string()),
+                (2, "Design".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn converts_wikitext_to_markdown() {
+        let wikitext = "{{Infobox language|name={{lang|en|Rust}}}}\n\
+'''Rust''' is a [[programming language|language]] by [[Mozilla]].<ref name=\"a\">cite</ref>\n\
+[[File:Rust logo.svg|thumb|The [[logo]]]]\n\
+== Features ==\n\
+* ''Ownership''\n\
+** Borrowing<br/>checker\n\
+# First\n\
+See [https://www.rust-lang.org the site].<!-- hidden -->\n\
+{| class=\"wikitable\"\n! Name !! Year\n|-\n| [[Rust]] || style=\"x\" | 2015\n|}\n\
+[[Category:Languages]]";
+
+        let md = to_markdown(wikitext, "https://en.wikipedia.org/wiki/");
+        assert_eq!(
+            md,
+            "**Rust** is a [language](https://en.wikipedia.org/wiki/programming_language) by \
+[Mozilla](https://en.wikipedia.org/wiki/Mozilla).\n\n## Features\n\n- *Ownership*\n  - Borrowing\nchecker\n\
+1. First\nSee [the site](https://www.rust-lang.org).\n\n| Name | Year |\n| --- | --- |\n\
+| [Rust](https://en.wikipedia.org/wiki/Rust) | 2015 |"
+        );
+    }
+}
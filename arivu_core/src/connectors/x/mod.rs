@@ -21,6 +21,8 @@ use agent_twitter_client::search::SearchMode;
 
 use rmcp::model::*;
 
+mod thread;
+
 pub struct XConnector {
     scraper: Scraper, // Directly use AgentScraper
 }
@@ -41,6 +43,109 @@ impl XConnector {
 
         Ok(connector)
     }
+
+    async fn tweet_value(&self, tweet_id: &str) -> Result<Value, ConnectorError> {
+        let tweet: Tweet = self
+            .scraper
+            .get_tweet(tweet_id)
+            .await
+            .map_err(|e| ConnectorError::Other(e.to_string()))?;
+        Ok(serde_json::to_value(&tweet)?)
+    }
+
+    /// Latest-mode search results as JSON, with the next-page cursor.
+    async fn search_values(
+        &self,
+        query: &str,
+        limit: i32,
+        cursor: Option<String>,
+    ) -> Result<(Vec<Value>, Option<String>), ConnectorError> {
+        let response: QueryTweetsResponse = self
+            .scraper
+            .search_tweets(query, limit, SearchMode::Latest, cursor)
+            .await
+            .map_err(|e| ConnectorError::Other(e.to_string()))?;
+        let response = serde_json::to_value(&response)?;
+        let next = response
+            .get("next")
+            .and_then(|n| n.as_str())
+            .map(str::to_string);
+        Ok((thread::response_tweets(&response), next))
+    }
+
+    /// The author's thread containing `tweet_id`, root first: parents are
+    /// followed up while the author replies to themself, then the
+    /// conversation is searched for the author's later replies.
+    async fn get_thread(
+        &self,
+        tweet_id: &str,
+        include_replies: bool,
+        max_replies: i32,
+    ) -> Result<Value, ConnectorError> {
+        let focal = self.tweet_value(tweet_id).await?;
+        let username = thread::author(&focal).unwrap_or_default();
+
+        let mut chain = vec![focal.clone()];
+        let mut context = None;
+        while chain.len() < thread::MAX_THREAD_TWEETS {
+            let Some(parent_id) = thread::reply_to(&chain[0]) else {
+                break;
+            };
+            // Deleted or protected parents end the walk
+            let Ok(parent) = self.tweet_value(&parent_id).await else {
+                break;
+            };
+            if !thread::author_matches(&parent, &username) {
+                context = Some(thread::concise_tweet(&parent));
+                break;
+            }
+            chain.insert(0, parent);
+        }
+
+        let root_id = thread::id(&chain[0]).unwrap_or_else(|| tweet_id.to_string());
+        let conversation_id = thread::conversation_id(&chain[0]).unwrap_or(root_id);
+        let mut candidates = thread::attached_thread(&focal);
+        let mut complete = true;
+        match self
+            .search_values(
+                &format!("conversation_id:{} from:{}", conversation_id, username),
+                thread::MAX_THREAD_TWEETS as i32,
+                None,
+            )
+            .await
+        {
+            Ok((found, _)) => candidates.extend(found),
+            Err(_) => complete = false,
+        }
+        let tweets = thread::extend_thread(chain, candidates, &username);
+
+        let mut payload = json!({
+            "conversation_id": conversation_id,
+            "author": username,
+            "count": tweets.len(),
+            "tweets": tweets.iter().map(thread::concise_tweet).collect::<Vec<_>>(),
+            "complete": complete,
+        });
+        if let Some(context) = context {
+            payload["in_reply_to"] = context;
+        }
+        if include_replies {
+            let (found, _) = self
+                .search_values(
+                    &format!("conversation_id:{}", conversation_id),
+                    max_replies,
+                    None,
+                )
+                .await?;
+            let replies: Vec<Value> = found
+                .iter()
+                .filter(|t| !thread::author_matches(t, &username))
+                .map(thread::concise_tweet)
+                .collect();
+            payload["replies"] = json!(replies);
+        }
+        Ok(payload)
+    }
 }
 
 #[async_trait]
@@ -406,6 +511,72 @@ query=\"rust lang:en\" limit=20.",
                 annotations: None,
                 icons: None,
             },
+            Tool {
+                name: Cow::Borrowed("get_thread"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Unroll the author's thread containing a tweet: give any tweet ID or status URL in the thread and get every tweet of it in order, root first, with photo/video URLs under media. Optionally include other people's replies.",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties":{
+                            "tweet_id":{
+                                "type": "string",
+                                "description": "Any tweet ID (or status URL) in the thread"
+                            },
+                            "include_replies":{
+                                "type": "boolean",
+                                "description": "Also return replies from other accounts (default false)"
+                            },
+                            "max_replies":{
+                                "type": "integer",
+                                "description": "Replies to fetch when include_replies is set (default 50)"
+                            }
+                        },
+                        "required": ["tweet_id"]
+                    })
+                    .as_object()
+                    .expect("Schema object")
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
+            Tool {
+                name: Cow::Borrowed("get_list_timeline"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Get the latest tweets from an X list by list ID or URL, newest first, with photo/video URLs under media.",
+                )),
+                input_schema: Arc::new(
+                    json!({
+                        "type": "object",
+                        "properties":{
+                            "list_id":{
+                                "type": "string",
+                                "description": "List ID or x.com/i/lists/<id> URL"
+                            },
+                            "limit":{
+                                "type": "integer",
+                                "description": "Number of tweets to return (default 20)"
+                            },
+                            "cursor":{
+                                "type": "string",
+                                "description": "Optional cursor for pagination"
+                            }
+                        },
+                        "required": ["list_id"]
+                    })
+                    .as_object()
+                    .expect("Schema object")
+                    .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
             Tool {
                 name: Cow::Borrowed("get_home_timeline"),
                 title: None,
@@ -644,6 +815,46 @@ only when the user asked you to message someone.",
                 let text = serde_json::to_string(&tweet)?;
                 Ok(structured_result_with_text(&tweet, Some(text))?)
             }
+            "get_thread" => {
+                let input = args["tweet_id"]
+                    .as_str()
+                    .ok_or(ConnectorError::InvalidParams(
+                        "Missing 'tweet_id' parameter".to_string(),
+                    ))?;
+                let tweet_id = thread::tweet_id(input).ok_or_else(|| {
+                    ConnectorError::InvalidParams(format!("Not a tweet ID or URL: {}", input))
+                })?;
+                let include_replies = args["include_replies"].as_bool().unwrap_or(false);
+                let max_replies = args["max_replies"].as_i64().unwrap_or(50).clamp(1, 500) as i32;
+                let payload = self
+                    .get_thread(&tweet_id, include_replies, max_replies)
+                    .await?;
+                let text = serde_json::to_string(&payload)?;
+                Ok(structured_result_with_text(&payload, Some(text))?)
+            }
+            "get_list_timeline" => {
+                let input = args["list_id"]
+                    .as_str()
+                    .ok_or(ConnectorError::InvalidParams(
+                        "Missing 'list_id' parameter".to_string(),
+                    ))?;
+                let list_id = thread::list_id(input).ok_or_else(|| {
+                    ConnectorError::InvalidParams(format!("Not a list ID or URL: {}", input))
+                })?;
+                let limit = args["limit"].as_i64().unwrap_or(20).clamp(1, 500) as i32;
+                let cursor = args["cursor"].as_str().map(String::from);
+                // The `list:` search operator is the list's timeline in Latest mode
+                let (tweets, next_cursor) = self
+                    .search_values(&format!("list:{}", list_id), limit, cursor)
+                    .await?;
+                let payload = json!({
+                    "list_id": list_id,
+                    "tweets": tweets.iter().map(thread::concise_tweet).collect::<Vec<_>>(),
+                    "next_cursor": next_cursor,
+                });
+                let text = serde_json::to_string(&payload)?;
+                Ok(structured_result_with_text(&payload, Some(text))?)
+            }
             "get_home_timeline" => {
                 let count = args["count"].as_i64().unwrap_or(20) as i32;
                 let exclude_replies: Vec<String> = match args["exclude_replies"].as_bool() {
@@ -0,0 +1,241 @@
+// src/connectors/x/thread.rs
+//
+// Thread unrolling and compact tweet payloads. Tweets are handled as the
+// JSON the scraper serializes, reading both snake_case and camelCase keys
+// so the shape doesn't depend on the client's serde attributes.
+
+use serde_json::{json, Value};
+
+/// Longest thread we'll walk, in either direction.
+pub(super) const MAX_THREAD_TWEETS: usize = 100;
+
+fn field<'a>(v: &'a Value, keys: &[&str]) -> Option<&'a Value> {
+    keys.iter()
+        .filter_map(|k| v.get(*k))
+        .find(|value| !value.is_null())
+}
+
+fn str_field(v: &Value, keys: &[&str]) -> Option<String> {
+    field(v, keys).and_then(|s| s.as_str()).map(str::to_string)
+}
+
+pub(super) fn id(tweet: &Value) -> Option<String> {
+    str_field(tweet, &["id", "rest_id"])
+}
+
+pub(super) fn author(tweet: &Value) -> Option<String> {
+    str_field(tweet, &["username", "screen_name"])
+}
+
+pub(super) fn reply_to(tweet: &Value) -> Option<String> {
+    str_field(tweet, &["in_reply_to_status_id", "inReplyToStatusId"])
+}
+
+pub(super) fn conversation_id(tweet: &Value) -> Option<String> {
+    str_field(tweet, &["conversation_id", "conversationId"])
+}
+
+/// Tweets the client already attached as the author's self-thread.
+pub(super) fn attached_thread(tweet: &Value) -> Vec<Value> {
+    tweet
+        .get("thread")
+        .and_then(|t| t.as_array())
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Numeric order of snowflake IDs (string order breaks at length changes).
+fn id_key(tweet: &Value) -> u64 {
+    id(tweet).and_then(|i| i.parse().ok()).unwrap_or(u64::MAX)
+}
+
+/// A tweet ID from an ID or a status URL (x.com, twitter.com, mobile).
+pub(super) fn tweet_id(input: &str) -> Option<String> {
+    let input = input.trim();
+    let candidate = match input.find("/status/") {
+        Some(idx) => &input[idx + "/status/".len()..],
+        None => input,
+    };
+    let digits: String = candidate
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    (!digits.is_empty()).then_some(digits)
+}
+
+/// A list ID from an ID or an x.com/i/lists/<id> URL.
+pub(super) fn list_id(input: &str) -> Option<String> {
+    let input = input.trim();
+    let candidate = match input.find("/lists/") {
+        Some(idx) => &input[idx + "/lists/".len()..],
+        None => input,
+    };
+    let digits: String = candidate
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    (!digits.is_empty()).then_some(digits)
+}
+
+/// Extend `chain` (root .. known tail) with `username`'s replies to the
+/// tail, earliest first, until no candidate continues it.
+pub(super) fn extend_thread(
+    mut chain: Vec<Value>,
+    candidates: Vec<Value>,
+    username: &str,
+) -> Vec<Value> {
+    let mut pool: Vec<Value> = candidates
+        .into_iter()
+        .filter(|t| author_matches(t, username))
+        .collect();
+    pool.sort_by_key(id_key);
+    while chain.len() < MAX_THREAD_TWEETS {
+        let Some(tail) = chain.last().and_then(id) else {
+            break;
+        };
+        let Some(pos) = pool
+            .iter()
+            .position(|t| reply_to(t).as_deref() == Some(tail.as_str()))
+        else {
+            break;
+        };
+        let next = pool.remove(pos);
+        if chain.iter().any(|t| id(t) == id(&next)) {
+            continue;
+        }
+        chain.push(next);
+    }
+    chain
+}
+
+pub(super) fn author_matches(tweet: &Value, username: &str) -> bool {
+    author(tweet).is_some_and(|a| a.eq_ignore_ascii_case(username))
+}
+
+/// Photo and video URLs of a tweet, best quality the payload offers.
+pub(super) fn media(tweet: &Value) -> Vec<Value> {
+    let mut out = Vec::new();
+    for photo in field(tweet, &["photos"])
+        .and_then(|p| p.as_array())
+        .into_iter()
+        .flatten()
+    {
+        if let Some(url) = str_field(photo, &["url"]) {
+            out.push(json!({
+                "type": "photo",
+                "url": url,
+                "alt_text": str_field(photo, &["alt_text", "altText"]),
+            }));
+        }
+    }
+    for video in field(tweet, &["videos"])
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+    {
+        let url = str_field(video, &["url"]);
+        let preview = str_field(video, &["preview"]);
+        if url.is_some() || preview.is_some() {
+            out.push(json!({ "type": "video", "url": url, "preview": preview }));
+        }
+    }
+    out
+}
+
+/// The fields worth reading, plus extracted media.
+pub(super) fn concise_tweet(tweet: &Value) -> Value {
+    let mut out = json!({
+        "id": id(tweet),
+        "username": author(tweet),
+        "name": str_field(tweet, &["name"]),
+        "text": str_field(tweet, &["text"]),
+        "created_at": field(tweet, &["time_parsed", "timeParsed", "created_at", "timestamp"]),
+        "url": str_field(tweet, &["permanent_url", "permanentUrl"]),
+        "likes": field(tweet, &["likes"]),
+        "retweets": field(tweet, &["retweets"]),
+        "replies": field(tweet, &["replies"]),
+        "views": field(tweet, &["views"]),
+    });
+    if let Some(parent) = reply_to(tweet) {
+        out["in_reply_to"] = json!(parent);
+    }
+    if let Some(urls) = field(tweet, &["urls"])
+        .and_then(|u| u.as_array())
+        .filter(|u| !u.is_empty())
+    {
+        out["links"] = json!(urls);
+    }
+    let media = media(tweet);
+    if !media.is_empty() {
+        out["media"] = json!(media);
+    }
+    if let Some(quoted) = field(tweet, &["quoted_status", "quotedStatus"]).filter(|q| q.is_object())
+    {
+        out["quoted"] = concise_tweet(quoted);
+    }
+    out
+}
+
+/// Tweets of a serialized search/timeline response.
+pub(super) fn response_tweets(response: &Value) -> Vec<Value> {
+    response
+        .get("tweets")
+        .and_then(|t| t.as_array())
+        .cloned()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tweet(id: &str, user: &str, reply_to: Option<&str>) -> Value {
+        json!({ "id": id, "username": user, "text": format!("tweet {}", id),
+                "in_reply_to_status_id": reply_to, "photos": [], "videos": [] })
+    }
+
+    #[test]
+    fn unrolls_self_replies_in_order() {
+        let root = tweet("900", "ana", None);
+        let candidates = vec![
+            tweet("1000", "ana", Some("950")),
+            tweet("950", "ana", Some("900")),
+            // Someone else's reply and the author's reply to it are not the thread
+            tweet("960", "raj", Some("950")),
+            tweet("970", "ana", Some("960")),
+            tweet("1100", "ANA", Some("1000")),
+        ];
+        let chain = extend_thread(vec![root], candidates, "ana");
+        let ids: Vec<String> = chain.iter().filter_map(id).collect();
+        assert_eq!(ids, vec!["900", "950", "1000", "1100"]);
+    }
+
+    #[test]
+    fn parses_ids_and_extracts_media() {
+        assert_eq!(
+            tweet_id("https://x.com/ana/status/1790000000000000001?s=20").as_deref(),
+            Some("1790000000000000001")
+        );
+        assert_eq!(
+            tweet_id("1790000000000000001").as_deref(),
+            Some("1790000000000000001")
+        );
+        assert_eq!(tweet_id("https://x.com/ana"), None);
+        assert_eq!(
+            list_id("https://x.com/i/lists/1234567890").as_deref(),
+            Some("1234567890")
+        );
+
+        let t = json!({
+            "id": "1", "username": "ana", "text": "look",
+            "photos": [{ "id": "p1", "url": "https://pbs.twimg.com/media/a.jpg", "altText": "a chart" }],
+            "videos": [{ "id": "v1", "preview": "https://pbs.twimg.com/v.jpg", "url": "https://video.twimg.com/v.mp4" }],
+            "quotedStatus": { "id": "0", "username": "raj", "text": "original" }
+        });
+        let concise = concise_tweet(&t);
+        assert_eq!(concise["media"][0]["alt_text"], "a chart");
+        assert_eq!(concise["media"][1]["url"], "https://video.twimg.com/v.mp4");
+        assert_eq!(concise["quoted"]["username"], "raj");
+        assert!(concise.get("in_reply_to").is_none());
+    }
+}
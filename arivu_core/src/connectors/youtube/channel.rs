@@ -0,0 +1,313 @@
+// src/connectors/youtube/channel.rs
+//
+// Channel metadata from the channel's About panel (description, counts, links)
+// plus an upload-cadence summary computed from the channel's Atom feed.
+
+use super::innertube::{find_key, text_of, WebPage};
+use super::{parse_rfc3339, ListedVideo};
+use crate::error::ConnectorError;
+use chrono::{DateTime, Duration, Utc};
+use reqwest::Client as HttpClient;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Default)]
+pub struct ChannelDetails {
+    pub channel_id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    pub url: String,
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscriber_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub view_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_count: Option<u64>,
+    /// e.g. "Jan 5, 2012"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub joined: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<ChannelLink>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upload_cadence: Option<UploadCadence>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recent_uploads: Vec<ListedVideo>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, PartialEq)]
+pub struct ChannelLink {
+    pub title: String,
+    pub url: String,
+}
+
+/// How often the channel publishes, from its newest (up to ~15) uploads.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, PartialEq)]
+pub struct UploadCadence {
+    /// Uploads the figures are based on
+    pub sample_size: usize,
+    pub latest_upload: String,
+    pub days_since_latest: i64,
+    pub uploads_last_30_days: usize,
+    pub uploads_last_90_days: usize,
+    /// Mean gap between consecutive uploads in the sample
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_days_between_uploads: Option<f64>,
+}
+
+/// Read the About panel of a channel. Newer layouts only embed a continuation
+/// for the panel in the page, which is then fetched through innertube.
+pub(super) async fn fetch_channel_details(
+    client: &HttpClient,
+    channel_id: &str,
+) -> Result<ChannelDetails, ConnectorError> {
+    let page = WebPage::fetch(
+        client,
+        &format!("https://www.youtube.com/channel/{}/about", channel_id),
+        &[],
+    )
+    .await?;
+
+    let mut details = ChannelDetails {
+        channel_id: channel_id.to_string(),
+        url: format!("https://www.youtube.com/channel/{}", channel_id),
+        ..Default::default()
+    };
+    apply_channel_metadata(&page.initial_data, &mut details);
+
+    let about = match find_key(&page.initial_data, "aboutChannelViewModel") {
+        Some(about) => Some(about.clone()),
+        None => match about_continuation(&page.initial_data) {
+            Some(token) => {
+                let response = page.browse_continuation(client, &token).await?;
+                find_key(&response, "aboutChannelViewModel").cloned()
+            }
+            None => None,
+        },
+    };
+    if let Some(about) = about {
+        apply_about(&about, &mut details);
+    }
+
+    if details.title.is_empty() {
+        return Err(ConnectorError::ResourceNotFound);
+    }
+    Ok(details)
+}
+
+fn apply_channel_metadata(data: &Value, details: &mut ChannelDetails) {
+    let Some(meta) = find_key(data, "channelMetadataRenderer") else {
+        return;
+    };
+    if let Some(title) = meta["title"].as_str() {
+        details.title = title.to_string();
+    }
+    if let Some(description) = meta["description"].as_str() {
+        details.description = description.to_string();
+    }
+    if let Some(handle) = meta["vanityChannelUrl"]
+        .as_str()
+        .and_then(|url| url.rsplit('/').next())
+        .filter(|h| h.starts_with('@'))
+    {
+        details.handle = Some(handle.to_string());
+    }
+    details.keywords = meta["keywords"]
+        .as_str()
+        .map(split_keywords)
+        .unwrap_or_default();
+}
+
+fn apply_about(about: &Value, details: &mut ChannelDetails) {
+    if let Some(description) = about["description"].as_str().filter(|d| !d.is_empty()) {
+        details.description = description.to_string();
+    }
+    details.subscriber_count = about["subscriberCountText"].as_str().and_then(parse_count);
+    details.view_count = about["viewCountText"].as_str().and_then(parse_count);
+    details.video_count = about["videoCountText"].as_str().and_then(parse_count);
+    details.joined = text_of(&about["joinedDateText"])
+        .map(|t| t.trim_start_matches("Joined").trim().to_string());
+    details.country = about["country"].as_str().map(str::to_string);
+    if let Some(url) = about["canonicalChannelUrl"].as_str() {
+        if let Some(handle) = url.rsplit('/').next().filter(|h| h.starts_with('@')) {
+            details.handle = Some(handle.to_string());
+        }
+    }
+    details.links = about["links"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|link| {
+            let link = &link["channelExternalLinkViewModel"];
+            let title = text_of(&link["title"])?;
+            let url = text_of(&link["link"])?;
+            let url = if url.starts_with("http") {
+                url
+            } else {
+                format!("https://{}", url)
+            };
+            Some(ChannelLink { title, url })
+        })
+        .collect();
+}
+
+/// The About panel's continuation token, found under an engagement panel.
+fn about_continuation(data: &Value) -> Option<String> {
+    let panels = data.get("engagementPanels")?;
+    find_key(panels, "continuationCommand")?["token"]
+        .as_str()
+        .map(str::to_string)
+}
+
+/// Channel keywords are space separated, with multi-word keywords quoted.
+fn split_keywords(raw: &str) -> Vec<String> {
+    let mut keywords = Vec::new();
+    for (i, part) in raw.split('"').enumerate() {
+        if i % 2 == 1 {
+            if !part.trim().is_empty() {
+                keywords.push(part.trim().to_string());
+            }
+        } else {
+            keywords.extend(part.split_whitespace().map(str::to_string));
+        }
+    }
+    keywords
+}
+
+/// Parse "1.23M subscribers", "45K", "123,456,789 views" or "No videos".
+fn parse_count(text: &str) -> Option<u64> {
+    let token = text.split_whitespace().next()?.replace(',', "");
+    if token.eq_ignore_ascii_case("no") {
+        return Some(0);
+    }
+    let (number, multiplier) = match token.chars().last()? {
+        'K' | 'k' => (&token[..token.len() - 1], 1_000.0),
+        'M' => (&token[..token.len() - 1], 1_000_000.0),
+        'B' => (&token[..token.len() - 1], 1_000_000_000.0),
+        _ => (token.as_str(), 1.0),
+    };
+    let value: f64 = number.parse().ok()?;
+    Some((value * multiplier).round() as u64)
+}
+
+/// Summarize upload frequency from feed entries (any order).
+pub(super) fn upload_cadence(videos: &[ListedVideo], now: DateTime<Utc>) -> Option<UploadCadence> {
+    let mut dates: Vec<DateTime<Utc>> = videos
+        .iter()
+        .filter_map(|v| v.published_at.as_deref().and_then(parse_rfc3339))
+        .collect();
+    dates.sort_unstable_by(|a, b| b.cmp(a));
+    let latest = *dates.first()?;
+
+    let within = |days: i64| {
+        dates
+            .iter()
+            .filter(|d| **d >= now - Duration::days(days))
+            .count()
+    };
+    let average_days_between_uploads = (dates.len() > 1).then(|| {
+        let span = (latest - *dates.last().expect("non-empty")).num_hours() as f64 / 24.0;
+        (span / (dates.len() - 1) as f64 * 10.0).round() / 10.0
+    });
+
+    Some(UploadCadence {
+        sample_size: dates.len(),
+        latest_upload: latest.to_rfc3339(),
+        days_since_latest: (now - latest).num_days(),
+        uploads_last_30_days: within(30),
+        uploads_last_90_days: within(90),
+        average_days_between_uploads,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_about_panel() {
+        let data = json!({
+            "metadata": {"channelMetadataRenderer": {
+                "title": "Some Channel",
+                "description": "short",
+                "keywords": "science \"deep learning\" podcast",
+                "vanityChannelUrl": "http://www.youtube.com/@somechannel"
+            }},
+            "onResponseReceivedEndpoints": [{"appendContinuationItemsAction": {"continuationItems": [
+                {"aboutChannelRenderer": {"metadata": {"aboutChannelViewModel": {
+                    "description": "The full description",
+                    "subscriberCountText": "1.23M subscribers",
+                    "viewCountText": "123,456,789 views",
+                    "videoCountText": "1,234 videos",
+                    "joinedDateText": {"content": "Joined Jan 5, 2012"},
+                    "country": "Canada",
+                    "links": [{"channelExternalLinkViewModel": {
+                        "title": {"content": "Website"},
+                        "link": {"content": "example.com/about"}
+                    }}]
+                }}}}
+            ]}}]
+        });
+
+        let mut details = ChannelDetails::default();
+        apply_channel_metadata(&data, &mut details);
+        apply_about(
+            find_key(&data, "aboutChannelViewModel").unwrap(),
+            &mut details,
+        );
+
+        assert_eq!(details.title, "Some Channel");
+        assert_eq!(details.handle.as_deref(), Some("@somechannel"));
+        assert_eq!(
+            details.keywords,
+            vec!["science", "deep learning", "podcast"]
+        );
+        assert_eq!(details.description, "The full description");
+        assert_eq!(details.subscriber_count, Some(1_230_000));
+        assert_eq!(details.view_count, Some(123_456_789));
+        assert_eq!(details.video_count, Some(1_234));
+        assert_eq!(details.joined.as_deref(), Some("Jan 5, 2012"));
+        assert_eq!(
+            details.links,
+            vec![ChannelLink {
+                title: "Website".to_string(),
+                url: "https://example.com/about".to_string()
+            }]
+        );
+        assert_eq!(parse_count("No videos"), Some(0));
+    }
+
+    #[test]
+    fn computes_upload_cadence() {
+        let video = |date: &str| ListedVideo {
+            id: date.to_string(),
+            title: String::new(),
+            url: String::new(),
+            published_at: Some(date.to_string()),
+            published_approximate: false,
+            channel_title: None,
+            duration_seconds: None,
+        };
+        let videos = vec![
+            video("2026-01-01T00:00:00Z"),
+            video("2026-02-10T00:00:00Z"),
+            video("2026-02-20T00:00:00Z"),
+        ];
+        let now = parse_rfc3339("2026-03-01T00:00:00Z").unwrap();
+
+        let cadence = upload_cadence(&videos, now).unwrap();
+        assert_eq!(cadence.sample_size, 3);
+        assert_eq!(cadence.days_since_latest, 9);
+        assert_eq!(cadence.uploads_last_30_days, 2);
+        assert_eq!(cadence.uploads_last_90_days, 3);
+        assert_eq!(cadence.average_days_between_uploads, Some(25.0));
+        assert!(upload_cadence(&[], now).is_none());
+    }
+}
@@ -0,0 +1,311 @@
+// src/connectors/youtube/download.rs
+//
+// Opt-in media download (`youtube-download` feature). Files are streamed to
+// disk with a size cap and returned as a `file://` resource link so a
+// transcription step can pick them up. Everything is written under one root,
+// `ARIVU_DOWNLOAD_DIR` or `<temp dir>/arivu-youtube`.
+
+use super::extract_video_id;
+use crate::error::ConnectorError;
+use crate::utils::structured_result_with_text;
+use futures::FutureExt;
+use rmcp::model::*;
+use rusty_ytdl::stream::Stream as _;
+use rusty_ytdl::{choose_format, Video, VideoOptions, VideoQuality, VideoSearchOptions};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::panic::AssertUnwindSafe;
+use std::path::{Component, Path, PathBuf};
+
+const DEFAULT_MAX_MB: u64 = 200;
+const MAX_MAX_MB: u64 = 4096;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DownloadKind {
+    /// Audio-only stream (default; smallest input for transcription)
+    #[default]
+    Audio,
+    /// Video with audio
+    Video,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DownloadQuality {
+    #[default]
+    Highest,
+    Lowest,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DownloadInput {
+    /// The YouTube video ID or full URL
+    pub video_id: String,
+    /// What to download: 'audio' (default) or 'video'
+    #[serde(default)]
+    pub kind: DownloadKind,
+    /// Stream quality: 'highest' (default) or 'lowest'
+    #[serde(default)]
+    pub quality: DownloadQuality,
+    /// Subdirectory of the download directory to write into (relative; default: the download
+    /// directory itself, ARIVU_DOWNLOAD_DIR or <temp dir>/arivu-youtube)
+    #[serde(default)]
+    pub output_dir: Option<String>,
+    /// Refuse streams larger than this many megabytes (default: 200)
+    #[serde(default)]
+    pub max_mb: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DownloadOutput {
+    pub video_id: String,
+    pub title: String,
+    pub path: String,
+    pub uri: String,
+    pub mime_type: String,
+    pub size_bytes: u64,
+    pub kind: DownloadKind,
+}
+
+pub(super) fn tool() -> Result<Tool, ConnectorError> {
+    Ok(Tool {
+        name: "download".into(),
+        title: None,
+        description: Some(
+            "Download a video's audio (default) or video to a local file, e.g. for transcription. \
+Returns the file path and a file:// resource link. Streams over max_mb are refused. \
+Example: video_id=\"dQw4w9WgXcQ\" kind=\"audio\" quality=\"lowest\"."
+                .into(),
+        ),
+        input_schema: std::sync::Arc::new(
+            serde_json::to_value(schemars::schema_for!(DownloadInput))
+                .map_err(|e| ConnectorError::Other(e.to_string()))?
+                .as_object()
+                .expect("Schema object")
+                .clone(),
+        ),
+        output_schema: None,
+        annotations: None,
+        icons: None,
+    })
+}
+
+pub(super) async fn download(
+    base_options: &VideoOptions,
+    input: DownloadInput,
+) -> Result<CallToolResult, ConnectorError> {
+    let video_id = extract_video_id(&input.video_id);
+    if video_id.is_empty()
+        || !video_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(ConnectorError::InvalidParams(format!(
+            "Invalid YouTube video id: {}",
+            input.video_id
+        )));
+    }
+    let max_bytes = input.max_mb.unwrap_or(DEFAULT_MAX_MB).clamp(1, MAX_MAX_MB) * 1024 * 1024;
+
+    let options = VideoOptions {
+        quality: match (input.kind, input.quality) {
+            (DownloadKind::Audio, DownloadQuality::Highest) => VideoQuality::HighestAudio,
+            (DownloadKind::Audio, DownloadQuality::Lowest) => VideoQuality::LowestAudio,
+            (DownloadKind::Video, DownloadQuality::Highest) => VideoQuality::Highest,
+            (DownloadKind::Video, DownloadQuality::Lowest) => VideoQuality::Lowest,
+        },
+        filter: match input.kind {
+            DownloadKind::Audio => VideoSearchOptions::Audio,
+            DownloadKind::Video => VideoSearchOptions::VideoAudio,
+        },
+        ..base_options.clone()
+    };
+    let video = Video::new_with_options(
+        format!("https://www.youtube.com/watch?v={}", video_id).as_str(),
+        options.clone(),
+    )
+    .map_err(|e| ConnectorError::Other(e.to_string()))?;
+
+    // Guard against upstream panics in rusty_ytdl
+    let info = AssertUnwindSafe(video.get_info())
+        .catch_unwind()
+        .await
+        .map_err(|_| ConnectorError::Other("YouTube get_info panicked".to_string()))?
+        .map_err(|e| ConnectorError::Other(e.to_string()))?;
+    let format =
+        choose_format(&info.formats, &options).map_err(|e| ConnectorError::Other(e.to_string()))?;
+    if let Some(size) = format
+        .content_length
+        .as_deref()
+        .and_then(|s| s.parse::<u64>().ok())
+        .filter(|s| *s > max_bytes)
+    {
+        return Err(ConnectorError::InvalidParams(format!(
+            "stream is {} bytes, over max_mb={}; try quality=\"lowest\" or kind=\"audio\"",
+            size,
+            max_bytes / (1024 * 1024)
+        )));
+    }
+
+    let root = download_root();
+    let dir = resolve_output_dir(&root, input.output_dir.as_deref())?;
+    std::fs::create_dir_all(&dir)?;
+    // A symlink inside the root could still point elsewhere
+    if !dir.canonicalize()?.starts_with(root.canonicalize()?) {
+        return Err(ConnectorError::InvalidParams(format!(
+            "output_dir resolves outside the download directory {}",
+            root.display()
+        )));
+    }
+    let file_name = format!(
+        "{}.{}",
+        video_id,
+        extension_for(&format.mime_type.container, input.kind)
+    );
+    let path = dir.join(&file_name);
+
+    let stream = video
+        .stream()
+        .await
+        .map_err(|e| ConnectorError::Other(e.to_string()))?;
+    let size_bytes = match write_stream(stream.as_ref(), &path, max_bytes).await {
+        Ok(size) => size,
+        Err(e) => {
+            // Never leave a truncated file behind
+            let _ = std::fs::remove_file(&path);
+            return Err(e);
+        }
+    };
+
+    let path = path.canonicalize().unwrap_or(path);
+    let path_str = path.to_string_lossy().to_string();
+    let uri = url::Url::from_file_path(&path)
+        .map(|u| u.to_string())
+        .unwrap_or_else(|_| format!("file://{}", path_str));
+    let mime_type = format.mime_type.mime.to_string();
+    let out = DownloadOutput {
+        video_id: video_id.clone(),
+        title: info.video_details.title.clone(),
+        path: path_str,
+        uri: uri.clone(),
+        mime_type: mime_type.clone(),
+        size_bytes,
+        kind: input.kind,
+    };
+
+    let mut result = structured_result_with_text(&out, None)?;
+    result.content.push(
+        RawContent::ResourceLink(RawResource {
+            uri,
+            name: file_name,
+            title: Some(out.title.clone()),
+            description: None,
+            mime_type: Some(mime_type),
+            size: u32::try_from(size_bytes).ok(),
+            icons: None,
+        })
+        .no_annotation(),
+    );
+    Ok(result)
+}
+
+async fn write_stream(
+    stream: &(dyn rusty_ytdl::stream::Stream + Send + Sync),
+    path: &Path,
+    max_bytes: u64,
+) -> Result<u64, ConnectorError> {
+    let mut file = std::fs::File::create(path)?;
+    let mut written = 0u64;
+    while let Some(chunk) = stream
+        .chunk()
+        .await
+        .map_err(|e| ConnectorError::Other(e.to_string()))?
+    {
+        written += chunk.len() as u64;
+        if written > max_bytes {
+            return Err(ConnectorError::InvalidParams(format!(
+                "download exceeded max_mb={}",
+                max_bytes / (1024 * 1024)
+            )));
+        }
+        file.write_all(&chunk)?;
+    }
+    file.flush()?;
+    Ok(written)
+}
+
+/// Directory all downloads go under: `ARIVU_DOWNLOAD_DIR`, or `<temp dir>/arivu-youtube`.
+fn download_root() -> PathBuf {
+    std::env::var("ARIVU_DOWNLOAD_DIR")
+        .ok()
+        .filter(|d| !d.trim().is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| std::env::temp_dir().join("arivu-youtube"))
+}
+
+/// `output_dir` joined to `root`. Only plain relative paths are accepted, so a caller can't
+/// write outside the root.
+fn resolve_output_dir(root: &Path, output_dir: Option<&str>) -> Result<PathBuf, ConnectorError> {
+    let Some(sub) = output_dir.map(str::trim).filter(|d| !d.is_empty()) else {
+        return Ok(root.to_path_buf());
+    };
+    let relative = Path::new(sub)
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir));
+    if !relative {
+        return Err(ConnectorError::InvalidParams(format!(
+            "output_dir must be a relative subdirectory of the download directory {}, got {}",
+            root.display(),
+            sub
+        )));
+    }
+    Ok(root.join(sub))
+}
+
+/// File extension for a stream container; audio-only mp4 is conventionally `.m4a`.
+fn extension_for(container: &str, kind: DownloadKind) -> &str {
+    match (container, kind) {
+        ("mp4", DownloadKind::Audio) => "m4a",
+        ("", _) => "bin",
+        (other, _) => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_files_by_container() {
+        assert_eq!(extension_for("mp4", DownloadKind::Audio), "m4a");
+        assert_eq!(extension_for("mp4", DownloadKind::Video), "mp4");
+        assert_eq!(extension_for("webm", DownloadKind::Audio), "webm");
+
+        let input: DownloadInput = serde_json::from_value(serde_json::json!({
+            "video_id": "dQw4w9WgXcQ"
+        }))
+        .unwrap();
+        assert_eq!(input.kind, DownloadKind::Audio);
+        assert_eq!(input.quality, DownloadQuality::Highest);
+    }
+
+    #[test]
+    fn keeps_output_dir_under_the_download_root() {
+        let root = Path::new("/tmp/arivu-youtube");
+        assert_eq!(resolve_output_dir(root, None).unwrap(), root);
+        assert_eq!(resolve_output_dir(root, Some(" ")).unwrap(), root);
+        assert_eq!(
+            resolve_output_dir(root, Some("talks/2025")).unwrap(),
+            root.join("talks/2025")
+        );
+        for outside in ["/etc", "../escape", "talks/../../escape"] {
+            assert!(
+                resolve_output_dir(root, Some(outside)).is_err(),
+                "{}",
+                outside
+            );
+        }
+    }
+}
@@ -0,0 +1,134 @@
+// src/connectors/youtube/innertube.rs
+//
+// Helpers shared by the scrapers that read YouTube's web pages and the
+// innertube (`/youtubei/v1/*`) API used by the web client.
+
+use crate::error::ConnectorError;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use reqwest::Client as HttpClient;
+use serde_json::{json, Value};
+
+const FALLBACK_CLIENT_VERSION: &str = "2.20240101.00.00";
+// Skips the EU consent interstitial, which has no ytInitialData.
+const CONSENT_COOKIE: &str = "SOCS=CAI; CONSENT=YES+cb";
+
+/// A YouTube web page with its `ytInitialData` and the innertube settings
+/// needed to request continuations.
+pub(super) struct WebPage {
+    pub initial_data: Value,
+    api_key: Option<String>,
+    client_version: String,
+}
+
+impl WebPage {
+    /// GET a youtube.com page (e.g. `/playlist`) in English.
+    pub async fn fetch(
+        client: &HttpClient,
+        url: &str,
+        query: &[(&str, &str)],
+    ) -> Result<Self, ConnectorError> {
+        let html = client
+            .get(url)
+            .query(query)
+            .query(&[("hl", "en")])
+            .header("Accept-Language", "en-US,en;q=0.9")
+            .header("Cookie", CONSENT_COOKIE)
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?
+            .error_for_status()
+            .map_err(ConnectorError::HttpRequest)?
+            .text()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+        Self::parse(&html).ok_or_else(|| {
+            ConnectorError::Other("YouTube page did not include ytInitialData".to_string())
+        })
+    }
+
+    pub fn parse(html: &str) -> Option<Self> {
+        static API_KEY_RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r#""INNERTUBE_API_KEY"\s*:\s*"([^"]+)""#).expect("api key regex")
+        });
+        static CLIENT_VERSION_RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r#""INNERTUBE_CLIENT_VERSION"\s*:\s*"([^"]+)""#)
+                .expect("client version regex")
+        });
+
+        Some(Self {
+            initial_data: extract_initial_data(html)?,
+            api_key: API_KEY_RE.captures(html).map(|c| c[1].to_string()),
+            client_version: CLIENT_VERSION_RE
+                .captures(html)
+                .map(|c| c[1].to_string())
+                .unwrap_or_else(|| FALLBACK_CLIENT_VERSION.to_string()),
+        })
+    }
+
+    /// Request the next page of a browse response by continuation token.
+    pub async fn browse_continuation(
+        &self,
+        client: &HttpClient,
+        token: &str,
+    ) -> Result<Value, ConnectorError> {
+        let mut request = client
+            .post("https://www.youtube.com/youtubei/v1/browse")
+            .header("Cookie", CONSENT_COOKIE)
+            .json(&json!({
+                "context": {
+                    "client": {
+                        "clientName": "WEB",
+                        "clientVersion": self.client_version,
+                        "hl": "en"
+                    }
+                },
+                "continuation": token
+            }));
+        if let Some(key) = &self.api_key {
+            request = request.query(&[("key", key.as_str()), ("prettyPrint", "false")]);
+        }
+        request
+            .send()
+            .await
+            .map_err(ConnectorError::HttpRequest)?
+            .error_for_status()
+            .map_err(ConnectorError::HttpRequest)?
+            .json()
+            .await
+            .map_err(ConnectorError::HttpRequest)
+    }
+}
+
+fn extract_initial_data(html: &str) -> Option<Value> {
+    let marker = ["var ytInitialData = ", "window[\"ytInitialData\"] = "]
+        .iter()
+        .find_map(|m| html.find(m).map(|i| i + m.len()))?;
+    // Parse one JSON value and ignore the trailing `;</script>...`
+    serde_json::Deserializer::from_str(&html[marker..])
+        .into_iter::<Value>()
+        .next()?
+        .ok()
+}
+
+/// Depth-first search for the first object stored under `key`.
+pub(super) fn find_key<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    match value {
+        Value::Object(map) => map
+            .get(key)
+            .or_else(|| map.values().find_map(|child| find_key(child, key))),
+        Value::Array(items) => items.iter().find_map(|item| find_key(item, key)),
+        _ => None,
+    }
+}
+
+/// Innertube text is either `{simpleText}`, `{runs: [{text}]}` or, in newer
+/// view models, `{content}`.
+pub(super) fn text_of(value: &Value) -> Option<String> {
+    if let Some(text) = value["simpleText"].as_str().or(value["content"].as_str()) {
+        return Some(text.to_string());
+    }
+    let runs = value["runs"].as_array()?;
+    let text: String = runs.iter().filter_map(|r| r["text"].as_str()).collect();
+    (!text.is_empty()).then_some(text)
+}
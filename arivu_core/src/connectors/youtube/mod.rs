@@ -22,12 +22,22 @@ use std::cmp::Ordering;
 use std::panic::AssertUnwindSafe;
 use std::sync::Arc;
 use url::Url;
-use yt_transcript_rs::YouTubeTranscriptApi;
 use {
     quick_xml::events::Event as XmlEvent, quick_xml::reader::Reader as XmlReader,
     reqwest::Client as HttpClient,
 };
 
+mod channel;
+#[cfg(feature = "youtube-download")]
+mod download;
+mod innertube;
+mod playlist;
+mod transcript;
+
+pub use channel::{ChannelDetails, ChannelLink, UploadCadence};
+pub use transcript::{TranscriptMatch, TranscriptTrack};
+use transcript::{TranscriptOutcome, TranscriptRequest, TranscriptSnippet};
+
 // Input/Output structs for tools
 /// Response format for controlling output verbosity
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, Copy, PartialEq, Eq, Default)]
@@ -47,6 +57,20 @@ pub struct GetVideoDetailsInput {
     /// Response verbosity: 'concise' returns only title and transcript/chapters, 'detailed' includes description and all metadata
     #[serde(default)]
     pub response_format: ResponseFormat,
+    /// Transcript language codes in order of preference (e.g., ['de', 'en'])
+    #[serde(default = "default_transcript_languages")]
+    #[schemars(default = "default_transcript_languages")]
+    pub languages: Vec<String>,
+    /// Prefer auto-generated captions over creator-uploaded ones
+    #[serde(default)]
+    pub prefer_auto: bool,
+    /// Machine-translate the transcript into this language code (e.g., 'en')
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translate_to: Option<String>,
+}
+
+fn default_transcript_languages() -> Vec<String> {
+    vec!["en".to_string()]
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
@@ -103,10 +127,11 @@ pub struct ListVideosInput {
     pub channel: Option<String>,
 
     /// Playlist identifier. Accepts a playlist ID (PL.../UU...) or a playlist URL.
+    /// Playlists are enumerated in playlist order and can return up to 1000 items.
     #[serde(default)]
     pub playlist: Option<String>,
 
-    /// Max number of videos to return (default: 5).
+    /// Max number of videos to return (default: 5; channels return at most ~15).
     #[serde(default = "default_list_limit")]
     #[schemars(default = "default_list_limit")]
     pub limit: u64,
@@ -127,7 +152,12 @@ pub struct ListedVideo {
     pub title: String,
     pub url: String,
     pub published_at: Option<String>,
+    /// True when `published_at` was derived from a relative label like "2 years ago".
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub published_approximate: bool,
     pub channel_title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_seconds: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
@@ -168,6 +198,58 @@ pub struct ResolveChannelInput {
     pub prefer_verified: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GetChannelInput {
+    /// Channel ID (UC...), channel URL, or handle like "@hubermanlab"
+    pub channel: String,
+    /// Recent uploads to include alongside the cadence summary (default: 5, max: 15)
+    #[serde(default = "default_limit")]
+    #[schemars(default = "default_limit")]
+    pub recent_limit: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SearchTranscriptInput {
+    /// The YouTube video ID or full URL
+    pub video_id: String,
+    /// Words to find (all must appear); wrap exact phrases in double quotes
+    pub query: String,
+    /// Transcript language codes in order of preference (e.g., ['de', 'en'])
+    #[serde(default = "default_transcript_languages")]
+    #[schemars(default = "default_transcript_languages")]
+    pub languages: Vec<String>,
+    /// Caption lines of context to include before and after each match (default: 1)
+    #[serde(default = "default_transcript_context")]
+    #[schemars(default = "default_transcript_context")]
+    pub context: u64,
+    /// Maximum number of matches to return (default: 20)
+    #[serde(default = "default_transcript_match_limit")]
+    #[schemars(default = "default_transcript_match_limit")]
+    pub limit: u64,
+}
+
+fn default_transcript_context() -> u64 {
+    1
+}
+
+fn default_transcript_match_limit() -> u64 {
+    20
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TranscriptSearchOutput {
+    pub video_id: String,
+    pub query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transcript_language: Option<String>,
+    /// Matches before `limit` was applied
+    pub total_matches: usize,
+    pub matches: Vec<TranscriptMatch>,
+    /// Tracks that do exist when none of the requested languages were found
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub available_transcripts: Vec<TranscriptTrack>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Clone)]
 pub struct ChannelCandidate {
     pub channel_id: String,
@@ -667,6 +749,18 @@ pub struct YouTubeContent {
     pub transcript: Option<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub chapters: Vec<ChapterContent>,
+    /// Language of the transcript text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transcript_language: Option<String>,
+    /// Whether the transcript comes from auto-generated captions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transcript_generated: Option<bool>,
+    /// Whether the transcript was machine-translated (`translate_to`)
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub transcript_translated: bool,
+    /// Tracks that do exist when none of the requested languages were found
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub available_transcripts: Vec<TranscriptTrack>,
 }
 
 /// Concise version of YouTubeContent for token efficiency
@@ -677,6 +771,10 @@ pub struct YouTubeContentConcise {
     pub transcript: Option<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub chapters: Vec<ChapterContentConcise>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transcript_language: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub available_transcripts: Vec<TranscriptTrack>,
 }
 
 /// Concise chapter content - just heading and content
@@ -885,13 +983,16 @@ impl Connector for YouTubeConnector {
         &self,
         _request: Option<PaginatedRequestParam>,
     ) -> Result<ListToolsResult, ConnectorError> {
-        let tools = vec![
+        #[allow(unused_mut)]
+        let mut tools = vec![
             Tool {
                 name: Cow::Borrowed("get"),
                 title: None,
                 description: Some(Cow::Borrowed(
                     "Get title/description plus transcript + chapters (when available). Input is a \
-	video ID or URL. Example: video_id=\"dQw4w9WgXcQ\" response_format=\"concise\".",
+	video ID or URL. Pick the caption track with languages=[\"de\",\"en\"], \
+	prefer_auto, and translate_to=\"en\"; when no requested language exists the available \
+	tracks are listed. Example: video_id=\"dQw4w9WgXcQ\" response_format=\"concise\".",
                 )),
                 input_schema: Arc::new(
                     serde_json::to_value(schemars::schema_for!(GetVideoDetailsInput))
@@ -904,6 +1005,25 @@ impl Connector for YouTubeConnector {
                 annotations: None,
                 icons: None,
             },
+            Tool {
+                name: Cow::Borrowed("search_transcript"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Find where something is said in one video. Returns matching transcript passages \
+with timestamps and deep links (&t=123s). Use this for \"where in this 3-hour podcast do they \
+discuss X\". Example: video_id=\"dQw4w9WgXcQ\" query=\"intermittent fasting\" context=1.",
+                )),
+                input_schema: Arc::new(
+                    serde_json::to_value(schemars::schema_for!(SearchTranscriptInput))
+                        .map_err(|e| ConnectorError::Other(e.to_string()))?
+                        .as_object()
+                        .expect("Schema object")
+                        .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
             Tool {
                 name: Cow::Borrowed("search"),
                 title: None,
@@ -941,6 +1061,25 @@ source=\"channel\" channel=\"@hubermanlab\" limit=5 published_within_days=7.",
                 annotations: None,
                 icons: None,
             },
+            Tool {
+                name: Cow::Borrowed("get_channel"),
+                title: None,
+                description: Some(Cow::Borrowed(
+                    "Get channel metadata: description, subscriber/view/video counts, links, join date, \
+and recent upload cadence. Accepts a UC... ID, channel URL or @handle (use resolve_channel first for a \
+free-text name). Example: channel=\"@hubermanlab\".",
+                )),
+                input_schema: Arc::new(
+                    serde_json::to_value(schemars::schema_for!(GetChannelInput))
+                        .map_err(|e| ConnectorError::Other(e.to_string()))?
+                        .as_object()
+                        .expect("Schema object")
+                        .clone(),
+                ),
+                output_schema: None,
+                annotations: None,
+                icons: None,
+            },
             Tool {
                 name: Cow::Borrowed("resolve_channel"),
                 title: None,
@@ -961,6 +1100,8 @@ Example: query=\"Andrew Huberman\" limit=5 prefer_verified=true.",
                 icons: None,
             },
         ];
+        #[cfg(feature = "youtube-download")]
+        tools.push(download::tool()?);
 
         Ok(ListToolsResult {
             tools,
@@ -998,17 +1139,43 @@ Example: query=\"Andrew Huberman\" limit=5 prefer_verified=true.",
                     .map_err(|e| ConnectorError::Other(e.to_string()))?;
 
                 let chapters = video_info.video_details.chapters.clone();
-                let api = YouTubeTranscriptApi::new(None, None, None)
-                    .map_err(|e| ConnectorError::Other(e.to_string()))?;
+                let request = TranscriptRequest {
+                    languages: if input.languages.is_empty() {
+                        default_transcript_languages()
+                    } else {
+                        input.languages.clone()
+                    },
+                    prefer_auto: input.prefer_auto,
+                    translate_to: input.translate_to.clone(),
+                };
+
+                let client = HttpClient::builder()
+                    .user_agent("rzn-datasourcer/0.2.x youtube-connector")
+                    .timeout(std::time::Duration::from_secs(20))
+                    .build()
+                    .map_err(ConnectorError::HttpRequest)?;
+
+                let mut transcript_language = None;
+                let mut transcript_generated = None;
+                let mut transcript_translated = false;
+                let mut available_transcripts = Vec::new();
 
                 // Fetch transcript parts once; we will decide whether to expose
                 // chapterized content or a raw transcript, but never both.
                 let (chapters_out, transcript_out) =
-                    match api.fetch_transcript(&video_id, &["en"], false).await {
-                        Ok(fetched) => {
+                    match transcript::fetch_transcript(&client, &video_id, &request).await {
+                        Ok(TranscriptOutcome::Found {
+                            snippets,
+                            language_code,
+                            is_generated,
+                            translated,
+                        }) => {
+                            transcript_language = Some(language_code);
+                            transcript_generated = Some(is_generated);
+                            transcript_translated = translated;
+
                             // Build a raw transcript string from parts (cleaned) for fallback.
-                            let parts = fetched.parts();
-                            let raw_text = parts
+                            let raw_text = snippets
                                 .iter()
                                 .map(|p| p.text.clone())
                                 .collect::<Vec<_>>()
@@ -1017,7 +1184,8 @@ Example: query=\"Andrew Huberman\" limit=5 prefer_verified=true.",
 
                             if !chapters.is_empty() {
                                 // Prefer chapterized content when real chapter metadata exists.
-                                let grouped = group_transcript_by_chapters_new(&chapters, fetched);
+                                let grouped =
+                                    group_transcript_by_chapters_new(&chapters, &snippets);
                                 if !grouped.is_empty() {
                                     (grouped, None)
                                 } else if !cleaned.is_empty() {
@@ -1032,6 +1200,11 @@ Example: query=\"Andrew Huberman\" limit=5 prefer_verified=true.",
                                 (Vec::new(), None)
                             }
                         }
+                        Ok(TranscriptOutcome::Missing(tracks)) => {
+                            // Let the caller retry with a language that exists
+                            available_transcripts = tracks;
+                            (Vec::new(), None)
+                        }
                         Err(e) => {
                             tracing::warn!(
                                 error = %e,
@@ -1055,6 +1228,8 @@ Example: query=\"Andrew Huberman\" limit=5 prefer_verified=true.",
                         title: video_info.video_details.title.clone(),
                         transcript: transcript_out,
                         chapters: concise_chapters,
+                        transcript_language,
+                        available_transcripts,
                     };
                     let text = serde_json::to_string(&youtube_content)?;
                     Ok(structured_result_with_text(&youtube_content, Some(text))?)
@@ -1065,11 +1240,73 @@ Example: query=\"Andrew Huberman\" limit=5 prefer_verified=true.",
                         description: video_info.video_details.description.clone(),
                         transcript: transcript_out,
                         chapters: chapters_out,
+                        transcript_language,
+                        transcript_generated,
+                        transcript_translated,
+                        available_transcripts,
                     };
                     let text = serde_json::to_string(&youtube_content)?;
                     Ok(structured_result_with_text(&youtube_content, Some(text))?)
                 }
             }
+            "search_transcript" => {
+                let input: SearchTranscriptInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                if input.query.trim().is_empty() {
+                    return Err(ConnectorError::InvalidParams(
+                        "query must not be empty".to_string(),
+                    ));
+                }
+
+                let video_id = extract_video_id(&input.video_id);
+                let client = HttpClient::builder()
+                    .user_agent("rzn-datasourcer/0.2.x youtube-connector")
+                    .timeout(std::time::Duration::from_secs(20))
+                    .build()
+                    .map_err(ConnectorError::HttpRequest)?;
+                let request = TranscriptRequest {
+                    languages: if input.languages.is_empty() {
+                        default_transcript_languages()
+                    } else {
+                        input.languages.clone()
+                    },
+                    ..Default::default()
+                };
+
+                let mut out = TranscriptSearchOutput {
+                    video_id: video_id.clone(),
+                    query: input.query.clone(),
+                    transcript_language: None,
+                    total_matches: 0,
+                    matches: Vec::new(),
+                    available_transcripts: Vec::new(),
+                };
+                match transcript::fetch_transcript(&client, &video_id, &request).await? {
+                    TranscriptOutcome::Found {
+                        mut snippets,
+                        language_code,
+                        ..
+                    } => {
+                        for snippet in &mut snippets {
+                            snippet.text = clean_html_entities(&snippet.text.replace('\n', " "));
+                        }
+                        let mut matches = transcript::search_snippets(
+                            &snippets,
+                            &video_id,
+                            &input.query,
+                            input.context.min(10) as usize,
+                        );
+                        out.total_matches = matches.len();
+                        matches.truncate(input.limit.clamp(1, 200) as usize);
+                        out.matches = matches;
+                        out.transcript_language = Some(language_code);
+                    }
+                    TranscriptOutcome::Missing(tracks) => out.available_transcripts = tracks,
+                }
+
+                let text = serde_json::to_string(&out)?;
+                Ok(structured_result_with_text(&out, Some(text))?)
+            }
             "search" | "search_videos" => {
                 let input: SearchVideosInput = serde_json::from_value(Value::Object(args_map))
                     .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
@@ -1196,7 +1433,12 @@ Example: query=\"Andrew Huberman\" limit=5 prefer_verified=true.",
                 let input: ListVideosInput = serde_json::from_value(Value::Object(args_map))
                     .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
 
-                let limit = input.limit.clamp(1, 50) as usize;
+                let limit = match input.source {
+                    ListSource::Channel => input.limit.clamp(1, 50) as usize,
+                    ListSource::Playlist => {
+                        input.limit.clamp(1, playlist::MAX_PLAYLIST_ITEMS as u64) as usize
+                    }
+                };
                 let client = HttpClient::builder()
                     .user_agent("rzn-datasourcer/0.2.x youtube-connector")
                     .timeout(std::time::Duration::from_secs(20))
@@ -1244,13 +1486,45 @@ Example: query=\"Andrew Huberman\" limit=5 prefer_verified=true.",
                     .await
                     .map_err(ConnectorError::HttpRequest)?;
 
-                let mut videos = parse_youtube_atom_feed(&xml)?;
+                let feed_videos = parse_youtube_atom_feed(&xml)?;
 
                 let after = if let Some(days) = input.published_within_days {
                     Some(Utc::now() - Duration::days(days as i64))
                 } else {
                     input.published_after.as_deref().and_then(parse_rfc3339)
                 };
+
+                // Playlists: page through innertube up to the limit, then take exact dates from the feed
+                // where it has them. A date filter needs the whole playlist, since
+                // playlist order is not chronological.
+                let mut videos = match playlist_id.as_deref() {
+                    Some(pid) => {
+                        let wanted = if after.is_some() {
+                            playlist::MAX_PLAYLIST_ITEMS
+                        } else {
+                            limit
+                        };
+                        match playlist::fetch_playlist_videos(&client, pid, wanted).await {
+                            Ok(mut all) if !all.is_empty() => {
+                                for video in &mut all {
+                                    if let Some(exact) =
+                                        feed_videos.iter().find(|f| f.id == video.id)
+                                    {
+                                        video.published_at = exact.published_at.clone();
+                                        video.published_approximate = false;
+                                    }
+                                }
+                                all
+                            }
+                            Ok(_) => feed_videos,
+                            Err(e) => {
+                                tracing::warn!(error = %e, "Playlist enumeration failed; using the Atom feed");
+                                feed_videos
+                            }
+                        }
+                    }
+                    None => feed_videos,
+                };
                 if let Some(after) = after {
                     videos.retain(|v| {
                         v.published_at
@@ -1261,19 +1535,22 @@ Example: query=\"Andrew Huberman\" limit=5 prefer_verified=true.",
                     });
                 }
 
-                videos.sort_by(|a, b| {
-                    let ad = a
-                        .published_at
-                        .as_deref()
-                        .and_then(parse_rfc3339)
-                        .unwrap_or_else(|| DateTime::<Utc>::from_timestamp(0, 0).unwrap());
-                    let bd = b
-                        .published_at
-                        .as_deref()
-                        .and_then(parse_rfc3339)
-                        .unwrap_or_else(|| DateTime::<Utc>::from_timestamp(0, 0).unwrap());
-                    bd.cmp(&ad)
-                });
+                // Channel uploads newest first; playlists keep their own order
+                if input.source == ListSource::Channel {
+                    videos.sort_by(|a, b| {
+                        let ad = a
+                            .published_at
+                            .as_deref()
+                            .and_then(parse_rfc3339)
+                            .unwrap_or_else(|| DateTime::<Utc>::from_timestamp(0, 0).unwrap());
+                        let bd = b
+                            .published_at
+                            .as_deref()
+                            .and_then(parse_rfc3339)
+                            .unwrap_or_else(|| DateTime::<Utc>::from_timestamp(0, 0).unwrap());
+                        bd.cmp(&ad)
+                    });
+                }
 
                 videos.truncate(limit);
 
@@ -1286,6 +1563,55 @@ Example: query=\"Andrew Huberman\" limit=5 prefer_verified=true.",
                 let text = serde_json::to_string(&out)?;
                 Ok(structured_result_with_text(&out, Some(text))?)
             }
+            "get_channel" => {
+                let input: GetChannelInput = serde_json::from_value(Value::Object(args_map))
+                    .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+
+                let client = HttpClient::builder()
+                    .user_agent("rzn-datasourcer/0.2.x youtube-connector")
+                    .timeout(std::time::Duration::from_secs(20))
+                    .build()
+                    .map_err(ConnectorError::HttpRequest)?;
+                let channel_id = resolve_channel_id_best_effort(&client, &input.channel)
+                    .await
+                    .ok_or_else(|| {
+                        ConnectorError::InvalidInput(
+                            "Could not resolve channel_id from channel input. Provide a UC... channel ID, a channel URL or an @handle."
+                                .to_string(),
+                        )
+                    })?;
+
+                let mut details = channel::fetch_channel_details(&client, &channel_id).await?;
+
+                // The cadence is best-effort: the feed is missing for channels without uploads
+                let feed = async {
+                    let xml = client
+                        .get(feed_url_for_channel(&channel_id))
+                        .send()
+                        .await
+                        .map_err(ConnectorError::HttpRequest)?
+                        .error_for_status()
+                        .map_err(ConnectorError::HttpRequest)?
+                        .text()
+                        .await
+                        .map_err(ConnectorError::HttpRequest)?;
+                    parse_youtube_atom_feed(&xml)
+                };
+                match feed.await {
+                    Ok(mut uploads) => {
+                        details.upload_cadence = channel::upload_cadence(&uploads, Utc::now());
+                        uploads.sort_by(|a, b| b.published_at.cmp(&a.published_at));
+                        uploads.truncate(input.recent_limit.min(15) as usize);
+                        details.recent_uploads = uploads;
+                    }
+                    Err(e) => {
+                        tracing::debug!(error = %e, channel_id = %channel_id, "YouTube channel feed unavailable");
+                    }
+                }
+
+                let text = serde_json::to_string(&details)?;
+                Ok(structured_result_with_text(&details, Some(text))?)
+            }
             "resolve_channel" => {
                 let input: ResolveChannelInput = serde_json::from_value(Value::Object(args_map))
                     .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
@@ -1365,6 +1691,13 @@ Example: query=\"Andrew Huberman\" limit=5 prefer_verified=true.",
                 let text = serde_json::to_string(&out)?;
                 Ok(structured_result_with_text(&out, Some(text))?)
             }
+            #[cfg(feature = "youtube-download")]
+            "download" => {
+                let input: download::DownloadInput =
+                    serde_json::from_value(Value::Object(args_map))
+                        .map_err(|e| ConnectorError::InvalidParams(e.to_string()))?;
+                download::download(&self.video_options, input).await
+            }
             _ => Err(ConnectorError::ToolNotFound),
         }
     }
@@ -1553,7 +1886,9 @@ fn parse_youtube_atom_feed(xml: &str) -> Result<Vec<ListedVideo>, ConnectorError
                             id,
                             title,
                             published_at: cur_published.take(),
+                            published_approximate: false,
                             channel_title: cur_author.take(),
+                            duration_seconds: None,
                         });
                     }
                 } else if matches!(
@@ -1631,10 +1966,8 @@ fn score_channel_candidate(
 
 fn group_transcript_by_chapters_new(
     chapters: &[rusty_ytdl::Chapter],
-    transcript: yt_transcript_rs::FetchedTranscript,
+    parts: &[TranscriptSnippet],
 ) -> Vec<ChapterContent> {
-    let parts = transcript.parts();
-
     if chapters.is_empty() {
         let raw_text = parts
             .iter()
@@ -0,0 +1,165 @@
+// src/connectors/youtube/playlist.rs
+//
+// Full playlist enumeration through the web client's innertube `browse` API.
+// The Atom feed only carries the newest ~15 entries, so larger playlists are
+// read from the playlist page's `ytInitialData` and then continued page by page.
+
+use super::innertube::{text_of, WebPage};
+use super::{parse_uploaded_timestamp, ListedVideo};
+use crate::error::ConnectorError;
+use reqwest::Client as HttpClient;
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Upper bound on items returned from one playlist.
+pub(super) const MAX_PLAYLIST_ITEMS: usize = 1000;
+// Each continuation returns up to 100 items.
+const MAX_CONTINUATIONS: usize = 20;
+
+/// Enumerate up to `limit` videos of a playlist in playlist order.
+/// `published_at` is derived from the relative "3 years ago" label and is
+/// therefore approximate (`published_approximate = true`).
+pub(super) async fn fetch_playlist_videos(
+    client: &HttpClient,
+    playlist_id: &str,
+    limit: usize,
+) -> Result<Vec<ListedVideo>, ConnectorError> {
+    let web_page = WebPage::fetch(
+        client,
+        "https://www.youtube.com/playlist",
+        &[("list", playlist_id)],
+    )
+    .await?;
+
+    let mut page = PlaylistPage::default();
+    collect_playlist_items(&web_page.initial_data, &mut page);
+    let mut videos = Vec::new();
+    let mut seen = HashSet::new();
+    page.drain_into(&mut videos, &mut seen);
+
+    let mut requests = 0;
+    while videos.len() < limit && requests < MAX_CONTINUATIONS {
+        let Some(token) = page.continuation.take() else {
+            break;
+        };
+        requests += 1;
+
+        let response = web_page.browse_continuation(client, &token).await?;
+        collect_playlist_items(&response, &mut page);
+        let before = videos.len();
+        page.drain_into(&mut videos, &mut seen);
+        if videos.len() == before {
+            break;
+        }
+    }
+
+    videos.truncate(limit);
+    Ok(videos)
+}
+
+#[derive(Default)]
+struct PlaylistPage {
+    videos: Vec<ListedVideo>,
+    continuation: Option<String>,
+}
+
+impl PlaylistPage {
+    fn drain_into(&mut self, out: &mut Vec<ListedVideo>, seen: &mut HashSet<String>) {
+        for video in self.videos.drain(..) {
+            if seen.insert(video.id.clone()) {
+                out.push(video);
+            }
+        }
+    }
+}
+
+/// Walk an innertube response, collecting `playlistVideoRenderer`s and the
+/// continuation token for the next page.
+fn collect_playlist_items(value: &Value, page: &mut PlaylistPage) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                match key.as_str() {
+                    "playlistVideoRenderer" => {
+                        if let Some(video) = video_from_renderer(child) {
+                            page.videos.push(video);
+                        }
+                    }
+                    "continuationItemRenderer" => {
+                        if let Some(token) = child
+                            .pointer("/continuationEndpoint/continuationCommand/token")
+                            .and_then(Value::as_str)
+                        {
+                            page.continuation = Some(token.to_string());
+                        }
+                    }
+                    _ => collect_playlist_items(child, page),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_playlist_items(item, page);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn video_from_renderer(renderer: &Value) -> Option<ListedVideo> {
+    // Private and deleted entries stay in playlists as unplayable stubs
+    if renderer["isPlayable"].as_bool() == Some(false) {
+        return None;
+    }
+    let id = renderer["videoId"].as_str()?.to_string();
+    let title = text_of(&renderer["title"])?;
+    let published_at = renderer["videoInfo"]["runs"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|run| run["text"].as_str())
+        .find_map(parse_uploaded_timestamp)
+        .map(|dt| dt.to_rfc3339());
+
+    Some(ListedVideo {
+        url: format!("https://www.youtube.com/watch?v={}", id),
+        id,
+        title,
+        published_approximate: published_at.is_some(),
+        published_at,
+        channel_title: text_of(&renderer["shortBylineText"]),
+        duration_seconds: renderer["lengthSeconds"]
+            .as_str()
+            .and_then(|s| s.parse().ok()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_videos_and_continuation() {
+        let html = r#"<script>var ytInitialData = {"contents":{"items":[
+            {"playlistVideoRenderer":{"videoId":"abc123def45","title":{"runs":[{"text":"Episode 1"}]},
+              "shortBylineText":{"runs":[{"text":"Some Channel"}]},"lengthSeconds":"3605",
+              "videoInfo":{"runs":[{"text":"1.2M views"},{"text":" • "},{"text":"2 years ago"}]}}},
+            {"playlistVideoRenderer":{"videoId":"zzz","title":{"simpleText":"[Private video]"},"isPlayable":false}},
+            {"continuationItemRenderer":{"continuationEndpoint":{"continuationCommand":{"token":"NEXT"}}}}
+        ]}};</script><script>var other = {};</script>"#;
+
+        let data = WebPage::parse(html).expect("initial data").initial_data;
+        let mut page = PlaylistPage::default();
+        collect_playlist_items(&data, &mut page);
+
+        assert_eq!(page.continuation.as_deref(), Some("NEXT"));
+        assert_eq!(page.videos.len(), 1);
+        let video = &page.videos[0];
+        assert_eq!(video.id, "abc123def45");
+        assert_eq!(video.title, "Episode 1");
+        assert_eq!(video.channel_title.as_deref(), Some("Some Channel"));
+        assert_eq!(video.duration_seconds, Some(3605));
+        assert!(video.published_approximate);
+        assert!(video.published_at.is_some());
+    }
+}
@@ -0,0 +1,458 @@
+// src/connectors/youtube/transcript.rs
+//
+// Caption track selection (language preference, manual vs auto-generated) and
+// translation. yt-transcript-rs lists the tracks; translated or explicitly
+// chosen tracks are fetched here from the track's timedtext URL.
+
+use crate::error::ConnectorError;
+use crate::utils::clean_html_entities;
+use quick_xml::events::Event as XmlEvent;
+use quick_xml::reader::Reader as XmlReader;
+use reqwest::Client as HttpClient;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use yt_transcript_rs::YouTubeTranscriptApi;
+
+/// One timed caption line.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct TranscriptSnippet {
+    pub text: String,
+    /// Seconds from the start of the video
+    pub start: f64,
+    pub duration: f64,
+}
+
+/// A caption track offered for a video.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone)]
+pub struct TranscriptTrack {
+    pub language_code: String,
+    pub language: String,
+    /// Auto-generated (ASR) rather than uploaded by the creator
+    pub is_generated: bool,
+    /// YouTube can machine-translate this track (`translate_to`)
+    pub translatable: bool,
+    #[serde(skip)]
+    #[schemars(skip)]
+    url: String,
+}
+
+/// Which transcript to fetch.
+#[derive(Debug, Clone)]
+pub(super) struct TranscriptRequest {
+    /// Language codes in order of preference
+    pub languages: Vec<String>,
+    /// Prefer auto-generated captions over creator-uploaded ones
+    pub prefer_auto: bool,
+    /// Machine-translate the chosen track into this language
+    pub translate_to: Option<String>,
+}
+
+impl Default for TranscriptRequest {
+    fn default() -> Self {
+        Self {
+            languages: vec!["en".to_string()],
+            prefer_auto: false,
+            translate_to: None,
+        }
+    }
+}
+
+/// Result of a transcript lookup.
+pub(super) enum TranscriptOutcome {
+    Found {
+        snippets: Vec<TranscriptSnippet>,
+        /// Language of the returned text (the translation target when translated)
+        language_code: String,
+        is_generated: bool,
+        translated: bool,
+    },
+    /// None of the requested languages exist; these tracks do
+    Missing(Vec<TranscriptTrack>),
+}
+
+pub(super) async fn fetch_transcript(
+    client: &HttpClient,
+    video_id: &str,
+    request: &TranscriptRequest,
+) -> Result<TranscriptOutcome, ConnectorError> {
+    let api = YouTubeTranscriptApi::new(None, None, None)
+        .map_err(|e| ConnectorError::Other(e.to_string()))?;
+
+    // The common case (first available of the preferred languages, creator
+    // captions first) goes through the library's own fetch.
+    if !request.prefer_auto && request.translate_to.is_none() {
+        let languages: Vec<&str> = request.languages.iter().map(String::as_str).collect();
+        if let Ok(fetched) = api.fetch_transcript(video_id, &languages, false).await {
+            let snippets = fetched
+                .parts()
+                .iter()
+                .map(|p| TranscriptSnippet {
+                    text: p.text.clone(),
+                    start: p.start,
+                    duration: p.duration,
+                })
+                .collect();
+            return Ok(TranscriptOutcome::Found {
+                snippets,
+                language_code: fetched.language_code.clone(),
+                is_generated: fetched.is_generated,
+                translated: false,
+            });
+        }
+    }
+
+    let list = api
+        .list_transcripts(video_id)
+        .await
+        .map_err(|e| ConnectorError::Other(format!("No transcripts available: {}", e)))?;
+    let tracks: Vec<TranscriptTrack> = list
+        .manually_created_transcripts
+        .values()
+        .chain(list.generated_transcripts.values())
+        .map(|t| TranscriptTrack {
+            language_code: t.language_code.clone(),
+            language: t.language.clone(),
+            is_generated: t.is_generated,
+            translatable: !t.translation_languages.is_empty(),
+            url: t.url.clone(),
+        })
+        .collect();
+
+    let Some(track) = select_track(&tracks, request) else {
+        let mut tracks = tracks;
+        tracks.sort_by(|a, b| {
+            (a.is_generated, &a.language_code).cmp(&(b.is_generated, &b.language_code))
+        });
+        return Ok(TranscriptOutcome::Missing(tracks));
+    };
+
+    let mut url = track.url.clone();
+    if let Some(target) = &request.translate_to {
+        url.push_str("&tlang=");
+        url.push_str(target);
+    }
+    let xml = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(ConnectorError::HttpRequest)?
+        .error_for_status()
+        .map_err(ConnectorError::HttpRequest)?
+        .text()
+        .await
+        .map_err(ConnectorError::HttpRequest)?;
+
+    Ok(TranscriptOutcome::Found {
+        snippets: parse_timedtext(&xml)?,
+        language_code: request
+            .translate_to
+            .clone()
+            .unwrap_or_else(|| track.language_code.clone()),
+        is_generated: track.is_generated,
+        translated: request.translate_to.is_some(),
+    })
+}
+
+/// Pick the track for the first requested language that has one. When
+/// translating, any translatable track will do as a last resort.
+fn select_track<'a>(
+    tracks: &'a [TranscriptTrack],
+    request: &TranscriptRequest,
+) -> Option<&'a TranscriptTrack> {
+    let usable = |t: &&TranscriptTrack| request.translate_to.is_none() || t.translatable;
+    for language in &request.languages {
+        let mut matching: Vec<&TranscriptTrack> = tracks
+            .iter()
+            .filter(|t| t.language_code.eq_ignore_ascii_case(language))
+            .filter(usable)
+            .collect();
+        // Creator captions first unless auto-generated ones were asked for
+        matching.sort_by_key(|t| t.is_generated != request.prefer_auto);
+        if let Some(track) = matching.first() {
+            return Some(track);
+        }
+    }
+    if request.translate_to.is_some() {
+        return tracks
+            .iter()
+            .filter(usable)
+            .min_by_key(|t| t.is_generated != request.prefer_auto);
+    }
+    None
+}
+
+/// Parse YouTube timedtext XML: either `<text start dur>` (seconds) or the
+/// `format=3` `<p t d>` form (milliseconds, text possibly split into `<s>`).
+pub(super) fn parse_timedtext(xml: &str) -> Result<Vec<TranscriptSnippet>, ConnectorError> {
+    let mut reader = XmlReader::from_str(xml);
+    let mut buf = Vec::new();
+    let mut out = Vec::new();
+    let mut current: Option<TranscriptSnippet> = None;
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(XmlEvent::Start(e)) => {
+                let (start_key, dur_key, scale) = match e.name().as_ref() {
+                    b"text" => (b"start".as_slice(), b"dur".as_slice(), 1.0),
+                    b"p" => (b"t".as_slice(), b"d".as_slice(), 1000.0),
+                    _ => {
+                        buf.clear();
+                        continue;
+                    }
+                };
+                let mut start = 0.0;
+                let mut duration = 0.0;
+                for attr in e.attributes().flatten() {
+                    let value = String::from_utf8_lossy(&attr.value).parse::<f64>().ok();
+                    if attr.key.as_ref() == start_key {
+                        start = value.unwrap_or(0.0) / scale;
+                    } else if attr.key.as_ref() == dur_key {
+                        duration = value.unwrap_or(0.0) / scale;
+                    }
+                }
+                current = Some(TranscriptSnippet {
+                    text: String::new(),
+                    start,
+                    duration,
+                });
+            }
+            Ok(XmlEvent::Text(e)) => {
+                if let Some(snippet) = current.as_mut() {
+                    let text = e
+                        .unescape()
+                        .map(|t| t.into_owned())
+                        .unwrap_or_else(|_| String::from_utf8_lossy(&e).into_owned());
+                    snippet.text.push_str(&text);
+                }
+            }
+            Ok(XmlEvent::End(e)) => {
+                if matches!(e.name().as_ref(), b"text" | b"p") {
+                    if let Some(mut snippet) = current.take() {
+                        // Captions are often double-escaped (&amp;#39;)
+                        snippet.text = clean_html_entities(&snippet.text.replace('\n', " "))
+                            .trim()
+                            .to_string();
+                        if !snippet.text.is_empty() {
+                            out.push(snippet);
+                        }
+                    }
+                }
+            }
+            Ok(XmlEvent::Eof) => break,
+            Err(e) => {
+                return Err(ConnectorError::Other(format!(
+                    "Failed to parse YouTube caption XML: {}",
+                    e
+                )))
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(out)
+}
+
+/// A transcript passage matching a search, with a link that starts playback there.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, PartialEq)]
+pub struct TranscriptMatch {
+    /// Offset into the video in whole seconds
+    pub start_seconds: u64,
+    /// Human-readable offset (e.g., "1:02:03")
+    pub timestamp: String,
+    pub url: String,
+    /// The matching caption line plus surrounding context
+    pub text: String,
+}
+
+/// Find caption lines containing every query term (quoted phrases stay whole,
+/// case-insensitive). A line also matches when a term straddles it and the next
+/// line, since captions break mid-sentence. Each hit carries `context` lines on
+/// either side; overlapping hits are merged into one passage.
+pub(super) fn search_snippets(
+    snippets: &[TranscriptSnippet],
+    video_id: &str,
+    query: &str,
+    context: usize,
+) -> Vec<TranscriptMatch> {
+    let terms = query_terms(query);
+    if terms.is_empty() {
+        return Vec::new();
+    }
+    let lowered: Vec<String> = snippets.iter().map(|s| s.text.to_lowercase()).collect();
+
+    // (first hit, context start, context end) per passage
+    let mut passages: Vec<(usize, usize, usize)> = Vec::new();
+    for i in 0..snippets.len() {
+        let window = match lowered.get(i + 1) {
+            Some(next) => format!("{} {}", lowered[i], next),
+            None => lowered[i].clone(),
+        };
+        // The hit must touch this line, not only the next one
+        let hit = terms.iter().all(|t| window.contains(t.as_str()))
+            && !terms
+                .iter()
+                .all(|t| lowered.get(i + 1).is_some_and(|n| n.contains(t.as_str())));
+        if !hit {
+            continue;
+        }
+
+        let from = i.saturating_sub(context);
+        let to = (i + 1 + context).min(snippets.len());
+        match passages.last_mut() {
+            Some(last) if from <= last.2 => last.2 = to,
+            _ => passages.push((i, from, to)),
+        }
+    }
+
+    passages
+        .into_iter()
+        .map(|(hit, from, to)| {
+            let start_seconds = snippets[hit].start.max(0.0) as u64;
+            TranscriptMatch {
+                start_seconds,
+                timestamp: format_timestamp(start_seconds),
+                url: format!(
+                    "https://www.youtube.com/watch?v={}&t={}s",
+                    video_id, start_seconds
+                ),
+                text: snippets[from..to]
+                    .iter()
+                    .map(|s| s.text.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            }
+        })
+        .collect()
+}
+
+fn query_terms(query: &str) -> Vec<String> {
+    let mut terms = Vec::new();
+    for (i, part) in query.split('"').enumerate() {
+        if i % 2 == 1 {
+            let phrase = part.trim().to_lowercase();
+            if !phrase.is_empty() {
+                terms.push(phrase);
+            }
+        } else {
+            terms.extend(part.split_whitespace().map(str::to_lowercase));
+        }
+    }
+    terms
+}
+
+/// `h:mm:ss`, or `m:ss` under an hour.
+pub(super) fn format_timestamp(seconds: u64) -> String {
+    let (h, m, s) = (seconds / 3600, (seconds % 3600) / 60, seconds % 60);
+    if h > 0 {
+        format!("{}:{:02}:{:02}", h, m, s)
+    } else {
+        format!("{}:{:02}", m, s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track(code: &str, generated: bool, translatable: bool) -> TranscriptTrack {
+        TranscriptTrack {
+            language_code: code.to_string(),
+            language: code.to_string(),
+            is_generated: generated,
+            translatable,
+            url: format!("https://example.test/{}", code),
+        }
+    }
+
+    #[test]
+    fn selects_tracks_by_preference() {
+        let tracks = vec![
+            track("en", true, true),
+            track("en", false, true),
+            track("fr", false, false),
+        ];
+        let request = TranscriptRequest {
+            languages: vec!["de".to_string(), "en".to_string()],
+            ..Default::default()
+        };
+        let chosen = select_track(&tracks, &request).unwrap();
+        assert_eq!(
+            (chosen.language_code.as_str(), chosen.is_generated),
+            ("en", false)
+        );
+
+        let request = TranscriptRequest {
+            prefer_auto: true,
+            ..request
+        };
+        assert!(select_track(&tracks, &request).unwrap().is_generated);
+
+        let request = TranscriptRequest {
+            languages: vec!["fr".to_string()],
+            translate_to: Some("de".to_string()),
+            ..Default::default()
+        };
+        // fr cannot be translated, so fall back to a translatable track
+        assert_eq!(select_track(&tracks, &request).unwrap().language_code, "en");
+
+        let request = TranscriptRequest {
+            languages: vec!["ja".to_string()],
+            ..Default::default()
+        };
+        assert!(select_track(&tracks, &request).is_none());
+    }
+
+    #[test]
+    fn parses_both_timedtext_formats() {
+        let classic = r#"<?xml version="1.0"?><transcript><text start="1.5" dur="2">It&amp;#39;s here</text><text start="3.5" dur="1"></text></transcript>"#;
+        let snippets = parse_timedtext(classic).unwrap();
+        assert_eq!(
+            snippets,
+            vec![TranscriptSnippet {
+                text: "It's here".to_string(),
+                start: 1.5,
+                duration: 2.0
+            }]
+        );
+
+        let format3 = r#"<timedtext format="3"><body><p t="61000" d="1500"><s>hello</s><s> world</s></p></body></timedtext>"#;
+        let snippets = parse_timedtext(format3).unwrap();
+        assert_eq!(snippets[0].text, "hello world");
+        assert_eq!(snippets[0].start, 61.0);
+    }
+
+    fn snippet(text: &str, start: f64) -> TranscriptSnippet {
+        TranscriptSnippet {
+            text: text.to_string(),
+            start,
+            duration: 2.0,
+        }
+    }
+
+    #[test]
+    fn searches_transcript_with_timestamps() {
+        let snippets = vec![
+            snippet("welcome back to the show", 0.0),
+            snippet("today we talk about sleep", 3725.4),
+            snippet("and how caffeine affects", 3728.0),
+            snippet("your sleep quality", 3731.0),
+            snippet("later on, exercise", 4000.0),
+        ];
+
+        let hits = search_snippets(&snippets, "abc", "sleep", 1);
+        assert_eq!(hits.len(), 1, "adjacent hits merge: {:?}", hits);
+        assert_eq!(hits[0].start_seconds, 3725);
+        assert_eq!(hits[0].timestamp, "1:02:05");
+        assert_eq!(hits[0].url, "https://www.youtube.com/watch?v=abc&t=3725s");
+        assert!(hits[0].text.starts_with("welcome back"));
+
+        // Phrase split across two caption lines
+        let hits = search_snippets(&snippets, "abc", "\"caffeine affects your\"", 0);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].start_seconds, 3728);
+
+        assert!(search_snippets(&snippets, "abc", "sleep diet", 1).is_empty());
+        assert_eq!(format_timestamp(65), "1:05");
+    }
+}
@@ -0,0 +1,839 @@
+use crate::auth_store::{AuthStore, FileAuthStore};
+use crate::capabilities::{ConnectorConfigSchema, Field, FieldType};
+use crate::error::ConnectorError;
+use crate::oauth;
+use crate::utils::{parse_args, structured_result_with_text, tool};
+use crate::{auth::AuthDetails, Connector};
+use async_trait::async_trait;
+use chrono::{Duration as ChronoDuration, NaiveDate, Utc};
+use reqwest::{Client, StatusCode};
+use rmcp::model::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+const API_BASE: &str = "https://api.zoom.us/v2";
+/// Zoom only accepts `from`/`to` ranges of up to one month per request.
+const WINDOW_DAYS: i64 = 30;
+const DEFAULT_LOOKBACK_DAYS: i64 = 30;
+const DEFAULT_SEARCH_LOOKBACK_DAYS: i64 = 180;
+const MAX_RANGE_DAYS: i64 = 730;
+const DEFAULT_LIMIT: usize = 30;
+const MAX_LIMIT: usize = 300;
+
+#[derive(Debug, Deserialize)]
+struct ListRecordingsArgs {
+    #[serde(default)]
+    user_id: Option<String>,
+    #[serde(default)]
+    from: Option<String>,
+    #[serde(default)]
+    to: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchRecordingsArgs {
+    query: String,
+    #[serde(default)]
+    user_id: Option<String>,
+    #[serde(default)]
+    from: Option<String>,
+    #[serde(default)]
+    to: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranscriptArgs {
+    meeting_id: String,
+    #[serde(default)]
+    format: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+enum AuthMode {
+    /// Server-to-Server OAuth app (account credentials grant).
+    Account,
+    /// User OAuth app with a refresh token.
+    OAuth,
+    /// A fixed access token.
+    Token(String),
+    None,
+}
+
+fn auth_mode(auth: &AuthDetails) -> AuthMode {
+    let get = |k: &str| auth.get(k).filter(|v| !v.trim().is_empty());
+    let has_client = get("client_id").is_some() && get("client_secret").is_some();
+    if has_client && get("account_id").is_some() {
+        AuthMode::Account
+    } else if has_client && get("refresh_token").is_some() {
+        AuthMode::OAuth
+    } else if let Some(token) = get("access_token").or_else(|| get("token")) {
+        AuthMode::Token(token.clone())
+    } else {
+        AuthMode::None
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Segment {
+    start: String,
+    end: String,
+    speaker: Option<String>,
+    text: String,
+}
+
+/// Parse a Zoom WebVTT transcript; cue text is `Speaker Name: words`.
+fn parse_vtt(body: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    for block in body.replace("\r\n", "\n").split("\n\n") {
+        let mut lines = block.lines().map(str::trim).filter(|l| !l.is_empty());
+        let Some(timing) = lines.by_ref().find(|l| l.contains("-->")) else {
+            continue;
+        };
+        let mut parts = timing.split("-->");
+        let start = parts.next().unwrap_or_default().trim().to_string();
+        let end = parts
+            .next()
+            .and_then(|p| p.split_whitespace().next())
+            .unwrap_or_default()
+            .to_string();
+        let text = lines.collect::<Vec<_>>().join(" ");
+        if text.is_empty() {
+            continue;
+        }
+        let (speaker, text) = match text.split_once(": ") {
+            Some((name, rest)) if !name.is_empty() && name.len() <= 80 => {
+                (Some(name.to_string()), rest.to_string())
+            }
+            _ => (None, text),
+        };
+        segments.push(Segment {
+            start,
+            end,
+            speaker,
+            text,
+        });
+    }
+    segments
+}
+
+/// Readable transcript with consecutive cues from one speaker merged.
+fn transcript_text(segments: &[Segment]) -> String {
+    let mut out: Vec<String> = Vec::new();
+    let mut last_speaker: Option<&str> = None;
+    for seg in segments {
+        let speaker = seg.speaker.as_deref();
+        match out.last_mut() {
+            Some(line) if speaker == last_speaker => {
+                line.push(' ');
+                line.push_str(&seg.text);
+            }
+            _ => out.push(match speaker {
+                Some(name) => format!("{}: {}", name, seg.text),
+                None => seg.text.clone(),
+            }),
+        }
+        last_speaker = speaker;
+    }
+    out.join("\n")
+}
+
+fn parse_date(value: Option<&str>, field: &str) -> Result<Option<NaiveDate>, ConnectorError> {
+    value
+        .filter(|v| !v.trim().is_empty())
+        .map(|v| {
+            NaiveDate::parse_from_str(v.trim(), "%Y-%m-%d").map_err(|_| {
+                ConnectorError::InvalidParams(format!("{} must be YYYY-MM-DD, got '{}'", field, v))
+            })
+        })
+        .transpose()
+}
+
+/// Resolve the requested range (defaulting to `lookback_days` before `to`)
+/// and split it into Zoom-sized windows, newest first.
+fn date_windows(
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    lookback_days: i64,
+) -> Result<Vec<(NaiveDate, NaiveDate)>, ConnectorError> {
+    let to = to.unwrap_or_else(|| Utc::now().date_naive());
+    let from = from.unwrap_or(to - ChronoDuration::days(lookback_days));
+    if from > to {
+        return Err(ConnectorError::InvalidParams(
+            "'from' must not be after 'to'".to_string(),
+        ));
+    }
+    if (to - from).num_days() > MAX_RANGE_DAYS {
+        return Err(ConnectorError::InvalidParams(format!(
+            "Date range is limited to {} days",
+            MAX_RANGE_DAYS
+        )));
+    }
+    let mut windows = Vec::new();
+    let mut end = to;
+    loop {
+        let start = (end - ChronoDuration::days(WINDOW_DAYS - 1)).max(from);
+        windows.push((start, end));
+        if start == from {
+            break;
+        }
+        end = start - ChronoDuration::days(1);
+    }
+    Ok(windows)
+}
+
+/// Meeting UUIDs that start with `/` or contain `//` must be double-encoded.
+fn meeting_path_id(id: &str) -> String {
+    let id = id.trim();
+    if id.starts_with('/') || id.contains("//") {
+        urlencoding::encode(&urlencoding::encode(id)).into_owned()
+    } else {
+        urlencoding::encode(id).into_owned()
+    }
+}
+
+fn summarize_meeting(meeting: &Value) -> Value {
+    let files: Vec<Value> = meeting["recording_files"]
+        .as_array()
+        .map(|files| {
+            files
+                .iter()
+                .map(|f| {
+                    json!({
+                        "id": f["id"],
+                        "file_type": f["file_type"],
+                        "recording_type": f["recording_type"],
+                        "file_size": f["file_size"],
+                        "recording_start": f["recording_start"],
+                        "recording_end": f["recording_end"],
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let has_transcript = files.iter().any(|f| f["file_type"] == "TRANSCRIPT");
+    json!({
+        "uuid": meeting["uuid"],
+        "meeting_id": meeting["id"],
+        "topic": meeting["topic"],
+        "start_time": meeting["start_time"],
+        "duration_minutes": meeting["duration"],
+        "host_email": meeting["host_email"],
+        "share_url": meeting["share_url"],
+        "has_transcript": has_transcript,
+        "files": files,
+    })
+}
+
+struct CachedToken {
+    token: String,
+    expires_at: Instant,
+}
+
+pub struct ZoomConnector {
+    client: Client,
+    auth: AuthDetails,
+    token: Arc<Mutex<Option<CachedToken>>>,
+}
+
+impl ZoomConnector {
+    pub async fn new(auth: AuthDetails) -> Result<Self, ConnectorError> {
+        let client = Client::builder()
+            .user_agent("rzn_datasourcer/0.1.0")
+            .build()
+            .map_err(ConnectorError::HttpRequest)?;
+
+        let mut auth = auth;
+        for (key, var) in [
+            ("account_id", "ZOOM_ACCOUNT_ID"),
+            ("client_id", "ZOOM_CLIENT_ID"),
+            ("client_secret", "ZOOM_CLIENT_SECRET"),
+            ("refresh_token", "ZOOM_REFRESH_TOKEN"),
+            ("access_token", "ZOOM_ACCESS_TOKEN"),
+        ] {
+            if !auth.contains_key(key) {
+                if let Ok(value) = std::env::var(var) {
+                    auth.insert(key.to_string(), value);
+                }
+            }
+        }
+
+        Ok(Self {
+            client,
+            auth,
+            token: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Explicit config merged with tokens persisted by a previous refresh;
+    /// stored values win so a rotated refresh token is picked up.
+    fn merged_auth(&self) -> AuthDetails {
+        let mut auth = FileAuthStore::new_default()
+            .load(self.name())
+            .unwrap_or_default();
+        for (k, v) in self.auth.iter() {
+            auth.entry(k.clone()).or_insert(v.clone());
+        }
+        auth
+    }
+
+    async fn access_token(&self, force_refresh: bool) -> Result<String, ConnectorError> {
+        let mut cached = self.token.lock().await;
+        if !force_refresh {
+            if let Some(t) = cached.as_ref().filter(|t| Instant::now() < t.expires_at) {
+                return Ok(t.token.clone());
+            }
+        }
+
+        let mut auth = self.merged_auth();
+        let get = |k: &str| auth.get(k).cloned().unwrap_or_default();
+        let tokens = match auth_mode(&auth) {
+            AuthMode::Account => {
+                oauth::zoom_account_token(
+                    &get("account_id"),
+                    &get("client_id"),
+                    &get("client_secret"),
+                )
+                .await?
+            }
+            AuthMode::OAuth => {
+                let tokens = oauth::zoom_refresh_token(
+                    &get("client_id"),
+                    &get("client_secret"),
+                    &get("refresh_token"),
+                )
+                .await?;
+                if let Some(refresh) = tokens.refresh_token.clone() {
+                    auth.insert("refresh_token".to_string(), refresh);
+                    // Zoom invalidates the previous refresh token once a new one is issued.
+                    if let Err(e) = FileAuthStore::new_default().save(self.name(), &auth) {
+                        tracing::warn!("failed to persist rotated Zoom refresh token: {}", e);
+                    }
+                }
+                tokens
+            }
+            AuthMode::Token(token) => {
+                if force_refresh {
+                    return Err(ConnectorError::Authentication(
+                        "Zoom access token expired or was revoked".to_string(),
+                    ));
+                }
+                oauth::OAuthTokens {
+                    access_token: token,
+                    refresh_token: None,
+                    expires_in: Some(3600),
+                    scope: None,
+                    token_type: None,
+                }
+            }
+            AuthMode::None => {
+                return Err(ConnectorError::Authentication(
+                    "Zoom auth not configured: set account_id + client_id + client_secret \
+(Server-to-Server OAuth), client_id + client_secret + refresh_token, or access_token \
+(arivu setup zoom)"
+                        .to_string(),
+                ))
+            }
+        };
+
+        let expires_in = tokens.expires_in.unwrap_or(3600).max(0) as u64;
+        *cached = Some(CachedToken {
+            token: tokens.access_token.clone(),
+            expires_at: Instant::now() + Duration::from_secs(expires_in.saturating_sub(60)),
+        });
+        Ok(tokens.access_token)
+    }
+
+    /// GET an absolute URL with the Zoom token, retrying once after a forced
+    /// refresh on 401.
+    async fn get(
+        &self,
+        url: &str,
+        params: &[(&str, String)],
+    ) -> Result<reqwest::Response, ConnectorError> {
+        let mut token = self.access_token(false).await?;
+        for attempt in 0..2 {
+            let response = self
+                .client
+                .get(url)
+                .query(params)
+                .bearer_auth(&token)
+                .send()
+                .await
+                .map_err(ConnectorError::HttpRequest)?;
+
+            let status = response.status();
+            if status == StatusCode::UNAUTHORIZED && attempt == 0 {
+                token = self.access_token(true).await?;
+                continue;
+            }
+            if status.is_success() {
+                return Ok(response);
+            }
+
+            let body: Value = response.json().await.unwrap_or(Value::Null);
+            let message = body["message"].as_str().unwrap_or_default().to_string();
+            return Err(match status {
+                StatusCode::NOT_FOUND => ConnectorError::ResourceNotFound,
+                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                    ConnectorError::Authentication(format!("Zoom: {}", message))
+                }
+                StatusCode::BAD_REQUEST => {
+                    ConnectorError::InvalidParams(format!("Zoom: {}", message))
+                }
+                s => ConnectorError::Other(format!(
+                    "Zoom API returned error status: {} {}",
+                    s, message
+                )),
+            });
+        }
+        Err(ConnectorError::Authentication(
+            "Zoom rejected the refreshed token".to_string(),
+        ))
+    }
+
+    async fn get_json(
+        &self,
+        path: &str,
+        params: &[(&str, String)],
+    ) -> Result<Value, ConnectorError> {
+        self.get(&format!("{}{}", API_BASE, path), params)
+            .await?
+            .json()
+            .await
+            .map_err(ConnectorError::HttpRequest)
+    }
+
+    /// Collect meetings with recordings across date windows, newest first,
+    /// keeping those accepted by `keep` until `limit` is reached.
+    async fn collect_recordings<F>(
+        &self,
+        user_id: &str,
+        windows: &[(NaiveDate, NaiveDate)],
+        limit: usize,
+        keep: F,
+    ) -> Result<(Vec<Value>, bool), ConnectorError>
+    where
+        F: Fn(&Value) -> bool,
+    {
+        let path = format!("/users/{}/recordings", urlencoding::encode(user_id));
+        let mut meetings = Vec::new();
+        for (from, to) in windows {
+            let mut page_token: Option<String> = None;
+            loop {
+                let mut params = vec![
+                    ("from", from.to_string()),
+                    ("to", to.to_string()),
+                    ("page_size", "300".to_string()),
+                ];
+                if let Some(token) = &page_token {
+                    params.push(("next_page_token", token.clone()));
+                }
+                let page = self.get_json(&path, &params).await?;
+                for meeting in page["meetings"].as_array().into_iter().flatten() {
+                    if keep(meeting) {
+                        if meetings.len() == limit {
+                            return Ok((meetings, true));
+                        }
+                        meetings.push(summarize_meeting(meeting));
+                    }
+                }
+                page_token = page["next_page_token"]
+                    .as_str()
+                    .filter(|t| !t.is_empty())
+                    .map(str::to_string);
+                if page_token.is_none() {
+                    break;
+                }
+            }
+        }
+        Ok((meetings, false))
+    }
+
+    async fn list_recordings(&self, args: ListRecordingsArgs) -> Result<Value, ConnectorError> {
+        let windows = date_windows(
+            parse_date(args.from.as_deref(), "from")?,
+            parse_date(args.to.as_deref(), "to")?,
+            DEFAULT_LOOKBACK_DAYS,
+        )?;
+        let limit = args.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        let user_id = args.user_id.unwrap_or_else(|| "me".to_string());
+        let (meetings, truncated) = self
+            .collect_recordings(&user_id, &windows, limit, |_| true)
+            .await?;
+        Ok(json!({
+            "user_id": user_id,
+            "from": windows.last().map(|w| w.0.to_string()),
+            "to": windows.first().map(|w| w.1.to_string()),
+            "count": meetings.len(),
+            "truncated": truncated,
+            "meetings": meetings,
+        }))
+    }
+
+    async fn search_recordings(&self, args: SearchRecordingsArgs) -> Result<Value, ConnectorError> {
+        let terms: Vec<String> = args
+            .query
+            .split_whitespace()
+            .map(|t| t.to_lowercase())
+            .collect();
+        if terms.is_empty() {
+            return Err(ConnectorError::InvalidParams(
+                "query must not be empty".to_string(),
+            ));
+        }
+        let windows = date_windows(
+            parse_date(args.from.as_deref(), "from")?,
+            parse_date(args.to.as_deref(), "to")?,
+            DEFAULT_SEARCH_LOOKBACK_DAYS,
+        )?;
+        let limit = args.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        let user_id = args.user_id.unwrap_or_else(|| "me".to_string());
+        // The recordings API has no text search, so match topics client-side.
+        let (meetings, truncated) = self
+            .collect_recordings(&user_id, &windows, limit, |m| {
+                let topic = m["topic"].as_str().unwrap_or_default().to_lowercase();
+                terms.iter().all(|t| topic.contains(t.as_str()))
+            })
+            .await?;
+        Ok(json!({
+            "query": args.query,
+            "from": windows.last().map(|w| w.0.to_string()),
+            "to": windows.first().map(|w| w.1.to_string()),
+            "count": meetings.len(),
+            "truncated": truncated,
+            "meetings": meetings,
+        }))
+    }
+
+    async fn get_transcript(&self, args: TranscriptArgs) -> Result<Value, ConnectorError> {
+        let format = args.format.as_deref().unwrap_or("text");
+        if !matches!(format, "text" | "segments" | "vtt") {
+            return Err(ConnectorError::InvalidParams(format!(
+                "Unknown format '{}'; use text, segments or vtt",
+                format
+            )));
+        }
+        let meeting = self
+            .get_json(
+                &format!("/meetings/{}/recordings", meeting_path_id(&args.meeting_id)),
+                &[],
+            )
+            .await?;
+        let file = meeting["recording_files"]
+            .as_array()
+            .and_then(|files| files.iter().find(|f| f["file_type"] == "TRANSCRIPT"))
+            .ok_or_else(|| {
+                ConnectorError::Other(
+                    "This recording has no transcript; enable audio transcripts in Zoom \
+cloud recording settings"
+                        .to_string(),
+                )
+            })?;
+        let download_url = file["download_url"]
+            .as_str()
+            .ok_or_else(|| ConnectorError::Other("Transcript has no download URL".to_string()))?;
+        let vtt = self
+            .get(download_url, &[])
+            .await?
+            .text()
+            .await
+            .map_err(ConnectorError::HttpRequest)?;
+        let segments = parse_vtt(&vtt);
+        let mut speakers: Vec<&str> = Vec::new();
+        for name in segments.iter().filter_map(|s| s.speaker.as_deref()) {
+            if !speakers.contains(&name) {
+                speakers.push(name);
+            }
+        }
+
+        let mut data = json!({
+            "uuid": meeting["uuid"],
+            "meeting_id": meeting["id"],
+            "topic": meeting["topic"],
+            "start_time": meeting["start_time"],
+            "duration_minutes": meeting["duration"],
+            "speakers": speakers,
+            "segment_count": segments.len(),
+            "format": format,
+        });
+        data["content"] = match format {
+            "segments" => json!(segments
+                .iter()
+                .map(|s| json!({
+                    "start": s.start,
+                    "end": s.end,
+                    "speaker": s.speaker,
+                    "text": s.text,
+                }))
+                .collect::<Vec<_>>()),
+            "vtt" => json!(vtt),
+            _ => json!(transcript_text(&segments)),
+        };
+        Ok(data)
+    }
+}
+
+#[async_trait]
+impl Connector for ZoomConnector {
+    fn name(&self) -> &'static str {
+        "zoom"
+    }
+
+    fn description(&self) -> &'static str {
+        "Zoom cloud recordings: list and search meetings by topic/date and fetch meeting transcripts."
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        Ok(self.auth.clone())
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        *self = Self::new(details).await?;
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.get_json("/users/me", &[]).await?;
+        Ok(())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        let field = |name: &str, label: &str, secret: bool, description: &str| Field {
+            name: name.to_string(),
+            label: label.to_string(),
+            field_type: if secret {
+                FieldType::Secret
+            } else {
+                FieldType::Text
+            },
+            required: false,
+            description: Some(description.to_string()),
+            options: None,
+        };
+        ConnectorConfigSchema {
+            fields: vec![
+                field(
+                    "account_id",
+                    "Account ID",
+                    false,
+                    "Server-to-Server OAuth app account id (with client_id + client_secret)",
+                ),
+                field("client_id", "Client ID", false, "OAuth app client id"),
+                field(
+                    "client_secret",
+                    "Client Secret",
+                    true,
+                    "OAuth app client secret",
+                ),
+                field(
+                    "refresh_token",
+                    "Refresh Token",
+                    true,
+                    "User OAuth refresh token (rotated tokens are saved automatically)",
+                ),
+                field(
+                    "access_token",
+                    "Access Token",
+                    true,
+                    "Short-lived access token for quick tests",
+                ),
+            ],
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Use `search_recordings` (topic words, optional from/to) or `list_recordings` to \
+find a meeting, then `get_transcript` with its uuid or meeting_id. Dates are YYYY-MM-DD. \
+Meeting content is private: only access it with explicit user permission."
+                    .to_string(),
+            ),
+        })
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let tools = vec![
+            tool(
+                "list_recordings",
+                "List cloud recordings in a date range, newest first (requires explicit user permission).",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "user_id": { "type": "string", "description": "Zoom user id or email (default: me)" },
+                        "from": { "type": "string", "description": "Start date YYYY-MM-DD (default: 30 days ago)" },
+                        "to": { "type": "string", "description": "End date YYYY-MM-DD (default: today)" },
+                        "limit": { "type": "integer", "description": "Max meetings (default: 30, max: 300)" }
+                    }
+                }),
+            ),
+            tool(
+                "search_recordings",
+                "Find recordings whose topic contains all query words (requires explicit user permission). Example: query=\"weekly sync\" from=\"2024-01-01\".",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "query": { "type": "string", "description": "Words to match in the meeting topic" },
+                        "user_id": { "type": "string", "description": "Zoom user id or email (default: me)" },
+                        "from": { "type": "string", "description": "Start date YYYY-MM-DD (default: 180 days ago)" },
+                        "to": { "type": "string", "description": "End date YYYY-MM-DD (default: today)" },
+                        "limit": { "type": "integer", "description": "Max meetings (default: 30, max: 300)" }
+                    },
+                    "required": ["query"]
+                }),
+            ),
+            tool(
+                "get_transcript",
+                "Download a recorded meeting's transcript with speakers (requires explicit user permission).",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "meeting_id": { "type": "string", "description": "Meeting uuid (specific occurrence) or numeric meeting id (latest occurrence)" },
+                        "format": {
+                            "type": "string",
+                            "enum": ["text", "segments", "vtt"],
+                            "description": "text (speaker turns), segments (timestamped cues) or raw vtt (default: text)"
+                        }
+                    },
+                    "required": ["meeting_id"]
+                }),
+            ),
+        ];
+
+        Ok(ListToolsResult {
+            tools,
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        let data = match request.name.as_ref() {
+            "list_recordings" => self.list_recordings(parse_args(request.arguments)?).await?,
+            "search_recordings" => {
+                self.search_recordings(parse_args(request.arguments)?)
+                    .await?
+            }
+            "get_transcript" => self.get_transcript(parse_args(request.arguments)?).await?,
+            _ => return Err(ConnectorError::ToolNotFound),
+        };
+        Ok(structured_result_with_text(&data, None)?)
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_zoom_vtt() {
+        let vtt = "WEBVTT\n\n1\n00:00:01.000 --> 00:00:04.000\nAda Lovelace: Morning all.\n\n2\n00:00:04.500 --> 00:00:06.000\nAda Lovelace: Quick agenda.\n\n3\n00:00:06.000 --> 00:00:08.000\nAlan Turing: Sounds good.\n";
+        let segments = parse_vtt(vtt);
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[0].start, "00:00:01.000");
+        assert_eq!(segments[2].speaker.as_deref(), Some("Alan Turing"));
+        assert_eq!(
+            transcript_text(&segments),
+            "Ada Lovelace: Morning all. Quick agenda.\nAlan Turing: Sounds good."
+        );
+    }
+
+    #[test]
+    fn splits_ranges_into_month_windows() {
+        let d = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        let windows = date_windows(Some(d("2024-01-01")), Some(d("2024-03-01")), 30).unwrap();
+        assert_eq!(windows.first(), Some(&(d("2024-01-31"), d("2024-03-01"))));
+        assert_eq!(windows.last().map(|w| w.0), Some(d("2024-01-01")));
+        assert_eq!(windows.len(), 2);
+        assert!(date_windows(Some(d("2024-02-01")), Some(d("2024-01-01")), 30).is_err());
+    }
+
+    #[test]
+    fn encodes_meeting_ids() {
+        assert_eq!(meeting_path_id("85746065432"), "85746065432");
+        assert_eq!(meeting_path_id("4444AAA/BBB=="), "4444AAA%2FBBB%3D%3D");
+        assert_eq!(
+            meeting_path_id("/ajXp112QmuoKj4854875=="),
+            "%252FajXp112QmuoKj4854875%253D%253D"
+        );
+    }
+
+    #[test]
+    fn picks_auth_mode() {
+        let mut auth = AuthDetails::new();
+        assert_eq!(auth_mode(&auth), AuthMode::None);
+        auth.insert("token".to_string(), "t".to_string());
+        assert_eq!(auth_mode(&auth), AuthMode::Token("t".to_string()));
+        auth.insert("client_id".to_string(), "id".to_string());
+        auth.insert("client_secret".to_string(), "secret".to_string());
+        auth.insert("refresh_token".to_string(), "rt".to_string());
+        assert_eq!(auth_mode(&auth), AuthMode::OAuth);
+        auth.insert("account_id".to_string(), "acct".to_string());
+        assert_eq!(auth_mode(&auth), AuthMode::Account);
+    }
+}
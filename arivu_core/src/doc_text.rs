@@ -0,0 +1,381 @@
+// src/doc_text.rs
+//
+// Text extraction from in-memory documents (PDF, DOCX, EPUB, HTML, plain
+// text) for connectors that download files: mail attachments, shared drives,
+// chat uploads, document URLs. Parsing is CPU-bound; call it through
+// `cpu_pool::spawn_cpu`.
+
+use std::io::{Cursor, Read};
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use crate::error::ConnectorError;
+use crate::pdf::{PdfOptions, PdfPage, PdfSection};
+use crate::utils::{html_to_text, truncate_chars};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentKind {
+    Pdf,
+    Docx,
+    Epub,
+    Html,
+    Text,
+}
+
+impl DocumentKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DocumentKind::Pdf => "pdf",
+            DocumentKind::Docx => "docx",
+            DocumentKind::Epub => "epub",
+            DocumentKind::Html => "html",
+            DocumentKind::Text => "text",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentText {
+    pub kind: DocumentKind,
+    /// EPUB `dc:title`
+    pub title: Option<String>,
+    pub text: String,
+    /// PDF pages or EPUB spine chapters, cut to the same budget as `text`;
+    /// empty for unpaged formats
+    pub pages: Vec<String>,
+    pub truncated: bool,
+}
+
+impl DocumentText {
+    /// A PDF's pages split into sections at headings; empty for other kinds.
+    pub fn pdf_sections(&self) -> Vec<PdfSection> {
+        if self.kind != DocumentKind::Pdf {
+            return Vec::new();
+        }
+        let pages: Vec<PdfPage> = self
+            .pages
+            .iter()
+            .enumerate()
+            .map(|(i, text)| PdfPage {
+                number: i as u32 + 1,
+                text: text.clone(),
+            })
+            .collect();
+        crate::pdf::split_sections(&pages)
+    }
+}
+
+/// Which extractor applies, from the MIME type or else the file extension.
+pub fn document_kind(filename: &str, mime_type: Option<&str>) -> Option<DocumentKind> {
+    let mime = mime_type.unwrap_or_default().to_ascii_lowercase();
+    match mime.as_str() {
+        "application/pdf" => return Some(DocumentKind::Pdf),
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document" => {
+            return Some(DocumentKind::Docx)
+        }
+        "application/epub+zip" => return Some(DocumentKind::Epub),
+        "text/html" | "application/xhtml+xml" => return Some(DocumentKind::Html),
+        "application/json" | "application/xml" => return Some(DocumentKind::Text),
+        m if m.starts_with("text/") => return Some(DocumentKind::Text),
+        _ => {}
+    }
+    let ext = filename.rsplit_once('.')?.1.to_ascii_lowercase();
+    match ext.as_str() {
+        "pdf" => Some(DocumentKind::Pdf),
+        "docx" => Some(DocumentKind::Docx),
+        "epub" => Some(DocumentKind::Epub),
+        "html" | "htm" | "xhtml" => Some(DocumentKind::Html),
+        "txt" | "md" | "markdown" | "csv" | "tsv" | "json" | "xml" | "yaml" | "yml" | "log"
+        | "ics" | "vcf" => Some(DocumentKind::Text),
+        _ => None,
+    }
+}
+
+/// Extract text from `bytes`, cut at `max_chars`. Errors for unsupported
+/// types and for documents with no text layer.
+pub fn extract_text(
+    bytes: &[u8],
+    filename: &str,
+    mime_type: Option<&str>,
+    max_chars: usize,
+) -> Result<DocumentText, ConnectorError> {
+    let kind = document_kind(filename, mime_type).ok_or_else(|| {
+        ConnectorError::InvalidParams(format!(
+            "No text extraction for '{}' ({})",
+            filename,
+            mime_type.unwrap_or("unknown type")
+        ))
+    })?;
+    let mut title = None;
+    let mut pages = Vec::new();
+    let mut text = match kind {
+        DocumentKind::Pdf => {
+            pages = pdf_pages(bytes)?;
+            pages.join("\n")
+        }
+        DocumentKind::Epub => {
+            let (epub_title, chapters) = epub_chapters(bytes)?;
+            title = epub_title;
+            pages = chapters;
+            pages.join("\n\n")
+        }
+        DocumentKind::Docx => docx_text(bytes)?,
+        DocumentKind::Html => html_to_text(&String::from_utf8_lossy(bytes)),
+        DocumentKind::Text => String::from_utf8_lossy(bytes).into_owned(),
+    };
+    let truncated = truncate_chars(&mut text, max_chars);
+    if truncated {
+        let mut budget = max_chars;
+        pages.retain_mut(|page| {
+            let keep = budget > 0;
+            truncate_chars(page, budget);
+            budget = budget.saturating_sub(page.chars().count());
+            keep
+        });
+    }
+    Ok(DocumentText {
+        kind,
+        title,
+        text,
+        pages,
+        truncated,
+    })
+}
+
+fn pdf_pages(bytes: &[u8]) -> Result<Vec<String>, ConnectorError> {
+    let pdf = crate::pdf::extract(bytes, &PdfOptions::default())?;
+    Ok(pdf.pages.into_iter().map(|page| page.text).collect())
+}
+
+fn zip_entry(
+    archive: &mut zip::ZipArchive<Cursor<&[u8]>>,
+    name: &str,
+) -> Result<String, ConnectorError> {
+    let mut out = String::new();
+    archive
+        .by_name(name)
+        .map_err(|e| ConnectorError::Other(format!("invalid EPUB: {}: {}", name, e)))?
+        .read_to_string(&mut out)
+        .map_err(|e| ConnectorError::Other(format!("invalid EPUB: {}: {}", name, e)))?;
+    Ok(out)
+}
+
+/// `dc:title` and the text of each spine document, in reading order.
+fn epub_chapters(bytes: &[u8]) -> Result<(Option<String>, Vec<String>), ConnectorError> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes))
+        .map_err(|e| ConnectorError::Other(format!("invalid EPUB: {}", e)))?;
+    let container = zip_entry(&mut archive, "META-INF/container.xml")?;
+    let opf_path = first_attr(&container, b"rootfile", b"full-path")
+        .ok_or_else(|| ConnectorError::Other("invalid EPUB: no rootfile".to_string()))?;
+    let opf = zip_entry(&mut archive, &opf_path)?;
+    let base = opf_path
+        .rsplit_once('/')
+        .map(|(dir, _)| format!("{}/", dir))
+        .unwrap_or_default();
+
+    // Manifest ids to hrefs, spine order and the title, in one pass
+    let mut reader = Reader::from_str(&opf);
+    let mut buf = Vec::new();
+    let mut manifest = std::collections::HashMap::new();
+    let mut spine = Vec::new();
+    let mut title = None;
+    let mut in_title = false;
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) if e.local_name().as_ref() == b"title" => in_title = true,
+            Ok(Event::End(e)) if e.local_name().as_ref() == b"title" => in_title = false,
+            Ok(Event::Text(e)) if in_title && title.is_none() => {
+                title = Some(e.unescape().unwrap_or_default().trim().to_string());
+            }
+            Ok(Event::Start(e)) | Ok(Event::Empty(e)) => {
+                let attr = |name: &[u8]| {
+                    e.attributes()
+                        .flatten()
+                        .find(|a| a.key.local_name().as_ref() == name)
+                        .and_then(|a| a.unescape_value().ok())
+                        .map(|v| v.into_owned())
+                };
+                match e.local_name().as_ref() {
+                    b"item" => {
+                        if let (Some(id), Some(href)) = (attr(b"id"), attr(b"href")) {
+                            manifest.insert(id, href);
+                        }
+                    }
+                    b"itemref" => spine.extend(attr(b"idref")),
+                    _ => {}
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(ConnectorError::Other(format!("invalid EPUB: {}", e))),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    let mut chapters = Vec::new();
+    for idref in spine {
+        let Some(href) = manifest.get(&idref) else {
+            continue;
+        };
+        let href = urlencoding::decode(href)
+            .map(|h| h.into_owned())
+            .unwrap_or_else(|_| href.clone());
+        if let Ok(xhtml) = zip_entry(&mut archive, &format!("{}{}", base, href)) {
+            let text = html_to_text(&xhtml);
+            if !text.trim().is_empty() {
+                chapters.push(text);
+            }
+        }
+    }
+    if chapters.is_empty() {
+        return Err(ConnectorError::Other(
+            "EPUB has no readable chapters".to_string(),
+        ));
+    }
+    Ok((title.filter(|t| !t.is_empty()), chapters))
+}
+
+/// Value of `attr` on the first `tag` element in `xml`.
+fn first_attr(xml: &str, tag: &[u8], attr: &[u8]) -> Option<String> {
+    let mut reader = Reader::from_str(xml);
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) | Ok(Event::Empty(e)) if e.local_name().as_ref() == tag => {
+                return e
+                    .attributes()
+                    .flatten()
+                    .find(|a| a.key.local_name().as_ref() == attr)
+                    .and_then(|a| a.unescape_value().ok())
+                    .map(|v| v.into_owned());
+            }
+            Ok(Event::Eof) | Err(_) => return None,
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+/// Paragraph text of `word/document.xml`, one paragraph per line.
+fn docx_text(bytes: &[u8]) -> Result<String, ConnectorError> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes))
+        .map_err(|e| ConnectorError::Other(format!("invalid DOCX: {}", e)))?;
+    let mut xml = String::new();
+    archive
+        .by_name("word/document.xml")
+        .map_err(|e| ConnectorError::Other(format!("invalid DOCX: {}", e)))?
+        .read_to_string(&mut xml)
+        .map_err(|e| ConnectorError::Other(format!("invalid DOCX: {}", e)))?;
+
+    let mut reader = Reader::from_str(&xml);
+    let mut buf = Vec::new();
+    let mut text = String::new();
+    let mut in_text = false;
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) if e.local_name().as_ref() == b"t" => in_text = true,
+            Ok(Event::End(e)) => match e.local_name().as_ref() {
+                b"t" => in_text = false,
+                b"p" => text.push('\n'),
+                _ => {}
+            },
+            Ok(Event::Empty(e)) => match e.local_name().as_ref() {
+                b"tab" => text.push('\t'),
+                b"br" | b"cr" => text.push('\n'),
+                _ => {}
+            },
+            Ok(Event::Text(e)) if in_text => {
+                text.push_str(&e.unescape().unwrap_or_default());
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(ConnectorError::Other(format!("invalid DOCX: {}", e))),
+            _ => {}
+        }
+        buf.clear();
+    }
+    Ok(text.trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn extracts_docx_html_and_text() {
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        zip.start_file(
+            "word/document.xml",
+            zip::write::SimpleFileOptions::default(),
+        )
+        .unwrap();
+        zip.write_all(
+            br#"<w:document xmlns:w="x"><w:body>
+<w:p><w:r><w:t>Quarterly</w:t></w:r><w:r><w:tab/><w:t xml:space="preserve">report &amp; notes</w:t></w:r></w:p>
+<w:p><w:r><w:t>Second line</w:t></w:r></w:p>
+</w:body></w:document>"#,
+        )
+        .unwrap();
+        let docx = zip.finish().unwrap().into_inner();
+
+        let out = extract_text(&docx, "Q3.docx", None, 1_000).unwrap();
+        assert_eq!(out.kind, DocumentKind::Docx);
+        assert_eq!(out.text, "Quarterly\treport & notes\nSecond line");
+
+        let html =
+            extract_text(b"<p>Hello <b>there</b></p>", "x.bin", Some("text/html"), 5).unwrap();
+        assert_eq!(html.text, "Hello");
+        assert!(html.truncated);
+
+        assert_eq!(
+            document_kind("notes.TXT", Some("application/octet-stream")),
+            Some(DocumentKind::Text)
+        );
+        assert!(extract_text(b"\x89PNG", "logo.png", Some("image/png"), 10).is_err());
+        assert!(extract_text(b"not a pdf", "a.pdf", None, 10).is_err());
+    }
+
+    #[test]
+    fn extracts_epub_chapters_in_spine_order() {
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let files: [(&str, &str); 4] = [
+            (
+                "META-INF/container.xml",
+                r#"<container><rootfiles><rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/></rootfiles></container>"#,
+            ),
+            (
+                "OEBPS/content.opf",
+                r#"<package xmlns:dc="http://purl.org/dc/elements/1.1/"><metadata><dc:title>Tides &amp; Moons</dc:title></metadata>
+<manifest><item id="c2" href="text/two.xhtml"/><item id="c1" href="text/chapter%20one.xhtml"/></manifest>
+<spine><itemref idref="c1"/><itemref idref="c2"/></spine></package>"#,
+            ),
+            (
+                "OEBPS/text/chapter one.xhtml",
+                "<html><body><h1>One</h1><p>The moon pulls.</p></body></html>",
+            ),
+            (
+                "OEBPS/text/two.xhtml",
+                "<html><body><h1>Two</h1><p>The sea answers.</p></body></html>",
+            ),
+        ];
+        for (name, body) in files {
+            zip.start_file(name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            zip.write_all(body.as_bytes()).unwrap();
+        }
+        let epub = zip.finish().unwrap().into_inner();
+
+        let out = extract_text(&epub, "book.epub", None, 1_000).unwrap();
+        assert_eq!(out.kind, DocumentKind::Epub);
+        assert_eq!(out.title.as_deref(), Some("Tides & Moons"));
+        assert_eq!(out.pages.len(), 2);
+        assert!(out.pages[0].contains("The moon pulls."));
+        assert!(out.pages[1].contains("The sea answers."));
+
+        let cut = extract_text(&epub, "book", Some("application/epub+zip"), 8).unwrap();
+        assert!(cut.truncated);
+        assert_eq!(cut.pages.len(), 1);
+        assert_eq!(cut.pages[0], cut.text);
+    }
+}
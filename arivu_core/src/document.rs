@@ -0,0 +1,510 @@
+//! A shared shape for fetched content.
+//!
+//! Connectors return whatever JSON suits their source: a web page has `content` and `metadata`,
+//! a YouTube video has `transcript` and `chapters`, a Hacker News story has `by` and `time`.
+//! [`Document`] is the one shape that export, indexing and summarization work with, and
+//! [`Document::from_tool_output`] maps a connector's result into it. Language detection and
+//! translation are filled in afterwards by [`crate::language`].
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::language::{DetectedLanguage, Translation};
+
+/// Wrapper keys some connectors nest the actual item under (`{"paper": {...}}`).
+const WRAPPER_KEYS: &[&str] = &[
+    "paper", "article", "work", "item", "story", "post", "video", "page", "issue", "book",
+];
+const TITLE_KEYS: &[&str] = &["title", "name", "headline"];
+const AUTHOR_KEYS: &[&str] = &[
+    "authors",
+    "author",
+    "creators",
+    "creator",
+    "by",
+    "channel_name",
+    "uploader",
+];
+const DATE_KEYS: &[&str] = &[
+    "published_at",
+    "publishedAt",
+    "published",
+    "published_date",
+    "publication_date",
+    "pub_date",
+    "date",
+    "created_at",
+    "created_utc",
+    "upload_date",
+    "uploaded_at",
+    "time",
+    "year",
+];
+const URL_KEYS: &[&str] = &["url", "html_url", "link", "permalink", "web_url", "abs_url"];
+const SECTION_KEYS: &[&str] = &["sections", "chapters"];
+/// Body text fields, most complete first.
+const TEXT_KEYS: &[&str] = &[
+    "content",
+    "text",
+    "transcript",
+    "markdown",
+    "body",
+    "selftext",
+    "extract",
+    "abstract",
+    "summary",
+    "description",
+];
+
+/// A titled part of a document: a page section, a video chapter, a paper's methods.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocumentSection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<String>,
+    pub text: String,
+}
+
+/// Fetched content in one shape regardless of the connector that produced it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Document {
+    /// Connector that produced the content (e.g. "web", "arxiv")
+    pub source: String,
+
+    pub title: String,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<DateTime<Utc>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sections: Vec<DocumentSection>,
+
+    /// The full plain text; the sections joined when the source only has sections
+    pub text: String,
+
+    /// Language of the text, set by [`crate::language::process_document`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<DetectedLanguage>,
+
+    /// Translated title and text, when a translation was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translation: Option<Translation>,
+
+    /// Source fields that have no place above (ids, scores, tags, ...)
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub metadata: Value,
+}
+
+impl Document {
+    /// Create a document with only the required fields.
+    pub fn new(source: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            title: title.into(),
+            authors: Vec::new(),
+            published_at: None,
+            url: None,
+            sections: Vec::new(),
+            text: String::new(),
+            language: None,
+            translation: None,
+            metadata: Value::Null,
+        }
+    }
+
+    /// Builder method to add authors.
+    pub fn with_authors(mut self, authors: Vec<String>) -> Self {
+        self.authors = authors;
+        self
+    }
+
+    /// Builder method to add the publication time.
+    pub fn with_published_at(mut self, published_at: DateTime<Utc>) -> Self {
+        self.published_at = Some(published_at);
+        self
+    }
+
+    /// Builder method to add a URL.
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Builder method to add a section.
+    pub fn with_section(mut self, heading: Option<String>, text: impl Into<String>) -> Self {
+        self.sections.push(DocumentSection {
+            heading,
+            text: text.into(),
+        });
+        self
+    }
+
+    /// Builder method to set the plain text.
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    /// Builder method to add metadata.
+    pub fn with_metadata(mut self, metadata: Value) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// A stable identifier: the URL, else `source:id` from the metadata, else `source:title`.
+    /// `None` when the document has none of them.
+    pub fn key(&self) -> Option<String> {
+        if let Some(url) = &self.url {
+            return Some(url.clone());
+        }
+        let id = self.metadata.get("id").and_then(|id| match id {
+            Value::String(s) if !s.is_empty() => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        });
+        match id {
+            Some(id) => Some(format!("{}:{}", self.source, id)),
+            None if !self.title.trim().is_empty() => {
+                Some(format!("{}:{}", self.source, self.title.trim()))
+            }
+            None => None,
+        }
+    }
+
+    /// Map a connector's tool result into a document.
+    ///
+    /// Common field names are probed in order (`title`/`name`, `authors`/`by`, `published`/`date`,
+    /// `content`/`transcript`/`abstract`, ...), looking inside a single wrapper object such as
+    /// `{"paper": {...}}` when the top level has no title. Fields that are not mapped are kept in
+    /// `metadata`.
+    pub fn from_tool_output(source: &str, output: &Value) -> Self {
+        let Some(top) = output.as_object() else {
+            let text = match output {
+                Value::String(s) => s.clone(),
+                Value::Null => String::new(),
+                other => other.to_string(),
+            };
+            return Self::new(source, "").with_text(text);
+        };
+
+        let item = if first_string(top, TITLE_KEYS).is_some() {
+            top
+        } else {
+            WRAPPER_KEYS
+                .iter()
+                .find_map(|k| top.get(*k).and_then(Value::as_object))
+                .unwrap_or(top)
+        };
+
+        let mut used: Vec<&str> = Vec::new();
+        let mut take = |keys: &[&'static str]| -> Option<(&'static str, &Value)> {
+            keys.iter().find_map(|k| {
+                let value = item.get(*k)?;
+                if is_empty(value) {
+                    return None;
+                }
+                used.push(*k);
+                Some((*k, value))
+            })
+        };
+
+        // The web connector keeps the byline and date in a nested `metadata` object
+        let nested = item.get("metadata").and_then(Value::as_object);
+
+        let title = take(TITLE_KEYS)
+            .and_then(|(_, v)| v.as_str().or_else(|| v.get(0).and_then(Value::as_str)))
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        let authors = take(AUTHOR_KEYS)
+            .map(|(k, v)| parse_authors(k, v))
+            .or_else(|| {
+                let author = nested?.get("author")?;
+                Some(parse_authors("author", author))
+            })
+            .unwrap_or_default();
+        let published_at = take(DATE_KEYS)
+            .and_then(|(_, v)| parse_date(v))
+            .or_else(|| {
+                nested
+                    .and_then(|m| m.get("published_date"))
+                    .and_then(parse_date)
+            });
+        let url = take(URL_KEYS)
+            .and_then(|(_, v)| v.as_str())
+            .map(str::to_string);
+        let sections = take(SECTION_KEYS)
+            .and_then(|(_, v)| v.as_array())
+            .map(|items| items.iter().filter_map(parse_section).collect::<Vec<_>>())
+            .unwrap_or_default();
+        let mut text = take(TEXT_KEYS)
+            .and_then(|(_, v)| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        if text.trim().is_empty() && !sections.is_empty() {
+            text = sections_text(&sections);
+        }
+
+        let mut metadata: Map<String, Value> = item
+            .iter()
+            .filter(|(k, _)| !used.contains(&k.as_str()))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        if !std::ptr::eq(item, top) {
+            // Keep siblings of the wrapper (e.g. comments next to a story)
+            for (k, v) in top {
+                if !WRAPPER_KEYS.contains(&k.as_str()) {
+                    metadata.entry(k.clone()).or_insert_with(|| v.clone());
+                }
+            }
+        }
+
+        Self {
+            source: source.to_string(),
+            title,
+            authors,
+            published_at,
+            url,
+            sections,
+            text,
+            language: None,
+            translation: None,
+            metadata: if metadata.is_empty() {
+                Value::Null
+            } else {
+                Value::Object(metadata)
+            },
+        }
+    }
+
+    /// The document as Markdown: title, a byline, then the sections or the plain text.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        if !self.title.is_empty() {
+            out.push_str(&format!("# {}\n\n", self.title));
+        }
+        let mut byline = Vec::new();
+        if !self.authors.is_empty() {
+            byline.push(self.authors.join(", "));
+        }
+        if let Some(published_at) = self.published_at {
+            byline.push(published_at.format("%Y-%m-%d").to_string());
+        }
+        if let Some(url) = &self.url {
+            byline.push(format!("<{}>", url));
+        }
+        if !byline.is_empty() {
+            out.push_str(&format!("{}\n\n", byline.join(" · ")));
+        }
+        if self.sections.is_empty() {
+            out.push_str(self.text.trim());
+            out.push('\n');
+        } else {
+            for section in &self.sections {
+                if let Some(heading) = &section.heading {
+                    out.push_str(&format!("## {}\n\n", heading));
+                }
+                out.push_str(section.text.trim());
+                out.push_str("\n\n");
+            }
+        }
+        out.trim_end().to_string() + "\n"
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.trim().is_empty(),
+        Value::Array(a) => a.is_empty(),
+        _ => false,
+    }
+}
+
+fn first_string<'a>(item: &'a Map<String, Value>, keys: &[&str]) -> Option<&'a str> {
+    keys.iter()
+        .find_map(|k| item.get(*k).and_then(Value::as_str))
+        .filter(|s| !s.trim().is_empty())
+}
+
+/// Author names from a string, a list of strings, or a list of person objects.
+fn parse_authors(key: &str, value: &Value) -> Vec<String> {
+    let names: Vec<String> = match value {
+        // Only a plural field holds several names in one string
+        Value::String(s) if key == "authors" || key == "creators" => {
+            s.split([',', ';']).map(|n| n.trim().to_string()).collect()
+        }
+        Value::String(s) => vec![s.trim().to_string()],
+        Value::Array(items) => items.iter().filter_map(person_name).collect(),
+        other => person_name(other).into_iter().collect(),
+    };
+    names.into_iter().filter(|n| !n.is_empty()).collect()
+}
+
+fn person_name(value: &Value) -> Option<String> {
+    if let Some(s) = value.as_str() {
+        return Some(s.trim().to_string());
+    }
+    for key in ["name", "display_name", "displayName", "full_name", "login"] {
+        if let Some(name) = value.get(key).and_then(Value::as_str) {
+            return Some(name.trim().to_string());
+        }
+    }
+    // Crossref style
+    let family = value.get("family").and_then(Value::as_str)?;
+    Some(match value.get("given").and_then(Value::as_str) {
+        Some(given) => format!("{} {}", given, family),
+        None => family.to_string(),
+    })
+}
+
+/// Dates as RFC 3339, RFC 2822, `YYYY-MM-DD[ hh:mm:ss]`, `YYYYMMDD`, Unix seconds or a bare year.
+fn parse_date(value: &Value) -> Option<DateTime<Utc>> {
+    if let Some(n) = value.as_i64() {
+        return if (1000..=9999).contains(&n) {
+            NaiveDate::from_ymd_opt(n as i32, 1, 1)?
+                .and_hms_opt(0, 0, 0)
+                .map(|t| t.and_utc())
+        } else {
+            DateTime::from_timestamp(n, 0)
+        };
+    }
+    if let Some(f) = value.as_f64() {
+        return DateTime::from_timestamp(f as i64, 0);
+    }
+    let s = value.as_str()?.trim();
+    if let Ok(t) = DateTime::parse_from_rfc3339(s) {
+        return Some(t.with_timezone(&Utc));
+    }
+    if let Ok(t) = DateTime::parse_from_rfc2822(s) {
+        return Some(t.with_timezone(&Utc));
+    }
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S"] {
+        if let Ok(t) = NaiveDateTime::parse_from_str(s, format) {
+            return Some(t.and_utc());
+        }
+    }
+    for format in ["%Y-%m-%d", "%Y%m%d", "%Y/%m/%d"] {
+        if let Ok(d) = NaiveDate::parse_from_str(s, format) {
+            return d.and_hms_opt(0, 0, 0).map(|t| t.and_utc());
+        }
+    }
+    match s.parse::<i64>() {
+        Ok(n) => parse_date(&Value::from(n)),
+        Err(_) => None,
+    }
+}
+
+fn parse_section(value: &Value) -> Option<DocumentSection> {
+    if let Some(text) = value.as_str() {
+        return Some(DocumentSection {
+            heading: None,
+            text: text.to_string(),
+        });
+    }
+    let heading = first_string(value.as_object()?, &["heading", "title", "name"]);
+    let text = ["content", "text", "body"]
+        .iter()
+        .find_map(|k| value.get(*k).and_then(Value::as_str))
+        .unwrap_or_default();
+    if heading.is_none() && text.trim().is_empty() {
+        return None;
+    }
+    Some(DocumentSection {
+        heading: heading.map(str::to_string),
+        text: text.to_string(),
+    })
+}
+
+fn sections_text(sections: &[DocumentSection]) -> String {
+    sections
+        .iter()
+        .map(|s| match &s.heading {
+            Some(heading) => format!("{}\n\n{}", heading, s.text.trim()),
+            None => s.text.trim().to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn maps_web_page_and_video_output() {
+        let page = json!({
+            "url": "https://example.com/post",
+            "title": "A Post",
+            "content": "Body text",
+            "metadata": {"author": "Ann", "published_date": "2024-03-01T10:00:00Z", "keywords": []}
+        });
+        let doc = Document::from_tool_output("web", &page);
+        assert_eq!(doc.title, "A Post");
+        assert_eq!(doc.authors, vec!["Ann"]);
+        assert_eq!(doc.url.as_deref(), Some("https://example.com/post"));
+        assert_eq!(doc.text, "Body text");
+        assert_eq!(
+            doc.published_at.unwrap().to_rfc3339(),
+            "2024-03-01T10:00:00+00:00"
+        );
+        assert_eq!(doc.metadata["metadata"]["keywords"], json!([]));
+
+        let video = json!({
+            "title": "Talk",
+            "channel_name": "Conf",
+            "upload_date": "20230105",
+            "chapters": [
+                {"heading": "Intro", "start_time": 0, "content": "Hello"},
+                {"heading": "Demo", "start_time": 60, "content": "Watch"}
+            ]
+        });
+        let doc = Document::from_tool_output("youtube", &video);
+        assert_eq!(doc.authors, vec!["Conf"]);
+        assert_eq!(doc.sections.len(), 2);
+        assert_eq!(doc.text, "Intro\n\nHello\n\nDemo\n\nWatch");
+        assert_eq!(
+            doc.published_at.unwrap().format("%Y-%m-%d").to_string(),
+            "2023-01-05"
+        );
+        assert!(doc.to_markdown().contains("## Demo\n\nWatch"));
+    }
+
+    #[test]
+    fn unwraps_items_and_parses_authors() {
+        let story = json!({
+            "story": {"id": 1, "title": "Show HN", "by": "pg", "time": 1_720_000_000, "text": "hi"},
+            "comments": [{"by": "x", "text": "nice"}]
+        });
+        let doc = Document::from_tool_output("hackernews", &story);
+        assert_eq!(doc.title, "Show HN");
+        assert_eq!(doc.authors, vec!["pg"]);
+        assert_eq!(doc.published_at.unwrap().timestamp(), 1_720_000_000);
+        assert_eq!(doc.metadata["id"], 1);
+        assert!(doc.metadata["comments"].is_array());
+
+        let work = json!({
+            "title": "Paper",
+            "authors": [{"given": "Ada", "family": "Lovelace"}, {"name": "Alan Turing"}],
+            "year": 1843
+        });
+        let doc = Document::from_tool_output("crossref", &work);
+        assert_eq!(doc.authors, vec!["Ada Lovelace", "Alan Turing"]);
+        assert_eq!(
+            parse_authors("authors", &json!("A. One, B. Two")),
+            vec!["A. One", "B. Two"]
+        );
+        assert_eq!(doc.published_at.unwrap().format("%Y").to_string(), "1843");
+        assert_eq!(
+            Document::from_tool_output("x", &json!("plain")).text,
+            "plain"
+        );
+    }
+}
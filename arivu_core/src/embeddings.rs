@@ -0,0 +1,256 @@
+//! Text embeddings for semantic search (feature `embeddings`).
+//!
+//! Embeddings come from the backend named in the environment:
+//!
+//! | Variable | Meaning |
+//! |----------|---------|
+//! | `ARIVU_EMBED_BACKEND` | `openai` (any OpenAI-compatible embeddings API), `ollama` (a local model served by Ollama) or `hash` |
+//! | `ARIVU_EMBED_API_KEY` | Key for the `openai` backend (falls back to `OPENAI_API_KEY`) |
+//! | `ARIVU_EMBED_BASE_URL` | API base URL (default `https://api.openai.com/v1`, or `http://localhost:11434` for Ollama) |
+//! | `ARIVU_EMBED_MODEL` | Model (default `text-embedding-3-small`, or `nomic-embed-text` for Ollama) |
+//!
+//! The `hash` backend needs no model: it hashes words and word pairs into a fixed-size vector.
+//! It only matches passages that share vocabulary with the query, so it is a baseline for offline
+//! use rather than a semantic model. Every backend returns unit-length vectors, so cosine
+//! similarity is a dot product.
+
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use crate::language::checked_json;
+use crate::ConnectorError;
+
+const DEFAULT_OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
+const DEFAULT_OPENAI_MODEL: &str = "text-embedding-3-small";
+const DEFAULT_OLLAMA_BASE_URL: &str = "http://localhost:11434";
+const DEFAULT_OLLAMA_MODEL: &str = "nomic-embed-text";
+const HASH_DIMS: usize = 512;
+/// Inputs per embeddings request
+const BATCH_SIZE: usize = 64;
+
+/// A configured embedding model.
+#[derive(Debug, Clone)]
+pub enum EmbeddingBackend {
+    /// Any OpenAI-compatible embeddings API, including local servers such as LM Studio
+    OpenAi {
+        base_url: String,
+        api_key: Option<String>,
+        model: String,
+    },
+    Ollama {
+        base_url: String,
+        model: String,
+    },
+    /// Hashed bag of words and word pairs
+    Hash {
+        dims: usize,
+    },
+}
+
+impl EmbeddingBackend {
+    /// The backend named by `ARIVU_EMBED_BACKEND`, or `None` when it is unset.
+    pub fn from_env() -> Result<Option<Self>, ConnectorError> {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+        let Some(backend) = var("ARIVU_EMBED_BACKEND") else {
+            return Ok(None);
+        };
+        let base_url = |default: &str| {
+            var("ARIVU_EMBED_BASE_URL")
+                .unwrap_or_else(|| default.to_string())
+                .trim_end_matches('/')
+                .to_string()
+        };
+        match backend.trim().to_ascii_lowercase().as_str() {
+            "openai" => Ok(Some(Self::OpenAi {
+                base_url: base_url(DEFAULT_OPENAI_BASE_URL),
+                api_key: var("ARIVU_EMBED_API_KEY").or_else(|| var("OPENAI_API_KEY")),
+                model: var("ARIVU_EMBED_MODEL").unwrap_or_else(|| DEFAULT_OPENAI_MODEL.to_string()),
+            })),
+            "ollama" | "local" => Ok(Some(Self::Ollama {
+                base_url: base_url(DEFAULT_OLLAMA_BASE_URL),
+                model: var("ARIVU_EMBED_MODEL").unwrap_or_else(|| DEFAULT_OLLAMA_MODEL.to_string()),
+            })),
+            "hash" => Ok(Some(Self::Hash { dims: HASH_DIMS })),
+            other => Err(ConnectorError::InvalidInput(format!(
+                "Unknown ARIVU_EMBED_BACKEND '{}' (expected openai, ollama or hash)",
+                other
+            ))),
+        }
+    }
+
+    /// Backend and model, e.g. `openai:text-embedding-3-small`. Vectors are only comparable
+    /// between texts embedded under the same name.
+    pub fn name(&self) -> String {
+        match self {
+            Self::OpenAi { model, .. } => format!("openai:{}", model),
+            Self::Ollama { model, .. } => format!("ollama:{}", model),
+            Self::Hash { dims } => format!("hash:{}", dims),
+        }
+    }
+
+    /// Unit-length embeddings of `texts`, in order.
+    pub async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, ConnectorError> {
+        if let Self::Hash { dims } = self {
+            return Ok(texts.iter().map(|t| hash_embedding(t, *dims)).collect());
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(120))
+            .build()?;
+        let mut vectors = Vec::with_capacity(texts.len());
+        for batch in texts.chunks(BATCH_SIZE) {
+            let embedded = match self {
+                Self::OpenAi {
+                    base_url,
+                    api_key,
+                    model,
+                } => {
+                    let mut request = client
+                        .post(format!("{}/embeddings", base_url))
+                        .json(&json!({ "model": model, "input": batch }));
+                    if let Some(key) = api_key {
+                        request = request.bearer_auth(key);
+                    }
+                    parse_openai(&checked_json(request.send().await?, "Embeddings API").await?)?
+                }
+                Self::Ollama { base_url, model } => {
+                    let response = client
+                        .post(format!("{}/api/embed", base_url))
+                        .json(&json!({ "model": model, "input": batch }))
+                        .send()
+                        .await?;
+                    parse_ollama(&checked_json(response, "Ollama").await?)?
+                }
+                Self::Hash { .. } => unreachable!("handled above"),
+            };
+            if embedded.len() != batch.len() {
+                return Err(ConnectorError::Other(format!(
+                    "Embeddings API returned {} vectors for {} inputs",
+                    embedded.len(),
+                    batch.len()
+                )));
+            }
+            vectors.extend(embedded.into_iter().map(|mut v| {
+                normalize(&mut v);
+                v
+            }));
+        }
+        Ok(vectors)
+    }
+}
+
+/// `data[].embedding` of an OpenAI embeddings response, in input order.
+fn parse_openai(body: &Value) -> Result<Vec<Vec<f32>>, ConnectorError> {
+    let mut data: Vec<(u64, Vec<f32>)> = body
+        .get("data")
+        .and_then(Value::as_array)
+        .ok_or_else(|| ConnectorError::Other("Embeddings API returned no data".to_string()))?
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let index = item
+                .get("index")
+                .and_then(Value::as_u64)
+                .unwrap_or(i as u64);
+            (index, floats(item.get("embedding")))
+        })
+        .collect();
+    data.sort_by_key(|(index, _)| *index);
+    Ok(data.into_iter().map(|(_, v)| v).collect())
+}
+
+/// `embeddings` of an Ollama `/api/embed` response.
+fn parse_ollama(body: &Value) -> Result<Vec<Vec<f32>>, ConnectorError> {
+    body.get("embeddings")
+        .and_then(Value::as_array)
+        .map(|items| items.iter().map(|v| floats(Some(v))).collect())
+        .ok_or_else(|| ConnectorError::Other("Ollama returned no embeddings".to_string()))
+}
+
+fn floats(value: Option<&Value>) -> Vec<f32> {
+    value
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(Value::as_f64)
+                .map(|x| x as f32)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Scale `vector` to unit length (zero vectors are left alone).
+pub fn normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|x| *x /= norm);
+    }
+}
+
+/// Dot product; cosine similarity for unit vectors.
+pub fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Words and word pairs hashed into `dims` signed buckets.
+fn hash_embedding(text: &str, dims: usize) -> Vec<f32> {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() > 1)
+        .map(str::to_lowercase)
+        .collect();
+    let mut vector = vec![0.0f32; dims.max(1)];
+    let mut add = |feature: &str, weight: f32| {
+        let hash = fnv1a(feature);
+        let bucket = (hash % vector.len() as u64) as usize;
+        // The top bit picks the sign so unrelated features cancel out on average
+        vector[bucket] += if hash >> 63 == 0 { weight } else { -weight };
+    };
+    for word in &words {
+        add(word, 1.0);
+    }
+    for pair in words.windows(2) {
+        add(&format!("{} {}", pair[0], pair[1]), 0.5);
+    }
+    normalize(&mut vector);
+    vector
+}
+
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_responses_and_hashes_text_to_unit_vectors() {
+        let body = json!({"data": [
+            {"index": 1, "embedding": [0.0, 2.0]},
+            {"index": 0, "embedding": [1.0, 0.0]}
+        ]});
+        assert_eq!(
+            parse_openai(&body).unwrap(),
+            vec![vec![1.0, 0.0], vec![0.0, 2.0]]
+        );
+        let body = json!({"embeddings": [[0.5, 0.5]]});
+        assert_eq!(parse_ollama(&body).unwrap(), vec![vec![0.5, 0.5]]);
+        assert!(parse_ollama(&json!({"error": "no model"})).is_err());
+
+        let fasting = hash_embedding("Intermittent fasting extends lifespan in mice", 256);
+        let similar = hash_embedding("Does fasting extend the lifespan of mice?", 256);
+        let unrelated = hash_embedding("Transformers rely on self-attention layers", 256);
+        let norm: f32 = fasting.iter().map(|x| x * x).sum();
+        assert!((norm - 1.0).abs() < 1e-5);
+        assert!(dot(&fasting, &similar) > dot(&fasting, &unrelated) + 0.2);
+        assert_eq!(
+            fasting,
+            hash_embedding("intermittent FASTING extends lifespan in mice", 256)
+        );
+    }
+}
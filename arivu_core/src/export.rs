@@ -0,0 +1,258 @@
+//! Chunked exports of fetched documents for external RAG pipelines.
+//!
+//! [`chunk_document`] splits a [`Document`] into overlapping chunks of at most
+//! [`ChunkOptions::max_tokens`] tokens (counted with [`crate::tokens::count_tokens`]), section by
+//! section so a chunk never spans two headings. Chunks end at a paragraph or sentence break
+//! when one falls in the second half of the window. Each chunk carries the document's source,
+//! title, URL, authors, date and language, so it can be cited on its own. [`write_jsonl`] writes
+//! one chunk per line in the `{"id", "text", "metadata"}` shape that LangChain, LlamaIndex and
+//! most vector databases ingest directly.
+
+use std::io::Write;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::document::Document;
+use crate::tokens::count_tokens;
+use crate::ConnectorError;
+
+pub const DEFAULT_CHUNK_TOKENS: usize = 512;
+pub const DEFAULT_OVERLAP_TOKENS: usize = 64;
+
+/// Chunk size and overlap, in tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkOptions {
+    pub max_tokens: usize,
+    pub overlap_tokens: usize,
+}
+
+impl Default for ChunkOptions {
+    fn default() -> Self {
+        Self {
+            max_tokens: DEFAULT_CHUNK_TOKENS,
+            overlap_tokens: DEFAULT_OVERLAP_TOKENS,
+        }
+    }
+}
+
+impl ChunkOptions {
+    /// Options with the overlap checked to be smaller than the chunk.
+    pub fn new(max_tokens: usize, overlap_tokens: usize) -> Result<Self, ConnectorError> {
+        if max_tokens == 0 {
+            return Err(ConnectorError::InvalidParams(
+                "chunk size must be at least 1 token".to_string(),
+            ));
+        }
+        if overlap_tokens >= max_tokens {
+            return Err(ConnectorError::InvalidParams(format!(
+                "overlap ({}) must be smaller than the chunk size ({})",
+                overlap_tokens, max_tokens
+            )));
+        }
+        Ok(Self {
+            max_tokens,
+            overlap_tokens,
+        })
+    }
+}
+
+/// One line of a RAG export.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RagChunk {
+    /// `<doc_id>#<chunk_index>`
+    pub id: String,
+    pub text: String,
+    pub metadata: ChunkMetadata,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChunkMetadata {
+    pub doc_id: String,
+    pub chunk_index: usize,
+    pub chunk_count: usize,
+    /// Connector that produced the document
+    pub source: String,
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<DateTime<Utc>>,
+    /// Heading of the section the chunk comes from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    pub token_count: usize,
+}
+
+/// Split `document` into chunks identified as `<doc_id>#<n>`. Documents without text yield none.
+pub fn chunk_document(document: &Document, doc_id: &str, options: &ChunkOptions) -> Vec<RagChunk> {
+    let units: Vec<(Option<&str>, &str)> = if document.sections.is_empty() {
+        vec![(None, document.text.as_str())]
+    } else {
+        document
+            .sections
+            .iter()
+            .map(|s| (s.heading.as_deref(), s.text.as_str()))
+            .collect()
+    };
+
+    let mut pieces: Vec<(Option<&str>, &str)> = Vec::new();
+    for (heading, text) in units {
+        for range in split_ranges(text, options) {
+            let chunk = text[range].trim();
+            if !chunk.is_empty() {
+                pieces.push((heading, chunk));
+            }
+        }
+    }
+
+    let chunk_count = pieces.len();
+    pieces
+        .into_iter()
+        .enumerate()
+        .map(|(i, (heading, text))| RagChunk {
+            id: format!("{}#{}", doc_id, i),
+            text: text.to_string(),
+            metadata: ChunkMetadata {
+                doc_id: doc_id.to_string(),
+                chunk_index: i,
+                chunk_count,
+                source: document.source.clone(),
+                title: document.title.clone(),
+                url: document.url.clone(),
+                authors: document.authors.clone(),
+                published_at: document.published_at,
+                section: heading.map(str::to_string),
+                language: document.language.as_ref().map(|l| l.code.clone()),
+                token_count: count_tokens(text),
+            },
+        })
+        .collect()
+}
+
+/// Write `chunks` as JSON Lines. Returns how many were written.
+pub fn write_jsonl<W: Write>(chunks: &[RagChunk], mut out: W) -> Result<usize, ConnectorError> {
+    for chunk in chunks {
+        serde_json::to_writer(&mut out, chunk)?;
+        out.write_all(b"\n")?;
+    }
+    out.flush()?;
+    Ok(chunks.len())
+}
+
+/// Byte ranges of `text` that each fit in `max_tokens`, overlapping by about `overlap_tokens`.
+fn split_ranges(text: &str, options: &ChunkOptions) -> Vec<std::ops::Range<usize>> {
+    // Words with the whitespace before them, so each is priced like a BPE pre-token
+    let mut starts = vec![0];
+    let mut prev_space = false;
+    for (i, c) in text.char_indices() {
+        if c.is_whitespace() && !prev_space && i > 0 {
+            starts.push(i);
+        }
+        prev_space = c.is_whitespace();
+    }
+    let piece = |i: usize| &text[starts[i]..starts.get(i + 1).copied().unwrap_or(text.len())];
+    let costs: Vec<usize> = (0..starts.len()).map(|i| count_tokens(piece(i))).collect();
+    let n = starts.len();
+
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    while start < n {
+        let mut end = start;
+        let mut used = 0;
+        while end < n && (end == start || used + costs[end] <= options.max_tokens) {
+            used += costs[end];
+            end += 1;
+        }
+        if end < n {
+            // Prefer a paragraph break, then a sentence break, in the second half of the window
+            let half = start + (end - start).div_ceil(2);
+            let breaks = (half..end).rev();
+            // A piece is a word and the whitespace before it
+            let paragraph = breaks.clone().find(|&i| piece(i).contains("\n\n"));
+            let sentence = breaks.clone().find(|&i| {
+                piece(i).starts_with(char::is_whitespace)
+                    && piece(i - 1).trim_end().ends_with(['.', '!', '?', ':'])
+            });
+            if let Some(at) = paragraph.or(sentence).filter(|&at| at > start) {
+                end = at;
+            }
+        }
+        ranges.push(starts[start]..starts.get(end).copied().unwrap_or(text.len()));
+        if end >= n {
+            break;
+        }
+
+        let mut next = end;
+        let mut overlap = 0;
+        while next > start + 1 && overlap + costs[next - 1] <= options.overlap_tokens {
+            overlap += costs[next - 1];
+            next -= 1;
+        }
+        start = next;
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_documents_with_overlap_and_metadata() {
+        let paragraph = "Fasting extended lifespan in mice. ".repeat(12);
+        let text = format!("{}\n\n{}\n\n{}", paragraph, paragraph, paragraph);
+        let doc = Document::new("pubmed", "Fasting and lifespan")
+            .with_url("https://pubmed.ncbi.nlm.nih.gov/1/")
+            .with_text(text);
+        let options = ChunkOptions::new(100, 20).unwrap();
+        let chunks = chunk_document(&doc, "pubmed:1", &options);
+
+        assert!(chunks.len() >= 3, "{}", chunks.len());
+        for (i, chunk) in chunks.iter().enumerate() {
+            assert_eq!(chunk.id, format!("pubmed:1#{}", i));
+            assert!(
+                chunk.metadata.token_count <= 100,
+                "{}",
+                chunk.metadata.token_count
+            );
+            assert_eq!(chunk.metadata.chunk_count, chunks.len());
+            assert_eq!(
+                chunk.metadata.url.as_deref(),
+                Some("https://pubmed.ncbi.nlm.nih.gov/1/")
+            );
+            assert!(
+                chunk.text.ends_with('.'),
+                "ends at a sentence: {:?}",
+                chunk.text
+            );
+        }
+        // Consecutive chunks share their boundary text
+        let tail: String = chunks[0].text.chars().rev().take(20).collect();
+        let tail: String = tail.chars().rev().collect();
+        assert!(chunks[1].text.contains(tail.trim()));
+
+        let sections = Document::new("web", "Guide")
+            .with_section(Some("Intro".to_string()), "Short intro.")
+            .with_section(Some("Usage".to_string()), "Run it.");
+        let chunks = chunk_document(&sections, "guide", &ChunkOptions::default());
+        let headings: Vec<_> = chunks
+            .iter()
+            .map(|c| c.metadata.section.as_deref())
+            .collect();
+        assert_eq!(headings, [Some("Intro"), Some("Usage")]);
+
+        assert!(chunk_document(&Document::new("web", "Empty"), "e", &options).is_empty());
+        assert!(ChunkOptions::new(50, 50).is_err());
+
+        let mut out = Vec::new();
+        assert_eq!(write_jsonl(&chunks, &mut out).unwrap(), 2);
+        let line = String::from_utf8(out).unwrap();
+        let first: RagChunk = serde_json::from_str(line.lines().next().unwrap()).unwrap();
+        assert_eq!(first, chunks[0]);
+    }
+}
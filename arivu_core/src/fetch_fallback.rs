@@ -0,0 +1,384 @@
+//! Fallback sources for `arivu fetch`.
+//!
+//! When the live fetch fails, content can still come from an earlier response kept in the local
+//! response cache, from the Internet Archive's closest Wayback Machine snapshot, or from a search
+//! provider's crawled copy of the page. This module holds the pieces that do not need a connector
+//! registry: the chain definition, the on-disk cache and the Wayback availability lookup.
+//!
+//! The Wayback and search sources send the URL to a third party, so they are opt-in and never
+//! used for hosts that aren't public (localhost, private and link-local addresses, single-label
+//! and `.local`/`.internal` names).
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::error::ConnectorError;
+
+/// Chain used when neither `--fallback` nor `ARIVU_FETCH_FALLBACK` is set. Only local sources;
+/// `wayback` and `search` have to be asked for.
+pub const DEFAULT_CHAIN: &str = "live,cache";
+
+/// Responses larger than this are not cached.
+const MAX_CACHED_BYTES: usize = 5 * 1024 * 1024;
+
+const WAYBACK_AVAILABILITY_URL: &str = "https://archive.org/wayback/available";
+
+/// Search connectors that can return their crawled copy of a URL, with the tool to call.
+pub const SEARCH_COPY_PROVIDERS: &[(&str, &str)] = &[("exa-search", "get_contents")];
+
+/// One step of the fallback chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FetchSource {
+    /// The connector the input resolved to
+    Live,
+    /// A previous successful response for the same input
+    Cache,
+    /// The closest Internet Archive snapshot (URLs only)
+    Wayback,
+    /// A search provider's crawled copy (URLs only)
+    Search,
+}
+
+impl FetchSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FetchSource::Live => "live",
+            FetchSource::Cache => "cache",
+            FetchSource::Wayback => "wayback",
+            FetchSource::Search => "search",
+        }
+    }
+
+    /// Whether this source only works for http(s) URLs, and sends them to a third party.
+    pub fn needs_url(&self) -> bool {
+        matches!(self, FetchSource::Wayback | FetchSource::Search)
+    }
+}
+
+/// Parse a comma-separated chain such as `live,wayback`. Order is kept and repeats are dropped.
+pub fn parse_chain(spec: &str) -> Result<Vec<FetchSource>, String> {
+    let mut chain = Vec::new();
+    for name in spec.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let source = match name.to_ascii_lowercase().as_str() {
+            "live" => FetchSource::Live,
+            "cache" | "cached" => FetchSource::Cache,
+            "wayback" | "archive" => FetchSource::Wayback,
+            "search" => FetchSource::Search,
+            other => {
+                return Err(format!(
+                    "Unknown fetch source '{}' (expected live, cache, wayback or search)",
+                    other
+                ))
+            }
+        };
+        if !chain.contains(&source) {
+            chain.push(source);
+        }
+    }
+    if chain.is_empty() {
+        return Err("The fallback chain is empty".to_string());
+    }
+    Ok(chain)
+}
+
+/// A response stored by [`ResponseCache`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedResponse {
+    pub input: String,
+    pub connector: String,
+    pub tool: String,
+    /// Seconds since the Unix epoch
+    pub stored_at: u64,
+    pub content: Value,
+}
+
+impl CachedResponse {
+    pub fn age(&self) -> Duration {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Duration::from_secs(now.saturating_sub(self.stored_at))
+    }
+}
+
+/// File-per-input cache of successful fetch results under `~/.arivu/cache/fetch`
+/// (or `ARIVU_CACHE_DIR/fetch`).
+pub struct ResponseCache {
+    dir: PathBuf,
+}
+
+impl ResponseCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    pub fn new_default() -> Self {
+        let base = std::env::var("ARIVU_CACHE_DIR")
+            .ok()
+            .filter(|d| !d.trim().is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| {
+                dirs::home_dir()
+                    .unwrap_or_else(|| PathBuf::from("."))
+                    .join(".arivu")
+                    .join("cache")
+            });
+        Self::new(base.join("fetch"))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path_for(&self, input: &str) -> PathBuf {
+        self.dir.join(format!("{:016x}.json", cache_key(input)))
+    }
+
+    /// The stored response for `input`, if there is one no older than `max_age`.
+    pub fn get(&self, input: &str, max_age: Option<Duration>) -> Option<CachedResponse> {
+        let bytes = std::fs::read(self.path_for(input)).ok()?;
+        let cached: CachedResponse = serde_json::from_slice(&bytes).ok()?;
+        // Hash collisions are possible in principle; never serve another input's content
+        if cached.input != input.trim() {
+            return None;
+        }
+        match max_age {
+            Some(max_age) if cached.age() > max_age => None,
+            _ => Some(cached),
+        }
+    }
+
+    /// Store a response, replacing any earlier one for the same input. Oversized responses are
+    /// skipped and reported as `Ok(false)`.
+    pub fn put(
+        &self,
+        input: &str,
+        connector: &str,
+        tool: &str,
+        content: &Value,
+    ) -> Result<bool, ConnectorError> {
+        let entry = CachedResponse {
+            input: input.trim().to_string(),
+            connector: connector.to_string(),
+            tool: tool.to_string(),
+            stored_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            content: content.clone(),
+        };
+        let bytes = serde_json::to_vec(&entry)?;
+        if bytes.len() > MAX_CACHED_BYTES {
+            return Ok(false);
+        }
+        std::fs::create_dir_all(&self.dir)?;
+        let path = self.path_for(input);
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, &bytes)?;
+        std::fs::rename(&tmp, &path)?;
+        Ok(true)
+    }
+}
+
+/// Stable 64-bit FNV-1a hash of the trimmed input, used as the cache file name.
+fn cache_key(input: &str) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in input.trim().as_bytes() {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// The closest archived copy of a URL.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WaybackSnapshot {
+    /// Snapshot page as listed by the Wayback Machine
+    pub url: String,
+    /// `YYYYMMDDhhmmss`
+    pub timestamp: String,
+    /// The snapshot's original bytes, without the Wayback toolbar or rewritten links
+    pub raw_url: String,
+}
+
+/// Look up the closest available snapshot of `url`.
+pub async fn wayback_snapshot(url: &str) -> Result<Option<WaybackSnapshot>, ConnectorError> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(20))
+        .build()?;
+    let body: Value = client
+        .get(WAYBACK_AVAILABILITY_URL)
+        .query(&[("url", url)])
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    Ok(parse_availability(&body, url))
+}
+
+fn parse_availability(body: &Value, original: &str) -> Option<WaybackSnapshot> {
+    let closest = body.pointer("/archived_snapshots/closest")?;
+    if closest.get("available").and_then(Value::as_bool) == Some(false) {
+        return None;
+    }
+    if let Some(status) = closest.get("status").and_then(Value::as_str) {
+        if !status.starts_with('2') {
+            return None;
+        }
+    }
+    let timestamp = closest.get("timestamp")?.as_str()?.to_string();
+    let url = closest.get("url")?.as_str()?.to_string();
+    Some(WaybackSnapshot {
+        raw_url: format!("https://web.archive.org/web/{}id_/{}", timestamp, original),
+        url,
+        timestamp,
+    })
+}
+
+/// Suffixes of names that only resolve on a local network
+const PRIVATE_SUFFIXES: &[&str] = &[
+    ".localhost",
+    ".local",
+    ".internal",
+    ".intranet",
+    ".lan",
+    ".home",
+    ".corp",
+    ".home.arpa",
+];
+
+/// Whether `input` is an http(s) URL on a public host, which archive and search sources may be
+/// asked about. Loopback, private, link-local and shared addresses, `localhost`, single-label
+/// names and local-network suffixes are not.
+pub fn is_public_url(input: &str) -> bool {
+    let Ok(url) = url::Url::parse(input.trim()) else {
+        return false;
+    };
+    if !matches!(url.scheme(), "http" | "https") {
+        return false;
+    }
+    match url.host() {
+        Some(url::Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            domain.contains('.')
+                && !PRIVATE_SUFFIXES
+                    .iter()
+                    .any(|suffix| domain.ends_with(suffix))
+        }
+        Some(url::Host::Ipv4(ip)) => is_public_ipv4(ip),
+        Some(url::Host::Ipv6(ip)) => match ip.to_ipv4_mapped() {
+            Some(v4) => is_public_ipv4(v4),
+            None => {
+                let first = ip.segments()[0];
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || (first & 0xfe00) == 0xfc00
+                    || (first & 0xffc0) == 0xfe80)
+            }
+        },
+        None => false,
+    }
+}
+
+fn is_public_ipv4(ip: std::net::Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || a == 0
+        // Shared address space (carrier-grade NAT)
+        || (a == 100 && (64..128).contains(&b)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_chain_and_wayback_availability() {
+        assert_eq!(
+            parse_chain(DEFAULT_CHAIN).unwrap(),
+            vec![FetchSource::Live, FetchSource::Cache]
+        );
+        assert_eq!(
+            parse_chain("live,wayback,search").unwrap(),
+            vec![FetchSource::Live, FetchSource::Wayback, FetchSource::Search]
+        );
+        assert_eq!(
+            parse_chain(" cache, live ,cache").unwrap(),
+            vec![FetchSource::Cache, FetchSource::Live]
+        );
+        assert!(parse_chain("live,google").is_err());
+        assert!(parse_chain(" , ").is_err());
+
+        let body = json!({"url": "example.com/a", "archived_snapshots": {"closest": {
+            "status": "200", "available": true,
+            "url": "http://web.archive.org/web/20240101000000/https://example.com/a",
+            "timestamp": "20240101000000"
+        }}});
+        assert_eq!(
+            parse_availability(&body, "https://example.com/a"),
+            Some(WaybackSnapshot {
+                url: "http://web.archive.org/web/20240101000000/https://example.com/a".into(),
+                timestamp: "20240101000000".into(),
+                raw_url: "https://web.archive.org/web/20240101000000id_/https://example.com/a"
+                    .into(),
+            })
+        );
+        assert_eq!(
+            parse_availability(&json!({"archived_snapshots": {}}), "x"),
+            None
+        );
+
+        assert!(is_public_url("https://example.com/a?b=1"));
+        assert!(is_public_url("http://93.184.215.14/"));
+        assert!(!is_public_url("arXiv:2301.07041"));
+        for private in [
+            "http://localhost:8080/admin",
+            "http://intranet/wiki",
+            "https://build.corp/job/1",
+            "http://printer.local/",
+            "http://10.0.0.5/",
+            "http://192.168.1.1/",
+            "http://127.0.0.1:3000/",
+            "http://169.254.169.254/latest/meta-data",
+            "http://100.64.0.1/",
+            "http://[::1]/",
+            "http://[fd00::1]/",
+            "http://[::ffff:10.1.2.3]/",
+        ] {
+            assert!(!is_public_url(private), "{}", private);
+        }
+        assert_eq!(cache_key(" x "), cache_key("x"));
+    }
+
+    #[test]
+    fn cache_round_trips_and_checks_age() {
+        let dir =
+            std::env::temp_dir().join(format!("arivu-fetch-cache-test-{}", std::process::id()));
+        let cache = ResponseCache::new(dir.clone());
+        assert!(cache.get("https://example.com", None).is_none());
+
+        let content = json!({"title": "Example"});
+        assert!(cache
+            .put("https://example.com", "web", "scrape_url", &content)
+            .unwrap());
+        let hit = cache.get(" https://example.com ", None).unwrap();
+        assert_eq!(hit.content, content);
+        assert_eq!(hit.connector, "web");
+        assert!(cache
+            .get("https://example.com", Some(Duration::from_secs(3600)))
+            .is_some());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
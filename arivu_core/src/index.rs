@@ -0,0 +1,982 @@
+//! Local full-text index over fetched content (feature `index`).
+//!
+//! [`ContentIndex`] keeps a tantivy index under `~/.arivu/index` (or `ARIVU_INDEX_DIR`). With
+//! `ARIVU_INDEX=on` every connector in the registry is wrapped in an [`IndexingConnector`], so
+//! each fetched document and each search hit is added as it comes back, from the CLI, the MCP
+//! server or the research tool alike. Entries are keyed by URL (or connector and id), so
+//! fetching the same page again replaces it. Searching needs no network: `arivu index search`
+//! and the `index` MCP tool query the titles and text with BM25 ranking.
+//!
+//! The index is stored unencrypted, so only connectors that return public content
+//! ([`PUBLIC_CONNECTORS`], minus a few account tools) are indexed by default. Anything else,
+//! from mail and notes to the databases, trackers and business systems reached with the user's
+//! credentials, is left out unless named in `ARIVU_INDEX_INCLUDE`.
+//!
+//! Writers take tantivy's lock only while they commit; if another process is committing at the
+//! same moment, that ingestion is skipped with a debug log. Searches never wait on writers.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use futures::future::BoxFuture;
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use tantivy::collector::{DocSetCollector, TopDocs};
+use tantivy::directory::MmapDirectory;
+use tantivy::query::{AllQuery, BooleanQuery, Occur, Query, QueryParser, TermQuery};
+use tantivy::schema::{
+    Field, IndexRecordOption, Schema, Value as _, INDEXED, STORED, STRING, TEXT,
+};
+use tantivy::snippet::SnippetGenerator;
+use tantivy::{Index, IndexReader, IndexWriter, ReloadPolicy, TantivyDocument, Term};
+use tracing::{debug, warn};
+
+use crate::document::Document;
+use crate::{
+    auth::AuthDetails, CallToolRequestParam, CallToolResult, Connector, ConnectorError,
+    InitializeRequestParam, InitializeResult, ListPromptsResult, ListResourcesResult,
+    ListToolsResult, PaginatedRequestParam, Prompt, RawContent, ReadResourceRequestParam,
+    ResourceContents, ServerCapabilities, Tool,
+};
+
+/// Name of the search tool over MCP.
+pub const TOOL_NAME: &str = "index";
+
+const DEFAULT_LIMIT: usize = 10;
+const MAX_LIMIT: usize = 50;
+/// Characters of each document that are indexed and stored
+const MAX_TEXT_CHARS: usize = 200_000;
+const SNIPPET_CHARS: usize = 240;
+const WRITER_HEAP_BYTES: usize = 20_000_000;
+
+/// Arrays that hold the hits of a search result, as in the federated engine
+const RESULT_LIST_KEYS: &[&str] = &[
+    "results", "articles", "papers", "items", "stories", "posts", "videos",
+];
+
+/// Connectors that return public content. The results of every other connector, including
+/// ones added later, are only indexed when the connector is listed in `ARIVU_INDEX_INCLUDE`.
+pub const PUBLIC_CONNECTORS: &[&str] = &[
+    "anthropic-search",
+    "arxiv",
+    "biorxiv",
+    "brave-search",
+    "coingecko",
+    "crates-io",
+    "crossref",
+    "dblp",
+    "ddg-search",
+    "dockerhub",
+    "exa",
+    "firecrawl-search",
+    "gemini-search",
+    "google-scholar",
+    "hackernews",
+    "market-data",
+    "npm",
+    "openai-search",
+    "openlibrary",
+    "parallel-search",
+    "perplexity-search",
+    "podcasts",
+    "pubmed",
+    "pypi",
+    "reddit",
+    "rss",
+    "scihub",
+    "semantic-scholar",
+    "serpapi-search",
+    "serper-search",
+    "tavily-search",
+    "twitch",
+    "vimeo",
+    "web",
+    "wikipedia",
+    "x",
+    "xai-search",
+    "youtube",
+];
+
+/// Tools of public connectors that return the signed-in account's private data
+const PERSONAL_TOOLS: &[(&str, &str)] = &[
+    ("reddit", "get_inbox"),
+    ("reddit", "get_saved"),
+    ("reddit", "get_upvoted"),
+    ("rss", "export_opml"),
+    ("rss", "list_subscriptions"),
+    ("x", "get_direct_message_conversations"),
+    ("x", "get_home_timeline"),
+    ("x", "get_list_timeline"),
+];
+
+/// Where the index lives and which tool results are added automatically.
+#[derive(Debug, Clone)]
+pub struct IndexConfig {
+    pub dir: PathBuf,
+    pub auto_ingest: bool,
+    /// Personal-data connectors whose results are indexed anyway
+    pub include: Vec<String>,
+}
+
+impl IndexConfig {
+    /// Read `ARIVU_INDEX` (`on`/`1`/`true` to ingest tool results), `ARIVU_INDEX_DIR` and
+    /// `ARIVU_INDEX_INCLUDE` (comma-separated non-public connectors to index as well).
+    pub fn from_env() -> Self {
+        let auto_ingest = std::env::var("ARIVU_INDEX").is_ok_and(|v| {
+            matches!(
+                v.trim().to_ascii_lowercase().as_str(),
+                "1" | "on" | "true" | "yes"
+            )
+        });
+        let dir = std::env::var("ARIVU_INDEX_DIR")
+            .ok()
+            .filter(|d| !d.trim().is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| {
+                dirs::home_dir()
+                    .unwrap_or_else(|| PathBuf::from("."))
+                    .join(".arivu")
+                    .join("index")
+            });
+        let include = std::env::var("ARIVU_INDEX_INCLUDE")
+            .map(|v| {
+                v.split(',')
+                    .map(|name| name.trim().to_ascii_lowercase())
+                    .filter(|name| !name.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            dir,
+            auto_ingest,
+            include,
+        }
+    }
+
+    /// Whether any result of `connector` may be indexed; false for connectors that aren't
+    /// public and aren't in `include`.
+    pub fn allows_connector(&self, connector: &str) -> bool {
+        PUBLIC_CONNECTORS.contains(&connector) || self.includes(connector)
+    }
+
+    /// Whether the results of `connector`'s `tool` may be indexed.
+    pub fn allows(&self, connector: &str, tool: &str) -> bool {
+        let personal_tool = PERSONAL_TOOLS.contains(&(connector, tool));
+        self.allows_connector(connector) && (!personal_tool || self.includes(connector))
+    }
+
+    fn includes(&self, connector: &str) -> bool {
+        self.include.iter().any(|name| name == connector)
+    }
+}
+
+/// One entry of the index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedItem {
+    /// URL, or `connector:id` when there is none; adding the same key replaces the entry
+    pub key: String,
+    pub source: String,
+    /// "document" for fetched content, "search_result" for a search hit
+    pub kind: &'static str,
+    pub title: String,
+    pub url: Option<String>,
+    pub text: String,
+}
+
+/// A stored entry with the time it was last written.
+#[derive(Debug, Clone)]
+pub struct IndexEntry {
+    pub key: String,
+    pub source: String,
+    pub kind: String,
+    pub title: String,
+    pub url: Option<String>,
+    pub text: String,
+    /// Unix seconds
+    pub indexed_at: i64,
+}
+
+/// A search hit from the index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexHit {
+    pub key: String,
+    pub source: String,
+    pub kind: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    pub snippet: String,
+    pub score: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indexed_at: Option<String>,
+}
+
+/// Hits for a query plus the size of the index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexSearchResult {
+    pub query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    pub total_documents: u64,
+    pub hits: Vec<IndexHit>,
+}
+
+#[derive(Clone, Copy)]
+struct Fields {
+    key: Field,
+    source: Field,
+    kind: Field,
+    title: Field,
+    url: Field,
+    text: Field,
+    indexed_at: Field,
+}
+
+/// A tantivy index of fetched documents and search hits.
+pub struct ContentIndex {
+    dir: PathBuf,
+    index: Index,
+    reader: IndexReader,
+    fields: Fields,
+    /// Serializes writers within this process
+    write_lock: Mutex<()>,
+}
+
+impl ContentIndex {
+    /// Open the index in `dir`, creating it when it doesn't exist.
+    pub fn open(dir: &Path) -> Result<Self, ConnectorError> {
+        std::fs::create_dir_all(dir)?;
+        let mut builder = Schema::builder();
+        let fields = Fields {
+            key: builder.add_text_field("key", STRING | STORED),
+            source: builder.add_text_field("source", STRING | STORED),
+            kind: builder.add_text_field("kind", STRING | STORED),
+            title: builder.add_text_field("title", TEXT | STORED),
+            url: builder.add_text_field("url", STORED),
+            text: builder.add_text_field("text", TEXT | STORED),
+            indexed_at: builder.add_i64_field("indexed_at", INDEXED | STORED),
+        };
+        let directory = MmapDirectory::open(dir).map_err(index_error)?;
+        let index = Index::open_or_create(directory, builder.build()).map_err(index_error)?;
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()
+            .map_err(index_error)?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            index,
+            reader,
+            fields,
+            write_lock: Mutex::new(()),
+        })
+    }
+
+    /// The index at the configured location, opened once per process.
+    pub fn shared() -> Result<Arc<ContentIndex>, ConnectorError> {
+        static SHARED: OnceCell<Arc<ContentIndex>> = OnceCell::new();
+        SHARED
+            .get_or_try_init(|| ContentIndex::open(&IndexConfig::from_env().dir).map(Arc::new))
+            .cloned()
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Number of entries in the index.
+    pub fn num_docs(&self) -> Result<u64, ConnectorError> {
+        self.reader.reload().map_err(index_error)?;
+        Ok(self.reader.searcher().num_docs())
+    }
+
+    /// Every stored entry, for stores derived from the index such as the vector store.
+    pub fn entries(&self) -> Result<Vec<IndexEntry>, ConnectorError> {
+        let f = self.fields;
+        self.reader.reload().map_err(index_error)?;
+        let searcher = self.reader.searcher();
+        let addresses = searcher
+            .search(&AllQuery, &DocSetCollector)
+            .map_err(index_error)?;
+        let mut entries = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            let doc: TantivyDocument = searcher.doc(address).map_err(index_error)?;
+            let text = |field: Field| {
+                doc.get_first(field)
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            entries.push(IndexEntry {
+                key: text(f.key),
+                source: text(f.source),
+                kind: text(f.kind),
+                title: text(f.title),
+                url: Some(text(f.url)).filter(|u| !u.is_empty()),
+                text: text(f.text),
+                indexed_at: doc
+                    .get_first(f.indexed_at)
+                    .and_then(|v| v.as_i64())
+                    .unwrap_or_default(),
+            });
+        }
+        Ok(entries)
+    }
+
+    /// Add or replace entries and commit. Returns how many were written.
+    pub fn add(&self, items: &[IndexedItem]) -> Result<usize, ConnectorError> {
+        if items.is_empty() {
+            return Ok(0);
+        }
+        let now = Utc::now().timestamp();
+        self.with_writer(|writer, f| {
+            for item in items {
+                writer.delete_term(Term::from_field_text(f.key, &item.key));
+                let mut doc = TantivyDocument::default();
+                doc.add_text(f.key, &item.key);
+                doc.add_text(f.source, &item.source);
+                doc.add_text(f.kind, item.kind);
+                doc.add_text(f.title, &item.title);
+                if let Some(url) = &item.url {
+                    doc.add_text(f.url, url);
+                }
+                doc.add_text(f.text, &item.text);
+                doc.add_i64(f.indexed_at, now);
+                writer.add_document(doc)?;
+            }
+            writer.commit()?;
+            Ok(items.len())
+        })
+    }
+
+    /// Index a connector's tool result: each hit of a search, or the fetched document.
+    pub fn ingest_tool_result(
+        &self,
+        connector: &str,
+        output: &Value,
+    ) -> Result<usize, ConnectorError> {
+        self.add(&items_from_tool_result(connector, output))
+    }
+
+    /// Remove every entry.
+    pub fn clear(&self) -> Result<(), ConnectorError> {
+        self.with_writer(|writer, _| {
+            writer.delete_all_documents()?;
+            writer.commit()?;
+            Ok(())
+        })
+    }
+
+    /// Search titles and text, optionally within one connector's entries.
+    pub fn search(
+        &self,
+        query: &str,
+        source: Option<&str>,
+        limit: usize,
+    ) -> Result<IndexSearchResult, ConnectorError> {
+        let f = self.fields;
+        self.reader.reload().map_err(index_error)?;
+        let searcher = self.reader.searcher();
+
+        let mut parser = QueryParser::for_index(&self.index, vec![f.title, f.text]);
+        parser.set_conjunction_by_default();
+        parser.set_field_boost(f.title, 2.0);
+        let (text_query, _) = parser.parse_query_lenient(query);
+        let parsed: Box<dyn Query> = match source {
+            Some(source) => Box::new(BooleanQuery::new(vec![
+                (Occur::Must, text_query),
+                (
+                    Occur::Must,
+                    Box::new(TermQuery::new(
+                        Term::from_field_text(f.source, source),
+                        IndexRecordOption::Basic,
+                    )) as Box<dyn Query>,
+                ),
+            ])),
+            None => text_query,
+        };
+
+        let limit = limit.clamp(1, MAX_LIMIT);
+        let top = searcher
+            .search(parsed.as_ref(), &TopDocs::with_limit(limit))
+            .map_err(index_error)?;
+        let mut snippets =
+            SnippetGenerator::create(&searcher, parsed.as_ref(), f.text).map_err(index_error)?;
+        snippets.set_max_num_chars(SNIPPET_CHARS);
+
+        let mut hits = Vec::with_capacity(top.len());
+        for (score, address) in top {
+            let doc: TantivyDocument = searcher.doc(address).map_err(index_error)?;
+            let text = |field: Field| {
+                doc.get_first(field)
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            let mut snippet = snippets.snippet_from_doc(&doc).fragment().to_string();
+            if snippet.trim().is_empty() {
+                // Matched on the title only
+                snippet = text(f.text).chars().take(SNIPPET_CHARS).collect();
+            }
+            hits.push(IndexHit {
+                key: text(f.key),
+                source: text(f.source),
+                kind: text(f.kind),
+                title: text(f.title),
+                url: Some(text(f.url)).filter(|u| !u.is_empty()),
+                snippet: snippet.trim().to_string(),
+                score,
+                indexed_at: doc
+                    .get_first(f.indexed_at)
+                    .and_then(|v| v.as_i64())
+                    .and_then(|t| Utc.timestamp_opt(t, 0).single())
+                    .map(|t| t.to_rfc3339()),
+            });
+        }
+
+        Ok(IndexSearchResult {
+            query: query.to_string(),
+            source: source.map(str::to_string),
+            total_documents: searcher.num_docs(),
+            hits,
+        })
+    }
+
+    fn with_writer<T>(
+        &self,
+        write: impl FnOnce(&mut IndexWriter, &Fields) -> tantivy::Result<T>,
+    ) -> Result<T, ConnectorError> {
+        let _guard = self
+            .write_lock
+            .lock()
+            .map_err(|_| ConnectorError::Other("index write lock poisoned".to_string()))?;
+        // Dropped on return, which releases the directory lock for other processes
+        let mut writer: IndexWriter = self
+            .index
+            .writer_with_num_threads(1, WRITER_HEAP_BYTES)
+            .map_err(index_error)?;
+        write(&mut writer, &self.fields).map_err(index_error)
+    }
+}
+
+/// The shared index when `ARIVU_INDEX` asks for tool results to be ingested.
+pub fn auto_ingest_index() -> Option<Arc<ContentIndex>> {
+    if !IndexConfig::from_env().auto_ingest {
+        return None;
+    }
+    match ContentIndex::shared() {
+        Ok(index) => Some(index),
+        Err(e) => {
+            warn!("Not indexing tool results: {}", e);
+            None
+        }
+    }
+}
+
+/// Entries for a tool result: one per hit when it holds a list of results, otherwise one for the
+/// document it maps to. Items without a URL, id or title can't be keyed and are skipped.
+pub fn items_from_tool_result(connector: &str, output: &Value) -> Vec<IndexedItem> {
+    let hits = output.as_object().and_then(|map| {
+        RESULT_LIST_KEYS
+            .iter()
+            .find_map(|k| map.get(*k).and_then(Value::as_array))
+    });
+    match hits {
+        Some(hits) => hits
+            .iter()
+            .filter(|hit| hit.is_object())
+            .filter_map(|hit| {
+                let document = Document::from_tool_output(connector, hit);
+                item_from_document(connector, "search_result", &document)
+            })
+            .collect(),
+        None => {
+            let document = Document::from_tool_output(connector, output);
+            item_from_document(connector, "document", &document)
+                .into_iter()
+                .collect()
+        }
+    }
+}
+
+fn item_from_document(
+    connector: &str,
+    kind: &'static str,
+    document: &Document,
+) -> Option<IndexedItem> {
+    let mut text = if document.text.trim().is_empty() {
+        let sections: Vec<&str> = document.sections.iter().map(|s| s.text.as_str()).collect();
+        sections.join("\n\n")
+    } else {
+        document.text.clone()
+    };
+    if text.trim().is_empty() {
+        // Search hits often carry only a snippet, which the document model leaves in metadata
+        text = ["snippet", "excerpt", "content_snippet"]
+            .iter()
+            .find_map(|k| document.metadata.get(*k).and_then(Value::as_str))
+            .unwrap_or_default()
+            .to_string();
+    }
+    if document.title.trim().is_empty() && text.trim().is_empty() {
+        return None;
+    }
+
+    Some(IndexedItem {
+        key: document.key()?,
+        source: connector.to_string(),
+        kind,
+        title: document.title.clone(),
+        url: document.url.clone(),
+        text: text.chars().take(MAX_TEXT_CHARS).collect(),
+    })
+}
+
+/// The MCP definition of the search tool.
+pub fn tool() -> Tool {
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "query": {
+                "type": "string",
+                "description": "Words to find in previously fetched titles and text; supports \"phrases\", OR and -exclusions"
+            },
+            "source": {
+                "type": "string",
+                "description": "Only return entries from this connector (e.g. \"web\", \"arxiv\")"
+            },
+            "limit": {
+                "type": "integer",
+                "minimum": 1,
+                "maximum": MAX_LIMIT,
+                "description": "Maximum hits to return (default 10)"
+            }
+        },
+        "required": ["query"]
+    });
+    Tool {
+        name: TOOL_NAME.into(),
+        title: Some("Search Local Index".into()),
+        description: Some(
+            "Search content fetched or found earlier (works offline). Entries are added when \
+             ARIVU_INDEX=on."
+                .into(),
+        ),
+        input_schema: Arc::new(
+            schema
+                .as_object()
+                .expect("Schema must be an object")
+                .clone(),
+        ),
+        output_schema: None,
+        annotations: None,
+        icons: None,
+    }
+}
+
+/// Read the query, source filter and limit from tool-call arguments.
+pub fn parse_args(
+    args: &Map<String, Value>,
+) -> Result<(String, Option<String>, usize), ConnectorError> {
+    let query = args
+        .get("query")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|q| !q.is_empty())
+        .ok_or_else(|| ConnectorError::InvalidParams("query is required".to_string()))?;
+    let source = args
+        .get("source")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string);
+    let limit = args
+        .get("limit")
+        .and_then(Value::as_u64)
+        .map_or(DEFAULT_LIMIT, |n| n as usize);
+    Ok((query.to_string(), source, limit))
+}
+
+fn index_error(e: impl std::fmt::Display) -> ConnectorError {
+    ConnectorError::Other(format!("index: {}", e))
+}
+
+/// Add a successful tool result to `index`. Failures are only logged.
+async fn ingest_result(
+    index: Arc<ContentIndex>,
+    connector: &'static str,
+    tool_name: &str,
+    result: &CallToolResult,
+) {
+    if tool_name.starts_with("auth_") || result.is_error == Some(true) {
+        return;
+    }
+    let output = match &result.structured_content {
+        Some(value) => value.clone(),
+        None => {
+            let text: Vec<&str> = result
+                .content
+                .iter()
+                .filter_map(|c| match &c.raw {
+                    RawContent::Text(t) => Some(t.text.as_str()),
+                    _ => None,
+                })
+                .collect();
+            serde_json::from_str(&text.join("\n")).unwrap_or(Value::Null)
+        }
+    };
+    // Committing touches the disk; keep it off the async workers
+    match tokio::task::spawn_blocking(move || index.ingest_tool_result(connector, &output)).await {
+        Ok(Ok(count)) => debug!("Indexed {} entries from {}/{}", count, connector, tool_name),
+        Ok(Err(e)) => debug!("Indexing {}/{} failed: {}", connector, tool_name, e),
+        Err(e) => debug!("Indexing {}/{} failed: {}", connector, tool_name, e),
+    }
+}
+
+/// Connector wrapper that adds every successful tool result the config allows to the index.
+pub struct IndexingConnector {
+    inner: Box<dyn Connector>,
+    index: Arc<ContentIndex>,
+    config: IndexConfig,
+}
+
+impl IndexingConnector {
+    pub fn new(inner: Box<dyn Connector>, index: Arc<ContentIndex>, config: IndexConfig) -> Self {
+        Self {
+            inner,
+            index,
+            config,
+        }
+    }
+}
+
+#[async_trait]
+impl Connector for IndexingConnector {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn description(&self) -> &'static str {
+        self.inner.description()
+    }
+
+    fn credential_provider(&self) -> &'static str {
+        self.inner.credential_provider()
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        self.inner.capabilities().await
+    }
+
+    async fn initialize(
+        &self,
+        request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        self.inner.initialize(request).await
+    }
+
+    async fn list_resources(
+        &self,
+        request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        self.inner.list_resources(request).await
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        self.inner.read_resource(request).await
+    }
+
+    async fn list_tools(
+        &self,
+        request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        self.inner.list_tools(request).await
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        let tool_name = request.name.to_string();
+        let result = self.inner.call_tool(request).await?;
+        if self.config.allows(self.name(), &tool_name) {
+            ingest_result(Arc::clone(&self.index), self.name(), &tool_name, &result).await;
+        }
+        Ok(result)
+    }
+
+    fn detached_call(
+        &self,
+        request: &CallToolRequestParam,
+    ) -> Option<BoxFuture<'static, Result<CallToolResult, ConnectorError>>> {
+        let detached = self.inner.detached_call(request)?;
+        let tool_name = request.name.to_string();
+        let connector = self.name();
+        let index = self
+            .config
+            .allows(connector, &tool_name)
+            .then(|| Arc::clone(&self.index));
+        Some(Box::pin(async move {
+            let result = detached.await?;
+            if let Some(index) = index {
+                ingest_result(index, connector, &tool_name, &result).await;
+            }
+            Ok(result)
+        }))
+    }
+
+    async fn list_prompts(
+        &self,
+        request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        self.inner.list_prompts(request).await
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        self.inner.get_prompt(name).await
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        self.inner.get_auth_details().await
+    }
+
+    async fn set_auth_details(&mut self, details: AuthDetails) -> Result<(), ConnectorError> {
+        self.inner.set_auth_details(details).await
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        self.inner.test_auth().await
+    }
+
+    fn config_schema(&self) -> crate::capabilities::ConnectorConfigSchema {
+        self.inner.config_schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_search_hits_and_documents_to_entries() {
+        let search = json!({
+            "query": "rust",
+            "results": [
+                {"title": "Rust 1.80", "url": "https://blog.rust-lang.org/1.80", "snippet": "LazyCell and LazyLock"},
+                {"id": 42, "title": "Ask HN: Rust jobs?"},
+                {"score": 3},
+            ]
+        });
+        let items = items_from_tool_result("hackernews", &search);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].key, "https://blog.rust-lang.org/1.80");
+        assert_eq!(items[0].kind, "search_result");
+        assert_eq!(items[0].text, "LazyCell and LazyLock");
+        assert_eq!(items[1].key, "hackernews:42");
+
+        let page = json!({
+            "title": "Tantivy",
+            "url": "https://github.com/quickwit-oss/tantivy",
+            "content": "A full-text search engine library written in Rust."
+        });
+        let items = items_from_tool_result("web", &page);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].kind, "document");
+        assert!(items[0].text.starts_with("A full-text search engine"));
+
+        assert!(items_from_tool_result("web", &json!({"ok": true})).is_empty());
+    }
+
+    #[test]
+    fn leaves_personal_data_out_unless_included() {
+        let mut config = IndexConfig {
+            dir: PathBuf::from("unused"),
+            auto_ingest: true,
+            include: Vec::new(),
+        };
+        assert!(config.allows("web", "fetch"));
+        assert!(config.allows("reddit", "search"));
+        assert!(!config.allows_connector("google-gmail"));
+        assert!(!config.allows("imap", "fetch_message"));
+        assert!(!config.allows("reddit", "get_inbox"));
+
+        config.include = vec!["imap".to_string(), "reddit".to_string()];
+        assert!(config.allows("imap", "fetch_message"));
+        assert!(config.allows("reddit", "get_saved"));
+        assert!(!config.allows("apple-messages", "list_messages"));
+    }
+
+    #[test]
+    fn leaves_credentialed_connectors_out_unless_included() {
+        let mut config = IndexConfig {
+            dir: PathBuf::from("unused"),
+            auto_ingest: true,
+            include: Vec::new(),
+        };
+        for connector in ["salesforce", "sql", "s3", "tracker", "github", "confluence"] {
+            assert!(!config.allows_connector(connector), "{}", connector);
+        }
+        // A connector that isn't known to be public stays out too
+        assert!(!config.allows_connector("some-new-connector"));
+        assert!(!config.allows("x", "get_direct_message_conversations"));
+        assert!(config.allows("x", "search_tweets"));
+
+        config.include = vec!["salesforce".to_string()];
+        assert!(config.allows("salesforce", "query"));
+        assert!(!config.allows_connector("hubspot"));
+    }
+
+    #[test]
+    fn searches_and_replaces_entries_by_key() {
+        let dir = std::env::temp_dir().join(format!("arivu-index-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let index = ContentIndex::open(&dir).unwrap();
+
+        let page = |text: &str| IndexedItem {
+            key: "https://example.com/fasting".to_string(),
+            source: "web".to_string(),
+            kind: "document",
+            title: "Intermittent fasting".to_string(),
+            url: Some("https://example.com/fasting".to_string()),
+            text: text.to_string(),
+        };
+        let paper = IndexedItem {
+            key: "arxiv:2301.07041".to_string(),
+            source: "arxiv".to_string(),
+            kind: "document",
+            title: "Attention in transformers".to_string(),
+            url: None,
+            text: "Self-attention lets every token look at every other token.".to_string(),
+        };
+        index
+            .add(&[page("Fasting extended lifespan in mice."), paper])
+            .unwrap();
+        index
+            .add(&[page("Time-restricted eating improved sleep in mice.")])
+            .unwrap();
+        assert_eq!(index.num_docs().unwrap(), 2);
+        let mut keys: Vec<String> = index
+            .entries()
+            .unwrap()
+            .into_iter()
+            .map(|e| e.key)
+            .collect();
+        keys.sort();
+        assert_eq!(keys, ["arxiv:2301.07041", "https://example.com/fasting"]);
+
+        let found = index.search("mice sleep", None, 10).unwrap();
+        assert_eq!(found.hits.len(), 1);
+        assert_eq!(found.hits[0].source, "web");
+        assert!(found.hits[0].snippet.contains("sleep"), "{:?}", found.hits);
+        assert!(index.search("lifespan", None, 10).unwrap().hits.is_empty());
+
+        assert_eq!(
+            index.search("token", Some("arxiv"), 10).unwrap().hits.len(),
+            1
+        );
+        assert!(index
+            .search("token", Some("web"), 10)
+            .unwrap()
+            .hits
+            .is_empty());
+
+        index.clear().unwrap();
+        assert_eq!(index.num_docs().unwrap(), 0);
+        drop(index);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn indexes_results_of_detached_calls() {
+        let dir = std::env::temp_dir().join(format!("arivu-index-detached-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let index = Arc::new(ContentIndex::open(&dir).unwrap());
+        let stub = crate::test_support::StubConnector::new(
+            "web",
+            json!({
+                "title": "Tantivy",
+                "url": "https://github.com/quickwit-oss/tantivy",
+                "content": "A full-text search engine library written in Rust."
+            }),
+        );
+        let release = Arc::clone(&stub.release);
+        let config = IndexConfig {
+            dir: dir.clone(),
+            auto_ingest: true,
+            include: Vec::new(),
+        };
+        let connector = IndexingConnector::new(Box::new(stub), Arc::clone(&index), config);
+        let request = |name: &'static str| CallToolRequestParam {
+            name: name.into(),
+            arguments: None,
+        };
+
+        assert!(connector.detached_call(&request("get")).is_none());
+        let call = connector
+            .detached_call(&request("wait"))
+            .expect("wait runs detached");
+        release.notify_one();
+        call.await.unwrap();
+        assert_eq!(index.num_docs().unwrap(), 1);
+
+        drop(connector);
+        drop(index);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn leaves_results_of_credentialed_connectors_out_of_the_index() {
+        let dir = std::env::temp_dir().join(format!("arivu-index-private-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let index = Arc::new(ContentIndex::open(&dir).unwrap());
+        let page = |url: &str| {
+            json!({
+                "title": "Quarterly pipeline",
+                "url": url,
+                "content": "Renewal forecast for the enterprise accounts."
+            })
+        };
+        let mut registry = crate::ProviderRegistry::new();
+        for (name, url) in [
+            ("salesforce", "https://example.my.salesforce.com/006"),
+            ("web", "https://example.com/pipeline"),
+        ] {
+            let stub = crate::test_support::StubConnector::new(name, page(url));
+            registry.register_provider(Box::new(stub));
+        }
+        let config = IndexConfig {
+            dir: dir.clone(),
+            auto_ingest: true,
+            include: Vec::new(),
+        };
+        let registry = registry.with_index(Arc::clone(&index), &config);
+
+        for name in ["salesforce", "web"] {
+            let connector = registry.get_provider(name).unwrap();
+            let request = CallToolRequestParam {
+                name: "get".into(),
+                arguments: None,
+            };
+            crate::call_locked_connector(connector, request)
+                .await
+                .unwrap();
+        }
+        let keys: Vec<String> = index
+            .entries()
+            .unwrap()
+            .into_iter()
+            .map(|e| e.key)
+            .collect();
+        assert_eq!(keys, ["https://example.com/pipeline"]);
+
+        drop(registry);
+        drop(index);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,607 @@
+//! Language detection and optional translation for fetched documents.
+//!
+//! Detection is a local heuristic: the dominant script decides most non-Latin languages, and
+//! common function words tell Latin-script languages apart. It needs no network access and runs
+//! on every fetched [`Document`]. Translation only happens when a backend is configured:
+//!
+//! | Variable | Meaning |
+//! |----------|---------|
+//! | `ARIVU_TRANSLATE_BACKEND` | `deepl` or `openai` (any OpenAI-compatible chat API) |
+//! | `DEEPL_API_KEY` | DeepL key; `:fx` keys use the free API host |
+//! | `ARIVU_TRANSLATE_API_KEY` | Key for the `openai` backend (falls back to `OPENAI_API_KEY`) |
+//! | `ARIVU_TRANSLATE_BASE_URL` | Chat API base URL (default `https://api.openai.com/v1`) |
+//! | `ARIVU_TRANSLATE_MODEL` | Chat model (default `gpt-4o-mini`) |
+//! | `ARIVU_TRANSLATE_TO` | Default target language for `arivu fetch --translate` |
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+use crate::document::Document;
+use crate::error::ConnectorError;
+
+/// Leading characters looked at when detecting; enough to settle the script and word counts.
+const DETECT_SAMPLE_CHARS: usize = 5_000;
+/// Fewer letters than this is too little to tell languages apart.
+const MIN_LETTERS: usize = 20;
+/// Longer texts are translated up to this point and marked truncated.
+const MAX_TRANSLATE_CHARS: usize = 40_000;
+/// Text is sent in paragraph-aligned chunks of about this size.
+const CHUNK_CHARS: usize = 4_000;
+const DEFAULT_OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
+const DEFAULT_OPENAI_MODEL: &str = "gpt-4o-mini";
+
+/// Function words that are frequent in running text and rare in other languages.
+const STOPWORDS: &[(&str, &[&str])] = &[
+    (
+        "en",
+        &[
+            "the", "and", "of", "to", "is", "in", "that", "it", "for", "with", "was", "are",
+            "this", "on", "be", "as", "by", "have", "from", "not",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "el", "la", "los", "las", "que", "de", "y", "en", "es", "por", "para", "con", "una",
+            "del", "se", "su", "al", "como", "más", "pero",
+        ],
+    ),
+    (
+        "fr",
+        &[
+            "le", "la", "les", "des", "et", "est", "une", "dans", "que", "pour", "pas", "sur",
+            "au", "du", "avec", "il", "qui", "ce", "sont", "mais",
+        ],
+    ),
+    (
+        "de",
+        &[
+            "der", "die", "das", "und", "ist", "nicht", "ein", "eine", "zu", "den", "mit", "von",
+            "sich", "auch", "auf", "für", "dem", "des", "im", "wird",
+        ],
+    ),
+    (
+        "it",
+        &[
+            "il", "di", "che", "e", "la", "per", "non", "una", "sono", "del", "della", "con",
+            "gli", "anche", "come", "più", "ma", "nel", "questo", "essere",
+        ],
+    ),
+    (
+        "pt",
+        &[
+            "o", "os", "de", "que", "e", "do", "da", "em", "um", "uma", "não", "para", "com",
+            "por", "as", "dos", "mais", "mas", "foi", "são",
+        ],
+    ),
+    (
+        "nl",
+        &[
+            "de", "het", "een", "en", "van", "is", "dat", "niet", "op", "te", "zijn", "voor",
+            "met", "die", "ook", "aan", "er", "maar", "om", "wordt",
+        ],
+    ),
+    (
+        "sv",
+        &[
+            "och", "att", "det", "som", "en", "är", "på", "för", "med", "till", "av", "inte",
+            "den", "har", "jag", "om", "ett", "var", "men", "så",
+        ],
+    ),
+    (
+        "pl",
+        &[
+            "i", "w", "nie", "się", "na", "jest", "że", "do", "to", "z", "jak", "ale", "co", "tak",
+            "po", "od", "przez", "dla", "są", "być",
+        ],
+    ),
+    (
+        "tr",
+        &[
+            "ve", "bir", "bu", "da", "de", "için", "ile", "çok", "olarak", "daha", "gibi", "ama",
+            "ne", "değil", "olan", "var", "en", "kadar", "sonra", "her",
+        ],
+    ),
+    (
+        "id",
+        &[
+            "yang", "dan", "di", "ini", "itu", "dengan", "untuk", "tidak", "dari", "dalam", "akan",
+            "pada", "juga", "ke", "karena", "ada", "bisa", "oleh", "atau", "saya",
+        ],
+    ),
+    (
+        "ro",
+        &[
+            "și", "în", "de", "la", "care", "cu", "nu", "pe", "este", "o", "un", "să", "mai",
+            "din", "pentru", "ca", "sunt", "dar", "fost", "acest",
+        ],
+    ),
+];
+
+/// A detected language with an ISO 639-1 code.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DetectedLanguage {
+    pub code: String,
+    pub name: String,
+    /// 0.0-1.0: the dominant script's share, or the winning language's share of matched words
+    pub confidence: f64,
+}
+
+/// A translated copy of a document's title and text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Translation {
+    /// Target language as requested (e.g. "en")
+    pub language: String,
+    pub backend: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub title: String,
+    pub text: String,
+    /// Whether the text was cut before translating
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+/// English name of a language code, or the code itself when unknown.
+pub fn language_name(code: &str) -> &str {
+    match code {
+        "ar" => "Arabic",
+        "bn" => "Bengali",
+        "de" => "German",
+        "el" => "Greek",
+        "en" => "English",
+        "es" => "Spanish",
+        "fa" => "Persian",
+        "fr" => "French",
+        "he" => "Hebrew",
+        "hi" => "Hindi",
+        "id" => "Indonesian",
+        "it" => "Italian",
+        "ja" => "Japanese",
+        "ko" => "Korean",
+        "nl" => "Dutch",
+        "pl" => "Polish",
+        "pt" => "Portuguese",
+        "ro" => "Romanian",
+        "ru" => "Russian",
+        "sv" => "Swedish",
+        "ta" => "Tamil",
+        "th" => "Thai",
+        "tr" => "Turkish",
+        "uk" => "Ukrainian",
+        "zh" => "Chinese",
+        other => other,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Script {
+    Latin,
+    Cyrillic,
+    Greek,
+    Arabic,
+    Hebrew,
+    Devanagari,
+    Bengali,
+    Tamil,
+    Thai,
+    Hangul,
+    Kana,
+    Han,
+}
+
+fn script(c: char) -> Option<Script> {
+    Some(match c as u32 {
+        0x41..=0x5A | 0x61..=0x7A | 0xC0..=0x24F => Script::Latin,
+        0x370..=0x3FF => Script::Greek,
+        0x400..=0x4FF => Script::Cyrillic,
+        0x590..=0x5FF => Script::Hebrew,
+        0x600..=0x6FF | 0x750..=0x77F => Script::Arabic,
+        0x900..=0x97F => Script::Devanagari,
+        0x980..=0x9FF => Script::Bengali,
+        0xB80..=0xBFF => Script::Tamil,
+        0xE00..=0xE7F => Script::Thai,
+        0x1100..=0x11FF | 0xAC00..=0xD7AF => Script::Hangul,
+        0x3040..=0x30FF => Script::Kana,
+        0x4E00..=0x9FFF | 0x3400..=0x4DBF => Script::Han,
+        _ => return None,
+    })
+}
+
+/// Detect the language of `text`. Returns `None` for very short or unrecognized text.
+pub fn detect(text: &str) -> Option<DetectedLanguage> {
+    let sample: String = text.chars().take(DETECT_SAMPLE_CHARS).collect();
+    let mut counts: Vec<(Script, usize)> = Vec::new();
+    for script in sample.chars().filter_map(script) {
+        match counts.iter_mut().find(|(s, _)| *s == script) {
+            Some((_, n)) => *n += 1,
+            None => counts.push((script, 1)),
+        }
+    }
+    let letters: usize = counts.iter().map(|(_, n)| n).sum();
+    if letters < MIN_LETTERS {
+        return None;
+    }
+    let count = |script: Script| {
+        counts
+            .iter()
+            .find(|(s, _)| *s == script)
+            .map_or(0, |(_, n)| *n)
+    };
+    let (dominant, dominant_count) = counts.iter().copied().max_by_key(|(_, n)| *n)?;
+    let share = dominant_count as f64 / letters as f64;
+
+    // Japanese mixes kana with kanji; any real share of kana settles it
+    let cjk = count(Script::Han) + count(Script::Kana);
+    if cjk > letters / 3 && count(Script::Kana) * 10 >= cjk {
+        return Some(language("ja", cjk as f64 / letters as f64));
+    }
+    let code = match dominant {
+        Script::Latin => return detect_latin(&sample),
+        Script::Cyrillic => {
+            let ukrainian = sample.chars().filter(|c| "іїєґІЇЄҐ".contains(*c)).count();
+            if ukrainian * 100 >= dominant_count {
+                "uk"
+            } else {
+                "ru"
+            }
+        }
+        Script::Arabic => {
+            let persian = sample.chars().filter(|c| "پچژگی".contains(*c)).count();
+            if persian * 50 >= dominant_count {
+                "fa"
+            } else {
+                "ar"
+            }
+        }
+        Script::Greek => "el",
+        Script::Hebrew => "he",
+        Script::Devanagari => "hi",
+        Script::Bengali => "bn",
+        Script::Tamil => "ta",
+        Script::Thai => "th",
+        Script::Hangul => "ko",
+        Script::Kana => "ja",
+        Script::Han => "zh",
+    };
+    Some(language(code, share))
+}
+
+fn detect_latin(sample: &str) -> Option<DetectedLanguage> {
+    let words: Vec<String> = sample
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    let mut scores: Vec<(&str, usize)> = STOPWORDS
+        .iter()
+        .map(|(code, list)| {
+            let hits = words.iter().filter(|w| list.contains(&w.as_str())).count();
+            (*code, hits)
+        })
+        .collect();
+    // Stable sort keeps the table order on ties
+    scores.sort_by_key(|(_, hits)| std::cmp::Reverse(*hits));
+    let (code, best) = scores[0];
+    if best < 2 {
+        return None;
+    }
+    let total: usize = scores.iter().map(|(_, n)| n).sum();
+    Some(language(code, best as f64 / total as f64))
+}
+
+fn language(code: &str, confidence: f64) -> DetectedLanguage {
+    DetectedLanguage {
+        code: code.to_string(),
+        name: language_name(code).to_string(),
+        confidence: (confidence * 100.0).round() / 100.0,
+    }
+}
+
+/// Base language of a tag such as `en-US` or `PT_br`.
+fn base_code(tag: &str) -> String {
+    tag.split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+/// A configured translation service.
+#[derive(Debug, Clone)]
+pub enum TranslationBackend {
+    DeepL {
+        api_key: String,
+    },
+    /// Any OpenAI-compatible chat completions API, including local servers
+    OpenAi {
+        base_url: String,
+        api_key: Option<String>,
+        model: String,
+    },
+}
+
+impl TranslationBackend {
+    /// The backend named by `ARIVU_TRANSLATE_BACKEND`, or `None` when it is unset.
+    pub fn from_env() -> Result<Option<Self>, ConnectorError> {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+        let Some(backend) = var("ARIVU_TRANSLATE_BACKEND") else {
+            return Ok(None);
+        };
+        match backend.trim().to_ascii_lowercase().as_str() {
+            "deepl" => {
+                let api_key = var("DEEPL_API_KEY").ok_or_else(|| {
+                    ConnectorError::Authentication(
+                        "ARIVU_TRANSLATE_BACKEND=deepl needs DEEPL_API_KEY".to_string(),
+                    )
+                })?;
+                Ok(Some(Self::DeepL { api_key }))
+            }
+            "openai" | "llm" => Ok(Some(Self::OpenAi {
+                base_url: var("ARIVU_TRANSLATE_BASE_URL")
+                    .unwrap_or_else(|| DEFAULT_OPENAI_BASE_URL.to_string())
+                    .trim_end_matches('/')
+                    .to_string(),
+                api_key: var("ARIVU_TRANSLATE_API_KEY").or_else(|| var("OPENAI_API_KEY")),
+                model: var("ARIVU_TRANSLATE_MODEL")
+                    .unwrap_or_else(|| DEFAULT_OPENAI_MODEL.to_string()),
+            })),
+            other => Err(ConnectorError::InvalidInput(format!(
+                "Unknown ARIVU_TRANSLATE_BACKEND '{}' (expected deepl or openai)",
+                other
+            ))),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Self::DeepL { .. } => "deepl".to_string(),
+            Self::OpenAi { model, .. } => format!("openai:{}", model),
+        }
+    }
+
+    /// Translate `text` into `target`, chunk by chunk. Returns the translation and whether the
+    /// input was cut at the translation budget.
+    pub async fn translate(
+        &self,
+        text: &str,
+        target: &str,
+    ) -> Result<(String, bool), ConnectorError> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(120))
+            .build()?;
+        let (chunks, truncated) = chunk_text(text, CHUNK_CHARS, MAX_TRANSLATE_CHARS);
+        let mut out = Vec::with_capacity(chunks.len());
+        for chunk in chunks {
+            out.push(match self {
+                Self::DeepL { api_key } => deepl(&client, api_key, chunk, target).await?,
+                Self::OpenAi {
+                    base_url,
+                    api_key,
+                    model,
+                } => chat(&client, base_url, api_key.as_deref(), model, chunk, target).await?,
+            });
+        }
+        Ok((out.join("\n\n"), truncated))
+    }
+}
+
+async fn deepl(
+    client: &reqwest::Client,
+    api_key: &str,
+    text: &str,
+    target: &str,
+) -> Result<String, ConnectorError> {
+    let host = if api_key.ends_with(":fx") {
+        "https://api-free.deepl.com"
+    } else {
+        "https://api.deepl.com"
+    };
+    // DeepL wants a regional variant for English and Portuguese
+    let target_lang = match base_code(target).as_str() {
+        "en" if !target.contains(['-', '_']) => "EN-US".to_string(),
+        "pt" if !target.contains(['-', '_']) => "PT-PT".to_string(),
+        _ => target.replace('_', "-").to_ascii_uppercase(),
+    };
+    let response = client
+        .post(format!("{}/v2/translate", host))
+        .header("Authorization", format!("DeepL-Auth-Key {}", api_key))
+        .json(&json!({ "text": [text], "target_lang": target_lang }))
+        .send()
+        .await?;
+    let body = checked_json(response, "DeepL").await?;
+    body.pointer("/translations/0/text")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| ConnectorError::Other("DeepL returned no translation".to_string()))
+}
+
+async fn chat(
+    client: &reqwest::Client,
+    base_url: &str,
+    api_key: Option<&str>,
+    model: &str,
+    text: &str,
+    target: &str,
+) -> Result<String, ConnectorError> {
+    let instructions = format!(
+        "Translate the user's text into {}. Keep Markdown formatting, links, code and numbers \
+unchanged. Reply with the translation only.",
+        language_name(&base_code(target))
+    );
+    let mut request = client
+        .post(format!("{}/chat/completions", base_url))
+        .json(&json!({
+            "model": model,
+            "temperature": 0,
+            "messages": [
+                {"role": "system", "content": instructions},
+                {"role": "user", "content": text}
+            ]
+        }));
+    if let Some(key) = api_key {
+        request = request.bearer_auth(key);
+    }
+    let body = checked_json(request.send().await?, "Translation API").await?;
+    body.pointer("/choices/0/message/content")
+        .and_then(Value::as_str)
+        .map(|s| s.trim().to_string())
+        .ok_or_else(|| ConnectorError::Other("Translation API returned no content".to_string()))
+}
+
+/// The JSON body of a successful response, or the service's error message as an error.
+pub(crate) async fn checked_json(
+    response: reqwest::Response,
+    service: &str,
+) -> Result<Value, ConnectorError> {
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if status.is_success() {
+        return Ok(body);
+    }
+    let message = body
+        .pointer("/error/message")
+        .or_else(|| body.get("message"))
+        .and_then(Value::as_str)
+        .unwrap_or("request failed");
+    Err(match status.as_u16() {
+        401 | 403 => ConnectorError::Authentication(format!("{}: {}", service, message)),
+        _ => ConnectorError::Other(format!("{} error {}: {}", service, status, message)),
+    })
+}
+
+/// Split `text` at paragraph breaks into chunks of about `chunk_chars`, stopping at `max_chars`.
+/// Paragraphs longer than a chunk are split at character boundaries.
+pub(crate) fn chunk_text(text: &str, chunk_chars: usize, max_chars: usize) -> (Vec<&str>, bool) {
+    let mut chunks = Vec::new();
+    let mut used = 0;
+    let mut rest = text.trim();
+    while !rest.is_empty() {
+        if used >= max_chars {
+            return (chunks, true);
+        }
+        let limit = chunk_chars.min(max_chars - used);
+        let hard_end = rest
+            .char_indices()
+            .nth(limit)
+            .map_or(rest.len(), |(i, _)| i);
+        let end = if hard_end == rest.len() || rest[hard_end..].starts_with("\n\n") {
+            hard_end
+        } else {
+            rest[..hard_end]
+                .rfind("\n\n")
+                .filter(|&i| i > 0)
+                .unwrap_or(hard_end)
+        };
+        let chunk = rest[..end].trim();
+        if !chunk.is_empty() {
+            chunks.push(chunk);
+        }
+        used += rest[..end].chars().count();
+        rest = rest[end..].trim_start();
+    }
+    (chunks, false)
+}
+
+/// The post-processing stage for fetched documents: detect the language of the text (or the
+/// title when there is no text) and, when `translate` names a backend and target language that
+/// differs from the detected one, attach a translation.
+pub async fn process_document(
+    doc: &mut Document,
+    translate: Option<(&TranslationBackend, &str)>,
+) -> Result<(), ConnectorError> {
+    let sample = if doc.text.trim().is_empty() {
+        doc.title.as_str()
+    } else {
+        doc.text.as_str()
+    };
+    doc.language = detect(sample);
+
+    let Some((backend, target)) = translate else {
+        return Ok(());
+    };
+    let same = doc
+        .language
+        .as_ref()
+        .is_some_and(|l| l.code == base_code(target));
+    if same || sample.trim().is_empty() {
+        return Ok(());
+    }
+    let title = if doc.title.trim().is_empty() {
+        String::new()
+    } else {
+        backend.translate(&doc.title, target).await?.0
+    };
+    let (text, truncated) = if doc.text.trim().is_empty() {
+        (String::new(), false)
+    } else {
+        backend.translate(&doc.text, target).await?
+    };
+    doc.translation = Some(Translation {
+        language: target.to_string(),
+        backend: backend.name(),
+        title,
+        text,
+        truncated,
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_scripts_and_latin_languages() {
+        let code = |text: &str| detect(text).map(|l| l.code);
+        assert_eq!(
+            code("The committee said that the report was not ready for release this week."),
+            Some("en".to_string())
+        );
+        assert_eq!(
+            code("El gobierno anunció que las nuevas medidas entrarán en vigor para todos los ciudadanos."),
+            Some("es".to_string())
+        );
+        assert_eq!(
+            code(
+                "Die Regierung hat angekündigt, dass die neuen Regeln nicht vor dem Sommer gelten."
+            ),
+            Some("de".to_string())
+        );
+        assert_eq!(
+            code(
+                "Le gouvernement a annoncé que les nouvelles mesures sont en vigueur dans le pays."
+            ),
+            Some("fr".to_string())
+        );
+        assert_eq!(
+            code("Правительство объявило о новых мерах поддержки для малого бизнеса."),
+            Some("ru".to_string())
+        );
+        assert_eq!(
+            code("政府は来年から新しい制度を導入すると発表しました。"),
+            Some("ja".to_string())
+        );
+        assert_eq!(
+            code("政府宣布将从明年开始实施新的制度和政策措施。"),
+            Some("zh".to_string())
+        );
+        assert_eq!(code("OK"), None);
+
+        let en = detect("The cat sat on the mat and it was happy with the warm sun.").unwrap();
+        assert_eq!(en.name, "English");
+        assert!(en.confidence > 0.5);
+    }
+
+    #[test]
+    fn chunks_at_paragraphs_within_budget() {
+        let text = "aaaa\n\nbbbb\n\ncccc";
+        assert_eq!(
+            chunk_text(text, 10, 100),
+            (vec!["aaaa\n\nbbbb", "cccc"], false)
+        );
+        assert_eq!(chunk_text(text, 6, 8), (vec!["aaaa", "bbbb"], true));
+        assert_eq!(chunk_text("abcdef", 4, 100), (vec!["abcd", "ef"], false));
+        assert_eq!(base_code("pt_BR"), "pt");
+    }
+}
@@ -4,21 +4,42 @@ pub mod auth_store;
 pub mod capabilities; // Keep for config schema
 pub mod connectors;
 pub mod cpu_pool;
+#[cfg(feature = "doc-text")]
+pub mod doc_text;
+pub mod document;
+#[cfg(feature = "embeddings")]
+pub mod embeddings;
 pub mod error;
+pub mod export;
 pub mod federated;
+pub mod fetch_fallback;
+#[cfg(feature = "index")]
+pub mod index;
+pub mod language;
 pub mod logging;
 pub mod mcp_server;
 pub mod metered;
 pub mod oauth;
 pub mod oauth_client;
+pub mod output_pipeline;
+#[cfg(feature = "pdf")]
+pub mod pdf;
 pub mod prompts;
+pub mod research;
 pub mod resolver;
 pub mod resources;
+pub mod result_store;
+#[cfg(test)]
+mod test_support;
+pub mod tokens;
+pub mod tool_context;
 pub mod tools;
 pub mod transport;
 pub mod usage;
 pub mod usage_context;
 pub mod utils;
+#[cfg(feature = "embeddings")]
+pub mod vector_store;
 use std::sync::Arc;
 
 // Re-export types from rmcp that users of your library might need
@@ -32,6 +53,7 @@ pub use rmcp::model::{
 use crate::error::ConnectorError;
 use crate::metered::MeteredConnector;
 use async_trait::async_trait;
+use futures::future::BoxFuture;
 #[cfg(all(feature = "browser-cookies", target_os = "macos"))]
 pub use rookie::safari;
 #[cfg(feature = "browser-cookies")]
@@ -91,6 +113,17 @@ pub trait Connector: Send + Sync {
         &self,
         request: CallToolRequestParam,
     ) -> Result<CallToolResult, ConnectorError>;
+
+    /// Take over a call that waits a long time (e.g. for new mail) as a future that owns what it
+    /// needs, so the caller can release the connector's lock before awaiting it. Returns `None`
+    /// (the default) for calls that should go through [`Connector::call_tool`].
+    fn detached_call(
+        &self,
+        _request: &CallToolRequestParam,
+    ) -> Option<BoxFuture<'static, Result<CallToolResult, ConnectorError>>> {
+        None
+    }
+
     async fn list_prompts(
         &self,
         request: Option<PaginatedRequestParam>,
@@ -104,6 +137,21 @@ pub trait Connector: Send + Sync {
     async fn test_auth(&self) -> Result<(), ConnectorError>;
     fn config_schema(&self) -> ConnectorConfigSchema;
 }
+
+/// Call a tool on a registry connector, holding its lock only as long as the call needs it.
+pub async fn call_locked_connector(
+    connector: &tokio::sync::Mutex<Box<dyn Connector>>,
+    request: CallToolRequestParam,
+) -> Result<CallToolResult, ConnectorError> {
+    let c = connector.lock().await;
+    let detached = c.detached_call(&request);
+    if let Some(call) = detached {
+        drop(c);
+        return call.await;
+    }
+    c.call_tool(request).await
+}
+
 // ProviderRegistry and ServerInfo remain the same
 
 pub struct ProviderRegistry {
@@ -125,6 +173,14 @@ impl ProviderRegistry {
         );
     }
 
+    /// A registry sharing this one's connectors, for long work that shouldn't hold a lock on it.
+    pub fn snapshot(&self) -> ProviderRegistry {
+        ProviderRegistry {
+            providers: self.providers.clone(),
+            aliases: self.aliases.clone(),
+        }
+    }
+
     pub fn register_alias(&mut self, alias: &str, canonical_name: &str) {
         self.aliases
             .insert(alias.to_string(), canonical_name.to_string());
@@ -151,6 +207,40 @@ impl ProviderRegistry {
         registry
     }
 
+    /// Wrap every connector `config` allows so its tool results are added to `index`.
+    #[cfg(feature = "index")]
+    pub fn with_index(
+        self,
+        index: Arc<index::ContentIndex>,
+        config: &index::IndexConfig,
+    ) -> ProviderRegistry {
+        let mut registry = ProviderRegistry::new();
+        registry.aliases = self.aliases;
+        for (name, provider) in self.providers {
+            if !config.allows_connector(&name) {
+                registry.providers.insert(name, provider);
+                continue;
+            }
+            match Arc::try_unwrap(provider) {
+                Ok(mutex) => {
+                    let inner = mutex.into_inner();
+                    let wrapped: Box<dyn Connector> = Box::new(index::IndexingConnector::new(
+                        inner,
+                        index.clone(),
+                        config.clone(),
+                    ));
+                    registry
+                        .providers
+                        .insert(name, Arc::new(tokio::sync::Mutex::new(wrapped)));
+                }
+                Err(provider) => {
+                    registry.providers.insert(name, provider);
+                }
+            }
+        }
+        registry
+    }
+
     pub fn get_provider(&self, name: &str) -> Option<&Arc<tokio::sync::Mutex<Box<dyn Connector>>>> {
         // First try direct lookup
         if let Some(provider) = self.providers.get(name) {
@@ -263,6 +353,240 @@ pub async fn build_registry_enabled_only() -> ProviderRegistry {
         }
     }
 
+    #[cfg(feature = "crossref")]
+    {
+        if let Ok(connector) =
+            connectors::crossref::CrossrefConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "confluence")]
+    {
+        if let Ok(connector) =
+            connectors::confluence::ConfluenceConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "crates-io")]
+    {
+        if let Ok(connector) =
+            connectors::crates_io::CratesIoConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "dockerhub")]
+    {
+        if let Ok(connector) =
+            connectors::dockerhub::DockerHubConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "openapi")]
+    {
+        if let Ok(connector) =
+            connectors::openapi::OpenApiConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "graphql")]
+    {
+        if let Ok(connector) =
+            connectors::graphql::GraphqlConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "newsletters")]
+    {
+        if let Ok(connector) =
+            connectors::newsletters::NewslettersConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "sentry")]
+    {
+        if let Ok(connector) =
+            connectors::sentry::SentryConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "pagerduty")]
+    {
+        if let Ok(connector) =
+            connectors::pagerduty::PagerDutyConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "inbox")]
+    {
+        if let Ok(connector) =
+            connectors::inbox::InboxConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "paper-library")]
+    {
+        if let Ok(connector) =
+            connectors::paper_library::PaperLibraryConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "pypi")]
+    {
+        if let Ok(connector) = connectors::pypi::PypiConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "npm")]
+    {
+        if let Ok(connector) = connectors::npm::NpmConnector::new(auth::AuthDetails::new()).await {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "zoom")]
+    {
+        if let Ok(connector) = connectors::zoom::ZoomConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "sql")]
+    {
+        if let Ok(connector) = connectors::sql::SqlConnector::new(auth::AuthDetails::new()).await {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "s3")]
+    {
+        if let Ok(connector) = connectors::s3::S3Connector::new(auth::AuthDetails::new()).await {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "dblp")]
+    {
+        if let Ok(connector) = connectors::dblp::DblpConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "market-data")]
+    {
+        if let Ok(connector) =
+            connectors::market_data::MarketDataConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "coingecko")]
+    {
+        if let Ok(connector) =
+            connectors::coingecko::CoinGeckoConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "openlibrary")]
+    {
+        if let Ok(connector) =
+            connectors::openlibrary::OpenLibraryConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "podcasts")]
+    {
+        if let Ok(connector) =
+            connectors::podcasts::PodcastsConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "twitch")]
+    {
+        if let Ok(connector) =
+            connectors::twitch::TwitchConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "vimeo")]
+    {
+        if let Ok(connector) =
+            connectors::vimeo::VimeoConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "salesforce")]
+    {
+        if let Ok(connector) =
+            connectors::salesforce::SalesforceConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "hubspot")]
+    {
+        if let Ok(connector) =
+            connectors::hubspot::HubSpotConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "airtable")]
+    {
+        if let Ok(connector) =
+            connectors::airtable::AirtableConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
+    #[cfg(feature = "box")]
+    {
+        if let Ok(connector) =
+            connectors::box_com::BoxConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+        }
+    }
+
     #[cfg(feature = "rss")]
     {
         if let Ok(connector) = connectors::rss::RssConnector::new(auth::AuthDetails::new()).await {
@@ -404,6 +728,18 @@ pub async fn build_registry_enabled_only() -> ProviderRegistry {
         registry.register_provider(Box::new(connector));
     }
 
+    #[cfg(all(target_os = "linux", feature = "tracker"))]
+    {
+        let connector = connectors::tracker::TrackerConnector::new();
+        registry.register_provider(Box::new(connector));
+    }
+
+    #[cfg(feature = "browser-history")]
+    {
+        let connector = connectors::browser_history::BrowserHistoryConnector::new();
+        registry.register_provider(Box::new(connector));
+    }
+
     // Apple Ecosystem connectors (macOS only)
     #[cfg(all(target_os = "macos", feature = "apple-mail"))]
     {
@@ -435,6 +771,19 @@ pub async fn build_registry_enabled_only() -> ProviderRegistry {
         registry.register_provider(Box::new(connector));
     }
 
+    #[cfg(all(target_os = "macos", feature = "apple-calendar"))]
+    {
+        let connector = connectors::apple_calendar::AppleCalendarConnector::new();
+        registry.register_provider(Box::new(connector));
+    }
+
+    // Windows desktop connectors
+    #[cfg(all(target_os = "windows", feature = "outlook-desktop"))]
+    {
+        let connector = connectors::outlook_desktop::OutlookDesktopConnector::new();
+        registry.register_provider(Box::new(connector));
+    }
+
     // EXPERIMENTAL - NOT READY: HealthKit data store not available on macOS
     // See: arivu_core/src/connectors/apple_health/NOT_READY.md
     // #[cfg(all(target_os = "macos", feature = "apple-health"))]
@@ -565,6 +914,25 @@ pub async fn build_registry_enabled_only() -> ProviderRegistry {
             registry.register_provider(Box::new(connector));
         }
     }
+    #[cfg(feature = "brave-search")]
+    {
+        if let Ok(connector) =
+            connectors::brave_search::BraveSearchConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+            registry.register_alias("brave", "brave-search");
+        }
+    }
+    #[cfg(feature = "ddg-search")]
+    {
+        if let Ok(connector) =
+            connectors::ddg_search::DdgSearchConnector::new(auth::AuthDetails::new()).await
+        {
+            registry.register_provider(Box::new(connector));
+            registry.register_alias("ddg", "ddg-search");
+            registry.register_alias("duckduckgo", "ddg-search");
+        }
+    }
 
     #[cfg(feature = "localfs")]
     {
@@ -572,6 +940,11 @@ pub async fn build_registry_enabled_only() -> ProviderRegistry {
         registry.register_provider(Box::new(connector));
     }
 
+    #[cfg(feature = "index")]
+    if let Some(index) = index::auto_ingest_index() {
+        return registry.with_index(index, &index::IndexConfig::from_env());
+    }
+
     registry
 }
 
@@ -586,3 +959,45 @@ pub struct ServerInfo {
     pub name: String,
     pub description: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::StubConnector;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn detached_calls_release_the_connector_lock() {
+        let stub = StubConnector::new("stub", json!({ "ok": true }));
+        let received = Arc::clone(&stub.received);
+        let release = Arc::clone(&stub.release);
+        let mut registry = ProviderRegistry::new();
+        registry.register_provider(Box::new(stub));
+        let connector = Arc::clone(registry.get_provider("stub").unwrap());
+        let request = |name: &'static str| CallToolRequestParam {
+            name: name.into(),
+            arguments: None,
+        };
+
+        let waiting = tokio::spawn({
+            let connector = Arc::clone(&connector);
+            async move { call_locked_connector(&connector, request("wait")).await }
+        });
+        while received.lock().unwrap().is_empty() {
+            tokio::task::yield_now().await;
+        }
+
+        // `wait` is still pending, yet other calls can take the lock
+        let get = call_locked_connector(&connector, request("get"));
+        tokio::time::timeout(Duration::from_secs(5), get)
+            .await
+            .expect("connector lock held during a detached call")
+            .unwrap();
+        assert!(!waiting.is_finished());
+
+        release.notify_one();
+        waiting.await.unwrap().unwrap();
+        assert_eq!(received.lock().unwrap().len(), 2);
+    }
+}
@@ -1,11 +1,15 @@
 use serde_json::{json, Value};
 use std::sync::Arc;
 use tokio::sync::Mutex;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 use crate::{
     auth::AuthDetails,
     capabilities::{ConnectorConfigSchema, FieldType},
+    output_pipeline::{self, OutputPipeline, PipelineStep},
+    research,
+    result_store::{self, ResultStore, ResultStoreConfig},
+    tokens,
     utils::structured_result_with_text,
     ConnectorError, ProviderRegistry,
 };
@@ -22,16 +26,40 @@ struct AuthState {
 pub struct McpServer {
     registry: Arc<Mutex<ProviderRegistry>>,
     auth_status: Arc<Mutex<std::collections::HashMap<String, AuthState>>>,
+    output_pipeline: OutputPipeline,
+    result_store: Arc<Mutex<ResultStore>>,
 }
 
 impl McpServer {
     pub fn new(registry: Arc<Mutex<ProviderRegistry>>) -> Self {
+        let output_pipeline = OutputPipeline::from_env().unwrap_or_else(|e| {
+            warn!("Ignoring output pipeline settings: {}", e);
+            OutputPipeline::default()
+        });
+        let store_config = ResultStoreConfig::from_env().unwrap_or_else(|e| {
+            warn!("Ignoring result resource settings: {}", e);
+            ResultStoreConfig::default()
+        });
         Self {
             registry,
             auth_status: Arc::new(Mutex::new(std::collections::HashMap::new())),
+            output_pipeline,
+            result_store: Arc::new(Mutex::new(ResultStore::new(store_config))),
         }
     }
 
+    /// Replace the normalization applied to tool results (see [`OutputPipeline`]).
+    pub fn with_output_pipeline(mut self, pipeline: OutputPipeline) -> Self {
+        self.output_pipeline = pipeline;
+        self
+    }
+
+    /// Replace when and how large results are stored as resources (see [`ResultStore`]).
+    pub fn with_result_store(mut self, config: ResultStoreConfig) -> Self {
+        self.result_store = Arc::new(Mutex::new(ResultStore::new(config)));
+        self
+    }
+
     /// Get aggregated capabilities from all connectors
     pub async fn get_capabilities(&self) -> ServerCapabilities {
         let registry = self.registry.lock().await;
@@ -51,6 +79,10 @@ impl McpServer {
                 capabilities.prompts = conn_caps.prompts;
             }
         }
+        // Oversized tool results are served as resources
+        if capabilities.resources.is_none() && self.result_store.lock().await.config().enabled() {
+            capabilities.resources = Some(Default::default());
+        }
 
         capabilities
     }
@@ -97,6 +129,8 @@ impl McpServer {
             }
         }
 
+        all_resources.extend(self.result_store.lock().await.list());
+
         Ok(ListResourcesResult {
             resources: all_resources,
             next_cursor: None,
@@ -108,6 +142,10 @@ impl McpServer {
         &self,
         request: ReadResourceRequestParam,
     ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        if result_store::is_result_uri(&request.uri) {
+            return self.result_store.lock().await.read(&request.uri);
+        }
+
         let registry = self.registry.lock().await;
 
         // Try each connector until one handles the resource
@@ -142,6 +180,7 @@ impl McpServer {
                         .into_iter()
                         .map(|mut tool| {
                             tool.name = format!("{}/{}", connector_name, tool.name).into();
+                            tool.input_schema = with_max_result_tokens_arg(&tool.input_schema);
                             tool
                         })
                         .collect();
@@ -156,6 +195,15 @@ impl McpServer {
             }
         }
 
+        // Built-in tools that work across connectors
+        let mut research_tool = research::tool();
+        research_tool.input_schema = with_max_result_tokens_arg(&research_tool.input_schema);
+        all_tools.push(research_tool);
+        #[cfg(feature = "index")]
+        all_tools.push(crate::index::tool());
+        #[cfg(feature = "embeddings")]
+        all_tools.push(crate::vector_store::tool());
+
         // Add generic auth tools per connector following MCP tool semantics
         for (connector_name, connector) in registry.providers.iter() {
             let c = connector.lock().await;
@@ -382,6 +430,55 @@ impl McpServer {
             }
         }
 
+        if request.name == research::TOOL_NAME {
+            let mut args = request.arguments.unwrap_or_default();
+            let max_tokens = tokens::take_max_result_tokens(&mut args);
+            let (question, options) = research::parse_args(&args)?;
+            // Research runs for a while; work on a snapshot so the registry stays available
+            let registry = self.registry.lock().await.snapshot();
+            let bundle = research::research(&registry, &question, &options).await;
+            let result = structured_result_with_text(&bundle, None)?;
+            return Ok(self
+                .finish_result(research::TOOL_NAME, max_tokens, result)
+                .await);
+        }
+
+        #[cfg(feature = "index")]
+        if request.name == crate::index::TOOL_NAME {
+            let args = request.arguments.unwrap_or_default();
+            let (query, source, limit) = crate::index::parse_args(&args)?;
+            let found = tokio::task::spawn_blocking(move || {
+                let index = crate::index::ContentIndex::shared()?;
+                index.search(&query, source.as_deref(), limit)
+            })
+            .await
+            .map_err(|e| ConnectorError::Other(format!("index: {}", e)))??;
+            let result = structured_result_with_text(&found, None)?;
+            return Ok(self
+                .finish_result(crate::index::TOOL_NAME, None, result)
+                .await);
+        }
+
+        #[cfg(feature = "embeddings")]
+        if request.name == crate::vector_store::TOOL_NAME {
+            let args = request.arguments.unwrap_or_default();
+            let (query, source, limit) = crate::index::parse_args(&args)?;
+            let backend = crate::vector_store::backend_from_env()?;
+            let index = crate::index::ContentIndex::shared()?;
+            let found = crate::vector_store::semantic_search(
+                index,
+                &backend,
+                &query,
+                source.as_deref(),
+                limit,
+            )
+            .await?;
+            let result = structured_result_with_text(&found, None)?;
+            return Ok(self
+                .finish_result(crate::vector_store::TOOL_NAME, None, result)
+                .await);
+        }
+
         // Parse connector name from tool name (format: "connector/tool")
         let parts: Vec<&str> = request.name.split('/').collect();
         if parts.len() != 2 {
@@ -394,17 +491,28 @@ impl McpServer {
         let connector_name = parts[0];
         let tool_name = parts[1];
 
-        let registry = self.registry.lock().await;
-
-        if let Some(connector) = registry.providers.get(connector_name) {
+        // Release the registry before calling so concurrent calls to other
+        // connectors are not serialized behind a long-running tool
+        let connector = self
+            .registry
+            .lock()
+            .await
+            .providers
+            .get(connector_name)
+            .cloned();
+
+        if let Some(connector) = connector {
+            // The budget is ours to apply; tools may have a `max_tokens` of their own
+            let mut arguments = request.arguments;
+            let max_tokens = arguments.as_mut().and_then(tokens::take_max_result_tokens);
             // Create a new request with the unprefixed tool name
             let unprefixed_request = CallToolRequestParam {
                 name: tool_name.to_string().into(),
-                arguments: request.arguments,
+                arguments,
             };
 
-            let c = connector.lock().await;
-            c.call_tool(unprefixed_request).await
+            let result = crate::call_locked_connector(&connector, unprefixed_request).await?;
+            Ok(self.finish_result(&request.name, max_tokens, result).await)
         } else {
             Err(ConnectorError::InvalidInput(format!(
                 "Unknown connector: {}",
@@ -413,6 +521,54 @@ impl McpServer {
         }
     }
 
+    /// Fit a tool result to the caller's `max_result_tokens`, or store it as a resource when it is too
+    /// large to return, then normalize it.
+    async fn finish_result(
+        &self,
+        name: &str,
+        max_tokens: Option<usize>,
+        mut result: CallToolResult,
+    ) -> CallToolResult {
+        let report = if let Some(max_tokens) = max_tokens {
+            // The caller set a budget: drop deep and late list items, then cut strings
+            if let Some(value) = result.structured_content.as_mut() {
+                let omitted = tokens::fit_value(value, max_tokens);
+                if omitted > 0 {
+                    if let Some(map) = value.as_object_mut() {
+                        map.insert("omitted_items".to_string(), json!(omitted));
+                    }
+                }
+            }
+            self.output_pipeline
+                .with_max_tokens(max_tokens)
+                .apply(&mut result)
+        } else {
+            let mut store = self.result_store.lock().await;
+            if store.should_store(output_pipeline::result_chars(&result)) {
+                // Keep everything for the resource; truncating would lose what it's there for
+                self.output_pipeline
+                    .without(PipelineStep::Truncate)
+                    .apply(&mut result);
+                if store.should_store(output_pipeline::result_chars(&result)) {
+                    debug!("Storing {} output as a resource", name);
+                    return store.store(name, &result);
+                }
+            }
+            drop(store);
+            self.output_pipeline.apply(&mut result)
+        };
+        if let Some(report) = report {
+            debug!(
+                "Normalized {} output: {} -> {} chars{}",
+                name,
+                report.chars_before,
+                report.chars_after,
+                if report.truncated { " (truncated)" } else { "" }
+            );
+        }
+        result
+    }
+
     /// Handle list_prompts request - aggregates from all connectors
     pub async fn handle_list_prompts(
         &self,
@@ -483,6 +639,23 @@ impl McpServer {
     }
 }
 
+/// Advertise the per-call `max_result_tokens` output budget on every tool.
+fn with_max_result_tokens_arg(schema: &Arc<JsonObject>) -> Arc<JsonObject> {
+    let mut schema = (**schema).clone();
+    if let Value::Object(properties) = schema.entry("properties").or_insert_with(|| json!({})) {
+        properties
+            .entry(tokens::MAX_RESULT_TOKENS_ARG)
+            .or_insert_with(|| {
+                json!({
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "Approximate cap on the size of the result in tokens. Deeply nested items (e.g. replies) are dropped first, then long text is cut."
+                })
+            });
+    }
+    Arc::new(schema)
+}
+
 fn config_schema_to_jsonschema(
     schema: &ConnectorConfigSchema,
 ) -> serde_json::Map<String, serde_json::Value> {
@@ -771,3 +944,79 @@ impl JsonRpcHandler {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::StubConnector;
+
+    fn server() -> McpServer {
+        McpServer::new(Arc::new(Mutex::new(ProviderRegistry::new())))
+            .with_output_pipeline(OutputPipeline::default())
+            .with_result_store(ResultStoreConfig::default())
+    }
+
+    #[tokio::test]
+    async fn returns_results_unchanged_when_the_pipeline_is_off() {
+        // A CSV export the steps would mangle: tabs, padding, "boilerplate" rows, smart quotes
+        let row = "id\tname  \tnote\n1\tSkip to content\t\u{201c}quoted\u{201d}\u{a0}text\n\n\n\n";
+        let content = row.repeat(200);
+        assert!(content.len() > output_pipeline::DEFAULT_MIN_CHARS);
+        let result = structured_result_with_text(&json!({ "content": content }), None).unwrap();
+
+        let finished = server()
+            .finish_result("files/get", None, result.clone())
+            .await;
+        assert_eq!(
+            serde_json::to_value(&finished).unwrap(),
+            serde_json::to_value(&result).unwrap()
+        );
+
+        // The same result is rewritten once the steps are turned on
+        let all = OutputPipeline {
+            steps: output_pipeline::parse_steps(output_pipeline::ALL_STEPS).unwrap(),
+            ..OutputPipeline::default()
+        };
+        let normalized = server()
+            .with_output_pipeline(all)
+            .finish_result("files/get", None, result.clone())
+            .await;
+        assert_ne!(
+            serde_json::to_value(&normalized).unwrap(),
+            serde_json::to_value(&result).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn applies_the_result_budget_without_passing_it_to_the_connector() {
+        let items: Vec<Value> = (0..50)
+            .map(|i| json!({ "id": i, "text": "word ".repeat(20) }))
+            .collect();
+        let stub = StubConnector::new("stub", json!({ "items": items }));
+        let received = Arc::clone(&stub.received);
+        let mut registry = ProviderRegistry::new();
+        registry.register_provider(Box::new(stub));
+        let server = McpServer::new(Arc::new(Mutex::new(registry)))
+            .with_output_pipeline(OutputPipeline::default());
+
+        let args = json!({ "query": "rust", "max_tokens": 64, "max_result_tokens": 200 });
+        let result = server
+            .handle_call_tool(CallToolRequestParam {
+                name: "stub/get".into(),
+                arguments: args.as_object().cloned(),
+            })
+            .await
+            .unwrap();
+
+        // The tool's own `max_tokens` is left alone
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].name, "get");
+        let expected = json!({ "query": "rust", "max_tokens": 64 });
+        assert_eq!(received[0].arguments, expected.as_object().cloned());
+
+        let structured = result.structured_content.unwrap();
+        assert!(structured["omitted_items"].as_u64().unwrap() > 0);
+        assert!(tokens::count_value_tokens(&structured) <= 300);
+    }
+}
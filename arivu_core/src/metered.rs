@@ -1,7 +1,9 @@
+use std::future::Future;
 use std::sync::Arc;
 use std::time::Instant;
 
 use async_trait::async_trait;
+use futures::future::BoxFuture;
 use rmcp::model::Meta;
 use serde_json::{Map, Value};
 use tracing::debug;
@@ -24,6 +26,103 @@ impl MeteredConnector {
     pub fn new(inner: Box<dyn Connector>, usage: Arc<UsageManager>) -> Self {
         Self { inner, usage }
     }
+
+    /// Strip the call's `_meta` from `request` and capture what its usage event needs.
+    fn start_call(&self, mut request: CallToolRequestParam) -> (CallToolRequestParam, MeteredCall) {
+        let tool_name = request.name.to_string();
+        let model = request
+            .arguments
+            .as_ref()
+            .and_then(|args| args.get("model"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+        let call_meta = extract_call_meta(&mut request.arguments);
+        let run_id = call_meta
+            .run_id
+            .or_else(|| current_context().map(|ctx| ctx.run_id))
+            .unwrap_or_else(|| new_id("run"));
+        let request_id = call_meta.request_id.unwrap_or_else(|| new_id("req"));
+        let call = MeteredCall {
+            usage: Arc::clone(&self.usage),
+            connector: self.name(),
+            provider: self.credential_provider(),
+            tool_name,
+            model,
+            run_id,
+            request_id,
+            key_id: call_meta.key_id,
+        };
+        (request, call)
+    }
+}
+
+/// A call in progress and the details its usage event is recorded with.
+struct MeteredCall {
+    usage: Arc<UsageManager>,
+    connector: &'static str,
+    provider: &'static str,
+    tool_name: String,
+    model: Option<String>,
+    run_id: String,
+    request_id: String,
+    key_id: Option<String>,
+}
+
+impl MeteredCall {
+    /// Await `call` and record its usage.
+    async fn finish(
+        mut self,
+        call: impl Future<Output = Result<CallToolResult, ConnectorError>>,
+    ) -> Result<CallToolResult, ConnectorError> {
+        let start = Instant::now();
+        let result = call.await;
+        let duration_ms = start.elapsed().as_millis() as u64;
+
+        match result {
+            Ok(mut ok) => {
+                if let Some(Value::String(m)) =
+                    ok.structured_content.as_ref().and_then(|v| v.get("model"))
+                {
+                    self.model = Some(m.clone());
+                }
+                let (event, meta) = self.usage.estimate_event(
+                    self.connector,
+                    &self.tool_name,
+                    self.provider,
+                    &self.run_id,
+                    &self.request_id,
+                    self.key_id.clone(),
+                    "ok",
+                    duration_ms,
+                    ok.structured_content.as_ref(),
+                    self.model.as_deref(),
+                );
+                if let Err(err) = self.usage.store.record(&event) {
+                    debug!("usage record failed: {}", err);
+                }
+                ok.meta = merge_meta(ok.meta, meta);
+                Ok(ok)
+            }
+            Err(err) => {
+                let (event, _meta) = self.usage.estimate_event(
+                    self.connector,
+                    &self.tool_name,
+                    self.provider,
+                    &self.run_id,
+                    &self.request_id,
+                    self.key_id,
+                    "error",
+                    duration_ms,
+                    None,
+                    self.model.as_deref(),
+                );
+                if let Err(store_err) = self.usage.store.record(&event) {
+                    debug!("usage record failed: {}", store_err);
+                }
+                Err(err)
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -74,72 +173,19 @@ impl Connector for MeteredConnector {
 
     async fn call_tool(
         &self,
-        mut request: CallToolRequestParam,
+        request: CallToolRequestParam,
     ) -> Result<CallToolResult, ConnectorError> {
-        let tool_name = request.name.to_string();
-        let mut model = request
-            .arguments
-            .as_ref()
-            .and_then(|args| args.get("model"))
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
-        let call_meta = extract_call_meta(&mut request.arguments);
-        let run_id = call_meta
-            .run_id
-            .or_else(|| current_context().map(|ctx| ctx.run_id))
-            .unwrap_or_else(|| new_id("run"));
-        let request_id = call_meta.request_id.unwrap_or_else(|| new_id("req"));
-        let key_id = call_meta.key_id.clone();
-        let provider = self.credential_provider();
-
-        let start = Instant::now();
-        let result = self.inner.call_tool(request).await;
-        let duration_ms = start.elapsed().as_millis() as u64;
+        let (request, call) = self.start_call(request);
+        call.finish(self.inner.call_tool(request)).await
+    }
 
-        match result {
-            Ok(mut ok) => {
-                if let Some(Value::String(m)) =
-                    ok.structured_content.as_ref().and_then(|v| v.get("model"))
-                {
-                    model = Some(m.clone());
-                }
-                let (event, meta) = self.usage.estimate_event(
-                    self.name(),
-                    &tool_name,
-                    provider,
-                    &run_id,
-                    &request_id,
-                    key_id.clone(),
-                    "ok",
-                    duration_ms,
-                    ok.structured_content.as_ref(),
-                    model.as_deref(),
-                );
-                if let Err(err) = self.usage.store.record(&event) {
-                    debug!("usage record failed: {}", err);
-                }
-                ok.meta = merge_meta(ok.meta, meta);
-                Ok(ok)
-            }
-            Err(err) => {
-                let (event, _meta) = self.usage.estimate_event(
-                    self.name(),
-                    &tool_name,
-                    provider,
-                    &run_id,
-                    &request_id,
-                    key_id,
-                    "error",
-                    duration_ms,
-                    None,
-                    model.as_deref(),
-                );
-                if let Err(store_err) = self.usage.store.record(&event) {
-                    debug!("usage record failed: {}", store_err);
-                }
-                Err(err)
-            }
-        }
+    fn detached_call(
+        &self,
+        request: &CallToolRequestParam,
+    ) -> Option<BoxFuture<'static, Result<CallToolResult, ConnectorError>>> {
+        let (request, call) = self.start_call(request.clone());
+        let detached = self.inner.detached_call(&request)?;
+        Some(Box::pin(call.finish(detached)))
     }
 
     async fn list_prompts(
@@ -215,3 +261,38 @@ fn new_id(prefix: &str) -> String {
     let pid = std::process::id();
     format!("{}-{}-{}-{}", prefix, ts, pid, seq)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::StubConnector;
+    use crate::usage::{InMemoryUsageStore, PricingCatalog, UsageStore};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn forwards_detached_calls_and_records_their_usage() {
+        let store = Arc::new(InMemoryUsageStore::new());
+        let usage = Arc::new(UsageManager::new(
+            store.clone(),
+            PricingCatalog::load_default().unwrap(),
+        ));
+        let stub = StubConnector::new("stub", json!({ "ok": true }));
+        let release = Arc::clone(&stub.release);
+        let metered = MeteredConnector::new(Box::new(stub), usage);
+        let request = |name: &'static str| CallToolRequestParam {
+            name: name.into(),
+            arguments: None,
+        };
+
+        assert!(metered.detached_call(&request("get")).is_none());
+        let call = metered
+            .detached_call(&request("wait"))
+            .expect("wait runs detached");
+        release.notify_one();
+        call.await.unwrap();
+
+        let events = store.load_all().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].tool, "wait");
+    }
+}
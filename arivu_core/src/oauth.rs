@@ -407,3 +407,307 @@ pub async fn google_refresh_token(
             .map(|s| s.to_string()),
     })
 }
+
+/// Refresh a Salesforce access token. Salesforce does not return `expires_in`
+/// (sessions follow the org's timeout policy), but it does return the
+/// `instance_url` that API calls must target.
+pub async fn salesforce_refresh_token(
+    login_url: &str,
+    client_id: &str,
+    client_secret: Option<&str>,
+    refresh_token: &str,
+) -> Result<(OAuthTokens, Option<String>), ConnectorError> {
+    let url = format!("{}/services/oauth2/token", login_url.trim_end_matches('/'));
+    let mut body = vec![
+        ("grant_type", "refresh_token".to_string()),
+        ("client_id", client_id.to_string()),
+        ("refresh_token", refresh_token.to_string()),
+    ];
+    if let Some(cs) = client_secret {
+        if !cs.is_empty() {
+            body.push(("client_secret", cs.to_string()));
+        }
+    }
+    let resp = reqwest::Client::new()
+        .post(url)
+        .form(&body)
+        .send()
+        .await
+        .map_err(ConnectorError::HttpRequest)?;
+    let status = resp.status();
+    let v = resp
+        .json::<serde_json::Value>()
+        .await
+        .map_err(|e| ConnectorError::Other(e.to_string()))?;
+    if !status.is_success() {
+        return Err(ConnectorError::Authentication(format!(
+            "refresh failed: {}",
+            v
+        )));
+    }
+    let tokens = OAuthTokens {
+        access_token: v["access_token"].as_str().unwrap_or_default().to_string(),
+        refresh_token: v
+            .get("refresh_token")
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_string()),
+        expires_in: None,
+        scope: v
+            .get("scope")
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_string()),
+        token_type: v
+            .get("token_type")
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_string()),
+    };
+    let instance_url = v
+        .get("instance_url")
+        .and_then(|s| s.as_str())
+        .map(|s| s.to_string());
+    Ok((tokens, instance_url))
+}
+
+/// Force a Salesforce token refresh and write the new `access_token` (and
+/// `instance_url`, when returned) back into `auth`.
+pub async fn refresh_salesforce_access(
+    auth: &mut HashMap<String, String>,
+) -> Result<String, ConnectorError> {
+    let rt = auth
+        .get("refresh_token")
+        .cloned()
+        .ok_or_else(|| ConnectorError::Authentication("Missing refresh_token".to_string()))?;
+    let client_id = auth.get("client_id").cloned().ok_or_else(|| {
+        ConnectorError::Authentication("Missing client_id for refresh".to_string())
+    })?;
+    let login_url = auth
+        .get("login_url")
+        .cloned()
+        .unwrap_or_else(|| "https://login.salesforce.com".to_string());
+    let client_secret = auth.get("client_secret").cloned();
+    let (tokens, instance_url) =
+        salesforce_refresh_token(&login_url, &client_id, client_secret.as_deref(), &rt).await?;
+    auth.insert("access_token".to_string(), tokens.access_token.clone());
+    if let Some(r) = tokens.refresh_token {
+        auth.insert("refresh_token".to_string(), r);
+    }
+    if let Some(url) = instance_url {
+        auth.insert("instance_url".to_string(), url);
+    }
+    Ok(tokens.access_token)
+}
+
+/// Refresh a Box access token. Box refresh tokens are single-use: the response
+/// always carries a new `refresh_token` that replaces the one just spent.
+pub async fn box_refresh_token(
+    client_id: &str,
+    client_secret: &str,
+    refresh_token: &str,
+) -> Result<OAuthTokens, ConnectorError> {
+    let body = vec![
+        ("grant_type", "refresh_token".to_string()),
+        ("client_id", client_id.to_string()),
+        ("client_secret", client_secret.to_string()),
+        ("refresh_token", refresh_token.to_string()),
+    ];
+    let resp = reqwest::Client::new()
+        .post("https://api.box.com/oauth2/token")
+        .form(&body)
+        .send()
+        .await
+        .map_err(ConnectorError::HttpRequest)?;
+    let status = resp.status();
+    let v = resp
+        .json::<serde_json::Value>()
+        .await
+        .map_err(|e| ConnectorError::Other(e.to_string()))?;
+    if !status.is_success() {
+        return Err(ConnectorError::Authentication(format!(
+            "refresh failed: {}",
+            v
+        )));
+    }
+    Ok(OAuthTokens {
+        access_token: v["access_token"].as_str().unwrap_or_default().to_string(),
+        refresh_token: v
+            .get("refresh_token")
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_string()),
+        expires_in: v.get("expires_in").and_then(|i| i.as_i64()),
+        scope: None,
+        token_type: v
+            .get("token_type")
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_string()),
+    })
+}
+
+async fn zoom_token(
+    client_id: &str,
+    client_secret: &str,
+    form: &[(&str, &str)],
+) -> Result<OAuthTokens, ConnectorError> {
+    let resp = reqwest::Client::new()
+        .post("https://zoom.us/oauth/token")
+        .basic_auth(client_id, Some(client_secret))
+        .form(form)
+        .send()
+        .await
+        .map_err(ConnectorError::HttpRequest)?;
+    let status = resp.status();
+    let v = resp
+        .json::<serde_json::Value>()
+        .await
+        .map_err(|e| ConnectorError::Other(e.to_string()))?;
+    if !status.is_success() {
+        return Err(ConnectorError::Authentication(format!(
+            "refresh failed: {}",
+            v
+        )));
+    }
+    Ok(OAuthTokens {
+        access_token: v["access_token"].as_str().unwrap_or_default().to_string(),
+        refresh_token: v
+            .get("refresh_token")
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_string()),
+        expires_in: v.get("expires_in").and_then(|i| i.as_i64()),
+        scope: v
+            .get("scope")
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_string()),
+        token_type: v
+            .get("token_type")
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_string()),
+    })
+}
+
+/// Refresh a Zoom user OAuth token. Zoom rotates refresh tokens, so callers
+/// must store the returned `refresh_token`.
+pub async fn zoom_refresh_token(
+    client_id: &str,
+    client_secret: &str,
+    refresh_token: &str,
+) -> Result<OAuthTokens, ConnectorError> {
+    zoom_token(
+        client_id,
+        client_secret,
+        &[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ],
+    )
+    .await
+}
+
+/// Get an access token for a Zoom Server-to-Server OAuth app.
+pub async fn zoom_account_token(
+    account_id: &str,
+    client_id: &str,
+    client_secret: &str,
+) -> Result<OAuthTokens, ConnectorError> {
+    zoom_token(
+        client_id,
+        client_secret,
+        &[
+            ("grant_type", "account_credentials"),
+            ("account_id", account_id),
+        ],
+    )
+    .await
+}
+
+pub const REDDIT_REDIRECT_URI: &str = "http://localhost:8080";
+pub const REDDIT_SCOPES: &str = "identity read history mysubreddits privatemessages";
+
+/// Authorization URL for Reddit's installed-app flow. `duration=permanent`
+/// asks for a refresh token so the user only has to approve once.
+pub fn reddit_authorize_url(client_id: &str, state: &str) -> String {
+    format!(
+        "https://www.reddit.com/api/v1/authorize?client_id={}&response_type=code&state={}&redirect_uri={}&duration=permanent&scope={}",
+        urlencoding::encode(client_id),
+        urlencoding::encode(state),
+        urlencoding::encode(REDDIT_REDIRECT_URI),
+        urlencoding::encode(REDDIT_SCOPES)
+    )
+}
+
+async fn reddit_token(
+    client_id: &str,
+    client_secret: &str,
+    form: &[(&str, &str)],
+) -> Result<OAuthTokens, ConnectorError> {
+    // Installed apps have no secret; Reddit expects an empty password then
+    let resp = reqwest::Client::new()
+        .post("https://www.reddit.com/api/v1/access_token")
+        .header("User-Agent", "rzn_datasourcer/0.1.0")
+        .basic_auth(client_id, Some(client_secret))
+        .form(form)
+        .send()
+        .await
+        .map_err(ConnectorError::HttpRequest)?;
+    let status = resp.status();
+    let v = resp
+        .json::<serde_json::Value>()
+        .await
+        .map_err(|e| ConnectorError::Other(e.to_string()))?;
+    // Reddit reports some failures as 200 with an `error` field
+    if !status.is_success() || v.get("error").is_some() {
+        return Err(ConnectorError::Authentication(format!(
+            "token request failed: {}",
+            v
+        )));
+    }
+    Ok(OAuthTokens {
+        access_token: v["access_token"].as_str().unwrap_or_default().to_string(),
+        refresh_token: v
+            .get("refresh_token")
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_string()),
+        expires_in: v.get("expires_in").and_then(|i| i.as_i64()),
+        scope: v
+            .get("scope")
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_string()),
+        token_type: v
+            .get("token_type")
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_string()),
+    })
+}
+
+/// Exchange the `code` from the Reddit redirect for access and refresh tokens.
+pub async fn reddit_exchange_code(
+    client_id: &str,
+    client_secret: &str,
+    code: &str,
+) -> Result<OAuthTokens, ConnectorError> {
+    reddit_token(
+        client_id,
+        client_secret,
+        &[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", REDDIT_REDIRECT_URI),
+        ],
+    )
+    .await
+}
+
+/// Refresh a Reddit access token. Reddit keeps the refresh token unchanged.
+pub async fn reddit_refresh_token(
+    client_id: &str,
+    client_secret: &str,
+    refresh_token: &str,
+) -> Result<OAuthTokens, ConnectorError> {
+    reddit_token(
+        client_id,
+        client_secret,
+        &[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ],
+    )
+    .await
+}
@@ -0,0 +1,604 @@
+//! Normalization of large tool results before they are returned over MCP.
+//!
+//! Page and document text often carries navigation, cookie banners, share widgets, ad labels,
+//! runs of blank lines and invisible Unicode. None of it helps a model and all of it costs
+//! context. When enabled, results larger than a threshold have every long string in them run
+//! through these steps, in this order:
+//!
+//! | Step | Effect |
+//! |------|--------|
+//! | `boilerplate` | Drops lines such as "Skip to content", "Accept all cookies", "Share on X" |
+//! | `unicode` | Folds non-breaking and zero-width spaces, smart quotes, ligatures and decomposed accents |
+//! | `whitespace` | Trims line ends and collapses space runs and blank lines (code blocks are kept) |
+//! | `truncate` | Caps the token count (see [`crate::tokens`]) by cutting the longest strings at paragraph or sentence boundaries |
+//!
+//! | Variable | Meaning |
+//! |----------|---------|
+//! | `ARIVU_MCP_PIPELINE` | Comma-separated steps, `all`, or `off` (default: `off`) |
+//! | `ARIVU_MCP_PIPELINE_MIN_CHARS` | Results smaller than this are returned untouched (default 8000) |
+//! | `ARIVU_MCP_MAX_TOKENS` | Token cap for `truncate` (default 20000) |
+//!
+//! The pipeline is off by default because the steps can't tell prose from raw content: a file
+//! from GitHub or S3, a SQL result or a CSV would lose its tabs, exact characters and any line
+//! that looks like boilerplate. A call that sets `max_result_tokens` still gets `truncate`.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rmcp::model::{CallToolResult, RawContent};
+use serde_json::Value;
+
+use crate::error::ConnectorError;
+use crate::tokens::count_tokens;
+use crate::utils::truncate_chars;
+
+/// Steps used when `ARIVU_MCP_PIPELINE` is not set.
+pub const DEFAULT_STEPS: &str = "off";
+/// Every step, in order; what `ARIVU_MCP_PIPELINE=all` selects.
+pub const ALL_STEPS: &str = "boilerplate,unicode,whitespace,truncate";
+pub const DEFAULT_MIN_CHARS: usize = 8_000;
+pub const DEFAULT_MAX_TOKENS: usize = 20_000;
+
+/// Strings shorter than this (titles, ids, URLs) are never rewritten.
+const MIN_STRING_CHARS: usize = 200;
+/// Truncation never cuts a string below this, even when the cap cannot otherwise be met.
+const MIN_KEPT_CHARS: usize = 500;
+
+/// One step of the pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipelineStep {
+    Boilerplate,
+    Unicode,
+    Whitespace,
+    Truncate,
+}
+
+impl PipelineStep {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PipelineStep::Boilerplate => "boilerplate",
+            PipelineStep::Unicode => "unicode",
+            PipelineStep::Whitespace => "whitespace",
+            PipelineStep::Truncate => "truncate",
+        }
+    }
+}
+
+/// Parse a comma-separated step list such as `whitespace,truncate`. `off` or `none` disables the
+/// pipeline and `all` selects every step. Steps always run in the fixed order above, whatever
+/// order they are listed in.
+pub fn parse_steps(spec: &str) -> Result<Vec<PipelineStep>, String> {
+    let spec = spec.trim();
+    match spec.to_ascii_lowercase().as_str() {
+        "off" | "none" | "false" | "0" => return Ok(Vec::new()),
+        "all" | "on" => return parse_steps(ALL_STEPS),
+        _ => {}
+    }
+    let mut steps = Vec::new();
+    for name in spec.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let step = match name.to_ascii_lowercase().as_str() {
+            "boilerplate" => PipelineStep::Boilerplate,
+            "unicode" => PipelineStep::Unicode,
+            "whitespace" => PipelineStep::Whitespace,
+            "truncate" => PipelineStep::Truncate,
+            other => {
+                return Err(format!(
+                    "Unknown pipeline step '{}' (expected boilerplate, unicode, whitespace or truncate)",
+                    other
+                ))
+            }
+        };
+        if !steps.contains(&step) {
+            steps.push(step);
+        }
+    }
+    steps.sort_by_key(|s| *s as u8);
+    Ok(steps)
+}
+
+/// What [`OutputPipeline::apply`] did to a result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PipelineReport {
+    pub chars_before: usize,
+    pub chars_after: usize,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputPipeline {
+    pub steps: Vec<PipelineStep>,
+    /// Results below this size, in characters, are left alone
+    pub min_chars: usize,
+    /// Estimated token budget for the whole result when `truncate` is on
+    pub max_tokens: usize,
+}
+
+impl Default for OutputPipeline {
+    fn default() -> Self {
+        Self {
+            steps: parse_steps(DEFAULT_STEPS).expect("default steps"),
+            min_chars: DEFAULT_MIN_CHARS,
+            max_tokens: DEFAULT_MAX_TOKENS,
+        }
+    }
+}
+
+impl OutputPipeline {
+    /// A pipeline that returns every result unchanged.
+    pub fn disabled() -> Self {
+        Self {
+            steps: Vec::new(),
+            ..Self::default()
+        }
+    }
+
+    /// Defaults overridden by `ARIVU_MCP_PIPELINE`, `ARIVU_MCP_PIPELINE_MIN_CHARS` and
+    /// `ARIVU_MCP_MAX_TOKENS`.
+    pub fn from_env() -> Result<Self, ConnectorError> {
+        let mut pipeline = Self::default();
+        if let Some(spec) = env_var("ARIVU_MCP_PIPELINE") {
+            pipeline.steps = parse_steps(&spec).map_err(ConnectorError::InvalidInput)?;
+        }
+        if let Some(min) = env_var("ARIVU_MCP_PIPELINE_MIN_CHARS") {
+            pipeline.min_chars = min.parse().map_err(|_| {
+                ConnectorError::InvalidInput(format!(
+                    "ARIVU_MCP_PIPELINE_MIN_CHARS must be a number, got '{}'",
+                    min
+                ))
+            })?;
+        }
+        if let Some(max) = env_var("ARIVU_MCP_MAX_TOKENS") {
+            pipeline.max_tokens = max.parse().map_err(|_| {
+                ConnectorError::InvalidInput(format!(
+                    "ARIVU_MCP_MAX_TOKENS must be a number, got '{}'",
+                    max
+                ))
+            })?;
+        }
+        Ok(pipeline)
+    }
+
+    pub fn has(&self, step: PipelineStep) -> bool {
+        self.steps.contains(&step)
+    }
+
+    /// The same pipeline with `step` left out.
+    pub fn without(&self, step: PipelineStep) -> Self {
+        Self {
+            steps: self.steps.iter().copied().filter(|s| *s != step).collect(),
+            ..self.clone()
+        }
+    }
+
+    /// The pipeline for a call that asked for at most `max_tokens`: truncation is on and applies
+    /// whatever the result's size.
+    pub fn with_max_tokens(&self, max_tokens: usize) -> Self {
+        let mut steps = self.steps.clone();
+        if !steps.contains(&PipelineStep::Truncate) {
+            steps.push(PipelineStep::Truncate);
+        }
+        Self {
+            steps,
+            min_chars: 0,
+            max_tokens,
+        }
+    }
+
+    /// Normalize the text content and structured content of a tool result in place. Returns
+    /// `None` when the result was below `min_chars` or the pipeline is off.
+    pub fn apply(&self, result: &mut CallToolResult) -> Option<PipelineReport> {
+        let total = result_chars(result);
+        let mut strings = Vec::new();
+        for content in result.content.iter_mut() {
+            if let RawContent::Text(t) = &mut content.raw {
+                strings.push(&mut t.text);
+            }
+        }
+        if let Some(value) = result.structured_content.as_mut() {
+            collect_strings(value, &mut strings);
+        }
+        self.run(strings, total)
+    }
+
+    /// [`apply`](Self::apply) for a bare JSON value.
+    pub fn apply_value(&self, value: &mut Value) -> Option<PipelineReport> {
+        let total = value.to_string().chars().count();
+        let mut strings = Vec::new();
+        collect_strings(value, &mut strings);
+        self.run(strings, total)
+    }
+
+    /// The text steps (everything but `truncate`) for a single string.
+    pub fn normalize_text(&self, text: &str) -> String {
+        let mut text = text.to_string();
+        if self.has(PipelineStep::Boilerplate) {
+            text = strip_boilerplate(&text);
+        }
+        if self.has(PipelineStep::Unicode) {
+            text = normalize_unicode(&text);
+        }
+        if self.has(PipelineStep::Whitespace) {
+            text = collapse_whitespace(&text);
+        }
+        text
+    }
+
+    fn run(&self, mut strings: Vec<&mut String>, total_chars: usize) -> Option<PipelineReport> {
+        if self.steps.is_empty() || total_chars < self.min_chars {
+            return None;
+        }
+
+        let long_before: usize = strings.iter().map(|s| s.chars().count()).sum();
+        for s in strings.iter_mut() {
+            if s.chars().count() >= MIN_STRING_CHARS {
+                let normalized = self.normalize_text(s);
+                **s = normalized;
+            }
+        }
+        let lengths: Vec<usize> = strings.iter().map(|s| s.chars().count()).collect();
+        let long_after: usize = lengths.iter().sum();
+        // JSON keys, punctuation and short values; approximate but stable across the steps
+        let overhead = total_chars.saturating_sub(long_before);
+        let mut chars_after = overhead + long_after;
+        // Quotes, colons and braces run about two characters to a token
+        let tokens_after = strings.iter().map(|s| count_tokens(s)).sum::<usize>() + overhead / 2;
+
+        let mut truncated = false;
+        if self.has(PipelineStep::Truncate) && tokens_after > self.max_tokens {
+            // Cut in characters at this result's own characters-per-token ratio
+            let budget =
+                (self.max_tokens as f64 * chars_after as f64 / tokens_after as f64) as usize;
+            let limit = fair_share(&lengths, budget.saturating_sub(overhead)).max(MIN_KEPT_CHARS);
+            for s in strings.iter_mut() {
+                if s.chars().count() > limit {
+                    let cut = truncate_text(s, limit);
+                    **s = cut;
+                    truncated = true;
+                }
+            }
+            chars_after = overhead + strings.iter().map(|s| s.chars().count()).sum::<usize>();
+        }
+
+        Some(PipelineReport {
+            chars_before: total_chars,
+            chars_after,
+            truncated,
+        })
+    }
+}
+
+/// Size of a tool result in characters: its text content plus its serialized structured content.
+pub fn result_chars(result: &CallToolResult) -> usize {
+    let text: usize = result
+        .content
+        .iter()
+        .map(|c| match &c.raw {
+            RawContent::Text(t) => t.text.chars().count(),
+            _ => 0,
+        })
+        .sum();
+    text + result
+        .structured_content
+        .as_ref()
+        .map_or(0, |v| v.to_string().chars().count())
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.trim().is_empty())
+}
+
+fn collect_strings<'a>(value: &'a mut Value, out: &mut Vec<&'a mut String>) {
+    match value {
+        Value::String(s) => out.push(s),
+        Value::Array(items) => items.iter_mut().for_each(|v| collect_strings(v, out)),
+        Value::Object(map) => map.values_mut().for_each(|v| collect_strings(v, out)),
+        _ => {}
+    }
+}
+
+/// The largest per-string length that fits `available` characters in total, so short strings
+/// are kept whole and only the longest ones are cut.
+fn fair_share(lengths: &[usize], available: usize) -> usize {
+    let mut sorted = lengths.to_vec();
+    sorted.sort_unstable();
+    let mut remaining = available;
+    for (i, len) in sorted.iter().enumerate() {
+        let left = sorted.len() - i;
+        if len.saturating_mul(left) > remaining {
+            return remaining / left;
+        }
+        remaining -= len;
+    }
+    usize::MAX
+}
+
+static BOILERPLATE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?ix)^(?:
+            skip\ to\ (?:main\ )?content | skip\ navigation | jump\ to\ (?:navigation|search|content)
+            | toggle\ navigation | (?:open|close|main)\ menu | menu
+            | share | share\ this(?:\ article|\ post|\ page|\ story)? | share\ (?:on|via)\ \w+
+            | tweet | pin\ it | email\ this | print(?:\ this)?(?:\ article|\ page)?
+            | copy\ link | link\ copied
+            | advertisement | sponsored(?:\ content)? | promoted | ad | ads\ by\ .{1,40}
+            | subscribe(?:\ now)? | subscribe\ to\ our\ newsletter | sign\ up\ for\ our\ newsletter.{0,60}
+            | follow\ us(?:\ on\ .{1,40})? | back\ to\ top | scroll\ to\ top
+            | read\ more | continue\ reading | you\ may\ also\ like | recommended(?:\ for\ you)?
+            | related\ (?:articles|posts|stories)
+            | log\ ?in | sign\ ?in | sign\ ?up | register
+            | all\ rights\ reserved\.? | (?:©|\(c\)|copyright)\ ?(?:©\ ?)?(?:\d{4}|.{0,80}all\ rights\ reserved).{0,80}
+        )[.!:]?$",
+    )
+    .expect("boilerplate regex")
+});
+
+static COOKIE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\bcookies?\b.*\b(?:accept|consent|we use|policy|settings|preferences|agree)\b|\b(?:accept|reject|manage)\b.{0,20}\bcookies\b")
+        .expect("cookie regex")
+});
+
+static LINK_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\[[^\]]*\]\([^)]*\)").expect("markdown link regex"));
+
+/// Drop navigation, cookie, share, ad and footer lines. Lines inside code fences are kept.
+pub fn strip_boilerplate(text: &str) -> String {
+    let mut out = Vec::new();
+    let mut in_code = false;
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+            out.push(line);
+            continue;
+        }
+        if in_code || !is_boilerplate(trimmed) {
+            out.push(line);
+        }
+    }
+    out.join("\n")
+}
+
+fn is_boilerplate(line: &str) -> bool {
+    // Markdown list and heading markers don't change what the line says
+    let bare = line.trim_start_matches(['#', '*', '-', '+', '>']).trim();
+    if bare.is_empty() {
+        return false;
+    }
+    if bare.len() <= 120 && BOILERPLATE_RE.is_match(bare) {
+        return true;
+    }
+    if bare.len() <= 300 && COOKIE_RE.is_match(bare) {
+        return true;
+    }
+    is_nav_line(bare)
+}
+
+/// Breadcrumbs and menus: "Home | News | Sport", or a line of nothing but links.
+fn is_nav_line(line: &str) -> bool {
+    let links = LINK_RE.find_iter(line).count();
+    if links >= 3 {
+        let rest = LINK_RE.replace_all(line, "");
+        if rest
+            .chars()
+            .all(|c| c.is_whitespace() || matches!(c, '|' | '•' | '·' | '»' | '/' | '>' | ','))
+        {
+            return true;
+        }
+    }
+    // Markdown table rows also use pipes
+    if line.len() > 120 || line.starts_with('|') || line.ends_with('|') {
+        return false;
+    }
+    let parts: Vec<&str> = line
+        .split(['|', '•', '·', '»'])
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .collect();
+    parts.len() >= 3 && parts.iter().all(|p| p.split_whitespace().count() <= 3)
+}
+
+/// Fold characters that cost tokens without carrying meaning: non-breaking and zero-width
+/// spaces, soft hyphens, smart quotes, ligatures and accents left decomposed by PDF extraction.
+pub fn normalize_unicode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' => {
+                if chars.peek() != Some(&'\n') {
+                    out.push('\n');
+                }
+            }
+            '\u{2028}' | '\u{2029}' => out.push('\n'),
+            '\u{00A0}' | '\u{2000}'..='\u{200A}' | '\u{202F}' | '\u{205F}' | '\u{3000}' => {
+                out.push(' ')
+            }
+            '\u{00AD}' | '\u{200B}' | '\u{2060}' | '\u{FEFF}' => {}
+            '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' => out.push('\''),
+            '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{2033}' => out.push('"'),
+            '\u{2010}' | '\u{2011}' | '\u{2212}' => out.push('-'),
+            '\u{2026}' => out.push_str("..."),
+            '\u{FB00}' => out.push_str("ff"),
+            '\u{FB01}' => out.push_str("fi"),
+            '\u{FB02}' => out.push_str("fl"),
+            '\u{FB03}' => out.push_str("ffi"),
+            '\u{FB04}' => out.push_str("ffl"),
+            _ => match chars.peek().and_then(|&mark| compose(c, mark)) {
+                Some(composed) => {
+                    chars.next();
+                    out.push(composed);
+                }
+                None => out.push(c),
+            },
+        }
+    }
+    out
+}
+
+/// Precomposed form of a Latin letter followed by a combining accent, where Latin-1 has one.
+fn compose(base: char, mark: char) -> Option<char> {
+    let (bases, composed) = match mark {
+        '\u{0300}' => ("AEIOUaeiou", "ÀÈÌÒÙàèìòù"),
+        '\u{0301}' => ("AEIOUYaeiouy", "ÁÉÍÓÚÝáéíóúý"),
+        '\u{0302}' => ("AEIOUaeiou", "ÂÊÎÔÛâêîôû"),
+        '\u{0303}' => ("ANOano", "ÃÑÕãñõ"),
+        '\u{0308}' => ("AEIOUaeiouy", "ÄËÏÖÜäëïöüÿ"),
+        '\u{0327}' => ("Cc", "Çç"),
+        _ => return None,
+    };
+    let idx = bases.chars().position(|b| b == base)?;
+    composed.chars().nth(idx)
+}
+
+/// Trim line ends, collapse runs of spaces and tabs after the indent, and keep at most one
+/// blank line in a row. Code fences are left as they are.
+pub fn collapse_whitespace(text: &str) -> String {
+    let mut out: Vec<String> = Vec::new();
+    let mut in_code = false;
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            out.push(line.trim_end().to_string());
+            continue;
+        }
+        if in_code {
+            out.push(line.to_string());
+            continue;
+        }
+        let trimmed = line.trim_end();
+        if trimmed.is_empty() {
+            if out.last().is_some_and(|l| !l.is_empty()) {
+                out.push(String::new());
+            }
+            continue;
+        }
+        let body = trimmed.trim_start();
+        let indent = &trimmed[..trimmed.len() - body.len()];
+        let mut collapsed = indent.to_string();
+        let mut last_space = false;
+        for c in body.chars() {
+            if c == ' ' || c == '\t' {
+                if !last_space {
+                    collapsed.push(' ');
+                }
+                last_space = true;
+            } else {
+                collapsed.push(c);
+                last_space = false;
+            }
+        }
+        out.push(collapsed);
+    }
+    while out.last().is_some_and(|l| l.is_empty()) {
+        out.pop();
+    }
+    out.join("\n")
+}
+
+/// Cut `text` to at most `max_chars` characters plus a marker, preferring a paragraph break,
+/// then a line break, then a sentence end, then a word boundary in the last fifth of the kept
+/// text. An open code fence is closed.
+pub fn truncate_text(text: &str, max_chars: usize) -> String {
+    let total = text.chars().count();
+    if total <= max_chars {
+        return text.to_string();
+    }
+    let mut window = text.to_string();
+    truncate_chars(&mut window, max_chars);
+    let floor = window
+        .char_indices()
+        .nth(max_chars * 4 / 5)
+        .map_or(0, |(i, _)| i);
+
+    let mut end = window.len();
+    let candidates = [
+        window.rfind("\n\n"),
+        window.rfind('\n'),
+        [". ", "! ", "? ", ".\n"]
+            .iter()
+            .filter_map(|p| window.rfind(p).map(|i| i + 1))
+            .max(),
+        window.rfind(char::is_whitespace),
+    ];
+    if let Some(cut) = candidates.into_iter().flatten().find(|&i| i >= floor) {
+        end = cut;
+    }
+
+    let mut out = window[..end].trim_end().to_string();
+    if out.matches("```").count() % 2 == 1 {
+        out.push_str("\n```");
+    }
+    out.push_str(&format!(
+        "\n\n[truncated: showing {} of {} characters]",
+        out.chars().count(),
+        total
+    ));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_steps_and_normalizes_text() {
+        assert_eq!(
+            parse_steps("truncate, whitespace,truncate").unwrap(),
+            vec![PipelineStep::Whitespace, PipelineStep::Truncate]
+        );
+        assert!(parse_steps("off").unwrap().is_empty());
+        assert!(OutputPipeline::default().steps.is_empty());
+        assert_eq!(parse_steps("all").unwrap(), parse_steps(ALL_STEPS).unwrap());
+        assert!(parse_steps("whitespace,minify").is_err());
+
+        let page = "Skip to content\n[Home](/) | [News](/news) | [Sport](/sport)\n\
+            Home | World | Business | Tech\n\n\n# Title\n\
+            We use cookies to improve your experience. Accept all?\n\
+            The  caf\u{0065}\u{0301}   said \u{201C}hi\u{201D}\u{00A0}to the \u{FB01}sh.   \n\
+            \n\n\n| a | b | c |\n* Share on Twitter\n```\nshare\n  x    y\n```\n\
+            Advertisement\n\u{00A9} 2024 Example Inc.\n";
+        let pipeline = OutputPipeline {
+            steps: parse_steps("all").unwrap(),
+            ..OutputPipeline::default()
+        };
+        let normalized = pipeline.normalize_text(page);
+        assert_eq!(
+            normalized,
+            "# Title\nThe café said \"hi\" to the fish.\n\n| a | b | c |\n\
+             ```\nshare\n  x    y\n```"
+        );
+    }
+
+    #[test]
+    fn truncates_longest_strings_at_boundaries() {
+        let sentence = "This sentence is filler text. ";
+        let body = sentence.repeat(400);
+        let mut value = json!({
+            "title": "Kept whole",
+            "items": [{"text": body.clone()}, {"text": "short"}],
+            "summary": sentence.repeat(30),
+        });
+        let pipeline = OutputPipeline {
+            steps: parse_steps("whitespace,truncate").unwrap(),
+            min_chars: 1_000,
+            max_tokens: 1_000,
+        };
+        let report = pipeline.apply_value(&mut value).unwrap();
+        assert!(report.truncated);
+        assert!(report.chars_after < report.chars_before);
+        assert!(crate::tokens::count_value_tokens(&value) <= 1_100);
+        assert_eq!(value["title"], "Kept whole");
+        assert_eq!(value["summary"], sentence.repeat(30).trim_end());
+        let text = value["items"][0]["text"].as_str().unwrap();
+        assert!(text.contains("filler text.\n\n[truncated: showing"));
+        assert!(text.ends_with(&format!("of {} characters]", body.trim_end().len())));
+
+        let mut large = json!({"text": body});
+        assert!(OutputPipeline::default().apply_value(&mut large).is_none());
+        assert!(pipeline.apply_value(&mut large).is_some());
+
+        let fenced = format!("```\n{}", "code line\n".repeat(100));
+        let cut = truncate_text(&fenced, 300);
+        assert!(cut.contains("code line\n```\n\n[truncated"));
+    }
+}
@@ -0,0 +1,258 @@
+// src/pdf.rs
+//
+// PDF text extraction shared by the connectors that download papers and
+// files (arXiv, Sci-Hub, Drive, mail attachments, document URLs). Text is
+// kept per page so callers can cite page numbers, and can optionally be split
+// into sections at headings. Parsing is CPU-bound; `extract_on_pool` runs it
+// on `cpu_pool`.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConnectorError;
+
+/// Stop after this many pages unless the caller asks otherwise.
+pub const DEFAULT_MAX_PAGES: u32 = 500;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfOptions {
+    pub max_pages: u32,
+    /// Text budget shared across pages, in characters
+    pub max_chars: usize,
+    /// Also split the text into sections at headings
+    pub sections: bool,
+}
+
+impl Default for PdfOptions {
+    fn default() -> Self {
+        Self {
+            max_pages: DEFAULT_MAX_PAGES,
+            max_chars: usize::MAX,
+            sections: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PdfPage {
+    /// 1-based page number in the document
+    pub number: u32,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PdfSection {
+    /// Empty for text before the first heading
+    pub heading: String,
+    /// 1 for top-level sections, 2+ for numbered subsections
+    pub level: u8,
+    /// Page the section starts on
+    pub page: u32,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PdfText {
+    /// Pages in the document, including any past `max_pages`
+    pub page_count: u32,
+    pub pages: Vec<PdfPage>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sections: Vec<PdfSection>,
+    /// Whether pages or text were cut by `max_pages` or `max_chars`
+    pub truncated: bool,
+}
+
+impl PdfText {
+    /// All page text, one page after another.
+    pub fn text(&self) -> String {
+        self.pages
+            .iter()
+            .map(|p| p.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Whether `bytes` look like a PDF. Mirrors and file hosts sometimes answer
+/// with an HTML page instead.
+pub fn is_pdf(bytes: &[u8]) -> bool {
+    bytes.starts_with(b"%PDF")
+}
+
+/// Extract per-page text. Errors for invalid PDFs and for documents with no
+/// text layer.
+pub fn extract(bytes: &[u8], options: &PdfOptions) -> Result<PdfText, ConnectorError> {
+    let doc = lopdf::Document::load_mem(bytes)
+        .map_err(|e| ConnectorError::Other(format!("invalid PDF: {}", e)))?;
+    let page_count = doc.get_pages().len() as u32;
+    let mut truncated = page_count > options.max_pages;
+
+    // Page by page so one bad font table doesn't lose the rest
+    let mut pages = Vec::new();
+    let mut budget = options.max_chars;
+    for number in 1..=page_count.min(options.max_pages) {
+        if budget == 0 {
+            truncated = true;
+            break;
+        }
+        let mut text = doc.extract_text(&[number]).unwrap_or_default();
+        if let Some((idx, _)) = text.char_indices().nth(budget) {
+            text.truncate(idx);
+            truncated = true;
+        }
+        budget = budget.saturating_sub(text.chars().count());
+        pages.push(PdfPage { number, text });
+    }
+    if pages.iter().all(|p| p.text.trim().is_empty()) {
+        return Err(ConnectorError::Other(
+            "PDF has no extractable text (scanned?)".to_string(),
+        ));
+    }
+
+    let sections = if options.sections {
+        split_sections(&pages)
+    } else {
+        Vec::new()
+    };
+    Ok(PdfText {
+        page_count,
+        pages,
+        sections,
+        truncated,
+    })
+}
+
+/// [`extract`] on the CPU pool.
+pub async fn extract_on_pool<B>(bytes: B, options: PdfOptions) -> Result<PdfText, ConnectorError>
+where
+    B: AsRef<[u8]> + Send + 'static,
+{
+    crate::cpu_pool::spawn_cpu(move || extract(bytes.as_ref(), &options)).await
+}
+
+static NUMBERED_HEADING_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^((?:\d{1,2})(?:\.\d{1,2}){0,2})\.?\s+([A-Z][^.!?]{1,80})$")
+        .expect("numbered heading regex")
+});
+
+/// Split page text at headings: "Abstract"/"References"-style lines and
+/// numbered headings whose top-level number never goes backwards or skips.
+pub fn split_sections(pages: &[PdfPage]) -> Vec<PdfSection> {
+    const NAMED: &[&str] = &[
+        "abstract",
+        "references",
+        "bibliography",
+        "acknowledgments",
+        "acknowledgements",
+        "acknowledgment",
+        "acknowledgement",
+        "appendix",
+    ];
+
+    let mut sections = vec![PdfSection {
+        heading: String::new(),
+        level: 1,
+        page: pages.first().map_or(1, |p| p.number),
+        text: String::new(),
+    }];
+    let mut last_top = 0u32;
+    for page in pages {
+        for line in page.text.lines() {
+            let trimmed = line.trim();
+            let heading = if NAMED.contains(&trimmed.to_lowercase().as_str()) {
+                Some((trimmed.to_string(), 1))
+            } else {
+                NUMBERED_HEADING_RE.captures(trimmed).and_then(|caps| {
+                    let numbers: Vec<u32> =
+                        caps[1].split('.').filter_map(|n| n.parse().ok()).collect();
+                    let top = *numbers.first()?;
+                    let plausible = if numbers.len() == 1 {
+                        top == last_top + 1
+                    } else {
+                        top == last_top
+                    };
+                    if !plausible || caps[2].split_whitespace().count() > 12 {
+                        return None;
+                    }
+                    last_top = top;
+                    Some((trimmed.to_string(), numbers.len() as u8))
+                })
+            };
+
+            if let Some((heading, level)) = heading {
+                sections.push(PdfSection {
+                    heading,
+                    level,
+                    page: page.number,
+                    text: String::new(),
+                });
+                continue;
+            }
+            let current = sections.last_mut().expect("at least one section");
+            if !trimmed.is_empty() {
+                if !current.text.is_empty() {
+                    current.text.push('\n');
+                }
+                current.text.push_str(trimmed);
+            }
+        }
+    }
+    sections.retain(|s| !s.heading.is_empty() || !s.text.is_empty());
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(number: u32, text: &str) -> PdfPage {
+        PdfPage {
+            number,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn splits_pages_at_headings() {
+        let pages = vec![
+            page(
+                1,
+                "A Paper Title\nAbstract\nWe study things.\n1 Introduction\nThings matter.",
+            ),
+            page(
+                2,
+                "3 apples were used in total\n2 Method\n2.1 Setup\nWe set up.\n128 GPUs",
+            ),
+            page(3, "References\n[1] Someone."),
+        ];
+        let sections = split_sections(&pages);
+        let headings: Vec<(&str, u8, u32)> = sections
+            .iter()
+            .map(|s| (s.heading.as_str(), s.level, s.page))
+            .collect();
+        assert_eq!(
+            headings,
+            vec![
+                ("", 1, 1),
+                ("Abstract", 1, 1),
+                ("1 Introduction", 1, 1),
+                ("2 Method", 1, 2),
+                ("2.1 Setup", 2, 2),
+                ("References", 1, 3)
+            ]
+        );
+        assert_eq!(
+            sections[2].text,
+            "Things matter.\n3 apples were used in total"
+        );
+        assert_eq!(sections[4].text, "We set up.\n128 GPUs");
+    }
+
+    #[test]
+    fn rejects_non_pdfs() {
+        assert!(is_pdf(b"%PDF-1.7\n"));
+        assert!(!is_pdf(b"<html>captcha</html>"));
+        assert!(extract(b"not a pdf", &PdfOptions::default()).is_err());
+    }
+}
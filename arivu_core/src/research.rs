@@ -0,0 +1,833 @@
+//! Built-in `research` meta-tool.
+//!
+//! Given a question, [`research`] plans searches across the registered connectors, runs them
+//! through the federated engine, merges hits that point at the same work, fetches the top
+//! sources through the smart resolver and pulls out the sentences that bear on the question.
+//! The result is a [`ResearchBundle`]: numbered sources plus claims that each cite the sources
+//! that make them. Every step is deterministic; no model is called.
+//!
+//! Planning is keyword based. Web search providers get the question as asked, everything else
+//! gets its keywords; academic databases join when the question reads like a research question
+//! and forums join when it asks for opinions or comparisons.
+
+use crate::document::Document;
+use crate::error::ConnectorError;
+use crate::federated::{
+    FederatedResults, FederatedSearch, MergeMode, SearchProfile, SourceError, UnifiedSearchResult,
+};
+use crate::resolver::{extract_doi, SmartResolver};
+use crate::{CallToolRequestParam, ProviderRegistry, RawContent, Tool};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::time::timeout;
+
+/// Name of the tool over MCP.
+pub const TOOL_NAME: &str = "research";
+
+const FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+/// Characters of each fetched document scanned for claims
+const MAX_SCAN_CHARS: usize = 30_000;
+const CLAIMS_PER_SOURCE: usize = 3;
+/// Share of content words two sentences need in common to count as one claim
+const SAME_CLAIM_SIMILARITY: f32 = 0.5;
+const MIN_CLAIM_CHARS: usize = 40;
+const MAX_CLAIM_CHARS: usize = 400;
+
+const STOPWORDS: &[&str] = &[
+    "a", "about", "actually", "all", "also", "am", "an", "and", "any", "are", "as", "at", "be",
+    "been", "being", "between", "but", "by", "can", "could", "did", "do", "does", "doing",
+    "during", "each", "explain", "for", "from", "get", "had", "has", "have", "he", "her", "his",
+    "how", "i", "if", "in", "into", "is", "it", "its", "just", "know", "many", "may", "me",
+    "might", "more", "most", "much", "must", "my", "no", "not", "of", "on", "or", "other", "our",
+    "out", "over", "really", "shall", "she", "should", "so", "some", "such", "tell", "than",
+    "that", "the", "their", "them", "then", "there", "these", "they", "this", "those", "to", "up",
+    "us", "versus", "vs", "was", "we", "were", "what", "when", "where", "whether", "which",
+    "while", "who", "whom", "whose", "why", "will", "with", "would", "you", "your",
+];
+
+/// Words that send a question to the academic databases
+const ACADEMIC_MARKERS: &[&str] = &[
+    "algorithm",
+    "benchmark",
+    "cancer",
+    "cell",
+    "clinical",
+    "dataset",
+    "disease",
+    "dose",
+    "drug",
+    "effect",
+    "efficacy",
+    "evidence",
+    "gene",
+    "mechanism",
+    "meta-analysis",
+    "paper",
+    "protein",
+    "quantum",
+    "research",
+    "risk",
+    "species",
+    "studies",
+    "study",
+    "theorem",
+    "treatment",
+    "trial",
+    "vaccine",
+];
+
+/// Words that send a question to forums
+const OPINION_MARKERS: &[&str] = &[
+    "alternative",
+    "best",
+    "experience",
+    "opinion",
+    "recommend",
+    "recommendation",
+    "review",
+    "versus",
+    "vs",
+    "worth",
+];
+
+/// Knobs for a research run.
+#[derive(Debug, Clone)]
+pub struct ResearchOptions {
+    /// Connectors to search instead of the planned ones
+    pub sources: Option<Vec<String>>,
+    /// Results requested from each connector
+    pub per_source_limit: u32,
+    /// How many of the top sources to fetch in full
+    pub max_fetch: usize,
+    /// How many claims the bundle keeps
+    pub max_claims: usize,
+}
+
+impl Default for ResearchOptions {
+    fn default() -> Self {
+        Self {
+            sources: None,
+            per_source_limit: 5,
+            max_fetch: 6,
+            max_claims: 15,
+        }
+    }
+}
+
+/// One search the planner decided to run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlannedQuery {
+    pub query: String,
+    pub connectors: Vec<String>,
+}
+
+/// A distinct source found while researching, cited by id from claims.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvidenceSource {
+    /// "S1", "S2", ... in rank order
+    pub id: String,
+    /// Connector whose search found it first
+    pub connector: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+    /// Other connectors that returned the same source
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub also_found_in: Vec<String>,
+    /// Tool that fetched the full text (e.g. "web/scrape_url"), when it was fetched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fetched_with: Option<String>,
+}
+
+/// A sentence bearing on the question and the sources that state it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Claim {
+    pub text: String,
+    /// Ids of the supporting sources, the one quoted first
+    pub sources: Vec<String>,
+    /// Share of the question's keywords the sentence mentions (0.0 - 1.0)
+    pub relevance: f32,
+}
+
+/// Everything a research run found.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResearchBundle {
+    pub question: String,
+    pub queries: Vec<PlannedQuery>,
+    pub sources: Vec<EvidenceSource>,
+    /// Most corroborated first
+    pub claims: Vec<Claim>,
+    /// Searches and fetches that failed; the bundle is built from the rest
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<SourceError>,
+    pub duration_ms: u64,
+}
+
+/// The MCP definition of the tool.
+pub fn tool() -> Tool {
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "question": {
+                "type": "string",
+                "description": "The question to research"
+            },
+            "sources": {
+                "type": "array",
+                "items": { "type": "string" },
+                "description": "Connectors to search instead of the planned ones (e.g. [\"arxiv\", \"pubmed\"])"
+            },
+            "max_fetch": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "How many of the top sources to fetch in full (default 6, 0 to use search snippets only)"
+            },
+            "max_claims": {
+                "type": "integer",
+                "minimum": 1,
+                "description": "How many claims to return (default 15)"
+            }
+        },
+        "required": ["question"]
+    });
+    Tool {
+        name: TOOL_NAME.into(),
+        title: Some("Research".into()),
+        description: Some(
+            "Research a question across the enabled connectors: plans searches, deduplicates \
+             the hits, fetches the top sources and returns numbered sources with the claims \
+             each one supports."
+                .into(),
+        ),
+        input_schema: Arc::new(
+            schema
+                .as_object()
+                .expect("Schema must be an object")
+                .clone(),
+        ),
+        output_schema: None,
+        annotations: None,
+        icons: None,
+    }
+}
+
+/// Read the question and options from tool-call arguments.
+pub fn parse_args(args: &Map<String, Value>) -> Result<(String, ResearchOptions), ConnectorError> {
+    let question = args
+        .get("question")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|q| !q.is_empty())
+        .ok_or_else(|| ConnectorError::InvalidParams("question is required".to_string()))?;
+
+    let mut options = ResearchOptions::default();
+    if let Some(sources) = args.get("sources").and_then(Value::as_array) {
+        let names: Vec<String> = sources
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect();
+        if !names.is_empty() {
+            options.sources = Some(names);
+        }
+    }
+    if let Some(n) = args.get("max_fetch").and_then(Value::as_u64) {
+        options.max_fetch = n as usize;
+    }
+    if let Some(n) = args
+        .get("max_claims")
+        .and_then(Value::as_u64)
+        .filter(|n| *n > 0)
+    {
+        options.max_claims = n as usize;
+    }
+    Ok((question.to_string(), options))
+}
+
+/// Research `question` with the connectors in `registry`.
+///
+/// Connectors that fail or time out are reported in the bundle's `errors`; the rest still count.
+pub async fn research(
+    registry: &ProviderRegistry,
+    question: &str,
+    options: &ResearchOptions,
+) -> ResearchBundle {
+    let start = Instant::now();
+    let mut errors = Vec::new();
+
+    let mut available: Vec<String> = registry.providers.keys().cloned().collect();
+    available.sort();
+    if let Some(requested) = &options.sources {
+        for name in requested {
+            if registry.get_provider(name).is_none() {
+                errors.push(SourceError {
+                    source: name.clone(),
+                    error: "connector is not enabled".to_string(),
+                    is_timeout: false,
+                });
+            }
+        }
+    }
+    let queries = plan(question, &available, options.sources.as_deref());
+
+    // Search
+    let engine = FederatedSearch::new(registry);
+    let searches = queries.iter().map(|planned| {
+        let mut profile = SearchProfile::new(TOOL_NAME, planned.connectors.clone());
+        profile.defaults.limit = options.per_source_limit;
+        let engine = &engine;
+        async move {
+            engine
+                .search_with_profile(&planned.query, &profile, Some(MergeMode::Interleaved))
+                .await
+        }
+    });
+    let mut hits: Vec<UnifiedSearchResult> = Vec::new();
+    for result in futures::future::join_all(searches).await {
+        errors.extend(result.errors);
+        if let FederatedResults::Interleaved { results } = result.results {
+            hits.extend(results);
+        }
+    }
+    // Each search is ranked already; interleave the searches by the same score
+    hits.sort_by(|a, b| {
+        let a = a.federation.score.unwrap_or(0.0);
+        let b = b.federation.score.unwrap_or(0.0);
+        b.partial_cmp(&a).unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let mut sources = merge_duplicates(hits);
+
+    // Fetch
+    let mut texts: HashMap<String, String> = sources
+        .iter()
+        .filter_map(|s| Some((s.id.clone(), s.snippet.clone()?)))
+        .collect();
+    let resolver = SmartResolver::new();
+    let fetches = sources
+        .iter()
+        .filter_map(|s| Some((s.id.clone(), s.url.clone()?)))
+        .take(options.max_fetch)
+        .map(|(id, url)| {
+            let resolver = &resolver;
+            async move {
+                let outcome =
+                    match timeout(FETCH_TIMEOUT, fetch_source(registry, resolver, &url)).await {
+                        Ok(outcome) => outcome,
+                        Err(_) => Err(SourceError {
+                            source: url.clone(),
+                            error: format!("fetch timed out after {}s", FETCH_TIMEOUT.as_secs()),
+                            is_timeout: true,
+                        }),
+                    };
+                (id, outcome)
+            }
+        });
+    for (id, outcome) in futures::future::join_all(fetches).await {
+        match outcome {
+            Ok((fetched_with, document)) => {
+                let text = document_text(&document);
+                if text.trim().is_empty() {
+                    continue;
+                }
+                if let Some(source) = sources.iter_mut().find(|s| s.id == id) {
+                    source.fetched_with = Some(fetched_with);
+                }
+                texts.insert(id, text);
+            }
+            Err(error) => errors.push(error),
+        }
+    }
+
+    // Extract
+    let passages: Vec<(String, String)> = sources
+        .iter()
+        .filter_map(|s| Some((s.id.clone(), texts.remove(&s.id)?)))
+        .collect();
+    let claims = extract_claims(&keywords(question), &passages, options.max_claims);
+
+    ResearchBundle {
+        question: question.to_string(),
+        queries,
+        sources,
+        claims,
+        errors,
+        duration_ms: start.elapsed().as_millis() as u64,
+    }
+}
+
+/// Decide which connectors to search and with what query.
+///
+/// `requested` replaces the planned connectors. Web search providers take the question as
+/// asked; the others take its keywords, which keyword-matching backends handle better.
+pub fn plan(
+    question: &str,
+    available: &[String],
+    requested: Option<&[String]>,
+) -> Vec<PlannedQuery> {
+    let web = profile_connectors("web");
+    let stems: Vec<String> = words(question).iter().map(|w| stem(w)).collect();
+    let mentions = |markers: &[&str]| markers.iter().any(|m| stems.contains(&stem(m)));
+
+    let candidates: Vec<String> = match requested {
+        Some(names) => names.to_vec(),
+        None => {
+            let mut names = web.clone();
+            names.push("wikipedia".to_string());
+            if mentions(ACADEMIC_MARKERS) {
+                names.extend(profile_connectors("research"));
+            }
+            if mentions(OPINION_MARKERS) {
+                names.extend(profile_connectors("social"));
+            }
+            if !names.iter().any(|n| available.contains(n)) {
+                // No web search is configured: fall back to the keyless sources
+                for profile in ["media", "research", "social"] {
+                    names.extend(profile_connectors(profile));
+                }
+            }
+            names
+        }
+    };
+
+    let mut connectors: Vec<String> = Vec::new();
+    for name in candidates {
+        if available.contains(&name) && !connectors.contains(&name) {
+            connectors.push(name);
+        }
+    }
+
+    let keyword_query = match keywords(question) {
+        k if k.is_empty() => question.to_string(),
+        k => k.join(" "),
+    };
+    let (asked, keyed): (Vec<String>, Vec<String>) =
+        connectors.into_iter().partition(|c| web.contains(c));
+    [(question.to_string(), asked), (keyword_query, keyed)]
+        .into_iter()
+        .filter(|(_, connectors)| !connectors.is_empty())
+        .map(|(query, connectors)| PlannedQuery { query, connectors })
+        .collect()
+}
+
+/// Content words of the question, in order, without repeats.
+pub fn keywords(question: &str) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for word in words(question) {
+        if word.len() >= 2 && !STOPWORDS.contains(&word.as_str()) && !out.contains(&word) {
+            out.push(word);
+        }
+    }
+    out
+}
+
+fn profile_connectors(name: &str) -> Vec<String> {
+    SearchProfile::get_builtin(name)
+        .map(|p| p.connectors)
+        .unwrap_or_default()
+}
+
+/// Fetch a source through whichever connector the resolver routes its URL to.
+async fn fetch_source(
+    registry: &ProviderRegistry,
+    resolver: &SmartResolver,
+    url: &str,
+) -> Result<(String, Document), SourceError> {
+    let failed = |source: &str, error: String| SourceError {
+        source: source.to_string(),
+        error,
+        is_timeout: false,
+    };
+    let action = resolver
+        .resolve(url)
+        .ok_or_else(|| failed(url, "no connector can fetch this URL".to_string()))?;
+    let provider = registry
+        .get_provider(&action.connector)
+        .ok_or_else(|| failed(&action.connector, "connector is not enabled".to_string()))?;
+
+    let request = CallToolRequestParam {
+        name: action.tool.clone().into(),
+        arguments: action.tool_arguments(),
+    };
+    let result = {
+        let connector = provider.lock().await;
+        connector.call_tool(request).await
+    }
+    .map_err(|e| failed(&action.connector, format!("fetching {}: {}", url, e)))?;
+
+    let output = result.structured_content.unwrap_or_else(|| {
+        let text: Vec<&str> = result
+            .content
+            .iter()
+            .filter_map(|c| match &c.raw {
+                RawContent::Text(t) => Some(t.text.as_str()),
+                _ => None,
+            })
+            .collect();
+        Value::String(text.join("\n"))
+    });
+    let document = Document::from_tool_output(&action.connector, &output);
+    Ok((format!("{}/{}", action.connector, action.tool), document))
+}
+
+/// The start of a document's text, falling back to its sections.
+fn document_text(document: &Document) -> String {
+    let text = if document.text.trim().is_empty() {
+        document
+            .sections
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    } else {
+        document.text.clone()
+    };
+    text.chars().take(MAX_SCAN_CHARS).collect()
+}
+
+/// Collapse hits for the same work (same normalized URL, DOI or title) into one source, keeping
+/// the rank of the first.
+fn merge_duplicates(hits: Vec<UnifiedSearchResult>) -> Vec<EvidenceSource> {
+    let mut sources: Vec<EvidenceSource> = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+
+    for hit in hits {
+        let keys = dedup_keys(&hit);
+        let snippet = hit.snippet.filter(|s| !s.trim().is_empty());
+        if let Some(&index) = keys.iter().find_map(|k| seen.get(k)) {
+            let source = &mut sources[index];
+            if source.connector != hit.source && !source.also_found_in.contains(&hit.source) {
+                source.also_found_in.push(hit.source);
+            }
+            let longer = |s: &String| source.snippet.as_ref().map_or(0, String::len) < s.len();
+            if snippet.as_ref().is_some_and(longer) {
+                source.snippet = snippet;
+            }
+            if source.url.is_none() {
+                source.url = hit.url;
+            }
+            for key in keys {
+                seen.entry(key).or_insert(index);
+            }
+            continue;
+        }
+
+        let index = sources.len();
+        for key in keys {
+            seen.insert(key, index);
+        }
+        sources.push(EvidenceSource {
+            id: format!("S{}", index + 1),
+            connector: hit.source,
+            title: hit.title,
+            url: hit.url,
+            snippet,
+            also_found_in: Vec::new(),
+            fetched_with: None,
+        });
+    }
+    sources
+}
+
+fn dedup_keys(hit: &UnifiedSearchResult) -> Vec<String> {
+    let mut keys = Vec::new();
+    if let Some(url) = hit.url.as_deref().and_then(normalize_url) {
+        keys.push(format!("url:{}", url));
+    }
+    if let Some(doi) = hit
+        .url
+        .as_deref()
+        .and_then(extract_doi)
+        .or_else(|| extract_doi(&hit.id))
+    {
+        keys.push(format!("doi:{}", doi.to_lowercase()));
+    }
+    // Short titles ("Introduction", "Home") say nothing about identity
+    let title = words(&hit.title).join(" ");
+    if title.len() >= 20 {
+        keys.push(format!("title:{}", title));
+    }
+    keys
+}
+
+/// Host and path without `www.`, scheme, fragment, trailing slash or tracking parameters.
+fn normalize_url(url: &str) -> Option<String> {
+    let parsed = url::Url::parse(url).ok()?;
+    let host = parsed.host_str()?;
+    let host = host.strip_prefix("www.").unwrap_or(host);
+    let path = parsed.path().trim_end_matches('/');
+    let query: Vec<String> = parsed
+        .query_pairs()
+        .filter(|(k, _)| !k.starts_with("utm_") && k != "ref")
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect();
+    Some(if query.is_empty() {
+        format!("{}{}", host, path)
+    } else {
+        format!("{}{}?{}", host, path, query.join("&"))
+    })
+}
+
+/// Pick the sentences of each passage that mention the keywords and merge the ones that say the
+/// same thing, so each claim lists every source behind it.
+fn extract_claims(
+    keywords: &[String],
+    passages: &[(String, String)],
+    max_claims: usize,
+) -> Vec<Claim> {
+    if keywords.is_empty() {
+        return Vec::new();
+    }
+    let stems: HashSet<String> = keywords.iter().map(|k| stem(k)).collect();
+    let needed = stems.len().min(2);
+
+    let mut candidates: Vec<(&str, &str, f32)> = Vec::new();
+    for (id, text) in passages {
+        let mut scored: Vec<(&str, f32)> = split_sentences(text)
+            .into_iter()
+            .filter(|s| is_claim_like(s))
+            .filter_map(|sentence| {
+                let found: HashSet<String> = words(sentence)
+                    .iter()
+                    .map(|w| stem(w))
+                    .filter(|w| stems.contains(w))
+                    .collect();
+                if found.len() < needed {
+                    return None;
+                }
+                Some((sentence, found.len() as f32 / stems.len() as f32))
+            })
+            .collect();
+        // Stable, so equally relevant sentences stay in reading order
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        candidates.extend(
+            scored
+                .into_iter()
+                .take(CLAIMS_PER_SOURCE)
+                .map(|(sentence, relevance)| (id.as_str(), sentence, relevance)),
+        );
+    }
+    candidates.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut claims: Vec<(Claim, HashSet<String>)> = Vec::new();
+    for (id, sentence, relevance) in candidates {
+        let content = content_words(sentence);
+        let same = claims
+            .iter_mut()
+            .find(|(_, other)| similarity(&content, other) >= SAME_CLAIM_SIMILARITY);
+        match same {
+            Some((claim, _)) => {
+                if !claim.sources.iter().any(|s| s == id) {
+                    claim.sources.push(id.to_string());
+                }
+            }
+            None => claims.push((
+                Claim {
+                    text: sentence.to_string(),
+                    sources: vec![id.to_string()],
+                    relevance: (relevance * 100.0).round() / 100.0,
+                },
+                content,
+            )),
+        }
+    }
+
+    let mut claims: Vec<Claim> = claims.into_iter().map(|(claim, _)| claim).collect();
+    claims.sort_by(|a, b| {
+        b.sources.len().cmp(&a.sources.len()).then(
+            b.relevance
+                .partial_cmp(&a.relevance)
+                .unwrap_or(std::cmp::Ordering::Equal),
+        )
+    });
+    claims.truncate(max_claims);
+    claims
+}
+
+/// Split text into sentences at `.`, `!` or `?` followed by a space and a capital or digit, and
+/// at line breaks.
+fn split_sentences(text: &str) -> Vec<&str> {
+    let mut out = Vec::new();
+    for line in text.lines() {
+        let chars: Vec<(usize, char)> = line.char_indices().collect();
+        let mut start = 0;
+        for (i, &(pos, c)) in chars.iter().enumerate() {
+            if !matches!(c, '.' | '!' | '?') {
+                continue;
+            }
+            let space = chars.get(i + 1).is_some_and(|(_, n)| n.is_whitespace());
+            let opens = chars
+                .get(i + 2)
+                .is_some_and(|(_, n)| n.is_uppercase() || n.is_ascii_digit() || *n == '"');
+            if space && opens {
+                out.push(line[start..=pos].trim());
+                start = pos + 1;
+            }
+        }
+        out.push(line[start..].trim());
+    }
+    out.into_iter()
+        .map(|s| s.trim_start_matches(['-', '*', '>', '#', ' ']))
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// A complete sentence of reasonable length, not a heading, menu or table row.
+fn is_claim_like(sentence: &str) -> bool {
+    let len = sentence.chars().count();
+    (MIN_CLAIM_CHARS..=MAX_CLAIM_CHARS).contains(&len)
+        && sentence.split_whitespace().count() >= 6
+        && !sentence.contains('|')
+        && sentence.ends_with(['.', '!', '?', '"', ')', '\u{201D}'])
+}
+
+/// Lowercase words; hyphenated words stay whole.
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '-')
+        .map(|w| w.trim_matches('-').to_lowercase())
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+/// Plural-insensitive form of a word.
+fn stem(word: &str) -> String {
+    match word.strip_suffix('s') {
+        // Not "class", "versus" or "analysis"
+        Some(base) if base.len() >= 3 && !base.ends_with(['s', 'u', 'i']) => base.to_string(),
+        _ => word.to_string(),
+    }
+}
+
+fn content_words(sentence: &str) -> HashSet<String> {
+    words(sentence)
+        .iter()
+        .filter(|w| w.len() > 2 && !STOPWORDS.contains(&w.as_str()))
+        .map(|w| stem(w))
+        .collect()
+}
+
+/// Overlap of two word sets relative to the smaller one.
+fn similarity(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    let smaller = a.len().min(b.len());
+    if smaller == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / smaller as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn plans_connectors_and_queries_from_the_question() {
+        let available = names(&["arxiv", "exa-search", "hackernews", "pubmed", "wikipedia"]);
+
+        let queries = plan(
+            "What does the evidence say about intermittent fasting?",
+            &available,
+            None,
+        );
+        assert_eq!(
+            queries,
+            vec![
+                PlannedQuery {
+                    query: "What does the evidence say about intermittent fasting?".to_string(),
+                    connectors: names(&["exa-search"]),
+                },
+                PlannedQuery {
+                    query: "evidence say intermittent fasting".to_string(),
+                    connectors: names(&["wikipedia", "pubmed", "arxiv"]),
+                },
+            ]
+        );
+
+        let queries = plan("Is Zed worth switching to from VS Code?", &available, None);
+        assert_eq!(
+            queries[1].connectors,
+            names(&["wikipedia", "hackernews"]),
+            "{:?}",
+            queries
+        );
+
+        let requested = names(&["pubmed", "reddit"]);
+        let queries = plan("choline and memory", &available, Some(&requested));
+        assert_eq!(
+            queries,
+            vec![PlannedQuery {
+                query: "choline memory".to_string(),
+                connectors: names(&["pubmed"]),
+            }]
+        );
+    }
+
+    #[test]
+    fn merges_duplicates_and_cites_every_source_of_a_claim() {
+        let hit = |source: &str, title: &str, url: &str| {
+            UnifiedSearchResult::new(source, url, title, 1).with_url(url)
+        };
+        let sources = merge_duplicates(vec![
+            hit(
+                "exa-search",
+                "Fasting and longevity",
+                "https://www.example.org/fasting/?utm_source=x",
+            ),
+            hit(
+                "wikipedia",
+                "Intermittent fasting",
+                "https://en.wikipedia.org/wiki/IF",
+            ),
+            hit(
+                "tavily-search",
+                "Fasting & Longevity",
+                "http://example.org/fasting",
+            ),
+            hit("pubmed", "A trial", "https://doi.org/10.1000/XYZ.1"),
+            hit(
+                "arxiv",
+                "Another copy",
+                "https://example.com/paper?doi=10.1000/xyz.1",
+            ),
+        ]);
+        let ids: Vec<&str> = sources.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, ["S1", "S2", "S3"]);
+        assert_eq!(sources[0].also_found_in, ["tavily-search"]);
+        assert_eq!(sources[2].connector, "pubmed");
+        assert_eq!(sources[2].also_found_in, ["arxiv"]);
+
+        let passages = vec![
+            (
+                "S1".to_string(),
+                "Home | About\nIntermittent fasting extended lifespan in mice across several \
+                 trials. Unrelated sentence about the weather in spring today."
+                    .to_string(),
+            ),
+            (
+                "S2".to_string(),
+                "In several trials, intermittent fasting extended the lifespan of mice. \
+                 Fasting is also practised for religious reasons in many cultures."
+                    .to_string(),
+            ),
+        ];
+        let claims = extract_claims(
+            &keywords("Does intermittent fasting extend lifespan?"),
+            &passages,
+            10,
+        );
+        assert_eq!(claims[0].sources, ["S1", "S2"]);
+        assert_eq!(
+            claims[0].text,
+            "Intermittent fasting extended lifespan in mice across several trials."
+        );
+        assert!(claims.iter().all(|c| !c.text.contains("weather")));
+        assert!(claims.iter().all(|c| !c.text.contains("Home")));
+    }
+}
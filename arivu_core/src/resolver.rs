@@ -26,6 +26,11 @@
 //!
 //! Note: the resolver only routes to tools that are implemented and exposed by each connector's
 //! `list_tools()` surface (kept intentionally small for agent use).
+//!
+//! Matches are refined after the regex step: ISBNs must pass their checksum, DOIs lose trailing
+//! punctuation, and arXiv DOIs (`10.48550/arXiv.*`) go to the arxiv connector. Input that is
+//! not an identifier as a whole (a sentence, a pasted reference) is scanned for the first
+//! token that is one.
 
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -46,6 +51,34 @@ pub struct ResolvedAction {
     pub priority: u32,
     /// Human-readable description of what was detected
     pub description: String,
+    /// Id of the pattern that matched (e.g., "doi_url", "isbn_bare")
+    #[serde(default)]
+    pub pattern: String,
+}
+
+impl ResolvedAction {
+    /// Tool arguments for this action.
+    ///
+    /// Captures are strings; the ones connectors expect as numeric ids (`id`, `number`, `item_id`,
+    /// `channel_id`) become integers when they parse as one. Others such as `pmid` or `video_id`
+    /// stay strings.
+    pub fn tool_arguments(&self) -> Option<serde_json::Map<String, serde_json::Value>> {
+        if self.arguments.is_empty() {
+            return None;
+        }
+        let mut args = serde_json::Map::new();
+        for (key, value) in &self.arguments {
+            let numeric = matches!(key.as_str(), "id" | "number" | "item_id" | "channel_id");
+            if numeric {
+                if let Some(num) = value.as_str().and_then(|s| s.parse::<i64>().ok()) {
+                    args.insert(key.clone(), serde_json::Value::Number(num.into()));
+                    continue;
+                }
+            }
+            args.insert(key.clone(), value.clone());
+        }
+        Some(args)
+    }
 }
 
 /// Pattern definition for matching inputs
@@ -92,128 +125,52 @@ impl SmartResolver {
     ///
     /// Returns `None` if no pattern matches the input.
     pub fn resolve(&self, input: &str) -> Option<ResolvedAction> {
-        let input = input.trim();
-
-        for pattern in &self.patterns {
-            if let Some(captures) = pattern.pattern.captures(input) {
-                let mut arguments = HashMap::new();
-
-                // Extract captures and map to arguments
-                for (capture_name, arg_name) in pattern.arg_mapping {
-                    if let Some(m) = captures.name(capture_name) {
-                        arguments.insert(
-                            arg_name.to_string(),
-                            serde_json::Value::String(m.as_str().to_string()),
-                        );
-                    }
-                }
-
-                // Special handling for biorxiv/medrxiv server argument
-                if pattern.id == "biorxiv_url" {
-                    if let Some(server_match) = captures.name("server") {
-                        arguments.insert(
-                            "server".to_string(),
-                            serde_json::Value::String(server_match.as_str().to_string()),
-                        );
-                    }
-                } else if pattern.id == "biorxiv_doi" {
-                    if let Some(prefix_match) = captures.name("prefix") {
-                        let server = match prefix_match.as_str() {
-                            "biorxiv" => "biorxiv",
-                            "medrxiv" => "medrxiv",
-                            _ => "biorxiv", // Default if prefix is not biorxiv/medrxiv (should not happen with regex)
-                        };
-                        arguments.insert(
-                            "server".to_string(),
-                            serde_json::Value::String(server.to_string()),
-                        );
-                    } else {
-                        // Default to biorxiv if no prefix is matched (e.g. bare DOI)
-                        arguments.insert(
-                            "server".to_string(),
-                            serde_json::Value::String("biorxiv".to_string()),
-                        );
-                    }
-                }
-
-                return Some(ResolvedAction {
-                    connector: pattern.connector.to_string(),
-                    tool: pattern.tool.to_string(),
-                    arguments,
-                    confidence: 1.0,
-                    priority: pattern.priority,
-                    description: pattern.description.to_string(),
-                });
-            }
-        }
-
-        None
+        self.resolve_all(input).into_iter().next()
     }
 
-    /// Resolve input, returning all possible matches sorted by confidence
+    /// Resolve input, returning all possible matches, most specific pattern first
+    ///
+    /// When the input as a whole matches nothing, its tokens are tried in order and the matches
+    /// for the first token that is a specific identifier are returned with lower confidence.
     pub fn resolve_all(&self, input: &str) -> Vec<ResolvedAction> {
         let input = input.trim();
-        let mut results = Vec::new();
-
-        for pattern in &self.patterns {
-            if let Some(captures) = pattern.pattern.captures(input) {
-                let mut arguments = HashMap::new();
-
-                for (capture_name, arg_name) in pattern.arg_mapping {
-                    if let Some(m) = captures.name(capture_name) {
-                        arguments.insert(
-                            arg_name.to_string(),
-                            serde_json::Value::String(m.as_str().to_string()),
-                        );
-                    }
-                }
-
-                // Special handling for biorxiv/medrxiv server argument
-                if pattern.id == "biorxiv_url" {
-                    if let Some(server_match) = captures.name("server") {
-                        arguments.insert(
-                            "server".to_string(),
-                            serde_json::Value::String(server_match.as_str().to_string()),
-                        );
-                    }
-                } else if pattern.id == "biorxiv_doi" {
-                    if let Some(prefix_match) = captures.name("prefix") {
-                        let server = match prefix_match.as_str() {
-                            "biorxiv" => "biorxiv",
-                            "medrxiv" => "medrxiv",
-                            _ => "biorxiv", // Default if prefix is not biorxiv/medrxiv (should not happen with regex)
-                        };
-                        arguments.insert(
-                            "server".to_string(),
-                            serde_json::Value::String(server.to_string()),
-                        );
-                    } else {
-                        // Default to biorxiv if no prefix is matched (e.g. bare DOI)
-                        arguments.insert(
-                            "server".to_string(),
-                            serde_json::Value::String("biorxiv".to_string()),
-                        );
-                    }
-                }
+        let results = self.match_patterns(input);
+        if !results.is_empty() || !input.contains(char::is_whitespace) {
+            return results;
+        }
 
-                results.push(ResolvedAction {
-                    connector: pattern.connector.to_string(),
-                    tool: pattern.tool.to_string(),
-                    arguments,
-                    confidence: 1.0,
-                    priority: pattern.priority,
-                    description: pattern.description.to_string(),
-                });
+        for token in input.split_whitespace() {
+            let token = token.trim_matches(|c: char| "\"'<>()[]{},;.:".contains(c));
+            let found: Vec<ResolvedAction> = self
+                .match_patterns(token)
+                .into_iter()
+                .filter(|a| a.priority >= MIN_EMBEDDED_PRIORITY)
+                .map(|mut a| {
+                    a.confidence = 0.8;
+                    a.description = format!("{} (found in text)", a.description);
+                    a
+                })
+                .collect();
+            if !found.is_empty() {
+                return found;
             }
         }
+        Vec::new()
+    }
 
-        results
+    fn match_patterns(&self, input: &str) -> Vec<ResolvedAction> {
+        self.patterns
+            .iter()
+            .filter_map(|pattern| {
+                let captures = pattern.pattern.captures(input)?;
+                build_action(pattern, &captures)
+            })
+            .collect()
     }
 
     /// Check if an input matches any pattern
     pub fn can_resolve(&self, input: &str) -> bool {
-        let input = input.trim();
-        self.patterns.iter().any(|p| p.pattern.is_match(input))
+        !self.resolve_all(input).is_empty()
     }
 
     /// Get list of all supported patterns (for documentation/help)
@@ -231,6 +188,137 @@ impl SmartResolver {
     }
 }
 
+/// Extract a DOI from arbitrary input (doi.org URL, `doi:` prefix, publisher URL, or bare DOI).
+///
+/// Used by `arivu fetch` to enrich results with Crossref metadata regardless of which
+/// connector the input was routed to.
+pub fn extract_doi(input: &str) -> Option<String> {
+    use once_cell::sync::Lazy;
+
+    static DOI_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"(?i)\b(10\.\d{4,9}/[^\s"'<>?#]+)"#).unwrap());
+
+    let doi = DOI_RE.captures(input.trim())?.get(1)?.as_str();
+    // Trailing punctuation is almost always sentence/URL noise rather than part of the DOI.
+    let doi = doi.trim_end_matches(['.', ',', ';', ')', ']']);
+    Some(doi.to_string())
+}
+
+/// Lowest pattern priority trusted for identifiers found inside longer text. This keeps bare
+/// numbers and 11-character words from being read as PubMed, HN or YouTube ids.
+const MIN_EMBEDDED_PRIORITY: u32 = 85;
+
+/// Turn a pattern match into an action, applying per-identifier checks and rerouting.
+/// Returns `None` when the match fails validation (e.g., an ISBN with a bad checksum).
+fn build_action(pattern: &InputPattern, captures: &regex::Captures) -> Option<ResolvedAction> {
+    let mut action = ResolvedAction {
+        connector: pattern.connector.to_string(),
+        tool: pattern.tool.to_string(),
+        arguments: HashMap::new(),
+        confidence: 1.0,
+        priority: pattern.priority,
+        description: pattern.description.to_string(),
+        pattern: pattern.id.to_string(),
+    };
+
+    for (capture_name, arg_name) in pattern.arg_mapping {
+        if let Some(m) = captures.name(capture_name) {
+            action.arguments.insert(
+                arg_name.to_string(),
+                serde_json::Value::String(m.as_str().to_string()),
+            );
+        }
+    }
+
+    // ISBNs must check out, so phone numbers and other digit runs fall through
+    if let Some(raw) = action.arguments.get("isbn").and_then(|v| v.as_str()) {
+        let isbn = normalize_isbn(raw)?;
+        action
+            .arguments
+            .insert("isbn".to_string(), serde_json::Value::String(isbn));
+    }
+
+    if pattern.id.starts_with("doi_") {
+        let raw = action.arguments.get("paper_id")?.as_str()?;
+        let doi = raw.trim_end_matches(['.', ',', ';', ')', ']']).to_string();
+        if let Some(arxiv_id) = arxiv_id_from_doi(&doi) {
+            action.connector = "arxiv".to_string();
+            action.tool = "get".to_string();
+            action.description = "arXiv DOI (10.48550/arXiv.*)".to_string();
+            action
+                .arguments
+                .insert("paper_id".to_string(), serde_json::Value::String(arxiv_id));
+        } else {
+            action
+                .arguments
+                .insert("paper_id".to_string(), serde_json::Value::String(doi));
+        }
+    }
+
+    // bioRxiv and medRxiv share a connector; the server comes from the URL or prefix
+    if pattern.id == "biorxiv_url" || pattern.id == "biorxiv_doi" {
+        let server = match captures
+            .name("server")
+            .or_else(|| captures.name("prefix"))
+            .map(|m| m.as_str())
+        {
+            Some("medrxiv") => "medrxiv",
+            _ => "biorxiv",
+        };
+        action.arguments.insert(
+            "server".to_string(),
+            serde_json::Value::String(server.to_string()),
+        );
+    }
+
+    Some(action)
+}
+
+/// Digits-only ISBN-10 or ISBN-13 (hyphens and spaces removed, `X` check digit upper-cased)
+/// when the check digit is valid.
+pub fn normalize_isbn(input: &str) -> Option<String> {
+    let isbn: String = input
+        .chars()
+        .filter(|c| !matches!(c, '-' | ' '))
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    let digit = |c: char| c.to_digit(10);
+    let valid = match isbn.len() {
+        10 => {
+            let mut sum = 0;
+            for (i, c) in isbn.chars().enumerate() {
+                let value = match c {
+                    'X' if i == 9 => 10,
+                    _ => digit(c)?,
+                };
+                sum += value * (10 - i as u32);
+            }
+            sum % 11 == 0
+        }
+        13 => {
+            let mut sum = 0;
+            for (i, c) in isbn.chars().enumerate() {
+                sum += digit(c)? * if i % 2 == 0 { 1 } else { 3 };
+            }
+            sum % 10 == 0
+        }
+        _ => false,
+    };
+    valid.then_some(isbn)
+}
+
+/// The arXiv id inside an arXiv-issued DOI (`10.48550/arXiv.2301.07041`).
+fn arxiv_id_from_doi(doi: &str) -> Option<String> {
+    let (prefix, suffix) = doi.split_once('/')?;
+    if prefix != "10.48550" {
+        return None;
+    }
+    let id = suffix
+        .strip_prefix("arXiv.")
+        .or_else(|| suffix.strip_prefix("arxiv."))?;
+    (!id.is_empty()).then(|| id.to_string())
+}
+
 /// Information about a pattern for documentation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PatternInfo {
@@ -249,7 +337,7 @@ fn build_default_patterns() -> Vec<InputPattern> {
             id: "youtube_url_watch",
             connector: "youtube",
             tool: "get",
-            pattern: Regex::new(r"(?:https?://)?(?:www\.)?youtube\.com/watch\?v=(?P<video_id>[a-zA-Z0-9_-]{11})").unwrap(),
+            pattern: Regex::new(r"(?:https?://)?(?:(?:www|m|music)\.)?youtube\.com/watch\?(?:[^\s#]*&)?v=(?P<video_id>[a-zA-Z0-9_-]{11})").unwrap(),
             captures: &["video_id"],
             arg_mapping: &[("video_id", "video_id")],
             priority: 100,
@@ -275,6 +363,16 @@ fn build_default_patterns() -> Vec<InputPattern> {
             priority: 100,
             description: "YouTube embed URL",
         },
+        InputPattern {
+            id: "youtube_url_shorts",
+            connector: "youtube",
+            tool: "get",
+            pattern: Regex::new(r"(?:https?://)?(?:(?:www|m)\.)?youtube\.com/(?:shorts|live|v)/(?P<video_id>[a-zA-Z0-9_-]{11})").unwrap(),
+            captures: &["video_id"],
+            arg_mapping: &[("video_id", "video_id")],
+            priority: 100,
+            description: "YouTube Shorts or live URL",
+        },
         InputPattern {
             id: "youtube_video_id",
             connector: "youtube",
@@ -291,7 +389,7 @@ fn build_default_patterns() -> Vec<InputPattern> {
             id: "hackernews_url",
             connector: "hackernews",
             tool: "get_post",
-            pattern: Regex::new(r"(?:https?://)?news\.ycombinator\.com/item\?id=(?P<item_id>\d+)").unwrap(),
+            pattern: Regex::new(r"(?:https?://)?news\.ycombinator\.com/item\?(?:[^\s#]*&)?id=(?P<item_id>\d+)").unwrap(),
             captures: &["item_id"],
             arg_mapping: &[("item_id", "id")],
             priority: 100,
@@ -307,13 +405,23 @@ fn build_default_patterns() -> Vec<InputPattern> {
             priority: 50,
             description: "Hacker News item ID (7-9 digits, optionally prefixed with hn:)",
         },
+        InputPattern {
+            id: "hackernews_prefixed_id",
+            connector: "hackernews",
+            tool: "get_post",
+            pattern: Regex::new(r"^(?i:hn):(?P<item_id>\d{1,10})$").unwrap(),
+            captures: &["item_id"],
+            arg_mapping: &[("item_id", "id")],
+            priority: 95,
+            description: "Hacker News item ID with hn: prefix",
+        },
 
         // === ArXiv ===
         InputPattern {
             id: "arxiv_url",
             connector: "arxiv",
             tool: "get",
-            pattern: Regex::new(r"(?:https?://)?arxiv\.org/(?:abs|pdf)/(?P<arxiv_id>\d{4}\.\d{4,5}(?:v\d+)?)").unwrap(),
+            pattern: Regex::new(r"(?:https?://)?(?:(?:www|export)\.)?arxiv\.org/(?:abs|pdf|html)/(?P<arxiv_id>(?:\d{4}\.\d{4,5}|[a-z-]+(?:\.[A-Z]{2})?/\d{7})(?:v\d+)?)").unwrap(),
             captures: &["arxiv_id"],
             arg_mapping: &[("arxiv_id", "paper_id")],
             priority: 100,
@@ -333,7 +441,7 @@ fn build_default_patterns() -> Vec<InputPattern> {
             id: "arxiv_old_id",
             connector: "arxiv",
             tool: "get",
-            pattern: Regex::new(r"^(?:arXiv:|arxiv:)?(?P<arxiv_id>[a-z-]+/\d{7})$").unwrap(),
+            pattern: Regex::new(r"^(?:arXiv:|arxiv:)?(?P<arxiv_id>[a-z-]+(?:\.[A-Z]{2})?/\d{7}(?:v\d+)?)$").unwrap(),
             captures: &["arxiv_id"],
             arg_mapping: &[("arxiv_id", "paper_id")],
             priority: 90,
@@ -361,6 +469,16 @@ fn build_default_patterns() -> Vec<InputPattern> {
             priority: 80,
             description: "PubMed ID (7-8 digits, optionally prefixed with PMID:)",
         },
+        InputPattern {
+            id: "pubmed_prefixed_id",
+            connector: "pubmed",
+            tool: "get",
+            pattern: Regex::new(r"^(?i:pmid|pubmed):\s*(?P<pmid>\d{1,8})$").unwrap(),
+            captures: &["pmid"],
+            arg_mapping: &[("pmid", "pmid")],
+            priority: 95,
+            description: "PubMed ID with PMID: prefix",
+        },
 
         // === DOI ===
         InputPattern {
@@ -383,6 +501,26 @@ fn build_default_patterns() -> Vec<InputPattern> {
             priority: 90,
             description: "DOI (e.g., 10.1234/example)",
         },
+        InputPattern {
+            id: "doi_in_url",
+            connector: "semantic-scholar",
+            tool: "get_paper_details",
+            pattern: Regex::new(r"^https?://[^\s]+?[/=](?P<doi>10\.\d{4,9}/[^\s?#&]+)").unwrap(),
+            captures: &["doi"],
+            arg_mapping: &[("doi", "paper_id")],
+            priority: 60,
+            description: "Publisher URL containing a DOI",
+        },
+        InputPattern {
+            id: "crossref_doi",
+            connector: "crossref",
+            tool: "get_work",
+            pattern: Regex::new(r"^crossref:(?P<doi>10\.\d{4,}/[^\s]+)$").unwrap(),
+            captures: &["doi"],
+            arg_mapping: &[("doi", "doi")],
+            priority: 95,
+            description: "Crossref DOI metadata (e.g., crossref:10.1234/example)",
+        },
 
         // === Semantic Scholar ===
         InputPattern {
@@ -396,6 +534,58 @@ fn build_default_patterns() -> Vec<InputPattern> {
             description: "Semantic Scholar paper URL",
         },
 
+        // === ISBN / Open Library ===
+        InputPattern {
+            id: "isbn_prefixed",
+            connector: "openlibrary",
+            tool: "get_edition",
+            pattern: Regex::new(r"^(?i:isbn)(?:-?1[03])?:?\s*(?P<isbn>(?:\d[\- ]?){9}[\dXx]|(?:\d[\- ]?){12}\d)$").unwrap(),
+            captures: &["isbn"],
+            arg_mapping: &[("isbn", "isbn")],
+            priority: 95,
+            description: "ISBN with prefix (e.g., ISBN 978-0-441-01359-3)",
+        },
+        InputPattern {
+            id: "isbn_bare",
+            connector: "openlibrary",
+            tool: "get_edition",
+            pattern: Regex::new(r"^(?P<isbn>(?:\d[\- ]?){9}[\dXx]|(?:\d[\- ]?){12}\d)$").unwrap(),
+            captures: &["isbn"],
+            arg_mapping: &[("isbn", "isbn")],
+            priority: 85,
+            description: "ISBN-10 or ISBN-13 (checksum validated)",
+        },
+        InputPattern {
+            id: "openlibrary_isbn_url",
+            connector: "openlibrary",
+            tool: "get_edition",
+            pattern: Regex::new(r"(?:https?://)?(?:www\.)?openlibrary\.org/isbn/(?P<isbn>[\dXx-]{10,17})").unwrap(),
+            captures: &["isbn"],
+            arg_mapping: &[("isbn", "isbn")],
+            priority: 100,
+            description: "Open Library ISBN URL",
+        },
+        InputPattern {
+            id: "openlibrary_work_url",
+            connector: "openlibrary",
+            tool: "get_work",
+            pattern: Regex::new(r"(?:https?://)?(?:www\.)?openlibrary\.org/works/(?P<id>OL\d+W)").unwrap(),
+            captures: &["id"],
+            arg_mapping: &[("id", "id")],
+            priority: 100,
+            description: "Open Library work URL",
+        },
+        InputPattern {
+            id: "openlibrary_edition_url",
+            connector: "openlibrary",
+            tool: "get_edition",
+            pattern: Regex::new(r"(?:https?://)?(?:www\.)?openlibrary\.org/books/(?P<id>OL\d+M)").unwrap(),
+            captures: &["id"],
+            arg_mapping: &[("id", "id")],
+            priority: 100,
+            description: "Open Library edition URL",
+        },
+
         // === Wikipedia ===
         InputPattern {
             id: "wikipedia_url",
@@ -455,7 +645,7 @@ fn build_default_patterns() -> Vec<InputPattern> {
             id: "reddit_post_url",
             connector: "reddit",
             tool: "get",
-            pattern: Regex::new(r"(?P<post_url>(?:https?://)?(?:www\.)?reddit\.com/r/[a-zA-Z0-9_]+/comments/[a-z0-9]+(?:/[^\s?#]+)?)").unwrap(),
+            pattern: Regex::new(r"(?P<post_url>(?:https?://)?(?:(?:www|old|new|np|m)\.)?reddit\.com/r/[a-zA-Z0-9_]+/comments/[a-z0-9]+(?:/[^\s?#]+)?)").unwrap(),
             captures: &["post_url"],
             arg_mapping: &[("post_url", "post_url")],
             priority: 100,
@@ -487,7 +677,7 @@ fn build_default_patterns() -> Vec<InputPattern> {
             id: "twitter_tweet_url",
             connector: "x",
             tool: "get_tweet",
-            pattern: Regex::new(r"(?:https?://)?(?:www\.)?(?:twitter\.com|x\.com)/(?P<username>[a-zA-Z0-9_]+)/status/(?P<tweet_id>\d+)").unwrap(),
+            pattern: Regex::new(r"(?:^|[/.])(?:twitter|x|fxtwitter|vxtwitter|fixupx|fixvx)\.com/(?:i/web|(?P<username>[a-zA-Z0-9_]+))/status(?:es)?/(?P<tweet_id>\d+)").unwrap(),
             captures: &["username", "tweet_id"],
             arg_mapping: &[("tweet_id", "tweet_id")],
             priority: 100,
@@ -497,7 +687,7 @@ fn build_default_patterns() -> Vec<InputPattern> {
             id: "twitter_profile_url",
             connector: "x",
             tool: "get_profile",
-            pattern: Regex::new(r"(?:https?://)?(?:www\.)?(?:twitter\.com|x\.com)/(?P<username>[a-zA-Z0-9_]+)/?$").unwrap(),
+            pattern: Regex::new(r"^(?:https?://)?(?:(?:www|mobile)\.)?(?:twitter|x)\.com/(?P<username>[a-zA-Z0-9_]+)/?$").unwrap(),
             captures: &["username"],
             arg_mapping: &[("username", "username")],
             priority: 90,
@@ -687,16 +877,26 @@ fn get_pattern_example(pattern_id: &str) -> String {
         "youtube_url_watch" => "https://www.youtube.com/watch?v=dQw4w9WgXcQ",
         "youtube_url_short" => "https://youtu.be/dQw4w9WgXcQ",
         "youtube_url_embed" => "https://www.youtube.com/embed/dQw4w9WgXcQ",
+        "youtube_url_shorts" => "https://www.youtube.com/shorts/dQw4w9WgXcQ",
         "youtube_video_id" => "dQw4w9WgXcQ",
         "hackernews_url" => "https://news.ycombinator.com/item?id=38500000",
         "hackernews_id" => "38500000",
+        "hackernews_prefixed_id" => "hn:1",
         "arxiv_url" => "https://arxiv.org/abs/2301.07041",
         "arxiv_id" => "arXiv:2301.07041",
         "arxiv_old_id" => "hep-th/9901001",
         "pubmed_url" => "https://pubmed.ncbi.nlm.nih.gov/12345678",
-        "pubmed_id" => "PMID:12345678",
+        "pubmed_id" => "12345678",
+        "pubmed_prefixed_id" => "PMID:123456",
         "doi_url" => "https://doi.org/10.1038/nature12373",
         "doi_bare" => "10.1038/nature12373",
+        "doi_in_url" => "https://onlinelibrary.wiley.com/doi/full/10.1002/anie.201915678",
+        "isbn_prefixed" => "ISBN 978-0-441-01359-3",
+        "isbn_bare" => "9780441013593",
+        "openlibrary_isbn_url" => "https://openlibrary.org/isbn/9780441013593",
+        "openlibrary_work_url" => "https://openlibrary.org/works/OL893415W",
+        "openlibrary_edition_url" => "https://openlibrary.org/books/OL7353617M",
+        "crossref_doi" => "crossref:10.1038/nature12373",
         "semantic_scholar_url" => {
             "https://www.semanticscholar.org/paper/Attention-Is-All-You-Need/abc123..."
         }
@@ -833,6 +1033,118 @@ mod tests {
         assert_eq!(action.connector, "web");
     }
 
+    #[test]
+    fn test_crossref_and_extract_doi() {
+        let resolver = SmartResolver::new();
+
+        let action = resolver.resolve("crossref:10.1038/nature12373").unwrap();
+        assert_eq!(action.connector, "crossref");
+        assert_eq!(action.tool, "get_work");
+        assert_eq!(action.arguments.get("doi").unwrap(), "10.1038/nature12373");
+
+        assert_eq!(
+            extract_doi("https://doi.org/10.1038/nature12373").as_deref(),
+            Some("10.1038/nature12373")
+        );
+        assert_eq!(
+            extract_doi("https://www.biorxiv.org/content/10.1101/2024.01.01.000000v1.").as_deref(),
+            Some("10.1101/2024.01.01.000000v1")
+        );
+        assert_eq!(extract_doi("https://example.com/page"), None);
+    }
+
+    #[test]
+    fn test_isbn() {
+        let resolver = SmartResolver::new();
+
+        let action = resolver.resolve("ISBN 978-0-441-01359-3").unwrap();
+        assert_eq!(action.connector, "openlibrary");
+        assert_eq!(action.tool, "get_edition");
+        assert_eq!(action.pattern, "isbn_prefixed");
+        assert_eq!(action.arguments.get("isbn").unwrap(), "9780441013593");
+
+        let action = resolver.resolve("0-8044-2957-x").unwrap();
+        assert_eq!(action.arguments.get("isbn").unwrap(), "080442957X");
+
+        // A 13-digit number with a bad check digit is not an ISBN
+        assert!(resolver.resolve("9780441013594").is_none());
+        assert_eq!(
+            normalize_isbn("978 0 441 01359 3").as_deref(),
+            Some("9780441013593")
+        );
+    }
+
+    #[test]
+    fn test_identifier_routing() {
+        let resolver = SmartResolver::new();
+
+        // arXiv DOIs go to the arxiv connector rather than Semantic Scholar
+        let action = resolver
+            .resolve("https://doi.org/10.48550/arXiv.2301.07041")
+            .unwrap();
+        assert_eq!(action.connector, "arxiv");
+        assert_eq!(action.arguments.get("paper_id").unwrap(), "2301.07041");
+
+        let action = resolver
+            .resolve("https://onlinelibrary.wiley.com/doi/full/10.1002/anie.201915678")
+            .unwrap();
+        assert_eq!(action.connector, "semantic-scholar");
+        assert_eq!(action.pattern, "doi_in_url");
+        assert_eq!(
+            action.arguments.get("paper_id").unwrap(),
+            "10.1002/anie.201915678"
+        );
+
+        let action = resolver
+            .resolve("https://www.youtube.com/watch?feature=shared&v=dQw4w9WgXcQ")
+            .unwrap();
+        assert_eq!(action.arguments.get("video_id").unwrap(), "dQw4w9WgXcQ");
+        let action = resolver
+            .resolve("https://youtube.com/shorts/dQw4w9WgXcQ")
+            .unwrap();
+        assert_eq!(action.connector, "youtube");
+
+        let action = resolver
+            .resolve("https://fxtwitter.com/i/web/status/1234567890")
+            .unwrap();
+        assert_eq!(action.tool, "get_tweet");
+        assert_eq!(action.arguments.get("tweet_id").unwrap(), "1234567890");
+        let action = resolver.resolve("https://dropbox.com/about").unwrap();
+        assert_eq!(action.connector, "web");
+
+        let action = resolver.resolve("PMID: 1234").unwrap();
+        assert_eq!(action.connector, "pubmed");
+        assert_eq!(action.arguments.get("pmid").unwrap(), "1234");
+
+        let action = resolver
+            .resolve("https://arxiv.org/abs/math.GT/0309136v2")
+            .unwrap();
+        assert_eq!(
+            action.arguments.get("paper_id").unwrap(),
+            "math.GT/0309136v2"
+        );
+    }
+
+    #[test]
+    fn test_identifiers_in_text() {
+        let resolver = SmartResolver::new();
+
+        let action = resolver
+            .resolve("Jumper et al. (doi:10.1038/s41586-021-03819-2).")
+            .unwrap();
+        assert_eq!(action.connector, "semantic-scholar");
+        assert_eq!(
+            action.arguments.get("paper_id").unwrap(),
+            "10.1038/s41586-021-03819-2"
+        );
+        assert!(action.confidence < 1.0);
+
+        // Bare numbers and 11-letter words in prose are not identifiers
+        assert!(resolver
+            .resolve("see programming notes from 12345678")
+            .is_none());
+    }
+
     #[test]
     #[cfg(feature = "all-connectors")]
     fn resolver_patterns_reference_real_tools() {
@@ -0,0 +1,426 @@
+//! Temporary MCP resources for tool results too large to return inline.
+//!
+//! When a result is bigger than the threshold, the full payload is kept in memory and split into
+//! chunks, each readable through `resources/read` at `arivu://results/<id>/<part>`. The tool call
+//! itself returns an outline of the result (structure kept, long strings and lists cut short)
+//! with a resource link per chunk, so clients with small context windows can decide what to read.
+//!
+//! | Variable | Meaning |
+//! |----------|---------|
+//! | `ARIVU_MCP_RESOURCE_THRESHOLD` | Results larger than this many characters are stored (default 60000, `0` or `off` disables) |
+//! | `ARIVU_MCP_RESOURCE_CHUNK_CHARS` | Characters per chunk (default 40000) |
+//! | `ARIVU_MCP_RESOURCE_TTL_SECS` | How long stored results stay readable (default 3600) |
+
+use rmcp::model::{
+    AnnotateAble, CallToolResult, RawContent, RawResource, Resource, ResourceContents,
+};
+use serde_json::{json, Map, Value};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::error::ConnectorError;
+
+pub const URI_PREFIX: &str = "arivu://results/";
+pub const DEFAULT_THRESHOLD_CHARS: usize = 60_000;
+pub const DEFAULT_CHUNK_CHARS: usize = 40_000;
+pub const DEFAULT_TTL_SECS: u64 = 3_600;
+
+/// Oldest results are dropped past this many.
+const MAX_RESULTS: usize = 32;
+/// Strings in the outline are cut to this many characters.
+const PREVIEW_CHARS: usize = 300;
+/// Arrays in the outline keep this many items.
+const PREVIEW_ITEMS: usize = 3;
+/// Nesting below this depth is summarized rather than shown.
+const PREVIEW_DEPTH: usize = 5;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResultStoreConfig {
+    /// Results larger than this many characters are stored; 0 turns storing off
+    pub threshold_chars: usize,
+    pub chunk_chars: usize,
+    pub ttl: Duration,
+}
+
+impl Default for ResultStoreConfig {
+    fn default() -> Self {
+        Self {
+            threshold_chars: DEFAULT_THRESHOLD_CHARS,
+            chunk_chars: DEFAULT_CHUNK_CHARS,
+            ttl: Duration::from_secs(DEFAULT_TTL_SECS),
+        }
+    }
+}
+
+impl ResultStoreConfig {
+    /// Defaults overridden by `ARIVU_MCP_RESOURCE_THRESHOLD`, `ARIVU_MCP_RESOURCE_CHUNK_CHARS`
+    /// and `ARIVU_MCP_RESOURCE_TTL_SECS`.
+    pub fn from_env() -> Result<Self, ConnectorError> {
+        let mut config = Self::default();
+        if let Some(threshold) = env_var("ARIVU_MCP_RESOURCE_THRESHOLD") {
+            config.threshold_chars = if threshold.eq_ignore_ascii_case("off") {
+                0
+            } else {
+                parse_number("ARIVU_MCP_RESOURCE_THRESHOLD", &threshold)?
+            };
+        }
+        if let Some(chunk) = env_var("ARIVU_MCP_RESOURCE_CHUNK_CHARS") {
+            config.chunk_chars = parse_number("ARIVU_MCP_RESOURCE_CHUNK_CHARS", &chunk)?.max(1);
+        }
+        if let Some(ttl) = env_var("ARIVU_MCP_RESOURCE_TTL_SECS") {
+            config.ttl = Duration::from_secs(parse_number("ARIVU_MCP_RESOURCE_TTL_SECS", &ttl)?);
+        }
+        Ok(config)
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.threshold_chars > 0
+    }
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+fn parse_number<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, ConnectorError> {
+    value.parse().map_err(|_| {
+        ConnectorError::InvalidInput(format!("{} must be a number, got '{}'", name, value))
+    })
+}
+
+struct StoredResult {
+    id: String,
+    tool: String,
+    mime_type: &'static str,
+    stored_at: Instant,
+    chunks: Vec<String>,
+}
+
+/// In-memory store of oversized tool results, exposed as MCP resources.
+pub struct ResultStore {
+    config: ResultStoreConfig,
+    results: VecDeque<StoredResult>,
+    /// Keeps URIs from an earlier server process from resolving to a different result
+    session: u64,
+    next_id: u64,
+}
+
+impl ResultStore {
+    pub fn new(config: ResultStoreConfig) -> Self {
+        Self {
+            config,
+            results: VecDeque::new(),
+            session: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            next_id: 1,
+        }
+    }
+
+    pub fn config(&self) -> &ResultStoreConfig {
+        &self.config
+    }
+
+    /// Whether a result of `chars` characters should be stored instead of returned.
+    pub fn should_store(&self, chars: usize) -> bool {
+        self.config.enabled() && chars > self.config.threshold_chars
+    }
+
+    /// Keep the full payload of `result` and return an outline with links to its chunks.
+    pub fn store(&mut self, tool: &str, result: &CallToolResult) -> CallToolResult {
+        self.prune();
+
+        let (payload, mime_type) = match &result.structured_content {
+            Some(value) => (
+                serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string()),
+                "application/json",
+            ),
+            None => (
+                result
+                    .content
+                    .iter()
+                    .filter_map(|c| match &c.raw {
+                        RawContent::Text(t) => Some(t.text.as_str()),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n\n"),
+                "text/plain",
+            ),
+        };
+        let size_chars = payload.chars().count();
+        let chunks = split_chunks(&payload, self.config.chunk_chars);
+
+        let id = format!("{:x}-{}", self.session, self.next_id);
+        self.next_id += 1;
+        let stored = StoredResult {
+            id,
+            tool: tool.to_string(),
+            mime_type,
+            stored_at: Instant::now(),
+            chunks,
+        };
+
+        let parts: Vec<Value> = stored
+            .chunks
+            .iter()
+            .enumerate()
+            .map(|(i, chunk)| {
+                json!({
+                    "uri": chunk_uri(&stored.id, i + 1),
+                    "part": i + 1,
+                    "chars": chunk.chars().count(),
+                })
+            })
+            .collect();
+        let shape = match &result.structured_content {
+            Some(value) => outline(value, 0),
+            None => Value::String(preview(&payload, PREVIEW_CHARS * 4)),
+        };
+        let summary = json!({
+            "stored_as_resource": true,
+            "tool": tool,
+            "size_chars": size_chars,
+            "mime_type": mime_type,
+            "expires_in_secs": self.config.ttl.as_secs(),
+            "resources": parts,
+            "outline": shape,
+            "note": "The full result was too large to return inline. Read the resources in order \
+                     with resources/read; together they make up the complete payload.",
+        });
+
+        // Links kept from the original result still point somewhere useful
+        let mut content: Vec<_> = result
+            .content
+            .iter()
+            .filter(|c| matches!(c.raw, RawContent::ResourceLink(_)))
+            .cloned()
+            .collect();
+        content.extend(
+            stored_resources(&stored)
+                .into_iter()
+                .map(|r| RawContent::ResourceLink(r.raw).no_annotation()),
+        );
+
+        self.results.push_back(stored);
+        while self.results.len() > MAX_RESULTS {
+            self.results.pop_front();
+        }
+
+        CallToolResult {
+            content,
+            structured_content: Some(summary),
+            is_error: result.is_error,
+            meta: None,
+        }
+    }
+
+    /// Chunks of stored results that have not expired.
+    pub fn list(&mut self) -> Vec<Resource> {
+        self.prune();
+        self.results.iter().flat_map(stored_resources).collect()
+    }
+
+    /// Read one chunk. Errors with `ResourceNotFound` for unknown or expired URIs.
+    pub fn read(&mut self, uri: &str) -> Result<Vec<ResourceContents>, ConnectorError> {
+        self.prune();
+        let (id, part) = uri
+            .strip_prefix(URI_PREFIX)
+            .and_then(|rest| rest.rsplit_once('/'))
+            .ok_or(ConnectorError::ResourceNotFound)?;
+        let part: usize = part.parse().map_err(|_| ConnectorError::ResourceNotFound)?;
+        let chunk = self
+            .results
+            .iter()
+            .find(|r| r.id == id)
+            .and_then(|r| r.chunks.get(part.checked_sub(1)?))
+            .ok_or(ConnectorError::ResourceNotFound)?;
+        Ok(vec![ResourceContents::text(chunk.clone(), uri)])
+    }
+
+    fn prune(&mut self) {
+        let ttl = self.config.ttl;
+        self.results.retain(|r| r.stored_at.elapsed() <= ttl);
+    }
+}
+
+/// Whether `uri` names a stored result rather than a connector resource.
+pub fn is_result_uri(uri: &str) -> bool {
+    uri.starts_with(URI_PREFIX)
+}
+
+fn chunk_uri(id: &str, part: usize) -> String {
+    format!("{}{}/{}", URI_PREFIX, id, part)
+}
+
+fn stored_resources(stored: &StoredResult) -> Vec<Resource> {
+    let total = stored.chunks.len();
+    stored
+        .chunks
+        .iter()
+        .enumerate()
+        .map(|(i, chunk)| Resource {
+            raw: RawResource {
+                uri: chunk_uri(&stored.id, i + 1),
+                name: format!("{} result part {}/{}", stored.tool, i + 1, total),
+                title: None,
+                description: Some(format!(
+                    "Part {} of {} of a stored {} result",
+                    i + 1,
+                    total,
+                    stored.tool
+                )),
+                mime_type: Some(stored.mime_type.to_string()),
+                size: u32::try_from(chunk.len()).ok(),
+                icons: None,
+            },
+            annotations: None,
+        })
+        .collect()
+}
+
+/// Split `text` into pieces of at most `chunk_chars` characters, at line breaks where one falls
+/// in the second half of a piece.
+fn split_chunks(text: &str, chunk_chars: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let hard_end = rest
+            .char_indices()
+            .nth(chunk_chars)
+            .map_or(rest.len(), |(i, _)| i);
+        let mut end = hard_end;
+        if hard_end < rest.len() {
+            let half = rest
+                .char_indices()
+                .nth(chunk_chars / 2)
+                .map_or(0, |(i, _)| i);
+            if let Some(nl) = rest[..hard_end].rfind('\n').filter(|&nl| nl >= half) {
+                end = nl + 1;
+            }
+        }
+        chunks.push(rest[..end].to_string());
+        rest = &rest[end..];
+    }
+    if chunks.is_empty() {
+        chunks.push(String::new());
+    }
+    chunks
+}
+
+fn preview(text: &str, max_chars: usize) -> String {
+    let total = text.chars().count();
+    if total <= max_chars {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(max_chars).collect();
+    format!("{}... ({} chars)", cut.trim_end(), total)
+}
+
+/// The shape of a result with long strings and lists cut short.
+fn outline(value: &Value, depth: usize) -> Value {
+    match value {
+        Value::String(s) => Value::String(preview(s, PREVIEW_CHARS)),
+        Value::Array(items) if depth >= PREVIEW_DEPTH => {
+            Value::String(format!("[{} items]", items.len()))
+        }
+        Value::Object(map) if depth >= PREVIEW_DEPTH => {
+            Value::String(format!("{{{} fields}}", map.len()))
+        }
+        Value::Array(items) => {
+            let mut out: Vec<Value> = items
+                .iter()
+                .take(PREVIEW_ITEMS)
+                .map(|v| outline(v, depth + 1))
+                .collect();
+            if items.len() > PREVIEW_ITEMS {
+                out.push(Value::String(format!(
+                    "... {} more items",
+                    items.len() - PREVIEW_ITEMS
+                )));
+            }
+            Value::Array(out)
+        }
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), outline(v, depth + 1)))
+                .collect::<Map<String, Value>>(),
+        ),
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_chunks_at_line_breaks() {
+        let text = "line of text\n".repeat(10);
+        let chunks = split_chunks(&text, 50);
+        assert_eq!(chunks.concat(), text);
+        assert!(chunks.iter().all(|c| c.chars().count() <= 50));
+        assert!(chunks[..chunks.len() - 1].iter().all(|c| c.ends_with('\n')));
+        assert_eq!(split_chunks("abcdef", 4), vec!["abcd", "ef"]);
+
+        let value = json!({
+            "title": "Short",
+            "body": "x".repeat(1_000),
+            "items": [1, 2, 3, 4, 5],
+        });
+        assert_eq!(
+            outline(&value, 0),
+            json!({
+                "title": "Short",
+                "body": format!("{}... (1000 chars)", "x".repeat(PREVIEW_CHARS)),
+                "items": [1, 2, 3, "... 2 more items"],
+            })
+        );
+    }
+
+    #[test]
+    fn stores_large_results_as_resources() {
+        let mut store = ResultStore::new(ResultStoreConfig {
+            threshold_chars: 100,
+            chunk_chars: 60,
+            ttl: Duration::from_secs(60),
+        });
+        assert!(!store.should_store(100));
+        assert!(store.should_store(101));
+
+        let original = CallToolResult {
+            content: Vec::new(),
+            structured_content: Some(json!({"text": "word ".repeat(40)})),
+            is_error: Some(false),
+            meta: None,
+        };
+        let summary = store.store("web/scrape_url", &original);
+        let structured = summary.structured_content.unwrap();
+        let parts = structured["resources"].as_array().unwrap();
+        assert!(parts.len() > 1);
+        assert_eq!(summary.content.len(), parts.len());
+        assert_eq!(store.list().len(), parts.len());
+
+        let mut payload = String::new();
+        for part in parts {
+            let uri = part["uri"].as_str().unwrap();
+            assert!(is_result_uri(uri));
+            match &store.read(uri).unwrap()[0] {
+                ResourceContents::TextResourceContents { text, .. } => payload.push_str(text),
+                other => panic!("unexpected contents {:?}", other),
+            }
+        }
+        assert_eq!(
+            serde_json::from_str::<Value>(&payload).unwrap(),
+            original.structured_content.unwrap()
+        );
+        assert!(matches!(
+            store.read(&format!("{}missing/1", URI_PREFIX)),
+            Err(ConnectorError::ResourceNotFound)
+        ));
+    }
+}
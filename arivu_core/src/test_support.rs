@@ -0,0 +1,167 @@
+// src/test_support.rs
+//
+// A connector double for tests of the registry, the connector wrappers and
+// the MCP server. It records every request it is handed and answers with a
+// fixed result; `wait` runs as a detached call that blocks until released.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use rmcp::model::*;
+use serde_json::{json, Value};
+use tokio::sync::Notify;
+
+use crate::auth::AuthDetails;
+use crate::capabilities::ConnectorConfigSchema;
+use crate::error::ConnectorError;
+use crate::utils::{structured_result_with_text, tool};
+use crate::Connector;
+
+pub(crate) struct StubConnector {
+    name: &'static str,
+    result: Value,
+    /// Requests as they reached the connector, in order
+    pub received: Arc<Mutex<Vec<CallToolRequestParam>>>,
+    /// Lets a pending `wait` call finish
+    pub release: Arc<Notify>,
+}
+
+impl StubConnector {
+    /// A connector called `name` whose tools all return `result` as structured content.
+    pub fn new(name: &'static str, result: Value) -> Self {
+        Self {
+            name,
+            result,
+            received: Arc::default(),
+            release: Arc::default(),
+        }
+    }
+
+    fn respond(&self, request: &CallToolRequestParam) -> Result<CallToolResult, ConnectorError> {
+        self.received.lock().unwrap().push(request.clone());
+        structured_result_with_text(&self.result, None)
+    }
+}
+
+#[async_trait]
+impl Connector for StubConnector {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn description(&self) -> &'static str {
+        "Test connector"
+    }
+
+    async fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            tools: Some(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    async fn initialize(
+        &self,
+        _request: InitializeRequestParam,
+    ) -> Result<InitializeResult, ConnectorError> {
+        Ok(InitializeResult {
+            protocol_version: ProtocolVersion::LATEST,
+            capabilities: self.capabilities().await,
+            server_info: Implementation {
+                name: self.name().to_string(),
+                title: None,
+                version: "0.1.0".to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: None,
+        })
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListResourcesResult, ConnectorError> {
+        Ok(ListResourcesResult {
+            resources: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        _request: ReadResourceRequestParam,
+    ) -> Result<Vec<ResourceContents>, ConnectorError> {
+        Err(ConnectorError::ResourceNotFound)
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListToolsResult, ConnectorError> {
+        let schema = json!({ "type": "object", "properties": {} });
+        Ok(ListToolsResult {
+            tools: vec![
+                tool("get", "Return the fixed result.", schema.clone()),
+                tool("wait", "Return the fixed result once released.", schema),
+            ],
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Result<CallToolResult, ConnectorError> {
+        self.respond(&request)
+    }
+
+    fn detached_call(
+        &self,
+        request: &CallToolRequestParam,
+    ) -> Option<BoxFuture<'static, Result<CallToolResult, ConnectorError>>> {
+        if request.name != "wait" {
+            return None;
+        }
+        let result = self.respond(request);
+        let release = Arc::clone(&self.release);
+        Some(Box::pin(async move {
+            release.notified().await;
+            result
+        }))
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+    ) -> Result<ListPromptsResult, ConnectorError> {
+        Ok(ListPromptsResult {
+            prompts: vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(&self, name: &str) -> Result<Prompt, ConnectorError> {
+        Err(ConnectorError::InvalidParams(format!(
+            "Prompt '{}' not found",
+            name
+        )))
+    }
+
+    async fn get_auth_details(&self) -> Result<AuthDetails, ConnectorError> {
+        Ok(AuthDetails::new())
+    }
+
+    async fn set_auth_details(&mut self, _details: AuthDetails) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    async fn test_auth(&self) -> Result<(), ConnectorError> {
+        Ok(())
+    }
+
+    fn config_schema(&self) -> ConnectorConfigSchema {
+        ConnectorConfigSchema { fields: vec![] }
+    }
+}
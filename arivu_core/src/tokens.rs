@@ -0,0 +1,276 @@
+//! Token counting and budget-aware trimming.
+//!
+//! `count_tokens` estimates what a BPE tokenizer such as `cl100k_base` would produce without
+//! shipping its vocabulary: text is split the way those tokenizers pre-tokenize it (words with
+//! their leading space, digit groups, punctuation runs, whitespace runs) and each piece is priced
+//! by length and script. On English prose and JSON it lands within about 10% of the real count,
+//! which is close enough to size outputs; it is not meant for billing.
+//!
+//! MCP tools accept a per-call `max_result_tokens` argument, which the server removes before the
+//! call reaches the connector so it never clashes with a tool's own arguments. [`fit_value`] trims
+//! structured results to it by dropping the most deeply nested list items first and the last items
+//! of each list before the first, so a thread keeps its title and top comments and loses deep
+//! replies.
+
+use serde_json::Value;
+use std::cmp::Reverse;
+
+/// Argument name for the per-call output budget the MCP server applies.
+pub const MAX_RESULT_TOKENS_ARG: &str = "max_result_tokens";
+
+/// Estimated token count of `text`.
+pub fn count_tokens(text: &str) -> usize {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        // A single space belongs to the piece after it
+        let start = if c == ' ' && chars.get(i + 1).is_some_and(|n| !n.is_whitespace()) {
+            i + 1
+        } else {
+            i
+        };
+        let first = chars[start];
+        let class = CharClass::of(first);
+        let mut end = start + 1;
+        while end < chars.len() && CharClass::of(chars[end]) == class && class.joins() {
+            end += 1;
+        }
+        let len = end - start;
+        tokens += match class {
+            CharClass::Latin => latin_word_tokens(len),
+            CharClass::Digit => len.div_ceil(3),
+            CharClass::Space | CharClass::Newline => 1,
+            CharClass::Punct => len.div_ceil(2),
+            CharClass::Ideograph => len,
+            CharClass::OtherLetter => len.div_ceil(2),
+            CharClass::Symbol => {
+                if first as u32 >= 0x1F000 {
+                    2
+                } else {
+                    1
+                }
+            }
+        };
+        i = end;
+    }
+    tokens
+}
+
+/// Estimated token count of a JSON value as it would be serialized.
+pub fn count_value_tokens(value: &Value) -> usize {
+    count_tokens(&value.to_string())
+}
+
+/// The token budget in argument `name` of a tool call, when it is a positive integer.
+pub fn budget_arg(args: &serde_json::Map<String, Value>, name: &str) -> Option<usize> {
+    args.get(name)
+        .and_then(Value::as_u64)
+        .filter(|n| *n > 0)
+        .map(|n| n as usize)
+}
+
+/// Remove the `max_result_tokens` argument from a tool call and return its budget.
+pub fn take_max_result_tokens(args: &mut serde_json::Map<String, Value>) -> Option<usize> {
+    let budget = budget_arg(args, MAX_RESULT_TOKENS_ARG);
+    args.remove(MAX_RESULT_TOKENS_ARG);
+    budget
+}
+
+/// Common words are one token; longer ones split into pieces of about five letters.
+fn latin_word_tokens(len: usize) -> usize {
+    if len <= 8 {
+        1
+    } else {
+        len.div_ceil(5)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CharClass {
+    Latin,
+    Digit,
+    Space,
+    Newline,
+    Punct,
+    /// CJK ideographs, kana and Hangul: about a token each
+    Ideograph,
+    /// Other alphabets (Cyrillic, Greek, Arabic, Devanagari, ...)
+    OtherLetter,
+    /// Emoji and other non-ASCII symbols, priced one by one
+    Symbol,
+}
+
+impl CharClass {
+    fn of(c: char) -> Self {
+        match c {
+            '\n' | '\r' => CharClass::Newline,
+            c if c.is_whitespace() => CharClass::Space,
+            c if c.is_ascii_alphabetic() => CharClass::Latin,
+            c if c.is_ascii_digit() => CharClass::Digit,
+            c if c.is_ascii() => CharClass::Punct,
+            '\u{00C0}'..='\u{024F}' => CharClass::Latin,
+            '\u{1100}'..='\u{11FF}'
+            | '\u{3040}'..='\u{30FF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{AC00}'..='\u{D7AF}'
+            | '\u{F900}'..='\u{FAFF}' => CharClass::Ideograph,
+            c if c.is_alphabetic() => CharClass::OtherLetter,
+            c if c.is_numeric() => CharClass::Digit,
+            _ => CharClass::Symbol,
+        }
+    }
+
+    /// Whether neighbouring characters of this class form one piece.
+    fn joins(&self) -> bool {
+        !matches!(self, CharClass::Ideograph | CharClass::Symbol)
+    }
+}
+
+/// Drop list items from `value` until it fits in `max_tokens`: items of the most deeply nested
+/// lists go first, and within a level the last items of each list go before the first. Returns
+/// how many items were dropped. Values that are still too large once every list is empty are
+/// left for string truncation.
+pub fn fit_value(value: &mut Value, max_tokens: usize) -> usize {
+    let mut total = count_value_tokens(value);
+    if total <= max_tokens {
+        return 0;
+    }
+
+    let mut dropped = 0;
+    for level in (1..=list_depth(value)).rev() {
+        let mut items = Vec::new();
+        collect_items(value, level, 0, String::new(), &mut items);
+        // Last items first; at the same position, later lists before earlier ones
+        items.reverse();
+        items.sort_by_key(|(_, index, _)| Reverse(*index));
+
+        let mut remove = Vec::new();
+        for (parent, index, cost) in items {
+            if total <= max_tokens {
+                break;
+            }
+            total = total.saturating_sub(cost);
+            remove.push((parent, index));
+        }
+        // Highest indices come first, so earlier removals don't shift later ones
+        for (parent, index) in &remove {
+            if let Some(Value::Array(list)) = value.pointer_mut(parent) {
+                list.remove(*index);
+            }
+        }
+        dropped += remove.len();
+        if total <= max_tokens {
+            break;
+        }
+    }
+    dropped
+}
+
+/// How many lists deep `value` nests.
+fn list_depth(value: &Value) -> usize {
+    match value {
+        Value::Array(items) => 1 + items.iter().map(list_depth).max().unwrap_or(0),
+        Value::Object(map) => map.values().map(list_depth).max().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Items of lists nested `level` deep, as (JSON pointer of the list, index, token cost).
+fn collect_items(
+    value: &Value,
+    level: usize,
+    depth: usize,
+    pointer: String,
+    out: &mut Vec<(String, usize, usize)>,
+) {
+    match value {
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                if depth + 1 == level {
+                    // The separating comma is a token too
+                    out.push((pointer.clone(), i, count_value_tokens(item) + 1));
+                } else {
+                    collect_items(item, level, depth + 1, format!("{}/{}", pointer, i), out);
+                }
+            }
+        }
+        Value::Object(map) => {
+            for (key, item) in map {
+                let key = key.replace('~', "~0").replace('/', "~1");
+                collect_items(item, level, depth, format!("{}/{}", pointer, key), out);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn estimates_tokens_like_a_bpe_tokenizer() {
+        assert_eq!(count_tokens(""), 0);
+        // cl100k_base: "Hello", ",", " world", "!"
+        assert_eq!(count_tokens("Hello, world!"), 4);
+        // Punctuation pairs merge and digits go in threes: "{\"", "id", "\":", " 123", "45", "}"
+        assert_eq!(count_tokens("{\"id\": 12345}"), 6);
+        assert_eq!(count_tokens("line one\n\nline two"), 5);
+        assert_eq!(count_tokens("日本語"), 3);
+
+        let prose = "The quick brown fox jumps over the lazy dog. ".repeat(20);
+        let estimate = count_tokens(&prose);
+        // cl100k_base gives about ten tokens per sentence
+        assert!((180..=220).contains(&estimate), "{}", estimate);
+    }
+
+    #[test]
+    fn takes_the_result_budget_and_leaves_other_arguments() {
+        let mut args = json!({"query": "rust", "max_tokens": 512, "max_result_tokens": 2000});
+        let args = args.as_object_mut().unwrap();
+        assert_eq!(take_max_result_tokens(args), Some(2000));
+        assert!(!args.contains_key(MAX_RESULT_TOKENS_ARG));
+        assert_eq!(budget_arg(args, "max_tokens"), Some(512));
+
+        // Invalid budgets are dropped too, so they never reach the connector
+        let mut args = json!({"max_result_tokens": 0}).as_object().unwrap().clone();
+        assert_eq!(take_max_result_tokens(&mut args), None);
+        assert!(args.is_empty());
+        assert_eq!(budget_arg(&args, "max_tokens"), None);
+    }
+
+    #[test]
+    fn fits_values_by_dropping_deep_and_late_items() {
+        let reply = |text: &str, replies: Vec<Value>| json!({"text": text, "comments": replies});
+        let long = "word ".repeat(40);
+        let mut thread = json!({
+            "title": "Story",
+            "comments": [
+                reply(&long, vec![reply(&long, vec![reply(&long, vec![])])]),
+                reply(&long, vec![reply(&long, vec![])]),
+                reply(&long, vec![]),
+            ],
+        });
+        let before = count_value_tokens(&thread);
+        assert_eq!(fit_value(&mut thread, before), 0);
+
+        // Room for everything but two replies: the deepest one goes, then the later thread's
+        let budget = before - 2 * (count_tokens(&long) + 6);
+        let dropped = fit_value(&mut thread, budget);
+        assert!(count_value_tokens(&thread) <= budget);
+        assert_eq!(dropped, 2);
+        assert_eq!(thread["title"], "Story");
+        assert_eq!(thread["comments"].as_array().unwrap().len(), 3);
+        assert_eq!(thread["comments"][0]["comments"][0]["comments"], json!([]));
+        assert_eq!(thread["comments"][0]["comments"][0]["text"], long);
+        assert_eq!(thread["comments"][1]["comments"], json!([]));
+
+        let mut tiny = thread.clone();
+        fit_value(&mut tiny, 20);
+        assert_eq!(tiny, json!({"title": "Story", "comments": []}));
+    }
+}
@@ -0,0 +1,129 @@
+// src/tool_context.rs
+//
+// Per-call context for long-running tools: MCP progress notifications and
+// cancellation. The stdio transport scopes each tools/call in a context;
+// connectors report progress and poll for cancellation through the free
+// functions below, which are no-ops outside a scope (CLI, tests).
+
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use serde_json::{json, Value};
+use tokio::sync::mpsc::UnboundedSender;
+
+#[derive(Debug, Clone, Default)]
+pub struct ToolCallContext {
+    progress_token: Option<Value>,
+    notifier: Option<UnboundedSender<Value>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl ToolCallContext {
+    /// `progress_token` is the request's `_meta.progressToken`; notifications
+    /// are only sent when the client supplied one.
+    pub fn new(progress_token: Option<Value>, notifier: Option<UnboundedSender<Value>>) -> Self {
+        Self {
+            progress_token,
+            notifier,
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Flag shared with the running call; set it to request cancellation.
+    pub fn cancel_handle(&self) -> Arc<AtomicBool> {
+        self.cancelled.clone()
+    }
+
+    /// Whether the client cancelled this call.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Send a `notifications/progress` if the client asked for progress.
+    pub fn report_progress(&self, progress: f64, total: Option<f64>, message: impl Into<String>) {
+        let Some(token) = &self.progress_token else {
+            return;
+        };
+        let mut params = json!({
+            "progressToken": token,
+            "progress": progress,
+            "message": message.into(),
+        });
+        if let Some(total) = total {
+            params["total"] = json!(total);
+        }
+        self.notify("notifications/progress", params);
+    }
+
+    /// Send any other notification, e.g. `notifications/resources/updated`.
+    pub fn notify(&self, method: &str, params: Value) {
+        if let Some(notifier) = &self.notifier {
+            let _ = notifier.send(json!({
+                "jsonrpc": "2.0",
+                "method": method,
+                "params": params,
+            }));
+        }
+    }
+
+    pub async fn scope<F, Fut, T>(self, f: F) -> T
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = T>,
+    {
+        TOOL_CONTEXT.scope(self, f()).await
+    }
+}
+
+tokio::task_local! {
+    static TOOL_CONTEXT: ToolCallContext;
+}
+
+/// The current call's context, for work moved to a blocking thread where
+/// the task-local isn't visible. A detached default outside any scope.
+pub fn current() -> ToolCallContext {
+    TOOL_CONTEXT.try_with(|ctx| ctx.clone()).unwrap_or_default()
+}
+
+/// Whether the client cancelled the current tool call.
+pub fn is_cancelled() -> bool {
+    TOOL_CONTEXT
+        .try_with(|ctx| ctx.is_cancelled())
+        .unwrap_or(false)
+}
+
+/// Send a `notifications/progress` for the current tool call.
+pub fn report_progress(progress: f64, total: Option<f64>, message: impl Into<String>) {
+    let message = message.into();
+    let _ = TOOL_CONTEXT.try_with(|ctx| ctx.report_progress(progress, total, message));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn reports_progress_and_cancellation_in_scope() {
+        assert!(!is_cancelled());
+        report_progress(1.0, None, "outside any scope");
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let ctx = ToolCallContext::new(Some(json!("tok-1")), Some(tx));
+        let cancel = ctx.cancel_handle();
+        let seen = ctx
+            .scope(|| async {
+                report_progress(2.0, Some(10.0), "page 2");
+                let before = is_cancelled();
+                cancel.store(true, Ordering::Relaxed);
+                (before, is_cancelled())
+            })
+            .await;
+        assert_eq!(seen, (false, true));
+
+        let note = rx.recv().await.unwrap();
+        assert_eq!(note["method"], "notifications/progress");
+        assert_eq!(note["params"]["progressToken"], "tok-1");
+        assert_eq!(note["params"]["total"], 10.0);
+    }
+}
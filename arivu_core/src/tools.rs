@@ -244,6 +244,27 @@ impl Tools {
             }
         }
 
+        #[cfg(feature = "brave-search")]
+        {
+            if let Ok(c) =
+                crate::connectors::brave_search::BraveSearchConnector::new(AuthDetails::new()).await
+            {
+                connectors.insert(
+                    "brave-search".to_string(),
+                    Arc::new(Mutex::new(Box::new(c))),
+                );
+            }
+        }
+
+        #[cfg(feature = "ddg-search")]
+        {
+            if let Ok(c) =
+                crate::connectors::ddg_search::DdgSearchConnector::new(AuthDetails::new()).await
+            {
+                connectors.insert("ddg-search".to_string(), Arc::new(Mutex::new(Box::new(c))));
+            }
+        }
+
         Tools {
             connectors,
             store: None,
@@ -311,8 +332,7 @@ impl Tools {
             arguments: Some(arg_map),
         };
 
-        let c = conn.lock().await;
-        c.call_tool(req).await
+        crate::call_locked_connector(&conn, req).await
     }
 
     /// Set authentication details for a specific provider.
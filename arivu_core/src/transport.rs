@@ -1,19 +1,28 @@
 use serde_json::Value;
+use std::collections::HashMap;
 use std::io::{self, BufRead, BufReader, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader as AsyncBufReader};
 use tokio::sync::mpsc;
 use tracing::{debug, error, info};
 
 use crate::mcp_server::JsonRpcHandler;
+use crate::tool_context::ToolCallContext;
+
+/// Cancellation flags of running tools/call requests, keyed by request id.
+type InFlight = Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>;
 
 /// Stdio transport for MCP server
 pub struct StdioTransport {
-    handler: JsonRpcHandler,
+    handler: Arc<JsonRpcHandler>,
 }
 
 impl StdioTransport {
     pub fn new(handler: JsonRpcHandler) -> Self {
-        Self { handler }
+        Self {
+            handler: Arc::new(handler),
+        }
     }
 
     /// Run the stdio transport, reading from stdin and writing to stdout
@@ -53,30 +62,45 @@ impl StdioTransport {
                 }
             }
         });
+        drop(tx);
+
+        // Responses and progress notifications from concurrent tool calls
+        // share a single writer so lines never interleave
+        let (out_tx, mut out_rx) = mpsc::unbounded_channel::<Value>();
+        let writer = tokio::spawn(async move {
+            while let Some(message) = out_rx.recv().await {
+                if let Err(e) = write_message(&message).await {
+                    error!("Error writing to stdout: {}", e);
+                    break;
+                }
+            }
+        });
 
         // Process messages
+        let in_flight = InFlight::default();
         while let Some(line) = rx.recv().await {
-            if let Err(e) = self.process_line(&line).await {
-                error!("Error processing line: {}", e);
-            }
+            self.process_line(&line, &out_tx, &in_flight).await;
         }
 
+        // The writer finishes once every in-flight call has responded
+        drop(out_tx);
+        let _ = writer.await;
         Ok(())
     }
 
-    /// Process a single line of input
-    async fn process_line(&self, line: &str) -> io::Result<()> {
+    /// Process a single line of input. Tool calls run on their own task so
+    /// that a later `notifications/cancelled` can reach them.
+    async fn process_line(
+        &self,
+        line: &str,
+        out: &mpsc::UnboundedSender<Value>,
+        in_flight: &InFlight,
+    ) {
         debug!("Processing line: {}", line);
 
         // Parse JSON-RPC request
-        match serde_json::from_str::<Value>(line) {
-            Ok(request) => {
-                // Handle the request
-                let response = self.handler.handle_request(request).await;
-
-                // Write response to stdout
-                self.write_response(&response).await?;
-            }
+        let request = match serde_json::from_str::<Value>(line) {
+            Ok(request) => request,
             Err(e) => {
                 error!("Failed to parse JSON-RPC request: {}", e);
 
@@ -91,26 +115,70 @@ impl StdioTransport {
                     "id": null
                 });
 
-                self.write_response(&error_response).await?;
+                let _ = out.send(error_response);
+                return;
+            }
+        };
+
+        match request.get("method").and_then(|m| m.as_str()) {
+            Some("notifications/cancelled") => {
+                let Some(key) = request
+                    .pointer("/params/requestId")
+                    .map(|id| id.to_string())
+                else {
+                    return;
+                };
+                let flags = in_flight.lock().unwrap_or_else(|e| e.into_inner());
+                if let Some(flag) = flags.get(&key) {
+                    debug!("Cancelling request {}", key);
+                    flag.store(true, Ordering::Relaxed);
+                }
+            }
+            Some("tools/call") => {
+                let progress_token = request.pointer("/params/_meta/progressToken").cloned();
+                let ctx = ToolCallContext::new(progress_token, Some(out.clone()));
+                let key = request.get("id").map(|id| id.to_string());
+                if let Some(key) = &key {
+                    in_flight
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .insert(key.clone(), ctx.cancel_handle());
+                }
+                let handler = self.handler.clone();
+                let out = out.clone();
+                let in_flight = in_flight.clone();
+                tokio::spawn(async move {
+                    let response = ctx.scope(|| handler.handle_request(request)).await;
+                    if let Some(key) = key {
+                        in_flight
+                            .lock()
+                            .unwrap_or_else(|e| e.into_inner())
+                            .remove(&key);
+                    }
+                    let _ = out.send(response);
+                });
+            }
+            _ => {
+                // Handle the request
+                let response = self.handler.handle_request(request).await;
+                let _ = out.send(response);
             }
         }
-
-        Ok(())
     }
+}
 
-    /// Write a response to stdout
-    async fn write_response(&self, response: &Value) -> io::Result<()> {
-        let mut stdout = tokio::io::stdout();
-        let response_str = serde_json::to_string(response)?;
+/// Write one JSON-RPC message to stdout
+async fn write_message(message: &Value) -> io::Result<()> {
+    let mut stdout = tokio::io::stdout();
+    let message_str = serde_json::to_string(message)?;
 
-        stdout.write_all(response_str.as_bytes()).await?;
-        stdout.write_all(b"\n").await?;
-        stdout.flush().await?;
+    stdout.write_all(message_str.as_bytes()).await?;
+    stdout.write_all(b"\n").await?;
+    stdout.flush().await?;
 
-        debug!("Sent response: {}", response_str);
+    debug!("Sent message: {}", message_str);
 
-        Ok(())
-    }
+    Ok(())
 }
 
 /// Synchronous stdio transport for environments that don't support async
@@ -0,0 +1,941 @@
+// src/utils/markdown.rs
+//
+// HTML → Markdown conversion shared by connectors that return page, feed or
+// message content. The input is parsed leniently (unclosed tags, stray end
+// tags and bare `<` are all tolerated) into a small tree and rendered as
+// CommonMark with GFM tables. Scripts, styles, forms and other non-content
+// elements are dropped.
+
+use url::Url;
+
+/// Elements dropped together with everything inside them.
+const DROPPED_TAGS: &[&str] = &[
+    "script", "style", "noscript", "template", "svg", "head", "title", "iframe", "canvas",
+    "object", "embed", "select", "button", "input", "textarea", "audio", "video", "map",
+];
+
+/// Elements whose content is not HTML.
+const RAW_TEXT_TAGS: &[&str] = &["script", "style", "textarea", "title", "xmp"];
+
+const VOID_TAGS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+const BLOCK_TAGS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "body",
+    "caption",
+    "center",
+    "dd",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hgroup",
+    "hr",
+    "html",
+    "li",
+    "main",
+    "menu",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "tr",
+    "ul",
+];
+
+/// How [`html_to_markdown_with`] renders a document.
+#[derive(Debug, Clone)]
+pub struct MarkdownOptions {
+    /// Render `<a href>` as `[text](url)`; only the link text otherwise
+    pub links: bool,
+    /// Render `<img>` as `![alt](src)`; dropped otherwise
+    pub images: bool,
+    /// Resolve relative link and image URLs against this URL
+    pub base_url: Option<Url>,
+    /// Further elements to drop with their content (e.g. `nav`, `footer`)
+    pub skip_tags: Vec<&'static str>,
+    /// Drop elements carrying any of these classes
+    pub skip_classes: Vec<&'static str>,
+}
+
+impl Default for MarkdownOptions {
+    fn default() -> Self {
+        Self {
+            links: true,
+            images: true,
+            base_url: None,
+            skip_tags: Vec::new(),
+            skip_classes: Vec::new(),
+        }
+    }
+}
+
+/// Convert HTML to Markdown, keeping links and images.
+pub fn html_to_markdown(html: &str) -> String {
+    html_to_markdown_with(html, &MarkdownOptions::default())
+}
+
+/// Convert HTML to Markdown: headings, emphasis, links, images, lists, block
+/// quotes, fenced code blocks (with the language from `language-*` classes)
+/// and GFM tables. Tables used for layout (one column, or tables nested in
+/// cells, as in most HTML email) are flattened into paragraphs.
+pub fn html_to_markdown_with(html: &str, options: &MarkdownOptions) -> String {
+    let root = parse(html);
+    let mut blocks = Vec::new();
+    Renderer { options }.blocks(&root.children, &mut blocks);
+    blocks.join("\n\n")
+}
+
+#[derive(Debug)]
+enum Node {
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Debug)]
+struct Element {
+    name: String,
+    attrs: Vec<(String, String)>,
+    children: Vec<Node>,
+}
+
+impl Element {
+    fn new(name: String, attrs: Vec<(String, String)>) -> Self {
+        Self {
+            name,
+            attrs,
+            children: Vec::new(),
+        }
+    }
+
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn has_class(&self, class: &str) -> bool {
+        self.attr("class")
+            .is_some_and(|c| c.split_whitespace().any(|c| c == class))
+    }
+
+    fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|n| match n {
+            Node::Element(e) => Some(e),
+            Node::Text(_) => None,
+        })
+    }
+}
+
+fn is_block(name: &str) -> bool {
+    BLOCK_TAGS.contains(&name)
+}
+
+// ---------------------------------------------------------------------------
+// Parsing
+// ---------------------------------------------------------------------------
+
+struct Tag {
+    name: String,
+    attrs: Vec<(String, String)>,
+    self_closing: bool,
+    len: usize,
+}
+
+fn parse(html: &str) -> Element {
+    let mut stack = vec![Element::new("#root".to_string(), Vec::new())];
+    let mut i = 0;
+    while i < html.len() {
+        let rest = &html[i..];
+        if rest.starts_with("<!--") {
+            i += rest.find("-->").map_or(rest.len(), |end| end + 3);
+            continue;
+        }
+        if rest.starts_with("<!") || rest.starts_with("<?") {
+            i += rest.find('>').map_or(rest.len(), |end| end + 1);
+            continue;
+        }
+        if let Some((name, len)) = end_tag(rest) {
+            close(&mut stack, &name);
+            i += len;
+            continue;
+        }
+        if let Some(tag) = start_tag(rest) {
+            i += tag.len;
+            close_implied_by(&mut stack, &tag.name);
+            let mut el = Element::new(tag.name, tag.attrs);
+            if RAW_TEXT_TAGS.contains(&el.name.as_str()) {
+                let end = find_end_tag(&html[i..], &el.name).unwrap_or(html.len() - i);
+                el.children.push(Node::Text(html[i..i + end].to_string()));
+                i += end;
+                if let Some((_, len)) = end_tag(&html[i..]) {
+                    i += len;
+                }
+                append(&mut stack, Node::Element(el));
+            } else if tag.self_closing || VOID_TAGS.contains(&el.name.as_str()) {
+                append(&mut stack, Node::Element(el));
+            } else {
+                stack.push(el);
+            }
+            continue;
+        }
+        // Text up to the next tag; a `<` that starts no tag is literal text
+        let first = rest.chars().next().map_or(1, char::len_utf8);
+        let end = rest[first..].find('<').map_or(rest.len(), |p| p + first);
+        append_text(&mut stack, &rest[..end]);
+        i += end;
+    }
+    while stack.len() > 1 {
+        pop_into_parent(&mut stack);
+    }
+    stack.pop().expect("root element")
+}
+
+fn tag_name_len(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .take_while(|b| b.is_ascii_alphanumeric() || **b == b'-' || **b == b':')
+        .count()
+}
+
+/// `</name ...>` at the start of `rest`: the lowercased name and the tag length.
+fn end_tag(rest: &str) -> Option<(String, usize)> {
+    let bytes = rest.as_bytes();
+    if !rest.starts_with("</") || !bytes.get(2).is_some_and(u8::is_ascii_alphabetic) {
+        return None;
+    }
+    let name_end = 2 + tag_name_len(&bytes[2..]);
+    let gt = rest[name_end..].find('>')?;
+    Some((rest[2..name_end].to_ascii_lowercase(), name_end + gt + 1))
+}
+
+/// Offset of the first `</name` in `text`, ignoring case.
+fn find_end_tag(text: &str, name: &str) -> Option<usize> {
+    text.match_indices("</").map(|(i, _)| i).find(|&i| {
+        text.as_bytes()
+            .get(i + 2..i + 2 + name.len())
+            .is_some_and(|candidate| candidate.eq_ignore_ascii_case(name.as_bytes()))
+    })
+}
+
+/// `<name attr="value" ...>` at the start of `rest`.
+fn start_tag(rest: &str) -> Option<Tag> {
+    let bytes = rest.as_bytes();
+    if bytes.first() != Some(&b'<') || !bytes.get(1).is_some_and(u8::is_ascii_alphabetic) {
+        return None;
+    }
+    let mut p = 1 + tag_name_len(&bytes[1..]);
+    let name = rest[1..p].to_ascii_lowercase();
+    let mut attrs = Vec::new();
+    let mut self_closing = false;
+    let skip_whitespace = |p: &mut usize| {
+        while bytes.get(*p).is_some_and(u8::is_ascii_whitespace) {
+            *p += 1;
+        }
+    };
+    loop {
+        skip_whitespace(&mut p);
+        match *bytes.get(p)? {
+            b'>' => {
+                p += 1;
+                break;
+            }
+            b'/' => {
+                p += 1;
+                self_closing = bytes.get(p) == Some(&b'>');
+                continue;
+            }
+            _ => {}
+        }
+        let start = p;
+        while bytes
+            .get(p)
+            .is_some_and(|b| !b.is_ascii_whitespace() && !matches!(b, b'=' | b'>' | b'/'))
+        {
+            p += 1;
+        }
+        if p == start {
+            // A stray `=`
+            p += 1;
+            continue;
+        }
+        let key = rest[start..p].to_ascii_lowercase();
+        skip_whitespace(&mut p);
+        let mut value = String::new();
+        if bytes.get(p) == Some(&b'=') {
+            p += 1;
+            skip_whitespace(&mut p);
+            match bytes.get(p) {
+                Some(&quote) if quote == b'"' || quote == b'\'' => {
+                    let end = p + 1 + rest[p + 1..].find(quote as char)?;
+                    value = decode(&rest[p + 1..end]);
+                    p = end + 1;
+                }
+                _ => {
+                    let start = p;
+                    while bytes
+                        .get(p)
+                        .is_some_and(|b| !b.is_ascii_whitespace() && *b != b'>')
+                    {
+                        p += 1;
+                    }
+                    value = decode(&rest[start..p]);
+                }
+            }
+        }
+        attrs.push((key, value));
+    }
+    Some(Tag {
+        name,
+        attrs,
+        self_closing,
+        len: p,
+    })
+}
+
+fn pop_into_parent(stack: &mut Vec<Element>) {
+    if let Some(el) = stack.pop() {
+        append(stack, Node::Element(el));
+    }
+}
+
+fn append(stack: &mut [Element], node: Node) {
+    if let Some(parent) = stack.last_mut() {
+        parent.children.push(node);
+    }
+}
+
+fn append_text(stack: &mut [Element], text: &str) {
+    if let Some(parent) = stack.last_mut() {
+        match parent.children.last_mut() {
+            Some(Node::Text(existing)) => existing.push_str(text),
+            _ => parent.children.push(Node::Text(text.to_string())),
+        }
+    }
+}
+
+/// Close the innermost open element called `name`, if there is one.
+fn close(stack: &mut Vec<Element>, name: &str) {
+    if let Some(idx) = stack.iter().rposition(|el| el.name == name) {
+        if idx > 0 {
+            while stack.len() > idx {
+                pop_into_parent(stack);
+            }
+        }
+    }
+}
+
+/// Elements HTML closes implicitly, e.g. an open `<li>` when the next `<li>`
+/// starts, or an open `<p>` when a block starts.
+fn close_implied_by(stack: &mut Vec<Element>, name: &str) {
+    match name {
+        "li" => close_implied(stack, &["li"], |n| {
+            matches!(n, "ul" | "ol" | "menu" | "table")
+        }),
+        "dt" | "dd" => close_implied(stack, &["dt", "dd"], |n| matches!(n, "dl" | "table")),
+        "tr" => close_implied(stack, &["tr"], |n| {
+            matches!(n, "table" | "thead" | "tbody" | "tfoot")
+        }),
+        "td" | "th" => close_implied(stack, &["td", "th"], |n| matches!(n, "tr" | "table")),
+        "thead" | "tbody" | "tfoot" => {
+            close_implied(stack, &["thead", "tbody", "tfoot"], |n| n == "table")
+        }
+        "option" => close_implied(stack, &["option"], |n| n == "select"),
+        _ => {}
+    }
+    if is_block(name) {
+        close_implied(stack, &["p"], |n| n != "p" && is_block(n));
+    }
+}
+
+fn close_implied(stack: &mut Vec<Element>, targets: &[&str], boundary: impl Fn(&str) -> bool) {
+    for idx in (1..stack.len()).rev() {
+        let name = stack[idx].name.as_str();
+        if targets.contains(&name) {
+            while stack.len() > idx {
+                pop_into_parent(stack);
+            }
+            return;
+        }
+        if boundary(name) {
+            return;
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Rendering
+// ---------------------------------------------------------------------------
+
+struct Renderer<'a> {
+    options: &'a MarkdownOptions,
+}
+
+impl Renderer<'_> {
+    fn skipped(&self, el: &Element) -> bool {
+        let name = el.name.as_str();
+        DROPPED_TAGS.contains(&name)
+            || self.options.skip_tags.contains(&name)
+            || (name == "img" && !self.options.images)
+            || el.attr("hidden").is_some()
+            || el.attr("aria-hidden") == Some("true")
+            || el.attr("style").is_some_and(|style| {
+                let style: String = style.chars().filter(|c| !c.is_whitespace()).collect();
+                style.to_ascii_lowercase().contains("display:none")
+            })
+            || self.options.skip_classes.iter().any(|c| el.has_class(c))
+    }
+
+    /// Render a run of nodes as Markdown blocks, gathering inline content
+    /// between block elements into paragraphs.
+    fn blocks(&self, nodes: &[Node], out: &mut Vec<String>) {
+        let mut inline = String::new();
+        for node in nodes {
+            match node {
+                Node::Text(text) => inline.push_str(&collapse(&decode(text))),
+                Node::Element(el) if self.skipped(el) => {}
+                Node::Element(el) if is_block(&el.name) => {
+                    flush(&mut inline, out);
+                    self.block(el, out);
+                }
+                Node::Element(el) => inline.push_str(&self.inline(el)),
+            }
+        }
+        flush(&mut inline, out);
+    }
+
+    fn block(&self, el: &Element, out: &mut Vec<String>) {
+        let name = el.name.as_str();
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let text = tidy_inline(&self.inline_children(el)).replace('\n', " ");
+                if !text.is_empty() {
+                    let level = name[1..].parse::<usize>().unwrap_or(1);
+                    out.push(format!("{} {}", "#".repeat(level), text));
+                }
+            }
+            "pre" => out.extend(self.code_block(el)),
+            "blockquote" => {
+                let mut inner = Vec::new();
+                self.blocks(&el.children, &mut inner);
+                if !inner.is_empty() {
+                    let quoted: Vec<String> = inner
+                        .join("\n\n")
+                        .lines()
+                        .map(|line| {
+                            if line.is_empty() {
+                                ">".to_string()
+                            } else {
+                                format!("> {}", line)
+                            }
+                        })
+                        .collect();
+                    out.push(quoted.join("\n"));
+                }
+            }
+            "ul" | "ol" | "menu" => {
+                let list = self.list(el);
+                if !list.is_empty() {
+                    out.push(list);
+                }
+            }
+            "li" => {
+                let item = self.list_item(el, "-");
+                if !item.is_empty() {
+                    out.push(item);
+                }
+            }
+            "dt" => {
+                let term = tidy_inline(&self.inline_children(el));
+                if !term.is_empty() {
+                    out.push(format!("**{}**", term));
+                }
+            }
+            "table" => self.table(el, out),
+            "hr" => out.push("---".to_string()),
+            _ => self.blocks(&el.children, out),
+        }
+    }
+
+    fn code_block(&self, el: &Element) -> Option<String> {
+        let mut code = String::new();
+        self.raw_text(el, &mut code);
+        let code = code.replace("\r\n", "\n");
+        let code = code.strip_prefix('\n').unwrap_or(&code).trim_end();
+        if code.trim().is_empty() {
+            return None;
+        }
+        let language = code_language(el)
+            .or_else(|| {
+                el.elements()
+                    .find(|c| c.name == "code")
+                    .and_then(code_language)
+            })
+            .unwrap_or_default();
+        let fence = "`".repeat(longest_run(code, '`').max(2) + 1);
+        Some(format!("{}{}\n{}\n{}", fence, language, code, fence))
+    }
+
+    fn list(&self, el: &Element) -> String {
+        let ordered = el.name == "ol";
+        let mut number = el
+            .attr("start")
+            .and_then(|s| s.trim().parse::<usize>().ok())
+            .unwrap_or(1);
+        let mut items: Vec<String> = Vec::new();
+        for child in el.elements() {
+            if self.skipped(child) {
+                continue;
+            }
+            if matches!(child.name.as_str(), "ul" | "ol" | "menu") {
+                // A list directly inside a list belongs to the previous item
+                let nested = self.list(child);
+                match items.last_mut() {
+                    _ if nested.is_empty() => {}
+                    Some(last) => {
+                        last.push('\n');
+                        last.push_str(&indent(&nested, "  "));
+                    }
+                    None => items.push(nested),
+                }
+                continue;
+            }
+            let marker = if ordered {
+                format!("{}.", number)
+            } else {
+                "-".to_string()
+            };
+            let item = self.list_item(child, &marker);
+            if !item.is_empty() {
+                items.push(item);
+                number += 1;
+            }
+        }
+        items.join("\n")
+    }
+
+    fn list_item(&self, el: &Element, marker: &str) -> String {
+        let mut blocks = Vec::new();
+        self.blocks(&el.children, &mut blocks);
+        let body = blocks.join("\n");
+        if body.trim().is_empty() {
+            return String::new();
+        }
+        let (first, rest) = body.split_once('\n').unwrap_or((&body, ""));
+        let mut item = format!("{} {}", marker, first);
+        if !rest.is_empty() {
+            item.push('\n');
+            item.push_str(&indent(rest, &" ".repeat(marker.len() + 1)));
+        }
+        item
+    }
+
+    fn table(&self, el: &Element, out: &mut Vec<String>) {
+        let mut rows = Vec::new();
+        let mut caption = None;
+        collect_rows(el, &mut rows, &mut caption);
+        if let Some(caption) = caption {
+            let text = tidy_inline(&self.inline_children(caption));
+            if !text.is_empty() {
+                out.push(text);
+            }
+        }
+
+        let width = rows.iter().map(|row| cell_count(row)).max().unwrap_or(0);
+        let layout = width <= 1
+            || el.attr("role") == Some("presentation")
+            || rows.iter().any(|row| row.elements().any(contains_table));
+        if layout {
+            for cell in rows.iter().flat_map(|row| row.elements()) {
+                if !self.skipped(cell) {
+                    self.blocks(&cell.children, out);
+                }
+            }
+            return;
+        }
+
+        let mut lines = Vec::new();
+        for row in &rows {
+            let mut cells = self.table_row(row);
+            if cells.is_empty() {
+                continue;
+            }
+            cells.resize(width, String::new());
+            lines.push(format!("| {} |", cells.join(" | ")));
+            if lines.len() == 1 {
+                lines.push(format!("|{}", " --- |".repeat(width)));
+            }
+        }
+        if !lines.is_empty() {
+            out.push(lines.join("\n"));
+        }
+    }
+
+    fn table_row(&self, row: &Element) -> Vec<String> {
+        let mut cells = Vec::new();
+        for cell in row.elements() {
+            if !matches!(cell.name.as_str(), "td" | "th") || self.skipped(cell) {
+                continue;
+            }
+            let mut blocks = Vec::new();
+            self.blocks(&cell.children, &mut blocks);
+            let text = blocks.join(" ").replace('\n', " ").replace('|', "\\|");
+            cells.push(text.trim().to_string());
+            for _ in 1..colspan(cell) {
+                cells.push(String::new());
+            }
+        }
+        cells
+    }
+
+    fn inline_children(&self, el: &Element) -> String {
+        let mut out = String::new();
+        for node in &el.children {
+            match node {
+                Node::Text(text) => out.push_str(&collapse(&decode(text))),
+                Node::Element(child) if self.skipped(child) => {}
+                Node::Element(child) => out.push_str(&self.inline(child)),
+            }
+        }
+        out
+    }
+
+    fn inline(&self, el: &Element) -> String {
+        match el.name.as_str() {
+            "br" => "\n".to_string(),
+            "strong" | "b" => wrap(&self.inline_children(el), "**"),
+            "em" | "i" | "cite" | "dfn" => wrap(&self.inline_children(el), "*"),
+            "del" | "s" | "strike" => wrap(&self.inline_children(el), "~~"),
+            "code" | "kbd" | "samp" | "tt" => {
+                let mut text = String::new();
+                self.raw_text(el, &mut text);
+                code_span(&collapse(&text))
+            }
+            "q" => wrap(&self.inline_children(el), "\""),
+            "a" => self.link(el),
+            "img" => self.image(el),
+            "hr" => " ".to_string(),
+            // A block inside inline content (e.g. a `<div>` in a link)
+            name if is_block(name) => format!(" {} ", self.inline_children(el)),
+            _ => self.inline_children(el),
+        }
+    }
+
+    fn link(&self, el: &Element) -> String {
+        let text = self.inline_children(el);
+        let href = el.attr("href").map(str::trim).unwrap_or_default();
+        let label = text.trim().replace('\n', " ");
+        if !self.options.links
+            || label.is_empty()
+            || href.is_empty()
+            || href.starts_with('#')
+            || href.to_ascii_lowercase().starts_with("javascript:")
+        {
+            return text;
+        }
+        let url = self.resolve(href);
+        let link = if label == href || label == url {
+            format!("<{}>", url)
+        } else {
+            format!("[{}]({})", label, url)
+        };
+        keep_padding(&text, &link)
+    }
+
+    fn image(&self, el: &Element) -> String {
+        let src = el
+            .attr("src")
+            .or_else(|| el.attr("data-src"))
+            .map(str::trim)
+            .unwrap_or_default();
+        // Inline data URIs are large and useless as text
+        if src.is_empty() || src.starts_with("data:") {
+            return String::new();
+        }
+        let alt = collapse(el.attr("alt").unwrap_or_default());
+        format!("![{}]({})", alt.trim(), self.resolve(src))
+    }
+
+    fn resolve(&self, href: &str) -> String {
+        let url = match &self.options.base_url {
+            Some(base) => base
+                .join(href)
+                .map(|u| u.to_string())
+                .unwrap_or_else(|_| href.to_string()),
+            None => href.to_string(),
+        };
+        url.replace(' ', "%20")
+    }
+
+    /// Text content as written, for code: entities decoded, `<br>` as a line break.
+    fn raw_text(&self, el: &Element, out: &mut String) {
+        for node in &el.children {
+            match node {
+                Node::Text(text) => out.push_str(&decode(text)),
+                Node::Element(child) if child.name == "br" => out.push('\n'),
+                Node::Element(child) if self.skipped(child) => {}
+                Node::Element(child) => self.raw_text(child, out),
+            }
+        }
+    }
+}
+
+fn collect_rows<'a>(
+    el: &'a Element,
+    rows: &mut Vec<&'a Element>,
+    caption: &mut Option<&'a Element>,
+) {
+    for child in el.elements() {
+        match child.name.as_str() {
+            "caption" => *caption = Some(child),
+            "thead" | "tbody" | "tfoot" => collect_rows(child, rows, caption),
+            "tr" => rows.push(child),
+            _ => {}
+        }
+    }
+}
+
+fn colspan(cell: &Element) -> usize {
+    cell.attr("colspan")
+        .and_then(|s| s.trim().parse::<usize>().ok())
+        .unwrap_or(1)
+        .clamp(1, 20)
+}
+
+fn cell_count(row: &Element) -> usize {
+    row.elements()
+        .filter(|c| matches!(c.name.as_str(), "td" | "th"))
+        .map(colspan)
+        .sum()
+}
+
+fn contains_table(el: &Element) -> bool {
+    el.name == "table" || el.elements().any(contains_table)
+}
+
+fn code_language(el: &Element) -> Option<String> {
+    let class = el.attr("class")?;
+    class.split_whitespace().find_map(|c| {
+        ["language-", "lang-", "highlight-source-"]
+            .iter()
+            .find_map(|prefix| c.strip_prefix(prefix))
+            .filter(|lang| !lang.is_empty())
+            .map(str::to_string)
+    })
+}
+
+fn decode(text: &str) -> String {
+    html_escape::decode_html_entities(text).into_owned()
+}
+
+/// Collapse whitespace runs (including non-breaking spaces) to one space.
+fn collapse(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !space {
+                out.push(' ');
+            }
+            space = true;
+        } else {
+            out.push(c);
+            space = false;
+        }
+    }
+    out
+}
+
+/// Inline content as a paragraph: single spaces, trimmed lines, at most one
+/// blank line in a row (from consecutive `<br>`s).
+fn tidy_inline(text: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.split('\n') {
+        let line = line
+            .split(' ')
+            .filter(|w| !w.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        if line.is_empty() && lines.last().map(String::is_empty).unwrap_or(true) {
+            continue;
+        }
+        lines.push(line);
+    }
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+fn flush(inline: &mut String, out: &mut Vec<String>) {
+    let text = tidy_inline(inline);
+    if !text.is_empty() {
+        out.push(text);
+    }
+    inline.clear();
+}
+
+/// `marker` around `content`, with surrounding whitespace kept outside so
+/// `<b> bold </b>` becomes ` **bold** `.
+fn wrap(content: &str, marker: &str) -> String {
+    let trimmed = content.trim();
+    if trimmed.is_empty() {
+        return content.to_string();
+    }
+    keep_padding(content, &format!("{}{}{}", marker, trimmed, marker))
+}
+
+fn keep_padding(original: &str, rendered: &str) -> String {
+    let lead = if original.starts_with(char::is_whitespace) {
+        " "
+    } else {
+        ""
+    };
+    let trail = if original.ends_with(char::is_whitespace) {
+        " "
+    } else {
+        ""
+    };
+    format!("{}{}{}", lead, rendered, trail)
+}
+
+fn code_span(text: &str) -> String {
+    let text = text.trim();
+    if text.is_empty() {
+        return String::new();
+    }
+    let ticks = "`".repeat(longest_run(text, '`') + 1);
+    if text.starts_with('`') || text.ends_with('`') {
+        format!("{} {} {}", ticks, text, ticks)
+    } else {
+        format!("{}{}{}", ticks, text, ticks)
+    }
+}
+
+fn longest_run(text: &str, ch: char) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+    for c in text.chars() {
+        if c == ch {
+            current += 1;
+            longest = longest.max(current);
+        } else {
+            current = 0;
+        }
+    }
+    longest
+}
+
+fn indent(text: &str, pad: &str) -> String {
+    text.lines()
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("{}{}", pad, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_text_structure() {
+        let html = r#"<html><head><title>T</title><style>p { color: red }</style></head><body>
+<h1>Intro &amp; <em>overview</em></h1>
+<p>Some <b>bold </b>text with a <a href="/docs/guide">guide</a> and <code>x &lt; y</code>.<br>
+Next line <img src="pic.png" alt="A pic"></p>
+<script>alert("<p>no</p>")</SCRIPT>
+<ul>
+  <li>One
+  <li>Two<ol start="3"><li>Three</li><li>Four</ol>
+</ul>
+<pre class="language-rust"><code>fn main() {
+    println!("&lt;hi&gt;");
+}
+</code></pre>
+<blockquote><p>Quoted</p><p>twice</p></blockquote>
+<hr>
+<p>Stray < sign</div> and end</p>
+</body></html>"#;
+        let options = MarkdownOptions {
+            base_url: Url::parse("https://example.com/a/b").ok(),
+            ..MarkdownOptions::default()
+        };
+        assert_eq!(
+            html_to_markdown_with(html, &options),
+            "# Intro & *overview*\n\n\
+Some **bold** text with a [guide](https://example.com/docs/guide) and `x < y`.\n\
+Next line ![A pic](https://example.com/a/pic.png)\n\n\
+- One\n- Two\n  3. Three\n  4. Four\n\n\
+```rust\nfn main() {\n    println!(\"<hi>\");\n}\n```\n\n\
+> Quoted\n>\n> twice\n\n\
+---\n\n\
+Stray < sign and end"
+        );
+    }
+
+    #[test]
+    fn renders_tables_and_flattens_layout_tables() {
+        let html = r#"<table><caption>Scores</caption>
+<thead><tr><th>Name<th>Score</thead>
+<tbody><tr><td>A|B<td>1
+<tr><td colspan="2">total: <b>3</b></td></tr></tbody></table>
+<table role="presentation"><tr><td><p>Hello</p></td></tr><tr><td>World</td></tr></table>"#;
+        assert_eq!(
+            html_to_markdown(html),
+            "Scores\n\n| Name | Score |\n| --- | --- |\n| A\\|B | 1 |\n| total: **3** |  |\n\nHello\n\nWorld"
+        );
+    }
+
+    #[test]
+    fn honours_skip_options() {
+        let html = r#"<nav><a href="/">Home</a></nav>
+<div class="content">Read <a href="https://example.org">the docs</a>.<img src="x.png">
+<sup class="reference">[1]</sup><span style="display: none">hidden</span></div>"#;
+        let options = MarkdownOptions {
+            links: false,
+            images: false,
+            skip_tags: vec!["nav"],
+            skip_classes: vec!["reference"],
+            ..MarkdownOptions::default()
+        };
+        assert_eq!(html_to_markdown_with(html, &options), "Read the docs.");
+        assert_eq!(
+            html_to_markdown("<p>See <a href=\"https://x.io\">https://x.io</a></p>"),
+            "See <https://x.io>"
+        );
+    }
+}
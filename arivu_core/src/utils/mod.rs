@@ -1,19 +1,26 @@
+use crate::auth_store::{AuthStore, FileAuthStore};
 use crate::error::ConnectorError;
 use chrono::{Datelike, Duration, Utc};
 #[cfg(feature = "browser-cookies")]
 use publicsuffix::{List, Psl};
-use rmcp::model::CallToolResult;
+use rmcp::model::{CallToolResult, Tool};
 #[cfg(all(feature = "browser-cookies", target_os = "macos"))]
 use rookie::safari;
 #[cfg(feature = "browser-cookies")]
 use rookie::{brave, chrome, common::enums::CookieToString, firefox};
 use serde::Serialize;
 use serde_json::{Map as JsonMap, Value as JsonValue};
+use std::borrow::Cow;
 use std::collections::HashSet;
 use std::future::Future;
+use std::sync::Arc;
 use thiserror::Error;
 use url::Url;
 
+mod markdown;
+
+pub use markdown::{html_to_markdown, html_to_markdown_with, MarkdownOptions};
+
 #[cfg(feature = "browser-cookies")]
 #[derive(Debug, Clone)]
 pub enum Browser {
@@ -253,6 +260,31 @@ pub fn clean_html_entities(text: &str) -> String {
         .replace("&amp;", "&")
 }
 
+/// Cut `text` to its first `max_chars` characters; true if anything was dropped.
+pub fn truncate_chars(text: &mut String, max_chars: usize) -> bool {
+    match text.char_indices().nth(max_chars) {
+        Some((idx, _)) => {
+            text.truncate(idx);
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod truncate_tests {
+    use super::*;
+
+    #[test]
+    fn truncates_on_char_boundaries() {
+        let mut text = "héllo wörld".to_string();
+        assert!(truncate_chars(&mut text, 2));
+        assert_eq!(text, "hé");
+        assert!(!truncate_chars(&mut text, 10));
+        assert_eq!(text, "hé");
+    }
+}
+
 pub struct Page<T, C> {
     pub items: Vec<T>,
     pub next_cursor: Option<C>,
@@ -534,6 +566,42 @@ pub fn html_to_text(html: &str) -> String {
     text.trim().to_string()
 }
 
+/// Flatten WebVTT or SRT captions into plain text.
+/// Drops headers, cue numbers, timings, NOTE/STYLE blocks and inline cue tags,
+/// and collapses lines repeated by roll-up captions.
+pub fn subtitles_to_text(body: &str) -> String {
+    use once_cell::sync::Lazy;
+    use regex::Regex;
+
+    static RE_CUE_TAGS: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]+>").unwrap());
+
+    let mut lines: Vec<String> = Vec::new();
+    let mut in_block = false;
+    for line in body.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            in_block = false;
+            continue;
+        }
+        if in_block
+            || line.starts_with("WEBVTT")
+            || line.contains("-->")
+            || line.chars().all(|c| c.is_ascii_digit())
+        {
+            continue;
+        }
+        if line.starts_with("NOTE") || line.starts_with("STYLE") {
+            in_block = true;
+            continue;
+        }
+        let cleaned = RE_CUE_TAGS.replace_all(line, "").trim().to_string();
+        if !cleaned.is_empty() && lines.last() != Some(&cleaned) {
+            lines.push(cleaned);
+        }
+    }
+    lines.join(" ")
+}
+
 //     html_escape::decode_html_entities(text).into_owned().replace("\n", " ").replace("&#39;", "'")
 // }
 
@@ -716,6 +784,27 @@ pub fn structured_result_with_text<T: Serialize>(
     })
 }
 
+/// Tool definition with a JSON object as its input schema.
+pub fn tool(name: &'static str, description: &'static str, schema: JsonValue) -> Tool {
+    Tool {
+        name: Cow::Borrowed(name),
+        title: None,
+        description: Some(Cow::Borrowed(description)),
+        input_schema: Arc::new(schema.as_object().expect("Schema object").clone()),
+        output_schema: None,
+        annotations: None,
+        icons: None,
+    }
+}
+
+/// Deserialize a tool call's arguments; missing arguments read as `{}`.
+pub fn parse_args<T: serde::de::DeserializeOwned>(
+    args: Option<JsonMap<String, JsonValue>>,
+) -> Result<T, ConnectorError> {
+    serde_json::from_value(JsonValue::Object(args.unwrap_or_default()))
+        .map_err(|e| ConnectorError::InvalidParams(e.to_string()))
+}
+
 // --- Uniform search filter helpers for connectors ---
 
 #[derive(Debug, Clone)]
@@ -854,3 +943,63 @@ pub fn build_filters_clause(filters: &SearchFilters) -> String {
         format!("\nFilters: {}", parts.join("; "))
     }
 }
+
+/// Gate for connectors whose write tools are off by default. A write needs the connector's
+/// `allow_writes` setting (`configured`, the saved connector config, or `env_var`) and a
+/// per-call `confirm=true` given once the user has approved it.
+pub fn write_gate(
+    connector: &str,
+    env_var: &str,
+    configured: Option<&str>,
+    confirm: bool,
+) -> Result<(), ConnectorError> {
+    let flag = configured
+        .map(str::to_string)
+        .or_else(|| {
+            FileAuthStore::new_default()
+                .load(connector)
+                .and_then(|m| m.get("allow_writes").cloned())
+        })
+        .or_else(|| std::env::var(env_var).ok());
+    let allowed = flag
+        .is_some_and(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "true" | "1" | "yes"));
+    check_write(connector, env_var, allowed, confirm)
+}
+
+fn check_write(
+    connector: &str,
+    env_var: &str,
+    allowed: bool,
+    confirm: bool,
+) -> Result<(), ConnectorError> {
+    if !allowed {
+        return Err(ConnectorError::InvalidParams(format!(
+            "{} write tools are disabled (read-only). Set allow_writes=true in the {} connector \
+config or {}=1 to enable them.",
+            connector, connector, env_var
+        )));
+    }
+    if !confirm {
+        return Err(ConnectorError::InvalidParams(format!(
+            "Pass confirm=true once the user has approved this write to {}.",
+            connector
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod write_gate_tests {
+    use super::*;
+
+    #[test]
+    fn writes_need_opt_in_and_confirmation() {
+        let env_var = "ARIVU_TEST_ALLOW_WRITES";
+        assert!(check_write("github", env_var, false, true).is_err());
+        assert!(check_write("github", env_var, true, false).is_err());
+        assert!(check_write("github", env_var, true, true).is_ok());
+        assert!(write_gate("github", env_var, Some("yes"), true).is_ok());
+        assert!(write_gate("github", env_var, Some("false"), true).is_err());
+        assert!(write_gate("github", env_var, Some("true"), false).is_err());
+    }
+}
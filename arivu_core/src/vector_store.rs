@@ -0,0 +1,547 @@
+//! Local vector store over the content index (feature `embeddings`).
+//!
+//! The store is derived from [`ContentIndex`]: [`VectorStore::sync`] splits each indexed entry
+//! into passages of about 1,200 characters, embeds the passages of entries it hasn't seen (or
+//! that were fetched again since) and drops those of entries that are gone. Vectors from
+//! different models can't be compared, so each model gets its own pair of files under
+//! `~/.arivu/vectors` (or `ARIVU_VECTOR_DIR`): `<model>.jsonl` with a header line and one passage
+//! per line, and `<model>.f32` with their unit vectors. Search is an exact cosine scan.
+//!
+//! [`semantic_search`] syncs before it searches, so everything fetched with `ARIVU_INDEX=on` can
+//! be found by meaning through the `semantic_search` MCP tool and `arivu index semantic`.
+
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tracing::warn;
+
+use crate::embeddings::{dot, EmbeddingBackend};
+use crate::index::{ContentIndex, IndexEntry};
+use crate::language::chunk_text;
+use crate::{ConnectorError, Tool};
+
+/// Name of the search tool over MCP.
+pub const TOOL_NAME: &str = "semantic_search";
+
+const PASSAGE_CHARS: usize = 1_200;
+const MAX_PASSAGES_PER_ENTRY: usize = 24;
+/// Entries embedded per request round; progress is saved even if a later round fails
+const SYNC_BATCH_ENTRIES: usize = 16;
+const MAX_LIMIT: usize = 50;
+
+/// One syncing process at a time; concurrent calls would embed the same entries twice
+static SYNC_LOCK: Lazy<tokio::sync::Mutex<()>> = Lazy::new(|| tokio::sync::Mutex::new(()));
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Header {
+    model: String,
+    dims: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Passage {
+    key: String,
+    source: String,
+    title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    /// `indexed_at` of the entry when it was embedded
+    indexed_at: i64,
+    text: String,
+}
+
+/// A passage that matched a semantic query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SemanticHit {
+    pub key: String,
+    pub source: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The best-matching passage of the entry
+    pub passage: String,
+    pub score: f32,
+}
+
+/// Hits for a query, best passage per entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SemanticSearchResult {
+    pub query: String,
+    pub model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    pub total_passages: usize,
+    /// Entries embedded by the sync that ran before this search
+    pub newly_embedded: usize,
+    pub hits: Vec<SemanticHit>,
+}
+
+/// What a sync changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncStats {
+    pub embedded: usize,
+    pub removed: usize,
+}
+
+/// Passages and their embeddings for one model.
+pub struct VectorStore {
+    model: String,
+    meta_path: PathBuf,
+    vector_path: PathBuf,
+    dims: usize,
+    passages: Vec<Passage>,
+    /// `passages.len() * dims` floats
+    vectors: Vec<f32>,
+}
+
+impl VectorStore {
+    /// `ARIVU_VECTOR_DIR`, or `~/.arivu/vectors`.
+    pub fn default_dir() -> PathBuf {
+        std::env::var("ARIVU_VECTOR_DIR")
+            .ok()
+            .filter(|d| !d.trim().is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| {
+                dirs::home_dir()
+                    .unwrap_or_else(|| PathBuf::from("."))
+                    .join(".arivu")
+                    .join("vectors")
+            })
+    }
+
+    /// Load the store for `model` from `dir`, or start an empty one. A store whose files don't
+    /// agree with each other is discarded and rebuilt by the next sync.
+    pub fn open(dir: &Path, model: &str) -> Result<Self, ConnectorError> {
+        std::fs::create_dir_all(dir)?;
+        let slug: String = model
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let mut store = Self {
+            model: model.to_string(),
+            meta_path: dir.join(format!("{}.jsonl", slug)),
+            vector_path: dir.join(format!("{}.f32", slug)),
+            dims: 0,
+            passages: Vec::new(),
+            vectors: Vec::new(),
+        };
+        if !store.meta_path.exists() {
+            return Ok(store);
+        }
+
+        let mut lines = BufReader::new(std::fs::File::open(&store.meta_path)?).lines();
+        let header: Option<Header> = lines
+            .next()
+            .transpose()?
+            .and_then(|line| serde_json::from_str(&line).ok());
+        let mut passages = Vec::new();
+        for line in lines {
+            match serde_json::from_str::<Passage>(&line?) {
+                Ok(passage) => passages.push(passage),
+                Err(_) => return Ok(store.discarded("unreadable passage")),
+            }
+        }
+        let bytes = std::fs::read(&store.vector_path).unwrap_or_default();
+        let vectors: Vec<f32> = bytes
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect();
+        match header {
+            Some(header)
+                if header.model == model && vectors.len() == passages.len() * header.dims =>
+            {
+                store.dims = header.dims;
+                store.passages = passages;
+                store.vectors = vectors;
+                Ok(store)
+            }
+            _ => Ok(store.discarded("header or vector count mismatch")),
+        }
+    }
+
+    fn discarded(self, reason: &str) -> Self {
+        warn!(
+            "Rebuilding vector store {}: {}",
+            self.meta_path.display(),
+            reason
+        );
+        self
+    }
+
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Number of stored passages.
+    pub fn len(&self) -> usize {
+        self.passages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.passages.is_empty()
+    }
+
+    /// Bring the store in line with `entries`: drop passages of entries that are gone or were
+    /// re-indexed, embed the rest, and save.
+    pub async fn sync(
+        &mut self,
+        entries: &[IndexEntry],
+        backend: &EmbeddingBackend,
+    ) -> Result<SyncStats, ConnectorError> {
+        let current: HashMap<&str, i64> = entries
+            .iter()
+            .map(|e| (e.key.as_str(), e.indexed_at))
+            .collect();
+        let before = self.stored_keys().len();
+        self.retain(|p| current.get(p.key.as_str()) == Some(&p.indexed_at));
+        let kept = self.stored_keys();
+        let mut stats = SyncStats {
+            removed: before - kept.len(),
+            ..SyncStats::default()
+        };
+
+        let pending: Vec<&IndexEntry> = entries
+            .iter()
+            .filter(|e| !kept.contains(e.key.as_str()))
+            .collect();
+        let mut result = Ok(());
+        for batch in pending.chunks(SYNC_BATCH_ENTRIES) {
+            let passages: Vec<Passage> = batch.iter().flat_map(|e| split_passages(e)).collect();
+            // The title gives each passage its context
+            let inputs: Vec<String> = passages
+                .iter()
+                .map(|p| format!("{}\n\n{}", p.title, p.text))
+                .collect();
+            match backend
+                .embed(&inputs)
+                .await
+                .and_then(|v| self.push(passages, v))
+            {
+                Ok(()) => stats.embedded += batch.len(),
+                Err(e) => {
+                    result = Err(e);
+                    break;
+                }
+            }
+        }
+
+        if stats != SyncStats::default() {
+            self.save()?;
+        }
+        result.map(|_| stats)
+    }
+
+    /// Entries ranked by their best passage's similarity to `query` (a unit vector from the
+    /// same model).
+    pub fn search(&self, query: &[f32], source: Option<&str>, limit: usize) -> Vec<SemanticHit> {
+        if self.dims == 0 || query.len() != self.dims {
+            return Vec::new();
+        }
+        let mut best: HashMap<&str, (usize, f32)> = HashMap::new();
+        for (i, passage) in self.passages.iter().enumerate() {
+            if source.is_some_and(|s| s != passage.source) {
+                continue;
+            }
+            let score = dot(query, &self.vectors[i * self.dims..(i + 1) * self.dims]);
+            let slot = best.entry(passage.key.as_str()).or_insert((i, score));
+            if score > slot.1 {
+                *slot = (i, score);
+            }
+        }
+
+        let mut ranked: Vec<(usize, f32)> = best.into_values().collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        ranked
+            .into_iter()
+            .take(limit.clamp(1, MAX_LIMIT))
+            .map(|(i, score)| {
+                let p = &self.passages[i];
+                SemanticHit {
+                    key: p.key.clone(),
+                    source: p.source.clone(),
+                    title: p.title.clone(),
+                    url: p.url.clone(),
+                    passage: p.text.clone(),
+                    score,
+                }
+            })
+            .collect()
+    }
+
+    fn stored_keys(&self) -> HashSet<&str> {
+        self.passages.iter().map(|p| p.key.as_str()).collect()
+    }
+
+    fn retain(&mut self, keep: impl Fn(&Passage) -> bool) {
+        let dims = self.dims;
+        let mut passages = Vec::with_capacity(self.passages.len());
+        let mut vectors = Vec::with_capacity(self.vectors.len());
+        for (i, passage) in self.passages.drain(..).enumerate() {
+            if keep(&passage) {
+                vectors.extend_from_slice(&self.vectors[i * dims..(i + 1) * dims]);
+                passages.push(passage);
+            }
+        }
+        self.passages = passages;
+        self.vectors = vectors;
+    }
+
+    fn push(
+        &mut self,
+        passages: Vec<Passage>,
+        vectors: Vec<Vec<f32>>,
+    ) -> Result<(), ConnectorError> {
+        for vector in &vectors {
+            if self.dims == 0 {
+                self.dims = vector.len();
+            }
+            if vector.len() != self.dims || vector.is_empty() {
+                return Err(ConnectorError::Other(format!(
+                    "{} returned a {}-dimensional vector; the store holds {} dimensions",
+                    self.model,
+                    vector.len(),
+                    self.dims
+                )));
+            }
+        }
+        self.passages.extend(passages);
+        self.vectors.extend(vectors.into_iter().flatten());
+        Ok(())
+    }
+
+    /// Write both files through temporary copies so readers never see half a store.
+    fn save(&self) -> Result<(), ConnectorError> {
+        let meta_tmp = self.meta_path.with_extension("jsonl.tmp");
+        let mut meta = BufWriter::new(std::fs::File::create(&meta_tmp)?);
+        let header = Header {
+            model: self.model.clone(),
+            dims: self.dims,
+        };
+        writeln!(meta, "{}", serde_json::to_string(&header)?)?;
+        for passage in &self.passages {
+            writeln!(meta, "{}", serde_json::to_string(passage)?)?;
+        }
+        meta.flush()?;
+
+        let vector_tmp = self.vector_path.with_extension("f32.tmp");
+        let bytes: Vec<u8> = self.vectors.iter().flat_map(|x| x.to_le_bytes()).collect();
+        std::fs::write(&vector_tmp, bytes)?;
+
+        std::fs::rename(&vector_tmp, &self.vector_path)?;
+        std::fs::rename(&meta_tmp, &self.meta_path)?;
+        Ok(())
+    }
+}
+
+/// Split an entry into passages; an entry without text is its title.
+fn split_passages(entry: &IndexEntry) -> Vec<Passage> {
+    let passage = |text: &str| Passage {
+        key: entry.key.clone(),
+        source: entry.source.clone(),
+        title: entry.title.clone(),
+        url: entry.url.clone(),
+        indexed_at: entry.indexed_at,
+        text: text.to_string(),
+    };
+    let (chunks, _) = chunk_text(
+        &entry.text,
+        PASSAGE_CHARS,
+        PASSAGE_CHARS * MAX_PASSAGES_PER_ENTRY,
+    );
+    if chunks.is_empty() {
+        return vec![passage(entry.title.trim())];
+    }
+    chunks.into_iter().map(passage).collect()
+}
+
+/// Sync the vector store for `backend` with `index`, then rank indexed entries by meaning.
+pub async fn semantic_search(
+    index: Arc<ContentIndex>,
+    backend: &EmbeddingBackend,
+    query: &str,
+    source: Option<&str>,
+    limit: usize,
+) -> Result<SemanticSearchResult, ConnectorError> {
+    let _guard = SYNC_LOCK.lock().await;
+    let model = backend.name();
+    let (entries, mut store) = tokio::task::spawn_blocking(move || {
+        let entries = index.entries()?;
+        let store = VectorStore::open(&VectorStore::default_dir(), &model)?;
+        Ok::<_, ConnectorError>((entries, store))
+    })
+    .await
+    .map_err(|e| ConnectorError::Other(format!("vector store: {}", e)))??;
+
+    let stats = store.sync(&entries, backend).await?;
+    let query_vector = backend
+        .embed(&[query.to_string()])
+        .await?
+        .pop()
+        .unwrap_or_default();
+
+    Ok(SemanticSearchResult {
+        query: query.to_string(),
+        model: store.model().to_string(),
+        source: source.map(str::to_string),
+        total_passages: store.len(),
+        newly_embedded: stats.embedded,
+        hits: store.search(&query_vector, source, limit),
+    })
+}
+
+/// The configured backend, or an error explaining how to set one.
+pub fn backend_from_env() -> Result<EmbeddingBackend, ConnectorError> {
+    EmbeddingBackend::from_env()?.ok_or_else(|| {
+        ConnectorError::InvalidInput(
+            "Semantic search needs an embedding backend: set ARIVU_EMBED_BACKEND to openai, \
+             ollama or hash"
+                .to_string(),
+        )
+    })
+}
+
+/// The MCP definition of the search tool.
+pub fn tool() -> Tool {
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "query": {
+                "type": "string",
+                "description": "What to look for, in natural language"
+            },
+            "source": {
+                "type": "string",
+                "description": "Only return entries from this connector (e.g. \"web\", \"pubmed\")"
+            },
+            "limit": {
+                "type": "integer",
+                "minimum": 1,
+                "maximum": MAX_LIMIT,
+                "description": "Maximum hits to return (default 10)"
+            }
+        },
+        "required": ["query"]
+    });
+    Tool {
+        name: TOOL_NAME.into(),
+        title: Some("Semantic Search".into()),
+        description: Some(
+            "Find previously fetched content by meaning, best passage per document. Embeds new \
+             index entries first with the ARIVU_EMBED_BACKEND model."
+                .into(),
+        ),
+        input_schema: Arc::new(
+            schema
+                .as_object()
+                .expect("Schema must be an object")
+                .clone(),
+        ),
+        output_schema: None,
+        annotations: None,
+        icons: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(key: &str, source: &str, text: &str, indexed_at: i64) -> IndexEntry {
+        IndexEntry {
+            key: key.to_string(),
+            source: source.to_string(),
+            kind: "document".to_string(),
+            title: key.to_string(),
+            url: None,
+            text: text.to_string(),
+            indexed_at,
+        }
+    }
+
+    #[tokio::test]
+    async fn syncs_with_the_index_and_ranks_by_similarity() {
+        let dir = std::env::temp_dir().join(format!("arivu-vectors-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let backend = EmbeddingBackend::Hash { dims: 256 };
+        let long = "Calorie restriction slows ageing in rodents. ".repeat(60);
+        let mut entries = vec![
+            entry(
+                "fasting",
+                "pubmed",
+                "Intermittent fasting extends lifespan in mice.",
+                1,
+            ),
+            entry(
+                "attention",
+                "arxiv",
+                "Transformers rely on self-attention layers.",
+                1,
+            ),
+            entry("ageing", "pubmed", &long, 1),
+        ];
+
+        let mut store = VectorStore::open(&dir, &backend.name()).unwrap();
+        let stats = store.sync(&entries, &backend).await.unwrap();
+        assert_eq!(
+            stats,
+            SyncStats {
+                embedded: 3,
+                removed: 0
+            }
+        );
+        assert!(store.len() > 3, "long entries are split into passages");
+
+        let query = &backend
+            .embed(&["fasting lifespan of mice".to_string()])
+            .await
+            .unwrap()[0];
+        let hits = store.search(query, None, 10);
+        assert_eq!(hits[0].key, "fasting");
+        assert_eq!(hits.len(), 3, "one hit per entry");
+        assert!(store
+            .search(query, Some("arxiv"), 10)
+            .iter()
+            .all(|h| h.source == "arxiv"));
+
+        // Re-fetched entries are re-embedded, removed ones dropped, and the rest kept
+        entries[0] = entry(
+            "fasting",
+            "pubmed",
+            "Time-restricted eating improved sleep.",
+            2,
+        );
+        entries.remove(1);
+        let mut reopened = VectorStore::open(&dir, &backend.name()).unwrap();
+        assert_eq!(reopened.len(), store.len());
+        let stats = reopened.sync(&entries, &backend).await.unwrap();
+        assert_eq!(
+            stats,
+            SyncStats {
+                embedded: 1,
+                removed: 2
+            }
+        );
+        assert_eq!(
+            reopened.sync(&entries, &backend).await.unwrap(),
+            SyncStats::default()
+        );
+        let query = &backend
+            .embed(&["eating and sleep".to_string()])
+            .await
+            .unwrap()[0];
+        assert_eq!(reopened.search(query, None, 1)[0].key, "fasting");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}